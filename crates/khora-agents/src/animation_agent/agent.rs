@@ -0,0 +1,245 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Defines the AnimationAgent — owns `LaneKind::Animation` lanes only.
+//!
+//! Per CLAD, an Agent owns exactly one `LaneKind` and stores **only** its
+//! own GORNA/strategy state. Frame timing is read from the shared
+//! [`FrameTime`](khora_core::utils::frame_time::FrameTime) service.
+
+use std::time::{Duration, Instant};
+
+use khora_core::agent::{Agent, AgentAffinity, AgentImportance, ExecutionPhase, ExecutionTiming};
+use khora_core::control::gorna::{
+    AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    StrategyOption,
+};
+use khora_core::lane::{AnimationBlendLimit, AnimationDeltaTime, LaneContext, LaneRegistry, Slot};
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::EngineContext;
+use khora_data::ecs::World;
+use khora_data::AudioEvents;
+use khora_lanes::animation_lane::{AnimationGraphLane, TimelineLane};
+
+const COST_TO_MS_SCALE: f32 = 2.0;
+
+/// Evaluation settings for each GORNA strategy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AnimationQuality {
+    /// Minimum time between two evaluations, in seconds. Zero updates every frame.
+    update_interval: f32,
+    /// Maximum motions blended per entity.
+    blend_limit: usize,
+}
+
+impl AnimationQuality {
+    fn for_strategy(strategy: StrategyId) -> Self {
+        match strategy {
+            // Dominant motion only, at 30 Hz.
+            StrategyId::LowPower => Self {
+                update_interval: 1.0 / 30.0,
+                blend_limit: 1,
+            },
+            StrategyId::HighPerformance => Self {
+                update_interval: 0.0,
+                blend_limit: usize::MAX,
+            },
+            StrategyId::Balanced | StrategyId::Custom(_) => Self {
+                update_interval: 0.0,
+                blend_limit: 4,
+            },
+        }
+    }
+}
+
+/// The agent responsible for evaluating animation graphs.
+pub struct AnimationAgent {
+    /// All animation lanes — the agent's strategies.
+    lanes: LaneRegistry,
+    /// Current GORNA strategy ID.
+    current_strategy: StrategyId,
+    /// Settings derived from the current strategy.
+    quality: AnimationQuality,
+    /// Frame time not yet consumed by an evaluation.
+    pending_delta: f32,
+    /// Duration of the last evaluation.
+    last_update_time: Duration,
+    /// Time budget allocated by GORNA.
+    time_budget: Duration,
+    /// Total evaluations performed.
+    frame_count: u64,
+    /// Number of `execute` invocations attempted.
+    execute_attempts: u64,
+}
+
+impl Agent for AnimationAgent {
+    fn id(&self) -> AgentId {
+        AgentId::Animation
+    }
+
+    fn negotiate(&mut self, _request: NegotiationRequest) -> NegotiationResponse {
+        NegotiationResponse {
+            strategies: vec![
+                StrategyOption {
+                    id: StrategyId::LowPower,
+                    estimated_time: Duration::from_secs_f32(
+                        (0.25 * COST_TO_MS_SCALE).max(0.1) / 1000.0,
+                    ),
+                    estimated_vram: 0,
+                },
+                StrategyOption {
+                    id: StrategyId::Balanced,
+                    estimated_time: Duration::from_secs_f32(
+                        (0.75 * COST_TO_MS_SCALE).max(0.25) / 1000.0,
+                    ),
+                    estimated_vram: 0,
+                },
+                StrategyOption {
+                    id: StrategyId::HighPerformance,
+                    estimated_time: Duration::from_secs_f32(
+                        (1.5 * COST_TO_MS_SCALE).max(0.5) / 1000.0,
+                    ),
+                    estimated_vram: 0,
+                },
+            ],
+            timing_adjustment: None,
+        }
+    }
+
+    fn apply_budget(&mut self, budget: ResourceBudget) {
+        log::info!(
+            "AnimationAgent: Strategy update to {:?} (time_limit={:?})",
+            budget.strategy_id,
+            budget.time_limit,
+        );
+
+        if let StrategyId::Custom(_) = budget.strategy_id {
+            log::warn!(
+                "AnimationAgent received unsupported custom strategy. Falling back to Balanced."
+            );
+        }
+
+        self.quality = AnimationQuality::for_strategy(budget.strategy_id);
+        self.current_strategy = budget.strategy_id;
+        self.time_budget = budget.time_limit;
+    }
+
+    fn execute(&mut self, context: &mut EngineContext<'_>) {
+        self.execute_attempts += 1;
+
+        let delta = context
+            .services
+            .get::<SharedFrameTime>()
+            .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+            .unwrap_or(0.0);
+        self.pending_delta += delta;
+        if self.pending_delta < self.quality.update_interval {
+            return;
+        }
+
+        let Some(world_any) = context.world.as_deref_mut() else {
+            return;
+        };
+        let Some(world) = world_any.downcast_mut::<World>() else {
+            return;
+        };
+
+        let start = Instant::now();
+
+        let mut ctx = LaneContext::new();
+        ctx.insert(AnimationDeltaTime(self.pending_delta));
+        ctx.insert(AnimationBlendLimit(self.quality.blend_limit));
+        ctx.insert(Slot::new(world));
+        if let Some(audio) = context.services.get::<AudioEvents>() {
+            ctx.insert(audio.clone());
+        }
+
+        // Cutscenes override gameplay animation, so timelines run after
+        // the graphs, whatever the strategy.
+        for name in ["AnimationGraph", "Timeline"] {
+            if let Some(lane) = self.lanes.get(name) {
+                if let Err(e) = lane.execute(&mut ctx) {
+                    log::error!("Animation lane {} failed: {}", lane.strategy_name(), e);
+                }
+            }
+        }
+
+        self.pending_delta = 0.0;
+        self.last_update_time = start.elapsed();
+        self.frame_count += 1;
+    }
+
+    fn report_status(&self) -> AgentStatus {
+        let health_score = if self.time_budget.is_zero() || self.frame_count == 0 {
+            1.0
+        } else {
+            let ratio =
+                self.time_budget.as_secs_f32() / self.last_update_time.as_secs_f32().max(0.0001);
+            ratio.min(1.0)
+        };
+
+        AgentStatus {
+            agent_id: self.id(),
+            health_score,
+            current_strategy: self.current_strategy,
+            // LowPower legitimately skips frames, so only a run that never
+            // evaluated counts as stalled.
+            is_stalled: self.execute_attempts > 60 && self.frame_count == 0,
+            message: format!(
+                "update_time={:.2}ms blend_limit={}",
+                self.last_update_time.as_secs_f32() * 1000.0,
+                self.quality.blend_limit,
+            ),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn execution_timing(&self) -> ExecutionTiming {
+        ExecutionTiming {
+            allowed_phases: vec![ExecutionPhase::TRANSFORM],
+            default_phase: ExecutionPhase::TRANSFORM,
+            // Before physics (0.9), so kinematic bodies follow animated poses.
+            priority: 0.95,
+            importance: AgentImportance::Important,
+            fixed_timestep: None,
+            dependencies: Vec::new(),
+            affinity: AgentAffinity::MainThread,
+        }
+    }
+}
+
+impl Default for AnimationAgent {
+    fn default() -> Self {
+        let mut lanes = LaneRegistry::new();
+        lanes.register(Box::new(AnimationGraphLane::new()));
+        lanes.register(Box::new(TimelineLane::new()));
+
+        Self {
+            lanes,
+            current_strategy: StrategyId::Balanced,
+            quality: AnimationQuality::for_strategy(StrategyId::Balanced),
+            pending_delta: 0.0,
+            last_update_time: Duration::ZERO,
+            time_budget: Duration::ZERO,
+            frame_count: 0,
+            execute_attempts: 0,
+        }
+    }
+}
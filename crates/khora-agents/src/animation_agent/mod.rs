@@ -0,0 +1,27 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Acts as the **[A]gent** for the animation subsystem, fulfilling the role of an ISA.
+//!
+//! This module drives the `animation_lane`s: the graph lane evaluates every
+//! `Animator` and writes the blended poses back into the ECS, the timeline
+//! lane plays `TimelinePlayer` cutscenes over them. Inverse kinematics is
+//! applied afterwards by the `ik_agent`.
+//!
+//! As an **Intelligent Subsystem Agent (ISA)**, it adapts evaluation cost to
+//! the budget allocated by GORNA: the update rate and the number of motions
+//! blended per entity both scale with the selected strategy.
+
+mod agent;
+
+pub use agent::*;
@@ -0,0 +1,310 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Defines the AssetAgent — owns `LaneKind::Asset` lanes only.
+//!
+//! Per CLAD, an Agent owns exactly one `LaneKind` and stores **only** its
+//! own GORNA/strategy state. The asset service, GPU cache and unload
+//! configuration are looked up from the service registry each pass.
+//!
+//! Each strategy picks an [`AssetLoadQuality`]: full loads, textures
+//! without their top mip, or metadata-only prefetches. Its I/O and VRAM
+//! costs are estimated from the prefetches still queued on the service.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use khora_core::agent::{Agent, AgentAffinity, AgentImportance, ExecutionPhase, ExecutionTiming};
+use khora_core::asset::AssetLoadQuality;
+use khora_core::control::gorna::{
+    AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    StrategyOption,
+};
+use khora_core::lane::{
+    AssetPrefetchLimit, AssetUnloadLimit, AssetsPrefetched, AssetsUnloaded, LaneContext,
+    LaneRegistry,
+};
+use khora_core::renderer::GraphicsDevice;
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::EngineContext;
+use khora_data::assets::AssetUnloadConfig;
+use khora_data::GpuCache;
+use khora_io::asset::{AssetService, PrefetchEstimate};
+use khora_lanes::asset_lane::{AssetPrefetchLane, AssetUnloadLane};
+
+/// Disk throughput assumed when estimating prefetch I/O time, in bytes
+/// per second.
+const ASSUMED_IO_BYTES_PER_SECOND: f64 = 200.0 * 1024.0 * 1024.0;
+
+/// Fixed cost of one pass, on top of its I/O.
+const PASS_OVERHEAD: Duration = Duration::from_micros(20);
+
+/// Cost of resolving the metadata of one prefetched asset.
+const METADATA_PREFETCH_COST: Duration = Duration::from_micros(2);
+
+/// Unload and load settings for each GORNA strategy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AssetQuality {
+    /// Minimum time between two passes, in seconds.
+    interval: f32,
+    /// Maximum assets released by one pass.
+    unload_limit: usize,
+    /// Maximum queued prefetches completed by one pass.
+    prefetch_limit: usize,
+    /// Quality of prefetches and loads.
+    load_quality: AssetLoadQuality,
+}
+
+impl AssetQuality {
+    fn for_strategy(strategy: StrategyId) -> Self {
+        match strategy {
+            StrategyId::LowPower => Self {
+                interval: 2.0,
+                unload_limit: 4,
+                prefetch_limit: 32,
+                load_quality: AssetLoadQuality::MetadataOnly,
+            },
+            StrategyId::HighPerformance => Self {
+                interval: 0.25,
+                unload_limit: 128,
+                prefetch_limit: 16,
+                load_quality: AssetLoadQuality::Full,
+            },
+            StrategyId::Balanced | StrategyId::Custom(_) => Self {
+                interval: 1.0,
+                unload_limit: 32,
+                prefetch_limit: 4,
+                load_quality: AssetLoadQuality::ReducedTextures { skip_mips: 1 },
+            },
+        }
+    }
+
+    /// Estimates the time and VRAM one pass costs with `pending` queued.
+    fn estimate(&self, pending: &PrefetchEstimate) -> (Duration, u64) {
+        if pending.requests == 0 {
+            return (PASS_OVERHEAD, 0);
+        }
+        let served = self.prefetch_limit.min(pending.requests);
+        if !self.load_quality.prefetches_payload() {
+            return (PASS_OVERHEAD + METADATA_PREFETCH_COST * served as u32, 0);
+        }
+
+        // Assume the queued assets are of similar size.
+        let share = served as f64 / pending.requests as f64;
+        let io_bytes = pending.io_bytes as f64 * share;
+        let texture_bytes = pending.texture_bytes as f64 * share;
+        let io_time = Duration::from_secs_f64(io_bytes / ASSUMED_IO_BYTES_PER_SECOND);
+        let vram = io_bytes - texture_bytes
+            + texture_bytes * self.load_quality.texture_memory_scale() as f64;
+        (PASS_OVERHEAD + io_time, vram as u64)
+    }
+}
+
+/// The agent responsible for unloading unused assets.
+pub struct AssetAgent {
+    /// All asset lanes — the agent's strategies.
+    lanes: LaneRegistry,
+    /// Current GORNA strategy ID.
+    current_strategy: StrategyId,
+    /// Settings derived from the current strategy.
+    quality: AssetQuality,
+    /// Prefetches queued on the service at the last pass.
+    pending: PrefetchEstimate,
+    /// Frame time elapsed since the last pass.
+    pending_delta: f32,
+    /// Duration of the last pass.
+    last_pass_time: Duration,
+    /// Assets released by the last pass.
+    last_unloaded: usize,
+    /// Assets released since startup.
+    total_unloaded: usize,
+    /// Prefetches completed by the last pass.
+    last_prefetched: usize,
+    /// Time budget allocated by GORNA.
+    time_budget: Duration,
+}
+
+impl Agent for AssetAgent {
+    fn id(&self) -> AgentId {
+        AgentId::Asset
+    }
+
+    fn negotiate(&mut self, _request: NegotiationRequest) -> NegotiationResponse {
+        let strategies = [
+            StrategyId::LowPower,
+            StrategyId::Balanced,
+            StrategyId::HighPerformance,
+        ]
+        .into_iter()
+        .map(|id| {
+            let (estimated_time, estimated_vram) =
+                AssetQuality::for_strategy(id).estimate(&self.pending);
+            StrategyOption {
+                id,
+                estimated_time,
+                estimated_vram,
+            }
+        })
+        .collect();
+
+        NegotiationResponse {
+            strategies,
+            timing_adjustment: None,
+        }
+    }
+
+    fn apply_budget(&mut self, budget: ResourceBudget) {
+        log::info!(
+            "AssetAgent: Strategy update to {:?} (time_limit={:?})",
+            budget.strategy_id,
+            budget.time_limit,
+        );
+
+        if let StrategyId::Custom(_) = budget.strategy_id {
+            log::warn!(
+                "AssetAgent received unsupported custom strategy. Falling back to Balanced."
+            );
+        }
+
+        self.quality = AssetQuality::for_strategy(budget.strategy_id);
+        self.current_strategy = budget.strategy_id;
+        self.time_budget = budget.time_limit;
+    }
+
+    fn execute(&mut self, context: &mut EngineContext<'_>) {
+        let delta = context
+            .services
+            .get::<SharedFrameTime>()
+            .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+            .unwrap_or(0.0);
+        self.pending_delta += delta;
+        if self.pending_delta < self.quality.interval {
+            return;
+        }
+        self.pending_delta = 0.0;
+
+        let start = Instant::now();
+
+        let mut ctx = LaneContext::new();
+        ctx.insert(AssetUnloadLimit(self.quality.unload_limit));
+        ctx.insert(AssetPrefetchLimit(self.quality.prefetch_limit));
+        ctx.insert(self.quality.load_quality);
+        ctx.insert(
+            context
+                .services
+                .get::<AssetUnloadConfig>()
+                .copied()
+                .unwrap_or_default(),
+        );
+        if let Some(service) = context.services.get::<Arc<Mutex<AssetService>>>() {
+            if let Ok(service) = service.lock() {
+                self.pending = service.prefetch_estimate();
+            }
+            ctx.insert(service.clone());
+        }
+        if let Some(cache) = context.services.get::<GpuCache>() {
+            ctx.insert(cache.clone());
+        }
+        if let Some(device) = context.services.get::<Arc<dyn GraphicsDevice>>() {
+            ctx.insert(device.clone());
+        }
+
+        // Unload first, so prefetched assets do not count as unused.
+        for name in ["AssetUnload", "AssetPrefetch"] {
+            let Some(lane) = self.lanes.get(name) else {
+                continue;
+            };
+            if let Err(e) = lane.execute(&mut ctx) {
+                log::error!("Asset lane {} failed: {}", lane.strategy_name(), e);
+            }
+        }
+
+        self.last_unloaded = ctx.get::<AssetsUnloaded>().map_or(0, |u| u.0);
+        self.last_prefetched = ctx.get::<AssetsPrefetched>().map_or(0, |p| p.0);
+        self.total_unloaded += self.last_unloaded;
+        self.last_pass_time = start.elapsed();
+    }
+
+    fn report_status(&self) -> AgentStatus {
+        let health_score = if self.time_budget.is_zero() || self.last_pass_time.is_zero() {
+            1.0
+        } else {
+            let ratio =
+                self.time_budget.as_secs_f32() / self.last_pass_time.as_secs_f32().max(0.0001);
+            ratio.min(1.0)
+        };
+
+        AgentStatus {
+            agent_id: self.id(),
+            health_score,
+            current_strategy: self.current_strategy,
+            is_stalled: false,
+            message: format!(
+                "pass_time={:.2}ms unloaded={} total_unloaded={} limit={} prefetched={} \
+                 pending={} quality={:?}",
+                self.last_pass_time.as_secs_f32() * 1000.0,
+                self.last_unloaded,
+                self.total_unloaded,
+                self.quality.unload_limit,
+                self.last_prefetched,
+                self.pending.requests,
+                self.quality.load_quality,
+            ),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn execution_timing(&self) -> ExecutionTiming {
+        ExecutionTiming {
+            // After every agent has dropped the handles it no longer needs.
+            allowed_phases: vec![ExecutionPhase::FINALIZE],
+            default_phase: ExecutionPhase::FINALIZE,
+            priority: 0.5,
+            importance: AgentImportance::Optional,
+            fixed_timestep: None,
+            dependencies: Vec::new(),
+            // Everything it touches comes from services, and the graphics
+            // device can destroy buffers from any thread.
+            affinity: AgentAffinity::Worker,
+        }
+    }
+}
+
+impl Default for AssetAgent {
+    fn default() -> Self {
+        let mut lanes = LaneRegistry::new();
+        lanes.register(Box::new(AssetUnloadLane::new()));
+        lanes.register(Box::new(AssetPrefetchLane::new()));
+
+        Self {
+            lanes,
+            current_strategy: StrategyId::Balanced,
+            quality: AssetQuality::for_strategy(StrategyId::Balanced),
+            pending: PrefetchEstimate::default(),
+            pending_delta: 0.0,
+            last_pass_time: Duration::ZERO,
+            last_unloaded: 0,
+            total_unloaded: 0,
+            last_prefetched: 0,
+            time_budget: Duration::ZERO,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Acts as the **[A]gent** for the asset subsystem, fulfilling the role of an ISA.
+//!
+//! Loading stays on demand through the `AssetService`. This agent drives
+//! the `asset_lane` unload pass, which releases the CPU and GPU copies of
+//! assets that nothing has referenced for the configured grace period.
+//!
+//! As an **Intelligent Subsystem Agent (ISA)**, it adapts to the budget
+//! allocated by GORNA: how often the pass runs and how many assets one
+//! pass may release both scale with the selected strategy.
+
+mod agent;
+
+pub use agent::*;
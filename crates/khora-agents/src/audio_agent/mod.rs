@@ -17,29 +17,74 @@
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use khora_core::agent::{Agent, AgentImportance, ExecutionPhase, ExecutionTiming};
+use khora_core::agent::{Agent, AgentAffinity, AgentImportance, ExecutionPhase, ExecutionTiming};
 use khora_core::audio::device::AudioDevice;
 use khora_core::control::gorna::{
     AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
     StrategyOption,
 };
-use khora_core::lane::{LaneContext, LaneRegistry};
+use khora_core::lane::{AudioMixMode, LaneContext, LaneRegistry};
 use khora_core::EngineContext;
+use khora_data::audio::AudioMixSettings;
 use khora_lanes::audio_lane::SpatialMixingLane;
 
+/// Mixing settings for each GORNA strategy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AudioQuality {
+    /// Spatialization and effects applied to each source.
+    mode: AudioMixMode,
+    /// Maximum sources mixed audibly per callback.
+    voice_limit: usize,
+    /// Expected mixing cost per frame.
+    estimated_time: Duration,
+}
+
+impl AudioQuality {
+    fn for_strategy(strategy: StrategyId) -> Self {
+        match strategy {
+            // Stereo passthrough: no attenuation, no panning.
+            StrategyId::LowPower => Self {
+                mode: AudioMixMode::Stereo,
+                voice_limit: 8,
+                estimated_time: Duration::from_micros(100),
+            },
+            // Spatial only.
+            StrategyId::Balanced => Self {
+                mode: AudioMixMode::Spatial,
+                voice_limit: 32,
+                estimated_time: Duration::from_micros(500),
+            },
+            // Full spatial + effects.
+            StrategyId::HighPerformance => Self {
+                mode: AudioMixMode::SpatialEffects,
+                voice_limit: 128,
+                estimated_time: Duration::from_micros(2000),
+            },
+            StrategyId::Custom(voices) => Self {
+                voice_limit: voices as usize,
+                ..Self::for_strategy(StrategyId::Balanced)
+            },
+        }
+    }
+}
+
 /// The ISA that orchestrates the audio subsystem.
 ///
-/// Chooses audio lanes, negotiates resource budgets with GORNA, and dispatches
-/// `Lane::execute()` for spatial mixing each frame.
+/// Chooses audio lanes and negotiates resource budgets with GORNA. Each
+/// strategy trades spatialization, effects and voice count for CPU time;
+/// the chosen settings are published through the [`AudioMixSettings`]
+/// service, which the audio callback hands to the mixing lane.
 pub struct AudioAgent {
     /// The audio device backend, obtained from the service registry.
     device: Option<Arc<Mutex<Box<dyn AudioDevice>>>>,
+    /// Shared mixing settings, obtained from the service registry.
+    mix_settings: Option<AudioMixSettings>,
     /// Audio processing lanes.
     lanes: LaneRegistry,
     /// Current GORNA strategy.
     current_strategy: StrategyId,
-    /// Max audio sources to process per frame (from budget).
-    max_sources_per_frame: usize,
+    /// Settings derived from the current strategy.
+    quality: AudioQuality,
     /// Frame counter.
     frame_count: u64,
 }
@@ -51,14 +96,24 @@ impl Default for AudioAgent {
 
         Self {
             device: None,
+            mix_settings: None,
             lanes,
             current_strategy: StrategyId::Balanced,
-            max_sources_per_frame: 32,
+            quality: AudioQuality::for_strategy(StrategyId::Balanced),
             frame_count: 0,
         }
     }
 }
 
+impl AudioAgent {
+    /// Writes the current quality into the shared mixing settings.
+    fn publish_quality(&self) {
+        if let Some(settings) = &self.mix_settings {
+            settings.set(self.quality.mode, self.quality.voice_limit);
+        }
+    }
+}
+
 impl Agent for AudioAgent {
     fn id(&self) -> AgentId {
         AgentId::Audio
@@ -66,23 +121,18 @@ impl Agent for AudioAgent {
 
     fn negotiate(&mut self, _request: NegotiationRequest) -> NegotiationResponse {
         NegotiationResponse {
-            strategies: vec![
-                StrategyOption {
-                    id: StrategyId::LowPower,
-                    estimated_time: Duration::from_micros(100),
-                    estimated_vram: 0,
-                },
-                StrategyOption {
-                    id: StrategyId::Balanced,
-                    estimated_time: Duration::from_micros(500),
-                    estimated_vram: 0,
-                },
-                StrategyOption {
-                    id: StrategyId::HighPerformance,
-                    estimated_time: Duration::from_micros(2000),
-                    estimated_vram: 0,
-                },
-            ],
+            strategies: [
+                StrategyId::LowPower,
+                StrategyId::Balanced,
+                StrategyId::HighPerformance,
+            ]
+            .into_iter()
+            .map(|id| StrategyOption {
+                id,
+                estimated_time: AudioQuality::for_strategy(id).estimated_time,
+                estimated_vram: 0,
+            })
+            .collect(),
             timing_adjustment: None,
         }
     }
@@ -91,12 +141,8 @@ impl Agent for AudioAgent {
         log::info!("AudioAgent: Strategy update to {:?}", budget.strategy_id);
 
         self.current_strategy = budget.strategy_id;
-        self.max_sources_per_frame = match budget.strategy_id {
-            StrategyId::LowPower => 8,
-            StrategyId::Balanced => 32,
-            StrategyId::HighPerformance => 128,
-            StrategyId::Custom(n) => n as usize,
-        };
+        self.quality = AudioQuality::for_strategy(budget.strategy_id);
+        self.publish_quality();
     }
 
     fn on_initialize(&mut self, context: &mut EngineContext<'_>) {
@@ -107,6 +153,10 @@ impl Agent for AudioAgent {
                 .get::<Arc<Mutex<Box<dyn AudioDevice>>>>()
                 .cloned();
         }
+        if self.mix_settings.is_none() {
+            self.mix_settings = context.services.get::<AudioMixSettings>().cloned();
+            self.publish_quality();
+        }
 
         // Initialize audio lanes. The SpatialMixingLane doesn't need
         // GPU resources — it runs on the audio callback thread.
@@ -132,12 +182,17 @@ impl Agent for AudioAgent {
                 .get::<Arc<Mutex<Box<dyn AudioDevice>>>>()
                 .cloned();
         }
+        if self.mix_settings.is_none() {
+            self.mix_settings = context.services.get::<AudioMixSettings>().cloned();
+            self.publish_quality();
+        }
 
         // Audio mixing happens in real-time on the audio callback thread.
         // The SpatialMixingLane::execute() is called directly from the
         // audio callback with AudioStreamInfo + AudioOutputSlot.
         // This agent manages strategy negotiation and lane lifecycle,
-        // but does not drive the audio lane from the main thread.
+        // but does not drive the audio lane from the main thread: the
+        // callback reads the strategy's settings from `AudioMixSettings`.
         self.frame_count += 1;
     }
 
@@ -148,8 +203,8 @@ impl Agent for AudioAgent {
             current_strategy: self.current_strategy,
             is_stalled: false,
             message: format!(
-                "max_sources={} frame={}",
-                self.max_sources_per_frame, self.frame_count
+                "mode={:?} voices={} frame={}",
+                self.quality.mode, self.quality.voice_limit, self.frame_count
             ),
         }
     }
@@ -170,6 +225,10 @@ impl Agent for AudioAgent {
             importance: AgentImportance::Important,
             fixed_timestep: None,
             dependencies: Vec::new(),
+            // Mixing already happens on the audio callback thread; the
+            // per-frame bookkeeping needs no World, so keep it off the
+            // frame thread as well.
+            affinity: AgentAffinity::Worker,
         }
     }
 }
@@ -0,0 +1,226 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Defines the IkAgent — owns `LaneKind::InverseKinematics` lanes only.
+//!
+//! Per CLAD, an Agent owns exactly one `LaneKind` and stores **only** its
+//! own GORNA/strategy state.
+
+use std::time::{Duration, Instant};
+
+use khora_core::agent::{
+    Agent, AgentAffinity, AgentDependency, AgentImportance, DependencyKind, ExecutionPhase,
+    ExecutionTiming,
+};
+use khora_core::control::gorna::{
+    AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    StrategyOption,
+};
+use khora_core::lane::{IkChainsSolved, IkIterationLimit, LaneContext, LaneRegistry, Slot};
+use khora_core::EngineContext;
+use khora_data::ecs::World;
+use khora_lanes::ik_lane::InverseKinematicsLane;
+
+/// Estimated cost of one solver iteration on one chain, in milliseconds,
+/// until a solve has been measured.
+const DEFAULT_ITERATION_COST_MS: f32 = 0.002;
+
+/// Chains assumed before the first solve, so the first negotiation still
+/// orders the strategies by cost.
+const DEFAULT_CHAIN_COUNT: usize = 16;
+
+/// Returns the FABRIK iterations per chain for `strategy`. Zero skips the
+/// IK lane entirely.
+fn iterations_for(strategy: StrategyId) -> usize {
+    match strategy {
+        StrategyId::LowPower => 0,
+        StrategyId::HighPerformance => 20,
+        StrategyId::Balanced | StrategyId::Custom(_) => 8,
+    }
+}
+
+/// The agent responsible for solving inverse kinematics constraints.
+pub struct IkAgent {
+    /// All IK lanes — the agent's strategies.
+    lanes: LaneRegistry,
+    /// Current GORNA strategy ID.
+    current_strategy: StrategyId,
+    /// FABRIK iterations per chain. Zero skips the IK lane.
+    iterations: usize,
+    /// Duration of the last solve.
+    last_solve_time: Duration,
+    /// Constraints solved by the last solve.
+    last_chain_count: usize,
+    /// Measured cost of one iteration on one chain, in milliseconds.
+    iteration_cost_ms: f32,
+    /// Time budget allocated by GORNA.
+    time_budget: Duration,
+    /// Total solves performed.
+    frame_count: u64,
+}
+
+impl IkAgent {
+    /// Returns the estimated cost of solving every chain `iterations` times.
+    fn estimate(&self, iterations: usize) -> Duration {
+        let chains = if self.frame_count == 0 {
+            DEFAULT_CHAIN_COUNT
+        } else {
+            self.last_chain_count
+        };
+        let ms = self.iteration_cost_ms * (chains * iterations) as f32;
+        Duration::from_secs_f32(ms / 1000.0)
+    }
+}
+
+impl Agent for IkAgent {
+    fn id(&self) -> AgentId {
+        AgentId::InverseKinematics
+    }
+
+    fn negotiate(&mut self, _request: NegotiationRequest) -> NegotiationResponse {
+        let strategies = [
+            StrategyId::LowPower,
+            StrategyId::Balanced,
+            StrategyId::HighPerformance,
+        ]
+        .into_iter()
+        .map(|id| StrategyOption {
+            id,
+            estimated_time: self.estimate(iterations_for(id)),
+            estimated_vram: 0,
+        })
+        .collect();
+
+        NegotiationResponse {
+            strategies,
+            timing_adjustment: None,
+        }
+    }
+
+    fn apply_budget(&mut self, budget: ResourceBudget) {
+        log::info!(
+            "IkAgent: Strategy update to {:?} (time_limit={:?})",
+            budget.strategy_id,
+            budget.time_limit,
+        );
+
+        if let StrategyId::Custom(_) = budget.strategy_id {
+            log::warn!("IkAgent received unsupported custom strategy. Falling back to Balanced.");
+        }
+
+        self.iterations = iterations_for(budget.strategy_id);
+        self.current_strategy = budget.strategy_id;
+        self.time_budget = budget.time_limit;
+    }
+
+    fn execute(&mut self, context: &mut EngineContext<'_>) {
+        if self.iterations == 0 {
+            return;
+        }
+
+        let Some(world_any) = context.world.as_deref_mut() else {
+            return;
+        };
+        let Some(world) = world_any.downcast_mut::<World>() else {
+            return;
+        };
+
+        let start = Instant::now();
+
+        let mut ctx = LaneContext::new();
+        ctx.insert(IkIterationLimit(self.iterations));
+        ctx.insert(Slot::new(world));
+
+        if let Some(lane) = self.lanes.get("InverseKinematics") {
+            if let Err(e) = lane.execute(&mut ctx) {
+                log::error!("IK lane {} failed: {}", lane.strategy_name(), e);
+            }
+        }
+
+        self.last_solve_time = start.elapsed();
+        self.last_chain_count = ctx.get::<IkChainsSolved>().map_or(0, |solved| solved.0);
+        if self.last_chain_count > 0 {
+            self.iteration_cost_ms = self.last_solve_time.as_secs_f32() * 1000.0
+                / (self.last_chain_count * self.iterations) as f32;
+        }
+        self.frame_count += 1;
+    }
+
+    fn report_status(&self) -> AgentStatus {
+        let health_score = if self.time_budget.is_zero() || self.frame_count == 0 {
+            1.0
+        } else {
+            let ratio =
+                self.time_budget.as_secs_f32() / self.last_solve_time.as_secs_f32().max(0.0001);
+            ratio.min(1.0)
+        };
+
+        AgentStatus {
+            agent_id: self.id(),
+            health_score,
+            current_strategy: self.current_strategy,
+            // LowPower skips every solve on purpose.
+            is_stalled: false,
+            message: format!(
+                "solve_time={:.2}ms chains={} iterations={}",
+                self.last_solve_time.as_secs_f32() * 1000.0,
+                self.last_chain_count,
+                self.iterations,
+            ),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn execution_timing(&self) -> ExecutionTiming {
+        ExecutionTiming {
+            allowed_phases: vec![ExecutionPhase::TRANSFORM],
+            default_phase: ExecutionPhase::TRANSFORM,
+            // After animation (0.95), before physics (0.9).
+            priority: 0.92,
+            importance: AgentImportance::Important,
+            fixed_timestep: None,
+            // IK corrects the pose the animation agent wrote this frame.
+            dependencies: vec![AgentDependency {
+                target: AgentId::Animation,
+                kind: DependencyKind::Hard,
+                condition: None,
+            }],
+            affinity: AgentAffinity::MainThread,
+        }
+    }
+}
+
+impl Default for IkAgent {
+    fn default() -> Self {
+        let mut lanes = LaneRegistry::new();
+        lanes.register(Box::new(InverseKinematicsLane::new()));
+
+        Self {
+            lanes,
+            current_strategy: StrategyId::Balanced,
+            iterations: iterations_for(StrategyId::Balanced),
+            last_solve_time: Duration::ZERO,
+            last_chain_count: 0,
+            iteration_cost_ms: DEFAULT_ITERATION_COST_MS,
+            time_budget: Duration::ZERO,
+            frame_count: 0,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Acts as the **[A]gent** for inverse kinematics, fulfilling the role of an ISA.
+//!
+//! This module drives the `ik_lane`, which corrects the poses written by the
+//! animation agent towards `IkConstraint` targets. It is an agent of its own
+//! so GORNA budgets IK apart from animation sampling: the solver iteration
+//! count scales with the selected strategy, and IK is skipped entirely under
+//! `LowPower`.
+
+mod agent;
+
+pub use agent::*;
@@ -18,10 +18,16 @@
 
 #![warn(missing_docs)]
 
+pub mod animation_agent;
+pub mod asset_agent;
+#[cfg(feature = "audio")]
 pub mod audio_agent;
+pub mod ik_agent;
+#[cfg(feature = "physics")]
 pub mod physics_agent;
 pub mod render_agent;
 pub mod shadow_agent;
 pub mod ui_agent;
 
+#[cfg(feature = "physics")]
 pub use physics_agent::PhysicsQueryService;
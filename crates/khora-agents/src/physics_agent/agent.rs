@@ -17,24 +17,33 @@
 //! Per CLAD, an Agent owns exactly one `LaneKind` and stores **only** its
 //! own GORNA/strategy state.  The shared `PhysicsProvider` is fetched from
 //! the [`ServiceRegistry`] each frame — agents are not the owners.
+//!
+//! The agent runs on its own worker thread. It reaches the world through
+//! the frame's [`WorldChannel`], so only the simulation step itself runs
+//! beside the frame thread; see [`khora_lanes::physics_lane`].
 
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
-use khora_core::agent::{Agent, AgentImportance, ExecutionPhase, ExecutionTiming};
+use khora_core::agent::{Agent, AgentAffinity, AgentImportance, ExecutionPhase, ExecutionTiming};
 use khora_core::control::gorna::{
     AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
     StrategyOption,
 };
-use khora_core::lane::PhysicsDeltaTime;
 use khora_core::lane::{LaneContext, LaneRegistry, Slot};
+use khora_core::lane::{PhysicsDeltaTime, PhysicsInterpolationAlpha};
 use khora_core::physics::PhysicsProvider;
+use khora_core::utils::frame_time::SharedFrameTime;
 use khora_core::EngineContext;
-use khora_data::ecs::World;
-use khora_lanes::physics_lane::StandardPhysicsLane;
+use khora_data::ecs::{World, WorldChannel};
+use khora_lanes::physics_lane::{PhysicsInterpolationLane, StandardPhysicsLane};
 
 const COST_TO_MS_SCALE: f32 = 3.0;
 
+/// Most fixed steps simulated in one frame. Time beyond that is dropped, so
+/// a slow frame cannot snowball into ever longer ones.
+const MAX_STEPS_PER_FRAME: u32 = 4;
+
 /// Strategies for physics simulation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PhysicsStrategy {
@@ -68,6 +77,8 @@ pub struct PhysicsAgent {
     fixed_timestep: f32,
     /// Number of `execute` invocations attempted.
     execute_attempts: u64,
+    /// Frame time not yet consumed by fixed steps.
+    pending_delta: f32,
 }
 
 impl Agent for PhysicsAgent {
@@ -156,34 +167,72 @@ impl Agent for PhysicsAgent {
         };
         let provider_arc: Arc<Mutex<Box<dyn PhysicsProvider>>> = (*provider_arc).clone();
 
-        let Some(world_any) = context.world.as_deref_mut() else {
-            return;
-        };
-        let Some(world) = world_any.downcast_mut::<World>() else {
-            return;
-        };
-
-        let start = Instant::now();
+        let mut ctx = LaneContext::new();
+        ctx.insert(PhysicsDeltaTime(self.fixed_timestep));
 
-        let mut provider_guard = match provider_arc.lock() {
-            Ok(g) => g,
-            Err(e) => {
-                log::error!("PhysicsAgent: provider mutex poisoned: {}", e);
-                return;
+        // When run inline, the agent gets the world and locks the provider for
+        // the whole frame. On its worker thread, the lanes go through the
+        // world channel and lock the provider step by step.
+        let mut provider_guard = None;
+        match context.world.as_deref_mut() {
+            Some(world_any) => {
+                let Some(world) = world_any.downcast_mut::<World>() else {
+                    return;
+                };
+                let guard = match provider_arc.lock() {
+                    Ok(g) => provider_guard.insert(g),
+                    Err(e) => {
+                        log::error!("PhysicsAgent: provider mutex poisoned: {}", e);
+                        return;
+                    }
+                };
+                ctx.insert(Slot::new(world));
+                ctx.insert(Slot::new(guard.as_mut()));
             }
-        };
+            None => {
+                let Some(channel) = context.services.get::<WorldChannel>() else {
+                    log::debug!("PhysicsAgent: no world and no world channel, skipping step");
+                    return;
+                };
+                ctx.insert(channel.clone());
+                ctx.insert(Arc::clone(&provider_arc));
+            }
+        }
 
-        let mut ctx = LaneContext::new();
-        ctx.insert(PhysicsDeltaTime(self.fixed_timestep));
-        ctx.insert(Slot::new(world));
-        ctx.insert(Slot::new(provider_guard.as_mut()));
+        // Without a frame clock (e.g. headless tests), step once per frame.
+        let delta = context
+            .services
+            .get::<SharedFrameTime>()
+            .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+            .unwrap_or(self.fixed_timestep);
+        self.pending_delta += delta;
+
+        let start = Instant::now();
 
         let lane_name = match self.strategy {
             PhysicsStrategy::Standard | PhysicsStrategy::Simplified => "StandardPhysics",
             PhysicsStrategy::Debug => "PhysicsDebug",
         };
 
-        if let Some(lane) = self.lanes.get(lane_name) {
+        let mut steps = 0;
+        while self.pending_delta >= self.fixed_timestep && steps < MAX_STEPS_PER_FRAME {
+            if let Some(lane) = self.lanes.get(lane_name) {
+                if let Err(e) = lane.execute(&mut ctx) {
+                    log::error!("Physics lane {} failed: {}", lane.strategy_name(), e);
+                }
+            }
+            self.pending_delta -= self.fixed_timestep;
+            steps += 1;
+        }
+        if self.pending_delta >= self.fixed_timestep {
+            self.pending_delta %= self.fixed_timestep;
+        }
+
+        // Rendering blends between the last two ticks by the time left over.
+        ctx.insert(PhysicsInterpolationAlpha(
+            self.pending_delta / self.fixed_timestep,
+        ));
+        if let Some(lane) = self.lanes.get("PhysicsInterpolation") {
             if let Err(e) = lane.execute(&mut ctx) {
                 log::error!("Physics lane {} failed: {}", lane.strategy_name(), e);
             }
@@ -230,6 +279,7 @@ impl Agent for PhysicsAgent {
             importance: AgentImportance::Critical,
             fixed_timestep: Some(Duration::from_secs_f32(self.fixed_timestep)),
             dependencies: Vec::new(),
+            affinity: AgentAffinity::Worker,
         }
     }
 }
@@ -239,6 +289,7 @@ impl Default for PhysicsAgent {
         let mut lanes = LaneRegistry::new();
         lanes.register(Box::new(StandardPhysicsLane::new()));
         lanes.register(Box::new(khora_lanes::physics_lane::PhysicsDebugLane::new()));
+        lanes.register(Box::new(PhysicsInterpolationLane::new()));
 
         Self {
             lanes,
@@ -249,6 +300,7 @@ impl Default for PhysicsAgent {
             frame_count: 0,
             fixed_timestep: 1.0 / 60.0,
             execute_attempts: 0,
+            pending_delta: 0.0,
         }
     }
 }
@@ -21,7 +21,7 @@
 
 use khora_core::{
     math::Vec3,
-    physics::{PhysicsProvider, Ray, RaycastHit},
+    physics::{ColliderHandle, PhysicsProvider, Ray, RaycastHit},
 };
 use std::sync::{Arc, Mutex};
 
@@ -51,11 +51,18 @@ impl PhysicsQueryService {
     /// * `solid` – If `true`, the ray starts inside a solid collider and
     ///   returns that collider as the hit. If `false`, the ray must exit
     ///   the solid to register a hit.
-    pub fn cast_ray(&self, ray: &Ray, max_toi: f32, solid: bool) -> Option<RaycastHit> {
+    /// * `exclude` – A collider to ignore, typically the caster's own.
+    pub fn cast_ray(
+        &self,
+        ray: &Ray,
+        max_toi: f32,
+        solid: bool,
+        exclude: Option<ColliderHandle>,
+    ) -> Option<RaycastHit> {
         self.provider
             .lock()
             .ok()
-            .and_then(|g| g.cast_ray(ray, max_toi, solid))
+            .and_then(|g| g.cast_ray(ray, max_toi, solid, exclude))
     }
 
     /// Returns debug line-segment geometry from the physics world.
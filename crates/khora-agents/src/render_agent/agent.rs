@@ -24,37 +24,60 @@ use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
 
 use khora_core::agent::{
-    Agent, AgentDependency, AgentImportance, DependencyKind, ExecutionPhase, ExecutionTiming,
+    Agent, AgentAffinity, AgentDependency, AgentImportance, DependencyKind, ExecutionPhase,
+    ExecutionTiming,
 };
 use khora_core::control::gorna::{
     AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
     StrategyOption,
 };
+use khora_core::cvar::CVarRegistry;
 use khora_core::lane::{
-    ClearColor, ColorTarget, DepthTarget, LaneContext, LaneKind, LaneRegistry, ShadowAtlasView,
-    ShadowComparisonSampler, Slot,
+    ClearColor, ColorTarget, DepthTarget, DeviceCapabilities, HdrSceneTarget, LaneContext,
+    LaneKind, LaneRegistry, PostStage, RenderDeltaTime, ShadowAtlasView, ShadowComparisonSampler,
+    Slot, TargetSize, VertexSkinning,
+};
+use khora_core::renderer::api::core::{
+    AntiAliasingMode, DepthMode, DepthPrepassMode, FrameContext, LightCullingStats,
+    LightingDebugView, RenderSettings,
 };
-use khora_core::renderer::api::core::FrameContext;
 use khora_core::renderer::api::scene::GpuMesh;
+use khora_core::renderer::api::util::SharedReadback;
 use khora_core::renderer::{GraphicsDevice, RenderSystem};
+use khora_core::utils::frame_time::SharedFrameTime;
 use khora_core::EngineContext;
 use khora_data::assets::Assets;
 use khora_data::ecs::World;
 use khora_data::render::{
-    extract_active_camera_view, PassDescriptor, RenderWorld, ResourceId, SharedFrameGraph,
+    extract_active_camera_view, PassDescriptor, PassLayer, RenderWorld, ResourceId,
+    SharedEntityPicker, SharedFrameGraph,
 };
 use khora_data::GpuCache;
-use khora_lanes::render_lane::{ForwardPlusLane, LitForwardLane, SimpleUnlitLane};
+use khora_lanes::render_lane::{
+    AutoExposureLane, BloomLane, DepthPrepassLane, ForwardPlusLane, FxaaLane, IdPassLane,
+    LitForwardLane, MotionVectorLane, MsaaLane, SimpleUnlitLane, SkinningLane, TaaLane,
+    ToneMapLane,
+};
 
 /// Threshold for switching to Forward+ rendering.
 const FORWARD_PLUS_LIGHT_THRESHOLD: usize = 20;
 
+/// Estimated overdraw, in screens covered by opaque meshes, above which the
+/// depth prepass saves more shading than its extra geometry pass costs.
+const DEPTH_PREPASS_OVERDRAW_THRESHOLD: f32 = 2.5;
+
 /// Scale factor converting lane cost units to milliseconds of GPU time.
 const COST_TO_MS_SCALE: f32 = 5.0;
 
 /// Approximate VRAM per mesh in bytes (vertex + index buffers).
 const DEFAULT_VRAM_PER_MESH: u64 = 100 * 1024;
 
+/// Console variable selecting the lighting debug view by name.
+const CVAR_DEBUG_VIEW: &str = "r.debug_view";
+
+/// Console variable enabling the Forward+ light culling statistics.
+const CVAR_LIGHT_STATS: &str = "r.light_stats";
+
 /// Rendering strategy selection mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum RenderingStrategy {
@@ -79,6 +102,11 @@ pub struct RenderAgent {
     lanes: LaneRegistry,
     /// Current rendering strategy selection mode.
     strategy: RenderingStrategy,
+    /// Anti-aliasing technique of the current strategy, used by cameras
+    /// whose `AntiAliasing` does not force one.
+    anti_aliasing: AntiAliasingMode,
+    /// Whether the current strategy affords bloom on cameras that ask for it.
+    bloom: bool,
     /// Current GORNA strategy ID applied via `apply_budget`.
     current_strategy: StrategyId,
     /// Time budget assigned by GORNA via `apply_budget`.
@@ -93,6 +121,14 @@ pub struct RenderAgent {
     frame_count: u64,
     /// Number of lights in the most recently extracted scene (for status).
     last_light_count: usize,
+    /// Lighting debug view drawn by the lit lanes, from `r.debug_view`.
+    debug_view: LightingDebugView,
+    /// Whether Forward+ reads its light grid back, from `r.light_stats`.
+    light_stats: bool,
+    /// Console variable generation `debug_view` and `light_stats` were read at.
+    cvar_generation: Option<u64>,
+    /// Latest Forward+ light culling statistics (for status).
+    culling_stats: Option<LightCullingStats>,
     /// Number of `execute` invocations attempted.  Used by `is_stalled` to
     /// distinguish "never tried" from "tried but produced no frame".
     execute_attempts: u64,
@@ -113,10 +149,6 @@ impl Agent for RenderAgent {
         ctx.insert(Slot::new(&mut stub_world));
 
         for lane in self.lanes.find_by_kind(LaneKind::Render) {
-            let cost = lane.estimate_cost(&ctx);
-            let estimated_time =
-                Duration::from_secs_f32((cost * COST_TO_MS_SCALE).max(0.1) / 1000.0);
-
             let (strategy_id, vram_overhead) = match lane.strategy_name() {
                 "SimpleUnlit" => (StrategyId::LowPower, 0u64),
                 "LitForward" => (StrategyId::Balanced, 4096u64),
@@ -124,6 +156,14 @@ impl Agent for RenderAgent {
                 _ => continue,
             };
 
+            // Each strategy also pays for the anti-aliasing and the
+            // post-processing effects it switches to.
+            let cost = lane.estimate_cost(&ctx)
+                + anti_aliasing_cost(&self.lanes, anti_aliasing_for(strategy_id), &ctx)
+                + post_process_cost(&self.lanes, bloom_for(strategy_id), &ctx);
+            let estimated_time =
+                Duration::from_secs_f32((cost * COST_TO_MS_SCALE).max(0.1) / 1000.0);
+
             // Without a populated RenderWorld we can only quote VRAM at the
             // lane-overhead level; per-mesh VRAM is folded in by the lane's
             // own cost estimator at execute time when it has the real scene.
@@ -180,6 +220,8 @@ impl Agent for RenderAgent {
             }
         }
 
+        self.anti_aliasing = anti_aliasing_for(budget.strategy_id);
+        self.bloom = bloom_for(budget.strategy_id);
         self.current_strategy = budget.strategy_id;
         self.time_budget = budget.time_limit;
     }
@@ -192,9 +234,26 @@ impl Agent for RenderAgent {
             log::warn!("RenderAgent: graphics device unavailable in on_initialize");
             return;
         };
+        self.lanes
+            .set_capabilities(DeviceCapabilities::from_device(device_arc.as_ref()));
+        if let Some(cvars) = context.services.get::<CVarRegistry>() {
+            let debug_view = context
+                .services
+                .get::<RenderSettings>()
+                .map(|settings| settings.debug_view)
+                .unwrap_or_default();
+            register_debug_cvars(cvars, debug_view);
+        }
 
         let mut init_ctx = LaneContext::new();
         init_ctx.insert(device_arc);
+        init_ctx.insert(
+            context
+                .services
+                .get::<DepthMode>()
+                .copied()
+                .unwrap_or_default(),
+        );
         for lane in self.lanes.all() {
             if let Err(e) = lane.on_initialize(&mut init_ctx) {
                 log::error!(
@@ -206,6 +265,21 @@ impl Agent for RenderAgent {
         }
     }
 
+    fn on_shutdown(&mut self, context: &mut EngineContext<'_>) {
+        // Mirror of on_initialize: give every lane the device so it can
+        // release its pipelines and buffers before the device goes away.
+        let Some(device_arc) = context.services.get::<Arc<dyn GraphicsDevice>>().cloned() else {
+            return;
+        };
+
+        let mut shutdown_ctx = LaneContext::new();
+        shutdown_ctx.insert(device_arc);
+        for lane in self.lanes.all() {
+            lane.on_shutdown(&mut shutdown_ctx);
+        }
+        log::debug!("RenderAgent: Lanes shut down");
+    }
+
     fn execute(&mut self, context: &mut EngineContext<'_>) {
         self.execute_attempts += 1;
 
@@ -250,12 +324,29 @@ impl Agent for RenderAgent {
             return;
         };
         let depth_target = fctx.get::<DepthTarget>().map(|a| *a);
-        let clear_color = fctx
-            .get::<ClearColor>()
-            .map(|a| *a)
-            .unwrap_or_else(|| ClearColor(khora_core::math::LinearRgba::new(0.1, 0.1, 0.15, 1.0)));
+        if let Some(cvars) = context.services.get::<CVarRegistry>() {
+            if self.cvar_generation != Some(cvars.generation()) {
+                self.cvar_generation = Some(cvars.generation());
+                (self.debug_view, self.light_stats) = read_debug_cvars(cvars);
+            }
+        }
+
+        // A scene Sky takes over the engine's clear color. The overdraw
+        // view accumulates on black.
+        let clear_color = if self.debug_view == LightingDebugView::Overdraw {
+            ClearColor(khora_core::math::LinearRgba::BLACK)
+        } else {
+            render_world
+                .sky
+                .map(ClearColor)
+                .or_else(|| fctx.get::<ClearColor>().map(|a| *a))
+                .unwrap_or_else(|| {
+                    ClearColor(khora_core::math::LinearRgba::new(0.1, 0.1, 0.15, 1.0))
+                })
+        };
         let shadow_atlas = fctx.get::<ShadowAtlasView>().map(|a| *a);
         let shadow_sampler = fctx.get::<ShadowComparisonSampler>().map(|a| *a);
+        let target_size = fctx.get::<TargetSize>().map(|a| *a);
 
         // Push the active camera view into the render system if present.
         if let Some(world_any) = context.world.as_deref_mut() {
@@ -270,7 +361,82 @@ impl Agent for RenderAgent {
 
         let frame_start = Instant::now();
         let strategy = self.strategy;
-        let select_name = lane_name_for_strategy(strategy, render_world);
+        let select_name = lane_name_for_strategy(strategy, render_world, &self.lanes);
+
+        let depth_mode = context
+            .services
+            .get::<DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        let depth_prepass = depth_target.is_some()
+            && wants_depth_prepass(
+                context
+                    .services
+                    .get::<DepthPrepassMode>()
+                    .copied()
+                    .unwrap_or_default(),
+                render_world,
+            );
+
+        // Pose skinned meshes first, in their own pre-scene pass, so both the
+        // shadow pass and the scene pass draw the skinned vertices.
+        let mut skinned = false;
+        if render_world.meshes.iter().any(|m| m.skin.is_some()) {
+            if let Some(lane) = self.lanes.get("GpuSkinning") {
+                let mut encoder = device.create_command_encoder(Some("Khora Skinning Encoder"));
+                {
+                    let mut ctx = LaneContext::new();
+                    ctx.insert(device.clone());
+                    // SAFETY: same contract as the scene encoder below.
+                    let encoder_slot = Slot::new(encoder.as_mut());
+                    ctx.insert(unsafe {
+                        std::mem::transmute::<
+                            Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                            Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                        >(encoder_slot)
+                    });
+                    ctx.insert(khora_core::lane::Ref::new(render_world));
+                    ctx.insert(VertexSkinning(select_name == "LitForward"));
+                    if let Err(e) = lane.execute(&mut ctx) {
+                        log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                    }
+                }
+                frame_graph
+                    .lock()
+                    .expect("FrameGraph mutex poisoned")
+                    .add_pass(
+                        PassDescriptor::new("SkinningPass")
+                            .layer(PassLayer::Prepare)
+                            .writes(ResourceId::SkinnedVertices),
+                        encoder.finish(),
+                    );
+                skinned = true;
+            }
+        }
+
+        // With auto-exposure or a post-processing stack, the scene renders
+        // into the tone mapping lane's HDR target; the resolve stage runs
+        // the post effects on it, then tone-maps it into the color target.
+        let wants_hdr = render_world.auto_exposure.is_some() || render_world.post_process.is_some();
+        let tone_mapping = match (wants_hdr, target_size) {
+            (true, Some(size)) => self.lanes.get("ToneMapping").map(|lane| (lane, size)),
+            _ => None,
+        };
+        let mut hdr_scene = None;
+
+        // Anti-aliasing either gives the scene multisampled targets (MSAA)
+        // or redirects it into an intermediate target that the resolve
+        // stage filters into the color target (FXAA, TAA).
+        let anti_aliasing = match (render_world.anti_aliasing, target_size) {
+            (Some(settings), Some(size)) => {
+                let mode = settings.mode.unwrap_or(self.anti_aliasing);
+                self.lanes
+                    .get(lane_name_for_anti_aliasing(mode))
+                    .map(|lane| (lane, mode, size))
+            }
+            _ => None,
+        };
+        let mut scene_color = color_target;
 
         // Encode the scene pass into a fresh command buffer; the FrameGraph
         // submits it once all agents have finished recording.
@@ -302,12 +468,43 @@ impl Agent for RenderAgent {
                 ctx.insert(dt);
             }
             ctx.insert(clear_color);
+            ctx.insert(depth_mode);
+            ctx.insert(self.debug_view);
+            if self.light_stats {
+                if let Some(readback) = context.services.get::<SharedReadback>() {
+                    ctx.insert(readback.clone());
+                }
+            }
             if let Some(view) = shadow_atlas {
                 ctx.insert(view);
             }
             if let Some(sampler) = shadow_sampler {
                 ctx.insert(sampler);
             }
+            if let Some(size) = target_size {
+                ctx.insert(size);
+            }
+            ctx.insert(PostStage::Prepare);
+            if let Some((lane, _)) = tone_mapping {
+                if let Err(e) = lane.execute(&mut ctx) {
+                    log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                }
+                hdr_scene = ctx.get::<HdrSceneTarget>().copied();
+            }
+            if let Some((lane, _, _)) = anti_aliasing {
+                if let Err(e) = lane.execute(&mut ctx) {
+                    log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                }
+                scene_color = ctx.get::<ColorTarget>().map_or(color_target, |c| *c);
+            }
+            // After the AA prepare, so the prepass fills MSAA's depth target.
+            if depth_prepass {
+                if let Some(lane) = self.lanes.get("DepthPrepass") {
+                    if let Err(e) = lane.execute(&mut ctx) {
+                        log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                    }
+                }
+            }
 
             if let Some(lane) = self.lanes.get(select_name) {
                 if let Err(e) = lane.execute(&mut ctx) {
@@ -323,11 +520,141 @@ impl Agent for RenderAgent {
         if shadow_atlas.is_some() {
             descriptor = descriptor.reads(ResourceId::ShadowAtlas);
         }
+        if skinned {
+            descriptor = descriptor.reads(ResourceId::SkinnedVertices);
+        }
         frame_graph
             .lock()
             .expect("FrameGraph mutex poisoned")
             .add_pass(descriptor, cmd_buf);
 
+        if let (Some((tone_map, size)), Some(hdr)) = (tone_mapping, hdr_scene) {
+            // Metering and bloom feed the tone mapping lane; bloom is the
+            // effect dropped when the budget runs short.
+            let bloom = self.bloom && render_world.post_process.is_some_and(|p| p.bloom.is_some());
+            let post_lanes = [
+                render_world
+                    .auto_exposure
+                    .and_then(|_| self.lanes.get("AutoExposure")),
+                self.lanes.get("Bloom").filter(|_| bloom),
+                Some(tone_map),
+            ];
+            let delta_time = context
+                .services
+                .get::<SharedFrameTime>()
+                .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+                .unwrap_or(0.0);
+            let mut encoder = device.create_command_encoder(Some("Khora Post-Processing Encoder"));
+            {
+                let mut ctx = LaneContext::new();
+                ctx.insert(device.clone());
+                // SAFETY: same contract as the scene encoder above.
+                let encoder_slot = Slot::new(encoder.as_mut());
+                ctx.insert(unsafe {
+                    std::mem::transmute::<
+                        Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                        Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                    >(encoder_slot)
+                });
+                ctx.insert(khora_core::lane::Ref::new(render_world));
+                ctx.insert(scene_color);
+                ctx.insert(hdr);
+                ctx.insert(size);
+                ctx.insert(RenderDeltaTime(delta_time));
+                ctx.insert(PostStage::Resolve);
+                for lane in post_lanes.into_iter().flatten() {
+                    if let Err(e) = lane.execute(&mut ctx) {
+                        log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                    }
+                }
+            }
+            frame_graph
+                .lock()
+                .expect("FrameGraph mutex poisoned")
+                .add_pass(
+                    PassDescriptor::new("PostProcessPass")
+                        .reads(ResourceId::Color)
+                        .writes(ResourceId::Color),
+                    encoder.finish(),
+                );
+        }
+
+        // Only FXAA and TAA moved the scene off the color target; MSAA
+        // resolved in the scene pass already.
+        if let (Some((lane, mode, size)), true) = (anti_aliasing, scene_color.0 != color_target.0) {
+            let mut encoder = device.create_command_encoder(Some("Khora Anti-Aliasing Encoder"));
+            {
+                let mut ctx = LaneContext::new();
+                ctx.insert(device.clone());
+                ctx.insert(gpu_meshes.clone());
+                // SAFETY: same contract as the scene encoder above.
+                let encoder_slot = Slot::new(encoder.as_mut());
+                ctx.insert(unsafe {
+                    std::mem::transmute::<
+                        Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                        Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                    >(encoder_slot)
+                });
+                ctx.insert(khora_core::lane::Ref::new(render_world));
+                ctx.insert(size);
+                if mode == AntiAliasingMode::Taa {
+                    if let Some(motion) = self.lanes.get("MotionVectors") {
+                        if let Err(e) = motion.execute(&mut ctx) {
+                            log::error!("Render lane {} failed: {}", motion.strategy_name(), e);
+                        }
+                    }
+                }
+                ctx.insert(color_target);
+                ctx.insert(PostStage::Resolve);
+                if let Err(e) = lane.execute(&mut ctx) {
+                    log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                }
+            }
+            frame_graph
+                .lock()
+                .expect("FrameGraph mutex poisoned")
+                .add_pass(
+                    PassDescriptor::new("AntiAliasingPass")
+                        .reads(ResourceId::Color)
+                        .writes(ResourceId::Color),
+                    encoder.finish(),
+                );
+        }
+
+        // Draw entity IDs only while picks wait for them.
+        let picker = context.services.get::<SharedEntityPicker>().cloned();
+        let readback = context.services.get::<SharedReadback>().cloned();
+        if let (Some(picker), Some(readback), Some(lane)) =
+            (picker, readback, self.lanes.get("EntityIdPass"))
+        {
+            if picker.lock().is_ok_and(|p| p.has_pending()) {
+                let mut encoder = device.create_command_encoder(Some("Khora Entity ID Encoder"));
+                {
+                    let mut ctx = LaneContext::new();
+                    ctx.insert(device.clone());
+                    ctx.insert(gpu_meshes.clone());
+                    // SAFETY: same contract as the scene encoder above.
+                    let encoder_slot = Slot::new(encoder.as_mut());
+                    ctx.insert(unsafe {
+                        std::mem::transmute::<
+                            Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                            Slot<dyn khora_core::renderer::traits::CommandEncoder>,
+                        >(encoder_slot)
+                    });
+                    ctx.insert(khora_core::lane::Ref::new(render_world));
+                    ctx.insert(picker);
+                    ctx.insert(readback);
+                    if let Err(e) = lane.execute(&mut ctx) {
+                        log::error!("Render lane {} failed: {}", lane.strategy_name(), e);
+                    }
+                }
+                frame_graph
+                    .lock()
+                    .expect("FrameGraph mutex poisoned")
+                    .add_pass(PassDescriptor::new("EntityIdPass"), encoder.finish());
+            }
+        }
+
         self.last_frame_time = frame_start.elapsed();
 
         // Refresh per-frame metrics from the LaneBus's RenderWorld view.
@@ -336,6 +663,14 @@ impl Agent for RenderAgent {
         self.last_light_count = render_world.directional_light_count()
             + render_world.point_light_count()
             + render_world.spot_light_count();
+        self.culling_stats = if self.light_stats {
+            self.lanes
+                .get("ForwardPlus")
+                .and_then(|lane| lane.as_any().downcast_ref::<ForwardPlusLane>())
+                .and_then(ForwardPlusLane::culling_stats)
+        } else {
+            None
+        };
 
         self.frame_count += 1;
     }
@@ -349,18 +684,30 @@ impl Agent for RenderAgent {
             ratio.min(1.0)
         };
 
+        let mut message = format!(
+            "frame_time={:.2}ms draws={} tris={} lights={}",
+            self.last_frame_time.as_secs_f32() * 1000.0,
+            self.draw_call_count,
+            self.triangle_count,
+            self.last_light_count,
+        );
+        if let Some(stats) = &self.culling_stats {
+            message.push_str(&format!(
+                " tile_lights(avg={:.1} max={} empty={}/{} saturated={})",
+                stats.average_tile_lights(),
+                stats.max_tile_lights,
+                stats.empty_tiles,
+                stats.tiles,
+                stats.saturated_tiles,
+            ));
+        }
+
         AgentStatus {
             agent_id: self.id(),
             health_score,
             current_strategy: self.current_strategy,
             is_stalled: self.execute_attempts > 0 && self.frame_count == 0,
-            message: format!(
-                "frame_time={:.2}ms draws={} tris={} lights={}",
-                self.last_frame_time.as_secs_f32() * 1000.0,
-                self.draw_call_count,
-                self.triangle_count,
-                self.last_light_count,
-            ),
+            message,
         }
     }
 
@@ -387,6 +734,7 @@ impl Agent for RenderAgent {
                 kind: DependencyKind::Hard,
                 condition: None,
             }],
+            affinity: AgentAffinity::MainThread,
         }
     }
 }
@@ -397,10 +745,22 @@ impl Default for RenderAgent {
         lanes.register(Box::new(SimpleUnlitLane::new()));
         lanes.register(Box::new(LitForwardLane::new()));
         lanes.register(Box::new(ForwardPlusLane::new()));
+        lanes.register(Box::new(SkinningLane::new()));
+        lanes.register(Box::new(IdPassLane::new()));
+        lanes.register(Box::new(ToneMapLane::new()));
+        lanes.register(Box::new(AutoExposureLane::new()));
+        lanes.register(Box::new(BloomLane::new()));
+        lanes.register(Box::new(MsaaLane::new()));
+        lanes.register(Box::new(FxaaLane::new()));
+        lanes.register(Box::new(TaaLane::new()));
+        lanes.register(Box::new(MotionVectorLane::new()));
+        lanes.register(Box::new(DepthPrepassLane::new()));
 
         Self {
             lanes,
             strategy: RenderingStrategy::Auto,
+            anti_aliasing: anti_aliasing_for(StrategyId::Balanced),
+            bloom: bloom_for(StrategyId::Balanced),
             current_strategy: StrategyId::Balanced,
             time_budget: Duration::ZERO,
             last_frame_time: Duration::ZERO,
@@ -408,6 +768,10 @@ impl Default for RenderAgent {
             triangle_count: 0,
             frame_count: 0,
             last_light_count: 0,
+            debug_view: LightingDebugView::None,
+            light_stats: false,
+            cvar_generation: None,
+            culling_stats: None,
             execute_attempts: 0,
         }
     }
@@ -417,8 +781,12 @@ impl Default for RenderAgent {
 // Free helpers — kept off the agent struct per CLAD trait-purity rule.
 // ─────────────────────────────────────────────────────────────────────
 
-fn lane_name_for_strategy(strategy: RenderingStrategy, world: &RenderWorld) -> &'static str {
-    match strategy {
+fn lane_name_for_strategy(
+    strategy: RenderingStrategy,
+    world: &RenderWorld,
+    lanes: &LaneRegistry,
+) -> &'static str {
+    let name = match strategy {
         RenderingStrategy::Unlit => "SimpleUnlit",
         RenderingStrategy::LitForward => "LitForward",
         RenderingStrategy::ForwardPlus => "ForwardPlus",
@@ -434,6 +802,95 @@ fn lane_name_for_strategy(strategy: RenderingStrategy, world: &RenderWorld) -> &
                 "SimpleUnlit"
             }
         }
+    };
+    // Forward+ is excluded on devices without compute shaders.
+    if name == "ForwardPlus" && lanes.get(name).is_none() {
+        "LitForward"
+    } else {
+        name
+    }
+}
+
+/// Anti-aliasing technique paired with each GORNA strategy: FXAA when
+/// power is short, TAA by default and MSAA with GPU headroom.
+fn anti_aliasing_for(strategy: StrategyId) -> AntiAliasingMode {
+    match strategy {
+        StrategyId::LowPower => AntiAliasingMode::Fxaa,
+        StrategyId::HighPerformance => AntiAliasingMode::Msaa,
+        StrategyId::Balanced | StrategyId::Custom(_) => AntiAliasingMode::Taa,
+    }
+}
+
+/// Whether a GORNA strategy affords bloom; it is the first effect dropped
+/// when power is short.
+fn bloom_for(strategy: StrategyId) -> bool {
+    !matches!(strategy, StrategyId::LowPower)
+}
+
+fn lane_name_for_anti_aliasing(mode: AntiAliasingMode) -> &'static str {
+    match mode {
+        AntiAliasingMode::Taa => "Taa",
+        AntiAliasingMode::Msaa => "Msaa",
+        _ => "Fxaa",
+    }
+}
+
+/// Estimated cost of `mode`'s lanes, including the motion vectors TAA
+/// reprojects through.
+fn anti_aliasing_cost(lanes: &LaneRegistry, mode: AntiAliasingMode, ctx: &LaneContext) -> f32 {
+    let cost = |name: &str| lanes.get(name).map_or(0.0, |lane| lane.estimate_cost(ctx));
+    match mode {
+        AntiAliasingMode::Taa => cost("Taa") + cost("MotionVectors"),
+        mode => cost(lane_name_for_anti_aliasing(mode)),
+    }
+}
+
+/// Estimated cost of the post-processing lanes, with or without bloom.
+fn post_process_cost(lanes: &LaneRegistry, bloom: bool, ctx: &LaneContext) -> f32 {
+    let cost = |name: &str| lanes.get(name).map_or(0.0, |lane| lane.estimate_cost(ctx));
+    let bloom_cost = if bloom { cost("Bloom") } else { 0.0 };
+    cost("ToneMapping") + bloom_cost
+}
+
+/// Registers the lighting debug console variables, starting the debug
+/// view at `debug_view`.
+fn register_debug_cvars(cvars: &CVarRegistry, debug_view: LightingDebugView) {
+    let names: Vec<_> = LightingDebugView::ALL.iter().map(|v| v.name()).collect();
+    let registered = [
+        cvars.register(
+            CVAR_DEBUG_VIEW,
+            &format!("Lighting debug view ({})", names.join(", ")),
+            debug_view.name(),
+        ),
+        cvars.register(
+            CVAR_LIGHT_STATS,
+            "Read back Forward+ light culling statistics",
+            false,
+        ),
+    ];
+    for error in registered.into_iter().filter_map(Result::err) {
+        log::warn!("RenderAgent: {}", error);
+    }
+}
+
+/// Reads the lighting debug view and whether light culling statistics are
+/// on. Unknown view names fall back to regular shading.
+fn read_debug_cvars(cvars: &CVarRegistry) -> (LightingDebugView, bool) {
+    let name = cvars.get_string(CVAR_DEBUG_VIEW).unwrap_or_default();
+    let debug_view = LightingDebugView::from_name(&name).unwrap_or_else(|| {
+        log::warn!("RenderAgent: Unknown lighting debug view '{}'", name);
+        LightingDebugView::None
+    });
+    let light_stats = cvars.get_bool(CVAR_LIGHT_STATS).unwrap_or(false);
+    (debug_view, light_stats)
+}
+
+/// Whether the scene gets a depth prepass this frame.
+fn wants_depth_prepass(mode: DepthPrepassMode, world: &RenderWorld) -> bool {
+    match mode {
+        DepthPrepassMode::Always => true,
+        DepthPrepassMode::Auto => world.depth_complexity >= DEPTH_PREPASS_OVERDRAW_THRESHOLD,
+        _ => false,
     }
 }
 
@@ -509,6 +966,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_budget_pairs_anti_aliasing_with_strategy() {
+        let mut agent = RenderAgent::default();
+        assert_eq!(agent.anti_aliasing, AntiAliasingMode::Taa);
+        for (strategy_id, mode) in [
+            (StrategyId::LowPower, AntiAliasingMode::Fxaa),
+            (StrategyId::HighPerformance, AntiAliasingMode::Msaa),
+            (StrategyId::Balanced, AntiAliasingMode::Taa),
+        ] {
+            agent.apply_budget(ResourceBudget {
+                strategy_id,
+                time_limit: Duration::from_millis(16),
+                memory_limit: None,
+                extra_params: std::collections::HashMap::new(),
+            });
+            assert_eq!(agent.anti_aliasing, mode);
+        }
+    }
+
+    #[test]
+    fn test_apply_budget_drops_bloom_when_power_is_short() {
+        let mut agent = RenderAgent::default();
+        assert!(agent.bloom);
+        for (strategy_id, bloom) in [
+            (StrategyId::LowPower, false),
+            (StrategyId::HighPerformance, true),
+            (StrategyId::Balanced, true),
+        ] {
+            agent.apply_budget(ResourceBudget {
+                strategy_id,
+                time_limit: Duration::from_millis(16),
+                memory_limit: None,
+                extra_params: std::collections::HashMap::new(),
+            });
+            assert_eq!(agent.bloom, bloom);
+        }
+
+        let ctx = LaneContext::new();
+        assert!(
+            post_process_cost(&agent.lanes, true, &ctx)
+                > post_process_cost(&agent.lanes, false, &ctx)
+        );
+    }
+
+    #[test]
+    fn test_depth_prepass_follows_mode_and_overdraw() {
+        let mut world = RenderWorld::new();
+        world.depth_complexity = 1.0;
+        assert!(!wants_depth_prepass(DepthPrepassMode::Auto, &world));
+        assert!(wants_depth_prepass(DepthPrepassMode::Always, &world));
+
+        world.depth_complexity = DEPTH_PREPASS_OVERDRAW_THRESHOLD;
+        assert!(wants_depth_prepass(DepthPrepassMode::Auto, &world));
+        assert!(!wants_depth_prepass(DepthPrepassMode::Never, &world));
+    }
+
+    #[test]
+    fn test_debug_cvars_select_view_and_stats() {
+        let cvars = CVarRegistry::new();
+        register_debug_cvars(&cvars, LightingDebugView::Albedo);
+        assert_eq!(read_debug_cvars(&cvars), (LightingDebugView::Albedo, false));
+
+        cvars.set_str(CVAR_DEBUG_VIEW, "light_heatmap").unwrap();
+        cvars.set(CVAR_LIGHT_STATS, true).unwrap();
+        assert_eq!(
+            read_debug_cvars(&cvars),
+            (LightingDebugView::LightHeatmap, true)
+        );
+
+        cvars.set_str(CVAR_DEBUG_VIEW, "wireframe").unwrap();
+        assert_eq!(read_debug_cvars(&cvars).0, LightingDebugView::None);
+    }
+
     #[test]
     fn test_report_status_initial_state() {
         let agent = RenderAgent::default();
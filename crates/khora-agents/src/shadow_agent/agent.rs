@@ -22,23 +22,27 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use khora_core::agent::{Agent, AgentImportance, ExecutionPhase, ExecutionTiming};
+use khora_core::agent::{Agent, AgentAffinity, AgentImportance, ExecutionPhase, ExecutionTiming};
 use khora_core::control::gorna::{
     AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
     StrategyOption,
 };
 use khora_core::lane::{
-    LaneContext, LaneKind, LaneRegistry, Ref, ShadowAtlasView, ShadowComparisonSampler, Slot,
+    DeviceCapabilities, LaneContext, LaneKind, LaneRegistry, Ref, ShadowAtlasView,
+    ShadowComparisonSampler, Slot,
 };
 use khora_core::renderer::api::core::FrameContext;
 use khora_core::renderer::GraphicsDevice;
 use khora_core::EngineContext;
-use khora_data::render::RenderWorld;
+use khora_data::render::{PassDescriptor, RenderWorld, ResourceId, SharedFrameGraph};
 use khora_data::GpuCache;
-use khora_lanes::render_lane::ShadowPassLane;
+use khora_lanes::render_lane::{CascadedShadowLane, ShadowPassLane};
 
 const COST_TO_MS_SCALE: f32 = 5.0;
 
+/// Bytes of one 2048×2048 Depth32Float shadow atlas layer.
+const ATLAS_LAYER_BYTES: u64 = 2048 * 2048 * 4;
+
 /// The agent responsible for shadow map rendering (`LaneKind::Shadow`).
 ///
 /// Holds **only** its own strategy state — every other dependency
@@ -73,27 +77,26 @@ impl Agent for ShadowAgent {
         let mut ctx = LaneContext::new();
         ctx.insert(Ref::new(&stub_world));
 
-        let cost = self
-            .lanes
-            .find_by_kind(LaneKind::Shadow)
-            .first()
-            .map(|lane| lane.estimate_cost(&ctx))
-            .unwrap_or(1.0);
-        let estimated_time = Duration::from_secs_f32((cost * COST_TO_MS_SCALE).max(0.1) / 1000.0);
-
-        // 2048×2048×4 layers @ Depth32Float = 64 MB for the atlas.
-        let estimated_vram = 64u64 * 1024 * 1024;
-
         let mut strategies = Vec::new();
-        let fits_constraint = request
-            .constraints
-            .max_vram_bytes
-            .map(|max| estimated_vram <= max)
-            .unwrap_or(true);
-        if fits_constraint {
+        for lane in self.lanes.find_by_kind(LaneKind::Shadow) {
+            // 4 atlas layers for single maps, 8 for two suns' cascades.
+            let (strategy_id, estimated_vram) = match lane.strategy_name() {
+                "ShadowPass" => (StrategyId::Balanced, 4 * ATLAS_LAYER_BYTES),
+                "CascadedShadow" => (StrategyId::HighPerformance, 8 * ATLAS_LAYER_BYTES),
+                _ => continue,
+            };
+            if let Some(max_vram) = request.constraints.max_vram_bytes {
+                if estimated_vram > max_vram {
+                    continue;
+                }
+            }
+
+            let cost = lane.estimate_cost(&ctx);
             strategies.push(StrategyOption {
-                id: StrategyId::HighPerformance,
-                estimated_time,
+                id: strategy_id,
+                estimated_time: Duration::from_secs_f32(
+                    (cost * COST_TO_MS_SCALE).max(0.1) / 1000.0,
+                ),
                 estimated_vram,
             });
         }
@@ -124,6 +127,8 @@ impl Agent for ShadowAgent {
             log::warn!("ShadowAgent: graphics device unavailable in on_initialize");
             return;
         };
+        self.lanes
+            .set_capabilities(DeviceCapabilities::from_device(device_arc.as_ref()));
 
         let mut init_ctx = LaneContext::new();
         init_ctx.insert(device_arc);
@@ -138,6 +143,21 @@ impl Agent for ShadowAgent {
         }
     }
 
+    fn on_shutdown(&mut self, context: &mut EngineContext<'_>) {
+        // Mirror of on_initialize: give every lane the device so it can
+        // release its pipelines and buffers before the device goes away.
+        let Some(device_arc) = context.services.get::<Arc<dyn GraphicsDevice>>().cloned() else {
+            return;
+        };
+
+        let mut shutdown_ctx = LaneContext::new();
+        shutdown_ctx.insert(device_arc);
+        for lane in self.lanes.all() {
+            lane.on_shutdown(&mut shutdown_ctx);
+        }
+        log::debug!("ShadowAgent: Lanes shut down");
+    }
+
     fn execute(&mut self, context: &mut EngineContext<'_>) {
         self.execute_attempts += 1;
         let frame_start = Instant::now();
@@ -189,7 +209,9 @@ impl Agent for ShadowAgent {
             // before the slot is dropped.
             ctx.insert(Slot::new(&mut *context.deck));
 
-            for lane in self.lanes.find_by_kind(LaneKind::Shadow) {
+            // Both lanes write the same atlas outputs: run only the one
+            // the current strategy selects.
+            if let Some(lane) = self.lanes.get(shadow_lane_for(self.current_strategy)) {
                 if let Err(e) = lane.execute(&mut ctx) {
                     log::error!(
                         "ShadowAgent: shadow lane {} failed: {}",
@@ -212,7 +234,21 @@ impl Agent for ShadowAgent {
                 }
             }
         }
-        device.submit_command_buffer(encoder.finish());
+        let cmd_buf = encoder.finish();
+
+        // Hand the shadow pass to the FrameGraph so it runs after any
+        // skinning pre-pass producing the vertices it draws.
+        let mut pass = PassDescriptor::new("ShadowPass").writes(ResourceId::ShadowAtlas);
+        if render_world.meshes.iter().any(|m| m.skin.is_some()) {
+            pass = pass.reads(ResourceId::SkinnedVertices);
+        }
+        match context.services.get::<SharedFrameGraph>() {
+            Some(frame_graph) => frame_graph
+                .lock()
+                .expect("FrameGraph mutex poisoned")
+                .add_pass(pass, cmd_buf),
+            None => device.submit_command_buffer(cmd_buf),
+        }
 
         self.last_frame_time = frame_start.elapsed();
         self.frame_count += 1;
@@ -247,6 +283,7 @@ impl Agent for ShadowAgent {
             importance: AgentImportance::Important,
             dependencies: vec![],
             fixed_timestep: None,
+            affinity: AgentAffinity::MainThread,
         }
     }
 
@@ -263,6 +300,7 @@ impl Default for ShadowAgent {
     fn default() -> Self {
         let mut lanes = LaneRegistry::new();
         lanes.register(Box::new(ShadowPassLane::default()));
+        lanes.register(Box::new(CascadedShadowLane::default()));
 
         Self {
             lanes,
@@ -274,3 +312,14 @@ impl Default for ShadowAgent {
         }
     }
 }
+
+/// Returns the name of the shadow lane `strategy` runs.
+///
+/// `HighPerformance` renders cascaded maps for directional lights; the
+/// cheaper strategies keep one map per light.
+fn shadow_lane_for(strategy: StrategyId) -> &'static str {
+    match strategy {
+        StrategyId::HighPerformance | StrategyId::Custom(_) => "CascadedShadow",
+        StrategyId::Balanced | StrategyId::LowPower => "ShadowPass",
+    }
+}
@@ -30,7 +30,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
 
-use khora_core::agent::{Agent, AgentImportance, ExecutionPhase, ExecutionTiming};
+use khora_core::agent::{Agent, AgentAffinity, AgentImportance, ExecutionPhase, ExecutionTiming};
 use khora_core::asset::AssetUUID;
 use khora_core::context::EngineContext;
 use khora_core::control::gorna::{
@@ -45,7 +45,7 @@ use khora_core::renderer::api::util::{AtlasRect, TextureAtlas};
 use khora_core::renderer::GraphicsDevice;
 use khora_core::ui::LayoutSystem;
 use khora_data::assets::Assets;
-use khora_data::render::{PassDescriptor, ResourceId, SharedFrameGraph};
+use khora_data::render::{PassDescriptor, PassLayer, ResourceId, SharedFrameGraph};
 use khora_data::ui::{UiAtlasMap, UiScene};
 use khora_lanes::render_lane::UiRenderLane;
 use khora_lanes::ui_lane::StandardUiLane;
@@ -140,6 +140,24 @@ impl Agent for UiAgent {
         }
     }
 
+    fn on_shutdown(&mut self, context: &mut EngineContext<'_>) {
+        let Some(device) = context.services.get::<Arc<dyn GraphicsDevice>>().cloned() else {
+            return;
+        };
+        if let Some(atlas) = self.image_atlas.take() {
+            atlas.destroy(device.as_ref());
+        }
+
+        let mut shutdown_ctx = LaneContext::new();
+        shutdown_ctx.insert(device);
+        if let Some(lane) = self.render_lane.as_ref() {
+            lane.on_shutdown(&mut shutdown_ctx);
+        }
+        if let Some(lane) = self.layout_lane.as_ref() {
+            lane.on_shutdown(&mut shutdown_ctx);
+        }
+    }
+
     fn execute(&mut self, context: &mut EngineContext<'_>) {
         // Look up everything from services every frame.
         let Some(device_arc) = context.services.get::<Arc<dyn GraphicsDevice>>() else {
@@ -208,7 +226,7 @@ impl Agent for UiAgent {
         }
 
         // Run the render lane into a fresh command buffer; the FrameGraph
-        // submits it after the scene pass.
+        // submits the UI layer after every scene pass.
         let Some(lane) = self.render_lane.as_ref() else {
             return;
         };
@@ -250,6 +268,7 @@ impl Agent for UiAgent {
             .expect("FrameGraph mutex poisoned")
             .add_pass(
                 PassDescriptor::new("UiPass")
+                    .layer(PassLayer::Ui)
                     .reads(ResourceId::Color)
                     .writes(ResourceId::Color),
                 cmd_buf,
@@ -282,6 +301,7 @@ impl Agent for UiAgent {
             importance: AgentImportance::Important,
             fixed_timestep: None,
             dependencies: Vec::new(),
+            affinity: AgentAffinity::MainThread,
         }
     }
 }
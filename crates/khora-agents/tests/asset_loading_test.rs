@@ -13,11 +13,14 @@
 // limitations under the License.
 
 use anyhow::Result;
-use khora_core::asset::{Asset, AssetMetadata, AssetSource, AssetUUID};
-use khora_io::asset::{AssetDecoder, AssetService, PackLoader};
+use khora_core::asset::{
+    ArchiveInfo, Asset, AssetMetadata, AssetPlatform, AssetSource, AssetUUID, PlatformTarget,
+};
+use khora_io::asset::{AssetDecoder, AssetDelta, AssetService, PackLoader};
 
 use khora_telemetry::MetricsRegistry;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, error::Error, fs::File};
 use tempfile::tempdir;
 
@@ -95,6 +98,149 @@ fn test_load_asset_from_pack() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_load_selects_platform_variant() -> Result<()> {
+    let dir = tempdir()?;
+    let data_path = dir.path().join("data.pack");
+
+    let texture_uuid = AssetUUID::new_v5("test/texture.png");
+    let mut data_bytes = Vec::new();
+    let mut variants = HashMap::new();
+    for (key, id) in [("default", 1u32), ("mobile", 2), ("mobile-low", 3)] {
+        variants.insert(
+            key.to_string(),
+            AssetSource::Packed {
+                offset: data_bytes.len() as u64,
+                size: 4,
+            },
+        );
+        data_bytes.extend_from_slice(&id.to_le_bytes());
+    }
+    let metadata_vec = vec![AssetMetadata {
+        uuid: texture_uuid,
+        source_path: "test/texture.png".into(),
+        asset_type_name: "texture".to_string(),
+        dependencies: vec![],
+        variants,
+        tags: vec![],
+    }];
+    let index_bytes = bincode::serde::encode_to_vec(&metadata_vec, bincode::config::standard())?;
+    std::fs::write(&data_path, &data_bytes)?;
+
+    let load_for = |target: PlatformTarget| -> Result<u32> {
+        let mut service = AssetService::new(
+            &index_bytes,
+            Box::new(PackLoader::new(File::open(&data_path)?)),
+            Arc::new(MetricsRegistry::new()),
+        )?;
+        service.register_decoder("texture", TestTextureLoader);
+        service.set_platform_target(target);
+        Ok(service.load::<TestTexture>(&texture_uuid)?.id)
+    };
+
+    assert_eq!(load_for(PlatformTarget::new(AssetPlatform::Desktop))?, 1);
+    assert_eq!(load_for(PlatformTarget::new(AssetPlatform::Mobile))?, 2);
+    assert_eq!(
+        load_for(PlatformTarget::new(AssetPlatform::Mobile).with_tier("low"))?,
+        3
+    );
+    assert_eq!(
+        load_for(PlatformTarget::new(AssetPlatform::Mobile).with_tier("high"))?,
+        2
+    );
+    Ok(())
+}
+
+#[test]
+fn test_patch_archive_overrides_base() -> Result<()> {
+    let dir = tempdir()?;
+    let base_path = dir.path().join("base.pack");
+    let patch_path = dir.path().join("patch.pack");
+
+    let entry = |name: &str, variants: HashMap<String, AssetSource>| AssetMetadata {
+        uuid: AssetUUID::new_v5(name),
+        source_path: name.into(),
+        asset_type_name: "texture".to_string(),
+        dependencies: vec![],
+        variants,
+        tags: vec![],
+    };
+    let packed = |offset: u64| {
+        HashMap::from([(
+            "default".to_string(),
+            AssetSource::Packed { offset, size: 4 },
+        )])
+    };
+    let config = bincode::config::standard();
+
+    // Base v1: `changed` = 10, `removed` = 20.
+    let changed = AssetUUID::new_v5("changed.png");
+    let removed = AssetUUID::new_v5("removed.png");
+    let added = AssetUUID::new_v5("added.png");
+    std::fs::write(
+        &base_path,
+        [10u32.to_le_bytes(), 20u32.to_le_bytes()].concat(),
+    )?;
+    let base_index = bincode::serde::encode_to_vec(
+        vec![
+            entry("changed.png", packed(0)),
+            entry("removed.png", packed(4)),
+        ],
+        config,
+    )?;
+
+    // Patch v1 → v2: `changed` becomes 11 via a delta, `added` = 30.
+    let delta = AssetDelta::diff(&10u32.to_le_bytes(), &11u32.to_le_bytes()).encode()?;
+    let mut patch_data = delta.clone();
+    patch_data.extend_from_slice(&30u32.to_le_bytes());
+    std::fs::write(&patch_path, &patch_data)?;
+    let patch_index = bincode::serde::encode_to_vec(
+        vec![
+            entry(
+                "changed.png",
+                HashMap::from([(
+                    "default".to_string(),
+                    AssetSource::Delta {
+                        offset: 0,
+                        size: delta.len() as u64,
+                    },
+                )]),
+            ),
+            entry("removed.png", HashMap::new()),
+            entry("added.png", packed(delta.len() as u64)),
+        ],
+        config,
+    )?;
+
+    let mut service = AssetService::new(
+        &base_index,
+        Box::new(PackLoader::new(File::open(&base_path)?)),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    service.register_decoder("texture", TestTextureLoader);
+
+    // A patch for another base version is refused.
+    assert!(service
+        .mount_patch(
+            &patch_index,
+            ArchiveInfo::patch(1, 2),
+            Box::new(PackLoader::new(File::open(&patch_path)?)),
+        )
+        .is_err());
+
+    service.mount_patch(
+        &patch_index,
+        ArchiveInfo::patch(0, 2),
+        Box::new(PackLoader::new(File::open(&patch_path)?)),
+    )?;
+    assert_eq!(service.content_version(), 2);
+
+    assert_eq!(service.load::<TestTexture>(&changed)?.id, 11);
+    assert_eq!(service.load::<TestTexture>(&added)?.id, 30);
+    assert!(service.load::<TestTexture>(&removed).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_load_texture_from_pack() -> Result<()> {
     use image::ImageEncoder;
@@ -247,3 +393,323 @@ fn test_asset_caching_and_shared_ownership() -> Result<()> {
     println!("Cache test passed: Asset is loaded only once and handles share the same data.");
     Ok(())
 }
+
+#[test]
+fn test_unload_unused_releases_unreferenced_assets() -> Result<()> {
+    let dir = tempdir()?;
+    let data_path = dir.path().join("data.pack");
+
+    let texture_uuid = AssetUUID::new_v5("test/texture.png");
+    let data_bytes = 42u32.to_le_bytes();
+    let mut variants = HashMap::new();
+    variants.insert(
+        "default".to_string(),
+        AssetSource::Packed { offset: 0, size: 4 },
+    );
+    let metadata_vec = vec![AssetMetadata {
+        uuid: texture_uuid,
+        source_path: "test/texture.png".into(),
+        asset_type_name: "texture".to_string(),
+        dependencies: vec![],
+        variants,
+        tags: vec![],
+    }];
+    let index_bytes = bincode::serde::encode_to_vec(&metadata_vec, bincode::config::standard())?;
+    std::fs::write(&data_path, data_bytes)?;
+
+    let mut service = AssetService::new(
+        &index_bytes,
+        Box::new(PackLoader::new(File::open(&data_path)?)),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    service.register_decoder("texture", TestTextureLoader);
+
+    let grace = Duration::from_secs(5);
+    let start = Instant::now();
+    let handle = service.load::<TestTexture>(&texture_uuid)?;
+    assert_eq!(handle.strong_count(), 2);
+
+    // Still referenced: never unloaded, however long it waits.
+    assert_eq!(
+        service.unload_unused(start + grace * 10, grace, usize::MAX),
+        0
+    );
+
+    // Unreferenced: kept for the grace period, then released.
+    drop(handle);
+    assert_eq!(service.unload_unused(start, grace, usize::MAX), 0);
+    assert_eq!(service.unload_unused(start + grace, grace, usize::MAX), 1);
+    assert_eq!(service.unload_count(), 1);
+
+    // The next load reads the asset again.
+    assert_eq!(service.load::<TestTexture>(&texture_uuid)?.id, 42);
+    assert_eq!(service.load_count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_weak_handle_upgrade_reloads_unloaded_asset() -> Result<()> {
+    let dir = tempdir()?;
+    let data_path = dir.path().join("data.pack");
+
+    let texture_uuid = AssetUUID::new_v5("test/texture.png");
+    let mut variants = HashMap::new();
+    variants.insert(
+        "default".to_string(),
+        AssetSource::Packed { offset: 0, size: 4 },
+    );
+    let metadata_vec = vec![AssetMetadata {
+        uuid: texture_uuid,
+        source_path: "test/texture.png".into(),
+        asset_type_name: "texture".to_string(),
+        dependencies: vec![],
+        variants,
+        tags: vec![],
+    }];
+    let index_bytes = bincode::serde::encode_to_vec(&metadata_vec, bincode::config::standard())?;
+    std::fs::write(&data_path, 7u32.to_le_bytes())?;
+
+    let mut service = AssetService::new(
+        &index_bytes,
+        Box::new(PackLoader::new(File::open(&data_path)?)),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    service.register_decoder("texture", TestTextureLoader);
+
+    // A weak handle does not pin the asset: it is unloaded right away.
+    let mut weak = service.load_weak::<TestTexture>(&texture_uuid)?;
+    assert!(weak.is_loaded());
+    service.unload_unused(Instant::now(), Duration::ZERO, usize::MAX);
+    assert!(!weak.is_loaded());
+
+    // Upgrading reloads it and rebinds the weak handle.
+    let handle = service.upgrade(&mut weak)?;
+    assert_eq!(handle.id, 7);
+    assert_eq!(service.load_count(), 2);
+    assert!(weak.get().is_some_and(|h| h == handle));
+
+    // While a strong handle is alive, upgrading does not reload.
+    service.upgrade(&mut weak)?;
+    assert_eq!(service.load_count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_prefetch_follows_load_quality() -> Result<()> {
+    use image::ImageEncoder;
+    use khora_core::asset::AssetLoadQuality;
+    use khora_core::renderer::api::resource::CpuTexture;
+    use khora_io::asset::decoders::TextureDecoder;
+
+    let dir = tempdir()?;
+    let data_path = dir.path().join("data.pack");
+
+    // A 4x4 opaque grey PNG.
+    let mut png_data = Vec::new();
+    image::codecs::png::PngEncoder::new(&mut png_data).write_image(
+        &[128u8; 4 * 4 * 4],
+        4,
+        4,
+        image::ExtendedColorType::Rgba8,
+    )?;
+
+    let texture_uuid = AssetUUID::new_v5("test/texture.png");
+    let mut variants = HashMap::new();
+    variants.insert(
+        "default".to_string(),
+        AssetSource::Packed {
+            offset: 0,
+            size: png_data.len() as u64,
+        },
+    );
+    let metadata_vec = vec![AssetMetadata {
+        uuid: texture_uuid,
+        source_path: "test/texture.png".into(),
+        asset_type_name: "texture".to_string(),
+        dependencies: vec![],
+        variants,
+        tags: vec![],
+    }];
+    let index_bytes = bincode::serde::encode_to_vec(&metadata_vec, bincode::config::standard())?;
+    std::fs::write(&data_path, &png_data)?;
+
+    let mut service = AssetService::new(
+        &index_bytes,
+        Box::new(PackLoader::new(File::open(&data_path)?)),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    service.register_decoder("texture", TextureDecoder);
+
+    assert!(service.prefetch(texture_uuid));
+    assert!(!service.prefetch(texture_uuid));
+    assert!(!service.prefetch(AssetUUID::new_v5("missing")));
+    let estimate = service.prefetch_estimate();
+    assert_eq!(estimate.requests, 1);
+    assert_eq!(estimate.io_bytes, png_data.len() as u64);
+    assert_eq!(estimate.texture_bytes, estimate.io_bytes);
+
+    // Metadata-only: the prefetch completes without caching the payload.
+    service.set_load_quality(AssetLoadQuality::MetadataOnly);
+    assert_eq!(service.process_prefetch(usize::MAX), 1);
+    assert_eq!(service.pending_prefetch_count(), 0);
+    assert_eq!(service.cached_type_count(), 0);
+
+    // Reduced textures: the prefetched copy skips the top mip, and the
+    // next load returns it without reading the pack again.
+    service.set_load_quality(AssetLoadQuality::ReducedTextures { skip_mips: 1 });
+    assert!(service.prefetch(texture_uuid));
+    assert_eq!(service.process_prefetch(usize::MAX), 1);
+    let texture = service.load::<CpuTexture>(&texture_uuid)?;
+    assert_eq!((texture.size.width, texture.size.height), (2, 2));
+    assert_eq!(texture.pixels.len(), 2 * 2 * 4);
+    assert_eq!(service.load_count(), 0);
+    assert_eq!(service.prefetch_count(), 2);
+
+    // Already cached: nothing left to prefetch.
+    assert!(!service.prefetch(texture_uuid));
+    Ok(())
+}
+
+#[test]
+fn test_read_async_from_mapped_pack() -> Result<()> {
+    use khora_core::executor::{block_on, Executor};
+    use khora_infra::ThreadPoolExecutor;
+    use khora_io::asset::{AssetIo, MappedPackLoader};
+
+    let dir = tempdir()?;
+    let data_path = dir.path().join("data.pack");
+
+    let uuids = [AssetUUID::new_v5("a.png"), AssetUUID::new_v5("b.png")];
+    let mut data_bytes = Vec::new();
+    let mut metadata_vec = Vec::new();
+    for (uuid, id) in uuids.iter().zip([7u32, 8]) {
+        let mut variants = HashMap::new();
+        variants.insert(
+            "default".to_string(),
+            AssetSource::Packed {
+                offset: data_bytes.len() as u64,
+                size: 4,
+            },
+        );
+        data_bytes.extend_from_slice(&id.to_le_bytes());
+        metadata_vec.push(AssetMetadata {
+            uuid: *uuid,
+            source_path: "test/texture.png".into(),
+            asset_type_name: "texture".to_string(),
+            dependencies: vec![],
+            variants,
+            tags: vec![],
+        });
+    }
+    let index_bytes = bincode::serde::encode_to_vec(&metadata_vec, bincode::config::standard())?;
+    std::fs::write(&data_path, &data_bytes)?;
+
+    // Reads through the mapping hand out views, not copies.
+    let mut pack = MappedPackLoader::open(&data_path)?;
+    let bytes = pack.load_shared(&AssetSource::Packed { offset: 4, size: 4 })?;
+    assert!(bytes.is_mapped());
+    assert_eq!(&bytes[..], &8u32.to_le_bytes());
+    assert!(pack
+        .load_shared(&AssetSource::Packed { offset: 6, size: 4 })
+        .is_err());
+
+    let mut service = AssetService::new(
+        &index_bytes,
+        Box::new(pack),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    service.register_decoder("texture", TestTextureLoader);
+
+    let executor = ThreadPoolExecutor::new(1, 1)?;
+    let executor: &dyn Executor = &executor;
+    let tasks = uuids
+        .iter()
+        .map(|uuid| service.read_async(uuid, executor))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(service
+        .read_async(&AssetUUID::new_v5("missing"), executor)
+        .is_err());
+
+    let mut ids = Vec::new();
+    for task in tasks {
+        let payload = block_on(task).expect("read task dropped")?;
+        assert!(payload.bytes.is_mapped());
+        ids.push(service.load_payload::<TestTexture>(payload)?.id);
+    }
+    assert_eq!(ids, vec![7, 8]);
+    assert_eq!(service.load_count(), 2);
+
+    // The async path fills the same cache as `load`.
+    assert_eq!(service.load::<TestTexture>(&uuids[0])?.id, 7);
+    assert_eq!(service.load_count(), 2);
+    executor.shutdown();
+    Ok(())
+}
+
+#[test]
+fn test_asset_agent_prefetches_from_its_worker_thread() -> Result<()> {
+    use khora_agents::asset_agent::AssetAgent;
+    use khora_core::agent::Agent;
+    use khora_core::utils::frame_time::FrameTime;
+    use khora_core::{EngineContext, ServiceRegistry};
+    use std::sync::{Mutex, RwLock};
+
+    let dir = tempdir()?;
+    let data_path = dir.path().join("data.pack");
+
+    let texture_uuid = AssetUUID::new_v5("test/texture.png");
+    let mut variants = HashMap::new();
+    variants.insert(
+        "default".to_string(),
+        AssetSource::Packed { offset: 0, size: 4 },
+    );
+    let metadata_vec = vec![AssetMetadata {
+        uuid: texture_uuid,
+        source_path: "test/texture.png".into(),
+        asset_type_name: "texture".to_string(),
+        dependencies: vec![],
+        variants,
+        tags: vec![],
+    }];
+    let index_bytes = bincode::serde::encode_to_vec(&metadata_vec, bincode::config::standard())?;
+    std::fs::write(&data_path, 42u32.to_le_bytes())?;
+
+    let mut service = AssetService::new(
+        &index_bytes,
+        Box::new(PackLoader::new(File::open(&data_path)?)),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    service.register_decoder("texture", TestTextureLoader);
+    assert!(service.prefetch(texture_uuid));
+    let service = Arc::new(Mutex::new(service));
+
+    // A frame long enough for the pass interval of any strategy.
+    let mut services = ServiceRegistry::new();
+    services.insert(Arc::clone(&service));
+    services.insert(Arc::new(RwLock::new(FrameTime {
+        delta_seconds: 10.0,
+        ..Default::default()
+    })));
+
+    let mut agent = AssetAgent::default();
+    assert!(agent.execution_timing().affinity.is_worker());
+
+    std::thread::spawn(move || {
+        let bus = khora_core::lane::LaneBus::new();
+        let mut deck = khora_core::lane::OutputDeck::new();
+        let mut ctx = EngineContext {
+            world: None,
+            services: Arc::new(services),
+            bus: &bus,
+            deck: &mut deck,
+        };
+        agent.execute(&mut ctx);
+    })
+    .join()
+    .unwrap();
+
+    let service = service.lock().unwrap();
+    assert_eq!(service.pending_prefetch_count(), 0);
+    assert_eq!(service.prefetch_count(), 1);
+    Ok(())
+}
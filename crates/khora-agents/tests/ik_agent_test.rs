@@ -0,0 +1,155 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for the IkAgent's GORNA protocol implementation.
+//!
+//! The agent exposes only the `Agent` trait + `Default`, so these tests
+//! drive it through `Agent` and observe the world and `report_status()`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use khora_agents::ik_agent::IkAgent;
+use khora_core::agent::{Agent, EngineMode, ExecutionTiming};
+use khora_core::context::EngineContext;
+use khora_core::control::gorna::{
+    AgentId, NegotiationRequest, ResourceBudget, ResourceConstraints, StrategyId,
+};
+use khora_core::lane::{LaneBus, OutputDeck};
+use khora_core::math::{Quaternion, Vec3};
+use khora_core::service_registry::ServiceRegistry;
+use khora_data::ecs::{IkConstraint, Parent, Transform, World};
+
+fn default_request() -> NegotiationRequest {
+    NegotiationRequest {
+        target_latency: Duration::from_millis(16),
+        priority_weight: 1.0,
+        constraints: ResourceConstraints::default(),
+        current_mode: EngineMode::Playing,
+        agent_timing: ExecutionTiming::default(),
+    }
+}
+
+fn budget_for(strategy_id: StrategyId) -> ResourceBudget {
+    ResourceBudget {
+        strategy_id,
+        time_limit: Duration::from_millis(1),
+        memory_limit: None,
+        extra_params: HashMap::new(),
+    }
+}
+
+/// Spawns a two-bone arm reaching for a target off its rest pose, and
+/// returns the shoulder.
+fn spawn_arm(world: &mut World) -> khora_core::ecs::entity::EntityId {
+    let shoulder = world.spawn(Transform::identity());
+    let elbow = world.spawn((
+        Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+        Parent(shoulder),
+    ));
+    world.spawn((
+        Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+        Parent(elbow),
+        IkConstraint::two_bone(Vec3::new(1.0, 1.0, 0.0), Some(Vec3::new(0.0, 0.0, 1.0))),
+    ));
+    shoulder
+}
+
+fn execute(agent: &mut IkAgent, world: &mut World) {
+    let bus = LaneBus::new();
+    let mut deck = OutputDeck::new();
+    let mut ctx = EngineContext {
+        world: Some(world as &mut dyn std::any::Any),
+        services: Arc::new(ServiceRegistry::new()),
+        bus: &bus,
+        deck: &mut deck,
+    };
+    agent.execute(&mut ctx);
+}
+
+#[test]
+fn test_ik_negotiates_under_its_own_agent_id() {
+    let agent = IkAgent::default();
+    assert_eq!(agent.id(), AgentId::InverseKinematics);
+    assert!(agent
+        .execution_timing()
+        .dependencies
+        .iter()
+        .any(|dep| dep.target == AgentId::Animation));
+}
+
+#[test]
+fn test_negotiate_offers_three_strategies_ordered_by_cost() {
+    let mut agent = IkAgent::default();
+    let response = agent.negotiate(default_request());
+
+    let estimate = |id| {
+        response
+            .strategies
+            .iter()
+            .find(|s| s.id == id)
+            .map(|s| s.estimated_time)
+            .expect("strategy offered")
+    };
+    let low = estimate(StrategyId::LowPower);
+    let balanced = estimate(StrategyId::Balanced);
+    let high = estimate(StrategyId::HighPerformance);
+    assert_eq!(
+        low,
+        Duration::ZERO,
+        "LowPower skips IK, so it costs nothing"
+    );
+    assert!(low < balanced && balanced < high);
+}
+
+#[test]
+fn test_low_power_budget_skips_solving() {
+    let mut world = World::new();
+    let shoulder = spawn_arm(&mut world);
+
+    let mut agent = IkAgent::default();
+    agent.apply_budget(budget_for(StrategyId::LowPower));
+    execute(&mut agent, &mut world);
+
+    assert_eq!(
+        world.get::<Transform>(shoulder).unwrap().rotation,
+        Quaternion::IDENTITY
+    );
+    assert_eq!(agent.report_status().current_strategy, StrategyId::LowPower);
+}
+
+#[test]
+fn test_balanced_budget_solves_and_measures_chains() {
+    let mut world = World::new();
+    let shoulder = spawn_arm(&mut world);
+
+    let mut agent = IkAgent::default();
+    agent.apply_budget(budget_for(StrategyId::Balanced));
+    execute(&mut agent, &mut world);
+
+    assert_ne!(
+        world.get::<Transform>(shoulder).unwrap().rotation,
+        Quaternion::IDENTITY
+    );
+    let status = agent.report_status();
+    assert!(status.message.contains("chains=1"), "{}", status.message);
+
+    // Estimates now follow the measured scene: one chain instead of the default.
+    let response = agent.negotiate(default_request());
+    assert!(response
+        .strategies
+        .iter()
+        .all(|s| s.estimated_time < Duration::from_millis(16)));
+}
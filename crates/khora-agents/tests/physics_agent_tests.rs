@@ -18,6 +18,8 @@
 //!   - `step()` is private — tests drive the agent via `execute()`.
 //!   - `cast_ray()` was removed from the agent — tests use `PhysicsQueryService`.
 
+#![cfg(feature = "physics")]
+
 use khora_agents::physics_agent::PhysicsAgent;
 use khora_agents::PhysicsQueryService;
 use khora_core::agent::Agent;
@@ -25,7 +27,9 @@ use khora_core::context::EngineContext;
 use khora_core::math::Vec3;
 use khora_core::physics::BodyType;
 use khora_core::service_registry::ServiceRegistry;
-use khora_data::ecs::{RigidBody, Transform, World};
+use khora_data::ecs::{
+    world_channel, GlobalTransform, GravityZone, PhysicsInterpolation, RigidBody, Transform, World,
+};
 use khora_infra::physics::rapier::RapierPhysicsWorld;
 use std::sync::{Arc, Mutex};
 
@@ -105,6 +109,156 @@ fn test_physics_gravity_influence() {
     );
 }
 
+#[test]
+fn test_physics_steps_from_its_worker_thread() {
+    let mut world = World::new();
+    let provider: Arc<Mutex<Box<dyn khora_core::physics::PhysicsProvider>>> =
+        Arc::new(Mutex::new(Box::new(RapierPhysicsWorld::default())));
+
+    let (channel, requests) = world_channel();
+    let mut services = ServiceRegistry::new();
+    services.insert(Arc::clone(&provider));
+    services.insert(channel);
+    let services = Arc::new(services);
+
+    let mut agent = PhysicsAgent::default();
+    assert!(agent.execution_timing().affinity.is_worker());
+
+    let entity = world.spawn((
+        Transform::new(Vec3::new(0.0, 10.0, 0.0), Default::default(), Vec3::ONE),
+        GlobalTransform::at_position(Vec3::new(0.0, 10.0, 0.0)),
+        RigidBody::new_dynamic(1.0),
+    ));
+
+    // The agent gets no world, only the channel; this thread serves it,
+    // as the frame thread does for a worker agent.
+    let worker = std::thread::spawn(move || {
+        let bus = khora_core::lane::LaneBus::new();
+        let mut deck = khora_core::lane::OutputDeck::new();
+        for _ in 0..10 {
+            let mut ctx = EngineContext {
+                world: None,
+                services: Arc::clone(&services),
+                bus: &bus,
+                deck: &mut deck,
+            };
+            agent.execute(&mut ctx);
+        }
+    });
+    while !worker.is_finished() {
+        requests.serve(&mut world);
+        std::thread::yield_now();
+    }
+    worker.join().unwrap();
+
+    let transform = world.get::<Transform>(entity).unwrap();
+    assert!(
+        transform.translation.y < 10.0,
+        "Entity should have fallen under gravity. Current Y: {}",
+        transform.translation.y
+    );
+}
+
+#[test]
+fn test_physics_gravity_zone_and_scale() {
+    let mut world = World::new();
+    let provider: Arc<Mutex<Box<dyn khora_core::physics::PhysicsProvider>>> =
+        Arc::new(Mutex::new(Box::new(RapierPhysicsWorld::default())));
+
+    let services = make_services(&provider);
+    let mut agent = PhysicsAgent::default();
+
+    {
+        let bus = khora_core::lane::LaneBus::new();
+        let mut deck = khora_core::lane::OutputDeck::new();
+        let mut ctx = make_init_ctx(&mut world, &services, &bus, &mut deck);
+        agent.on_initialize(&mut ctx);
+    }
+
+    // A weightless body ignores world gravity.
+    let floating = world.spawn((
+        Transform::new(Vec3::new(-50.0, 10.0, 0.0), Default::default(), Vec3::ONE),
+        GlobalTransform::at_position(Vec3::new(-50.0, 10.0, 0.0)),
+        RigidBody::new_dynamic(1.0).with_gravity_scale(0.0),
+    ));
+
+    // A planet at (10, 10, 0) attracts the body sideways, replacing world gravity.
+    world.spawn((
+        GlobalTransform::at_position(Vec3::new(10.0, 10.0, 0.0)),
+        GravityZone::spherical(20.0, 9.81),
+    ));
+    let orbiting = world.spawn((
+        Transform::new(Vec3::new(0.0, 10.0, 0.0), Default::default(), Vec3::ONE),
+        GlobalTransform::at_position(Vec3::new(0.0, 10.0, 0.0)),
+        RigidBody::new_dynamic(1.0),
+    ));
+
+    step_n(&mut agent, &mut world, &services, 10);
+
+    let floating = world.get::<Transform>(floating).unwrap();
+    assert!(
+        (floating.translation.y - 10.0).abs() < 1e-4,
+        "Weightless body should not fall. Current Y: {}",
+        floating.translation.y
+    );
+
+    let orbiting = world.get::<Transform>(orbiting).unwrap();
+    assert!(
+        orbiting.translation.x > 0.0,
+        "Body should be pulled towards the planet. Current X: {}",
+        orbiting.translation.x
+    );
+    assert!(
+        (orbiting.translation.y - 10.0).abs() < 1e-4,
+        "Zone should replace world gravity. Current Y: {}",
+        orbiting.translation.y
+    );
+}
+
+#[test]
+fn test_physics_interpolation_records_ticks() {
+    let mut world = World::new();
+    let provider: Arc<Mutex<Box<dyn khora_core::physics::PhysicsProvider>>> =
+        Arc::new(Mutex::new(Box::new(RapierPhysicsWorld::default())));
+
+    let services = make_services(&provider);
+    let mut agent = PhysicsAgent::default();
+
+    {
+        let bus = khora_core::lane::LaneBus::new();
+        let mut deck = khora_core::lane::OutputDeck::new();
+        let mut ctx = make_init_ctx(&mut world, &services, &bus, &mut deck);
+        agent.on_initialize(&mut ctx);
+    }
+
+    let entity = world.spawn((
+        Transform::new(Vec3::new(0.0, 10.0, 0.0), Default::default(), Vec3::ONE),
+        GlobalTransform::at_position(Vec3::new(0.0, 10.0, 0.0)),
+        RigidBody::new_dynamic(1.0),
+        PhysicsInterpolation::interpolated(),
+    ));
+
+    // Propagate transforms between frames, as the engine does, so the
+    // lane does not mistake the fall for a teleport.
+    for _ in 0..3 {
+        step_n(&mut agent, &mut world, &services, 1);
+        let matrix = world.get::<Transform>(entity).unwrap().to_mat4();
+        *world.get_mut::<GlobalTransform>(entity).unwrap() = GlobalTransform::new(matrix);
+    }
+
+    let interpolation = world.get::<PhysicsInterpolation>(entity).unwrap();
+    let previous = interpolation.previous.expect("previous tick recorded");
+    let current = interpolation.current.expect("latest tick recorded");
+    assert!(
+        current.translation.y < previous.translation.y,
+        "Falling body should record descending poses: {} -> {}",
+        previous.translation.y,
+        current.translation.y
+    );
+    // Without a frame clock every frame consumes exactly one tick.
+    assert!(interpolation.alpha.abs() < 1e-5);
+}
+
 #[test]
 fn test_physics_raycast() {
     let mut world = World::new();
@@ -139,7 +293,7 @@ fn test_physics_raycast() {
         direction: Vec3::new(0.0, -1.0, 0.0),
     };
 
-    let hit = query_svc.cast_ray(&ray, 10.0, true);
+    let hit = query_svc.cast_ray(&ray, 10.0, true, None);
     assert!(hit.is_some(), "Ray should hit the static box");
 
     let hit = hit.unwrap();
@@ -149,6 +303,11 @@ fn test_physics_raycast() {
         "Hit Y should be ~1.0, got {}",
         hit.position.y
     );
+
+    // Excluding the box lets the ray through.
+    assert!(query_svc
+        .cast_ray(&ray, 10.0, true, Some(hit.collider))
+        .is_none());
 }
 
 #[test]
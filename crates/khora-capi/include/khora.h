@@ -0,0 +1,92 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*
+ * Khora C API — embed the engine in a C or C++ application.
+ *
+ * The engine runs headless: the host feeds input, steps frames, loads
+ * scenes and reads entity transforms back. An engine is not thread-safe;
+ * call it from one thread at a time. Every function that returns a
+ * KhoraResult leaves a message in khora_last_error() when it fails.
+ */
+
+#ifndef KHORA_H
+#define KHORA_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum KhoraResult {
+    KHORA_OK = 0,
+    KHORA_NULL_POINTER = 1,
+    KHORA_INVALID_ARGUMENT = 2,
+    KHORA_NOT_FOUND = 3,
+    KHORA_IO = 4,
+    KHORA_INVALID_SCENE = 5,
+    KHORA_PANIC = 6,
+} KhoraResult;
+
+typedef struct KhoraEngine KhoraEngine;
+
+typedef struct KhoraEntity {
+    uint32_t index;
+    uint32_t generation;
+} KhoraEntity;
+
+typedef struct KhoraTransform {
+    float translation[3];
+    float rotation[4]; /* x, y, z, w */
+    float scale[3];
+} KhoraTransform;
+
+/* Message of the last error on this thread; valid until the next failure. */
+const char *khora_last_error(void);
+
+/* Lifecycle. khora_engine_create returns NULL on failure. */
+KhoraEngine *khora_engine_create(void);
+void khora_engine_destroy(KhoraEngine *engine);
+
+/* Frames. */
+KhoraResult khora_engine_step(KhoraEngine *engine);
+uint64_t khora_engine_frame(const KhoraEngine *engine);
+
+/* Input, queued for the next frame. Mouse buttons: 0 left, 1 right,
+ * 2 middle, 3 back, 4 forward. */
+KhoraResult khora_engine_push_key(KhoraEngine *engine, const char *key_code, bool pressed);
+KhoraResult khora_engine_push_mouse_button(KhoraEngine *engine, uint16_t button, bool pressed);
+KhoraResult khora_engine_push_mouse_move(KhoraEngine *engine, float x, float y);
+KhoraResult khora_engine_push_mouse_wheel(KhoraEngine *engine, float delta_x, float delta_y);
+
+/* Scenes: replaces the world with a .kscene file. */
+KhoraResult khora_engine_load_scene(KhoraEngine *engine, const char *path);
+
+/* Entities. khora_engine_entities writes up to `capacity` handles and
+ * returns the total count. */
+size_t khora_engine_entities(const KhoraEngine *engine, KhoraEntity *out, size_t capacity);
+KhoraResult khora_entity_get_transform(const KhoraEngine *engine, KhoraEntity entity,
+                                       KhoraTransform *out);
+/* 16 floats, column-major. */
+KhoraResult khora_entity_get_world_matrix(const KhoraEngine *engine, KhoraEntity entity,
+                                          float *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* KHORA_H */
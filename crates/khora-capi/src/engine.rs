@@ -0,0 +1,318 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Engine lifecycle, input, frame stepping and scene loading.
+
+use std::ffi::{c_char, CStr};
+
+use khora_sdk::prelude::MouseButton;
+use khora_sdk::{
+    AgentProvider, DccService, EngineApp, GameWorld, HeadlessRunner, InputEvent, PhaseProvider,
+    SceneFile, SerializationService, ServiceRegistry, WindowConfig,
+};
+
+use crate::result::{fail, guard, KhoraResult};
+
+/// An embedded engine instance, opaque to C.
+///
+/// Created by [`khora_engine_create`] and released by
+/// [`khora_engine_destroy`]. An engine is not thread-safe: call it from
+/// one thread at a time.
+pub struct KhoraEngine {
+    pub(crate) runner: HeadlessRunner<EmbeddedApp>,
+}
+
+/// The app the embedded engine runs: the host drives everything through
+/// the C API.
+pub(crate) struct EmbeddedApp;
+
+impl AgentProvider for EmbeddedApp {
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+}
+
+impl PhaseProvider for EmbeddedApp {}
+
+impl EngineApp for EmbeddedApp {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn setup(&mut self, _world: &mut GameWorld, _services: &ServiceRegistry) {}
+
+    fn update(&mut self, _world: &mut GameWorld, _inputs: &[InputEvent]) {}
+}
+
+/// Resolves an engine pointer, or returns [`KhoraResult::NullPointer`].
+///
+/// # Safety
+///
+/// `engine` must be null or come from [`khora_engine_create`] and not be
+/// destroyed yet.
+pub(crate) unsafe fn engine_mut<'a>(
+    engine: *mut KhoraEngine,
+) -> Result<&'a mut KhoraEngine, KhoraResult> {
+    // SAFETY: a non-null `engine` is a live engine, per the contract above.
+    engine
+        .as_mut()
+        .ok_or_else(|| fail(KhoraResult::NullPointer, "engine is null"))
+}
+
+/// Reads a UTF-8 C string argument.
+///
+/// # Safety
+///
+/// `value` must be null or point to a NUL-terminated string.
+unsafe fn read_str<'a>(value: *const c_char, name: &str) -> Result<&'a str, KhoraResult> {
+    if value.is_null() {
+        return Err(fail(KhoraResult::NullPointer, format!("{name} is null")));
+    }
+    // SAFETY: `value` is not null, so it points to a NUL-terminated string,
+    // per the contract above.
+    CStr::from_ptr(value).to_str().map_err(|_| {
+        fail(
+            KhoraResult::InvalidArgument,
+            format!("{name} is not valid UTF-8"),
+        )
+    })
+}
+
+/// Creates a headless engine. Returns null on failure.
+#[no_mangle]
+pub extern "C" fn khora_engine_create() -> *mut KhoraEngine {
+    let mut engine = None;
+    guard(|| {
+        engine = Some(Box::new(KhoraEngine {
+            runner: HeadlessRunner::new(EmbeddedApp),
+        }));
+        KhoraResult::Ok
+    });
+    engine.map_or(std::ptr::null_mut(), Box::into_raw)
+}
+
+/// Shuts an engine down and frees it. Does nothing if `engine` is null.
+///
+/// # Safety
+///
+/// `engine` must be null or come from [`khora_engine_create`], and must
+/// not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_destroy(engine: *mut KhoraEngine) {
+    if engine.is_null() {
+        return;
+    }
+    // SAFETY: `engine` was boxed by `khora_engine_create`, and the caller
+    // gives it up here.
+    let engine = Box::from_raw(engine);
+    guard(move || {
+        engine.runner.shutdown();
+        KhoraResult::Ok
+    });
+}
+
+/// Runs one frame.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_step(engine: *mut KhoraEngine) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract.
+    let engine = match engine_mut(engine) {
+        Ok(engine) => engine,
+        Err(result) => return result,
+    };
+    guard(|| {
+        engine.runner.step();
+        KhoraResult::Ok
+    })
+}
+
+/// Returns the number of frames run so far, or `0` if `engine` is null.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_frame(engine: *const KhoraEngine) -> u64 {
+    // SAFETY: a non-null `engine` is a live engine, per the contract above.
+    engine.as_ref().map_or(0, |engine| engine.runner.frame())
+}
+
+/// Queues an input event for the next frame.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine.
+unsafe fn push(
+    engine: *mut KhoraEngine,
+    event: impl FnOnce() -> Result<InputEvent, KhoraResult>,
+) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract.
+    let engine = match engine_mut(engine) {
+        Ok(engine) => engine,
+        Err(result) => return result,
+    };
+    match event() {
+        Ok(event) => {
+            engine.runner.inject(event);
+            KhoraResult::Ok
+        }
+        Err(result) => result,
+    }
+}
+
+/// Queues a key press or release. `key_code` is the physical key name,
+/// e.g. `"KeyW"` or `"Space"`.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine, and `key_code` null or a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_push_key(
+    engine: *mut KhoraEngine,
+    key_code: *const c_char,
+    pressed: bool,
+) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract, which also covers
+    // `key_code`.
+    push(engine, || {
+        let key_code = read_str(key_code, "key_code")?.to_owned();
+        Ok(if pressed {
+            InputEvent::KeyPressed { key_code }
+        } else {
+            InputEvent::KeyReleased { key_code }
+        })
+    })
+}
+
+/// Queues a mouse button press or release. `button` is `0` left, `1`
+/// right, `2` middle, `3` back, `4` forward; other values are passed
+/// through as-is.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_push_mouse_button(
+    engine: *mut KhoraEngine,
+    button: u16,
+    pressed: bool,
+) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract.
+    push(engine, || {
+        let button = match button {
+            0 => MouseButton::Left,
+            1 => MouseButton::Right,
+            2 => MouseButton::Middle,
+            3 => MouseButton::Back,
+            4 => MouseButton::Forward,
+            other => MouseButton::Other(other),
+        };
+        Ok(if pressed {
+            InputEvent::MouseButtonPressed { button }
+        } else {
+            InputEvent::MouseButtonReleased { button }
+        })
+    })
+}
+
+/// Queues a cursor move, in physical pixels.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_push_mouse_move(
+    engine: *mut KhoraEngine,
+    x: f32,
+    y: f32,
+) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract.
+    push(engine, || Ok(InputEvent::MouseMoved { x, y }))
+}
+
+/// Queues a mouse wheel scroll.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_push_mouse_wheel(
+    engine: *mut KhoraEngine,
+    delta_x: f32,
+    delta_y: f32,
+) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract.
+    push(engine, || {
+        Ok(InputEvent::MouseWheelScrolled { delta_x, delta_y })
+    })
+}
+
+/// Replaces the world with the scene saved at `path` (a `.kscene` file).
+///
+/// On failure the world may be left empty.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine, and `path` null or a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_load_scene(
+    engine: *mut KhoraEngine,
+    path: *const c_char,
+) -> KhoraResult {
+    // SAFETY: forwarded from this function's contract.
+    let engine = match engine_mut(engine) {
+        Ok(engine) => engine,
+        Err(result) => return result,
+    };
+    // SAFETY: forwarded from this function's contract.
+    let path = match read_str(path, "path") {
+        Ok(path) => path,
+        Err(result) => return result,
+    };
+    guard(|| {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) => return fail(KhoraResult::Io, format!("cannot read '{path}': {e}")),
+        };
+        let scene = match SceneFile::from_bytes(&bytes) {
+            Ok(scene) => scene,
+            Err(e) => {
+                return fail(
+                    KhoraResult::InvalidScene,
+                    format!("'{path}' is not a scene file: {e:?}"),
+                )
+            }
+        };
+
+        let world = engine.runner.world_mut();
+        let entities: Vec<_> = world.iter_entities().collect();
+        for entity in entities {
+            world.despawn(entity);
+        }
+        match SerializationService::new().load_world(&scene, world.inner_world_mut()) {
+            Ok(()) => KhoraResult::Ok,
+            Err(e) => fail(
+                KhoraResult::InvalidScene,
+                format!("cannot load '{path}': {e:?}"),
+            ),
+        }
+    })
+}
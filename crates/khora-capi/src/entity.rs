@@ -0,0 +1,161 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entity enumeration and transform queries.
+
+use khora_sdk::prelude::ecs::{EntityId, GlobalTransform, Transform};
+
+use crate::engine::KhoraEngine;
+use crate::result::{fail, guard, KhoraResult};
+use crate::transform::KhoraTransform;
+
+/// A generational entity handle.
+///
+/// A handle stays valid until its entity is despawned; after that, the
+/// queries report [`KhoraResult::NotFound`] even if the slot is reused.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KhoraEntity {
+    /// Slot of the entity.
+    pub index: u32,
+    /// Generation of the slot when the handle was taken.
+    pub generation: u32,
+}
+
+impl From<EntityId> for KhoraEntity {
+    fn from(id: EntityId) -> Self {
+        Self {
+            index: id.index,
+            generation: id.generation,
+        }
+    }
+}
+
+impl From<KhoraEntity> for EntityId {
+    fn from(entity: KhoraEntity) -> Self {
+        Self {
+            index: entity.index,
+            generation: entity.generation,
+        }
+    }
+}
+
+/// Writes up to `capacity` live entities to `out` and returns the total
+/// number of live entities, so a host can size its buffer by calling it
+/// first with a capacity of `0`. Returns `0` if `engine` is null.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine, and `out` valid for `capacity`
+/// writes (it may be null when `capacity` is `0`).
+#[no_mangle]
+pub unsafe extern "C" fn khora_engine_entities(
+    engine: *const KhoraEngine,
+    out: *mut KhoraEntity,
+    capacity: usize,
+) -> usize {
+    // SAFETY: a non-null `engine` is a live engine, per the contract above.
+    let Some(engine) = engine.as_ref() else {
+        return 0;
+    };
+    let mut total = 0;
+    for id in engine.runner.world().iter_entities() {
+        if total < capacity && !out.is_null() {
+            // SAFETY: `out` is valid for `capacity` writes and
+            // `total < capacity`.
+            out.add(total).write(id.into());
+        }
+        total += 1;
+    }
+    total
+}
+
+/// Reads the local transform of `entity`.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine, and `out` null or valid for one
+/// write.
+#[no_mangle]
+pub unsafe extern "C" fn khora_entity_get_transform(
+    engine: *const KhoraEngine,
+    entity: KhoraEntity,
+    out: *mut KhoraTransform,
+) -> KhoraResult {
+    // SAFETY: a non-null `engine` is a live engine, per the contract above.
+    let (Some(engine), false) = (engine.as_ref(), out.is_null()) else {
+        return fail(KhoraResult::NullPointer, "engine or out is null");
+    };
+    guard(|| {
+        let Some(transform) = engine
+            .runner
+            .world()
+            .get_component::<Transform>(entity.into())
+        else {
+            return fail(
+                KhoraResult::NotFound,
+                format!("{entity:?} has no transform"),
+            );
+        };
+        let (t, r, s) = (transform.translation, transform.rotation, transform.scale);
+        // SAFETY: `out` is not null, so it is valid for one write.
+        out.write(KhoraTransform {
+            translation: [t.x, t.y, t.z],
+            rotation: [r.x, r.y, r.z, r.w],
+            scale: [s.x, s.y, s.z],
+        });
+        KhoraResult::Ok
+    })
+}
+
+/// Reads the world matrix of `entity` into 16 floats, column-major.
+///
+/// The matrix is the one computed by the last frame's transform
+/// propagation.
+///
+/// # Safety
+///
+/// `engine` must be null or a live engine, and `out` null or valid for 16
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn khora_entity_get_world_matrix(
+    engine: *const KhoraEngine,
+    entity: KhoraEntity,
+    out: *mut f32,
+) -> KhoraResult {
+    // SAFETY: a non-null `engine` is a live engine, per the contract above.
+    let (Some(engine), false) = (engine.as_ref(), out.is_null()) else {
+        return fail(KhoraResult::NullPointer, "engine or out is null");
+    };
+    guard(|| {
+        let Some(global) = engine
+            .runner
+            .world()
+            .get_component::<GlobalTransform>(entity.into())
+        else {
+            return fail(
+                KhoraResult::NotFound,
+                format!("{entity:?} has no global transform"),
+            );
+        };
+        let matrix = global.to_matrix();
+        for (i, col) in matrix.cols.iter().enumerate() {
+            for (j, value) in [col.x, col.y, col.z, col.w].into_iter().enumerate() {
+                // SAFETY: `out` is not null, so it is valid for 16 writes.
+                out.add(i * 4 + j).write(value);
+            }
+        }
+        KhoraResult::Ok
+    })
+}
@@ -0,0 +1,37 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Khora C API
+//!
+//! A minimal, stable C ABI for embedding the engine in an existing
+//! application or binding it to another language. The matching header is
+//! `include/khora.h`.
+//!
+//! The embedded engine runs headless: the host drives it frame by frame
+//! with [`khora_engine_step`], feeds it input, loads scenes and reads back
+//! entity transforms. Every function catches panics and reports failures
+//! through [`KhoraResult`], with a message available from
+//! [`khora_last_error`].
+
+#![warn(missing_docs)]
+
+mod engine;
+mod entity;
+mod result;
+mod transform;
+
+pub use engine::*;
+pub use entity::*;
+pub use result::*;
+pub use transform::*;
@@ -0,0 +1,70 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Status codes and the last error message.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Status returned by the C API functions.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KhoraResult {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// An argument was malformed, e.g. a string that is not UTF-8.
+    InvalidArgument = 2,
+    /// The entity is dead or lacks the requested component.
+    NotFound = 3,
+    /// A file could not be read.
+    Io = 4,
+    /// A scene file could not be parsed or loaded.
+    InvalidScene = 5,
+    /// The engine panicked. The engine should be destroyed.
+    Panic = 6,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// Logs `message`, records it as this thread's last error and returns
+/// `result`.
+pub(crate) fn fail(result: KhoraResult, message: impl Into<String>) -> KhoraResult {
+    let message = message.into();
+    log::error!("khora-capi: {}", message);
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+    result
+}
+
+/// Runs `f`, turning a panic into [`KhoraResult::Panic`] so it never
+/// unwinds into the host.
+pub(crate) fn guard(f: impl FnOnce() -> KhoraResult) -> KhoraResult {
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| fail(KhoraResult::Panic, "the engine panicked"))
+}
+
+/// Returns the message of the last error raised on the calling thread, or
+/// an empty string.
+///
+/// The string is owned by the library and stays valid until the next
+/// failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn khora_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ptr())
+}
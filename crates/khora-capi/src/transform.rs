@@ -0,0 +1,27 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The transform of an entity, as seen from C.
+
+/// The local transform of an entity.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct KhoraTransform {
+    /// Translation `x, y, z`.
+    pub translation: [f32; 3],
+    /// Rotation quaternion `x, y, z, w`.
+    pub rotation: [f32; 4],
+    /// Scale `x, y, z`.
+    pub scale: [f32; 3],
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! C API integration test: drives an embedded engine through the exported
+//! functions, as a C host would.
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use khora_capi::*;
+use khora_sdk::khora_core::math::Vec3;
+use khora_sdk::prelude::ecs::Transform;
+use khora_sdk::{GameWorld, SerializationGoal, SerializationService};
+use tempfile::tempdir;
+
+#[test]
+fn embedded_engine_loads_a_scene_and_reports_transforms() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("scene.kscene");
+    let mut source = GameWorld::new();
+    source.spawn_entity(&Transform::from_translation(Vec3::new(1.0, 2.0, 3.0)));
+    let scene = SerializationService::new()
+        .save_world(source.inner_world(), SerializationGoal::EditorInterchange)
+        .unwrap();
+    std::fs::write(&path, scene.to_bytes()).unwrap();
+    let path = CString::new(path.to_str().unwrap()).unwrap();
+
+    unsafe {
+        let engine = khora_engine_create();
+        assert!(!engine.is_null());
+
+        assert_eq!(
+            khora_engine_load_scene(engine, path.as_ptr()),
+            KhoraResult::Ok
+        );
+        let key = CString::new("KeyW").unwrap();
+        assert_eq!(
+            khora_engine_push_key(engine, key.as_ptr(), true),
+            KhoraResult::Ok
+        );
+        assert_eq!(khora_engine_step(engine), KhoraResult::Ok);
+        assert_eq!(khora_engine_frame(engine), 1);
+
+        let count = khora_engine_entities(engine, ptr::null_mut(), 0);
+        let mut entities = vec![
+            KhoraEntity {
+                index: 0,
+                generation: 0
+            };
+            count
+        ];
+        assert_eq!(
+            khora_engine_entities(engine, entities.as_mut_ptr(), count),
+            count
+        );
+
+        let found = entities.iter().any(|&entity| {
+            let mut transform = KhoraTransform::default();
+            khora_entity_get_transform(engine, entity, &mut transform) == KhoraResult::Ok
+                && transform.translation == [1.0, 2.0, 3.0]
+        });
+        assert!(found, "the scene entity was not found");
+
+        khora_engine_destroy(engine);
+    }
+}
+
+#[test]
+fn failures_are_reported_without_panicking() {
+    unsafe {
+        assert_eq!(khora_engine_step(ptr::null_mut()), KhoraResult::NullPointer);
+
+        let engine = khora_engine_create();
+        let missing = CString::new("/nonexistent/scene.kscene").unwrap();
+        assert_eq!(
+            khora_engine_load_scene(engine, missing.as_ptr()),
+            KhoraResult::Io
+        );
+        assert!(!CStr::from_ptr(khora_last_error()).to_bytes().is_empty());
+
+        let dead = KhoraEntity {
+            index: 9999,
+            generation: 0,
+        };
+        let mut matrix = [0.0f32; 16];
+        assert_eq!(
+            khora_entity_get_world_matrix(engine, dead, matrix.as_mut_ptr()),
+            KhoraResult::NotFound
+        );
+
+        khora_engine_destroy(engine);
+    }
+}
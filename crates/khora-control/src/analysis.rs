@@ -23,6 +23,7 @@ use crate::context::Context;
 use crate::metrics::MetricStore;
 use khora_core::platform::{BatteryLevel, ThermalStatus};
 use khora_core::telemetry::MetricId;
+use serde::{Deserialize, Serialize};
 
 /// Threshold (ms) above which frame time is considered problematic.
 const FRAME_TIME_WARN_THRESHOLD_MS: f32 = 18.0;
@@ -39,8 +40,11 @@ const GPU_LOAD_CRITICAL: f32 = 0.95;
 /// GPU load threshold for a warning-level response.
 const GPU_LOAD_WARN: f32 = 0.90;
 
+/// Target latency (ms) reported while the engine is in the background mode.
+const BACKGROUND_LATENCY_MS: f32 = 250.0;
+
 /// Analysis results and alerts produced by the `HeuristicEngine`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisReport {
     /// `true` if a resource conflict or performance drop is detected and GORNA
     /// should run a full negotiation round.
@@ -74,7 +78,8 @@ impl HeuristicEngine {
     /// Evaluates the full set of heuristics:
     /// 1. **Phase heuristics**: Adjust target FPS for the current execution phase.
     /// 2. **Thermal analysis**: Detect throttling / critical and reduce budgets.
-    /// 3. **Battery analysis**: Conserve power on low/critical battery.
+    /// 3. **Battery analysis**: Conserve power on low/critical battery and
+    ///    hold the target to the power governor's FPS cap.
     /// 4. **Frame time analysis**: Detect sustained performance drops.
     /// 5. **Stutter analysis**: Detect high frame time variance.
     /// 6. **Trend analysis**: Preempt worsening performance via slope detection.
@@ -86,6 +91,13 @@ impl HeuristicEngine {
         // ── 1. Target latency (60 FPS baseline) ──────────────────────────
         report.suggested_latency_ms = 16.66;
 
+        // A suspended engine renders nothing, so frame metrics are stale and
+        // the hardware heuristics have nothing left to cut.
+        if context.mode.is_background() {
+            report.suggested_latency_ms = BACKGROUND_LATENCY_MS;
+            return report;
+        }
+
         // ── 2. Thermal Analysis ──────────────────────────────────────────
         match context.hardware.thermal {
             ThermalStatus::Critical => {
@@ -133,6 +145,17 @@ impl HeuristicEngine {
             }
             BatteryLevel::High | BatteryLevel::Mains => {}
         }
+        if let Some(interval) = context.power_policy.frame_interval() {
+            let cap_ms = interval.as_secs_f32() * 1000.0;
+            if cap_ms > report.suggested_latency_ms {
+                report.suggested_latency_ms = cap_ms;
+                report.alerts.push(format!(
+                    "Power: {} — capping to {} FPS.",
+                    context.power.name(),
+                    context.power_policy.fps_cap.unwrap_or_default()
+                ));
+            }
+        }
 
         // ── 4. Frame Time Analysis ───────────────────────────────────────
         let frame_time_id = MetricId::new("renderer", "frame_time");
@@ -292,6 +315,25 @@ mod tests {
         assert!((report.suggested_latency_ms - 16.66).abs() < 0.1);
     }
 
+    #[test]
+    fn test_background_mode_skips_frame_analysis() {
+        let engine = HeuristicEngine;
+        let ctx = Context {
+            mode: EngineMode::background(),
+            ..Default::default()
+        };
+        let mut store = MetricStore::new();
+        let id = MetricId::new("renderer", "frame_time");
+        for _ in 0..20 {
+            store.push(id.clone(), 40.0);
+        }
+
+        let report = engine.analyze(&ctx, &store);
+        assert!(!report.needs_negotiation);
+        assert!(report.alerts.is_empty());
+        assert!((report.suggested_latency_ms - BACKGROUND_LATENCY_MS).abs() < 0.1);
+    }
+
     // ── Thermal Heuristics ───────────────────────────────────────────
 
     #[test]
@@ -344,6 +386,19 @@ mod tests {
         assert!(report.suggested_latency_ms >= 50.0);
     }
 
+    #[test]
+    fn test_power_policy_fps_cap_sets_target() {
+        let engine = HeuristicEngine;
+        let mut ctx = simulation_context();
+        ctx.hardware.battery = BatteryLevel::High;
+        ctx.power = crate::PowerState::Battery;
+        ctx.power_policy.fps_cap = Some(40);
+        let store = MetricStore::new();
+
+        let report = engine.analyze(&ctx, &store);
+        assert!((report.suggested_latency_ms - 25.0).abs() < 0.01);
+    }
+
     // ── Frame Time Heuristics ────────────────────────────────────────
 
     #[test]
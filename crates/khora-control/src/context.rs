@@ -15,10 +15,12 @@
 //! Context for the Dynamic Context Core.
 
 pub use khora_core::agent::EngineMode;
-pub use khora_core::platform::{BatteryLevel, ThermalStatus};
+pub use khora_core::platform::{BatteryLevel, PowerPolicy, PowerState, ThermalStatus};
+
+use serde::{Deserialize, Serialize};
 
 /// Hardware context observed by the DCC.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HardwareState {
     /// Current thermal status.
     pub thermal: ThermalStatus,
@@ -35,7 +37,7 @@ pub struct HardwareState {
 }
 
 /// The complete context model used for strategic decision making.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Context {
     /// Observed hardware state.
     pub hardware: HardwareState,
@@ -54,6 +56,13 @@ pub struct Context {
     /// | Throttling | 0.6 |
     /// | Critical thermal or battery | 0.4 |
     pub global_budget_multiplier: f32,
+    /// State chosen by the [`PowerGovernor`](crate::power::PowerGovernor).
+    #[serde(default)]
+    pub power: PowerState,
+    /// Policy of [`power`](Self::power): FPS cap, strategy ceiling and
+    /// background work scale.
+    #[serde(default)]
+    pub power_policy: PowerPolicy,
 }
 
 impl Default for Context {
@@ -62,6 +71,8 @@ impl Default for Context {
             hardware: HardwareState::default(),
             mode: EngineMode::Playing,
             global_budget_multiplier: 1.0,
+            power: PowerState::Unrestricted,
+            power_policy: PowerPolicy::UNRESTRICTED,
         }
     }
 }
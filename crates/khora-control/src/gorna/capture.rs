@@ -0,0 +1,84 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recorded arbitration rounds, for replaying GORNA offline.
+//!
+//! When [`DccConfig::capture_path`](crate::DccConfig::capture_path) is set,
+//! the DCC appends every arbitration round to that file as one JSON object
+//! per line: the context and analysis it started from, and every agent's
+//! negotiated strategies. [`replay`](super::replay) re-runs the fitting on
+//! those rounds without any agent.
+
+use std::io::{self, BufRead, Write};
+
+use khora_core::control::gorna::{AgentId, StrategyOption};
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::AnalysisReport;
+use crate::context::Context;
+
+/// The strategies one agent offered during a recorded round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedNegotiation {
+    /// The agent that negotiated.
+    pub agent_id: AgentId,
+    /// The priority the arbitrator gave it. Edit it in a capture to try out
+    /// a different weighting.
+    pub priority: f32,
+    /// Its strategies, cheapest first.
+    pub strategies: Vec<StrategyOption>,
+}
+
+/// Everything an arbitration round decided from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GornaRound {
+    /// The DCC context at the start of the round.
+    pub context: Context,
+    /// The analysis report the round was triggered with.
+    pub report: AnalysisReport,
+    /// Every agent asked to take part, answered or not.
+    pub agents: Vec<AgentId>,
+    /// Number of agents that reported themselves stalled.
+    pub stalled_agents: usize,
+    /// The negotiations collected from agents that answered in time.
+    pub negotiations: Vec<RecordedNegotiation>,
+}
+
+impl GornaRound {
+    /// Appends this round to a capture as one line of JSON.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        serde_json::to_writer(&mut *writer, self)?;
+        writer.write_all(b"\n")
+    }
+}
+
+/// Reads every round of a capture, in recording order. Blank lines are
+/// skipped; a malformed line fails the whole read with its line number.
+pub fn read_capture(reader: impl BufRead) -> io::Result<Vec<GornaRound>> {
+    let mut rounds = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let round = serde_json::from_str(&line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", index + 1, e),
+            )
+        })?;
+        rounds.push(round);
+    }
+    Ok(rounds)
+}
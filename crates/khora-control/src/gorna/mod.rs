@@ -20,43 +20,37 @@
 //! 1. Polling agent health via `report_status()`.
 //! 2. Sending `NegotiationRequest` to each agent and collecting strategy options.
 //! 3. Running a global budget-fitting solver that respects total frame time.
-//! 4. Applying thermal/battery multipliers from the `AnalysisReport`.
+//! 4. Applying thermal/battery multipliers from the `AnalysisReport`, and
+//!    the power governor's strategy ceiling from the `Context`.
 //! 5. Detecting and handling "death spiral" conditions.
 //! 6. Issuing `ResourceBudget` to each agent.
+//!
+//! The arbitrator never touches an agent directly: every exchange goes
+//! through the agent's [`AgentMailbox`] with a reply deadline. An agent that
+//! misses the deadline is reported in the [`ArbitrationOutcome`] instead of
+//! being waited on.
+
+mod capture;
+mod outcome;
+mod replay;
+mod solver;
+
+pub use capture::{read_capture, GornaRound, RecordedNegotiation};
+pub use outcome::ArbitrationOutcome;
+pub use replay::replay;
+pub use solver::GornaSolver;
 
 use crate::analysis::AnalysisReport;
 use crate::context::Context;
-use khora_core::agent::Agent;
+use crate::mailbox::{AgentCommand, AgentMailbox, AgentReply};
 use khora_core::control::gorna::{
     AgentId, NegotiationRequest, ResourceBudget, ResourceConstraints, StrategyId, StrategyOption,
 };
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 const MAX_STALLED_AGENTS: usize = 2;
 
-fn try_lock_agent_with_timeout<T: ?Sized>(
-    mutex: &Mutex<T>,
-    timeout: Duration,
-) -> Option<std::sync::MutexGuard<'_, T>> {
-    let start = Instant::now();
-    loop {
-        match mutex.try_lock() {
-            Ok(guard) => return Some(guard),
-            Err(std::sync::TryLockError::WouldBlock) => {
-                if start.elapsed() >= timeout {
-                    return None;
-                }
-                std::thread::yield_now();
-            }
-            Err(std::sync::TryLockError::Poisoned(err)) => {
-                log::error!("Agent mutex poisoned: {}", err);
-                return None;
-            }
-        }
-    }
-}
-
 /// Arbitrates resource allocation between multiple ISAs.
 ///
 /// The arbitrator implements a two-pass approach:
@@ -64,46 +58,53 @@ fn try_lock_agent_with_timeout<T: ?Sized>(
 /// - **Pass 2 (Fitting)**: Selects the optimal strategy combination that fits
 ///   within the global frame budget, respecting priorities and VRAM constraints.
 pub struct GornaArbitrator {
-    lock_timeout: Duration,
-}
-
-/// A collected negotiation from a single agent, used during the fitting pass.
-struct AgentNegotiation {
-    agent_index: usize,
-    agent_id: AgentId,
-    priority: f32,
-    strategies: Vec<StrategyOption>,
-}
-
-/// A resolved allocation for a single agent.
-struct AgentAllocation {
-    agent_index: usize,
-    strategy: StrategyOption,
+    reply_timeout: Duration,
 }
 
 impl GornaArbitrator {
-    /// Creates a new arbitrator with the specified lock timeout.
+    /// Creates a new arbitrator with the specified reply timeout.
     ///
-    /// The lock timeout determines how long to wait when acquiring locks on agents
-    /// during negotiation and budget issuance. Agents that cannot be locked within
-    /// this timeout are skipped.
-    pub fn new(lock_timeout: Duration) -> Self {
-        Self { lock_timeout }
+    /// The timeout bounds how long one arbitration round waits for agents to
+    /// answer their status and negotiation requests. Agents that have not
+    /// answered by then are listed as unresponsive in the outcome.
+    pub fn new(reply_timeout: Duration) -> Self {
+        Self { reply_timeout }
     }
+
     /// Performs a full GORNA arbitration round.
     ///
     /// # Arguments
     /// - `context`: The current DCC situational model (phase, hardware, multiplier).
     /// - `report`: The analysis report from the `HeuristicEngine`.
-    /// - `agents`: The registered ISA agents.
+    /// - `agents`: The mailboxes of the registered ISA agents.
     pub fn arbitrate(
         &self,
         context: &Context,
         report: &AnalysisReport,
-        agents: &mut [Arc<Mutex<dyn Agent>>],
-    ) {
+        agents: &[AgentMailbox],
+    ) -> ArbitrationOutcome {
+        let mut outcome = ArbitrationOutcome::default();
         if agents.is_empty() {
-            return;
+            return outcome;
+        }
+
+        // While suspended nothing is rendered, so there is nothing to fit:
+        // every agent is parked at its cheapest strategy without a round trip.
+        if context.mode.is_background() {
+            log::info!(
+                "GORNA: Engine in background — parking {} agents at LowPower.",
+                agents.len()
+            );
+            outcome.background = true;
+            self.issue_low_power(agents, &mut outcome);
+            outcome.round = Some(GornaRound {
+                context: context.clone(),
+                report: report.clone(),
+                agents: agents.iter().map(AgentMailbox::agent_id).collect(),
+                stalled_agents: 0,
+                negotiations: Vec::new(),
+            });
+            return outcome;
         }
 
         log::debug!(
@@ -113,18 +114,6 @@ impl GornaArbitrator {
             context.global_budget_multiplier
         );
 
-        // ── 0. Health Check ──────────────────────────────────────────────
-        let stalled_count = self.check_agent_health(agents);
-        if stalled_count >= MAX_STALLED_AGENTS || report.death_spiral_detected {
-            log::error!(
-                "GORNA: Death spiral detected ({} stalled agents). \
-                Forcing emergency LowPower on all agents.",
-                stalled_count
-            );
-            self.emergency_stop(agents);
-            return;
-        }
-
         // ── 1. Compute effective frame budget ────────────────────────────
         // Start from the analysis-suggested latency (accounts for phase, thermal, battery).
         let base_latency_ms = report.suggested_latency_ms;
@@ -138,34 +127,86 @@ impl GornaArbitrator {
             context.global_budget_multiplier
         );
 
-        // ── 2. Negotiation Pass ──────────────────────────────────────────
-        let mut negotiations: Vec<AgentNegotiation> = Vec::with_capacity(agents.len());
+        // ── 2. Fan out status + negotiation requests ─────────────────────
+        // Both requests go out before waiting on any answer, so a single
+        // servicing pass on the agent's side answers both, and one slow agent
+        // never delays the others' requests.
+        let deadline = Instant::now() + self.reply_timeout;
+        let pending: Vec<_> = agents
+            .iter()
+            .map(|mailbox| {
+                let agent_id = mailbox.agent_id();
+                let request = NegotiationRequest {
+                    target_latency: Duration::from_secs_f64(effective_budget_ms as f64 / 1000.0),
+                    priority_weight: self.get_agent_priority(agent_id),
+                    constraints: ResourceConstraints {
+                        must_run: self.is_critical_agent(agent_id),
+                        ..Default::default()
+                    },
+                    current_mode: context.mode.clone(),
+                    agent_timing: Default::default(),
+                };
+                (
+                    mailbox.request(AgentCommand::ReportStatus, deadline),
+                    mailbox.request(AgentCommand::Negotiate(request), deadline),
+                )
+            })
+            .collect();
 
-        for (i, agent_mutex) in agents.iter().enumerate() {
-            let Some(mut agent) = try_lock_agent_with_timeout(agent_mutex, self.lock_timeout)
-            else {
-                log::warn!(
-                    "GORNA: Failed to lock agent {} for negotiation (timeout). Skipping.",
-                    i
-                );
-                continue;
-            };
-            let agent_id = agent.id();
-            let priority = self.get_agent_priority(agent_id);
-            let timing = agent.execution_timing();
-
-            let request = NegotiationRequest {
-                target_latency: Duration::from_secs_f64(effective_budget_ms as f64 / 1000.0),
-                priority_weight: priority,
-                constraints: ResourceConstraints {
-                    must_run: self.is_critical_agent(agent_id),
-                    ..Default::default()
-                },
-                current_mode: context.mode.clone(),
-                agent_timing: timing,
-            };
+        // ── 3. Health check + negotiation collection ─────────────────────
+        let mut stalled_count = 0;
+        let mut negotiations: Vec<RecordedNegotiation> = Vec::with_capacity(agents.len());
+        // Index into `agents` of each entry of `negotiations`.
+        let mut agent_indices: Vec<usize> = Vec::with_capacity(agents.len());
+
+        for (i, (status, negotiation)) in pending.into_iter().enumerate() {
+            let agent_id = agents[i].agent_id();
+
+            match status.wait() {
+                Ok(AgentReply::Status(status)) => {
+                    if status.is_stalled {
+                        log::warn!(
+                            "GORNA: Agent {:?} is STALLED. Health={:.2}, Message: {}",
+                            status.agent_id,
+                            status.health_score,
+                            status.message
+                        );
+                        stalled_count += 1;
+                    } else if status.health_score < 0.5 {
+                        log::warn!(
+                            "GORNA: Agent {:?} health degraded ({:.2}). Message: {}",
+                            status.agent_id,
+                            status.health_score,
+                            status.message
+                        );
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!(
+                        "GORNA: Agent {:?} did not answer its status request ({:?}); {} missed so far.",
+                        agent_id,
+                        e,
+                        agents[i].stats().missed_deadlines
+                    );
+                    outcome.unresponsive.push(agent_id);
+                    continue;
+                }
+            }
 
-            let response = agent.negotiate(request);
+            let response = match negotiation.wait() {
+                Ok(AgentReply::Negotiation(response)) => response,
+                Ok(_) => continue,
+                Err(e) => {
+                    log::warn!(
+                        "GORNA: Agent {:?} did not answer its negotiation ({:?}).",
+                        agent_id,
+                        e
+                    );
+                    outcome.unresponsive.push(agent_id);
+                    continue;
+                }
+            };
 
             if response.strategies.is_empty() {
                 log::warn!(
@@ -179,228 +220,95 @@ impl GornaArbitrator {
             let mut strategies = response.strategies;
             strategies.sort_by_key(|s| s.estimated_time);
 
-            negotiations.push(AgentNegotiation {
-                agent_index: i,
+            // On battery, strategies above the governor's ceiling are out of
+            // reach; the cheapest one always stays.
+            let policy = context.power_policy;
+            let cheapest = strategies[0].clone();
+            strategies.retain(|s| policy.allows(s.id));
+            if strategies.is_empty() {
+                strategies.push(cheapest);
+            }
+
+            negotiations.push(RecordedNegotiation {
                 agent_id,
-                priority,
+                priority: self.get_agent_priority(agent_id),
                 strategies,
             });
+            agent_indices.push(i);
+        }
+        outcome.negotiated = negotiations.len();
+        let round = GornaRound {
+            context: context.clone(),
+            report: report.clone(),
+            agents: agents.iter().map(AgentMailbox::agent_id).collect(),
+            stalled_agents: stalled_count,
+            negotiations,
+        };
+
+        if stalled_count >= MAX_STALLED_AGENTS || report.death_spiral_detected {
+            log::error!(
+                "GORNA: Death spiral detected ({} stalled agents). \
+                Forcing emergency LowPower on all agents.",
+                stalled_count
+            );
+            self.emergency_stop(agents, &mut outcome);
+            outcome.round = Some(round);
+            return outcome;
         }
 
-        // ── 3. Global Budget Fitting ─────────────────────────────────────
+        // ── 4. Global Budget Fitting ─────────────────────────────────────
         let max_vram = context
             .hardware
             .available_vram
             .or(context.hardware.total_vram);
-        let allocations = self.fit_budgets(&negotiations, effective_budget_ms, max_vram);
-
-        // ── 4. Issuance Pass ─────────────────────────────────────────────
-        for alloc in &allocations {
-            let Some(mut agent) =
-                try_lock_agent_with_timeout(&agents[alloc.agent_index], self.lock_timeout)
-            else {
-                log::warn!(
-                    "GORNA: Failed to lock agent for budget issuance (index {}). Skipping.",
-                    alloc.agent_index
-                );
-                continue;
-            };
+        let allocations =
+            GornaSolver::Greedy.solve(&round.negotiations, effective_budget_ms, max_vram);
 
-            let budget = ResourceBudget {
-                strategy_id: alloc.strategy.id,
-                time_limit: alloc.strategy.estimated_time,
-                memory_limit: Some(alloc.strategy.estimated_vram),
-                extra_params: std::collections::HashMap::new(),
-            };
+        // ── 5. Issuance Pass ─────────────────────────────────────────────
+        for (strategy, &agent_index) in allocations.iter().zip(&agent_indices) {
+            let mailbox = &agents[agent_index];
+            let budget = strategy_budget(strategy);
 
             log::info!(
                 "GORNA: Issuing budget to {:?} — strategy={:?}, time={:.2}ms, vram={}KB",
-                agent.id(),
+                mailbox.agent_id(),
                 budget.strategy_id,
                 budget.time_limit.as_secs_f64() * 1000.0,
-                alloc.strategy.estimated_vram / 1024
+                strategy.estimated_vram / 1024
             );
 
-            agent.apply_budget(budget);
+            mailbox.post(AgentCommand::ApplyBudget(budget.clone()));
+            outcome.budgets.push((mailbox.agent_id(), budget));
         }
 
         log::debug!(
-            "GORNA: Arbitration complete. {} budgets issued.",
-            allocations.len()
+            "GORNA: Arbitration complete. {} budgets issued, {} agents unresponsive.",
+            outcome.budgets.len(),
+            outcome.unresponsive.len()
         );
-    }
-
-    /// Polls all agents for health status and returns the count of stalled agents.
-    fn check_agent_health(&self, agents: &[Arc<Mutex<dyn Agent>>]) -> usize {
-        let mut stalled = 0;
-        for (i, agent_mutex) in agents.iter().enumerate() {
-            let Some(agent) = try_lock_agent_with_timeout(agent_mutex, self.lock_timeout) else {
-                log::warn!(
-                    "GORNA: Failed to lock agent {} for health check (timeout).",
-                    i
-                );
-                continue;
-            };
-            let status = agent.report_status();
-            if status.is_stalled {
-                log::warn!(
-                    "GORNA: Agent {:?} is STALLED. Health={:.2}, Message: {}",
-                    status.agent_id,
-                    status.health_score,
-                    status.message
-                );
-                stalled += 1;
-            } else if status.health_score < 0.5 {
-                log::warn!(
-                    "GORNA: Agent {:?} health degraded ({:.2}). Message: {}",
-                    status.agent_id,
-                    status.health_score,
-                    status.message
-                );
-            }
-        }
-        stalled
+        outcome.round = Some(round);
+        outcome
     }
 
     /// Forces all agents to their lowest-cost strategy as an emergency measure.
-    fn emergency_stop(&self, agents: &mut [Arc<Mutex<dyn Agent>>]) {
-        for (i, agent_mutex) in agents.iter_mut().enumerate() {
-            let Some(mut agent) = try_lock_agent_with_timeout(agent_mutex, self.lock_timeout)
-            else {
-                log::warn!(
-                    "GORNA: Failed to lock agent {} for emergency stop (timeout).",
-                    i
-                );
-                continue;
-            };
-
-            let budget = ResourceBudget {
-                strategy_id: StrategyId::LowPower,
-                time_limit: Duration::from_millis(2),
-                memory_limit: None,
-                extra_params: std::collections::HashMap::new(),
-            };
-
-            log::warn!("GORNA: Emergency LowPower issued to {:?}.", agent.id());
-            agent.apply_budget(budget);
-        }
-    }
-
-    /// Runs the global budget fitting algorithm.
-    ///
-    /// Strategy: Priority-weighted greedy allocation.
-    /// 1. Sort agents by priority (highest first).
-    /// 2. Try to give each agent its most expensive strategy that fits.
-    /// 3. If the total exceeds the budget, downgrade lower-priority agents first.
-    /// 4. Respect VRAM constraints if specified.
-    fn fit_budgets(
-        &self,
-        negotiations: &[AgentNegotiation],
-        total_budget_ms: f32,
-        max_vram_bytes: Option<u64>,
-    ) -> Vec<AgentAllocation> {
-        if negotiations.is_empty() {
-            return Vec::new();
-        }
-
-        let mut sorted_indices: Vec<usize> = (0..negotiations.len()).collect();
-        sorted_indices.sort_by(|&a, &b| {
-            negotiations[b]
-                .priority
-                .partial_cmp(&negotiations[a].priority)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        let mut allocations: Vec<AgentAllocation> = negotiations
-            .iter()
-            .map(|n| AgentAllocation {
-                agent_index: n.agent_index,
-                strategy: n.strategies[0].clone(),
-            })
-            .collect();
-
-        let total_min_ms: f32 = allocations
-            .iter()
-            .map(|a| a.strategy.estimated_time.as_secs_f32() * 1000.0)
-            .sum();
-
-        let total_min_vram: u64 = allocations.iter().map(|a| a.strategy.estimated_vram).sum();
-
-        if total_min_ms > total_budget_ms {
+    fn emergency_stop(&self, agents: &[AgentMailbox], outcome: &mut ArbitrationOutcome) {
+        outcome.emergency = true;
+        for mailbox in agents {
             log::warn!(
-                "GORNA: Even minimum strategies ({:.2}ms) exceed budget ({:.2}ms). \
-                All agents at LowPower.",
-                total_min_ms,
-                total_budget_ms
+                "GORNA: Emergency LowPower issued to {:?}.",
+                mailbox.agent_id()
             );
-            return allocations;
-        }
-
-        if let Some(max_vram) = max_vram_bytes {
-            if total_min_vram > max_vram {
-                log::warn!(
-                    "GORNA: Even minimum strategies VRAM ({:.2}MB) exceeds budget ({:.2}MB).",
-                    total_min_vram as f64 / (1024.0 * 1024.0),
-                    max_vram as f64 / (1024.0 * 1024.0)
-                );
-            }
-        }
-
-        let mut remaining_ms = total_budget_ms - total_min_ms;
-        let mut current_vram = total_min_vram;
-
-        for &idx in &sorted_indices {
-            let negotiation = &negotiations[idx];
-            let current_cost_ms = allocations[idx].strategy.estimated_time.as_secs_f32() * 1000.0;
-            let current_vram_cost = allocations[idx].strategy.estimated_vram;
-
-            let mut best_upgrade: Option<&StrategyOption> = None;
-            for strategy in negotiation.strategies.iter().rev() {
-                let cost_ms = strategy.estimated_time.as_secs_f32() * 1000.0;
-                let delta_ms = cost_ms - current_cost_ms;
-                let delta_vram = strategy.estimated_vram.saturating_sub(current_vram_cost);
-
-                let time_fits = delta_ms <= remaining_ms;
-                let vram_fits = max_vram_bytes
-                    .map(|max| current_vram + delta_vram <= max)
-                    .unwrap_or(true);
-
-                if time_fits && vram_fits {
-                    best_upgrade = Some(strategy);
-                    break;
-                }
-            }
-
-            if let Some(upgrade) = best_upgrade {
-                let old_cost = current_cost_ms;
-                let new_cost = upgrade.estimated_time.as_secs_f32() * 1000.0;
-                let delta_vram = upgrade.estimated_vram.saturating_sub(current_vram_cost);
-
-                remaining_ms -= new_cost - old_cost;
-                current_vram += delta_vram;
-                allocations[idx].strategy = upgrade.clone();
-
-                log::trace!(
-                    "GORNA: Upgraded {:?} from {:.2}ms to {:.2}ms (remaining={:.2}ms, vram={:.2}MB)",
-                    negotiation.agent_id,
-                    old_cost,
-                    new_cost,
-                    remaining_ms,
-                    current_vram as f64 / (1024.0 * 1024.0)
-                );
-            }
         }
+        self.issue_low_power(agents, outcome);
+    }
 
-        if let Some(max_vram) = max_vram_bytes {
-            let total_vram: u64 = allocations.iter().map(|a| a.strategy.estimated_vram).sum();
-            log::debug!(
-                "GORNA: Total VRAM allocated: {:.2}MB / {:.2}MB",
-                total_vram as f64 / (1024.0 * 1024.0),
-                max_vram as f64 / (1024.0 * 1024.0)
-            );
+    /// Posts a LowPower budget to every agent and records it in `outcome`.
+    fn issue_low_power(&self, agents: &[AgentMailbox], outcome: &mut ArbitrationOutcome) {
+        for mailbox in agents {
+            let budget = low_power_budget();
+            mailbox.post(AgentCommand::ApplyBudget(budget.clone()));
+            outcome.budgets.push((mailbox.agent_id(), budget));
         }
-
-        allocations
     }
 
     /// Returns the priority weight for an agent.
@@ -412,6 +320,8 @@ impl GornaArbitrator {
             AgentId::Renderer => 1.0,
             AgentId::ShadowRenderer => 1.0,
             AgentId::Physics => 1.0,
+            AgentId::Animation => 0.75,
+            AgentId::InverseKinematics => 0.65,
             AgentId::Ecs => 0.8,
             AgentId::Ui => 0.7,
             AgentId::Audio => 0.6,
@@ -429,11 +339,32 @@ impl GornaArbitrator {
     }
 }
 
+/// The budget issued for a fitted strategy.
+fn strategy_budget(strategy: &StrategyOption) -> ResourceBudget {
+    ResourceBudget {
+        strategy_id: strategy.id,
+        time_limit: strategy.estimated_time,
+        memory_limit: Some(strategy.estimated_vram),
+        extra_params: HashMap::new(),
+    }
+}
+
+/// The budget issued in the background mode and on emergency stops.
+fn low_power_budget() -> ResourceBudget {
+    ResourceBudget {
+        strategy_id: StrategyId::LowPower,
+        time_limit: Duration::from_millis(2),
+        memory_limit: None,
+        extra_params: HashMap::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::analysis::AnalysisReport;
     use crate::context::Context;
+    use crate::mailbox;
     use crate::EngineMode;
     use khora_core::agent::Agent;
     use khora_core::control::gorna::{
@@ -441,6 +372,9 @@ mod tests {
         StrategyOption,
     };
     use khora_core::EngineContext;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread::JoinHandle;
 
     // ── Mock Agent ───────────────────────────────────────────────────
 
@@ -528,6 +462,49 @@ mod tests {
         }
     }
 
+    // ── Harness: each agent serviced by its own owner thread ─────────
+
+    struct Harness {
+        mailboxes: Vec<AgentMailbox>,
+        stop: Arc<AtomicBool>,
+        owners: Vec<JoinHandle<MockAgent>>,
+    }
+
+    impl Harness {
+        fn serve(agents: Vec<MockAgent>) -> Self {
+            let stop = Arc::new(AtomicBool::new(false));
+            let mut mailboxes = Vec::new();
+            let mut owners = Vec::new();
+            for mut agent in agents {
+                let (mailbox, inbox) = mailbox::channel(agent.id);
+                let stop = Arc::clone(&stop);
+                mailboxes.push(mailbox);
+                owners.push(std::thread::spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        inbox.service(&mut agent);
+                        std::thread::sleep(Duration::from_millis(1));
+                    }
+                    // Deliver anything posted just before the stop signal.
+                    inbox.service(&mut agent);
+                    agent
+                }));
+            }
+            Self {
+                mailboxes,
+                stop,
+                owners,
+            }
+        }
+
+        fn finish(self) -> Vec<MockAgent> {
+            self.stop.store(true, Ordering::Relaxed);
+            self.owners
+                .into_iter()
+                .map(|h| h.join().expect("owner thread panicked"))
+                .collect()
+        }
+    }
+
     fn normal_report() -> AnalysisReport {
         AnalysisReport {
             needs_negotiation: true,
@@ -548,22 +525,18 @@ mod tests {
     // ── Tests ────────────────────────────────────────────────────────
 
     fn create_arbitrator() -> GornaArbitrator {
-        GornaArbitrator::new(Duration::from_millis(100))
+        GornaArbitrator::new(Duration::from_millis(500))
     }
 
     #[test]
     fn test_arbitrate_single_agent_gets_best_strategy() {
         let arbitrator = create_arbitrator();
-        let ctx = simulation_ctx();
-        let report = normal_report();
-        let agent = MockAgent::new(AgentId::Renderer);
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> = vec![Arc::new(Mutex::new(agent))];
+        let harness = Harness::serve(vec![MockAgent::new(AgentId::Renderer)]);
 
-        arbitrator.arbitrate(&ctx, &report, &mut agents);
+        arbitrator.arbitrate(&simulation_ctx(), &normal_report(), &harness.mailboxes);
 
-        let lock = agents[0].lock().unwrap();
-        let mock = unsafe { &*((&*lock as *const dyn Agent) as *const MockAgent) };
-        let budget = mock
+        let agents = harness.finish();
+        let budget = agents[0]
             .applied_budget
             .as_ref()
             .expect("Budget should be applied");
@@ -574,43 +547,28 @@ mod tests {
     #[test]
     fn test_arbitrate_respects_global_budget() {
         let arbitrator = create_arbitrator();
-        let ctx = simulation_ctx();
-        let report = normal_report();
 
         // Two agents: Renderer (priority 1.0) and Physics (priority 1.0)
         // Total budget: 16.66ms
         // Each agent offers: LowPower(2ms), Balanced(8ms), HighPerformance(14ms)
         // Both can't be HighPerformance (14+14=28ms > 16.66ms)
         // With priority-based allocation, they should get strategies that fit.
-        let renderer = MockAgent::new(AgentId::Renderer);
-        let physics = MockAgent::new(AgentId::Physics);
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> = vec![
-            Arc::new(Mutex::new(renderer)),
-            Arc::new(Mutex::new(physics)),
-        ];
+        let harness = Harness::serve(vec![
+            MockAgent::new(AgentId::Renderer),
+            MockAgent::new(AgentId::Physics),
+        ]);
 
-        arbitrator.arbitrate(&ctx, &report, &mut agents);
+        let outcome = arbitrator.arbitrate(&simulation_ctx(), &normal_report(), &harness.mailboxes);
+        let agents = harness.finish();
 
         // Both should have received budgets
-        for agent_mutex in &agents {
-            let lock = agent_mutex.lock().unwrap();
-            let mock = unsafe { &*((&*lock as *const dyn Agent) as *const MockAgent) };
-            assert!(mock.applied_budget.is_some());
-        }
+        assert!(agents.iter().all(|a| a.applied_budget.is_some()));
+        assert_eq!(outcome.budgets.len(), 2);
 
         // Total cost should not exceed 16.66ms
         let total_cost_ms: f64 = agents
             .iter()
-            .map(|a| {
-                let lock = a.lock().unwrap();
-                let mock = unsafe { &*((&*lock as *const dyn Agent) as *const MockAgent) };
-                mock.applied_budget
-                    .as_ref()
-                    .unwrap()
-                    .time_limit
-                    .as_secs_f64()
-                    * 1000.0
-            })
+            .map(|a| a.applied_budget.as_ref().unwrap().time_limit.as_secs_f64() * 1000.0)
             .sum();
         assert!(
             total_cost_ms <= 16.66 + 0.1,
@@ -619,6 +577,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_power_policy_caps_strategies() {
+        let arbitrator = create_arbitrator();
+        let mut ctx = simulation_ctx();
+        ctx.power = crate::PowerState::Battery;
+        ctx.power_policy.max_strategy = StrategyId::Balanced;
+
+        let harness = Harness::serve(vec![MockAgent::new(AgentId::Renderer)]);
+        arbitrator.arbitrate(&ctx, &normal_report(), &harness.mailboxes);
+
+        let agents = harness.finish();
+        let budget = agents[0]
+            .applied_budget
+            .as_ref()
+            .expect("Budget should be applied");
+        // HighPerformance would fit the 16.66ms budget but is above the ceiling.
+        assert_eq!(budget.strategy_id, StrategyId::Balanced);
+    }
+
     #[test]
     fn test_arbitrate_thermal_reduces_budget() {
         let arbitrator = create_arbitrator();
@@ -629,14 +606,11 @@ mod tests {
         let mut report = normal_report();
         report.suggested_latency_ms = 33.33; // Heuristic suggestion for throttling
 
-        let agent = MockAgent::new(AgentId::Renderer);
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> = vec![Arc::new(Mutex::new(agent))];
+        let harness = Harness::serve(vec![MockAgent::new(AgentId::Renderer)]);
+        arbitrator.arbitrate(&ctx, &report, &harness.mailboxes);
 
-        arbitrator.arbitrate(&ctx, &report, &mut agents);
-
-        let lock = agents[0].lock().unwrap();
-        let mock = unsafe { &*((&*lock as *const dyn Agent) as *const MockAgent) };
-        let budget = mock
+        let agents = harness.finish();
+        let budget = agents[0]
             .applied_budget
             .as_ref()
             .expect("Budget should be applied");
@@ -647,24 +621,19 @@ mod tests {
     #[test]
     fn test_emergency_stop_on_death_spiral() {
         let arbitrator = create_arbitrator();
-        let ctx = simulation_ctx();
         let mut report = normal_report();
         report.death_spiral_detected = true;
 
-        let renderer = MockAgent::new(AgentId::Renderer);
-        let physics = MockAgent::new(AgentId::Physics);
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> = vec![
-            Arc::new(Mutex::new(renderer)),
-            Arc::new(Mutex::new(physics)),
-        ];
-
-        arbitrator.arbitrate(&ctx, &report, &mut agents);
+        let harness = Harness::serve(vec![
+            MockAgent::new(AgentId::Renderer),
+            MockAgent::new(AgentId::Physics),
+        ]);
+        let outcome = arbitrator.arbitrate(&simulation_ctx(), &report, &harness.mailboxes);
+        assert!(outcome.emergency);
 
         // Both agents should be forced to LowPower
-        for agent_mutex in &agents {
-            let lock = agent_mutex.lock().unwrap();
-            let mock = unsafe { &*((&*lock as *const dyn Agent) as *const MockAgent) };
-            let budget = mock
+        for agent in harness.finish() {
+            let budget = agent
                 .applied_budget
                 .as_ref()
                 .expect("Budget should be applied");
@@ -675,24 +644,17 @@ mod tests {
     #[test]
     fn test_emergency_stop_on_stalled_agents() {
         let arbitrator = create_arbitrator();
-        let ctx = simulation_ctx();
-        let report = normal_report();
 
         // Two stalled agents should trigger emergency stop
-        let stalled1 = MockAgent::stalled(AgentId::Renderer);
-        let stalled2 = MockAgent::stalled(AgentId::Physics);
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> = vec![
-            Arc::new(Mutex::new(stalled1)),
-            Arc::new(Mutex::new(stalled2)),
-        ];
-
-        arbitrator.arbitrate(&ctx, &report, &mut agents);
+        let harness = Harness::serve(vec![
+            MockAgent::stalled(AgentId::Renderer),
+            MockAgent::stalled(AgentId::Physics),
+        ]);
+        arbitrator.arbitrate(&simulation_ctx(), &normal_report(), &harness.mailboxes);
 
         // Both should be forced to LowPower
-        for agent_mutex in &agents {
-            let lock = agent_mutex.lock().unwrap();
-            let mock = unsafe { &*((&*lock as *const dyn Agent) as *const MockAgent) };
-            let budget = mock
+        for agent in harness.finish() {
+            let budget = agent
                 .applied_budget
                 .as_ref()
                 .expect("Budget should be applied");
@@ -700,43 +662,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_background_mode_parks_agents_at_low_power() {
+        let arbitrator = create_arbitrator();
+        let ctx = Context {
+            mode: EngineMode::background(),
+            ..simulation_ctx()
+        };
+
+        let harness = Harness::serve(vec![
+            MockAgent::new(AgentId::Renderer),
+            MockAgent::new(AgentId::Physics),
+        ]);
+        let outcome = arbitrator.arbitrate(&ctx, &normal_report(), &harness.mailboxes);
+        assert!(outcome.background);
+        assert!(!outcome.emergency);
+        assert_eq!(outcome.budgets.len(), 2);
+
+        for agent in harness.finish() {
+            let budget = agent
+                .applied_budget
+                .as_ref()
+                .expect("Budget should be applied");
+            assert_eq!(budget.strategy_id, StrategyId::LowPower);
+        }
+    }
+
+    #[test]
+    fn test_unresponsive_agent_is_reported_without_blocking_others() {
+        let arbitrator = GornaArbitrator::new(Duration::from_millis(50));
+        let harness = Harness::serve(vec![MockAgent::new(AgentId::Renderer)]);
+
+        // An agent whose owner never services its inbox.
+        let (silent, _silent_inbox) = mailbox::channel(AgentId::Audio);
+        let mut mailboxes = harness.mailboxes.clone();
+        mailboxes.push(silent.clone());
+
+        let start = Instant::now();
+        let outcome = arbitrator.arbitrate(&simulation_ctx(), &normal_report(), &mailboxes);
+        assert!(start.elapsed() < Duration::from_secs(2));
+
+        assert_eq!(outcome.unresponsive, vec![AgentId::Audio]);
+        assert_eq!(outcome.negotiated, 1);
+        assert_eq!(outcome.budgets.len(), 1);
+        assert_eq!(outcome.budgets[0].0, AgentId::Renderer);
+        assert!(silent.stats().missed_deadlines >= 1);
+
+        let agents = harness.finish();
+        assert!(agents[0].applied_budget.is_some());
+    }
+
     #[test]
     fn test_arbitrate_empty_agents() {
         let arbitrator = create_arbitrator();
-        let ctx = simulation_ctx();
-        let report = normal_report();
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> = vec![];
 
         // Should not panic
-        arbitrator.arbitrate(&ctx, &report, &mut agents);
+        let outcome = arbitrator.arbitrate(&simulation_ctx(), &normal_report(), &[]);
+        assert!(outcome.budgets.is_empty());
     }
 
     #[test]
     fn test_priority_order_renderer_before_asset_in_simulation() {
         let arbitrator = create_arbitrator();
-        let ctx = simulation_ctx();
-        let report = normal_report();
 
         // Tight budget: only 10ms total. Renderer (priority 1.0) should be
         // upgraded before Asset (priority 0.5).
-        let mut tight_report = report;
+        let mut tight_report = normal_report();
         tight_report.suggested_latency_ms = 10.0;
 
-        let renderer = MockAgent::new(AgentId::Renderer);
-        let asset = MockAgent::new(AgentId::Asset);
-        let mut agents: Vec<Arc<Mutex<dyn Agent>>> =
-            vec![Arc::new(Mutex::new(renderer)), Arc::new(Mutex::new(asset))];
-
-        arbitrator.arbitrate(&ctx, &tight_report, &mut agents);
+        let harness = Harness::serve(vec![
+            MockAgent::new(AgentId::Renderer),
+            MockAgent::new(AgentId::Asset),
+        ]);
+        arbitrator.arbitrate(&simulation_ctx(), &tight_report, &harness.mailboxes);
+        let agents = harness.finish();
 
         // With 10ms total: both minimum = 2+2=4ms, remaining=6ms.
         // Renderer (priority 1.0) should be upgraded first: +6ms → Balanced (8ms).
         // Asset (priority 0.5) stays at LowPower (2ms). Total: 8+2=10ms ≤ 10ms.
-        let renderer_lock = agents[0].lock().unwrap();
-        let renderer_mock =
-            unsafe { &*((&*renderer_lock as *const dyn Agent) as *const MockAgent) };
         assert_eq!(
-            renderer_mock.applied_budget.as_ref().unwrap().strategy_id,
+            agents[0].applied_budget.as_ref().unwrap().strategy_id,
             StrategyId::Balanced
         );
     }
@@ -0,0 +1,38 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The result of one arbitration round.
+
+use khora_core::control::gorna::{AgentId, ResourceBudget};
+
+use super::GornaRound;
+
+/// What a GORNA arbitration round decided, and who did not take part.
+#[derive(Debug, Clone, Default)]
+pub struct ArbitrationOutcome {
+    /// Budgets issued this round, in issuance order.
+    pub budgets: Vec<(AgentId, ResourceBudget)>,
+    /// Agents that missed their reply deadline and were left out.
+    pub unresponsive: Vec<AgentId>,
+    /// Number of agents whose negotiation was collected.
+    pub negotiated: usize,
+    /// `true` if the round ended in an emergency LowPower fallback.
+    pub emergency: bool,
+    /// `true` if every agent was parked at LowPower because the engine is
+    /// in the background mode.
+    pub background: bool,
+    /// What the round decided from, for captures. `None` when there were
+    /// no agents, and in outcomes produced by [`replay`](super::replay).
+    pub round: Option<GornaRound>,
+}
@@ -0,0 +1,192 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline re-runs of recorded arbitration rounds.
+
+use super::{
+    low_power_budget, strategy_budget, ArbitrationOutcome, GornaRound, GornaSolver,
+    MAX_STALLED_AGENTS,
+};
+
+/// Re-runs a recorded round with `solver`, without any agent, and returns
+/// the budgets it would have issued.
+///
+/// Follows the arbitrator's decisions: background and death-spiral rounds
+/// park every agent at LowPower whatever the solver, and agents that did
+/// not answer in the recording get nothing. Rounds can be edited before
+/// replay — a different priority, a tighter VRAM limit — to see how the
+/// allocations move.
+pub fn replay(round: &GornaRound, solver: GornaSolver) -> ArbitrationOutcome {
+    let mut outcome = ArbitrationOutcome {
+        negotiated: round.negotiations.len(),
+        ..Default::default()
+    };
+    outcome.unresponsive = round
+        .agents
+        .iter()
+        .copied()
+        .filter(|id| !round.negotiations.iter().any(|n| n.agent_id == *id))
+        .collect();
+
+    let context = &round.context;
+    if context.mode.is_background() {
+        outcome.background = true;
+        outcome.unresponsive.clear();
+        outcome.negotiated = 0;
+        outcome.budgets = round
+            .agents
+            .iter()
+            .map(|&id| (id, low_power_budget()))
+            .collect();
+        return outcome;
+    }
+    if round.stalled_agents >= MAX_STALLED_AGENTS || round.report.death_spiral_detected {
+        outcome.emergency = true;
+        outcome.budgets = round
+            .agents
+            .iter()
+            .map(|&id| (id, low_power_budget()))
+            .collect();
+        return outcome;
+    }
+
+    let effective_budget_ms = round.report.suggested_latency_ms * context.global_budget_multiplier;
+    let max_vram = context
+        .hardware
+        .available_vram
+        .or(context.hardware.total_vram);
+    let allocations = solver.solve(&round.negotiations, effective_budget_ms, max_vram);
+    outcome.budgets = round
+        .negotiations
+        .iter()
+        .zip(&allocations)
+        .map(|(negotiation, strategy)| (negotiation.agent_id, strategy_budget(strategy)))
+        .collect();
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisReport;
+    use crate::context::Context;
+    use crate::gorna::{read_capture, RecordedNegotiation};
+    use crate::EngineMode;
+    use khora_core::control::gorna::{AgentId, StrategyId, StrategyOption};
+    use std::time::Duration;
+
+    fn option(id: StrategyId, ms: u64) -> StrategyOption {
+        StrategyOption {
+            id,
+            estimated_time: Duration::from_millis(ms),
+            estimated_vram: 0,
+        }
+    }
+
+    fn negotiation(agent_id: AgentId, priority: f32, high_ms: u64) -> RecordedNegotiation {
+        RecordedNegotiation {
+            agent_id,
+            priority,
+            strategies: vec![
+                option(StrategyId::LowPower, 2),
+                option(StrategyId::Balanced, 6),
+                option(StrategyId::HighPerformance, high_ms),
+            ],
+        }
+    }
+
+    fn round(budget_ms: f32) -> GornaRound {
+        GornaRound {
+            context: Context::default(),
+            report: AnalysisReport {
+                suggested_latency_ms: budget_ms,
+                ..Default::default()
+            },
+            agents: vec![AgentId::Renderer, AgentId::Physics, AgentId::Audio],
+            stalled_agents: 0,
+            negotiations: vec![
+                negotiation(AgentId::Renderer, 1.0, 12),
+                negotiation(AgentId::Physics, 0.9, 8),
+            ],
+        }
+    }
+
+    fn strategies(outcome: &ArbitrationOutcome) -> Vec<(AgentId, StrategyId)> {
+        outcome
+            .budgets
+            .iter()
+            .map(|(id, budget)| (*id, budget.strategy_id))
+            .collect()
+    }
+
+    #[test]
+    fn solvers_can_disagree_on_the_same_round() {
+        // 14.5ms: greedy upgrades the renderer first (12ms) and leaves physics
+        // at 2ms; the exhaustive search finds Balanced + HighPerformance
+        // (6ms + 8ms) ranks higher overall.
+        let round = round(14.5);
+
+        let greedy = replay(&round, GornaSolver::Greedy);
+        assert_eq!(
+            strategies(&greedy),
+            vec![
+                (AgentId::Renderer, StrategyId::HighPerformance),
+                (AgentId::Physics, StrategyId::LowPower),
+            ]
+        );
+        assert_eq!(greedy.unresponsive, vec![AgentId::Audio]);
+
+        let exhaustive = replay(&round, GornaSolver::Exhaustive);
+        assert_eq!(
+            strategies(&exhaustive),
+            vec![
+                (AgentId::Renderer, StrategyId::Balanced),
+                (AgentId::Physics, StrategyId::HighPerformance),
+            ]
+        );
+    }
+
+    #[test]
+    fn background_and_death_spiral_rounds_park_everyone() {
+        let mut background = round(100.0);
+        background.context.mode = EngineMode::background();
+        let outcome = replay(&background, GornaSolver::Exhaustive);
+        assert!(outcome.background);
+        assert_eq!(outcome.budgets.len(), 3);
+
+        let mut spiral = round(100.0);
+        spiral.stalled_agents = MAX_STALLED_AGENTS;
+        let outcome = replay(&spiral, GornaSolver::Greedy);
+        assert!(outcome.emergency);
+        assert!(outcome
+            .budgets
+            .iter()
+            .all(|(_, b)| b.strategy_id == StrategyId::LowPower));
+    }
+
+    #[test]
+    fn captures_round_trip_through_json_lines() {
+        let mut bytes = Vec::new();
+        round(14.0).write_to(&mut bytes).unwrap();
+        bytes.extend_from_slice(b"\n");
+        round(30.0).write_to(&mut bytes).unwrap();
+
+        let rounds = read_capture(bytes.as_slice()).unwrap();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[1].report.suggested_latency_ms, 30.0);
+        assert_eq!(rounds[0].negotiations[1].agent_id, AgentId::Physics);
+
+        assert!(read_capture(&b"{not json}\n"[..]).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Budget-fitting solvers.
+
+use khora_core::control::gorna::StrategyOption;
+use serde::{Deserialize, Serialize};
+
+use super::capture::RecordedNegotiation;
+
+/// Above this many strategy combinations the exhaustive solver falls back
+/// to the greedy one.
+const MAX_EXHAUSTIVE_COMBINATIONS: usize = 1 << 16;
+
+/// How the arbitrator fits negotiated strategies into the frame budget.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GornaSolver {
+    /// Priority-weighted greedy allocation. The one the arbitrator runs.
+    ///
+    /// 1. Start every agent at its cheapest strategy.
+    /// 2. In priority order (highest first), upgrade each agent to its most
+    ///    expensive strategy that still fits the remaining time and VRAM.
+    Greedy,
+    /// Tries every combination and keeps the one with the highest
+    /// priority-weighted strategy rank that fits; ties go to the cheaper
+    /// combination. A reference for judging the greedy solver offline.
+    Exhaustive,
+}
+
+impl GornaSolver {
+    /// Every solver, in a stable order.
+    pub const ALL: [GornaSolver; 2] = [GornaSolver::Greedy, GornaSolver::Exhaustive];
+
+    /// Returns the solver's name, as accepted by [`from_name`](Self::from_name).
+    pub fn name(&self) -> &'static str {
+        match self {
+            GornaSolver::Greedy => "greedy",
+            GornaSolver::Exhaustive => "exhaustive",
+        }
+    }
+
+    /// Parses a solver name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|solver| solver.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Picks one strategy per negotiation, in negotiation order.
+    ///
+    /// Strategies must be sorted cheapest first. When even the cheapest
+    /// strategies exceed `total_budget_ms`, every agent gets its cheapest.
+    pub fn solve(
+        &self,
+        negotiations: &[RecordedNegotiation],
+        total_budget_ms: f32,
+        max_vram_bytes: Option<u64>,
+    ) -> Vec<StrategyOption> {
+        if negotiations.is_empty() {
+            return Vec::new();
+        }
+
+        let cheapest: Vec<StrategyOption> = negotiations
+            .iter()
+            .map(|n| n.strategies[0].clone())
+            .collect();
+        let total_min_ms: f32 = cheapest.iter().map(cost_ms).sum();
+        let total_min_vram: u64 = cheapest.iter().map(|s| s.estimated_vram).sum();
+
+        if total_min_ms > total_budget_ms {
+            log::warn!(
+                "GORNA: Even minimum strategies ({:.2}ms) exceed budget ({:.2}ms). \
+                All agents at LowPower.",
+                total_min_ms,
+                total_budget_ms
+            );
+            return cheapest;
+        }
+
+        if let Some(max_vram) = max_vram_bytes {
+            if total_min_vram > max_vram {
+                log::warn!(
+                    "GORNA: Even minimum strategies VRAM ({:.2}MB) exceeds budget ({:.2}MB).",
+                    total_min_vram as f64 / (1024.0 * 1024.0),
+                    max_vram as f64 / (1024.0 * 1024.0)
+                );
+            }
+        }
+
+        let allocations = match self {
+            GornaSolver::Greedy => greedy(negotiations, cheapest, total_budget_ms, max_vram_bytes),
+            GornaSolver::Exhaustive => exhaustive(negotiations, total_budget_ms, max_vram_bytes)
+                .unwrap_or_else(|| {
+                    log::warn!(
+                        "GORNA: Too many strategy combinations for the exhaustive solver; \
+                        using the greedy one."
+                    );
+                    greedy(negotiations, cheapest, total_budget_ms, max_vram_bytes)
+                }),
+        };
+
+        if let Some(max_vram) = max_vram_bytes {
+            let total_vram: u64 = allocations.iter().map(|s| s.estimated_vram).sum();
+            log::debug!(
+                "GORNA: Total VRAM allocated: {:.2}MB / {:.2}MB",
+                total_vram as f64 / (1024.0 * 1024.0),
+                max_vram as f64 / (1024.0 * 1024.0)
+            );
+        }
+
+        allocations
+    }
+}
+
+/// Estimated time of a strategy, in milliseconds.
+fn cost_ms(strategy: &StrategyOption) -> f32 {
+    strategy.estimated_time.as_secs_f32() * 1000.0
+}
+
+/// The [`GornaSolver::Greedy`] upgrade pass, starting from `allocations`.
+fn greedy(
+    negotiations: &[RecordedNegotiation],
+    mut allocations: Vec<StrategyOption>,
+    total_budget_ms: f32,
+    max_vram_bytes: Option<u64>,
+) -> Vec<StrategyOption> {
+    let mut sorted_indices: Vec<usize> = (0..negotiations.len()).collect();
+    sorted_indices.sort_by(|&a, &b| {
+        negotiations[b]
+            .priority
+            .partial_cmp(&negotiations[a].priority)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut remaining_ms = total_budget_ms - allocations.iter().map(cost_ms).sum::<f32>();
+    let mut current_vram: u64 = allocations.iter().map(|s| s.estimated_vram).sum();
+
+    for &idx in &sorted_indices {
+        let negotiation = &negotiations[idx];
+        let current_cost_ms = cost_ms(&allocations[idx]);
+        let current_vram_cost = allocations[idx].estimated_vram;
+
+        let best_upgrade = negotiation.strategies.iter().rev().find(|strategy| {
+            let delta_ms = cost_ms(strategy) - current_cost_ms;
+            let delta_vram = strategy.estimated_vram.saturating_sub(current_vram_cost);
+            let time_fits = delta_ms <= remaining_ms;
+            let vram_fits = max_vram_bytes
+                .map(|max| current_vram + delta_vram <= max)
+                .unwrap_or(true);
+            time_fits && vram_fits
+        });
+
+        if let Some(upgrade) = best_upgrade {
+            let new_cost = cost_ms(upgrade);
+            remaining_ms -= new_cost - current_cost_ms;
+            current_vram += upgrade.estimated_vram.saturating_sub(current_vram_cost);
+            allocations[idx] = upgrade.clone();
+
+            log::trace!(
+                "GORNA: Upgraded {:?} from {:.2}ms to {:.2}ms (remaining={:.2}ms, vram={:.2}MB)",
+                negotiation.agent_id,
+                current_cost_ms,
+                new_cost,
+                remaining_ms,
+                current_vram as f64 / (1024.0 * 1024.0)
+            );
+        }
+    }
+
+    allocations
+}
+
+/// The [`GornaSolver::Exhaustive`] search, or `None` if the search space
+/// is larger than [`MAX_EXHAUSTIVE_COMBINATIONS`].
+fn exhaustive(
+    negotiations: &[RecordedNegotiation],
+    total_budget_ms: f32,
+    max_vram_bytes: Option<u64>,
+) -> Option<Vec<StrategyOption>> {
+    let combinations = negotiations.iter().try_fold(1usize, |acc, n| {
+        acc.checked_mul(n.strategies.len())
+            .filter(|&c| c <= MAX_EXHAUSTIVE_COMBINATIONS)
+    })?;
+
+    // (score, cost) of the best fitting combination so far.
+    let mut best: Option<(f32, f32, Vec<usize>)> = None;
+    let mut choice = vec![0usize; negotiations.len()];
+    for mut code in 0..combinations {
+        for (slot, negotiation) in choice.iter_mut().zip(negotiations) {
+            *slot = code % negotiation.strategies.len();
+            code /= negotiation.strategies.len();
+        }
+
+        let picked = || {
+            choice
+                .iter()
+                .zip(negotiations)
+                .map(|(&i, n)| &n.strategies[i])
+        };
+        let cost: f32 = picked().map(cost_ms).sum();
+        let vram: u64 = picked().map(|s| s.estimated_vram).sum();
+        if cost > total_budget_ms || max_vram_bytes.is_some_and(|max| vram > max) {
+            continue;
+        }
+
+        let score: f32 = choice
+            .iter()
+            .zip(negotiations)
+            .map(|(&i, n)| n.priority * i as f32)
+            .sum();
+        let better = match &best {
+            None => true,
+            Some((best_score, best_cost, _)) => {
+                score > *best_score || (score == *best_score && cost < *best_cost)
+            }
+        };
+        if better {
+            best = Some((score, cost, choice.clone()));
+        }
+    }
+
+    // Only VRAM can rule out every combination: the cheapest one fits the
+    // time budget. Keep the cheapest, as the greedy solver does.
+    let choice = best.map_or_else(|| vec![0; negotiations.len()], |(_, _, choice)| choice);
+    Some(
+        choice
+            .iter()
+            .zip(negotiations)
+            .map(|(&i, n)| n.strategies[i].clone())
+            .collect(),
+    )
+}
@@ -41,17 +41,24 @@ pub mod analysis;
 pub mod budget_channel;
 pub mod context;
 pub mod gorna;
+pub mod mailbox;
 pub mod metrics;
 pub mod plugin;
+pub mod power;
 pub mod registry;
 pub mod scheduler;
 pub mod service;
 pub mod substrate;
+pub mod worker;
 
 pub use analysis::AnalysisReport;
-pub use context::{BatteryLevel, Context, EngineMode, HardwareState, ThermalStatus};
-pub use gorna::GornaArbitrator;
+pub use context::{
+    BatteryLevel, Context, EngineMode, HardwareState, PowerPolicy, PowerState, ThermalStatus,
+};
+pub use gorna::{ArbitrationOutcome, GornaArbitrator, GornaRound, GornaSolver};
+pub use mailbox::{AgentInbox, AgentMailbox};
 pub use plugin::EnginePlugin;
+pub use power::PowerGovernor;
 pub use registry::AgentRegistry;
 pub use scheduler::ExecutionScheduler;
 pub use service::{DccConfig, DccService};
@@ -0,0 +1,159 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The DCC-side handle of an agent's mailbox.
+
+use super::message::{AgentCommand, AgentReply, Envelope};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use khora_core::control::gorna::AgentId;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Counters shared by both ends of a mailbox.
+#[derive(Default)]
+pub(super) struct MailboxCounters {
+    pub(super) sent: AtomicU64,
+    pub(super) answered: AtomicU64,
+    pub(super) missed_deadlines: AtomicU64,
+    pub(super) expired: AtomicU64,
+    pub(super) last_round_trip_us: AtomicU64,
+}
+
+/// A snapshot of a mailbox's traffic, for diagnostics and tests.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MailboxStats {
+    /// Commands sent to the agent.
+    pub sent: u64,
+    /// Requests answered before their deadline.
+    pub answered: u64,
+    /// Requests the DCC stopped waiting for.
+    pub missed_deadlines: u64,
+    /// Commands the agent received after their deadline and dropped.
+    pub expired: u64,
+    /// Send-to-answer time of the most recent answered request.
+    pub last_round_trip: Option<Duration>,
+}
+
+/// Why a request got no answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MailboxError {
+    /// The agent did not answer before the deadline.
+    DeadlineMissed,
+    /// The agent's inbox was dropped.
+    Disconnected,
+}
+
+/// The DCC's handle for talking to one agent. Cheap to clone.
+#[derive(Clone)]
+pub struct AgentMailbox {
+    agent_id: AgentId,
+    tx: Sender<Envelope>,
+    counters: Arc<MailboxCounters>,
+}
+
+impl AgentMailbox {
+    pub(super) fn new(
+        agent_id: AgentId,
+        tx: Sender<Envelope>,
+        counters: Arc<MailboxCounters>,
+    ) -> Self {
+        Self {
+            agent_id,
+            tx,
+            counters,
+        }
+    }
+
+    /// The agent on the other end.
+    pub fn agent_id(&self) -> AgentId {
+        self.agent_id
+    }
+
+    /// Sends a request that must be answered before `deadline`.
+    pub fn request(&self, command: AgentCommand, deadline: Instant) -> PendingReply {
+        let (reply_tx, reply_rx) = crossbeam_channel::bounded(1);
+        self.send(Envelope {
+            command,
+            deadline: Some(deadline),
+            reply: Some(reply_tx),
+        });
+        PendingReply {
+            rx: reply_rx,
+            deadline,
+            sent_at: Instant::now(),
+            counters: Arc::clone(&self.counters),
+        }
+    }
+
+    /// Sends a command that needs no answer and never expires.
+    pub fn post(&self, command: AgentCommand) {
+        self.send(Envelope {
+            command,
+            deadline: None,
+            reply: None,
+        });
+    }
+
+    /// Returns the mailbox's traffic counters.
+    pub fn stats(&self) -> MailboxStats {
+        let c = &self.counters;
+        let last = c.last_round_trip_us.load(Ordering::Relaxed);
+        MailboxStats {
+            sent: c.sent.load(Ordering::Relaxed),
+            answered: c.answered.load(Ordering::Relaxed),
+            missed_deadlines: c.missed_deadlines.load(Ordering::Relaxed),
+            expired: c.expired.load(Ordering::Relaxed),
+            last_round_trip: (last > 0).then(|| Duration::from_micros(last)),
+        }
+    }
+
+    fn send(&self, envelope: Envelope) {
+        self.counters.sent.fetch_add(1, Ordering::Relaxed);
+        // A disconnected inbox shows up as `Disconnected` on the reply side.
+        let _ = self.tx.send(envelope);
+    }
+}
+
+/// A request waiting for its answer.
+pub struct PendingReply {
+    rx: Receiver<AgentReply>,
+    deadline: Instant,
+    sent_at: Instant,
+    counters: Arc<MailboxCounters>,
+}
+
+impl PendingReply {
+    /// Blocks until the answer arrives or the deadline passes.
+    pub fn wait(self) -> Result<AgentReply, MailboxError> {
+        match self.rx.recv_deadline(self.deadline) {
+            Ok(reply) => {
+                let round_trip = self.sent_at.elapsed().as_micros().max(1) as u64;
+                self.counters.answered.fetch_add(1, Ordering::Relaxed);
+                self.counters
+                    .last_round_trip_us
+                    .store(round_trip, Ordering::Relaxed);
+                Ok(reply)
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                self.counters
+                    .missed_deadlines
+                    .fetch_add(1, Ordering::Relaxed);
+                Err(MailboxError::DeadlineMissed)
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(MailboxError::Disconnected),
+        }
+    }
+}
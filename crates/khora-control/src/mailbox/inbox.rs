@@ -0,0 +1,74 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The agent-side end of a mailbox.
+
+use super::agent_mailbox::MailboxCounters;
+use super::message::{AgentCommand, AgentReply, Envelope};
+use crossbeam_channel::Receiver;
+use khora_core::agent::Agent;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Receives the DCC's commands for one agent.
+///
+/// Owned by the agent registry and drained by the thread that runs the agent.
+pub struct AgentInbox {
+    rx: Receiver<Envelope>,
+    counters: Arc<MailboxCounters>,
+}
+
+impl AgentInbox {
+    pub(super) fn new(rx: Receiver<Envelope>, counters: Arc<MailboxCounters>) -> Self {
+        Self { rx, counters }
+    }
+
+    /// Returns `true` if commands are waiting.
+    pub fn has_pending(&self) -> bool {
+        !self.rx.is_empty()
+    }
+
+    /// Handles every pending command against `agent`, answering requests.
+    ///
+    /// Commands whose deadline has already passed are dropped: the DCC has
+    /// stopped waiting, and acting on a stale negotiation would be wrong.
+    /// Returns the number of commands handled.
+    pub fn service(&self, agent: &mut dyn Agent) -> usize {
+        let mut handled = 0;
+        while let Ok(envelope) = self.rx.try_recv() {
+            if envelope.deadline.is_some_and(|d| Instant::now() > d) {
+                self.counters.expired.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+
+            let reply = match envelope.command {
+                AgentCommand::Negotiate(mut request) => {
+                    request.agent_timing = agent.execution_timing();
+                    AgentReply::Negotiation(agent.negotiate(request))
+                }
+                AgentCommand::ApplyBudget(budget) => {
+                    agent.apply_budget(budget);
+                    AgentReply::BudgetApplied
+                }
+                AgentCommand::ReportStatus => AgentReply::Status(agent.report_status()),
+            };
+            if let Some(reply_tx) = envelope.reply {
+                let _ = reply_tx.send(reply);
+            }
+            handled += 1;
+        }
+        handled
+    }
+}
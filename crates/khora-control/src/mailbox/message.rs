@@ -0,0 +1,53 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages exchanged between the DCC and an agent.
+
+use crossbeam_channel::Sender;
+use khora_core::control::gorna::{
+    AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget,
+};
+use std::time::Instant;
+
+/// A request from the DCC to an agent.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AgentCommand {
+    /// Ask for strategy options. The inbox fills in `agent_timing` from the
+    /// agent before forwarding.
+    Negotiate(NegotiationRequest),
+    /// Apply a budget chosen by GORNA.
+    ApplyBudget(ResourceBudget),
+    /// Ask for the agent's current health.
+    ReportStatus,
+}
+
+/// An agent's answer to an [`AgentCommand`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AgentReply {
+    /// Answer to [`AgentCommand::Negotiate`].
+    Negotiation(NegotiationResponse),
+    /// Answer to [`AgentCommand::ApplyBudget`].
+    BudgetApplied,
+    /// Answer to [`AgentCommand::ReportStatus`].
+    Status(AgentStatus),
+}
+
+/// A command in flight, with where (and until when) to answer.
+pub(super) struct Envelope {
+    pub(super) command: AgentCommand,
+    pub(super) deadline: Option<Instant>,
+    pub(super) reply: Option<Sender<AgentReply>>,
+}
@@ -0,0 +1,151 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message-passing channel between the DCC and the agents.
+//!
+//! The DCC cold thread never touches an agent directly. It talks to each one
+//! through an [`AgentMailbox`]: requests go out as [`AgentCommand`]s with a
+//! deadline, and answers come back as [`AgentReply`]s. The matching
+//! [`AgentInbox`] is drained by the thread that owns the agent (the scheduler,
+//! once per frame), so every agent method runs on the hot path with no lock
+//! contention.
+//!
+//! An agent that does not answer before the deadline is reported as a missed
+//! deadline in its [`MailboxStats`] and listed in
+//! [`ArbitrationOutcome::unresponsive`]. It keeps its current budget for that
+//! round, and is not counted as stalled: GORNA's stalled count only comes
+//! from the status reports agents do answer. Nothing blocks and nothing is
+//! skipped silently.
+//!
+//! [`ArbitrationOutcome::unresponsive`]: crate::gorna::ArbitrationOutcome::unresponsive
+
+mod agent_mailbox;
+mod inbox;
+mod message;
+
+pub use agent_mailbox::{AgentMailbox, MailboxError, MailboxStats, PendingReply};
+pub use inbox::AgentInbox;
+pub use message::{AgentCommand, AgentReply};
+
+use khora_core::control::gorna::AgentId;
+use std::sync::Arc;
+
+/// Creates the two ends of an agent's mailbox.
+pub fn channel(agent_id: AgentId) -> (AgentMailbox, AgentInbox) {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let counters = Arc::new(agent_mailbox::MailboxCounters::default());
+    (
+        AgentMailbox::new(agent_id, tx, Arc::clone(&counters)),
+        AgentInbox::new(rx, counters),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::agent::Agent;
+    use khora_core::control::gorna::{
+        AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    };
+    use std::time::{Duration, Instant};
+
+    #[derive(Default)]
+    struct Probe {
+        budget: Option<StrategyId>,
+    }
+
+    impl Agent for Probe {
+        fn id(&self) -> AgentId {
+            AgentId::Audio
+        }
+        fn negotiate(&mut self, _: NegotiationRequest) -> NegotiationResponse {
+            NegotiationResponse {
+                strategies: Vec::new(),
+                timing_adjustment: None,
+            }
+        }
+        fn apply_budget(&mut self, budget: ResourceBudget) {
+            self.budget = Some(budget.strategy_id);
+        }
+        fn report_status(&self) -> AgentStatus {
+            AgentStatus {
+                agent_id: AgentId::Audio,
+                current_strategy: self.budget.unwrap_or(StrategyId::Balanced),
+                health_score: 1.0,
+                is_stalled: false,
+                message: String::new(),
+            }
+        }
+        fn execute(&mut self, _: &mut khora_core::EngineContext<'_>) {}
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn serviced_request_is_answered() {
+        let (mailbox, inbox) = channel(AgentId::Audio);
+        let mut agent = Probe::default();
+
+        let pending = mailbox.request(
+            AgentCommand::ReportStatus,
+            Instant::now() + Duration::from_secs(1),
+        );
+        assert_eq!(inbox.service(&mut agent), 1);
+
+        match pending.wait() {
+            Ok(AgentReply::Status(status)) => assert_eq!(status.agent_id, AgentId::Audio),
+            other => panic!("unexpected reply: {other:?}"),
+        }
+        let stats = mailbox.stats();
+        assert_eq!(stats.answered, 1);
+        assert_eq!(stats.missed_deadlines, 0);
+    }
+
+    #[test]
+    fn unserviced_request_misses_its_deadline() {
+        let (mailbox, inbox) = channel(AgentId::Audio);
+        let mut agent = Probe::default();
+
+        let pending = mailbox.request(
+            AgentCommand::ReportStatus,
+            Instant::now() + Duration::from_millis(10),
+        );
+        assert!(matches!(pending.wait(), Err(MailboxError::DeadlineMissed)));
+        assert_eq!(mailbox.stats().missed_deadlines, 1);
+
+        // The late command is dropped rather than answered.
+        assert_eq!(inbox.service(&mut agent), 0);
+        assert_eq!(mailbox.stats().expired, 1);
+    }
+
+    #[test]
+    fn posted_budget_is_applied_on_service() {
+        let (mailbox, inbox) = channel(AgentId::Audio);
+        let mut agent = Probe::default();
+
+        mailbox.post(AgentCommand::ApplyBudget(ResourceBudget {
+            strategy_id: StrategyId::LowPower,
+            time_limit: Duration::from_millis(2),
+            memory_limit: None,
+            extra_params: Default::default(),
+        }));
+        assert_eq!(agent.budget, None);
+        inbox.service(&mut agent);
+        assert_eq!(agent.budget, Some(StrategyId::LowPower));
+    }
+}
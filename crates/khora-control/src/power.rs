@@ -0,0 +1,246 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The power governor.
+//!
+//! [`PowerGovernor`] turns the battery level of the [`HardwareState`] into a
+//! [`PowerState`] and the [`PowerPolicy`] that goes with it, reading its
+//! tuning from console variables so players and tools can change it at
+//! runtime. The DCC runs it every tick and publishes the result in the
+//! [`Context`](crate::Context): the heuristics cap the frame target to the
+//! policy's FPS, GORNA never allocates a strategy above its ceiling, and the
+//! SDK paces frames and trims background work.
+//!
+//! | CVar | Default | Meaning |
+//! |---|---|---|
+//! | `power.governor` | `true` | Off: always [`PowerState::Unrestricted`] |
+//! | `power.battery_fps_cap` | `30` | FPS cap on battery, `0` for none |
+//! | `power.critical_fps_cap` | `20` | FPS cap on critical battery, `0` for none |
+//! | `power.battery_strategy` | `"balanced"` | Strategy ceiling on battery |
+//! | `power.battery_background_scale` | `0.5` | Background work kept on battery |
+//! | `power.critical_background_scale` | `0.25` | Background work kept on critical battery |
+
+use khora_core::control::gorna::StrategyId;
+use khora_core::cvar::CVarRegistry;
+use khora_core::platform::{BatteryLevel, PowerPolicy, PowerState};
+
+use crate::context::HardwareState;
+
+/// Enables the governor.
+pub const CVAR_GOVERNOR: &str = "power.governor";
+/// FPS cap on battery.
+pub const CVAR_BATTERY_FPS_CAP: &str = "power.battery_fps_cap";
+/// FPS cap on critical battery.
+pub const CVAR_CRITICAL_FPS_CAP: &str = "power.critical_fps_cap";
+/// Strategy ceiling on battery: `low_power`, `balanced` or `high_performance`.
+pub const CVAR_BATTERY_STRATEGY: &str = "power.battery_strategy";
+/// Background work kept on battery.
+pub const CVAR_BATTERY_BACKGROUND_SCALE: &str = "power.battery_background_scale";
+/// Background work kept on critical battery.
+pub const CVAR_CRITICAL_BACKGROUND_SCALE: &str = "power.critical_background_scale";
+
+/// Maps the battery level to a power state and policy.
+#[derive(Debug)]
+pub struct PowerGovernor {
+    cvars: CVarRegistry,
+    state: PowerState,
+    policy: PowerPolicy,
+}
+
+impl PowerGovernor {
+    /// Creates a governor reading its tuning from `cvars`, registering the
+    /// variables it uses.
+    pub fn new(cvars: CVarRegistry) -> Self {
+        let registered = [
+            cvars.register(CVAR_GOVERNOR, "Adapt the engine to battery power", true),
+            cvars.register_ranged(
+                CVAR_BATTERY_FPS_CAP,
+                "FPS cap on battery (0 = uncapped)",
+                30,
+                0.0,
+                240.0,
+            ),
+            cvars.register_ranged(
+                CVAR_CRITICAL_FPS_CAP,
+                "FPS cap on critical battery (0 = uncapped)",
+                20,
+                0.0,
+                240.0,
+            ),
+            cvars.register(
+                CVAR_BATTERY_STRATEGY,
+                "Most expensive GORNA strategy on battery (low_power, balanced, high_performance)",
+                "balanced",
+            ),
+            cvars.register_ranged(
+                CVAR_BATTERY_BACKGROUND_SCALE,
+                "Fraction of background work kept on battery",
+                0.5,
+                0.0,
+                1.0,
+            ),
+            cvars.register_ranged(
+                CVAR_CRITICAL_BACKGROUND_SCALE,
+                "Fraction of background work kept on critical battery",
+                0.25,
+                0.0,
+                1.0,
+            ),
+        ];
+        for error in registered.into_iter().filter_map(Result::err) {
+            log::warn!("PowerGovernor: {}", error);
+        }
+        Self {
+            cvars,
+            state: PowerState::Unrestricted,
+            policy: PowerPolicy::UNRESTRICTED,
+        }
+    }
+
+    /// The current state.
+    pub fn state(&self) -> PowerState {
+        self.state
+    }
+
+    /// The policy of the current state.
+    pub fn policy(&self) -> PowerPolicy {
+        self.policy
+    }
+
+    /// Computes the state and policy for `hardware` with the current tuning.
+    pub fn evaluate(&self, hardware: &HardwareState) -> (PowerState, PowerPolicy) {
+        if !self.cvars.get_bool(CVAR_GOVERNOR).unwrap_or(true) {
+            return (PowerState::Unrestricted, PowerPolicy::UNRESTRICTED);
+        }
+        match hardware.battery {
+            BatteryLevel::Mains => (PowerState::Unrestricted, PowerPolicy::UNRESTRICTED),
+            BatteryLevel::High | BatteryLevel::Low => (
+                PowerState::Battery,
+                PowerPolicy {
+                    fps_cap: self.fps_cap(CVAR_BATTERY_FPS_CAP),
+                    max_strategy: self
+                        .cvars
+                        .get_string(CVAR_BATTERY_STRATEGY)
+                        .and_then(|name| parse_strategy(&name))
+                        .unwrap_or(StrategyId::Balanced),
+                    background_work_scale: self.scale(CVAR_BATTERY_BACKGROUND_SCALE),
+                },
+            ),
+            BatteryLevel::Critical => (
+                PowerState::Critical,
+                PowerPolicy {
+                    fps_cap: self.fps_cap(CVAR_CRITICAL_FPS_CAP),
+                    max_strategy: StrategyId::LowPower,
+                    background_work_scale: self.scale(CVAR_CRITICAL_BACKGROUND_SCALE),
+                },
+            ),
+        }
+    }
+
+    /// Re-evaluates for `hardware`. Returns `true` if the state or its
+    /// policy changed.
+    pub fn update(&mut self, hardware: &HardwareState) -> bool {
+        let (state, policy) = self.evaluate(hardware);
+        if state == self.state && policy == self.policy {
+            return false;
+        }
+        if state != self.state {
+            log::info!(
+                "Power governor: {} → {} (fps cap {:?}, strategies up to {:?})",
+                self.state.name(),
+                state.name(),
+                policy.fps_cap,
+                policy.max_strategy
+            );
+        }
+        self.state = state;
+        self.policy = policy;
+        true
+    }
+
+    fn fps_cap(&self, name: &str) -> Option<u32> {
+        self.cvars
+            .get_int(name)
+            .and_then(|fps| u32::try_from(fps).ok())
+            .filter(|&fps| fps > 0)
+    }
+
+    fn scale(&self, name: &str) -> f32 {
+        self.cvars.get_float(name).unwrap_or(1.0).clamp(0.0, 1.0) as f32
+    }
+}
+
+/// Parses a strategy ceiling name.
+fn parse_strategy(name: &str) -> Option<StrategyId> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "low_power" | "lowpower" => Some(StrategyId::LowPower),
+        "balanced" => Some(StrategyId::Balanced),
+        "high_performance" | "highperformance" => Some(StrategyId::HighPerformance),
+        other => {
+            log::warn!("Power governor: unknown strategy '{}'", other);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn on(battery: BatteryLevel) -> HardwareState {
+        HardwareState {
+            battery,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_battery_levels_map_to_states() {
+        let governor = PowerGovernor::new(CVarRegistry::new());
+
+        let (state, policy) = governor.evaluate(&on(BatteryLevel::Mains));
+        assert_eq!(state, PowerState::Unrestricted);
+        assert_eq!(policy, PowerPolicy::UNRESTRICTED);
+
+        let (state, policy) = governor.evaluate(&on(BatteryLevel::High));
+        assert_eq!(state, PowerState::Battery);
+        assert_eq!(policy.fps_cap, Some(30));
+        assert_eq!(policy.max_strategy, StrategyId::Balanced);
+
+        let (state, policy) = governor.evaluate(&on(BatteryLevel::Critical));
+        assert_eq!(state, PowerState::Critical);
+        assert_eq!(policy.fps_cap, Some(20));
+        assert_eq!(policy.max_strategy, StrategyId::LowPower);
+    }
+
+    #[test]
+    fn test_cvars_retune_and_disable_the_governor() {
+        let cvars = CVarRegistry::new();
+        let mut governor = PowerGovernor::new(cvars.clone());
+        let battery = on(BatteryLevel::Low);
+
+        assert!(governor.update(&battery));
+        assert!(!governor.update(&battery));
+
+        cvars.set(CVAR_BATTERY_FPS_CAP, 0).unwrap();
+        cvars.set(CVAR_BATTERY_STRATEGY, "low_power").unwrap();
+        assert!(governor.update(&battery));
+        assert_eq!(governor.policy().fps_cap, None);
+        assert_eq!(governor.policy().max_strategy, StrategyId::LowPower);
+
+        cvars.set(CVAR_GOVERNOR, false).unwrap();
+        assert!(governor.update(&battery));
+        assert_eq!(governor.state(), PowerState::Unrestricted);
+    }
+}
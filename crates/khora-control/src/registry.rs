@@ -13,30 +13,49 @@
 // limitations under the License.
 
 //! Agent registry for automatic registration and ordered iteration.
+//!
+//! The registry owns every agent outright. Nothing else holds a handle to
+//! one: the DCC talks to agents through their mailboxes, and the scheduler
+//! borrows an agent for the length of its `execute()` with
+//! [`AgentRegistry::lend`], handing it back with [`AgentRegistry::give_back`].
 
+use crate::mailbox::{self, AgentInbox, AgentMailbox};
 use khora_core::agent::dependency::AgentDependency;
 use khora_core::agent::timing::AgentImportance;
-use khora_core::agent::{Agent, EngineMode, ExecutionPhase};
+use khora_core::agent::{Agent, AgentAffinity, EngineMode, ExecutionPhase};
 use khora_core::control::gorna::AgentId;
-use std::sync::{Arc, Mutex};
+use khora_core::lane::{LaneBus, OutputDeck};
+use khora_core::threading::{self, ThreadRole};
+use khora_core::{EngineContext, ServiceRegistry};
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Tuple returned by [`AgentRegistry::collect_for_phase`] for each agent
-/// matching the requested phase and mode: `(agent, importance, priority,
-/// dependencies)`.
+/// matching the requested phase and mode: `(agent id, importance, priority,
+/// dependencies, affinity)`.
 pub type PhaseAgentEntry = (
-    Arc<Mutex<dyn Agent>>,
+    AgentId,
     AgentImportance,
     f32,
     Vec<AgentDependency>,
+    AgentAffinity,
 );
 
 /// Entry in the agent registry containing the agent and its priority.
 struct AgentEntry {
-    agent: Arc<Mutex<dyn Agent>>,
+    id: AgentId,
+    /// The agent itself; `None` while it is lent out.
+    agent: Option<Box<dyn Agent>>,
     priority: f32,
     /// Modes in which this agent is active.
     /// Empty = active in all modes.
     active_modes: Vec<EngineMode>,
+    /// Thread affinity overriding the agent's declared one, if set.
+    affinity_override: Option<AgentAffinity>,
+    /// DCC-side handle of this agent's mailbox.
+    mailbox: AgentMailbox,
+    /// Commands from the DCC waiting to be applied on the owning thread.
+    inbox: AgentInbox,
 }
 
 /// Registry that manages all registered agents with automatic priority ordering.
@@ -59,7 +78,7 @@ impl AgentRegistry {
     ///
     /// Higher priority values mean the agent is updated first.
     /// The agent is active in all engine modes.
-    pub fn register(&mut self, agent: Arc<Mutex<dyn Agent>>, priority: f32) {
+    pub fn register(&mut self, agent: Box<dyn Agent>, priority: f32) {
         self.register_for_mode(agent, priority, vec![]);
     }
 
@@ -69,11 +88,11 @@ impl AgentRegistry {
     /// If `modes` is empty, the agent is active in all modes.
     pub fn register_for_mode(
         &mut self,
-        agent: Arc<Mutex<dyn Agent>>,
+        agent: Box<dyn Agent>,
         priority: f32,
         modes: Vec<EngineMode>,
     ) {
-        let id = agent.lock().map(|a| a.id()).unwrap_or(AgentId::Asset);
+        let id = agent.id();
         log::info!(
             "AgentRegistry: Registered {:?} (priority={:.2}, modes={:?})",
             id,
@@ -81,10 +100,15 @@ impl AgentRegistry {
             modes
         );
 
+        let (mailbox, inbox) = mailbox::channel(id);
         self.entries.push(AgentEntry {
-            agent,
+            id,
+            agent: Some(agent),
             priority,
             active_modes: modes,
+            affinity_override: None,
+            mailbox,
+            inbox,
         });
         self.entries.sort_by(|a, b| {
             b.priority
@@ -103,61 +127,145 @@ impl AgentRegistry {
         self.entries.is_empty()
     }
 
-    /// Returns an iterator over all agents in priority order (highest first).
-    pub fn iter(&self) -> impl Iterator<Item = &Arc<Mutex<dyn Agent>>> {
-        self.entries.iter().map(|e| &e.agent)
+    /// Returns an iterator over the agents currently in the registry, in
+    /// priority order (highest first).
+    ///
+    /// Agents lent out for execution are left out.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Agent> {
+        self.entries.iter().filter_map(|e| e.agent.as_deref())
     }
 
     /// Returns the [`AgentId`]s of every registered agent in priority order.
     ///
     /// Used by the scheduler to seed a fresh `AgentCompletionMap` per frame.
     pub fn all_ids(&self) -> Vec<AgentId> {
+        self.entries.iter().map(|entry| entry.id).collect()
+    }
+
+    /// Returns the IDs of the agents currently lent out.
+    pub fn lent_ids(&self) -> Vec<AgentId> {
         self.entries
             .iter()
-            .filter_map(|entry| entry.agent.lock().ok().map(|a| a.id()))
+            .filter(|entry| entry.agent.is_none())
+            .map(|entry| entry.id)
             .collect()
     }
 
+    /// Overrides the thread affinity declared by an agent's
+    /// `execution_timing()`. Returns `false` if no such agent is registered.
+    pub fn set_affinity(&mut self, id: AgentId, affinity: AgentAffinity) -> bool {
+        let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) else {
+            return false;
+        };
+        log::info!("AgentRegistry: {:?} affinity set to {:?}", id, affinity);
+        entry.affinity_override = Some(affinity);
+        true
+    }
+
+    /// Returns the mailbox of every registered agent in priority order.
+    ///
+    /// The DCC talks to agents exclusively through these handles; it never
+    /// touches an agent itself.
+    pub fn mailboxes(&self) -> Vec<AgentMailbox> {
+        self.entries.iter().map(|e| e.mailbox.clone()).collect()
+    }
+
+    /// Applies pending DCC commands to their agents and answers requests.
+    ///
+    /// Must be called by the thread that owns agent execution, typically once
+    /// per frame. Commands for an agent that is lent out stay queued until
+    /// it is back. Returns the number of commands handled.
+    pub fn service_mailboxes(&mut self) -> usize {
+        let mut handled = 0;
+        for entry in &mut self.entries {
+            if let Some(agent) = entry.agent.as_deref_mut() {
+                handled += entry.inbox.service(agent);
+            }
+        }
+        handled
+    }
+
+    /// Takes the agent with the given ID out of the registry to execute it.
+    ///
+    /// Returns `None` if no such agent is registered or it is already lent
+    /// out. The agent must be returned with [`give_back`](Self::give_back).
+    pub fn lend(&mut self, id: AgentId) -> Option<Box<dyn Agent>> {
+        self.entries
+            .iter_mut()
+            .find(|e| e.id == id)
+            .and_then(|e| e.agent.take())
+    }
+
+    /// Puts an agent taken with [`lend`](Self::lend) back in the registry.
+    pub fn give_back(&mut self, agent: Box<dyn Agent>) {
+        let id = agent.id();
+        match self.entries.iter_mut().find(|e| e.id == id) {
+            Some(entry) if entry.agent.is_none() => entry.agent = Some(agent),
+            _ => log::error!("AgentRegistry: {:?} given back but was not lent out", id),
+        }
+    }
+
     /// Initializes all agents once after registration.
     ///
     /// Calls `on_initialize()` on each agent in priority order, giving them
     /// access to the engine services for caching and lane setup.
-    pub fn initialize_all(&self, context: &mut khora_core::EngineContext<'_>) {
-        for entry in &self.entries {
-            if let Ok(mut agent) = entry.agent.lock() {
-                let id = agent.id();
+    pub fn initialize_all(&mut self, context: &mut khora_core::EngineContext<'_>) {
+        for entry in &mut self.entries {
+            if let Some(agent) = entry.agent.as_mut() {
                 agent.on_initialize(context);
-                log::info!("AgentRegistry: Initialized {:?}", id);
+                log::info!("AgentRegistry: Initialized {:?}", entry.id);
             }
         }
     }
 
-    /// Executes all agents in priority order.
+    /// Shuts every agent down in reverse priority order.
     ///
-    /// Called each frame. Each agent selects the appropriate lanes and
-    /// dispatches their execution.
-    pub fn execute_all(&self, context: &mut khora_core::EngineContext<'_>) {
-        for entry in &self.entries {
-            if let Ok(mut agent) = entry.agent.lock() {
-                agent.execute(context);
+    /// Each agent's `on_shutdown()` runs on a `khora-shutdown-<id>` thread
+    /// and has `timeout` to return. An agent that misses it is left behind
+    /// on that thread and the next one is shut down. Agents still lent out
+    /// (a worker thread that never returned its agent) cannot be shut down.
+    /// Both are logged and returned.
+    pub fn shutdown_all(
+        &mut self,
+        services: &Arc<ServiceRegistry>,
+        timeout: Duration,
+    ) -> Vec<AgentId> {
+        let mut skipped = Vec::new();
+        for entry in self.entries.iter_mut().rev() {
+            let Some(agent) = entry.agent.take() else {
+                log::warn!(
+                    "AgentRegistry: {:?} is still lent out; skipping its shutdown",
+                    entry.id
+                );
+                skipped.push(entry.id);
+                continue;
+            };
+            match shutdown_within(agent, Arc::clone(services), timeout) {
+                Some(agent) => {
+                    entry.agent = Some(agent);
+                    log::info!("AgentRegistry: Shut down {:?}", entry.id);
+                }
+                None => skipped.push(entry.id),
             }
         }
+        skipped
     }
 
-    /// Returns the agent with the given ID, if registered.
-    pub fn get_by_id(&self, id: AgentId) -> Option<Arc<Mutex<dyn Agent>>> {
-        for entry in &self.entries {
-            if let Ok(agent) = entry.agent.lock() {
-                if agent.id() == id {
-                    return Some(entry.agent.clone());
-                }
-            }
+    /// Executes all agents in priority order.
+    ///
+    /// Called each frame. Each agent selects the appropriate lanes and
+    /// dispatches their execution.
+    pub fn execute_all(&mut self, context: &mut khora_core::EngineContext<'_>) {
+        for agent in self.entries.iter_mut().filter_map(|e| e.agent.as_mut()) {
+            agent.execute(context);
         }
-        None
     }
 
     /// Collects agents that are allowed to run in the given phase and mode.
-    /// Returns a list of (agent, importance, priority, dependencies).
+    /// Returns a list of (agent id, importance, priority, dependencies,
+    /// affinity).
+    ///
+    /// Agents lent out (still running on their worker thread) are left out.
     pub fn collect_for_phase(
         &self,
         phase: ExecutionPhase,
@@ -166,8 +274,7 @@ impl AgentRegistry {
         self.entries
             .iter()
             .filter_map(|entry| {
-                let agent = entry.agent.lock().ok()?;
-                let timing = agent.execution_timing();
+                let timing = entry.agent.as_ref()?.execution_timing();
 
                 // Filter by phase
                 if !timing.allowed_phases.contains(&phase) {
@@ -180,24 +287,36 @@ impl AgentRegistry {
                 }
 
                 Some((
-                    entry.agent.clone(),
+                    entry.id,
                     timing.importance,
                     timing.priority,
                     timing.dependencies,
+                    entry.affinity_override.unwrap_or(timing.affinity),
                 ))
             })
             .collect()
     }
 
     /// Executes a specific agent by ID.
-    pub fn execute_agent(&self, id: AgentId, context: &mut khora_core::EngineContext<'_>) -> bool {
-        if let Some(agent) = self.get_by_id(id) {
-            if let Ok(mut a) = agent.lock() {
-                a.execute(context);
-                return true;
+    ///
+    /// Returns `false` if no such agent is registered or it is lent out.
+    pub fn execute_agent(
+        &mut self,
+        id: AgentId,
+        context: &mut khora_core::EngineContext<'_>,
+    ) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.id == id)
+            .and_then(|e| e.agent.as_mut())
+        {
+            Some(agent) => {
+                agent.execute(context);
+                true
             }
+            None => false,
         }
-        false
     }
 }
 
@@ -206,3 +325,194 @@ impl Default for AgentRegistry {
         Self::new()
     }
 }
+
+/// Runs `agent.on_shutdown()` on its own thread and waits up to `timeout`
+/// for it. Returns the agent, or `None` if it panicked or is still running.
+fn shutdown_within(
+    mut agent: Box<dyn Agent>,
+    services: Arc<ServiceRegistry>,
+    timeout: Duration,
+) -> Option<Box<dyn Agent>> {
+    let id = agent.id();
+    let (done_tx, done_rx) = crossbeam_channel::bounded(1);
+    let spawned = threading::spawn_named(
+        format!("khora-shutdown-{id:?}"),
+        ThreadRole::Worker,
+        move || {
+            let bus = LaneBus::new();
+            let mut deck = OutputDeck::new();
+            let mut context = EngineContext {
+                world: None,
+                services,
+                bus: &bus,
+                deck: &mut deck,
+            };
+            agent.on_shutdown(&mut context);
+            let _ = done_tx.send(agent);
+        },
+    );
+    if let Err(e) = spawned {
+        log::error!("AgentRegistry: cannot spawn the shutdown thread of {id:?}: {e}");
+        return None;
+    }
+    match done_rx.recv_timeout(timeout) {
+        Ok(agent) => Some(agent),
+        Err(crossbeam_channel::RecvTimeoutError::Timeout) => {
+            log::warn!("AgentRegistry: {id:?} did not shut down within {timeout:?}; leaving it");
+            None
+        }
+        Err(crossbeam_channel::RecvTimeoutError::Disconnected) => {
+            log::error!("AgentRegistry: {id:?} panicked while shutting down");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mailbox::{AgentCommand, AgentReply};
+    use khora_core::control::gorna::{
+        AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    };
+    use std::time::{Duration, Instant};
+
+    struct Probe;
+
+    impl Agent for Probe {
+        fn id(&self) -> AgentId {
+            AgentId::Audio
+        }
+        fn negotiate(&mut self, _: NegotiationRequest) -> NegotiationResponse {
+            NegotiationResponse {
+                strategies: Vec::new(),
+                timing_adjustment: None,
+            }
+        }
+        fn apply_budget(&mut self, _: ResourceBudget) {}
+        fn report_status(&self) -> AgentStatus {
+            AgentStatus {
+                agent_id: AgentId::Audio,
+                current_strategy: StrategyId::Balanced,
+                health_score: 1.0,
+                is_stalled: false,
+                message: String::new(),
+            }
+        }
+        fn execute(&mut self, _: &mut khora_core::EngineContext<'_>) {}
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn lent_agent_is_left_out_until_given_back() {
+        let mut registry = AgentRegistry::new();
+        registry.register(Box::new(Probe), 1.0);
+        let phase = ExecutionPhase::OUTPUT;
+        let mode = EngineMode::Playing;
+
+        let agent = registry.lend(AgentId::Audio).unwrap();
+        assert!(registry.lend(AgentId::Audio).is_none());
+        assert!(registry.collect_for_phase(phase, &mode).is_empty());
+        assert_eq!(registry.lent_ids(), vec![AgentId::Audio]);
+        assert_eq!(registry.all_ids(), vec![AgentId::Audio]);
+
+        registry.give_back(agent);
+        assert_eq!(registry.collect_for_phase(phase, &mode).len(), 1);
+        assert!(registry.lent_ids().is_empty());
+    }
+
+    #[test]
+    fn commands_for_a_lent_agent_wait_for_its_return() {
+        let mut registry = AgentRegistry::new();
+        registry.register(Box::new(Probe), 1.0);
+        let mailbox = registry.mailboxes().remove(0);
+
+        let agent = registry.lend(AgentId::Audio).unwrap();
+        let pending = mailbox.request(
+            AgentCommand::ReportStatus,
+            Instant::now() + Duration::from_secs(1),
+        );
+        assert_eq!(registry.service_mailboxes(), 0);
+
+        registry.give_back(agent);
+        assert_eq!(registry.service_mailboxes(), 1);
+        assert!(matches!(pending.wait(), Ok(AgentReply::Status(_))));
+    }
+
+    /// An agent whose shutdown takes `shutdown_time`, then sets `done`.
+    struct SlowShutdown {
+        id: AgentId,
+        shutdown_time: Duration,
+        done: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl Agent for SlowShutdown {
+        fn id(&self) -> AgentId {
+            self.id
+        }
+        fn negotiate(&mut self, _: NegotiationRequest) -> NegotiationResponse {
+            NegotiationResponse {
+                strategies: Vec::new(),
+                timing_adjustment: None,
+            }
+        }
+        fn apply_budget(&mut self, _: ResourceBudget) {}
+        fn report_status(&self) -> AgentStatus {
+            AgentStatus {
+                agent_id: self.id,
+                current_strategy: StrategyId::Balanced,
+                health_score: 1.0,
+                is_stalled: false,
+                message: String::new(),
+            }
+        }
+        fn execute(&mut self, _: &mut khora_core::EngineContext<'_>) {}
+        fn on_shutdown(&mut self, _: &mut khora_core::EngineContext<'_>) {
+            std::thread::sleep(self.shutdown_time);
+            self.done.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn hung_shutdown_is_left_behind_after_its_deadline() {
+        let quick_done = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut registry = AgentRegistry::new();
+        registry.register(
+            Box::new(SlowShutdown {
+                id: AgentId::Audio,
+                shutdown_time: Duration::ZERO,
+                done: Arc::clone(&quick_done),
+            }),
+            2.0,
+        );
+        // Lowest priority, so shut down first.
+        registry.register(
+            Box::new(SlowShutdown {
+                id: AgentId::Physics,
+                shutdown_time: Duration::from_secs(30),
+                done: Arc::default(),
+            }),
+            1.0,
+        );
+
+        let start = Instant::now();
+        let skipped =
+            registry.shutdown_all(&Arc::new(ServiceRegistry::new()), Duration::from_millis(50));
+
+        assert_eq!(skipped, vec![AgentId::Physics]);
+        assert!(quick_done.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(registry.lent_ids(), vec![AgentId::Physics]);
+    }
+}
@@ -17,14 +17,15 @@
 use crate::budget_channel::BudgetChannel;
 use crate::context::Context;
 use crate::plugin::EnginePlugin;
-use crate::registry::AgentRegistry;
+use crate::registry::{AgentRegistry, PhaseAgentEntry};
 use crate::substrate;
+use crate::worker::{AgentWorker, FrameBarrier, WorkerJob};
 use khora_core::agent::completion::{AgentCompletionMap, CompletionOutcome};
 use khora_core::agent::dependency::DependencyKind;
 use khora_core::agent::timing::AgentImportance;
-use khora_core::agent::{AgentDependency, EngineMode, ExecutionPhase};
+use khora_core::agent::{Agent, AgentDependency, EngineMode, ExecutionPhase};
 use khora_core::control::gorna::{AgentId, ResourceBudget};
-use khora_core::graph::topological_sort;
+use khora_core::graph::{CycleError, Dag, NodeId};
 use khora_core::lane::{LaneBus, OutputDeck};
 use khora_core::{EngineContext, ServiceRegistry};
 use khora_data::ecs::World;
@@ -32,12 +33,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
-type AgentSlot = (
-    Arc<Mutex<dyn khora_core::agent::Agent>>,
-    AgentImportance,
-    f32,
-    Vec<AgentDependency>,
-);
+type AgentSlot = PhaseAgentEntry;
 
 /// The hot-path execution scheduler.
 pub struct ExecutionScheduler {
@@ -52,6 +48,13 @@ pub struct ExecutionScheduler {
     /// layer can drain typed lane outputs (recorded GPU commands, draw
     /// lists, etc.) after the scheduler has finished.
     last_deck: OutputDeck,
+    /// Dedicated threads of [`AgentAffinity::Worker`](khora_core::agent::AgentAffinity::Worker) agents, spawned on
+    /// first dispatch.
+    workers: HashMap<AgentId, AgentWorker>,
+    /// Joins worker agents before the frame's deck is handed off.
+    barrier: FrameBarrier,
+    /// `execute()` time of each worker agent during the last frame.
+    worker_timings: Vec<(AgentId, Duration)>,
 }
 
 impl ExecutionScheduler {
@@ -70,6 +73,9 @@ impl ExecutionScheduler {
             frame_start: Instant::now(),
             frame_budget: Duration::from_millis(16),
             last_deck: OutputDeck::new(),
+            workers: HashMap::new(),
+            barrier: FrameBarrier::new(),
+            worker_timings: Vec::new(),
         }
     }
 
@@ -79,6 +85,29 @@ impl ExecutionScheduler {
         &mut self.last_deck
     }
 
+    /// Stops the worker threads of worker agents.
+    ///
+    /// Called once during engine shutdown, after the last frame. Every frame
+    /// already joins its worker jobs, so the threads are normally idle; a
+    /// thread still running after `timeout` is detached and its agent
+    /// returned.
+    pub fn shutdown(&mut self, timeout: Duration) -> Vec<AgentId> {
+        let deadline = Instant::now() + timeout;
+        let mut stuck = Vec::new();
+        for (id, worker) in self.workers.drain() {
+            if !worker.stop(deadline) {
+                log::warn!("Scheduler: Worker thread for {:?} did not exit in time", id);
+                stuck.push(id);
+            }
+        }
+        stuck
+    }
+
+    /// Returns how long each worker agent spent in `execute()` last frame.
+    pub fn worker_timings(&self) -> &[(AgentId, Duration)] {
+        &self.worker_timings
+    }
+
     /// Returns a reference to the budget channel for the DCC to send budgets.
     pub fn budget_channel(&self) -> &BudgetChannel {
         &self.budget_channel
@@ -118,19 +147,23 @@ impl ExecutionScheduler {
     ///
     /// This is called every frame by the engine loop.
     pub fn run_frame(&mut self, world: &mut World, services: Arc<ServiceRegistry>) {
-        // 1. Sync budgets from cold thread
+        // 1. Sync budgets from cold thread and answer pending GORNA
+        //    requests on the thread that owns the agents.
         self.budget_channel.sync();
+        self.registry.lock().unwrap().service_mailboxes();
         self.frame_start = Instant::now();
 
         // 2. Build the per-frame completion map and overlay registry.
         //    The map carries one handle per known agent; the overlay shadows
         //    the engine-level registry so agents can fetch the map by type
-        //    without changing the EngineContext shape.
+        //    without changing the EngineContext shape. Worker agents find
+        //    the barrier's world channel there too.
         let agent_ids = self.registry.lock().unwrap().all_ids();
         let completion_map = Arc::new(AgentCompletionMap::new(&agent_ids));
 
         let mut frame_overlay = ServiceRegistry::with_parent(services);
         frame_overlay.insert(Arc::clone(&completion_map));
+        frame_overlay.insert(self.barrier.world_channel());
         let overlay_arc = Arc::new(frame_overlay);
 
         // 3. Read current mode
@@ -151,6 +184,8 @@ impl ExecutionScheduler {
         //    the service registry.
         let budgets = self.snapshot_budgets(&agent_ids);
         substrate::run_flows(world, &mut bus, &budgets, &overlay_arc);
+        // Worker agents read the bus from their own threads.
+        let bus = Arc::new(bus);
 
         // 6. Clone phase order to avoid borrow conflicts
         let phases: Vec<ExecutionPhase> = self.phase_order.clone();
@@ -175,7 +210,12 @@ impl ExecutionScheduler {
             );
         }
 
-        // 8. Hand the populated deck off to the engine for the I/O boundary.
+        // 8. Frame barrier: join worker agents and fold their outputs into
+        //    the deck before it reaches submit/present.
+        self.worker_timings = self.barrier.wait_all(world, &mut deck, &completion_map);
+        self.give_back_worker_agents();
+
+        // 9. Hand the populated deck off to the engine for the I/O boundary.
         self.last_deck = deck;
     }
 
@@ -195,14 +235,12 @@ impl ExecutionScheduler {
         services: &Arc<ServiceRegistry>,
         mode: &EngineMode,
         completion_map: &Arc<AgentCompletionMap>,
-        bus: &LaneBus,
+        bus: &Arc<LaneBus>,
         deck: &mut OutputDeck,
     ) {
-        // Collect agents for this phase and mode
-        let agents = {
-            let registry = self.registry.lock().unwrap();
-            registry.collect_for_phase(phase, mode)
-        };
+        // Collect agents for this phase and mode. Agents still running on
+        // their worker thread are lent out and left out.
+        let agents = self.registry.lock().unwrap().collect_for_phase(phase, mode);
 
         if agents.is_empty() {
             return;
@@ -213,19 +251,17 @@ impl ExecutionScheduler {
     }
 
     fn execute_agents_sequential(
-        &self,
+        &mut self,
         agents: Vec<AgentSlot>,
         world: &mut World,
         services: &Arc<ServiceRegistry>,
         completion_map: &Arc<AgentCompletionMap>,
-        bus: &LaneBus,
+        bus: &Arc<LaneBus>,
         deck: &mut OutputDeck,
     ) {
-        for (agent, importance, _priority, dependencies) in agents {
-            let agent_id = match agent.lock().ok().map(|a| a.id()) {
-                Some(id) => id,
-                None => continue,
-            };
+        for (agent_id, importance, _priority, dependencies, affinity) in agents {
+            // Run what worker agents asked of the world in the meantime.
+            self.barrier.serve_world(world);
 
             // Skip optional if under budget pressure
             if importance == AgentImportance::Optional && self.is_under_budget_pressure() {
@@ -233,46 +269,120 @@ impl ExecutionScheduler {
                 continue;
             }
 
+            // Hard dependencies still running on a worker thread must land
+            // before this agent can see their outcome.
+            for dep in &dependencies {
+                if matches!(dep.kind, DependencyKind::Hard) {
+                    self.barrier
+                        .wait_for(dep.target, world, deck, completion_map);
+                }
+            }
+            self.give_back_worker_agents();
+
             // Skip if hard dependencies were skipped or are unmarked
             if !are_hard_dependencies_completed(&dependencies, completion_map) {
                 completion_map.mark(agent_id, CompletionOutcome::Skipped);
                 continue;
             }
 
+            // Worker agents are marked complete by the frame barrier. If no
+            // worker thread is available the agent runs inline instead.
+            let Some(mut agent) = self.registry.lock().unwrap().lend(agent_id) else {
+                continue;
+            };
+            if affinity.is_worker() {
+                match self.dispatch_to_worker(agent_id, agent, services, bus) {
+                    Ok(()) => continue,
+                    Err(returned) => agent = returned,
+                }
+            }
+
             // Build EngineContext and execute (CLAD descent: the agent
             // chooses and invokes its lane internally).
             let mut engine_ctx = EngineContext {
                 world: Some(world as &mut dyn std::any::Any),
                 services: Arc::clone(services),
-                bus,
+                bus: bus.as_ref(),
                 deck,
             };
 
-            if let Ok(mut a) = agent.lock() {
-                a.execute(&mut engine_ctx);
-            }
+            agent.execute(&mut engine_ctx);
+            self.registry.lock().unwrap().give_back(agent);
             completion_map.mark(agent_id, CompletionOutcome::Completed);
         }
     }
 
+    /// Hands `agent` to its worker thread, spawning the thread on first use.
+    ///
+    /// Returns the agent if it could not be dispatched.
+    fn dispatch_to_worker(
+        &mut self,
+        agent_id: AgentId,
+        agent: Box<dyn Agent>,
+        services: &Arc<ServiceRegistry>,
+        bus: &Arc<LaneBus>,
+    ) -> Result<(), Box<dyn Agent>> {
+        if !self.workers.contains_key(&agent_id) {
+            match AgentWorker::spawn(agent_id, self.barrier.results_sender()) {
+                Ok(worker) => {
+                    log::info!("Scheduler: Spawned worker thread for {:?}", agent_id);
+                    self.workers.insert(agent_id, worker);
+                }
+                Err(e) => {
+                    log::error!(
+                        "Scheduler: Failed to spawn a worker for {:?}, running inline: {e}",
+                        agent_id
+                    );
+                    return Err(agent);
+                }
+            }
+        }
+
+        let job = WorkerJob {
+            agent,
+            services: Arc::clone(services),
+            bus: Arc::clone(bus),
+        };
+        let worker = &self.workers[&agent_id];
+        self.barrier.dispatch(worker, job).map_err(|job| {
+            // The thread is gone; respawn it next frame.
+            self.workers.remove(&agent_id);
+            job.agent
+        })
+    }
+
+    /// Returns the agents of collected worker jobs to the registry.
+    fn give_back_worker_agents(&mut self) {
+        let agents = self.barrier.take_returned();
+        if agents.is_empty() {
+            return;
+        }
+        let mut registry = self.registry.lock().unwrap();
+        for agent in agents {
+            registry.give_back(agent);
+        }
+    }
+
     /// Parallel execution path — Phase 4 stub.
     ///
     /// The intended structure (once enabled):
     /// ```ignore
-    /// for (agent, _, _, deps) in agents {
+    /// for (id, _, _, deps, _) in agents {
+    ///     let mut agent = registry.lend(id)?;
     ///     let map = Arc::clone(completion_map);
     ///     tokio::spawn(async move {
     ///         for dep in deps.iter().filter(|d| d.kind == DependencyKind::Hard) {
     ///             match map.wait(dep.target).await {
     ///                 Some(CompletionOutcome::Completed) => {}
-    ///                 _ => { map.mark(agent.id(), Skipped); return; }
+    ///                 _ => { map.mark(id, Skipped); return agent; }
     ///             }
     ///         }
-    ///         agent.lock().execute(&mut ctx);
-    ///         map.mark(agent.id(), Completed);
+    ///         agent.execute(&mut ctx);
+    ///         map.mark(id, Completed);
+    ///         agent
     ///     });
     /// }
-    /// // join_all spawned handles before returning.
+    /// // join_all spawned handles and give every agent back before returning.
     /// ```
     #[allow(dead_code)]
     fn execute_agents_parallel(
@@ -306,49 +416,40 @@ fn sort_agents(mut agents: Vec<AgentSlot>) -> Vec<AgentSlot> {
         b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    // Build (id -> index) map after the importance sort so the topo result
-    // can be reassembled cheaply.
-    let id_to_index: std::collections::HashMap<AgentId, usize> = agents
-        .iter()
-        .enumerate()
-        .filter_map(|(idx, slot)| slot.0.lock().ok().map(|a| (a.id(), idx)))
-        .collect();
-
-    let nodes: Vec<AgentId> = id_to_index.keys().copied().collect();
-
-    // Build hard-dep edges (parent = dep target, child = dependent agent)
-    // — the dep target must run first.
-    let mut edges: Vec<(AgentId, AgentId)> = Vec::new();
-    for slot in &agents {
-        let agent_id = match slot.0.lock().ok().map(|a| a.id()) {
-            Some(id) => id,
-            None => continue,
-        };
+    // One node per agent, added in importance order: Kahn's FIFO ready
+    // queue keeps that order among agents the dependencies leave free.
+    let mut graph: Dag<AgentId> = Dag::new();
+    let nodes: Vec<NodeId> = agents.iter().map(|slot| graph.add_node(slot.0)).collect();
+    let by_id: HashMap<AgentId, NodeId> = nodes.iter().map(|&node| (graph[node], node)).collect();
+
+    // Hard-dep edges (dep target -> dependent agent) — the dep target must
+    // run first.
+    for (slot, &node) in agents.iter().zip(&nodes) {
         for dep in &slot.3 {
-            if matches!(dep.kind, DependencyKind::Hard) && id_to_index.contains_key(&dep.target) {
-                edges.push((dep.target, agent_id));
+            if !matches!(dep.kind, DependencyKind::Hard) {
+                continue;
+            }
+            if let Some(&target) = by_id.get(&dep.target) {
+                graph.add_edge(target, node, ());
             }
         }
     }
 
-    match topological_sort(nodes, edges) {
+    match graph.topological_order() {
         Ok(order) => {
-            // Reassemble agents in topo order. Equal-priority nodes inherit
-            // the importance+priority ordering already applied above.
             let mut taken: Vec<Option<AgentSlot>> = agents.into_iter().map(Some).collect();
             order
                 .into_iter()
-                .filter_map(|id| {
-                    id_to_index
-                        .get(&id)
-                        .copied()
-                        .and_then(|idx| taken[idx].take())
-                })
+                .filter_map(|node| taken[node.index()].take())
                 .collect()
         }
-        Err(_) => {
+        Err(err) => {
+            let cycle = CycleError {
+                cycle: err.cycle.iter().map(|&node| graph[node]).collect(),
+            };
             log::error!(
-                "Scheduler: cycle detected in Hard agent dependencies — falling back to importance/priority order"
+                "Scheduler: {} in Hard agent dependencies — falling back to importance/priority order",
+                cycle
             );
             agents
         }
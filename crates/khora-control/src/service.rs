@@ -19,9 +19,13 @@ use crate::context::Context;
 use crate::metrics::MetricStore;
 use crate::EngineMode;
 use crossbeam_channel::{Receiver, Sender};
-use khora_core::agent::Agent;
-use khora_core::control::gorna::ResourceBudget;
+use khora_core::agent::{Agent, AgentAffinity};
+use khora_core::cvar::CVarRegistry;
 use khora_core::telemetry::TelemetryEvent;
+use khora_core::threading::{self, ThreadRole};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread;
@@ -29,6 +33,7 @@ use std::time::{Duration, Instant};
 
 use crate::analysis::HeuristicEngine;
 use crate::gorna::GornaArbitrator;
+use crate::power::PowerGovernor;
 use crate::registry::AgentRegistry;
 use khora_core::control::gorna::AgentId;
 use std::sync::Mutex;
@@ -41,9 +46,12 @@ pub struct DccConfig {
     /// Maximum number of telemetry events to buffer.
     /// If the buffer is full, new events are dropped.
     pub telemetry_buffer_size: usize,
-    /// Timeout for acquiring locks on agents during negotiation.
-    /// If an agent lock cannot be acquired within this time, the agent is skipped.
-    pub agent_lock_timeout_ms: u64,
+    /// How long an arbitration round waits for agents to answer through
+    /// their mailboxes. Agents that miss it are reported as unresponsive.
+    pub agent_reply_timeout_ms: u64,
+    /// File every arbitration round is appended to, one JSON line per
+    /// round, for offline replay with [`gorna::replay`](crate::gorna::replay).
+    pub capture_path: Option<PathBuf>,
 }
 
 impl Default for DccConfig {
@@ -51,7 +59,8 @@ impl Default for DccConfig {
         Self {
             tick_rate: 20,
             telemetry_buffer_size: 1000,
-            agent_lock_timeout_ms: 100,
+            agent_reply_timeout_ms: 100,
+            capture_path: None,
         }
     }
 }
@@ -67,12 +76,15 @@ pub struct DccService {
     running: Arc<AtomicBool>,
     handle: Option<thread::JoinHandle<()>>,
     event_tx: Sender<TelemetryEvent>,
+    cvars: CVarRegistry,
+    governor: Option<PowerGovernor>,
 }
 
 impl DccService {
     /// Creates a new DCC service.
     pub fn new(config: DccConfig) -> (Self, Receiver<TelemetryEvent>) {
         let (tx, rx) = crossbeam_channel::bounded(config.telemetry_buffer_size);
+        let cvars = CVarRegistry::new();
         let service = Self {
             config,
             context: Arc::new(std::sync::RwLock::new(Context::default())),
@@ -81,6 +93,8 @@ impl DccService {
             running: Arc::new(AtomicBool::new(false)),
             handle: None,
             event_tx: tx,
+            governor: Some(PowerGovernor::new(cvars.clone())),
+            cvars,
         };
         (service, rx)
     }
@@ -95,7 +109,7 @@ impl DccService {
     ///
     /// Higher priority values mean the agent is updated first in each frame.
     /// The agent is active in all engine modes.
-    pub fn register_agent(&self, agent: Arc<std::sync::Mutex<dyn Agent>>, priority: f32) {
+    pub fn register_agent(&self, agent: Box<dyn Agent>, priority: f32) {
         let mut registry = self.registry.lock().unwrap();
         registry.register(agent, priority);
     }
@@ -106,7 +120,7 @@ impl DccService {
     /// If `modes` is empty, the agent is active in all modes.
     pub fn register_agent_for_mode(
         &self,
-        agent: Arc<std::sync::Mutex<dyn Agent>>,
+        agent: Box<dyn Agent>,
         priority: f32,
         modes: Vec<EngineMode>,
     ) {
@@ -126,13 +140,20 @@ impl DccService {
         let registry = Arc::clone(&self.registry);
         let budget_channel = self.budget_channel.clone();
         let tick_duration = Duration::from_secs_f32(1.0 / self.config.tick_rate as f32);
-        let agent_lock_timeout = Duration::from_millis(self.config.agent_lock_timeout_ms);
-
-        let handle = thread::spawn(move || {
+        let agent_reply_timeout = Duration::from_millis(self.config.agent_reply_timeout_ms);
+        let mut capture = self.config.capture_path.as_deref().and_then(open_capture);
+        let mut governor = self
+            .governor
+            .take()
+            .unwrap_or_else(|| PowerGovernor::new(self.cvars.clone()));
+
+        let spawned = threading::spawn_named("khora-dcc", ThreadRole::Control, move || {
             let mut store = MetricStore::new();
             let heuristic_engine = HeuristicEngine;
-            let arbitrator = GornaArbitrator::new(agent_lock_timeout);
+            let arbitrator = GornaArbitrator::new(agent_reply_timeout);
             let mut initial_negotiation_done = false;
+            let mut negotiated_mode: Option<EngineMode> = None;
+            let mut negotiated_power = None;
 
             log::info!("DCC Service thread started.");
 
@@ -154,7 +175,6 @@ impl DccService {
                             ctx.hardware.battery = report.battery;
                             ctx.hardware.cpu_load = report.cpu_load;
                             ctx.hardware.gpu_load = report.gpu_load.unwrap_or(0.0);
-                            ctx.hardware.available_vram = report.gpu_timings.as_ref().map(|_| 0);
                             ctx.refresh_budget_multiplier();
 
                             if let Some(gpu_timings) = report.gpu_timings {
@@ -176,6 +196,16 @@ impl DccService {
                                 ctx.hardware.gpu_load
                             );
                         }
+                        TelemetryEvent::HardwareSurvey(survey) => {
+                            let mut ctx = context.write().unwrap();
+                            ctx.hardware.total_vram = survey.vram_bytes();
+                            log::info!(
+                                "DCC Hardware survey: GPU={:?}, VRAM={:?}, CPU threads={}",
+                                survey.gpu.as_ref().map(|gpu| gpu.name.as_str()),
+                                ctx.hardware.total_vram,
+                                survey.cpu.logical_cores
+                            );
+                        }
                         TelemetryEvent::PhaseChange(phase_name) => {
                             let mut ctx = context.write().unwrap();
                             if let Some(new_mode) = EngineMode::from_name(&phase_name) {
@@ -214,6 +244,10 @@ impl DccService {
                 let (report, ctx_copy) = {
                     let mut ctx = context.write().unwrap();
                     ctx.refresh_budget_multiplier();
+                    if governor.update(&ctx.hardware) {
+                        ctx.power = governor.state();
+                        ctx.power_policy = governor.policy();
+                    }
                     let report = heuristic_engine.analyze(&ctx, &store);
                     (report, ctx.clone())
                 };
@@ -223,34 +257,43 @@ impl DccService {
                 }
 
                 // 3. GORNA Negotiation
-                if report.needs_negotiation || !initial_negotiation_done {
-                    let registry_lock = registry.lock().unwrap();
-                    if !registry_lock.is_empty() {
-                        let agents: Vec<_> = registry_lock.iter().cloned().collect();
-                        drop(registry_lock);
-
-                        let mut agents_slice: Vec<Arc<std::sync::Mutex<dyn Agent>>> = agents;
-                        arbitrator.arbitrate(&ctx_copy, &report, &mut agents_slice);
+                // A mode change (e.g. entering or leaving the background mode)
+                // or a new power policy always triggers a fresh round so
+                // budgets follow them.
+                let mode_changed = negotiated_mode.as_ref() != Some(&ctx_copy.mode);
+                let power_changed = negotiated_power != Some(ctx_copy.power_policy);
+                if report.needs_negotiation
+                    || !initial_negotiation_done
+                    || mode_changed
+                    || power_changed
+                {
+                    // Only the mailbox handles are taken; agents themselves are
+                    // never locked from this thread.
+                    let mailboxes = registry.lock().unwrap().mailboxes();
+                    let outcome = arbitrator.arbitrate(&ctx_copy, &report, &mailboxes);
+                    if let (Some(writer), Some(round)) = (capture.as_mut(), &outcome.round) {
+                        if let Err(e) = round.write_to(writer).and_then(|()| writer.flush()) {
+                            log::warn!("DCC: GORNA capture stopped: {}", e);
+                            capture = None;
+                        }
+                    }
+                    if outcome.negotiated > 0 || outcome.emergency || outcome.background {
                         initial_negotiation_done = true;
+                        negotiated_mode = Some(ctx_copy.mode.clone());
+                        negotiated_power = Some(ctx_copy.power_policy);
+                    }
+                    if !outcome.unresponsive.is_empty() {
+                        log::warn!(
+                            "DCC: {} agent(s) missed the GORNA reply deadline: {:?}",
+                            outcome.unresponsive.len(),
+                            outcome.unresponsive
+                        );
+                    }
 
-                        // Send budgets through the budget channel to the Scheduler.
-                        if let Some(ref budget_channel) = budget_channel {
-                            for agent_arc in &agents_slice {
-                                if let Ok(agent) = agent_arc.lock() {
-                                    // The agent's current budget was set by apply_budget() during arbitration.
-                                    // We need to reconstruct the budget from the agent's status.
-                                    let status = agent.report_status();
-                                    let budget = ResourceBudget {
-                                        strategy_id: status.current_strategy,
-                                        time_limit: Duration::from_secs_f32(
-                                            report.suggested_latency_ms / 1000.0,
-                                        ),
-                                        memory_limit: None,
-                                        extra_params: std::collections::HashMap::new(),
-                                    };
-                                    budget_channel.send(agent.id(), budget);
-                                }
-                            }
+                    // Send budgets through the budget channel to the Scheduler.
+                    if let Some(ref budget_channel) = budget_channel {
+                        for (agent_id, budget) in outcome.budgets {
+                            budget_channel.send(agent_id, budget);
                         }
                     }
                 }
@@ -264,7 +307,13 @@ impl DccService {
             log::info!("DCC Service thread stopped.");
         });
 
-        self.handle = Some(handle);
+        match spawned {
+            Ok(handle) => self.handle = Some(handle),
+            Err(e) => {
+                log::error!("Failed to spawn the DCC thread: {e}");
+                self.running.store(false, Ordering::SeqCst);
+            }
+        }
     }
 
     /// Stops the DCC background thread.
@@ -285,6 +334,12 @@ impl DccService {
         self.event_tx.clone()
     }
 
+    /// Returns the console variables of the engine. The DCC registers the
+    /// power governor's tuning (`power.*`) in it.
+    pub fn cvars(&self) -> &CVarRegistry {
+        &self.cvars
+    }
+
     /// Returns the current context.
     pub fn get_context(&self) -> Context {
         self.context.read().unwrap().clone()
@@ -304,30 +359,64 @@ impl DccService {
     /// Should be called once after all agents are registered, giving them
     /// access to engine services for caching and lane setup.
     pub fn initialize_agents(&self, context: &mut khora_core::EngineContext<'_>) {
-        if let Ok(registry) = self.registry.lock() {
+        if let Ok(mut registry) = self.registry.lock() {
             registry.initialize_all(context);
         }
     }
 
+    /// Shuts all registered agents down in reverse priority order, giving
+    /// each `timeout` to return from `on_shutdown()`.
+    ///
+    /// Should be called once, after [`stop`](Self::stop) and the last frame,
+    /// while the graphics device is still alive. Returns the agents that
+    /// were not shut down: those a worker thread never gave back, and those
+    /// that missed their deadline.
+    pub fn shutdown_agents(
+        &self,
+        services: &Arc<khora_core::ServiceRegistry>,
+        timeout: Duration,
+    ) -> Vec<AgentId> {
+        match self.registry.lock() {
+            Ok(mut registry) => registry.shutdown_all(services, timeout),
+            Err(_) => Vec::new(),
+        }
+    }
+
     /// Executes all registered agents in priority order.
     ///
     /// Called each frame. Each agent selects the appropriate lanes and
     /// dispatches their execution.
     pub fn execute_agents(&self, context: &mut khora_core::EngineContext<'_>) {
-        if let Ok(registry) = self.registry.lock() {
+        if let Ok(mut registry) = self.registry.lock() {
             registry.execute_all(context);
         }
     }
 
+    /// Applies pending GORNA commands to the registered agents.
+    ///
+    /// Must be called regularly by the thread that executes agents (the
+    /// scheduler does so every frame); arbitration rounds wait on it.
+    pub fn service_agent_mailboxes(&self) -> usize {
+        self.registry
+            .lock()
+            .map(|mut r| r.service_mailboxes())
+            .unwrap_or(0)
+    }
+
+    /// Overrides the thread affinity of a registered agent.
+    ///
+    /// Returns `false` if no agent with this ID is registered.
+    pub fn set_agent_affinity(&self, id: AgentId, affinity: AgentAffinity) -> bool {
+        self.registry
+            .lock()
+            .map(|mut r| r.set_affinity(id, affinity))
+            .unwrap_or(false)
+    }
+
     /// Returns the number of registered agents.
     pub fn agent_count(&self) -> usize {
         self.registry.lock().map(|r| r.len()).unwrap_or(0)
     }
-
-    /// Returns a reference to the agent with the given ID, if registered.
-    pub fn get_agent(&self, id: AgentId) -> Option<Arc<Mutex<dyn Agent>>> {
-        self.registry.lock().ok()?.get_by_id(id)
-    }
 }
 
 impl Drop for DccService {
@@ -336,6 +425,21 @@ impl Drop for DccService {
     }
 }
 
+/// Opens a GORNA capture for appending. Failing to open it only disables
+/// the capture.
+fn open_capture(path: &Path) -> Option<BufWriter<File>> {
+    match OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => {
+            log::info!("DCC: capturing GORNA rounds to {}", path.display());
+            Some(BufWriter::new(file))
+        }
+        Err(e) => {
+            log::warn!("DCC: cannot open GORNA capture {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,7 +451,7 @@ mod tests {
     use khora_core::telemetry::{MetricId, MetricValue};
 
     struct StubAgent {
-        budget_applied: bool,
+        budget_applied: Arc<AtomicBool>,
     }
 
     impl Agent for StubAgent {
@@ -365,7 +469,7 @@ mod tests {
             }
         }
         fn apply_budget(&mut self, _: ResourceBudget) {
-            self.budget_applied = true;
+            self.budget_applied.store(true, Ordering::SeqCst);
         }
         fn report_status(&self) -> AgentStatus {
             AgentStatus {
@@ -434,15 +538,24 @@ mod tests {
             tick_rate: 100,
             ..Default::default()
         });
-        let agent = Arc::new(std::sync::Mutex::new(StubAgent {
-            budget_applied: false,
-        }));
-        dcc.register_agent(agent.clone(), 1.0);
+        let budget_applied = Arc::new(AtomicBool::new(false));
+        dcc.register_agent(
+            Box::new(StubAgent {
+                budget_applied: Arc::clone(&budget_applied),
+            }),
+            1.0,
+        );
         dcc.start(rx);
 
-        thread::sleep(Duration::from_millis(200));
+        // Play the part of the main thread: service the agent's mailbox
+        // while the DCC negotiates.
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_millis(200) {
+            dcc.service_agent_mailboxes();
+            thread::sleep(Duration::from_millis(1));
+        }
 
-        let applied = agent.lock().unwrap().budget_applied;
+        let applied = budget_applied.load(Ordering::SeqCst);
         dcc.stop();
 
         assert!(
@@ -98,10 +98,11 @@ fn sort_systems(systems: &mut Vec<&'static DataSystemRegistration>) {
                 .filter_map(|name| by_name.get(name).copied())
                 .collect();
         }
-        Err(_) => {
+        Err(err) => {
             log::error!(
-                "substrate: cycle detected in DataSystem `runs_after` for phase {:?} — \
+                "substrate: {} in DataSystem `runs_after` for phase {:?} — \
                  falling back to (order_hint, name) order",
+                err,
                 systems.first().map(|s| s.phase),
             );
         }
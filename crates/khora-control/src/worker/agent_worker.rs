@@ -0,0 +1,155 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A long-lived thread executing one agent.
+
+use crossbeam_channel::{Receiver, Sender};
+use khora_core::agent::Agent;
+use khora_core::control::gorna::AgentId;
+use khora_core::lane::{LaneBus, OutputDeck};
+use khora_core::threading::{self, ThreadRole};
+use khora_core::{EngineContext, ServiceRegistry};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// One frame's worth of work for a worker agent.
+pub struct WorkerJob {
+    /// The agent to execute, lent by the registry until the job is done.
+    pub agent: Box<dyn Agent>,
+    /// The frame's service registry (including the per-frame overlay).
+    pub services: Arc<ServiceRegistry>,
+    /// The frame's Flow views, shared read-only with the frame thread.
+    pub bus: Arc<LaneBus>,
+}
+
+/// What a worker sends back to the frame barrier.
+pub struct WorkerResult {
+    /// The agent that ran.
+    pub agent_id: AgentId,
+    /// The agent itself, to be given back to the registry.
+    pub agent: Box<dyn Agent>,
+    /// The lane outputs the agent wrote this frame.
+    pub deck: OutputDeck,
+    /// Wall-clock time spent in `execute()`.
+    pub elapsed: Duration,
+    /// `true` if `execute()` panicked.
+    pub failed: bool,
+}
+
+/// A dedicated thread that runs one agent's `execute()` on request.
+///
+/// The thread lives until the worker is dropped, which closes the job
+/// channel and joins it.
+pub struct AgentWorker {
+    agent_id: AgentId,
+    jobs: Option<Sender<WorkerJob>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl AgentWorker {
+    /// Spawns the worker thread for `agent_id`, reporting to `results`.
+    pub fn spawn(agent_id: AgentId, results: Sender<WorkerResult>) -> std::io::Result<Self> {
+        let (jobs_tx, jobs_rx) = crossbeam_channel::unbounded();
+        let name = format!("khora-agent-{:?}", agent_id).to_lowercase();
+        let handle = threading::spawn_named(name, ThreadRole::Worker, move || {
+            run_worker(agent_id, jobs_rx, results)
+        })?;
+        Ok(Self {
+            agent_id,
+            jobs: Some(jobs_tx),
+            handle: Some(handle),
+        })
+    }
+
+    /// The agent this worker runs.
+    pub fn agent_id(&self) -> AgentId {
+        self.agent_id
+    }
+
+    /// Closes the job queue and waits until `deadline` for the thread to exit.
+    ///
+    /// Returns `false` if the thread was still running at the deadline; it is
+    /// then detached rather than joined.
+    pub fn stop(mut self, deadline: Instant) -> bool {
+        self.jobs.take();
+        let Some(handle) = self.handle.take() else {
+            return true;
+        };
+        while !handle.is_finished() {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let _ = handle.join();
+        true
+    }
+
+    /// Queues a job. Hands the job back if the worker thread is gone.
+    pub fn dispatch(&self, job: WorkerJob) -> Result<(), WorkerJob> {
+        match &self.jobs {
+            Some(tx) => tx.send(job).map_err(|e| e.into_inner()),
+            None => Err(job),
+        }
+    }
+}
+
+impl Drop for AgentWorker {
+    fn drop(&mut self) {
+        self.jobs.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_worker(agent_id: AgentId, jobs: Receiver<WorkerJob>, results: Sender<WorkerResult>) {
+    log::debug!("Agent worker for {:?} started.", agent_id);
+    for job in jobs {
+        let WorkerJob {
+            mut agent,
+            services,
+            bus,
+        } = job;
+        let mut deck = OutputDeck::new();
+        let start = Instant::now();
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut context = EngineContext {
+                world: None,
+                services,
+                bus: &bus,
+                deck: &mut deck,
+            };
+            agent.execute(&mut context);
+        }));
+        let failed = outcome.is_err();
+        if failed {
+            log::error!("Agent {:?} failed on its worker thread.", agent_id);
+        }
+
+        let result = WorkerResult {
+            agent_id,
+            agent,
+            deck,
+            elapsed: start.elapsed(),
+            failed,
+        };
+        if results.send(result).is_err() {
+            break;
+        }
+    }
+    log::debug!("Agent worker for {:?} stopped.", agent_id);
+}
@@ -0,0 +1,189 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The join point between worker agents and the frame thread.
+
+use super::agent_worker::{AgentWorker, WorkerJob, WorkerResult};
+use crossbeam_channel::{Receiver, Sender};
+use khora_core::agent::completion::{AgentCompletionMap, CompletionOutcome};
+use khora_core::agent::Agent;
+use khora_core::control::gorna::AgentId;
+use khora_core::lane::OutputDeck;
+use khora_data::ecs::{self, World, WorldChannel, WorldRequests};
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// Tracks the worker jobs in flight during a frame and joins them.
+///
+/// Results are folded into the frame's [`OutputDeck`] and the completion
+/// map as they are collected, so agents that hard-depend on a worker agent
+/// can wait for just that agent with [`FrameBarrier::wait_for`]. The
+/// agents themselves come back with their results and wait in the barrier
+/// until [`FrameBarrier::take_returned`] hands them back to their owner.
+///
+/// Worker agents reach the world through the barrier's [`WorldChannel`].
+/// Their requests are run whenever the frame thread waits here, and by
+/// [`FrameBarrier::serve_world`] between two frame-thread agents.
+pub struct FrameBarrier {
+    results_tx: Sender<WorkerResult>,
+    results_rx: Receiver<WorkerResult>,
+    world_tx: WorldChannel,
+    world_rx: WorldRequests,
+    pending: HashSet<AgentId>,
+    timings: Vec<(AgentId, Duration)>,
+    returned: Vec<Box<dyn Agent>>,
+}
+
+impl FrameBarrier {
+    /// Creates a barrier with no jobs in flight.
+    pub fn new() -> Self {
+        let (results_tx, results_rx) = crossbeam_channel::unbounded();
+        let (world_tx, world_rx) = ecs::world_channel();
+        Self {
+            results_tx,
+            results_rx,
+            world_tx,
+            world_rx,
+            pending: HashSet::new(),
+            timings: Vec::new(),
+            returned: Vec::new(),
+        }
+    }
+
+    /// The channel workers report to. Handed to [`AgentWorker::spawn`].
+    pub fn results_sender(&self) -> Sender<WorkerResult> {
+        self.results_tx.clone()
+    }
+
+    /// The channel worker agents send their world requests to.
+    pub fn world_channel(&self) -> WorldChannel {
+        self.world_tx.clone()
+    }
+
+    /// Runs the world requests sent so far. Returns the number run.
+    pub fn serve_world(&self, world: &mut World) -> usize {
+        self.world_rx.serve(world)
+    }
+
+    /// Dispatches `job` to `worker` and tracks it until joined.
+    ///
+    /// Hands the job back if the worker is gone; it is then not tracked.
+    pub fn dispatch(&mut self, worker: &AgentWorker, job: WorkerJob) -> Result<(), WorkerJob> {
+        worker.dispatch(job)?;
+        self.pending.insert(worker.agent_id());
+        Ok(())
+    }
+
+    /// Returns `true` if `id` has a job in flight.
+    pub fn is_pending(&self, id: AgentId) -> bool {
+        self.pending.contains(&id)
+    }
+
+    /// Number of jobs in flight.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Takes the agents of every job collected so far.
+    pub fn take_returned(&mut self) -> Vec<Box<dyn Agent>> {
+        std::mem::take(&mut self.returned)
+    }
+
+    /// Blocks until `id`'s job (if any) has been collected.
+    pub fn wait_for(
+        &mut self,
+        id: AgentId,
+        world: &mut World,
+        deck: &mut OutputDeck,
+        completion_map: &AgentCompletionMap,
+    ) {
+        while self.pending.contains(&id) {
+            if !self.collect_one(world, deck, completion_map) {
+                break;
+            }
+        }
+    }
+
+    /// Blocks until every job in flight has been collected.
+    ///
+    /// Returns the `execute()` time of every worker agent joined since the
+    /// previous call.
+    pub fn wait_all(
+        &mut self,
+        world: &mut World,
+        deck: &mut OutputDeck,
+        completion_map: &AgentCompletionMap,
+    ) -> Vec<(AgentId, Duration)> {
+        while !self.pending.is_empty() {
+            if !self.collect_one(world, deck, completion_map) {
+                break;
+            }
+        }
+        std::mem::take(&mut self.timings)
+    }
+
+    /// Waits for the next worker result, running world requests as they
+    /// come in. Returns `false` if no result can arrive anymore.
+    fn collect_one(
+        &mut self,
+        world: &mut World,
+        deck: &mut OutputDeck,
+        completion_map: &AgentCompletionMap,
+    ) -> bool {
+        // The barrier holds both senders itself, so neither channel ever
+        // disconnects; a stuck worker blocks here and is the watchdog's
+        // business.
+        let result = loop {
+            crossbeam_channel::select! {
+                recv(self.results_rx) -> result => break result,
+                recv(self.world_rx.receiver()) -> request => {
+                    if let Ok(request) = request {
+                        request(world);
+                    }
+                }
+            }
+        };
+        let Ok(result) = result else {
+            self.pending.clear();
+            return false;
+        };
+        self.returned.push(result.agent);
+        if !self.pending.remove(&result.agent_id) {
+            return true;
+        }
+
+        let dropped = deck.absorb(result.deck);
+        if dropped > 0 {
+            log::warn!(
+                "Agent {:?} wrote {} deck slot(s) already written on the frame thread; dropped.",
+                result.agent_id,
+                dropped
+            );
+        }
+        let outcome = if result.failed {
+            CompletionOutcome::Skipped
+        } else {
+            CompletionOutcome::Completed
+        };
+        completion_map.mark(result.agent_id, outcome);
+        self.timings.push((result.agent_id, result.elapsed));
+        true
+    }
+}
+
+impl Default for FrameBarrier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dedicated worker threads for agents with [`AgentAffinity::Worker`].
+//!
+//! Each worker agent gets its own long-lived thread. The scheduler dispatches
+//! the agent in its phase, keeps running the remaining agents on the frame
+//! thread, and joins every outstanding job at the [`FrameBarrier`] before the
+//! frame's output deck is handed to the I/O boundary.
+//!
+//! Worker agents get no `&mut World`. They find the barrier's
+//! [`WorldChannel`] in their services instead and send it the work that
+//! needs the world; the frame thread runs it between its own agents.
+//!
+//! [`WorldChannel`]: khora_data::ecs::WorldChannel
+//! [`AgentAffinity::Worker`]: khora_core::agent::AgentAffinity::Worker
+
+mod agent_worker;
+mod barrier;
+
+pub use agent_worker::{AgentWorker, WorkerJob, WorkerResult};
+pub use barrier::FrameBarrier;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::agent::completion::{AgentCompletionMap, CompletionOutcome};
+    use khora_core::agent::Agent;
+    use khora_core::control::gorna::{
+        AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    };
+    use khora_core::lane::{LaneBus, OutputDeck};
+    use khora_core::{EngineContext, ServiceRegistry};
+    use khora_data::ecs::{Transform, World, WorldChannel};
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct ThreadNote(Vec<String>);
+
+    struct WorkerProbe {
+        panic: bool,
+    }
+
+    impl Agent for WorkerProbe {
+        fn id(&self) -> AgentId {
+            AgentId::Audio
+        }
+
+        fn negotiate(&mut self, _: NegotiationRequest) -> NegotiationResponse {
+            NegotiationResponse {
+                strategies: Vec::new(),
+                timing_adjustment: None,
+            }
+        }
+
+        fn apply_budget(&mut self, _: ResourceBudget) {}
+
+        fn report_status(&self) -> AgentStatus {
+            AgentStatus {
+                agent_id: AgentId::Audio,
+                current_strategy: StrategyId::Balanced,
+                health_score: 1.0,
+                is_stalled: false,
+                message: String::new(),
+            }
+        }
+
+        fn execute(&mut self, context: &mut EngineContext<'_>) {
+            assert!(context.world.is_none());
+            if self.panic {
+                panic!("probe failure");
+            }
+            let name = std::thread::current()
+                .name()
+                .unwrap_or_default()
+                .to_string();
+            context.deck.slot::<ThreadNote>().0.push(name);
+            if let Some(channel) = context.services.get::<WorldChannel>() {
+                channel
+                    .with_world(|world| world.spawn(Transform::identity()))
+                    .expect("world request answered");
+            }
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    fn job(panic: bool, services: ServiceRegistry) -> WorkerJob {
+        WorkerJob {
+            agent: Box::new(WorkerProbe { panic }),
+            services: Arc::new(services),
+            bus: Arc::new(LaneBus::new()),
+        }
+    }
+
+    #[test]
+    fn barrier_collects_worker_output_and_marks_completion() {
+        let mut barrier = FrameBarrier::new();
+        let worker = AgentWorker::spawn(AgentId::Audio, barrier.results_sender()).unwrap();
+        let completion = AgentCompletionMap::new(&[AgentId::Audio]);
+        let mut world = World::new();
+        let mut deck = OutputDeck::new();
+
+        assert!(barrier
+            .dispatch(&worker, job(false, ServiceRegistry::new()))
+            .is_ok());
+        assert!(barrier.is_pending(AgentId::Audio));
+
+        let timings = barrier.wait_all(&mut world, &mut deck, &completion);
+        assert_eq!(timings.len(), 1);
+        assert_eq!(barrier.pending_len(), 0);
+        assert_eq!(
+            completion.outcome(AgentId::Audio),
+            Some(CompletionOutcome::Completed)
+        );
+        assert_eq!(deck.take::<ThreadNote>().0, vec!["khora-agent-audio"]);
+        assert_eq!(barrier.take_returned().len(), 1);
+    }
+
+    #[test]
+    fn panicking_worker_agent_is_marked_skipped() {
+        let mut barrier = FrameBarrier::new();
+        let worker = AgentWorker::spawn(AgentId::Audio, barrier.results_sender()).unwrap();
+        let completion = AgentCompletionMap::new(&[AgentId::Audio]);
+        let mut world = World::new();
+        let mut deck = OutputDeck::new();
+
+        assert!(barrier
+            .dispatch(&worker, job(true, ServiceRegistry::new()))
+            .is_ok());
+        barrier.wait_for(AgentId::Audio, &mut world, &mut deck, &completion);
+
+        assert!(!barrier.is_pending(AgentId::Audio));
+        assert_eq!(
+            completion.outcome(AgentId::Audio),
+            Some(CompletionOutcome::Skipped)
+        );
+        // The agent survives its panic and goes back to its owner.
+        let returned = barrier.take_returned();
+        assert_eq!(returned.len(), 1);
+        assert_eq!(returned[0].id(), AgentId::Audio);
+    }
+
+    #[test]
+    fn worker_agents_reach_the_world_while_the_barrier_waits() {
+        let mut barrier = FrameBarrier::new();
+        let worker = AgentWorker::spawn(AgentId::Audio, barrier.results_sender()).unwrap();
+        let completion = AgentCompletionMap::new(&[AgentId::Audio]);
+        let mut world = World::new();
+        let mut deck = OutputDeck::new();
+
+        let mut services = ServiceRegistry::new();
+        services.insert(barrier.world_channel());
+        assert!(barrier.dispatch(&worker, job(false, services)).is_ok());
+        barrier.wait_all(&mut world, &mut deck, &completion);
+
+        assert_eq!(
+            completion.outcome(AgentId::Audio),
+            Some(CompletionOutcome::Completed)
+        );
+        assert_eq!(world.query::<&Transform>().count(), 1);
+    }
+}
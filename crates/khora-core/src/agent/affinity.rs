@@ -0,0 +1,43 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Thread affinity declarations for agents.
+
+/// Which thread an agent's `execute()` runs on.
+///
+/// The scheduler dispatches [`AgentAffinity::Worker`] agents to a dedicated
+/// thread in their phase and joins them at the frame barrier, before the
+/// output deck reaches the I/O boundary (submit, present).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum AgentAffinity {
+    /// Runs inline on the thread driving the frame, with full `World` access.
+    #[default]
+    MainThread,
+    /// Runs on the agent's own worker thread, concurrently with later agents.
+    ///
+    /// Worker agents receive no `World` (`EngineContext::world` is `None`):
+    /// they consume Flow views from the bus, send the work that needs the
+    /// world through the frame's `WorldChannel` service, and write lane
+    /// outputs into a private deck that is merged into the frame deck at the
+    /// barrier.
+    Worker,
+}
+
+impl AgentAffinity {
+    /// Returns `true` if the agent runs off the frame thread.
+    pub fn is_worker(self) -> bool {
+        matches!(self, AgentAffinity::Worker)
+    }
+}
@@ -14,6 +14,7 @@
 
 //! Traits for autonomous engine subsystems (Agents).
 
+pub mod affinity;
 pub mod completion;
 pub mod dependency;
 pub mod execution_phase;
@@ -25,10 +26,11 @@ use crate::control::gorna::{AgentStatus, NegotiationRequest, NegotiationResponse
 use crate::EngineContext;
 use std::any::Any;
 
+pub use affinity::AgentAffinity;
 pub use completion::{AgentCompletionMap, AgentDone, CompletionOutcome};
 pub use dependency::{AgentDependency, DependencyCondition, DependencyKind};
 pub use execution_phase::ExecutionPhase;
-pub use mode::EngineMode;
+pub use mode::{EngineMode, BACKGROUND_MODE_NAME};
 pub use timing::{AgentImportance, ExecutionTiming};
 
 /// The foundational interface for an Intelligent Subsystem Agent (ISA).
@@ -71,6 +73,14 @@ pub trait Agent: Send + Sync {
     /// Default implementation is a no-op.
     fn on_initialize(&mut self, _context: &mut EngineContext<'_>) {}
 
+    /// Called **once** during engine shutdown, after the last frame.
+    ///
+    /// Agents are shut down in reverse priority order while the graphics
+    /// device is still alive, so this is where lanes release their GPU
+    /// resources via [`Lane::on_shutdown()`](crate::lane::Lane::on_shutdown).
+    /// Default implementation is a no-op.
+    fn on_shutdown(&mut self, _context: &mut EngineContext<'_>) {}
+
     /// Called **every frame** by the engine loop.
     ///
     /// The agent selects the appropriate lanes based on the current GORNA
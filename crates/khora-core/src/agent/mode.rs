@@ -14,14 +14,20 @@
 
 //! Engine execution modes.
 //!
-//! The base engine only knows about **Playing** mode.
+//! The base engine only knows about **Playing** mode, plus the
+//! `"background"` custom mode it enters while suspended.
 //! Plugins inject their own modes via `Custom(String)`.
 
+use serde::{Deserialize, Serialize};
+
+/// Name of the built-in mode used while the engine is suspended.
+pub const BACKGROUND_MODE_NAME: &str = "background";
+
 /// The current mode of the engine.
 ///
 /// Different modes activate different agents and change rendering behavior.
 /// The base engine only defines `Playing`; other modes are injected by plugins.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EngineMode {
     /// Simulation mode — scene cameras, physics, audio, ECS snapshot.
     Playing,
@@ -39,6 +45,18 @@ impl EngineMode {
         }
     }
 
+    /// The mode the engine drops into while its window is minimized or
+    /// occluded: no frames are rendered and every agent is parked at its
+    /// lowest-cost strategy.
+    pub fn background() -> Self {
+        EngineMode::Custom(BACKGROUND_MODE_NAME.to_string())
+    }
+
+    /// Returns `true` for the [`background`](Self::background) mode.
+    pub fn is_background(&self) -> bool {
+        matches!(self, EngineMode::Custom(s) if s == BACKGROUND_MODE_NAME)
+    }
+
     /// Returns a human-readable name for this mode.
     pub fn name(&self) -> &str {
         match self {
@@ -16,6 +16,7 @@
 
 use std::time::Duration;
 
+use super::affinity::AgentAffinity;
 use super::dependency::AgentDependency;
 use super::execution_phase::ExecutionPhase;
 
@@ -41,6 +42,9 @@ pub struct ExecutionTiming {
     pub fixed_timestep: Option<Duration>,
     /// Dependencies on other agents.
     pub dependencies: Vec<AgentDependency>,
+    /// Thread the agent executes on. Can be overridden per agent at
+    /// registration time via `AgentRegistry::set_affinity()`.
+    pub affinity: AgentAffinity,
 }
 
 impl Default for ExecutionTiming {
@@ -52,6 +56,7 @@ impl Default for ExecutionTiming {
             importance: AgentImportance::Important,
             fixed_timestep: None,
             dependencies: Vec::new(),
+            affinity: AgentAffinity::MainThread,
         }
     }
 }
@@ -0,0 +1,73 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpolation contract for animated values.
+
+use crate::math::{LinearRgba, Quaternion, Vec2, Vec3};
+
+/// A value that can be blended between two keyframes.
+pub trait Animatable: Clone {
+    /// Interpolates from `a` to `b` by `t` in `[0.0, 1.0]`.
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self;
+}
+
+impl Animatable for f32 {
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        a + (b - a) * t
+    }
+}
+
+impl Animatable for Vec2 {
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        Vec2::lerp(*a, *b, t)
+    }
+}
+
+impl Animatable for Vec3 {
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        Vec3::lerp(*a, *b, t)
+    }
+}
+
+impl Animatable for Quaternion {
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        Quaternion::slerp(*a, *b, t)
+    }
+}
+
+impl Animatable for LinearRgba {
+    #[inline]
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        LinearRgba::lerp(*a, *b, t)
+    }
+}
+
+/// Per-element blend, used for morph target weight sets. A shorter side is
+/// treated as padded with zeros.
+impl Animatable for Vec<f32> {
+    fn interpolate(a: &Self, b: &Self, t: f32) -> Self {
+        let len = a.len().max(b.len());
+        (0..len)
+            .map(|i| {
+                let x = a.get(i).copied().unwrap_or(0.0);
+                let y = b.get(i).copied().unwrap_or(0.0);
+                x + (y - x) * t
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Blend spaces — clips placed in a 1D or 2D parameter space and blended by
+//! proximity to the current parameter values.
+
+use super::{AnimationClip, AnimationParameters};
+use crate::asset::AssetHandle;
+use crate::math::Vec2;
+
+/// Clips laid out along one parameter axis (e.g. `speed`: idle, walk, run).
+///
+/// The two clips bracketing the parameter value are blended linearly;
+/// values outside the range clamp to the nearest end.
+#[derive(Debug, Clone, Default)]
+pub struct BlendSpace1D {
+    /// Name of the driving parameter.
+    pub parameter: String,
+    points: Vec<(f32, AssetHandle<AnimationClip>)>,
+}
+
+impl BlendSpace1D {
+    /// Creates an empty blend space driven by `parameter`.
+    pub fn new(parameter: impl Into<String>) -> Self {
+        Self {
+            parameter: parameter.into(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Places `clip` at `position` on the axis.
+    pub fn with_clip(mut self, position: f32, clip: AssetHandle<AnimationClip>) -> Self {
+        let index = self.points.partition_point(|(p, _)| *p <= position);
+        self.points.insert(index, (position, clip));
+        self
+    }
+
+    /// Returns the clips contributing at `params`, with weights summing to one.
+    pub fn weights(&self, params: &AnimationParameters) -> Vec<(&AssetHandle<AnimationClip>, f32)> {
+        let value = params.float(&self.parameter);
+        let upper = self.points.partition_point(|(p, _)| *p < value);
+        match (upper.checked_sub(1), self.points.get(upper)) {
+            (None, Some((_, clip))) => vec![(clip, 1.0)],
+            (Some(lower), None) => vec![(&self.points[lower].1, 1.0)],
+            (Some(lower), Some((hi, hi_clip))) => {
+                let (lo, lo_clip) = &self.points[lower];
+                let t = if hi > lo {
+                    (value - lo) / (hi - lo)
+                } else {
+                    1.0
+                };
+                vec![(lo_clip, 1.0 - t), (hi_clip, t)]
+            }
+            (None, None) => Vec::new(),
+        }
+    }
+}
+
+/// Clips laid out on a two-parameter plane (e.g. `direction_x`,
+/// `direction_y` for strafing locomotion).
+///
+/// Weights use inverse-distance weighting; a clip placed exactly at the
+/// parameter position takes the full weight.
+#[derive(Debug, Clone, Default)]
+pub struct BlendSpace2D {
+    /// Name of the parameter on the horizontal axis.
+    pub parameter_x: String,
+    /// Name of the parameter on the vertical axis.
+    pub parameter_y: String,
+    points: Vec<(Vec2, AssetHandle<AnimationClip>)>,
+}
+
+impl BlendSpace2D {
+    /// Creates an empty blend space driven by `parameter_x` and `parameter_y`.
+    pub fn new(parameter_x: impl Into<String>, parameter_y: impl Into<String>) -> Self {
+        Self {
+            parameter_x: parameter_x.into(),
+            parameter_y: parameter_y.into(),
+            points: Vec::new(),
+        }
+    }
+
+    /// Places `clip` at `position` on the plane.
+    pub fn with_clip(mut self, position: Vec2, clip: AssetHandle<AnimationClip>) -> Self {
+        self.points.push((position, clip));
+        self
+    }
+
+    /// Returns the clips contributing at `params`, with weights summing to one.
+    pub fn weights(&self, params: &AnimationParameters) -> Vec<(&AssetHandle<AnimationClip>, f32)> {
+        let at = Vec2::new(
+            params.float(&self.parameter_x),
+            params.float(&self.parameter_y),
+        );
+        let mut weights = Vec::with_capacity(self.points.len());
+        for (position, clip) in &self.points {
+            let d = *position - at;
+            let distance_sq = d.x * d.x + d.y * d.y;
+            if distance_sq <= f32::EPSILON {
+                return vec![(clip, 1.0)];
+            }
+            weights.push((clip, 1.0 / distance_sq));
+        }
+        let total: f32 = weights.iter().map(|(_, w)| w).sum();
+        for (_, w) in &mut weights {
+            *w /= total;
+        }
+        weights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clip(name: &str) -> AssetHandle<AnimationClip> {
+        AssetHandle::new(AnimationClip::new(name))
+    }
+
+    #[test]
+    fn test_blend_space_1d_brackets_parameter() {
+        let space = BlendSpace1D::new("speed")
+            .with_clip(0.0, clip("idle"))
+            .with_clip(4.0, clip("run"))
+            .with_clip(2.0, clip("walk"));
+        let mut params = AnimationParameters::new();
+
+        params.set_float("speed", 3.0);
+        let weights = space.weights(&params);
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights[0].0.name, "walk");
+        assert!((weights[0].1 - 0.5).abs() < 1e-6);
+
+        params.set_float("speed", 10.0);
+        let weights = space.weights(&params);
+        assert_eq!(weights.len(), 1);
+        assert_eq!(weights[0].0.name, "run");
+    }
+
+    #[test]
+    fn test_blend_space_2d_exact_point_wins() {
+        let space = BlendSpace2D::new("x", "y")
+            .with_clip(Vec2::new(0.0, 1.0), clip("forward"))
+            .with_clip(Vec2::new(1.0, 0.0), clip("right"));
+        let mut params = AnimationParameters::new();
+        params.set_float("y", 1.0);
+
+        let weights = space.weights(&params);
+        assert_eq!(weights.len(), 1);
+        assert_eq!(weights[0].0.name, "forward");
+
+        params.set_float("x", 0.5);
+        params.set_float("y", 0.5);
+        let total: f32 = space.weights(&params).iter().map(|(_, w)| w).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+}
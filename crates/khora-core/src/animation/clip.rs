@@ -0,0 +1,122 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Animation clips — named sets of tracks played as a unit.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::{AnimationTrack, SampledValue, TrackValues};
+use crate::asset::Asset;
+use crate::math::Vec3;
+
+/// A reusable animation: a set of [`AnimationTrack`]s sharing one timeline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct AnimationClip {
+    /// Human-readable clip name.
+    pub name: String,
+    /// Tracks in the clip.
+    pub tracks: Vec<AnimationTrack>,
+}
+
+impl Asset for AnimationClip {}
+
+impl AnimationClip {
+    /// Creates an empty clip.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tracks: Vec::new(),
+        }
+    }
+
+    /// Adds a track targeting the entity that plays the clip.
+    pub fn with_track(mut self, values: TrackValues) -> Self {
+        self.tracks.push(AnimationTrack {
+            target: None,
+            values,
+        });
+        self
+    }
+
+    /// Adds a track targeting the descendant named `target`.
+    pub fn with_target_track(mut self, target: impl Into<String>, values: TrackValues) -> Self {
+        self.tracks.push(AnimationTrack {
+            target: Some(target.into()),
+            values,
+        });
+        self
+    }
+
+    /// Returns the length of the longest track, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.tracks
+            .iter()
+            .map(|t| t.values.duration())
+            .fold(0.0, f32::max)
+    }
+
+    /// Samples the first translation track targeting `target` at `time`
+    /// seconds. `None` targets the entity playing the clip.
+    pub fn root_translation(&self, target: Option<&str>, time: f32) -> Option<Vec3> {
+        self.tracks
+            .iter()
+            .filter(|t| t.target.as_deref() == target)
+            .find_map(|t| match &t.values {
+                TrackValues::Translation(curve) => curve.sample(time),
+                _ => None,
+            })
+    }
+
+    /// Samples every track at `time` seconds, yielding each track's target
+    /// alongside its value.
+    pub fn sample(&self, time: f32) -> impl Iterator<Item = (Option<&str>, SampledValue)> + '_ {
+        self.tracks
+            .iter()
+            .filter_map(move |t| Some((t.target.as_deref(), t.values.sample(time)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Curve;
+    use crate::math::Vec3;
+
+    #[test]
+    fn test_clip_samples_all_tracks() {
+        let clip = AnimationClip::new("blink")
+            .with_track(TrackValues::Translation(Curve::new([
+                (0.0, Vec3::ZERO),
+                (1.0, Vec3::new(2.0, 0.0, 0.0)),
+            ])))
+            .with_target_track(
+                "face",
+                TrackValues::MorphWeights(Curve::new([(0.0, vec![0.0]), (2.0, vec![1.0, 0.5])])),
+            );
+
+        assert_eq!(clip.duration(), 2.0);
+
+        let samples: Vec<_> = clip.sample(1.0).collect();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(
+            samples[0],
+            (None, SampledValue::Translation(Vec3::new(2.0, 0.0, 0.0)))
+        );
+        assert_eq!(
+            samples[1],
+            (Some("face"), SampledValue::MorphWeights(vec![0.5, 0.25]))
+        );
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keyframed curves.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::Animatable;
+
+/// How a [`Curve`] fills the gap between two keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum Interpolation {
+    /// Hold the previous keyframe's value until the next one.
+    Step,
+    /// Blend linearly (spherically for rotations) between keyframes.
+    #[default]
+    Linear,
+}
+
+/// A single sample point on a [`Curve`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct Keyframe<T> {
+    /// Time of the keyframe, in seconds from the start of the curve.
+    pub time: f32,
+    /// Value at `time`.
+    pub value: T,
+}
+
+/// A sequence of keyframes sorted by time.
+///
+/// Sampling before the first keyframe returns the first value, and after the
+/// last keyframe returns the last value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct Curve<T> {
+    keyframes: Vec<Keyframe<T>>,
+    /// Interpolation mode between consecutive keyframes.
+    pub interpolation: Interpolation,
+}
+
+impl<T> Default for Curve<T> {
+    fn default() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            interpolation: Interpolation::default(),
+        }
+    }
+}
+
+impl<T: Animatable> Curve<T> {
+    /// Creates a linear curve from `(time, value)` pairs, sorting them by time.
+    pub fn new(keyframes: impl IntoIterator<Item = (f32, T)>) -> Self {
+        let mut curve = Self::default();
+        for (time, value) in keyframes {
+            curve.insert(time, value);
+        }
+        curve
+    }
+
+    /// Sets the interpolation mode.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Inserts a keyframe, keeping the keyframes sorted. A keyframe at an
+    /// existing time replaces it.
+    pub fn insert(&mut self, time: f32, value: T) {
+        match self.keyframes.binary_search_by(|k| k.time.total_cmp(&time)) {
+            Ok(i) => self.keyframes[i].value = value,
+            Err(i) => self.keyframes.insert(i, Keyframe { time, value }),
+        }
+    }
+
+    /// Returns the keyframes in time order.
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Returns `true` if the curve has no keyframes.
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    /// Returns the time of the last keyframe, or `0.0` for an empty curve.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |k| k.time)
+    }
+
+    /// Samples the curve at `time` seconds. Returns `None` for an empty curve.
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if time <= first.time {
+            return Some(first.value.clone());
+        }
+        if time >= last.time {
+            return Some(last.value.clone());
+        }
+
+        // First keyframe strictly after `time`; guaranteed in 1..len here.
+        let next = self.keyframes.partition_point(|k| k.time <= time);
+        let a = &self.keyframes[next - 1];
+        let b = &self.keyframes[next];
+        match self.interpolation {
+            Interpolation::Step => Some(a.value.clone()),
+            Interpolation::Linear => {
+                let span = b.time - a.time;
+                let t = if span > 0.0 {
+                    (time - a.time) / span
+                } else {
+                    0.0
+                };
+                Some(T::interpolate(&a.value, &b.value, t))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_clamps_outside_range() {
+        let curve = Curve::new([(1.0, 10.0f32), (2.0, 20.0)]);
+        assert_eq!(curve.sample(0.0), Some(10.0));
+        assert_eq!(curve.sample(5.0), Some(20.0));
+        assert_eq!(Curve::<f32>::default().sample(0.0), None);
+    }
+
+    #[test]
+    fn test_sample_linear_and_step() {
+        let curve = Curve::new([(0.0, 0.0f32), (2.0, 10.0), (1.0, 4.0)]);
+        assert_eq!(curve.duration(), 2.0);
+        assert_eq!(curve.sample(0.5), Some(2.0));
+        assert_eq!(curve.sample(1.5), Some(7.0));
+
+        let step = curve.with_interpolation(Interpolation::Step);
+        assert_eq!(step.sample(1.5), Some(4.0));
+    }
+
+    #[test]
+    fn test_insert_replaces_existing_time() {
+        let mut curve = Curve::new([(0.0, 1.0f32)]);
+        curve.insert(0.0, 3.0);
+        assert_eq!(curve.keyframes().len(), 1);
+        assert_eq!(curve.sample(0.0), Some(3.0));
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Easing functions shaping normalized progress.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// How progress accelerates between the start and the end of a motion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Starts slow and accelerates.
+    EaseIn,
+    /// Starts fast and decelerates.
+    EaseOut,
+    /// Accelerates, then decelerates.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps `t` in `[0.0, 1.0]` to eased progress in `[0.0, 1.0]`. `t` is
+    /// clamped first.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Animation graphs — state machines whose states play clips or blend spaces.
+
+use super::{
+    AnimationClip, AnimationParameters, BlendSpace1D, BlendSpace2D, Pose, SampledValue, Transition,
+};
+use crate::asset::{Asset, AssetHandle};
+use crate::math::Vec3;
+
+/// What a state plays.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Motion {
+    /// A single clip.
+    Clip(AssetHandle<AnimationClip>),
+    /// Clips blended along one parameter.
+    BlendSpace1D(BlendSpace1D),
+    /// Clips blended on a two-parameter plane.
+    BlendSpace2D(BlendSpace2D),
+}
+
+impl Motion {
+    /// Returns the contributing clips and their weights at `params`.
+    pub fn weights(&self, params: &AnimationParameters) -> Vec<(&AssetHandle<AnimationClip>, f32)> {
+        match self {
+            Self::Clip(clip) => vec![(clip, 1.0)],
+            Self::BlendSpace1D(space) => space.weights(params),
+            Self::BlendSpace2D(space) => space.weights(params),
+        }
+    }
+
+    /// Returns the weighted duration of the contributing clips, in seconds.
+    ///
+    /// Blended clips are time-warped onto this shared cycle so that, e.g.,
+    /// walk and run footfalls stay in phase.
+    pub fn duration(&self, params: &AnimationParameters) -> f32 {
+        self.weights(params)
+            .iter()
+            .map(|(clip, w)| clip.duration() * w)
+            .sum()
+    }
+
+    /// Returns the at most `max_clips` heaviest contributing clips, with
+    /// weights renormalized to sum to one.
+    fn dominant_weights(
+        &self,
+        params: &AnimationParameters,
+        max_clips: usize,
+    ) -> Vec<(&AssetHandle<AnimationClip>, f32)> {
+        let mut weights = self.weights(params);
+        if weights.len() > max_clips {
+            weights.sort_by(|a, b| b.1.total_cmp(&a.1));
+            weights.truncate(max_clips);
+        }
+        let total: f32 = weights.iter().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+        for (_, w) in &mut weights {
+            *w /= total;
+        }
+        weights
+    }
+
+    /// Samples the motion at `normalized_time` into `pose` with `weight`.
+    ///
+    /// At most `max_clips` clips contribute, keeping the heaviest ones.
+    pub fn sample_into(
+        &self,
+        pose: &mut Pose,
+        normalized_time: f32,
+        weight: f32,
+        params: &AnimationParameters,
+        max_clips: usize,
+    ) {
+        for (clip, w) in self.dominant_weights(params, max_clips) {
+            pose.add_clip(clip, normalized_time * clip.duration(), weight * w);
+        }
+    }
+
+    /// Like [`sample_into`](Self::sample_into), but pins the horizontal
+    /// (XZ) translation of the `root` track to its value at the start of
+    /// each clip, leaving the displacement to
+    /// [`root_displacement`](Self::root_displacement).
+    pub fn sample_into_without_root_motion(
+        &self,
+        pose: &mut Pose,
+        normalized_time: f32,
+        weight: f32,
+        params: &AnimationParameters,
+        max_clips: usize,
+        root: &str,
+    ) {
+        for (clip, w) in self.dominant_weights(params, max_clips) {
+            let rest = clip.root_translation(Some(root), 0.0);
+            for (target, value) in clip.sample(normalized_time * clip.duration()) {
+                let value = match (value, rest) {
+                    (SampledValue::Translation(v), Some(rest)) if target == Some(root) => {
+                        SampledValue::Translation(Vec3::new(rest.x, v.y, rest.z))
+                    }
+                    (value, _) => value,
+                };
+                pose.add(target, value, weight * w);
+            }
+        }
+    }
+
+    /// Returns the horizontal (XZ) displacement of the `root` track between
+    /// two normalized times, expressed in the root's parent space.
+    ///
+    /// `cycles` is the number of times playback wrapped around in between:
+    /// `1` when a looping state passed its end going forward, `-1` going
+    /// backward, `0` otherwise.
+    pub fn root_displacement(
+        &self,
+        from: f32,
+        to: f32,
+        cycles: i32,
+        params: &AnimationParameters,
+        max_clips: usize,
+        root: &str,
+    ) -> Vec3 {
+        let mut total = Vec3::ZERO;
+        for (clip, w) in self.dominant_weights(params, max_clips) {
+            let duration = clip.duration();
+            let at = |t: f32| clip.root_translation(Some(root), t * duration);
+            let (Some(a), Some(b)) = (at(from), at(to)) else {
+                continue;
+            };
+            let mut delta = b - a;
+            if cycles != 0 {
+                if let (Some(start), Some(end)) = (at(0.0), at(1.0)) {
+                    delta = delta + (end - start) * cycles as f32;
+                }
+            }
+            total = total + Vec3::new(delta.x, 0.0, delta.z) * w;
+        }
+        total
+    }
+}
+
+/// A node of an [`AnimationGraph`].
+#[derive(Debug, Clone)]
+pub struct AnimationState {
+    /// Unique name, referenced by [`Transition`]s.
+    pub name: String,
+    /// What the state plays.
+    pub motion: Motion,
+    /// Playback rate multiplier.
+    pub speed: f32,
+    /// Wrap around at the end instead of holding the last pose.
+    pub looping: bool,
+    /// Play the root bone's horizontal motion in place and extract it as a
+    /// displacement instead. Requires [`AnimationGraph::with_root_bone`].
+    pub root_motion: bool,
+}
+
+impl AnimationState {
+    /// Creates a looping state playing `motion` at normal speed.
+    pub fn new(name: impl Into<String>, motion: Motion) -> Self {
+        Self {
+            name: name.into(),
+            motion,
+            speed: 1.0,
+            looping: true,
+            root_motion: false,
+        }
+    }
+
+    /// Sets the playback rate multiplier.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Plays the state once and holds the last pose.
+    pub fn once(mut self) -> Self {
+        self.looping = false;
+        self
+    }
+
+    /// Enables root motion extraction for this state.
+    pub fn with_root_motion(mut self) -> Self {
+        self.root_motion = true;
+        self
+    }
+
+    /// Returns the normalized time reached after advancing `normalized_time`
+    /// by `delta_seconds`.
+    pub fn advance(
+        &self,
+        normalized_time: f32,
+        delta_seconds: f32,
+        params: &AnimationParameters,
+    ) -> f32 {
+        let duration = self.motion.duration(params);
+        if duration <= 0.0 {
+            return 0.0;
+        }
+        let t = normalized_time + delta_seconds * self.speed / duration;
+        if self.looping {
+            t.rem_euclid(1.0)
+        } else {
+            t.clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// A state machine over [`AnimationState`]s, evaluated per entity by the
+/// animation lane against each `Animator`'s parameters.
+#[derive(Debug, Clone)]
+pub struct AnimationGraph {
+    states: Vec<AnimationState>,
+    transitions: Vec<Transition>,
+    entry: usize,
+    root_bone: Option<String>,
+}
+
+impl Asset for AnimationGraph {}
+
+impl AnimationGraph {
+    /// Creates a graph whose entry state is `entry`.
+    pub fn new(entry: AnimationState) -> Self {
+        Self {
+            states: vec![entry],
+            transitions: Vec::new(),
+            entry: 0,
+            root_bone: None,
+        }
+    }
+
+    /// Adds a state. A state with the same name is replaced.
+    pub fn with_state(mut self, state: AnimationState) -> Self {
+        match self.state_index(&state.name) {
+            Some(index) => self.states[index] = state,
+            None => self.states.push(state),
+        }
+        self
+    }
+
+    /// Adds a transition. Transitions are tested in insertion order.
+    pub fn with_transition(mut self, transition: Transition) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    /// Sets the descendant whose translation track carries root motion.
+    ///
+    /// The extracted displacement is meant to move the animated entity
+    /// itself, so the root bone must be one of its descendants.
+    pub fn with_root_bone(mut self, name: impl Into<String>) -> Self {
+        self.root_bone = Some(name.into());
+        self
+    }
+
+    /// Returns the root bone name, if root motion is configured.
+    pub fn root_bone(&self) -> Option<&str> {
+        self.root_bone.as_deref()
+    }
+
+    /// Returns the index of the entry state.
+    pub fn entry(&self) -> usize {
+        self.entry
+    }
+
+    /// Returns the state at `index`.
+    pub fn state(&self, index: usize) -> Option<&AnimationState> {
+        self.states.get(index)
+    }
+
+    /// Returns the index of the state named `name`.
+    pub fn state_index(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|s| s.name == name)
+    }
+
+    /// Returns all states.
+    pub fn states(&self) -> &[AnimationState] {
+        &self.states
+    }
+
+    /// Returns all transitions.
+    pub fn transitions(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    /// Returns the first transition out of the state at `index` that fires
+    /// given `params` and the state's normalized time, with the index of its
+    /// destination. Transitions into the current state are ignored.
+    pub fn next_transition(
+        &self,
+        index: usize,
+        normalized_time: f32,
+        params: &AnimationParameters,
+    ) -> Option<(&Transition, usize)> {
+        let current = self.states.get(index)?;
+        self.transitions
+            .iter()
+            .filter(|t| t.leaves(&current.name) && t.is_satisfied(params, normalized_time))
+            .find_map(|t| {
+                self.state_index(&t.to)
+                    .filter(|&to| to != index)
+                    .map(|to| (t, to))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Condition;
+
+    fn clip_state(name: &str) -> AnimationState {
+        AnimationState::new(
+            name,
+            Motion::Clip(AssetHandle::new(AnimationClip::new(name))),
+        )
+    }
+
+    #[test]
+    fn test_graph_transition_conditions() {
+        let graph = AnimationGraph::new(clip_state("idle"))
+            .with_state(clip_state("run"))
+            .with_state(clip_state("jump"))
+            .with_transition(
+                Transition::new("idle", "run").when(Condition::Greater("speed".into(), 0.1)),
+            )
+            .with_transition(Transition::from_any("jump").when(Condition::Trigger("jump".into())));
+        let mut params = AnimationParameters::new();
+        assert!(graph.next_transition(0, 0.0, &params).is_none());
+
+        params.set_float("speed", 1.0);
+        let (_, to) = graph.next_transition(0, 0.0, &params).unwrap();
+        assert_eq!(graph.state(to).unwrap().name, "run");
+
+        params.set_trigger("jump");
+        let (transition, to) = graph.next_transition(1, 0.0, &params).unwrap();
+        assert_eq!(graph.state(to).unwrap().name, "jump");
+        transition.consume_triggers(&mut params);
+        assert!(!params.is_triggered("jump"));
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Inverse kinematics solvers operating on world-space joint positions.
+//!
+//! Solvers only move joint positions; converting the result back into
+//! joint rotations is left to the caller, which owns the hierarchy.
+
+use crate::math::Vec3;
+
+/// Solves a two-bone chain (`root` → `mid` → `end`) towards `target`.
+///
+/// Bone lengths are preserved. The bend plane is the one containing `pole`
+/// when given, otherwise the chain's current bend is kept. Unreachable
+/// targets fully extend the chain towards them.
+///
+/// Returns the new `(mid, end)` positions; `root` does not move.
+pub fn solve_two_bone(
+    root: Vec3,
+    mid: Vec3,
+    end: Vec3,
+    target: Vec3,
+    pole: Option<Vec3>,
+) -> (Vec3, Vec3) {
+    let upper = root.distance(mid);
+    let lower = mid.distance(end);
+    let to_target = target - root;
+    let reach = to_target
+        .length()
+        .clamp((upper - lower).abs(), upper + lower);
+    let dir = to_target.normalize();
+    if dir == Vec3::ZERO || upper <= 0.0 || lower <= 0.0 {
+        return (mid, end);
+    }
+
+    // Bend direction: the component of the pole (or current mid joint)
+    // perpendicular to the root → target axis.
+    let hint = pole.unwrap_or(mid) - root;
+    let mut bend = (hint - dir * hint.dot(dir)).normalize();
+    if bend == Vec3::ZERO {
+        let fallback = if dir.y.abs() < 0.99 {
+            Vec3::new(0.0, 1.0, 0.0)
+        } else {
+            Vec3::new(1.0, 0.0, 0.0)
+        };
+        bend = (fallback - dir * fallback.dot(dir)).normalize();
+    }
+
+    // Law of cosines for the angle at the root joint.
+    let cos_root =
+        ((upper * upper + reach * reach - lower * lower) / (2.0 * upper * reach)).clamp(-1.0, 1.0);
+    let sin_root = (1.0 - cos_root * cos_root).sqrt();
+    let new_mid = root + dir * (upper * cos_root) + bend * (upper * sin_root);
+    let new_end = root + dir * reach;
+    (new_mid, new_end)
+}
+
+/// Solves a chain of joints towards `target` with FABRIK (Forward And
+/// Backward Reaching Inverse Kinematics).
+///
+/// `joints[0]` is the fixed root and the last entry is the end effector.
+/// Segment lengths are preserved. Iterates until the effector is within
+/// `tolerance` of the target or `max_iterations` is reached.
+///
+/// Returns `true` if the target was reached.
+pub fn solve_fabrik(
+    joints: &mut [Vec3],
+    target: Vec3,
+    tolerance: f32,
+    max_iterations: usize,
+) -> bool {
+    let n = joints.len();
+    if n < 2 {
+        return false;
+    }
+    let lengths: Vec<f32> = joints.windows(2).map(|w| w[0].distance(w[1])).collect();
+    let root = joints[0];
+
+    // Unreachable: straighten the chain towards the target.
+    if root.distance(target) >= lengths.iter().sum::<f32>() {
+        let dir = (target - root).normalize();
+        for i in 1..n {
+            joints[i] = joints[i - 1] + dir * lengths[i - 1];
+        }
+        return false;
+    }
+
+    for _ in 0..max_iterations {
+        if joints[n - 1].distance(target) <= tolerance {
+            return true;
+        }
+        // Backward pass: pin the effector to the target.
+        joints[n - 1] = target;
+        for i in (0..n - 1).rev() {
+            let dir = (joints[i] - joints[i + 1]).normalize();
+            joints[i] = joints[i + 1] + dir * lengths[i];
+        }
+        // Forward pass: pin the root back in place.
+        joints[0] = root;
+        for i in 1..n {
+            let dir = (joints[i] - joints[i - 1]).normalize();
+            joints[i] = joints[i - 1] + dir * lengths[i - 1];
+        }
+    }
+    joints[n - 1].distance(target) <= tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_bone_reaches_target() {
+        let root = Vec3::ZERO;
+        let mid = Vec3::new(1.0, 0.0, 0.0);
+        let end = Vec3::new(2.0, 0.0, 0.0);
+        let target = Vec3::new(1.0, 1.0, 0.0);
+
+        let (new_mid, new_end) =
+            solve_two_bone(root, mid, end, target, Some(Vec3::new(0.0, 0.0, 1.0)));
+        assert!(new_end.distance(target) < 1e-4);
+        assert!((new_mid.distance(root) - 1.0).abs() < 1e-4);
+        assert!((new_mid.distance(new_end) - 1.0).abs() < 1e-4);
+        assert!(new_mid.z > 0.0);
+    }
+
+    #[test]
+    fn test_fabrik_preserves_lengths() {
+        let mut joints = [
+            Vec3::ZERO,
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.0),
+        ];
+        let target = Vec3::new(1.5, 1.5, 0.0);
+
+        assert!(solve_fabrik(&mut joints, target, 1e-3, 32));
+        assert_eq!(joints[0], Vec3::ZERO);
+        for pair in joints.windows(2) {
+            assert!((pair[0].distance(pair[1]) - 1.0).abs() < 1e-3);
+        }
+    }
+}
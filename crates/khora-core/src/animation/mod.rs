@@ -0,0 +1,49 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Animation primitives shared by the data layer and the lanes.
+//!
+//! Provides keyframed [`Curve`]s over any [`Animatable`] value,
+//! [`AnimationClip`]s grouping curves into tracks that drive entity
+//! properties, [`AnimationGraph`]s blending clips through a state
+//! machine and blend spaces, [`ik`] solvers adjusting the result, and
+//! [`Timeline`]s sequencing cutscenes. [`Spline`]s and [`Easing`] shape
+//! paths that entities follow.
+
+mod animatable;
+mod blend_space;
+mod clip;
+mod curve;
+mod easing;
+mod graph;
+pub mod ik;
+mod parameters;
+mod pose;
+mod spline;
+mod timeline;
+mod track;
+mod transition;
+
+pub use animatable::*;
+pub use blend_space::*;
+pub use clip::*;
+pub use curve::*;
+pub use easing::*;
+pub use graph::*;
+pub use parameters::*;
+pub use pose::*;
+pub use spline::*;
+pub use timeline::*;
+pub use track::*;
+pub use transition::*;
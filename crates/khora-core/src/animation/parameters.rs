@@ -0,0 +1,72 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Named parameters driving an animation graph.
+
+use std::collections::{HashMap, HashSet};
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// The values an [`AnimationGraph`](super::AnimationGraph) reads to pick
+/// transitions and blend-space weights.
+///
+/// Booleans are stored as `0.0` / `1.0` floats. Triggers are one-shot flags
+/// cleared by the transition that consumes them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct AnimationParameters {
+    values: HashMap<String, f32>,
+    triggers: HashSet<String>,
+}
+
+impl AnimationParameters {
+    /// Creates an empty parameter set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the float parameter `name`.
+    pub fn set_float(&mut self, name: impl Into<String>, value: f32) {
+        self.values.insert(name.into(), value);
+    }
+
+    /// Returns the float parameter `name`, or `0.0` if unset.
+    pub fn float(&self, name: &str) -> f32 {
+        self.values.get(name).copied().unwrap_or(0.0)
+    }
+
+    /// Sets the boolean parameter `name`.
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) {
+        self.set_float(name, if value { 1.0 } else { 0.0 });
+    }
+
+    /// Returns the boolean parameter `name`, or `false` if unset.
+    pub fn bool(&self, name: &str) -> bool {
+        self.float(name) != 0.0
+    }
+
+    /// Raises the trigger `name` until a transition consumes it.
+    pub fn set_trigger(&mut self, name: impl Into<String>) {
+        self.triggers.insert(name.into());
+    }
+
+    /// Returns `true` if the trigger `name` is raised.
+    pub fn is_triggered(&self, name: &str) -> bool {
+        self.triggers.contains(name)
+    }
+
+    /// Clears the trigger `name`.
+    pub fn reset_trigger(&mut self, name: &str) {
+        self.triggers.remove(name);
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Blended poses — weighted accumulations of sampled track values.
+
+use super::{AnimationClip, SampledValue};
+
+/// One animated property of one target in a [`Pose`].
+#[derive(Debug, Clone, PartialEq)]
+struct PoseEntry {
+    target: Option<String>,
+    value: SampledValue,
+    weight: f32,
+}
+
+/// The result of blending several clips: one value per animated property.
+///
+/// Contributions are folded in incrementally — each new value is blended
+/// into the running result by its share of the accumulated weight — so
+/// weights do not need to sum to one.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pose {
+    entries: Vec<PoseEntry>,
+}
+
+impl Pose {
+    /// Creates an empty pose.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if no property has been written.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Blends `value` for `target` into the pose with `weight`.
+    pub fn add(&mut self, target: Option<&str>, value: SampledValue, weight: f32) {
+        if weight <= 0.0 {
+            return;
+        }
+        let existing = self
+            .entries
+            .iter_mut()
+            .find(|e| e.target.as_deref() == target && e.value.same_property(&value));
+        match existing {
+            Some(entry) => {
+                let total = entry.weight + weight;
+                if let Some(blended) = entry.value.blend(&value, weight / total) {
+                    entry.value = blended;
+                }
+                entry.weight = total;
+            }
+            None => self.entries.push(PoseEntry {
+                target: target.map(str::to_owned),
+                value,
+                weight,
+            }),
+        }
+    }
+
+    /// Samples every track of `clip` at `time` seconds into the pose.
+    pub fn add_clip(&mut self, clip: &AnimationClip, time: f32, weight: f32) {
+        for (target, value) in clip.sample(time) {
+            self.add(target, value, weight);
+        }
+    }
+
+    /// Iterates the blended values alongside their targets.
+    pub fn iter(&self) -> impl Iterator<Item = (Option<&str>, &SampledValue)> {
+        self.entries.iter().map(|e| (e.target.as_deref(), &e.value))
+    }
+
+    /// Consumes the pose, yielding the blended values alongside their targets.
+    pub fn into_samples(self) -> impl Iterator<Item = (Option<String>, SampledValue)> {
+        self.entries.into_iter().map(|e| (e.target, e.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Vec3;
+
+    #[test]
+    fn test_pose_weighted_blend() {
+        let mut pose = Pose::new();
+        pose.add(None, SampledValue::Translation(Vec3::ZERO), 0.25);
+        pose.add(
+            None,
+            SampledValue::Translation(Vec3::new(4.0, 0.0, 0.0)),
+            0.75,
+        );
+        pose.add(Some("arm"), SampledValue::Scale(Vec3::ONE), 1.0);
+
+        let samples: Vec<_> = pose.into_samples().collect();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(
+            samples[0],
+            (None, SampledValue::Translation(Vec3::new(3.0, 0.0, 0.0)))
+        );
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Catmull-Rom splines through authored control points.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::math::Vec3;
+
+/// Chords measured per segment when computing arc lengths.
+const SAMPLES_PER_SEGMENT: usize = 16;
+
+/// A smooth path passing through every control point.
+///
+/// The path is parameterized by `t` in `[0.0, 1.0]`, each segment between
+/// two control points taking an equal share of the range. Segments have
+/// different lengths, so moving at constant speed goes through
+/// [`t_at_distance`](Self::t_at_distance).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct Spline {
+    points: Vec<Vec3>,
+    /// Whether the last control point connects back to the first.
+    pub closed: bool,
+}
+
+impl Spline {
+    /// Creates an open spline through `points`.
+    pub fn new(points: impl IntoIterator<Item = Vec3>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            closed: false,
+        }
+    }
+
+    /// Sets whether the spline loops back to its first point.
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// Returns the control points in path order.
+    pub fn points(&self) -> &[Vec3] {
+        &self.points
+    }
+
+    /// Appends a control point at the end of the path.
+    pub fn push(&mut self, point: Vec3) {
+        self.points.push(point);
+    }
+
+    /// Inserts a control point before `index`, or at the end if `index` is
+    /// past it.
+    pub fn insert(&mut self, index: usize, point: Vec3) {
+        self.points.insert(index.min(self.points.len()), point);
+    }
+
+    /// Moves the control point at `index`. Returns `false` if there is none.
+    pub fn set(&mut self, index: usize, point: Vec3) -> bool {
+        match self.points.get_mut(index) {
+            Some(slot) => {
+                *slot = point;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes and returns the control point at `index`.
+    pub fn remove(&mut self, index: usize) -> Option<Vec3> {
+        (index < self.points.len()).then(|| self.points.remove(index))
+    }
+
+    /// Returns the number of segments between control points.
+    pub fn segment_count(&self) -> usize {
+        match self.points.len() {
+            0 | 1 => 0,
+            n if self.closed => n,
+            n => n - 1,
+        }
+    }
+
+    /// Returns the position at `t`, clamped to `[0.0, 1.0]`. Returns `None`
+    /// for a spline without control points.
+    pub fn position(&self, t: f32) -> Option<Vec3> {
+        if self.segment_count() == 0 {
+            return self.points.first().copied();
+        }
+        let (segment, u) = self.locate(t);
+        let [p0, p1, p2, p3] = self.segment_points(segment);
+        let u2 = u * u;
+        let u3 = u2 * u;
+        Some(
+            (p1 * 2.0
+                + (p2 - p0) * u
+                + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * u2
+                + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * u3)
+                * 0.5,
+        )
+    }
+
+    /// Returns the unit direction of travel at `t`. Returns `None` if the
+    /// spline has fewer than two points or does not move there.
+    pub fn tangent(&self, t: f32) -> Option<Vec3> {
+        if self.segment_count() == 0 {
+            return None;
+        }
+        let (segment, u) = self.locate(t);
+        let [p0, p1, p2, p3] = self.segment_points(segment);
+        let derivative = ((p2 - p0)
+            + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * (2.0 * u)
+            + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * (3.0 * u * u))
+            * 0.5;
+        (derivative.length_squared() > f32::EPSILON).then(|| derivative.normalize())
+    }
+
+    /// Returns the approximate length of the path.
+    pub fn length(&self) -> f32 {
+        self.samples().last().map_or(0.0, |(_, distance)| *distance)
+    }
+
+    /// Returns the `t` reached after travelling `distance` from the start,
+    /// clamped to the path.
+    pub fn t_at_distance(&self, distance: f32) -> f32 {
+        let samples = self.samples();
+        let Some(index) = samples.iter().position(|(_, d)| *d >= distance) else {
+            return if samples.is_empty() { 0.0 } else { 1.0 };
+        };
+        if index == 0 {
+            return 0.0;
+        }
+        let (t0, d0) = samples[index - 1];
+        let (t1, d1) = samples[index];
+        let span = d1 - d0;
+        if span > 0.0 {
+            t0 + (t1 - t0) * (distance - d0) / span
+        } else {
+            t1
+        }
+    }
+
+    /// Splits `t` into a segment index and the position within it.
+    fn locate(&self, t: f32) -> (usize, f32) {
+        let count = self.segment_count();
+        let scaled = t.clamp(0.0, 1.0) * count as f32;
+        let segment = (scaled as usize).min(count - 1);
+        (segment, scaled - segment as f32)
+    }
+
+    /// The four control points shaping `segment`. Open splines repeat their
+    /// end points; closed ones wrap around.
+    fn segment_points(&self, segment: usize) -> [Vec3; 4] {
+        let n = self.points.len() as isize;
+        let point = |i: isize| {
+            let i = if self.closed {
+                i.rem_euclid(n)
+            } else {
+                i.clamp(0, n - 1)
+            };
+            self.points[i as usize]
+        };
+        let i = segment as isize;
+        [point(i - 1), point(i), point(i + 1), point(i + 2)]
+    }
+
+    /// `(t, distance from start)` pairs along the path, starting at `(0, 0)`.
+    fn samples(&self) -> Vec<(f32, f32)> {
+        let steps = self.segment_count() * SAMPLES_PER_SEGMENT;
+        if steps == 0 {
+            return Vec::new();
+        }
+        let mut samples = Vec::with_capacity(steps + 1);
+        let mut previous = self.position(0.0).unwrap_or_default();
+        let mut distance = 0.0;
+        samples.push((0.0, 0.0));
+        for step in 1..=steps {
+            let t = step as f32 / steps as f32;
+            let current = self.position(t).unwrap_or_default();
+            distance += (current - previous).length();
+            samples.push((t, distance));
+            previous = current;
+        }
+        samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: Vec3, b: Vec3) -> bool {
+        (a - b).length() < 1e-2
+    }
+
+    #[test]
+    fn test_passes_through_control_points() {
+        let spline = Spline::new([
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+        ]);
+        assert!(approx(spline.position(0.0).unwrap(), Vec3::ZERO));
+        assert!(approx(
+            spline.position(0.5).unwrap(),
+            Vec3::new(1.0, 0.0, 0.0)
+        ));
+        assert!(approx(
+            spline.position(1.0).unwrap(),
+            Vec3::new(2.0, 1.0, 0.0)
+        ));
+        assert_eq!(Spline::default().position(0.5), None);
+    }
+
+    #[test]
+    fn test_straight_line_length_and_tangent() {
+        let spline = Spline::new([Vec3::ZERO, Vec3::new(0.0, 0.0, 4.0)]);
+        assert!((spline.length() - 4.0).abs() < 1e-3);
+        assert!(approx(spline.tangent(0.5).unwrap(), Vec3::Z));
+        let t = spline.t_at_distance(1.0);
+        assert!(approx(
+            spline.position(t).unwrap(),
+            Vec3::new(0.0, 0.0, 1.0)
+        ));
+        assert_eq!(spline.t_at_distance(10.0), 1.0);
+    }
+
+    #[test]
+    fn test_closed_spline_adds_a_segment() {
+        let points = [Vec3::ZERO, Vec3::X, Vec3::new(1.0, 0.0, 1.0)];
+        let open = Spline::new(points);
+        let closed = Spline::new(points).with_closed(true);
+        assert_eq!(open.segment_count(), 2);
+        assert_eq!(closed.segment_count(), 3);
+        assert!(approx(closed.position(1.0).unwrap(), Vec3::ZERO));
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Timelines — authored cutscenes sequencing cameras, motion, sound and
+//! gameplay callbacks on one clock.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::{SampledValue, TrackValues};
+use crate::asset::Asset;
+
+/// A named event at a point on a timeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct TimelineMarker {
+    /// Time of the marker, in seconds from the start of the timeline.
+    pub time: f32,
+    /// Sound event or callback name.
+    pub name: String,
+}
+
+impl TimelineMarker {
+    /// Creates a marker at `time`.
+    pub fn new(time: f32, name: impl Into<String>) -> Self {
+        Self {
+            time,
+            name: name.into(),
+        }
+    }
+}
+
+/// One lane of a [`Timeline`].
+///
+/// Tracks address entities through bindings: names resolved by the
+/// playing `TimelinePlayer`, falling back to entities with that `Name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum TimelineTrack {
+    /// Switches the active camera. Each marker names the camera binding
+    /// that becomes active at its time.
+    CameraCuts(Vec<TimelineMarker>),
+    /// Animates the transform (or morph weights) of a bound entity.
+    Animation {
+        /// Binding of the animated entity.
+        binding: String,
+        /// The keyframed values.
+        values: TrackValues,
+    },
+    /// Posts sound events, emitted from a bound entity.
+    Audio {
+        /// Binding of the emitting entity.
+        binding: String,
+        /// Sound events to post, by event name.
+        events: Vec<TimelineMarker>,
+    },
+    /// Named callbacks handed to gameplay code when crossed.
+    Callbacks(Vec<TimelineMarker>),
+}
+
+impl TimelineTrack {
+    /// Returns the time of the track's last key, in seconds.
+    pub fn duration(&self) -> f32 {
+        match self {
+            Self::Animation { values, .. } => values.duration(),
+            Self::CameraCuts(markers)
+            | Self::Audio {
+                events: markers, ..
+            }
+            | Self::Callbacks(markers) => markers.iter().map(|m| m.time).fold(0.0, f32::max),
+        }
+    }
+}
+
+/// An authored sequence of tracks played as one cutscene.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct Timeline {
+    /// Human-readable timeline name.
+    pub name: String,
+    /// Tracks, evaluated in order.
+    pub tracks: Vec<TimelineTrack>,
+    /// Explicit length in seconds. `None` ends on the last key.
+    pub length: Option<f32>,
+}
+
+impl Asset for Timeline {}
+
+impl Timeline {
+    /// Creates an empty timeline.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a track.
+    pub fn with_track(mut self, track: TimelineTrack) -> Self {
+        self.tracks.push(track);
+        self
+    }
+
+    /// Sets an explicit length, e.g. to hold the last shot.
+    pub fn with_length(mut self, seconds: f32) -> Self {
+        self.length = Some(seconds);
+        self
+    }
+
+    /// Returns the length of the timeline, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.length.unwrap_or_else(|| {
+            self.tracks
+                .iter()
+                .map(TimelineTrack::duration)
+                .fold(0.0, f32::max)
+        })
+    }
+
+    /// Returns the camera binding active at `time`, if a cut happened yet.
+    pub fn camera_at(&self, time: f32) -> Option<&str> {
+        self.tracks
+            .iter()
+            .filter_map(|track| match track {
+                TimelineTrack::CameraCuts(cuts) => cuts
+                    .iter()
+                    .filter(|cut| cut.time <= time)
+                    .max_by(|a, b| a.time.total_cmp(&b.time)),
+                _ => None,
+            })
+            .max_by(|a, b| a.time.total_cmp(&b.time))
+            .map(|cut| cut.name.as_str())
+    }
+
+    /// Samples every animation track at `time`, yielding each binding
+    /// alongside its value.
+    pub fn sample(&self, time: f32) -> impl Iterator<Item = (&str, SampledValue)> + '_ {
+        self.tracks.iter().filter_map(move |track| match track {
+            TimelineTrack::Animation { binding, values } => {
+                Some((binding.as_str(), values.sample(time)?))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the markers crossed when playback moves from `from`
+    /// (exclusive) to `to` (inclusive): sound events with their emitter
+    /// binding, and callbacks with `None`.
+    ///
+    /// Pass a negative `from` to include markers at time zero.
+    pub fn crossed(&self, from: f32, to: f32) -> Vec<(Option<&str>, &TimelineMarker)> {
+        let hit = |m: &&TimelineMarker| m.time > from && m.time <= to;
+        let mut crossed: Vec<(Option<&str>, &TimelineMarker)> = Vec::new();
+        for track in &self.tracks {
+            match track {
+                TimelineTrack::Audio { binding, events } => {
+                    crossed.extend(
+                        events
+                            .iter()
+                            .filter(hit)
+                            .map(|m| (Some(binding.as_str()), m)),
+                    );
+                }
+                TimelineTrack::Callbacks(markers) => {
+                    crossed.extend(markers.iter().filter(hit).map(|m| (None, m)));
+                }
+                _ => {}
+            }
+        }
+        crossed.sort_by(|a, b| a.1.time.total_cmp(&b.1.time));
+        crossed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Curve;
+    use crate::math::Vec3;
+
+    fn cutscene() -> Timeline {
+        Timeline::new("intro")
+            .with_track(TimelineTrack::CameraCuts(vec![
+                TimelineMarker::new(0.0, "wide"),
+                TimelineMarker::new(2.0, "close"),
+            ]))
+            .with_track(TimelineTrack::Animation {
+                binding: "hero".into(),
+                values: TrackValues::Translation(Curve::new([
+                    (0.0, Vec3::ZERO),
+                    (4.0, Vec3::new(4.0, 0.0, 0.0)),
+                ])),
+            })
+            .with_track(TimelineTrack::Audio {
+                binding: "hero".into(),
+                events: vec![TimelineMarker::new(1.0, "footstep")],
+            })
+            .with_track(TimelineTrack::Callbacks(vec![
+                TimelineMarker::new(0.0, "start"),
+                TimelineMarker::new(3.0, "open_door"),
+            ]))
+    }
+
+    #[test]
+    fn test_timeline_duration_and_camera_cuts() {
+        let timeline = cutscene();
+        assert_eq!(timeline.duration(), 4.0);
+        assert_eq!(timeline.camera_at(1.0), Some("wide"));
+        assert_eq!(timeline.camera_at(2.5), Some("close"));
+        assert_eq!(timeline.with_length(6.0).duration(), 6.0);
+    }
+
+    #[test]
+    fn test_timeline_markers_fire_once_per_crossing() {
+        let timeline = cutscene();
+        let names = |from, to| {
+            timeline
+                .crossed(from, to)
+                .into_iter()
+                .map(|(_, m)| m.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(-1.0, 0.5), vec!["start"]);
+        assert_eq!(names(0.5, 3.0), vec!["footstep", "open_door"]);
+        assert!(names(3.0, 3.5).is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Animation tracks — one animated property on one target.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::{Animatable, Curve};
+use crate::math::{Quaternion, Vec3};
+
+/// The keyframed values of a track, typed by the property they drive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum TrackValues {
+    /// Local translation.
+    Translation(Curve<Vec3>),
+    /// Local rotation.
+    Rotation(Curve<Quaternion>),
+    /// Local scale.
+    Scale(Curve<Vec3>),
+    /// Morph target weights, one entry per blend shape.
+    MorphWeights(Curve<Vec<f32>>),
+}
+
+/// A value sampled from a [`TrackValues`] at a given time.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum SampledValue {
+    /// Local translation.
+    Translation(Vec3),
+    /// Local rotation.
+    Rotation(Quaternion),
+    /// Local scale.
+    Scale(Vec3),
+    /// Morph target weights.
+    MorphWeights(Vec<f32>),
+}
+
+impl SampledValue {
+    /// Blends `self` towards `other` by `t`. Returns `None` when the two
+    /// values drive different properties.
+    pub fn blend(&self, other: &Self, t: f32) -> Option<Self> {
+        Some(match (self, other) {
+            (Self::Translation(a), Self::Translation(b)) => {
+                Self::Translation(Animatable::interpolate(a, b, t))
+            }
+            (Self::Rotation(a), Self::Rotation(b)) => {
+                Self::Rotation(Animatable::interpolate(a, b, t))
+            }
+            (Self::Scale(a), Self::Scale(b)) => Self::Scale(Animatable::interpolate(a, b, t)),
+            (Self::MorphWeights(a), Self::MorphWeights(b)) => {
+                Self::MorphWeights(Animatable::interpolate(a, b, t))
+            }
+            _ => return None,
+        })
+    }
+
+    /// Returns `true` if both values drive the same property.
+    pub fn same_property(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl TrackValues {
+    /// Returns the time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        match self {
+            Self::Translation(c) | Self::Scale(c) => c.duration(),
+            Self::Rotation(c) => c.duration(),
+            Self::MorphWeights(c) => c.duration(),
+        }
+    }
+
+    /// Samples the track at `time` seconds.
+    pub fn sample(&self, time: f32) -> Option<SampledValue> {
+        match self {
+            Self::Translation(c) => c.sample(time).map(SampledValue::Translation),
+            Self::Rotation(c) => c.sample(time).map(SampledValue::Rotation),
+            Self::Scale(c) => c.sample(time).map(SampledValue::Scale),
+            Self::MorphWeights(c) => c.sample(time).map(SampledValue::MorphWeights),
+        }
+    }
+}
+
+/// One animated property of one entity in a clip.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct AnimationTrack {
+    /// `Name` of the animated entity, searched among the descendants of the
+    /// entity playing the clip. `None` targets the player entity itself.
+    pub target: Option<String>,
+    /// The keyframed values.
+    pub values: TrackValues,
+}
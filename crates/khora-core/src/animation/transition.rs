@@ -0,0 +1,124 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! State machine transitions between animation states.
+
+use super::AnimationParameters;
+
+/// A test against one [`AnimationParameters`] entry.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Condition {
+    /// The float parameter is strictly greater than the threshold.
+    Greater(String, f32),
+    /// The float parameter is strictly less than the threshold.
+    Less(String, f32),
+    /// The boolean parameter is set.
+    IsTrue(String),
+    /// The boolean parameter is cleared.
+    IsFalse(String),
+    /// The trigger is raised. Consumed when the transition fires.
+    Trigger(String),
+}
+
+impl Condition {
+    /// Evaluates the condition against `params`.
+    pub fn evaluate(&self, params: &AnimationParameters) -> bool {
+        match self {
+            Self::Greater(name, threshold) => params.float(name) > *threshold,
+            Self::Less(name, threshold) => params.float(name) < *threshold,
+            Self::IsTrue(name) => params.bool(name),
+            Self::IsFalse(name) => !params.bool(name),
+            Self::Trigger(name) => params.is_triggered(name),
+        }
+    }
+}
+
+/// A directed edge of an [`AnimationGraph`](super::AnimationGraph).
+///
+/// Fires when every condition holds (and the exit time, if any, has been
+/// reached), then crossfades into the destination state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    /// Source state name. `None` means the transition may fire from any state.
+    pub from: Option<String>,
+    /// Destination state name.
+    pub to: String,
+    /// Conditions that must all hold.
+    pub conditions: Vec<Condition>,
+    /// Crossfade duration, in seconds. Zero snaps to the destination.
+    pub crossfade: f32,
+    /// Normalized time (`0.0..=1.0`) of the source state before which the
+    /// transition cannot fire.
+    pub exit_time: Option<f32>,
+}
+
+impl Transition {
+    /// Creates a transition from the state `from` to the state `to`.
+    pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            from: Some(from.into()),
+            ..Self::from_any(to)
+        }
+    }
+
+    /// Creates a transition to `to` that may fire from any state.
+    pub fn from_any(to: impl Into<String>) -> Self {
+        Self {
+            from: None,
+            to: to.into(),
+            conditions: Vec::new(),
+            crossfade: 0.0,
+            exit_time: None,
+        }
+    }
+
+    /// Adds a condition.
+    pub fn when(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Sets the crossfade duration, in seconds.
+    pub fn with_crossfade(mut self, seconds: f32) -> Self {
+        self.crossfade = seconds.max(0.0);
+        self
+    }
+
+    /// Sets the normalized exit time of the source state.
+    pub fn with_exit_time(mut self, normalized_time: f32) -> Self {
+        self.exit_time = Some(normalized_time.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Returns `true` if the transition may fire from `state`.
+    pub fn leaves(&self, state: &str) -> bool {
+        self.from.as_deref().is_none_or(|from| from == state)
+    }
+
+    /// Returns `true` if the transition fires given `params` and the source
+    /// state's normalized time.
+    pub fn is_satisfied(&self, params: &AnimationParameters, normalized_time: f32) -> bool {
+        self.exit_time.is_none_or(|t| normalized_time >= t)
+            && self.conditions.iter().all(|c| c.evaluate(params))
+    }
+
+    /// Clears the triggers this transition consumed.
+    pub fn consume_triggers(&self, params: &mut AnimationParameters) {
+        for condition in &self.conditions {
+            if let Condition::Trigger(name) = condition {
+                params.reset_trigger(name);
+            }
+        }
+    }
+}
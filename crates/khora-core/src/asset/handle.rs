@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::Asset;
+use super::{Asset, AssetUUID, WeakHandle};
 use std::{ops::Deref, sync::Arc};
 
 /// A thread-safe, reference-counted handle to a loaded asset's data.
@@ -67,6 +67,24 @@ impl<T: Asset> AssetHandle<T> {
     {
         Self(Arc::new(T::default()))
     }
+
+    /// Returns the number of handles sharing this asset, this one included.
+    ///
+    /// A handle held only by an asset storage has a count of `1`, which is
+    /// how unused assets are detected for unloading.
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+
+    /// Creates a [`WeakHandle`] to this asset, which does not keep it
+    /// loaded. `uuid` is the asset's UUID, used to load it again later.
+    pub fn downgrade(&self, uuid: AssetUUID) -> WeakHandle<T> {
+        WeakHandle::from_weak(uuid, Arc::downgrade(&self.0))
+    }
+
+    pub(crate) fn from_arc(asset: Arc<T>) -> Self {
+        Self(asset)
+    }
 }
 
 impl<T: Asset> Clone for AssetHandle<T> {
@@ -0,0 +1,55 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! How much of an asset a load brings in, chosen by the `AssetAgent` to fit
+//! its GORNA budget.
+
+/// The quality at which the asset service loads assets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum AssetLoadQuality {
+    /// Every asset is decoded at full quality.
+    #[default]
+    Full,
+    /// Textures are decoded without their largest mip levels: each skipped
+    /// level halves the width and height. Other assets load at full quality.
+    ReducedTextures {
+        /// Number of top mip levels to skip.
+        skip_mips: u32,
+    },
+    /// Prefetches only resolve metadata and leave the payload on disk.
+    /// Explicit loads still decode at full quality.
+    MetadataOnly,
+}
+
+impl AssetLoadQuality {
+    /// Number of top texture mip levels a load skips.
+    pub fn skipped_texture_mips(self) -> u32 {
+        match self {
+            Self::ReducedTextures { skip_mips } => skip_mips,
+            Self::Full | Self::MetadataOnly => 0,
+        }
+    }
+
+    /// Returns `true` if prefetches read and decode the payload.
+    pub fn prefetches_payload(self) -> bool {
+        !matches!(self, Self::MetadataOnly)
+    }
+
+    /// Share of a texture's full-resolution memory a load keeps, from
+    /// `0.0` to `1.0`.
+    pub fn texture_memory_scale(self) -> f32 {
+        0.25f32.powi(self.skipped_texture_mips().min(16) as i32)
+    }
+}
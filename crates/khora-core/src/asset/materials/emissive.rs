@@ -106,6 +106,10 @@ impl Material for EmissiveMaterial {
     fn emissive_color(&self) -> crate::math::LinearRgba {
         self.emissive_color
     }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        self.alpha_mode
+    }
 }
 
 #[cfg(test)]
@@ -16,12 +16,14 @@
 
 mod alpha_mode;
 mod emissive;
+mod params;
 mod standard;
 mod unlit;
 mod wireframe;
 
 pub use alpha_mode::*;
 pub use emissive::*;
+pub use params::*;
 pub use standard::*;
 pub use unlit::*;
 pub use wireframe::*;
@@ -71,6 +73,12 @@ pub trait Material: Asset + AsAny {
     fn ambient_color(&self) -> crate::math::LinearRgba {
         crate::math::LinearRgba::new(0.1, 0.1, 0.1, 0.0)
     }
+
+    /// Returns how the material handles transparency.
+    /// Default implementation is [`AlphaMode::Opaque`].
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Opaque
+    }
 }
 
 /// This is the key to our type-erased material handle system.
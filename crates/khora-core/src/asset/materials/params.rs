@@ -0,0 +1,53 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolved per-draw material parameters.
+
+use super::Material;
+use crate::math::LinearRgba;
+
+/// The material parameters a lane uploads for a single draw.
+///
+/// Starts from the material's own values and may be adjusted per entity
+/// before being written into the material uniforms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaterialParams {
+    /// Base color (albedo).
+    pub base_color: LinearRgba,
+    /// Emissive color.
+    pub emissive: LinearRgba,
+    /// Specular power.
+    pub specular_power: f32,
+}
+
+impl Default for MaterialParams {
+    fn default() -> Self {
+        Self {
+            base_color: LinearRgba::WHITE,
+            emissive: LinearRgba::BLACK,
+            specular_power: 32.0,
+        }
+    }
+}
+
+impl MaterialParams {
+    /// Reads the parameters exposed by `material`.
+    pub fn from_material(material: &dyn Material) -> Self {
+        Self {
+            base_color: material.base_color(),
+            emissive: material.emissive_color(),
+            specular_power: material.specular_power(),
+        }
+    }
+}
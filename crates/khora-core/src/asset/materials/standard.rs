@@ -180,6 +180,10 @@ impl Material for StandardMaterial {
     fn emissive_color(&self) -> crate::math::LinearRgba {
         self.emissive
     }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        self.alpha_mode
+    }
 }
 
 #[cfg(test)]
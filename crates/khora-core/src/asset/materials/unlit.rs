@@ -107,6 +107,10 @@ impl Material for UnlitMaterial {
     fn base_color(&self) -> crate::math::LinearRgba {
         self.base_color
     }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        self.alpha_mode
+    }
 }
 
 #[cfg(test)]
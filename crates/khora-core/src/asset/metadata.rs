@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::platform::PlatformTarget;
 use super::uuid::AssetUUID;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, path::PathBuf};
@@ -33,6 +34,51 @@ pub enum AssetSource {
         /// The total size of the asset's data in bytes.
         size: u64,
     },
+    /// The asset is a binary delta stored in a patch archive.
+    ///
+    /// Applying the delta to the same asset variant in the archives mounted
+    /// below the patch yields the new bytes.
+    Delta {
+        /// The byte offset of the delta within the patch's packfile.
+        offset: u64,
+        /// The size of the encoded delta in bytes.
+        size: u64,
+    },
+}
+
+/// Version information stored next to an archive's index (`archive.bin`).
+///
+/// A full archive has no `base_version`. A patch archive records the version
+/// it applies on top of, so it can only be mounted over that exact version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ArchiveInfo {
+    /// Version of the asset content once this archive is mounted.
+    pub version: u32,
+    /// Version a patch archive must be mounted over, `None` for a full archive.
+    pub base_version: Option<u32>,
+}
+
+impl ArchiveInfo {
+    /// Describes a full archive.
+    pub fn full(version: u32) -> Self {
+        Self {
+            version,
+            base_version: None,
+        }
+    }
+
+    /// Describes a patch from `base_version` to `version`.
+    pub fn patch(base_version: u32, version: u32) -> Self {
+        Self {
+            version,
+            base_version: Some(base_version),
+        }
+    }
+
+    /// Returns `true` if this is a patch archive.
+    pub fn is_patch(&self) -> bool {
+        self.base_version.is_some()
+    }
 }
 
 /// Serializable metadata that describes an asset and its relationships.
@@ -68,6 +114,9 @@ pub struct AssetMetadata {
     /// and the value is the source of the compiled, engine-ready file for that variant. (Which contains the necessary metadata for loading the asset.)
     /// This map allows the `AssetAgent` to make strategic choices, such as loading
     /// a lower-quality texture to stay within a VRAM budget.
+    ///
+    /// In a patch archive, an entry with no variants marks the asset as
+    /// removed.
     pub variants: HashMap<String, AssetSource>,
 
     /// A collection of semantic tags for advanced querying and organization.
@@ -75,3 +124,16 @@ pub struct AssetMetadata {
     /// loading all assets for a specific game level or character.
     pub tags: Vec<String>,
 }
+
+impl AssetMetadata {
+    /// Picks the variant the given target should load.
+    ///
+    /// Tries [`PlatformTarget::candidates`] in order, so a tier-specific
+    /// encoding wins over the platform one, which wins over `"default"`.
+    pub fn select_variant(&self, target: &PlatformTarget) -> Option<(String, &AssetSource)> {
+        target
+            .candidates()
+            .into_iter()
+            .find_map(|key| self.variants.get(&key).map(|source| (key, source)))
+    }
+}
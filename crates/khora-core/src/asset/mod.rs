@@ -30,15 +30,25 @@
 /// Font asset definitions and metadata.
 pub mod font;
 mod handle;
+mod load_quality;
 mod materials;
 mod metadata;
+mod platform;
+mod residency;
+mod thumbnail;
 mod uuid;
+mod weak_handle;
 
 pub use handle::AssetHandle as Handle;
 pub use handle::*;
+pub use load_quality::*;
 pub use materials::*;
 pub use metadata::*;
+pub use platform::*;
+pub use residency::*;
+pub use thumbnail::*;
 pub use uuid::*;
+pub use weak_handle::*;
 
 /// A marker trait for types that can be managed by the asset system.
 ///
@@ -0,0 +1,169 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Platform vocabulary for per-platform asset variants.
+//!
+//! A packed asset may carry several encodings (e.g. BC7 textures for desktop,
+//! ASTC at half resolution for mobile). Each encoding is stored under a
+//! variant key in [`AssetMetadata::variants`](super::AssetMetadata), and a
+//! [`PlatformTarget`] decides which key the runtime picks.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Key of the variant every asset carries and falls back to.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Family of devices an asset variant is encoded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssetPlatform {
+    /// Windows, macOS and Linux.
+    Desktop,
+    /// Android and iOS.
+    Mobile,
+    /// Browsers (WebAssembly).
+    Web,
+}
+
+impl AssetPlatform {
+    /// Returns the platform the engine was compiled for.
+    pub fn current() -> Self {
+        if cfg!(any(target_os = "android", target_os = "ios")) {
+            Self::Mobile
+        } else if cfg!(target_arch = "wasm32") {
+            Self::Web
+        } else {
+            Self::Desktop
+        }
+    }
+
+    /// Returns the lowercase name used in variant keys.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Desktop => "desktop",
+            Self::Mobile => "mobile",
+            Self::Web => "web",
+        }
+    }
+}
+
+impl fmt::Display for AssetPlatform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AssetPlatform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "desktop" => Ok(Self::Desktop),
+            "mobile" => Ok(Self::Mobile),
+            "web" => Ok(Self::Web),
+            other => Err(format!("unknown asset platform '{other}'")),
+        }
+    }
+}
+
+/// The platform and optional quality tier the runtime loads variants for.
+///
+/// Variant keys are `"<platform>"` or `"<platform>-<tier>"`, for example
+/// `"mobile"` or `"mobile-low"`. Lookup tries the most specific key first
+/// and ends with [`DEFAULT_VARIANT`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlatformTarget {
+    /// Target device family.
+    pub platform: AssetPlatform,
+    /// Optional quality tier within the platform (e.g. `"low"`).
+    pub tier: Option<String>,
+}
+
+impl PlatformTarget {
+    /// Creates a target for a platform with no tier.
+    pub fn new(platform: AssetPlatform) -> Self {
+        Self {
+            platform,
+            tier: None,
+        }
+    }
+
+    /// Returns the target for the platform the engine was compiled for.
+    pub fn current() -> Self {
+        Self::new(AssetPlatform::current())
+    }
+
+    /// Sets the quality tier.
+    pub fn with_tier(mut self, tier: impl Into<String>) -> Self {
+        self.tier = Some(tier.into());
+        self
+    }
+
+    /// Returns the variant keys to try, most specific first.
+    pub fn candidates(&self) -> Vec<String> {
+        let mut keys = Vec::with_capacity(3);
+        if let Some(tier) = &self.tier {
+            keys.push(format!("{}-{}", self.platform, tier));
+        }
+        keys.push(self.platform.to_string());
+        keys.push(DEFAULT_VARIANT.to_string());
+        keys
+    }
+
+    /// Returns `true` if a variant stored under `key` may be loaded by this
+    /// target's platform, whatever its tier. Keys not tagged with a platform
+    /// are accepted everywhere.
+    pub fn accepts(&self, key: &str) -> bool {
+        variant_platform(key).is_none_or(|platform| platform == self.platform)
+    }
+}
+
+impl Default for PlatformTarget {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+/// Returns the platform a variant key is tagged with, or `None` for
+/// [`DEFAULT_VARIANT`] and untagged keys (e.g. `"LOD0"`).
+pub fn variant_platform(key: &str) -> Option<AssetPlatform> {
+    key.split('-').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidates_go_from_tier_to_default() {
+        let target = PlatformTarget::new(AssetPlatform::Mobile).with_tier("low");
+        assert_eq!(target.candidates(), vec!["mobile-low", "mobile", "default"]);
+        assert_eq!(
+            PlatformTarget::new(AssetPlatform::Desktop).candidates(),
+            vec!["desktop", "default"]
+        );
+    }
+
+    #[test]
+    fn accepts_own_platform_and_untagged_keys() {
+        let target = PlatformTarget::new(AssetPlatform::Mobile);
+        assert!(target.accepts("default"));
+        assert!(target.accepts("mobile"));
+        assert!(target.accepts("mobile-low"));
+        assert!(target.accepts("LOD0"));
+        assert!(!target.accepts("desktop"));
+        assert_eq!(variant_platform("web-high"), Some(AssetPlatform::Web));
+    }
+}
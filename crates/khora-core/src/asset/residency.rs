@@ -0,0 +1,159 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Memory residency vocabulary for assets that live on both CPU and GPU.
+//!
+//! An uploaded asset can keep its CPU copy (so it can be re-uploaded after a
+//! device loss without touching disk) or drop it to save RAM. These types
+//! describe where an asset currently lives and what it is allowed to keep.
+
+/// Where a loaded asset's data currently lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Residency {
+    /// Neither copy is resident; the asset must be reloaded before use.
+    #[default]
+    Unloaded,
+    /// Only the CPU copy exists.
+    CpuOnly,
+    /// Only the GPU copy exists; the CPU copy was dropped after upload.
+    GpuOnly,
+    /// Both the CPU and the GPU copies are resident.
+    Both,
+}
+
+impl Residency {
+    /// Builds a residency from the presence of each copy.
+    pub fn from_parts(cpu: bool, gpu: bool) -> Self {
+        match (cpu, gpu) {
+            (false, false) => Self::Unloaded,
+            (true, false) => Self::CpuOnly,
+            (false, true) => Self::GpuOnly,
+            (true, true) => Self::Both,
+        }
+    }
+
+    /// Returns `true` if the CPU copy is resident.
+    pub fn has_cpu(self) -> bool {
+        matches!(self, Self::CpuOnly | Self::Both)
+    }
+
+    /// Returns `true` if the GPU copy is resident.
+    pub fn has_gpu(self) -> bool {
+        matches!(self, Self::GpuOnly | Self::Both)
+    }
+
+    /// Returns `true` if the given tier is resident.
+    pub fn contains(self, tier: ResidencyTier) -> bool {
+        match tier {
+            ResidencyTier::Cpu => self.has_cpu(),
+            ResidencyTier::Gpu => self.has_gpu(),
+        }
+    }
+}
+
+/// One side of an asset's residency, used by explicit residency requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResidencyTier {
+    /// The decoded CPU-side copy.
+    Cpu,
+    /// The uploaded GPU-side copy.
+    Gpu,
+}
+
+/// What an asset is allowed to keep once it has been loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ResidencyPolicy {
+    /// Upload to the GPU and keep the CPU copy for device-loss recovery
+    /// and CPU-side access (picking, deformation, serialization).
+    #[default]
+    KeepCpuCopy,
+    /// Upload to the GPU, then drop the CPU copy to save RAM.
+    DropCpuAfterUpload,
+    /// Never upload; the asset is only used on the CPU.
+    CpuOnly,
+}
+
+impl ResidencyPolicy {
+    /// Returns `true` if assets under this policy are uploaded to the GPU.
+    pub fn uploads(self) -> bool {
+        !matches!(self, Self::CpuOnly)
+    }
+
+    /// Returns `true` if the CPU copy is dropped once the upload succeeded.
+    pub fn drops_cpu_copy(self) -> bool {
+        matches!(self, Self::DropCpuAfterUpload)
+    }
+}
+
+/// Aggregate residency counters, reported to telemetry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResidencyStats {
+    /// Assets with only a CPU copy.
+    pub cpu_only: usize,
+    /// Assets with only a GPU copy.
+    pub gpu_only: usize,
+    /// Assets resident on both sides.
+    pub both: usize,
+    /// Tracked assets with no resident copy.
+    pub unloaded: usize,
+    /// Bytes held by resident CPU copies.
+    pub cpu_bytes: u64,
+    /// Bytes held by resident GPU copies.
+    pub gpu_bytes: u64,
+}
+
+impl ResidencyStats {
+    /// Counts one asset in the bucket matching `residency`.
+    pub fn record(&mut self, residency: Residency, cpu_bytes: u64, gpu_bytes: u64) {
+        match residency {
+            Residency::Unloaded => self.unloaded += 1,
+            Residency::CpuOnly => self.cpu_only += 1,
+            Residency::GpuOnly => self.gpu_only += 1,
+            Residency::Both => self.both += 1,
+        }
+        if residency.has_cpu() {
+            self.cpu_bytes += cpu_bytes;
+        }
+        if residency.has_gpu() {
+            self.gpu_bytes += gpu_bytes;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_only_count_resident_bytes() {
+        let mut stats = ResidencyStats::default();
+        stats.record(Residency::Both, 100, 80);
+        stats.record(Residency::GpuOnly, 50, 40);
+        stats.record(Residency::Unloaded, 10, 10);
+
+        assert_eq!(stats.both, 1);
+        assert_eq!(stats.gpu_only, 1);
+        assert_eq!(stats.unloaded, 1);
+        assert_eq!(stats.cpu_bytes, 100);
+        assert_eq!(stats.gpu_bytes, 120);
+    }
+
+    #[test]
+    fn residency_from_parts_round_trips() {
+        let r = Residency::from_parts(false, true);
+        assert_eq!(r, Residency::GpuOnly);
+        assert!(r.contains(ResidencyTier::Gpu));
+        assert!(!r.contains(ResidencyTier::Cpu));
+    }
+}
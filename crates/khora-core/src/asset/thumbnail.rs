@@ -0,0 +1,55 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Preview images of assets, for asset browsers.
+
+use serde::{Deserialize, Serialize};
+
+/// Edge length, in pixels, of thumbnails generated at pack time.
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 128;
+
+/// A small preview image of an asset.
+///
+/// Pixels are sRGB RGBA8, tightly packed, top row first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Thumbnail {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// `width * height * 4` bytes.
+    pub pixels: Vec<u8>,
+}
+
+impl Thumbnail {
+    /// Creates a thumbnail from RGBA8 pixels.
+    ///
+    /// Returns `None` if `pixels` does not hold `width * height * 4` bytes.
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Option<Self> {
+        (pixels.len() == width as usize * height as usize * 4).then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Returns the RGBA8 pixel at (`x`, `y`), or `None` outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let at = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels.get(at..at + 4)?.try_into().ok()
+    }
+}
@@ -75,6 +75,22 @@ impl AssetUUID {
     }
 }
 
+impl std::fmt::Display for AssetUUID {
+    /// Formats the UUID in its hyphenated form.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.hyphenated().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AssetUUID {
+    type Err = uuid::Error;
+
+    /// Parses a UUID in any of the usual text forms.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s).map(Self)
+    }
+}
+
 impl Default for AssetUUID {
     /// Creates a new, random (version 4) `AssetUUID`.
     fn default() -> Self {
@@ -0,0 +1,126 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Asset, AssetHandle, AssetUUID};
+use std::sync::Weak;
+
+/// A handle that refers to an asset without keeping it loaded.
+///
+/// Caches and editor tools use it to remember assets they do not own:
+/// once every [`AssetHandle`] is gone, the asset can be unloaded even
+/// though weak handles to it remain. A weak handle keeps the asset's UUID,
+/// so the asset service can load it again on demand.
+///
+/// # Examples
+///
+/// ```
+/// # use khora_core::asset::{Asset, AssetHandle, AssetUUID};
+/// # struct Texture {}
+/// # impl Asset for Texture {}
+/// let uuid = AssetUUID::new_v5("textures/brick.png");
+/// let handle = AssetHandle::new(Texture {});
+/// let weak = handle.downgrade(uuid);
+///
+/// assert!(weak.get().is_some());
+/// drop(handle);
+/// assert!(weak.get().is_none());
+/// ```
+#[derive(Debug)]
+pub struct WeakHandle<T: Asset> {
+    uuid: AssetUUID,
+    asset: Weak<T>,
+}
+
+impl<T: Asset> WeakHandle<T> {
+    pub(crate) fn from_weak(uuid: AssetUUID, asset: Weak<T>) -> Self {
+        Self { uuid, asset }
+    }
+
+    /// Creates a weak handle to an asset that is not loaded.
+    ///
+    /// It never upgrades on its own; the asset service loads the asset
+    /// the first time it is upgraded.
+    pub fn unloaded(uuid: AssetUUID) -> Self {
+        Self {
+            uuid,
+            asset: Weak::new(),
+        }
+    }
+
+    /// Returns the UUID of the referenced asset.
+    pub fn uuid(&self) -> AssetUUID {
+        self.uuid
+    }
+
+    /// Returns a strong handle if the asset is still loaded.
+    ///
+    /// This never loads anything. Use the asset service's `upgrade` to
+    /// load the asset again when it was unloaded.
+    pub fn get(&self) -> Option<AssetHandle<T>> {
+        self.asset.upgrade().map(AssetHandle::from_arc)
+    }
+
+    /// Returns `true` if the asset is still loaded.
+    pub fn is_loaded(&self) -> bool {
+        self.asset.strong_count() > 0
+    }
+
+    /// Points this weak handle at `handle`, a freshly loaded copy of the
+    /// same asset.
+    pub fn rebind(&mut self, handle: &AssetHandle<T>) {
+        self.asset = handle.downgrade(self.uuid).asset;
+    }
+}
+
+impl<T: Asset> Clone for WeakHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            uuid: self.uuid,
+            asset: self.asset.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Blob;
+    impl Asset for Blob {}
+
+    #[test]
+    fn test_weak_handle_does_not_keep_the_asset_alive() {
+        let uuid = AssetUUID::new_v5("blob");
+        let handle = AssetHandle::new(Blob);
+        let weak = handle.downgrade(uuid);
+        assert_eq!(handle.strong_count(), 1);
+        assert!(weak.get().is_some_and(|h| h == handle));
+
+        drop(handle);
+        assert!(!weak.is_loaded());
+        assert!(weak.get().is_none());
+        assert_eq!(weak.uuid(), uuid);
+    }
+
+    #[test]
+    fn test_rebind_points_at_the_new_copy() {
+        let uuid = AssetUUID::new_v5("blob");
+        let mut weak = WeakHandle::<Blob>::unloaded(uuid);
+        assert!(weak.get().is_none());
+
+        let handle = AssetHandle::new(Blob);
+        weak.rebind(&handle);
+        assert!(weak.get().is_some_and(|h| h == handle));
+    }
+}
@@ -31,6 +31,10 @@ pub enum AgentId {
     ShadowRenderer,
     /// The physics simulation agent.
     Physics,
+    /// The skeletal/property animation agent.
+    Animation,
+    /// The inverse kinematics agent, correcting animated poses.
+    InverseKinematics,
     /// The ECS/Logic coordination agent.
     Ecs,
     /// The UI layout and interaction agent.
@@ -107,7 +111,7 @@ pub struct TimingAdjustment {
 }
 
 /// A specific execution strategy offered by an Agent.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyOption {
     /// Unique identifier for the strategy.
     pub id: StrategyId,
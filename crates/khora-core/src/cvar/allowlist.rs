@@ -0,0 +1,57 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Restricting which console variables tools may change.
+
+use serde::{Deserialize, Serialize};
+
+/// The set of console variables tools may change, for shipping builds.
+///
+/// Each entry is either an exact name or a prefix ending in `*`:
+/// `power.*` permits every variable under `power.`. Installed with
+/// [`CVarRegistry::set_allowlist`](super::CVarRegistry::set_allowlist), it
+/// only restricts changes coming from tools; the engine's own code can
+/// always set its variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CVarAllowlist {
+    entries: Vec<String>,
+}
+
+impl CVarAllowlist {
+    /// Creates an allowlist from names and `prefix*` patterns.
+    pub fn new<I, S>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            entries: entries.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns `true` if `name` matches an entry.
+    pub fn permits(&self, name: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| match entry.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => entry == name,
+            })
+    }
+
+    /// Returns the entries, in insertion order.
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+}
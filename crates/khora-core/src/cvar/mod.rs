@@ -0,0 +1,45 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Console variables: named, typed engine settings changeable at runtime.
+//!
+//! Subsystems register the variables they read in a shared
+//! [`CVarRegistry`] and poll them; tools and the app change them by name.
+//! Every change is logged and bumps the registry's
+//! [`generation`](CVarRegistry::generation), so readers can skip work while
+//! nothing changed.
+//!
+//! ```rust,ignore
+//! let cvars = services.get::<CVarRegistry>().unwrap();
+//! cvars.set("power.battery_fps_cap", 45)?;
+//! cvars.set_str("power.governor", "false")?;
+//! ```
+//!
+//! Tools change variables through the `_from` setters, which tag the change
+//! with its [`CVarOrigin`] in the log and honor an optional
+//! [`CVarAllowlist`]. Remote tools talk to the registry with
+//! [`CVarRequest`] / [`CVarResponse`] messages.
+//!
+//! [`save`](CVarRegistry::save) and [`load`](CVarRegistry::load) keep
+//! changed values across runs in a JSON file.
+
+mod allowlist;
+mod registry;
+mod remote;
+mod value;
+
+pub use allowlist::CVarAllowlist;
+pub use registry::{CVar, CVarError, CVarOrigin, CVarRegistry};
+pub use remote::{CVarRequest, CVarResponse};
+pub use value::{CVarKind, CVarValue};
@@ -0,0 +1,633 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The shared console variable registry.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use super::allowlist::CVarAllowlist;
+use super::value::{CVarKind, CVarValue};
+
+/// A registered console variable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CVar {
+    /// Dotted name, e.g. `power.battery_fps_cap`.
+    pub name: String,
+    /// One-line description shown by tools.
+    pub description: String,
+    /// Value at registration, restored by [`CVarRegistry::reset`].
+    pub default: CVarValue,
+    /// Current value.
+    pub value: CVarValue,
+    /// Inclusive bounds of a numeric variable.
+    pub range: Option<(f64, f64)>,
+}
+
+impl CVar {
+    /// Returns the type of the variable.
+    pub fn kind(&self) -> CVarKind {
+        self.default.kind()
+    }
+}
+
+/// Who changed a console variable, recorded in the change log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CVarOrigin {
+    /// Engine or game code. Never restricted by the allowlist.
+    Code,
+    /// The in-editor debug overlay.
+    Overlay,
+    /// A remote debugging client, identified by its peer name.
+    Remote(String),
+}
+
+impl CVarOrigin {
+    /// Returns `true` for tools, whose changes the allowlist restricts.
+    pub fn is_tool(&self) -> bool {
+        !matches!(self, CVarOrigin::Code)
+    }
+}
+
+impl fmt::Display for CVarOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CVarOrigin::Code => f.write_str("code"),
+            CVarOrigin::Overlay => f.write_str("overlay"),
+            CVarOrigin::Remote(peer) => write!(f, "remote:{peer}"),
+        }
+    }
+}
+
+/// An error raised when registering or changing a console variable.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CVarError {
+    /// No variable has this name.
+    Unknown(String),
+    /// The value does not have the variable's type.
+    TypeMismatch {
+        /// Name of the variable.
+        name: String,
+        /// Type of the variable.
+        expected: CVarKind,
+        /// Type of the rejected value.
+        found: CVarKind,
+    },
+    /// A numeric value lies outside the variable's range.
+    OutOfRange {
+        /// Name of the variable.
+        name: String,
+        /// The rejected value.
+        value: f64,
+        /// Lower bound.
+        min: f64,
+        /// Upper bound.
+        max: f64,
+    },
+    /// Text could not be parsed as the variable's type.
+    Parse {
+        /// Name of the variable.
+        name: String,
+        /// The rejected text.
+        text: String,
+        /// Type of the variable.
+        expected: CVarKind,
+    },
+    /// The allowlist does not let this origin change the variable.
+    NotAllowed {
+        /// Name of the variable.
+        name: String,
+        /// Who attempted the change.
+        origin: CVarOrigin,
+    },
+}
+
+impl fmt::Display for CVarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CVarError::Unknown(name) => write!(f, "Unknown cvar '{name}'"),
+            CVarError::TypeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(f, "Cvar '{name}' is {expected:?}, got {found:?}"),
+            CVarError::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => write!(f, "Cvar '{name}': {value} is outside {min}..={max}"),
+            CVarError::Parse {
+                name,
+                text,
+                expected,
+            } => write!(f, "Cvar '{name}': cannot parse '{text}' as {expected:?}"),
+            CVarError::NotAllowed { name, origin } => {
+                write!(f, "Cvar '{name}' cannot be changed from {origin}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CVarError {}
+
+/// Shared registry of console variables.
+///
+/// Cloning is cheap and every clone sees the same variables, so the
+/// registry is handed around as a service.
+#[derive(Debug, Clone, Default)]
+pub struct CVarRegistry {
+    vars: Arc<RwLock<BTreeMap<String, CVar>>>,
+    generation: Arc<AtomicU64>,
+    allowlist: Arc<RwLock<Option<CVarAllowlist>>>,
+    /// Values read by [`load`](Self::load) for variables not registered
+    /// yet, applied when they are.
+    loaded: Arc<RwLock<BTreeMap<String, CVarValue>>>,
+}
+
+impl CVarRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a variable.
+    ///
+    /// Registering a name again with the same type keeps its current value,
+    /// so several owners can declare the variables they read.
+    pub fn register(
+        &self,
+        name: &str,
+        description: &str,
+        default: impl Into<CVarValue>,
+    ) -> Result<(), CVarError> {
+        self.insert(name, description, default.into(), None)
+    }
+
+    /// Registers a numeric variable bounded to `min..=max`.
+    pub fn register_ranged(
+        &self,
+        name: &str,
+        description: &str,
+        default: impl Into<CVarValue>,
+        min: f64,
+        max: f64,
+    ) -> Result<(), CVarError> {
+        self.insert(name, description, default.into(), Some((min, max)))
+    }
+
+    fn insert(
+        &self,
+        name: &str,
+        description: &str,
+        default: CVarValue,
+        range: Option<(f64, f64)>,
+    ) -> Result<(), CVarError> {
+        let mut vars = self.vars.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = vars.get(name) {
+            return if existing.kind() == default.kind() {
+                Ok(())
+            } else {
+                Err(CVarError::TypeMismatch {
+                    name: name.to_string(),
+                    expected: existing.kind(),
+                    found: default.kind(),
+                })
+            };
+        }
+        if let (Some(value), Some((min, max))) = (default.as_float(), range) {
+            if !(min..=max).contains(&value) {
+                return Err(CVarError::OutOfRange {
+                    name: name.to_string(),
+                    value,
+                    min,
+                    max,
+                });
+            }
+        }
+        vars.insert(
+            name.to_string(),
+            CVar {
+                name: name.to_string(),
+                description: description.to_string(),
+                value: default.clone(),
+                default,
+                range,
+            },
+        );
+        drop(vars);
+
+        let loaded = self
+            .loaded
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(name);
+        if let Some(value) = loaded {
+            self.apply_loaded(name, value);
+        }
+        Ok(())
+    }
+
+    /// Returns the current value of `name`.
+    pub fn get(&self, name: &str) -> Option<CVarValue> {
+        let vars = self.vars.read().unwrap_or_else(|e| e.into_inner());
+        vars.get(name).map(|var| var.value.clone())
+    }
+
+    /// Returns `name` as a boolean.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get(name)?.as_bool()
+    }
+
+    /// Returns `name` as an integer.
+    pub fn get_int(&self, name: &str) -> Option<i64> {
+        self.get(name)?.as_int()
+    }
+
+    /// Returns `name` as a float (integers are converted).
+    pub fn get_float(&self, name: &str) -> Option<f64> {
+        self.get(name)?.as_float()
+    }
+
+    /// Returns `name` as text.
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        match self.get(name)? {
+            CVarValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the full description of `name`.
+    pub fn info(&self, name: &str) -> Option<CVar> {
+        let vars = self.vars.read().unwrap_or_else(|e| e.into_inner());
+        vars.get(name).cloned()
+    }
+
+    /// Returns every variable, sorted by name.
+    pub fn list(&self) -> Vec<CVar> {
+        let vars = self.vars.read().unwrap_or_else(|e| e.into_inner());
+        vars.values().cloned().collect()
+    }
+
+    /// Restricts which variables tools may change, or lifts the restriction
+    /// with `None`.
+    pub fn set_allowlist(&self, allowlist: Option<CVarAllowlist>) {
+        *self.allowlist.write().unwrap_or_else(|e| e.into_inner()) = allowlist;
+    }
+
+    /// Returns `true` if `origin` may change `name` under the current
+    /// allowlist.
+    pub fn is_editable(&self, name: &str, origin: &CVarOrigin) -> bool {
+        if !origin.is_tool() {
+            return true;
+        }
+        let allowlist = self.allowlist.read().unwrap_or_else(|e| e.into_inner());
+        allowlist.as_ref().is_none_or(|list| list.permits(name))
+    }
+
+    /// Sets `name` to `value` and returns the previous value.
+    ///
+    /// Integers are accepted by float variables. Numeric values must lie in
+    /// the variable's range.
+    pub fn set(&self, name: &str, value: impl Into<CVarValue>) -> Result<CVarValue, CVarError> {
+        self.set_from(name, value, CVarOrigin::Code)
+    }
+
+    /// Sets `name` on behalf of `origin`, checking the allowlist.
+    ///
+    /// The change is logged with its origin; refused attempts are logged as
+    /// warnings.
+    pub fn set_from(
+        &self,
+        name: &str,
+        value: impl Into<CVarValue>,
+        origin: CVarOrigin,
+    ) -> Result<CVarValue, CVarError> {
+        if !self.is_editable(name, &origin) {
+            log::warn!("CVar {name}: change from {origin} refused by the allowlist");
+            return Err(CVarError::NotAllowed {
+                name: name.to_string(),
+                origin,
+            });
+        }
+
+        let mut value = value.into();
+        let mut vars = self.vars.write().unwrap_or_else(|e| e.into_inner());
+        let var = vars
+            .get_mut(name)
+            .ok_or_else(|| CVarError::Unknown(name.to_string()))?;
+
+        if let (CVarKind::Float, CVarValue::Int(int)) = (var.kind(), &value) {
+            value = CVarValue::Float(*int as f64);
+        }
+        if value.kind() != var.kind() {
+            return Err(CVarError::TypeMismatch {
+                name: name.to_string(),
+                expected: var.kind(),
+                found: value.kind(),
+            });
+        }
+        if let (Some(number), Some((min, max))) = (value.as_float(), var.range) {
+            if !(min..=max).contains(&number) {
+                return Err(CVarError::OutOfRange {
+                    name: name.to_string(),
+                    value: number,
+                    min,
+                    max,
+                });
+            }
+        }
+
+        let previous = std::mem::replace(&mut var.value, value);
+        if previous != var.value {
+            log::info!(
+                "CVar {} = {} (was {}) by {}",
+                name,
+                var.value,
+                previous,
+                origin
+            );
+            self.generation.fetch_add(1, Ordering::Release);
+        }
+        Ok(previous)
+    }
+
+    /// Parses `text` as the type of `name` and sets it.
+    pub fn set_str(&self, name: &str, text: &str) -> Result<CVarValue, CVarError> {
+        self.set_str_from(name, text, CVarOrigin::Code)
+    }
+
+    /// Parses `text` as the type of `name` and sets it on behalf of `origin`.
+    pub fn set_str_from(
+        &self,
+        name: &str,
+        text: &str,
+        origin: CVarOrigin,
+    ) -> Result<CVarValue, CVarError> {
+        let kind = self
+            .info(name)
+            .ok_or_else(|| CVarError::Unknown(name.to_string()))?
+            .kind();
+        let value = CVarValue::parse(kind, text).ok_or_else(|| CVarError::Parse {
+            name: name.to_string(),
+            text: text.to_string(),
+            expected: kind,
+        })?;
+        self.set_from(name, value, origin)
+    }
+
+    /// Restores the default value of `name`.
+    pub fn reset(&self, name: &str) -> Result<CVarValue, CVarError> {
+        self.reset_from(name, CVarOrigin::Code)
+    }
+
+    /// Restores the default value of `name` on behalf of `origin`.
+    pub fn reset_from(&self, name: &str, origin: CVarOrigin) -> Result<CVarValue, CVarError> {
+        let default = self
+            .info(name)
+            .ok_or_else(|| CVarError::Unknown(name.to_string()))?
+            .default;
+        self.set_from(name, default, origin)
+    }
+
+    /// Counter bumped by every change of value.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Writes every variable changed from its default to `path`, as JSON.
+    ///
+    /// Values loaded for variables that were never registered this run are
+    /// written back too, so they are not lost. Returns the number of values
+    /// written.
+    pub fn save(&self, path: &Path) -> io::Result<usize> {
+        let mut values = self
+            .loaded
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        let vars = self.vars.read().unwrap_or_else(|e| e.into_inner());
+        for var in vars.values().filter(|var| var.value != var.default) {
+            values.insert(var.name.clone(), var.value.clone());
+        }
+        drop(vars);
+
+        let json = serde_json::to_string_pretty(&values).map_err(io::Error::other)?;
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, json)?;
+        Ok(values.len())
+    }
+
+    /// Reads values written by [`save`](Self::save) from `path`.
+    ///
+    /// Registered variables are set at once; the others are set when they
+    /// are registered. Values that no longer fit their variable are logged
+    /// and skipped. A missing file is not an error: nothing was saved yet.
+    /// Returns the number of values read.
+    pub fn load(&self, path: &Path) -> io::Result<usize> {
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let values: BTreeMap<String, CVarValue> = serde_json::from_str(&json)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let count = values.len();
+        for (name, value) in values {
+            if self.info(&name).is_some() {
+                self.apply_loaded(&name, value);
+            } else {
+                self.loaded
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .insert(name, value);
+            }
+        }
+        Ok(count)
+    }
+
+    /// Sets `name` to a value read by [`load`](Self::load).
+    fn apply_loaded(&self, name: &str, value: CVarValue) {
+        if let Err(e) = self.set(name, value) {
+            log::warn!("CVar {name}: saved value skipped: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_get_and_set() {
+        let cvars = CVarRegistry::new();
+        cvars.register("r.vsync", "Wait for vblank", true).unwrap();
+        cvars
+            .register_ranged("r.scale", "Render scale", 1.0, 0.25, 2.0)
+            .unwrap();
+
+        assert_eq!(cvars.get_bool("r.vsync"), Some(true));
+        let generation = cvars.generation();
+
+        assert_eq!(cvars.set("r.vsync", false), Ok(CVarValue::Bool(true)));
+        assert_eq!(cvars.set("r.scale", 2), Ok(CVarValue::Float(1.0)));
+        assert_eq!(cvars.get_float("r.scale"), Some(2.0));
+        assert_eq!(cvars.generation(), generation + 2);
+
+        // Setting the same value again is not a change.
+        cvars.set("r.scale", 2.0).unwrap();
+        assert_eq!(cvars.generation(), generation + 2);
+    }
+
+    #[test]
+    fn test_rejects_bad_values() {
+        let cvars = CVarRegistry::new();
+        cvars
+            .register_ranged("fps_cap", "Frame cap", 60, 0.0, 240.0)
+            .unwrap();
+
+        assert!(matches!(
+            cvars.set("fps_cap", true),
+            Err(CVarError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            cvars.set("fps_cap", 500),
+            Err(CVarError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            cvars.set_str("fps_cap", "fast"),
+            Err(CVarError::Parse { .. })
+        ));
+        assert_eq!(
+            cvars.set("missing", 1),
+            Err(CVarError::Unknown("missing".into()))
+        );
+        assert_eq!(cvars.get_int("fps_cap"), Some(60));
+    }
+
+    #[test]
+    fn test_reregister_keeps_value_and_reset_restores_default() {
+        let cvars = CVarRegistry::new();
+        cvars
+            .register("power.governor", "Governor on", true)
+            .unwrap();
+        cvars.set_str("power.governor", "off").unwrap();
+
+        cvars
+            .register("power.governor", "Governor on", true)
+            .unwrap();
+        assert_eq!(cvars.get_bool("power.governor"), Some(false));
+        assert!(cvars.register("power.governor", "Wrong type", 1).is_err());
+
+        cvars.reset("power.governor").unwrap();
+        assert_eq!(cvars.get_bool("power.governor"), Some(true));
+        assert_eq!(cvars.list().len(), 1);
+    }
+
+    #[test]
+    fn test_allowlist_restricts_tools_only() {
+        let cvars = CVarRegistry::new();
+        cvars
+            .register("power.governor", "Governor on", true)
+            .unwrap();
+        cvars.register("r.wireframe", "Wireframe", false).unwrap();
+        cvars.set_allowlist(Some(CVarAllowlist::new(["power.*"])));
+
+        let remote = CVarOrigin::Remote("127.0.0.1:7777".into());
+        assert!(cvars
+            .set_from("power.governor", false, remote.clone())
+            .is_ok());
+        assert_eq!(
+            cvars.set_from("r.wireframe", true, remote.clone()),
+            Err(CVarError::NotAllowed {
+                name: "r.wireframe".into(),
+                origin: remote,
+            })
+        );
+        assert!(!cvars.is_editable("r.wireframe", &CVarOrigin::Overlay));
+        assert!(cvars.set("r.wireframe", true).is_ok());
+
+        cvars.set_allowlist(None);
+        assert!(cvars.is_editable("r.wireframe", &CVarOrigin::Overlay));
+    }
+
+    #[test]
+    fn test_save_and_load_keep_changed_values() {
+        let path =
+            std::env::temp_dir().join(format!("khora-cvars-roundtrip-{}.json", std::process::id()));
+        let cvars = CVarRegistry::new();
+        cvars.register("r.vsync", "Wait for vblank", true).unwrap();
+        cvars
+            .register_ranged("r.scale", "Render scale", 1.0, 0.25, 2.0)
+            .unwrap();
+        cvars.register("game.name", "Save slot", "default").unwrap();
+        cvars.set("r.scale", 0.5).unwrap();
+        cvars.set("game.name", "slot 2").unwrap();
+        // Only changed values are written.
+        assert_eq!(cvars.save(&path).unwrap(), 2);
+
+        let restored = CVarRegistry::new();
+        restored
+            .register_ranged("r.scale", "Render scale", 1.0, 0.25, 2.0)
+            .unwrap();
+        assert_eq!(restored.load(&path).unwrap(), 2);
+        assert_eq!(restored.get_float("r.scale"), Some(0.5));
+
+        // Registered after loading: picks up its saved value then.
+        restored
+            .register("game.name", "Save slot", "default")
+            .unwrap();
+        assert_eq!(restored.get_string("game.name"), Some("slot 2".into()));
+        restored
+            .register("r.vsync", "Wait for vblank", true)
+            .unwrap();
+        assert_eq!(restored.get_bool("r.vsync"), Some(true));
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(CVarRegistry::new().load(&path).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_unregistered_loaded_values_are_saved_again() {
+        let path = std::env::temp_dir().join(format!(
+            "khora-cvars-unregistered-{}.json",
+            std::process::id()
+        ));
+        let cvars = CVarRegistry::new();
+        cvars.register("audio.volume", "Volume", 1.0).unwrap();
+        cvars.set("audio.volume", 0.25).unwrap();
+        cvars.save(&path).unwrap();
+
+        // A run that never registers the variable keeps it in the file.
+        let other = CVarRegistry::new();
+        other.load(&path).unwrap();
+        other.save(&path).unwrap();
+
+        let last = CVarRegistry::new();
+        last.load(&path).unwrap();
+        last.register("audio.volume", "Volume", 1.0).unwrap();
+        assert_eq!(last.get_float("audio.volume"), Some(0.25));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Console variable messages for remote debugging tools.
+//!
+//! The messages are transport-agnostic: a debug server deserializes a
+//! [`CVarRequest`] from its connection, answers it with
+//! [`CVarRegistry::handle_request`] and sends the [`CVarResponse`] back.
+//! Clients replicate the variables by polling [`CVarRequest::List`] with the
+//! last generation they saw; an unchanged registry answers
+//! [`CVarResponse::Unchanged`] without copying anything.
+
+use serde::{Deserialize, Serialize};
+
+use super::registry::{CVar, CVarOrigin, CVarRegistry};
+use super::value::CVarValue;
+
+/// A request from a remote tool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CVarRequest {
+    /// Lists the variables the client may change.
+    List {
+        /// The generation the client already holds, if any.
+        since: Option<u64>,
+    },
+    /// Describes one variable.
+    Get {
+        /// Name of the variable.
+        name: String,
+    },
+    /// Sets a variable to a typed value.
+    Set {
+        /// Name of the variable.
+        name: String,
+        /// The new value.
+        value: CVarValue,
+    },
+    /// Parses text as the variable's type and sets it.
+    SetText {
+        /// Name of the variable.
+        name: String,
+        /// The new value, as typed in a console.
+        text: String,
+    },
+    /// Restores a variable's default value.
+    Reset {
+        /// Name of the variable.
+        name: String,
+    },
+}
+
+/// The answer to a [`CVarRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CVarResponse {
+    /// The variables the client may change, sorted by name.
+    List {
+        /// Generation of the registry the list was taken at.
+        generation: u64,
+        /// The variables.
+        vars: Vec<CVar>,
+    },
+    /// No variable changed since the generation the client holds.
+    Unchanged {
+        /// Current generation of the registry.
+        generation: u64,
+    },
+    /// One variable, after the request was applied.
+    Var(CVar),
+    /// The request failed; the message is meant for display.
+    Error(String),
+}
+
+impl CVarRegistry {
+    /// Answers a request from the remote tool identified by `peer`.
+    ///
+    /// Changes go through [`CVarRegistry::set_from`] with
+    /// [`CVarOrigin::Remote`], so they are logged and checked against the
+    /// allowlist. Variables the allowlist hides from tools are neither listed
+    /// nor described.
+    pub fn handle_request(&self, request: CVarRequest, peer: &str) -> CVarResponse {
+        let origin = CVarOrigin::Remote(peer.to_string());
+        let result = match request {
+            CVarRequest::List { since } => {
+                // Read the generation first: a change racing the list then
+                // shows up again on the next poll rather than being missed.
+                let generation = self.generation();
+                if since == Some(generation) {
+                    return CVarResponse::Unchanged { generation };
+                }
+                let vars = self
+                    .list()
+                    .into_iter()
+                    .filter(|var| self.is_editable(&var.name, &origin))
+                    .collect();
+                return CVarResponse::List { generation, vars };
+            }
+            CVarRequest::Get { name } => Ok(name),
+            CVarRequest::Set { name, value } => {
+                self.set_from(&name, value, origin.clone()).map(|_| name)
+            }
+            CVarRequest::SetText { name, text } => self
+                .set_str_from(&name, &text, origin.clone())
+                .map(|_| name),
+            CVarRequest::Reset { name } => self.reset_from(&name, origin.clone()).map(|_| name),
+        };
+
+        match result {
+            Ok(name) => match self.info(&name) {
+                Some(var) if self.is_editable(&name, &origin) => CVarResponse::Var(var),
+                _ => CVarResponse::Error(format!("Unknown cvar '{name}'")),
+            },
+            Err(e) => CVarResponse::Error(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cvar::CVarAllowlist;
+
+    #[test]
+    fn test_remote_list_set_and_replication() {
+        let cvars = CVarRegistry::new();
+        cvars
+            .register_ranged("power.battery_fps_cap", "FPS cap", 30, 0.0, 240.0)
+            .unwrap();
+        cvars.register("r.wireframe", "Wireframe", false).unwrap();
+
+        let CVarResponse::List { generation, vars } =
+            cvars.handle_request(CVarRequest::List { since: None }, "tool")
+        else {
+            panic!("expected a list");
+        };
+        assert_eq!(vars.len(), 2);
+        assert_eq!(
+            cvars.handle_request(
+                CVarRequest::List {
+                    since: Some(generation)
+                },
+                "tool"
+            ),
+            CVarResponse::Unchanged { generation }
+        );
+
+        let response = cvars.handle_request(
+            CVarRequest::SetText {
+                name: "power.battery_fps_cap".into(),
+                text: "45".into(),
+            },
+            "tool",
+        );
+        assert!(matches!(response, CVarResponse::Var(var) if var.value == CVarValue::Int(45)));
+        assert!(matches!(
+            cvars.handle_request(
+                CVarRequest::List {
+                    since: Some(generation)
+                },
+                "tool"
+            ),
+            CVarResponse::List { .. }
+        ));
+    }
+
+    #[test]
+    fn test_remote_respects_allowlist() {
+        let cvars = CVarRegistry::new();
+        cvars
+            .register("power.governor", "Governor on", true)
+            .unwrap();
+        cvars.register("r.wireframe", "Wireframe", false).unwrap();
+        cvars.set_allowlist(Some(CVarAllowlist::new(["power.governor"])));
+
+        let CVarResponse::List { vars, .. } =
+            cvars.handle_request(CVarRequest::List { since: None }, "tool")
+        else {
+            panic!("expected a list");
+        };
+        assert_eq!(vars.len(), 1);
+        assert!(matches!(
+            cvars.handle_request(
+                CVarRequest::Set {
+                    name: "r.wireframe".into(),
+                    value: CVarValue::Bool(true),
+                },
+                "tool",
+            ),
+            CVarResponse::Error(_)
+        ));
+        assert!(matches!(
+            cvars.handle_request(
+                CVarRequest::Get {
+                    name: "r.wireframe".into()
+                },
+                "tool"
+            ),
+            CVarResponse::Error(_)
+        ));
+        assert_eq!(cvars.get_bool("r.wireframe"), Some(false));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Values held by console variables.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The type of a console variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CVarKind {
+    /// `true` or `false`.
+    Bool,
+    /// A signed integer.
+    Int,
+    /// A floating-point number.
+    Float,
+    /// Free text.
+    String,
+}
+
+/// The value of a console variable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CVarValue {
+    /// A boolean.
+    Bool(bool),
+    /// A signed integer.
+    Int(i64),
+    /// A floating-point number.
+    Float(f64),
+    /// Free text.
+    String(String),
+}
+
+impl CVarValue {
+    /// Returns the type of the value.
+    pub fn kind(&self) -> CVarKind {
+        match self {
+            CVarValue::Bool(_) => CVarKind::Bool,
+            CVarValue::Int(_) => CVarKind::Int,
+            CVarValue::Float(_) => CVarKind::Float,
+            CVarValue::String(_) => CVarKind::String,
+        }
+    }
+
+    /// Returns the boolean, if this is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            CVarValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer, if this is one.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            CVarValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the number as a float, for both integers and floats.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            CVarValue::Int(value) => Some(*value as f64),
+            CVarValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the text, if this is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            CVarValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Parses `text` as a value of type `kind`.
+    ///
+    /// Booleans accept `true`/`false`, `on`/`off` and `1`/`0`.
+    pub fn parse(kind: CVarKind, text: &str) -> Option<Self> {
+        let text = text.trim();
+        match kind {
+            CVarKind::Bool => match text.to_ascii_lowercase().as_str() {
+                "true" | "on" | "1" => Some(CVarValue::Bool(true)),
+                "false" | "off" | "0" => Some(CVarValue::Bool(false)),
+                _ => None,
+            },
+            CVarKind::Int => text.parse().ok().map(CVarValue::Int),
+            CVarKind::Float => text
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(CVarValue::Float),
+            CVarKind::String => Some(CVarValue::String(text.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for CVarValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CVarValue::Bool(value) => write!(f, "{value}"),
+            CVarValue::Int(value) => write!(f, "{value}"),
+            CVarValue::Float(value) => write!(f, "{value}"),
+            CVarValue::String(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+impl From<bool> for CVarValue {
+    fn from(value: bool) -> Self {
+        CVarValue::Bool(value)
+    }
+}
+
+impl From<i64> for CVarValue {
+    fn from(value: i64) -> Self {
+        CVarValue::Int(value)
+    }
+}
+
+impl From<i32> for CVarValue {
+    fn from(value: i32) -> Self {
+        CVarValue::Int(value.into())
+    }
+}
+
+impl From<u32> for CVarValue {
+    fn from(value: u32) -> Self {
+        CVarValue::Int(value.into())
+    }
+}
+
+impl From<f64> for CVarValue {
+    fn from(value: f64) -> Self {
+        CVarValue::Float(value)
+    }
+}
+
+impl From<f32> for CVarValue {
+    fn from(value: f32) -> Self {
+        CVarValue::Float(value.into())
+    }
+}
+
+impl From<&str> for CVarValue {
+    fn from(value: &str) -> Self {
+        CVarValue::String(value.to_string())
+    }
+}
+
+impl From<String> for CVarValue {
+    fn from(value: String) -> Self {
+        CVarValue::String(value)
+    }
+}
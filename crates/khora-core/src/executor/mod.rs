@@ -0,0 +1,39 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Executor abstraction for async work: asset streaming, networking, HTTP.
+//!
+//! Code that needs async takes a [`SharedExecutor`] from the
+//! `ServiceRegistry` instead of picking a runtime itself. The trait only
+//! asks for the primitives every runtime has — spawn a future, run a
+//! blocking job, wake up at a deadline — so tokio, async-std or the
+//! engine's own executors in `khora-infra` can all stand behind it.
+//!
+//! ```rust,ignore
+//! let executor = services.get::<SharedExecutor>().unwrap();
+//! let task = executor.spawn_blocking(move || std::fs::read(path));
+//! executor.spawn(async move {
+//!     if let Some(Ok(bytes)) = task.await {
+//!         // ...
+//!     }
+//! });
+//! ```
+
+mod block_on;
+mod spawn;
+mod task;
+
+pub use block_on::block_on;
+pub use spawn::{BoxFuture, Executor, SharedExecutor};
+pub use task::Task;
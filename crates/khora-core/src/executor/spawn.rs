@@ -0,0 +1,108 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The [`Executor`] trait and its typed helpers.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+use super::task::Task;
+
+/// A boxed, sendable future.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Shared handle to the engine's executor, registered in the
+/// `ServiceRegistry`.
+pub type SharedExecutor = Arc<dyn Executor>;
+
+/// Runs async tasks, blocking jobs and timers.
+///
+/// Implementations only provide the type-erased primitives. Callers use the
+/// typed helpers on `dyn Executor` — [`spawn`](#method.spawn),
+/// [`spawn_blocking`](#method.spawn_blocking), [`sleep`](#method.sleep) and
+/// [`timeout`](#method.timeout) — which return the task's output through a
+/// [`Task`].
+pub trait Executor: Send + Sync {
+    /// A short name for logs.
+    fn name(&self) -> &str;
+
+    /// Polls `future` to completion on the executor.
+    fn spawn_boxed(&self, future: BoxFuture<'static, ()>);
+
+    /// Runs `job` on a thread where blocking (file I/O, DNS, compression)
+    /// is allowed.
+    fn spawn_blocking_boxed(&self, job: Box<dyn FnOnce() + Send + 'static>);
+
+    /// Returns a future that completes once `deadline` has passed.
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()>;
+
+    /// Gives the executor a chance to make progress on the engine thread.
+    /// Called once per frame; executors with their own threads ignore it.
+    fn tick(&self) {}
+
+    /// Stops accepting work and releases the executor's threads. Tasks that
+    /// have not finished are dropped.
+    fn shutdown(&self) {}
+}
+
+impl dyn Executor {
+    /// Spawns `future` and returns a [`Task`] resolving to its output.
+    pub fn spawn<T, F>(&self, future: F) -> Task<T>
+    where
+        T: Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        let (completer, task) = Task::pair();
+        self.spawn_boxed(Box::pin(async move {
+            completer.complete(future.await);
+        }));
+        task
+    }
+
+    /// Runs `job` on a blocking thread and returns a [`Task`] resolving to
+    /// its result.
+    pub fn spawn_blocking<T, F>(&self, job: F) -> Task<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (completer, task) = Task::pair();
+        self.spawn_blocking_boxed(Box::new(move || completer.complete(job())));
+        task
+    }
+
+    /// Returns a future that completes after `duration`.
+    pub fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        self.sleep_until(Instant::now() + duration)
+    }
+
+    /// Runs `future` until it completes or `duration` runs out, whichever
+    /// comes first. Resolves to `None` on timeout.
+    pub fn timeout<T, F>(&self, duration: Duration, future: F) -> impl Future<Output = Option<T>>
+    where
+        F: Future<Output = T>,
+    {
+        let mut deadline = self.sleep(duration);
+        let mut future = Box::pin(future);
+        std::future::poll_fn(move |cx| {
+            if let Poll::Ready(value) = future.as_mut().poll(cx) {
+                return Poll::Ready(Some(value));
+            }
+            deadline.as_mut().poll(cx).map(|()| None)
+        })
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The handle to a spawned task's output.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+struct Slot<T> {
+    value: Option<T>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+/// The output of a task spawned through an [`Executor`](super::Executor).
+///
+/// Awaiting it yields `Some(output)`, or `None` if the task was dropped
+/// before finishing: it panicked or the executor shut down. Dropping a
+/// `Task` does not cancel the work; the output is discarded.
+pub struct Task<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+/// The sending half of a [`Task`], moved into the spawned work.
+pub(crate) struct Completer<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+impl<T> Task<T> {
+    pub(crate) fn pair() -> (Completer<T>, Task<T>) {
+        let slot = Arc::new(Mutex::new(Slot {
+            value: None,
+            closed: false,
+            waker: None,
+        }));
+        (
+            Completer {
+                slot: Arc::clone(&slot),
+            },
+            Task { slot },
+        )
+    }
+
+    /// Returns `true` once the task has finished or was dropped.
+    pub fn is_finished(&self) -> bool {
+        self.slot
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .closed
+    }
+
+    /// Takes the output if the task has finished, without waiting.
+    pub fn try_take(&mut self) -> Option<T> {
+        self.slot
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .value
+            .take()
+    }
+}
+
+impl<T> Future for Task<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut slot = self.slot.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(value) = slot.value.take() {
+            return Poll::Ready(Some(value));
+        }
+        if slot.closed {
+            return Poll::Ready(None);
+        }
+        slot.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Completer<T> {
+    /// Stores the task's output and wakes the awaiting side.
+    pub(crate) fn complete(self, value: T) {
+        self.slot
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .value = Some(value);
+        // `Drop` closes the slot and wakes the task.
+    }
+}
+
+impl<T> Drop for Completer<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut slot = self.slot.lock().unwrap_or_else(PoisonError::into_inner);
+            slot.closed = true;
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The error returned when a dependency graph is not acyclic.
+
+use std::fmt;
+
+/// An error indicating that a cycle was detected in the graph.
+///
+/// Carries the nodes of one cycle so the offending dependencies can be
+/// reported: each node depends on the one before it, and the first node
+/// depends on the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<T> {
+    /// The nodes forming the cycle, in dependency order.
+    pub cycle: Vec<T>,
+}
+
+impl<T> CycleError<T> {
+    /// Converts the nodes of the cycle, e.g. from graph IDs to names.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> CycleError<U> {
+        CycleError {
+            cycle: self.cycle.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for CycleError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dependency cycle: ")?;
+        for node in &self.cycle {
+            write!(f, "{:?} -> ", node)?;
+        }
+        match self.cycle.first() {
+            Some(first) => write!(f, "{:?}", first),
+            None => write!(f, "(unknown)"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for CycleError<T> {}
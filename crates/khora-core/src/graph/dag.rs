@@ -0,0 +1,331 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A directed acyclic graph with typed node and edge payloads.
+
+use std::collections::VecDeque;
+use std::ops::{Index, IndexMut};
+
+use super::CycleError;
+
+/// Identifies a node of a [`Dag`]. Only meaningful for the graph that
+/// returned it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the insertion index of the node.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A dependency graph: an edge `from -> to` means `from` must come first.
+///
+/// Nodes carry an `N` payload and edges an `E` payload. Acyclicity is not
+/// enforced on insertion; [`topological_order`](Self::topological_order)
+/// and [`batches`](Self::batches) report a cycle when they meet one, and
+/// [`find_cycle`](Self::find_cycle) looks for one explicitly.
+///
+/// Every ordering is stable: nodes that are free to go in any order keep
+/// their insertion order.
+///
+/// ```rust
+/// use khora_core::graph::Dag;
+///
+/// let mut graph: Dag<&str> = Dag::new();
+/// let shadows = graph.add_node("shadows");
+/// let scene = graph.add_node("scene");
+/// let ui = graph.add_node("ui");
+/// graph.add_edge(shadows, scene, ());
+///
+/// let batches = graph.batches().unwrap();
+/// assert_eq!(batches, vec![vec![shadows, ui], vec![scene]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dag<N, E = ()> {
+    nodes: Vec<N>,
+    edges: Vec<(NodeId, NodeId, E)>,
+    /// Indices into `edges` leaving each node.
+    outgoing: Vec<Vec<usize>>,
+    /// Indices into `edges` entering each node.
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<N, E> Default for Dag<N, E> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+}
+
+impl<N, E> Dag<N, E> {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node and returns its ID.
+    pub fn add_node(&mut self, payload: N) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(payload);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        id
+    }
+
+    /// Adds an edge stating that `from` comes before `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is not part of this graph.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, payload: E) {
+        assert!(
+            from.0 < self.nodes.len() && to.0 < self.nodes.len(),
+            "Dag::add_edge: unknown node"
+        );
+        let edge = self.edges.len();
+        self.edges.push((from, to, payload));
+        self.outgoing[from.0].push(edge);
+        self.incoming[to.0].push(edge);
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the payload of `id`.
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.nodes.get(id.0)
+    }
+
+    /// Returns the payload of `id` mutably.
+    pub fn node_mut(&mut self, id: NodeId) -> Option<&mut N> {
+        self.nodes.get_mut(id.0)
+    }
+
+    /// Iterates over the nodes in insertion order.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate().map(|(i, n)| (NodeId(i), n))
+    }
+
+    /// Iterates over the edges as `(from, to, payload)`, in insertion order.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId, &E)> {
+        self.edges.iter().map(|(from, to, e)| (*from, *to, e))
+    }
+
+    /// Iterates over the nodes that come right after `id`.
+    pub fn successors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.outgoing
+            .get(id.0)
+            .into_iter()
+            .flatten()
+            .map(|&edge| self.edges[edge].1)
+    }
+
+    /// Iterates over the nodes that come right before `id`.
+    pub fn predecessors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.incoming
+            .get(id.0)
+            .into_iter()
+            .flatten()
+            .map(|&edge| self.edges[edge].0)
+    }
+
+    /// Returns the nodes in an order where every edge points forward.
+    ///
+    /// Uses Kahn's algorithm with a FIFO ready queue seeded in insertion
+    /// order, so a graph whose insertion order is already valid comes out
+    /// unchanged.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, CycleError<NodeId>> {
+        let mut in_degree: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&i| in_degree[i] == 0)
+            .collect();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(NodeId(node));
+            for &edge in &self.outgoing[node] {
+                let to = self.edges[edge].1 .0;
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Ok(order)
+        } else {
+            Err(self.cycle_error())
+        }
+    }
+
+    /// Groups the nodes into batches that can run in parallel.
+    ///
+    /// A node lands in the batch right after the latest of its
+    /// predecessors, so running the batches one after the other respects
+    /// every edge. Nodes keep their insertion order within a batch.
+    pub fn batches(&self) -> Result<Vec<Vec<NodeId>>, CycleError<NodeId>> {
+        let order = self.topological_order()?;
+        let mut level = vec![0usize; self.nodes.len()];
+        for id in &order {
+            level[id.0] = self
+                .predecessors(*id)
+                .map(|pred| level[pred.0] + 1)
+                .max()
+                .unwrap_or(0);
+        }
+
+        let mut batches: Vec<Vec<NodeId>> = Vec::new();
+        for (i, &l) in level.iter().enumerate() {
+            if batches.len() <= l {
+                batches.resize_with(l + 1, Vec::new);
+            }
+            batches[l].push(NodeId(i));
+        }
+        Ok(batches)
+    }
+
+    /// Returns the nodes of one cycle, in dependency order, or `None` if
+    /// the graph is acyclic.
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Unvisited,
+            OnPath,
+            Done,
+        }
+
+        let mut marks = vec![Mark::Unvisited; self.nodes.len()];
+        for start in 0..self.nodes.len() {
+            if marks[start] != Mark::Unvisited {
+                continue;
+            }
+            // Depth-first walk; each entry is a node and its next edge.
+            let mut path: Vec<(usize, usize)> = vec![(start, 0)];
+            marks[start] = Mark::OnPath;
+            while let Some(top) = path.last_mut() {
+                let node = top.0;
+                let Some(&edge) = self.outgoing[node].get(top.1) else {
+                    marks[node] = Mark::Done;
+                    path.pop();
+                    continue;
+                };
+                top.1 += 1;
+                let to = self.edges[edge].1 .0;
+                match marks[to] {
+                    Mark::Unvisited => {
+                        marks[to] = Mark::OnPath;
+                        path.push((to, 0));
+                    }
+                    Mark::OnPath => {
+                        let begin = path.iter().position(|&(n, _)| n == to).unwrap_or(0);
+                        return Some(path[begin..].iter().map(|&(n, _)| NodeId(n)).collect());
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    fn cycle_error(&self) -> CycleError<NodeId> {
+        CycleError {
+            cycle: self.find_cycle().unwrap_or_default(),
+        }
+    }
+}
+
+impl<N, E> Index<NodeId> for Dag<N, E> {
+    type Output = N;
+
+    fn index(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+}
+
+impl<N, E> IndexMut<NodeId> for Dag<N, E> {
+    fn index_mut(&mut self, id: NodeId) -> &mut N {
+        &mut self.nodes[id.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topological_order_keeps_valid_insertion_order() {
+        let mut graph: Dag<&str> = Dag::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, c, ());
+        assert_eq!(graph.topological_order().unwrap(), vec![a, b, c]);
+
+        graph.add_edge(c, b, ());
+        assert_eq!(graph.topological_order().unwrap(), vec![a, c, b]);
+    }
+
+    #[test]
+    fn batches_group_independent_nodes() {
+        let mut graph: Dag<u32, &str> = Dag::new();
+        let ids: Vec<NodeId> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(ids[0], ids[2], "x");
+        graph.add_edge(ids[1], ids[2], "y");
+        graph.add_edge(ids[2], ids[4], "z");
+
+        let batches = graph.batches().unwrap();
+        assert_eq!(
+            batches,
+            vec![vec![ids[0], ids[1], ids[3]], vec![ids[2]], vec![ids[4]]]
+        );
+        assert_eq!(graph.predecessors(ids[2]).count(), 2);
+    }
+
+    #[test]
+    fn cycles_are_reported_with_their_nodes() {
+        let mut graph: Dag<&str> = Dag::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        graph.add_edge(c, b, ());
+        graph.add_edge(c, d, ());
+        assert_eq!(graph.find_cycle(), Some(vec![b, c]));
+
+        let err = graph.batches().unwrap_err().map(|id| graph[id]);
+        assert_eq!(err.cycle, vec!["b", "c"]);
+        assert_eq!(err.to_string(), r#"dependency cycle: "b" -> "c" -> "b""#);
+    }
+}
@@ -13,7 +13,16 @@
 // limitations under the License.
 
 //! Provides generic, reusable graph algorithms and data structures.
+//!
+//! [`Dag`] is the general dependency graph: typed node and edge payloads,
+//! cycle detection, topological order and parallel batches. The render
+//! graph and the agent scheduler order their work with it.
+//! [`topological_sort`] covers the one-shot case of sorting a node list.
 
+mod cycle_error;
+mod dag;
 mod topological_sort;
 
+pub use cycle_error::CycleError;
+pub use dag::{Dag, NodeId};
 pub use topological_sort::*;
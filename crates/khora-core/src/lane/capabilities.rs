@@ -0,0 +1,233 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lane capability declarations, checked against the device at registration.
+//!
+//! A lane states what it needs from the platform through
+//! [`Lane::requirements`](super::Lane::requirements). Once the owning agent
+//! knows the device, it hands a [`DeviceCapabilities`] snapshot to its
+//! [`LaneRegistry`](super::LaneRegistry), which sets aside every lane whose
+//! [`LaneRequirements`] are not met. Excluded lanes are never initialized,
+//! negotiated or executed, so an unsupported strategy is dropped up front
+//! instead of failing inside `execute`.
+
+use std::fmt;
+
+use crate::renderer::GraphicsDevice;
+
+/// Device features a lane needs in order to run.
+///
+/// The default requires nothing, which is correct for every CPU-only lane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LaneRequirements {
+    /// The lane dispatches compute shaders.
+    pub compute: bool,
+    /// The lane cannot run without GPU timestamp queries.
+    pub timestamp_queries: bool,
+    /// Smallest 2D texture dimension, in texels, the device must support.
+    pub min_texture_size: u32,
+}
+
+impl LaneRequirements {
+    /// Requirements of a lane that runs on any device.
+    pub const NONE: Self = Self {
+        compute: false,
+        timestamp_queries: false,
+        min_texture_size: 0,
+    };
+
+    /// Adds the compute shader requirement.
+    pub const fn with_compute(mut self) -> Self {
+        self.compute = true;
+        self
+    }
+
+    /// Adds the timestamp query requirement.
+    pub const fn with_timestamp_queries(mut self) -> Self {
+        self.timestamp_queries = true;
+        self
+    }
+
+    /// Requires textures of at least `size` texels per side.
+    pub const fn with_min_texture_size(mut self, size: u32) -> Self {
+        self.min_texture_size = size;
+        self
+    }
+
+    /// Checks these requirements against `capabilities`, returning the first
+    /// one the device does not meet.
+    pub fn check(&self, capabilities: &DeviceCapabilities) -> Result<(), UnmetRequirement> {
+        if self.compute && !capabilities.compute {
+            return Err(UnmetRequirement::Compute);
+        }
+        if self.timestamp_queries && !capabilities.timestamp_queries {
+            return Err(UnmetRequirement::TimestampQueries);
+        }
+        if self.min_texture_size > capabilities.max_texture_size {
+            return Err(UnmetRequirement::TextureSize {
+                required: self.min_texture_size,
+                supported: capabilities.max_texture_size,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// What the active device offers, as far as lane requirements are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceCapabilities {
+    /// Compute shaders are available.
+    pub compute: bool,
+    /// GPU timestamp queries are enabled on the device.
+    pub timestamp_queries: bool,
+    /// Largest supported 2D texture dimension, in texels.
+    pub max_texture_size: u32,
+}
+
+impl DeviceCapabilities {
+    /// Queries the capabilities of `device`.
+    pub fn from_device(device: &dyn GraphicsDevice) -> Self {
+        Self {
+            compute: device.supports_feature("compute_shaders"),
+            timestamp_queries: device.supports_feature("gpu_timestamps"),
+            max_texture_size: device.max_texture_dimension_2d(),
+        }
+    }
+}
+
+/// A [`LaneRequirements`] entry the device does not satisfy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UnmetRequirement {
+    /// The lane needs compute shaders.
+    Compute,
+    /// The lane needs GPU timestamp queries.
+    TimestampQueries,
+    /// The lane needs larger textures than the device supports.
+    TextureSize {
+        /// Texture dimension the lane needs.
+        required: u32,
+        /// Largest texture dimension the device supports.
+        supported: u32,
+    },
+}
+
+impl fmt::Display for UnmetRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnmetRequirement::Compute => write!(f, "compute shaders are not supported"),
+            UnmetRequirement::TimestampQueries => {
+                write!(f, "timestamp queries are not supported")
+            }
+            UnmetRequirement::TextureSize {
+                required,
+                supported,
+            } => write!(
+                f,
+                "requires {required}px textures, device supports {supported}px"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnmetRequirement {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lane::{Lane, LaneKind, LaneRegistry};
+    use std::any::Any;
+
+    struct ComputeLane;
+
+    impl Lane for ComputeLane {
+        fn strategy_name(&self) -> &'static str {
+            "Compute"
+        }
+        fn lane_kind(&self) -> LaneKind {
+            LaneKind::Render
+        }
+        fn requirements(&self) -> LaneRequirements {
+            LaneRequirements::NONE.with_compute()
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    const WEAK_DEVICE: DeviceCapabilities = DeviceCapabilities {
+        compute: false,
+        timestamp_queries: false,
+        max_texture_size: 1024,
+    };
+
+    #[test]
+    fn no_requirements_run_anywhere() {
+        assert_eq!(LaneRequirements::NONE.check(&WEAK_DEVICE), Ok(()));
+        assert_eq!(LaneRequirements::default(), LaneRequirements::NONE);
+    }
+
+    #[test]
+    fn missing_features_are_reported() {
+        let compute = LaneRequirements::NONE.with_compute();
+        assert_eq!(compute.check(&WEAK_DEVICE), Err(UnmetRequirement::Compute));
+
+        let timestamps = LaneRequirements::NONE.with_timestamp_queries();
+        assert_eq!(
+            timestamps.check(&WEAK_DEVICE),
+            Err(UnmetRequirement::TimestampQueries)
+        );
+    }
+
+    #[test]
+    fn texture_size_is_compared_against_the_device_limit() {
+        let fits = LaneRequirements::NONE.with_min_texture_size(1024);
+        assert_eq!(fits.check(&WEAK_DEVICE), Ok(()));
+
+        let too_big = LaneRequirements::NONE.with_min_texture_size(2048);
+        assert_eq!(
+            too_big.check(&WEAK_DEVICE),
+            Err(UnmetRequirement::TextureSize {
+                required: 2048,
+                supported: 1024,
+            })
+        );
+    }
+
+    #[test]
+    fn registry_excludes_lanes_the_device_cannot_run() {
+        let mut registry = LaneRegistry::new();
+        registry.register(Box::new(ComputeLane));
+        assert!(registry.get("Compute").is_some());
+
+        registry.set_capabilities(WEAK_DEVICE);
+        assert!(registry.get("Compute").is_none());
+        assert!(registry.find_by_kind(LaneKind::Render).is_empty());
+        assert_eq!(registry.excluded().len(), 1);
+
+        // Lanes registered after validation are checked right away.
+        registry.register(Box::new(ComputeLane));
+        assert_eq!(registry.excluded().len(), 2);
+
+        registry.set_capabilities(DeviceCapabilities {
+            compute: true,
+            ..WEAK_DEVICE
+        });
+        assert_eq!(registry.len(), 2);
+        assert!(registry.excluded().is_empty());
+    }
+}
@@ -24,9 +24,20 @@
 //! |------------------------------|----------------------------------------------|
 //! | [`ColorTarget`]              | Texture view to render colour into            |
 //! | [`DepthTarget`]              | Texture view for depth testing                |
+//! | [`TargetSize`]               | Pixel size of the colour and depth targets    |
 //! | [`ClearColor`]               | Framebuffer clear colour                      |
 //! | [`ShadowAtlasView`]          | Shadow atlas view written by shadow lanes     |
 //! | [`ShadowComparisonSampler`]  | PCF comparison sampler for shadow sampling    |
+//! | [`VertexSkinning`]           | Scene lane skins meshes in its vertex shader  |
+//! | [`HdrSceneTarget`]           | Linear HDR view the scene lane renders into   |
+//! | [`PostStage`]                | Which half of a post effect to run            |
+//! | [`RenderDeltaTime`]          | Seconds since the previous rendered frame     |
+//! | [`ProjectionJitter`]         | Sub-pixel offset applied to the projection    |
+//! | [`MultisampleTargets`]       | MSAA views the scene lane renders into        |
+//! | [`MotionVectorTarget`]       | Per-pixel screen-space motion of the scene    |
+//! | [`DepthPrepared`]            | Scene depth already laid down by a prepass    |
+//! | [`ExposureBuffer`]           | Exposure metered from the HDR scene           |
+//! | [`BloomTarget`]              | Blurred highlights of the HDR scene           |
 //!
 //! # Physics domain
 //!
@@ -34,14 +45,43 @@
 //! |----------------------|-----------------------------------|
 //! | [`PhysicsDeltaTime`] | Fixed timestep for the current step |
 //!
+//! # Animation domain
+//!
+//! | Key                     | Meaning                                       |
+//! |-------------------------|-----------------------------------------------|
+//! | [`AnimationDeltaTime`]  | Time elapsed since the last animation update  |
+//! | [`AnimationBlendLimit`] | Max motions blended per animated entity       |
+//!
+//! # Inverse kinematics domain
+//!
+//! | Key                  | Meaning                                    |
+//! |----------------------|--------------------------------------------|
+//! | [`IkIterationLimit`] | Max iterations for iterative IK solvers    |
+//! | [`IkChainsSolved`]   | Constraints solved by the last IK pass     |
+//!
 //! # Audio domain
 //!
 //! | Key                | Meaning                               |
 //! |--------------------|---------------------------------------|
 //! | [`AudioStreamInfo`]| Sample rate, channels, etc.           |
 //! | [`AudioOutputSlot`]| Mutable borrow of the output buffer   |
+//! | [`AudioMixMode`]   | Spatialization and effects to apply   |
+//! | [`AudioVoiceLimit`]| Max sources mixed audibly per callback|
+//!
+//! # Asset domain
+//!
+//! | Key                    | Meaning                                   |
+//! |------------------------|-------------------------------------------|
+//! | [`AssetUnloadLimit`]   | Max assets unloaded by one unload pass    |
+//! | [`AssetsUnloaded`]     | Assets unloaded by the last unload pass   |
+//! | [`AssetPrefetchLimit`] | Max prefetches done by one prefetch pass  |
+//! | [`AssetsPrefetched`]   | Prefetches done by the last prefetch pass |
+//!
+//! The prefetch lane also reads the
+//! [`AssetLoadQuality`](crate::asset::AssetLoadQuality) picked by the agent.
 
-use crate::renderer::api::resource::{SamplerId, TextureViewId};
+use crate::math::{Extent2D, Vec2};
+use crate::renderer::api::resource::{BufferId, SamplerId, TextureViewId};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Render domain
@@ -55,6 +95,10 @@ pub struct ColorTarget(pub TextureViewId);
 #[derive(Debug, Clone, Copy)]
 pub struct DepthTarget(pub TextureViewId);
 
+/// Size of the color and depth targets for the current frame, in pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct TargetSize(pub Extent2D);
+
 /// Clear color for the current frame.
 #[derive(Debug, Clone, Copy)]
 pub struct ClearColor(pub crate::math::LinearRgba);
@@ -67,6 +111,84 @@ pub struct ShadowAtlasView(pub TextureViewId);
 #[derive(Debug, Clone, Copy)]
 pub struct ShadowComparisonSampler(pub SamplerId);
 
+/// Whether the active scene lane skins vertex-shader-mode meshes itself.
+///
+/// When `false`, the skinning lane poses those meshes in its compute
+/// pre-pass too, so lanes without a skinned pipeline still draw them posed.
+#[derive(Debug, Clone, Copy)]
+pub struct VertexSkinning(pub bool);
+
+/// Linear HDR color target the scene lane renders into instead of
+/// [`ColorTarget`], written by the tone mapping lane during
+/// [`PostStage::Prepare`].
+///
+/// Scene lanes skip tone mapping when this key is present; post lanes read
+/// the HDR image during [`PostStage::Resolve`], and the tone mapping lane
+/// finally maps it into [`ColorTarget`].
+#[derive(Debug, Clone, Copy)]
+pub struct HdrSceneTarget(pub TextureViewId);
+
+/// Which half of a post-processing lane to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PostStage {
+    /// Before the scene pass: provide the targets the scene renders into.
+    Prepare,
+    /// After the scene pass: process those targets into [`ColorTarget`].
+    Resolve,
+}
+
+/// Seconds elapsed since the previous rendered frame.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderDeltaTime(pub f32);
+
+/// Offset, in NDC units, that scene lanes add to clip-space positions.
+///
+/// Written by the TAA lane during [`PostStage::Prepare`] so each frame
+/// samples a different sub-pixel position.
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectionJitter(pub Vec2);
+
+/// Multisampled color and depth views the scene lane renders into, written
+/// by the MSAA lane during [`PostStage::Prepare`].
+///
+/// The color view resolves into the scene's regular color target (the
+/// [`HdrSceneTarget`] if present, else [`ColorTarget`]) at the end of the
+/// scene pass.
+#[derive(Debug, Clone, Copy)]
+pub struct MultisampleTargets {
+    /// Multisampled view in the format of the resolved color target.
+    pub color: TextureViewId,
+    /// Multisampled `Depth32Float` view.
+    pub depth: TextureViewId,
+}
+
+/// Per-pixel screen-space motion of the scene since the previous frame,
+/// in UV units, written by the motion vector lane.
+#[derive(Debug, Clone, Copy)]
+pub struct MotionVectorTarget(pub TextureViewId);
+
+/// Marker written by the depth prepass lane once it has filled the scene's
+/// depth target.
+///
+/// Scene lanes then load depth instead of clearing it, so only the nearest
+/// fragment of each pixel is shaded.
+#[derive(Debug, Clone, Copy)]
+pub struct DepthPrepared;
+
+/// Storage buffer holding the exposure the HDR scene is scaled by before
+/// tone mapping, written by the auto-exposure lane during
+/// [`PostStage::Resolve`].
+///
+/// Laid out as `{ ev: f32, exposure: f32, initialized: u32, _pad: u32 }`.
+#[derive(Debug, Clone, Copy)]
+pub struct ExposureBuffer(pub BufferId);
+
+/// Blurred highlights of the [`HdrSceneTarget`], written by the bloom lane
+/// during [`PostStage::Resolve`] and added to the scene before tone mapping.
+#[derive(Debug, Clone, Copy)]
+pub struct BloomTarget(pub TextureViewId);
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Physics domain
 // ─────────────────────────────────────────────────────────────────────────────
@@ -75,6 +197,38 @@ pub struct ShadowComparisonSampler(pub SamplerId);
 #[derive(Debug, Clone, Copy)]
 pub struct PhysicsDeltaTime(pub f32);
 
+/// Fraction of a physics tick elapsed since the latest one, in `[0, 1)`,
+/// used to blend rendered poses between ticks.
+#[derive(Debug, Clone, Copy)]
+pub struct PhysicsInterpolationAlpha(pub f32);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Animation domain
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Seconds elapsed since the previous animation update.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationDeltaTime(pub f32);
+
+/// Maximum number of motions blended into one entity's pose.
+///
+/// `1` keeps only the dominant motion (no crossfades or blend-space mixing).
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationBlendLimit(pub usize);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Inverse kinematics domain
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maximum number of iterations for iterative IK solvers (FABRIK).
+#[derive(Debug, Clone, Copy)]
+pub struct IkIterationLimit(pub usize);
+
+/// Number of IK constraints solved by the last IK pass, written back by
+/// the lane.
+#[derive(Debug, Clone, Copy)]
+pub struct IkChainsSolved(pub usize);
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Audio domain
 // ─────────────────────────────────────────────────────────────────────────────
@@ -122,3 +276,47 @@ impl AudioOutputSlot {
         unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
 }
+
+/// How much work the mixer spends on each source, from cheapest to richest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum AudioMixMode {
+    /// Sources play at their own volume on every channel: no distance
+    /// attenuation, no panning.
+    Stereo,
+    /// Distance attenuation and panning relative to the listener.
+    #[default]
+    Spatial,
+    /// [`Spatial`](Self::Spatial), plus air absorption: distant sources are
+    /// low-pass filtered.
+    SpatialEffects,
+}
+
+/// Maximum number of sources mixed audibly in one audio callback.
+///
+/// The loudest sources win. The others keep advancing silently, so they
+/// resume in sync once a voice frees up.
+#[derive(Debug, Clone, Copy)]
+pub struct AudioVoiceLimit(pub usize);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Asset domain
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maximum number of assets one unload pass may release.
+#[derive(Debug, Clone, Copy)]
+pub struct AssetUnloadLimit(pub usize);
+
+/// Number of assets released by the last unload pass, written by the
+/// unload lane after `execute()`.
+#[derive(Debug, Clone, Copy)]
+pub struct AssetsUnloaded(pub usize);
+
+/// Maximum number of queued prefetches one prefetch pass may complete.
+#[derive(Debug, Clone, Copy)]
+pub struct AssetPrefetchLimit(pub usize);
+
+/// Number of prefetches completed by the last prefetch pass, written by
+/// the prefetch lane after `execute()`.
+#[derive(Debug, Clone, Copy)]
+pub struct AssetsPrefetched(pub usize);
@@ -69,6 +69,25 @@ impl OutputDeck {
             .unwrap_or_default()
     }
 
+    /// Moves every slot of `other` into this deck.
+    ///
+    /// Used at the frame barrier to fold a worker agent's private deck into
+    /// the frame deck. Slots are opaque, so they cannot be combined: when
+    /// both decks hold the same type, this deck's slot is kept and the
+    /// incoming one dropped. Returns the number of dropped slots.
+    pub fn absorb(&mut self, other: OutputDeck) -> usize {
+        let mut dropped = 0;
+        for (type_id, slot) in other.slots {
+            match self.slots.entry(type_id) {
+                std::collections::hash_map::Entry::Occupied(_) => dropped += 1,
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    entry.insert(slot);
+                }
+            }
+        }
+        dropped
+    }
+
     /// Reports whether a slot of the given type has been written this tick.
     pub fn contains<T: Any + Send + Sync>(&self) -> bool {
         self.slots.contains_key(&TypeId::of::<T>())
@@ -135,6 +154,20 @@ mod tests {
         assert!(taken.is_empty());
     }
 
+    #[test]
+    fn absorb_moves_slots_and_keeps_existing_on_collision() {
+        let mut deck = OutputDeck::new();
+        deck.slot::<Vec<u32>>().push(1);
+
+        let mut worker = OutputDeck::new();
+        worker.slot::<Vec<u32>>().push(2);
+        worker.slot::<Vec<u8>>().push(3);
+
+        assert_eq!(deck.absorb(worker), 1);
+        assert_eq!(deck.take::<Vec<u32>>(), vec![1]);
+        assert_eq!(deck.take::<Vec<u8>>(), vec![3]);
+    }
+
     #[test]
     fn distinct_types_dont_alias() {
         let mut deck = OutputDeck::new();
@@ -26,7 +26,8 @@
 //! The Lane system follows a two-level trait hierarchy:
 //!
 //! 1. **`Lane`** (this trait) — Common interface shared by ALL lane types.
-//!    Provides identity, classification, and cost estimation.
+//!    Provides identity, classification, cost estimation, and the device
+//!    requirements checked at registration.
 //!
 //! 2. **Domain-specific traits** — Extend `Lane` with domain-specific execution
 //!    methods. Examples:
@@ -68,9 +69,11 @@ use std::collections::HashMap;
 use std::fmt;
 
 pub mod bus;
+pub mod capabilities;
 pub mod context_keys;
 pub mod deck;
 pub use bus::LaneBus;
+pub use capabilities::{DeviceCapabilities, LaneRequirements, UnmetRequirement};
 pub use context_keys::*;
 pub use deck::OutputDeck;
 
@@ -139,6 +142,10 @@ pub enum LaneKind {
     Shadow,
     /// Physics simulation
     Physics,
+    /// Animation graph evaluation
+    Animation,
+    /// Inverse kinematics, applied to animated poses
+    InverseKinematics,
     /// Audio mixing and spatialization
     Audio,
     /// Asset loading and processing
@@ -157,6 +164,8 @@ impl std::fmt::Display for LaneKind {
             LaneKind::Render => write!(f, "Render"),
             LaneKind::Shadow => write!(f, "Shadow"),
             LaneKind::Physics => write!(f, "Physics"),
+            LaneKind::Animation => write!(f, "Animation"),
+            LaneKind::InverseKinematics => write!(f, "IK"),
             LaneKind::Audio => write!(f, "Audio"),
             LaneKind::Asset => write!(f, "Asset"),
             LaneKind::Scene => write!(f, "Scene"),
@@ -374,21 +383,71 @@ impl<T: ?Sized> Ref<T> {
 /// // Find all render lanes
 /// let render_lanes = reg.find_by_kind(LaneKind::Render);
 /// ```
+///
+/// Once the device is known, [`set_capabilities`](Self::set_capabilities)
+/// sets aside every lane whose [`Lane::requirements`] it does not meet.
+/// Excluded lanes are invisible to every lookup below.
 pub struct LaneRegistry {
     lanes: Vec<Box<dyn Lane>>,
+    /// Lanes whose requirements the current device does not meet.
+    excluded: Vec<Box<dyn Lane>>,
+    /// Capabilities lanes are validated against, once known.
+    capabilities: Option<DeviceCapabilities>,
 }
 
 impl LaneRegistry {
     /// Creates an empty registry.
     pub fn new() -> Self {
-        Self { lanes: Vec::new() }
+        Self {
+            lanes: Vec::new(),
+            excluded: Vec::new(),
+            capabilities: None,
+        }
     }
 
     /// Adds a lane to the registry.
+    ///
+    /// If device capabilities are already known and the lane's requirements
+    /// are not met, the lane is excluded instead.
     pub fn register(&mut self, lane: Box<dyn Lane>) {
+        if let Some(capabilities) = &self.capabilities {
+            if let Err(reason) = lane.requirements().check(capabilities) {
+                log::warn!(
+                    "LaneRegistry: Excluding {} lane {}: {}",
+                    lane.lane_kind(),
+                    lane.strategy_name(),
+                    reason
+                );
+                self.excluded.push(lane);
+                return;
+            }
+        }
         self.lanes.push(lane);
     }
 
+    /// Validates every lane against `capabilities`.
+    ///
+    /// Lanes the device cannot run are excluded; lanes excluded for a
+    /// previous device are re-admitted if this one supports them.
+    pub fn set_capabilities(&mut self, capabilities: DeviceCapabilities) {
+        self.capabilities = Some(capabilities);
+        let lanes = std::mem::take(&mut self.lanes);
+        let excluded = std::mem::take(&mut self.excluded);
+        for lane in lanes.into_iter().chain(excluded) {
+            self.register(lane);
+        }
+    }
+
+    /// Returns the capabilities lanes were last validated against.
+    pub fn capabilities(&self) -> Option<&DeviceCapabilities> {
+        self.capabilities.as_ref()
+    }
+
+    /// Returns the lanes excluded because the device does not support them.
+    pub fn excluded(&self) -> &[Box<dyn Lane>] {
+        &self.excluded
+    }
+
     /// Finds a lane by its strategy name.
     pub fn get(&self, name: &str) -> Option<&dyn Lane> {
         self.lanes
@@ -411,12 +470,12 @@ impl LaneRegistry {
         &self.lanes
     }
 
-    /// Returns the number of registered lanes.
+    /// Returns the number of registered lanes, excluded ones aside.
     pub fn len(&self) -> usize {
         self.lanes.len()
     }
 
-    /// Returns `true` if no lanes are registered.
+    /// Returns `true` if no usable lanes are registered.
     pub fn is_empty(&self) -> bool {
         self.lanes.is_empty()
     }
@@ -477,6 +536,17 @@ pub trait Lane: Send + Sync {
         1.0
     }
 
+    /// Device features this lane needs.
+    ///
+    /// Checked by [`LaneRegistry`] once the device is known: a lane whose
+    /// requirements are not met is excluded from initialization, negotiation
+    /// and execution.
+    ///
+    /// Default requires nothing.
+    fn requirements(&self) -> LaneRequirements {
+        LaneRequirements::NONE
+    }
+
     // --- Lifecycle ---
 
     /// Called once when the lane is registered or the underlying context resets.
@@ -20,13 +20,16 @@
 #![warn(missing_docs)]
 
 pub mod agent;
+pub mod animation;
 pub mod asset;
 pub mod audio;
 pub mod context;
 pub mod control;
+pub mod cvar;
 
 pub mod ecs;
 pub mod event;
+pub mod executor;
 pub mod graph;
 pub mod lane;
 pub mod math;
@@ -37,6 +40,7 @@ pub mod renderer;
 pub mod scene;
 pub mod service_registry;
 pub mod telemetry;
+pub mod threading;
 pub mod ui;
 pub mod utils;
 
@@ -564,6 +564,47 @@ impl Mat4 {
         )
     }
 
+    /// Creates a right-handed perspective projection with an infinite far plane
+    /// and a [0, 1] depth range (ZO). Points at infinity map to depth 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `fov_y_radians`: Vertical field of view in radians.
+    /// * `aspect_ratio`: Width divided by height of the viewport.
+    /// * `z_near`: Distance to the near clipping plane (must be positive).
+    #[inline]
+    pub fn perspective_infinite_rh_zo(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        assert!(z_near > 0.0);
+        let f = 1.0 / (fov_y_radians / 2.0).tan();
+
+        Self::from_cols(
+            Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, -1.0, -1.0),
+            Vec4::new(0.0, 0.0, -z_near, 0.0),
+        )
+    }
+
+    /// Creates a right-handed perspective projection with an infinite far plane
+    /// and a reversed [1, 0] depth range: the near plane maps to 1 and points at
+    /// infinity to 0. Pair it with a `Greater` depth test and a depth clear of 0.
+    #[inline]
+    pub fn perspective_infinite_reverse_rh_zo(
+        fov_y_radians: f32,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        assert!(z_near > 0.0);
+        let f = 1.0 / (fov_y_radians / 2.0).tan();
+
+        Self::from_cols(
+            Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, -1.0),
+            Vec4::new(0.0, 0.0, z_near, 0.0),
+        )
+    }
+
     /// Creates a right-handed orthographic projection matrix with a [0, 1] depth range (ZO).
     #[inline]
     pub fn orthographic_rh_zo(
@@ -1235,6 +1276,26 @@ mod tests {
         assert!(approx_eq(m.cols[3].z, -(far * near) / (far - near)));
     }
 
+    #[test]
+    fn test_perspective_infinite_rh_zo() {
+        let near = 0.1;
+        let m = Mat4::perspective_infinite_rh_zo(PI / 4.0, 16.0 / 9.0, near);
+        let depth = |z: f32| {
+            let clip = m * Vec4::new(0.0, 0.0, z, 1.0);
+            clip.z / clip.w
+        };
+        assert!(approx_eq(depth(-near), 0.0));
+        assert!(depth(-1.0e4) < 1.0 && depth(-1.0e4) > 0.999);
+
+        let r = Mat4::perspective_infinite_reverse_rh_zo(PI / 4.0, 16.0 / 9.0, near);
+        let reversed = |z: f32| {
+            let clip = r * Vec4::new(0.0, 0.0, z, 1.0);
+            clip.z / clip.w
+        };
+        assert!(approx_eq(reversed(-near), 1.0));
+        assert!(reversed(-1.0e7) > 0.0 && reversed(-1.0e7) < 1.0e-6);
+    }
+
     #[test]
     fn test_orthographic_rh_zo() {
         let left = -1.0;
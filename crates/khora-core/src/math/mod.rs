@@ -45,7 +45,9 @@ pub mod color;
 pub mod dimension;
 pub mod geometry;
 pub mod matrix;
+pub mod photometry;
 pub mod quaternion;
+pub mod smoothing;
 pub mod vector;
 
 // --- Re-export Principal Types ---
@@ -56,6 +58,7 @@ pub use self::dimension::{Extent1D, Extent2D, Extent3D, Origin2D, Origin3D};
 pub use self::geometry::Aabb;
 pub use self::matrix::{Mat3, Mat4};
 pub use self::quaternion::{Quat, Quaternion};
+pub use self::smoothing::{smooth_damp, smooth_damp_angle, smooth_damp_vec3};
 pub use self::vector::{Vec2, Vec3, Vec4};
 
 // --- Utility Functions ---
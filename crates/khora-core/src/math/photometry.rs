@@ -0,0 +1,123 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Photometric units for lights and cameras.
+//!
+//! Lights and exposure use physical units, so a scene lit for daylight looks
+//! the same wherever it is loaded:
+//!
+//! | Quantity | Unit | Used for |
+//! |---|---|---|
+//! | Luminous power | lumen (lm) | Point and spot light intensity |
+//! | Luminous intensity | candela (cd, lm/sr) | Point and spot light shading |
+//! | Illuminance | lux (lx, lm/m²) | Directional light intensity |
+//! | Luminance | nit (cd/m²) | What a camera meters |
+//! | Exposure | EV100 | Camera exposure, at ISO 100 |
+//!
+//! The shaders multiply light colors by candela or lux scaled by the
+//! camera's exposure, [`exposure_from_ev100`].
+
+use super::PI;
+
+/// Direct sunlight at noon, in lux.
+pub const LUX_DIRECT_SUNLIGHT: f32 = 100_000.0;
+/// Daylight in the shade, without direct sun, in lux.
+pub const LUX_DAYLIGHT: f32 = 10_000.0;
+/// An overcast day, in lux.
+pub const LUX_OVERCAST: f32 = 1_000.0;
+/// A lit office, in lux.
+pub const LUX_OFFICE: f32 = 500.0;
+/// A full moon, in lux.
+pub const LUX_FULL_MOON: f32 = 0.25;
+
+/// A 60 W incandescent bulb, in lumens.
+pub const LUMENS_60W_BULB: f32 = 800.0;
+
+/// Exposure for a sunny day (the "sunny 16" rule).
+pub const EV100_SUNNY: f32 = 15.0;
+/// Exposure for an overcast day.
+pub const EV100_OVERCAST: f32 = 12.0;
+/// Exposure for a lit interior.
+pub const EV100_INDOOR: f32 = 7.0;
+/// Exposure for a street at night.
+pub const EV100_NIGHT: f32 = 3.0;
+
+/// Reflected-light meter calibration constant.
+const METER_K: f32 = 12.5;
+
+/// Luminous intensity of a point light emitting `lumens` in every
+/// direction.
+pub fn point_candela(lumens: f32) -> f32 {
+    lumens / (4.0 * PI)
+}
+
+/// Luminous intensity of a spot light emitting `lumens` into a cone whose
+/// half-angle is `outer_cone_angle`, in radians.
+///
+/// Narrowing the cone concentrates the same power, so the spot gets
+/// brighter.
+pub fn spot_candela(lumens: f32, outer_cone_angle: f32) -> f32 {
+    let solid_angle = 2.0 * PI * (1.0 - outer_cone_angle.cos());
+    lumens / solid_angle.max(1e-4)
+}
+
+/// Illuminance at `distance` meters from a source of `candela`, facing it.
+pub fn candela_to_lux(candela: f32, distance: f32) -> f32 {
+    candela / (distance * distance).max(1e-8)
+}
+
+/// EV100 of a camera with the given f-number, shutter time in seconds and
+/// ISO sensitivity.
+pub fn ev100_from_camera(aperture: f32, shutter_time: f32, iso: f32) -> f32 {
+    ((aperture * aperture) / shutter_time * 100.0 / iso).log2()
+}
+
+/// EV100 that exposes an average scene `luminance`, in nits, correctly.
+pub fn ev100_from_luminance(luminance: f32) -> f32 {
+    (luminance * 100.0 / METER_K).log2()
+}
+
+/// Average scene luminance, in nits, that `ev100` exposes correctly.
+pub fn luminance_from_ev100(ev100: f32) -> f32 {
+    METER_K / 100.0 * ev100.exp2()
+}
+
+/// Scale from scene luminance to display values at `ev100`: the inverse of
+/// the luminance that saturates the sensor.
+pub fn exposure_from_ev100(ev100: f32) -> f32 {
+    1.0 / (1.2 * ev100.exp2())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{approx_eq, approx_eq_eps, FRAC_PI_2};
+
+    #[test]
+    fn converts_between_photometric_units() {
+        assert!(approx_eq(point_candela(4.0 * PI), 1.0));
+        // A hemisphere spreads like half a point light.
+        assert!(approx_eq(spot_candela(2.0 * PI, FRAC_PI_2), 1.0));
+        assert!(spot_candela(100.0, 0.2) > spot_candela(100.0, 0.6));
+        assert!(approx_eq(candela_to_lux(100.0, 2.0), 25.0));
+
+        // f/1.0, 1 s, ISO 100 is EV 0.
+        assert!(approx_eq(ev100_from_camera(1.0, 1.0, 100.0), 0.0));
+        let sunny = ev100_from_camera(16.0, 1.0 / 100.0, 100.0);
+        assert!(approx_eq_eps(sunny, 14.643856, 1e-4));
+        let ev = ev100_from_luminance(4_000.0);
+        assert!(approx_eq(luminance_from_ev100(ev) / 4_000.0, 1.0));
+        assert!(approx_eq(exposure_from_ev100(0.0), 1.0 / 1.2));
+    }
+}
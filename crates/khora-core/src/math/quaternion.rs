@@ -83,6 +83,52 @@ impl Quaternion {
         }
     }
 
+    /// Creates the shortest rotation taking direction `from` onto direction `to`.
+    ///
+    /// Both vectors are normalized internally. Opposite directions rotate by
+    /// half a turn around an arbitrary perpendicular axis.
+    pub fn from_rotation_arc(from: Vec3, to: Vec3) -> Self {
+        let from = from.normalize();
+        let to = to.normalize();
+        let d = from.dot(to);
+        if d >= 1.0 - EPSILON {
+            return Self::IDENTITY;
+        }
+        if d <= -1.0 + EPSILON {
+            let mut axis = Vec3::new(1.0, 0.0, 0.0).cross(from);
+            if axis.length_squared() < EPSILON {
+                axis = Vec3::new(0.0, 1.0, 0.0).cross(from);
+            }
+            return Self::from_axis_angle(axis, std::f32::consts::PI);
+        }
+        let c = from.cross(to);
+        Self::new(c.x, c.y, c.z, 1.0 + d).normalize()
+    }
+
+    /// Creates the rotation of a view turned by `yaw` around +Y, then
+    /// raised by `pitch` around its local +X.
+    ///
+    /// With both angles at zero the forward (-Z) axis is unchanged; a
+    /// positive pitch looks up.
+    pub fn from_yaw_pitch(yaw: f32, pitch: f32) -> Self {
+        Self::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), yaw)
+            * Self::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), pitch)
+    }
+
+    /// Creates an upright rotation whose forward (-Z) axis points along
+    /// `direction`, keeping +Y as up.
+    ///
+    /// Returns `None` if `direction` is zero.
+    pub fn look_rotation(direction: Vec3) -> Option<Self> {
+        if direction.length_squared() < EPSILON * EPSILON {
+            return None;
+        }
+        let d = direction.normalize();
+        let yaw = (-d.x).atan2(-d.z);
+        let pitch = d.y.clamp(-1.0, 1.0).asin();
+        Some(Self::from_yaw_pitch(yaw, pitch))
+    }
+
     /// Creates a quaternion from a 4x4 rotation matrix.
     ///
     /// This method only considers the upper 3x3 part of the matrix for the conversion.
@@ -386,6 +432,27 @@ mod tests {
         approx::relative_eq!(dot, 1.0, epsilon = EPSILON * 10.0) // Use abs dot product
     }
 
+    #[test]
+    fn look_rotation_points_forward_axis() {
+        let direction = Vec3::new(1.0, 1.0, -2.0).normalize();
+        let rotation = Quaternion::look_rotation(direction).unwrap();
+        let forward = rotation * Vec3::new(0.0, 0.0, -1.0);
+        assert!((forward - direction).length() < 1e-5);
+        assert!(Quaternion::look_rotation(Vec3::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_from_rotation_arc() {
+        let from = Vec3::new(1.0, 0.0, 0.0);
+        let to = Vec3::new(0.0, 0.0, -2.0);
+        let q = Quaternion::from_rotation_arc(from, to);
+        let rotated = q.rotate_vec3(from);
+        assert_relative_eq!(rotated.z, -1.0, epsilon = EPSILON * 10.0);
+
+        let flipped = Quaternion::from_rotation_arc(from, -from);
+        assert_relative_eq!(flipped.rotate_vec3(from).x, -1.0, epsilon = EPSILON * 10.0);
+    }
+
     #[test]
     fn test_identity_and_default() {
         let q_ident = Quaternion::IDENTITY;
@@ -0,0 +1,124 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Frame-rate independent smoothing with critically damped springs.
+//!
+//! Each function moves a value towards a target as a spring that never
+//! overshoots, settling in roughly `smooth_time` seconds. The caller owns the
+//! velocity, which carries the spring's state between frames; results are
+//! stable for any `dt`, so camera motion feels the same at 30 and 240 Hz.
+
+use super::{Vec3, PI, TAU};
+
+/// Moves `current` towards `target` with a critically damped spring.
+///
+/// `smooth_time` is the approximate time to reach the target, in seconds.
+/// A non-positive `smooth_time` snaps to the target and resets `velocity`.
+///
+/// # Examples
+///
+/// ```
+/// use khora_core::math::smooth_damp;
+///
+/// let mut velocity = 0.0;
+/// let mut value = 0.0;
+/// for _ in 0..120 {
+///     value = smooth_damp(value, 10.0, &mut velocity, 0.2, 1.0 / 60.0);
+/// }
+/// assert!((value - 10.0).abs() < 0.01);
+/// ```
+pub fn smooth_damp(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    dt: f32,
+) -> f32 {
+    if smooth_time <= 0.0 {
+        *velocity = 0.0;
+        return target;
+    }
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    // Padé approximation of exp(-x), accurate for the x range of game frames.
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * decay;
+    target + (change + temp) * decay
+}
+
+/// Component-wise [`smooth_damp`] for positions.
+pub fn smooth_damp_vec3(
+    current: Vec3,
+    target: Vec3,
+    velocity: &mut Vec3,
+    smooth_time: f32,
+    dt: f32,
+) -> Vec3 {
+    Vec3::new(
+        smooth_damp(current.x, target.x, &mut velocity.x, smooth_time, dt),
+        smooth_damp(current.y, target.y, &mut velocity.y, smooth_time, dt),
+        smooth_damp(current.z, target.z, &mut velocity.z, smooth_time, dt),
+    )
+}
+
+/// [`smooth_damp`] for angles in radians, taking the shortest way around.
+pub fn smooth_damp_angle(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    dt: f32,
+) -> f32 {
+    let delta = (target - current + PI).rem_euclid(TAU) - PI;
+    smooth_damp(current, current + delta, velocity, smooth_time, dt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(dt: f32, duration: f32) -> f32 {
+        let mut velocity = 0.0;
+        let mut value = 0.0;
+        for _ in 0..(duration / dt).round() as usize {
+            value = smooth_damp(value, 1.0, &mut velocity, 0.3, dt);
+        }
+        value
+    }
+
+    #[test]
+    fn independent_of_frame_rate() {
+        let slow = settle(1.0 / 30.0, 0.3);
+        let fast = settle(1.0 / 240.0, 0.3);
+        assert!((slow - fast).abs() < 0.02, "{slow} vs {fast}");
+    }
+
+    #[test]
+    fn never_overshoots() {
+        let mut velocity = 0.0;
+        let mut value = 0.0;
+        for _ in 0..600 {
+            value = smooth_damp(value, 1.0, &mut velocity, 0.1, 1.0 / 60.0);
+            assert!(value <= 1.0 + 1e-4);
+        }
+    }
+
+    #[test]
+    fn angle_takes_shortest_path() {
+        let mut velocity = 0.0;
+        let next = smooth_damp_angle(3.0, -3.0, &mut velocity, 0.2, 1.0 / 60.0);
+        assert!(next > 3.0, "should wrap through PI, got {next}");
+    }
+}
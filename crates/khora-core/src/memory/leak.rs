@@ -0,0 +1,417 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Leak-check mode built on the allocator lifetime counters.
+//!
+//! The tracking allocator keeps live allocation counts and bytes per
+//! [`SizeBucket`]. A [`LeakSnapshot`] captures those counters. Diffing two
+//! snapshots gives a [`LeakReport`] with the net change per bucket.
+//! [`LeakScope`] wraps that pattern around a region of code such as a scene
+//! load/unload or a subsystem shutdown.
+//!
+//! In debug builds, [`enable`] can also record a backtrace for every large
+//! allocation. Large allocations still alive when a report is produced are
+//! listed with the call site that made them.
+
+use super::tracking_allocator::{LARGE_ALLOCATION_THRESHOLD, SMALL_ALLOCATION_THRESHOLD};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Size classes used by the leak report, matching the allocator's small/large thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SizeBucket {
+    /// Allocations smaller than 1 KB.
+    Small,
+    /// Allocations between 1 KB and 1 MB.
+    Medium,
+    /// Allocations of 1 MB or more.
+    Large,
+}
+
+impl SizeBucket {
+    /// All buckets, in ascending size order.
+    pub const ALL: [SizeBucket; 3] = [SizeBucket::Small, SizeBucket::Medium, SizeBucket::Large];
+
+    /// Classifies an allocation of `size` bytes.
+    pub fn of(size: usize) -> Self {
+        if size >= LARGE_ALLOCATION_THRESHOLD {
+            SizeBucket::Large
+        } else if size < SMALL_ALLOCATION_THRESHOLD {
+            SizeBucket::Small
+        } else {
+            SizeBucket::Medium
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// A short human-readable label for reports.
+    pub fn label(self) -> &'static str {
+        match self {
+            SizeBucket::Small => "small (<1KB)",
+            SizeBucket::Medium => "medium (1KB-1MB)",
+            SizeBucket::Large => "large (>=1MB)",
+        }
+    }
+}
+
+// --- Live counters (maintained by the tracking allocator) ---
+
+static LIVE_ALLOCATIONS: [AtomicI64; 3] = [AtomicI64::new(0), AtomicI64::new(0), AtomicI64::new(0)];
+static LIVE_BYTES: [AtomicI64; 3] = [AtomicI64::new(0), AtomicI64::new(0), AtomicI64::new(0)];
+
+// --- Leak-check mode state ---
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CAPTURE_BACKTRACES: AtomicBool = AtomicBool::new(false);
+static BASELINE: Mutex<Option<LeakSnapshot>> = Mutex::new(None);
+
+/// Monotonic sequence number stamped on each recorded large allocation, so a
+/// scope only reports the allocations made after it began.
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+static RECORDED: AtomicUsize = AtomicUsize::new(0);
+static LARGE_RECORDS: Mutex<BTreeMap<usize, LargeRecord>> = Mutex::new(BTreeMap::new());
+
+thread_local! {
+    /// Set while the hook itself allocates (backtrace capture, map insertion),
+    /// so those nested allocations are not recorded recursively.
+    static IN_HOOK: Cell<bool> = const { Cell::new(false) };
+}
+
+struct LargeRecord {
+    sequence: u64,
+    size: usize,
+    backtrace: String,
+}
+
+/// Called by the tracking allocator after every successful allocation.
+pub(super) fn on_alloc(ptr: *mut u8, size: usize) {
+    let bucket = SizeBucket::of(size).index();
+    LIVE_ALLOCATIONS[bucket].fetch_add(1, Ordering::Relaxed);
+    LIVE_BYTES[bucket].fetch_add(size as i64, Ordering::Relaxed);
+
+    if cfg!(debug_assertions)
+        && size >= LARGE_ALLOCATION_THRESHOLD
+        && CAPTURE_BACKTRACES.load(Ordering::Relaxed)
+    {
+        with_hook_guard(|| {
+            let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+            let record = LargeRecord {
+                sequence: SEQUENCE.fetch_add(1, Ordering::Relaxed),
+                size,
+                backtrace,
+            };
+            let mut records = LARGE_RECORDS.lock().unwrap_or_else(|e| e.into_inner());
+            if records.insert(ptr as usize, record).is_none() {
+                RECORDED.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+    }
+}
+
+/// Called by the tracking allocator before every deallocation.
+pub(super) fn on_dealloc(ptr: *mut u8, size: usize) {
+    let bucket = SizeBucket::of(size).index();
+    LIVE_ALLOCATIONS[bucket].fetch_sub(1, Ordering::Relaxed);
+    LIVE_BYTES[bucket].fetch_sub(size as i64, Ordering::Relaxed);
+
+    if size >= LARGE_ALLOCATION_THRESHOLD && RECORDED.load(Ordering::Relaxed) > 0 {
+        with_hook_guard(|| {
+            let mut records = LARGE_RECORDS.lock().unwrap_or_else(|e| e.into_inner());
+            if records.remove(&(ptr as usize)).is_some() {
+                RECORDED.fetch_sub(1, Ordering::Relaxed);
+            }
+        });
+    }
+}
+
+/// Runs `f` unless this thread is already inside a hook (or is being torn down).
+fn with_hook_guard(f: impl FnOnce()) {
+    let entered = IN_HOOK
+        .try_with(|flag| !flag.replace(true))
+        .unwrap_or(false);
+    if entered {
+        f();
+        let _ = IN_HOOK.try_with(|flag| flag.set(false));
+    }
+}
+
+/// Turns on leak-check mode and records the current counters as the baseline
+/// for [`report_since_enabled`].
+///
+/// `capture_backtraces` records a call stack for every large allocation made
+/// from now on. It only has an effect in debug builds and makes each large
+/// allocation noticeably slower.
+pub fn enable(capture_backtraces: bool) {
+    *BASELINE.lock().unwrap_or_else(|e| e.into_inner()) = Some(LeakSnapshot::capture());
+    CAPTURE_BACKTRACES.store(capture_backtraces, Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Turns leak-check mode off and stops recording backtraces.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+    CAPTURE_BACKTRACES.store(false, Ordering::Relaxed);
+}
+
+/// Returns `true` if leak-check mode is on.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Reports the net change since [`enable`] was called, or `None` if
+/// leak-check mode was never enabled.
+pub fn report_since_enabled() -> Option<LeakReport> {
+    let baseline = (*BASELINE.lock().unwrap_or_else(|e| e.into_inner()))?;
+    Some(baseline.report_until_now("since leak-check enabled"))
+}
+
+/// Live allocation counters per size bucket at one point in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LeakSnapshot {
+    allocations: [i64; 3],
+    bytes: [i64; 3],
+    sequence: u64,
+}
+
+impl LeakSnapshot {
+    /// Captures the current live counters.
+    pub fn capture() -> Self {
+        Self {
+            allocations: std::array::from_fn(|i| LIVE_ALLOCATIONS[i].load(Ordering::Relaxed)),
+            bytes: std::array::from_fn(|i| LIVE_BYTES[i].load(Ordering::Relaxed)),
+            sequence: SEQUENCE.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Builds a report of the net change from `self` to `later`.
+    pub fn diff(&self, later: &LeakSnapshot, label: impl Into<String>) -> LeakReport {
+        let buckets = SizeBucket::ALL.map(|bucket| {
+            let i = bucket.index();
+            BucketDelta {
+                bucket,
+                allocations: later.allocations[i] - self.allocations[i],
+                bytes: later.bytes[i] - self.bytes[i],
+            }
+        });
+        LeakReport {
+            label: label.into(),
+            buckets,
+            unfreed_large: unfreed_large_since(self.sequence),
+        }
+    }
+
+    /// Builds a report of the net change from `self` to the current counters.
+    pub fn report_until_now(&self, label: impl Into<String>) -> LeakReport {
+        self.diff(&LeakSnapshot::capture(), label)
+    }
+}
+
+fn unfreed_large_since(sequence: u64) -> Vec<UnfreedAllocation> {
+    let mut out = Vec::new();
+    with_hook_guard(|| {
+        let records = LARGE_RECORDS.lock().unwrap_or_else(|e| e.into_inner());
+        out = records
+            .values()
+            .filter(|r| r.sequence >= sequence)
+            .map(|r| UnfreedAllocation {
+                size: r.size,
+                backtrace: r.backtrace.clone(),
+            })
+            .collect();
+    });
+    out
+}
+
+/// Net change for one size bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BucketDelta {
+    /// The bucket this delta describes.
+    pub bucket: SizeBucket,
+    /// Net live allocations gained (negative if more were freed than made).
+    pub allocations: i64,
+    /// Net live bytes gained.
+    pub bytes: i64,
+}
+
+/// A large allocation that was recorded with a backtrace and is still alive.
+#[derive(Debug, Clone)]
+pub struct UnfreedAllocation {
+    /// Size of the allocation in bytes.
+    pub size: usize,
+    /// The call stack that made the allocation.
+    pub backtrace: String,
+}
+
+/// The net allocation change over a scope, per size bucket.
+#[derive(Debug, Clone)]
+pub struct LeakReport {
+    /// What the report covers (e.g. `"scene unload"`).
+    pub label: String,
+    /// Net change per bucket, in [`SizeBucket::ALL`] order.
+    pub buckets: [BucketDelta; 3],
+    /// Recorded large allocations made in the scope that are still alive.
+    /// Empty unless backtrace capture is on in a debug build.
+    pub unfreed_large: Vec<UnfreedAllocation>,
+}
+
+impl LeakReport {
+    /// Net live allocations gained across all buckets.
+    pub fn net_allocations(&self) -> i64 {
+        self.buckets.iter().map(|b| b.allocations).sum()
+    }
+
+    /// Net live bytes gained across all buckets.
+    pub fn net_bytes(&self) -> i64 {
+        self.buckets.iter().map(|b| b.bytes).sum()
+    }
+
+    /// Returns `true` if the scope ended with more live memory than it began with.
+    pub fn has_leaks(&self) -> bool {
+        self.net_bytes() > 0 || self.net_allocations() > 0
+    }
+
+    /// Logs the report: `warn` if anything leaked, `info` otherwise.
+    pub fn log(&self) {
+        if self.has_leaks() {
+            log::warn!("{self}");
+        } else {
+            log::info!("{self}");
+        }
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Leak report [{}]: net {} bytes in {} allocations",
+            self.label,
+            self.net_bytes(),
+            self.net_allocations()
+        )?;
+        for delta in &self.buckets {
+            write!(
+                f,
+                "\n  {:<18} {:>+12} bytes {:>+8} allocs",
+                delta.bucket.label(),
+                delta.bytes,
+                delta.allocations
+            )?;
+        }
+        for unfreed in &self.unfreed_large {
+            write!(
+                f,
+                "\n  unfreed large allocation of {} bytes at:\n{}",
+                unfreed.size, unfreed.backtrace
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Measures the net allocation change over a region of code.
+///
+/// Call [`LeakScope::finish`] to get the report. A scope dropped without
+/// finishing logs its report on its own while leak-check mode is on.
+///
+/// ```rust,ignore
+/// let scope = LeakScope::begin("scene load/unload");
+/// load_and_unload_scene();
+/// let report = scope.finish();
+/// assert!(!report.has_leaks());
+/// ```
+#[derive(Debug)]
+pub struct LeakScope {
+    label: String,
+    start: LeakSnapshot,
+    finished: bool,
+}
+
+impl LeakScope {
+    /// Starts measuring from the current counters.
+    pub fn begin(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            start: LeakSnapshot::capture(),
+            finished: false,
+        }
+    }
+
+    /// Ends the scope and returns its report.
+    pub fn finish(mut self) -> LeakReport {
+        self.finished = true;
+        self.start.report_until_now(std::mem::take(&mut self.label))
+    }
+}
+
+impl Drop for LeakScope {
+    fn drop(&mut self) {
+        if !self.finished && is_enabled() {
+            self.start.report_until_now(self.label.as_str()).log();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_follow_allocator_thresholds() {
+        assert_eq!(SizeBucket::of(16), SizeBucket::Small);
+        assert_eq!(
+            SizeBucket::of(SMALL_ALLOCATION_THRESHOLD),
+            SizeBucket::Medium
+        );
+        assert_eq!(
+            SizeBucket::of(LARGE_ALLOCATION_THRESHOLD),
+            SizeBucket::Large
+        );
+    }
+
+    #[test]
+    fn diff_reports_net_change_per_bucket() {
+        let before = LeakSnapshot {
+            allocations: [10, 2, 0],
+            bytes: [100, 4096, 0],
+            sequence: u64::MAX,
+        };
+        let after = LeakSnapshot {
+            allocations: [12, 1, 1],
+            bytes: [164, 2048, 2 << 20],
+            sequence: u64::MAX,
+        };
+
+        let report = before.diff(&after, "test");
+        assert_eq!(report.buckets[0].allocations, 2);
+        assert_eq!(report.buckets[1].bytes, -2048);
+        assert_eq!(report.net_allocations(), 2);
+        assert!(report.has_leaks());
+        assert!(report.unfreed_large.is_empty());
+    }
+
+    #[test]
+    fn balanced_scope_has_no_leaks() {
+        let snapshot = LeakSnapshot::default();
+        let report = snapshot.diff(&snapshot, "balanced");
+        assert_eq!(report.net_bytes(), 0);
+        assert!(!report.has_leaks());
+    }
+}
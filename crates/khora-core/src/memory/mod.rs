@@ -24,7 +24,9 @@
 
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
+pub mod leak;
 mod tracking_allocator;
+pub use leak::{LeakReport, LeakScope, LeakSnapshot};
 pub use tracking_allocator::SaaTrackingAllocator;
 
 // --- Global Memory Counters ---
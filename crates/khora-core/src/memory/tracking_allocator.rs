@@ -19,9 +19,9 @@ use std::alloc::{GlobalAlloc, Layout, System};
 use std::sync::atomic::Ordering;
 
 /// The size, in bytes, above which an allocation is considered "large".
-const LARGE_ALLOCATION_THRESHOLD: usize = 1024 * 1024; // 1MB
+pub(super) const LARGE_ALLOCATION_THRESHOLD: usize = 1024 * 1024; // 1MB
 /// The size, in bytes, below which an allocation is considered "small".
-const SMALL_ALLOCATION_THRESHOLD: usize = 1024; // 1KB
+pub(super) const SMALL_ALLOCATION_THRESHOLD: usize = 1024; // 1KB
 
 /// A wrapper around a `GlobalAlloc` implementation (like `std::alloc::System`)
 /// that intercepts allocation calls to update the global memory counters defined
@@ -67,6 +67,7 @@ unsafe impl<A: GlobalAlloc> GlobalAlloc for SaaTrackingAllocator<A> {
         let ptr = self.inner.alloc(layout);
         if !ptr.is_null() {
             let size = layout.size();
+            leak::on_alloc(ptr, size);
             let result = CURRENTLY_ALLOCATED_BYTES.fetch_update(
                 Ordering::Relaxed,
                 Ordering::Relaxed,
@@ -101,6 +102,7 @@ unsafe impl<A: GlobalAlloc> GlobalAlloc for SaaTrackingAllocator<A> {
     /// The caller must ensure that `ptr` was allocated by this allocator with the same `layout`.
     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
         let size = layout.size();
+        leak::on_dealloc(ptr, size);
         let result = CURRENTLY_ALLOCATED_BYTES.fetch_update(
             Ordering::Relaxed,
             Ordering::Relaxed,
@@ -126,6 +128,7 @@ unsafe impl<A: GlobalAlloc> GlobalAlloc for SaaTrackingAllocator<A> {
         let ptr = self.inner.alloc_zeroed(layout);
         if !ptr.is_null() {
             let size = layout.size();
+            leak::on_alloc(ptr, size);
             let result = CURRENTLY_ALLOCATED_BYTES.fetch_update(
                 Ordering::Relaxed,
                 Ordering::Relaxed,
@@ -161,6 +164,8 @@ unsafe impl<A: GlobalAlloc> GlobalAlloc for SaaTrackingAllocator<A> {
         let old_size = layout.size();
         let new_ptr = self.inner.realloc(ptr, layout, new_size);
         if !new_ptr.is_null() {
+            leak::on_dealloc(ptr, old_size);
+            leak::on_alloc(new_ptr, new_size);
             TOTAL_REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
             let size_diff = new_size as isize - old_size as isize;
             let fetch_result = match size_diff.cmp(&0) {
@@ -65,6 +65,8 @@ pub struct RigidBodyDesc {
     pub mass: f32,
     /// Whether to enable Continuous Collision Detection (CCD).
     pub ccd_enabled: bool,
+    /// Multiplier applied to the gravity acting on the body.
+    pub gravity_scale: f32,
 }
 
 /// Description for creating a collider.
@@ -122,6 +124,14 @@ pub trait PhysicsProvider: Send + Sync {
     /// Sets the global gravity vector.
     fn set_gravity(&mut self, gravity: Vec3);
 
+    /// Returns the global gravity vector.
+    fn gravity(&self) -> Vec3;
+
+    /// Overrides the gravity acceleration felt by a single body, e.g. inside
+    /// a gravity zone. `None` restores the global gravity. The body's
+    /// gravity scale applies to the override as well.
+    fn set_body_gravity(&mut self, handle: RigidBodyHandle, gravity: Option<Vec3>);
+
     /// Adds a rigid body to the simulation.
     fn add_body(&mut self, desc: RigidBodyDesc) -> RigidBodyHandle;
 
@@ -149,6 +159,10 @@ pub trait PhysicsProvider: Send + Sync {
     /// Updates the properties of an existing rigid body.
     fn update_body_properties(&mut self, handle: RigidBodyHandle, desc: RigidBodyDesc);
 
+    /// Puts a rigid body to sleep, or wakes it up. A sleeping body is not
+    /// integrated until something touches it or it is woken up.
+    fn set_body_sleeping(&mut self, handle: RigidBodyHandle, sleeping: bool);
+
     /// Updates the properties of an existing collider.
     fn update_collider_properties(&mut self, handle: ColliderHandle, desc: ColliderDesc);
 
@@ -156,7 +170,16 @@ pub trait PhysicsProvider: Send + Sync {
     fn get_debug_render_data(&self) -> (Vec<Vec3>, Vec<[u32; 2]>);
 
     /// Casts a ray into the physics world and returns the closest hit.
-    fn cast_ray(&self, ray: &Ray, max_toi: f32, solid: bool) -> Option<RaycastHit>;
+    ///
+    /// The `exclude` collider is ignored, e.g. the collider of the entity
+    /// the ray starts from, which would otherwise be hit first.
+    fn cast_ray(
+        &self,
+        ray: &Ray,
+        max_toi: f32,
+        solid: bool,
+        exclude: Option<ColliderHandle>,
+    ) -> Option<RaycastHit>;
 
     /// Returns the collision events that occurred during the last step.
     fn get_collision_events(&self) -> Vec<CollisionEvent>;
@@ -19,13 +19,21 @@
 //! windowing, input, and filesystem access.
 
 pub mod input;
+pub mod monitor;
+pub mod power;
+pub mod survey;
 pub mod window;
 
 pub use input::{InputEvent, MouseButton};
+pub use monitor::MonitorInfo;
+pub use power::{PowerEvent, PowerPolicy, PowerState};
+pub use survey::{CpuSurvey, GpuSurvey, HardwareSurvey};
 pub use window::{KhoraWindow, KhoraWindowHandle, WindowHandle};
 
+use serde::{Deserialize, Serialize};
+
 /// Represents the thermal state of the device.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ThermalStatus {
     /// Device is running cool.
     #[default]
@@ -39,7 +47,7 @@ pub enum ThermalStatus {
 }
 
 /// Represents the power source and battery level.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum BatteryLevel {
     /// Device is connected to a stable power source.
     #[default]
@@ -0,0 +1,40 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Description of a display connected to the host.
+
+use serde::{Deserialize, Serialize};
+
+/// A display the window can be shown on, as reported by the windowing
+/// backend.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct MonitorInfo {
+    /// The monitor's name, if the platform exposes one.
+    pub name: Option<String>,
+    /// Resolution in physical pixels (width, height).
+    pub resolution: (u32, u32),
+    /// Refresh rate in millihertz (e.g. `59_940`), if known.
+    pub refresh_rate_millihertz: Option<u32>,
+    /// The monitor's scale factor, used for HiDPI rendering.
+    pub scale_factor: f64,
+    /// `true` for the monitor the window is currently on.
+    pub is_current: bool,
+}
+
+impl MonitorInfo {
+    /// Refresh rate in hertz, if known.
+    pub fn refresh_rate_hz(&self) -> Option<f32> {
+        self.refresh_rate_millihertz.map(|mhz| mhz as f32 / 1000.0)
+    }
+}
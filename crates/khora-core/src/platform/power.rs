@@ -0,0 +1,137 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Power-saving state of the engine.
+//!
+//! The DCC's power governor maps the [`BatteryLevel`](super::BatteryLevel)
+//! reported by the platform monitor to a [`PowerState`] and the
+//! [`PowerPolicy`] the engine follows in it: a frame-rate cap, the most
+//! expensive GORNA strategy agents may receive, and how much background work
+//! to keep. Each change of state is published as a [`PowerEvent`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::control::gorna::StrategyId;
+
+/// Power-saving state chosen by the governor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum PowerState {
+    /// On mains power, or the governor is disabled: no restriction.
+    #[default]
+    Unrestricted,
+    /// Running on battery.
+    Battery,
+    /// The battery is critically low.
+    Critical,
+}
+
+impl PowerState {
+    /// Returns the lowercase name of the state.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerState::Unrestricted => "unrestricted",
+            PowerState::Battery => "battery",
+            PowerState::Critical => "critical",
+        }
+    }
+}
+
+/// What the engine does in a [`PowerState`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PowerPolicy {
+    /// Frames per second the windowed driver paces to, or `None` to run
+    /// uncapped.
+    pub fps_cap: Option<u32>,
+    /// Most expensive strategy GORNA may allocate. Agent-specific
+    /// [`StrategyId::Custom`] strategies are not restricted.
+    pub max_strategy: StrategyId,
+    /// Fraction (0..=1) of the normal background work to keep: ECS
+    /// compaction time and low-power tick rate while suspended.
+    pub background_work_scale: f32,
+}
+
+impl PowerPolicy {
+    /// The policy of [`PowerState::Unrestricted`].
+    pub const UNRESTRICTED: Self = Self {
+        fps_cap: None,
+        max_strategy: StrategyId::HighPerformance,
+        background_work_scale: 1.0,
+    };
+
+    /// Returns the frame duration matching [`fps_cap`](Self::fps_cap).
+    pub fn frame_interval(&self) -> Option<std::time::Duration> {
+        self.fps_cap
+            .filter(|&fps| fps > 0)
+            .map(|fps| std::time::Duration::from_secs_f64(1.0 / f64::from(fps)))
+    }
+
+    /// Whether GORNA may allocate `strategy` under this policy.
+    pub fn allows(&self, strategy: StrategyId) -> bool {
+        match (power_rank(strategy), power_rank(self.max_strategy)) {
+            (Some(rank), Some(max)) => rank <= max,
+            _ => true,
+        }
+    }
+}
+
+impl Default for PowerPolicy {
+    fn default() -> Self {
+        Self::UNRESTRICTED
+    }
+}
+
+/// Position of a predefined strategy on the power scale, cheapest first.
+fn power_rank(strategy: StrategyId) -> Option<u8> {
+    match strategy {
+        StrategyId::LowPower => Some(0),
+        StrategyId::Balanced => Some(1),
+        StrategyId::HighPerformance => Some(2),
+        StrategyId::Custom(_) => None,
+    }
+}
+
+/// Sent when the power governor changes state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerEvent {
+    /// State before the change.
+    pub previous: PowerState,
+    /// State after the change.
+    pub state: PowerState,
+    /// Policy now in effect.
+    pub policy: PowerPolicy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_caps_predefined_strategies_only() {
+        let policy = PowerPolicy {
+            fps_cap: Some(30),
+            max_strategy: StrategyId::Balanced,
+            background_work_scale: 0.5,
+        };
+        assert!(policy.allows(StrategyId::LowPower));
+        assert!(policy.allows(StrategyId::Balanced));
+        assert!(!policy.allows(StrategyId::HighPerformance));
+        assert!(policy.allows(StrategyId::Custom(7)));
+        assert_eq!(
+            policy.frame_interval(),
+            Some(std::time::Duration::from_secs_f64(1.0 / 30.0))
+        );
+        assert_eq!(PowerPolicy::UNRESTRICTED.frame_interval(), None);
+    }
+}
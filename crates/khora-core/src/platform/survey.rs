@@ -0,0 +1,166 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The startup hardware report.
+
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::MonitorInfo;
+use crate::renderer::api::core::GraphicsAdapterInfo;
+
+/// The graphics adapter section of a [`HardwareSurvey`].
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct GpuSurvey {
+    /// The adapter's name (e.g., "NVIDIA GeForce RTX 4090").
+    pub name: String,
+    /// The graphics API in use (e.g., "Vulkan").
+    pub backend: String,
+    /// The physical type of the adapter (e.g., "DiscreteGpu").
+    pub device_type: String,
+    /// The driver name, empty if not reported.
+    pub driver: String,
+    /// The driver version or build information, empty if not reported.
+    pub driver_info: String,
+    /// Dedicated VRAM in bytes, if the backend reports it.
+    pub vram_bytes: Option<u64>,
+}
+
+impl GpuSurvey {
+    /// Builds the section from the adapter the renderer runs on.
+    pub fn from_adapter(info: &GraphicsAdapterInfo, vram_bytes: Option<u64>) -> Self {
+        Self {
+            name: info.name.clone(),
+            backend: format!("{:?}", info.backend_type),
+            device_type: format!("{:?}", info.device_type),
+            driver: info.driver.clone(),
+            driver_info: info.driver_info.clone(),
+            vram_bytes,
+        }
+    }
+}
+
+/// The processor section of a [`HardwareSurvey`].
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CpuSurvey {
+    /// The CPU brand string (e.g., "AMD Ryzen 9 7950X").
+    pub brand: String,
+    /// Number of physical cores, if the platform reports it.
+    pub physical_cores: Option<usize>,
+    /// Number of logical cores (hardware threads).
+    pub logical_cores: usize,
+    /// Nominal frequency in MHz, `0` if unknown.
+    pub frequency_mhz: u64,
+}
+
+/// A one-off description of the machine the engine runs on, collected at
+/// startup.
+///
+/// It is saved as JSON for support tickets, forwarded to the DCC so its
+/// hardware context starts from real figures, and kept by the telemetry
+/// service for observers.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct HardwareSurvey {
+    /// Operating system name and version (e.g., "Ubuntu 24.04").
+    pub os: String,
+    /// The processor.
+    pub cpu: CpuSurvey,
+    /// Installed system memory in bytes.
+    pub total_memory_bytes: u64,
+    /// The graphics adapter, `None` when running without a renderer.
+    pub gpu: Option<GpuSurvey>,
+    /// Connected displays.
+    pub monitors: Vec<MonitorInfo>,
+}
+
+impl HardwareSurvey {
+    /// Serializes the survey as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        // Only strings, numbers and options: serialization cannot fail.
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Parses a survey previously written by [`write_json`](Self::write_json).
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Writes the survey as JSON to `path`, creating parent directories.
+    pub fn write_json(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Total VRAM of the graphics adapter in bytes, if known.
+    pub fn vram_bytes(&self) -> Option<u64> {
+        self.gpu.as_ref().and_then(|gpu| gpu.vram_bytes)
+    }
+
+    /// The monitor the window is on, or the first one listed.
+    pub fn current_monitor(&self) -> Option<&MonitorInfo> {
+        self.monitors
+            .iter()
+            .find(|m| m.is_current)
+            .or_else(|| self.monitors.first())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let survey = HardwareSurvey {
+            os: "TestOS 1.0".to_string(),
+            cpu: CpuSurvey {
+                brand: "Test CPU".to_string(),
+                physical_cores: Some(8),
+                logical_cores: 16,
+                frequency_mhz: 3600,
+            },
+            total_memory_bytes: 32 << 30,
+            gpu: Some(GpuSurvey {
+                name: "Test GPU".to_string(),
+                backend: "Vulkan".to_string(),
+                vram_bytes: Some(8 << 30),
+                ..Default::default()
+            }),
+            monitors: vec![
+                MonitorInfo {
+                    resolution: (1920, 1080),
+                    refresh_rate_millihertz: Some(60_000),
+                    scale_factor: 1.0,
+                    ..Default::default()
+                },
+                MonitorInfo {
+                    name: Some("Main".to_string()),
+                    resolution: (2560, 1440),
+                    refresh_rate_millihertz: Some(143_856),
+                    scale_factor: 1.25,
+                    is_current: true,
+                },
+            ],
+        };
+
+        let parsed = HardwareSurvey::from_json(&survey.to_json()).unwrap();
+        assert_eq!(parsed, survey);
+        assert_eq!(parsed.vram_bytes(), Some(8 << 30));
+        assert_eq!(parsed.current_monitor().unwrap().resolution, (2560, 1440));
+    }
+}
@@ -14,6 +14,7 @@
 
 //! Defines the `KhoraWindow` trait and related types for windowing abstraction.
 
+use super::MonitorInfo;
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -62,4 +63,10 @@ pub trait KhoraWindow: HasWindowHandle + HasDisplayHandle + Send + Sync {
 
     /// Returns a unique identifier for the window.
     fn id(&self) -> u64;
+
+    /// Lists the displays connected to the host. Backends that cannot
+    /// enumerate them return an empty list.
+    fn monitors(&self) -> Vec<MonitorInfo> {
+        Vec::new()
+    }
 }
@@ -25,4 +25,8 @@ pub struct GraphicsAdapterInfo {
     pub backend_type: GraphicsBackendType,
     /// The physical type of the adapter.
     pub device_type: RendererDeviceType,
+    /// The driver name (e.g., "NVIDIA"), empty if the backend does not report it.
+    pub driver: String,
+    /// The driver version or build information, empty if not reported.
+    pub driver_info: String,
 }
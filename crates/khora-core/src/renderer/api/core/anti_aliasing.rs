@@ -0,0 +1,40 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The anti-aliasing techniques the renderer can negotiate between.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// Anti-aliasing technique, from cheapest to most expensive.
+///
+/// The render agent picks one from its GORNA budget unless the camera's
+/// anti-aliasing settings force it.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Encode, Decode,
+)]
+#[non_exhaustive]
+pub enum AntiAliasingMode {
+    /// Fast approximate AA: one fullscreen pass blurring along luminance
+    /// edges. Cheap, but softens texture detail.
+    #[default]
+    Fxaa,
+    /// Temporal AA: each frame is rendered with a sub-pixel jitter and
+    /// blended with the reprojected history through per-pixel motion
+    /// vectors.
+    Taa,
+    /// Multisample AA: the scene is rasterized with several samples per
+    /// pixel and resolved at the end of the scene pass.
+    Msaa,
+}
@@ -15,8 +15,12 @@
 //! Rendering context structures for grouping related rendering parameters.
 
 use crate::{
-    math::LinearRgba,
-    renderer::api::resource::{SamplerId, TextureViewId},
+    math::{LinearRgba, Mat4, Vec2, Vec3},
+    renderer::api::{
+        command::LoadOp,
+        core::{DepthMode, LightingDebugView},
+        resource::{SamplerId, TextureViewId},
+    },
 };
 
 /// Groups rendering parameters that are commonly passed together.
@@ -31,6 +35,21 @@ pub struct RenderContext<'a> {
     pub shadow_atlas: Option<&'a TextureViewId>,
     /// The comparison sampler for shadows.
     pub shadow_sampler: Option<&'a SamplerId>,
+    /// The depth convention, which selects the depth clear value.
+    pub depth_mode: DepthMode,
+    /// Whether `color_target` is a linear HDR target, so lanes must skip
+    /// tone mapping and use their HDR pipelines.
+    pub hdr: bool,
+    /// When set, `color_target` is multisampled and resolves into this view
+    /// at the end of the pass.
+    pub resolve_target: Option<&'a TextureViewId>,
+    /// Sub-pixel offset, in NDC units, added to clip-space positions.
+    pub jitter: Vec2,
+    /// Whether a depth prepass already filled `depth_target`, so the scene
+    /// pass must keep its contents.
+    pub depth_prepared: bool,
+    /// The lighting debug visualization lit lanes draw instead of shading.
+    pub debug_view: LightingDebugView,
 }
 
 impl<'a> RenderContext<'a> {
@@ -52,8 +71,42 @@ impl<'a> RenderContext<'a> {
             clear_color,
             shadow_atlas: None,
             shadow_sampler: None,
+            depth_mode: DepthMode::default(),
+            hdr: false,
+            resolve_target: None,
+            jitter: Vec2::ZERO,
+            depth_prepared: false,
+            debug_view: LightingDebugView::None,
         }
     }
+
+    /// Redirects drawing to the multisampled `color` and `depth` views,
+    /// resolving into the current color target at the end of the pass.
+    pub fn multisample_into(&mut self, color: &'a TextureViewId, depth: &'a TextureViewId) {
+        self.resolve_target = Some(self.color_target);
+        self.color_target = color;
+        self.depth_target = Some(depth);
+    }
+
+    /// Whether the color and depth targets are multisampled.
+    pub fn multisampled(&self) -> bool {
+        self.resolve_target.is_some()
+    }
+
+    /// How the scene pass loads `depth_target`: kept after a depth prepass,
+    /// cleared to the far plane otherwise.
+    pub fn depth_load(&self) -> LoadOp<f32> {
+        if self.depth_prepared {
+            LoadOp::Load
+        } else {
+            LoadOp::Clear(self.depth_mode.clear_depth())
+        }
+    }
+
+    /// Applies [`jitter`](Self::jitter) to a view-projection matrix.
+    pub fn jittered(&self, view_proj: Mat4) -> Mat4 {
+        Mat4::from_translation(Vec3::new(self.jitter.x, self.jitter.y, 0.0)) * view_proj
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +136,43 @@ mod tests {
         assert_eq!(*ctx.color_target, TextureViewId(1));
         assert!(ctx.depth_target.is_none());
     }
+
+    #[test]
+    fn test_multisample_into_resolves_into_color_target() {
+        let color_view = TextureViewId(1);
+        let msaa_color = TextureViewId(3);
+        let msaa_depth = TextureViewId(4);
+
+        let mut ctx = RenderContext::new(&color_view, None, LinearRgba::BLACK);
+        assert!(!ctx.multisampled());
+        ctx.multisample_into(&msaa_color, &msaa_depth);
+
+        assert!(ctx.multisampled());
+        assert_eq!(*ctx.color_target, TextureViewId(3));
+        assert_eq!(ctx.resolve_target.copied(), Some(TextureViewId(1)));
+        assert_eq!(ctx.depth_target.copied(), Some(TextureViewId(4)));
+    }
+
+    #[test]
+    fn test_depth_load_keeps_prepass_depth() {
+        let color_view = TextureViewId(1);
+        let mut ctx = RenderContext::new(&color_view, None, LinearRgba::BLACK);
+        ctx.depth_mode = DepthMode::Reversed;
+        assert!(matches!(ctx.depth_load(), LoadOp::Clear(depth) if depth == 0.0));
+
+        ctx.depth_prepared = true;
+        assert!(matches!(ctx.depth_load(), LoadOp::Load));
+    }
+
+    #[test]
+    fn test_jittered_offsets_ndc_position() {
+        let color_view = TextureViewId(1);
+        let mut ctx = RenderContext::new(&color_view, None, LinearRgba::BLACK);
+        ctx.jitter = Vec2::new(0.25, -0.5);
+
+        let clip = ctx.jittered(Mat4::IDENTITY) * crate::math::Vec4::new(0.0, 0.0, 0.5, 2.0);
+        assert!((clip.x / clip.w - 0.25).abs() < 1e-6);
+        assert!((clip.y / clip.w + 0.5).abs() < 1e-6);
+        assert!((clip.z - 0.5).abs() < 1e-6);
+    }
 }
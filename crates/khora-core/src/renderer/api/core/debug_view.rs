@@ -0,0 +1,107 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lighting debug visualizations of the scene pass.
+
+/// What the lit scene lanes draw instead of the shaded scene.
+///
+/// Chosen at runtime through the `r.debug_view` console variable, whose
+/// initial value comes from [`RenderSettings`](super::RenderSettings).
+/// Only the lit lanes (`LitForward`, `ForwardPlus`) honor it; views they
+/// cannot produce render as plain geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum LightingDebugView {
+    /// Regular shading.
+    #[default]
+    None,
+    /// Number of lights shading each pixel, from blue (none) to red (16 or
+    /// more). Forward+ shows the per-tile count left by light culling.
+    LightHeatmap,
+    /// Number of fragments shaded per pixel, accumulated additively with
+    /// the depth test off.
+    Overdraw,
+    /// World-space normals, mapped from `[-1, 1]` to `[0, 1]`.
+    Normals,
+    /// Surface roughness as gray, derived from the specular power.
+    Roughness,
+    /// Unlit material base color.
+    Albedo,
+    /// Tints each pixel by the cascade of the first shadowed directional
+    /// light it samples: red, green, blue, then yellow.
+    ShadowCascades,
+}
+
+impl LightingDebugView {
+    /// Every view, in shader index order.
+    pub const ALL: [LightingDebugView; 7] = [
+        LightingDebugView::None,
+        LightingDebugView::LightHeatmap,
+        LightingDebugView::Overdraw,
+        LightingDebugView::Normals,
+        LightingDebugView::Roughness,
+        LightingDebugView::Albedo,
+        LightingDebugView::ShadowCascades,
+    ];
+
+    /// The console name of the view.
+    pub fn name(self) -> &'static str {
+        match self {
+            LightingDebugView::None => "none",
+            LightingDebugView::LightHeatmap => "light_heatmap",
+            LightingDebugView::Overdraw => "overdraw",
+            LightingDebugView::Normals => "normals",
+            LightingDebugView::Roughness => "roughness",
+            LightingDebugView::Albedo => "albedo",
+            LightingDebugView::ShadowCascades => "shadow_cascades",
+        }
+    }
+
+    /// Parses a console name, ignoring case and surrounding whitespace.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|view| view.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The index the lit shaders switch on.
+    pub fn shader_index(self) -> u32 {
+        self as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_round_trip() {
+        for view in LightingDebugView::ALL {
+            assert_eq!(LightingDebugView::from_name(view.name()), Some(view));
+        }
+        assert_eq!(
+            LightingDebugView::from_name(" Shadow_Cascades "),
+            Some(LightingDebugView::ShadowCascades)
+        );
+        assert_eq!(LightingDebugView::from_name("wireframe"), None);
+    }
+
+    #[test]
+    fn test_shader_index_follows_all_order() {
+        for (index, view) in LightingDebugView::ALL.into_iter().enumerate() {
+            assert_eq!(view.shader_index(), index as u32);
+        }
+    }
+}
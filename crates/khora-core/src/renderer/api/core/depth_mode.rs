@@ -0,0 +1,130 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The depth-buffer convention used by scene passes.
+
+use crate::math::{Mat4, Vec4};
+use crate::renderer::api::pipeline::enums::CompareFunction;
+
+/// How scene depth is mapped into the `[0, 1]` depth buffer.
+///
+/// With [`Reversed`](Self::Reversed), the near plane maps to 1 and the far
+/// plane to 0. Floating-point depth then keeps its precision in the distance,
+/// which removes far-field z-fighting and allows an infinite far plane.
+///
+/// The mode is registered in the service registry. Projections, depth
+/// compares and depth clears must all use the same mode; the helpers below
+/// keep them consistent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepthMode {
+    /// Near maps to 0, far to 1; nearer fragments have smaller depth.
+    #[default]
+    Standard,
+    /// Near maps to 1, far to 0; nearer fragments have larger depth.
+    Reversed,
+}
+
+impl DepthMode {
+    /// Returns `true` for [`DepthMode::Reversed`].
+    pub fn is_reversed(self) -> bool {
+        matches!(self, DepthMode::Reversed)
+    }
+
+    /// The value depth attachments are cleared to — the far plane.
+    pub fn clear_depth(self) -> f32 {
+        match self {
+            DepthMode::Standard => 1.0,
+            DepthMode::Reversed => 0.0,
+        }
+    }
+
+    /// The depth test that keeps the nearer fragment.
+    pub fn compare(self) -> CompareFunction {
+        match self {
+            DepthMode::Standard => CompareFunction::Less,
+            DepthMode::Reversed => CompareFunction::Greater,
+        }
+    }
+
+    /// Like [`compare`](Self::compare), but also passes equal depths.
+    pub fn compare_or_equal(self) -> CompareFunction {
+        match self {
+            DepthMode::Standard => CompareFunction::LessEqual,
+            DepthMode::Reversed => CompareFunction::GreaterEqual,
+        }
+    }
+
+    /// The NDC depth of the near plane.
+    pub fn near_ndc(self) -> f32 {
+        1.0 - self.clear_depth()
+    }
+
+    /// Converts a standard `[0, 1]` projection (or view-projection) matrix to
+    /// this mode.
+    ///
+    /// Reversing maps clip `z` to `w - z`. Because the remap acts on clip
+    /// space, it applies equally to a projection `P` and to `P * view`.
+    pub fn apply(self, projection: Mat4) -> Mat4 {
+        match self {
+            DepthMode::Standard => projection,
+            DepthMode::Reversed => {
+                let flip = Mat4::from_cols(
+                    Vec4::new(1.0, 0.0, 0.0, 0.0),
+                    Vec4::new(0.0, 1.0, 0.0, 0.0),
+                    Vec4::new(0.0, 0.0, -1.0, 0.0),
+                    Vec4::new(0.0, 0.0, 1.0, 1.0),
+                );
+                flip * projection
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ndc_depth(projection: Mat4, view_z: f32) -> f32 {
+        let clip = projection * Vec4::new(0.0, 0.0, view_z, 1.0);
+        clip.z / clip.w
+    }
+
+    #[test]
+    fn standard_is_identity() {
+        let p = Mat4::perspective_rh_zo(1.0, 1.5, 0.1, 100.0);
+        assert_eq!(DepthMode::Standard.apply(p), p);
+        assert_eq!(DepthMode::Standard.clear_depth(), 1.0);
+        assert_eq!(DepthMode::Standard.compare(), CompareFunction::Less);
+    }
+
+    #[test]
+    fn reversed_swaps_near_and_far() {
+        let p = DepthMode::Reversed.apply(Mat4::perspective_rh_zo(1.0, 1.5, 0.1, 100.0));
+        assert!((ndc_depth(p, -0.1) - 1.0).abs() < 1e-5);
+        assert!(ndc_depth(p, -100.0).abs() < 1e-5);
+        assert!(ndc_depth(p, -1.0) > ndc_depth(p, -10.0));
+        assert_eq!(DepthMode::Reversed.clear_depth(), 0.0);
+        assert_eq!(DepthMode::Reversed.near_ndc(), 1.0);
+        assert_eq!(DepthMode::Reversed.compare(), CompareFunction::Greater);
+    }
+
+    #[test]
+    fn reversed_infinite_matches_dedicated_projection() {
+        let flipped = DepthMode::Reversed.apply(Mat4::perspective_infinite_rh_zo(1.0, 1.5, 0.1));
+        let direct = Mat4::perspective_infinite_reverse_rh_zo(1.0, 1.5, 0.1);
+        for z in [-0.1, -1.0, -1000.0, -1.0e6] {
+            assert!((ndc_depth(flipped, z) - ndc_depth(direct, z)).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! When the scene gets a depth-only prepass.
+
+/// Whether the scene's depth is laid down by a depth-only prepass before the
+/// shading pass.
+///
+/// The prepass draws every opaque mesh once more, but then each pixel runs
+/// the fragment shader only for its nearest surface (early-Z). It pays off
+/// when fragment shading dominates and meshes overlap heavily.
+///
+/// The mode is read from the service registry; apps may insert one in
+/// bootstrap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DepthPrepassMode {
+    /// The render agent runs the prepass when the scene's estimated overdraw
+    /// is high enough to pay for the extra geometry pass.
+    #[default]
+    Auto,
+    /// Always run the prepass.
+    Always,
+    /// Never run the prepass.
+    Never,
+}
@@ -27,14 +27,20 @@ pub enum GpuHook {
     MainPassEnd,
     /// Marks the absolute end of GPU work for a frame.
     FrameEnd,
+    /// Marks the beginning of the depth-only prepass.
+    DepthPrepassBegin,
+    /// Marks the end of the depth-only prepass.
+    DepthPrepassEnd,
 }
 
 impl GpuHook {
     /// An array containing all `GpuHook` variants.
-    pub const ALL: [GpuHook; 4] = [
+    pub const ALL: [GpuHook; 6] = [
         GpuHook::FrameStart,
         GpuHook::MainPassBegin,
         GpuHook::MainPassEnd,
         GpuHook::FrameEnd,
+        GpuHook::DepthPrepassBegin,
+        GpuHook::DepthPrepassEnd,
     ];
 }
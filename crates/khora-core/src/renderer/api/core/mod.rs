@@ -21,19 +21,29 @@
 pub const MAX_FRAMES_IN_FLIGHT: usize = 2;
 
 pub mod adapter;
+pub mod anti_aliasing;
 pub mod backend;
 pub mod context;
+pub mod debug_view;
+pub mod depth_mode;
+pub mod depth_prepass;
 pub mod frame_context;
 pub mod gpu_hook;
 pub mod settings;
 pub mod shader;
 pub mod stats;
+pub mod tone_mapping;
 
 pub use self::adapter::*;
+pub use self::anti_aliasing::AntiAliasingMode;
 pub use self::backend::*;
 pub use self::context::*;
+pub use self::debug_view::LightingDebugView;
+pub use self::depth_mode::DepthMode;
+pub use self::depth_prepass::DepthPrepassMode;
 pub use self::frame_context::{FrameContext, StageHandle};
 pub use self::gpu_hook::*;
 pub use self::settings::*;
 pub use self::shader::*;
 pub use self::stats::*;
+pub use self::tone_mapping::ToneMapping;
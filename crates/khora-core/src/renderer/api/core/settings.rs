@@ -14,6 +14,7 @@
 
 //! Global settings for the rendering system.
 
+use crate::renderer::api::core::LightingDebugView;
 use crate::renderer::api::util::enums::RenderStrategy;
 
 /// A collection of global settings that can affect the rendering process.
@@ -31,6 +32,8 @@ pub struct RenderSettings {
     pub resize_max_pending_frames: u32,
     /// A runtime toggle to enable/disable GPU timestamp instrumentation for profiling.
     pub enable_gpu_timestamps: bool,
+    /// The lighting debug visualization drawn instead of the shaded scene.
+    pub debug_view: LightingDebugView,
 }
 
 impl Default for RenderSettings {
@@ -42,6 +45,7 @@ impl Default for RenderSettings {
             resize_debounce_ms: 120,
             resize_max_pending_frames: 10,
             enable_gpu_timestamps: true,
+            debug_view: LightingDebugView::None,
         }
     }
 }
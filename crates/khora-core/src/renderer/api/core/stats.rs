@@ -27,12 +27,20 @@ pub struct RenderStats {
     pub gpu_main_pass_time_ms: f32,
     /// The total GPU execution time for the entire frame, as measured by timestamp queries.
     pub gpu_frame_total_time_ms: f32,
+    /// The GPU execution time of the depth prepass, or `0.0` on frames where
+    /// it did not run.
+    pub gpu_depth_prepass_time_ms: f32,
     /// The number of draw calls encoded for the frame.
     pub draw_calls: u32,
     /// The total number of triangles submitted for the frame.
     pub triangles_rendered: u32,
     /// An estimate of the VRAM usage in megabytes.
     pub vram_usage_estimate_mb: f32,
+    /// The number of destroyed GPU resources still waiting for the GPU to
+    /// finish the frame that retired them.
+    pub pending_gpu_destructions: u32,
+    /// The VRAM held by those pending destructions, in megabytes.
+    pub pending_gpu_destruction_mb: f32,
 }
 
 impl Default for RenderStats {
@@ -43,9 +51,86 @@ impl Default for RenderStats {
             cpu_render_submission_time_ms: 0.0,
             gpu_main_pass_time_ms: 0.0,
             gpu_frame_total_time_ms: 0.0,
+            gpu_depth_prepass_time_ms: 0.0,
             draw_calls: 0,
             triangles_rendered: 0,
             vram_usage_estimate_mb: 0.0,
+            pending_gpu_destructions: 0,
+            pending_gpu_destruction_mb: 0.0,
         }
     }
 }
+
+/// How Forward+ light culling distributed the scene's lights over the
+/// screen tiles, read back from the tile light grid.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LightCullingStats {
+    /// The number of lights submitted to the culling pass.
+    pub lights: u32,
+    /// The number of screen tiles.
+    pub tiles: u32,
+    /// The number of tiles no light reaches.
+    pub empty_tiles: u32,
+    /// The number of tiles whose light list hit the per-tile cap, so
+    /// lights beyond it were dropped.
+    pub saturated_tiles: u32,
+    /// The largest light count of a single tile.
+    pub max_tile_lights: u32,
+    /// The sum of the light counts of every tile.
+    pub total_tile_lights: u64,
+}
+
+impl LightCullingStats {
+    /// Aggregates the per-tile light counts of a culling pass that kept at
+    /// most `max_lights_per_tile` lights per tile.
+    pub fn from_tile_counts(
+        lights: u32,
+        max_lights_per_tile: u32,
+        counts: impl IntoIterator<Item = u32>,
+    ) -> Self {
+        let mut stats = Self {
+            lights,
+            ..Self::default()
+        };
+        for count in counts {
+            stats.tiles += 1;
+            stats.total_tile_lights += u64::from(count);
+            stats.max_tile_lights = stats.max_tile_lights.max(count);
+            if count == 0 {
+                stats.empty_tiles += 1;
+            }
+            if count >= max_lights_per_tile {
+                stats.saturated_tiles += 1;
+            }
+        }
+        stats
+    }
+
+    /// The mean number of lights per tile.
+    pub fn average_tile_lights(&self) -> f32 {
+        if self.tiles == 0 {
+            0.0
+        } else {
+            self.total_tile_lights as f32 / self.tiles as f32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_culling_stats_from_tile_counts() {
+        let stats = LightCullingStats::from_tile_counts(5, 4, [0, 2, 4, 0, 1, 5]);
+
+        assert_eq!(stats.lights, 5);
+        assert_eq!(stats.tiles, 6);
+        assert_eq!(stats.empty_tiles, 2);
+        assert_eq!(stats.saturated_tiles, 2);
+        assert_eq!(stats.max_tile_lights, 5);
+        assert_eq!(stats.total_tile_lights, 12);
+        assert!((stats.average_tile_lights() - 2.0).abs() < 1e-6);
+        assert_eq!(LightCullingStats::default().average_tile_lights(), 0.0);
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! The operators that compress the exposed HDR scene into display range.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// Tone mapping operator applied by the post-processing stack.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Encode, Decode,
+)]
+#[non_exhaustive]
+pub enum ToneMapping {
+    /// Clamps the exposed color to `[0, 1]`; highlights clip.
+    Clamp,
+    /// Reinhard's `c / (1 + c)`: a soft shoulder that never clips, at the
+    /// cost of flat, desaturated highlights.
+    #[default]
+    Reinhard,
+    /// Narkowicz's fit of the ACES filmic curve: more contrast and
+    /// saturation than Reinhard, with a gentle roll-off to white.
+    Aces,
+}
+
+impl ToneMapping {
+    /// Index of the operator in the tone mapping shader's `TONE_MAP_*`
+    /// constants.
+    pub fn shader_index(self) -> u32 {
+        self as u32
+    }
+}
@@ -18,8 +18,12 @@ pub mod descriptor;
 pub mod enums;
 pub mod layout;
 pub mod state;
+pub mod vertex;
+pub mod vertex_registry;
 
 pub use self::descriptor::*;
 pub use self::enums::*;
 pub use self::layout::*;
 pub use self::state::*;
+pub use self::vertex::*;
+pub use self::vertex_registry::*;
@@ -31,7 +31,7 @@ pub struct VertexAttributeDescriptor {
 }
 
 /// Describes the memory layout of a single vertex buffer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VertexBufferLayoutDescriptor<'a> {
     /// The byte distance between consecutive elements in the buffer.
     pub array_stride: u64,
@@ -0,0 +1,173 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Compile-time vertex structs and their buffer layouts.
+//!
+//! Each built-in struct mirrors one named format of the
+//! [`VertexFormatRegistry`](super::VertexFormatRegistry). Lanes build their
+//! pipelines from [`Vertex::LAYOUT`] instead of spelling offsets by hand, so
+//! a struct and the layout describing it cannot drift apart.
+
+use super::enums::{VertexFormat, VertexStepMode};
+use super::state::{VertexAttributeDescriptor, VertexBufferLayoutDescriptor};
+use bytemuck::{Pod, Zeroable};
+use std::borrow::Cow;
+
+/// A plain-old-data vertex type with a known buffer layout.
+pub trait Vertex: Pod {
+    /// Name under which the layout is registered.
+    const NAME: &'static str;
+    /// Layout of one element of a buffer of `Self`.
+    const LAYOUT: VertexBufferLayoutDescriptor<'static>;
+}
+
+const fn attribute(
+    shader_location: u32,
+    format: VertexFormat,
+    offset: u64,
+) -> VertexAttributeDescriptor {
+    VertexAttributeDescriptor {
+        shader_location,
+        format,
+        offset,
+    }
+}
+
+/// Vertex of a static mesh, as uploaded by [`Mesh::create_vertex_buffer`]
+/// when its layout holds position, normal and uv only.
+///
+/// [`Mesh::create_vertex_buffer`]: crate::renderer::api::scene::Mesh::create_vertex_buffer
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Pod, Zeroable)]
+pub struct StaticVertex {
+    /// Object-space position, location 0.
+    pub position: [f32; 3],
+    /// Object-space normal, location 1.
+    pub normal: [f32; 3],
+    /// Texture coordinates, location 2.
+    pub uv: [f32; 2],
+}
+
+impl StaticVertex {
+    /// Attributes of the position stream only, for depth-only passes.
+    pub const POSITION_ONLY: VertexBufferLayoutDescriptor<'static> = VertexBufferLayoutDescriptor {
+        array_stride: 32,
+        step_mode: VertexStepMode::Vertex,
+        attributes: Cow::Borrowed(&[attribute(0, VertexFormat::Float32x3, 0)]),
+    };
+}
+
+impl Vertex for StaticVertex {
+    const NAME: &'static str = "static";
+    const LAYOUT: VertexBufferLayoutDescriptor<'static> = VertexBufferLayoutDescriptor {
+        array_stride: 32,
+        step_mode: VertexStepMode::Vertex,
+        attributes: Cow::Borrowed(&[
+            attribute(0, VertexFormat::Float32x3, 0),
+            attribute(1, VertexFormat::Float32x3, 12),
+            attribute(2, VertexFormat::Float32x2, 24),
+        ]),
+    };
+}
+
+/// Vertex of a skinned mesh: a [`StaticVertex`] followed by four joint
+/// influences.
+///
+/// Locations 3 and 4 stay free for tangents and colors. The built-in lanes
+/// skin on the GPU from a separate
+/// [`SkinInfluence`](crate::renderer::api::scene::SkinInfluence) buffer;
+/// this format is for custom lanes that stream influences as attributes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Pod, Zeroable)]
+pub struct SkinnedVertex {
+    /// Object-space position, location 0.
+    pub position: [f32; 3],
+    /// Object-space normal, location 1.
+    pub normal: [f32; 3],
+    /// Texture coordinates, location 2.
+    pub uv: [f32; 2],
+    /// Joint indices into the skin, location 5.
+    pub joints: [u32; 4],
+    /// Joint weights, summing to `1.0`, location 6.
+    pub weights: [f32; 4],
+}
+
+impl Vertex for SkinnedVertex {
+    const NAME: &'static str = "skinned";
+    const LAYOUT: VertexBufferLayoutDescriptor<'static> = VertexBufferLayoutDescriptor {
+        array_stride: 64,
+        step_mode: VertexStepMode::Vertex,
+        attributes: Cow::Borrowed(&[
+            attribute(0, VertexFormat::Float32x3, 0),
+            attribute(1, VertexFormat::Float32x3, 12),
+            attribute(2, VertexFormat::Float32x2, 24),
+            attribute(5, VertexFormat::Uint32x4, 32),
+            attribute(6, VertexFormat::Float32x4, 48),
+        ]),
+    };
+}
+
+/// Per-instance data of a camera-facing particle quad.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Pod, Zeroable)]
+pub struct ParticleVertex {
+    /// World-space center, location 0.
+    pub position: [f32; 3],
+    /// Edge length of the quad in world units, location 1.
+    pub size: f32,
+    /// Linear RGBA tint, location 2.
+    pub color: [f32; 4],
+    /// Rotation around the view axis in radians, location 3.
+    pub rotation: f32,
+    /// Keeps the stride a multiple of 16 bytes.
+    pub _padding: [f32; 3],
+}
+
+impl Vertex for ParticleVertex {
+    const NAME: &'static str = "particle";
+    const LAYOUT: VertexBufferLayoutDescriptor<'static> = VertexBufferLayoutDescriptor {
+        array_stride: 48,
+        step_mode: VertexStepMode::Instance,
+        attributes: Cow::Borrowed(&[
+            attribute(0, VertexFormat::Float32x3, 0),
+            attribute(1, VertexFormat::Float32, 12),
+            attribute(2, VertexFormat::Float32x4, 16),
+            attribute(3, VertexFormat::Float32, 32),
+        ]),
+    };
+}
+
+/// Vertex of a UI or overlay triangle, matching the egui vertex layout.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Pod, Zeroable)]
+pub struct UiVertex {
+    /// Position in logical pixels, location 0.
+    pub position: [f32; 2],
+    /// Texture coordinates, location 1.
+    pub uv: [f32; 2],
+    /// sRGB color with premultiplied alpha, location 2.
+    pub color: [u8; 4],
+}
+
+impl Vertex for UiVertex {
+    const NAME: &'static str = "ui";
+    const LAYOUT: VertexBufferLayoutDescriptor<'static> = VertexBufferLayoutDescriptor {
+        array_stride: 20,
+        step_mode: VertexStepMode::Vertex,
+        attributes: Cow::Borrowed(&[
+            attribute(0, VertexFormat::Float32x2, 0),
+            attribute(1, VertexFormat::Float32x2, 8),
+            attribute(2, VertexFormat::Unorm8x4, 16),
+        ]),
+    };
+}
@@ -0,0 +1,441 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! The shared registry of named vertex buffer layouts.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use super::state::{VertexAttributeDescriptor, VertexBufferLayoutDescriptor};
+use super::vertex::{ParticleVertex, SkinnedVertex, StaticVertex, UiVertex, Vertex};
+use crate::renderer::api::scene::Mesh;
+
+/// Why a vertex layout was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VertexLayoutError {
+    /// No format is registered under this name.
+    UnknownFormat(String),
+    /// The name is already registered with a different layout.
+    Conflict(String),
+    /// Two attributes share a shader location.
+    DuplicateLocation(u32),
+    /// An attribute ends past the stride of the buffer.
+    OutOfStride {
+        /// Shader location of the attribute.
+        location: u32,
+        /// Byte offset just past the attribute.
+        end: u64,
+        /// Stride of the buffer.
+        stride: u64,
+    },
+    /// Two attributes cover some of the same bytes.
+    Overlap {
+        /// Location of the attribute with the lower offset.
+        first: u32,
+        /// Location of the attribute it runs into.
+        second: u32,
+    },
+    /// A provided layout lacks an attribute the format requires.
+    MissingAttribute {
+        /// Name of the expected format.
+        format: String,
+        /// Shader location of the missing attribute.
+        location: u32,
+    },
+    /// A provided attribute differs in format or offset.
+    MismatchedAttribute {
+        /// Name of the expected format.
+        format: String,
+        /// The attribute the format requires.
+        expected: VertexAttributeDescriptor,
+        /// The attribute that was provided.
+        found: VertexAttributeDescriptor,
+    },
+    /// A provided buffer is not laid out with the format's stride.
+    StrideMismatch {
+        /// Name of the expected format.
+        format: String,
+        /// Stride of the format.
+        expected: u64,
+        /// Stride that was provided.
+        found: u64,
+    },
+}
+
+impl fmt::Display for VertexLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VertexLayoutError::UnknownFormat(name) => {
+                write!(f, "Unknown vertex format '{name}'")
+            }
+            VertexLayoutError::Conflict(name) => {
+                write!(f, "Vertex format '{name}' is already registered differently")
+            }
+            VertexLayoutError::DuplicateLocation(location) => {
+                write!(f, "Shader location {location} is used twice")
+            }
+            VertexLayoutError::OutOfStride {
+                location,
+                end,
+                stride,
+            } => write!(
+                f,
+                "Attribute at location {location} ends at byte {end}, past the stride of {stride}"
+            ),
+            VertexLayoutError::Overlap { first, second } => {
+                write!(f, "Attributes at locations {first} and {second} overlap")
+            }
+            VertexLayoutError::MissingAttribute { format, location } => write!(
+                f,
+                "Layout lacks location {location} required by vertex format '{format}'"
+            ),
+            VertexLayoutError::MismatchedAttribute {
+                format,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Location {} of vertex format '{format}' expects {:?} at offset {}, got {:?} at offset {}",
+                expected.shader_location, expected.format, expected.offset, found.format, found.offset
+            ),
+            VertexLayoutError::StrideMismatch {
+                format,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Vertex format '{format}' has a stride of {expected} bytes, got {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VertexLayoutError {}
+
+/// Checks that a layout is self-consistent: unique locations, and
+/// attributes that fit the stride without overlapping.
+///
+/// A stride of zero is accepted and not bounds-checked, as for a buffer
+/// bound once and read by every vertex.
+pub fn validate_layout(layout: &VertexBufferLayoutDescriptor<'_>) -> Result<(), VertexLayoutError> {
+    let mut attributes: Vec<&VertexAttributeDescriptor> = layout.attributes.iter().collect();
+    attributes.sort_by_key(|a| a.shader_location);
+    for pair in attributes.windows(2) {
+        if pair[0].shader_location == pair[1].shader_location {
+            return Err(VertexLayoutError::DuplicateLocation(
+                pair[0].shader_location,
+            ));
+        }
+    }
+
+    attributes.sort_by_key(|a| a.offset);
+    for attribute in &attributes {
+        let end = attribute.offset + attribute.format.size() as u64;
+        if layout.array_stride != 0 && end > layout.array_stride {
+            return Err(VertexLayoutError::OutOfStride {
+                location: attribute.shader_location,
+                end,
+                stride: layout.array_stride,
+            });
+        }
+    }
+    for pair in attributes.windows(2) {
+        if pair[0].offset + pair[0].format.size() as u64 > pair[1].offset {
+            return Err(VertexLayoutError::Overlap {
+                first: pair[0].shader_location,
+                second: pair[1].shader_location,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Named vertex buffer layouts that lanes and materials agree on.
+///
+/// Cloning is cheap and every clone shares the same table, so the engine
+/// hands one registry around as a service. It starts with the built-in
+/// `static`, `skinned`, `particle` and `ui` formats; custom lanes register
+/// their own and check incoming meshes against them.
+#[derive(Debug, Clone)]
+pub struct VertexFormatRegistry {
+    formats: Arc<RwLock<BTreeMap<String, VertexBufferLayoutDescriptor<'static>>>>,
+}
+
+impl Default for VertexFormatRegistry {
+    fn default() -> Self {
+        let registry = Self {
+            formats: Arc::default(),
+        };
+        registry.insert::<StaticVertex>();
+        registry.insert::<SkinnedVertex>();
+        registry.insert::<ParticleVertex>();
+        registry.insert::<UiVertex>();
+        registry
+    }
+}
+
+impl VertexFormatRegistry {
+    /// Creates a registry holding the built-in formats.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert<V: Vertex>(&self) {
+        self.formats
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(V::NAME.to_owned(), V::LAYOUT);
+    }
+
+    /// Registers a layout under `name` after validating it.
+    ///
+    /// Registering the same layout twice is a no-op; registering a
+    /// different layout under a taken name fails.
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        layout: VertexBufferLayoutDescriptor<'static>,
+    ) -> Result<(), VertexLayoutError> {
+        let name = name.into();
+        validate_layout(&layout)?;
+        let mut formats = self.formats.write().unwrap_or_else(|e| e.into_inner());
+        match formats.get(&name) {
+            Some(existing) if *existing == layout => Ok(()),
+            Some(_) => Err(VertexLayoutError::Conflict(name)),
+            None => {
+                log::debug!("Registered vertex format '{name}'");
+                formats.insert(name, layout);
+                Ok(())
+            }
+        }
+    }
+
+    /// Registers the layout of a compile-time vertex struct under its name.
+    pub fn register_vertex<V: Vertex>(&self) -> Result<(), VertexLayoutError> {
+        self.register(V::NAME, V::LAYOUT)
+    }
+
+    /// Returns the layout registered under `name`.
+    pub fn get(&self, name: &str) -> Option<VertexBufferLayoutDescriptor<'static>> {
+        self.formats
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned()
+    }
+
+    /// Names of every registered format, sorted.
+    pub fn names(&self) -> Vec<String> {
+        self.formats
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Checks that `attributes` provide every attribute of the format
+    /// `name` at the same location, format and offset.
+    ///
+    /// Extra attributes are allowed, so a position-only pass accepts a full
+    /// static layout.
+    pub fn check(
+        &self,
+        name: &str,
+        attributes: &[VertexAttributeDescriptor],
+    ) -> Result<(), VertexLayoutError> {
+        let layout = self
+            .get(name)
+            .ok_or_else(|| VertexLayoutError::UnknownFormat(name.to_owned()))?;
+        for expected in layout.attributes.iter() {
+            let found = attributes
+                .iter()
+                .find(|a| a.shader_location == expected.shader_location)
+                .ok_or_else(|| VertexLayoutError::MissingAttribute {
+                    format: name.to_owned(),
+                    location: expected.shader_location,
+                })?;
+            if found != expected {
+                return Err(VertexLayoutError::MismatchedAttribute {
+                    format: name.to_owned(),
+                    expected: expected.clone(),
+                    found: found.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the vertex buffer of `mesh` can be drawn with the
+    /// format `name`: matching attributes and the same stride.
+    pub fn check_mesh(&self, name: &str, mesh: &Mesh) -> Result<(), VertexLayoutError> {
+        self.check(name, &mesh.vertex_layout)?;
+        let expected = self
+            .get(name)
+            .map(|layout| layout.array_stride)
+            .unwrap_or_default();
+        let found = mesh.vertex_size() as u64;
+        if found != expected {
+            return Err(VertexLayoutError::StrideMismatch {
+                format: name.to_owned(),
+                expected,
+                found,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::api::pipeline::{VertexFormat, VertexStepMode};
+    use std::borrow::Cow;
+
+    fn attr(shader_location: u32, format: VertexFormat, offset: u64) -> VertexAttributeDescriptor {
+        VertexAttributeDescriptor {
+            shader_location,
+            format,
+            offset,
+        }
+    }
+
+    fn layout(
+        stride: u64,
+        attributes: Vec<VertexAttributeDescriptor>,
+    ) -> VertexBufferLayoutDescriptor<'static> {
+        VertexBufferLayoutDescriptor {
+            array_stride: stride,
+            step_mode: VertexStepMode::Vertex,
+            attributes: Cow::Owned(attributes),
+        }
+    }
+
+    fn assert_matches_struct<V: Vertex>() {
+        assert_eq!(
+            V::LAYOUT.array_stride,
+            std::mem::size_of::<V>() as u64,
+            "{}",
+            V::NAME
+        );
+        validate_layout(&V::LAYOUT).unwrap();
+    }
+
+    #[test]
+    fn test_builtin_layouts_match_their_structs() {
+        assert_matches_struct::<StaticVertex>();
+        assert_matches_struct::<SkinnedVertex>();
+        assert_matches_struct::<ParticleVertex>();
+        assert_matches_struct::<UiVertex>();
+        validate_layout(&StaticVertex::POSITION_ONLY).unwrap();
+
+        let registry = VertexFormatRegistry::new();
+        assert_eq!(registry.names(), ["particle", "skinned", "static", "ui"]);
+        assert_eq!(registry.get("static"), Some(StaticVertex::LAYOUT));
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_layouts() {
+        let duplicate = layout(
+            16,
+            vec![
+                attr(0, VertexFormat::Float32x2, 0),
+                attr(0, VertexFormat::Float32x2, 8),
+            ],
+        );
+        assert_eq!(
+            validate_layout(&duplicate),
+            Err(VertexLayoutError::DuplicateLocation(0))
+        );
+
+        let too_long = layout(12, vec![attr(0, VertexFormat::Float32x4, 0)]);
+        assert_eq!(
+            validate_layout(&too_long),
+            Err(VertexLayoutError::OutOfStride {
+                location: 0,
+                end: 16,
+                stride: 12
+            })
+        );
+
+        let overlapping = layout(
+            24,
+            vec![
+                attr(1, VertexFormat::Float32x3, 8),
+                attr(0, VertexFormat::Float32x3, 0),
+            ],
+        );
+        assert_eq!(
+            validate_layout(&overlapping),
+            Err(VertexLayoutError::Overlap {
+                first: 0,
+                second: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_register_custom_format() {
+        let registry = VertexFormatRegistry::new();
+        let terrain = layout(
+            16,
+            vec![
+                attr(0, VertexFormat::Float32x3, 0),
+                attr(1, VertexFormat::Unorm8x4, 12),
+            ],
+        );
+        registry.register("terrain", terrain.clone()).unwrap();
+        // Re-registering the same layout is fine, a different one is not.
+        registry.register("terrain", terrain).unwrap();
+        assert_eq!(
+            registry.register("terrain", StaticVertex::LAYOUT),
+            Err(VertexLayoutError::Conflict("terrain".into()))
+        );
+        assert_eq!(registry.register_vertex::<StaticVertex>(), Ok(()));
+
+        // Clones share the table.
+        assert!(registry.clone().get("terrain").is_some());
+    }
+
+    #[test]
+    fn test_check_against_format() {
+        let registry = VertexFormatRegistry::new();
+        let full = StaticVertex::LAYOUT.attributes.into_owned();
+        registry.check("static", &full).unwrap();
+        registry
+            .register("position", StaticVertex::POSITION_ONLY)
+            .unwrap();
+        registry.check("position", &full).unwrap();
+
+        assert_eq!(
+            registry.check("static", &full[..2]),
+            Err(VertexLayoutError::MissingAttribute {
+                format: "static".into(),
+                location: 2
+            })
+        );
+
+        let mut shifted = full.clone();
+        shifted[2].offset = 28;
+        assert!(matches!(
+            registry.check("static", &shifted),
+            Err(VertexLayoutError::MismatchedAttribute { .. })
+        ));
+        assert_eq!(
+            registry.check("missing", &full),
+            Err(VertexLayoutError::UnknownFormat("missing".into()))
+        );
+    }
+}
@@ -20,14 +20,20 @@ use crate::math::LinearRgba;
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct DirectionalLightUniform {
-    /// Direction vector (xyz), with padding (w).
-    pub direction: [f32; 4], // w is padding
+    /// Direction vector (xyz) and shadow cascade count (w).
+    pub direction: [f32; 4], // w is cascade count
     /// Color (rgb) and Intensity (a).
     pub color: LinearRgba,
-    /// View-projection matrix for the shadow map.
-    pub shadow_view_proj: [[f32; 4]; 4],
-    /// Shadow parameters: x = shadow_map_index, y = shadow_bias, z = shadow_normal_bias, w = padding.
-    pub shadow_params: [f32; 4],
+    /// View-projection matrix of each shadow cascade, nearest first.
+    pub cascade_view_proj: [[[f32; 4]; 4]; MAX_SHADOW_CASCADES],
+    /// World-space size of a shadow texel in each cascade; scales the
+    /// normal bias so far cascades do not acne.
+    pub cascade_texel_sizes: [f32; MAX_SHADOW_CASCADES],
+    /// Shadow parameters: x = atlas index of the first cascade, y = shadow_bias,
+    /// z = shadow_normal_bias.
+    pub shadow_params: [f32; 3],
+    /// Render layer mask; the light only affects meshes sharing a layer.
+    pub layers: u32,
 }
 
 /// Data for a single point light, formatted for GPU consumption.
@@ -38,8 +44,10 @@ pub struct PointLightUniform {
     pub position: [f32; 4], // w is range
     /// Color (rgb) and Intensity (a).
     pub color: LinearRgba,
-    /// Shadow parameters: x = shadow_map_index (-1 if no shadow), y = shadow_bias, z = shadow_normal_bias, w = padding.
-    pub shadow_params: [f32; 4],
+    /// Shadow parameters: x = shadow_map_index (-1 if no shadow), y = shadow_bias, z = shadow_normal_bias.
+    pub shadow_params: [f32; 3],
+    /// Render layer mask; the light only affects meshes sharing a layer.
+    pub layers: u32,
 }
 
 /// Data for a single spot light, formatted for GPU consumption.
@@ -56,8 +64,10 @@ pub struct SpotLightUniform {
     pub params: [f32; 4], // x = outer_cone_cos, yzw = padding
     /// View-projection matrix for the shadow map.
     pub shadow_view_proj: [[f32; 4]; 4],
-    /// Shadow parameters: x = shadow_map_index, y = shadow_bias, z = shadow_normal_bias, w = padding.
-    pub shadow_params: [f32; 4],
+    /// Shadow parameters: x = shadow_map_index, y = shadow_bias, z = shadow_normal_bias.
+    pub shadow_params: [f32; 3],
+    /// Render layer mask; the light only affects meshes sharing a layer.
+    pub layers: u32,
 }
 
 /// Constants for maximum light counts.
@@ -66,6 +76,9 @@ pub const MAX_DIRECTIONAL_LIGHTS: usize = 4;
 pub const MAX_POINT_LIGHTS: usize = 16;
 /// Maximum number of spot lights supported in the global lighting buffer.
 pub const MAX_SPOT_LIGHTS: usize = 8;
+/// Maximum number of shadow cascades of a directional light. Cascade `c`
+/// lives in atlas layer `shadow_params.x + c`.
+pub const MAX_SHADOW_CASCADES: usize = 4;
 
 /// The structure of the global lighting uniform buffer.
 #[repr(C)]
@@ -83,8 +96,9 @@ pub struct LightingUniforms {
     pub num_point_lights: u32,
     /// Number of active spot lights.
     pub num_spot_lights: u32,
-    /// Padding for 16-byte alignment.
-    pub _padding: u32,
+    /// The [`LightingDebugView`](crate::renderer::api::core::LightingDebugView)
+    /// shader index; also pads the struct to 16-byte alignment.
+    pub debug_view: u32,
 }
 
 /// Data for the light culling compute shader.
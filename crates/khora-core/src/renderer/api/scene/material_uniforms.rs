@@ -34,6 +34,8 @@ pub struct MaterialUniforms {
     pub base_color: LinearRgba,
     /// Emissive color (rgb) and Specular Power (a).
     pub emissive: LinearRgba,
-    /// Ambient color (rgb) and Padding (a).
-    pub ambient: LinearRgba,
+    /// Ambient color (rgb).
+    pub ambient: [f32; 3],
+    /// Render layer mask of the drawn mesh, matched against light masks.
+    pub layers: u32,
 }
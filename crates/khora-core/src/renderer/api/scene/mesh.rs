@@ -16,7 +16,7 @@
 
 use crate::{
     asset::Asset,
-    math::{Aabb, Vec2, Vec3, Vec4},
+    math::{Aabb, Mat4, Vec2, Vec3, Vec4},
     renderer::api::{
         pipeline::{PrimitiveTopology, VertexAttributeDescriptor},
         resource::BufferId,
@@ -24,8 +24,10 @@ use crate::{
     },
 };
 
+use super::{MeshSkin, MorphTarget};
+
 /// Represents a complete mesh with vertex data and indices.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Mesh {
     /// Vertex positions
     pub positions: Vec<Vec3>,
@@ -45,12 +47,113 @@ pub struct Mesh {
     pub bounding_box: Aabb,
     /// Vertex format layout
     pub vertex_layout: Vec<VertexAttributeDescriptor>,
+    /// Blend shapes that can deform this mesh. Empty for static meshes.
+    pub morph_targets: Vec<MorphTarget>,
+    /// Joint influences for skeletal animation. `None` for rigid meshes.
+    pub skin: Option<MeshSkin>,
 }
 
 // Implement the core Asset trait for Mesh
 impl Asset for Mesh {}
 
 impl Mesh {
+    /// Returns `true` if the mesh carries blend shapes.
+    pub fn has_morph_targets(&self) -> bool {
+        !self.morph_targets.is_empty()
+    }
+
+    /// Returns a copy of this mesh with its morph targets applied.
+    ///
+    /// `weights[i]` scales `morph_targets[i]`; missing weights count as zero
+    /// and extra weights are ignored. The result carries no morph targets.
+    pub fn morphed(&self, weights: &[f32]) -> Mesh {
+        let mut positions = self.positions.clone();
+        let mut normals = self.normals.clone();
+
+        for (target, &weight) in self.morph_targets.iter().zip(weights) {
+            if weight == 0.0 {
+                continue;
+            }
+            for (p, d) in positions.iter_mut().zip(&target.position_deltas) {
+                *p = *p + *d * weight;
+            }
+            if let (Some(normals), Some(deltas)) = (normals.as_mut(), &target.normal_deltas) {
+                for (n, d) in normals.iter_mut().zip(deltas) {
+                    *n = *n + *d * weight;
+                }
+            }
+        }
+        if let Some(normals) = normals.as_mut() {
+            for n in normals.iter_mut() {
+                *n = n.normalize();
+            }
+        }
+
+        Mesh {
+            positions,
+            normals,
+            tex_coords: self.tex_coords.clone(),
+            tangents: self.tangents.clone(),
+            colors: self.colors.clone(),
+            indices: self.indices.clone(),
+            primitive_type: self.primitive_type,
+            bounding_box: self.bounding_box,
+            vertex_layout: self.vertex_layout.clone(),
+            morph_targets: Vec::new(),
+            skin: self.skin.clone(),
+        }
+    }
+
+    /// Returns a copy of this mesh posed by `joint_matrices` (linear blend skinning).
+    ///
+    /// `joint_matrices[i]` maps bind-pose mesh space to posed mesh space for
+    /// joint `i`. This is the CPU reference of the GPU skinning shaders;
+    /// vertices without influences are left in place.
+    pub fn skinned(&self, joint_matrices: &[Mat4]) -> Mesh {
+        let mut posed = self.clone();
+        let Some(skin) = &self.skin else {
+            return posed;
+        };
+        let joint = |j: u16| {
+            joint_matrices
+                .get(j as usize)
+                .copied()
+                .unwrap_or(Mat4::IDENTITY)
+        };
+
+        for (i, (joints, weights)) in skin
+            .joint_indices
+            .iter()
+            .zip(&skin.joint_weights)
+            .enumerate()
+        {
+            if weights.iter().sum::<f32>() == 0.0 {
+                continue;
+            }
+            let mut position = Vec3::ZERO;
+            let mut normal = Vec3::ZERO;
+            for (&j, &w) in joints.iter().zip(weights) {
+                if w == 0.0 {
+                    continue;
+                }
+                let m = joint(j);
+                if let Some(p) = self.positions.get(i) {
+                    position = position + m.transform_point(*p) * w;
+                }
+                if let Some(n) = self.normals.as_ref().and_then(|n| n.get(i)) {
+                    normal = normal + m.transform_vector(*n) * w;
+                }
+            }
+            if let Some(p) = posed.positions.get_mut(i) {
+                *p = position;
+            }
+            if let Some(n) = posed.normals.as_mut().and_then(|n| n.get_mut(i)) {
+                *n = normal.normalize();
+            }
+        }
+        posed
+    }
+
     /// Calculates the stride of a single vertex in bytes based on the vertex layout.
     pub fn vertex_size(&self) -> usize {
         self.vertex_layout
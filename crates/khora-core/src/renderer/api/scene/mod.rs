@@ -17,9 +17,13 @@
 pub mod lighting;
 pub mod material_uniforms;
 pub mod mesh;
+pub mod morph_target;
 pub mod render_object;
+pub mod skin;
 
 pub use self::lighting::*;
 pub use self::material_uniforms::*;
 pub use self::mesh::*;
+pub use self::morph_target::*;
 pub use self::render_object::*;
+pub use self::skin::*;
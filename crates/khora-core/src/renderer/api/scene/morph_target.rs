@@ -0,0 +1,30 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Blend-shape (morph target) data attached to a mesh.
+
+use crate::math::Vec3;
+
+/// A single blend shape: per-vertex offsets from the base mesh.
+///
+/// The deformed vertex is `base + Σ weight_i * delta_i` over all targets.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MorphTarget {
+    /// Name of the shape (e.g. `"smile"`), as authored in the source asset.
+    pub name: String,
+    /// Position offsets, one per base vertex.
+    pub position_deltas: Vec<Vec3>,
+    /// Optional normal offsets, one per base vertex.
+    pub normal_deltas: Option<Vec<Vec3>>,
+}
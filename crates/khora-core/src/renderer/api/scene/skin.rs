@@ -0,0 +1,176 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Skinning data attached to a mesh, and how skinned meshes reach the GPU.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use super::Mesh;
+
+/// Per-vertex joint influences of a skinned mesh.
+///
+/// Each vertex is bound to up to four joints. Indices refer to the joint
+/// list of the entity's skin, not to scene entities.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshSkin {
+    /// Joints influencing each vertex, one set per base vertex.
+    pub joint_indices: Vec<[u16; 4]>,
+    /// Weight of each of those joints; a set should sum to `1.0`.
+    pub joint_weights: Vec<[f32; 4]>,
+}
+
+impl MeshSkin {
+    /// Number of joints the influences reference (highest index + 1).
+    pub fn joint_count(&self) -> usize {
+        self.joint_indices
+            .iter()
+            .zip(&self.joint_weights)
+            .flat_map(|(joints, weights)| {
+                joints
+                    .iter()
+                    .zip(weights)
+                    .filter(|(_, w)| **w != 0.0)
+                    .map(|(j, _)| *j as usize + 1)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Packs the influences into the layout read by the skinning shaders.
+    pub fn gpu_influences(&self) -> Vec<SkinInfluence> {
+        self.joint_indices
+            .iter()
+            .zip(&self.joint_weights)
+            .map(|(joints, weights)| SkinInfluence {
+                joints: joints.map(u32::from),
+                weights: *weights,
+            })
+            .collect()
+    }
+}
+
+/// GPU layout of one vertex's joint influences (32 bytes).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SkinInfluence {
+    /// Joint indices, widened to `u32` for WGSL.
+    pub joints: [u32; 4],
+    /// Joint weights.
+    pub weights: [f32; 4],
+}
+
+/// Where the skinning shaders find attributes in an interleaved vertex buffer.
+///
+/// Offsets and stride are in 4-byte words; attributes the mesh lacks use
+/// [`SkinVertexLayout::ABSENT`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SkinVertexLayout {
+    /// Number of vertices in the buffer.
+    pub vertex_count: u32,
+    /// Distance between two vertices.
+    pub stride: u32,
+    /// Offset of the normal within a vertex.
+    pub normal_offset: u32,
+    /// Offset of the first texture coordinate within a vertex.
+    pub uv_offset: u32,
+}
+
+impl SkinVertexLayout {
+    /// Offset value for an attribute the mesh does not have.
+    pub const ABSENT: u32 = u32::MAX;
+
+    /// Derives the layout of the buffer [`Mesh::create_vertex_buffer`] builds.
+    pub fn from_mesh(mesh: &Mesh) -> Self {
+        let offset = |location: u32| {
+            mesh.vertex_layout
+                .iter()
+                .find(|a| a.shader_location == location)
+                .map_or(Self::ABSENT, |a| (a.offset / 4) as u32)
+        };
+        Self {
+            vertex_count: mesh.positions.len() as u32,
+            stride: (mesh.vertex_size() / 4) as u32,
+            normal_offset: if mesh.normals.is_some() {
+                offset(1)
+            } else {
+                Self::ABSENT
+            },
+            uv_offset: if mesh.tex_coords.is_some() {
+                offset(2)
+            } else {
+                Self::ABSENT
+            },
+        }
+    }
+}
+
+/// How a skinned mesh is deformed on the GPU.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Encode, Decode,
+)]
+#[non_exhaustive]
+pub enum SkinningMode {
+    /// Each pass skins the bind pose in its vertex shader. Cheapest when
+    /// the mesh is drawn once per frame.
+    #[default]
+    VertexShader,
+    /// A compute pass writes the skinned vertices to a buffer once per
+    /// frame, which every pass then draws like a static mesh.
+    ComputePrePass,
+}
+
+impl SkinningMode {
+    /// Picks the cheaper mode for a mesh drawn `passes` times per frame.
+    ///
+    /// Skinning once up front pays off as soon as a second pass (e.g. a
+    /// shadow map) would otherwise repeat the work.
+    pub fn for_pass_count(passes: u32) -> Self {
+        if passes > 1 {
+            Self::ComputePrePass
+        } else {
+            Self::VertexShader
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_follows_pass_count() {
+        assert_eq!(SkinningMode::for_pass_count(0), SkinningMode::VertexShader);
+        assert_eq!(SkinningMode::for_pass_count(1), SkinningMode::VertexShader);
+        assert_eq!(
+            SkinningMode::for_pass_count(2),
+            SkinningMode::ComputePrePass
+        );
+        assert_eq!(
+            SkinningMode::for_pass_count(5),
+            SkinningMode::ComputePrePass
+        );
+    }
+
+    #[test]
+    fn joint_count_ignores_zero_weights() {
+        let skin = MeshSkin {
+            joint_indices: vec![[0, 3, 7, 0], [1, 2, 0, 0]],
+            joint_weights: vec![[0.5, 0.5, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0]],
+        };
+        assert_eq!(skin.joint_count(), 4);
+        assert_eq!(skin.gpu_influences()[0].joints, [0, 3, 7, 0]);
+    }
+}
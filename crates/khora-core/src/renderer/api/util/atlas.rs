@@ -161,4 +161,14 @@ impl TextureAtlas {
     pub fn size(&self) -> u32 {
         self.size
     }
+
+    /// Releases the atlas texture and its view.
+    pub fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Err(e) = device.destroy_texture_view(self.view) {
+            log::warn!("TextureAtlas: failed to destroy view: {e:?}");
+        }
+        if let Err(e) = device.destroy_texture(self.texture) {
+            log::warn!("TextureAtlas: failed to destroy texture: {e:?}");
+        }
+    }
 }
@@ -130,6 +130,9 @@ pub enum TextureFormat {
     Rg32Float,
     /// Four 32-bit float components.
     Rgba32Float,
+    // 32-bit integer formats
+    /// Two 32-bit unsigned integer components.
+    Rg32Uint,
     // Depth/stencil formats
     /// A 16-bit unsigned normalized depth format.
     Depth16Unorm,
@@ -159,6 +162,7 @@ impl TextureFormat {
             TextureFormat::R32Float => 4,
             TextureFormat::Rg32Float => 8,
             TextureFormat::Rgba32Float => 16,
+            TextureFormat::Rg32Uint => 8,
             TextureFormat::Depth16Unorm => 2,
             TextureFormat::Depth24Plus => 4,
             TextureFormat::Depth24PlusStencil8 => 4,
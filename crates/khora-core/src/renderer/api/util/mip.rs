@@ -0,0 +1,132 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mip chain helpers: level sizes, CPU downsampling and the distance
+//! heuristic mip streaming uses to pick a level.
+
+use crate::math::Extent3D;
+use crate::renderer::api::util::enums::TextureFormat;
+
+/// Returns the number of levels in a full mip chain for a `width` x `height`
+/// texture, down to 1x1.
+pub fn mip_level_count(width: u32, height: u32) -> u32 {
+    32 - width.max(height).max(1).leading_zeros()
+}
+
+/// Returns the size of mip `level` of a texture whose base level is `size`.
+///
+/// Array layers are not mipmapped and are kept as-is.
+pub fn mip_extent(size: Extent3D, level: u32) -> Extent3D {
+    Extent3D {
+        width: (size.width >> level).max(1),
+        height: (size.height >> level).max(1),
+        depth_or_array_layers: size.depth_or_array_layers,
+    }
+}
+
+/// Returns `true` if [`downsample_2x`] can build mips for `format`: every
+/// channel is an 8-bit unsigned normalized value.
+pub fn is_downsamplable(format: TextureFormat) -> bool {
+    matches!(
+        format,
+        TextureFormat::R8Unorm
+            | TextureFormat::Rg8Unorm
+            | TextureFormat::Rgba8Unorm
+            | TextureFormat::Rgba8UnormSrgb
+            | TextureFormat::Bgra8UnormSrgb
+    )
+}
+
+/// Halves a tightly packed 8-bit image with a 2x2 box filter.
+///
+/// `channels` is the number of bytes per pixel. Odd edges reuse their last
+/// row or column. sRGB data is averaged as stored, which slightly darkens
+/// high-contrast detail but is close enough for streamed mips.
+pub fn downsample_2x(pixels: &[u8], width: u32, height: u32, channels: u32) -> Vec<u8> {
+    let (width, height, channels) = (width as usize, height as usize, channels as usize);
+    let out_width = (width / 2).max(1);
+    let out_height = (height / 2).max(1);
+    let mut out = vec![0u8; out_width * out_height * channels];
+    for y in 0..out_height {
+        let y0 = (y * 2).min(height - 1);
+        let y1 = (y * 2 + 1).min(height - 1);
+        for x in 0..out_width {
+            let x0 = (x * 2).min(width - 1);
+            let x1 = (x * 2 + 1).min(width - 1);
+            for c in 0..channels {
+                let texel = |px: usize, py: usize| pixels[(py * width + px) * channels + c] as u32;
+                let sum = texel(x0, y0) + texel(x1, y0) + texel(x0, y1) + texel(x1, y1);
+                out[(y * out_width + x) * channels + c] = ((sum + 2) / 4) as u8;
+            }
+        }
+    }
+    out
+}
+
+/// Picks the finest mip worth sampling for a texture of `texture_size`
+/// texels spread over `screen_pixels` pixels on screen.
+///
+/// Each level halves the resolution, so the level is `log2` of the texel
+/// to pixel ratio, rounded down to stay sharp and clamped to the chain.
+pub fn mip_for_screen_size(texture_size: u32, screen_pixels: f32, mip_count: u32) -> u32 {
+    let coarsest = mip_count.saturating_sub(1);
+    if screen_pixels <= 0.0 || !screen_pixels.is_finite() {
+        return coarsest;
+    }
+    let ratio = texture_size as f32 / screen_pixels;
+    if ratio <= 1.0 {
+        return 0;
+    }
+    (ratio.log2().floor() as u32).min(coarsest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_length_and_level_sizes() {
+        assert_eq!(mip_level_count(1, 1), 1);
+        assert_eq!(mip_level_count(256, 64), 9);
+        assert_eq!(mip_level_count(300, 10), 9);
+
+        let base = Extent3D {
+            width: 256,
+            height: 64,
+            depth_or_array_layers: 1,
+        };
+        let level = mip_extent(base, 7);
+        assert_eq!((level.width, level.height), (2, 1));
+    }
+
+    #[test]
+    fn downsampling_averages_each_channel() {
+        // 2x2 RG image: the result is the per-channel mean.
+        let pixels = [0, 100, 100, 100, 200, 100, 100, 100];
+        assert_eq!(downsample_2x(&pixels, 2, 2, 2), vec![100, 100]);
+
+        // Odd widths fold the last column into the previous pixel.
+        let row = [10, 20, 30];
+        assert_eq!(downsample_2x(&row, 3, 1, 1), vec![15]);
+    }
+
+    #[test]
+    fn screen_size_picks_the_matching_level() {
+        assert_eq!(mip_for_screen_size(1024, 2048.0, 11), 0);
+        assert_eq!(mip_for_screen_size(1024, 256.0, 11), 2);
+        assert_eq!(mip_for_screen_size(1024, 300.0, 11), 1);
+        assert_eq!(mip_for_screen_size(1024, 0.5, 11), 10);
+        assert_eq!(mip_for_screen_size(1024, 0.0, 11), 10);
+    }
+}
@@ -17,11 +17,15 @@
 pub mod dynamic_uniform_buffer;
 pub mod enums;
 pub mod flags;
+pub mod mip;
+pub mod readback;
 pub mod uniform_ring_buffer;
 
 pub use self::dynamic_uniform_buffer::*;
 pub use self::enums::*;
 pub use self::flags::*;
+pub use self::mip::*;
+pub use self::readback::*;
 pub use self::uniform_ring_buffer::*;
 
 /// A rect within a texture atlas (UV coordinates).
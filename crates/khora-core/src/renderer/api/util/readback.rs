@@ -0,0 +1,458 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Frame-lagged GPU-to-CPU readback.
+//!
+//! Reading GPU data back on the CPU stalls the pipeline if done on the spot.
+//! [`GpuReadback`] instead records a copy into a pooled staging buffer and
+//! maps it once the GPU is done, a frame or more later. The result arrives
+//! through a [`ReadbackHandle`], which can be polled, awaited, or given a
+//! callback.
+//!
+//! ```ignore
+//! let readback = services.get::<SharedReadback>().unwrap();
+//! let handle = readback.lock().unwrap().read_texture(
+//!     device.as_ref(),
+//!     encoder.as_mut(),
+//!     &texture,
+//!     TextureFormat::Rgba8Unorm,
+//!     Origin3D::default(),
+//!     Extent3D { width: 1, height: 1, depth_or_array_layers: 1 },
+//! )?;
+//! handle.on_ready(|pixel| log::info!("pixel: {:?}", pixel.map(|p| p.bytes)));
+//! ```
+
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::math::{Extent3D, Origin3D};
+use crate::renderer::api::resource::{BufferDescriptor, BufferId, BufferUsage, TextureId};
+use crate::renderer::api::util::TextureFormat;
+use crate::renderer::error::ResourceError;
+use crate::renderer::traits::CommandEncoder;
+use crate::renderer::GraphicsDevice;
+
+/// Alignment, in bytes, of the rows of a texture copied into a buffer.
+pub const COPY_BYTES_PER_ROW_ALIGNMENT: u32 = 256;
+
+/// Alignment, in bytes, of buffer copy sizes.
+const COPY_BUFFER_ALIGNMENT: u64 = 4;
+
+/// Staging buffers kept for reuse once their readback completed.
+const MAX_POOLED_BUFFERS: usize = 16;
+
+/// The [`GpuReadback`] shared through the service registry.
+pub type SharedReadback = Arc<Mutex<GpuReadback>>;
+
+/// Result of a readback.
+pub type ReadbackResult = Result<ReadbackData, ResourceError>;
+
+type ReadbackCallback = Box<dyn FnOnce(ReadbackResult) + Send>;
+type MapFuture = Pin<Box<dyn Future<Output = Result<Vec<u8>, ResourceError>> + Send>>;
+
+/// Bytes read back from the GPU.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadbackData {
+    /// The bytes. Texture rows are tightly packed, top row first.
+    pub bytes: Vec<u8>,
+    /// Frame the readback was requested in.
+    pub requested_frame: u64,
+    /// Frame the readback completed in.
+    pub completed_frame: u64,
+}
+
+impl ReadbackData {
+    /// Frames between the request and the result.
+    pub fn latency(&self) -> u64 {
+        self.completed_frame - self.requested_frame
+    }
+}
+
+#[derive(Default)]
+struct HandleState {
+    result: Option<ReadbackResult>,
+    callback: Option<ReadbackCallback>,
+    waker: Option<Waker>,
+}
+
+/// The pending result of a readback requested from [`GpuReadback`].
+///
+/// Poll it with [`try_take`](Self::try_take), `.await` it, or hand it a
+/// callback with [`on_ready`](Self::on_ready).
+pub struct ReadbackHandle {
+    state: Arc<Mutex<HandleState>>,
+}
+
+impl ReadbackHandle {
+    /// Returns `true` once the result arrived and was not taken yet.
+    pub fn is_ready(&self) -> bool {
+        self.state.lock().is_ok_and(|s| s.result.is_some())
+    }
+
+    /// Takes the result if it arrived.
+    pub fn try_take(&self) -> Option<ReadbackResult> {
+        self.state.lock().ok().and_then(|mut s| s.result.take())
+    }
+
+    /// Calls `callback` with the result when it arrives, or right away if it
+    /// already did.
+    pub fn on_ready(self, callback: impl FnOnce(ReadbackResult) + Send + 'static) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        match state.result.take() {
+            Some(result) => {
+                drop(state);
+                callback(result);
+            }
+            None => state.callback = Some(Box::new(callback)),
+        }
+    }
+}
+
+impl Future for ReadbackHandle {
+    type Output = ReadbackResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Ok(mut state) = self.state.lock() else {
+            return Poll::Ready(Err(ResourceError::BackendError(
+                "readback state poisoned".into(),
+            )));
+        };
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Delivers `result` to the handle's callback, or stores it for the handle.
+fn complete(state: &Mutex<HandleState>, result: ReadbackResult) {
+    let Ok(mut state) = state.lock() else {
+        return;
+    };
+    if let Some(callback) = state.callback.take() {
+        drop(state);
+        callback(result);
+        return;
+    }
+    state.result = Some(result);
+    if let Some(waker) = state.waker.take() {
+        waker.wake();
+    }
+}
+
+/// Row layout of a texture copy: rows are padded to
+/// [`COPY_BYTES_PER_ROW_ALIGNMENT`] in the staging buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RowLayout {
+    unpadded: u32,
+    padded: u32,
+    rows: u32,
+}
+
+impl RowLayout {
+    fn new(format: TextureFormat, size: Extent3D) -> Self {
+        let unpadded = size.width * format.bytes_per_pixel();
+        Self {
+            unpadded,
+            padded: unpadded.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT,
+            rows: size.height * size.depth_or_array_layers.max(1),
+        }
+    }
+
+    fn padded_size(&self) -> u64 {
+        self.padded as u64 * self.rows as u64
+    }
+
+    /// Drops the row padding of `bytes`.
+    fn unpad(&self, bytes: Vec<u8>) -> Vec<u8> {
+        if self.padded == self.unpadded {
+            return bytes;
+        }
+        bytes
+            .chunks(self.padded as usize)
+            .take(self.rows as usize)
+            .flat_map(|row| &row[..self.unpadded as usize])
+            .copied()
+            .collect()
+    }
+}
+
+struct Pending {
+    buffer: BufferId,
+    capacity: u64,
+    size: u64,
+    rows: Option<RowLayout>,
+    /// Mapping of the staging buffer, started at the end of the frame the
+    /// copy was recorded in.
+    mapping: Option<MapFuture>,
+    requested_frame: u64,
+    state: Arc<Mutex<HandleState>>,
+}
+
+/// Reads buffers and textures back from the GPU without stalling.
+///
+/// Request a readback while recording a frame; the copy goes into the given
+/// encoder. Call [`end_frame`](Self::end_frame) once per frame, after the
+/// command buffers holding the copies were submitted: it starts mapping the
+/// new staging buffers and delivers the readbacks the GPU finished. Results
+/// typically arrive one to three frames after the request. Staging buffers
+/// are pooled and reused.
+pub struct GpuReadback {
+    pending: Vec<Pending>,
+    free: Vec<(BufferId, u64)>,
+    frame: u64,
+}
+
+impl Default for GpuReadback {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GpuReadback {
+    /// Creates an empty readback queue.
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            free: Vec::new(),
+            frame: 0,
+        }
+    }
+
+    /// Number of frames ended so far.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Number of readbacks waiting for the GPU. Callers that read back every
+    /// frame can skip a request while this is high.
+    pub fn in_flight(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Records a copy of `size` bytes of `source` from `offset`. The buffer
+    /// must have the `COPY_SRC` usage, and `offset` and `size` must be
+    /// multiples of four.
+    pub fn read_buffer(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        source: &BufferId,
+        offset: u64,
+        size: u64,
+    ) -> Result<ReadbackHandle, ResourceError> {
+        if size == 0
+            || !offset.is_multiple_of(COPY_BUFFER_ALIGNMENT)
+            || !size.is_multiple_of(COPY_BUFFER_ALIGNMENT)
+        {
+            return Err(ResourceError::OutOfBounds);
+        }
+        let (buffer, capacity) = self.acquire(device, size)?;
+        encoder.copy_buffer_to_buffer(source, offset, &buffer, 0, size);
+        Ok(self.push(buffer, capacity, size, None))
+    }
+
+    /// Records a copy of the `size` region at `origin` of the first mip
+    /// level of `texture`, whose texels are in `format`. The texture must
+    /// have the `COPY_SRC` usage.
+    pub fn read_texture(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        texture: &TextureId,
+        format: TextureFormat,
+        origin: Origin3D,
+        size: Extent3D,
+    ) -> Result<ReadbackHandle, ResourceError> {
+        let rows = RowLayout::new(format, size);
+        if rows.unpadded == 0 || rows.rows == 0 {
+            return Err(ResourceError::OutOfBounds);
+        }
+        let staging_size = rows.padded_size();
+        let (buffer, capacity) = self.acquire(device, staging_size)?;
+        encoder.copy_texture_to_buffer(texture, origin, &buffer, 0, rows.padded, size);
+        Ok(self.push(buffer, capacity, staging_size, Some(rows)))
+    }
+
+    /// Advances the frame: starts mapping the copies recorded since the last
+    /// call and delivers the readbacks the GPU finished.
+    pub fn end_frame(&mut self, device: &dyn GraphicsDevice) {
+        self.frame += 1;
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut index = 0;
+        while index < self.pending.len() {
+            let pending = &mut self.pending[index];
+            let mapping = pending.mapping.get_or_insert_with(|| {
+                Box::into_pin(device.read_buffer_async(pending.buffer, 0, pending.size))
+            });
+            let Poll::Ready(result) = mapping.as_mut().poll(&mut cx) else {
+                index += 1;
+                continue;
+            };
+
+            let pending = self.pending.remove(index);
+            self.release(device, pending.buffer, pending.capacity);
+            let result = result.map(|bytes| ReadbackData {
+                bytes: match pending.rows {
+                    Some(rows) => rows.unpad(bytes),
+                    None => bytes,
+                },
+                requested_frame: pending.requested_frame,
+                completed_frame: self.frame,
+            });
+            complete(&pending.state, result);
+        }
+    }
+
+    /// Destroys every staging buffer. Readbacks still in flight fail.
+    pub fn destroy(&mut self, device: &dyn GraphicsDevice) {
+        for pending in self.pending.drain(..) {
+            let _ = device.destroy_buffer(pending.buffer);
+            complete(
+                &pending.state,
+                Err(ResourceError::BackendError("readback cancelled".into())),
+            );
+        }
+        for (buffer, _) in self.free.drain(..) {
+            let _ = device.destroy_buffer(buffer);
+        }
+    }
+
+    /// Takes the smallest pooled staging buffer holding `size` bytes, or
+    /// creates one.
+    fn acquire(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        size: u64,
+    ) -> Result<(BufferId, u64), ResourceError> {
+        let best = self
+            .free
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, capacity))| *capacity >= size)
+            .min_by_key(|(_, (_, capacity))| *capacity)
+            .map(|(index, _)| index);
+        if let Some(index) = best {
+            return Ok(self.free.swap_remove(index));
+        }
+        let capacity = size.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT as u64)
+            * COPY_BYTES_PER_ROW_ALIGNMENT as u64;
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: Some(Cow::Borrowed("gpu_readback_staging")),
+            size: capacity,
+            usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+            mapped_at_creation: false,
+        })?;
+        Ok((buffer, capacity))
+    }
+
+    fn release(&mut self, device: &dyn GraphicsDevice, buffer: BufferId, capacity: u64) {
+        if self.free.len() < MAX_POOLED_BUFFERS {
+            self.free.push((buffer, capacity));
+        } else {
+            let _ = device.destroy_buffer(buffer);
+        }
+    }
+
+    fn push(
+        &mut self,
+        buffer: BufferId,
+        capacity: u64,
+        size: u64,
+        rows: Option<RowLayout>,
+    ) -> ReadbackHandle {
+        let state = Arc::new(Mutex::new(HandleState::default()));
+        self.pending.push(Pending {
+            buffer,
+            capacity,
+            size,
+            rows,
+            mapping: None,
+            requested_frame: self.frame,
+            state: Arc::clone(&state),
+        });
+        ReadbackHandle { state }
+    }
+}
+
+/// Waker for polling the mapping futures from [`GpuReadback::end_frame`],
+/// which polls them again every frame anyway.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_texture_rows_are_padded_then_unpadded() {
+        let rows = RowLayout::new(
+            TextureFormat::Rgba8Unorm,
+            Extent3D {
+                width: 3,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+        );
+        assert_eq!(rows.unpadded, 12);
+        assert_eq!(rows.padded, COPY_BYTES_PER_ROW_ALIGNMENT);
+        assert_eq!(rows.padded_size(), 2 * COPY_BYTES_PER_ROW_ALIGNMENT as u64);
+
+        let mut padded = vec![0u8; rows.padded_size() as usize];
+        padded[..12].fill(1);
+        padded[256..268].fill(2);
+        let bytes = rows.unpad(padded);
+        assert_eq!(bytes.len(), 24);
+        assert!(bytes[..12].iter().all(|&b| b == 1));
+        assert!(bytes[12..].iter().all(|&b| b == 2));
+    }
+
+    #[test]
+    fn test_handle_delivers_to_a_late_callback() {
+        let handle = ReadbackHandle {
+            state: Arc::new(Mutex::new(HandleState::default())),
+        };
+        assert!(!handle.is_ready());
+        complete(
+            &handle.state,
+            Ok(ReadbackData {
+                bytes: vec![7],
+                requested_frame: 2,
+                completed_frame: 4,
+            }),
+        );
+        assert!(handle.is_ready());
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        handle.on_ready(move |result| {
+            let _ = tx.send(result);
+        });
+        let data = rx.try_recv().unwrap().unwrap();
+        assert_eq!(data.bytes, vec![7]);
+        assert_eq!(data.latency(), 2);
+    }
+}
@@ -344,6 +344,17 @@ mod tests {
         ) {
         }
 
+        fn copy_texture_to_buffer(
+            &mut self,
+            _src: &TextureId,
+            _origin: crate::math::dimension::Origin3D,
+            _dst: &BufferId,
+            _dst_off: u64,
+            _bpr: u32,
+            _size: crate::math::dimension::Extent3D,
+        ) {
+        }
+
         fn finish(self: Box<Self>) -> CommandBufferId {
             CommandBufferId(0)
         }
@@ -435,6 +446,15 @@ mod tests {
         {
             Box::new(async { Ok(()) })
         }
+        fn read_buffer_async(
+            &self,
+            _id: BufferId,
+            _offset: u64,
+            size: u64,
+        ) -> Box<dyn std::future::Future<Output = Result<Vec<u8>, ResourceError>> + Send + 'static>
+        {
+            Box::new(async move { Ok(vec![0; size as usize]) })
+        }
         fn create_texture(
             &self,
             _d: &texture_mod::TextureDescriptor,
@@ -454,6 +474,17 @@ mod tests {
         ) -> Result<(), ResourceError> {
             Ok(())
         }
+        fn write_texture_mip(
+            &self,
+            _id: TextureId,
+            _mip_level: u32,
+            _data: &[u8],
+            _bpr: Option<u32>,
+            _offset: crate::math::dimension::Origin3D,
+            _size: crate::math::dimension::Extent3D,
+        ) -> Result<(), ResourceError> {
+            Ok(())
+        }
         fn create_texture_view(
             &self,
             _id: TextureId,
@@ -485,11 +516,15 @@ mod tests {
                 name: "MockDevice".to_string(),
                 backend_type: GraphicsBackendType::Unknown,
                 device_type: RendererDeviceType::Unknown,
+                ..Default::default()
             }
         }
         fn supports_feature(&self, _feature: &str) -> bool {
             true
         }
+        fn max_texture_dimension_2d(&self) -> u32 {
+            8192
+        }
     }
 
     fn create_test_layout(device: &MockGraphicsDevice) -> BindGroupLayoutId {
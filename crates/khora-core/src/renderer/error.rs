@@ -237,6 +237,9 @@ pub enum RenderError {
     /// The graphics device was lost (e.g., GPU driver crashed or was updated).
     /// This is a catastrophic error that typically requires reinitialization.
     DeviceLost,
+    /// The render system is suspended (window minimized or occluded) and
+    /// will not acquire frames until it is resumed.
+    Suspended,
     /// An unexpected or internal error occurred.
     Internal(String),
 }
@@ -263,6 +266,9 @@ impl fmt::Display for RenderError {
                 f,
                 "The graphics device was lost and needs to be reinitialized."
             ),
+            RenderError::Suspended => {
+                write!(f, "The rendering system is suspended.")
+            }
             RenderError::Internal(msg) => {
                 write!(f, "An internal or unexpected error occurred: {msg}")
             }
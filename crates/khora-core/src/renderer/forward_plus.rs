@@ -154,7 +154,8 @@ impl ForwardPlusTileConfig {
 ///
 /// # Memory Layout
 ///
-/// Total size: 72 bytes (18 × 4-byte fields), padded from 64 after shadow fields were added.
+/// Total size: 80 bytes (20 × 4-byte fields), matching the WGSL struct's
+/// 16-byte alignment.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
 pub struct GpuLight {
@@ -165,7 +166,8 @@ pub struct GpuLight {
 
     /// Light color (RGB, linear space).
     pub color: [f32; 3],
-    /// Light intensity multiplier.
+    /// Shading intensity: lux for directional lights, candela for point and
+    /// spot lights, scaled by the camera exposure.
     pub intensity: f32,
 
     /// Light direction (normalized, for directional/spot lights).
@@ -184,8 +186,10 @@ pub struct GpuLight {
     pub shadow_bias: f32,
     /// Shadow normal bias.
     pub shadow_normal_bias: f32,
-    /// Padding/Reserved.
-    pub _unused: f32,
+    /// Render layer mask; the light only affects meshes sharing a layer.
+    pub layers: u32,
+    /// Padding to the WGSL struct size.
+    pub _padding: [u32; 2],
 }
 
 impl GpuLight {
@@ -197,6 +201,9 @@ impl GpuLight {
     pub const TYPE_SPOT: u32 = 2;
 
     /// Creates a `GpuLight` from world-space position, direction, and light properties.
+    ///
+    /// The intensity is in physical units; callers multiply it by the
+    /// camera exposure before upload.
     pub fn from_parts(
         position: [f32; 3],
         direction: [f32; 3],
@@ -207,7 +214,7 @@ impl GpuLight {
                 position: [0.0; 3],
                 range: 0.0,
                 color: [l.color.r, l.color.g, l.color.b],
-                intensity: l.intensity,
+                intensity: ty.shading_intensity(),
                 direction,
                 light_type: Self::TYPE_DIRECTIONAL,
                 inner_cone_cos: 0.0,
@@ -215,13 +222,14 @@ impl GpuLight {
                 shadow_map_index: -1,
                 shadow_bias: l.shadow_bias,
                 shadow_normal_bias: l.shadow_normal_bias,
-                _unused: 0.0,
+                layers: u32::MAX,
+                _padding: [0; 2],
             },
             super::light::LightType::Point(l) => Self {
                 position,
                 range: l.range,
                 color: [l.color.r, l.color.g, l.color.b],
-                intensity: l.intensity,
+                intensity: ty.shading_intensity(),
                 direction: [0.0; 3],
                 light_type: Self::TYPE_POINT,
                 inner_cone_cos: 0.0,
@@ -229,13 +237,14 @@ impl GpuLight {
                 shadow_map_index: -1,
                 shadow_bias: l.shadow_bias,
                 shadow_normal_bias: l.shadow_normal_bias,
-                _unused: 0.0,
+                layers: u32::MAX,
+                _padding: [0; 2],
             },
             super::light::LightType::Spot(l) => Self {
                 position,
                 range: l.range,
                 color: [l.color.r, l.color.g, l.color.b],
-                intensity: l.intensity,
+                intensity: ty.shading_intensity(),
                 direction,
                 light_type: Self::TYPE_SPOT,
                 inner_cone_cos: l.inner_cone_angle.cos(),
@@ -243,7 +252,8 @@ impl GpuLight {
                 shadow_map_index: -1,
                 shadow_bias: l.shadow_bias,
                 shadow_normal_bias: l.shadow_normal_bias,
-                _unused: 0.0,
+                layers: u32::MAX,
+                _padding: [0; 2],
             },
         }
     }
@@ -263,7 +273,8 @@ impl Default for GpuLight {
             shadow_map_index: -1,
             shadow_bias: 0.01,
             shadow_normal_bias: 0.0,
-            _unused: 0.0,
+            layers: u32::MAX,
+            _padding: [0; 2],
         }
     }
 }
@@ -350,7 +361,7 @@ mod tests {
     fn test_gpu_light_size_and_alignment() {
         // GpuLight should be exactly 72 bytes (18 x 4-byte fields)
         // Updated from 64 after shadow fields (shadow_map_index, shadow_bias, shadow_normal_bias, _padding) were added.
-        assert_eq!(std::mem::size_of::<GpuLight>(), 72);
+        assert_eq!(std::mem::size_of::<GpuLight>(), 80);
     }
 
     #[test]
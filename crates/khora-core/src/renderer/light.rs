@@ -17,10 +17,14 @@
 //! This module provides the data structures for representing different light sources
 //! in a scene. These types are used by the ECS components in `khora-data` and by
 //! the render lanes in `khora-lanes` to calculate lighting during rendering.
+//!
+//! Intensities are photometric: directional lights in lux, point and spot
+//! lights in lumens. See [`crate::math::photometry`] for reference values.
 
 use bincode::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
+use crate::math::photometry::{self, LUMENS_60W_BULB, LUX_DIRECT_SUNLIGHT};
 use crate::math::{LinearRgba, Vec3};
 
 /// A directional light source that illuminates from a uniform direction.
@@ -32,13 +36,13 @@ use crate::math::{LinearRgba, Vec3};
 ///
 /// ```
 /// use khora_core::renderer::light::DirectionalLight;
-/// use khora_core::math::{Vec3, LinearRgba};
+/// use khora_core::math::{photometry, Vec3, LinearRgba};
 ///
 /// // Create a warm sunlight
 /// let sun = DirectionalLight {
 ///     direction: Vec3::new(-0.5, -1.0, -0.3).normalize(),
 ///     color: LinearRgba::new(1.0, 0.95, 0.8, 1.0),
-///     intensity: 1.0,
+///     intensity: photometry::LUX_DIRECT_SUNLIGHT,
 ///     shadow_enabled: true,
 ///     shadow_bias: 0.005,
 ///     shadow_normal_bias: 0.02,
@@ -55,10 +59,9 @@ pub struct DirectionalLight {
     /// The color of the light in linear RGB space.
     pub color: LinearRgba,
 
-    /// The intensity multiplier for the light.
+    /// The illuminance of the light in lux, on a surface facing it.
     ///
-    /// A value of 1.0 represents standard intensity.
-    /// Higher values create brighter lights, useful for HDR rendering.
+    /// Direct sunlight is about 100 000 lx, an overcast sky about 1 000 lx.
     pub intensity: f32,
 
     /// Whether this light casts shadows.
@@ -75,7 +78,7 @@ impl Default for DirectionalLight {
             // Default: light coming from above and slightly forward
             direction: Vec3::new(0.0, -1.0, -0.5).normalize(),
             color: LinearRgba::WHITE,
-            intensity: 1.0,
+            intensity: LUX_DIRECT_SUNLIGHT,
             shadow_enabled: false,
             shadow_bias: 0.005,
             shadow_normal_bias: 0.0,
@@ -95,10 +98,10 @@ impl Default for DirectionalLight {
 /// use khora_core::renderer::light::PointLight;
 /// use khora_core::math::LinearRgba;
 ///
-/// // Create a warm indoor light
+/// // Create a warm indoor light, as bright as a 60 W bulb
 /// let lamp = PointLight {
 ///     color: LinearRgba::new(1.0, 0.9, 0.7, 1.0),
-///     intensity: 100.0,
+///     intensity: 800.0,
 ///     range: 10.0,
 ///     shadow_enabled: false,
 ///     shadow_bias: 0.01,
@@ -110,10 +113,11 @@ pub struct PointLight {
     /// The color of the light in linear RGB space.
     pub color: LinearRgba,
 
-    /// The intensity of the light in lumens.
+    /// The luminous power of the light in lumens.
     ///
-    /// Higher values create brighter lights. This is used in conjunction
-    /// with the physically-based attenuation formula.
+    /// A 60 W incandescent bulb emits about 800 lm. The shaders light
+    /// surfaces with [`PointLight::luminous_intensity`] and the
+    /// inverse-square law.
     pub intensity: f32,
 
     /// The maximum range of the light in world units.
@@ -135,7 +139,7 @@ impl Default for PointLight {
     fn default() -> Self {
         Self {
             color: LinearRgba::WHITE,
-            intensity: 100.0,
+            intensity: LUMENS_60W_BULB,
             range: 10.0,
             shadow_enabled: false,
             shadow_bias: 0.01,
@@ -144,6 +148,13 @@ impl Default for PointLight {
     }
 }
 
+impl PointLight {
+    /// Returns the luminous intensity of the light in candela.
+    pub fn luminous_intensity(&self) -> f32 {
+        photometry::point_candela(self.intensity)
+    }
+}
+
 /// A spot light source that emits light in a cone from a single point.
 ///
 /// Spot lights are like point lights but restricted to a cone of influence.
@@ -159,7 +170,7 @@ impl Default for PointLight {
 /// let flashlight = SpotLight {
 ///     direction: Vec3::new(0.0, 0.0, -1.0),
 ///     color: LinearRgba::WHITE,
-///     intensity: 200.0,
+///     intensity: 300.0,
 ///     range: 20.0,
 ///     inner_cone_angle: 15.0_f32.to_radians(),
 ///     outer_cone_angle: 30.0_f32.to_radians(),
@@ -176,7 +187,10 @@ pub struct SpotLight {
     /// The color of the light in linear RGB space.
     pub color: LinearRgba,
 
-    /// The intensity of the light in lumens.
+    /// The luminous power of the light in lumens.
+    ///
+    /// The power is spread over the outer cone, so narrowing the cone
+    /// makes the spot brighter.
     pub intensity: f32,
 
     /// The maximum range of the light in world units.
@@ -206,7 +220,7 @@ impl Default for SpotLight {
         Self {
             direction: Vec3::new(0.0, -1.0, 0.0),
             color: LinearRgba::WHITE,
-            intensity: 200.0,
+            intensity: LUMENS_60W_BULB,
             range: 15.0,
             inner_cone_angle: 20.0_f32.to_radians(),
             outer_cone_angle: 35.0_f32.to_radians(),
@@ -217,6 +231,14 @@ impl Default for SpotLight {
     }
 }
 
+impl SpotLight {
+    /// Returns the luminous intensity of the light in candela, inside the
+    /// outer cone.
+    pub fn luminous_intensity(&self) -> f32 {
+        photometry::spot_candela(self.intensity, self.outer_cone_angle)
+    }
+}
+
 /// An enumeration of all supported light types.
 ///
 /// This enum allows a single `Light` component to represent any type of light source.
@@ -237,6 +259,18 @@ impl Default for LightType {
     }
 }
 
+impl LightType {
+    /// Returns the intensity the shaders multiply the light color by: lux
+    /// for directional lights, candela for point and spot lights.
+    pub fn shading_intensity(&self) -> f32 {
+        match self {
+            LightType::Directional(light) => light.intensity,
+            LightType::Point(light) => light.luminous_intensity(),
+            LightType::Spot(light) => light.luminous_intensity(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,7 +284,7 @@ mod tests {
     fn test_directional_light_default() {
         let light = DirectionalLight::default();
         assert_eq!(light.color, LinearRgba::WHITE);
-        assert!(approx_eq(light.intensity, 1.0));
+        assert!(approx_eq(light.intensity, LUX_DIRECT_SUNLIGHT));
         // Direction should be normalized
         assert!(approx_eq(light.direction.length(), 1.0));
     }
@@ -274,7 +308,7 @@ mod tests {
     fn test_point_light_default() {
         let light = PointLight::default();
         assert_eq!(light.color, LinearRgba::WHITE);
-        assert!(approx_eq(light.intensity, 100.0));
+        assert!(approx_eq(light.intensity, LUMENS_60W_BULB));
         assert!(approx_eq(light.range, 10.0));
     }
 
@@ -330,4 +364,24 @@ mod tests {
         assert!(matches!(point, LightType::Point(_)));
         assert!(matches!(spot, LightType::Spot(_)));
     }
+
+    #[test]
+    fn test_shading_intensity_units() {
+        let sun = LightType::Directional(DirectionalLight::default());
+        assert!(approx_eq(sun.shading_intensity(), LUX_DIRECT_SUNLIGHT));
+
+        let point = PointLight {
+            intensity: 4.0 * std::f32::consts::PI,
+            ..Default::default()
+        };
+        assert!(approx_eq(LightType::Point(point).shading_intensity(), 1.0));
+
+        // A narrower spot with the same power is brighter.
+        let wide = SpotLight::default();
+        let narrow = SpotLight {
+            outer_cone_angle: wide.outer_cone_angle / 2.0,
+            ..wide
+        };
+        assert!(narrow.luminous_intensity() > wide.luminous_intensity());
+    }
 }
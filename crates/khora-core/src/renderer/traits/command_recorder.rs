@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::math::{Extent3D, Origin3D};
 use crate::renderer::api::{
     command::{
         BindGroupId, CommandBufferId, ComputePassDescriptor, ComputePipelineId,
         RenderPassDescriptor,
     },
     pipeline::RenderPipelineId,
-    resource::BufferId,
+    resource::{BufferId, TextureId},
     util::IndexFormat,
 };
 use crate::renderer::traits::GpuProfiler;
@@ -159,6 +160,22 @@ pub trait CommandEncoder {
         size: u64,
     );
 
+    /// Records a command to copy a region of a texture's first mip level into
+    /// a buffer.
+    ///
+    /// Rows are written `bytes_per_row` apart, starting at
+    /// `destination_offset`. Backends require `bytes_per_row` to be a multiple
+    /// of [`COPY_BYTES_PER_ROW_ALIGNMENT`](crate::renderer::api::util::COPY_BYTES_PER_ROW_ALIGNMENT).
+    fn copy_texture_to_buffer(
+        &mut self,
+        source: &TextureId,
+        origin: Origin3D,
+        destination: &BufferId,
+        destination_offset: u64,
+        bytes_per_row: u32,
+        size: Extent3D,
+    );
+
     /// Finalizes the command recording and returns a handle to the resulting command buffer.
     ///
     /// This method consumes the encoder. The returned [`CommandBufferId`] can then
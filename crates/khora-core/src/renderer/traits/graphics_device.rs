@@ -166,6 +166,19 @@ pub trait GraphicsDevice: Send + Sync + Debug + 'static {
         data: &'a [u8],
     ) -> Box<dyn Future<Output = Result<(), ResourceError>> + Send + 'static>;
 
+    /// Asynchronously reads `size` bytes of a `MAP_READ` buffer, starting at
+    /// `offset`, once the GPU has finished writing them.
+    ///
+    /// The future resolves with a copy of the bytes and leaves the buffer
+    /// unmapped, ready to be reused. It only makes progress while the device
+    /// is polled, which the render system does every frame.
+    fn read_buffer_async(
+        &self,
+        id: BufferId,
+        offset: u64,
+        size: u64,
+    ) -> Box<dyn Future<Output = Result<Vec<u8>, ResourceError>> + Send + 'static>;
+
     // --- Texture & Sampler Management ---
 
     /// Creates a new GPU texture.
@@ -191,6 +204,20 @@ pub trait GraphicsDevice: Send + Sync + Debug + 'static {
         size: dimension::Extent3D,
     ) -> Result<(), ResourceError>;
 
+    /// Writes data to a region of one mip level of a GPU texture.
+    ///
+    /// Same as [`write_texture`](Self::write_texture), which always targets
+    /// level 0, but `offset` and `size` are relative to `mip_level`.
+    fn write_texture_mip(
+        &self,
+        texture_id: TextureId,
+        mip_level: u32,
+        data: &[u8],
+        bytes_per_row: Option<u32>,
+        offset: dimension::Origin3D,
+        size: dimension::Extent3D,
+    ) -> Result<(), ResourceError>;
+
     /// Creates a new texture view for a given texture.
     /// A view describes how a shader will interpret a texture's data (e.g., its format, mip levels).
     fn create_texture_view(
@@ -234,4 +261,7 @@ pub trait GraphicsDevice: Send + Sync + Debug + 'static {
 
     /// Checks if a specific, optional rendering feature is supported by the backend.
     fn supports_feature(&self, feature_name: &str) -> bool;
+
+    /// Gets the largest width or height, in texels, of a 2D texture.
+    fn max_texture_dimension_2d(&self) -> u32;
 }
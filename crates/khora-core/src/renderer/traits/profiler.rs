@@ -64,6 +64,12 @@ pub trait GpuProfiler: Any + Send + Sync {
     /// This value is typically averaged over several frames to provide a stable reading.
     fn last_frame_total_ms(&self) -> f32;
 
+    /// Returns the smoothed duration of the depth prepass in milliseconds, or
+    /// `0.0` when it did not run in the last measured frame.
+    fn last_depth_prepass_ms(&self) -> f32 {
+        0.0
+    }
+
     /// Returns a reference to `self` as a `&dyn Any` trait object.
     fn as_any(&self) -> &dyn Any;
 
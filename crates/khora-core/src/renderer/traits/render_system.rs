@@ -14,9 +14,10 @@
 
 use std::sync::Arc;
 
+use crate::math::Extent2D;
 use crate::platform::window::KhoraWindow;
 use crate::renderer::api::{
-    core::{GraphicsAdapterInfo, RenderSettings, RenderStats},
+    core::{DepthMode, GraphicsAdapterInfo, RenderSettings, RenderStats},
     resource::{TextureViewId, ViewInfo},
     scene::RenderObject,
 };
@@ -35,6 +36,8 @@ pub struct FrameTargets {
     pub color: TextureViewId,
     /// Depth attachment, when depth buffering is enabled.
     pub depth: Option<TextureViewId>,
+    /// Size of the color and depth attachments, in pixels.
+    pub size: Extent2D,
 }
 
 /// A high-level trait representing the entire rendering subsystem.
@@ -116,6 +119,29 @@ pub trait RenderSystem: std::fmt::Debug + Send + Sync {
         Ok(())
     }
 
+    /// Stops presenting while the window is minimized or occluded.
+    ///
+    /// Implementations wait for in-flight GPU work and drop any acquired
+    /// frame; [`begin_frame`](Self::begin_frame) returns
+    /// [`RenderError::Suspended`] until [`resume`](Self::resume) is called.
+    /// The default implementation is a no-op.
+    fn suspend(&mut self) {}
+
+    /// Resumes presenting after [`suspend`](Self::suspend), reconfiguring the
+    /// surface to the last known size. The default implementation is a no-op.
+    fn resume(&mut self) {}
+
+    /// Returns the depth convention used by this system's depth attachments.
+    /// The default implementation returns [`DepthMode::Standard`].
+    fn depth_mode(&self) -> DepthMode {
+        DepthMode::Standard
+    }
+
+    /// Selects the depth convention. Must be called before [`init`](Self::init)
+    /// so pipelines are built with the matching depth test. The default
+    /// implementation ignores the request.
+    fn set_depth_mode(&mut self, _mode: DepthMode) {}
+
     /// Cleans up and releases all graphics resources.
     fn shutdown(&mut self);
 
@@ -22,10 +22,19 @@
 
 use std::convert::TryInto;
 
+use super::SceneMetadata;
+
 /// A unique byte sequence to identify Khora Scene Files. ("KHORASCN").
 pub const HEADER_MAGIC_BYTES: [u8; 8] = *b"KHORASCN";
 const STRATEGY_ID_LEN: usize = 32;
 
+/// The header version written by the engine. Version 2 files carry a
+/// [`SceneMetadata`] section after the payload.
+pub const SCENE_FORMAT_VERSION: u8 = 2;
+
+/// Largest metadata section read back, in bytes.
+const MAX_METADATA_LEN: usize = 1 << 20;
+
 /// An error that can occur when parsing a `SceneFile` from bytes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SceneFileError {
@@ -56,6 +65,8 @@ pub struct SceneFile {
     pub header: SceneHeader,
     /// The raw, variable-length payload data.
     pub payload: Vec<u8>,
+    /// Scene-level metadata. Empty for version 1 files.
+    pub metadata: SceneMetadata,
 }
 
 // NOTE: We are intentionally not using `serde` for the header.
@@ -91,6 +102,14 @@ impl SceneHeader {
         })
     }
 
+    /// Returns the strategy ID without its null padding, or `None` if it is
+    /// not valid UTF-8.
+    pub fn strategy_id_str(&self) -> Option<&str> {
+        std::str::from_utf8(&self.strategy_id)
+            .ok()
+            .map(|id| id.trim_end_matches('\0'))
+    }
+
     /// Serializes the header into a fixed-size byte array.
     pub fn to_bytes(&self) -> [u8; Self::SIZE] {
         let mut bytes = [0u8; Self::SIZE];
@@ -105,26 +124,77 @@ impl SceneHeader {
 
 impl SceneFile {
     /// Parses a `SceneFile` from a byte slice.
+    ///
+    /// The header's `payload_length` is checked against the bytes actually
+    /// present before anything is allocated, so a corrupted length can never
+    /// trigger an oversized allocation.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SceneFileError> {
+        if bytes.len() < SceneHeader::SIZE {
+            return Err(SceneFileError::TooShort);
+        }
         let header =
             SceneHeader::from_bytes(bytes).map_err(|_| SceneFileError::InvalidMagicBytes)?;
         let header_size = SceneHeader::SIZE;
-        let payload_end = header_size + header.payload_length as usize;
+        let payload_end = usize::try_from(header.payload_length)
+            .ok()
+            .and_then(|len| header_size.checked_add(len))
+            .ok_or(SceneFileError::TooShort)?;
 
         if bytes.len() < payload_end {
             return Err(SceneFileError::TooShort);
         }
 
         let payload = bytes[header_size..payload_end].to_vec();
-        Ok(Self { header, payload })
+        let metadata = if header.format_version >= 2 {
+            read_metadata(&bytes[payload_end..])
+        } else {
+            SceneMetadata::default()
+        };
+        Ok(Self {
+            header,
+            payload,
+            metadata,
+        })
     }
 
-    /// Serializes the entire `SceneFile` (header + payload) into a single byte vector.
+    /// Serializes the entire `SceneFile` (header + payload, then metadata
+    /// for version 2 headers) into a single byte vector.
     pub fn to_bytes(&self) -> Vec<u8> {
         let header_bytes = self.header.to_bytes();
         let mut file_bytes = Vec::with_capacity(header_bytes.len() + self.payload.len());
         file_bytes.extend_from_slice(&header_bytes);
         file_bytes.extend_from_slice(&self.payload);
+        if self.header.format_version >= 2 {
+            let metadata = bincode::encode_to_vec(&self.metadata, bincode::config::standard())
+                .unwrap_or_default();
+            file_bytes.extend_from_slice(&(metadata.len() as u64).to_le_bytes());
+            file_bytes.extend_from_slice(&metadata);
+        }
         file_bytes
     }
 }
+
+/// Reads the length-prefixed metadata section following the payload.
+///
+/// Metadata is advisory: a missing or damaged section is dropped with a
+/// warning instead of failing the whole file, so the scene still opens.
+fn read_metadata(bytes: &[u8]) -> SceneMetadata {
+    let section = bytes
+        .get(..8)
+        .and_then(|len| len.try_into().ok())
+        .map(u64::from_le_bytes)
+        .and_then(|len| usize::try_from(len).ok())
+        .filter(|&len| len <= MAX_METADATA_LEN)
+        .and_then(|len| bytes.get(8..8usize.checked_add(len)?));
+    let decoded = section.and_then(|section| {
+        let config = bincode::config::standard().with_limit::<MAX_METADATA_LEN>();
+        bincode::decode_from_slice::<SceneMetadata, _>(section, config).ok()
+    });
+    match decoded {
+        Some((metadata, _)) => metadata,
+        None => {
+            log::warn!("Scene file has no readable metadata section; using empty metadata");
+            SceneMetadata::default()
+        }
+    }
+}
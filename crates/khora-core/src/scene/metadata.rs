@@ -0,0 +1,51 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Scene-level metadata stored alongside the entity payload.
+
+use std::collections::BTreeMap;
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// Information about a scene as a whole, saved in its [`SceneFile`](super::SceneFile).
+///
+/// The engine keeps the metadata of the loaded scene as a world resource,
+/// so game logic and tools read and edit it like any other resource and
+/// the next save writes it back.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+pub struct SceneMetadata {
+    /// Who made the scene.
+    pub author: String,
+    /// Free-form description, shown by tools.
+    pub description: String,
+    /// Gameplay flags, such as `"pvp"` or `"night"`, read by game logic.
+    pub flags: BTreeMap<String, bool>,
+}
+
+impl SceneMetadata {
+    /// Returns `true` if no field is set.
+    pub fn is_empty(&self) -> bool {
+        self.author.is_empty() && self.description.is_empty() && self.flags.is_empty()
+    }
+
+    /// Returns the value of flag `name`, `false` if it is not set.
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.get(name).copied().unwrap_or(false)
+    }
+
+    /// Sets flag `name` to `value`.
+    pub fn set_flag(&mut self, name: impl Into<String>, value: bool) {
+        self.flags.insert(name.into(), value);
+    }
+}
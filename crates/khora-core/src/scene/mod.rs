@@ -19,7 +19,9 @@
 //! for defining, manipulating, and persisting these scenes.
 
 mod format;
+mod metadata;
 mod serialization;
 
 pub use format::*;
+pub use metadata::SceneMetadata;
 pub use serialization::*;
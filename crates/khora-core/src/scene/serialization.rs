@@ -49,4 +49,9 @@ pub enum SerializationGoal {
     /// Prioritizes a flexible, structured format suitable for editor
     /// operations, prefabs, and tool interchange.
     EditorInterchange,
+
+    /// Prioritizes compatibility with external tools and review workflows.
+    /// The output is JSON with the same schema as the `HumanReadableDebug`
+    /// output, so any JSON parser can read, diff or generate it.
+    ExternalTools,
 }
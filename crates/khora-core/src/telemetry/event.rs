@@ -14,6 +14,7 @@
 
 //! Event types for engine-wide telemetry.
 
+use crate::platform::HardwareSurvey;
 use crate::telemetry::metrics::{MetricId, MetricValue};
 use crate::telemetry::monitoring::{GpuReport, HardwareReport, ResourceUsageReport};
 
@@ -35,4 +36,6 @@ pub enum TelemetryEvent {
     GpuReport(GpuReport),
     /// A change in the execution phase signaled by the engine.
     PhaseChange(String),
+    /// The hardware survey taken at startup (GPU, CPU, memory, displays).
+    HardwareSurvey(Box<HardwareSurvey>),
 }
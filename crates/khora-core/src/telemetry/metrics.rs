@@ -50,6 +50,35 @@ impl MetricId {
         self
     }
 
+    /// Creates a `MetricId` from a hierarchical path: the last segment is the
+    /// name, the ones before it the namespace.
+    ///
+    /// `MetricId::from_path("render/pass/shadow/time_us")` has namespace
+    /// `render/pass/shadow` and name `time_us`.
+    pub fn from_path(path: &str) -> Self {
+        let path = path.trim_matches('/');
+        match path.rsplit_once('/') {
+            Some((namespace, name)) => Self::new(namespace, name),
+            None => Self::new("", path),
+        }
+    }
+
+    /// Returns the segments of the metric's hierarchical path. Both `/` and
+    /// `.` separate namespace segments, so `ecs.query` and `ecs/query` are
+    /// the same group.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.namespace
+            .split(['/', '.'])
+            .filter(|s| !s.is_empty())
+            .chain(std::iter::once(self.name.as_str()))
+    }
+
+    /// Returns the hierarchical path of the metric, segments joined by `/`
+    /// (e.g., `render/pass/shadow/time_us`). Labels are not part of it.
+    pub fn path(&self) -> String {
+        self.segments().collect::<Vec<_>>().join("/")
+    }
+
     /// Returns a formatted string representation of the ID (e.g., "namespace:name[k=v,...]").
     pub fn to_string_formatted(&self) -> String {
         if self.labels.is_empty() {
@@ -299,6 +328,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_metric_id_paths() {
+        let id = MetricId::from_path("render/pass/shadow/time_us");
+        assert_eq!(id.namespace, "render/pass/shadow");
+        assert_eq!(id.name, "time_us");
+        assert_eq!(id.path(), "render/pass/shadow/time_us");
+
+        let dotted = MetricId::new("ecs.query", "time_ms");
+        assert_eq!(dotted.path(), "ecs/query/time_ms");
+        assert_eq!(MetricId::from_path("uptime").path(), "uptime");
+    }
+
     #[test]
     fn test_metric_value_types() {
         let counter = MetricValue::Counter(42);
@@ -25,6 +25,7 @@
 pub mod event;
 pub mod metrics;
 pub mod monitoring;
+pub mod pattern;
 
 pub use self::event::TelemetryEvent;
 pub use self::metrics::{Metric, MetricId, MetricValue, MetricsError, MetricsResult};
@@ -32,3 +33,4 @@ pub use self::monitoring::{
     GpuReport, MemoryReport, MonitoredResourceType, ResourceMonitor, ResourceUsageReport,
     VramProvider, VramReport,
 };
+pub use self::pattern::MetricPattern;
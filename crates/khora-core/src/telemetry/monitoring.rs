@@ -116,7 +116,7 @@ pub struct GpuReport {
     pub frame_number: u64,
     /// Raw timestamp query results for each GPU hook, in microseconds.
     /// The order corresponds to the `GpuHook` enum definition.
-    pub hook_timings_us: [Option<u32>; 4],
+    pub hook_timings_us: [Option<u32>; GpuHook::ALL.len()],
     /// The CPU time spent preparing the frame, in microseconds.
     pub cpu_preparation_time_us: Option<u32>,
     /// The CPU time spent submitting commands for the frame, in microseconds.
@@ -260,6 +260,19 @@ impl GpuReport {
         }
     }
 
+    /// Calculates the duration of the depth prepass, in microseconds.
+    ///
+    /// `None` on frames where the prepass did not run.
+    pub fn depth_prepass_duration_us(&self) -> Option<u32> {
+        match (
+            self.get_hook_timing_us(GpuHook::DepthPrepassBegin),
+            self.get_hook_timing_us(GpuHook::DepthPrepassEnd),
+        ) {
+            (Some(begin), Some(end)) if end >= begin => Some(end - begin),
+            _ => None,
+        }
+    }
+
     /// Calculates the total GPU duration for the frame, in microseconds.
     pub fn frame_total_duration_us(&self) -> Option<u32> {
         match (
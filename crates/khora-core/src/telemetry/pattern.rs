@@ -0,0 +1,148 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wildcard patterns over hierarchical metric paths.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use super::metrics::MetricId;
+
+/// A pattern selecting a group of metrics by their [`MetricId::path`].
+///
+/// Segments are separated by `/`. A `*` inside a segment matches any run of
+/// characters within that segment; a segment that is exactly `**` matches
+/// any number of segments, including none.
+///
+/// | Pattern | Matches |
+/// |---|---|
+/// | `render/pass/*/time_us` | the `time_us` of every render pass |
+/// | `render/**` | everything under `render` |
+/// | `**/time_*` | every metric whose name starts with `time_` |
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetricPattern {
+    source: String,
+}
+
+impl MetricPattern {
+    /// Creates a pattern from its text. Leading and trailing `/` are ignored.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let source: String = pattern.into();
+        Self {
+            source: source.trim_matches('/').to_string(),
+        }
+    }
+
+    /// A pattern matching every metric.
+    pub fn all() -> Self {
+        Self::new("**")
+    }
+
+    /// Returns the pattern's text.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns `true` if the pattern selects `id`.
+    pub fn matches(&self, id: &MetricId) -> bool {
+        let path: Vec<&str> = id.segments().collect();
+        self.matches_segments(&path)
+    }
+
+    /// Returns `true` if the pattern selects the metric at `path`.
+    pub fn matches_path(&self, path: &str) -> bool {
+        let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        self.matches_segments(&path)
+    }
+
+    fn matches_segments(&self, path: &[&str]) -> bool {
+        let pattern: Vec<&str> = self.source.split('/').filter(|s| !s.is_empty()).collect();
+        match_segments(&pattern, path)
+    }
+}
+
+impl FromStr for MetricPattern {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<&str> for MetricPattern {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for MetricPattern {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl Display for MetricPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((first, path_rest)) => {
+                match_glob(segment.as_bytes(), first.as_bytes()) && match_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches one segment, where `*` stands for any run of characters.
+fn match_glob(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| match_glob(rest, &text[skip..])),
+        Some((c, rest)) => text.first() == Some(c) && match_glob(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcards_select_metric_groups() {
+        let shadow = MetricId::from_path("render/pass/shadow/time_us");
+        let main = MetricId::from_path("render/pass/main/time_us");
+        let draws = MetricId::from_path("render/pass/main/draw_calls");
+        let ecs = MetricId::new("ecs.query", "time_ms");
+
+        let per_pass = MetricPattern::new("render/pass/*/time_us");
+        assert!(per_pass.matches(&shadow) && per_pass.matches(&main));
+        assert!(!per_pass.matches(&draws));
+
+        let render = MetricPattern::new("render/**");
+        assert!(render.matches(&draws) && !render.matches(&ecs));
+
+        let timings = MetricPattern::new("**/time_*");
+        assert!(timings.matches(&shadow) && timings.matches(&ecs));
+        assert!(!timings.matches(&draws));
+
+        assert!(MetricPattern::all().matches(&ecs));
+        assert!(!MetricPattern::new("render/pass").matches(&shadow));
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Thread naming, roles and main-thread guards.
+//!
+//! Threads register a name and a [`ThreadRole`] so that logs, profilers and
+//! diagnostics can say *which* thread did something. The thread that drives
+//! the event loop registers itself as the main thread. APIs that must only
+//! run there (window creation, surface setup) guard themselves with
+//! [`assert_main_thread!`](crate::assert_main_thread).
+
+use std::cell::RefCell;
+use std::io;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle, ThreadId};
+
+/// What a thread is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThreadRole {
+    /// The thread running the platform event loop.
+    Main,
+    /// A thread dedicated to recording or submitting GPU work.
+    Render,
+    /// The Dynamic Context Core / GORNA control thread.
+    Control,
+    /// A general-purpose worker (jobs, agent work).
+    Worker,
+    /// Blocking file or network I/O.
+    Io,
+    /// Audio mixing or device callbacks.
+    Audio,
+    /// Diagnostics threads such as a watchdog.
+    Diagnostics,
+    /// Anything else.
+    Other,
+}
+
+/// A registered thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadInfo {
+    /// The OS-level thread id.
+    pub id: ThreadId,
+    /// The registered name.
+    pub name: Arc<str>,
+    /// The registered role.
+    pub role: ThreadRole,
+}
+
+static MAIN_THREAD: OnceLock<ThreadId> = OnceLock::new();
+static REGISTRY: Mutex<Vec<ThreadInfo>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static CURRENT: RefCell<Option<ThreadInfo>> = const { RefCell::new(None) };
+}
+
+/// Registers the calling thread as the main thread.
+///
+/// Only the first call takes effect; a later call from a different thread
+/// logs a warning and is ignored.
+pub fn register_main_thread() {
+    let id = thread::current().id();
+    let main = *MAIN_THREAD.get_or_init(|| id);
+    if main != id {
+        log::warn!(
+            "register_main_thread called from '{}', but the main thread is already registered",
+            current_thread_name()
+        );
+        return;
+    }
+    register_current_thread("main", ThreadRole::Main);
+}
+
+/// Registers a name and role for the calling thread, replacing any previous registration.
+pub fn register_current_thread(name: impl Into<String>, role: ThreadRole) {
+    let info = ThreadInfo {
+        id: thread::current().id(),
+        name: Arc::from(name.into()),
+        role,
+    };
+    {
+        let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        registry.retain(|t| t.id != info.id);
+        registry.push(info.clone());
+    }
+    let _ = CURRENT.try_with(|current| *current.borrow_mut() = Some(info));
+}
+
+/// Removes the calling thread from the registry.
+pub fn unregister_current_thread() {
+    let id = thread::current().id();
+    REGISTRY
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|t| t.id != id);
+    let _ = CURRENT.try_with(|current| current.borrow_mut().take());
+}
+
+/// Returns `true` if the calling thread is the registered main thread.
+///
+/// Before any thread registered as main this returns `true`, so guards stay
+/// silent in tests and tools that never start an event loop.
+pub fn is_main_thread() -> bool {
+    MAIN_THREAD
+        .get()
+        .is_none_or(|main| *main == thread::current().id())
+}
+
+/// The calling thread's registered role, if any.
+pub fn current_role() -> Option<ThreadRole> {
+    CURRENT
+        .try_with(|current| current.borrow().as_ref().map(|t| t.role))
+        .ok()
+        .flatten()
+}
+
+/// A name for the calling thread: the registered name, else the OS thread
+/// name, else its `ThreadId`.
+pub fn current_thread_name() -> String {
+    let registered = CURRENT
+        .try_with(|current| current.borrow().as_ref().map(|t| t.name.to_string()))
+        .ok()
+        .flatten();
+    registered.unwrap_or_else(|| {
+        let current = thread::current();
+        match current.name() {
+            Some(name) => name.to_owned(),
+            None => format!("{:?}", current.id()),
+        }
+    })
+}
+
+/// A snapshot of every registered thread.
+pub fn registered_threads() -> Vec<ThreadInfo> {
+    REGISTRY.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Spawns a thread that carries `name` at the OS level and is registered with
+/// `role` for as long as it runs.
+pub fn spawn_named<F, T>(
+    name: impl Into<String>,
+    role: ThreadRole,
+    f: F,
+) -> io::Result<JoinHandle<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let name = name.into();
+    thread::Builder::new().name(name.clone()).spawn(move || {
+        register_current_thread(name, role);
+        let _registration = Registration;
+        f()
+    })
+}
+
+/// Unregisters the owning thread when dropped, including on panic.
+struct Registration;
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        unregister_current_thread();
+    }
+}
+
+/// Asserts (in debug builds) that the caller is on the registered main thread.
+///
+/// Place it at the top of APIs that the platform only allows on the main
+/// thread. The optional argument names the API in the panic message; it
+/// defaults to the calling module's path.
+///
+/// ```rust,ignore
+/// pub fn build(self, event_loop: &ActiveEventLoop) -> Result<WinitWindow, OsError> {
+///     khora_core::assert_main_thread!("WindowBuilder::build");
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_main_thread {
+    () => {
+        $crate::assert_main_thread!(::core::module_path!())
+    };
+    ($api:expr) => {
+        debug_assert!(
+            $crate::threading::is_main_thread(),
+            "{} must be called on the main thread, but was called on '{}'",
+            $api,
+            $crate::threading::current_thread_name()
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawned_thread_is_registered_while_running() {
+        let handle = spawn_named("test-worker", ThreadRole::Worker, || {
+            let id = thread::current().id();
+            let listed = registered_threads()
+                .into_iter()
+                .any(|t| t.id == id && t.role == ThreadRole::Worker);
+            (current_thread_name(), current_role(), listed, id)
+        })
+        .expect("spawn failed");
+
+        let (name, role, listed, id) = handle.join().unwrap();
+        assert_eq!(name, "test-worker");
+        assert_eq!(role, Some(ThreadRole::Worker));
+        assert!(listed);
+        assert!(registered_threads().iter().all(|t| t.id != id));
+    }
+
+    #[test]
+    fn unregistered_thread_falls_back_to_os_name() {
+        let name = thread::Builder::new()
+            .name("plain".into())
+            .spawn(current_thread_name)
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(name, "plain");
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Named camera poses the user can jump back to.
+//!
+//! Bookmarks live in [`EditorState`](super::EditorState) so panels and
+//! shortcuts can save or restore them; the editor applies them to its
+//! [`EditorCamera`](super::EditorCamera).
+
+use std::collections::BTreeMap;
+
+use super::camera::CameraPose;
+
+/// A name-keyed store of [`CameraPose`]s, iterated in name order.
+#[derive(Debug, Clone, Default)]
+pub struct CameraBookmarks {
+    poses: BTreeMap<String, CameraPose>,
+}
+
+impl CameraBookmarks {
+    /// Saves `pose` under `name`, returning the pose it replaced.
+    pub fn save(&mut self, name: impl Into<String>, pose: CameraPose) -> Option<CameraPose> {
+        self.poses.insert(name.into(), pose)
+    }
+
+    /// Returns the pose saved under `name`.
+    pub fn get(&self, name: &str) -> Option<CameraPose> {
+        self.poses.get(name).copied()
+    }
+
+    /// Deletes the bookmark `name`, returning its pose.
+    pub fn remove(&mut self, name: &str) -> Option<CameraPose> {
+        self.poses.remove(name)
+    }
+
+    /// Iterates over `(name, pose)` pairs in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, CameraPose)> {
+        self.poses.iter().map(|(name, pose)| (name.as_str(), *pose))
+    }
+
+    /// Number of saved bookmarks.
+    pub fn len(&self) -> usize {
+        self.poses.len()
+    }
+
+    /// Returns `true` if no bookmark is saved.
+    pub fn is_empty(&self) -> bool {
+        self.poses.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Aabb, Vec3};
+    use crate::ui::editor::EditorCamera;
+
+    #[test]
+    fn test_bookmark_restores_pose() {
+        let mut camera = EditorCamera::default();
+        let mut bookmarks = CameraBookmarks::default();
+        let saved = camera.pose();
+        bookmarks.save("home", saved);
+
+        camera.orbit(120.0, -40.0);
+        camera.pan(30.0, 10.0);
+        assert_ne!(camera.pose(), saved);
+
+        camera.set_pose(bookmarks.get("home").unwrap());
+        assert_eq!(camera.pose(), saved);
+        assert!(bookmarks.get("missing").is_none());
+    }
+
+    #[test]
+    fn test_frame_bounds_fits_box() {
+        let mut camera = EditorCamera::default();
+        let bounds = Aabb::from_min_max(Vec3::new(8.0, 0.0, -2.0), Vec3::new(12.0, 4.0, 2.0));
+        assert!(camera.frame_bounds(&bounds));
+        assert_eq!(camera.target, bounds.center());
+
+        // The bounding sphere must fit inside the vertical field of view.
+        let radius = bounds.half_extents().length();
+        assert!(camera.distance * (camera.fov_y * 0.5).sin() >= radius);
+
+        let before = camera.pose();
+        assert!(!camera.frame_bounds(&Aabb::INVALID));
+        assert_eq!(camera.pose(), before);
+    }
+}
@@ -18,9 +18,28 @@
 //! handling is performed by the editor application, which translates
 //! [`InputEvent`](crate::platform) into the methods exposed here.
 
-use crate::math::{Mat4, Vec3, FRAC_PI_4};
+use crate::math::{Aabb, Mat4, Vec3, FRAC_PI_4};
 use crate::renderer::api::resource::view::ViewInfo;
 
+/// Padding applied around a framed box so it doesn't touch the viewport edges.
+const FRAME_MARGIN: f32 = 1.2;
+
+/// The navigable part of an [`EditorCamera`]: where it orbits and from where.
+///
+/// Lens settings (`fov_y`, clip planes) and input speeds are deliberately
+/// excluded so that restoring a bookmark never changes how the camera feels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPose {
+    /// Look-at target (orbit center).
+    pub target: Vec3,
+    /// Horizontal angle around Y axis (radians).
+    pub yaw: f32,
+    /// Vertical angle from the XZ plane (radians).
+    pub pitch: f32,
+    /// Distance from target.
+    pub distance: f32,
+}
+
 /// An orbit camera controller for the editor viewport.
 ///
 /// The camera orbits around a `target` point. Middle-click drag orbits,
@@ -109,6 +128,43 @@ impl EditorCamera {
         self.target = point;
     }
 
+    /// Returns the current orbit pose.
+    pub fn pose(&self) -> CameraPose {
+        CameraPose {
+            target: self.target,
+            yaw: self.yaw,
+            pitch: self.pitch,
+            distance: self.distance,
+        }
+    }
+
+    /// Moves the camera to a previously captured pose.
+    ///
+    /// Pitch and distance are clamped to the camera's limits.
+    pub fn set_pose(&mut self, pose: CameraPose) {
+        let limit = 89.0_f32.to_radians();
+        self.target = pose.target;
+        self.yaw = pose.yaw;
+        self.pitch = pose.pitch.clamp(-limit, limit);
+        self.distance = pose.distance.clamp(self.min_distance, self.max_distance);
+    }
+
+    /// Centers the camera on `bounds` and backs off until the whole box fits
+    /// in the vertical field of view. The viewing angle is kept.
+    ///
+    /// Returns `false` and leaves the camera untouched if `bounds` is invalid.
+    pub fn frame_bounds(&mut self, bounds: &Aabb) -> bool {
+        if !bounds.is_valid() {
+            return false;
+        }
+        let radius = bounds.half_extents().length().max(self.near);
+        let half_fov = (self.fov_y * 0.5).max(0.01);
+        self.target = bounds.center();
+        self.distance =
+            (radius * FRAME_MARGIN / half_fov.sin()).clamp(self.min_distance, self.max_distance);
+        true
+    }
+
     /// The camera's forward direction (from camera to target).
     pub fn forward(&self) -> Vec3 {
         (self.target - self.position()).normalize()
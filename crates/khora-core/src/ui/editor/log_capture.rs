@@ -61,10 +61,13 @@ impl log::Log for EditorLogCapture {
             return;
         }
 
+        let thread = crate::threading::current_thread_name();
+
         // Print to stderr for development debugging.
         eprintln!(
-            "[{}] {}: {}",
+            "[{}] ({}) {}: {}",
             record.level(),
+            thread,
             record.target(),
             record.args()
         );
@@ -79,6 +82,7 @@ impl log::Log for EditorLogCapture {
             },
             message: record.args().to_string(),
             target: record.target().to_string(),
+            thread,
         };
 
         if let Ok(mut entries) = self.entries.lock() {
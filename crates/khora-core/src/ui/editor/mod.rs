@@ -17,6 +17,7 @@
 //! All types here are backend-agnostic. Concrete implementations (egui, etc.)
 //! live in `khora-infra`.
 
+pub mod bookmarks;
 pub mod camera;
 pub mod command;
 pub mod fonts;
@@ -30,7 +31,8 @@ pub mod theme;
 pub mod ui_builder;
 pub mod viewport_texture;
 
-pub use camera::EditorCamera;
+pub use bookmarks::CameraBookmarks;
+pub use camera::{CameraPose, EditorCamera};
 pub use command::{CommandHistory, EditorCommand};
 pub use fonts::{FontHandle, FontPack, NamedFont};
 pub use gizmo::{generate_selection_gizmos, GizmoKind, GizmoLineInstance};
@@ -40,7 +42,7 @@ pub use panel::{EditorPanel, PanelLocation};
 pub use shell::EditorShell;
 pub use state::{
     AssetEntry, ComponentJson, EditorMode, EditorState, EntityIcon, GizmoMode, InspectedEntity,
-    LogEntry, LogLevel, PlayMode, PropertyEdit, SceneNode, StatusBarData,
+    LogEntry, LogLevel, PlayMode, PropertyEdit, SceneNode, StatusBarData, ViewportOverlays,
 };
 pub use theme::EditorTheme;
 pub use ui_builder::{FontFamilyHint, Interaction, TextAlign, UiBuilder};
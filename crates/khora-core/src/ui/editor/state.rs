@@ -19,6 +19,7 @@
 //! snapshot through a shared `Arc<Mutex<EditorState>>` retrieved from the
 //! `ServiceRegistry`.
 
+use super::bookmarks::CameraBookmarks;
 use crate::ecs::entity::EntityId;
 use std::collections::HashSet;
 
@@ -101,6 +102,24 @@ pub enum EntityIcon {
     Audio,
 }
 
+/// Navigation aids drawn over the viewport in Editing mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewportOverlays {
+    /// Draw the infinite ground grid.
+    pub grid: bool,
+    /// Draw the world X/Y/Z axes at the origin.
+    pub axes: bool,
+}
+
+impl Default for ViewportOverlays {
+    fn default() -> Self {
+        Self {
+            grid: true,
+            axes: true,
+        }
+    }
+}
+
 /// Shared editor state populated by `Application::update()` each frame.
 ///
 /// Panels read this to display the scene tree, selection highlights, etc.
@@ -184,9 +203,6 @@ pub struct EditorState {
     // ── Phase 7: Play Mode ─────────────────────────────
     /// Current play mode (Editing / Playing / Paused).
     pub play_mode: PlayMode,
-    /// Serialised snapshot of the scene taken when entering play mode.
-    /// Restored when the user presses Stop.
-    pub scene_snapshot: Option<Vec<u8>>,
 
     // ── Scene file path ──────────────────────────────
     /// Path to the currently open scene file (for Save).
@@ -226,6 +242,16 @@ pub struct EditorState {
     /// pops this each frame and applies it to the engine (when an engine
     /// `Visible` component lands; for now it just flips `hidden_entities`).
     pub pending_visibility_toggle: Option<EntityId>,
+    /// Which navigation overlays the viewport draws.
+    pub overlays: ViewportOverlays,
+    /// Saved editor camera poses.
+    pub bookmarks: CameraBookmarks,
+    /// Set to move the editor camera so the selection fills the viewport.
+    pub pending_frame_selected: bool,
+    /// Name under which to bookmark the editor camera's current pose.
+    pub pending_bookmark_save: Option<String>,
+    /// Name of the bookmark to move the editor camera to.
+    pub pending_bookmark_restore: Option<String>,
 }
 
 impl EditorState {
@@ -351,6 +377,8 @@ pub struct LogEntry {
     pub level: LogLevel,
     pub message: String,
     pub target: String,
+    /// Name of the thread that emitted the entry.
+    pub thread: String,
 }
 
 /// Log severity matching `log::Level`.
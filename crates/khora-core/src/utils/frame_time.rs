@@ -0,0 +1,44 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-frame timing shared with data systems and agents.
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Timing information for the current engine tick.
+///
+/// Updated once per tick by the engine before the substrate pass runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameTime {
+    /// Seconds elapsed since the previous tick.
+    pub delta_seconds: f32,
+    /// Seconds elapsed since the first tick.
+    pub elapsed_seconds: f64,
+    /// Number of ticks advanced so far.
+    pub frame_count: u64,
+}
+
+impl FrameTime {
+    /// Advances the clock by `delta`.
+    pub fn advance(&mut self, delta: Duration) {
+        self.delta_seconds = delta.as_secs_f32();
+        self.elapsed_seconds += delta.as_secs_f64();
+        self.frame_count += 1;
+    }
+}
+
+/// Shared handle to the engine's [`FrameTime`], registered in the
+/// `ServiceRegistry`.
+pub type SharedFrameTime = Arc<RwLock<FrameTime>>;
@@ -20,4 +20,6 @@
 
 pub mod any_map;
 pub mod bitflags;
+pub mod frame_time;
+pub mod rng;
 pub mod timer;
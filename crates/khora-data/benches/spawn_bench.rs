@@ -0,0 +1,58 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use khora_data::ecs::{Component, SemanticDomain, World};
+use std::hint::black_box;
+
+// Payload-only components, sized like real particle data.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, Default)]
+struct Position([f32; 3]);
+impl Component for Position {}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, Default)]
+struct Velocity([f32; 3]);
+impl Component for Velocity {}
+
+const PARTICLES: usize = 50_000;
+
+fn particle_world() -> World {
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    world
+}
+
+fn bench_spawn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ECS Spawn");
+
+    group.bench_function("spawn x50k", |b| {
+        b.iter_batched(
+            particle_world,
+            |mut world| {
+                for _ in 0..PARTICLES {
+                    black_box(world.spawn((Position::default(), Velocity::default())));
+                }
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("spawn_batch x50k", |b| {
+        b.iter_batched(
+            particle_world,
+            |mut world| {
+                black_box(world.spawn_batch(
+                    (0..PARTICLES).map(|_| (Position::default(), Velocity::default())),
+                ));
+                world
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_spawn);
+criterion_main!(benches);
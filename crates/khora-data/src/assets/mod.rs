@@ -15,7 +15,11 @@
 //! Defines asset types and their associated data structures.
 
 mod audio;
+mod sound_bank;
 mod storage;
+mod unload;
 
 pub use audio::*;
+pub use sound_bank::*;
 pub use storage::*;
+pub use unload::*;
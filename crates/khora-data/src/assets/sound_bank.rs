@@ -0,0 +1,129 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Defines the sound bank asset, mapping event names to randomized sounds.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use super::SoundData;
+use khora_core::asset::{Asset, AssetHandle};
+
+/// One candidate sound of a [`SoundEvent`].
+#[derive(Debug, Clone)]
+pub struct SoundVariation {
+    /// The sound to play.
+    pub clip: AssetHandle<SoundData>,
+    /// Relative probability of picking this variation.
+    pub weight: f32,
+}
+
+/// A named, randomized sound played through [`AudioEvents`](crate::audio::AudioEvents).
+///
+/// Each time the event is posted, one variation is picked by weight and
+/// played with a volume and pitch drawn from the configured ranges.
+#[derive(Debug, Clone)]
+pub struct SoundEvent {
+    /// Candidate sounds.
+    pub variations: Vec<SoundVariation>,
+    /// Volume range, where 1.0 is normal volume.
+    pub volume: RangeInclusive<f32>,
+    /// Pitch range, where 1.0 is the original pitch.
+    pub pitch: RangeInclusive<f32>,
+    /// Minimum time between two plays, in seconds. Posts inside the
+    /// cooldown are dropped.
+    pub cooldown: f32,
+    /// Maximum number of simultaneously playing instances. Posts above the
+    /// limit are dropped.
+    pub max_instances: usize,
+}
+
+impl Default for SoundEvent {
+    fn default() -> Self {
+        Self {
+            variations: Vec::new(),
+            volume: 1.0..=1.0,
+            pitch: 1.0..=1.0,
+            cooldown: 0.0,
+            max_instances: usize::MAX,
+        }
+    }
+}
+
+impl SoundEvent {
+    /// Creates an event with no variations, full volume and original pitch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a candidate sound with the given relative weight.
+    pub fn with_variation(mut self, clip: AssetHandle<SoundData>, weight: f32) -> Self {
+        self.variations.push(SoundVariation { clip, weight });
+        self
+    }
+
+    /// Sets the volume range.
+    pub fn with_volume(mut self, volume: RangeInclusive<f32>) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Sets the pitch range.
+    pub fn with_pitch(mut self, pitch: RangeInclusive<f32>) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Sets the minimum time between two plays, in seconds.
+    pub fn with_cooldown(mut self, seconds: f32) -> Self {
+        self.cooldown = seconds;
+        self
+    }
+
+    /// Sets the maximum number of simultaneously playing instances.
+    pub fn with_max_instances(mut self, max: usize) -> Self {
+        self.max_instances = max;
+        self
+    }
+}
+
+/// A collection of [`SoundEvent`]s addressed by name.
+#[derive(Debug, Clone, Default)]
+pub struct SoundBank {
+    events: HashMap<String, SoundEvent>,
+}
+
+impl Asset for SoundBank {}
+
+impl SoundBank {
+    /// Creates an empty bank.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (or replaces) the event `name`.
+    pub fn with_event(mut self, name: impl Into<String>, event: SoundEvent) -> Self {
+        self.events.insert(name.into(), event);
+        self
+    }
+
+    /// Returns the event `name`.
+    pub fn event(&self, name: &str) -> Option<&SoundEvent> {
+        self.events.get(name)
+    }
+
+    /// Iterates the event names.
+    pub fn event_names(&self) -> impl Iterator<Item = &str> {
+        self.events.keys().map(String::as_str)
+    }
+}
@@ -16,21 +16,28 @@
 
 use khora_core::asset::{Asset, AssetHandle, AssetUUID};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// A central, in-memory cache for a specific type of asset `A`.
 ///
 /// This structure maps a unique `AssetUUID` to a shared `AssetHandle<A>`.
 /// This ensures that any given asset is loaded only once. Subsequent requests
 /// for the same asset will receive a clone of the cached handle.
+///
+/// The storage also remembers since when each asset has had no handle
+/// outside of it, so [`sweep_unused`](Self::sweep_unused) can unload assets
+/// that stayed unreferenced for a grace period.
 #[derive(Default)]
 pub struct Assets<A: Asset> {
     storage: HashMap<AssetUUID, AssetHandle<A>>,
+    unused_since: HashMap<AssetUUID, Instant>,
 }
 
 impl<A: Asset> Clone for Assets<A> {
     fn clone(&self) -> Self {
         Self {
             storage: self.storage.clone(),
+            unused_since: self.unused_since.clone(),
         }
     }
 }
@@ -40,6 +47,7 @@ impl<A: Asset> Assets<A> {
     pub fn new() -> Self {
         Self {
             storage: HashMap::new(),
+            unused_since: HashMap::new(),
         }
     }
 
@@ -51,6 +59,7 @@ impl<A: Asset> Assets<A> {
     /// * `uuid` - The unique identifier for the asset.
     /// * `handle` - The handle to the asset to be stored.
     pub fn insert(&mut self, uuid: AssetUUID, handle: AssetHandle<A>) {
+        self.unused_since.remove(&uuid);
         self.storage.insert(uuid, handle);
     }
 
@@ -64,4 +73,131 @@ impl<A: Asset> Assets<A> {
     pub fn contains(&self, uuid: &AssetUUID) -> bool {
         self.storage.contains_key(uuid)
     }
+
+    /// Removes the handle associated with the given UUID and returns it.
+    ///
+    /// Other clones of the handle keep the asset alive until they are dropped.
+    pub fn remove(&mut self, uuid: &AssetUUID) -> Option<AssetHandle<A>> {
+        self.unused_since.remove(uuid);
+        self.storage.remove(uuid)
+    }
+
+    /// Returns the number of stored assets.
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns `true` if no asset is stored.
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// Returns the number of stored assets with no handle outside the storage.
+    pub fn unused_count(&self) -> usize {
+        self.storage
+            .values()
+            .filter(|handle| handle.strong_count() == 1)
+            .count()
+    }
+
+    /// Removes up to `limit` assets that have had no handle outside the
+    /// storage for at least `grace`, and returns their handles.
+    ///
+    /// Each call also records which assets became unused since the last
+    /// one, so the grace period is measured from the first sweep that saw
+    /// an asset unused. An asset that is referenced again before its grace
+    /// period ends starts over. Dropping the returned handles frees the
+    /// assets.
+    pub fn sweep_unused(
+        &mut self,
+        now: Instant,
+        grace: Duration,
+        limit: usize,
+    ) -> Vec<(AssetUUID, AssetHandle<A>)> {
+        let mut expired = Vec::new();
+        for (uuid, handle) in &self.storage {
+            if handle.strong_count() > 1 {
+                self.unused_since.remove(uuid);
+                continue;
+            }
+            let since = *self.unused_since.entry(*uuid).or_insert(now);
+            if now.saturating_duration_since(since) >= grace {
+                expired.push((since, *uuid));
+            }
+        }
+
+        // Oldest first, so a small limit still makes steady progress.
+        expired.sort_by_key(|(since, _)| *since);
+        expired
+            .into_iter()
+            .take(limit)
+            .filter_map(|(_, uuid)| self.remove(&uuid).map(|handle| (uuid, handle)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Blob;
+    impl Asset for Blob {}
+
+    fn uuid(n: u32) -> AssetUUID {
+        AssetUUID::new_v5(&format!("asset_{n}"))
+    }
+
+    #[test]
+    fn test_sweep_waits_for_the_grace_period() {
+        let mut assets = Assets::new();
+        assets.insert(uuid(1), AssetHandle::new(Blob));
+        let start = Instant::now();
+        let grace = Duration::from_secs(2);
+
+        assert!(assets.sweep_unused(start, grace, usize::MAX).is_empty());
+        assert!(assets
+            .sweep_unused(start + Duration::from_secs(1), grace, usize::MAX)
+            .is_empty());
+
+        let swept = assets.sweep_unused(start + grace, grace, usize::MAX);
+        assert_eq!(swept.len(), 1);
+        assert_eq!(swept[0].0, uuid(1));
+        assert!(!assets.contains(&uuid(1)));
+    }
+
+    #[test]
+    fn test_sweep_keeps_referenced_assets() {
+        let mut assets = Assets::new();
+        let handle = AssetHandle::new(Blob);
+        assets.insert(uuid(1), handle.clone());
+        let start = Instant::now();
+
+        assert!(assets
+            .sweep_unused(start + Duration::from_secs(60), Duration::ZERO, usize::MAX)
+            .is_empty());
+        assert_eq!(assets.unused_count(), 0);
+
+        // Dropping the last outside handle starts the grace period.
+        drop(handle);
+        let grace = Duration::from_secs(1);
+        assert!(assets.sweep_unused(start, grace, usize::MAX).is_empty());
+        assert_eq!(
+            assets.sweep_unused(start + grace, grace, usize::MAX).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_sweep_respects_the_limit_oldest_first() {
+        let mut assets = Assets::new();
+        let start = Instant::now();
+        assets.insert(uuid(1), AssetHandle::new(Blob));
+        assets.sweep_unused(start, Duration::ZERO, 0);
+        assets.insert(uuid(2), AssetHandle::new(Blob));
+
+        let swept = assets.sweep_unused(start + Duration::from_secs(1), Duration::ZERO, 1);
+        assert_eq!(swept.len(), 1);
+        assert_eq!(swept[0].0, uuid(1));
+        assert_eq!(assets.len(), 1);
+    }
 }
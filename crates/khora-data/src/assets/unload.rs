@@ -0,0 +1,51 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration of the automatic unload of unused assets.
+
+use std::time::Duration;
+
+/// How long an asset may stay unreferenced before it is unloaded.
+///
+/// Registered as a service at bootstrap. Apps that want another grace
+/// period insert their own before the engine does:
+///
+/// ```rust,ignore
+/// services.insert(AssetUnloadConfig::with_grace_period(Duration::from_secs(30)));
+/// ```
+///
+/// An asset is unreferenced once the only handle left is the one held by
+/// its storage. The grace period keeps assets that are dropped and loaded
+/// again shortly after, such as across a level restart, from being read
+/// twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetUnloadConfig {
+    /// Time an asset must stay unreferenced before it is unloaded.
+    pub grace_period: Duration,
+}
+
+impl AssetUnloadConfig {
+    /// Creates a configuration with the given grace period.
+    pub fn with_grace_period(grace_period: Duration) -> Self {
+        Self { grace_period }
+    }
+}
+
+impl Default for AssetUnloadConfig {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::from_secs(5),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! The sound event service — gameplay-facing entry point for posting
+//! named, randomized sounds.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use khora_core::asset::AssetHandle;
+use khora_core::ecs::entity::EntityId;
+use khora_core::utils::rng::Rng;
+
+use crate::assets::{SoundBank, SoundData};
+
+/// A sound event posted by gameplay code, waiting to be played.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostedEvent {
+    /// Name of the event, looked up in the loaded banks.
+    pub name: String,
+    /// The entity the sound is emitted from.
+    pub emitter: EntityId,
+}
+
+/// The concrete sound picked for a posted event.
+#[derive(Debug, Clone)]
+pub struct SelectedSound {
+    /// The variation to play.
+    pub clip: AssetHandle<SoundData>,
+    /// Volume drawn from the event's range.
+    pub volume: f32,
+    /// Pitch drawn from the event's range.
+    pub pitch: f32,
+}
+
+#[derive(Debug, Default)]
+struct AudioEventsInner {
+    banks: Vec<AssetHandle<SoundBank>>,
+    posted: Vec<PostedEvent>,
+    last_played: HashMap<String, f64>,
+    rng: Rng,
+}
+
+/// Posts named sound events defined in [`SoundBank`]s.
+///
+/// Registered in the service registry at bootstrap; clone the handle out of
+/// the registry in `setup` and keep it. Posting only queues the event: the
+/// `sound_events` data system resolves it on the next tick — picking a
+/// variation, enforcing cooldowns and instance limits — and spawns the
+/// playing sound as a child of the emitter.
+///
+/// ```rust,ignore
+/// let audio = services.get::<AudioEvents>().cloned().unwrap();
+/// audio.add_bank(footsteps);
+/// audio.post_event("footstep_grass", player);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AudioEvents {
+    inner: Arc<Mutex<AudioEventsInner>>,
+}
+
+impl AudioEvents {
+    /// Creates a service with no banks loaded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the events of `bank` available. Banks added later take
+    /// precedence for event names defined in several banks.
+    pub fn add_bank(&self, bank: AssetHandle<SoundBank>) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.banks.push(bank);
+        }
+    }
+
+    /// Removes every loaded bank.
+    pub fn clear_banks(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.banks.clear();
+        }
+    }
+
+    /// Queues the event `name`, emitted from `emitter`.
+    pub fn post_event(&self, name: impl Into<String>, emitter: EntityId) {
+        match self.inner.lock() {
+            Ok(mut inner) => inner.posted.push(PostedEvent {
+                name: name.into(),
+                emitter,
+            }),
+            Err(e) => log::error!("AudioEvents: mutex poisoned: {}", e),
+        }
+    }
+
+    /// Drains the events posted since the last call.
+    pub fn take_posted(&self) -> Vec<PostedEvent> {
+        self.inner
+            .lock()
+            .map(|mut inner| std::mem::take(&mut inner.posted))
+            .unwrap_or_default()
+    }
+
+    /// Resolves the event `name` at time `now` (seconds), given the number
+    /// of its instances still playing.
+    ///
+    /// Returns `None` if the event is unknown, has no playable variation,
+    /// is cooling down, or has reached its instance limit. On success the
+    /// event's cooldown restarts.
+    pub fn select(&self, name: &str, now: f64, live_instances: usize) -> Option<SelectedSound> {
+        let mut inner = self.inner.lock().ok()?;
+        let inner = &mut *inner;
+        let Some(event) = inner.banks.iter().rev().find_map(|b| b.event(name)) else {
+            log::warn!("AudioEvents: unknown sound event '{}'", name);
+            return None;
+        };
+        if live_instances >= event.max_instances {
+            return None;
+        }
+        if let Some(&last) = inner.last_played.get(name) {
+            if now - last < event.cooldown as f64 {
+                return None;
+            }
+        }
+
+        let weights: Vec<f32> = event.variations.iter().map(|v| v.weight).collect();
+        let variation = &event.variations[inner.rng.weighted_index(&weights)?];
+        let volume = inner
+            .rng
+            .range_f32(*event.volume.start(), *event.volume.end());
+        let pitch = inner
+            .rng
+            .range_f32(*event.pitch.start(), *event.pitch.end());
+        inner.last_played.insert(name.to_owned(), now);
+
+        Some(SelectedSound {
+            clip: variation.clip.clone(),
+            volume,
+            pitch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::SoundEvent;
+
+    fn events_with(event: SoundEvent) -> AudioEvents {
+        let events = AudioEvents::new();
+        let bank = SoundBank::new().with_event("step", event);
+        events.add_bank(AssetHandle::new(bank));
+        events
+    }
+
+    #[test]
+    fn test_select_respects_cooldown_and_instances() {
+        let clip = AssetHandle::new(SoundData::default());
+        let events = events_with(
+            SoundEvent::new()
+                .with_variation(clip, 1.0)
+                .with_pitch(0.9..=1.1)
+                .with_cooldown(0.5)
+                .with_max_instances(2),
+        );
+
+        let sound = events.select("step", 0.0, 0).unwrap();
+        assert!((0.9..=1.1).contains(&sound.pitch));
+        assert!(events.select("step", 0.25, 0).is_none());
+        assert!(events.select("step", 1.0, 2).is_none());
+        assert!(events.select("step", 1.0, 1).is_some());
+        assert!(events.select("missing", 2.0, 0).is_none());
+    }
+}
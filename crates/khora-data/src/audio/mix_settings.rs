@@ -0,0 +1,88 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The audio mix settings service — the mixing quality chosen by GORNA.
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{AudioMixMode, AudioVoiceLimit, LaneContext};
+
+#[derive(Debug, Clone, Copy)]
+struct MixSettings {
+    mode: AudioMixMode,
+    voice_limit: usize,
+}
+
+/// The mixing quality the audio callback should use.
+///
+/// Registered in the service registry at bootstrap. The `AudioAgent`
+/// writes it when GORNA assigns a strategy; whoever drives the audio
+/// callback copies it into the mixing lane's context with
+/// [`insert_into`](Self::insert_into) before each mix.
+#[derive(Debug, Clone)]
+pub struct AudioMixSettings {
+    inner: Arc<Mutex<MixSettings>>,
+}
+
+impl Default for AudioMixSettings {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(MixSettings {
+                mode: AudioMixMode::default(),
+                voice_limit: usize::MAX,
+            })),
+        }
+    }
+}
+
+impl AudioMixSettings {
+    /// Creates settings with full spatialization and no voice limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the mix mode and voice limit.
+    pub fn set(&self, mode: AudioMixMode, voice_limit: usize) {
+        match self.inner.lock() {
+            Ok(mut inner) => *inner = MixSettings { mode, voice_limit },
+            Err(e) => log::error!("AudioMixSettings: mutex poisoned: {}", e),
+        }
+    }
+
+    /// The current mix mode.
+    pub fn mode(&self) -> AudioMixMode {
+        self.inner
+            .lock()
+            .map(|inner| inner.mode)
+            .unwrap_or_default()
+    }
+
+    /// The current maximum number of audible voices.
+    pub fn voice_limit(&self) -> usize {
+        self.inner
+            .lock()
+            .map(|inner| inner.voice_limit)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Inserts the current [`AudioMixMode`] and [`AudioVoiceLimit`] into a
+    /// lane context.
+    pub fn insert_into(&self, ctx: &mut LaneContext) {
+        let Ok(inner) = self.inner.lock().map(|inner| *inner) else {
+            return;
+        };
+        ctx.insert(inner.mode);
+        ctx.insert(AudioVoiceLimit(inner.voice_limit));
+    }
+}
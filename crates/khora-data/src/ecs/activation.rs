@@ -0,0 +1,117 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enabling and showing entities together with their descendants.
+
+use std::collections::HashMap;
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{Component, Disabled, Hidden, IncludeDisabled, Parent, World};
+
+/// A marker that an entity either carries itself or inherits from an
+/// ancestor.
+trait InheritedFlag: Component + Copy {
+    fn new(inherited: bool) -> Self;
+    fn inherited(&self) -> bool;
+}
+
+impl InheritedFlag for Disabled {
+    fn new(inherited: bool) -> Self {
+        Self { inherited }
+    }
+
+    fn inherited(&self) -> bool {
+        self.inherited
+    }
+}
+
+impl InheritedFlag for Hidden {
+    fn new(inherited: bool) -> Self {
+        Self { inherited }
+    }
+
+    fn inherited(&self) -> bool {
+        self.inherited
+    }
+}
+
+impl World {
+    /// Enables or disables `entity` and its descendants.
+    ///
+    /// Disabling adds [`Disabled`] to the entity and to every descendant.
+    /// Enabling removes it again, except below descendants that were
+    /// disabled themselves, and keeps the entity disabled while an ancestor
+    /// is. Entities parented afterwards do not inherit the state.
+    pub fn set_enabled(&mut self, entity: EntityId, enabled: bool) {
+        self.set_flag::<Disabled>(entity, !enabled);
+    }
+
+    /// Returns `true` unless `entity` is disabled, itself or through an
+    /// ancestor.
+    pub fn is_enabled(&self, entity: EntityId) -> bool {
+        self.get::<Disabled>(entity).is_none()
+    }
+
+    /// Shows or hides `entity` and its descendants, following the same
+    /// rules as [`set_enabled`](Self::set_enabled) with [`Hidden`].
+    pub fn set_visible(&mut self, entity: EntityId, visible: bool) {
+        self.set_flag::<Hidden>(entity, !visible);
+    }
+
+    /// Returns `true` if `entity` is drawn: neither hidden nor disabled.
+    pub fn is_visible(&self, entity: EntityId) -> bool {
+        self.get::<Hidden>(entity).is_none() && self.is_enabled(entity)
+    }
+
+    fn set_flag<F: InheritedFlag>(&mut self, entity: EntityId, on: bool) {
+        let mut children: HashMap<EntityId, Vec<EntityId>> = HashMap::new();
+        for (child, parent, _) in self.query::<(EntityId, &Parent, IncludeDisabled)>() {
+            children.entry(parent.0).or_default().push(child);
+        }
+
+        if on {
+            let _ = self.insert_component(entity, F::new(false));
+            let mut pending = children.get(&entity).cloned().unwrap_or_default();
+            while let Some(descendant) = pending.pop() {
+                if self.get::<F>(descendant).is_none() {
+                    let _ = self.insert_component(descendant, F::new(true));
+                }
+                pending.extend(children.get(&descendant).into_iter().flatten());
+            }
+            return;
+        }
+
+        let parent_flagged = self
+            .get::<Parent>(entity)
+            .is_some_and(|parent| self.get::<F>(parent.0).is_some());
+        if parent_flagged {
+            let _ = self.insert_component(entity, F::new(true));
+            return;
+        }
+        let _ = self.remove_component_now::<F>(entity);
+        let mut pending = children.get(&entity).cloned().unwrap_or_default();
+        while let Some(descendant) = pending.pop() {
+            // Descendants flagged themselves keep their subtree flagged.
+            if self
+                .get::<F>(descendant)
+                .is_some_and(|flag| !flag.inherited())
+            {
+                continue;
+            }
+            let _ = self.remove_component_now::<F>(descendant);
+            pending.extend(children.get(&descendant).into_iter().flatten());
+        }
+    }
+}
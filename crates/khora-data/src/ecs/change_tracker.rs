@@ -0,0 +1,158 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-component change ticks backing the `Added<T>` and `Changed<T>` filters.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+/// The ticks at which one component of one entity was added and last
+/// written.
+#[derive(Debug, Default, Clone, Copy)]
+struct ComponentTicks {
+    added: u32,
+    changed: u32,
+}
+
+/// Records, for every component type and entity index, when the component
+/// was added and when it was last written.
+///
+/// Ticks are kept by entity index rather than by page row, so archetype
+/// migrations, compaction and orphaned rows never need to move them. A
+/// recycled index is stamped again when its new entity receives the
+/// component, and filters always check presence first.
+#[derive(Debug)]
+pub(crate) struct ChangeTracker {
+    /// The tick stamped on writes happening now.
+    change_tick: u32,
+    /// The tick filters compare against unless a query sets its own.
+    last_change_tick: u32,
+    ticks: HashMap<TypeId, Vec<ComponentTicks>>,
+    /// How many times each component type was added or written.
+    writes: HashMap<TypeId, u64>,
+}
+
+impl ChangeTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            change_tick: 1,
+            last_change_tick: 0,
+            ticks: HashMap::new(),
+            writes: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn change_tick(&self) -> u32 {
+        self.change_tick
+    }
+
+    pub(crate) fn last_change_tick(&self) -> u32 {
+        self.last_change_tick
+    }
+
+    /// Returns the current tick and moves on to the next one, so every
+    /// write from now on compares as newer.
+    pub(crate) fn increment_change_tick(&mut self) -> u32 {
+        let tick = self.change_tick;
+        self.change_tick = self.change_tick.wrapping_add(1).max(1);
+        tick
+    }
+
+    /// Makes the default filter baseline the current tick.
+    pub(crate) fn clear(&mut self) {
+        self.last_change_tick = self.increment_change_tick();
+    }
+
+    fn slot(&mut self, type_id: TypeId, index: u32) -> &mut ComponentTicks {
+        let column = self.ticks.entry(type_id).or_default();
+        let index = index as usize;
+        if index >= column.len() {
+            column.resize(index + 1, ComponentTicks::default());
+        }
+        &mut column[index]
+    }
+
+    /// Stamps component `type_id` of entity `index` as added and changed.
+    pub(crate) fn mark_added(&mut self, type_id: TypeId, index: u32) {
+        let tick = self.change_tick;
+        *self.slot(type_id, index) = ComponentTicks {
+            added: tick,
+            changed: tick,
+        };
+        *self.writes.entry(type_id).or_default() += 1;
+    }
+
+    /// Stamps component `type_id` of every entity in `indices` as added and
+    /// changed, looking the type up once.
+    pub(crate) fn mark_added_many(&mut self, type_id: TypeId, indices: &[u32]) {
+        let tick = self.change_tick;
+        let added = ComponentTicks {
+            added: tick,
+            changed: tick,
+        };
+        let column = self.ticks.entry(type_id).or_default();
+        if let Some(&max) = indices.iter().max() {
+            if max as usize >= column.len() {
+                column.resize(max as usize + 1, ComponentTicks::default());
+            }
+        }
+        for &index in indices {
+            column[index as usize] = added;
+        }
+        *self.writes.entry(type_id).or_default() += indices.len() as u64;
+    }
+
+    /// Stamps component `type_id` of entity `index` as changed.
+    pub(crate) fn mark_changed(&mut self, type_id: TypeId, index: u32) {
+        let tick = self.change_tick;
+        self.slot(type_id, index).changed = tick;
+        *self.writes.entry(type_id).or_default() += 1;
+    }
+
+    /// Returns how many times component `type_id` was added or written,
+    /// for caches derived from its values.
+    pub(crate) fn write_count(&self, type_id: TypeId) -> u64 {
+        self.writes.get(&type_id).copied().unwrap_or(0)
+    }
+
+    fn get(&self, type_id: TypeId, index: u32) -> ComponentTicks {
+        self.ticks
+            .get(&type_id)
+            .and_then(|column| column.get(index as usize))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the component was added after tick `since`.
+    pub(crate) fn is_added(&self, type_id: TypeId, index: u32, since: u32) -> bool {
+        is_newer(self.get(type_id, index).added, since)
+    }
+
+    /// Returns `true` if the component was added or written after tick
+    /// `since`.
+    pub(crate) fn is_changed(&self, type_id: TypeId, index: u32, since: u32) -> bool {
+        is_newer(self.get(type_id, index).changed, since)
+    }
+}
+
+impl Default for ChangeTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compares ticks across a `u32` wrap-around. Tick 0 means "never".
+fn is_newer(tick: u32, since: u32) -> bool {
+    tick != 0 && (tick.wrapping_sub(since) as i32) > 0
+}
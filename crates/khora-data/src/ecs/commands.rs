@@ -0,0 +1,171 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deferred structural changes.
+//!
+//! A query borrows the world, so spawning, despawning or adding components
+//! inside the loop is not possible. [`Commands`] records those operations
+//! instead and applies them, in order, once the borrow has ended:
+//!
+//! ```rust,ignore
+//! let mut commands = Commands::new();
+//! for (entity, health) in world.query::<(EntityId, &Health)>() {
+//!     if health.0 <= 0.0 {
+//!         commands.despawn_recursive(entity);
+//!         commands.spawn((Transform::identity(), Explosion::default()));
+//!     }
+//! }
+//! commands.apply(world);
+//! ```
+//!
+//! Parallel systems get a queue from
+//! [`SystemContext::commands`](crate::ecs::SystemContext::commands), applied
+//! by the schedule once their batch is done.
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{Component, ComponentBundle, World};
+
+type Command = Box<dyn FnOnce(&mut World) + Send + 'static>;
+
+/// A queue of structural changes to apply to a [`World`] later.
+///
+/// Operations on an entity that is dead by the time the queue is applied
+/// are skipped and logged at debug level.
+#[derive(Default)]
+pub struct Commands {
+    queue: Vec<Command>,
+}
+
+impl Commands {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the spawn of an entity with `bundle`.
+    pub fn spawn<B: ComponentBundle + Send + 'static>(&mut self, bundle: B) -> &mut Self {
+        self.add(move |world| {
+            world.spawn(bundle);
+        })
+    }
+
+    /// Queues the spawn of an entity with `bundle`, then calls `then` with
+    /// its ID — to parent it or add more components.
+    pub fn spawn_then<B, F>(&mut self, bundle: B, then: F) -> &mut Self
+    where
+        B: ComponentBundle + Send + 'static,
+        F: FnOnce(&mut World, EntityId) + Send + 'static,
+    {
+        self.add(move |world| {
+            let entity = world.spawn(bundle);
+            then(world, entity);
+        })
+    }
+
+    /// Queues the despawn of `entity`. Its children become roots.
+    pub fn despawn(&mut self, entity: EntityId) -> &mut Self {
+        self.add(move |world| {
+            if !world.despawn(entity) {
+                log::debug!("Commands: despawn of dead entity {:?} skipped", entity);
+            }
+        })
+    }
+
+    /// Queues the despawn of `entity` and all its descendants.
+    pub fn despawn_recursive(&mut self, entity: EntityId) -> &mut Self {
+        self.add(move |world| {
+            world.despawn_recursive(entity);
+        })
+    }
+
+    /// Queues adding `component` to `entity`, replacing any previous value.
+    pub fn insert<C: Component>(&mut self, entity: EntityId, component: C) -> &mut Self {
+        self.add(move |world| {
+            if let Err(e) = world.insert_component(entity, component) {
+                log::debug!(
+                    "Commands: insert of {} on {:?} skipped: {:?}",
+                    std::any::type_name::<C>(),
+                    entity,
+                    e
+                );
+            }
+        })
+    }
+
+    /// Queues removing `C` from `entity`.
+    pub fn remove<C: Component>(&mut self, entity: EntityId) -> &mut Self {
+        self.add(move |world| {
+            if let Err(e) = world.remove_component_now::<C>(entity) {
+                log::debug!(
+                    "Commands: removal of {} from {:?} skipped: {:?}",
+                    std::any::type_name::<C>(),
+                    entity,
+                    e
+                );
+            }
+        })
+    }
+
+    /// Queues a [`World::set_parent`].
+    pub fn set_parent(&mut self, child: EntityId, parent: Option<EntityId>) -> &mut Self {
+        self.add(move |world| {
+            world.set_parent(child, parent);
+        })
+    }
+
+    /// Queues sending `event` to the world's `Events<T>` queue.
+    pub fn send_event<T: Send + Sync + 'static>(&mut self, event: T) -> &mut Self {
+        self.add(move |world| world.send_event(event))
+    }
+
+    /// Queues an arbitrary operation on the world.
+    pub fn add(&mut self, command: impl FnOnce(&mut World) + Send + 'static) -> &mut Self {
+        self.queue.push(Box::new(command));
+        self
+    }
+
+    /// Moves every operation of `other` to the end of this queue.
+    pub fn append(&mut self, other: &mut Commands) {
+        self.queue.append(&mut other.queue);
+    }
+
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Applies the queued operations to `world` in the order they were
+    /// queued, and empties the queue. Returns how many were applied.
+    pub fn apply(&mut self, world: &mut World) -> usize {
+        let count = self.queue.len();
+        for command in self.queue.drain(..) {
+            command(world);
+        }
+        count
+    }
+}
+
+impl std::fmt::Debug for Commands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Commands")
+            .field("len", &self.queue.len())
+            .finish()
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Playback of an [`AnimationClip`] on an entity hierarchy.
+
+use khora_core::animation::AnimationClip;
+use khora_core::asset::AssetHandle;
+use khora_macros::Component;
+
+/// Plays an [`AnimationClip`] on this entity and its named descendants.
+///
+/// Sampled every tick by the `animation_player` data system, which writes
+/// into `Transform` and `MorphWeights` before transforms are propagated.
+#[derive(Debug, Clone, Component)]
+pub struct AnimationPlayer {
+    /// The clip being played. Not serialized; rebind after loading a scene.
+    #[component(skip)]
+    pub clip: Option<AssetHandle<AnimationClip>>,
+    /// Current playback position, in seconds.
+    pub time: f32,
+    /// Playback rate multiplier.
+    pub speed: f32,
+    /// Wrap around at the end instead of holding the last pose.
+    pub looping: bool,
+    /// Whether playback advances.
+    pub playing: bool,
+}
+
+impl Default for AnimationPlayer {
+    fn default() -> Self {
+        Self {
+            clip: None,
+            time: 0.0,
+            speed: 1.0,
+            looping: true,
+            playing: true,
+        }
+    }
+}
+
+impl AnimationPlayer {
+    /// Creates a player for `clip`, starting at time zero.
+    pub fn new(clip: AssetHandle<AnimationClip>) -> Self {
+        Self {
+            clip: Some(clip),
+            ..Default::default()
+        }
+    }
+
+    /// Switches to `clip` and rewinds.
+    pub fn play(&mut self, clip: AssetHandle<AnimationClip>) {
+        self.clip = Some(clip);
+        self.time = 0.0;
+        self.playing = true;
+    }
+
+    /// Advances playback by `delta_seconds`.
+    pub fn advance(&mut self, delta_seconds: f32) {
+        let Some(clip) = &self.clip else {
+            return;
+        };
+        if !self.playing {
+            return;
+        }
+        let duration = clip.duration();
+        self.time += delta_seconds * self.speed;
+        if self.looping && duration > 0.0 {
+            self.time = self.time.rem_euclid(duration);
+        } else {
+            self.time = self.time.clamp(0.0, duration);
+        }
+    }
+}
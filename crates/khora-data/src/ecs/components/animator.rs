@@ -0,0 +1,144 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Drives an entity hierarchy through an [`AnimationGraph`].
+
+use khora_core::animation::{AnimationGraph, AnimationParameters};
+use khora_core::asset::AssetHandle;
+use khora_core::math::Vec3;
+use khora_macros::Component;
+
+/// An in-flight crossfade out of a previous state.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Crossfade {
+    /// Index of the state being faded out.
+    pub from: usize,
+    /// Normalized time of the state being faded out.
+    pub from_time: f32,
+    /// Seconds elapsed since the transition fired.
+    pub elapsed: f32,
+    /// Total crossfade duration, in seconds.
+    pub duration: f32,
+}
+
+impl Crossfade {
+    /// Returns the weight of the destination state, in `[0.0, 1.0]`.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Runtime playback state of an [`Animator`], owned by the animation lane.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AnimatorPlayback {
+    /// Index of the current state. `None` until the first evaluation, which
+    /// enters the graph's entry state.
+    pub state: Option<usize>,
+    /// Normalized time of the current state.
+    pub normalized_time: f32,
+    /// The crossfade into the current state, if one is in progress.
+    pub crossfade: Option<Crossfade>,
+}
+
+/// Plays an [`AnimationGraph`] on this entity and its named descendants.
+///
+/// Gameplay code drives the graph through the parameter API
+/// ([`set_float`](Self::set_float), [`set_bool`](Self::set_bool),
+/// [`trigger`](Self::trigger)); the animation lane picks transitions,
+/// blends the active motions and writes the resulting pose into
+/// `Transform` and `MorphWeights`.
+///
+/// States with root motion enabled play in place; their root displacement
+/// accumulates in [`root_motion`](Self::root_motion). Kinematic characters
+/// can let the lane apply it by setting
+/// [`apply_root_motion`](Self::apply_root_motion); controllers driving a
+/// dynamic body call [`take_root_motion`](Self::take_root_motion) instead
+/// and turn it into a velocity.
+#[derive(Debug, Clone, Component)]
+pub struct Animator {
+    /// The graph being evaluated. Not serialized; rebind after loading a scene.
+    #[component(skip)]
+    pub graph: Option<AssetHandle<AnimationGraph>>,
+    /// Parameters read by transitions and blend spaces.
+    pub parameters: AnimationParameters,
+    /// Playback rate multiplier applied to every state.
+    pub speed: f32,
+    /// Move this entity's `Transform` by the extracted root motion.
+    pub apply_root_motion: bool,
+    /// Root displacement not yet consumed, in this entity's local space.
+    #[component(skip)]
+    pub root_motion: Vec3,
+    /// Runtime playback state.
+    #[component(skip)]
+    pub playback: AnimatorPlayback,
+}
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self {
+            graph: None,
+            parameters: AnimationParameters::default(),
+            speed: 1.0,
+            apply_root_motion: false,
+            root_motion: Vec3::ZERO,
+            playback: AnimatorPlayback::default(),
+        }
+    }
+}
+
+impl Animator {
+    /// Creates an animator for `graph`, starting in its entry state.
+    pub fn new(graph: AssetHandle<AnimationGraph>) -> Self {
+        Self {
+            graph: Some(graph),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the float parameter `name`.
+    pub fn set_float(&mut self, name: impl Into<String>, value: f32) {
+        self.parameters.set_float(name, value);
+    }
+
+    /// Sets the boolean parameter `name`.
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) {
+        self.parameters.set_bool(name, value);
+    }
+
+    /// Raises the trigger `name` until a transition consumes it.
+    pub fn trigger(&mut self, name: impl Into<String>) {
+        self.parameters.set_trigger(name);
+    }
+
+    /// Returns and clears the accumulated root displacement.
+    pub fn take_root_motion(&mut self) -> Vec3 {
+        std::mem::replace(&mut self.root_motion, Vec3::ZERO)
+    }
+
+    /// Returns the name of the current state, once evaluation has started.
+    pub fn current_state(&self) -> Option<&str> {
+        let graph = self.graph.as_ref()?;
+        graph
+            .state(self.playback.state?)
+            .map(|state| state.name.as_str())
+    }
+
+    /// Returns `true` while crossfading between two states.
+    pub fn is_transitioning(&self) -> bool {
+        self.playback.crossfade.is_some()
+    }
+}
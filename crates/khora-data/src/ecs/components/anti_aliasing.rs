@@ -0,0 +1,65 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Anti-aliasing settings of a camera.
+
+use khora_core::renderer::api::core::AntiAliasingMode;
+use khora_macros::Component;
+
+/// Enables anti-aliasing on the camera it is on.
+///
+/// The render agent picks the technique from its negotiated budget: FXAA
+/// when power is short, TAA by default and MSAA with GPU headroom, unless
+/// `mode` forces one. Only the first active camera's `AntiAliasing` is used.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct AntiAliasing {
+    /// Technique to use whatever the budget. `None` lets the agent choose.
+    pub mode: Option<AntiAliasingMode>,
+    /// Share of the reprojected history kept by each TAA frame, in
+    /// `[0, 1)`; higher values are smoother but ghost more.
+    pub taa_feedback: f32,
+    /// Strength of the sharpening applied by the TAA resolve, in `[0, 1]`;
+    /// zero disables it.
+    pub taa_sharpness: f32,
+}
+
+impl Default for AntiAliasing {
+    fn default() -> Self {
+        Self {
+            mode: None,
+            taa_feedback: 0.9,
+            taa_sharpness: 0.25,
+        }
+    }
+}
+
+impl AntiAliasing {
+    /// Forces `mode` instead of choosing it from the budget.
+    pub fn with_mode(mut self, mode: AntiAliasingMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the share of history kept by each TAA frame.
+    pub fn with_taa_feedback(mut self, feedback: f32) -> Self {
+        self.taa_feedback = feedback.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Sets the TAA sharpening strength.
+    pub fn with_taa_sharpness(mut self, sharpness: f32) -> Self {
+        self.taa_sharpness = sharpness.clamp(0.0, 1.0);
+        self
+    }
+}
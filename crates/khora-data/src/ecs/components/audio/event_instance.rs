@@ -0,0 +1,28 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Defines the `SoundEventInstance` marker for sounds spawned by events.
+
+use khora_macros::Component;
+
+/// Marks a transient entity playing one instance of a sound event.
+///
+/// Spawned as a child of the emitter by the `sound_events` data system,
+/// which counts live instances against the event's `max_instances` and
+/// despawns the entity once its `AudioSource` has finished.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Component)]
+#[component(no_serializable)]
+pub struct SoundEventInstance {
+    /// Name of the event that spawned this instance.
+    pub event: String,
+}
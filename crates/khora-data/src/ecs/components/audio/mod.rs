@@ -14,8 +14,10 @@
 
 //! Defines ECS components related to the audio system.
 
+mod event_instance;
 mod listener;
 mod source;
 
+pub use event_instance::*;
 pub use listener::*;
 pub use source::*;
@@ -26,6 +26,19 @@ use serde::{Deserialize, Serialize};
 pub struct PlaybackState {
     /// The current position in the sample data, in samples.
     pub cursor: f32,
+    /// Last output of the air-absorption low-pass filter.
+    #[serde(default)]
+    pub lowpass: f32,
+}
+
+impl PlaybackState {
+    /// State of a sound starting from its first sample.
+    pub fn start() -> Self {
+        Self {
+            cursor: 0.0,
+            lowpass: 0.0,
+        }
+    }
 }
 
 /// An ECS component that makes an entity an emitter of sound.
@@ -36,6 +49,8 @@ pub struct AudioSource {
     pub handle: AssetHandle<SoundData>,
     /// The volume of the sound, where 1.0 is normal volume.
     pub volume: f32,
+    /// The playback rate, where 1.0 is the original pitch.
+    pub pitch: f32,
     /// Whether the sound should loop back to the beginning when it finishes.
     pub looping: bool,
     /// Whether the sound should start playing automatically when this component is added.
@@ -51,6 +66,7 @@ impl Default for AudioSource {
         Self {
             handle: AssetHandle::dangling(),
             volume: 1.0,
+            pitch: 1.0,
             looping: false,
             autoplay: false,
             state: None,
@@ -64,6 +80,7 @@ impl AudioSource {
         Self {
             handle,
             volume: 1.0,
+            pitch: 1.0,
             looping: false,
             autoplay: true,
             state: None,
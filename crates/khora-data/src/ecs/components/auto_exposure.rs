@@ -0,0 +1,73 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Histogram-based eye adaptation of a camera.
+
+use khora_macros::Component;
+
+/// Adapts the exposure of the camera it is on to the brightness of the scene.
+///
+/// The renderer meters a luminance histogram of the HDR scene every frame,
+/// derives the exposure that maps its average to middle grey, and eases the
+/// current exposure toward it before tone mapping. Only the first active
+/// camera's `AutoExposure` is used.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct AutoExposure {
+    /// Lowest exposure value (EV100) the camera adapts to; bounds how far
+    /// dark scenes get brightened.
+    pub min_ev: f32,
+    /// Highest exposure value (EV100) the camera adapts to; bounds how far
+    /// bright scenes get darkened.
+    pub max_ev: f32,
+    /// Adaptation speed toward brighter scenes, in 1/seconds.
+    pub speed_up: f32,
+    /// Adaptation speed toward darker scenes, in 1/seconds.
+    pub speed_down: f32,
+    /// Exposure compensation in stops; positive values brighten the image.
+    pub compensation: f32,
+}
+
+impl Default for AutoExposure {
+    fn default() -> Self {
+        Self {
+            min_ev: -4.0,
+            max_ev: 14.0,
+            speed_up: 3.0,
+            speed_down: 1.0,
+            compensation: 0.0,
+        }
+    }
+}
+
+impl AutoExposure {
+    /// Limits adaptation to the `[min_ev, max_ev]` range.
+    pub fn with_range(mut self, min_ev: f32, max_ev: f32) -> Self {
+        self.min_ev = min_ev;
+        self.max_ev = max_ev.max(min_ev);
+        self
+    }
+
+    /// Sets the adaptation speeds toward brighter and darker scenes.
+    pub fn with_speed(mut self, speed_up: f32, speed_down: f32) -> Self {
+        self.speed_up = speed_up.max(0.0);
+        self.speed_down = speed_down.max(0.0);
+        self
+    }
+
+    /// Sets the exposure compensation, in stops.
+    pub fn with_compensation(mut self, compensation: f32) -> Self {
+        self.compensation = compensation;
+        self
+    }
+}
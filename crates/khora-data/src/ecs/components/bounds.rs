@@ -0,0 +1,60 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! World-space bounding boxes of entities and their hierarchies.
+
+use khora_core::math::Aabb;
+use khora_macros::Component;
+
+use crate::ecs::GlobalTransform;
+
+/// The world-space bounds of an entity, kept up to date by the engine.
+///
+/// Maintained by the `bounds_sync` data system for every entity with a mesh
+/// and a `GlobalTransform`, and for every ancestor of one. Culling, audio,
+/// AI and selection read it instead of transforming mesh boxes themselves.
+/// Derived data: not serialized, and overwritten if edited.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[component(no_serializable)]
+pub struct Bounds {
+    /// Box around the entity's own mesh, [`Aabb::INVALID`] if it has none.
+    pub world: Aabb,
+    /// Box around the entity and all its descendants.
+    pub hierarchy: Aabb,
+    /// Local mesh box and transform `world` was computed from.
+    pub(crate) source: Option<(Aabb, GlobalTransform)>,
+}
+
+impl Bounds {
+    /// Returns the box around the entity's own mesh, if it has one.
+    pub fn mesh(&self) -> Option<Aabb> {
+        self.world.is_valid().then_some(self.world)
+    }
+
+    /// Returns `true` if the entity has a mesh of its own, rather than only
+    /// bounding descendants.
+    pub fn has_mesh(&self) -> bool {
+        self.world.is_valid()
+    }
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self {
+            world: Aabb::INVALID,
+            hierarchy: Aabb::INVALID,
+            source: None,
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 use bincode::{Decode, Encode};
 use khora_core::math::Mat4;
+use khora_core::renderer::api::core::DepthMode;
 use khora_macros::Component;
 use serde::{Deserialize, Serialize};
 
@@ -55,6 +56,7 @@ pub struct Camera {
     /// The distance to the far clipping plane.
     /// Objects farther than this will not be rendered.
     /// Should be larger than `z_near` (e.g., 1000.0).
+    /// Perspective cameras accept `f32::INFINITY` for an infinite far plane.
     pub z_far: f32,
 
     /// Whether this camera is the active/primary camera.
@@ -74,6 +76,14 @@ impl Camera {
         }
     }
 
+    /// Creates a new perspective camera with an infinite far plane.
+    ///
+    /// Best paired with [`DepthMode::Reversed`], which keeps depth precision
+    /// at long range.
+    pub fn new_perspective_infinite(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        Self::new_perspective(fov_y_radians, aspect_ratio, z_near, f32::INFINITY)
+    }
+
     /// Creates a new orthographic camera with the given parameters.
     pub fn new_orthographic(width: f32, height: f32, z_near: f32, z_far: f32) -> Self {
         let aspect_ratio = if height > 0.0 { width / height } else { 1.0 };
@@ -110,8 +120,12 @@ impl Camera {
     ///
     /// This uses a right-handed coordinate system with a [0, 1] depth range,
     /// which is standard for modern rendering APIs like Vulkan and WebGPU.
+    /// An infinite `z_far` yields an infinite-far-plane perspective.
     pub fn projection_matrix(&self) -> Mat4 {
         match self.projection {
+            ProjectionType::Perspective { fov_y_radians } if self.z_far.is_infinite() => {
+                Mat4::perspective_infinite_rh_zo(fov_y_radians, self.aspect_ratio, self.z_near)
+            }
             ProjectionType::Perspective { fov_y_radians } => {
                 Mat4::perspective_rh_zo(fov_y_radians, self.aspect_ratio, self.z_near, self.z_far)
             }
@@ -130,6 +144,11 @@ impl Camera {
         }
     }
 
+    /// Calculates the projection matrix for the given depth convention.
+    pub fn projection_matrix_for(&self, depth_mode: DepthMode) -> Mat4 {
+        depth_mode.apply(self.projection_matrix())
+    }
+
     /// Updates the aspect ratio, typically called when the window is resized.
     pub fn set_aspect_ratio(&mut self, width: u32, height: u32) {
         if height > 0 {
@@ -207,6 +226,19 @@ mod tests {
         assert!(det.abs() > 0.0001, "Projection matrix is degenerate");
     }
 
+    #[test]
+    fn test_camera_infinite_reversed_projection() {
+        let camera = Camera::new_perspective_infinite(PI / 2.0, 1.0, 0.5);
+        let proj = camera.projection_matrix_for(DepthMode::Reversed);
+        let depth = |z: f32| {
+            let clip = proj * khora_core::math::Vec4::new(0.0, 0.0, z, 1.0);
+            clip.z / clip.w
+        };
+
+        assert!((depth(-0.5) - 1.0).abs() < 1e-5);
+        assert!(depth(-1.0e6) > 0.0 && depth(-1.0e6) < 1e-5);
+    }
+
     #[test]
     fn test_camera_orthographic_projection_matrix() {
         let camera = Camera::new_orthographic(100.0, 100.0, 0.1, 100.0);
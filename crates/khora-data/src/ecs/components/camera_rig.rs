@@ -0,0 +1,233 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Reusable camera rigs: follow, orbit and first-person.
+
+use bincode::{Decode, Encode};
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Vec3, FRAC_PI_2};
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+/// Highest pitch a rig accepts, just short of straight up or down.
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+/// How a [`CameraRig`] places the camera around its target.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum CameraRigMode {
+    /// Trails the target at a fixed world-space offset, looking at it.
+    Follow {
+        /// Camera position relative to the target.
+        offset: Vec3,
+    },
+    /// Circles the target at `distance`, steered by yaw and pitch.
+    Orbit {
+        /// Rotation around the world up axis, in radians.
+        yaw: f32,
+        /// Elevation above the horizon, in radians.
+        pitch: f32,
+        /// Desired distance from the target.
+        distance: f32,
+        /// Closest zoom allowed by [`CameraRig::zoom`].
+        min_distance: f32,
+        /// Farthest zoom allowed by [`CameraRig::zoom`].
+        max_distance: f32,
+    },
+    /// Sits at the target's eye point, steered by yaw and pitch.
+    FirstPerson {
+        /// Rotation around the world up axis, in radians.
+        yaw: f32,
+        /// Elevation above the horizon, in radians.
+        pitch: f32,
+    },
+}
+
+/// Pulls an orbit or follow camera in front of obstacles between it and
+/// its target.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct CameraCollision {
+    /// Clearance kept between the camera and the obstacle it hit.
+    pub radius: f32,
+    /// The camera never comes closer to the target than this.
+    pub min_distance: f32,
+}
+
+impl Default for CameraCollision {
+    fn default() -> Self {
+        Self {
+            radius: 0.2,
+            min_distance: 0.5,
+        }
+    }
+}
+
+/// Spring state carried between frames by the `camera_rig` system.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CameraRigState {
+    /// Whether the springs were seeded from the rig's first pose.
+    pub initialized: bool,
+    /// Smoothed point the camera is placed around.
+    pub pivot: Vec3,
+    /// Velocity of the pivot spring.
+    pub pivot_velocity: Vec3,
+    /// Smoothed yaw, in radians.
+    pub yaw: f32,
+    /// Velocity of the yaw spring.
+    pub yaw_velocity: f32,
+    /// Smoothed pitch, in radians.
+    pub pitch: f32,
+    /// Velocity of the pitch spring.
+    pub pitch_velocity: f32,
+    /// Current boom length, after collision.
+    pub distance: f32,
+    /// Velocity of the boom spring.
+    pub distance_velocity: f32,
+}
+
+/// Drives this entity's `Transform` as a camera around a target entity.
+///
+/// Position and rotation are smoothed with critically damped springs, so
+/// the motion is the same at any frame rate. Gameplay code steers the rig
+/// with [`rotate`](Self::rotate) and [`zoom`](Self::zoom); the
+/// `camera_rig` system places the camera after transform propagation,
+/// which means the rig must be a root entity.
+///
+/// ```rust,ignore
+/// world.spawn((
+///     Camera::default(),
+///     Transform::identity(),
+///     GlobalTransform::identity(),
+///     CameraRig::orbit(player, 6.0).with_collision(CameraCollision::default()),
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct CameraRig {
+    /// Placement around the target.
+    pub mode: CameraRigMode,
+    /// The entity the rig tracks. `None` holds the last pivot.
+    pub target: Option<EntityId>,
+    /// Offset from the target's origin to the tracked point (head height,
+    /// eye point).
+    pub target_offset: Vec3,
+    /// Seconds the pivot takes to catch up with the target. `0.0` snaps.
+    pub position_smoothing: f32,
+    /// Seconds yaw and pitch take to catch up with their input. `0.0` snaps.
+    pub rotation_smoothing: f32,
+    /// Collision-aware zoom. Requires a physics provider.
+    pub collision: Option<CameraCollision>,
+    /// Spring state, owned by the `camera_rig` system.
+    #[component(skip)]
+    pub state: CameraRigState,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            mode: CameraRigMode::Follow {
+                offset: Vec3::new(0.0, 3.0, 6.0),
+            },
+            target: None,
+            target_offset: Vec3::ZERO,
+            position_smoothing: 0.15,
+            rotation_smoothing: 0.05,
+            collision: None,
+            state: CameraRigState::default(),
+        }
+    }
+}
+
+impl CameraRig {
+    /// A rig trailing `target` at `offset`.
+    pub fn follow(target: EntityId, offset: Vec3) -> Self {
+        Self {
+            mode: CameraRigMode::Follow { offset },
+            target: Some(target),
+            ..Default::default()
+        }
+    }
+
+    /// A rig orbiting `target` at `distance`.
+    pub fn orbit(target: EntityId, distance: f32) -> Self {
+        Self {
+            mode: CameraRigMode::Orbit {
+                yaw: 0.0,
+                pitch: 0.3,
+                distance,
+                min_distance: 1.0,
+                max_distance: distance.max(1.0) * 4.0,
+            },
+            target: Some(target),
+            ..Default::default()
+        }
+    }
+
+    /// A first-person rig at `target`'s eye point, `eye_height` above its origin.
+    pub fn first_person(target: EntityId, eye_height: f32) -> Self {
+        Self {
+            mode: CameraRigMode::FirstPerson {
+                yaw: 0.0,
+                pitch: 0.0,
+            },
+            target: Some(target),
+            target_offset: Vec3::new(0.0, eye_height, 0.0),
+            position_smoothing: 0.0,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the smoothing times, in seconds.
+    pub fn with_smoothing(mut self, position: f32, rotation: f32) -> Self {
+        self.position_smoothing = position;
+        self.rotation_smoothing = rotation;
+        self
+    }
+
+    /// Sets the offset from the target's origin to the tracked point.
+    pub fn with_target_offset(mut self, offset: Vec3) -> Self {
+        self.target_offset = offset;
+        self
+    }
+
+    /// Enables collision-aware zoom.
+    pub fn with_collision(mut self, collision: CameraCollision) -> Self {
+        self.collision = Some(collision);
+        self
+    }
+
+    /// Turns an orbit or first-person rig by `yaw` and `pitch` radians.
+    /// Pitch is clamped short of the poles.
+    pub fn rotate(&mut self, delta_yaw: f32, delta_pitch: f32) {
+        match &mut self.mode {
+            CameraRigMode::Orbit { yaw, pitch, .. } | CameraRigMode::FirstPerson { yaw, pitch } => {
+                *yaw += delta_yaw;
+                *pitch = (*pitch + delta_pitch).clamp(-MAX_PITCH, MAX_PITCH);
+            }
+            CameraRigMode::Follow { .. } => {}
+        }
+    }
+
+    /// Moves an orbit rig closer (negative) or farther (positive), within
+    /// its distance limits.
+    pub fn zoom(&mut self, delta: f32) {
+        if let CameraRigMode::Orbit {
+            distance,
+            min_distance,
+            max_distance,
+            ..
+        } = &mut self.mode
+        {
+            *distance = (*distance + delta).clamp(*min_distance, *max_distance);
+        }
+    }
+}
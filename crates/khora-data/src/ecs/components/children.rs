@@ -21,7 +21,8 @@ use khora_macros::Component;
 /// of all `EntityId`s that have this entity as their `Parent`. It is primarily
 /// used for traversing the scene hierarchy downwards (from parent to child).
 ///
-/// Note: This component should be managed by a dedicated hierarchy maintenance
-/// system to ensure it stays in sync with `Parent` components on child entities.
+/// `World::set_parent` and `World::despawn` keep it up to date, and the
+/// `hierarchy_sync` data system rebuilds it for `Parent` components added
+/// directly. Prefer those over editing the list by hand.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Component)]
 pub struct Children(pub Vec<EntityId>);
@@ -0,0 +1,31 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Marker for entities switched off without being despawned.
+
+use khora_macros::Component;
+
+/// Switches an entity off without despawning it.
+///
+/// A disabled entity keeps all its components, but the engine ignores it:
+/// it is not rendered, its bodies and colliders leave the physics
+/// simulation and its sounds pause. Queries skip it unless they hold
+/// [`IncludeDisabled`](crate::ecs::IncludeDisabled). Use
+/// [`World::set_enabled`](crate::ecs::World::set_enabled) rather than adding
+/// it by hand, so the entity's descendants follow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+pub struct Disabled {
+    /// `true` when the entity is only disabled because an ancestor is.
+    pub inherited: bool,
+}
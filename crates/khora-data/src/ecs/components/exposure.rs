@@ -0,0 +1,66 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Manual exposure of a camera.
+
+use khora_core::math::photometry;
+use khora_macros::Component;
+
+/// Sets the exposure of the camera it is on, in EV100.
+///
+/// Lights are in physical units, so the camera has to be exposed for the
+/// scene like a real one: about EV 15 in direct sun, EV 12 on an overcast
+/// day and EV 7 indoors. A camera without `Exposure` uses
+/// [`Exposure::default`], exposed for sunlight. [`AutoExposure`] adapts on
+/// top of it. Only the first active camera's `Exposure` is used.
+///
+/// [`AutoExposure`]: super::AutoExposure
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct Exposure {
+    /// Exposure value at ISO 100; higher values darken the image.
+    pub ev100: f32,
+}
+
+impl Default for Exposure {
+    fn default() -> Self {
+        Self {
+            ev100: photometry::EV100_SUNNY,
+        }
+    }
+}
+
+impl Exposure {
+    /// Exposure for a sunny day.
+    pub const SUNNY: Self = Self::from_ev100(photometry::EV100_SUNNY);
+    /// Exposure for an overcast day.
+    pub const OVERCAST: Self = Self::from_ev100(photometry::EV100_OVERCAST);
+    /// Exposure for a lit interior.
+    pub const INDOOR: Self = Self::from_ev100(photometry::EV100_INDOOR);
+
+    /// Creates an exposure from an EV100 value.
+    pub const fn from_ev100(ev100: f32) -> Self {
+        Self { ev100 }
+    }
+
+    /// Creates the exposure of a physical camera from its f-number, shutter
+    /// time in seconds and ISO sensitivity.
+    pub fn from_camera_settings(aperture: f32, shutter_time: f32, iso: f32) -> Self {
+        Self::from_ev100(photometry::ev100_from_camera(aperture, shutter_time, iso))
+    }
+
+    /// Returns the factor the renderer scales scene luminance by.
+    pub fn scale(&self) -> f32 {
+        photometry::exposure_from_ev100(self.ev100)
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Marker for entities that are not drawn.
+
+use khora_macros::Component;
+
+/// Hides an entity: it is not drawn and casts no light, but keeps
+/// simulating, colliding and playing sounds.
+///
+/// Use [`World::set_visible`](crate::ecs::World::set_visible) rather than
+/// adding it by hand, so the entity's descendants follow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+pub struct Hidden {
+    /// `true` when the entity is only hidden because an ancestor is.
+    pub inherited: bool,
+}
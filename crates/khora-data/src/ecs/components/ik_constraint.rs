@@ -0,0 +1,100 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Inverse kinematics constraints applied after animation sampling.
+
+use bincode::{Decode, Encode};
+use khora_core::math::Vec3;
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+/// The solver used by an [`IkConstraint`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum IkSolver {
+    /// Analytic two-bone solve over the effector, its parent and its
+    /// grandparent (e.g. hand–forearm–upper arm).
+    TwoBone {
+        /// World-space point the middle joint bends towards (knee or elbow
+        /// direction). `None` keeps the animated bend.
+        pole: Option<Vec3>,
+    },
+    /// Iterative FABRIK solve over the effector and its `chain_length`
+    /// ancestors (e.g. tails, spines, tentacles).
+    Fabrik {
+        /// Number of bones above the effector included in the chain.
+        chain_length: usize,
+        /// Distance to the target under which the solve stops early.
+        tolerance: f32,
+    },
+}
+
+/// Pulls this entity (the end effector) towards a world-space target by
+/// rotating its ancestors.
+///
+/// Solved by the inverse kinematics lane after the animation graph has
+/// written the animated pose, so targets such as foot placement from
+/// physics raycasts correct the animation rather than replace it.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct IkConstraint {
+    /// The solver and the chain it operates on.
+    pub solver: IkSolver,
+    /// World-space target position for the effector.
+    pub target: Vec3,
+    /// Blend between the animated pose (`0.0`) and the solved pose (`1.0`).
+    pub weight: f32,
+    /// Whether the constraint is solved.
+    pub enabled: bool,
+}
+
+impl Default for IkConstraint {
+    fn default() -> Self {
+        Self {
+            solver: IkSolver::TwoBone { pole: None },
+            target: Vec3::ZERO,
+            weight: 1.0,
+            enabled: true,
+        }
+    }
+}
+
+impl IkConstraint {
+    /// Creates a two-bone constraint reaching for `target`.
+    pub fn two_bone(target: Vec3, pole: Option<Vec3>) -> Self {
+        Self {
+            solver: IkSolver::TwoBone { pole },
+            target,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a FABRIK constraint over `chain_length` bones reaching for `target`.
+    pub fn fabrik(target: Vec3, chain_length: usize) -> Self {
+        Self {
+            solver: IkSolver::Fabrik {
+                chain_length,
+                tolerance: 1e-3,
+            },
+            target,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the number of joints above the effector moved by the solver.
+    pub fn chain_length(&self) -> usize {
+        match self.solver {
+            IkSolver::TwoBone { .. } => 2,
+            IkSolver::Fabrik { chain_length, .. } => chain_length,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Entities that use interactable triggers.
+
+use khora_macros::Component;
+
+/// Lets this entity (usually the player) use the
+/// [`SceneTrigger`](crate::ecs::SceneTrigger)s around it.
+///
+/// When `key` is pressed, the nearest trigger with an `on_interact` event
+/// within its `interact_radius` fires.
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct Interactor {
+    /// Physical key code that interacts, as in
+    /// [`InputEvent::KeyPressed`](khora_core::platform::InputEvent::KeyPressed).
+    pub key: String,
+}
+
+impl Default for Interactor {
+    fn default() -> Self {
+        Self {
+            key: "KeyE".to_string(),
+        }
+    }
+}
+
+impl Interactor {
+    /// An interactor using `key`.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
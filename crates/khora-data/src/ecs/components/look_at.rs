@@ -0,0 +1,72 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Look-at constraint turning an entity towards another.
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Vec3;
+use khora_macros::Component;
+
+/// Keeps this entity's forward (-Z) axis pointed at another entity.
+///
+/// Applied by the `look_at` system after camera rigs, so it can aim a
+/// rigged camera at something other than the rig's target (a boss, a
+/// point of interest). Works on child entities: the parent's rotation is
+/// compensated.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct LookAt {
+    /// The entity to face.
+    pub target: EntityId,
+    /// Offset from the target's origin to the point looked at.
+    pub offset: Vec3,
+    /// Seconds the rotation takes to settle on the target. `0.0` snaps.
+    pub smoothing: f32,
+    /// Whether the constraint is applied.
+    pub enabled: bool,
+}
+
+impl Default for LookAt {
+    fn default() -> Self {
+        Self {
+            target: EntityId {
+                index: 0,
+                generation: 0,
+            },
+            offset: Vec3::ZERO,
+            smoothing: 0.0,
+            enabled: true,
+        }
+    }
+}
+
+impl LookAt {
+    /// Faces `target`'s origin without smoothing.
+    pub fn new(target: EntityId) -> Self {
+        Self {
+            target,
+            ..Default::default()
+        }
+    }
+
+    /// Faces a point offset from `target`'s origin.
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Settles on the target over `seconds`.
+    pub fn with_smoothing(mut self, seconds: f32) -> Self {
+        self.smoothing = seconds;
+        self
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keyframed animation of [`MaterialOverride`] parameters.
+
+use khora_core::animation::Curve;
+use khora_core::math::LinearRgba;
+use khora_macros::Component;
+
+use super::MaterialOverride;
+
+/// Drives an entity's [`MaterialOverride`] from curves.
+///
+/// Advanced every tick by the `material_animation` data system, which writes
+/// the sampled values into the entity's `MaterialOverride` (adding one if
+/// missing). Parameters without a curve are left untouched.
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct MaterialAnimation {
+    /// Curve for the base color override.
+    pub base_color: Option<Curve<LinearRgba>>,
+    /// Curve for the emissive color override.
+    pub emissive: Option<Curve<LinearRgba>>,
+    /// Curve for the specular power override.
+    pub specular_power: Option<Curve<f32>>,
+    /// Current playback position, in seconds.
+    pub time: f32,
+    /// Playback rate multiplier.
+    pub speed: f32,
+    /// Wrap around at the end instead of holding the last value.
+    pub looping: bool,
+    /// Whether playback advances.
+    pub playing: bool,
+}
+
+impl Default for MaterialAnimation {
+    fn default() -> Self {
+        Self {
+            base_color: None,
+            emissive: None,
+            specular_power: None,
+            time: 0.0,
+            speed: 1.0,
+            looping: false,
+            playing: true,
+        }
+    }
+}
+
+impl MaterialAnimation {
+    /// Creates an animation of the base color.
+    pub fn base_color(curve: Curve<LinearRgba>) -> Self {
+        Self {
+            base_color: Some(curve),
+            ..Default::default()
+        }
+    }
+
+    /// Creates an animation of the emissive color.
+    pub fn emissive(curve: Curve<LinearRgba>) -> Self {
+        Self {
+            emissive: Some(curve),
+            ..Default::default()
+        }
+    }
+
+    /// Makes the animation loop.
+    pub fn looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// Returns the length of the longest curve, in seconds.
+    pub fn duration(&self) -> f32 {
+        let base = self.base_color.as_ref().map_or(0.0, Curve::duration);
+        let emissive = self.emissive.as_ref().map_or(0.0, Curve::duration);
+        let specular = self.specular_power.as_ref().map_or(0.0, Curve::duration);
+        base.max(emissive).max(specular)
+    }
+
+    /// Advances playback by `delta_seconds`.
+    pub fn advance(&mut self, delta_seconds: f32) {
+        if !self.playing {
+            return;
+        }
+        let duration = self.duration();
+        self.time += delta_seconds * self.speed;
+        if self.looping && duration > 0.0 {
+            self.time = self.time.rem_euclid(duration);
+        } else {
+            self.time = self.time.clamp(0.0, duration);
+        }
+    }
+
+    /// Writes the values sampled at the current time into `target`.
+    pub fn sample_into(&self, target: &mut MaterialOverride) {
+        if let Some(curve) = &self.base_color {
+            target.base_color = curve.sample(self.time).or(target.base_color);
+        }
+        if let Some(curve) = &self.emissive {
+            target.emissive = curve.sample(self.time).or(target.emissive);
+        }
+        if let Some(curve) = &self.specular_power {
+            target.specular_power = curve.sample(self.time).or(target.specular_power);
+        }
+    }
+}
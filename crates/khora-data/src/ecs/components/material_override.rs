@@ -0,0 +1,65 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-entity material parameter overrides.
+
+use khora_core::asset::MaterialParams;
+use khora_core::math::LinearRgba;
+use khora_macros::Component;
+
+/// Sparse overrides applied on top of an entity's material for its draws.
+///
+/// Lets a single entity change, e.g., its tint or glow without cloning the
+/// shared material asset. Unset fields fall through to the material.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Component)]
+pub struct MaterialOverride {
+    /// Replaces the material's base color.
+    pub base_color: Option<LinearRgba>,
+    /// Replaces the material's emissive color.
+    pub emissive: Option<LinearRgba>,
+    /// Replaces the material's specular power.
+    pub specular_power: Option<f32>,
+}
+
+impl MaterialOverride {
+    /// Creates an override that only replaces the base color.
+    pub fn base_color(color: LinearRgba) -> Self {
+        Self {
+            base_color: Some(color),
+            ..Default::default()
+        }
+    }
+
+    /// Creates an override that only replaces the emissive color.
+    pub fn emissive(color: LinearRgba) -> Self {
+        Self {
+            emissive: Some(color),
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if no parameter is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.base_color.is_none() && self.emissive.is_none() && self.specular_power.is_none()
+    }
+
+    /// Applies the set fields on top of `params`.
+    pub fn apply(&self, params: MaterialParams) -> MaterialParams {
+        MaterialParams {
+            base_color: self.base_color.unwrap_or(params.base_color),
+            emissive: self.emissive.unwrap_or(params.emissive),
+            specular_power: self.specular_power.unwrap_or(params.specular_power),
+        }
+    }
+}
@@ -21,6 +21,7 @@
 //!
 //! The `#[derive(Material)]` proc-macro auto-generates the registration.
 
+use crate::scene::SCENE_DECODE_LIMIT;
 use bincode::config;
 use inventory::collect;
 use khora_core::asset::{AssetHandle, AssetUUID, Material};
@@ -85,28 +86,38 @@ pub fn serialize_material_component(
 pub fn deserialize_material_component(
     data: &[u8],
 ) -> Result<(AssetHandle<Box<dyn Material>>, AssetUUID), String> {
-    let (serializable, _): (SerializableMaterialData, _) =
-        bincode::decode_from_slice(data, config::standard()).map_err(|e| e.to_string())?;
+    let material = decode_material(data)?;
+    Ok((AssetHandle::new(material), AssetUUID::new()))
+}
+
+/// Decodes a material encoded by [`serialize_material_component`].
+///
+/// Also the payload format of material assets, so the asset pipeline and
+/// scene files share one encoding.
+pub fn decode_material(data: &[u8]) -> Result<Box<dyn Material>, String> {
+    let (serializable, _): (SerializableMaterialData, _) = bincode::decode_from_slice(
+        data,
+        config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+    )
+    .map_err(|e| e.to_string())?;
 
     if serializable.type_name == "__unknown__" {
         // Reconstruct a basic StandardMaterial from the fallback base color.
-        let (base_color, _): (LinearRgba, _) =
-            bincode::decode_from_slice(&serializable.data, config::standard())
-                .map_err(|e| e.to_string())?;
+        let (base_color, _): (LinearRgba, _) = bincode::decode_from_slice(
+            &serializable.data,
+            config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+        )
+        .map_err(|e| e.to_string())?;
         let mat = khora_core::asset::StandardMaterial {
             base_color,
             ..Default::default()
         };
-        let handle = AssetHandle::new(Box::new(mat) as Box<dyn Material>);
-        return Ok((handle, AssetUUID::new()));
+        return Ok(Box::new(mat));
     }
 
     for reg in inventory::iter::<MaterialRegistration> {
         if reg.type_name == serializable.type_name {
-            let material = (reg.deserialize)(&serializable.data)?;
-            let uuid = AssetUUID::new();
-            let handle = AssetHandle::new(material);
-            return Ok((handle, uuid));
+            return (reg.deserialize)(&serializable.data);
         }
     }
 
@@ -129,8 +140,11 @@ inventory::submit! {
             })
         },
         deserialize: |data| {
-            let (m, _) = bincode::decode_from_slice::<StandardMaterial, _>(data, config::standard())
-                .map_err(|e| e.to_string())?;
+            let (m, _) = bincode::decode_from_slice::<StandardMaterial, _>(
+                data,
+                config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+            )
+            .map_err(|e| e.to_string())?;
             Ok(Box::new(m) as Box<dyn Material>)
         },
         create_default: || Box::new(StandardMaterial::default()) as Box<dyn Material>,
@@ -146,8 +160,11 @@ inventory::submit! {
             })
         },
         deserialize: |data| {
-            let (m, _) = bincode::decode_from_slice::<UnlitMaterial, _>(data, config::standard())
-                .map_err(|e| e.to_string())?;
+            let (m, _) = bincode::decode_from_slice::<UnlitMaterial, _>(
+                data,
+                config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+            )
+            .map_err(|e| e.to_string())?;
             Ok(Box::new(m) as Box<dyn Material>)
         },
         create_default: || Box::new(UnlitMaterial::default()) as Box<dyn Material>,
@@ -163,8 +180,11 @@ inventory::submit! {
             })
         },
         deserialize: |data| {
-            let (m, _) = bincode::decode_from_slice::<EmissiveMaterial, _>(data, config::standard())
-                .map_err(|e| e.to_string())?;
+            let (m, _) = bincode::decode_from_slice::<EmissiveMaterial, _>(
+                data,
+                config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+            )
+            .map_err(|e| e.to_string())?;
             Ok(Box::new(m) as Box<dyn Material>)
         },
         create_default: || Box::new(EmissiveMaterial::default()) as Box<dyn Material>,
@@ -180,10 +200,93 @@ inventory::submit! {
             })
         },
         deserialize: |data| {
-            let (m, _) = bincode::decode_from_slice::<WireframeMaterial, _>(data, config::standard())
-                .map_err(|e| e.to_string())?;
+            let (m, _) = bincode::decode_from_slice::<WireframeMaterial, _>(
+                data,
+                config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+            )
+            .map_err(|e| e.to_string())?;
             Ok(Box::new(m) as Box<dyn Material>)
         },
         create_default: || Box::new(WireframeMaterial::default()) as Box<dyn Material>,
     }
 }
+
+// ─── ComponentRegistration for MaterialComponent ───
+
+use crate::ecs::{MaterialComponent, PendingAssetKind, PendingAssets, World};
+use crate::scene::registry::ComponentRegistration;
+use khora_core::ecs::entity::EntityId;
+use std::any::TypeId;
+
+/// Scene-file form of a `MaterialComponent`: the asset UUID alongside the
+/// inline material payload, so a reloaded scene keeps the same asset identity.
+#[derive(bincode::Encode, bincode::Decode, Debug, Clone)]
+struct SerializableMaterialRef {
+    uuid: AssetUUID,
+    data: Vec<u8>,
+}
+
+fn serialize_material_ref(world: &World, entity: EntityId) -> Option<Vec<u8>> {
+    let comp = world.get::<MaterialComponent>(entity)?;
+    let material: &dyn Material = &**comp.handle;
+    let data = serialize_material_component(material.base_color(), material)?;
+    let material_ref = SerializableMaterialRef {
+        uuid: comp.uuid,
+        data,
+    };
+    bincode::encode_to_vec(&material_ref, config::standard()).ok()
+}
+
+fn deserialize_material_ref(
+    world: &mut World,
+    entity: EntityId,
+    data: &[u8],
+) -> Result<(), String> {
+    let (material_ref, _): (SerializableMaterialRef, _) = bincode::decode_from_slice(
+        data,
+        config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+    )
+    .map_err(|e| e.to_string())?;
+    let (handle, _) = deserialize_material_component(&material_ref.data)?;
+    let component = MaterialComponent {
+        handle,
+        uuid: material_ref.uuid,
+    };
+    world.add_component(entity, component).ok();
+    // The inline copy renders at once; a material asset with this UUID
+    // replaces it once the asset pipeline has loaded it.
+    PendingAssets::request(world, entity, PendingAssetKind::Material, material_ref.uuid);
+    Ok(())
+}
+
+inventory::submit! {
+    ComponentRegistration {
+        type_id: TypeId::of::<MaterialComponent>(),
+        type_name: "MaterialComponent",
+        serialize_recipe: serialize_material_ref,
+        deserialize_recipe: deserialize_material_ref,
+        create_default: |world, entity| {
+            let component = MaterialComponent {
+                handle: AssetHandle::new(Box::new(StandardMaterial::default()) as Box<dyn Material>),
+                uuid: AssetUUID::new(),
+            };
+            world.add_component(entity, component).ok();
+            Ok(())
+        },
+        to_json: |world, entity| {
+            world.get::<MaterialComponent>(entity).and_then(|comp| {
+                serde_json::to_value(comp.uuid).ok()
+            })
+        },
+        from_json: |_world, _entity, _value| {
+            Err("Material editing from inspector not implemented".to_string())
+        },
+        remove: |world, entity| {
+            match world.remove_component::<MaterialComponent>(entity) {
+                Ok(_) => Ok(()),
+                Err(e) => Err(format!("{:?}", e)),
+            }
+        },
+        map_entities: None,
+    }
+}
@@ -22,11 +22,11 @@ use bincode::{Decode, Encode};
 use khora_core::asset::{AssetHandle, AssetUUID};
 use khora_core::math::{Aabb, Vec2, Vec3};
 use khora_core::renderer::api::{
-    pipeline::{PrimitiveTopology, VertexAttributeDescriptor, VertexFormat},
+    pipeline::{PrimitiveTopology, StaticVertex, Vertex, VertexAttributeDescriptor},
     scene::Mesh,
 };
 
-use crate::ecs::HandleComponent;
+use crate::ecs::{HandleComponent, PendingAssetKind, PendingAssets};
 
 /// Identifies a known procedural mesh primitive.
 #[derive(
@@ -73,6 +73,9 @@ impl SerializableMeshRef {
     }
 
     /// Reconstructs a `HandleComponent<Mesh>` from this serializable reference.
+    ///
+    /// Asset references produce an empty placeholder; scene loading does not
+    /// go through here for them, see [`PendingAssets`].
     pub fn into_handle(self) -> HandleComponent<Mesh> {
         match self {
             Self::Procedural { kind, params } => {
@@ -97,6 +100,8 @@ impl SerializableMeshRef {
                     primitive_type: PrimitiveTopology::TriangleList,
                     bounding_box: Aabb::from_min_max(Vec3::ZERO, Vec3::ZERO),
                     vertex_layout: default_vertex_layout(),
+                    morph_targets: Vec::new(),
+                    skin: None,
                 };
                 let handle = AssetHandle::new(mesh);
                 HandleComponent { handle, uuid }
@@ -193,23 +198,7 @@ fn reconstruct_procedural_mesh(kind: &ProceduralMeshKind, params: &[f32; 4]) ->
 }
 
 fn default_vertex_layout() -> Vec<VertexAttributeDescriptor> {
-    vec![
-        VertexAttributeDescriptor {
-            shader_location: 0,
-            format: VertexFormat::Float32x3,
-            offset: 0,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 1,
-            format: VertexFormat::Float32x3,
-            offset: 12,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 2,
-            format: VertexFormat::Float32x2,
-            offset: 24,
-        },
-    ]
+    StaticVertex::LAYOUT.attributes.into_owned()
 }
 
 // ─── Procedural mesh generation (mirrors khora-sdk/vessel.rs) ───
@@ -245,6 +234,8 @@ fn create_plane(size: f32, y: f32) -> Mesh {
         primitive_type: PrimitiveTopology::TriangleList,
         bounding_box: Aabb::from_min_max(Vec3::new(-half, y, -half), Vec3::new(half, y, half)),
         vertex_layout: default_vertex_layout(),
+        morph_targets: Vec::new(),
+        skin: None,
     }
 }
 
@@ -335,6 +326,8 @@ fn create_cube(size: f32) -> Mesh {
             Vec3::new(half, half, half),
         ),
         vertex_layout: default_vertex_layout(),
+        morph_targets: Vec::new(),
+        skin: None,
     }
 }
 
@@ -387,6 +380,8 @@ fn create_sphere(radius: f32, segments: u32, rings: u32) -> Mesh {
             Vec3::new(radius, radius, radius),
         ),
         vertex_layout: default_vertex_layout(),
+        morph_targets: Vec::new(),
+        skin: None,
     }
 }
 
@@ -394,22 +389,44 @@ fn create_sphere(radius: f32, segments: u32, rings: u32) -> Mesh {
 
 use crate::ecs::World;
 use crate::scene::registry::ComponentRegistration;
+use crate::scene::SCENE_DECODE_LIMIT;
 use bincode::config;
 use khora_core::ecs::entity::EntityId;
 use std::any::TypeId;
 
+/// Returns the mesh reference for `entity`, including one still waiting
+/// on the asset pipeline so that re-saving a freshly loaded scene is lossless.
+fn mesh_ref_of(world: &World, entity: EntityId) -> Option<SerializableMeshRef> {
+    if let Some(comp) = world.get::<HandleComponent<Mesh>>(entity) {
+        return Some(SerializableMeshRef::from_handle(comp));
+    }
+    world
+        .get::<PendingAssets>(entity)
+        .and_then(|pending| pending.uuid_of(PendingAssetKind::Mesh))
+        .map(SerializableMeshRef::Asset)
+}
+
 fn serialize_mesh_handle(world: &World, entity: EntityId) -> Option<Vec<u8>> {
-    world.get::<HandleComponent<Mesh>>(entity).map(|comp| {
-        let mesh_ref = SerializableMeshRef::from_handle(comp);
-        bincode::encode_to_vec(&mesh_ref, config::standard()).unwrap_or_default()
-    })
+    mesh_ref_of(world, entity)
+        .map(|mesh_ref| bincode::encode_to_vec(&mesh_ref, config::standard()).unwrap_or_default())
 }
 
 fn deserialize_mesh_handle(world: &mut World, entity: EntityId, data: &[u8]) -> Result<(), String> {
-    let (mesh_ref, _): (SerializableMeshRef, _) =
-        bincode::decode_from_slice(data, config::standard()).map_err(|e| e.to_string())?;
-    let component = mesh_ref.into_handle();
-    world.add_component(entity, component).ok();
+    let (mesh_ref, _): (SerializableMeshRef, _) = bincode::decode_from_slice(
+        data,
+        config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+    )
+    .map_err(|e| e.to_string())?;
+    match mesh_ref {
+        SerializableMeshRef::Asset(uuid) => {
+            // A placeholder handle would be uploaded and cached on the GPU
+            // under the real UUID; defer until the asset service binds it.
+            PendingAssets::request(world, entity, PendingAssetKind::Mesh, uuid);
+        }
+        procedural => {
+            world.add_component(entity, procedural.into_handle()).ok();
+        }
+    }
     Ok(())
 }
 
@@ -425,10 +442,7 @@ inventory::submit! {
             Err("Mesh handles cannot be created with defaults".to_string())
         },
         to_json: |world, entity| {
-            world.get::<HandleComponent<Mesh>>(entity).and_then(|comp| {
-                let mesh_ref = SerializableMeshRef::from_handle(comp);
-                serde_json::to_value(mesh_ref).ok()
-            })
+            mesh_ref_of(world, entity).and_then(|mesh_ref| serde_json::to_value(mesh_ref).ok())
         },
         from_json: |_world, _entity, _value| {
             // Editing a mesh handle from the inspector is not yet wired —
@@ -444,5 +458,6 @@ inventory::submit! {
                 Err(e) => Err(format!("{:?}", e)),
             }
         },
+        map_entities: None,
     }
 }
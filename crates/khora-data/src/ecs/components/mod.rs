@@ -12,30 +12,96 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod animation_player;
+mod animator;
+mod anti_aliasing;
 mod audio;
+mod auto_exposure;
+mod bounds;
 mod camera;
+mod camera_rig;
 mod children;
+mod disabled;
+mod exposure;
 mod global_transform;
 mod handle;
+mod hidden;
+mod ik_constraint;
+mod interactor;
 mod light;
+mod look_at;
 mod material;
+mod material_animation;
+mod material_override;
 mod material_registry;
 mod mesh_serialization;
+mod morph_weights;
 mod name;
+mod non_serialized;
 mod parent;
+mod path_follower;
+mod pending_assets;
 mod physics;
+mod post_process;
+mod render_layers;
+mod scene_trigger;
+mod simulation_anchor;
+mod simulation_lod;
+mod skin;
+mod sky;
+mod spline_path;
+mod static_batch;
+mod static_geometry;
+mod tags;
+mod time_of_day;
+mod timeline_player;
 mod transform;
+mod weather;
+mod weather_audio;
 
+pub use animation_player::*;
+pub use animator::*;
+pub use anti_aliasing::*;
 pub use audio::*;
+pub use auto_exposure::*;
+pub use bounds::*;
 pub use camera::*;
+pub use camera_rig::*;
 pub use children::*;
+pub use disabled::*;
+pub use exposure::*;
 pub use global_transform::*;
 pub use handle::*;
+pub use hidden::*;
+pub use ik_constraint::*;
+pub use interactor::*;
 pub use light::*;
+pub use look_at::*;
 pub use material::*;
+pub use material_animation::*;
+pub use material_override::*;
 pub use material_registry::*;
 pub use mesh_serialization::*;
+pub use morph_weights::*;
 pub use name::*;
+pub use non_serialized::*;
 pub use parent::*;
+pub use path_follower::*;
+pub use pending_assets::*;
 pub use physics::*;
+pub use post_process::*;
+pub use render_layers::*;
+pub use scene_trigger::*;
+pub use simulation_anchor::*;
+pub use simulation_lod::*;
+pub use skin::*;
+pub use sky::*;
+pub use spline_path::*;
+pub use static_batch::*;
+pub use static_geometry::*;
+pub use tags::*;
+pub use time_of_day::*;
+pub use timeline_player::*;
 pub use transform::*;
+pub use weather::*;
+pub use weather_audio::*;
@@ -0,0 +1,57 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Blend-shape weights for meshes with morph targets.
+
+use khora_core::asset::AssetUUID;
+use khora_macros::Component;
+
+/// Per-entity weights for the morph targets of the entity's mesh.
+///
+/// `weights[i]` scales the mesh's `morph_targets[i]`. Applied on the CPU by
+/// the `morph_target_sync` data system before extraction.
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+pub struct MorphWeights {
+    /// One weight per morph target, usually in `[0.0, 1.0]`.
+    pub weights: Vec<f32>,
+}
+
+impl MorphWeights {
+    /// Creates weights from a list of values.
+    pub fn new(weights: impl Into<Vec<f32>>) -> Self {
+        Self {
+            weights: weights.into(),
+        }
+    }
+
+    /// Sets the weight of target `index`, growing the list if needed.
+    pub fn set(&mut self, index: usize, weight: f32) {
+        if index >= self.weights.len() {
+            self.weights.resize(index + 1, 0.0);
+        }
+        self.weights[index] = weight;
+    }
+}
+
+/// Tracks the entity-owned GPU mesh produced from its [`MorphWeights`].
+///
+/// Managed by `morph_target_sync`; not serialized.
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+#[component(no_serializable)]
+pub struct MorphedMesh {
+    /// Cache key of the deformed GPU mesh, unique to this entity.
+    pub uuid: AssetUUID,
+    /// Weights the current GPU mesh was built from.
+    pub applied: Vec<f32>,
+}
@@ -0,0 +1,26 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Marker for runtime entities that scenes do not save.
+
+use khora_macros::Component;
+
+/// Keeps an entity out of saved scenes.
+///
+/// For entities spawned at runtime by engine systems, such as sound event
+/// instances, that would otherwise be saved with their level and spawned
+/// again on load. The Definition and Recipe strategies skip them; a parent's
+/// `Children` may still list them, and loading drops those entries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+#[component(no_serializable)]
+pub struct NonSerialized;
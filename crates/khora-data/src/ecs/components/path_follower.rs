@@ -0,0 +1,140 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Moves an entity along a [`SplinePath`](super::SplinePath).
+
+use bincode::{Decode, Encode};
+use khora_core::animation::Easing;
+use khora_core::ecs::entity::EntityId;
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+/// What a [`PathFollower`] does when it reaches the end of its path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum PathWrap {
+    /// Stops at the end.
+    #[default]
+    Once,
+    /// Starts over from the beginning.
+    Loop,
+    /// Travels back to the start, then forward again.
+    PingPong,
+}
+
+/// Progress carried between frames by the `path_follow` system.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PathFollowerState {
+    /// Distance travelled along the current pass, before easing.
+    pub distance: f32,
+    /// Whether a ping-pong follower is on its way back.
+    pub reversing: bool,
+    /// Whether a [`PathWrap::Once`] follower reached the end.
+    pub finished: bool,
+}
+
+/// Moves this entity along the path of another entity at a given speed.
+///
+/// The `path_follow` system writes the entity's `Transform` before
+/// transform propagation, so children (a crate on a moving platform)
+/// follow in the same frame. Works on child entities: the parent's
+/// transform is compensated.
+///
+/// ```rust,ignore
+/// let rail = world.spawn((
+///     Transform::identity(),
+///     GlobalTransform::identity(),
+///     SplinePath::new(Spline::new([Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0)])),
+/// ));
+/// world.spawn((
+///     Transform::identity(),
+///     GlobalTransform::identity(),
+///     PathFollower::new(rail, 2.0)
+///         .with_wrap(PathWrap::PingPong)
+///         .with_easing(Easing::EaseInOut),
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct PathFollower {
+    /// The entity carrying the [`SplinePath`](super::SplinePath).
+    pub path: EntityId,
+    /// Travel speed, in world units per second.
+    pub speed: f32,
+    /// Shapes the speed over each pass along the path.
+    pub easing: Easing,
+    /// Behavior at the end of the path.
+    pub wrap: PathWrap,
+    /// Whether the entity turns to face its direction of travel.
+    pub orient: bool,
+    /// Whether the follower moves.
+    pub enabled: bool,
+    /// Progress, owned by the `path_follow` system.
+    #[component(skip)]
+    pub state: PathFollowerState,
+}
+
+impl Default for PathFollower {
+    fn default() -> Self {
+        Self {
+            path: EntityId {
+                index: 0,
+                generation: 0,
+            },
+            speed: 1.0,
+            easing: Easing::Linear,
+            wrap: PathWrap::Once,
+            orient: false,
+            enabled: true,
+            state: PathFollowerState::default(),
+        }
+    }
+}
+
+impl PathFollower {
+    /// Follows `path` at `speed` units per second.
+    pub fn new(path: EntityId, speed: f32) -> Self {
+        Self {
+            path,
+            speed,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the easing of each pass.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the behavior at the end of the path.
+    pub fn with_wrap(mut self, wrap: PathWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Turns the entity to face its direction of travel.
+    pub fn oriented(mut self) -> Self {
+        self.orient = true;
+        self
+    }
+
+    /// Returns `true` once a [`PathWrap::Once`] follower reached the end.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished
+    }
+
+    /// Sends the follower back to the start of its path.
+    pub fn restart(&mut self) {
+        self.state = PathFollowerState::default();
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Asset references waiting to be resolved by the asset pipeline.
+//!
+//! Scene deserialization cannot reach the asset service, so imported assets
+//! are recorded here by UUID. The I/O layer loads them, attaches the real
+//! handle components, and removes this marker.
+
+use khora_core::asset::AssetUUID;
+use khora_core::ecs::entity::EntityId;
+use khora_macros::Component;
+
+use crate::ecs::World;
+
+/// The typed handle component an [`AssetRequest`] resolves into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PendingAssetKind {
+    /// Resolves into a `HandleComponent<Mesh>`.
+    Mesh,
+    /// Replaces the inline material of a `MaterialComponent` with the
+    /// material asset of the same UUID, if the asset index has one.
+    Material,
+}
+
+/// A single asset reference deserialized by UUID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetRequest {
+    /// Which handle component to attach once the asset is loaded.
+    pub kind: PendingAssetKind,
+    /// The asset to load.
+    pub uuid: AssetUUID,
+}
+
+/// Marks an entity whose asset handles have not been bound yet.
+///
+/// Transient: the original asset UUIDs are written back out by the owning
+/// component registrations, so a scene saved before resolution completes
+/// keeps its references.
+#[derive(Debug, Clone, Default, Component)]
+#[component(no_serializable)]
+pub struct PendingAssets {
+    /// Outstanding requests, in the order they were deserialized.
+    pub requests: Vec<AssetRequest>,
+}
+
+impl PendingAssets {
+    /// Records an asset request on `entity`, creating the marker if needed.
+    pub fn request(world: &mut World, entity: EntityId, kind: PendingAssetKind, uuid: AssetUUID) {
+        let request = AssetRequest { kind, uuid };
+        if let Some(pending) = world.get_mut::<PendingAssets>(entity) {
+            if !pending.requests.contains(&request) {
+                pending.requests.push(request);
+            }
+            return;
+        }
+        let _ = world.add_component(
+            entity,
+            PendingAssets {
+                requests: vec![request],
+            },
+        );
+    }
+
+    /// Returns the UUID of the first outstanding request of `kind`.
+    pub fn uuid_of(&self, kind: PendingAssetKind) -> Option<AssetUUID> {
+        self.requests
+            .iter()
+            .find(|r| r.kind == kind)
+            .map(|r| r.uuid)
+    }
+}
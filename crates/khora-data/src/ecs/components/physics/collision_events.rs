@@ -16,10 +16,14 @@ use khora_core::physics::CollisionEvent;
 use khora_macros::Component;
 use serde::{Deserialize, Serialize};
 
-/// A component that stores collision events for the current frame.
-/// Typically attached to a singleton entity or used as a resource.
+/// A component that stores the collision events involving its entity's
+/// [`Collider`](crate::ecs::Collider).
+///
+/// Refilled after every physics step. To observe every collision in the
+/// world, read the `Events<CollisionEvent>` queue with an
+/// [`EventReader`](crate::ecs::EventReader) instead.
 #[derive(Debug, Clone, Default, Component, Serialize, Deserialize)]
 pub struct CollisionEvents {
-    /// List of events that occurred in the last physics step.
+    /// Events of the last physics step that involve this entity's collider.
     pub events: Vec<CollisionEvent>,
 }
@@ -0,0 +1,128 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use khora_core::math::Vec3;
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+use crate::ecs::GlobalTransform;
+
+/// The volume of a [`GravityZone`] and the direction it pulls in.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[non_exhaustive]
+pub enum GravityZoneShape {
+    /// Attracts bodies within `radius` of the zone's origin towards it,
+    /// like a planet.
+    Spherical {
+        /// Radius of the zone in world units.
+        radius: f32,
+    },
+    /// Pulls bodies inside an oriented box along the zone's local -Y axis.
+    Planar {
+        /// Half-extents of the box, in the zone's local space.
+        half_extents: Vec3,
+    },
+}
+
+/// A volume that changes gravity for the rigid bodies inside it.
+///
+/// Zones are placed by the entity's [`GlobalTransform`]. A body inside
+/// several zones receives the sum of their accelerations; if any of them
+/// replaces world gravity, the global gravity vector is ignored for that
+/// body. The body's own `gravity_scale` still applies on top.
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct GravityZone {
+    /// Volume and direction of the zone.
+    pub shape: GravityZoneShape,
+    /// Acceleration applied inside the zone, in m/s².
+    pub strength: f32,
+    /// Whether bodies inside the zone stop feeling world gravity.
+    pub replace_world_gravity: bool,
+}
+
+impl Default for GravityZone {
+    fn default() -> Self {
+        Self::spherical(10.0, 9.81)
+    }
+}
+
+impl GravityZone {
+    /// Creates a spherical attractor replacing world gravity.
+    pub fn spherical(radius: f32, strength: f32) -> Self {
+        Self {
+            shape: GravityZoneShape::Spherical { radius },
+            strength,
+            replace_world_gravity: true,
+        }
+    }
+
+    /// Creates a planar zone replacing world gravity inside a box.
+    pub fn planar(half_extents: Vec3, strength: f32) -> Self {
+        Self {
+            shape: GravityZoneShape::Planar { half_extents },
+            strength,
+            replace_world_gravity: true,
+        }
+    }
+
+    /// Returns the acceleration this zone applies at `point`, or `None` if
+    /// the point lies outside the zone.
+    pub fn acceleration_at(&self, transform: &GlobalTransform, point: Vec3) -> Option<Vec3> {
+        match self.shape {
+            GravityZoneShape::Spherical { radius } => {
+                let to_center = transform.0.translation() - point;
+                (to_center.length_squared() <= radius * radius)
+                    .then(|| to_center.normalize() * self.strength)
+            }
+            GravityZoneShape::Planar { half_extents } => {
+                let local = transform.0.inverse()?.0.transform_point(point);
+                let inside = local.x.abs() <= half_extents.x
+                    && local.y.abs() <= half_extents.y
+                    && local.z.abs() <= half_extents.z;
+                inside.then(|| -transform.0.up().normalize() * self.strength)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::math::AffineTransform;
+
+    #[test]
+    fn spherical_zone_pulls_towards_center() {
+        let zone = GravityZone::spherical(10.0, 5.0);
+        let transform = GlobalTransform::at_position(Vec3::new(0.0, 0.0, 0.0));
+        let accel = zone
+            .acceleration_at(&transform, Vec3::new(4.0, 0.0, 0.0))
+            .unwrap();
+        assert!((accel - Vec3::new(-5.0, 0.0, 0.0)).length() < 1e-5);
+        assert!(zone
+            .acceleration_at(&transform, Vec3::new(11.0, 0.0, 0.0))
+            .is_none());
+    }
+
+    #[test]
+    fn planar_zone_follows_zone_orientation() {
+        let zone = GravityZone::planar(Vec3::new(5.0, 5.0, 5.0), 2.0);
+        let transform = GlobalTransform(AffineTransform::from_rotation_x(std::f32::consts::PI));
+        let accel = zone.acceleration_at(&transform, Vec3::ZERO).unwrap();
+        assert!((accel - Vec3::new(0.0, 2.0, 0.0)).length() < 1e-5);
+        assert!(zone
+            .acceleration_at(&transform, Vec3::new(0.0, 6.0, 0.0))
+            .is_none());
+    }
+}
@@ -16,8 +16,10 @@ mod active_events;
 mod collider;
 mod collision_events;
 mod collision_pairs;
+mod gravity_zone;
 mod kinematic_character_controller;
 mod physics_debug_data;
+mod physics_interpolation;
 mod physics_material;
 mod rigid_body;
 
@@ -25,7 +27,9 @@ pub use active_events::*;
 pub use collider::*;
 pub use collision_events::*;
 pub use collision_pairs::*;
+pub use gravity_zone::*;
 pub use kinematic_character_controller::*;
 pub use physics_debug_data::*;
+pub use physics_interpolation::*;
 pub use physics_material::*;
 pub use rigid_body::*;
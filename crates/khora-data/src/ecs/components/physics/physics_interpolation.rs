@@ -0,0 +1,176 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use khora_core::math::{Mat4, Quat, Vec3};
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+/// A rigid pose reported by the physics provider at the end of a tick.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct PhysicsPose {
+    /// World-space position.
+    pub translation: Vec3,
+    /// World-space orientation.
+    pub rotation: Quat,
+}
+
+impl PhysicsPose {
+    /// Returns the translation-rotation matrix of this pose.
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_translation(self.translation) * Mat4::from_quat(self.rotation)
+    }
+}
+
+/// Smooths the rendered motion of a rigid body between physics ticks.
+///
+/// The physics lane records the body's last two poses after every tick, and
+/// writes the fraction of a tick elapsed since the last one each frame.
+/// Rendering then draws the blend of both poses instead of the latest one,
+/// one tick behind the simulation. With `extrapolate`, it instead predicts
+/// past the latest pose from the last tick's motion, trading the latency for
+/// the occasional overshoot on sudden stops — a good fit for fast projectiles.
+///
+/// The correction also applies to the entity's descendants.
+#[derive(Debug, Clone, Component, Serialize, Deserialize, Default)]
+pub struct PhysicsInterpolation {
+    /// Whether to predict past the latest tick instead of blending up to it.
+    pub extrapolate: bool,
+    /// Pose at the end of the tick before the latest one.
+    #[component(skip)]
+    pub previous: Option<PhysicsPose>,
+    /// Pose at the end of the latest tick.
+    #[component(skip)]
+    pub current: Option<PhysicsPose>,
+    /// Fraction of a physics tick elapsed since the latest one, in `[0, 1)`.
+    #[component(skip)]
+    pub alpha: f32,
+}
+
+impl PhysicsInterpolation {
+    /// Creates a component blending between the last two ticks.
+    pub fn interpolated() -> Self {
+        Self::default()
+    }
+
+    /// Creates a component predicting past the latest tick.
+    pub fn extrapolated() -> Self {
+        Self {
+            extrapolate: true,
+            ..Self::default()
+        }
+    }
+
+    /// Records the pose reached by the latest tick.
+    pub fn push(&mut self, pose: PhysicsPose) {
+        self.previous = Some(self.current.unwrap_or(pose));
+        self.current = Some(pose);
+    }
+
+    /// Forgets the recorded poses, so a teleport is not smoothed over.
+    pub fn snap(&mut self) {
+        self.previous = None;
+        self.current = None;
+    }
+
+    /// Returns the pose to render this frame.
+    pub fn sample(&self) -> Option<PhysicsPose> {
+        let current = self.current?;
+        let previous = self.previous.unwrap_or(current);
+        let alpha = self.alpha.clamp(0.0, 1.0);
+
+        Some(if self.extrapolate {
+            let delta = (current.rotation * previous.rotation.inverse()).normalize();
+            PhysicsPose {
+                translation: current.translation
+                    + (current.translation - previous.translation) * alpha,
+                rotation: (Quat::slerp(Quat::IDENTITY, delta, alpha) * current.rotation)
+                    .normalize(),
+            }
+        } else {
+            PhysicsPose {
+                translation: Vec3::lerp(previous.translation, current.translation, alpha),
+                rotation: Quat::slerp(previous.rotation, current.rotation, alpha),
+            }
+        })
+    }
+
+    /// Returns the world-space matrix moving the latest simulated pose to the
+    /// rendered one, to be applied on the left of the entity's and its
+    /// descendants' global transforms.
+    pub fn correction(&self) -> Option<Mat4> {
+        let current = self.current?;
+        let sampled = self.sample()?;
+        let to_origin = Mat4::from_quat(current.rotation.conjugate())
+            * Mat4::from_translation(-current.translation);
+        Some(sampled.to_mat4() * to_origin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pose_at(x: f32) -> PhysicsPose {
+        PhysicsPose {
+            translation: Vec3::new(x, 0.0, 0.0),
+            rotation: Quat::IDENTITY,
+        }
+    }
+
+    #[test]
+    fn interpolation_blends_the_last_two_ticks() {
+        let mut interp = PhysicsInterpolation::interpolated();
+        interp.push(pose_at(0.0));
+        interp.push(pose_at(2.0));
+        interp.alpha = 0.25;
+        let sampled = interp.sample().unwrap();
+        assert!((sampled.translation.x - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn extrapolation_predicts_past_the_latest_tick() {
+        let mut interp = PhysicsInterpolation::extrapolated();
+        interp.push(pose_at(0.0));
+        interp.push(pose_at(2.0));
+        interp.alpha = 0.5;
+        let sampled = interp.sample().unwrap();
+        assert!((sampled.translation.x - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn first_tick_and_snap_do_not_blend() {
+        let mut interp = PhysicsInterpolation::interpolated();
+        assert!(interp.sample().is_none());
+        interp.push(pose_at(4.0));
+        interp.alpha = 0.5;
+        assert!((interp.sample().unwrap().translation.x - 4.0).abs() < 1e-5);
+
+        interp.snap();
+        interp.push(pose_at(10.0));
+        assert!((interp.sample().unwrap().translation.x - 10.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn correction_moves_the_simulated_pose_to_the_sampled_one() {
+        let mut interp = PhysicsInterpolation::interpolated();
+        interp.push(pose_at(0.0));
+        interp.push(pose_at(2.0));
+        interp.alpha = 0.5;
+        let corrected = interp
+            .correction()
+            .unwrap()
+            .transform_point(Vec3::new(2.0, 1.0, 0.0));
+        assert!((corrected - Vec3::new(1.0, 1.0, 0.0)).length() < 1e-5);
+    }
+}
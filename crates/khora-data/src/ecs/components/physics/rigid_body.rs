@@ -33,6 +33,9 @@ pub struct RigidBody {
     pub linear_velocity: Vec3,
     /// Current angular velocity.
     pub angular_velocity: Vec3,
+    /// Multiplier applied to the gravity this body feels, whether it comes
+    /// from the world or from a [`GravityZone`](super::GravityZone).
+    pub gravity_scale: f32,
 }
 
 impl Default for RigidBody {
@@ -44,6 +47,7 @@ impl Default for RigidBody {
             ccd_enabled: false,
             linear_velocity: Vec3::ZERO,
             angular_velocity: Vec3::ZERO,
+            gravity_scale: 1.0,
         }
     }
 }
@@ -58,6 +62,7 @@ impl RigidBody {
             ccd_enabled: false,
             linear_velocity: Vec3::ZERO,
             angular_velocity: Vec3::ZERO,
+            gravity_scale: 1.0,
         }
     }
 
@@ -70,6 +75,13 @@ impl RigidBody {
             ccd_enabled: false,
             linear_velocity: Vec3::ZERO,
             angular_velocity: Vec3::ZERO,
+            gravity_scale: 1.0,
         }
     }
+
+    /// Sets the gravity multiplier of this body.
+    pub fn with_gravity_scale(mut self, gravity_scale: f32) -> Self {
+        self.gravity_scale = gravity_scale;
+        self
+    }
 }
@@ -0,0 +1,124 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Post-processing stack of a camera.
+
+use bincode::{Decode, Encode};
+use khora_core::renderer::api::core::ToneMapping;
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+/// Glow bleeding out of the bright parts of the scene.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct Bloom {
+    /// Share of the blurred highlights added back to the scene.
+    pub intensity: f32,
+    /// Exposed luminance above which pixels start to glow.
+    pub threshold: f32,
+    /// Width of the soft transition around `threshold`; zero makes a hard
+    /// cut-off.
+    pub knee: f32,
+}
+
+impl Default for Bloom {
+    fn default() -> Self {
+        Self {
+            intensity: 0.1,
+            threshold: 1.0,
+            knee: 0.5,
+        }
+    }
+}
+
+/// Darkening of the image toward its corners.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Encode, Decode)]
+pub struct Vignette {
+    /// Darkening at the corners, in `[0, 1]`.
+    pub intensity: f32,
+    /// Distance from the center, as a share of the half-diagonal, where the
+    /// darkening starts.
+    pub radius: f32,
+    /// Distance over which the darkening fades in.
+    pub smoothness: f32,
+}
+
+impl Default for Vignette {
+    fn default() -> Self {
+        Self {
+            intensity: 0.3,
+            radius: 0.6,
+            smoothness: 0.4,
+        }
+    }
+}
+
+/// Post-processes the HDR scene of the camera it is on.
+///
+/// The scene is rendered into an HDR target; bloom is added to it, then it
+/// is tone-mapped, vignetted and gamma-corrected into the final image. The
+/// render agent drops bloom when its budget runs short. Only the first
+/// active camera's `PostProcess` is used.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct PostProcess {
+    /// Operator mapping the exposed scene into display range.
+    pub tone_mapping: ToneMapping,
+    /// Gamma the tone-mapped image is encoded with.
+    pub gamma: f32,
+    /// Bloom settings; `None` disables it.
+    pub bloom: Option<Bloom>,
+    /// Vignette settings; `None` disables it.
+    pub vignette: Option<Vignette>,
+}
+
+impl Default for PostProcess {
+    fn default() -> Self {
+        Self {
+            tone_mapping: ToneMapping::default(),
+            gamma: 2.2,
+            bloom: Some(Bloom::default()),
+            vignette: None,
+        }
+    }
+}
+
+impl PostProcess {
+    /// Sets the tone mapping operator.
+    pub fn with_tone_mapping(mut self, tone_mapping: ToneMapping) -> Self {
+        self.tone_mapping = tone_mapping;
+        self
+    }
+
+    /// Sets the gamma the final image is encoded with.
+    pub fn with_gamma(mut self, gamma: f32) -> Self {
+        self.gamma = gamma.max(0.1);
+        self
+    }
+
+    /// Enables bloom with `bloom`'s settings.
+    pub fn with_bloom(mut self, bloom: Bloom) -> Self {
+        self.bloom = Some(bloom);
+        self
+    }
+
+    /// Disables bloom.
+    pub fn without_bloom(mut self) -> Self {
+        self.bloom = None;
+        self
+    }
+
+    /// Enables the vignette with `vignette`'s settings.
+    pub fn with_vignette(mut self, vignette: Vignette) -> Self {
+        self.vignette = Some(vignette);
+        self
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Render layer bitmasks.
+
+use khora_macros::Component;
+
+/// A 32-bit set of render layers.
+///
+/// Meshes, lights and cameras each carry a mask. A camera draws a mesh, and a
+/// light illuminates or shadows it, only when their masks share a layer.
+/// A mesh without this component sits on [`RenderLayers::DEFAULT`] (layer 0);
+/// a camera or light without it sees [`RenderLayers::ALL`].
+///
+/// Typical uses: a first-person weapon on its own layer, hidden from the
+/// world camera with `ALL.without(1)`, or a camera that only sees a UI layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub struct RenderLayers(pub u32);
+
+impl RenderLayers {
+    /// Layer 0 only — the layer every entity is on by default.
+    pub const DEFAULT: Self = Self(1);
+    /// Every layer.
+    pub const ALL: Self = Self(u32::MAX);
+    /// No layer; the entity is never drawn or lit.
+    pub const NONE: Self = Self(0);
+
+    /// A mask containing only `layer` (0..32).
+    pub const fn layer(layer: u8) -> Self {
+        Self(1 << (layer % 32))
+    }
+
+    /// Returns this mask with `layer` added.
+    pub const fn with(self, layer: u8) -> Self {
+        Self(self.0 | Self::layer(layer).0)
+    }
+
+    /// Returns this mask with `layer` removed.
+    pub const fn without(self, layer: u8) -> Self {
+        Self(self.0 & !Self::layer(layer).0)
+    }
+
+    /// Returns `true` if `layer` is in this mask.
+    pub const fn contains(self, layer: u8) -> bool {
+        self.0 & Self::layer(layer).0 != 0
+    }
+
+    /// Returns `true` if the two masks share at least one layer.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl Default for RenderLayers {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_layer_zero() {
+        assert_eq!(RenderLayers::default(), RenderLayers::layer(0));
+        assert!(RenderLayers::default().contains(0));
+        assert!(!RenderLayers::default().contains(1));
+    }
+
+    #[test]
+    fn with_and_without_toggle_layers() {
+        let mask = RenderLayers::NONE.with(3).with(5).without(3);
+        assert!(!mask.contains(3));
+        assert!(mask.contains(5));
+    }
+
+    #[test]
+    fn intersection_decides_visibility() {
+        let weapon = RenderLayers::layer(1);
+        assert!(!RenderLayers::DEFAULT.intersects(weapon));
+        assert!(RenderLayers::DEFAULT.with(1).intersects(weapon));
+        assert!(RenderLayers::ALL.intersects(weapon));
+        assert!(!RenderLayers::NONE.intersects(RenderLayers::ALL));
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Trigger volumes and interactables firing named events.
+
+use khora_macros::Component;
+
+/// Fires named [`TriggerEvent`](crate::ecs::TriggerEvent)s when something
+/// enters or leaves this entity's collider, or interacts with it.
+///
+/// Enter and exit events need a sensor [`Collider`](crate::ecs::Collider)
+/// on the same entity; interaction only needs a `GlobalTransform`. Names
+/// are plain strings saved with the scene, matched against the callbacks
+/// registered with [`World::on_trigger`](crate::ecs::World::on_trigger).
+/// An event without a name is not fired.
+///
+/// ```rust,ignore
+/// world.spawn((
+///     Transform::from_translation(Vec3::new(0.0, 0.0, -10.0)),
+///     GlobalTransform::identity(),
+///     Collider { is_sensor: true, ..Collider::new_box(Vec3::splat(2.0)) },
+///     SceneTrigger::on_enter("boss_intro").once(),
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct SceneTrigger {
+    /// Event fired when an entity enters the volume.
+    pub on_enter: Option<String>,
+    /// Event fired when an entity leaves the volume.
+    pub on_exit: Option<String>,
+    /// Event fired when an [`Interactor`](crate::ecs::Interactor) in range
+    /// presses its key.
+    pub on_interact: Option<String>,
+    /// Distance from which an interactor reaches the trigger.
+    pub interact_radius: f32,
+    /// Whether the trigger stops after firing its first event.
+    pub once: bool,
+    /// Whether a `once` trigger already fired.
+    #[component(skip)]
+    pub fired: bool,
+}
+
+impl Default for SceneTrigger {
+    fn default() -> Self {
+        Self {
+            on_enter: None,
+            on_exit: None,
+            on_interact: None,
+            interact_radius: 2.0,
+            once: false,
+            fired: false,
+        }
+    }
+}
+
+impl SceneTrigger {
+    /// A trigger firing `name` when something enters it.
+    pub fn on_enter(name: impl Into<String>) -> Self {
+        Self {
+            on_enter: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A trigger firing `name` when an interactor in range uses it.
+    pub fn on_interact(name: impl Into<String>) -> Self {
+        Self {
+            on_interact: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Also fires `name` when something leaves the volume.
+    pub fn with_exit(mut self, name: impl Into<String>) -> Self {
+        self.on_exit = Some(name.into());
+        self
+    }
+
+    /// Sets the distance from which interactors reach the trigger.
+    pub fn with_interact_radius(mut self, radius: f32) -> Self {
+        self.interact_radius = radius;
+        self
+    }
+
+    /// Stops the trigger after its first event.
+    pub fn once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+
+    /// Returns the event name authored for `kind`.
+    pub fn event_name(&self, kind: crate::ecs::TriggerKind) -> Option<&str> {
+        use crate::ecs::TriggerKind;
+        match kind {
+            TriggerKind::Enter => self.on_enter.as_deref(),
+            TriggerKind::Exit => self.on_exit.as_deref(),
+            TriggerKind::Interact => self.on_interact.as_deref(),
+        }
+    }
+}
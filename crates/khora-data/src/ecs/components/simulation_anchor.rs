@@ -0,0 +1,36 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entities that keep the simulation around them at full rate.
+
+use khora_macros::Component;
+
+/// Marks an entity, typically a player, around which the simulation runs at
+/// full rate.
+///
+/// Simulation LOD measures every simulated entity's distance to the nearest
+/// anchor: active cameras are anchors too. Entities far from all of them
+/// are updated less often, then put to sleep.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct SimulationAnchor {
+    /// Multiplies the LOD distances around this anchor. Above `1.0` the
+    /// anchor keeps entities active further away.
+    pub range_scale: f32,
+}
+
+impl Default for SimulationAnchor {
+    fn default() -> Self {
+        Self { range_scale: 1.0 }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-entity simulation level of detail.
+
+use khora_macros::Component;
+
+/// How often an entity is simulated, from its distance to the nearest
+/// [`SimulationAnchor`](crate::ecs::SimulationAnchor) or active camera.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SimulationBand {
+    /// Close to an anchor: updated every frame.
+    #[default]
+    Full,
+    /// Further away: updated every few frames with the accumulated time.
+    Reduced,
+    /// Out of range: physics bodies sleep, animations pause, AI should not
+    /// run.
+    Dormant,
+}
+
+/// The simulation band of an entity, kept up to date by the engine.
+///
+/// `SimulationLodFlow` adds it to every entity with a rigid body, an
+/// animation player or an animator, and updates entities that carry it for
+/// other reasons, such as game AI. Systems that update an entity ask
+/// [`delta`](Self::delta) for the time to simulate this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[component(no_serializable)]
+pub struct SimulationLod {
+    /// The entity's current band.
+    pub band: SimulationBand,
+    /// Distance to the nearest anchor, divided by its range scale.
+    pub distance: f32,
+    /// Frames between two updates in the [`SimulationBand::Reduced`] band.
+    pub(crate) interval: u32,
+    /// Frame counter, offset per entity to spread reduced updates.
+    pub(crate) frame: u32,
+}
+
+impl SimulationLod {
+    /// Returns `true` if the entity is updated this frame.
+    pub fn ticks(&self) -> bool {
+        match self.band {
+            SimulationBand::Full => true,
+            SimulationBand::Reduced => self.frame.is_multiple_of(self.interval.max(1)),
+            SimulationBand::Dormant => false,
+        }
+    }
+
+    /// Returns the time to simulate this frame given the frame's `delta`,
+    /// or `None` if the entity skips this frame. A reduced entity catches up
+    /// on the frames it skipped.
+    pub fn delta(&self, delta: f32) -> Option<f32> {
+        if !self.ticks() {
+            return None;
+        }
+        match self.band {
+            SimulationBand::Reduced => Some(delta * self.interval.max(1) as f32),
+            _ => Some(delta),
+        }
+    }
+
+    /// [`delta`](Self::delta) for an entity that may not carry the
+    /// component, which is then always simulated.
+    pub fn delta_of(lod: Option<&SimulationLod>, delta: f32) -> Option<f32> {
+        lod.map_or(Some(delta), |lod| lod.delta(delta))
+    }
+
+    /// Returns `true` in the [`SimulationBand::Dormant`] band.
+    pub fn is_dormant(&self) -> bool {
+        self.band == SimulationBand::Dormant
+    }
+}
+
+impl Default for SimulationLod {
+    fn default() -> Self {
+        Self {
+            band: SimulationBand::Full,
+            distance: 0.0,
+            interval: 1,
+            frame: 0,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Skeletal skinning for meshes with joint influences.
+
+use khora_core::asset::AssetUUID;
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Mat4;
+use khora_core::renderer::api::resource::BufferId;
+use khora_core::renderer::api::scene::{SkinVertexLayout, SkinningMode};
+use khora_macros::Component;
+
+/// Binds the entity's mesh to a skeleton of joint entities.
+///
+/// `joints[i]` drives the vertices whose influences reference joint `i`.
+/// Joint poses are read from the joints' `GlobalTransform` every frame by
+/// the `skin_sync` data system, so anything that moves the joint entities
+/// (the animator, IK, gameplay code) deforms the mesh.
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+pub struct Skin {
+    /// Joint entities, in the order the mesh's influences index them.
+    pub joints: Vec<EntityId>,
+    /// Per-joint transform from mesh space to the joint's bind-pose space.
+    pub inverse_bind_matrices: Vec<Mat4>,
+    /// Forces a skinning mode. `None` picks one each frame from the number
+    /// of passes the mesh is drawn in.
+    pub mode: Option<SkinningMode>,
+}
+
+impl Skin {
+    /// Creates a skin over `joints` with their inverse bind matrices.
+    pub fn new(joints: Vec<EntityId>, inverse_bind_matrices: Vec<Mat4>) -> Self {
+        Self {
+            joints,
+            inverse_bind_matrices,
+            mode: None,
+        }
+    }
+
+    /// Forces `mode` instead of choosing it from the pass count.
+    pub fn with_mode(mut self, mode: SkinningMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+}
+
+/// Tracks the GPU buffers backing a [`Skin`].
+///
+/// Managed by `skin_sync`; not serialized.
+#[derive(Debug, Clone, PartialEq, Component)]
+#[component(no_serializable)]
+pub struct SkinnedMesh {
+    /// Cache key of the skinned output mesh, unique to this entity.
+    pub uuid: AssetUUID,
+    /// Bind-pose vertices, readable as a storage buffer.
+    pub bind_pose: BufferId,
+    /// Per-vertex [`SkinInfluence`](khora_core::renderer::api::scene::SkinInfluence)s.
+    pub influences: BufferId,
+    /// Joint matrices, rewritten every frame.
+    pub joint_matrices: BufferId,
+    /// Number of matrices `joint_matrices` holds.
+    pub joint_capacity: u32,
+    /// Layout of the bind-pose and output vertex buffers.
+    pub layout: SkinVertexLayout,
+}
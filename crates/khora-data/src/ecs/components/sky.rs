@@ -0,0 +1,43 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Background color of the scene.
+
+use khora_core::math::LinearRgba;
+use khora_macros::Component;
+
+/// The color the scene is drawn over, replacing the renderer's clear color.
+///
+/// Only the first visible `Sky` is used. A [`TimeOfDay`](crate::ecs::TimeOfDay)
+/// on the same entity drives it.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct Sky {
+    /// Background color.
+    pub color: LinearRgba,
+}
+
+impl Default for Sky {
+    fn default() -> Self {
+        Self {
+            color: LinearRgba::new(0.1, 0.1, 0.15, 1.0),
+        }
+    }
+}
+
+impl Sky {
+    /// Creates a sky of the given color.
+    pub fn new(color: LinearRgba) -> Self {
+        Self { color }
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Authored paths for entities to follow.
+
+use khora_core::animation::Spline;
+use khora_macros::Component;
+
+/// A spline placed in the world by this entity's transform.
+///
+/// Control points are in the entity's local space, so moving the entity
+/// moves the whole path. [`PathFollower`](super::PathFollower)s reference
+/// the entity to travel along it; one path can serve many followers
+/// (moving platforms on a rail, a patrol route shared by guards).
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+pub struct SplinePath {
+    /// The path, in local space.
+    pub spline: Spline,
+}
+
+impl SplinePath {
+    /// Wraps `spline`.
+    pub fn new(spline: Spline) -> Self {
+        Self { spline }
+    }
+}
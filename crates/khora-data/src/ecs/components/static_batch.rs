@@ -0,0 +1,32 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merged meshes of baked static entities.
+
+use khora_core::ecs::entity::EntityId;
+use khora_macros::Component;
+
+/// An entity created by [`bake_static`](crate::scene::bake_static) to draw
+/// the merged meshes of several [`Static`](crate::ecs::Static) entities.
+///
+/// It carries the combined mesh, already in world space, with the shared
+/// material and an identity transform. The members keep their own meshes
+/// for [`unbake_static`](crate::scene::unbake_static) but are no longer
+/// extracted for rendering. Runtime data: scene saving skips batch entities.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Component)]
+#[component(no_serializable)]
+pub struct StaticBatch {
+    /// The static entities whose meshes were merged into this one.
+    pub members: Vec<EntityId>,
+}
@@ -0,0 +1,74 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Marker for level geometry that never moves.
+
+use khora_core::ecs::entity::EntityId;
+use khora_macros::Component;
+
+/// Marks an entity as static: it will not move once the level is loaded.
+///
+/// Static entities are only special after a bake
+/// ([`bake_static`](crate::scene::bake_static)): their `GlobalTransform` is
+/// then frozen and `transform_propagation` stops recomputing it, and meshes
+/// sharing a material are merged into [`StaticBatch`](crate::ecs::StaticBatch)
+/// entities drawn in one call. Moving a baked entity has no visible effect
+/// until [`unbake_static`](crate::scene::unbake_static) runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct Static {
+    /// Whether the mesh may be merged into a static batch. Turn it off for
+    /// meshes that must stay separate draws, e.g. to change their material.
+    pub batch: bool,
+    /// Set by the bake; the transform is frozen while it is.
+    #[component(skip)]
+    pub(crate) baked: bool,
+    /// The batch entity drawing this mesh, if it was merged into one.
+    #[component(skip)]
+    pub(crate) batched_into: Option<EntityId>,
+}
+
+impl Static {
+    /// A static entity whose mesh may be batched.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A static entity whose mesh is never merged into a batch.
+    pub fn unbatched() -> Self {
+        Self {
+            batch: false,
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` once the entity has been baked.
+    pub fn is_baked(&self) -> bool {
+        self.baked
+    }
+
+    /// Returns the batch entity drawing this entity's mesh, if any.
+    pub fn batched_into(&self) -> Option<EntityId> {
+        self.batched_into
+    }
+}
+
+impl Default for Static {
+    fn default() -> Self {
+        Self {
+            batch: true,
+            baked: false,
+            batched_into: None,
+        }
+    }
+}
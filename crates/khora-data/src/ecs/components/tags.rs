@@ -0,0 +1,88 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! A small set of tags on an entity.
+
+use khora_macros::Component;
+
+use crate::ecs::Tag;
+
+/// The [`Tag`]s of an entity, such as `"enemy"` or `"interactable"`.
+///
+/// Holds each tag once, in insertion order. Sets are expected to be a
+/// handful of tags, so lookups scan them. Find tagged entities with
+/// [`World::query_tagged`](crate::ecs::World::query_tagged).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Component)]
+pub struct Tags(Vec<Tag>);
+
+impl Tags {
+    /// Creates a set holding `tags`.
+    pub fn new<'a>(tags: impl IntoIterator<Item = &'a str>) -> Self {
+        tags.into_iter().map(Tag::new).collect()
+    }
+
+    /// Adds `tag`. Returns `false` if it was already there.
+    pub fn insert(&mut self, tag: impl Into<Tag>) -> bool {
+        let tag = tag.into();
+        if self.has(tag) {
+            return false;
+        }
+        self.0.push(tag);
+        true
+    }
+
+    /// Removes `tag`. Returns `false` if it was not there.
+    pub fn remove(&mut self, tag: &str) -> bool {
+        let Some(tag) = Tag::get(tag) else {
+            return false;
+        };
+        let len = self.0.len();
+        self.0.retain(|&t| t != tag);
+        self.0.len() != len
+    }
+
+    /// Returns `true` if the set holds `tag`.
+    pub fn contains(&self, tag: &str) -> bool {
+        Tag::get(tag).is_some_and(|tag| self.has(tag))
+    }
+
+    /// Returns `true` if the set holds `tag`.
+    pub fn has(&self, tag: Tag) -> bool {
+        self.0.contains(&tag)
+    }
+
+    /// Iterates over the tags.
+    pub fn iter(&self) -> impl Iterator<Item = Tag> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns the number of tags.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set holds no tag.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<Tag> for Tags {
+    fn from_iter<I: IntoIterator<Item = Tag>>(iter: I) -> Self {
+        let mut tags = Self::default();
+        for tag in iter {
+            tags.insert(tag);
+        }
+        tags
+    }
+}
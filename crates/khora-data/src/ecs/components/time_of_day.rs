@@ -0,0 +1,126 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Day/night cycle driving a directional light and the sky.
+
+use khora_core::animation::Curve;
+use khora_core::math::photometry::LUX_DIRECT_SUNLIGHT;
+use khora_core::math::{LinearRgba, Vec3};
+use khora_macros::Component;
+
+/// Hours in a day.
+const DAY_HOURS: f32 = 24.0;
+
+/// Drives the directional [`Light`](crate::ecs::Light) and the
+/// [`Sky`](crate::ecs::Sky) of its entity from the hour of the day.
+///
+/// Advanced every tick by the `time_of_day` data system, which turns the
+/// light along an east-to-west arc and samples the curves, all keyed by the
+/// hour (`0.0..24.0`). The arc is expressed in the entity's local space, so
+/// rotating the entity tilts it. Curves left to `None` leave the matching
+/// value untouched. A [`Weather`](crate::ecs::Weather) in the scene dims the
+/// sun and greys the sky with its cloud cover.
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct TimeOfDay {
+    /// Current hour, in `0.0..24.0`.
+    pub hour: f32,
+    /// Real seconds for a full day. Zero freezes the clock.
+    pub day_length: f32,
+    /// Whether the clock advances.
+    pub playing: bool,
+    /// Sun color by hour.
+    pub sun_color: Option<Curve<LinearRgba>>,
+    /// Sun illuminance by hour, in lux.
+    pub sun_intensity: Option<Curve<f32>>,
+    /// Sky color by hour.
+    pub sky_color: Option<Curve<LinearRgba>>,
+}
+
+impl Default for TimeOfDay {
+    /// Noon of a twenty-minute day, with a clear-sky cycle of curves.
+    fn default() -> Self {
+        let night = LinearRgba::rgb(0.3, 0.35, 0.5);
+        let low_sun = LinearRgba::rgb(1.0, 0.5, 0.25);
+        let day_sun = LinearRgba::rgb(1.0, 0.95, 0.85);
+        let dark_sky = LinearRgba::rgb(0.01, 0.01, 0.03);
+        let dawn_sky = LinearRgba::rgb(0.6, 0.4, 0.3);
+        let day_sky = LinearRgba::rgb(0.35, 0.55, 0.85);
+        Self {
+            hour: 12.0,
+            day_length: 1200.0,
+            playing: true,
+            sun_color: Some(Curve::new([
+                (0.0, night),
+                (5.5, low_sun),
+                (8.0, day_sun),
+                (16.0, day_sun),
+                (18.5, low_sun),
+                (20.0, night),
+                (DAY_HOURS, night),
+            ])),
+            sun_intensity: Some(Curve::new([
+                (0.0, 0.0),
+                (5.0, 0.0),
+                (6.5, 0.6 * LUX_DIRECT_SUNLIGHT),
+                (9.0, LUX_DIRECT_SUNLIGHT),
+                (15.0, LUX_DIRECT_SUNLIGHT),
+                (17.5, 0.6 * LUX_DIRECT_SUNLIGHT),
+                (19.0, 0.0),
+                (DAY_HOURS, 0.0),
+            ])),
+            sky_color: Some(Curve::new([
+                (0.0, dark_sky),
+                (5.0, dark_sky),
+                (6.5, dawn_sky),
+                (9.0, day_sky),
+                (16.0, day_sky),
+                (18.5, dawn_sky),
+                (20.0, dark_sky),
+                (DAY_HOURS, dark_sky),
+            ])),
+        }
+    }
+}
+
+impl TimeOfDay {
+    /// Creates the default cycle, stopped at `hour`.
+    pub fn at(hour: f32) -> Self {
+        Self {
+            hour: hour.rem_euclid(DAY_HOURS),
+            playing: false,
+            ..Default::default()
+        }
+    }
+
+    /// Advances the clock by `delta_seconds` of real time, wrapping at
+    /// midnight.
+    pub fn advance(&mut self, delta_seconds: f32) {
+        if !self.playing || self.day_length <= 0.0 {
+            return;
+        }
+        self.hour = (self.hour + delta_seconds * DAY_HOURS / self.day_length).rem_euclid(DAY_HOURS);
+    }
+
+    /// Direction the sunlight travels, in the entity's local space: straight
+    /// down at noon, from `+X` at 6:00 and from `-X` at 18:00.
+    pub fn sun_direction(&self) -> Vec3 {
+        let angle = (self.hour / DAY_HOURS - 0.5) * std::f32::consts::TAU;
+        Vec3::new(angle.sin(), -angle.cos(), 0.0)
+    }
+
+    /// Returns `true` while the sun is above the horizon.
+    pub fn is_day(&self) -> bool {
+        self.sun_direction().y < 0.0
+    }
+}
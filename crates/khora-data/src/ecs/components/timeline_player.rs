@@ -0,0 +1,133 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Plays a [`Timeline`] cutscene.
+
+use std::collections::HashMap;
+
+use khora_core::animation::Timeline;
+use khora_core::asset::AssetHandle;
+use khora_core::ecs::entity::EntityId;
+use khora_macros::Component;
+
+/// Plays a [`Timeline`], driving the entities its tracks are bound to.
+///
+/// The timeline lane advances [`time`](Self::time) while
+/// [`playing`](Self::playing), writes animation tracks into the bound
+/// entities, activates the camera of the current cut, posts sound events
+/// and queues callbacks for gameplay code to drain with
+/// [`take_callbacks`](Self::take_callbacks).
+///
+/// Track bindings resolve through [`bindings`](Self::bindings) first, then
+/// to any entity whose `Name` matches.
+#[derive(Debug, Clone, Component)]
+pub struct TimelinePlayer {
+    /// The timeline being played. Not serialized; rebind after loading a scene.
+    #[component(skip)]
+    pub timeline: Option<AssetHandle<Timeline>>,
+    /// Explicit binding name → entity assignments.
+    pub bindings: HashMap<String, EntityId>,
+    /// Playback position, in seconds.
+    pub time: f32,
+    /// Playback rate multiplier.
+    pub speed: f32,
+    /// Restart from the beginning once the end is reached.
+    pub looping: bool,
+    /// Whether the timeline is advancing.
+    pub playing: bool,
+    /// Position at the previous evaluation; `None` before the first one,
+    /// so markers at time zero fire.
+    #[component(skip)]
+    pub last_time: Option<f32>,
+    /// The camera the last cut activated.
+    #[component(skip)]
+    pub active_camera: Option<EntityId>,
+    /// Cameras deactivated by the first cut, reactivated when the timeline
+    /// finishes or is stopped.
+    #[component(skip)]
+    pub suspended_cameras: Vec<EntityId>,
+    /// Callbacks crossed since the last [`take_callbacks`](Self::take_callbacks).
+    #[component(skip)]
+    pub callbacks: Vec<String>,
+}
+
+impl Default for TimelinePlayer {
+    fn default() -> Self {
+        Self {
+            timeline: None,
+            bindings: HashMap::new(),
+            time: 0.0,
+            speed: 1.0,
+            looping: false,
+            playing: false,
+            last_time: None,
+            active_camera: None,
+            suspended_cameras: Vec::new(),
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+impl TimelinePlayer {
+    /// Creates a player for `timeline`, starting immediately.
+    pub fn new(timeline: AssetHandle<Timeline>) -> Self {
+        Self {
+            timeline: Some(timeline),
+            playing: true,
+            ..Default::default()
+        }
+    }
+
+    /// Binds the track binding `name` to `entity`.
+    pub fn with_binding(mut self, name: impl Into<String>, entity: EntityId) -> Self {
+        self.bindings.insert(name.into(), entity);
+        self
+    }
+
+    /// Resumes playback.
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    /// Pauses playback at the current time.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Jumps to `time` without firing the markers in between.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.max(0.0);
+        self.last_time = Some(self.time);
+    }
+
+    /// Stops playback and rewinds to the start.
+    pub fn stop(&mut self) {
+        self.playing = false;
+        self.time = 0.0;
+        self.last_time = None;
+    }
+
+    /// Returns `true` once a non-looping timeline has reached its end.
+    pub fn is_finished(&self) -> bool {
+        !self.looping
+            && self
+                .timeline
+                .as_ref()
+                .is_some_and(|timeline| self.time >= timeline.duration())
+    }
+
+    /// Drains the callbacks crossed since the last call, in time order.
+    pub fn take_callbacks(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.callbacks)
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weather state of the scene.
+
+use bincode::{Decode, Encode};
+use khora_macros::Component;
+use serde::{Deserialize, Serialize};
+
+/// A weather preset, blended towards by [`Weather`].
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode,
+)]
+#[non_exhaustive]
+pub enum WeatherState {
+    /// No rain, a few clouds.
+    #[default]
+    Clear,
+    /// Overcast, no rain.
+    Cloudy,
+    /// Steady rain.
+    Rain,
+    /// Heavy rain under a closed sky.
+    Storm,
+}
+
+impl WeatherState {
+    /// Rain intensity of the preset, in `0.0..=1.0`.
+    pub fn rain(self) -> f32 {
+        match self {
+            WeatherState::Clear | WeatherState::Cloudy => 0.0,
+            WeatherState::Rain => 0.6,
+            WeatherState::Storm => 1.0,
+        }
+    }
+
+    /// Cloud cover of the preset, in `0.0..=1.0`.
+    pub fn cloud_cover(self) -> f32 {
+        match self {
+            WeatherState::Clear => 0.1,
+            WeatherState::Cloudy => 0.7,
+            WeatherState::Rain => 0.9,
+            WeatherState::Storm => 1.0,
+        }
+    }
+}
+
+/// The weather of the scene. Only the first `Weather` in the world is used.
+///
+/// The `weather` data system blends `rain` and `cloud_cover` towards the
+/// values of `state` over `transition` seconds, and drives every
+/// [`WeatherAudio`](crate::ecs::WeatherAudio) source with the rain. Cloud
+/// cover dims the sun of a [`TimeOfDay`](crate::ecs::TimeOfDay). Game code,
+/// such as rain particles, reads the current values.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct Weather {
+    /// The preset being blended towards.
+    pub state: WeatherState,
+    /// Current rain intensity, in `0.0..=1.0`.
+    pub rain: f32,
+    /// Current cloud cover, in `0.0..=1.0`.
+    pub cloud_cover: f32,
+    /// Seconds to go from one preset to another.
+    pub transition: f32,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self::new(WeatherState::Clear)
+    }
+}
+
+impl Weather {
+    /// Creates a weather settled in `state`.
+    pub fn new(state: WeatherState) -> Self {
+        Self {
+            state,
+            rain: state.rain(),
+            cloud_cover: state.cloud_cover(),
+            transition: 30.0,
+        }
+    }
+
+    /// Starts blending towards `state`.
+    pub fn set(&mut self, state: WeatherState) {
+        self.state = state;
+    }
+
+    /// Blends the current values towards the preset by `delta_seconds`.
+    pub fn advance(&mut self, delta_seconds: f32) {
+        let step = if self.transition > 0.0 {
+            delta_seconds / self.transition
+        } else {
+            1.0
+        };
+        self.rain = approach(self.rain, self.state.rain(), step);
+        self.cloud_cover = approach(self.cloud_cover, self.state.cloud_cover(), step);
+    }
+}
+
+/// Moves `value` towards `target` by at most `step`.
+fn approach(value: f32, target: f32, step: f32) -> f32 {
+    value + (target - value).clamp(-step, step)
+}
@@ -0,0 +1,32 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audio driven by the weather.
+
+use khora_macros::Component;
+
+/// Sets the [`AudioSource`](crate::ecs::AudioSource) volume of its entity
+/// from the rain intensity of the [`Weather`](crate::ecs::Weather), for rain
+/// and wind loops.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct WeatherAudio {
+    /// Volume at full rain intensity.
+    pub volume: f32,
+}
+
+impl Default for WeatherAudio {
+    fn default() -> Self {
+        Self { volume: 1.0 }
+    }
+}
@@ -0,0 +1,485 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dynamic components: components defined at runtime by a layout descriptor.
+//!
+//! Scripting and modding define component schemas in data, so they have no
+//! Rust type to hand to [`World::add_component`]. A dynamic component is
+//! registered once per process from a [`DynamicComponentLayout`] and gets a
+//! [`DynamicComponentId`]. Its rows live in the pages of its domain like any
+//! other component, in a byte column, so archetype signatures, compaction,
+//! snapshots and archetype serialization handle it unchanged.
+//!
+//! Pages key their columns by `TypeId`, so each registered component is
+//! bound to one of [`MAX_DYNAMIC_COMPONENTS`] marker types
+//! (`DynamicSlot<N>`) that lend it a distinct `TypeId`.
+
+use std::any::TypeId;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use khora_core::ecs::entity::EntityId;
+
+use super::dynamic_column::DynamicColumn;
+use super::dynamic_layout::{DynamicComponentLayout, DynamicFieldKind, DynamicValue};
+use super::{AddComponentError, AnyVec, PageIndex, RemoveComponentError, SemanticDomain, World};
+
+/// The number of dynamic components a process can register.
+pub const MAX_DYNAMIC_COMPONENTS: usize = 256;
+
+/// An error raised when defining or editing a dynamic component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DynamicComponentError {
+    /// Two fields of a layout share this name.
+    DuplicateField(String),
+    /// A component with this name is already registered with another layout
+    /// or domain.
+    LayoutConflict(String),
+    /// All [`MAX_DYNAMIC_COMPONENTS`] slots are taken.
+    TooManyComponents,
+    /// The layout has no field with this name.
+    UnknownField(String),
+    /// The value does not have the field's type.
+    FieldTypeMismatch {
+        /// Name of the field.
+        field: String,
+        /// Type of the field.
+        expected: DynamicFieldKind,
+        /// Type of the rejected value.
+        found: DynamicFieldKind,
+    },
+}
+
+impl fmt::Display for DynamicComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicComponentError::DuplicateField(name) => {
+                write!(f, "Duplicate dynamic component field '{name}'")
+            }
+            DynamicComponentError::LayoutConflict(name) => {
+                write!(
+                    f,
+                    "Dynamic component '{name}' is already registered differently"
+                )
+            }
+            DynamicComponentError::TooManyComponents => write!(
+                f,
+                "At most {MAX_DYNAMIC_COMPONENTS} dynamic components can be registered"
+            ),
+            DynamicComponentError::UnknownField(name) => {
+                write!(f, "Unknown dynamic component field '{name}'")
+            }
+            DynamicComponentError::FieldTypeMismatch {
+                field,
+                expected,
+                found,
+            } => write!(f, "Field '{field}' is {expected:?}, got {found:?}"),
+        }
+    }
+}
+
+impl std::error::Error for DynamicComponentError {}
+
+/// A registered dynamic component.
+struct DynamicComponentInfo {
+    layout: Arc<DynamicComponentLayout>,
+    domain: SemanticDomain,
+}
+
+/// Every dynamic component registered in the process, indexed by slot.
+static DYNAMIC_COMPONENTS: RwLock<Vec<DynamicComponentInfo>> = RwLock::new(Vec::new());
+
+/// Marker type lending its `TypeId` to the dynamic component in slot `N`.
+struct DynamicSlot<const N: usize>;
+
+/// The monomorphized functions of one slot.
+struct SlotFns {
+    type_id: fn() -> TypeId,
+    create_column: fn() -> Box<dyn AnyVec>,
+}
+
+fn slot_type_id<const N: usize>() -> TypeId {
+    TypeId::of::<DynamicSlot<N>>()
+}
+
+fn slot_column<const N: usize>() -> Box<dyn AnyVec> {
+    let components = DYNAMIC_COMPONENTS.read().unwrap_or_else(|e| e.into_inner());
+    let layout = &components[N].layout;
+    Box::new(DynamicColumn::new(layout.size(), layout.align()))
+}
+
+macro_rules! slot_row {
+    ($hi:literal) => {
+        slot_row!(@ $hi; 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15)
+    };
+    (@ $hi:literal; $($lo:literal)*) => {
+        [$(SlotFns {
+            type_id: slot_type_id::<{ $hi * 16 + $lo }>,
+            create_column: slot_column::<{ $hi * 16 + $lo }>,
+        }),*]
+    };
+}
+
+static SLOTS: [[SlotFns; 16]; 16] = [
+    slot_row!(0),
+    slot_row!(1),
+    slot_row!(2),
+    slot_row!(3),
+    slot_row!(4),
+    slot_row!(5),
+    slot_row!(6),
+    slot_row!(7),
+    slot_row!(8),
+    slot_row!(9),
+    slot_row!(10),
+    slot_row!(11),
+    slot_row!(12),
+    slot_row!(13),
+    slot_row!(14),
+    slot_row!(15),
+];
+
+/// Identifies a dynamic component registered in this process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DynamicComponentId(u16);
+
+impl DynamicComponentId {
+    /// Registers a dynamic component stored in the pages of `domain`.
+    ///
+    /// Registering the same name again with the same layout and domain
+    /// returns the existing ID, so every mod or script can declare the
+    /// components it uses.
+    pub fn register(
+        layout: DynamicComponentLayout,
+        domain: SemanticDomain,
+    ) -> Result<Self, DynamicComponentError> {
+        let mut components = DYNAMIC_COMPONENTS
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(index) = components
+            .iter()
+            .position(|c| c.layout.name() == layout.name())
+        {
+            let existing = &components[index];
+            return if *existing.layout == layout && existing.domain == domain {
+                Ok(Self(index as u16))
+            } else {
+                Err(DynamicComponentError::LayoutConflict(
+                    layout.name().to_string(),
+                ))
+            };
+        }
+        if components.len() >= MAX_DYNAMIC_COMPONENTS {
+            return Err(DynamicComponentError::TooManyComponents);
+        }
+        components.push(DynamicComponentInfo {
+            layout: Arc::new(layout),
+            domain,
+        });
+        Ok(Self((components.len() - 1) as u16))
+    }
+
+    /// Returns the ID of the dynamic component called `name`.
+    pub fn find(name: &str) -> Option<Self> {
+        let components = DYNAMIC_COMPONENTS.read().unwrap_or_else(|e| e.into_inner());
+        components
+            .iter()
+            .position(|c| c.layout.name() == name)
+            .map(|index| Self(index as u16))
+    }
+
+    /// Returns the layout of the component.
+    pub fn layout(self) -> Arc<DynamicComponentLayout> {
+        let components = DYNAMIC_COMPONENTS.read().unwrap_or_else(|e| e.into_inner());
+        components[self.0 as usize].layout.clone()
+    }
+
+    /// Returns the semantic domain whose pages store the component.
+    pub fn domain(self) -> SemanticDomain {
+        let components = DYNAMIC_COMPONENTS.read().unwrap_or_else(|e| e.into_inner());
+        components[self.0 as usize].domain
+    }
+
+    /// Returns the `TypeId` the component's columns are keyed by.
+    pub fn component_type_id(self) -> TypeId {
+        let slot = self.0 as usize;
+        (SLOTS[slot / 16][slot % 16].type_id)()
+    }
+
+    /// Returns the name the component is registered under in the world's
+    /// type registry, e.g. `dynamic:Health`.
+    pub fn type_name(self) -> String {
+        format!("dynamic:{}", self.layout().name())
+    }
+
+    fn column_constructor(self) -> fn() -> Box<dyn AnyVec> {
+        let slot = self.0 as usize;
+        SLOTS[slot / 16][slot % 16].create_column
+    }
+}
+
+/// An owned value of a dynamic component, ready to be inserted.
+#[derive(Debug, Clone)]
+pub struct DynamicComponent {
+    id: DynamicComponentId,
+    layout: Arc<DynamicComponentLayout>,
+    bytes: Vec<u8>,
+}
+
+impl DynamicComponent {
+    /// Creates a value of `id` with every field zeroed.
+    pub fn new(id: DynamicComponentId) -> Self {
+        let layout = id.layout();
+        let bytes = vec![0; layout.size()];
+        Self { id, layout, bytes }
+    }
+
+    /// Sets the field `name`, builder style.
+    pub fn with(
+        mut self,
+        name: &str,
+        value: impl Into<DynamicValue>,
+    ) -> Result<Self, DynamicComponentError> {
+        self.set(name, value)?;
+        Ok(self)
+    }
+
+    /// Sets the field `name`.
+    pub fn set(
+        &mut self,
+        name: &str,
+        value: impl Into<DynamicValue>,
+    ) -> Result<(), DynamicComponentError> {
+        self.layout.write(&mut self.bytes, name, value.into())
+    }
+
+    /// Returns the field `name`.
+    pub fn get(&self, name: &str) -> Option<DynamicValue> {
+        self.layout.read(&self.bytes, name)
+    }
+
+    /// Returns the component this value belongs to.
+    pub fn id(&self) -> DynamicComponentId {
+        self.id
+    }
+}
+
+/// A borrowed dynamic component stored in a world.
+#[derive(Debug, Clone)]
+pub struct DynamicRef<'a> {
+    layout: Arc<DynamicComponentLayout>,
+    bytes: &'a [u8],
+}
+
+impl DynamicRef<'_> {
+    /// Returns the field `name`.
+    pub fn get(&self, name: &str) -> Option<DynamicValue> {
+        self.layout.read(self.bytes, name)
+    }
+
+    /// Returns the layout of the component.
+    pub fn layout(&self) -> &DynamicComponentLayout {
+        &self.layout
+    }
+
+    /// Returns the raw bytes of the row.
+    pub fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+
+    /// Copies the value of the component `id` out of the world.
+    pub fn to_component(&self, id: DynamicComponentId) -> DynamicComponent {
+        DynamicComponent {
+            id,
+            layout: self.layout.clone(),
+            bytes: self.bytes.to_vec(),
+        }
+    }
+}
+
+/// A mutably borrowed dynamic component stored in a world.
+#[derive(Debug)]
+pub struct DynamicMut<'a> {
+    layout: Arc<DynamicComponentLayout>,
+    bytes: &'a mut [u8],
+}
+
+impl DynamicMut<'_> {
+    /// Returns the field `name`.
+    pub fn get(&self, name: &str) -> Option<DynamicValue> {
+        self.layout.read(self.bytes, name)
+    }
+
+    /// Sets the field `name`.
+    pub fn set(
+        &mut self,
+        name: &str,
+        value: impl Into<DynamicValue>,
+    ) -> Result<(), DynamicComponentError> {
+        self.layout.write(self.bytes, name, value.into())
+    }
+
+    /// Returns the layout of the component.
+    pub fn layout(&self) -> &DynamicComponentLayout {
+        &self.layout
+    }
+}
+
+impl World {
+    /// Registers the dynamic component `id` in this world.
+    ///
+    /// [`insert_dynamic`](Self::insert_dynamic) registers on first use, so
+    /// this is only needed before loading data that contains the component,
+    /// such as [`deserialize_archetype`](Self::deserialize_archetype).
+    pub fn register_dynamic_component(&mut self, id: DynamicComponentId) {
+        let type_id = id.component_type_id();
+        if self.storage.registry.get_domain(type_id).is_some() {
+            return;
+        }
+        self.storage
+            .registry
+            .register_dynamic(type_id, id.domain(), id.column_constructor());
+        self.type_registry.register_named(type_id, &id.type_name());
+    }
+
+    /// Adds a dynamic component to `entity_id`, moving the entity to the
+    /// page of its new signature like [`add_component`](Self::add_component).
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub fn insert_dynamic(
+        &mut self,
+        entity_id: EntityId,
+        component: DynamicComponent,
+    ) -> Result<Option<PageIndex>, AddComponentError> {
+        if self.live_metadata(entity_id, "insert_dynamic").is_none() {
+            return Err(AddComponentError::EntityNotFound);
+        }
+        self.register_dynamic_component(component.id);
+        self.add_component_with(entity_id, component.id.component_type_id(), |column| {
+            column
+                .as_any_mut()
+                .downcast_mut::<DynamicColumn>()
+                .unwrap()
+                .push(&component.bytes);
+        })
+    }
+
+    /// Removes the dynamic component `id` from `entity_id`, like
+    /// [`remove_component`](Self::remove_component).
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub fn remove_dynamic(
+        &mut self,
+        entity_id: EntityId,
+        id: DynamicComponentId,
+    ) -> Result<Option<PageIndex>, RemoveComponentError> {
+        if self.live_metadata(entity_id, "remove_dynamic").is_none() {
+            return Err(RemoveComponentError::EntityNotFound);
+        }
+        self.remove_component_by_type(entity_id, id.component_type_id())
+    }
+
+    /// Returns the dynamic component `id` of `entity_id`.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub fn get_dynamic(
+        &self,
+        entity_id: EntityId,
+        id: DynamicComponentId,
+    ) -> Option<DynamicRef<'_>> {
+        let metadata = self.live_metadata(entity_id, "get_dynamic")?;
+        let type_id = id.component_type_id();
+        let domain = self.storage.registry.get_domain(type_id)?;
+        let location = metadata.locations.get(&domain)?;
+        let column = self.storage.pages[location.page_id as usize]
+            .columns
+            .get(&type_id)?
+            .as_any()
+            .downcast_ref::<DynamicColumn>()?;
+        Some(DynamicRef {
+            layout: id.layout(),
+            bytes: column.row(location.row_index as usize),
+        })
+    }
+
+    /// Returns the dynamic component `id` of `entity_id` for writing, and
+    /// marks it changed.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub fn get_dynamic_mut(
+        &mut self,
+        entity_id: EntityId,
+        id: DynamicComponentId,
+    ) -> Option<DynamicMut<'_>> {
+        let metadata = self.live_metadata(entity_id, "get_dynamic_mut")?;
+        let type_id = id.component_type_id();
+        let domain = self.storage.registry.get_domain(type_id)?;
+        let location = *metadata.locations.get(&domain)?;
+        let column = self.storage.pages[location.page_id as usize]
+            .columns
+            .get_mut(&type_id)?
+            .as_any_mut()
+            .downcast_mut::<DynamicColumn>()?;
+        self.changes.mark_changed(type_id, entity_id.index);
+        Some(DynamicMut {
+            layout: id.layout(),
+            bytes: column.row_mut(location.row_index as usize),
+        })
+    }
+
+    /// Iterates over every entity carrying the dynamic component `id`.
+    pub fn query_dynamic(
+        &self,
+        id: DynamicComponentId,
+    ) -> impl Iterator<Item = (EntityId, DynamicRef<'_>)> + '_ {
+        let type_id = id.component_type_id();
+        let domain = self.storage.registry.get_domain(type_id);
+        let layout = id.layout();
+        self.storage
+            .pages
+            .iter()
+            .enumerate()
+            .filter_map(move |(page_id, page)| {
+                let column = page
+                    .columns
+                    .get(&type_id)?
+                    .as_any()
+                    .downcast_ref::<DynamicColumn>()?;
+                Some((page_id, page, column))
+            })
+            .flat_map(move |(page_id, page, column)| {
+                page.entities.iter().enumerate().filter_map({
+                    let layout = layout.clone();
+                    move |(row, &entity_id)| {
+                        // Rows left behind by a migration still hold the
+                        // entity; only its current location counts.
+                        let location = self
+                            .entities
+                            .get_metadata(entity_id)?
+                            .locations
+                            .get(&domain?)?;
+                        if location.page_id as usize != page_id
+                            || location.row_index as usize != row
+                        {
+                            return None;
+                        }
+                        Some((
+                            entity_id,
+                            DynamicRef {
+                                layout: layout.clone(),
+                                bytes: column.row(row),
+                            },
+                        ))
+                    }
+                })
+            })
+    }
+}
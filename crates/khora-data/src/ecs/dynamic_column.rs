@@ -0,0 +1,142 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The byte column storing a dynamic component in a page.
+
+use std::any::Any;
+
+use super::AnyVec;
+
+/// A page column of a dynamic component: rows of `stride` bytes laid out
+/// back to back.
+///
+/// Dynamic components only hold plain data, so rows are moved, cloned and
+/// serialized as raw bytes. Fields are read and written by copying bytes,
+/// which is why the column needs no alignment beyond the byte.
+#[derive(Debug, Clone)]
+pub(crate) struct DynamicColumn {
+    stride: usize,
+    align: usize,
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+impl DynamicColumn {
+    /// Creates an empty column for rows of `stride` bytes.
+    pub(crate) fn new(stride: usize, align: usize) -> Self {
+        Self {
+            stride,
+            align,
+            len: 0,
+            bytes: Vec::new(),
+        }
+    }
+
+    /// Appends a row. `row` must be exactly `stride` bytes long.
+    pub(crate) fn push(&mut self, row: &[u8]) {
+        debug_assert_eq!(row.len(), self.stride);
+        self.bytes.extend_from_slice(row);
+        self.len += 1;
+    }
+
+    /// Returns the bytes of row `index`.
+    pub(crate) fn row(&self, index: usize) -> &[u8] {
+        &self.bytes[index * self.stride..(index + 1) * self.stride]
+    }
+
+    /// Returns the bytes of row `index`, mutably.
+    pub(crate) fn row_mut(&mut self, index: usize) -> &mut [u8] {
+        &mut self.bytes[index * self.stride..(index + 1) * self.stride]
+    }
+}
+
+impl AnyVec for DynamicColumn {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn swap_remove_any(&mut self, index: usize) {
+        assert!(index < self.len, "swap_remove index out of bounds");
+        let last = self.len - 1;
+        if index != last {
+            let (head, tail) = self.bytes.split_at_mut(last * self.stride);
+            head[index * self.stride..(index + 1) * self.stride].copy_from_slice(tail);
+        }
+        self.bytes.truncate(last * self.stride);
+        self.len = last;
+    }
+
+    // SAFETY: the rows are stored as bytes already, so this is a plain
+    // borrow: no pointer is cast and no padding is read.
+    unsafe fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    // SAFETY: dynamic components are plain data, so any bytes make valid
+    // rows; only the length has to be a whole number of rows, which is
+    // asserted. Fields are copied out byte by byte, so alignment does not
+    // matter either.
+    unsafe fn set_from_bytes(&mut self, bytes: &[u8]) {
+        if self.stride == 0 {
+            return; // Same as zero-sized Rust components.
+        }
+        assert_eq!(
+            bytes.len() % self.stride,
+            0,
+            "Byte slice length is not a multiple of element size"
+        );
+        self.bytes.clear();
+        self.bytes.extend_from_slice(bytes);
+        self.len = bytes.len() / self.stride;
+    }
+
+    fn element_size(&self) -> usize {
+        self.stride
+    }
+
+    fn is_plain_data(&self) -> bool {
+        true
+    }
+
+    fn element_align(&self) -> usize {
+        self.align
+    }
+
+    fn element_type_name(&self) -> &'static str {
+        "dynamic component"
+    }
+
+    fn row_len(&self) -> usize {
+        self.len
+    }
+
+    fn row_capacity(&self) -> usize {
+        match self.stride {
+            0 => usize::MAX,
+            stride => self.bytes.capacity() / stride,
+        }
+    }
+
+    fn shrink_to_fit_any(&mut self) {
+        self.bytes.shrink_to_fit();
+    }
+
+    fn reserve_any(&mut self, additional: usize) {
+        self.bytes.reserve(additional * self.stride);
+    }
+}
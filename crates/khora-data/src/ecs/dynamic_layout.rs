@@ -0,0 +1,386 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Layout descriptors and values of dynamic components.
+
+use khora_core::math::{Vec2, Vec3, Vec4};
+use serde::{Deserialize, Serialize};
+
+use super::dynamic::DynamicComponentError;
+
+/// The type of a dynamic component field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum DynamicFieldKind {
+    /// A `bool`, stored as one byte.
+    Bool,
+    /// An `i32`.
+    I32,
+    /// A `u32`.
+    U32,
+    /// An `i64`.
+    I64,
+    /// A `u64`.
+    U64,
+    /// An `f32`.
+    F32,
+    /// An `f64`.
+    F64,
+    /// A [`Vec2`].
+    Vec2,
+    /// A [`Vec3`].
+    Vec3,
+    /// A [`Vec4`].
+    Vec4,
+}
+
+impl DynamicFieldKind {
+    /// Returns the size of the field in bytes.
+    pub fn size(self) -> usize {
+        match self {
+            DynamicFieldKind::Bool => 1,
+            DynamicFieldKind::I32 | DynamicFieldKind::U32 | DynamicFieldKind::F32 => 4,
+            DynamicFieldKind::I64 | DynamicFieldKind::U64 | DynamicFieldKind::F64 => 8,
+            DynamicFieldKind::Vec2 => 8,
+            DynamicFieldKind::Vec3 => 12,
+            DynamicFieldKind::Vec4 => 16,
+        }
+    }
+
+    /// Returns the alignment of the field in bytes.
+    pub fn align(self) -> usize {
+        match self {
+            DynamicFieldKind::Bool => 1,
+            DynamicFieldKind::I64 | DynamicFieldKind::U64 | DynamicFieldKind::F64 => 8,
+            _ => 4,
+        }
+    }
+}
+
+/// The value of a dynamic component field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum DynamicValue {
+    /// A `bool`.
+    Bool(bool),
+    /// An `i32`.
+    I32(i32),
+    /// A `u32`.
+    U32(u32),
+    /// An `i64`.
+    I64(i64),
+    /// A `u64`.
+    U64(u64),
+    /// An `f32`.
+    F32(f32),
+    /// An `f64`.
+    F64(f64),
+    /// A [`Vec2`].
+    Vec2(Vec2),
+    /// A [`Vec3`].
+    Vec3(Vec3),
+    /// A [`Vec4`].
+    Vec4(Vec4),
+}
+
+impl DynamicValue {
+    /// Returns the field type this value fits.
+    pub fn kind(&self) -> DynamicFieldKind {
+        match self {
+            DynamicValue::Bool(_) => DynamicFieldKind::Bool,
+            DynamicValue::I32(_) => DynamicFieldKind::I32,
+            DynamicValue::U32(_) => DynamicFieldKind::U32,
+            DynamicValue::I64(_) => DynamicFieldKind::I64,
+            DynamicValue::U64(_) => DynamicFieldKind::U64,
+            DynamicValue::F32(_) => DynamicFieldKind::F32,
+            DynamicValue::F64(_) => DynamicFieldKind::F64,
+            DynamicValue::Vec2(_) => DynamicFieldKind::Vec2,
+            DynamicValue::Vec3(_) => DynamicFieldKind::Vec3,
+            DynamicValue::Vec4(_) => DynamicFieldKind::Vec4,
+        }
+    }
+
+    /// Writes the value into `out`, which is exactly `kind().size()` bytes.
+    fn encode(&self, out: &mut [u8]) {
+        fn floats(out: &mut [u8], values: &[f32]) {
+            for (chunk, value) in out.chunks_exact_mut(4).zip(values) {
+                chunk.copy_from_slice(&value.to_ne_bytes());
+            }
+        }
+        match *self {
+            DynamicValue::Bool(v) => out[0] = v as u8,
+            DynamicValue::I32(v) => out.copy_from_slice(&v.to_ne_bytes()),
+            DynamicValue::U32(v) => out.copy_from_slice(&v.to_ne_bytes()),
+            DynamicValue::I64(v) => out.copy_from_slice(&v.to_ne_bytes()),
+            DynamicValue::U64(v) => out.copy_from_slice(&v.to_ne_bytes()),
+            DynamicValue::F32(v) => out.copy_from_slice(&v.to_ne_bytes()),
+            DynamicValue::F64(v) => out.copy_from_slice(&v.to_ne_bytes()),
+            DynamicValue::Vec2(v) => floats(out, &[v.x, v.y]),
+            DynamicValue::Vec3(v) => floats(out, &[v.x, v.y, v.z]),
+            DynamicValue::Vec4(v) => floats(out, &[v.x, v.y, v.z, v.w]),
+        }
+    }
+
+    /// Reads a value of `kind` from `bytes`, which is exactly `kind.size()` bytes.
+    fn decode(kind: DynamicFieldKind, bytes: &[u8]) -> Self {
+        let float = |i: usize| f32::from_ne_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        match kind {
+            DynamicFieldKind::Bool => DynamicValue::Bool(bytes[0] != 0),
+            DynamicFieldKind::I32 => {
+                DynamicValue::I32(i32::from_ne_bytes(bytes.try_into().unwrap()))
+            }
+            DynamicFieldKind::U32 => {
+                DynamicValue::U32(u32::from_ne_bytes(bytes.try_into().unwrap()))
+            }
+            DynamicFieldKind::I64 => {
+                DynamicValue::I64(i64::from_ne_bytes(bytes.try_into().unwrap()))
+            }
+            DynamicFieldKind::U64 => {
+                DynamicValue::U64(u64::from_ne_bytes(bytes.try_into().unwrap()))
+            }
+            DynamicFieldKind::F32 => DynamicValue::F32(float(0)),
+            DynamicFieldKind::F64 => {
+                DynamicValue::F64(f64::from_ne_bytes(bytes.try_into().unwrap()))
+            }
+            DynamicFieldKind::Vec2 => DynamicValue::Vec2(Vec2::new(float(0), float(1))),
+            DynamicFieldKind::Vec3 => DynamicValue::Vec3(Vec3::new(float(0), float(1), float(2))),
+            DynamicFieldKind::Vec4 => {
+                DynamicValue::Vec4(Vec4::new(float(0), float(1), float(2), float(3)))
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_for_dynamic_value {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for DynamicValue {
+                fn from(value: $ty) -> Self {
+                    DynamicValue::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_dynamic_value!(
+    bool => Bool,
+    i32 => I32,
+    u32 => U32,
+    i64 => I64,
+    u64 => U64,
+    f32 => F32,
+    f64 => F64,
+    Vec2 => Vec2,
+    Vec3 => Vec3,
+    Vec4 => Vec4,
+);
+
+/// A field of a [`DynamicComponentLayout`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicField {
+    /// Name of the field, unique within its component.
+    pub name: String,
+    /// Type of the field.
+    pub kind: DynamicFieldKind,
+    /// Byte offset of the field within a row. Computed by the layout.
+    #[serde(skip)]
+    pub offset: usize,
+}
+
+/// The schema of a dynamic component, as written in a data file.
+#[derive(Serialize, Deserialize)]
+struct LayoutSchema {
+    name: String,
+    fields: Vec<DynamicField>,
+}
+
+/// Describes a component defined at runtime rather than by a Rust type.
+///
+/// Fields are laid out in declaration order with C alignment rules, so a row
+/// is a plain block of `size()` bytes. Layouts deserialize from the same
+/// shape they are built from, which lets mods declare components in RON or
+/// JSON:
+///
+/// ```ron
+/// (name: "Health", fields: [(name: "current", kind: F32), (name: "max", kind: F32)])
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "LayoutSchema", into = "LayoutSchema")]
+pub struct DynamicComponentLayout {
+    name: String,
+    fields: Vec<DynamicField>,
+    size: usize,
+    align: usize,
+}
+
+impl DynamicComponentLayout {
+    /// Lays out `fields` for the component `name`.
+    ///
+    /// Fails with [`DynamicComponentError::DuplicateField`] if two fields
+    /// share a name.
+    pub fn new<I, S>(name: impl Into<String>, fields: I) -> Result<Self, DynamicComponentError>
+    where
+        I: IntoIterator<Item = (S, DynamicFieldKind)>,
+        S: Into<String>,
+    {
+        let mut laid_out: Vec<DynamicField> = Vec::new();
+        let mut offset = 0usize;
+        let mut align = 1;
+        for (field_name, kind) in fields {
+            let field_name = field_name.into();
+            if laid_out.iter().any(|f| f.name == field_name) {
+                return Err(DynamicComponentError::DuplicateField(field_name));
+            }
+            offset = offset.next_multiple_of(kind.align());
+            align = align.max(kind.align());
+            laid_out.push(DynamicField {
+                name: field_name,
+                kind,
+                offset,
+            });
+            offset += kind.size();
+        }
+        Ok(Self {
+            name: name.into(),
+            fields: laid_out,
+            size: offset.next_multiple_of(align),
+            align,
+        })
+    }
+
+    /// Returns the component name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the fields, in declaration order.
+    pub fn fields(&self) -> &[DynamicField] {
+        &self.fields
+    }
+
+    /// Returns the field called `name`.
+    pub fn field(&self, name: &str) -> Option<&DynamicField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Returns the size of a row in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the alignment of a row in bytes.
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Reads the field `name` from a row.
+    pub(crate) fn read(&self, row: &[u8], name: &str) -> Option<DynamicValue> {
+        let field = self.field(name)?;
+        let bytes = &row[field.offset..field.offset + field.kind.size()];
+        Some(DynamicValue::decode(field.kind, bytes))
+    }
+
+    /// Writes `value` into the field `name` of a row.
+    pub(crate) fn write(
+        &self,
+        row: &mut [u8],
+        name: &str,
+        value: DynamicValue,
+    ) -> Result<(), DynamicComponentError> {
+        let field = self
+            .field(name)
+            .ok_or_else(|| DynamicComponentError::UnknownField(name.to_string()))?;
+        if field.kind != value.kind() {
+            return Err(DynamicComponentError::FieldTypeMismatch {
+                field: name.to_string(),
+                expected: field.kind,
+                found: value.kind(),
+            });
+        }
+        value.encode(&mut row[field.offset..field.offset + field.kind.size()]);
+        Ok(())
+    }
+}
+
+impl TryFrom<LayoutSchema> for DynamicComponentLayout {
+    type Error = DynamicComponentError;
+
+    fn try_from(schema: LayoutSchema) -> Result<Self, Self::Error> {
+        Self::new(
+            schema.name,
+            schema.fields.into_iter().map(|f| (f.name, f.kind)),
+        )
+    }
+}
+
+impl From<DynamicComponentLayout> for LayoutSchema {
+    fn from(layout: DynamicComponentLayout) -> Self {
+        Self {
+            name: layout.name,
+            fields: layout.fields,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_follows_c_alignment() {
+        let layout = DynamicComponentLayout::new(
+            "Mixed",
+            [
+                ("alive", DynamicFieldKind::Bool),
+                ("score", DynamicFieldKind::U64),
+                ("pos", DynamicFieldKind::Vec3),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(layout.field("score").unwrap().offset, 8);
+        assert_eq!(layout.field("pos").unwrap().offset, 16);
+        assert_eq!(layout.size(), 32);
+        assert_eq!(layout.align(), 8);
+    }
+
+    #[test]
+    fn test_layout_round_trips_through_ron() {
+        let text =
+            r#"(name: "Health", fields: [(name: "current", kind: F32), (name: "max", kind: F32)])"#;
+        let layout: DynamicComponentLayout = ron::from_str(text).unwrap();
+        assert_eq!(layout.field("max").unwrap().offset, 4);
+
+        let back: DynamicComponentLayout =
+            ron::from_str(&ron::to_string(&layout).unwrap()).unwrap();
+        assert_eq!(back, layout);
+
+        let duplicate =
+            r#"(name: "Bad", fields: [(name: "a", kind: F32), (name: "a", kind: I32)])"#;
+        assert!(ron::from_str::<DynamicComponentLayout>(duplicate).is_err());
+    }
+
+    #[test]
+    fn test_read_write_checks_field_types() {
+        let layout =
+            DynamicComponentLayout::new("Speed", [("value", DynamicFieldKind::F32)]).unwrap();
+        let mut row = vec![0; layout.size()];
+
+        layout.write(&mut row, "value", 2.5f32.into()).unwrap();
+        assert_eq!(layout.read(&row, "value"), Some(DynamicValue::F32(2.5)));
+        assert!(layout.write(&mut row, "value", 1i32.into()).is_err());
+        assert!(layout.write(&mut row, "missing", 1f32.into()).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Old → new entity IDs after entities changed worlds.
+
+use std::collections::HashMap;
+
+use khora_core::ecs::entity::EntityId;
+
+/// Maps the IDs entities had in a source world to their IDs in the world
+/// they were moved into, as returned by [`World::merge`](crate::ecs::World::merge).
+///
+/// `Parent` and `Children` are rewritten by the engine. Components holding
+/// other entity references are rewritten by their owner with [`remap`](Self::remap).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityMap {
+    ids: HashMap<EntityId, EntityId>,
+}
+
+impl EntityMap {
+    /// Returns the new ID of `old`, or `None` if it was not moved.
+    pub fn get(&self, old: EntityId) -> Option<EntityId> {
+        self.ids.get(&old).copied()
+    }
+
+    /// Rewrites `entity` to its new ID. Returns `false`, leaving it as it
+    /// was, if it was not moved.
+    pub fn remap(&self, entity: &mut EntityId) -> bool {
+        match self.get(*entity) {
+            Some(new_id) => {
+                *entity = new_id;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterates over `(old, new)` pairs, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, EntityId)> + '_ {
+        self.ids.iter().map(|(&old, &new)| (old, new))
+    }
+
+    /// Iterates over the new IDs.
+    pub fn new_ids(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.ids.values().copied()
+    }
+
+    /// Returns the number of moved entities.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if no entity was moved.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> HashMap<EntityId, EntityId> {
+        self.ids
+    }
+}
+
+/// Data holding entity references that must follow their targets when
+/// entities get new IDs.
+///
+/// `#[derive(Component)]` calls it on every field that holds an
+/// [`EntityRef`](crate::ecs::EntityRef). Implement it for custom containers
+/// and mark the field `#[component(entities)]`.
+pub trait MapEntities {
+    /// Rewrites every reference through `map`, clearing those whose target
+    /// is not in it.
+    fn map_entities(&mut self, map: &EntityMap);
+}
+
+impl<T: MapEntities> MapEntities for Option<T> {
+    fn map_entities(&mut self, map: &EntityMap) {
+        if let Some(value) = self {
+            value.map_entities(map);
+        }
+    }
+}
+
+impl<T: MapEntities> MapEntities for Vec<T> {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.iter_mut().for_each(|value| value.map_entities(map));
+    }
+}
+
+impl<T: MapEntities, const N: usize> MapEntities for [T; N] {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.iter_mut().for_each(|value| value.map_entities(map));
+    }
+}
+
+impl From<HashMap<EntityId, EntityId>> for EntityMap {
+    fn from(ids: HashMap<EntityId, EntityId>) -> Self {
+        Self { ids }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entity references that survive serialization and despawns.
+
+use bincode::{Decode, Encode};
+use khora_core::ecs::entity::EntityId;
+use serde::{Deserialize, Serialize};
+
+use crate::ecs::{EntityMap, MapEntities, World};
+
+/// A weak reference from a component to another entity.
+///
+/// Unlike a raw [`EntityId`], an `EntityRef` is rewritten whenever entities
+/// get new IDs: on scene load, on [`World::merge`] and on
+/// [`migrate_entities`](crate::ecs::migrate_entities). In a scene file it is
+/// stored as its target's saved ID, the per-scene identifier the file gives
+/// every entity, and fixed up through the loader's old → new table. A
+/// reference to an entity outside the loaded or moved set is cleared.
+///
+/// It never keeps its target alive: once the target is despawned,
+/// [`get`](Self::get) returns `None`, even if the ID's slot was reused.
+///
+/// `#[derive(Component)]` fixes up fields of type `EntityRef`,
+/// `Option<EntityRef>`, `Vec<EntityRef>` and `[EntityRef; N]` on its own:
+///
+/// ```rust,ignore
+/// #[derive(Debug, Clone, Default, Component)]
+/// pub struct DoorLink {
+///     pub door: EntityRef,
+/// }
+///
+/// if let Some(door) = world.get::<DoorLink>(switch).and_then(|l| l.door.get(&world)) {
+///     // `door` is alive.
+/// }
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Encode, Decode,
+)]
+pub struct EntityRef(Option<EntityId>);
+
+impl EntityRef {
+    /// A reference to nothing.
+    pub const NONE: Self = Self(None);
+
+    /// Creates a reference to `target`.
+    pub fn new(target: EntityId) -> Self {
+        Self(Some(target))
+    }
+
+    /// Returns the target if it is still alive in `world`.
+    pub fn get(&self, world: &World) -> Option<EntityId> {
+        // A dead target is expected here, so skip `live_metadata`'s logging.
+        self.0
+            .filter(|&target| world.entities.get_metadata(target).is_some())
+    }
+
+    /// Returns the stored target without checking that it is alive.
+    pub fn target(&self) -> Option<EntityId> {
+        self.0
+    }
+
+    /// Points the reference at `target`, or at nothing.
+    pub fn set(&mut self, target: Option<EntityId>) {
+        self.0 = target;
+    }
+
+    /// Clears the reference.
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+
+    /// Returns `true` if a target is stored, alive or not.
+    pub fn is_set(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl From<EntityId> for EntityRef {
+    fn from(target: EntityId) -> Self {
+        Self::new(target)
+    }
+}
+
+impl From<Option<EntityId>> for EntityRef {
+    fn from(target: Option<EntityId>) -> Self {
+        Self(target)
+    }
+}
+
+impl MapEntities for EntityRef {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = self.0.and_then(|target| map.get(target));
+    }
+}
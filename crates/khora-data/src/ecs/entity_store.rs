@@ -42,6 +42,13 @@ impl EntityStore {
         }
     }
 
+    /// Reserves room for `additional` more entities beyond the recyclable
+    /// slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.entities
+            .reserve(additional.saturating_sub(self.freed_entities.len()));
+    }
+
     /// Allocates a new or recycled `EntityId`.
     ///
     /// If there are indices in the `freed_entities` list, one is popped and its
@@ -51,7 +58,7 @@ impl EntityStore {
         if let Some(index) = self.freed_entities.pop() {
             let index = index as usize;
             let (id_slot, metadata_slot) = &mut self.entities[index];
-            id_slot.generation += 1;
+            id_slot.generation = id_slot.generation.wrapping_add(1);
             *metadata_slot = Some(EntityMetadata::default());
             *id_slot
         } else {
@@ -66,6 +73,40 @@ impl EntityStore {
         }
     }
 
+    /// Returns a reference to an entity's metadata if the entity is alive.
+    ///
+    /// The generation of the provided `EntityId` must match the current generation in the store.
+    pub fn get_metadata(&self, id: EntityId) -> Option<&EntityMetadata> {
+        self.entities
+            .get(id.index as usize)
+            .and_then(|(slot_id, meta)| {
+                if slot_id.generation == id.generation {
+                    meta.as_ref()
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Explains why `id` does not resolve to a live entity.
+    #[cfg(feature = "entity-debug")]
+    pub fn describe_dead(&self, id: EntityId) -> String {
+        match self.entities.get(id.index as usize) {
+            None => format!("{id:?} was never spawned"),
+            Some((slot_id, _)) if slot_id.generation == id.generation => {
+                format!("{id:?} was despawned")
+            }
+            Some((slot_id, Some(_))) => format!(
+                "{id:?} is stale: index {} was reused by generation {}",
+                id.index, slot_id.generation
+            ),
+            Some((slot_id, None)) => format!(
+                "{id:?} is stale: index {} is free after generation {}",
+                id.index, slot_id.generation
+            ),
+        }
+    }
+
     /// Returns a mutable reference to an entity's metadata if the entity is alive.
     ///
     /// The generation of the provided `EntityId` must match the current generation in the store.
@@ -82,6 +123,7 @@ impl EntityStore {
     }
 
     /// Returns the total number of entity slots (both alive and dead).
+    #[cfg(test)]
     pub fn len(&self) -> usize {
         self.entities.len()
     }
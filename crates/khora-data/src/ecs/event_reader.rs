@@ -0,0 +1,101 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-consumer cursors into an [`Events`] queue.
+
+use std::marker::PhantomData;
+
+use crate::ecs::Events;
+
+/// Remembers which events of type `T` a consumer has already read.
+///
+/// Each system or lane that consumes events keeps its own reader, so several
+/// consumers can read the same queue independently. A fresh reader (from
+/// [`EventReader::default`]) sees every event still buffered; one created
+/// with [`Events::reader`] only sees events sent afterwards.
+#[derive(Debug)]
+pub struct EventReader<T> {
+    /// Sequence number of the next event to read.
+    cursor: u64,
+    /// Events dropped by the queue before this reader got to them.
+    missed: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for EventReader<T> {
+    fn default() -> Self {
+        Self::starting_at(0)
+    }
+}
+
+impl<T> Clone for EventReader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cursor: self.cursor,
+            missed: self.missed,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> EventReader<T> {
+    pub(crate) fn starting_at(cursor: u64) -> Self {
+        Self {
+            cursor,
+            missed: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the events this reader has not seen yet, oldest first, and
+    /// marks them as read.
+    ///
+    /// Events dropped before the reader got to them are counted in
+    /// [`missed`](Self::missed) and skipped.
+    pub fn read<'a>(&mut self, events: &'a Events<T>) -> impl Iterator<Item = &'a T> + 'a {
+        let oldest = events.oldest_sequence();
+        if self.cursor < oldest {
+            self.missed += oldest - self.cursor;
+            log::debug!(
+                "EventReader<{}>: {} event(s) dropped before being read",
+                std::any::type_name::<T>(),
+                oldest - self.cursor
+            );
+            self.cursor = oldest;
+        }
+        let skip = (self.cursor - oldest) as usize;
+        self.cursor = events.next_sequence();
+        events.iter().skip(skip)
+    }
+
+    /// Number of events waiting to be read.
+    pub fn len(&self, events: &Events<T>) -> usize {
+        (events.next_sequence() - self.cursor.max(events.oldest_sequence())) as usize
+    }
+
+    /// Returns `true` if there is nothing new to read.
+    pub fn is_empty(&self, events: &Events<T>) -> bool {
+        self.len(events) == 0
+    }
+
+    /// Marks every buffered event as read without reading it.
+    pub fn clear(&mut self, events: &Events<T>) {
+        self.cursor = events.next_sequence();
+    }
+
+    /// Total number of events dropped before this reader got to them.
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+}
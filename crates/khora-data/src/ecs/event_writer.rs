@@ -0,0 +1,58 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Write access to one [`Events`] queue.
+
+use crate::ecs::Events;
+
+/// Sends events of type `T` into the world's queue.
+///
+/// Obtained from [`World::event_writer`](crate::ecs::World::event_writer).
+/// Holding the writer borrows the world mutably, so a producer that sends
+/// many events looks the queue up once. Systems that only see `&World`
+/// queue their events with
+/// [`SystemContext::send_event`](crate::ecs::SystemContext::send_event).
+#[derive(Debug)]
+pub struct EventWriter<'a, T> {
+    events: &'a mut Events<T>,
+}
+
+impl<'a, T> EventWriter<'a, T> {
+    pub(crate) fn new(events: &'a mut Events<T>) -> Self {
+        Self { events }
+    }
+
+    /// Appends an event to the current frame.
+    pub fn send(&mut self, event: T) {
+        self.events.send(event);
+    }
+
+    /// Appends several events to the current frame, in order.
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = T>) {
+        self.events.send_batch(events);
+    }
+
+    /// Appends `T::default()` to the current frame.
+    pub fn send_default(&mut self)
+    where
+        T: Default,
+    {
+        self.events.send(T::default());
+    }
+
+    /// Number of events sent during the current frame, by any producer.
+    pub fn sent_this_frame(&self) -> usize {
+        self.events.iter_current().count()
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed, double-buffered event queues stored in the [`World`].
+//!
+//! Any `Send + Sync + 'static` type can be an event. Producers append to the
+//! [`Events<T>`] queue of their type with [`World::send_event`] or an
+//! [`EventWriter<T>`](crate::ecs::EventWriter); consumers
+//! keep an [`EventReader<T>`] cursor and read every event they have not seen
+//! yet with [`World::read_events`].
+//!
+//! Events live for two frames. The `event_update` data system calls
+//! [`World::update_events`] at the end of every tick, which moves the
+//! current frame's events into the previous buffer and drops the ones from
+//! the frame before. A reader that runs once per frame, at any point of the
+//! tick, therefore sees each event exactly once.
+
+use std::any::{Any, TypeId};
+
+use crate::ecs::{EventReader, EventWriter, World};
+
+/// A double-buffered queue of events of type `T`.
+///
+/// Every event gets a sequence number. The previous buffer holds the
+/// numbers `[previous_start, current_start)` and the current buffer the
+/// numbers from `current_start` on, so readers can track their position with
+/// a single counter.
+#[derive(Debug)]
+pub struct Events<T> {
+    /// Events sent during the previous frame.
+    previous: Vec<T>,
+    /// Events sent during the current frame.
+    current: Vec<T>,
+    /// Sequence number of the first event in `previous`.
+    previous_start: u64,
+    /// Sequence number of the first event in `current`.
+    current_start: u64,
+}
+
+impl<T> Default for Events<T> {
+    fn default() -> Self {
+        Self {
+            previous: Vec::new(),
+            current: Vec::new(),
+            previous_start: 0,
+            current_start: 0,
+        }
+    }
+}
+
+impl<T> Events<T> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an event to the current frame.
+    pub fn send(&mut self, event: T) {
+        self.current.push(event);
+    }
+
+    /// Appends several events to the current frame, in order.
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = T>) {
+        self.current.extend(events);
+    }
+
+    /// Ends the frame: drops the previous frame's events and keeps the
+    /// current ones readable for one more frame.
+    pub fn update(&mut self) {
+        std::mem::swap(&mut self.previous, &mut self.current);
+        self.current.clear();
+        self.previous_start = self.current_start;
+        self.current_start += self.previous.len() as u64;
+    }
+
+    /// Drops every buffered event. Readers skip past them.
+    pub fn clear(&mut self) {
+        self.previous_start = self.next_sequence();
+        self.current_start = self.previous_start;
+        self.previous.clear();
+        self.current.clear();
+    }
+
+    /// Number of buffered events, over both frames.
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    /// Returns `true` if no event is buffered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over every buffered event, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.previous.iter().chain(self.current.iter())
+    }
+
+    /// Iterates over the events sent during the current frame.
+    pub fn iter_current(&self) -> impl Iterator<Item = &T> {
+        self.current.iter()
+    }
+
+    /// Creates a reader that only sees events sent from now on.
+    pub fn reader(&self) -> EventReader<T> {
+        EventReader::starting_at(self.next_sequence())
+    }
+
+    /// Sequence number of the oldest buffered event.
+    pub(crate) fn oldest_sequence(&self) -> u64 {
+        self.previous_start
+    }
+
+    /// Sequence number the next sent event will get.
+    pub(crate) fn next_sequence(&self) -> u64 {
+        self.current_start + self.current.len() as u64
+    }
+}
+
+/// Type-erased access to an [`Events<T>`] queue, so the world can end the
+/// frame for every event type at once.
+pub(crate) trait EventQueue: Send + Sync {
+    /// See [`Events::update`].
+    fn update(&mut self);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Send + Sync + 'static> EventQueue for Events<T> {
+    fn update(&mut self) {
+        Events::update(self);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl World {
+    /// Creates the event queue for `T` if it does not exist yet.
+    pub fn add_event<T: Send + Sync + 'static>(&mut self) {
+        self.events_mut::<T>();
+    }
+
+    /// Appends an event to the queue of its type, creating the queue if needed.
+    pub fn send_event<T: Send + Sync + 'static>(&mut self, event: T) {
+        self.events_mut::<T>().send(event);
+    }
+
+    /// Returns a writer for the queue of `T`, creating the queue if needed.
+    pub fn event_writer<T: Send + Sync + 'static>(&mut self) -> EventWriter<'_, T> {
+        EventWriter::new(self.events_mut::<T>())
+    }
+
+    /// Returns the event queue for `T`, if any event of that type was added.
+    pub fn events<T: Send + Sync + 'static>(&self) -> Option<&Events<T>> {
+        self.events
+            .get(&TypeId::of::<T>())
+            .and_then(|queue| queue.as_any().downcast_ref::<Events<T>>())
+    }
+
+    /// Returns the event queue for `T`, creating it if needed.
+    pub fn events_mut<T: Send + Sync + 'static>(&mut self) -> &mut Events<T> {
+        self.events
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(Events::<T>::new()))
+            .as_any_mut()
+            .downcast_mut::<Events<T>>()
+            .expect("event queue stored under the TypeId of another type")
+    }
+
+    /// Reads the events of type `T` that `reader` has not seen yet.
+    ///
+    /// Returns an empty iterator if no event of that type was ever sent.
+    pub fn read_events<'a, T: Send + Sync + 'static>(
+        &'a self,
+        reader: &mut EventReader<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.events::<T>()
+            .map(|events| reader.read(events))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Ends the frame for every event queue. See [`Events::update`].
+    pub fn update_events(&mut self) {
+        for queue in self.events.values_mut() {
+            queue.update();
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The scene hierarchy: `Parent` on children, `Children` on parents.
+//!
+//! [`World::set_parent`] updates both sides at once. Entities parented by
+//! adding a `Parent` directly get their parent's `Children` entry from the
+//! `hierarchy_sync` data system on the next tick, which also turns children
+//! of despawned entities into roots.
+
+use std::collections::HashMap;
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{Children, IncludeDisabled, Parent, World};
+
+/// Bound on hierarchy depth, against malformed (cyclic) `Parent` chains.
+const MAX_DEPTH: usize = 1024;
+
+impl World {
+    /// Returns `entity`'s parent, if it has one.
+    pub fn parent(&self, entity: EntityId) -> Option<EntityId> {
+        self.get::<Parent>(entity).map(|parent| parent.0)
+    }
+
+    /// Returns `entity`'s direct children, in the order they were attached.
+    pub fn children(&self, entity: EntityId) -> &[EntityId] {
+        self.get::<Children>(entity)
+            .map_or(&[], |children| children.0.as_slice())
+    }
+
+    /// Reparents `child` under `new_parent`, or makes it a root when
+    /// `new_parent` is `None`.
+    ///
+    /// Updates the `Parent` of `child` and the `Children` of both its old
+    /// and new parent. Returns `false`, changing nothing, if either entity
+    /// is dead or if the move would create a cycle.
+    pub fn set_parent(&mut self, child: EntityId, new_parent: Option<EntityId>) -> bool {
+        if self.live_metadata(child, "set_parent").is_none() {
+            return false;
+        }
+        if let Some(parent) = new_parent {
+            if self.live_metadata(parent, "set_parent").is_none() {
+                return false;
+            }
+            if parent == child || self.is_descendant_of(parent, child) {
+                log::warn!(
+                    "set_parent: refused cycle (child={:?}, new_parent={:?})",
+                    child,
+                    parent
+                );
+                return false;
+            }
+        }
+
+        let old_parent = self.parent(child);
+        if let Some(old) = old_parent {
+            if new_parent == old_parent {
+                // Already in place; still make sure the parent lists it.
+                self.link_child(old, child);
+                return true;
+            }
+            self.unlink_child(old, child);
+        }
+
+        match new_parent {
+            Some(parent) => {
+                if let Some(existing) = self.get_mut::<Parent>(child) {
+                    existing.0 = parent;
+                } else {
+                    let _ = self.insert_component(child, Parent(parent));
+                }
+                self.link_child(parent, child);
+            }
+            None => {
+                let _ = self.remove_component_now::<Parent>(child);
+            }
+        }
+        true
+    }
+
+    /// Makes `child` a root. Shorthand for `set_parent(child, None)`.
+    pub fn remove_parent(&mut self, child: EntityId) -> bool {
+        self.set_parent(child, None)
+    }
+
+    /// Returns `true` if `candidate` is below `ancestor` in the hierarchy.
+    pub fn is_descendant_of(&self, candidate: EntityId, ancestor: EntityId) -> bool {
+        let mut current = candidate;
+        for _ in 0..MAX_DEPTH {
+            match self.parent(current) {
+                Some(parent) if parent == ancestor => return true,
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+        log::warn!("is_descendant_of: hierarchy traversal exceeded depth bound");
+        false
+    }
+
+    /// Returns every entity below `entity`, parents before their children.
+    ///
+    /// Follows `Parent` components, so children not yet listed in a
+    /// `Children` component are included.
+    pub fn descendants(&self, entity: EntityId) -> Vec<EntityId> {
+        let mut children: HashMap<EntityId, Vec<EntityId>> = HashMap::new();
+        for (child, parent, _) in self.query::<(EntityId, &Parent, IncludeDisabled)>() {
+            children.entry(parent.0).or_default().push(child);
+        }
+
+        let mut descendants = Vec::new();
+        let mut head = 0;
+        descendants.extend(children.remove(&entity).unwrap_or_default());
+        while let Some(&current) = descendants.get(head) {
+            head += 1;
+            descendants.extend(children.remove(&current).unwrap_or_default());
+        }
+        descendants
+    }
+
+    /// Despawns `entity` together with all its descendants, and removes it
+    /// from its parent's `Children`.
+    ///
+    /// Returns how many entities were despawned; `0` if `entity` was dead.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub fn despawn_recursive(&mut self, entity: EntityId) -> usize {
+        if self.live_metadata(entity, "despawn_recursive").is_none() {
+            return 0;
+        }
+        let descendants = self.descendants(entity);
+        if let Some(parent) = self.parent(entity) {
+            self.unlink_child(parent, entity);
+        }
+
+        let mut despawned = usize::from(self.despawn_unlinked(entity));
+        for descendant in descendants {
+            despawned += usize::from(self.despawn_unlinked(descendant));
+        }
+        despawned
+    }
+
+    /// (Internal) Removes `entity` from the hierarchy before it is
+    /// despawned: its parent forgets it and its children become roots.
+    pub(crate) fn detach_from_hierarchy(&mut self, entity: EntityId) {
+        if let Some(parent) = self.parent(entity) {
+            self.unlink_child(parent, entity);
+        }
+        let children = self.children(entity).to_vec();
+        for child in children {
+            if self.parent(child) == Some(entity) {
+                let _ = self.remove_component_now::<Parent>(child);
+            }
+        }
+    }
+
+    fn link_child(&mut self, parent: EntityId, child: EntityId) {
+        if let Some(children) = self.get::<Children>(parent) {
+            if !children.0.contains(&child) {
+                if let Some(children) = self.get_mut::<Children>(parent) {
+                    children.0.push(child);
+                }
+            }
+        } else {
+            let _ = self.insert_component(parent, Children(vec![child]));
+        }
+    }
+
+    fn unlink_child(&mut self, parent: EntityId, child: EntityId) {
+        if self.children(parent).contains(&child) {
+            if let Some(children) = self.get_mut::<Children>(parent) {
+                children.0.retain(|c| *c != child);
+            }
+        }
+    }
+}
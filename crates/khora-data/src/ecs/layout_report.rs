@@ -0,0 +1,341 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A snapshot of the CRPECS storage layout, for tuning page and component
+//! design.
+//!
+//! [`World::layout_report`] walks every page and records, per archetype,
+//! the pages that store it, how full they are and how many bytes each
+//! component column uses and reserves. The report renders as a
+//! human-readable table through `Display` and as JSON through
+//! [`WorldLayout::to_json`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::ecs::World;
+
+/// The layout of one component column in a page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnLayout {
+    /// Full type name of the component.
+    pub component: String,
+    /// `size_of` the component, in bytes.
+    pub size: usize,
+    /// `align_of` the component, in bytes.
+    pub align: usize,
+    /// Bytes holding live rows.
+    pub used_bytes: usize,
+    /// Bytes allocated for the column.
+    pub reserved_bytes: usize,
+}
+
+impl ColumnLayout {
+    /// Bytes allocated for rows that hold no entity.
+    pub fn waste_bytes(&self) -> usize {
+        self.reserved_bytes - self.used_bytes
+    }
+}
+
+/// The layout of one component page.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PageLayout {
+    /// Index of the page in the world's storage.
+    pub page_id: u32,
+    /// Number of entities stored in the page.
+    pub entities: usize,
+    /// Number of rows the page can hold before it grows.
+    pub capacity: usize,
+    /// One entry per component column, sorted by component name.
+    pub columns: Vec<ColumnLayout>,
+}
+
+impl PageLayout {
+    /// Share of the capacity holding entities, from `0.0` to `1.0`.
+    pub fn occupancy(&self) -> f32 {
+        if self.capacity == 0 {
+            0.0
+        } else {
+            self.entities as f32 / self.capacity as f32
+        }
+    }
+
+    /// Bytes holding live rows, over every column.
+    pub fn used_bytes(&self) -> usize {
+        self.columns.iter().map(|c| c.used_bytes).sum()
+    }
+
+    /// Bytes allocated, over every column.
+    pub fn reserved_bytes(&self) -> usize {
+        self.columns.iter().map(|c| c.reserved_bytes).sum()
+    }
+
+    /// Bytes allocated for rows that hold no entity, over every column.
+    pub fn waste_bytes(&self) -> usize {
+        self.reserved_bytes() - self.used_bytes()
+    }
+}
+
+/// The pages storing one archetype (one set of components).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArchetypeLayout {
+    /// Semantic domain of the archetype, if its components are registered.
+    pub domain: Option<String>,
+    /// Full type names of the components, sorted.
+    pub components: Vec<String>,
+    /// The pages storing the archetype.
+    pub pages: Vec<PageLayout>,
+}
+
+impl ArchetypeLayout {
+    /// Number of entities over every page of the archetype.
+    pub fn entities(&self) -> usize {
+        self.pages.iter().map(|p| p.entities).sum()
+    }
+}
+
+/// A snapshot of how a [`World`] lays out its component data.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorldLayout {
+    /// Every archetype with at least one page, sorted by domain then components.
+    pub archetypes: Vec<ArchetypeLayout>,
+}
+
+impl WorldLayout {
+    /// Number of pages over every archetype.
+    pub fn page_count(&self) -> usize {
+        self.archetypes.iter().map(|a| a.pages.len()).sum()
+    }
+
+    /// Bytes allocated for rows that hold no entity, over the whole world.
+    pub fn waste_bytes(&self) -> usize {
+        self.pages().map(PageLayout::waste_bytes).sum()
+    }
+
+    /// Serializes the report as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    fn pages(&self) -> impl Iterator<Item = &PageLayout> {
+        self.archetypes.iter().flat_map(|a| a.pages.iter())
+    }
+}
+
+/// Strips the module path from a type name, keeping generic arguments.
+fn short_name(type_name: &str) -> String {
+    let mut out = String::with_capacity(type_name.len());
+    let mut segment = String::new();
+    for c in type_name.chars() {
+        match c {
+            ':' => segment.clear(),
+            '<' | '>' | ',' | ' ' | '(' | ')' | '[' | ']' | '&' | ';' => {
+                out.push_str(&segment);
+                segment.clear();
+                out.push(c);
+            }
+            _ => segment.push(c),
+        }
+    }
+    out.push_str(&segment);
+    out
+}
+
+impl fmt::Display for WorldLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let used: usize = self.pages().map(PageLayout::used_bytes).sum();
+        let reserved: usize = self.pages().map(PageLayout::reserved_bytes).sum();
+        writeln!(
+            f,
+            "{} archetype(s), {} page(s), {} entities, {} B used, {} B reserved, {} B waste",
+            self.archetypes.len(),
+            self.page_count(),
+            self.archetypes.iter().map(|a| a.entities()).sum::<usize>(),
+            used,
+            reserved,
+            self.waste_bytes()
+        )?;
+        for archetype in &self.archetypes {
+            let components: Vec<String> =
+                archetype.components.iter().map(|c| short_name(c)).collect();
+            writeln!(
+                f,
+                "\n[{}] ({}) - {} page(s)",
+                archetype.domain.as_deref().unwrap_or("?"),
+                components.join(", "),
+                archetype.pages.len()
+            )?;
+            writeln!(
+                f,
+                "  {:>6} {:>8} {:>8} {:>9} {:>10} {:>10} {:>10}",
+                "page", "entities", "capacity", "occupancy", "used_B", "reserved_B", "waste_B"
+            )?;
+            for page in &archetype.pages {
+                writeln!(
+                    f,
+                    "  {:>6} {:>8} {:>8} {:>8.1}% {:>10} {:>10} {:>10}",
+                    page.page_id,
+                    page.entities,
+                    page.capacity,
+                    page.occupancy() * 100.0,
+                    page.used_bytes(),
+                    page.reserved_bytes(),
+                    page.waste_bytes()
+                )?;
+                for column in &page.columns {
+                    writeln!(
+                        f,
+                        "         {:<32} size {:>4}  align {:>2}  used {:>8}  waste {:>8}",
+                        short_name(&column.component),
+                        column.size,
+                        column.align,
+                        column.used_bytes,
+                        column.waste_bytes()
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl World {
+    /// Takes a snapshot of how the world lays out its component data.
+    ///
+    /// Pages that never held a column (such as freshly reserved ones) are
+    /// skipped.
+    pub fn layout_report(&self) -> WorldLayout {
+        let mut archetypes: BTreeMap<(Option<String>, Vec<String>), Vec<PageLayout>> =
+            BTreeMap::new();
+
+        for (page_id, page) in self.storage.pages.iter().enumerate() {
+            if page.columns.is_empty() {
+                continue;
+            }
+            let mut columns: Vec<ColumnLayout> = page
+                .columns
+                .values()
+                .map(|column| ColumnLayout {
+                    component: column.element_type_name().to_string(),
+                    size: column.element_size(),
+                    align: column.element_align(),
+                    used_bytes: column.row_len() * column.element_size(),
+                    reserved_bytes: column.row_capacity() * column.element_size(),
+                })
+                .collect();
+            columns.sort_by(|a, b| a.component.cmp(&b.component));
+
+            let capacity = page.row_capacity();
+            let domain = page
+                .type_ids
+                .first()
+                .and_then(|type_id| self.storage.registry.get_domain(*type_id))
+                .map(|domain| format!("{domain:?}"));
+            let components = columns.iter().map(|c| c.component.clone()).collect();
+
+            archetypes
+                .entry((domain, components))
+                .or_default()
+                .push(PageLayout {
+                    page_id: page_id as u32,
+                    entities: page.row_count(),
+                    capacity,
+                    columns,
+                });
+        }
+
+        WorldLayout {
+            archetypes: archetypes
+                .into_iter()
+                .map(|((domain, components), pages)| ArchetypeLayout {
+                    domain,
+                    components,
+                    pages,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{Component, SemanticDomain};
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, Copy)]
+    struct Position(u32);
+    impl Component for Position {}
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, Copy)]
+    struct Speed(u64);
+    impl Component for Speed {}
+
+    #[test]
+    fn test_layout_report_groups_pages_by_archetype() {
+        let mut world = World::default();
+        world.register_component::<Position>(SemanticDomain::Spatial);
+        world.register_component::<Speed>(SemanticDomain::Spatial);
+        for i in 0..3 {
+            world.spawn((Position(i), Speed(i as u64)));
+        }
+        world.spawn(Position(7));
+
+        let layout = world.layout_report();
+        assert_eq!(layout.archetypes.len(), 2);
+        assert_eq!(layout.page_count(), 2);
+
+        let both = layout
+            .archetypes
+            .iter()
+            .find(|a| a.components.len() == 2)
+            .unwrap();
+        assert_eq!(both.domain.as_deref(), Some("Spatial"));
+        assert_eq!(both.entities(), 3);
+        let page = &both.pages[0];
+        assert!(page.capacity >= 3);
+        assert!(page.occupancy() > 0.0 && page.occupancy() <= 1.0);
+        let speed = page
+            .columns
+            .iter()
+            .find(|c| c.component.ends_with("Speed"))
+            .unwrap();
+        assert_eq!((speed.size, speed.used_bytes), (8, 24));
+        assert_eq!(speed.waste_bytes(), speed.reserved_bytes - speed.used_bytes);
+    }
+
+    #[test]
+    fn test_layout_report_renders_table_and_json() {
+        let mut world = World::default();
+        world.register_component::<Position>(SemanticDomain::Spatial);
+        world.spawn(Position(1));
+
+        let layout = world.layout_report();
+        let table = layout.to_string();
+        assert!(table.contains("(Position)"), "{table}");
+        let json: serde_json::Value = serde_json::from_str(&layout.to_json().unwrap()).unwrap();
+        assert_eq!(json["archetypes"][0]["pages"][0]["entities"], 1);
+    }
+
+    #[test]
+    fn test_short_name_keeps_generics() {
+        assert_eq!(
+            short_name("khora_data::ecs::HandleComponent<khora_core::renderer::Mesh>"),
+            "HandleComponent<Mesh>"
+        );
+    }
+}
@@ -18,6 +18,12 @@
 //! Unlike Agents, there is no strategy negotiation — the maintenance runs
 //! a fixed number of items per frame to keep frame times predictable.
 //!
+//! After the queues, each tick compacts sparse pages — pages whose
+//! allocation outgrew their rows after spawn/despawn churn — walking the
+//! pages round-robin until a time budget runs out. The budget can follow a
+//! GORNA allocation through [`EcsMaintenance::apply_budget`]. Page
+//! occupancy is published to telemetry through [`EcsMaintenance::monitor`].
+//!
 //! # Usage
 //!
 //! ```rust,ignore
@@ -34,10 +40,17 @@
 //! ```
 
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use khora_core::control::gorna::ResourceBudget;
 
-use super::{PageIndex, SemanticDomain, World, WorldMaintenance};
+use super::maintenance_monitor::MaintenanceSample;
+use super::{
+    EcsMaintenanceMonitor, PageIndex, PageOccupancy, SemanticDomain, World, WorldMaintenance,
+};
 
 const DEFAULT_MAX_PER_FRAME: usize = 10;
+const DEFAULT_COMPACTION_BUDGET: Duration = Duration::from_micros(100);
 
 /// Direct ECS maintenance service.
 ///
@@ -48,6 +61,11 @@ pub struct EcsMaintenance {
     pending_vacuum: VecDeque<(u32, u32)>,
     max_per_frame: usize,
     last_cleanup_count: usize,
+    compaction_budget: Duration,
+    work_scale: f32,
+    compaction_cursor: usize,
+    last_compacted_count: usize,
+    monitor: EcsMaintenanceMonitor,
 }
 
 impl EcsMaintenance {
@@ -58,6 +76,11 @@ impl EcsMaintenance {
             pending_vacuum: VecDeque::new(),
             max_per_frame: DEFAULT_MAX_PER_FRAME,
             last_cleanup_count: 0,
+            compaction_budget: DEFAULT_COMPACTION_BUDGET,
+            work_scale: 1.0,
+            compaction_cursor: 0,
+            last_compacted_count: 0,
+            monitor: EcsMaintenanceMonitor::default(),
         }
     }
 
@@ -69,6 +92,30 @@ impl EcsMaintenance {
         }
     }
 
+    /// Sets the time each tick may spend compacting sparse pages.
+    ///
+    /// A zero budget disables compaction.
+    pub fn set_compaction_budget(&mut self, budget: Duration) {
+        self.compaction_budget = budget;
+    }
+
+    /// Scales the compaction budget by `scale` (clamped to 0..=1), e.g. to
+    /// keep less background work on battery power.
+    pub fn set_work_scale(&mut self, scale: f32) {
+        self.work_scale = scale.clamp(0.0, 1.0);
+    }
+
+    /// Takes the compaction time budget from a GORNA allocation.
+    pub fn apply_budget(&mut self, budget: &ResourceBudget) {
+        self.compaction_budget = budget.time_limit;
+    }
+
+    /// Returns a telemetry handle reporting page occupancy and compaction
+    /// progress after each tick.
+    pub fn monitor(&self) -> EcsMaintenanceMonitor {
+        self.monitor.clone()
+    }
+
     /// Queues a cleanup request for an orphaned data location.
     pub fn queue_cleanup(&mut self, page_index: PageIndex, domain: SemanticDomain) {
         self.pending_cleanup.push_back((page_index, domain));
@@ -82,8 +129,21 @@ impl EcsMaintenance {
     /// Runs one frame of maintenance on the given world.
     ///
     /// Drains up to `max_per_frame` items from the cleanup and vacuum queues
-    /// and executes the compaction operations.
+    /// and executes the compaction operations, then compacts sparse pages
+    /// within the compaction time budget.
     pub fn tick(&mut self, world: &mut World) {
+        self.drain_queues(world);
+        let bytes_reclaimed = self.compact_pages(world);
+
+        self.monitor.publish(MaintenanceSample {
+            occupancy: world.page_occupancy(),
+            pages_compacted: self.last_compacted_count,
+            bytes_reclaimed,
+            pending: self.pending_count(),
+        });
+    }
+
+    fn drain_queues(&mut self, world: &mut World) {
         if self.pending_cleanup.is_empty() && self.pending_vacuum.is_empty() {
             self.last_cleanup_count = 0;
             return;
@@ -122,6 +182,44 @@ impl EcsMaintenance {
         }
     }
 
+    /// Visits pages round-robin from where the last tick stopped, shrinking
+    /// the sparse ones, until every page was visited once or the budget ran
+    /// out. Returns the bytes released.
+    fn compact_pages(&mut self, world: &mut World) -> usize {
+        self.last_compacted_count = 0;
+        let page_count = world.storage.pages.len();
+        let budget = self.compaction_budget.mul_f32(self.work_scale);
+        if budget.is_zero() || page_count == 0 {
+            return 0;
+        }
+
+        let start = Instant::now();
+        let mut reclaimed = 0;
+        for _ in 0..page_count {
+            if start.elapsed() >= budget {
+                break;
+            }
+            if self.compaction_cursor >= page_count {
+                self.compaction_cursor = 0;
+            }
+            let freed = world.compact_page(self.compaction_cursor as u32);
+            self.compaction_cursor += 1;
+            if freed > 0 {
+                self.last_compacted_count += 1;
+                reclaimed += freed;
+            }
+        }
+
+        if self.last_compacted_count > 0 {
+            log::trace!(
+                "EcsMaintenance: Compacted {} page(s), released {} bytes",
+                self.last_compacted_count,
+                reclaimed,
+            );
+        }
+        reclaimed
+    }
+
     /// Returns the total number of pending requests.
     pub fn pending_count(&self) -> usize {
         self.pending_cleanup.len() + self.pending_vacuum.len()
@@ -136,6 +234,21 @@ impl EcsMaintenance {
     pub fn max_per_frame(&self) -> usize {
         self.max_per_frame
     }
+
+    /// Returns the time each tick may spend compacting pages.
+    pub fn compaction_budget(&self) -> Duration {
+        self.compaction_budget
+    }
+
+    /// Returns the number of pages compacted in the last tick.
+    pub fn last_compacted_count(&self) -> usize {
+        self.last_compacted_count
+    }
+
+    /// Returns the page occupancy measured by the last tick.
+    pub fn last_occupancy(&self) -> PageOccupancy {
+        self.monitor.occupancy()
+    }
 }
 
 impl Default for EcsMaintenance {
@@ -147,6 +260,14 @@ impl Default for EcsMaintenance {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ecs::Component;
+    use khora_core::telemetry::metrics::MetricId;
+    use khora_core::telemetry::monitoring::ResourceMonitor;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, Copy)]
+    struct Particle(u64);
+    impl Component for Particle {}
 
     #[test]
     fn test_maintenance_empty_tick() {
@@ -182,4 +303,53 @@ mod tests {
         maintenance.queue_vacuum(0, 5);
         assert_eq!(maintenance.pending_count(), 3);
     }
+
+    #[test]
+    fn test_maintenance_compacts_sparse_pages() {
+        let mut world = World::new();
+        world.register_component::<Particle>(SemanticDomain::Spatial);
+        let ids: Vec<_> = (0..256).map(|i| world.spawn(Particle(i))).collect();
+        for id in &ids[8..] {
+            world.despawn(*id);
+        }
+
+        let before = world.page_occupancy();
+        assert_eq!(before.rows, 8);
+        assert_eq!(before.sparse_pages, 1);
+
+        let mut maintenance = EcsMaintenance::new();
+        maintenance.set_compaction_budget(Duration::from_secs(1));
+        maintenance.tick(&mut world);
+
+        assert_eq!(maintenance.last_compacted_count(), 1);
+        let after = maintenance.last_occupancy();
+        assert_eq!(after.rows, 8);
+        assert_eq!(after.sparse_pages, 0);
+        assert!(after.waste_bytes < before.waste_bytes);
+        assert!(after.occupancy() > before.occupancy());
+        for id in &ids[..8] {
+            assert!(world.get::<Particle>(*id).is_some());
+        }
+
+        let metrics = maintenance.monitor().get_metrics();
+        assert!(metrics
+            .iter()
+            .any(|(id, _)| *id == MetricId::new("ecs.pages", "sparse_pages")));
+    }
+
+    #[test]
+    fn test_maintenance_zero_budget_skips_compaction() {
+        let mut world = World::new();
+        world.register_component::<Particle>(SemanticDomain::Spatial);
+        let ids: Vec<_> = (0..64).map(|i| world.spawn(Particle(i))).collect();
+        for id in &ids[1..] {
+            world.despawn(*id);
+        }
+
+        let mut maintenance = EcsMaintenance::new();
+        maintenance.set_compaction_budget(Duration::ZERO);
+        maintenance.tick(&mut world);
+        assert_eq!(maintenance.last_compacted_count(), 0);
+        assert_eq!(world.page_occupancy().sparse_pages, 1);
+    }
 }
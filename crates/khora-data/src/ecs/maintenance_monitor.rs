@@ -0,0 +1,96 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Telemetry for ECS maintenance: page occupancy and compaction progress.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use khora_core::telemetry::metrics::{MetricId, MetricValue};
+use khora_core::telemetry::monitoring::{
+    MonitoredResourceType, ResourceMonitor, ResourceUsageReport,
+};
+
+use crate::ecs::PageOccupancy;
+
+/// What the last maintenance tick saw and did.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct MaintenanceSample {
+    pub(crate) occupancy: PageOccupancy,
+    pub(crate) pages_compacted: usize,
+    pub(crate) bytes_reclaimed: usize,
+    pub(crate) pending: usize,
+}
+
+/// Reports [`EcsMaintenance`](super::EcsMaintenance) to telemetry as the
+/// `"EcsPages"` monitor.
+///
+/// Obtained from [`EcsMaintenance::monitor`](super::EcsMaintenance::monitor);
+/// clones share the sample the maintenance service writes after each tick.
+#[derive(Debug, Clone, Default)]
+pub struct EcsMaintenanceMonitor {
+    sample: Arc<Mutex<MaintenanceSample>>,
+}
+
+impl EcsMaintenanceMonitor {
+    pub(crate) fn publish(&self, sample: MaintenanceSample) {
+        if let Ok(mut guard) = self.sample.lock() {
+            *guard = sample;
+        }
+    }
+
+    fn sample(&self) -> MaintenanceSample {
+        self.sample.lock().map(|s| *s).unwrap_or_default()
+    }
+
+    /// Page occupancy measured by the last maintenance tick.
+    pub fn occupancy(&self) -> PageOccupancy {
+        self.sample().occupancy
+    }
+}
+
+impl ResourceMonitor for EcsMaintenanceMonitor {
+    fn monitor_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed("EcsPages")
+    }
+
+    fn resource_type(&self) -> MonitoredResourceType {
+        MonitoredResourceType::SystemRam
+    }
+
+    fn get_usage_report(&self) -> ResourceUsageReport {
+        ResourceUsageReport::default()
+    }
+
+    fn get_metrics(&self) -> Vec<(MetricId, MetricValue)> {
+        let sample = self.sample();
+        let occupancy = sample.occupancy;
+        let gauge =
+            |name: &str, value: f64| (MetricId::new("ecs.pages", name), MetricValue::Gauge(value));
+        vec![
+            gauge("pages", occupancy.pages as f64),
+            gauge("empty_pages", occupancy.empty_pages as f64),
+            gauge("sparse_pages", occupancy.sparse_pages as f64),
+            gauge("occupancy", occupancy.occupancy() as f64),
+            gauge("waste_bytes", occupancy.waste_bytes as f64),
+            gauge("compacted", sample.pages_compacted as f64),
+            gauge("reclaimed_bytes", sample.bytes_reclaimed as f64),
+            gauge("pending", sample.pending as f64),
+        ]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -24,36 +24,85 @@
 //!
 //! The primary entry point for interacting with the ECS is the [`World`] struct.
 
+mod activation;
 mod bitset;
 mod bundle;
+mod change_tracker;
+mod commands;
 pub mod component;
 mod components;
+mod dynamic;
+mod dynamic_column;
+mod dynamic_layout;
 mod entity;
+mod entity_map;
+mod entity_ref;
 mod entity_store;
+mod event_reader;
+mod event_writer;
+mod events;
+mod hierarchy;
+mod layout_report;
 pub mod maintenance;
+mod maintenance_monitor;
+mod name_index;
 mod page;
+mod page_occupancy;
 mod planner;
 mod query;
 mod query_plan;
+mod query_profiler;
 mod registry;
+mod resources;
+mod schedule;
 mod serialization;
+mod snapshot;
+mod sparse_set;
 mod storage;
+mod tag;
+mod tag_index;
 pub mod system;
 pub mod systems;
+mod transfer;
+mod trigger_callbacks;
+mod trigger_event;
 mod world;
+mod world_channel;
 
 pub use bitset::DomainBitset;
 pub use bundle::ComponentBundle;
+pub use commands::Commands;
 pub use component::Component;
 pub use components::*;
+pub use dynamic::{
+    DynamicComponent, DynamicComponentError, DynamicComponentId, DynamicMut, DynamicRef,
+    MAX_DYNAMIC_COMPONENTS,
+};
+pub use dynamic_layout::{DynamicComponentLayout, DynamicField, DynamicFieldKind, DynamicValue};
 pub use entity::*;
+pub use entity_map::{EntityMap, MapEntities};
+pub use entity_ref::EntityRef;
+pub use event_reader::EventReader;
+pub use event_writer::EventWriter;
+pub use events::Events;
+pub use layout_report::{ArchetypeLayout, ColumnLayout, PageLayout, WorldLayout};
 pub use maintenance::EcsMaintenance;
+pub use maintenance_monitor::EcsMaintenanceMonitor;
 pub use page::*;
+pub use page_occupancy::PageOccupancy;
 pub use query::*;
 pub use query_plan::{QueryMode, QueryPlan};
+pub use query_profiler::{QueryProfile, QueryProfiler};
 pub use registry::*;
+pub use schedule::{SystemAccess, SystemContext, SystemPool, SystemSchedule};
+pub use snapshot::WorldSnapshot;
 pub use system::{DataSystemRegistration, TickPhase};
+pub use tag::Tag;
+pub use transfer::migrate_entities;
+pub use trigger_callbacks::{TriggerCallback, TriggerCallbacks};
+pub use trigger_event::{TriggerEvent, TriggerKind};
 pub use world::*;
+pub use world_channel::{world_channel, WorldChannel, WorldRequest, WorldRequests};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,85 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Looking entities up by their [`Name`].
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{IncludeDisabled, Name, World};
+
+/// Entities by name, rebuilt on the first lookup after a `Name` was added
+/// or written.
+///
+/// Removed names and despawned entities are not tracked; lookups check
+/// every candidate against the world instead.
+#[derive(Debug, Default)]
+pub(crate) struct NameIndex {
+    /// `Name` write count the index was built at, `None` when stale.
+    built_at: Option<u64>,
+    entities: HashMap<String, Vec<EntityId>>,
+}
+
+impl NameIndex {
+    /// Forces a rebuild on the next lookup.
+    pub(crate) fn invalidate(&mut self) {
+        self.built_at = None;
+    }
+}
+
+impl World {
+    /// Returns an entity named `name`, or `None` if there is none.
+    ///
+    /// When several entities share the name, the one with the lowest index
+    /// wins; use [`find_all_by_name`](Self::find_all_by_name) to get them
+    /// all. Lookups are hash-map fast once the index is built,
+    /// which happens again after a [`Name`] is added or changed.
+    pub fn find_by_name(&self, name: &str) -> Option<EntityId> {
+        self.find_all_by_name(name).into_iter().next()
+    }
+
+    /// Returns every entity named `name`.
+    pub fn find_all_by_name(&self, name: &str) -> Vec<EntityId> {
+        let writes = self.changes.write_count(TypeId::of::<Name>());
+        let mut index = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+        if index.built_at != Some(writes) {
+            index.entities.clear();
+            for (entity, entity_name, _) in self.query::<(EntityId, &Name, IncludeDisabled)>() {
+                index
+                    .entities
+                    .entry(entity_name.0.clone())
+                    .or_default()
+                    .push(entity);
+            }
+            for entities in index.entities.values_mut() {
+                entities.sort_by_key(|entity| (entity.index, entity.generation));
+            }
+            index.built_at = Some(writes);
+        }
+        index
+            .entities
+            .get(name)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&entity| {
+                self.get::<Name>(entity)
+                    .is_some_and(|entity_name| entity_name.as_str() == name)
+            })
+            .collect()
+    }
+}
@@ -20,6 +20,9 @@ use std::{
 use bincode::{Decode, Encode};
 use khora_core::ecs::entity::EntityId;
 
+/// Pages holding fewer rows than this are never reported as sparse.
+const MIN_SPARSE_CAPACITY: usize = 16;
+
 /// An internal helper trait to perform vector operations on a type-erased `Box<dyn Any>`.
 ///
 /// This allows us to call methods like `swap_remove` on component columns without
@@ -44,6 +47,32 @@ pub trait AnyVec: Any + Send + Sync {
     /// The caller must guarantee that the bytes represent a valid sequence of `T`
     /// with the correct size and alignment.
     unsafe fn set_from_bytes(&mut self, bytes: &[u8]);
+
+    /// Returns `size_of::<T>()` for the element type of the underlying `Vec`.
+    fn element_size(&self) -> usize;
+
+    /// Returns `true` if `T` owns no resources (`!needs_drop::<T>()`), i.e. its
+    /// raw bytes can be copied in and out without aliasing heap allocations.
+    fn is_plain_data(&self) -> bool;
+
+    /// Returns `align_of::<T>()` for the element type of the underlying `Vec`.
+    fn element_align(&self) -> usize;
+
+    /// Returns `type_name::<T>()` for the element type of the underlying `Vec`.
+    fn element_type_name(&self) -> &'static str;
+
+    /// Returns the number of elements in the underlying `Vec`.
+    fn row_len(&self) -> usize;
+
+    /// Returns the number of elements the underlying `Vec` can hold without
+    /// reallocating.
+    fn row_capacity(&self) -> usize;
+
+    /// Shrinks the underlying `Vec`'s allocation to fit its elements.
+    fn shrink_to_fit_any(&mut self);
+
+    /// Reserves room for at least `additional` more elements.
+    fn reserve_any(&mut self, additional: usize);
 }
 
 // We implement this trait for any `Vec<T>` where T is `'static`.
@@ -88,13 +117,45 @@ impl<T: 'static + Send + Sync> AnyVec for Vec<T> {
         std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
         self.set_len(new_len);
     }
+
+    fn element_size(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    fn is_plain_data(&self) -> bool {
+        !std::mem::needs_drop::<T>()
+    }
+
+    fn element_align(&self) -> usize {
+        std::mem::align_of::<T>()
+    }
+
+    fn element_type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn row_len(&self) -> usize {
+        self.len()
+    }
+
+    fn row_capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn shrink_to_fit_any(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn reserve_any(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 /// A logical address pointing to an entity's component data within a specific `ComponentPage`.
 ///
 /// This struct is the core of the relational aspect of our ECS. It decouples an entity's
 /// identity from the physical storage of its data by acting as a coordinate.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
 pub struct PageIndex {
     /// The unique identifier of the `ComponentPage` that stores the component data.
     pub page_id: u32,
@@ -148,6 +209,15 @@ impl ComponentPage {
         self.entities.push(entity_id);
     }
 
+    /// Reserves room for `additional` more rows in every column and in the
+    /// entity list.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        for column in self.columns.values_mut() {
+            column.reserve_any(additional);
+        }
+        self.entities.reserve(additional);
+    }
+
     /// Performs a `swap_remove` on a specific row across all component columns
     /// and the entity list.
     ///
@@ -174,4 +244,46 @@ impl ComponentPage {
     pub(crate) fn row_count(&self) -> usize {
         self.entities.len()
     }
+
+    /// Returns the number of rows this page can hold before it grows.
+    ///
+    /// Rows are reserved column by column, so the page holds as many rows
+    /// as its fullest column can.
+    pub(crate) fn row_capacity(&self) -> usize {
+        self.columns
+            .values()
+            .map(|column| column.row_capacity())
+            .min()
+            .unwrap_or(0)
+            .max(self.row_count())
+    }
+
+    /// Returns the bytes allocated for component columns and the entity list.
+    pub(crate) fn reserved_bytes(&self) -> usize {
+        let columns: usize = self
+            .columns
+            .values()
+            .map(|column| column.row_capacity() * column.element_size())
+            .sum();
+        columns + self.entities.capacity() * std::mem::size_of::<EntityId>()
+    }
+
+    /// Returns `true` if less than half of the page's capacity holds rows,
+    /// which only happens after entities left it.
+    ///
+    /// Vectors double when they grow, so a page that only ever gained rows
+    /// is at least half full. Small pages are never considered sparse: the
+    /// memory a compaction would give back is not worth the reallocation.
+    pub(crate) fn is_sparse(&self) -> bool {
+        let capacity = self.row_capacity();
+        capacity >= MIN_SPARSE_CAPACITY && self.row_count() * 2 < capacity
+    }
+
+    /// Shrinks every column and the entity list to fit the live rows.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.entities.shrink_to_fit();
+        for column in self.columns.values_mut() {
+            column.shrink_to_fit_any();
+        }
+    }
 }
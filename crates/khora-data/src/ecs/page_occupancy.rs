@@ -0,0 +1,81 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Page occupancy: how much of the memory reserved by CRPECS pages holds
+//! live rows.
+//!
+//! Spawn/despawn churn leaves pages with large allocations and few rows.
+//! [`World::page_occupancy`] summarizes this cheaply enough to run every
+//! frame; [`EcsMaintenance`](super::EcsMaintenance) uses it to report
+//! fragmentation to telemetry. [`World::layout_report`] gives the detailed,
+//! per-column view.
+
+use crate::ecs::World;
+
+/// A summary of how full the world's pages are.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PageOccupancy {
+    /// Pages that store at least one component column.
+    pub pages: usize,
+    /// Pages holding no entity.
+    pub empty_pages: usize,
+    /// Pages less than half full, which a compaction would shrink.
+    pub sparse_pages: usize,
+    /// Entities stored, over every page.
+    pub rows: usize,
+    /// Rows the pages can hold before growing, over every page.
+    pub capacity: usize,
+    /// Bytes reserved for rows that hold no entity.
+    pub waste_bytes: usize,
+}
+
+impl PageOccupancy {
+    /// Share of the capacity holding entities, from `0.0` to `1.0`.
+    ///
+    /// A world without reserved rows is reported as fully occupied.
+    pub fn occupancy(&self) -> f32 {
+        if self.capacity == 0 {
+            1.0
+        } else {
+            self.rows as f32 / self.capacity as f32
+        }
+    }
+}
+
+impl World {
+    /// Summarizes how full the world's pages are.
+    pub fn page_occupancy(&self) -> PageOccupancy {
+        let mut occupancy = PageOccupancy::default();
+        for page in &self.storage.pages {
+            if page.columns.is_empty() {
+                continue;
+            }
+            let rows = page.row_count();
+            let used: usize = page
+                .columns
+                .values()
+                .map(|column| column.row_len() * column.element_size())
+                .sum::<usize>()
+                + rows * std::mem::size_of::<khora_core::ecs::entity::EntityId>();
+
+            occupancy.pages += 1;
+            occupancy.empty_pages += usize::from(rows == 0);
+            occupancy.sparse_pages += usize::from(page.is_sparse());
+            occupancy.rows += rows;
+            occupancy.capacity += page.row_capacity();
+            occupancy.waste_bytes += page.reserved_bytes().saturating_sub(used);
+        }
+        occupancy
+    }
+}
@@ -16,9 +16,10 @@ use khora_core::ecs::entity::EntityId;
 
 use crate::ecs::{
     page::{AnyVec, ComponentPage},
+    query_profiler::QueryProbe,
     Component, DomainBitset, QueryMode, QueryPlan, World,
 };
-use std::{any::TypeId, marker::PhantomData};
+use std::{any::TypeId, marker::PhantomData, time::Instant};
 
 // ------------------------- //
 // ---- WorldQuery Part ---- //
@@ -44,6 +45,49 @@ pub trait WorldQuery {
         Vec::new()
     }
 
+    /// Returns the `TypeId`s of the components borrowed mutably. A mutable
+    /// query stamps them as changed on every entity it yields.
+    fn mut_type_ids() -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    /// Returns the `TypeId`s of every component the query reads or writes,
+    /// optional ones included. The system scheduler uses it to tell which
+    /// systems may run side by side.
+    fn accessed_type_ids() -> Vec<TypeId> {
+        Self::type_ids()
+    }
+
+    /// Returns `true` if the query holds an `Added<T>` or `Changed<T>` filter.
+    fn has_change_filter() -> bool {
+        false
+    }
+
+    /// Returns `true` if the query holds an [`IncludeDisabled`] filter.
+    /// Other queries skip entities carrying
+    /// [`Disabled`](crate::ecs::Disabled), unless they name it themselves.
+    fn includes_disabled() -> bool {
+        false
+    }
+
+    /// Checks the query's `Added<T>` and `Changed<T>` filters for
+    /// `entity_id`, against components added or written after tick `since`.
+    fn matches_changes(_world: &World, _entity_id: EntityId, _since: u32) -> bool {
+        true
+    }
+
+    /// Returns `true` if the query holds an [`Or`] filter, checked entity by
+    /// entity.
+    fn has_entity_filter() -> bool {
+        false
+    }
+
+    /// Checks the query's [`Or`] filters for `entity_id`. `since` is the
+    /// baseline of the `Added<T>` and `Changed<T>` filters they hold.
+    fn matches_filters(_world: &World, _entity_id: EntityId, _since: u32) -> bool {
+        true
+    }
+
     /// Fetches the query's item from a specific row in a `ComponentPage`.
     ///
     /// # Safety
@@ -102,8 +146,13 @@ impl<T: Component> WorldQuery for &T {
     ) -> Option<Self::Item<'a>> {
         let world = &*world;
 
+        // Sparse-set components are looked up by entity, outside the pages.
+        if let Some(set) = world.sparse_set::<T>() {
+            return set.get(entity_id);
+        }
+
         // Get the entity's metadata.
-        let metadata = world.entities.get(entity_id.index as usize)?.1.as_ref()?;
+        let metadata = world.entities.get_metadata(entity_id)?;
 
         // Get the domain for the component type.
         let domain = world.storage.registry.get_domain(TypeId::of::<T>())?;
@@ -127,6 +176,10 @@ impl<T: Component> WorldQuery for &mut T {
         vec![TypeId::of::<T>()]
     }
 
+    fn mut_type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
     /// Fetches a mutable reference to the component `T` from the specified row.
     ///
     /// # Safety
@@ -149,6 +202,9 @@ impl<T: Component> WorldQuery for &mut T {
         entity_id: EntityId,
     ) -> Option<Self::Item<'a>> {
         let world_mut = &mut *(world as *mut World);
+        if world_mut.sparse.contains_key(&TypeId::of::<T>()) {
+            return world_mut.sparse_set_mut::<T>()?.get_mut(entity_id);
+        }
         let metadata = world_mut
             .entities
             .get_mut(entity_id.index as usize)?
@@ -173,6 +229,10 @@ impl<T: Component> WorldQuery for Option<&T> {
         Vec::new()
     }
 
+    fn accessed_type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
     unsafe fn fetch<'a>(page_ptr: *const ComponentPage, row_index: usize) -> Self::Item<'a> {
         let page = &*page_ptr;
         let column = page.columns.get(&TypeId::of::<T>())?;
@@ -185,16 +245,28 @@ impl<T: Component> WorldQuery for Option<&T> {
         entity_id: EntityId,
     ) -> Option<Self::Item<'a>> {
         let world = &*world;
-        let metadata = world.entities.get(entity_id.index as usize)?.1.as_ref()?;
-        let domain = world.storage.registry.get_domain(TypeId::of::<T>())?;
-        let location = metadata.locations.get(&domain)?;
+        if let Some(set) = world.sparse_set::<T>() {
+            return Some(set.get(entity_id));
+        }
+        let metadata = world.entities.get_metadata(entity_id)?;
+        let Some(location) = world
+            .storage
+            .registry
+            .get_domain(TypeId::of::<T>())
+            .and_then(|domain| metadata.locations.get(&domain))
+        else {
+            return Some(None);
+        };
 
         let page = &world.storage.pages[location.page_id as usize];
-        let column = page.columns.get(&TypeId::of::<T>())?;
-        let vec = column.as_any().downcast_ref::<Vec<T>>()?;
         // For optional in world, we return Some(Option).
         // If the component is missing, we return Some(None).
-        Some(vec.get(location.row_index as usize))
+        Some(
+            page.columns
+                .get(&TypeId::of::<T>())
+                .and_then(|column| column.as_any().downcast_ref::<Vec<T>>())
+                .and_then(|vec| vec.get(location.row_index as usize)),
+        )
     }
 }
 
@@ -206,6 +278,14 @@ impl<T: Component> WorldQuery for Option<&mut T> {
         Vec::new()
     }
 
+    fn mut_type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
+    fn accessed_type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
     unsafe fn fetch<'a>(page_ptr: *const ComponentPage, row_index: usize) -> Self::Item<'a> {
         let page = &mut *(page_ptr as *mut ComponentPage);
         let column = page.columns.get_mut(&TypeId::of::<T>())?;
@@ -218,19 +298,31 @@ impl<T: Component> WorldQuery for Option<&mut T> {
         entity_id: EntityId,
     ) -> Option<Self::Item<'a>> {
         let world_mut = &mut *(world as *mut World);
+        if world_mut.sparse.contains_key(&TypeId::of::<T>()) {
+            return Some(world_mut.sparse_set_mut::<T>()?.get_mut(entity_id));
+        }
         let metadata = world_mut
             .entities
             .get_mut(entity_id.index as usize)?
             .1
             .as_mut()?;
-        let domain = world_mut.storage.registry.get_domain(TypeId::of::<T>())?;
-        let location = metadata.locations.get(&domain)?;
+        let Some(location) = world_mut
+            .storage
+            .registry
+            .get_domain(TypeId::of::<T>())
+            .and_then(|domain| metadata.locations.get(&domain))
+        else {
+            return Some(None);
+        };
 
         let page = &mut world_mut.storage.pages[location.page_id as usize];
-        let column = page.columns.get_mut(&TypeId::of::<T>())?;
-        let vec = column.as_any_mut().downcast_mut::<Vec<T>>()?;
         // Return Some(Option)
-        Some(vec.get_mut(location.row_index as usize))
+        Some(
+            page.columns
+                .get_mut(&TypeId::of::<T>())
+                .and_then(|column| column.as_any_mut().downcast_mut::<Vec<T>>())
+                .and_then(|vec| vec.get_mut(location.row_index as usize)),
+        )
     }
 }
 
@@ -258,6 +350,42 @@ macro_rules! impl_query_tuple {
                 ids
             }
 
+            fn mut_type_ids() -> Vec<TypeId> {
+                let mut ids = Vec::new();
+                $(ids.extend($Q::mut_type_ids());)*
+                ids.sort();
+                ids.dedup();
+                ids
+            }
+
+            fn accessed_type_ids() -> Vec<TypeId> {
+                let mut ids = Vec::new();
+                $(ids.extend($Q::accessed_type_ids());)*
+                ids.sort();
+                ids.dedup();
+                ids
+            }
+
+            fn has_change_filter() -> bool {
+                false $(|| $Q::has_change_filter())*
+            }
+
+            fn includes_disabled() -> bool {
+                false $(|| $Q::includes_disabled())*
+            }
+
+            fn matches_changes(world: &World, entity_id: EntityId, since: u32) -> bool {
+                true $(&& $Q::matches_changes(world, entity_id, since))*
+            }
+
+            fn has_entity_filter() -> bool {
+                false $(|| $Q::has_entity_filter())*
+            }
+
+            fn matches_filters(world: &World, entity_id: EntityId, since: u32) -> bool {
+                true $(&& $Q::matches_filters(world, entity_id, since))*
+            }
+
             unsafe fn fetch<'a>(page_ptr: *const ComponentPage, row_index: usize) -> Self::Item<'a> {
                 ($($Q::fetch(page_ptr, row_index),)*)
             }
@@ -345,6 +473,21 @@ pub struct Query<'a, Q: WorldQuery> {
 
     /// Pre-computed bitset intersection for fast-failing transversal lookups.
     combined_bitset: Option<DomainBitset>,
+
+    /// Profiling counters, present only while query profiling is enabled.
+    probe: Option<QueryProbe>,
+
+    /// Baseline tick of the `Added<T>` / `Changed<T>` filters.
+    since: u32,
+
+    /// Whether `Q` holds change filters to check on each entity.
+    change_filtered: bool,
+
+    /// Whether `Q` holds `Or` filters to check on each entity.
+    entity_filtered: bool,
+
+    /// The entities of the driving sparse set (used in Sparse mode).
+    driver_entities: Vec<EntityId>,
 }
 
 impl<'a, Q: WorldQuery> Query<'a, Q> {
@@ -355,6 +498,8 @@ impl<'a, Q: WorldQuery> Query<'a, Q> {
     /// of matching pages as arguments.
     pub(crate) fn new(world: &'a World, plan: QueryPlan, matching_page_indices: Vec<u32>) -> Self {
         let combined_bitset = world.compute_query_bitset(&plan);
+        let probe = world.query_profiler.probe(std::any::type_name::<Q>());
+        let driver_entities = world.sparse_driver_entities(&plan);
         Self {
             world_ptr: world as *const _,
             matching_page_indices,
@@ -363,8 +508,21 @@ impl<'a, Q: WorldQuery> Query<'a, Q> {
             current_row_index: 0,
             _phantom: PhantomData,
             combined_bitset,
+            probe,
+            since: world.last_change_tick(),
+            change_filtered: Q::has_change_filter(),
+            entity_filtered: Q::has_entity_filter(),
+            driver_entities,
         }
     }
+
+    /// Makes `Added<T>` and `Changed<T>` match components added or written
+    /// after tick `since`, instead of since the world's last
+    /// [`clear_trackers`](World::clear_trackers).
+    pub fn since(mut self, since: u32) -> Self {
+        self.since = since;
+        self
+    }
 }
 
 impl<'a, Q: WorldQuery> Iterator for Query<'a, Q> {
@@ -373,15 +531,56 @@ impl<'a, Q: WorldQuery> Iterator for Query<'a, Q> {
 
     /// Advances the iterator and returns the next item.
     fn next(&mut self) -> Option<Self::Item> {
-        // Delegate to the appropriate execution path based on the pre-calculated plan.
+        if self.probe.is_none() {
+            return self.next_planned();
+        }
+        let start = Instant::now();
+        let item = self.next_planned();
+        let pages_touched = (self.current_page_index + 1).min(self.matching_page_indices.len());
+        if let Some(probe) = self.probe.as_mut() {
+            probe.record(start.elapsed(), item.is_some(), pages_touched);
+        }
+        item
+    }
+}
+
+impl<'a, Q: WorldQuery> Query<'a, Q> {
+    /// (Internal) Delegates to the execution path chosen by the plan.
+    fn next_planned(&mut self) -> Option<Q::Item<'a>> {
         match self.plan.mode {
             QueryMode::Native => self.next_native(),
             QueryMode::Transversal => self.next_transversal(),
+            QueryMode::Sparse => self.next_sparse(),
         }
     }
-}
 
-impl<'a, Q: WorldQuery> Query<'a, Q> {
+    /// (Internal) Walks the entities of the driving sparse set and fetches
+    /// the rest of the item per entity, skipping those that lack a part.
+    fn next_sparse(&mut self) -> Option<Q::Item<'a>> {
+        // SAFETY: `world_ptr` comes from the `&'a World` the query was built
+        // from, which stays borrowed for as long as the query lives.
+        let world = unsafe { &*self.world_ptr };
+        while let Some(&entity_id) = self.driver_entities.get(self.current_row_index) {
+            self.current_row_index += 1;
+
+            if self.plan.skip_disabled && !world.is_enabled(entity_id) {
+                continue;
+            }
+            if self.change_filtered && !Q::matches_changes(world, entity_id, self.since) {
+                continue;
+            }
+            if self.entity_filtered && !Q::matches_filters(world, entity_id, self.since) {
+                continue;
+            }
+            // SAFETY: `world` is valid for `'a` (see above), and the items
+            // are shared borrows, so they may alias each other.
+            if let Some(item) = unsafe { Q::fetch_from_world(world as *const _, entity_id) } {
+                return Some(item);
+            }
+        }
+        None
+    }
+
     /// (Internal) Performs a "Native" iteration, fetching data from a single domain.
     /// This is the most efficient execution path.
     fn next_native(&mut self) -> Option<Q::Item<'a>> {
@@ -401,13 +600,26 @@ impl<'a, Q: WorldQuery> Query<'a, Q> {
 
             // 3. Check if there are rows left in the current page.
             if self.current_row_index < page.row_count() {
-                let item = unsafe {
-                    // Safe because the page signature matches the query requirements.
-                    Q::fetch(page as *const _, self.current_row_index)
-                };
+                let row_index = self.current_row_index;
 
                 // Advance the row index for the next call.
                 self.current_row_index += 1;
+
+                let entity_id = page.entities[row_index];
+                if self.plan.skip_disabled && !world.is_enabled(entity_id) {
+                    continue;
+                }
+                if self.change_filtered && !Q::matches_changes(world, entity_id, self.since) {
+                    continue;
+                }
+                if self.entity_filtered && !Q::matches_filters(world, entity_id, self.since) {
+                    continue;
+                }
+
+                let item = unsafe {
+                    // Safe because the page signature matches the query requirements.
+                    Q::fetch(page as *const _, row_index)
+                };
                 return Some(item);
             } else {
                 self.current_page_index += 1;
@@ -442,7 +654,19 @@ impl<'a, Q: WorldQuery> Query<'a, Q> {
                     }
                 }
 
+                if self.plan.skip_disabled && !world.is_enabled(entity_id) {
+                    continue;
+                }
+                if self.change_filtered && !Q::matches_changes(world, entity_id, self.since) {
+                    continue;
+                }
+                if self.entity_filtered && !Q::matches_filters(world, entity_id, self.since) {
+                    continue;
+                }
+
                 // Attempt to fetch the Full item (driver + peers) from the world.
+                // SAFETY: `world` is borrowed for `'a`, and the items are
+                // shared borrows, so they may alias each other.
                 if let Some(item) = unsafe { Q::fetch_from_world(world as *const _, entity_id) } {
                     return Some(item);
                 }
@@ -455,6 +679,35 @@ impl<'a, Q: WorldQuery> Query<'a, Q> {
     }
 }
 
+/// A `WorldQuery` filter that matches entities that have component `T`,
+/// without borrowing it.
+///
+/// `Query<(EntityId, &Transform, With<Light>)>` yields the transform of
+/// every lit entity. On its own, `With<T>` is the same as `&T` with the
+/// value dropped; it is mostly useful inside [`Or`].
+pub struct With<T: Component>(PhantomData<T>);
+
+impl<T: Component> WorldQuery for With<T> {
+    /// This query item is a zero-sized unit type, as it fetches no data.
+    type Item<'a> = ();
+
+    /// The entity must carry `T`.
+    fn type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
+    unsafe fn fetch<'a>(_page_ptr: *const ComponentPage, _row_index: usize) -> Self::Item<'a> {}
+
+    unsafe fn fetch_from_world<'a>(
+        world: *const World,
+        entity_id: EntityId,
+    ) -> Option<Self::Item<'a>> {
+        // SAFETY: the caller's guarantees on `world` are the ones `&T`
+        // needs, and the borrow it returns is dropped right away.
+        <&T as WorldQuery>::fetch_from_world(world, entity_id).map(|_| ())
+    }
+}
+
 /// A `WorldQuery` filter that matches entities that do NOT have component `T`.
 ///
 /// This is used as a marker in a query tuple to exclude entities. For example,
@@ -489,8 +742,12 @@ impl<T: Component> WorldQuery for Without<T> {
         world: *const World,
         entity_id: EntityId,
     ) -> Option<Self::Item<'a>> {
+        // SAFETY: the caller passes a pointer to a live world.
         let world = unsafe { &*world };
-        let metadata = world.entities.get(entity_id.index as usize)?.1.as_ref()?;
+        if let Some(set) = world.sparse_set::<T>() {
+            return (!set.contains(entity_id)).then_some(());
+        }
+        let metadata = world.entities.get_metadata(entity_id)?;
 
         // Check ALL pages associated with this entity.
         // If ANY page contains the forbidden component, filtering failed.
@@ -504,6 +761,238 @@ impl<T: Component> WorldQuery for Without<T> {
     }
 }
 
+/// A `WorldQuery` filter that matches entities whose component `T` was added
+/// since the query's baseline tick.
+///
+/// The baseline is the world's last
+/// [`clear_trackers`](World::clear_trackers) unless the query sets its own
+/// with [`Query::since`]. For example,
+/// `Query<(EntityId, &RigidBody, Added<RigidBody>)>` only yields bodies
+/// spawned or given a `RigidBody` since then.
+pub struct Added<T: Component>(PhantomData<T>);
+
+impl<T: Component> WorldQuery for Added<T> {
+    /// This query item is a zero-sized unit type, as it fetches no data.
+    type Item<'a> = ();
+
+    /// The entity must carry `T` for it to have been added.
+    fn type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
+    fn has_change_filter() -> bool {
+        true
+    }
+
+    fn matches_changes(world: &World, entity_id: EntityId, since: u32) -> bool {
+        world
+            .changes
+            .is_added(TypeId::of::<T>(), entity_id.index, since)
+    }
+
+    unsafe fn fetch<'a>(_page_ptr: *const ComponentPage, _row_index: usize) -> Self::Item<'a> {}
+
+    unsafe fn fetch_from_world<'a>(
+        world: *const World,
+        entity_id: EntityId,
+    ) -> Option<Self::Item<'a>> {
+        // SAFETY: the caller's guarantees on `world` are the ones `&T`
+        // needs, and the borrow it returns is dropped right away.
+        <&T as WorldQuery>::fetch_from_world(world, entity_id).map(|_| ())
+    }
+}
+
+/// A `WorldQuery` filter that matches entities whose component `T` was added
+/// or written since the query's baseline tick.
+///
+/// Writes are tracked through [`World::query_mut`], [`World::get_mut`] and
+/// [`World::get_many_mut`]: borrowing `&mut T` counts as a write, whether or
+/// not the value is modified. `Query<(&Transform, Changed<Transform>)>`
+/// lets transform propagation skip every entity that did not move.
+pub struct Changed<T: Component>(PhantomData<T>);
+
+impl<T: Component> WorldQuery for Changed<T> {
+    /// This query item is a zero-sized unit type, as it fetches no data.
+    type Item<'a> = ();
+
+    /// The entity must carry `T` for it to have changed.
+    fn type_ids() -> Vec<TypeId> {
+        vec![TypeId::of::<T>()]
+    }
+
+    fn has_change_filter() -> bool {
+        true
+    }
+
+    fn matches_changes(world: &World, entity_id: EntityId, since: u32) -> bool {
+        world
+            .changes
+            .is_changed(TypeId::of::<T>(), entity_id.index, since)
+    }
+
+    unsafe fn fetch<'a>(_page_ptr: *const ComponentPage, _row_index: usize) -> Self::Item<'a> {}
+
+    unsafe fn fetch_from_world<'a>(
+        world: *const World,
+        entity_id: EntityId,
+    ) -> Option<Self::Item<'a>> {
+        // SAFETY: the caller's guarantees on `world` are the ones `&T`
+        // needs, and the borrow it returns is dropped right away.
+        <&T as WorldQuery>::fetch_from_world(world, entity_id).map(|_| ())
+    }
+}
+
+/// A `WorldQuery` filter that lets disabled entities through.
+///
+/// Queries skip entities carrying [`Disabled`](crate::ecs::Disabled) by
+/// default, so switched-off subtrees drop out of rendering, physics and
+/// audio without every system filtering them. Bookkeeping that must see the
+/// whole world (hierarchy upkeep, asset residency, scene tools) adds this
+/// filter: `Query<(EntityId, &Parent, IncludeDisabled)>`. A query naming
+/// `Disabled` itself, like `Query<(&RigidBody, &Disabled)>`, also sees them.
+pub struct IncludeDisabled;
+
+impl WorldQuery for IncludeDisabled {
+    /// This query item is a zero-sized unit type, as it fetches no data.
+    type Item<'a> = ();
+
+    fn type_ids() -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    fn includes_disabled() -> bool {
+        true
+    }
+
+    unsafe fn fetch<'a>(_page_ptr: *const ComponentPage, _row_index: usize) -> Self::Item<'a> {}
+
+    unsafe fn fetch_from_world<'a>(
+        _world: *const World,
+        _entity_id: EntityId,
+    ) -> Option<Self::Item<'a>> {
+        Some(())
+    }
+}
+
+/// A query part that only filters entities, and can be checked for any
+/// entity without borrowing its components.
+///
+/// Implemented by [`With`], [`Without`], [`Added`], [`Changed`], [`Or`],
+/// and tuples of filters, which match when all their parts do. [`Or`]
+/// accepts filters only.
+pub trait QueryFilter: WorldQuery {
+    /// Returns `true` if `entity_id` passes the filter. `since` is the
+    /// baseline tick of `Added<T>` and `Changed<T>`.
+    fn matches(world: &World, entity_id: EntityId, since: u32) -> bool;
+}
+
+impl<T: Component> QueryFilter for With<T> {
+    fn matches(world: &World, entity_id: EntityId, _since: u32) -> bool {
+        world.get::<T>(entity_id).is_some()
+    }
+}
+
+impl<T: Component> QueryFilter for Without<T> {
+    fn matches(world: &World, entity_id: EntityId, _since: u32) -> bool {
+        world.entities.get_metadata(entity_id).is_some() && world.get::<T>(entity_id).is_none()
+    }
+}
+
+impl<T: Component> QueryFilter for Added<T> {
+    fn matches(world: &World, entity_id: EntityId, since: u32) -> bool {
+        world.get::<T>(entity_id).is_some()
+            && world
+                .changes
+                .is_added(TypeId::of::<T>(), entity_id.index, since)
+    }
+}
+
+impl<T: Component> QueryFilter for Changed<T> {
+    fn matches(world: &World, entity_id: EntityId, since: u32) -> bool {
+        world.get::<T>(entity_id).is_some()
+            && world
+                .changes
+                .is_changed(TypeId::of::<T>(), entity_id.index, since)
+    }
+}
+
+/// A `WorldQuery` filter that matches entities passing at least one of the
+/// filters in the tuple `F`.
+///
+/// ```rust,ignore
+/// // Every entity that emits light, whichever way it does.
+/// for (entity, light, emissive, _) in world.query::<(
+///     EntityId,
+///     Option<&Light>,
+///     Option<&EmissiveMaterial>,
+///     Or<(With<Light>, With<EmissiveMaterial>)>,
+/// )>() {
+///     // ...
+/// }
+/// ```
+///
+/// The filters are checked entity by entity. When the rest of the query
+/// requires no component, the query walks every live entity once.
+pub struct Or<F>(PhantomData<F>);
+
+macro_rules! impl_or_filter {
+    ($($F:ident),*) => {
+        impl<$($F: QueryFilter),*> WorldQuery for Or<($($F,)*)> {
+            /// This query item is a zero-sized unit type, as it fetches no data.
+            type Item<'a> = ();
+
+            /// No single component is required, so `Or` leaves the page
+            /// signature alone.
+            fn type_ids() -> Vec<TypeId> {
+                Vec::new()
+            }
+
+            fn accessed_type_ids() -> Vec<TypeId> {
+                let mut ids = Vec::new();
+                $(ids.extend($F::accessed_type_ids());)*
+                ids.sort();
+                ids.dedup();
+                ids
+            }
+
+            fn has_entity_filter() -> bool {
+                true
+            }
+
+            fn matches_filters(world: &World, entity_id: EntityId, since: u32) -> bool {
+                <Self as QueryFilter>::matches(world, entity_id, since)
+            }
+
+            unsafe fn fetch<'a>(_page_ptr: *const ComponentPage, _row_index: usize) -> Self::Item<'a> {}
+
+            unsafe fn fetch_from_world<'a>(
+                _world: *const World,
+                _entity_id: EntityId,
+            ) -> Option<Self::Item<'a>> {
+                Some(())
+            }
+        }
+
+        impl<$($F: QueryFilter),*> QueryFilter for Or<($($F,)*)> {
+            fn matches(world: &World, entity_id: EntityId, since: u32) -> bool {
+                false $(|| $F::matches(world, entity_id, since))*
+            }
+        }
+
+        impl<$($F: QueryFilter),*> QueryFilter for ($($F,)*) {
+            fn matches(world: &World, entity_id: EntityId, since: u32) -> bool {
+                true $(&& $F::matches(world, entity_id, since))*
+            }
+        }
+    };
+}
+
+impl_or_filter!(F1, F2);
+impl_or_filter!(F1, F2, F3);
+impl_or_filter!(F1, F2, F3, F4);
+impl_or_filter!(F1, F2, F3, F4, F5);
+impl_or_filter!(F1, F2, F3, F4, F5, F6);
+
 // ------------------------- //
 // ---- QueryMut Part ---- //
 // ------------------------- //
@@ -520,6 +1009,19 @@ pub struct QueryMut<'a, Q: WorldQuery> {
     _phantom: PhantomData<(&'a (), Q)>,
     /// Pre-computed bitset intersection for fast-failing transversal lookups.
     combined_bitset: Option<DomainBitset>,
+    /// Profiling counters, present only while query profiling is enabled.
+    probe: Option<QueryProbe>,
+    /// Baseline tick of the `Added<T>` / `Changed<T>` filters.
+    since: u32,
+    /// Whether `Q` holds change filters to check on each entity.
+    change_filtered: bool,
+
+    /// Whether `Q` holds `Or` filters to check on each entity.
+    entity_filtered: bool,
+    /// Components stamped as changed on each yielded entity.
+    mut_type_ids: Vec<TypeId>,
+    /// The entities of the driving sparse set (used in Sparse mode).
+    driver_entities: Vec<EntityId>,
 }
 
 impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
@@ -532,6 +1034,9 @@ impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
         matching_page_indices: Vec<u32>,
     ) -> Self {
         let combined_bitset = world.compute_query_bitset(&plan);
+        let probe = world.query_profiler.probe(std::any::type_name::<Q>());
+        let since = world.last_change_tick();
+        let driver_entities = world.sparse_driver_entities(&plan);
         Self {
             world_ptr: world as *mut _,
             matching_page_indices,
@@ -540,22 +1045,79 @@ impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
             current_row_index: 0,
             _phantom: PhantomData,
             combined_bitset,
+            probe,
+            since,
+            change_filtered: Q::has_change_filter(),
+            entity_filtered: Q::has_entity_filter(),
+            mut_type_ids: Q::mut_type_ids(),
+            driver_entities,
         }
     }
+
+    /// Makes `Added<T>` and `Changed<T>` match components added or written
+    /// after tick `since`, instead of since the world's last
+    /// [`clear_trackers`](World::clear_trackers).
+    pub fn since(mut self, since: u32) -> Self {
+        self.since = since;
+        self
+    }
 }
 
 impl<'a, Q: WorldQuery> Iterator for QueryMut<'a, Q> {
     type Item = Q::Item<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.probe.is_none() {
+            return self.next_planned();
+        }
+        let start = Instant::now();
+        let item = self.next_planned();
+        let pages_touched = (self.current_page_index + 1).min(self.matching_page_indices.len());
+        if let Some(probe) = self.probe.as_mut() {
+            probe.record(start.elapsed(), item.is_some(), pages_touched);
+        }
+        item
+    }
+}
+
+impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
+    fn next_planned(&mut self) -> Option<Q::Item<'a>> {
         match self.plan.mode {
             QueryMode::Native => self.next_native(),
             QueryMode::Transversal => self.next_transversal(),
+            QueryMode::Sparse => self.next_sparse(),
         }
     }
-}
 
-impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
+    fn next_sparse(&mut self) -> Option<Q::Item<'a>> {
+        // SAFETY: `world_ptr` comes from the `&'a mut World` the query was
+        // built from, which stays exclusively borrowed for as long as the
+        // query lives.
+        let world = unsafe { &mut *self.world_ptr };
+        while let Some(&entity_id) = self.driver_entities.get(self.current_row_index) {
+            self.current_row_index += 1;
+
+            if self.plan.skip_disabled && !world.is_enabled(entity_id) {
+                continue;
+            }
+            if self.change_filtered && !Q::matches_changes(world, entity_id, self.since) {
+                continue;
+            }
+            if self.entity_filtered && !Q::matches_filters(world, entity_id, self.since) {
+                continue;
+            }
+            // SAFETY: the driving set lists each entity once, so no two
+            // items returned by this query point at the same components.
+            if let Some(item) = unsafe { Q::fetch_from_world(world as *const _, entity_id) } {
+                for type_id in &self.mut_type_ids {
+                    world.changes.mark_changed(*type_id, entity_id.index);
+                }
+                return Some(item);
+            }
+        }
+        None
+    }
+
     fn next_native(&mut self) -> Option<Q::Item<'a>> {
         loop {
             if self.current_page_index >= self.matching_page_indices.len() {
@@ -566,14 +1128,30 @@ impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
             let world = unsafe { &mut *self.world_ptr };
             let page_id = self.matching_page_indices[self.current_page_index] as usize;
 
-            // We get a mutable reference to the page, which is a safe operation
-            // because `world` is a mutable reference.
-            let page = &mut world.storage.pages[page_id];
+            let page = &world.storage.pages[page_id];
 
             if self.current_row_index < page.row_count() {
-                let item = unsafe { Q::fetch(page as *mut _ as *const _, self.current_row_index) };
-
+                let row_index = self.current_row_index;
                 self.current_row_index += 1;
+
+                let entity_id = page.entities[row_index];
+                if self.plan.skip_disabled && !world.is_enabled(entity_id) {
+                    continue;
+                }
+                if self.change_filtered && !Q::matches_changes(world, entity_id, self.since) {
+                    continue;
+                }
+                if self.entity_filtered && !Q::matches_filters(world, entity_id, self.since) {
+                    continue;
+                }
+
+                // We get a mutable reference to the page, which is a safe operation
+                // because `world` is a mutable reference.
+                let page = &mut world.storage.pages[page_id];
+                let item = unsafe { Q::fetch(page as *mut _ as *const _, row_index) };
+                for type_id in &self.mut_type_ids {
+                    world.changes.mark_changed(*type_id, entity_id.index);
+                }
                 return Some(item);
             } else {
                 self.current_page_index += 1;
@@ -605,8 +1183,24 @@ impl<'a, Q: WorldQuery> QueryMut<'a, Q> {
                     }
                 }
 
+                if self.plan.skip_disabled && !world.is_enabled(entity_id) {
+                    continue;
+                }
+                if self.change_filtered && !Q::matches_changes(world, entity_id, self.since) {
+                    continue;
+                }
+                if self.entity_filtered && !Q::matches_filters(world, entity_id, self.since) {
+                    continue;
+                }
+
                 // Attempt to fetch the Full item (driver + peers) from the world.
+                // SAFETY: each entity sits in a single row of the driving
+                // domain, so no two items returned by this query point at
+                // the same components.
                 if let Some(item) = unsafe { Q::fetch_from_world(world as *const _, entity_id) } {
+                    for type_id in &self.mut_type_ids {
+                        world.changes.mark_changed(*type_id, entity_id.index);
+                    }
                     return Some(item);
                 }
             } else {
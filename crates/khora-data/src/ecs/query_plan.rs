@@ -26,6 +26,11 @@ pub enum QueryMode {
     /// Join path: requested components span multiple domains.
     /// Iteration is "driven" by one domain and peers are looked up via bitsets.
     Transversal,
+    /// Sparse path: the query requires a sparse-set component. Iteration
+    /// walks the entities of the smallest such set and looks every other
+    /// component up per entity. Without a driving set, it walks every live
+    /// entity.
+    Sparse,
 }
 
 /// A pre-calculated execution strategy for a specific set of component types.
@@ -42,6 +47,12 @@ pub struct QueryPlan {
     pub peer_domains: HashSet<SemanticDomain>,
     /// The signature used to find matching pages (driver domain components).
     pub driver_signature: Vec<TypeId>,
+    /// If Sparse, the sparse-set component whose entities drive the
+    /// iteration, or `None` to walk every live entity.
+    pub sparse_driver: Option<TypeId>,
+    /// Whether disabled entities must be skipped one by one, because the
+    /// matched pages may hold them.
+    pub skip_disabled: bool,
 }
 
 impl QueryPlan {
@@ -61,6 +72,33 @@ impl QueryPlan {
             driver_domain,
             peer_domains,
             driver_signature,
+            sparse_driver: None,
+            skip_disabled: false,
+        }
+    }
+
+    /// Creates a plan iterating the entities of the sparse set of `driver`.
+    pub fn sparse(driver: TypeId) -> Self {
+        Self {
+            mode: QueryMode::Sparse,
+            driver_domain: None,
+            peer_domains: HashSet::new(),
+            driver_signature: Vec::new(),
+            sparse_driver: Some(driver),
+            skip_disabled: false,
+        }
+    }
+
+    /// Creates a plan walking every live entity once, for queries that
+    /// require no component and filter entities one by one.
+    pub fn entity_scan() -> Self {
+        Self {
+            mode: QueryMode::Sparse,
+            driver_domain: None,
+            peer_domains: HashSet::new(),
+            driver_signature: Vec::new(),
+            sparse_driver: None,
+            skip_disabled: false,
         }
     }
 }
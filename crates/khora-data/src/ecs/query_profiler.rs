@@ -0,0 +1,229 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opt-in per-query profiling for the CRPECS.
+//!
+//! When enabled, every [`Query`](super::Query) and [`QueryMut`](super::QueryMut)
+//! reports, per query signature, the time spent inside the iterator, the
+//! entities it yielded and the pages it walked. Counters are collected over
+//! a frame and published by [`QueryProfiler::end_frame`].
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use khora_core::telemetry::metrics::{MetricId, MetricValue};
+use khora_core::telemetry::monitoring::{
+    MonitoredResourceType, ResourceMonitor, ResourceUsageReport,
+};
+
+/// What one query signature cost over a frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryProfile {
+    /// The query type, e.g. `(&Transform, &mut Velocity)`.
+    pub signature: &'static str,
+    /// Number of queries created with this signature.
+    pub runs: u64,
+    /// Time spent inside the query iterators, excluding the caller's loop body.
+    pub time: Duration,
+    /// Number of entities yielded.
+    pub entities_matched: u64,
+    /// Number of pages walked.
+    pub pages_touched: u64,
+}
+
+#[derive(Debug, Default)]
+struct QueryCounters {
+    runs: AtomicU64,
+    nanos: AtomicU64,
+    matched: AtomicU64,
+    pages: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct ProfilerState {
+    enabled: AtomicBool,
+    current: Mutex<HashMap<&'static str, Arc<QueryCounters>>>,
+    last_frame: Mutex<Vec<QueryProfile>>,
+}
+
+/// Collects per-query timings, match counts and page counts.
+///
+/// Disabled by default, so queries pay nothing but a flag check. Clones
+/// share their counters: the engine keeps one in the service registry,
+/// hands it to the world, and reports it to telemetry as the
+/// `"EcsQueries"` monitor.
+///
+/// ```rust,ignore
+/// let profiler = services.get::<QueryProfiler>().unwrap();
+/// profiler.set_enabled(true);
+/// // ... a few frames later
+/// log::info!("{}", profiler.report());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryProfiler {
+    state: Arc<ProfilerState>,
+}
+
+impl QueryProfiler {
+    /// Creates a disabled profiler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turns profiling on or off. Counters of the current frame are kept.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.state.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if queries are being profiled.
+    pub fn is_enabled(&self) -> bool {
+        self.state.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Starts profiling one query run, if profiling is enabled.
+    pub(crate) fn probe(&self, signature: &'static str) -> Option<QueryProbe> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let counters = self
+            .state
+            .current
+            .lock()
+            .ok()?
+            .entry(signature)
+            .or_default()
+            .clone();
+        counters.runs.fetch_add(1, Ordering::Relaxed);
+        Some(QueryProbe {
+            counters,
+            pages_recorded: 0,
+        })
+    }
+
+    /// Publishes the counters collected since the last call and starts a
+    /// new frame. Called once per frame by the engine.
+    pub fn end_frame(&self) {
+        let Ok(mut current) = self.state.current.lock() else {
+            return;
+        };
+        let mut profiles: Vec<QueryProfile> = current
+            .drain()
+            .map(|(signature, counters)| QueryProfile {
+                signature,
+                runs: counters.runs.load(Ordering::Relaxed),
+                time: Duration::from_nanos(counters.nanos.load(Ordering::Relaxed)),
+                entities_matched: counters.matched.load(Ordering::Relaxed),
+                pages_touched: counters.pages.load(Ordering::Relaxed),
+            })
+            .collect();
+        drop(current);
+        profiles.sort_by(|a, b| b.time.cmp(&a.time).then(a.signature.cmp(b.signature)));
+        if let Ok(mut last_frame) = self.state.last_frame.lock() {
+            *last_frame = profiles;
+        }
+    }
+
+    /// Returns the profiles of the last finished frame, most expensive first.
+    pub fn last_frame(&self) -> Vec<QueryProfile> {
+        self.state
+            .last_frame
+            .lock()
+            .map(|profiles| profiles.clone())
+            .unwrap_or_default()
+    }
+
+    /// Formats the last finished frame as a table, one query per line.
+    pub fn report(&self) -> String {
+        let profiles = self.last_frame();
+        let mut out = String::from("    time_us   runs  matched  pages  query\n");
+        for p in &profiles {
+            let _ = writeln!(
+                out,
+                "{:>11.1} {:>6} {:>8} {:>6}  {}",
+                p.time.as_secs_f64() * 1_000_000.0,
+                p.runs,
+                p.entities_matched,
+                p.pages_touched,
+                p.signature
+            );
+        }
+        out
+    }
+}
+
+impl ResourceMonitor for QueryProfiler {
+    fn monitor_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed("EcsQueries")
+    }
+
+    fn resource_type(&self) -> MonitoredResourceType {
+        MonitoredResourceType::SystemRam
+    }
+
+    fn get_usage_report(&self) -> ResourceUsageReport {
+        ResourceUsageReport::default()
+    }
+
+    fn get_metrics(&self) -> Vec<(MetricId, MetricValue)> {
+        let mut metrics = Vec::new();
+        for p in self.last_frame() {
+            let gauge = |name: &str, value: f64| {
+                (
+                    MetricId::new("ecs.query", name).with_label("query", p.signature),
+                    MetricValue::Gauge(value),
+                )
+            };
+            metrics.push(gauge("time_us", p.time.as_secs_f64() * 1_000_000.0));
+            metrics.push(gauge("runs", p.runs as f64));
+            metrics.push(gauge("entities_matched", p.entities_matched as f64));
+            metrics.push(gauge("pages_touched", p.pages_touched as f64));
+        }
+        metrics
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Counters of one query run, held by the query iterator.
+pub(crate) struct QueryProbe {
+    counters: Arc<QueryCounters>,
+    pages_recorded: usize,
+}
+
+impl QueryProbe {
+    /// Records one `next()` call that took `elapsed`, yielded an item if
+    /// `matched`, and left the iterator having walked `pages_touched` pages.
+    pub(crate) fn record(&mut self, elapsed: Duration, matched: bool, pages_touched: usize) {
+        let counters = &self.counters;
+        counters
+            .nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+        if matched {
+            counters.matched.fetch_add(1, Ordering::Relaxed);
+        }
+        if pages_touched > self.pages_recorded {
+            counters.pages.fetch_add(
+                (pages_touched - self.pages_recorded) as u64,
+                Ordering::Relaxed,
+            );
+            self.pages_recorded = pages_touched;
+        }
+    }
+}
@@ -16,7 +16,7 @@
 
 use bincode::{Decode, Encode};
 
-use crate::ecs::{AnyVec, Component};
+use crate::ecs::{dynamic_column::DynamicColumn, AnyVec, Component};
 use std::{
     any::{self, TypeId},
     collections::HashMap,
@@ -25,6 +25,9 @@ use std::{
 /// Type alias for the row copy function pointer.
 type RowCopyFn = unsafe fn(&dyn AnyVec, usize, &mut dyn AnyVec);
 
+/// Type alias for the column clone function pointer.
+type ColumnCloneFn = fn(&dyn AnyVec) -> Box<dyn AnyVec>;
+
 /// Defines the semantic domains a component can belong to.
 ///
 /// This is used by the [`ComponentRegistry`] to map a component type to its
@@ -45,7 +48,7 @@ pub enum SemanticDomain {
 }
 
 /// Stores the set of type-erased functions for a registered component.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct ComponentVTable {
     /// The semantic domain this component belongs to.
     domain: SemanticDomain,
@@ -53,6 +56,8 @@ struct ComponentVTable {
     create_column: fn() -> Box<dyn AnyVec>,
     /// Copies a single element from a source column to a destination column.
     copy_row: RowCopyFn,
+    /// Clones a whole column.
+    clone_column: ColumnCloneFn,
 }
 
 /// A registry that maps component types to their semantic domains.
@@ -60,7 +65,7 @@ struct ComponentVTable {
 /// This is a critical internal part of the `World`. It provides a single source
 /// of truth for determining which semantic group a component's data belongs to,
 /// enabling the `World` to correctly store and retrieve component data from pages.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ComponentRegistry {
     /// Maps a component's `TypeId` to its VTable of operations.
     mapping: HashMap<TypeId, ComponentVTable>,
@@ -79,10 +84,55 @@ impl ComponentRegistry {
                     let dest_vec = dest_col.as_any_mut().downcast_mut::<Vec<T>>().unwrap();
                     dest_vec.push(src_vec.get_unchecked(src_row).clone());
                 },
+                clone_column: |column| {
+                    Box::new(column.as_any().downcast_ref::<Vec<T>>().unwrap().clone())
+                },
+            },
+        );
+    }
+
+    /// (Internal) Registers the storage slot of a dynamic component. Its
+    /// columns are [`DynamicColumn`]s built by `create_column`.
+    pub(crate) fn register_dynamic(
+        &mut self,
+        type_id: TypeId,
+        domain: SemanticDomain,
+        create_column: fn() -> Box<dyn AnyVec>,
+    ) {
+        self.mapping.insert(
+            type_id,
+            ComponentVTable {
+                domain,
+                create_column,
+                copy_row: |src_col, src_row, dest_col| {
+                    let src = src_col.as_any().downcast_ref::<DynamicColumn>().unwrap();
+                    let dest = dest_col
+                        .as_any_mut()
+                        .downcast_mut::<DynamicColumn>()
+                        .unwrap();
+                    dest.push(src.row(src_row));
+                },
+                clone_column: |column| {
+                    Box::new(
+                        column
+                            .as_any()
+                            .downcast_ref::<DynamicColumn>()
+                            .unwrap()
+                            .clone(),
+                    )
+                },
             },
         );
     }
 
+    /// (Internal) Copies the registration of `type_id` from `other`, unless
+    /// this registry already knows the type.
+    pub(crate) fn import(&mut self, other: &ComponentRegistry, type_id: TypeId) {
+        if let Some(vtable) = other.mapping.get(&type_id) {
+            self.mapping.entry(type_id).or_insert(*vtable);
+        }
+    }
+
     /// Looks up the `SemanticDomain` for a given `TypeId`.
     pub fn get_domain(&self, type_id: TypeId) -> Option<SemanticDomain> {
         self.mapping.get(&type_id).map(|vtable| vtable.domain)
@@ -101,6 +151,11 @@ impl ComponentRegistry {
         self.mapping.get(type_id).map(|vtable| vtable.copy_row)
     }
 
+    /// (Internal) Gets the column clone function for a given TypeId.
+    pub(crate) fn get_column_cloner(&self, type_id: &TypeId) -> Option<ColumnCloneFn> {
+        self.mapping.get(type_id).map(|vtable| vtable.clone_column)
+    }
+
     /// (Internal) Creates empty columns for a given type signature.
     pub(crate) fn create_columns_for_signature(
         &self,
@@ -116,7 +171,7 @@ impl ComponentRegistry {
 }
 
 /// A registry that provides reflection data, like type names.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TypeRegistry {
     /// Maps a component's `TypeId` to its string name.
     id_to_name: HashMap<TypeId, String>,
@@ -133,6 +188,25 @@ impl TypeRegistry {
         self.name_to_id.insert(type_name, type_id);
     }
 
+    /// Registers a type under an explicit name, for components without a
+    /// Rust type such as dynamic components.
+    pub(crate) fn register_named(&mut self, type_id: TypeId, type_name: &str) {
+        self.id_to_name.insert(type_id, type_name.to_string());
+        self.name_to_id.insert(type_name.to_string(), type_id);
+    }
+
+    /// Copies the name of `type_id` from `other`, unless this registry
+    /// already knows the type.
+    pub(crate) fn import(&mut self, other: &TypeRegistry, type_id: TypeId) {
+        if self.id_to_name.contains_key(&type_id) {
+            return;
+        }
+        if let Some(name) = other.id_to_name.get(&type_id) {
+            self.id_to_name.insert(type_id, name.clone());
+            self.name_to_id.insert(name.clone(), type_id);
+        }
+    }
+
     /// Gets the string name for a given TypeId.
     pub(crate) fn get_name_of(&self, type_id: &TypeId) -> Option<&str> {
         self.id_to_name.get(type_id).map(|s| s.as_str())
@@ -0,0 +1,102 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! World-level resources: one value per type, outside the component pages.
+//!
+//! Global state such as time, input state or settings lives in the world as
+//! a resource instead of being threaded through application methods. Any
+//! `Send + Sync + 'static` type can be a resource. Data systems reach them
+//! through `&mut World`, parallel systems through
+//! [`SystemContext::resource`](crate::ecs::SystemContext::resource), and an
+//! agent hands one to its lanes by inserting a `Ref` to it in the
+//! `LaneContext`.
+//!
+//! ```rust,ignore
+//! world.insert_resource(GameSettings { difficulty: 2 });
+//! let difficulty = world.get_resource::<GameSettings>().map(|s| s.difficulty);
+//! world.resource_or_default::<Score>().0 += 10;
+//! ```
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use crate::ecs::World;
+
+/// The type map holding a world's resources.
+#[derive(Default)]
+pub(crate) struct Resources {
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Resources {
+    fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    fn get_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.values.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+}
+
+impl World {
+    /// Stores `value` as the world's `T` resource and returns the previous
+    /// one, if any.
+    pub fn insert_resource<T: Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        self.resources
+            .values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|old| old.downcast().ok().map(|old| *old))
+    }
+
+    /// Returns the `T` resource, if one was inserted.
+    pub fn get_resource<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.resources.get::<T>()
+    }
+
+    /// Returns the `T` resource mutably, if one was inserted.
+    pub fn get_resource_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.resources.get_mut::<T>()
+    }
+
+    /// Returns the `T` resource, inserting `T::default()` first if needed.
+    pub fn resource_or_default<T: Default + Send + Sync + 'static>(&mut self) -> &mut T {
+        self.resource_or_insert_with(T::default)
+    }
+
+    /// Returns the `T` resource, inserting `f()` first if needed.
+    pub fn resource_or_insert_with<T: Send + Sync + 'static>(
+        &mut self,
+        f: impl FnOnce() -> T,
+    ) -> &mut T {
+        self.resources
+            .values
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(f()))
+            .downcast_mut()
+            .expect("resource stored under the TypeId of another type")
+    }
+
+    /// Removes the `T` resource and returns it.
+    pub fn remove_resource<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.resources
+            .values
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok().map(|value| *value))
+    }
+
+    /// Returns `true` if a `T` resource was inserted.
+    pub fn contains_resource<T: Send + Sync + 'static>(&self) -> bool {
+        self.resources.values.contains_key(&TypeId::of::<T>())
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Declared component access of a system.
+
+use std::any::TypeId;
+
+use crate::ecs::{query::WorldQuery, Component};
+
+/// The components a system reads and writes.
+///
+/// Two systems conflict when one writes a component the other reads or
+/// writes, or when either is exclusive. Conflicting systems never run at
+/// the same time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SystemAccess {
+    reads: Vec<TypeId>,
+    writes: Vec<TypeId>,
+    exclusive: bool,
+}
+
+impl SystemAccess {
+    /// An access that touches no component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The access of a system that needs the whole world mutably.
+    pub fn exclusive() -> Self {
+        Self {
+            exclusive: true,
+            ..Self::default()
+        }
+    }
+
+    /// The access needed to run query `Q`: its `&mut T` and
+    /// `Option<&mut T>` as writes, every other component as a read.
+    pub fn of<Q: WorldQuery>() -> Self {
+        Self::new().with_query::<Q>()
+    }
+
+    /// Adds a read of `T`.
+    pub fn read<T: Component>(mut self) -> Self {
+        insert(&mut self.reads, TypeId::of::<T>());
+        self
+    }
+
+    /// Adds a write of `T`.
+    pub fn write<T: Component>(mut self) -> Self {
+        insert(&mut self.writes, TypeId::of::<T>());
+        self
+    }
+
+    /// Adds the access needed to run query `Q`.
+    pub fn with_query<Q: WorldQuery>(mut self) -> Self {
+        let writes = Q::mut_type_ids();
+        for type_id in Q::accessed_type_ids() {
+            if !writes.contains(&type_id) {
+                insert(&mut self.reads, type_id);
+            }
+        }
+        for type_id in writes {
+            insert(&mut self.writes, type_id);
+        }
+        self
+    }
+
+    /// Returns `true` if the system needs the whole world mutably.
+    pub fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+
+    /// Returns `true` if this access and `other` cannot run at the same time.
+    pub fn conflicts_with(&self, other: &SystemAccess) -> bool {
+        if self.exclusive || other.exclusive {
+            return true;
+        }
+        self.writes
+            .iter()
+            .any(|t| other.reads.contains(t) || other.writes.contains(t))
+            || other.writes.iter().any(|t| self.reads.contains(t))
+    }
+
+    /// Returns `true` if running query `Q` stays within this access.
+    pub fn allows<Q: WorldQuery>(&self) -> bool {
+        if self.exclusive {
+            return true;
+        }
+        Q::mut_type_ids().iter().all(|t| self.writes.contains(t))
+            && Q::accessed_type_ids()
+                .iter()
+                .all(|t| self.reads.contains(t) || self.writes.contains(t))
+    }
+
+    /// Returns `true` if reading `T` stays within this access.
+    pub(crate) fn allows_read(&self, type_id: TypeId) -> bool {
+        self.exclusive || self.reads.contains(&type_id) || self.writes.contains(&type_id)
+    }
+}
+
+fn insert(ids: &mut Vec<TypeId>, type_id: TypeId) {
+    if let Err(index) = ids.binary_search(&type_id) {
+        ids.insert(index, type_id);
+    }
+}
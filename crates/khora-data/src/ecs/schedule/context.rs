@@ -0,0 +1,122 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The world view handed to parallel systems.
+
+use std::any::TypeId;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use khora_core::{ecs::entity::EntityId, ServiceRegistry};
+
+use crate::ecs::{query::WorldQuery, Commands, Component, EventReader, Events, Query, World};
+
+use super::SystemAccess;
+
+/// Shared access to the world for one run of a parallel system.
+///
+/// Queries and reads are checked against the system's declared
+/// [`SystemAccess`], and panic outside of it: touching an undeclared
+/// component could race with a system running on another thread.
+///
+/// Components a query can write are stamped for `Changed<T>` once the
+/// batch is done, on every entity the query matches, as
+/// [`World::query_mut`] would have. Structural changes go through
+/// [`commands`](Self::commands).
+pub struct SystemContext<'w> {
+    pub(super) name: &'w str,
+    pub(super) world: &'w World,
+    pub(super) services: &'w ServiceRegistry,
+    pub(super) access: &'w SystemAccess,
+    pub(super) commands: Mutex<Commands>,
+    /// The writing queries run, replayed on the world after the batch.
+    pub(super) writes: Mutex<Vec<fn(&mut World)>>,
+}
+
+impl<'w> SystemContext<'w> {
+    /// The system's name.
+    pub fn name(&self) -> &'w str {
+        self.name
+    }
+
+    /// The engine services.
+    pub fn services(&self) -> &'w ServiceRegistry {
+        self.services
+    }
+
+    /// Runs query `Q`, which must stay within the system's access.
+    pub fn query<Q: WorldQuery>(&self) -> Query<'w, Q> {
+        assert!(
+            self.access.allows::<Q>(),
+            "system `{}` runs query `{}` outside its declared access",
+            self.name,
+            std::any::type_name::<Q>()
+        );
+        if !Q::mut_type_ids().is_empty() {
+            self.writes
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(stamp_writes::<Q>);
+        }
+        self.world.query::<Q>()
+    }
+
+    /// Returns the world's `T` resource. Parallel systems only read
+    /// resources; an exclusive system writes them.
+    pub fn resource<T: Send + Sync + 'static>(&self) -> Option<&'w T> {
+        self.world.get_resource::<T>()
+    }
+
+    /// Returns the world's queue of `T` events, if one exists.
+    pub fn events<T: Send + Sync + 'static>(&self) -> Option<&'w Events<T>> {
+        self.world.events::<T>()
+    }
+
+    /// Reads the events of type `T` that `reader` has not seen yet. See
+    /// [`World::read_events`].
+    pub fn read_events<T: Send + Sync + 'static>(
+        &self,
+        reader: &mut EventReader<T>,
+    ) -> impl Iterator<Item = &'w T> + 'w {
+        self.world.read_events(reader)
+    }
+
+    /// Queues `event` for the world's `Events<T>` queue. It is sent with
+    /// the system's other [`commands`](Self::commands), once the batch is
+    /// done, so readers later in the tick see it.
+    pub fn send_event<T: Send + Sync + 'static>(&self, event: T) {
+        self.commands().send_event(event);
+    }
+
+    /// Returns the system's command queue. The schedule applies it once
+    /// every system of the batch has finished, in system order.
+    pub fn commands(&self) -> MutexGuard<'_, Commands> {
+        self.commands.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns `entity`'s `T`, which the system must read or write.
+    pub fn get<T: Component>(&self, entity: EntityId) -> Option<&'w T> {
+        assert!(
+            self.access.allows_read(TypeId::of::<T>()),
+            "system `{}` reads `{}` outside its declared access",
+            self.name,
+            std::any::type_name::<T>()
+        );
+        self.world.get::<T>(entity)
+    }
+}
+
+/// Stamps the components `Q` writes, on every entity it matches.
+fn stamp_writes<Q: WorldQuery>(world: &mut World) {
+    for _ in world.query_mut::<Q>() {}
+}
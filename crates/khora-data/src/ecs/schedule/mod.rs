@@ -0,0 +1,49 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parallel system scheduling.
+//!
+//! A [`SystemSchedule`] holds systems that declare which components they
+//! read and write ([`SystemAccess`]). It splits them into batches of
+//! systems whose accesses do not conflict, keeping the insertion order
+//! between conflicting ones, and runs each batch on a [`SystemPool`].
+//!
+//! Parallel systems see the world through a [`SystemContext`]: they can
+//! query and read components, but not spawn, despawn or change an entity's
+//! layout. Work that needs `&mut World` goes in an exclusive system, which
+//! runs alone in its batch.
+//!
+//! ```rust,ignore
+//! let pool = SystemPool::with_available_parallelism()?;
+//! let mut schedule = SystemSchedule::new();
+//! schedule
+//!     .add_system("integrate", SystemAccess::of::<(&mut Transform, &Velocity)>(), |ctx| {
+//!         for (transform, velocity) in ctx.query::<(&mut Transform, &Velocity)>() {
+//!             transform.translation += velocity.0;
+//!         }
+//!     })
+//!     .add_system("animate", SystemAccess::of::<&mut AnimationPlayer>(), animate)
+//!     .add_exclusive("despawn_dead", despawn_dead);
+//! schedule.run(world, services, &pool);
+//! ```
+
+mod access;
+mod context;
+mod pool;
+mod system_schedule;
+
+pub use access::SystemAccess;
+pub use context::SystemContext;
+pub use pool::SystemPool;
+pub use system_schedule::SystemSchedule;
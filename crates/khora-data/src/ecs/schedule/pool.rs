@@ -0,0 +1,169 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Worker threads that run the parallel batches of a schedule.
+
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+
+use khora_core::threading::{self, ThreadRole};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A fixed set of named worker threads.
+///
+/// The thread calling [`run_all`](Self::run_all) takes part in the work, so
+/// a pool of `n` threads runs up to `n + 1` systems at once.
+pub struct SystemPool {
+    sender: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl SystemPool {
+    /// Starts a pool of `threads` workers. With zero, every batch runs on
+    /// the calling thread.
+    pub fn new(threads: usize) -> io::Result<Self> {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..threads)
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                threading::spawn_named(format!("khora-system-{i}"), ThreadRole::Worker, move || {
+                    worker_loop(&receiver)
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self {
+            sender: Some(sender),
+            workers,
+        })
+    }
+
+    /// Starts one worker per available core, minus the calling thread.
+    pub fn with_available_parallelism() -> io::Result<Self> {
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self::new(cores.saturating_sub(1))
+    }
+
+    /// Returns the number of worker threads.
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Runs every job, on the workers and the calling thread, and returns
+    /// once all of them have finished. If a job panicked, the panic is
+    /// resumed here after the others are done.
+    pub fn run_all<'s>(&self, mut jobs: Vec<Box<dyn FnOnce() + Send + 's>>) {
+        let Some(inline) = jobs.pop() else {
+            return;
+        };
+
+        let latch = Arc::new(Latch::default());
+        for job in jobs {
+            // SAFETY: the job may borrow data living for 's. `run_all` does
+            // not return, nor unwind, before the latch has counted every
+            // job as finished, so no borrow outlives its data.
+            let job: Box<dyn FnOnce() + Send + 'static> = unsafe { std::mem::transmute(job) };
+            latch.add();
+            let task_latch = Arc::clone(&latch);
+            let task: Job = Box::new(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(job));
+                task_latch.done(result.err());
+            });
+            let unsent = match &self.sender {
+                Some(sender) => sender.send(task).err().map(|e| e.0),
+                None => Some(task),
+            };
+            if let Some(task) = unsent {
+                task();
+            }
+        }
+
+        let inline_result = panic::catch_unwind(AssertUnwindSafe(inline));
+        let worker_panic = latch.wait();
+        if let Err(payload) = inline_result {
+            panic::resume_unwind(payload);
+        }
+        if let Some(payload) = worker_panic {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+impl Drop for SystemPool {
+    fn drop(&mut self) {
+        // Closing the channel ends every worker loop.
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = {
+            let receiver = receiver.lock().unwrap_or_else(|e| e.into_inner());
+            match receiver.recv() {
+                Ok(job) => job,
+                Err(_) => return,
+            }
+        };
+        job();
+    }
+}
+
+type PanicPayload = Box<dyn std::any::Any + Send>;
+
+/// Counts the jobs of one `run_all` still running.
+#[derive(Default)]
+struct Latch {
+    state: Mutex<LatchState>,
+    finished: Condvar,
+}
+
+#[derive(Default)]
+struct LatchState {
+    pending: usize,
+    panic: Option<PanicPayload>,
+}
+
+impl Latch {
+    fn add(&self) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).pending += 1;
+    }
+
+    fn done(&self, panic: Option<PanicPayload>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.pending -= 1;
+        if state.panic.is_none() {
+            state.panic = panic;
+        }
+        if state.pending == 0 {
+            self.finished.notify_all();
+        }
+    }
+
+    /// Blocks until every job is done; returns the first panic, if any.
+    fn wait(&self) -> Option<PanicPayload> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        while state.pending > 0 {
+            state = self.finished.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+        state.panic.take()
+    }
+}
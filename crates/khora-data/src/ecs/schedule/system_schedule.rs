@@ -0,0 +1,199 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Batching and dispatch of scheduled systems.
+
+use std::sync::{Mutex, PoisonError};
+
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{Commands, World};
+
+use super::{SystemAccess, SystemContext, SystemPool};
+
+type ParallelFn = Box<dyn Fn(&SystemContext<'_>) + Send + Sync>;
+type ExclusiveFn = Box<dyn FnMut(&mut World, &ServiceRegistry) + Send>;
+
+enum SystemFn {
+    Parallel(ParallelFn),
+    Exclusive(ExclusiveFn),
+}
+
+struct ScheduledSystem {
+    name: String,
+    access: SystemAccess,
+    run: SystemFn,
+}
+
+/// An ordered list of systems, run in parallel where their accesses allow.
+///
+/// A system runs after every earlier system it conflicts with, and may run
+/// alongside or before earlier systems it does not conflict with.
+#[derive(Default)]
+pub struct SystemSchedule {
+    systems: Vec<ScheduledSystem>,
+    /// Indices into `systems`, one list per batch. Rebuilt after a change.
+    batches: Option<Vec<Vec<usize>>>,
+}
+
+impl SystemSchedule {
+    /// Creates an empty schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a system that reads and writes only what `access` declares.
+    pub fn add_system(
+        &mut self,
+        name: impl Into<String>,
+        access: SystemAccess,
+        run: impl Fn(&SystemContext<'_>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.push(name.into(), access, SystemFn::Parallel(Box::new(run)))
+    }
+
+    /// Adds a system that needs the whole world mutably. It runs alone.
+    pub fn add_exclusive(
+        &mut self,
+        name: impl Into<String>,
+        run: impl FnMut(&mut World, &ServiceRegistry) + Send + 'static,
+    ) -> &mut Self {
+        self.push(
+            name.into(),
+            SystemAccess::exclusive(),
+            SystemFn::Exclusive(Box::new(run)),
+        )
+    }
+
+    fn push(&mut self, name: String, access: SystemAccess, run: SystemFn) -> &mut Self {
+        self.systems.push(ScheduledSystem { name, access, run });
+        self.batches = None;
+        self
+    }
+
+    /// Returns the number of systems.
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Returns `true` if the schedule holds no system.
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    /// Returns the system names of each batch, in execution order.
+    pub fn batches(&mut self) -> Vec<Vec<&str>> {
+        self.ensure_batches();
+        let batches = self.batches.as_deref().unwrap_or_default();
+        batches
+            .iter()
+            .map(|batch| {
+                batch
+                    .iter()
+                    .map(|&i| self.systems[i].name.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Runs every system once, batch after batch. The systems of a batch
+    /// run concurrently on `pool` and the calling thread.
+    pub fn run(&mut self, world: &mut World, services: &ServiceRegistry, pool: &SystemPool) {
+        self.ensure_batches();
+        let batches = self.batches.take().unwrap_or_default();
+        for batch in &batches {
+            if let [index] = batch.as_slice() {
+                if let SystemFn::Exclusive(run) = &mut self.systems[*index].run {
+                    run(world, services);
+                    continue;
+                }
+            }
+
+            let shared: &World = world;
+            let systems: Vec<(&ParallelFn, SystemContext<'_>)> = batch
+                .iter()
+                .filter_map(|&i| {
+                    let system = &self.systems[i];
+                    let SystemFn::Parallel(run) = &system.run else {
+                        return None;
+                    };
+                    let context = SystemContext {
+                        name: &system.name,
+                        world: shared,
+                        services,
+                        access: &system.access,
+                        commands: Mutex::new(Commands::new()),
+                        writes: Mutex::new(Vec::new()),
+                    };
+                    Some((run, context))
+                })
+                .collect();
+            let jobs: Vec<Box<dyn FnOnce() + Send + '_>> = systems
+                .iter()
+                .map(|(run, context)| {
+                    Box::new(move || run(context)) as Box<dyn FnOnce() + Send + '_>
+                })
+                .collect();
+            pool.run_all(jobs);
+
+            // Sync point: the batch's change ticks, then its structural
+            // changes, in system order.
+            let mut commands = Commands::new();
+            let mut writes = Vec::new();
+            for (_, context) in systems {
+                let mut queued = context
+                    .commands
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner);
+                commands.append(&mut queued);
+                writes.extend(
+                    context
+                        .writes
+                        .into_inner()
+                        .unwrap_or_else(PoisonError::into_inner),
+                );
+            }
+            for stamp in writes {
+                stamp(world);
+            }
+            commands.apply(world);
+        }
+        self.batches = Some(batches);
+    }
+
+    /// Places each system one batch after the last earlier system it
+    /// conflicts with.
+    fn ensure_batches(&mut self) {
+        if self.batches.is_some() {
+            return;
+        }
+        let mut levels: Vec<usize> = Vec::with_capacity(self.systems.len());
+        let mut batches: Vec<Vec<usize>> = Vec::new();
+        for (i, system) in self.systems.iter().enumerate() {
+            let level = self.systems[..i]
+                .iter()
+                .zip(&levels)
+                .filter(|(earlier, _)| earlier.access.conflicts_with(&system.access))
+                .map(|(_, &level)| level + 1)
+                .max()
+                .unwrap_or(0);
+            levels.push(level);
+            if batches.len() <= level {
+                batches.resize_with(level + 1, Vec::new);
+            }
+            batches[level].push(i);
+        }
+        self.batches = Some(batches);
+    }
+}
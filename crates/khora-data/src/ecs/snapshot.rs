@@ -0,0 +1,150 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-memory snapshots of a [`World`], for rollback and play / stop.
+
+use std::any::TypeId;
+use std::fmt;
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{
+    entity::EntityMetadata,
+    page::ComponentPage,
+    registry::{ComponentRegistry, TypeRegistry},
+    sparse_set::AnySparseSet,
+    World,
+};
+
+/// A copy of every entity and component of a [`World`], taken with
+/// [`World::snapshot`] and put back with [`World::restore`].
+///
+/// It follows the archetype serialization path — the entity table, the
+/// free list and the component pages are copied as they are — but stays in
+/// memory: columns are cloned instead of encoded, so components owning heap
+/// data are captured too, and nothing needs validating on the way back.
+/// Sparse components are included. Resources, pending events and change
+/// ticks are not: they belong to the frame, not to the simulation state.
+pub struct WorldSnapshot {
+    entities: Vec<(EntityId, Option<EntityMetadata>)>,
+    freed_entities: Vec<u32>,
+    pages: Vec<ComponentPage>,
+    sparse: Vec<(TypeId, Box<dyn AnySparseSet>)>,
+    /// Registrations of the captured component types, so a world that does
+    /// not know them yet can still be restored.
+    registry: ComponentRegistry,
+    type_registry: TypeRegistry,
+}
+
+impl WorldSnapshot {
+    /// Returns the number of live entities in the snapshot.
+    pub fn entity_count(&self) -> usize {
+        self.entities
+            .iter()
+            .filter(|(_, metadata)| metadata.is_some())
+            .count()
+    }
+}
+
+impl fmt::Debug for WorldSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorldSnapshot")
+            .field("entities", &self.entity_count())
+            .field("pages", &self.pages.len())
+            .field("sparse_sets", &self.sparse.len())
+            .finish()
+    }
+}
+
+impl World {
+    /// Copies every entity and component of the world.
+    ///
+    /// Each column is cloned in one go, so plain-data components cost a
+    /// `memcpy`. Take one per confirmed frame for rollback netcode, or one
+    /// when the editor enters play mode.
+    pub fn snapshot(&self) -> WorldSnapshot {
+        WorldSnapshot {
+            entities: self.entities.entities.clone(),
+            freed_entities: self.entities.freed_entities.clone(),
+            pages: self
+                .storage
+                .pages
+                .iter()
+                .map(|page| clone_page(page, &self.storage.registry))
+                .collect(),
+            sparse: self
+                .sparse
+                .iter()
+                .map(|(type_id, set)| (*type_id, set.clone_set()))
+                .collect(),
+            registry: self.storage.registry.clone(),
+            type_registry: self.type_registry.clone(),
+        }
+    }
+
+    /// Puts the world back in the state captured by `snapshot`.
+    ///
+    /// Every entity and component is replaced: IDs that were alive in the
+    /// snapshot resolve again, with the same generation, and entities
+    /// spawned since are gone. The snapshot is left as it was, so it can be
+    /// restored again, e.g. each time rollback resimulates from the same
+    /// confirmed frame.
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) {
+        for page in &snapshot.pages {
+            for type_id in &page.type_ids {
+                self.storage.registry.import(&snapshot.registry, *type_id);
+                self.type_registry.import(&snapshot.type_registry, *type_id);
+            }
+        }
+        let pages = snapshot
+            .pages
+            .iter()
+            .map(|page| clone_page(page, &snapshot.registry))
+            .collect();
+
+        // Sparse types registered after the snapshot start out empty.
+        for set in self.sparse.values_mut() {
+            *set = set.empty();
+        }
+        for (type_id, set) in &snapshot.sparse {
+            self.type_registry.import(&snapshot.type_registry, *type_id);
+            self.sparse.insert(*type_id, set.clone_set());
+        }
+
+        self.install_pages(
+            snapshot.entities.clone(),
+            snapshot.freed_entities.clone(),
+            pages,
+        );
+    }
+}
+
+/// Clones `page`, columns included.
+fn clone_page(page: &ComponentPage, registry: &ComponentRegistry) -> ComponentPage {
+    // Pages are only ever created from registered types, so every column
+    // has a cloner.
+    let columns = page
+        .columns
+        .iter()
+        .filter_map(|(type_id, column)| {
+            let clone_column = registry.get_column_cloner(type_id)?;
+            Some((*type_id, clone_column(column.as_ref())))
+        })
+        .collect();
+    ComponentPage {
+        columns,
+        entities: page.entities.clone(),
+        type_ids: page.type_ids.clone(),
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sparse-set storage for volatile components.
+//!
+//! Page storage keeps an entity's components side by side, so adding or
+//! removing one moves the entity's whole row to another page. For tags
+//! toggled every few frames (`Selected`, `Damaged`) those moves dominate.
+//! A component registered with
+//! [`World::register_sparse_component`](super::World::register_sparse_component)
+//! lives in a [`SparseSet`] instead: insertion and removal are O(1) and
+//! never touch the entity's pages.
+
+use std::any::Any;
+
+use khora_core::ecs::entity::EntityId;
+
+/// Marks an entity index with no value in the set.
+const EMPTY: u32 = u32::MAX;
+
+/// Type-erased operations on a [`SparseSet`], for the paths that do not
+/// know the component type (despawn, query planning).
+pub(crate) trait AnySparseSet: Any + Send + Sync {
+    /// Casts the trait object to `&dyn Any`.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Casts the trait object to `&mut dyn Any`.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Drops the value of `entity`, returning `true` if it had one.
+    fn remove_entity(&mut self, entity: EntityId) -> bool;
+
+    /// Returns `true` if `entity` has a value in the set.
+    fn contains(&self, entity: EntityId) -> bool;
+
+    /// Returns the entities holding a value, in storage order.
+    fn entities(&self) -> &[EntityId];
+
+    /// Returns an empty set of the same component type.
+    fn empty(&self) -> Box<dyn AnySparseSet>;
+
+    /// Returns a copy of the set, values cloned.
+    fn clone_set(&self) -> Box<dyn AnySparseSet>;
+
+    /// Moves the value of `entity` into `dst`, a set of the same type, as
+    /// the value of `new_id`. Returns `false` if there was nothing to move.
+    fn move_entity(
+        &mut self,
+        entity: EntityId,
+        dst: &mut dyn AnySparseSet,
+        new_id: EntityId,
+    ) -> bool;
+}
+
+/// Values of one component type, indexed by entity.
+///
+/// `sparse` maps an entity index to a slot of the packed `dense` and
+/// `entities` arrays. Removal swaps the last slot into the hole, so the
+/// packed arrays stay contiguous for iteration.
+pub(crate) struct SparseSet<T> {
+    sparse: Vec<u32>,
+    dense: Vec<T>,
+    entities: Vec<EntityId>,
+}
+
+impl<T> Default for SparseSet<T> {
+    fn default() -> Self {
+        Self {
+            sparse: Vec::new(),
+            dense: Vec::new(),
+            entities: Vec::new(),
+        }
+    }
+}
+
+impl<T> SparseSet<T> {
+    /// Returns the slot of `entity`, checking its generation.
+    fn slot(&self, entity: EntityId) -> Option<usize> {
+        let slot = *self.sparse.get(entity.index as usize)?;
+        if slot == EMPTY || self.entities[slot as usize] != entity {
+            return None;
+        }
+        Some(slot as usize)
+    }
+
+    /// Sets the value of `entity`, returning the value it replaced.
+    pub(crate) fn insert(&mut self, entity: EntityId, value: T) -> Option<T> {
+        if let Some(slot) = self.slot(entity) {
+            return Some(std::mem::replace(&mut self.dense[slot], value));
+        }
+        let index = entity.index as usize;
+        if index >= self.sparse.len() {
+            self.sparse.resize(index + 1, EMPTY);
+        }
+        // A stale generation may still hold the slot: replace it.
+        let stale = self.sparse[index];
+        if stale != EMPTY {
+            self.dense[stale as usize] = value;
+            self.entities[stale as usize] = entity;
+            return None;
+        }
+        self.sparse[index] = self.dense.len() as u32;
+        self.dense.push(value);
+        self.entities.push(entity);
+        None
+    }
+
+    /// Removes the value of `entity`.
+    pub(crate) fn remove(&mut self, entity: EntityId) -> Option<T> {
+        let slot = self.slot(entity)?;
+        self.sparse[entity.index as usize] = EMPTY;
+        let value = self.dense.swap_remove(slot);
+        self.entities.swap_remove(slot);
+        if let Some(moved) = self.entities.get(slot) {
+            self.sparse[moved.index as usize] = slot as u32;
+        }
+        Some(value)
+    }
+
+    /// Returns `true` if `entity` has a value in the set.
+    pub(crate) fn contains(&self, entity: EntityId) -> bool {
+        self.slot(entity).is_some()
+    }
+
+    /// Returns the value of `entity`.
+    pub(crate) fn get(&self, entity: EntityId) -> Option<&T> {
+        self.slot(entity).map(|slot| &self.dense[slot])
+    }
+
+    /// Returns the value of `entity`, mutably.
+    pub(crate) fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
+        self.slot(entity).map(|slot| &mut self.dense[slot])
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> AnySparseSet for SparseSet<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn remove_entity(&mut self, entity: EntityId) -> bool {
+        self.remove(entity).is_some()
+    }
+
+    fn contains(&self, entity: EntityId) -> bool {
+        SparseSet::contains(self, entity)
+    }
+
+    fn entities(&self) -> &[EntityId] {
+        &self.entities
+    }
+
+    fn empty(&self) -> Box<dyn AnySparseSet> {
+        Box::new(SparseSet::<T>::default())
+    }
+
+    fn clone_set(&self) -> Box<dyn AnySparseSet> {
+        Box::new(SparseSet {
+            sparse: self.sparse.clone(),
+            dense: self.dense.clone(),
+            entities: self.entities.clone(),
+        })
+    }
+
+    fn move_entity(
+        &mut self,
+        entity: EntityId,
+        dst: &mut dyn AnySparseSet,
+        new_id: EntityId,
+    ) -> bool {
+        let Some(dst) = dst.as_any_mut().downcast_mut::<SparseSet<T>>() else {
+            return false;
+        };
+        let Some(value) = self.remove(entity) else {
+            return false;
+        };
+        dst.insert(new_id, value);
+        true
+    }
+}
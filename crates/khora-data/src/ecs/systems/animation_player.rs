@@ -0,0 +1,135 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Animation playback — samples each [`AnimationPlayer`]'s clip into the
+//! animated entities' `Transform` and `MorphWeights`.
+//!
+//! Runs in [`TickPhase::PostSimulation`], ahead of `transform_propagation`
+//! (lower `order_hint`), so animated local transforms are propagated in the
+//! same frame.
+
+use std::collections::HashMap;
+
+use khora_core::animation::SampledValue;
+use khora_core::ecs::entity::EntityId;
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    AnimationPlayer, Children, DataSystemRegistration, MorphWeights, Name, SimulationLod,
+    TickPhase, Transform, World,
+};
+
+fn animation_player_system(world: &mut World, services: &ServiceRegistry) {
+    let delta = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+
+    // Phase 1: advance every player and sample its clip.
+    let mut samples: Vec<(EntityId, Option<String>, SampledValue)> = Vec::new();
+    // Reduced players advance every few frames; dormant ones stay paused.
+    for (entity, player, lod) in
+        world.query_mut::<(EntityId, &mut AnimationPlayer, Option<&SimulationLod>)>()
+    {
+        let Some(delta) = SimulationLod::delta_of(lod, delta) else {
+            continue;
+        };
+        player.advance(delta);
+        let Some(clip) = &player.clip else {
+            continue;
+        };
+        for (target, value) in clip.sample(player.time) {
+            samples.push((entity, target.map(str::to_owned), value));
+        }
+    }
+
+    // Phase 2: resolve track targets and write the sampled values.
+    let mut targets: HashMap<(EntityId, String), Option<EntityId>> = HashMap::new();
+    for (root, target, value) in samples {
+        let entity = match target {
+            None => Some(root),
+            Some(name) => *targets
+                .entry((root, name))
+                .or_insert_with_key(|(root, name)| find_descendant(world, *root, name)),
+        };
+        if let Some(entity) = entity {
+            apply_sample(world, entity, value);
+        }
+    }
+}
+
+/// Breadth-first search for the descendant of `root` carrying `Name(name)`.
+///
+/// Shared with the animation lane, which resolves graph tracks the same way.
+pub fn find_descendant(world: &World, root: EntityId, name: &str) -> Option<EntityId> {
+    let mut queue: Vec<EntityId> = world
+        .get::<Children>(root)
+        .map(|c| c.0.clone())
+        .unwrap_or_default();
+    let mut i = 0;
+    while let Some(&entity) = queue.get(i) {
+        if world
+            .get::<Name>(entity)
+            .is_some_and(|n| n.as_str() == name)
+        {
+            return Some(entity);
+        }
+        if let Some(children) = world.get::<Children>(entity) {
+            queue.extend_from_slice(&children.0);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Writes a sampled animation value into `entity`'s `Transform` or
+/// `MorphWeights`.
+pub fn apply_sample(world: &mut World, entity: EntityId, value: SampledValue) {
+    match value {
+        SampledValue::Translation(v) => {
+            if let Some(t) = world.get_mut::<Transform>(entity) {
+                t.translation = v;
+            }
+        }
+        SampledValue::Rotation(q) => {
+            if let Some(t) = world.get_mut::<Transform>(entity) {
+                t.rotation = q;
+            }
+        }
+        SampledValue::Scale(v) => {
+            if let Some(t) = world.get_mut::<Transform>(entity) {
+                t.scale = v;
+            }
+        }
+        SampledValue::MorphWeights(weights) => match world.get_mut::<MorphWeights>(entity) {
+            Some(m) => m.weights = weights,
+            None => {
+                let _ = world.add_component(entity, MorphWeights::new(weights));
+            }
+        },
+        other => log::trace!("animation_player: unhandled sample {:?}", other),
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "animation_player",
+        phase: TickPhase::PostSimulation,
+        run: animation_player_system,
+        // Before `transform_propagation` (order_hint 0).
+        order_hint: -10,
+        runs_after: &[],
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounds sync — mesh boxes and `GlobalTransform` → world-space [`Bounds`].
+//!
+//! Runs in [`TickPhase::PostSimulation`] after `transform_propagation`. An
+//! entity's own box is only recomputed when its mesh box or its world
+//! transform changed; hierarchy boxes are merged from those, deepest
+//! entities first, and a component is only written when its value changes.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Aabb;
+use khora_core::renderer::api::scene::Mesh;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    Bounds, DataSystemRegistration, GlobalTransform, HandleComponent, IncludeDisabled, Parent,
+    TickPhase, World,
+};
+
+/// Brings every [`Bounds`] component in `world` up to date.
+///
+/// Entities with a mesh and a `GlobalTransform` get a box of their own;
+/// their ancestors get one around their descendants. Entities left with
+/// neither lose the component.
+pub fn bounds_sync_system(world: &mut World) {
+    // Stage 1: own boxes, reusing the previous one when its inputs match.
+    let mut computed: HashMap<EntityId, Bounds> = HashMap::new();
+    for (entity, mesh, global, previous, _) in world.query::<(
+        EntityId,
+        &HandleComponent<Mesh>,
+        &GlobalTransform,
+        Option<&Bounds>,
+        IncludeDisabled,
+    )>() {
+        let Some(local) = local_bounds(mesh) else {
+            continue;
+        };
+        let source = Some((local, *global));
+        let own = match previous {
+            Some(previous) if previous.source == source => previous.world,
+            _ => local.transform(&global.to_matrix()),
+        };
+        computed.insert(
+            entity,
+            Bounds {
+                world: own,
+                hierarchy: own,
+                source,
+            },
+        );
+    }
+
+    // Stage 2: merge each box into its ancestors, deepest entities first so
+    // a parent's hierarchy box is complete before it is merged upwards.
+    let parents: HashMap<EntityId, EntityId> = world
+        .query::<(EntityId, &Parent, IncludeDisabled)>()
+        .map(|(child, parent, _)| (child, parent.0))
+        .collect();
+    let mut order: Vec<(usize, EntityId)> = Vec::new();
+    let mut pending: Vec<EntityId> = computed.keys().copied().collect();
+    while let Some(entity) = pending.pop() {
+        let depth = depth_of(entity, &parents);
+        order.push((depth, entity));
+        if let Some(&parent) = parents.get(&entity) {
+            if let Entry::Vacant(slot) = computed.entry(parent) {
+                slot.insert(Bounds::default());
+                pending.push(parent);
+            }
+        }
+    }
+    order.sort_unstable_by_key(|&(depth, _)| Reverse(depth));
+    for (_, entity) in order {
+        let (Some(&parent), Some(child)) = (parents.get(&entity), computed.get(&entity)) else {
+            continue;
+        };
+        let hierarchy = child.hierarchy;
+        if let Some(parent) = computed.get_mut(&parent) {
+            parent.hierarchy = parent.hierarchy.merge(&hierarchy);
+        }
+    }
+
+    // Stage 3: write what changed, drop what no longer applies.
+    let stale: Vec<EntityId> = world
+        .query::<(EntityId, &Bounds, IncludeDisabled)>()
+        .filter(|(entity, _, _)| !computed.contains_key(entity))
+        .map(|(entity, _, _)| entity)
+        .collect();
+    for entity in stale {
+        let _ = world.remove_component_now::<Bounds>(entity);
+    }
+    for (entity, bounds) in computed {
+        match world.get_mut::<Bounds>(entity) {
+            Some(current) => {
+                if *current != bounds {
+                    *current = bounds;
+                }
+            }
+            None => {
+                let _ = world.insert_component(entity, bounds);
+            }
+        }
+    }
+}
+
+/// Returns the local box of a mesh, or `None` for an empty placeholder.
+///
+/// Falls back to the vertices when the decoder left no usable box.
+fn local_bounds(mesh: &Mesh) -> Option<Aabb> {
+    if mesh.positions.is_empty() {
+        return None;
+    }
+    let aabb = mesh.bounding_box;
+    if aabb.is_valid() && aabb.size().length() > 0.0 {
+        return Some(aabb);
+    }
+    Aabb::from_points(&mesh.positions)
+}
+
+/// Returns the number of ancestors of `entity`, stopping on a cycle.
+fn depth_of(entity: EntityId, parents: &HashMap<EntityId, EntityId>) -> usize {
+    let mut depth = 0;
+    let mut current = entity;
+    while let Some(&parent) = parents.get(&current) {
+        depth += 1;
+        current = parent;
+        if depth > parents.len() {
+            break;
+        }
+    }
+    depth
+}
+
+fn bounds_sync_entry(world: &mut World, _services: &ServiceRegistry) {
+    bounds_sync_system(world);
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "bounds_sync",
+        phase: TickPhase::PostSimulation,
+        run: bounds_sync_entry,
+        order_hint: 0,
+        runs_after: &["transform_propagation"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::systems::transform_propagation_system;
+    use crate::ecs::Transform;
+    use khora_core::asset::{AssetHandle, AssetUUID};
+    use khora_core::math::Vec3;
+    use khora_core::renderer::api::pipeline::PrimitiveTopology;
+
+    fn unit_cube() -> HandleComponent<Mesh> {
+        let positions = vec![Vec3::new(-0.5, -0.5, -0.5), Vec3::new(0.5, 0.5, 0.5)];
+        let mesh = Mesh {
+            bounding_box: Aabb::from_points(&positions).unwrap(),
+            positions,
+            normals: None,
+            tex_coords: None,
+            tangents: None,
+            colors: None,
+            indices: None,
+            primitive_type: PrimitiveTopology::TriangleList,
+            vertex_layout: Vec::new(),
+            morph_targets: Vec::new(),
+            skin: None,
+        };
+        HandleComponent {
+            handle: AssetHandle::new(mesh),
+            uuid: AssetUUID::new(),
+        }
+    }
+
+    fn at(x: f32) -> Transform {
+        Transform::from_translation(Vec3::new(x, 0.0, 0.0))
+    }
+
+    fn tick(world: &mut World) {
+        transform_propagation_system(world);
+        bounds_sync_system(world);
+    }
+
+    #[test]
+    fn test_mesh_bounds_follow_the_transform() {
+        let mut world = World::new();
+        let entity = world.spawn((at(3.0), GlobalTransform::identity(), unit_cube()));
+        tick(&mut world);
+
+        let bounds = *world.get::<Bounds>(entity).unwrap();
+        assert_eq!(bounds.world.center(), Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(bounds.hierarchy, bounds.world);
+
+        world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(-2.0, 0.0, 0.0);
+        tick(&mut world);
+        let moved = world.get::<Bounds>(entity).unwrap();
+        assert_eq!(moved.world.center(), Vec3::new(-2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_hierarchy_bounds_cover_descendants() {
+        let mut world = World::new();
+        let root = world.spawn((at(0.0), GlobalTransform::identity()));
+        let group = world.spawn((at(10.0), GlobalTransform::identity(), Parent(root)));
+        let leaf = world.spawn((
+            at(5.0),
+            GlobalTransform::identity(),
+            Parent(group),
+            unit_cube(),
+        ));
+        let sibling = world.spawn((
+            at(-1.0),
+            GlobalTransform::identity(),
+            Parent(root),
+            unit_cube(),
+        ));
+        tick(&mut world);
+
+        let root_bounds = world.get::<Bounds>(root).unwrap();
+        assert!(!root_bounds.has_mesh());
+        assert_eq!(root_bounds.hierarchy.min.x, -1.5);
+        assert_eq!(root_bounds.hierarchy.max.x, 15.5);
+        let group_bounds = world.get::<Bounds>(group).unwrap();
+        assert_eq!(group_bounds.hierarchy.center(), Vec3::new(15.0, 0.0, 0.0));
+
+        // Once the leaf is gone, its branch has nothing left to bound.
+        assert!(world.despawn(leaf));
+        tick(&mut world);
+        assert!(world.get::<Bounds>(group).is_none());
+        assert_eq!(
+            world.get::<Bounds>(root).unwrap().hierarchy,
+            world.get::<Bounds>(sibling).unwrap().world
+        );
+    }
+}
@@ -0,0 +1,359 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Camera rigs — places every [`CameraRig`] around its target.
+//!
+//! Runs in [`TickPhase::PostSimulation`] after `transform_propagation`, so
+//! targets are read at their final pose for the frame. Rigs are roots, so
+//! the system writes both `Transform` and `GlobalTransform` and the camera
+//! never lags a frame behind what it follows.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{smooth_damp, smooth_damp_angle, smooth_damp_vec3, Quaternion, Vec3};
+use khora_core::physics::{ColliderHandle, PhysicsProvider, Ray};
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    CameraRig, CameraRigMode, Collider, DataSystemRegistration, GlobalTransform, Parent, TickPhase,
+    Transform, Without, World,
+};
+
+/// Advances `rig`'s springs by `dt` and returns the camera pose.
+///
+/// `target` is the world position of the rig's target, if it has one;
+/// `current` seeds the pivot of a rig without target. `cast` returns the
+/// distance to the first obstacle along a ray (origin, direction, length).
+pub fn step_camera_rig(
+    rig: &mut CameraRig,
+    target: Option<Vec3>,
+    current: Vec3,
+    dt: f32,
+    cast: impl Fn(Vec3, Vec3, f32) -> Option<f32>,
+) -> (Vec3, Quaternion) {
+    let goal_pivot = match target {
+        Some(position) => position + rig.target_offset,
+        None if rig.state.initialized => rig.state.pivot,
+        None => current,
+    };
+
+    // Orbit and follow rigs aim down at the pivot from `elevation` above
+    // the horizon; first-person rigs look along their own pitch.
+    let (goal_yaw, goal_pitch, goal_distance, boom) = match rig.mode {
+        CameraRigMode::Follow { offset } => {
+            let distance = offset.length();
+            let elevation = if distance > 0.0 {
+                (offset.y / distance).clamp(-1.0, 1.0).asin()
+            } else {
+                0.0
+            };
+            (offset.x.atan2(offset.z), elevation, distance, true)
+        }
+        CameraRigMode::Orbit {
+            yaw,
+            pitch,
+            distance,
+            ..
+        } => (yaw, pitch, distance, true),
+        CameraRigMode::FirstPerson { yaw, pitch } => (yaw, pitch, 0.0, false),
+    };
+
+    let state = &mut rig.state;
+    if !state.initialized {
+        *state = Default::default();
+        state.initialized = true;
+        state.pivot = goal_pivot;
+        state.yaw = goal_yaw;
+        state.pitch = goal_pitch;
+        state.distance = goal_distance;
+    } else {
+        state.pivot = smooth_damp_vec3(
+            state.pivot,
+            goal_pivot,
+            &mut state.pivot_velocity,
+            rig.position_smoothing,
+            dt,
+        );
+        state.yaw = smooth_damp_angle(
+            state.yaw,
+            goal_yaw,
+            &mut state.yaw_velocity,
+            rig.rotation_smoothing,
+            dt,
+        );
+        state.pitch = smooth_damp(
+            state.pitch,
+            goal_pitch,
+            &mut state.pitch_velocity,
+            rig.rotation_smoothing,
+            dt,
+        );
+    }
+
+    let look_pitch = if boom { -state.pitch } else { state.pitch };
+    let rotation = Quaternion::from_yaw_pitch(state.yaw, look_pitch);
+    if !boom {
+        state.distance = 0.0;
+        return (state.pivot, rotation);
+    }
+
+    let back = rotation * Vec3::Z;
+    let mut allowed = goal_distance;
+    if let Some(collision) = rig.collision {
+        if let Some(hit) = cast(state.pivot, back, goal_distance) {
+            allowed = (hit - collision.radius)
+                .max(collision.min_distance)
+                .min(goal_distance);
+        }
+    }
+    if allowed < state.distance {
+        // Pull in at once so the camera never clips into the obstacle;
+        // ease back out once the view clears.
+        state.distance = allowed;
+        state.distance_velocity = 0.0;
+    } else {
+        state.distance = smooth_damp(
+            state.distance,
+            allowed,
+            &mut state.distance_velocity,
+            rig.position_smoothing,
+            dt,
+        );
+    }
+
+    (state.pivot + back * state.distance, rotation)
+}
+
+fn camera_rig_system(world: &mut World, services: &ServiceRegistry) {
+    let dt = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+    let physics = services.get::<Arc<Mutex<Box<dyn PhysicsProvider>>>>();
+    // The boom starts inside the target, so the target's own collider is
+    // skipped; otherwise it would always be the first obstacle.
+    let cast = |exclude: Option<ColliderHandle>| {
+        move |origin: Vec3, direction: Vec3, length: f32| {
+            physics?
+                .lock()
+                .ok()?
+                .cast_ray(&Ray { origin, direction }, length, false, exclude)
+                .map(|hit| hit.distance)
+        }
+    };
+
+    // Resolve targets up front: the rig query borrows the world.
+    let mut targets: HashMap<EntityId, (Vec3, Option<ColliderHandle>)> = HashMap::new();
+    let wanted: Vec<EntityId> = world
+        .query::<(&CameraRig, Without<Parent>)>()
+        .filter_map(|(rig, _)| rig.target)
+        .collect();
+    for target in wanted {
+        if let Some(global) = world.get::<GlobalTransform>(target) {
+            let collider = world.get::<Collider>(target).and_then(|c| c.handle);
+            targets.insert(target, (global.0.translation(), collider));
+        }
+    }
+
+    for (rig, transform, global, _) in world.query_mut::<(
+        &mut CameraRig,
+        &mut Transform,
+        &mut GlobalTransform,
+        Without<Parent>,
+    )>() {
+        let (target, exclude) = rig
+            .target
+            .and_then(|t| targets.get(&t).copied())
+            .map_or((None, None), |(position, collider)| {
+                (Some(position), collider)
+            });
+        let (position, rotation) =
+            step_camera_rig(rig, target, transform.translation, dt, cast(exclude));
+        transform.translation = position;
+        transform.rotation = rotation;
+        global.0 = transform.to_mat4().into();
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "camera_rig",
+        phase: TickPhase::PostSimulation,
+        run: camera_rig_system,
+        order_hint: 5,
+        runs_after: &["transform_propagation"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use khora_core::math::Quat;
+    use khora_core::physics::{
+        CharacterControllerOptions, ColliderDesc, CollisionEvent, RaycastHit, RigidBodyDesc,
+        RigidBodyHandle,
+    };
+
+    use super::*;
+    use crate::ecs::CameraCollision;
+
+    /// A physics world holding a single collider, a shell of radius 0.5
+    /// around the origin that every ray cast from inside it hits.
+    struct Shell(ColliderHandle);
+
+    impl PhysicsProvider for Shell {
+        fn cast_ray(
+            &self,
+            ray: &Ray,
+            max_toi: f32,
+            _solid: bool,
+            exclude: Option<ColliderHandle>,
+        ) -> Option<RaycastHit> {
+            if exclude == Some(self.0) || max_toi < 0.5 {
+                return None;
+            }
+            Some(RaycastHit {
+                collider: self.0,
+                distance: 0.5,
+                normal: -ray.direction,
+                position: ray.origin + ray.direction * 0.5,
+            })
+        }
+
+        fn step(&mut self, _dt: f32) {}
+        fn set_gravity(&mut self, _gravity: Vec3) {}
+        fn gravity(&self) -> Vec3 {
+            Vec3::ZERO
+        }
+        fn set_body_gravity(&mut self, _handle: RigidBodyHandle, _gravity: Option<Vec3>) {}
+        fn add_body(&mut self, _desc: RigidBodyDesc) -> RigidBodyHandle {
+            unimplemented!()
+        }
+        fn remove_body(&mut self, _handle: RigidBodyHandle) {}
+        fn add_collider(&mut self, _desc: ColliderDesc) -> ColliderHandle {
+            unimplemented!()
+        }
+        fn remove_collider(&mut self, _handle: ColliderHandle) {}
+        fn get_body_transform(&self, _handle: RigidBodyHandle) -> (Vec3, Quat) {
+            (Vec3::ZERO, Quat::IDENTITY)
+        }
+        fn set_body_transform(&mut self, _handle: RigidBodyHandle, _pos: Vec3, _rot: Quat) {}
+        fn get_all_bodies(&self) -> Vec<RigidBodyHandle> {
+            Vec::new()
+        }
+        fn get_all_colliders(&self) -> Vec<ColliderHandle> {
+            vec![self.0]
+        }
+        fn update_body_properties(&mut self, _handle: RigidBodyHandle, _desc: RigidBodyDesc) {}
+        fn set_body_sleeping(&mut self, _handle: RigidBodyHandle, _sleeping: bool) {}
+        fn update_collider_properties(&mut self, _handle: ColliderHandle, _desc: ColliderDesc) {}
+        fn get_debug_render_data(&self) -> (Vec<Vec3>, Vec<[u32; 2]>) {
+            (Vec::new(), Vec::new())
+        }
+        fn get_collision_events(&self) -> Vec<CollisionEvent> {
+            Vec::new()
+        }
+        fn move_character(
+            &self,
+            _collider: ColliderHandle,
+            desired_translation: Vec3,
+            _options: &CharacterControllerOptions,
+        ) -> (Vec3, bool) {
+            (desired_translation, false)
+        }
+    }
+
+    fn target_id() -> EntityId {
+        EntityId {
+            index: 1,
+            generation: 0,
+        }
+    }
+
+    #[test]
+    fn follow_rig_trails_target_and_looks_at_it() {
+        let mut rig = CameraRig::follow(target_id(), Vec3::new(0.0, 3.0, 4.0));
+        let (position, rotation) =
+            step_camera_rig(&mut rig, Some(Vec3::ZERO), Vec3::ZERO, 0.016, |_, _, _| {
+                None
+            });
+
+        assert!((position - Vec3::new(0.0, 3.0, 4.0)).length() < 1e-4);
+        let forward = rotation * -Vec3::Z;
+        let expected = (Vec3::ZERO - position).normalize();
+        assert!((forward - expected).length() < 1e-4);
+    }
+
+    #[test]
+    fn smoothing_is_frame_rate_independent() {
+        let run = |dt: f32| {
+            let mut rig = CameraRig::follow(target_id(), Vec3::new(0.0, 0.0, 5.0));
+            step_camera_rig(&mut rig, Some(Vec3::ZERO), Vec3::ZERO, dt, |_, _, _| None);
+            let mut position = Vec3::ZERO;
+            for _ in 0..(0.2 / dt).round() as usize {
+                position =
+                    step_camera_rig(&mut rig, Some(Vec3::X * 10.0), Vec3::ZERO, dt, |_, _, _| {
+                        None
+                    })
+                    .0;
+            }
+            position
+        };
+        let slow = run(1.0 / 30.0);
+        let fast = run(1.0 / 240.0);
+        assert!((slow - fast).length() < 0.1, "{slow:?} vs {fast:?}");
+    }
+
+    #[test]
+    fn collision_pulls_the_camera_in() {
+        let mut rig = CameraRig::orbit(target_id(), 8.0).with_collision(CameraCollision {
+            radius: 0.5,
+            min_distance: 1.0,
+        });
+        let wall = |_: Vec3, _: Vec3, _: f32| Some(3.0);
+        let (position, _) = step_camera_rig(&mut rig, Some(Vec3::ZERO), Vec3::ZERO, 0.016, wall);
+        assert!((position.length() - 2.5).abs() < 1e-4);
+
+        let clear = |_: Vec3, _: Vec3, _: f32| None;
+        let (position, _) = step_camera_rig(&mut rig, Some(Vec3::ZERO), Vec3::ZERO, 0.016, clear);
+        assert!(position.length() > 2.5 && position.length() < 8.0);
+    }
+
+    #[test]
+    fn collision_ignores_the_target_own_collider() {
+        let handle = ColliderHandle(7);
+        let mut world = World::new();
+        let mut collider = Collider::new_box(Vec3::new(0.5, 0.5, 0.5));
+        collider.handle = Some(handle);
+        let target = world.spawn((Transform::identity(), GlobalTransform::identity(), collider));
+        let camera = world.spawn((
+            Transform::identity(),
+            GlobalTransform::identity(),
+            CameraRig::orbit(target, 8.0).with_collision(CameraCollision {
+                radius: 0.25,
+                min_distance: 1.0,
+            }),
+        ));
+
+        let mut services = ServiceRegistry::new();
+        let provider: Box<dyn PhysicsProvider> = Box::new(Shell(handle));
+        services.insert(Arc::new(Mutex::new(provider)));
+        camera_rig_system(&mut world, &services);
+
+        // Not pulled in to `min_distance` by the collider it orbits.
+        let position = world.get::<Transform>(camera).unwrap().translation;
+        assert!((position.length() - 8.0).abs() < 1e-3, "{position:?}");
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Change trackers — closes the frame's change-detection window.
+//!
+//! Runs after the event update, so `Added<T>` and `Changed<T>` match what
+//! was added or written during the current frame up to this point. Readers
+//! running before the writers they care about keep their own baseline with
+//! [`World::increment_change_tick`] and [`crate::ecs::Query::since`].
+
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{DataSystemRegistration, TickPhase, World};
+
+fn change_trackers_system(world: &mut World, _services: &ServiceRegistry) {
+    world.clear_trackers();
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "change_trackers",
+        phase: TickPhase::Maintenance,
+        run: change_trackers_system,
+        order_hint: 110,
+        runs_after: &["event_update"],
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Event update — ends the frame for every ECS event queue.
+//!
+//! Runs last in the tick, so events sent at any point of frame `N` stay
+//! readable until the end of frame `N + 1`. See [`crate::ecs::Events`].
+
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{DataSystemRegistration, TickPhase, World};
+
+fn event_update_system(world: &mut World, _services: &ServiceRegistry) {
+    world.update_events();
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "event_update",
+        phase: TickPhase::Maintenance,
+        run: event_update_system,
+        order_hint: 100,
+        runs_after: &["ecs_maintenance"],
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hierarchy sync — keeps `Children` in step with `Parent`.
+//!
+//! Runs in [`TickPhase::PostSimulation`] before `transform_propagation`.
+//! Entities whose parent was despawned become roots, and every parent's
+//! `Children` lists exactly the entities pointing at it: existing entries
+//! keep their order and new children are appended. `Children` is only
+//! written where it differs, so `Changed<Children>` stays meaningful.
+
+use std::collections::HashMap;
+
+use khora_core::{ecs::entity::EntityId, ServiceRegistry};
+
+use crate::ecs::{Children, DataSystemRegistration, IncludeDisabled, Parent, TickPhase, World};
+
+/// Repairs the `Parent`/`Children` pairs of `world`.
+pub fn hierarchy_sync_system(world: &mut World) {
+    let mut expected: HashMap<EntityId, Vec<EntityId>> = HashMap::new();
+    let mut orphans = Vec::new();
+    for (child, parent, _) in world.query::<(EntityId, &Parent, IncludeDisabled)>() {
+        if world.entities.get_metadata(parent.0).is_some() {
+            expected.entry(parent.0).or_default().push(child);
+        } else {
+            orphans.push(child);
+        }
+    }
+    for orphan in orphans {
+        let _ = world.remove_component_now::<Parent>(orphan);
+    }
+
+    let mut rewrites = Vec::new();
+    for (parent, children, _) in world.query::<(EntityId, &Children, IncludeDisabled)>() {
+        let mut wanted = expected.remove(&parent).unwrap_or_default();
+        let mut list: Vec<EntityId> = children
+            .0
+            .iter()
+            .copied()
+            .filter(|child| wanted.contains(child))
+            .collect();
+        wanted.retain(|child| !list.contains(child));
+        list.append(&mut wanted);
+        if list != children.0 {
+            rewrites.push((parent, list));
+        }
+    }
+    for (parent, list) in rewrites {
+        if let Some(children) = world.get_mut::<Children>(parent) {
+            children.0 = list;
+        }
+    }
+
+    // Parents that have children but no `Children` component yet.
+    for (parent, list) in expected {
+        let _ = world.insert_component(parent, Children(list));
+    }
+}
+
+fn hierarchy_sync_entry(world: &mut World, _services: &ServiceRegistry) {
+    hierarchy_sync_system(world);
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "hierarchy_sync",
+        phase: TickPhase::PostSimulation,
+        run: hierarchy_sync_entry,
+        // Before `animation_player` (-10) and `transform_propagation` (0).
+        order_hint: -20,
+        runs_after: &[],
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Look-at constraints — turns every enabled [`LookAt`] towards its target.
+//!
+//! Runs in [`TickPhase::PostSimulation`] after `camera_rig`, so it can
+//! re-aim a rigged camera. Writes the local `Transform` (compensating the
+//! parent's rotation) and the entity's `GlobalTransform`, so the result is
+//! visible to extraction this frame.
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Mat4, Quaternion, Vec3};
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    DataSystemRegistration, GlobalTransform, LookAt, Parent, TickPhase, Transform, World,
+};
+
+/// Fraction of the remaining angle a smoothed constraint covers in `dt`.
+fn smoothing_factor(smoothing: f32, dt: f32) -> f32 {
+    if smoothing <= 0.0 {
+        1.0
+    } else {
+        1.0 - (-dt / smoothing).exp()
+    }
+}
+
+fn look_at_system(world: &mut World, services: &ServiceRegistry) {
+    let dt = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+
+    let jobs: Vec<(EntityId, Vec3, f32, Option<Mat4>)> = world
+        .query::<(EntityId, &LookAt, Option<&Parent>)>()
+        .filter(|(_, look_at, _)| look_at.enabled)
+        .filter_map(|(entity, look_at, parent)| {
+            let target = world.get::<GlobalTransform>(look_at.target)?;
+            let parent_matrix = match parent {
+                Some(parent) => Some(world.get::<GlobalTransform>(parent.0)?.to_matrix()),
+                None => None,
+            };
+            Some((
+                entity,
+                target.0.translation() + look_at.offset,
+                look_at.smoothing,
+                parent_matrix,
+            ))
+        })
+        .collect();
+
+    for (entity, point, smoothing, parent_matrix) in jobs {
+        let Some(eye) = world
+            .get::<GlobalTransform>(entity)
+            .map(|g| g.0.translation())
+        else {
+            continue;
+        };
+        let Some(world_rotation) = Quaternion::look_rotation(point - eye) else {
+            continue;
+        };
+        let parent_rotation = parent_matrix
+            .map(|m| Quaternion::from_rotation_matrix(&m))
+            .unwrap_or(Quaternion::IDENTITY);
+        let goal = parent_rotation.inverse() * world_rotation;
+
+        let Some(transform) = world.get_mut::<Transform>(entity) else {
+            continue;
+        };
+        transform.rotation =
+            Quaternion::slerp(transform.rotation, goal, smoothing_factor(smoothing, dt));
+        let local = transform.to_mat4();
+        let global = parent_matrix.map_or(local, |m| m * local);
+        if let Some(global_transform) = world.get_mut::<GlobalTransform>(entity) {
+            global_transform.0 = global.into();
+        }
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "look_at",
+        phase: TickPhase::PostSimulation,
+        run: look_at_system,
+        order_hint: 6,
+        runs_after: &["camera_rig"],
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Material animation — samples [`MaterialAnimation`] curves into each
+//! entity's [`MaterialOverride`].
+//!
+//! Runs in [`TickPhase::PreExtract`] so overrides set by `app.update` and
+//! by animations are both visible to `RenderFlow` in the same frame.
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{DataSystemRegistration, MaterialAnimation, MaterialOverride, TickPhase, World};
+
+fn material_animation_system(world: &mut World, services: &ServiceRegistry) {
+    let delta = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+
+    let mut missing = Vec::new();
+    for (entity, animation, target) in world.query_mut::<(
+        EntityId,
+        &mut MaterialAnimation,
+        Option<&mut MaterialOverride>,
+    )>() {
+        animation.advance(delta);
+        match target {
+            Some(target) => animation.sample_into(target),
+            None => {
+                let mut target = MaterialOverride::default();
+                animation.sample_into(&mut target);
+                missing.push((entity, target));
+            }
+        }
+    }
+
+    for (entity, target) in missing {
+        let _ = world.add_component(entity, target);
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "material_animation",
+        phase: TickPhase::PreExtract,
+        run: material_animation_system,
+        order_hint: 0,
+        runs_after: &[],
+    }
+}
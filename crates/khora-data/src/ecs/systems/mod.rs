@@ -20,8 +20,28 @@
 //! `inventory`; nothing else needs to know about a new system except its
 //! own file.
 
+pub mod animation_player;
+pub mod bounds_sync;
+pub mod camera_rig;
+pub mod change_trackers;
 pub mod ecs_maintenance;
+pub mod event_update;
 pub mod gpu_mesh_sync;
+pub mod hierarchy_sync;
+pub mod look_at;
+pub mod material_animation;
+pub mod morph_target_sync;
+pub mod path_follow;
+pub mod scene_triggers;
+pub mod skin_sync;
+pub mod sound_events;
+pub mod texture_streaming;
+pub mod time_of_day;
 pub mod transform_propagation;
+pub mod ui_interaction;
+pub mod ui_layout;
+pub mod weather;
 
+pub use bounds_sync::bounds_sync_system;
+pub use hierarchy_sync::hierarchy_sync_system;
 pub use transform_propagation::transform_propagation_system;
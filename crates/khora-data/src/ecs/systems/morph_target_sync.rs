@@ -0,0 +1,434 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Morph target sync — applies [`MorphWeights`] to the entity's mesh on the
+//! CPU and uploads the result as an entity-owned GPU mesh.
+//!
+//! Runs in [`TickPhase::PreExtract`] after `gpu_mesh_sync`. The deformed
+//! mesh replaces the entity's shared `HandleComponent<GpuMesh>` so
+//! `RenderFlow` extracts it unchanged; buffers are only rewritten when the
+//! weights actually change.
+//!
+//! An entity that loses its weights or its morph targets goes back to the
+//! shared mesh, and the deformed buffers of those entities and of despawned
+//! ones are destroyed.
+
+use std::sync::Arc;
+
+use khora_core::asset::{AssetHandle, AssetUUID};
+use khora_core::ecs::entity::EntityId;
+use khora_core::renderer::api::scene::{GpuMesh, Mesh};
+use khora_core::renderer::GraphicsDevice;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    DataSystemRegistration, HandleComponent, IncludeDisabled, MorphWeights, MorphedMesh, TickPhase,
+    World,
+};
+use crate::ProjectionRegistry;
+
+fn morph_target_sync_system(world: &mut World, services: &ServiceRegistry) {
+    let Some(proj) = services.get::<ProjectionRegistry>() else {
+        return;
+    };
+    let Some(device) = services.get::<Arc<dyn GraphicsDevice>>() else {
+        return;
+    };
+
+    restore_undeformed(world, proj);
+
+    let mut updates: Vec<(EntityId, AssetHandle<GpuMesh>, MorphedMesh)> = Vec::new();
+    for (entity, mesh, weights, morphed) in world.query::<(
+        EntityId,
+        &HandleComponent<Mesh>,
+        &MorphWeights,
+        Option<&MorphedMesh>,
+    )>() {
+        if !mesh.handle.has_morph_targets() {
+            continue;
+        }
+        if morphed.is_some_and(|m| m.applied == weights.weights) {
+            continue;
+        }
+        let uuid = morphed.map_or_else(AssetUUID::new, |m| m.uuid);
+        let deformed = mesh.handle.morphed(&weights.weights);
+        let handle = proj.upload_deformed(uuid, &deformed, device.as_ref());
+        updates.push((
+            entity,
+            handle,
+            MorphedMesh {
+                uuid,
+                applied: weights.weights.clone(),
+            },
+        ));
+    }
+
+    for (entity, handle, morphed) in updates {
+        let gpu = HandleComponent {
+            handle,
+            uuid: morphed.uuid,
+        };
+        let _ = world.insert_component(entity, gpu);
+        let _ = world.insert_component(entity, morphed);
+    }
+
+    proj.release_unused_deformed(world, device.as_ref());
+}
+
+/// Points entities that stopped deforming back at their shared GPU mesh
+/// and drops their [`MorphedMesh`], so the deformed copy gets released.
+fn restore_undeformed(world: &mut World, proj: &ProjectionRegistry) {
+    let stale: Vec<(EntityId, AssetUUID, Option<AssetUUID>)> = world
+        .query::<(
+            EntityId,
+            &MorphedMesh,
+            Option<&MorphWeights>,
+            Option<&HandleComponent<Mesh>>,
+            IncludeDisabled,
+        )>()
+        .filter(|(_, _, weights, mesh, _)| {
+            weights.is_none() || !mesh.is_some_and(|mesh| mesh.handle.has_morph_targets())
+        })
+        .map(|(entity, morphed, _, mesh, _)| (entity, morphed.uuid, mesh.map(|mesh| mesh.uuid)))
+        .collect();
+
+    for (entity, deformed, shared) in stale {
+        let _ = world.remove_component_now::<MorphedMesh>(entity);
+        let is_deformed = world
+            .get::<HandleComponent<GpuMesh>>(entity)
+            .is_some_and(|gpu| gpu.uuid == deformed);
+        if !is_deformed {
+            continue;
+        }
+        // Without a cached shared mesh, `gpu_mesh_sync` uploads it next frame.
+        let cached = shared.and_then(|uuid| {
+            let handle = proj.gpu_cache().0.read().unwrap().get(&uuid).cloned()?;
+            Some(HandleComponent { handle, uuid })
+        });
+        match cached {
+            Some(gpu) => {
+                if let Some(slot) = world.get_mut::<HandleComponent<GpuMesh>>(entity) {
+                    *slot = gpu;
+                }
+            }
+            None => {
+                let _ = world.remove_component_now::<HandleComponent<GpuMesh>>(entity);
+            }
+        }
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "morph_target_sync",
+        phase: TickPhase::PreExtract,
+        run: morph_target_sync_system,
+        order_hint: 0,
+        runs_after: &["gpu_mesh_sync"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::{ProceduralMeshKind, SerializableMeshRef};
+    use crate::GpuCache;
+    use khora_core::math::dimension::{Extent3D, Origin3D};
+    use khora_core::math::Vec3;
+    use khora_core::renderer::api::{
+        command::{
+            BindGroupDescriptor, BindGroupId, BindGroupLayoutDescriptor, BindGroupLayoutId,
+            CommandBufferId, ComputePipelineDescriptor, ComputePipelineId,
+        },
+        core::{GraphicsAdapterInfo, ShaderModuleDescriptor, ShaderModuleId},
+        pipeline::{
+            PipelineLayoutDescriptor, PipelineLayoutId, RenderPipelineDescriptor, RenderPipelineId,
+        },
+        resource::{
+            BufferDescriptor, BufferId, SamplerDescriptor, SamplerId, TextureDescriptor, TextureId,
+            TextureViewDescriptor, TextureViewId,
+        },
+        scene::MorphTarget,
+        util::TextureFormat,
+    };
+    use khora_core::renderer::traits::CommandEncoder;
+    use khora_core::renderer::ResourceError;
+    use std::collections::HashSet;
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    /// Records the buffers it hands out; everything else is a no-op.
+    #[derive(Debug, Default)]
+    struct BufferDevice {
+        next_id: AtomicUsize,
+        live: Mutex<HashSet<BufferId>>,
+    }
+
+    impl BufferDevice {
+        fn live_buffers(&self) -> usize {
+            self.live.lock().unwrap().len()
+        }
+
+        fn is_live(&self, buffer: BufferId) -> bool {
+            self.live.lock().unwrap().contains(&buffer)
+        }
+
+        fn next(&self) -> usize {
+            self.next_id.fetch_add(1, Ordering::Relaxed) + 1
+        }
+
+        fn new_buffer(&self) -> BufferId {
+            let id = BufferId(self.next());
+            self.live.lock().unwrap().insert(id);
+            id
+        }
+    }
+
+    impl GraphicsDevice for BufferDevice {
+        fn create_shader_module(
+            &self,
+            _d: &ShaderModuleDescriptor,
+        ) -> Result<ShaderModuleId, ResourceError> {
+            Ok(ShaderModuleId(self.next()))
+        }
+        fn destroy_shader_module(&self, _id: ShaderModuleId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_render_pipeline(
+            &self,
+            _d: &RenderPipelineDescriptor,
+        ) -> Result<RenderPipelineId, ResourceError> {
+            Ok(RenderPipelineId(self.next()))
+        }
+        fn create_pipeline_layout(
+            &self,
+            _d: &PipelineLayoutDescriptor,
+        ) -> Result<PipelineLayoutId, ResourceError> {
+            Ok(PipelineLayoutId(self.next()))
+        }
+        fn destroy_render_pipeline(&self, _id: RenderPipelineId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_compute_pipeline(
+            &self,
+            _d: &ComputePipelineDescriptor,
+        ) -> Result<ComputePipelineId, ResourceError> {
+            Ok(ComputePipelineId(self.next() as u64))
+        }
+        fn destroy_compute_pipeline(&self, _id: ComputePipelineId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_bind_group_layout(
+            &self,
+            _d: &BindGroupLayoutDescriptor,
+        ) -> Result<BindGroupLayoutId, ResourceError> {
+            Ok(BindGroupLayoutId(self.next()))
+        }
+        fn create_bind_group(
+            &self,
+            _d: &BindGroupDescriptor,
+        ) -> Result<BindGroupId, ResourceError> {
+            Ok(BindGroupId(self.next()))
+        }
+        fn destroy_bind_group_layout(&self, _id: BindGroupLayoutId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn destroy_bind_group(&self, _id: BindGroupId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_buffer(&self, _d: &BufferDescriptor) -> Result<BufferId, ResourceError> {
+            Ok(self.new_buffer())
+        }
+        fn create_buffer_with_data(
+            &self,
+            _d: &BufferDescriptor,
+            _data: &[u8],
+        ) -> Result<BufferId, ResourceError> {
+            Ok(self.new_buffer())
+        }
+        fn destroy_buffer(&self, id: BufferId) -> Result<(), ResourceError> {
+            self.live.lock().unwrap().remove(&id);
+            Ok(())
+        }
+        fn write_buffer(
+            &self,
+            _id: BufferId,
+            _off: u64,
+            _data: &[u8],
+        ) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn write_buffer_async<'a>(
+            &'a self,
+            _id: BufferId,
+            _off: u64,
+            _data: &'a [u8],
+        ) -> Box<dyn Future<Output = Result<(), ResourceError>> + Send + 'static> {
+            Box::new(async { Ok(()) })
+        }
+        fn read_buffer_async(
+            &self,
+            _id: BufferId,
+            _off: u64,
+            size: u64,
+        ) -> Box<dyn Future<Output = Result<Vec<u8>, ResourceError>> + Send + 'static> {
+            Box::new(async move { Ok(vec![0; size as usize]) })
+        }
+        fn create_texture(&self, _d: &TextureDescriptor) -> Result<TextureId, ResourceError> {
+            Ok(TextureId(self.next()))
+        }
+        fn destroy_texture(&self, _id: TextureId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn write_texture(
+            &self,
+            _id: TextureId,
+            _data: &[u8],
+            _bpr: Option<u32>,
+            _offset: Origin3D,
+            _size: Extent3D,
+        ) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn write_texture_mip(
+            &self,
+            _id: TextureId,
+            _mip_level: u32,
+            _data: &[u8],
+            _bpr: Option<u32>,
+            _offset: Origin3D,
+            _size: Extent3D,
+        ) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_texture_view(
+            &self,
+            _id: TextureId,
+            _d: &TextureViewDescriptor,
+        ) -> Result<TextureViewId, ResourceError> {
+            Ok(TextureViewId(self.next()))
+        }
+        fn destroy_texture_view(&self, _id: TextureViewId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_sampler(&self, _d: &SamplerDescriptor) -> Result<SamplerId, ResourceError> {
+            Ok(SamplerId(self.next()))
+        }
+        fn destroy_sampler(&self, _id: SamplerId) -> Result<(), ResourceError> {
+            Ok(())
+        }
+        fn create_command_encoder(&self, _label: Option<&str>) -> Box<dyn CommandEncoder> {
+            unimplemented!("morph target sync records no commands")
+        }
+        fn submit_command_buffer(&self, _cb: CommandBufferId) {}
+        fn get_surface_format(&self) -> Option<TextureFormat> {
+            None
+        }
+        fn get_surface_size(&self) -> (u32, u32) {
+            (0, 0)
+        }
+        fn get_adapter_info(&self) -> GraphicsAdapterInfo {
+            GraphicsAdapterInfo::default()
+        }
+        fn supports_feature(&self, _feature: &str) -> bool {
+            false
+        }
+        fn max_texture_dimension_2d(&self) -> u32 {
+            8192
+        }
+    }
+
+    /// A procedural mesh with one morph target lifting every vertex.
+    fn morphable(kind: ProceduralMeshKind) -> HandleComponent<Mesh> {
+        let base = SerializableMeshRef::Procedural {
+            kind,
+            params: [1.0, 8.0, 8.0, 0.0],
+        }
+        .into_handle();
+        let mut mesh = (*base.handle).clone();
+        mesh.morph_targets.push(MorphTarget {
+            name: "lift".to_string(),
+            position_deltas: vec![Vec3::Y; mesh.positions.len()],
+            normal_deltas: None,
+        });
+        HandleComponent {
+            handle: AssetHandle::new(mesh),
+            uuid: AssetUUID::new(),
+        }
+    }
+
+    fn services(device: &Arc<BufferDevice>) -> (ServiceRegistry, ProjectionRegistry) {
+        let proj = ProjectionRegistry::new(GpuCache::new());
+        let mut services = ServiceRegistry::new();
+        services.insert(proj.clone());
+        services.insert(device.clone() as Arc<dyn GraphicsDevice>);
+        (services, proj)
+    }
+
+    #[test]
+    fn deformed_mesh_is_reallocated_when_it_no_longer_fits() {
+        let device = Arc::new(BufferDevice::default());
+        let (_, proj) = services(&device);
+        let uuid = AssetUUID::new();
+        let plane = morphable(ProceduralMeshKind::Plane);
+        let sphere = morphable(ProceduralMeshKind::Sphere);
+
+        let first = proj.upload_deformed(uuid, &plane.handle.morphed(&[0.5]), device.as_ref());
+        let rewritten = proj.upload_deformed(uuid, &plane.handle.morphed(&[1.0]), device.as_ref());
+        assert_eq!(rewritten.vertex_buffer, first.vertex_buffer);
+        assert_eq!(device.live_buffers(), 2);
+
+        let grown = proj.upload_deformed(uuid, &sphere.handle.morphed(&[1.0]), device.as_ref());
+        assert_ne!(grown.vertex_buffer, first.vertex_buffer);
+        assert!(!device.is_live(first.vertex_buffer));
+        assert!(!device.is_live(first.index_buffer));
+        assert!(device.is_live(grown.vertex_buffer));
+        assert_eq!(device.live_buffers(), 2);
+    }
+
+    #[test]
+    fn deformed_meshes_are_freed_with_their_deformer_or_entity() {
+        let device = Arc::new(BufferDevice::default());
+        let (services, proj) = services(&device);
+        let mut world = World::new();
+        let kept = world.spawn((
+            morphable(ProceduralMeshKind::Plane),
+            MorphWeights::new([0.5]),
+        ));
+        let despawned = world.spawn((
+            morphable(ProceduralMeshKind::Plane),
+            MorphWeights::new([0.5]),
+        ));
+
+        morph_target_sync_system(&mut world, &services);
+        assert_eq!(device.live_buffers(), 4);
+        let deformed = world.get::<MorphedMesh>(kept).unwrap().uuid;
+        assert_eq!(
+            world.get::<HandleComponent<GpuMesh>>(kept).unwrap().uuid,
+            deformed
+        );
+
+        world.despawn(despawned);
+        morph_target_sync_system(&mut world, &services);
+        assert_eq!(device.live_buffers(), 2);
+
+        world.remove_component_now::<MorphWeights>(kept).unwrap();
+        morph_target_sync_system(&mut world, &services);
+        assert_eq!(device.live_buffers(), 0);
+        assert!(world.get::<MorphedMesh>(kept).is_none());
+        assert!(world.get::<HandleComponent<GpuMesh>>(kept).is_none());
+        assert!(!proj.gpu_cache().0.read().unwrap().contains(&deformed));
+    }
+}
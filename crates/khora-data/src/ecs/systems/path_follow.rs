@@ -0,0 +1,186 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Path following — moves every enabled [`PathFollower`] along its
+//! [`SplinePath`].
+//!
+//! Runs in [`TickPhase::PostSimulation`] before `transform_propagation`, so
+//! the followers and their children are propagated in the same frame. Paths
+//! are read at their `GlobalTransform`; followers get a local `Transform`
+//! that compensates their parent's.
+
+use std::collections::HashMap;
+
+use khora_core::animation::Spline;
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Mat4, Quaternion};
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    DataSystemRegistration, GlobalTransform, Parent, PathFollower, PathWrap, SplinePath, TickPhase,
+    Transform, World,
+};
+
+/// Advances `follower` by `dt` seconds along a path `length` long and
+/// returns the distance from the start of the path it stands at, after
+/// easing.
+pub fn step_path_follower(follower: &mut PathFollower, length: f32, dt: f32) -> f32 {
+    if length <= 0.0 {
+        return 0.0;
+    }
+    let state = &mut follower.state;
+    if follower.enabled && !state.finished {
+        state.distance += follower.speed.max(0.0) * dt;
+        if state.distance >= length {
+            match follower.wrap {
+                PathWrap::Once => {
+                    state.distance = length;
+                    state.finished = true;
+                }
+                PathWrap::Loop => state.distance = state.distance.rem_euclid(length),
+                PathWrap::PingPong => {
+                    let passes = (state.distance / length).floor();
+                    state.distance -= passes * length;
+                    if passes as u64 % 2 == 1 {
+                        state.reversing = !state.reversing;
+                    }
+                }
+            }
+        }
+    }
+
+    let progress = follower.easing.apply(state.distance / length);
+    let along = if state.reversing {
+        1.0 - progress
+    } else {
+        progress
+    };
+    along * length
+}
+
+fn path_follow_system(world: &mut World, services: &ServiceRegistry) {
+    let dt = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+
+    let followers: Vec<(EntityId, EntityId, Option<EntityId>)> = world
+        .query::<(EntityId, &PathFollower, Option<&Parent>)>()
+        .filter(|(_, follower, _)| follower.enabled && !follower.is_finished())
+        .map(|(entity, follower, parent)| (entity, follower.path, parent.map(|p| p.0)))
+        .collect();
+
+    // Paths are shared between followers: measure each one once.
+    let mut paths: HashMap<EntityId, Option<(Spline, f32, Mat4)>> = HashMap::new();
+    for (entity, path, parent) in followers {
+        let resolved = paths.entry(path).or_insert_with(|| {
+            let spline = world.get::<SplinePath>(path)?.spline.clone();
+            let matrix = world
+                .get::<GlobalTransform>(path)
+                .map_or(Mat4::IDENTITY, |g| g.to_matrix());
+            let length = spline.length();
+            Some((spline, length, matrix))
+        });
+        let Some((spline, length, path_matrix)) = resolved else {
+            continue;
+        };
+        let parent_matrix = parent
+            .and_then(|p| world.get::<GlobalTransform>(p))
+            .map(|g| g.to_matrix());
+
+        let Some(follower) = world.get_mut::<PathFollower>(entity) else {
+            continue;
+        };
+        let distance = step_path_follower(follower, *length, dt);
+        let (orient, reversing) = (follower.orient, follower.state.reversing);
+
+        let t = spline.t_at_distance(distance);
+        let Some(local_point) = spline.position(t) else {
+            continue;
+        };
+        let point = path_matrix.transform_point(local_point);
+        let rotation = if orient {
+            spline.tangent(t).and_then(|tangent| {
+                let direction = path_matrix.transform_point(local_point + tangent) - point;
+                Quaternion::look_rotation(if reversing { -direction } else { direction })
+            })
+        } else {
+            None
+        };
+
+        let (point, rotation) = match parent_matrix.and_then(|m| Some((m, m.inverse()?))) {
+            Some((matrix, inverse)) => {
+                let parent_rotation = Quaternion::from_rotation_matrix(&matrix);
+                (
+                    inverse.transform_point(point),
+                    rotation.map(|r| parent_rotation.inverse() * r),
+                )
+            }
+            None => (point, rotation),
+        };
+        if let Some(transform) = world.get_mut::<Transform>(entity) {
+            transform.translation = point;
+            if let Some(rotation) = rotation {
+                transform.rotation = rotation;
+            }
+        }
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "path_follow",
+        phase: TickPhase::PostSimulation,
+        run: path_follow_system,
+        // After `animation_player`, before `transform_propagation` (order_hint 0).
+        order_hint: -5,
+        runs_after: &["animation_player"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::animation::Easing;
+
+    fn follower(wrap: PathWrap) -> PathFollower {
+        PathFollower::default().with_wrap(wrap)
+    }
+
+    #[test]
+    fn test_once_stops_at_the_end() {
+        let mut f = follower(PathWrap::Once);
+        assert_eq!(step_path_follower(&mut f, 4.0, 1.0), 1.0);
+        assert_eq!(step_path_follower(&mut f, 4.0, 10.0), 4.0);
+        assert!(f.is_finished());
+        assert_eq!(step_path_follower(&mut f, 4.0, 1.0), 4.0);
+    }
+
+    #[test]
+    fn test_loop_and_ping_pong_wrap() {
+        let mut looped = follower(PathWrap::Loop);
+        assert_eq!(step_path_follower(&mut looped, 4.0, 5.0), 1.0);
+
+        let mut ping_pong = follower(PathWrap::PingPong);
+        assert_eq!(step_path_follower(&mut ping_pong, 4.0, 5.0), 3.0);
+        assert_eq!(step_path_follower(&mut ping_pong, 4.0, 4.0), 1.0);
+    }
+
+    #[test]
+    fn test_easing_shapes_each_pass() {
+        let mut f = follower(PathWrap::Once).with_easing(Easing::EaseIn);
+        let eased = step_path_follower(&mut f, 4.0, 2.0);
+        assert!(eased < 2.0);
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Scene triggers — turns collisions and interaction key presses into named
+//! [`TriggerEvent`]s, then runs the callbacks bound to those names.
+//!
+//! Runs in [`TickPhase::PostSimulation`] after `transform_propagation`, so
+//! interaction ranges use this frame's positions. Collision events come
+//! from the physics lane of the previous frame.
+
+use std::collections::HashMap;
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::physics::{ColliderHandle, CollisionEvent};
+use khora_core::platform::InputEvent;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    Collider, DataSystemRegistration, EventReader, GlobalTransform, Interactor, SceneTrigger,
+    TickPhase, TriggerCallbacks, TriggerEvent, TriggerKind, World,
+};
+
+/// Read positions of the system in the collision and input queues.
+#[derive(Default)]
+struct SceneTriggerReaders {
+    collisions: EventReader<CollisionEvent>,
+    input: EventReader<InputEvent>,
+}
+
+/// Builds the event for `kind` on `trigger`, marking `once` triggers as
+/// fired. Returns `None` when the trigger has no name for `kind` or is spent.
+fn fire(
+    world: &mut World,
+    trigger: EntityId,
+    instigator: EntityId,
+    kind: TriggerKind,
+) -> Option<TriggerEvent> {
+    let scene_trigger = world.get_mut::<SceneTrigger>(trigger)?;
+    if scene_trigger.once && scene_trigger.fired {
+        return None;
+    }
+    let name = scene_trigger.event_name(kind)?.to_string();
+    if scene_trigger.once {
+        scene_trigger.fired = true;
+    }
+    Some(TriggerEvent {
+        name,
+        kind,
+        trigger,
+        instigator,
+    })
+}
+
+/// Returns the closest trigger with an `on_interact` event that `position`
+/// reaches.
+fn nearest_interactable(
+    world: &World,
+    interactor: EntityId,
+    position: khora_core::math::Vec3,
+) -> Option<EntityId> {
+    world
+        .query::<(EntityId, &SceneTrigger, &GlobalTransform)>()
+        .filter(|(entity, trigger, _)| {
+            *entity != interactor
+                && trigger.on_interact.is_some()
+                && !(trigger.once && trigger.fired)
+        })
+        .filter_map(|(entity, trigger, global)| {
+            let distance = global.0.translation().distance(position);
+            (distance <= trigger.interact_radius).then_some((entity, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// Collects the trigger events of this frame without sending them.
+fn collect_trigger_events(world: &mut World) -> Vec<TriggerEvent> {
+    let mut readers = world
+        .remove_resource::<SceneTriggerReaders>()
+        .unwrap_or_default();
+    let collisions: Vec<CollisionEvent> = world
+        .read_events(&mut readers.collisions)
+        .copied()
+        .collect();
+    let pressed: Vec<String> = world
+        .read_events(&mut readers.input)
+        .filter_map(|event| match event {
+            InputEvent::KeyPressed { key_code } => Some(key_code.clone()),
+            _ => None,
+        })
+        .collect();
+    world.insert_resource(readers);
+
+    let mut fired = Vec::new();
+
+    if !collisions.is_empty() {
+        let owners: HashMap<ColliderHandle, EntityId> = world
+            .query::<(EntityId, &Collider)>()
+            .filter_map(|(entity, collider)| collider.handle.map(|handle| (handle, entity)))
+            .collect();
+        for event in collisions {
+            let (a, b, kind) = match event {
+                CollisionEvent::Started(a, b) => (a, b, TriggerKind::Enter),
+                CollisionEvent::Stopped(a, b) => (a, b, TriggerKind::Exit),
+            };
+            let (Some(&a), Some(&b)) = (owners.get(&a), owners.get(&b)) else {
+                continue;
+            };
+            fired.extend(fire(world, a, b, kind));
+            fired.extend(fire(world, b, a, kind));
+        }
+    }
+
+    if !pressed.is_empty() {
+        let interactors: Vec<(EntityId, khora_core::math::Vec3)> = world
+            .query::<(EntityId, &Interactor, &GlobalTransform)>()
+            .filter(|(_, interactor, _)| pressed.contains(&interactor.key))
+            .map(|(entity, _, global)| (entity, global.0.translation()))
+            .collect();
+        for (interactor, position) in interactors {
+            if let Some(trigger) = nearest_interactable(world, interactor, position) {
+                fired.extend(fire(world, trigger, interactor, TriggerKind::Interact));
+            }
+        }
+    }
+
+    fired
+}
+
+fn scene_triggers_system(world: &mut World, _services: &ServiceRegistry) {
+    let fired = collect_trigger_events(world);
+    if fired.is_empty() {
+        return;
+    }
+    world
+        .events_mut::<TriggerEvent>()
+        .send_batch(fired.iter().cloned());
+
+    // Callbacks get the whole world, so the set is taken out while they
+    // run. Callbacks registered meanwhile land in a fresh set, merged back.
+    let Some(mut callbacks) = world.remove_resource::<TriggerCallbacks>() else {
+        return;
+    };
+    for event in &fired {
+        let ran = callbacks.dispatch(world, event);
+        log::trace!(
+            "scene_triggers: '{}' ({:?}) ran {} callback(s)",
+            event.name,
+            event.kind,
+            ran
+        );
+    }
+    if let Some(added) = world.remove_resource::<TriggerCallbacks>() {
+        callbacks.append(added);
+    }
+    world.insert_resource(callbacks);
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "scene_triggers",
+        phase: TickPhase::PostSimulation,
+        run: scene_triggers_system,
+        order_hint: 8,
+        runs_after: &["transform_propagation"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use khora_core::math::Vec3;
+
+    use super::*;
+    use crate::ecs::Transform;
+
+    fn trigger_volume(world: &mut World, handle: u64, trigger: SceneTrigger) -> EntityId {
+        let mut collider = Collider::new_box(Vec3::new(1.0, 1.0, 1.0));
+        collider.handle = Some(ColliderHandle(handle));
+        collider.is_sensor = true;
+        world.spawn((
+            Transform::identity(),
+            GlobalTransform::identity(),
+            collider,
+            trigger,
+        ))
+    }
+
+    #[test]
+    fn collisions_fire_enter_and_exit_once() {
+        let mut world = World::new();
+        let volume = trigger_volume(
+            &mut world,
+            1,
+            SceneTrigger::on_enter("intro").with_exit("outro").once(),
+        );
+        let mut body = Collider::new_box(Vec3::new(0.5, 0.5, 0.5));
+        body.handle = Some(ColliderHandle(2));
+        let player = world.spawn((Transform::identity(), body));
+
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        world.on_trigger("intro", move |_, event| {
+            assert_eq!(event.kind, TriggerKind::Enter);
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let services = ServiceRegistry::new();
+        world.send_event(CollisionEvent::Started(
+            ColliderHandle(2),
+            ColliderHandle(1),
+        ));
+        scene_triggers_system(&mut world, &services);
+
+        let mut reader = EventReader::<TriggerEvent>::default();
+        let events: Vec<TriggerEvent> = world.read_events(&mut reader).cloned().collect();
+        assert_eq!(
+            events,
+            vec![TriggerEvent {
+                name: "intro".to_string(),
+                kind: TriggerKind::Enter,
+                trigger: volume,
+                instigator: player,
+            }]
+        );
+        assert_eq!(hits.load(Ordering::Relaxed), 1);
+
+        // The trigger is spent: leaving it fires nothing.
+        world.send_event(CollisionEvent::Stopped(
+            ColliderHandle(2),
+            ColliderHandle(1),
+        ));
+        scene_triggers_system(&mut world, &services);
+        assert_eq!(world.read_events(&mut reader).count(), 0);
+    }
+
+    #[test]
+    fn interaction_picks_the_nearest_trigger_in_range() {
+        let mut world = World::new();
+        let at = |x: f32| GlobalTransform::at_position(Vec3::new(x, 0.0, 0.0));
+        let near = world.spawn((at(1.0), SceneTrigger::on_interact("near")));
+        world.spawn((at(1.5), SceneTrigger::on_interact("far")));
+        world.spawn((
+            at(5.0),
+            SceneTrigger::on_interact("out_of_range").with_interact_radius(1.0),
+        ));
+        let player = world.spawn((at(0.0), Interactor::default()));
+
+        world.send_event(InputEvent::KeyPressed {
+            key_code: "KeyE".to_string(),
+        });
+        scene_triggers_system(&mut world, &ServiceRegistry::new());
+
+        let mut reader = EventReader::<TriggerEvent>::default();
+        let events: Vec<TriggerEvent> = world.read_events(&mut reader).cloned().collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "near");
+        assert_eq!(events[0].trigger, near);
+        assert_eq!(events[0].instigator, player);
+    }
+}
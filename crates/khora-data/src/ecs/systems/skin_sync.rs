@@ -0,0 +1,203 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Skin sync — poses skinned meshes from their joint entities.
+//!
+//! Runs in [`TickPhase::PreExtract`] after `gpu_mesh_sync`. The first time
+//! it sees a [`Skin`] it uploads the bind pose, the per-vertex influences
+//! and an entity-owned output mesh that replaces the entity's shared
+//! `HandleComponent<GpuMesh>`. Every frame it then rewrites the joint
+//! matrices; the actual vertex deformation happens on the GPU, either in
+//! the scene vertex shader or in the skinning compute pre-pass.
+//!
+//! Morph weights are not combined with skinning: a skinned entity always
+//! draws its skin output mesh.
+
+use std::sync::Arc;
+
+use khora_core::asset::{AssetHandle, AssetUUID};
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Mat4;
+use khora_core::renderer::api::resource::{BufferDescriptor, BufferId, BufferUsage};
+use khora_core::renderer::api::scene::{GpuMesh, Mesh, MeshSkin, SkinVertexLayout};
+use khora_core::renderer::GraphicsDevice;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    DataSystemRegistration, GlobalTransform, HandleComponent, Skin, SkinnedMesh, TickPhase, World,
+};
+use crate::ProjectionRegistry;
+
+fn skin_sync_system(world: &mut World, services: &ServiceRegistry) {
+    let Some(proj) = services.get::<ProjectionRegistry>() else {
+        return;
+    };
+    let Some(device) = services.get::<Arc<dyn GraphicsDevice>>() else {
+        return;
+    };
+
+    let mut created: Vec<(EntityId, SkinnedMesh)> = Vec::new();
+    let mut retarget: Vec<(EntityId, AssetHandle<GpuMesh>, AssetUUID)> = Vec::new();
+    for (entity, mesh, skin, global, skinned, gpu) in world.query::<(
+        EntityId,
+        &HandleComponent<Mesh>,
+        &Skin,
+        &GlobalTransform,
+        Option<&SkinnedMesh>,
+        Option<&HandleComponent<GpuMesh>>,
+    )>() {
+        let Some(mesh_skin) = &mesh.handle.skin else {
+            continue;
+        };
+        let skinned = match skinned {
+            Some(skinned) => skinned.clone(),
+            None => match create_skin_buffers(&mesh.handle, mesh_skin, skin, device.as_ref()) {
+                Some(skinned) => {
+                    created.push((entity, skinned.clone()));
+                    skinned
+                }
+                None => continue,
+            },
+        };
+        if gpu.is_none_or(|g| g.uuid != skinned.uuid) {
+            let handle = proj.upload_skin_target(skinned.uuid, &mesh.handle, device.as_ref());
+            retarget.push((entity, handle, skinned.uuid));
+        }
+
+        let matrices = joint_matrices(world, skin, global, skinned.joint_capacity as usize);
+        if let Err(e) =
+            device.write_buffer(skinned.joint_matrices, 0, bytemuck::cast_slice(&matrices))
+        {
+            log::warn!("Failed to update joint matrices of {:?}: {:?}", entity, e);
+        }
+    }
+
+    for (entity, skinned) in created {
+        let _ = world.add_component(entity, skinned);
+    }
+    for (entity, handle, uuid) in retarget {
+        let gpu = HandleComponent { handle, uuid };
+        match world.get_mut::<HandleComponent<GpuMesh>>(entity) {
+            Some(slot) => *slot = gpu,
+            None => {
+                let _ = world.add_component(entity, gpu);
+            }
+        }
+    }
+}
+
+/// Mesh-space joint matrices for `skin`, padded with identities to `capacity`.
+///
+/// Each matrix maps a bind-pose vertex to its posed position relative to
+/// the skinned entity, so the usual model matrix still applies afterwards.
+fn joint_matrices(
+    world: &World,
+    skin: &Skin,
+    root: &GlobalTransform,
+    capacity: usize,
+) -> Vec<Mat4> {
+    let inverse_root = root.to_matrix().inverse().unwrap_or(Mat4::IDENTITY);
+    let mut matrices: Vec<Mat4> = skin
+        .joints
+        .iter()
+        .take(capacity)
+        .enumerate()
+        .map(|(i, joint)| {
+            let joint_global = world
+                .get::<GlobalTransform>(*joint)
+                .map_or(Mat4::IDENTITY, GlobalTransform::to_matrix);
+            let inverse_bind = skin
+                .inverse_bind_matrices
+                .get(i)
+                .copied()
+                .unwrap_or(Mat4::IDENTITY);
+            inverse_root * joint_global * inverse_bind
+        })
+        .collect();
+    matrices.resize(capacity, Mat4::IDENTITY);
+    matrices
+}
+
+/// Uploads the static skinning inputs of `mesh`.
+fn create_skin_buffers(
+    mesh: &Mesh,
+    mesh_skin: &MeshSkin,
+    skin: &Skin,
+    device: &dyn GraphicsDevice,
+) -> Option<SkinnedMesh> {
+    let layout = SkinVertexLayout::from_mesh(mesh);
+    let mut influences = mesh_skin.gpu_influences();
+    influences.resize(layout.vertex_count as usize, bytemuck::Zeroable::zeroed());
+    let joint_capacity = skin.joints.len().max(mesh_skin.joint_count()).max(1);
+
+    let bind_pose = create_storage_buffer(
+        device,
+        "Skin Bind Pose Buffer",
+        &mesh.create_vertex_buffer(),
+        BufferUsage::STORAGE,
+    )?;
+    let influences = create_storage_buffer(
+        device,
+        "Skin Influence Buffer",
+        bytemuck::cast_slice(&influences),
+        BufferUsage::STORAGE,
+    )?;
+    let identities = vec![Mat4::IDENTITY; joint_capacity];
+    let joint_matrices = create_storage_buffer(
+        device,
+        "Skin Joint Matrix Buffer",
+        bytemuck::cast_slice(&identities),
+        BufferUsage::STORAGE | BufferUsage::COPY_DST,
+    )?;
+
+    Some(SkinnedMesh {
+        uuid: AssetUUID::new(),
+        bind_pose,
+        influences,
+        joint_matrices,
+        joint_capacity: joint_capacity as u32,
+        layout,
+    })
+}
+
+fn create_storage_buffer(
+    device: &dyn GraphicsDevice,
+    label: &'static str,
+    data: &[u8],
+    usage: BufferUsage,
+) -> Option<BufferId> {
+    let desc = BufferDescriptor {
+        label: Some(label.into()),
+        size: data.len() as u64,
+        usage,
+        mapped_at_creation: false,
+    };
+    match device.create_buffer_with_data(&desc, data) {
+        Ok(buffer) => Some(buffer),
+        Err(e) => {
+            log::warn!("Failed to create {}: {:?}", label, e);
+            None
+        }
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "skin_sync",
+        phase: TickPhase::PreExtract,
+        run: skin_sync_system,
+        order_hint: 0,
+        runs_after: &["gpu_mesh_sync", "morph_target_sync"],
+    }
+}
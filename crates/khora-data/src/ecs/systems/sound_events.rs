@@ -0,0 +1,180 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Sound events — resolves events posted through [`AudioEvents`] into
+//! playing `AudioSource`s, and cleans up finished instances.
+//!
+//! Runs in [`TickPhase::PostSimulation`] after `transform_propagation`, so
+//! events posted by `app.update` spawn at the emitter's current position.
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::audio::AudioEvents;
+use crate::ecs::{
+    AudioSource, DataSystemRegistration, GlobalTransform, NonSerialized, PlaybackState,
+    SoundEventInstance, TickPhase, Transform, World,
+};
+
+fn sound_events_system(world: &mut World, services: &ServiceRegistry) {
+    // Phase 1: despawn instances whose sound has finished.
+    let finished: Vec<EntityId> = world
+        .query::<(EntityId, &SoundEventInstance)>()
+        .map(|(entity, _)| entity)
+        .filter(|&entity| {
+            world
+                .get::<AudioSource>(entity)
+                .is_none_or(|s| s.state.is_none())
+        })
+        .collect();
+    for entity in finished {
+        world.despawn(entity);
+    }
+
+    // Phase 2: resolve newly posted events.
+    let Some(events) = services.get::<AudioEvents>() else {
+        return;
+    };
+    let posted = events.take_posted();
+    if posted.is_empty() {
+        return;
+    }
+    let now = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.elapsed_seconds))
+        .unwrap_or(0.0);
+
+    for event in posted {
+        let live = world
+            .query::<&SoundEventInstance>()
+            .filter(|i| i.event == event.name)
+            .count();
+        let Some(sound) = events.select(&event.name, now, live) else {
+            continue;
+        };
+        let Some(emitter) = world.get::<GlobalTransform>(event.emitter).copied() else {
+            log::debug!(
+                "sound_events: emitter {:?} of '{}' has no GlobalTransform",
+                event.emitter,
+                event.name
+            );
+            continue;
+        };
+        // Instances come and go with the sounds they play, so they are never
+        // saved, and they are linked into the emitter's `Children` right away
+        // rather than on the next `hierarchy_sync`.
+        let instance = world.spawn((
+            Transform::identity(),
+            emitter,
+            NonSerialized,
+            AudioSource {
+                handle: sound.clip,
+                volume: sound.volume,
+                pitch: sound.pitch,
+                looping: false,
+                autoplay: false,
+                state: Some(PlaybackState::start()),
+            },
+            SoundEventInstance { event: event.name },
+        ));
+        world.set_parent(instance, Some(event.emitter));
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "sound_events",
+        phase: TickPhase::PostSimulation,
+        run: sound_events_system,
+        order_hint: 10,
+        runs_after: &["transform_propagation"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use khora_core::asset::AssetHandle;
+
+    use super::*;
+    use crate::assets::{SoundBank, SoundData, SoundEvent};
+    use crate::scene::{
+        DefinitionSerializationStrategy, RecipeSerializationStrategy, SerializationStrategy,
+    };
+
+    /// A world with one emitter, and services able to play `step` from it.
+    fn emitter_world() -> (World, EntityId, ServiceRegistry) {
+        let mut world = World::new();
+        let emitter = world.spawn((Transform::identity(), GlobalTransform::identity()));
+
+        let events = AudioEvents::new();
+        let clip = AssetHandle::new(SoundData::default());
+        let bank = SoundBank::new().with_event("step", SoundEvent::new().with_variation(clip, 1.0));
+        events.add_bank(AssetHandle::new(bank));
+        events.post_event("step", emitter);
+        let mut services = ServiceRegistry::new();
+        services.insert(events);
+
+        (world, emitter, services)
+    }
+
+    fn instances(world: &World) -> Vec<EntityId> {
+        world
+            .query::<(EntityId, &SoundEventInstance)>()
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+
+    #[test]
+    fn instances_stay_in_their_emitter_children() {
+        let (mut world, emitter, services) = emitter_world();
+
+        sound_events_system(&mut world, &services);
+        let spawned = instances(&world);
+        assert_eq!(spawned.len(), 1);
+        assert_eq!(world.children(emitter), spawned.as_slice());
+        assert_eq!(world.parent(spawned[0]), Some(emitter));
+
+        // Once the sound has finished, the instance leaves the hierarchy.
+        world.get_mut::<AudioSource>(spawned[0]).unwrap().state = None;
+        sound_events_system(&mut world, &services);
+        assert!(instances(&world).is_empty());
+        assert!(world.children(emitter).is_empty());
+    }
+
+    #[test]
+    fn instances_are_left_out_of_saved_scenes() {
+        let (mut world, _, services) = emitter_world();
+        sound_events_system(&mut world, &services);
+        assert_eq!(instances(&world).len(), 1);
+
+        let strategies: [&dyn SerializationStrategy; 2] = [
+            &DefinitionSerializationStrategy::new(),
+            &RecipeSerializationStrategy::new(),
+        ];
+        for strategy in strategies {
+            let payload = strategy.serialize(&world).unwrap();
+            let mut loaded = World::new();
+            strategy.deserialize(&payload, &mut loaded).unwrap();
+
+            assert_eq!(
+                loaded.iter_entities().count(),
+                1,
+                "{}",
+                strategy.get_strategy_id()
+            );
+            let emitter = loaded.iter_entities().next().unwrap();
+            assert!(loaded.children(emitter).is_empty());
+        }
+    }
+}
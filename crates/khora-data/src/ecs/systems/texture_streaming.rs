@@ -0,0 +1,121 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Texture streaming — reports the mip each entity texture needs to the
+//! [`MipStreamer`] and advances it.
+//!
+//! Runs in [`TickPhase::PreExtract`]. Every `HandleComponent<CpuTexture>` is
+//! tracked while an entity references it. The needed mip comes from a
+//! distance heuristic: the entity's bounding sphere is projected by the
+//! active camera, and the texture is assumed to span that many pixels on
+//! screen. Textures no entity references anymore are untracked.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::executor::SharedExecutor;
+use khora_core::math::{Mat4, Vec3};
+use khora_core::renderer::api::resource::CpuTexture;
+use khora_core::renderer::api::util::{mip_for_screen_size, mip_level_count};
+use khora_core::renderer::GraphicsDevice;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    Bounds, Camera, DataSystemRegistration, GlobalTransform, HandleComponent, IncludeDisabled,
+    ProjectionType, TickPhase, World,
+};
+use crate::MipStreamer;
+
+fn texture_streaming_system(world: &mut World, services: &ServiceRegistry) {
+    let Some(streamer) = services.get::<MipStreamer>() else {
+        return;
+    };
+    let Some(device) = services.get::<Arc<dyn GraphicsDevice>>() else {
+        return;
+    };
+    let viewport_height = device.get_surface_size().1.max(1) as f32;
+    let camera = active_camera(world);
+
+    let mut referenced = HashSet::new();
+    for (entity, texture, global, bounds, _) in world.query::<(
+        EntityId,
+        &HandleComponent<CpuTexture>,
+        &GlobalTransform,
+        Option<&Bounds>,
+        IncludeDisabled,
+    )>() {
+        referenced.insert(texture.uuid);
+        if !streamer.is_tracked(&texture.uuid) {
+            streamer.track_for_entity(texture.uuid, texture.handle.clone());
+        }
+        let Some((camera, eye)) = camera else {
+            continue;
+        };
+        if !world.is_enabled(entity) {
+            continue;
+        }
+
+        let matrix = global.to_matrix();
+        let (center, radius) = match bounds.and_then(Bounds::mesh) {
+            Some(aabb) => (aabb.center(), aabb.half_extents().length()),
+            None => (translation(&matrix), 0.5 * max_scale(&matrix)),
+        };
+        let screen_pixels = match camera.projection {
+            ProjectionType::Perspective { fov_y_radians } => {
+                let distance = ((center - eye).length() - radius).max(camera.z_near);
+                radius / (distance * (fov_y_radians * 0.5).tan()) * viewport_height
+            }
+            ProjectionType::Orthographic { height, .. } => 2.0 * radius / height * viewport_height,
+        };
+        let size = texture.handle.size;
+        let mip = mip_for_screen_size(
+            size.width.max(size.height),
+            screen_pixels,
+            mip_level_count(size.width, size.height),
+        );
+        streamer.request_mip(&texture.uuid, mip);
+    }
+    streamer.untrack_unreferenced(&referenced);
+
+    streamer.update(device, services.get::<SharedExecutor>());
+}
+
+/// Returns the first active, enabled camera and its world position.
+fn active_camera(world: &World) -> Option<(Camera, Vec3)> {
+    world
+        .query::<(EntityId, &Camera, &GlobalTransform)>()
+        .find(|(entity, camera, _)| camera.is_active && world.is_enabled(*entity))
+        .map(|(_, camera, global)| (*camera, translation(&global.to_matrix())))
+}
+
+fn translation(matrix: &Mat4) -> Vec3 {
+    Vec3::new(matrix.cols[3][0], matrix.cols[3][1], matrix.cols[3][2])
+}
+
+fn max_scale(matrix: &Mat4) -> f32 {
+    (0..3)
+        .map(|i| Vec3::new(matrix.cols[i][0], matrix.cols[i][1], matrix.cols[i][2]).length())
+        .fold(0.0, f32::max)
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "texture_streaming",
+        phase: TickPhase::PreExtract,
+        run: texture_streaming_system,
+        order_hint: 0,
+        runs_after: &[],
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time of day — advances every [`TimeOfDay`] and writes the sampled sun
+//! and sky into the entity's [`Light`] and [`Sky`].
+//!
+//! Runs in [`TickPhase::PreExtract`], after `weather`, so `RenderFlow` sees
+//! the sun of this frame under the current cloud cover.
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::LinearRgba;
+use khora_core::renderer::light::LightType;
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{DataSystemRegistration, Light, Sky, TickPhase, TimeOfDay, Weather, World};
+
+/// Share of the sunlight a fully clouded sky blocks.
+const CLOUD_DIMMING: f32 = 0.75;
+/// How far a fully clouded sky is greyed.
+const CLOUD_GREYING: f32 = 0.6;
+
+fn time_of_day_system(world: &mut World, services: &ServiceRegistry) {
+    let delta = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+    let cloud_cover = world
+        .query::<&Weather>()
+        .next()
+        .map_or(0.0, |w| w.cloud_cover.clamp(0.0, 1.0));
+
+    let mut missing = Vec::new();
+    for (entity, time, light, sky) in world.query_mut::<(
+        EntityId,
+        &mut TimeOfDay,
+        Option<&mut Light>,
+        Option<&mut Sky>,
+    )>() {
+        time.advance(delta);
+
+        if let Some(LightType::Directional(sun)) = light.map(|l| &mut l.light_type) {
+            sun.direction = time.sun_direction();
+            if let Some(color) = time.sun_color.as_ref().and_then(|c| c.sample(time.hour)) {
+                sun.color = color;
+            }
+            if let Some(intensity) = time
+                .sun_intensity
+                .as_ref()
+                .and_then(|c| c.sample(time.hour))
+            {
+                sun.intensity = intensity * (1.0 - CLOUD_DIMMING * cloud_cover);
+            }
+        }
+
+        let Some(color) = time.sky_color.as_ref().and_then(|c| c.sample(time.hour)) else {
+            continue;
+        };
+        let grey = (color.r + color.g + color.b) / 3.0;
+        let color = LinearRgba::lerp(
+            color,
+            LinearRgba::rgb(grey, grey, grey),
+            CLOUD_GREYING * cloud_cover,
+        );
+        match sky {
+            Some(sky) => sky.color = color,
+            None => missing.push((entity, Sky::new(color))),
+        }
+    }
+
+    for (entity, sky) in missing {
+        let _ = world.add_component(entity, sky);
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "time_of_day",
+        phase: TickPhase::PreExtract,
+        run: time_of_day_system,
+        order_hint: 0,
+        runs_after: &["weather"],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::math::photometry::LUX_DIRECT_SUNLIGHT;
+    use khora_core::renderer::light::DirectionalLight;
+    use khora_core::utils::frame_time::FrameTime;
+    use std::sync::{Arc, RwLock};
+
+    fn services(delta_seconds: f32) -> ServiceRegistry {
+        let mut services = ServiceRegistry::new();
+        let time: SharedFrameTime = Arc::new(RwLock::new(FrameTime {
+            delta_seconds,
+            ..Default::default()
+        }));
+        services.insert(time);
+        services
+    }
+
+    fn sun(world: &World, entity: EntityId) -> DirectionalLight {
+        match world.get::<Light>(entity).unwrap().light_type {
+            LightType::Directional(sun) => sun,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_clock_drives_the_sun_and_sky() {
+        let mut world = World::new();
+        let entity = world.spawn((
+            TimeOfDay {
+                day_length: 24.0,
+                playing: true,
+                ..TimeOfDay::at(11.0)
+            },
+            Light::directional(),
+        ));
+
+        // One real second is one hour here.
+        time_of_day_system(&mut world, &services(1.0));
+        assert!((world.get::<TimeOfDay>(entity).unwrap().hour - 12.0).abs() < 1e-4);
+        let noon = sun(&world, entity);
+        assert!((noon.direction.y + 1.0).abs() < 1e-4);
+        assert!((noon.intensity - LUX_DIRECT_SUNLIGHT).abs() < 1e-1);
+        assert!(world.get::<Sky>(entity).is_some());
+
+        world.get_mut::<TimeOfDay>(entity).unwrap().hour = 23.0;
+        time_of_day_system(&mut world, &services(0.0));
+        let night = sun(&world, entity);
+        assert!(night.direction.y > 0.0);
+        assert_eq!(night.intensity, 0.0);
+    }
+
+    #[test]
+    fn test_clouds_dim_the_sun() {
+        let mut world = World::new();
+        let entity = world.spawn((TimeOfDay::at(12.0), Light::directional()));
+        world.spawn(Weather::new(crate::ecs::WeatherState::Storm));
+
+        time_of_day_system(&mut world, &services(0.0));
+        let dimmed = LUX_DIRECT_SUNLIGHT * (1.0 - CLOUD_DIMMING);
+        assert!((sun(&world, entity).intensity - dimmed).abs() < 1e-1);
+    }
+}
@@ -15,13 +15,15 @@
 //! Transform propagation — `Transform` → `GlobalTransform` for the scene
 //! hierarchy. Runs in [`TickPhase::PostSimulation`], after `app.update` has
 //! mutated local `Transform`s and before extraction reads `GlobalTransform`.
+//! Baked [`Static`] entities keep the `GlobalTransform` computed by the bake.
 
 use std::collections::{HashMap, VecDeque};
 
 use khora_core::{ecs::entity::EntityId, math::Mat4};
 
 use crate::ecs::{
-    DataSystemRegistration, GlobalTransform, Parent, TickPhase, Transform, Without, World,
+    DataSystemRegistration, GlobalTransform, IncludeDisabled, Parent, Static, TickPhase, Transform,
+    Without, World,
 };
 
 /// Propagates local `Transform` changes through the scene hierarchy to
@@ -33,16 +35,23 @@ pub fn transform_propagation_system(world: &mut World) {
     // Stage 1: initialize the work queue with all root entities.
     // A root has `Transform` and `GlobalTransform` but no `Parent`.
     let mut queue: VecDeque<EntityId> = VecDeque::new();
-    for (id, transform, global_transform, _) in
-        world.query::<(EntityId, &Transform, &mut GlobalTransform, Without<Parent>)>()
-    {
-        global_transform.0 = transform.to_mat4().into();
+    for (id, transform, global_transform, marker, _, _) in world.query::<(
+        EntityId,
+        &Transform,
+        &mut GlobalTransform,
+        Option<&Static>,
+        Without<Parent>,
+        IncludeDisabled,
+    )>() {
+        if !marker.is_some_and(Static::is_baked) {
+            global_transform.0 = transform.to_mat4().into();
+        }
         queue.push_back(id);
     }
 
     // Stage 2: build a parent -> children map for efficient traversal.
     let mut children_map: HashMap<EntityId, Vec<EntityId>> = HashMap::new();
-    for (child_id, parent) in world.query::<(EntityId, &Parent)>() {
+    for (child_id, parent, _) in world.query::<(EntityId, &Parent, IncludeDisabled)>() {
         children_map.entry(parent.0).or_default().push(child_id);
     }
 
@@ -64,6 +73,13 @@ pub fn transform_propagation_system(world: &mut World) {
         let parent_matrix = parent_global.0;
 
         for &child_id in children {
+            // Baked static entities are frozen, but their children may move.
+            if world.get::<Static>(child_id).is_some_and(Static::is_baked) {
+                if world.get::<GlobalTransform>(child_id).is_some() {
+                    queue.push_back(child_id);
+                }
+                continue;
+            }
             let Some(local_transform) = world.get::<Transform>(child_id) else {
                 continue;
             };
@@ -0,0 +1,233 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! UI interaction — hit-tests the routed pointer against the UI and updates
+//! [`UiInteraction`] states, [`UiButton`] clicks and button tints.
+//!
+//! Runs in [`TickPhase::PreSimulation`], right after the engine routed the
+//! tick's input into the [`UiInputRouter`], so `app.update` already sees
+//! this tick's clicks. Hit-testing uses the rects resolved by `ui_layout`
+//! on the previous tick.
+
+use std::cmp::Reverse;
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{DataSystemRegistration, TickPhase, World};
+use crate::ui::{UiButton, UiColor, UiInputRouter, UiInteraction, UiInteractionState, UiTransform};
+
+/// Applies the router's current pointer to every interactive element.
+///
+/// Elements are visited from the highest `z_index` down; the pointer hovers
+/// each of them until it meets one with `blocks_input`, which also becomes
+/// the target of presses. A click fires when the press is released over the
+/// element that received it.
+pub fn update_ui_interaction(world: &mut World, router: &UiInputRouter) {
+    let pointer = router.pointer();
+
+    let mut hits: Vec<(EntityId, i32, bool)> = match pointer.position {
+        Some(position) => world
+            .query::<(EntityId, &UiTransform, &UiInteraction, Option<&UiButton>)>()
+            .filter(|(_, _, _, button)| button.is_none_or(|b| b.enabled))
+            .filter(|(_, transform, _, _)| transform.contains(position))
+            .map(|(entity, transform, interaction, _)| {
+                (entity, transform.z_index, interaction.blocks_input)
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    hits.sort_by_key(|&(_, z_index, _)| Reverse(z_index));
+
+    let mut hovered = Vec::new();
+    let mut blocking = None;
+    for &(entity, _, blocks_input) in &hits {
+        hovered.push(entity);
+        if blocks_input {
+            blocking = Some(entity);
+            break;
+        }
+    }
+    let target = blocking.or_else(|| hovered.first().copied());
+
+    let mut captured = router.captured();
+    let mut focused = router.focused();
+    let mut clicked = None;
+    if pointer.just_pressed {
+        captured = target;
+        focused = target.filter(|&entity| {
+            world
+                .get::<UiInteraction>(entity)
+                .is_some_and(|interaction| interaction.focusable)
+        });
+    }
+    if pointer.just_released {
+        clicked = captured.filter(|entity| hovered.contains(entity));
+        captured = None;
+    } else if !pointer.down {
+        captured = None;
+    }
+    router.set_targets(blocking, captured, focused);
+
+    let interactive: Vec<EntityId> = world
+        .query::<(EntityId, &UiInteraction)>()
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in interactive {
+        let is_hovered = hovered.contains(&entity);
+        let state = if captured == Some(entity) && is_hovered {
+            UiInteractionState::Pressed
+        } else if is_hovered && captured.is_none_or(|c| c == entity) {
+            UiInteractionState::Hovered
+        } else if focused == Some(entity) {
+            UiInteractionState::Focused
+        } else {
+            UiInteractionState::Normal
+        };
+        if let Some(interaction) = world.get_mut::<UiInteraction>(entity) {
+            interaction.state = state;
+        }
+
+        let Some(button) = world.get_mut::<UiButton>(entity) else {
+            continue;
+        };
+        button.clicked = clicked == Some(entity);
+        let tint = UiColor(button.color_for(state));
+        if let Some(color) = world.get_mut::<UiColor>(entity) {
+            *color = tint;
+        } else {
+            let _ = world.add_component(entity, tint);
+        }
+    }
+}
+
+fn ui_interaction_system(world: &mut World, services: &ServiceRegistry) {
+    if let Some(router) = services.get::<UiInputRouter>() {
+        update_ui_interaction(world, router);
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "ui_interaction",
+        phase: TickPhase::PreSimulation,
+        run: ui_interaction_system,
+        order_hint: 0,
+        runs_after: &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::math::{Vec2, Vec4};
+    use khora_core::platform::{InputEvent, MouseButton};
+
+    fn spawn_button(world: &mut World, pos: Vec2, z_index: i32) -> EntityId {
+        world.spawn((
+            UiTransform {
+                pos,
+                size: Vec2::new(100.0, 40.0),
+                z_index,
+            },
+            UiInteraction {
+                blocks_input: true,
+                ..Default::default()
+            },
+            UiButton::new(Vec4::new(0.5, 0.5, 0.5, 1.0)),
+        ))
+    }
+
+    fn move_to(router: &UiInputRouter, x: f32, y: f32) {
+        router.route(&[InputEvent::MouseMoved { x, y }]);
+    }
+
+    #[test]
+    fn press_and_release_over_button_clicks_it() {
+        let mut world = World::new();
+        let button = spawn_button(&mut world, Vec2::ZERO, 0);
+        let router = UiInputRouter::new();
+
+        move_to(&router, 10.0, 10.0);
+        update_ui_interaction(&mut world, &router);
+        assert_eq!(
+            world.get::<UiInteraction>(button).unwrap().state,
+            UiInteractionState::Hovered
+        );
+        assert!(router.is_pointer_over_ui());
+
+        router.route(&[InputEvent::MouseButtonPressed {
+            button: MouseButton::Left,
+        }]);
+        update_ui_interaction(&mut world, &router);
+        assert_eq!(
+            world.get::<UiInteraction>(button).unwrap().state,
+            UiInteractionState::Pressed
+        );
+        let pressed = world.get::<UiButton>(button).unwrap().pressed;
+        assert_eq!(world.get::<UiColor>(button).unwrap().0, pressed);
+
+        router.route(&[InputEvent::MouseButtonReleased {
+            button: MouseButton::Left,
+        }]);
+        update_ui_interaction(&mut world, &router);
+        assert!(world.get::<UiButton>(button).unwrap().clicked);
+
+        router.route(&[]);
+        update_ui_interaction(&mut world, &router);
+        assert!(!world.get::<UiButton>(button).unwrap().clicked);
+    }
+
+    #[test]
+    fn release_outside_cancels_click() {
+        let mut world = World::new();
+        let button = spawn_button(&mut world, Vec2::ZERO, 0);
+        let router = UiInputRouter::new();
+
+        router.route(&[
+            InputEvent::MouseMoved { x: 10.0, y: 10.0 },
+            InputEvent::MouseButtonPressed {
+                button: MouseButton::Left,
+            },
+        ]);
+        update_ui_interaction(&mut world, &router);
+        router.route(&[
+            InputEvent::MouseMoved { x: 500.0, y: 500.0 },
+            InputEvent::MouseButtonReleased {
+                button: MouseButton::Left,
+            },
+        ]);
+        update_ui_interaction(&mut world, &router);
+
+        assert!(!world.get::<UiButton>(button).unwrap().clicked);
+        assert!(!router.is_pointer_over_ui());
+    }
+
+    #[test]
+    fn topmost_blocking_element_takes_the_pointer() {
+        let mut world = World::new();
+        let below = spawn_button(&mut world, Vec2::ZERO, 0);
+        let above = spawn_button(&mut world, Vec2::new(50.0, 0.0), 1);
+        let router = UiInputRouter::new();
+
+        move_to(&router, 60.0, 10.0);
+        update_ui_interaction(&mut world, &router);
+
+        assert_eq!(router.hovered(), Some(above));
+        assert_eq!(
+            world.get::<UiInteraction>(below).unwrap().state,
+            UiInteractionState::Normal
+        );
+    }
+}
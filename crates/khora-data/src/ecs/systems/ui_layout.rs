@@ -0,0 +1,148 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! UI anchor layout — resolves every [`UiAnchor`] into a [`UiTransform`].
+//!
+//! Runs in [`TickPhase::PostSimulation`] so anchors edited by `app.update`
+//! (and surface resizes) are visible to `UiFlow` extraction this frame.
+//! Parents are resolved before their children; an element whose `Parent`
+//! carries no `UiTransform` is anchored to the surface.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Vec2;
+use khora_core::renderer::GraphicsDevice;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{DataSystemRegistration, Parent, TickPhase, World};
+use crate::ui::{UiAnchor, UiTransform};
+
+/// Resolves the anchored elements of `world` against a surface of size
+/// `surface` (pixels).
+///
+/// Elements without a `UiTransform` get one, stacked one z-level above
+/// their parent.
+pub fn resolve_ui_anchors(world: &mut World, surface: Vec2) {
+    let mut anchored: Vec<(EntityId, UiAnchor, Option<EntityId>)> = world
+        .query::<(EntityId, &UiAnchor, Option<&Parent>)>()
+        .map(|(entity, anchor, parent)| (entity, *anchor, parent.map(|p| p.0)))
+        .collect();
+    if anchored.is_empty() {
+        return;
+    }
+
+    let parents: HashMap<EntityId, Option<EntityId>> = anchored
+        .iter()
+        .map(|(entity, _, parent)| (*entity, *parent))
+        .collect();
+    let depth = |mut entity: EntityId| {
+        let mut depth = 0usize;
+        while let Some(Some(parent)) = parents.get(&entity) {
+            depth += 1;
+            if depth > parents.len() {
+                break;
+            }
+            entity = *parent;
+        }
+        depth
+    };
+    anchored.sort_by_cached_key(|(entity, _, _)| depth(*entity));
+
+    for (entity, anchor, parent) in anchored {
+        let parent_rect = parent.and_then(|p| world.get::<UiTransform>(p).copied());
+        let (parent_pos, parent_size, parent_z) = match parent_rect {
+            Some(rect) => (rect.pos, rect.size, rect.z_index),
+            None => (Vec2::ZERO, surface, -1),
+        };
+        let (pos, size) = anchor.resolve(parent_pos, parent_size);
+        if let Some(transform) = world.get_mut::<UiTransform>(entity) {
+            transform.pos = pos;
+            transform.size = size;
+        } else {
+            let _ = world.add_component(
+                entity,
+                UiTransform {
+                    pos,
+                    size,
+                    z_index: parent_z + 1,
+                },
+            );
+        }
+    }
+}
+
+fn ui_layout_system(world: &mut World, services: &ServiceRegistry) {
+    let Some(device) = services.get::<Arc<dyn GraphicsDevice>>() else {
+        return;
+    };
+    let (width, height) = device.get_surface_size();
+    resolve_ui_anchors(world, Vec2::new(width as f32, height as f32));
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "ui_layout",
+        phase: TickPhase::PostSimulation,
+        run: ui_layout_system,
+        order_hint: 20,
+        runs_after: &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bottom_right_anchor_tracks_surface_size() {
+        let mut world = World::new();
+        let panel = world.spawn(UiAnchor::new(Vec2::ONE, Vec2::new(200.0, 100.0)));
+
+        resolve_ui_anchors(&mut world, Vec2::new(1280.0, 720.0));
+        let rect = *world.get::<UiTransform>(panel).unwrap();
+        assert_eq!(rect.pos, Vec2::new(1080.0, 620.0));
+        assert_eq!(rect.size, Vec2::new(200.0, 100.0));
+
+        resolve_ui_anchors(&mut world, Vec2::new(1920.0, 1080.0));
+        let rect = *world.get::<UiTransform>(panel).unwrap();
+        assert_eq!(rect.pos, Vec2::new(1720.0, 980.0));
+    }
+
+    #[test]
+    fn children_are_anchored_inside_their_parent() {
+        let mut world = World::new();
+        let parent = world.spawn(
+            UiAnchor::new(Vec2::new(0.5, 0.5), Vec2::new(400.0, 300.0))
+                .with_offset(Vec2::new(10.0, 0.0)),
+        );
+        let child = world.spawn((
+            UiAnchor {
+                size: Vec2::new(-20.0, -20.0),
+                ..UiAnchor::stretch()
+            },
+            Parent(parent),
+        ));
+
+        resolve_ui_anchors(&mut world, Vec2::new(1000.0, 1000.0));
+        let parent_rect = *world.get::<UiTransform>(parent).unwrap();
+        let child_rect = *world.get::<UiTransform>(child).unwrap();
+
+        assert_eq!(parent_rect.pos, Vec2::new(310.0, 350.0));
+        assert_eq!(child_rect.pos, Vec2::new(320.0, 360.0));
+        assert_eq!(child_rect.size, Vec2::new(380.0, 280.0));
+        assert!(child_rect.z_index > parent_rect.z_index);
+    }
+}
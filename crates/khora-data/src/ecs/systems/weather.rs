@@ -0,0 +1,50 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weather — blends every [`Weather`] towards its preset and drives the
+//! [`WeatherAudio`] sources with the rain.
+//!
+//! Runs in [`TickPhase::PreExtract`] so a preset changed by `app.update`
+//! starts blending the same frame.
+
+use khora_core::utils::frame_time::SharedFrameTime;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{AudioSource, DataSystemRegistration, TickPhase, Weather, WeatherAudio, World};
+
+fn weather_system(world: &mut World, services: &ServiceRegistry) {
+    let delta = services
+        .get::<SharedFrameTime>()
+        .and_then(|time| time.read().ok().map(|t| t.delta_seconds))
+        .unwrap_or(0.0);
+
+    for weather in world.query_mut::<&mut Weather>() {
+        weather.advance(delta);
+    }
+
+    let rain = world.query::<&Weather>().next().map_or(0.0, |w| w.rain);
+    for (source, audio) in world.query_mut::<(&mut AudioSource, &WeatherAudio)>() {
+        source.volume = audio.volume * rain;
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "weather",
+        phase: TickPhase::PreExtract,
+        run: weather_system,
+        order_hint: 0,
+        runs_after: &[],
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Interned tag strings.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Every tag string seen by the process, with its index.
+#[derive(Default)]
+struct TagInterner {
+    ids: HashMap<&'static str, u32>,
+    names: Vec<&'static str>,
+}
+
+fn interner() -> &'static RwLock<TagInterner> {
+    static INTERNER: OnceLock<RwLock<TagInterner>> = OnceLock::new();
+    INTERNER.get_or_init(Default::default)
+}
+
+/// An interned tag string, such as `"enemy"` or `"pickup"`.
+///
+/// Comparing and hashing a `Tag` is comparing integers. Each distinct
+/// string is stored once for the life of the process, so tags are meant for
+/// a bounded vocabulary, not for per-entity data. Tags serialize as their
+/// string, so saved scenes don't depend on interning order.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tag(u32);
+
+impl Tag {
+    /// Interns `name` and returns its tag.
+    pub fn new(name: &str) -> Self {
+        if let Some(tag) = Self::get(name) {
+            return tag;
+        }
+        let mut interner = interner().write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&id) = interner.ids.get(name) {
+            return Self(id);
+        }
+        let id = interner.names.len() as u32;
+        let name: &'static str = Box::leak(name.to_owned().into_boxed_str());
+        interner.names.push(name);
+        interner.ids.insert(name, id);
+        Self(id)
+    }
+
+    /// Returns the tag of `name` if it was interned, without interning it.
+    pub fn get(name: &str) -> Option<Self> {
+        let interner = interner().read().unwrap_or_else(PoisonError::into_inner);
+        interner.ids.get(name).copied().map(Self)
+    }
+
+    /// Returns the tag's string.
+    pub fn as_str(self) -> &'static str {
+        let interner = interner().read().unwrap_or_else(PoisonError::into_inner);
+        interner.names[self.0 as usize]
+    }
+}
+
+impl fmt::Debug for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tag({:?})", self.as_str())
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Tag {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl Serialize for Tag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Self::new(&name))
+    }
+}
+
+impl Encode for Tag {
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        Encode::encode(self.as_str(), encoder)
+    }
+}
+
+impl<Context> Decode<Context> for Tag {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let name: String = Decode::decode(decoder)?;
+        Ok(Self::new(&name))
+    }
+}
+
+impl<'de, Context> bincode::BorrowDecode<'de, Context> for Tag {
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let name: String = Decode::decode(decoder)?;
+        Ok(Self::new(&name))
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Looking entities up by their [`Tags`].
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{IncludeDisabled, Tag, Tags, World};
+
+/// Entities by tag, rebuilt on the first lookup after a `Tags` was added
+/// or written.
+///
+/// Like the name index, removed tags and despawned entities are not
+/// tracked; lookups check every candidate against the world instead.
+#[derive(Debug, Default)]
+pub(crate) struct TagIndex {
+    /// `Tags` write count the index was built at, `None` when stale.
+    built_at: Option<u64>,
+    entities: HashMap<Tag, Vec<EntityId>>,
+}
+
+impl TagIndex {
+    /// Forces a rebuild on the next lookup.
+    pub(crate) fn invalidate(&mut self) {
+        self.built_at = None;
+    }
+}
+
+impl World {
+    /// Returns every enabled entity tagged `tag`, lowest index first.
+    ///
+    /// Like queries, this skips [`Disabled`](crate::ecs::Disabled) entities.
+    /// Lookups are hash-map fast once the index is built, which happens
+    /// again after a [`Tags`] is added or changed.
+    pub fn query_tagged(&self, tag: &str) -> Vec<EntityId> {
+        let Some(tag) = Tag::get(tag) else {
+            return Vec::new();
+        };
+        let writes = self.changes.write_count(TypeId::of::<Tags>());
+        let mut index = self.tags.lock().unwrap_or_else(PoisonError::into_inner);
+        if index.built_at != Some(writes) {
+            index.entities.clear();
+            for (entity, tags, _) in self.query::<(EntityId, &Tags, IncludeDisabled)>() {
+                for tag in tags.iter() {
+                    index.entities.entry(tag).or_default().push(entity);
+                }
+            }
+            for entities in index.entities.values_mut() {
+                entities.sort_by_key(|entity| (entity.index, entity.generation));
+            }
+            index.built_at = Some(writes);
+        }
+        index
+            .entities
+            .get(&tag)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&entity| {
+                self.is_enabled(entity)
+                    && self.get::<Tags>(entity).is_some_and(|tags| tags.has(tag))
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `entity` has a [`Tags`] holding `tag`.
+    pub fn has_tag(&self, entity: EntityId, tag: &str) -> bool {
+        self.get::<Tags>(entity)
+            .is_some_and(|tags| tags.contains(tag))
+    }
+}
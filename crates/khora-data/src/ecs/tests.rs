@@ -227,6 +227,88 @@ fn test_entity_id_recycling_and_aba_protection() {
     );
 }
 
+#[test]
+fn test_stale_id_is_rejected_by_every_accessor_after_reuse() {
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+
+    let stale = world.spawn((Position(1), Velocity(1)));
+    assert!(world.despawn(stale));
+    let live = world.spawn((Position(2), Velocity(2)));
+    assert_eq!(live.index, stale.index);
+    assert_ne!(live.generation, stale.generation);
+
+    // Reads and writes through the stale handle never reach the new entity.
+    assert!(world.get::<Position>(stale).is_none());
+    assert!(world.get_mut::<Position>(stale).is_none());
+    let [stale_pos, live_pos] = world.get_many_mut::<Position, 2>([stale, live]);
+    assert!(stale_pos.is_none());
+    assert_eq!(live_pos.copied(), Some(Position(2)));
+    assert!(world.add_component(stale, RenderTag).is_err());
+    assert!(world.remove_component::<Velocity>(stale).is_err());
+    assert!(world.remove_component_domain::<Velocity>(stale).is_none());
+    assert!(!world.despawn(stale));
+
+    // The live entity is untouched.
+    assert_eq!(world.get::<Position>(live), Some(&Position(2)));
+    assert_eq!(world.get::<Velocity>(live), Some(&Velocity(2)));
+    assert!(world.get::<RenderTag>(live).is_none());
+}
+
+#[test]
+fn test_despawned_id_is_rejected_before_reuse() {
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+
+    let id = world.spawn(Position(1));
+    assert!(world.despawn(id));
+
+    assert!(world.get::<Position>(id).is_none());
+    assert!(world.get_mut::<Position>(id).is_none());
+    assert!(world.add_component(id, Position(3)).is_err());
+    assert!(!world.despawn(id));
+    assert_eq!(world.iter_entities().count(), 0);
+}
+
+#[test]
+fn test_id_reuse_across_many_generations() {
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+
+    let mut previous = Vec::new();
+    let mut id = world.spawn(Position(0));
+    for i in 1..32 {
+        assert!(world.despawn(id));
+        previous.push(id);
+        id = world.spawn(Position(i));
+        assert_eq!(id.index, 0);
+        assert_eq!(id.generation, i as u32);
+    }
+
+    for stale in previous {
+        assert!(world.get::<Position>(stale).is_none());
+    }
+    assert_eq!(world.get::<Position>(id), Some(&Position(31)));
+}
+
+#[test]
+fn test_generation_wraps_instead_of_overflowing() {
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+
+    let mut old = world.spawn(Position(1));
+    old.generation = u32::MAX;
+    world.entities.get_mut(0).unwrap().0.generation = u32::MAX;
+    assert!(world.despawn(old));
+
+    let recycled = world.spawn(Position(2));
+    assert_eq!(recycled.generation, 0);
+    assert!(world.get::<Position>(old).is_none());
+    assert_eq!(world.get::<Position>(recycled), Some(&Position(2)));
+}
+
 #[test]
 fn test_despawn_with_swap_remove_logic() {
     // --- 1. SETUP ---
@@ -992,3 +1074,1177 @@ fn test_get_many_mut() {
         assert!(p1.is_none());
     }
 }
+
+#[test]
+fn test_asset_mesh_reference_defers_to_pending_assets() {
+    use crate::ecs::{HandleComponent, PendingAssetKind, PendingAssets, SerializableMeshRef};
+    use crate::scene::registry::ComponentRegistration;
+    use khora_core::asset::AssetUUID;
+    use khora_core::renderer::api::scene::Mesh;
+    use std::any::TypeId;
+
+    let reg = inventory::iter::<ComponentRegistration>
+        .into_iter()
+        .find(|r| r.type_id == TypeId::of::<HandleComponent<Mesh>>())
+        .expect("mesh handle registration");
+
+    let mut world = World::new();
+    let entity = world.spawn(());
+    let uuid = AssetUUID::new_v5("meshes/rock.gltf");
+    let data = bincode::encode_to_vec(
+        SerializableMeshRef::Asset(uuid),
+        bincode::config::standard(),
+    )
+    .unwrap();
+
+    (reg.deserialize_recipe)(&mut world, entity, &data).unwrap();
+
+    // No placeholder mesh; the reference waits for the asset pipeline.
+    assert!(world.get::<HandleComponent<Mesh>>(entity).is_none());
+    let pending = world.get::<PendingAssets>(entity).unwrap();
+    assert_eq!(pending.uuid_of(PendingAssetKind::Mesh), Some(uuid));
+
+    // Re-saving before resolution keeps the original reference.
+    let saved = (reg.serialize_recipe)(&world, entity).unwrap();
+    assert_eq!(saved, data);
+}
+
+#[test]
+fn test_material_animation_drives_override() {
+    use crate::ecs::{MaterialAnimation, MaterialOverride};
+    use khora_core::animation::Curve;
+    use khora_core::asset::MaterialParams;
+    use khora_core::math::LinearRgba;
+
+    let red = LinearRgba::new(1.0, 0.0, 0.0, 1.0);
+    let mut anim =
+        MaterialAnimation::base_color(Curve::new([(0.0, LinearRgba::WHITE), (1.0, red)]));
+    let mut target = MaterialOverride::default();
+
+    anim.advance(2.0);
+    assert_eq!(anim.time, 1.0, "non-looping playback clamps to duration");
+    anim.sample_into(&mut target);
+    assert_eq!(target.base_color, Some(red));
+    assert!(target.emissive.is_none());
+
+    let params = target.apply(MaterialParams::default());
+    assert_eq!(params.base_color, red);
+    assert_eq!(
+        params.specular_power,
+        MaterialParams::default().specular_power
+    );
+}
+
+#[test]
+fn test_query_profiler_records_matches_and_pages() {
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    for i in 0..3 {
+        world.spawn((Position(i), Velocity(i)));
+    }
+    world.spawn(Position(9));
+
+    // Disabled by default: nothing is recorded.
+    assert_eq!(world.query::<&Position>().count(), 4);
+    world.query_profiler().end_frame();
+    assert!(world.query_profiler().last_frame().is_empty());
+
+    world.query_profiler().set_enabled(true);
+    assert_eq!(world.query::<&Position>().count(), 4);
+    assert_eq!(world.query::<&Position>().count(), 4);
+    for velocity in world.query_mut::<&mut Velocity>() {
+        velocity.0 += 1;
+    }
+    world.query_profiler().end_frame();
+
+    let profiles = world.query_profiler().last_frame();
+    assert_eq!(profiles.len(), 2);
+    let positions = profiles
+        .iter()
+        .find(|p| p.signature.contains("Position"))
+        .unwrap();
+    assert_eq!(positions.runs, 2);
+    assert_eq!(positions.entities_matched, 8);
+    assert_eq!(positions.pages_touched, 4, "two pages per run");
+    let velocities = profiles
+        .iter()
+        .find(|p| p.signature.contains("Velocity"))
+        .unwrap();
+    assert_eq!((velocities.runs, velocities.entities_matched), (1, 3));
+    assert!(world.query_profiler().report().contains("Velocity"));
+}
+
+#[test]
+fn test_events_live_for_two_frames() {
+    use crate::ecs::EventReader;
+
+    let mut world = World::default();
+    let mut reader = EventReader::<u32>::default();
+
+    world.send_event(1u32);
+    world.send_event(2u32);
+    assert_eq!(
+        world.read_events(&mut reader).copied().collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(world.read_events(&mut reader).count(), 0);
+
+    // Frame 1 ends: the events are still buffered for late readers.
+    world.update_events();
+    world.send_event(3u32);
+    let mut late = EventReader::<u32>::default();
+    assert_eq!(
+        world.read_events(&mut late).copied().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        world.read_events(&mut reader).copied().collect::<Vec<_>>(),
+        vec![3]
+    );
+
+    // Frame 2 ends: events from frame 1 are gone.
+    world.update_events();
+    world.update_events();
+    assert!(world.events::<u32>().unwrap().is_empty());
+}
+
+#[test]
+fn test_event_readers_are_independent_and_count_missed_events() {
+    use crate::ecs::Events;
+
+    let mut events = Events::<&'static str>::new();
+    let mut a = events.reader();
+    events.send("hit");
+    let mut b = events.reader();
+    events.send("miss");
+
+    assert_eq!(a.len(&events), 2);
+    assert_eq!(
+        a.read(&events).copied().collect::<Vec<_>>(),
+        ["hit", "miss"]
+    );
+    assert!(a.is_empty(&events));
+
+    // `b` never reads before both frames end; the event is dropped.
+    events.update();
+    events.update();
+    assert_eq!(b.read(&events).count(), 0);
+    assert_eq!(b.missed(), 1);
+}
+
+#[test]
+fn test_event_writer_and_system_events() {
+    use crate::ecs::{EventReader, SystemAccess, SystemPool, SystemSchedule};
+    use khora_core::ServiceRegistry;
+    use std::sync::Mutex;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.spawn(Position(-1));
+    world.spawn(Position(4));
+    {
+        let mut writer = world.event_writer::<u32>();
+        writer.send(1);
+        writer.send_batch([2, 3]);
+        assert_eq!(writer.sent_this_frame(), 3);
+    }
+
+    // A system reads with a reader it owns and sends through its context.
+    let reader = Mutex::new(EventReader::<u32>::default());
+    let mut schedule = SystemSchedule::new();
+    schedule.add_system("relay", SystemAccess::of::<&Position>(), move |ctx| {
+        let mut reader = reader.lock().unwrap();
+        let sum: u32 = ctx.read_events(&mut reader).sum();
+        ctx.send_event(format!("sum {sum}"));
+        for position in ctx.query::<&Position>() {
+            if position.0 < 0 {
+                ctx.send_event(position.0 as u32);
+            }
+        }
+    });
+    let pool = SystemPool::new(1).unwrap();
+    schedule.run(&mut world, &ServiceRegistry::new(), &pool);
+
+    let mut strings = EventReader::<String>::default();
+    assert_eq!(
+        world.read_events(&mut strings).collect::<Vec<_>>(),
+        ["sum 6"]
+    );
+    assert_eq!(world.events::<u32>().unwrap().len(), 4);
+}
+
+#[test]
+fn test_unknown_event_type_reads_nothing() {
+    use crate::ecs::EventReader;
+
+    let world = World::default();
+    let mut reader = EventReader::<u64>::default();
+    assert!(world.events::<u64>().is_none());
+    assert_eq!(world.read_events(&mut reader).count(), 0);
+}
+
+#[test]
+fn test_set_enabled_follows_the_hierarchy() {
+    use crate::ecs::{Disabled, Parent, Transform};
+
+    let mut world = World::default();
+    let root = world.spawn(Transform::identity());
+    let child = world.spawn((Transform::identity(), Parent(root)));
+    let grandchild = world.spawn((Transform::identity(), Parent(child)));
+
+    world.set_enabled(root, false);
+    assert!(!world.is_enabled(root) && !world.is_enabled(child) && !world.is_enabled(grandchild));
+    assert_eq!(
+        world.get::<Disabled>(grandchild),
+        Some(&Disabled { inherited: true })
+    );
+
+    // A child disabled on its own keeps its subtree disabled.
+    world.set_enabled(child, false);
+    world.set_enabled(root, true);
+    assert!(world.is_enabled(root));
+    assert!(!world.is_enabled(child) && !world.is_enabled(grandchild));
+
+    // Enabling a child while its parent is disabled only makes it follow.
+    world.set_enabled(root, false);
+    world.set_enabled(child, true);
+    assert!(!world.is_enabled(child));
+    world.set_enabled(root, true);
+    assert!(world.is_enabled(child) && world.is_enabled(grandchild));
+}
+
+#[test]
+fn test_queries_skip_disabled_entities() {
+    use crate::ecs::{Disabled, IncludeDisabled, Transform};
+    use khora_core::ecs::entity::EntityId;
+
+    let mut world = World::default();
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+    let on = world.spawn(Transform::identity());
+    let off = world.spawn(Transform::identity());
+    world.add_component(on, RenderTag).unwrap();
+    world.add_component(off, RenderTag).unwrap();
+    world.set_enabled(off, false);
+
+    // Same-domain pages are filtered out; other domains entity by entity.
+    assert_eq!(world.query::<&Transform>().count(), 1);
+    let tagged: Vec<EntityId> = world
+        .query::<(EntityId, &RenderTag)>()
+        .map(|(entity, _)| entity)
+        .collect();
+    assert_eq!(tagged, vec![on]);
+    assert_eq!(world.query_mut::<&mut RenderTag>().count(), 1);
+
+    // Opting in, or naming `Disabled`, lets them through.
+    assert_eq!(world.query::<(&RenderTag, IncludeDisabled)>().count(), 2);
+    let disabled: Vec<EntityId> = world
+        .query::<(EntityId, &Disabled)>()
+        .map(|(entity, _)| entity)
+        .collect();
+    assert_eq!(disabled, vec![off]);
+
+    world.set_enabled(off, true);
+    assert_eq!(world.query::<&RenderTag>().count(), 2);
+}
+
+#[test]
+fn test_hidden_entities_are_not_visible() {
+    use crate::ecs::{Parent, Transform};
+
+    let mut world = World::default();
+    let root = world.spawn(Transform::identity());
+    let child = world.spawn((Transform::identity(), Parent(root)));
+
+    world.set_visible(root, false);
+    assert!(!world.is_visible(child));
+    assert!(world.is_enabled(child));
+
+    world.set_visible(root, true);
+    assert!(world.is_visible(child));
+    world.set_enabled(child, false);
+    assert!(!world.is_visible(child));
+}
+
+#[test]
+fn test_transfer_entity_between_worlds() {
+    let mut src = World::default();
+    src.register_component::<Position>(SemanticDomain::Spatial);
+    src.register_component::<RenderTag>(SemanticDomain::Render);
+    let mut dst = World::default();
+
+    let stays = src.spawn(Position(1));
+    let moved = src.spawn((Position(2), RenderTag));
+
+    let new_id = src.transfer_entity(moved, &mut dst).unwrap();
+
+    assert!(src.get::<Position>(moved).is_none());
+    assert_eq!(src.get::<Position>(stays), Some(&Position(1)));
+    assert_eq!(dst.get::<Position>(new_id), Some(&Position(2)));
+    assert_eq!(dst.get::<RenderTag>(new_id), Some(&RenderTag));
+    assert_eq!(dst.query::<&Position>().count(), 1);
+
+    // The moved entity is gone from the source: transferring it again fails.
+    assert!(src.transfer_entity(moved, &mut dst).is_none());
+}
+
+#[test]
+fn test_migrate_entities_remaps_hierarchy_and_keeps_handles() {
+    use crate::ecs::{migrate_entities, Children, HandleComponent, Parent};
+    use khora_core::asset::{AssetHandle, AssetUUID, UnlitMaterial};
+
+    let mut src = World::new();
+    src.register_component::<Position>(SemanticDomain::Spatial);
+    src.register_component::<HandleComponent<UnlitMaterial>>(SemanticDomain::Render);
+    let mut dst = World::new();
+
+    let material = HandleComponent {
+        handle: AssetHandle::new(UnlitMaterial::default()),
+        uuid: AssetUUID::new(),
+    };
+    let stays = src.spawn(Position(0));
+    let root = src.spawn((Position(1), material.clone()));
+    let child = src.spawn((Position(2), Parent(root)));
+    let orphan = src.spawn((Position(3), Parent(stays)));
+    // `insert_component` leaves no orphaned row behind for the query below.
+    src.insert_component(root, Children(vec![child])).unwrap();
+    src.insert_component(stays, Children(vec![orphan])).unwrap();
+
+    let map = migrate_entities(&mut src, &mut dst, |world, entity| {
+        world.get::<Position>(entity).is_some_and(|p| p.0 > 0)
+    });
+
+    assert_eq!(map.len(), 3);
+    let (new_root, new_child, new_orphan) = (map[&root], map[&child], map[&orphan]);
+    assert_eq!(dst.get::<Parent>(new_child), Some(&Parent(new_root)));
+    assert_eq!(
+        dst.get::<Children>(new_root),
+        Some(&Children(vec![new_child]))
+    );
+    assert!(dst.get::<Parent>(new_orphan).is_none());
+
+    let moved = dst.get::<HandleComponent<UnlitMaterial>>(new_root).unwrap();
+    assert!(moved.handle == material.handle);
+    assert_eq!(moved.uuid, material.uuid);
+
+    assert_eq!(src.query::<&Position>().count(), 1);
+    assert_eq!(src.get::<Children>(stays), Some(&Children(vec![])));
+}
+
+#[test]
+fn test_added_filter_matches_spawns_and_insertions_until_cleared() {
+    use crate::ecs::query::Added;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+
+    let a = world.spawn(Position(1));
+    let b = world.spawn(Position(2));
+    assert_eq!(world.query::<(&Position, Added<Position>)>().count(), 2);
+
+    world.clear_trackers();
+    assert_eq!(world.query::<(&Position, Added<Position>)>().count(), 0);
+
+    world.add_component(b, Velocity(3)).unwrap();
+    assert!(world.is_added::<Velocity>(b));
+    assert!(!world.is_added::<Position>(b));
+    assert!(!world.is_added::<Velocity>(a));
+    assert_eq!(world.query::<(&Position, Added<Velocity>)>().count(), 1);
+}
+
+#[test]
+fn test_changed_filter_tracks_mutable_access() {
+    use crate::ecs::query::Changed;
+    use khora_core::ecs::entity::EntityId;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+
+    let a = world.spawn(Position(1));
+    let b = world.spawn(Position(2));
+    world.clear_trackers();
+    assert_eq!(world.query::<Changed<Position>>().count(), 0);
+
+    world.get_mut::<Position>(a).unwrap().0 = 10;
+    let changed: Vec<EntityId> = world
+        .query::<(EntityId, Changed<Position>)>()
+        .map(|(e, _)| e)
+        .collect();
+    assert_eq!(changed, vec![a]);
+    assert!(!world.is_changed::<Position>(b));
+
+    world.clear_trackers();
+    for position in world.query_mut::<&mut Position>() {
+        position.0 += 1;
+    }
+    assert!(world.is_changed::<Position>(a));
+    assert!(world.is_changed::<Position>(b));
+
+    // A shared query never counts as a write.
+    world.clear_trackers();
+    assert_eq!(world.query::<&Position>().count(), 2);
+    assert_eq!(world.query::<Changed<Position>>().count(), 0);
+}
+
+#[test]
+fn test_query_since_uses_caller_baseline() {
+    use crate::ecs::query::Changed;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+
+    let a = world.spawn(Position(1));
+    world.spawn(Position(2));
+    let baseline = world.increment_change_tick();
+
+    world.get_mut::<Position>(a).unwrap().0 = 5;
+    world.clear_trackers();
+
+    // The world-wide window is closed, but the caller's own baseline still
+    // sees the write.
+    assert_eq!(world.query::<Changed<Position>>().count(), 0);
+    assert_eq!(
+        world.query::<Changed<Position>>().since(baseline).count(),
+        1
+    );
+}
+
+#[test]
+fn test_schedule_batches_non_conflicting_systems_together() {
+    use crate::ecs::{SystemAccess, SystemSchedule};
+
+    let mut schedule = SystemSchedule::new();
+    schedule
+        .add_system(
+            "move",
+            SystemAccess::of::<(&mut Position, &Velocity)>(),
+            |_| {},
+        )
+        .add_system("render", SystemAccess::of::<&RenderTag>(), |_| {})
+        .add_system("read_pos", SystemAccess::of::<&Position>(), |_| {})
+        .add_system("steer", SystemAccess::of::<&mut Velocity>(), |_| {})
+        .add_exclusive("cleanup", |_, _| {})
+        .add_system("late", SystemAccess::of::<&RenderTag>(), |_| {});
+
+    assert_eq!(
+        schedule.batches(),
+        vec![
+            vec!["move", "render"],
+            vec!["read_pos", "steer"],
+            vec!["cleanup"],
+            vec!["late"],
+        ]
+    );
+}
+
+#[test]
+fn test_schedule_runs_systems_on_the_pool() {
+    use crate::ecs::{SystemAccess, SystemPool, SystemSchedule};
+    use khora_core::ServiceRegistry;
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use std::sync::Arc;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+    for i in 0..100 {
+        world.spawn((Position(i), Velocity(1)));
+        world.spawn(RenderTag);
+    }
+
+    let tags = Arc::new(AtomicI32::new(0));
+    let counted = Arc::clone(&tags);
+    let mut schedule = SystemSchedule::new();
+    schedule
+        .add_system(
+            "move",
+            SystemAccess::of::<(&mut Position, &Velocity)>(),
+            |ctx| {
+                for (position, velocity) in ctx.query::<(&mut Position, &Velocity)>() {
+                    position.0 += velocity.0;
+                }
+            },
+        )
+        .add_system("count_tags", SystemAccess::of::<&RenderTag>(), move |ctx| {
+            let count = ctx.query::<&RenderTag>().count() as i32;
+            counted.fetch_add(count, Ordering::Relaxed);
+        })
+        .add_exclusive("spawn", |world, _| {
+            world.spawn(RenderTag);
+        });
+
+    let pool = SystemPool::new(2).unwrap();
+    let services = ServiceRegistry::new();
+    schedule.run(&mut world, &services, &pool);
+    schedule.run(&mut world, &services, &pool);
+
+    let sum: i32 = world.query::<&Position>().map(|p| p.0).sum();
+    assert_eq!(sum, (0..100).sum::<i32>() + 200);
+    assert_eq!(tags.load(Ordering::Relaxed), 100 + 101);
+    assert_eq!(world.query::<&RenderTag>().count(), 102);
+}
+
+#[test]
+fn test_schedule_stamps_parallel_writes() {
+    use crate::ecs::query::Changed;
+    use crate::ecs::{SystemAccess, SystemPool, SystemSchedule};
+    use khora_core::ServiceRegistry;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    for i in 0..10 {
+        world.spawn((Position(i), Velocity(1)));
+    }
+    world.clear_trackers();
+
+    let mut schedule = SystemSchedule::new();
+    schedule.add_system(
+        "move",
+        SystemAccess::of::<(&mut Position, &Velocity)>(),
+        |ctx| {
+            for (position, velocity) in ctx.query::<(&mut Position, &Velocity)>() {
+                position.0 += velocity.0;
+            }
+        },
+    );
+    schedule.run(
+        &mut world,
+        &ServiceRegistry::new(),
+        &SystemPool::new(1).unwrap(),
+    );
+
+    assert_eq!(world.query::<Changed<Position>>().count(), 10);
+    assert_eq!(world.query::<Changed<Velocity>>().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "outside its declared access")]
+fn test_schedule_rejects_undeclared_access() {
+    use crate::ecs::{SystemAccess, SystemPool, SystemSchedule};
+    use khora_core::ServiceRegistry;
+
+    let mut world = World::default();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.spawn(Position(1));
+
+    let mut schedule = SystemSchedule::new();
+    schedule.add_system("sneaky", SystemAccess::of::<&Position>(), |ctx| {
+        for position in ctx.query::<&mut Position>() {
+            position.0 += 1;
+        }
+    });
+    schedule.run(
+        &mut world,
+        &ServiceRegistry::new(),
+        &SystemPool::new(0).unwrap(),
+    );
+}
+
+#[test]
+fn test_world_resources() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Score(u32);
+
+    let mut world = World::new();
+    assert!(world.get_resource::<Score>().is_none());
+
+    assert_eq!(world.insert_resource(Score(1)), None);
+    assert_eq!(world.insert_resource(Score(2)), Some(Score(1)));
+    world.get_resource_mut::<Score>().unwrap().0 += 3;
+    assert_eq!(world.get_resource::<Score>(), Some(&Score(5)));
+
+    assert_eq!(world.remove_resource::<Score>(), Some(Score(5)));
+    assert!(!world.contains_resource::<Score>());
+    world.resource_or_default::<Score>().0 += 1;
+    assert_eq!(world.get_resource::<Score>(), Some(&Score(1)));
+}
+
+#[test]
+fn test_set_parent_maintains_children() {
+    use crate::ecs::Transform;
+
+    let mut world = World::new();
+    let a = world.spawn(Transform::identity());
+    let b = world.spawn(Transform::identity());
+    let child = world.spawn(Transform::identity());
+
+    assert!(world.set_parent(child, Some(a)));
+    assert_eq!(world.children(a), &[child]);
+    assert!(world.set_parent(child, Some(b)));
+    assert!(world.children(a).is_empty());
+    assert_eq!(world.children(b), &[child]);
+    assert_eq!(world.parent(child), Some(b));
+
+    // Cycles are refused.
+    assert!(!world.set_parent(b, Some(child)));
+    assert!(world.remove_parent(child));
+    assert_eq!(world.parent(child), None);
+    assert!(world.children(b).is_empty());
+}
+
+#[test]
+fn test_despawn_recursive_and_orphans() {
+    use crate::ecs::systems::hierarchy_sync_system;
+    use crate::ecs::{Children, Parent, Transform};
+
+    let mut world = World::new();
+    let root = world.spawn(Transform::identity());
+    let mid = world.spawn(Transform::identity());
+    let leaf = world.spawn(Transform::identity());
+    let other = world.spawn(Transform::identity());
+    world.set_parent(mid, Some(root));
+    world.set_parent(leaf, Some(mid));
+    world.set_parent(other, Some(root));
+
+    // A plain despawn turns the children into roots.
+    assert!(world.despawn(other));
+    assert_eq!(world.children(root), &[mid]);
+
+    assert_eq!(world.despawn_recursive(mid), 2);
+    assert!(world.get::<Transform>(leaf).is_none());
+    assert!(world.children(root).is_empty());
+
+    // `Parent` added directly is picked up by `hierarchy_sync`.
+    let child = world.spawn((Transform::identity(), Parent(root)));
+    hierarchy_sync_system(&mut world);
+    assert_eq!(world.children(root), &[child]);
+
+    // Despawning the parent without its `Children` list leaves an orphan.
+    world.get_mut::<Children>(root).unwrap().0.clear();
+    assert!(world.despawn(root));
+    assert_eq!(world.parent(child), Some(root));
+    hierarchy_sync_system(&mut world);
+    assert_eq!(world.parent(child), None);
+}
+
+#[test]
+fn test_commands_apply_after_iteration() {
+    use crate::ecs::Commands;
+    use khora_core::ecs::entity::EntityId;
+
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+    for i in 0..4 {
+        world.spawn(Position(i));
+    }
+
+    let mut commands = Commands::new();
+    for (entity, position) in world.query::<(EntityId, &Position)>() {
+        if position.0 % 2 == 0 {
+            commands.despawn(entity);
+        } else {
+            commands.insert(entity, Velocity(position.0));
+            commands.spawn(RenderTag);
+        }
+    }
+    assert_eq!(commands.len(), 6);
+    assert_eq!(world.query::<&Position>().count(), 4);
+
+    assert_eq!(commands.apply(&mut world), 6);
+    assert!(commands.is_empty());
+    let mut moving: Vec<i32> = world
+        .query::<(&Position, &Velocity)>()
+        .map(|(p, v)| p.0 + v.0)
+        .collect();
+    moving.sort();
+    assert_eq!(moving, vec![2, 6]);
+    assert_eq!(world.query::<&Position>().count(), 2);
+    assert_eq!(world.query::<&RenderTag>().count(), 2);
+}
+
+#[test]
+fn test_schedule_applies_system_commands() {
+    use crate::ecs::{SystemAccess, SystemPool, SystemSchedule};
+    use khora_core::ecs::entity::EntityId;
+    use khora_core::ServiceRegistry;
+
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+    for i in 0..10 {
+        world.spawn(Position(i));
+    }
+
+    let mut schedule = SystemSchedule::new();
+    schedule.add_system("cull", SystemAccess::of::<&Position>(), |ctx| {
+        let mut commands = ctx.commands();
+        for (entity, position) in ctx.query::<(EntityId, &Position)>() {
+            if position.0 >= 5 {
+                commands.despawn(entity);
+            }
+        }
+        commands.spawn(RenderTag);
+    });
+
+    let pool = SystemPool::new(2).unwrap();
+    schedule.run(&mut world, &ServiceRegistry::new(), &pool);
+
+    assert_eq!(world.query::<&Position>().count(), 5);
+    assert_eq!(world.query::<&RenderTag>().count(), 1);
+}
+
+#[test]
+fn test_find_by_name_follows_renames_and_despawns() {
+    use crate::ecs::Name;
+
+    let mut world = World::new();
+    let player = world.spawn(Name::new("Player"));
+    let enemy = world.spawn(Name::new("Enemy"));
+    let other_enemy = world.spawn(Name::new("Enemy"));
+    assert_eq!(world.find_by_name("Player"), Some(player));
+    assert_eq!(world.find_all_by_name("Enemy"), vec![enemy, other_enemy]);
+    assert_eq!(world.find_by_name("Missing"), None);
+
+    world.get_mut::<Name>(player).unwrap().0 = "Hero".to_string();
+    assert_eq!(world.find_by_name("Player"), None);
+    assert_eq!(world.find_by_name("Hero"), Some(player));
+
+    world.despawn(enemy);
+    assert_eq!(world.find_all_by_name("Enemy"), vec![other_enemy]);
+    let _ = world.remove_component::<Name>(other_enemy);
+    assert_eq!(world.find_by_name("Enemy"), None);
+
+    let late = world.spawn(Name::new("Enemy"));
+    assert_eq!(world.find_by_name("Enemy"), Some(late));
+}
+
+#[test]
+fn test_validate_reports_broken_references() {
+    use crate::ecs::{
+        Children, GlobalTransform, Parent, PendingAssetKind, PendingAssets, Transform,
+    };
+    use crate::scene::SceneIssue;
+    use khora_core::asset::AssetUUID;
+    use khora_core::math::Vec3;
+
+    let mut world = World::new();
+    let root = world.spawn((Transform::default(), GlobalTransform::identity()));
+    world.spawn((
+        Transform::default(),
+        GlobalTransform::identity(),
+        Parent(root),
+    ));
+    assert!(world.validate().is_ok());
+
+    let gone = world.spawn(Transform::default());
+    world.despawn(gone);
+    let orphan = world.spawn((Transform::default(), Parent(gone)));
+    let lister = world.spawn((Transform::default(), Children(vec![gone])));
+    let frozen = world.spawn(GlobalTransform::identity());
+    let broken = world.spawn(Transform::from_translation(Vec3::new(f32::NAN, 0.0, 0.0)));
+    let a = world.spawn(Transform::default());
+    let b = world.spawn((Transform::default(), Parent(a)));
+    let _ = world.add_component(a, Parent(b));
+
+    let issues = world.validate().issues;
+    assert!(issues.contains(&SceneIssue::DanglingParent {
+        entity: orphan,
+        parent: gone
+    }));
+    assert!(issues.contains(&SceneIssue::DanglingChild {
+        entity: lister,
+        child: gone
+    }));
+    assert!(issues.contains(&SceneIssue::OrphanedGlobalTransform { entity: frozen }));
+    assert!(issues.contains(&SceneIssue::NonFiniteTransform { entity: broken }));
+    let cycles = issues
+        .iter()
+        .filter(|issue| matches!(issue, SceneIssue::ParentCycle { .. }))
+        .count();
+    assert_eq!(cycles, 1);
+    assert_eq!(issues.len(), 5);
+
+    let uuid = AssetUUID::new();
+    PendingAssets::request(&mut world, root, PendingAssetKind::Mesh, uuid);
+    assert_eq!(world.validate_with_assets(|_| true).issues.len(), 5);
+    let report = world.validate_with_assets(|_| false);
+    assert!(report.issues.contains(&SceneIssue::MissingAsset {
+        entity: root,
+        kind: PendingAssetKind::Mesh,
+        uuid
+    }));
+}
+
+#[test]
+fn test_sparse_component_toggles_without_moving_pages() {
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_sparse_component::<RenderTag>();
+    assert!(world.is_sparse_component(std::any::TypeId::of::<RenderTag>()));
+
+    let a = world.spawn(Position(1));
+    let b = world.spawn(Position(2));
+    let pages = world.storage.pages.len();
+
+    assert_eq!(world.add_component(a, RenderTag), Ok(None));
+    assert!(world.add_component(a, RenderTag).is_err());
+    assert_eq!(world.storage.pages.len(), pages);
+    assert!(world.get::<RenderTag>(a).is_some());
+
+    let tagged: Vec<i32> = world
+        .query::<(&Position, &RenderTag)>()
+        .map(|(position, _)| position.0)
+        .collect();
+    assert_eq!(tagged, vec![1]);
+    let untagged: Vec<i32> = world
+        .query::<(&Position, Without<RenderTag>)>()
+        .map(|(position, _)| position.0)
+        .collect();
+    assert_eq!(untagged, vec![2]);
+
+    assert_eq!(world.remove_component::<RenderTag>(a), Ok(None));
+    assert!(world.get::<RenderTag>(a).is_none());
+    world.add_component(b, RenderTag).unwrap();
+    world.despawn(b);
+    assert_eq!(world.query::<&RenderTag>().count(), 0);
+}
+
+#[test]
+fn test_query_tagged_follows_tag_edits() {
+    use crate::ecs::Tags;
+
+    let mut world = World::new();
+    let grunt = world.spawn(Tags::new(["enemy", "melee"]));
+    let archer = world.spawn(Tags::new(["enemy", "ranged"]));
+    let chest = world.spawn(Tags::new(["pickup"]));
+    assert_eq!(world.query_tagged("enemy"), vec![grunt, archer]);
+    assert_eq!(world.query_tagged("pickup"), vec![chest]);
+    assert!(world.query_tagged("never_used").is_empty());
+    assert!(world.has_tag(archer, "ranged"));
+
+    world.get_mut::<Tags>(grunt).unwrap().remove("enemy");
+    assert_eq!(world.query_tagged("enemy"), vec![archer]);
+
+    world.set_enabled(archer, false);
+    assert!(world.query_tagged("enemy").is_empty());
+    world.set_enabled(archer, true);
+
+    world.despawn(archer);
+    let boss = world.spawn(Tags::new(["enemy", "boss"]));
+    assert_eq!(world.query_tagged("enemy"), vec![boss]);
+}
+
+#[test]
+fn test_merge_world_built_on_another_thread() {
+    use crate::ecs::{Children, Parent};
+
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    let existing = world.spawn(Position(0));
+
+    let (chunk, chunk_root, chunk_child) = std::thread::spawn(|| {
+        let mut chunk = World::new();
+        chunk.register_component::<Position>(SemanticDomain::Spatial);
+        chunk.register_sparse_component::<RenderTag>();
+        let root = chunk.spawn(Position(1));
+        let child = chunk.spawn((Position(2), Parent(root)));
+        chunk.add_component(root, Children(vec![child])).unwrap();
+        chunk.add_component(child, RenderTag).unwrap();
+        (chunk, root, child)
+    })
+    .join()
+    .unwrap();
+
+    let map = world.merge(chunk);
+    assert_eq!(map.len(), 2);
+    let root = map.get(chunk_root).unwrap();
+    let child = map.get(chunk_child).unwrap();
+    assert_eq!(world.get::<Position>(existing), Some(&Position(0)));
+    assert_eq!(world.get::<Position>(child), Some(&Position(2)));
+    assert_eq!(world.get::<Parent>(child), Some(&Parent(root)));
+    assert_eq!(world.get::<Children>(root), Some(&Children(vec![child])));
+    assert!(world.get::<RenderTag>(child).is_some());
+    assert_eq!(world.query::<&Position>().count(), 3);
+
+    let mut link = chunk_child;
+    assert!(map.remap(&mut link));
+    assert_eq!(link, child);
+    // IDs the chunk never handed out are left alone.
+    let stranger = khora_core::ecs::entity::EntityId {
+        index: 7,
+        generation: 0,
+    };
+    let mut unknown = stranger;
+    assert!(!map.remap(&mut unknown));
+    assert_eq!(unknown, stranger);
+}
+
+#[test]
+fn test_with_and_or_filters() {
+    use crate::ecs::query::{Added, Or, With};
+    use khora_core::ecs::entity::EntityId;
+
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<Velocity>(SemanticDomain::Spatial);
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+
+    let lit = world.spawn((Position(1), RenderTag));
+    let moving = world.spawn(Velocity(2));
+    let plain = world.spawn(Position(3));
+
+    let with: Vec<EntityId> = world
+        .query::<(EntityId, &Position, With<RenderTag>)>()
+        .map(|(entity, _, _)| entity)
+        .collect();
+    assert_eq!(with, vec![lit]);
+
+    // `lit` has pages in two domains but is yielded once.
+    let mut either: Vec<EntityId> = world
+        .query::<(EntityId, Or<(With<RenderTag>, With<Velocity>)>)>()
+        .map(|(entity, _)| entity)
+        .collect();
+    either.sort_by_key(|entity| entity.index);
+    assert_eq!(either, vec![lit, moving]);
+
+    let positions: Vec<i32> = world
+        .query::<(&Position, Or<(With<RenderTag>, Without<Velocity>)>)>()
+        .map(|(position, _)| position.0)
+        .collect();
+    assert_eq!(positions, vec![1, 3]);
+
+    world.clear_trackers();
+    world.add_component(plain, Velocity(4)).unwrap();
+    let fresh: Vec<(EntityId, Option<&Velocity>)> = world
+        .query::<(
+            EntityId,
+            Option<&Velocity>,
+            Or<(Added<Velocity>, With<RenderTag>)>,
+        )>()
+        .map(|(entity, velocity, _)| (entity, velocity))
+        .collect();
+    assert_eq!(fresh.len(), 2);
+    assert!(fresh.contains(&(lit, None)));
+    assert!(fresh.contains(&(plain, Some(&Velocity(4)))));
+}
+
+/// A gameplay link between entities, going through the derive macro.
+#[derive(Debug, Clone, Default, khora_macros::Component)]
+struct Follower {
+    leader: crate::ecs::EntityRef,
+    squad: Vec<crate::ecs::EntityRef>,
+}
+
+#[test]
+fn test_entity_ref_survives_scene_round_trip_and_despawn() {
+    use crate::ecs::EntityRef;
+    use crate::scene::{DefinitionSerializationStrategy, SerializationStrategy};
+
+    let mut saved = World::new();
+    saved.register_component::<Follower>(SemanticDomain::Spatial);
+    let _padding = saved.spawn(());
+    let leader = saved.spawn(());
+    let outsider = saved.spawn(());
+    saved.spawn(Follower {
+        leader: EntityRef::new(leader),
+        squad: vec![EntityRef::new(leader), EntityRef::new(outsider)],
+    });
+    // Only entities carrying a serializable component are saved.
+    saved.add_component(leader, Follower::default()).unwrap();
+    saved.despawn(outsider);
+
+    let strategy = DefinitionSerializationStrategy::new();
+    let data = strategy.serialize(&saved).unwrap();
+    let mut loaded = World::new();
+    loaded.register_component::<Follower>(SemanticDomain::Spatial);
+    for _ in 0..3 {
+        loaded.spawn(());
+    }
+    let spawned = strategy.instantiate(&data, &mut loaded).unwrap();
+    assert_eq!(spawned.len(), 2);
+
+    let (new_leader, follower) = (spawned[0], spawned[1]);
+    let link = loaded.get::<Follower>(follower).unwrap().clone();
+    assert_ne!(new_leader, leader);
+    assert_eq!(link.leader.get(&loaded), Some(new_leader));
+    assert_eq!(
+        link.squad,
+        vec![EntityRef::new(new_leader), EntityRef::NONE]
+    );
+
+    loaded.despawn(new_leader);
+    let reused = loaded.spawn(());
+    assert_eq!(reused.index, new_leader.index);
+    assert!(link.leader.is_set());
+    assert_eq!(link.leader.get(&loaded), None);
+}
+
+#[test]
+fn test_snapshot_restore_rolls_back_entities_and_components() {
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<NonCopyableComponent>(SemanticDomain::Render);
+    world.register_sparse_component::<RenderTag>();
+    let kept = world.spawn((Position(1), NonCopyableComponent("kept".to_string())));
+    let doomed = world.spawn(Position(2));
+    world.add_component(doomed, RenderTag).unwrap();
+
+    let snapshot = world.snapshot();
+    assert_eq!(snapshot.entity_count(), 2);
+
+    for _ in 0..2 {
+        world.get_mut::<Position>(kept).unwrap().0 = 10;
+        world.get_mut::<NonCopyableComponent>(kept).unwrap().0 = "edited".to_string();
+        world.despawn(doomed);
+        let newcomer = world.spawn(Position(3));
+        world.add_component(newcomer, RenderTag).unwrap();
+
+        world.restore(&snapshot);
+        assert_eq!(world.get::<Position>(kept), Some(&Position(1)));
+        assert_eq!(
+            world.get::<NonCopyableComponent>(kept),
+            Some(&NonCopyableComponent("kept".to_string()))
+        );
+        assert_eq!(world.get::<Position>(doomed), Some(&Position(2)));
+        assert!(world.get::<RenderTag>(doomed).is_some());
+        assert!(world.get::<Position>(newcomer).is_none());
+        assert_eq!(world.query::<&Position>().count(), 2);
+    }
+
+    // A fresh world picks up the registrations from the snapshot.
+    let mut other = World::new();
+    other.restore(&snapshot);
+    assert_eq!(other.get::<Position>(doomed), Some(&Position(2)));
+    assert!(other.get::<RenderTag>(doomed).is_some());
+}
+
+#[test]
+fn test_spawn_batch_matches_spawn() {
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    world.register_component::<RenderTag>(SemanticDomain::Render);
+    let recycled = world.spawn(Position(-1));
+    world.despawn(recycled);
+    world.clear_trackers();
+
+    let batch = world.spawn_batch((0..100).map(|i| (Position(i), RenderTag)));
+    assert_eq!(batch.len(), 100);
+    assert_eq!(batch[0].index, recycled.index);
+    assert_ne!(batch[0], recycled);
+    for (i, &entity) in batch.iter().enumerate() {
+        assert_eq!(world.get::<Position>(entity), Some(&Position(i as i32)));
+        assert!(world.get::<RenderTag>(entity).is_some());
+        assert!(world.is_added::<Position>(entity));
+    }
+    assert_eq!(world.query::<(&Position, &RenderTag)>().count(), 100);
+
+    // Rows stay consistent when a batch entity leaves its page.
+    world.despawn(batch[10]);
+    assert_eq!(world.get::<Position>(batch[99]), Some(&Position(99)));
+    assert_eq!(world.query::<&Position>().count(), 99);
+
+    let single = world.spawn((Position(100), RenderTag));
+    assert_eq!(world.get::<Position>(single), Some(&Position(100)));
+    assert!(world.spawn_batch(std::iter::empty::<Position>()).is_empty());
+}
+
+#[test]
+fn test_dynamic_component_lives_in_pages() {
+    use crate::ecs::{
+        DynamicComponent, DynamicComponentError, DynamicComponentId, DynamicComponentLayout,
+        DynamicFieldKind, DynamicValue,
+    };
+
+    let layout = DynamicComponentLayout::new(
+        "TestHealth",
+        [
+            ("current", DynamicFieldKind::F32),
+            ("regen", DynamicFieldKind::Bool),
+        ],
+    )
+    .unwrap();
+    let health = DynamicComponentId::register(layout.clone(), SemanticDomain::Spatial).unwrap();
+    assert_eq!(
+        DynamicComponentId::register(layout, SemanticDomain::Spatial),
+        Ok(health)
+    );
+    assert_eq!(DynamicComponentId::find("TestHealth"), Some(health));
+    let conflicting =
+        DynamicComponentLayout::new("TestHealth", [("current", DynamicFieldKind::I32)]).unwrap();
+    assert!(matches!(
+        DynamicComponentId::register(conflicting, SemanticDomain::Spatial),
+        Err(DynamicComponentError::LayoutConflict(_))
+    ));
+
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    let a = world.spawn(Position(1));
+    let b = world.spawn(Position(2));
+    let value = DynamicComponent::new(health)
+        .with("current", 50.0f32)
+        .unwrap();
+    world.insert_dynamic(a, value.clone()).unwrap();
+    world
+        .insert_dynamic(b, value.with("regen", true).unwrap())
+        .unwrap();
+
+    // Shares the Spatial page with Position, which survives the migration.
+    assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+    let row = world.get_dynamic(b, health).unwrap();
+    assert_eq!(row.get("current"), Some(DynamicValue::F32(50.0)));
+    assert_eq!(row.get("regen"), Some(DynamicValue::Bool(true)));
+
+    let mut row = world.get_dynamic_mut(a, health).unwrap();
+    row.set("current", 10.0f32).unwrap();
+    assert!(matches!(
+        row.set("current", 1i32),
+        Err(DynamicComponentError::FieldTypeMismatch { .. })
+    ));
+    assert_eq!(
+        world.get_dynamic(a, health).unwrap().get("current"),
+        Some(DynamicValue::F32(10.0))
+    );
+    assert_eq!(world.query_dynamic(health).count(), 2);
+
+    // Snapshots and archetype serialization carry the byte column as is.
+    let snapshot = world.snapshot();
+    let bytes = world.serialize_archetype().unwrap();
+    world.remove_dynamic(a, health).unwrap();
+    assert!(world.get_dynamic(a, health).is_none());
+    assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+    assert_eq!(world.query_dynamic(health).count(), 1);
+
+    world.restore(&snapshot);
+    assert_eq!(
+        world.get_dynamic(a, health).unwrap().get("current"),
+        Some(DynamicValue::F32(10.0))
+    );
+
+    let mut loaded = World::new();
+    loaded.register_component::<Position>(SemanticDomain::Spatial);
+    loaded.register_dynamic_component(health);
+    loaded.deserialize_archetype(&bytes).unwrap();
+    assert_eq!(
+        loaded.get_dynamic(b, health).unwrap().get("regen"),
+        Some(DynamicValue::Bool(true))
+    );
+}
+
+#[test]
+fn test_is_alive_detects_recycled_handles() {
+    let mut world = World::new();
+    world.register_component::<Position>(SemanticDomain::Spatial);
+    let old = world.spawn(Position(1));
+    assert!(world.is_alive(old));
+    assert_eq!(world.generation(old.index), Some(old.generation));
+    assert_eq!(world.entity_at(old.index), Some(old));
+
+    world.despawn(old);
+    assert!(!world.is_alive(old));
+    assert_eq!(world.generation(old.index), Some(old.generation));
+    assert_eq!(world.entity_at(old.index), None);
+
+    let new = world.spawn(Position(2));
+    assert_eq!(new.index, old.index);
+    assert!(!world.is_alive(old));
+    assert!(world.is_alive(new));
+    assert_eq!(world.generation(old.index), Some(old.generation + 1));
+    assert_eq!(world.entity_at(old.index), Some(new));
+    assert_eq!(world.generation(99), None);
+    assert_eq!(world.entity_at(99), None);
+}
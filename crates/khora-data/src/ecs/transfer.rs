@@ -0,0 +1,198 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Moving entities from one [`World`] to another.
+
+use std::collections::{HashMap, HashSet};
+
+use khora_core::ecs::entity::EntityId;
+
+use crate::ecs::{Children, EntityMap, IncludeDisabled, PageIndex, World};
+use crate::scene::remap;
+
+/// Moves every entity of `src` accepted by `filter` into `dst`, and returns
+/// the map from their IDs in `src` to their IDs in `dst`.
+///
+/// Components are cloned across, so asset handles keep pointing at the
+/// same loaded asset and keep their UUID; nothing is reloaded. `Parent`
+/// and `Children` are rewritten through the returned map:
+///
+/// - a migrated entity whose parent stayed behind becomes a root in `dst`;
+/// - `Children` lists in `dst` keep only migrated children;
+/// - entities left in `src` drop migrated entities from their `Children`.
+///
+/// Every [`EntityRef`](crate::ecs::EntityRef) on a migrated entity is
+/// rewritten the same way, and cleared if its target stayed behind.
+///
+/// `filter` sees each live entity of `src` once, before anything moves.
+pub fn migrate_entities(
+    src: &mut World,
+    dst: &mut World,
+    mut filter: impl FnMut(&World, EntityId) -> bool,
+) -> HashMap<EntityId, EntityId> {
+    let selected: Vec<EntityId> = src
+        .iter_entities()
+        .filter(|&entity| filter(src, entity))
+        .collect();
+
+    let mut id_map = HashMap::with_capacity(selected.len());
+    for entity in selected {
+        if let Some(new_id) = src.transfer_entity(entity, dst) {
+            id_map.insert(entity, new_id);
+        }
+    }
+
+    remap::remap_parents(dst, &id_map);
+    remap::remap_children(dst, &id_map);
+    let id_map = EntityMap::from(id_map);
+    remap::remap_entity_refs(dst, &id_map);
+
+    // Parents that stayed behind still list the children that left.
+    let migrated: HashSet<EntityId> = id_map.iter().map(|(old, _)| old).collect();
+    for (children, _) in src.query_mut::<(&mut Children, IncludeDisabled)>() {
+        children.0.retain(|child| !migrated.contains(child));
+    }
+
+    log::debug!("migrate_entities: moved {} entities", id_map.len());
+    id_map.into_inner()
+}
+
+impl World {
+    /// Moves every entity of `other` into this world and returns the map
+    /// from their old IDs to their new ones.
+    ///
+    /// Built for worlds filled elsewhere, such as procedural chunks
+    /// generated on worker threads: build a `World` there, send it over and
+    /// merge it here. Components move with [`migrate_entities`] rules, so
+    /// `Parent`, `Children` and [`EntityRef`](crate::ecs::EntityRef)s are
+    /// rewritten. Raw `EntityId`s stored in components still hold `other`'s
+    /// IDs; fix them with [`EntityMap::remap`]:
+    ///
+    /// ```rust,ignore
+    /// let map = world.merge(chunk);
+    /// for entity in map.new_ids() {
+    ///     if let Some(link) = world.get_mut::<DoorLink>(entity) {
+    ///         map.remap(&mut link.door);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Resources and pending events of `other` are dropped.
+    pub fn merge(&mut self, mut other: World) -> EntityMap {
+        migrate_entities(&mut other, self, |_, _| true).into()
+    }
+
+    /// Moves `entity_id` and every component it carries into `dst`, and
+    /// returns its ID there.
+    ///
+    /// Component types `dst` does not know yet are registered in it with
+    /// the domain they have here. Entity references stored inside
+    /// components (such as `Parent`) are copied as-is: they still point
+    /// into this world, so move hierarchies as a whole and remap them.
+    ///
+    /// Returns `None`, leaving both worlds untouched, if the entity is not
+    /// alive.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub fn transfer_entity(&mut self, entity_id: EntityId, dst: &mut World) -> Option<EntityId> {
+        let locations = self
+            .live_metadata(entity_id, "transfer_entity")?
+            .locations
+            .clone();
+
+        let new_id = dst.entities.create_entity();
+        // Domains spawned in one bundle share a row; it is copied once.
+        let mut copied: HashMap<PageIndex, PageIndex> = HashMap::new();
+        for (domain, location) in locations {
+            let dest = match copied.get(&location) {
+                Some(&dest) => dest,
+                None => {
+                    let dest = self.copy_row_into(location, dst, new_id);
+                    copied.insert(location, dest);
+                    dest
+                }
+            };
+            if let Some(metadata) = dst.entities.get_metadata_mut(new_id) {
+                metadata.locations.insert(domain, dest);
+            }
+            dst.storage
+                .domain_bitsets
+                .entry(domain)
+                .or_default()
+                .set(new_id.index);
+            dst.storage
+                .domain_stats
+                .entry(domain)
+                .or_default()
+                .entity_count += 1;
+        }
+
+        for (type_id, set) in &mut self.sparse {
+            if !set.contains(entity_id) {
+                continue;
+            }
+            dst.type_registry.import(&self.type_registry, *type_id);
+            let dst_set = dst.sparse.entry(*type_id).or_insert_with(|| set.empty());
+            if set.move_entity(entity_id, dst_set.as_mut(), new_id) {
+                dst.changes.mark_added(*type_id, new_id.index);
+            }
+        }
+
+        self.despawn_unlinked(entity_id);
+        Some(new_id)
+    }
+
+    /// (Internal) Appends a copy of the row at `location` to the matching
+    /// page of `dst`, owned by `new_id`, and returns where it landed.
+    fn copy_row_into(&self, location: PageIndex, dst: &mut World, new_id: EntityId) -> PageIndex {
+        let src_page = &self.storage.pages[location.page_id as usize];
+        let signature = src_page.type_ids.clone();
+        for type_id in &signature {
+            dst.storage
+                .registry
+                .import(&self.storage.registry, *type_id);
+            dst.type_registry.import(&self.type_registry, *type_id);
+        }
+
+        let dest_page_id = dst.storage.find_or_create_page_for_signature(&signature);
+        let dest_page = &mut dst.storage.pages[dest_page_id as usize];
+        let dest_row = dest_page.entities.len() as u32;
+        for type_id in &signature {
+            let (Some(copier), Some(src_col), Some(dest_col)) = (
+                self.storage.registry.get_row_copier(type_id),
+                src_page.columns.get(type_id),
+                dest_page.columns.get_mut(type_id),
+            ) else {
+                continue;
+            };
+            // SAFETY: both columns hold the type registered under
+            // `type_id`, and `location` is a live row of the source page.
+            unsafe {
+                copier(
+                    src_col.as_ref(),
+                    location.row_index as usize,
+                    dest_col.as_mut(),
+                )
+            };
+        }
+        dest_page.add_entity(new_id);
+        for type_id in &signature {
+            dst.changes.mark_added(*type_id, new_id.index);
+        }
+
+        PageIndex {
+            page_id: dest_page_id,
+            row_index: dest_row,
+        }
+    }
+}
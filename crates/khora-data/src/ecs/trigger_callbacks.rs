@@ -0,0 +1,96 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Rust callbacks bound to trigger event names.
+
+use std::collections::HashMap;
+
+use crate::ecs::{TriggerEvent, World};
+
+/// A callback run when a trigger event of its name fires.
+pub type TriggerCallback = Box<dyn FnMut(&mut World, &TriggerEvent) + Send + Sync>;
+
+/// Callbacks registered by event name, stored as a world resource.
+///
+/// The `scene_triggers` data system runs them right after the events fire,
+/// so designers wire a trigger to behavior by typing a name in the scene,
+/// and code only provides the named behaviors.
+#[derive(Default)]
+pub struct TriggerCallbacks {
+    callbacks: HashMap<String, Vec<TriggerCallback>>,
+}
+
+impl TriggerCallbacks {
+    /// Runs `callback` every time an event named `name` fires. Several
+    /// callbacks can share a name; they run in registration order.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        callback: impl FnMut(&mut World, &TriggerEvent) + Send + Sync + 'static,
+    ) {
+        self.callbacks
+            .entry(name.into())
+            .or_default()
+            .push(Box::new(callback));
+    }
+
+    /// Drops every callback registered under `name`. Returns `false` if
+    /// there was none.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.callbacks.remove(name).is_some()
+    }
+
+    /// Returns `true` if a callback is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.callbacks.contains_key(name)
+    }
+
+    /// Runs the callbacks registered under the event's name and returns how
+    /// many ran.
+    pub(crate) fn dispatch(&mut self, world: &mut World, event: &TriggerEvent) -> usize {
+        let Some(callbacks) = self.callbacks.get_mut(&event.name) else {
+            return 0;
+        };
+        for callback in callbacks.iter_mut() {
+            callback(world, event);
+        }
+        callbacks.len()
+    }
+
+    /// Moves the callbacks of `other` after this set's own.
+    pub(crate) fn append(&mut self, other: TriggerCallbacks) {
+        for (name, callbacks) in other.callbacks {
+            self.callbacks.entry(name).or_default().extend(callbacks);
+        }
+    }
+}
+
+impl World {
+    /// Runs `callback` every time a trigger event named `name` fires.
+    ///
+    /// ```rust,ignore
+    /// world.on_trigger("open_gate", |world, _event| {
+    ///     if let Some(gate) = world.find_by_name("Gate") {
+    ///         world.set_enabled(gate, false);
+    ///     }
+    /// });
+    /// ```
+    pub fn on_trigger(
+        &mut self,
+        name: impl Into<String>,
+        callback: impl FnMut(&mut World, &TriggerEvent) + Send + Sync + 'static,
+    ) {
+        self.resource_or_default::<TriggerCallbacks>()
+            .register(name, callback);
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Named gameplay events fired by [`SceneTrigger`](crate::ecs::SceneTrigger)s.
+
+use khora_core::ecs::entity::EntityId;
+
+/// What made a [`SceneTrigger`](crate::ecs::SceneTrigger) fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TriggerKind {
+    /// An entity's collider started touching the trigger volume.
+    Enter,
+    /// An entity's collider stopped touching the trigger volume.
+    Exit,
+    /// An [`Interactor`](crate::ecs::Interactor) in range pressed its key.
+    Interact,
+}
+
+/// A named event fired by a [`SceneTrigger`](crate::ecs::SceneTrigger).
+///
+/// Sent to the world's `Events<TriggerEvent>` queue, where any system or
+/// script host reads it with an [`EventReader`](crate::ecs::EventReader),
+/// and handed to the callbacks registered under its name with
+/// [`World::on_trigger`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerEvent {
+    /// The name authored on the trigger (`"open_gate"`, `"boss_intro"`...).
+    pub name: String,
+    /// What made the trigger fire.
+    pub kind: TriggerKind,
+    /// The entity carrying the trigger.
+    pub trigger: EntityId,
+    /// The entity that entered, left or interacted.
+    pub instigator: EntityId,
+}
@@ -17,23 +17,35 @@
 use std::{
     any::TypeId,
     collections::{HashMap, HashSet},
+    sync::{Mutex, PoisonError},
 };
 
 use bincode::config;
 use khora_core::{
     ecs::entity::EntityId,
-    renderer::api::scene::{GpuMesh, Mesh},
+    renderer::api::{
+        resource::CpuTexture,
+        scene::{GpuMesh, Mesh},
+    },
 };
 
 use crate::ecs::{
+    change_tracker::ChangeTracker,
     components::HandleComponent,
+    entity::EntityMetadata,
     entity_store::EntityStore,
-    page::{ComponentPage, PageIndex},
+    events::EventQueue,
+    name_index::NameIndex,
+    page::{AnyVec, ComponentPage, PageIndex},
     planner::QueryPlanner,
     query::{Query, WorldQuery},
+    query_profiler::QueryProfiler,
     registry::ComponentRegistry,
+    resources::Resources,
     serialization::SceneMemoryLayout,
+    sparse_set::{AnySparseSet, SparseSet},
     storage::StorageManager,
+    tag_index::TagIndex,
     AudioListener, AudioSource, Camera, Children, Collider, Component, ComponentBundle,
     DomainBitset, GlobalTransform, MaterialComponent, Name, Parent, QueryMut, QueryPlan, RigidBody,
     SemanticDomain, SerializedPage, Transform, TypeRegistry,
@@ -81,6 +93,12 @@ pub trait WorldMaintenance {
     /// * `page_index` - The index of the page containing the hole.
     /// * `hole_row_index` - The row index of the hole to be filled.
     fn vacuum_hole_at(&mut self, page_index: u32, hole_row_index: u32);
+
+    /// Gives back the memory a sparse page reserves beyond its live rows.
+    ///
+    /// Returns the number of bytes released, `0` if the page is not sparse
+    /// or does not exist. Rows keep their index, so no location changes.
+    fn compact_page(&mut self, page_index: u32) -> usize;
 }
 
 /// The central container for the entire ECS, holding all entities, components, and metadata.
@@ -91,8 +109,22 @@ pub struct World {
     pub(crate) storage: StorageManager,
     /// Manages query planning and caching.
     pub(crate) planner: QueryPlanner,
+    /// Per-query profiling counters, shared with the engine.
+    pub(crate) query_profiler: QueryProfiler,
+    /// One double-buffered event queue per event type.
+    pub(crate) events: HashMap<TypeId, Box<dyn EventQueue>>,
     /// The type registry for serialization purposes.
-    type_registry: TypeRegistry,
+    pub(crate) type_registry: TypeRegistry,
+    /// When each component was added and last written.
+    pub(crate) changes: ChangeTracker,
+    /// One value per resource type.
+    pub(crate) resources: Resources,
+    /// Entities by `Name`, for `find_by_name`.
+    pub(crate) names: Mutex<NameIndex>,
+    /// Entities by tag, for `query_tagged`.
+    pub(crate) tags: Mutex<TagIndex>,
+    /// One sparse set per component registered with `register_sparse_component`.
+    pub(crate) sparse: HashMap<TypeId, Box<dyn AnySparseSet>>,
 }
 
 impl World {
@@ -107,24 +139,67 @@ impl World {
     }
 
     /// (Internal) A helper function to handle the `swap_remove` logic for a single component group.
-    fn remove_from_page(
-        &mut self,
-        entity_to_despawn: EntityId,
-        location: PageIndex,
-        domain: SemanticDomain,
-    ) {
-        let page = &mut self.storage.pages[location.page_id as usize];
-        if page.entities.is_empty() {
+    fn remove_from_page(&mut self, entity_to_despawn: EntityId, location: PageIndex) {
+        // A bundle spanning several domains stores all of them in one row,
+        // so the row may already be gone when its second domain comes up.
+        let page = &self.storage.pages[location.page_id as usize];
+        if page.entities.get(location.row_index as usize) != Some(&entity_to_despawn) {
             return;
         }
+        self.swap_remove_at(location);
+    }
 
-        let last_entity_in_page = *page.entities.last().unwrap();
+    /// (Internal) Swap-removes the row at `location` and points the entity
+    /// moved into the hole back at it.
+    ///
+    /// Only the locations that referenced the moved row are rewritten: when
+    /// that row was itself an orphan, its entity lives elsewhere.
+    fn swap_remove_at(&mut self, location: PageIndex) {
+        let page = &mut self.storage.pages[location.page_id as usize];
+        let Some(&last_entity_in_page) = page.entities.last() else {
+            return;
+        };
+        let last_row = PageIndex {
+            page_id: location.page_id,
+            row_index: page.entities.len() as u32 - 1,
+        };
         page.swap_remove_row(location.row_index);
 
-        if last_entity_in_page != entity_to_despawn {
-            let metadata = self.entities.get_metadata_mut(last_entity_in_page).unwrap();
-            metadata.locations.insert(domain, location);
+        if let Some(metadata) = self.entities.get_metadata_mut(last_entity_in_page) {
+            for loc in metadata.locations.values_mut() {
+                if *loc == last_row {
+                    *loc = location;
+                }
+            }
+        }
+    }
+
+    /// (Internal) Returns the metadata of `entity_id` if it is alive and its
+    /// generation matches.
+    ///
+    /// Every accessor taking an `EntityId` validates it here. With the
+    /// `entity-debug` feature, a dead or stale ID is logged together with the
+    /// call site of the public accessor.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
+    pub(crate) fn live_metadata(
+        &self,
+        entity_id: EntityId,
+        operation: &str,
+    ) -> Option<&EntityMetadata> {
+        let metadata = self.entities.get_metadata(entity_id);
+        #[cfg(feature = "entity-debug")]
+        if metadata.is_none() {
+            let caller = std::panic::Location::caller();
+            log::warn!(
+                "World::{}: {} (called from {})",
+                operation,
+                self.entities.describe_dead(entity_id),
+                caller
+            );
         }
+        #[cfg(not(feature = "entity-debug"))]
+        let _ = operation;
+        metadata
     }
 
     /// Finds or creates a page for the given signature of component `TypeId`s.
@@ -138,7 +213,14 @@ impl World {
             entities: EntityStore::new(),
             storage: StorageManager::new(ComponentRegistry::default()),
             planner: QueryPlanner::new(),
+            query_profiler: QueryProfiler::new(),
+            events: HashMap::new(),
             type_registry: TypeRegistry::default(),
+            changes: ChangeTracker::new(),
+            resources: Resources::default(),
+            names: Mutex::new(NameIndex::default()),
+            tags: Mutex::new(TagIndex::default()),
+            sparse: HashMap::new(),
         };
         // Registration of built-in components
         world.register_component::<Transform>(SemanticDomain::Spatial);
@@ -146,22 +228,59 @@ impl World {
         world.register_component::<Parent>(SemanticDomain::Spatial);
         world.register_component::<Children>(SemanticDomain::Spatial);
         world.register_component::<Name>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::Tags>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::AnimationPlayer>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::Animator>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::IkConstraint>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::CameraRig>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::LookAt>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::SplinePath>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::PathFollower>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::TimelinePlayer>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::Bounds>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::Static>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::Disabled>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::NonSerialized>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::SimulationAnchor>(SemanticDomain::Spatial);
+        world.register_component::<crate::ecs::SimulationLod>(SemanticDomain::Spatial);
 
         // Registration of render components
         world.register_component::<HandleComponent<Mesh>>(SemanticDomain::Render);
         world.register_component::<HandleComponent<GpuMesh>>(SemanticDomain::Render);
+        world.register_component::<HandleComponent<CpuTexture>>(SemanticDomain::Render);
         world.register_component::<MaterialComponent>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::MaterialOverride>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::MaterialAnimation>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::MorphWeights>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::MorphedMesh>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::PendingAssets>(SemanticDomain::Render);
         world.register_component::<Camera>(SemanticDomain::Render);
         world.register_component::<crate::ecs::Light>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::RenderLayers>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::Skin>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::SkinnedMesh>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::StaticBatch>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::Hidden>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::TimeOfDay>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::Sky>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::AutoExposure>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::Exposure>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::AntiAliasing>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::PostProcess>(SemanticDomain::Render);
+        world.register_component::<crate::ecs::Weather>(SemanticDomain::Render);
 
         // Registration of audio components
         world.register_component::<AudioSource>(SemanticDomain::Audio);
         world.register_component::<AudioListener>(SemanticDomain::Audio);
+        world.register_component::<crate::ecs::WeatherAudio>(SemanticDomain::Audio);
+        world.register_component::<crate::ecs::SoundEventInstance>(SemanticDomain::Audio);
 
         // Registration of physics components
         world.register_component::<RigidBody>(SemanticDomain::Physics);
         world.register_component::<Collider>(SemanticDomain::Physics);
         world.register_component::<crate::ecs::PhysicsMaterial>(SemanticDomain::Physics);
+        world.register_component::<crate::ecs::GravityZone>(SemanticDomain::Physics);
+        world.register_component::<crate::ecs::PhysicsInterpolation>(SemanticDomain::Physics);
         world.register_component::<crate::ecs::KinematicCharacterController>(
             SemanticDomain::Physics,
         );
@@ -169,6 +288,8 @@ impl World {
         world.register_component::<crate::ecs::CollisionPairs>(SemanticDomain::Physics);
         world.register_component::<crate::ecs::CollisionEvents>(SemanticDomain::Physics);
         world.register_component::<crate::ecs::PhysicsDebugData>(SemanticDomain::Physics);
+        world.register_component::<crate::ecs::SceneTrigger>(SemanticDomain::Physics);
+        world.register_component::<crate::ecs::Interactor>(SemanticDomain::Physics);
 
         // Registration of UI components
         world.register_component::<crate::ui::components::UiNode>(SemanticDomain::Ui);
@@ -179,6 +300,15 @@ impl World {
         world.register_component::<crate::ui::components::UiBorder>(SemanticDomain::Ui);
         world.register_component::<crate::ui::components::UiInteraction>(SemanticDomain::Ui);
         world.register_component::<crate::ui::components::UiText>(SemanticDomain::Ui);
+        world.register_component::<crate::ui::components::UiAnchor>(SemanticDomain::Ui);
+        world.register_component::<crate::ui::components::UiNineSlice>(SemanticDomain::Ui);
+        world.register_component::<crate::ui::components::UiButton>(SemanticDomain::Ui);
+
+        // Built-in event queues
+        world.add_event::<khora_core::physics::CollisionEvent>();
+        world.add_event::<khora_core::platform::InputEvent>();
+        world.add_event::<crate::ecs::TriggerEvent>();
+        world.add_event::<khora_core::platform::PowerEvent>();
 
         world
     }
@@ -225,6 +355,9 @@ impl World {
         B::update_metadata(metadata, location, &self.storage.registry);
 
         // --- Step 5: Update domain bitsets and stats for the newly created entity components. ---
+        for type_id in B::type_ids() {
+            self.changes.mark_added(type_id, entity_id.index);
+        }
         for domain in metadata.locations.keys() {
             self.storage
                 .domain_bitsets
@@ -242,33 +375,142 @@ impl World {
         entity_id
     }
 
+    /// Spawns one entity per bundle and returns their IDs, in order.
+    ///
+    /// Does what [`spawn`](Self::spawn) does for each bundle, but the page
+    /// is looked up once, its columns are reserved from the iterator's size
+    /// hint and written contiguously, and domain bookkeeping is done once
+    /// for the whole batch. Use it for particles, crowds and anything else
+    /// spawned by the thousand.
+    pub fn spawn_batch<B, I>(&mut self, bundles: I) -> Vec<EntityId>
+    where
+        B: ComponentBundle,
+        I: IntoIterator<Item = B>,
+    {
+        let bundles = bundles.into_iter();
+        let (hint, _) = bundles.size_hint();
+        let page_id = self.find_or_create_page_for_bundle::<B>();
+        self.storage.pages[page_id as usize].reserve(hint);
+        self.entities.reserve(hint);
+
+        // Every entity of the batch lives in the same page, so they share
+        // the same domains.
+        let mut template = EntityMetadata::default();
+        B::update_metadata(
+            &mut template,
+            PageIndex {
+                page_id,
+                row_index: 0,
+            },
+            &self.storage.registry,
+        );
+        let domains: Vec<SemanticDomain> = template.locations.keys().copied().collect();
+
+        let mut spawned = Vec::with_capacity(hint);
+        for bundle in bundles {
+            let entity_id = self.entities.create_entity();
+            let page = &mut self.storage.pages[page_id as usize];
+            let location = PageIndex {
+                page_id,
+                row_index: page.entities.len() as u32,
+            };
+            unsafe {
+                bundle.add_to_page(page);
+            }
+            page.add_entity(entity_id);
+            if let Some(metadata) = self.entities.get_metadata_mut(entity_id) {
+                for domain in &domains {
+                    metadata.locations.insert(*domain, location);
+                }
+            }
+            spawned.push(entity_id);
+        }
+
+        let indices: Vec<u32> = spawned.iter().map(|entity| entity.index).collect();
+        for type_id in B::type_ids() {
+            self.changes.mark_added_many(type_id, &indices);
+        }
+        for domain in domains {
+            let bitset = self.storage.domain_bitsets.entry(domain).or_default();
+            for &index in &indices {
+                bitset.set(index);
+            }
+            self.storage
+                .domain_stats
+                .entry(domain)
+                .or_default()
+                .entity_count += spawned.len() as u32;
+        }
+
+        spawned
+    }
+
+    /// Returns `true` if `entity_id` refers to a live entity.
+    ///
+    /// Unlike the accessors, a dead or stale ID is an expected answer here and
+    /// is never logged, so gameplay code and physics callbacks can drop
+    /// handles whose entity has gone.
+    pub fn is_alive(&self, entity_id: EntityId) -> bool {
+        self.entities.get_metadata(entity_id).is_some()
+    }
+
+    /// Returns the current generation of the slot at `index`, whether the
+    /// entity in it is alive or was despawned, or `None` if the slot was
+    /// never used.
+    ///
+    /// A handle with the same index and an older generation is stale.
+    pub fn generation(&self, index: u32) -> Option<u32> {
+        self.entities
+            .get(index as usize)
+            .map(|(slot_id, _)| slot_id.generation)
+    }
+
+    /// Returns the live entity occupying the slot at `index`, if any.
+    ///
+    /// Useful to rebuild a full handle from an index stored by a system that
+    /// only keeps `u32`s, such as a physics backend's user data.
+    pub fn entity_at(&self, index: u32) -> Option<EntityId> {
+        match self.entities.get(index as usize)? {
+            (slot_id, Some(_)) => Some(*slot_id),
+            (_, None) => None,
+        }
+    }
+
     /// Despawns an entity, removing all its components and freeing its ID for recycling.
     ///
     /// This method performs the following steps:
     /// 1. Verifies that the `EntityId` is valid by checking its index and generation.
-    /// 2. Removes the entity's component data from all pages where it is stored.
-    /// 3. Marks the entity's metadata slot as vacant and adds its index to the free list.
+    /// 2. Removes the entity from its parent's `Children`; its own children
+    ///    become roots. Use [`despawn_recursive`](Self::despawn_recursive) to
+    ///    remove them as well.
+    /// 3. Removes the entity's component data from all pages where it is stored.
+    /// 4. Marks the entity's metadata slot as vacant and adds its index to the free list.
     ///
     /// Returns `true` if the entity was valid and despawned, `false` otherwise.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn despawn(&mut self, entity_id: EntityId) -> bool {
         // Step 1: Validate the EntityId.
-        // First, check if the index is even valid for our entities Vec.
-        if entity_id.index as usize >= self.entities.len() {
+        // An ID is valid if its generation matches the one in the world,
+        // AND if the metadata slot is currently occupied.
+        if self.live_metadata(entity_id, "despawn").is_none() {
             return false;
         }
 
-        // Get the data at the slot.
-        let (id_in_world, metadata_slot) = self.entities.get(entity_id.index as usize).unwrap();
+        // Step 2: Keep the hierarchy free of dangling references.
+        self.detach_from_hierarchy(entity_id);
+        self.despawn_unlinked(entity_id)
+    }
 
-        // An ID is valid if its generation matches the one in the world,
-        // AND if the metadata slot is currently occupied (`is_some`).
-        if id_in_world.generation != entity_id.generation || metadata_slot.is_none() {
+    /// (Internal) Despawns an entity without touching the `Parent` and
+    /// `Children` components that refer to it.
+    pub(crate) fn despawn_unlinked(&mut self, entity_id: EntityId) -> bool {
+        if self.entities.get_metadata(entity_id).is_none() {
             return false;
         }
 
         // --- At this point, the ID is valid. ---
 
-        // Step 2: Take the metadata out of the slot, leaving it `None`.
+        // Take the metadata out of the slot, leaving it `None`.
         // This is what officially "kills" the entity.
         let metadata = self
             .entities
@@ -279,9 +521,13 @@ impl World {
             .unwrap();
         self.entities.freed_entities.push(entity_id.index);
 
-        // --- Step 3: Iterate over the entity's component locations and remove them ---
+        for set in self.sparse.values_mut() {
+            set.remove_entity(entity_id);
+        }
+
+        // --- Iterate over the entity's component locations and remove them ---
         for (domain, location) in metadata.locations {
-            self.remove_from_page(entity_id, location, domain);
+            self.remove_from_page(entity_id, location);
 
             // Clear the entity's bit in the domain bitset and update stats.
             if let Some(bitset) = self.storage.domain_bitsets.get_mut(&domain) {
@@ -304,6 +550,10 @@ impl World {
     /// `ComponentPage`s that satisfy the query's criteria. The returned iterator then
     /// efficiently iterates over the data in only those pages.
     ///
+    /// Disabled entities are skipped unless the query holds
+    /// [`IncludeDisabled`](crate::ecs::IncludeDisabled) or names
+    /// [`Disabled`](crate::ecs::Disabled) itself.
+    ///
     /// # Examples
     ///
     /// ```rust,ignore
@@ -318,11 +568,52 @@ impl World {
     /// }
     /// ```
     pub fn query<'a, Q: WorldQuery>(&'a self) -> Query<'a, Q> {
+        let (plan, matching_page_indices) = self.plan_query::<Q>();
+        Query::new(self, plan, matching_page_indices)
+    }
+
+    /// Creates a mutable iterator that queries the world for entities matching a set of components and filters.
+    ///
+    /// This method is similar to `query`, but it allows mutable access to the components.
+    /// It uses the same dynamic plan re-finding to ensure thread-safe consistency.
+    pub fn query_mut<'a, Q: WorldQuery>(&'a mut self) -> QueryMut<'a, Q> {
+        let (plan, matching_page_indices) = self.plan_query::<Q>();
+        QueryMut::new(self, plan, matching_page_indices)
+    }
+
+    /// (Internal) Builds the execution plan of `Q` and finds the pages it
+    /// iterates.
+    fn plan_query<Q: WorldQuery>(&self) -> (QueryPlan, Vec<u32>) {
         let type_ids = Q::type_ids();
+        let disabled = TypeId::of::<crate::ecs::Disabled>();
+        let skip_disabled = !Q::includes_disabled()
+            && !Q::accessed_type_ids().contains(&disabled)
+            && !Q::without_type_ids().contains(&disabled);
+
+        // 1. A required sparse component drives the iteration: walk its set,
+        // the smallest one if there are several.
+        if let Some(driver) = type_ids
+            .iter()
+            .filter_map(|type_id| self.sparse.get(type_id).map(|set| (*type_id, set)))
+            .min_by_key(|(_, set)| set.entities().len())
+            .map(|(type_id, _)| type_id)
+        {
+            let mut plan = QueryPlan::sparse(driver);
+            plan.skip_disabled = skip_disabled;
+            return (plan, Vec::new());
+        }
+
+        // An `Or` query requiring no component would visit an entity once per
+        // domain it has pages in: walk the entities instead.
+        if type_ids.is_empty() && Q::has_entity_filter() {
+            let mut plan = QueryPlan::entity_scan();
+            plan.skip_disabled = skip_disabled;
+            return (plan, Vec::new());
+        }
 
-        // 1. Try to fetch the strategy plan from the cache.
+        // 2. Try to fetch the strategy plan from the cache.
         // We cache the execution logic (Native vs Transversal), not the page indices.
-        let plan = {
+        let mut plan = {
             let cache = self.planner.query_cache.read().unwrap();
             if let Some(plan) = cache.get(&type_ids) {
                 plan.clone()
@@ -335,43 +626,121 @@ impl World {
             }
         };
 
-        // 2. Dynamically find matching pages for this call.
+        // 3. Optional or excluded sparse components are not in any page:
+        // they are checked per entity, which only the transversal path does.
+        let mut without_type_ids = Q::without_type_ids();
+        if Q::accessed_type_ids()
+            .iter()
+            .chain(&without_type_ids)
+            .any(|type_id| self.sparse.contains_key(type_id))
+        {
+            plan.mode = crate::ecs::QueryMode::Transversal;
+            without_type_ids.retain(|type_id| !self.sparse.contains_key(type_id));
+        }
+
+        // 4. Disabled entities are skipped by whole pages in the domain of
+        // `Disabled`, and one by one everywhere else.
+        if skip_disabled {
+            without_type_ids.push(disabled);
+            plan.skip_disabled = plan.mode != crate::ecs::QueryMode::Native
+                || plan.driver_domain != self.storage.registry.get_domain(disabled);
+        }
+
+        // 5. Dynamically find matching pages for this call.
         // This ensures the query is correct even if new archetypes were created
         // in a different domain since the last call.
         let matching_page_indices =
-            self.find_matching_pages(&plan.driver_signature, &Q::without_type_ids());
+            self.find_matching_pages(&plan.driver_signature, &without_type_ids);
+        (plan, matching_page_indices)
+    }
 
-        // 3. Return the query with the plan and the current matching pages.
-        Query::new(self, plan, matching_page_indices)
+    /// (Internal) Returns the entities a Sparse plan iterates, or nothing for
+    /// other plans.
+    pub(crate) fn sparse_driver_entities(&self, plan: &QueryPlan) -> Vec<EntityId> {
+        if plan.mode != crate::ecs::QueryMode::Sparse {
+            return Vec::new();
+        }
+        match plan.sparse_driver {
+            Some(driver) => self
+                .sparse
+                .get(&driver)
+                .map(|set| set.entities().to_vec())
+                .unwrap_or_default(),
+            None => self.iter_entities().collect(),
+        }
     }
 
-    /// Creates a mutable iterator that queries the world for entities matching a set of components and filters.
+    /// Returns the tick stamped on component writes happening now.
     ///
-    /// This method is similar to `query`, but it allows mutable access to the components.
-    /// It uses the same dynamic plan re-finding to ensure thread-safe consistency.
-    pub fn query_mut<'a, Q: WorldQuery>(&'a mut self) -> QueryMut<'a, Q> {
-        let type_ids = Q::type_ids();
+    /// `Added<T>` and `Changed<T>` compare component ticks against a
+    /// baseline: by default the tick of the last
+    /// [`clear_trackers`](Self::clear_trackers), or the one given to
+    /// [`Query::since`].
+    pub fn change_tick(&self) -> u32 {
+        self.changes.change_tick()
+    }
 
-        // 1. Get strategy from cache
-        let plan = {
-            let cache = self.planner.query_cache.read().unwrap();
-            if let Some(plan) = cache.get(&type_ids) {
-                plan.clone()
-            } else {
-                drop(cache);
-                let new_plan = self.analyze_query(&type_ids);
-                let mut cache = self.planner.query_cache.write().unwrap();
-                cache.insert(type_ids, new_plan.clone());
-                new_plan
-            }
-        };
+    /// Returns the default baseline of `Added<T>` and `Changed<T>`: the tick
+    /// at which [`clear_trackers`](Self::clear_trackers) last ran.
+    pub fn last_change_tick(&self) -> u32 {
+        self.changes.last_change_tick()
+    }
 
-        // 2. Dynamically find pages
-        let matching_page_indices =
-            self.find_matching_pages(&plan.driver_signature, &Q::without_type_ids());
+    /// Returns the current tick and advances to the next one.
+    ///
+    /// A reader that keeps its own baseline calls this right before
+    /// querying and passes the previous value to [`Query::since`]: it then
+    /// sees every write since its last run exactly once, whenever
+    /// [`clear_trackers`](Self::clear_trackers) runs.
+    ///
+    /// ```rust,ignore
+    /// let since = std::mem::replace(&mut self.last_run, world.increment_change_tick());
+    /// for (transform, _) in world.query::<(&Transform, Changed<Transform>)>().since(since) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn increment_change_tick(&mut self) -> u32 {
+        self.changes.increment_change_tick()
+    }
 
-        // 3. Construct the iterator
-        QueryMut::new(self, plan, matching_page_indices)
+    /// Starts a new change-detection window: from now on, `Added<T>` and
+    /// `Changed<T>` only match components added or written after this call.
+    ///
+    /// The engine calls this once per frame, at the end of the maintenance
+    /// phase.
+    pub fn clear_trackers(&mut self) {
+        self.changes.clear();
+    }
+
+    /// Returns `true` if `entity_id` has a `T` added since
+    /// [`last_change_tick`](Self::last_change_tick).
+    pub fn is_added<T: Component>(&self, entity_id: EntityId) -> bool {
+        self.get::<T>(entity_id).is_some()
+            && self
+                .changes
+                .is_added(TypeId::of::<T>(), entity_id.index, self.last_change_tick())
+    }
+
+    /// Returns `true` if `entity_id` has a `T` added or written since
+    /// [`last_change_tick`](Self::last_change_tick).
+    pub fn is_changed<T: Component>(&self, entity_id: EntityId) -> bool {
+        self.get::<T>(entity_id).is_some()
+            && self
+                .changes
+                .is_changed(TypeId::of::<T>(), entity_id.index, self.last_change_tick())
+    }
+
+    /// Returns the profiler that queries on this world report to.
+    pub fn query_profiler(&self) -> &QueryProfiler {
+        &self.query_profiler
+    }
+
+    /// Makes queries on this world report to `profiler`.
+    ///
+    /// Profilers share their counters when cloned, so the engine can keep
+    /// a clone in the service registry and publish one frame at a time.
+    pub fn set_query_profiler(&mut self, profiler: QueryProfiler) {
+        self.query_profiler = profiler;
     }
 
     /// Registers a component type with a specific semantic domain.
@@ -380,10 +749,65 @@ impl World {
     /// in a bundle, it must be registered with the world to define which semantic
     /// page group its data will be stored in.
     pub fn register_component<T: Component>(&mut self, domain: SemanticDomain) {
+        if self.sparse.contains_key(&TypeId::of::<T>()) {
+            log::warn!(
+                "World::register_component: {} is already stored in a sparse set",
+                std::any::type_name::<T>()
+            );
+            return;
+        }
         self.storage.registry.register::<T>(domain);
         self.type_registry.register::<T>();
     }
 
+    /// Registers a component type stored in a sparse set instead of pages.
+    ///
+    /// Adding or removing a sparse component never moves the entity between
+    /// pages, which suits markers and short-lived state toggled every few
+    /// frames (`Stunned`, `Hovered`, `Selected`...). Iterating them is slower
+    /// than iterating page columns, so components read every frame by hot
+    /// systems should stay in pages.
+    ///
+    /// Sparse components are runtime state: they are added with
+    /// [`add_component`](Self::add_component), not spawn bundles, and are
+    /// neither serialized nor kept across scene loads.
+    pub fn register_sparse_component<T: Component>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        if self.storage.registry.get_domain(type_id).is_some() {
+            log::warn!(
+                "World::register_sparse_component: {} is already stored in pages",
+                std::any::type_name::<T>()
+            );
+            return;
+        }
+        self.sparse
+            .entry(type_id)
+            .or_insert_with(|| Box::new(SparseSet::<T>::default()));
+        self.type_registry.register::<T>();
+    }
+
+    /// Returns `true` if components of `type_id` are stored in a sparse set.
+    pub fn is_sparse_component(&self, type_id: TypeId) -> bool {
+        self.sparse.contains_key(&type_id)
+    }
+
+    /// (Internal) Returns the sparse set of `T`, if `T` is a sparse component.
+    pub(crate) fn sparse_set<T: Component>(&self) -> Option<&SparseSet<T>> {
+        self.sparse
+            .get(&TypeId::of::<T>())?
+            .as_any()
+            .downcast_ref::<SparseSet<T>>()
+    }
+
+    /// (Internal) Returns the sparse set of `T` mutably, if `T` is a sparse
+    /// component.
+    pub(crate) fn sparse_set_mut<T: Component>(&mut self) -> Option<&mut SparseSet<T>> {
+        self.sparse
+            .get_mut(&TypeId::of::<T>())?
+            .as_any_mut()
+            .downcast_mut::<SparseSet<T>>()
+    }
+
     /// Analyzes a query's component signature to create an optimized execution plan.
     ///
     /// This method identifies if a query is transversal (spanning multiple domains)
@@ -507,21 +931,48 @@ impl World {
     ///   It is `None` if no migration was needed (e.g., adding to a new domain).
     /// - `Err(AddComponentError)`: If the operation failed (e.g., entity not alive,
     ///   component not registered, or component already present).
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn add_component<C: Component>(
         &mut self,
         entity_id: EntityId,
         component: C,
     ) -> Result<Option<PageIndex>, AddComponentError> {
         // 1. Validate EntityId and get metadata
-        let Some((id_in_world, Some(_))) = self.entities.get(entity_id.index as usize) else {
+        if self.live_metadata(entity_id, "add_component").is_none() {
             return Err(AddComponentError::EntityNotFound);
-        };
+        }
 
-        if id_in_world.generation != entity_id.generation {
-            return Err(AddComponentError::EntityNotFound);
+        if let Some(set) = self.sparse_set_mut::<C>() {
+            if set.contains(entity_id) {
+                return Err(AddComponentError::ComponentAlreadyExists);
+            }
+            set.insert(entity_id, component);
+            self.changes.mark_added(TypeId::of::<C>(), entity_id.index);
+            return Ok(None);
         }
 
-        let Some(domain) = self.storage.registry.get_domain(TypeId::of::<C>()) else {
+        self.add_component_with(entity_id, TypeId::of::<C>(), |column| {
+            column
+                .as_any_mut()
+                .downcast_mut::<Vec<C>>()
+                .unwrap()
+                .push(component);
+        })
+    }
+
+    /// (Internal) Migrates a live entity to the page of its domain signature
+    /// extended with `type_id`, then lets `push` append the new component to
+    /// the destination column.
+    ///
+    /// Shared by [`add_component`](Self::add_component) and the type-erased
+    /// insertion of dynamic components. The caller validates the entity.
+    pub(crate) fn add_component_with(
+        &mut self,
+        entity_id: EntityId,
+        type_id: TypeId,
+        push: impl FnOnce(&mut dyn AnyVec),
+    ) -> Result<Option<PageIndex>, AddComponentError> {
+        let Some(domain) = self.storage.registry.get_domain(type_id) else {
             return Err(AddComponentError::ComponentNotRegistered);
         };
 
@@ -539,7 +990,7 @@ impl World {
             self.storage.pages[loc.page_id as usize].type_ids.clone()
         });
         let mut new_type_ids = old_type_ids.clone();
-        new_type_ids.push(TypeId::of::<C>());
+        new_type_ids.push(type_id);
         new_type_ids.sort();
         new_type_ids.dedup();
 
@@ -580,26 +1031,25 @@ impl World {
                 }
             }
 
-            dest_page
-                .columns
-                .get_mut(&TypeId::of::<C>())
-                .unwrap()
-                .as_any_mut()
-                .downcast_mut::<Vec<C>>()
-                .unwrap()
-                .push(component);
+            push(dest_page.columns.get_mut(&type_id).unwrap().as_mut());
 
             dest_page.add_entity(entity_id);
         }
 
-        // 5. Update metadata and put it back
-        metadata.locations.insert(
-            domain,
-            PageIndex {
-                page_id: dest_page_id,
-                row_index: dest_row_index,
-            },
-        );
+        // 5. Update metadata and put it back. Domains sharing the old row
+        //    moved along with it.
+        let dest = PageIndex {
+            page_id: dest_page_id,
+            row_index: dest_row_index,
+        };
+        if let Some(old) = old_location_opt {
+            for loc in metadata.locations.values_mut() {
+                if *loc == old {
+                    *loc = dest;
+                }
+            }
+        }
+        metadata.locations.insert(domain, dest);
 
         // Update the domain bitset for the entity.
         self.storage
@@ -609,6 +1059,7 @@ impl World {
             .set(entity_id.index);
 
         self.entities.get_mut(entity_id.index as usize).unwrap().1 = Some(metadata);
+        self.changes.mark_added(type_id, entity_id.index);
 
         // 6. Return the old location for cleanup, without performing swap_remove
         Ok(old_location_opt)
@@ -638,20 +1089,36 @@ impl World {
     /// - `Err(RemoveComponentError::ComponentNotRegistered)` — type unknown.
     /// - `Err(RemoveComponentError::ComponentNotPresent)` — entity didn't
     ///   carry this component to begin with.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn remove_component<C: Component>(
         &mut self,
         entity_id: EntityId,
     ) -> Result<Option<PageIndex>, RemoveComponentError> {
         // 1. Validate the entity.
-        let Some((id_in_world, Some(_))) = self.entities.get(entity_id.index as usize) else {
-            return Err(RemoveComponentError::EntityNotFound);
-        };
-        if id_in_world.generation != entity_id.generation {
+        if self.live_metadata(entity_id, "remove_component").is_none() {
             return Err(RemoveComponentError::EntityNotFound);
         }
 
+        if let Some(set) = self.sparse_set_mut::<C>() {
+            return match set.remove(entity_id) {
+                Some(_) => Ok(None),
+                None => Err(RemoveComponentError::ComponentNotPresent),
+            };
+        }
+
+        self.remove_component_by_type(entity_id, TypeId::of::<C>())
+    }
+
+    /// (Internal) Removes the paged component `target_type` from a live
+    /// entity. Shared by [`remove_component`](Self::remove_component) and
+    /// dynamic components. The caller validates the entity.
+    pub(crate) fn remove_component_by_type(
+        &mut self,
+        entity_id: EntityId,
+        target_type: TypeId,
+    ) -> Result<Option<PageIndex>, RemoveComponentError> {
         // 2. Resolve the component's domain.
-        let Some(domain) = self.storage.registry.get_domain(TypeId::of::<C>()) else {
+        let Some(domain) = self.storage.registry.get_domain(target_type) else {
             return Err(RemoveComponentError::ComponentNotRegistered);
         };
 
@@ -670,7 +1137,6 @@ impl World {
             return Err(RemoveComponentError::ComponentNotPresent);
         };
 
-        let target_type = TypeId::of::<C>();
         let old_type_ids = self.storage.pages[loc.page_id as usize].type_ids.clone();
         if !old_type_ids.contains(&target_type) {
             // Entity is in this domain but doesn't have C specifically.
@@ -725,21 +1191,76 @@ impl World {
             dest_page.add_entity(entity_id);
         }
 
-        // 7. Update entity metadata to point at the new (page, row).
-        metadata.locations.insert(
-            domain,
-            PageIndex {
-                page_id: dest_page_id,
-                row_index: dest_row_index,
-            },
-        );
-        // The bitset stays set — other components remain in this domain.
+        // 7. Update entity metadata to point at the new (page, row). Domains
+        //    sharing the old row moved along with it.
+        let dest = PageIndex {
+            page_id: dest_page_id,
+            row_index: dest_row_index,
+        };
+        for location in metadata.locations.values_mut() {
+            if *location == loc {
+                *location = dest;
+            }
+        }
+        let registry = &self.storage.registry;
+        if !new_type_ids
+            .iter()
+            .any(|t| registry.get_domain(*t) == Some(domain))
+        {
+            // Only other domains were left in the row.
+            metadata.locations.remove(&domain);
+            if let Some(bitset) = self.storage.domain_bitsets.get_mut(&domain) {
+                bitset.clear(entity_id.index);
+            }
+        }
         self.entities.get_mut(entity_id.index as usize).unwrap().1 = Some(metadata);
 
         // 8. Hand the old location off to the GC.
         Ok(Some(loc))
     }
 
+    /// (Internal) Sets `component` on `entity`, overwriting the current value
+    /// if it has one, and frees the row a migration leaves behind right away.
+    ///
+    /// Engine systems use this rather than [`add_component`](Self::add_component)
+    /// so that no stale copy of the entity stays visible to queries.
+    pub(crate) fn insert_component<C: Component>(
+        &mut self,
+        entity_id: EntityId,
+        component: C,
+    ) -> Result<(), AddComponentError> {
+        if let Some(current) = self.get_mut::<C>(entity_id) {
+            *current = component;
+            return Ok(());
+        }
+        let orphan = self.add_component(entity_id, component)?;
+        self.reclaim_orphan::<C>(orphan);
+        Ok(())
+    }
+
+    /// (Internal) Removes `C` from `entity` like
+    /// [`remove_component`](Self::remove_component), and frees the old row
+    /// right away.
+    pub(crate) fn remove_component_now<C: Component>(
+        &mut self,
+        entity_id: EntityId,
+    ) -> Result<(), RemoveComponentError> {
+        let orphan = self.remove_component::<C>(entity_id)?;
+        self.reclaim_orphan::<C>(orphan);
+        Ok(())
+    }
+
+    /// (Internal) Swap-removes the orphaned row a migration of `C` left at
+    /// `orphan`, if any.
+    fn reclaim_orphan<C: Component>(&mut self, orphan: Option<PageIndex>) {
+        let (Some(location), Some(domain)) =
+            (orphan, self.storage.registry.get_domain(TypeId::of::<C>()))
+        else {
+            return;
+        };
+        self.cleanup_orphan_at(location, domain);
+    }
+
     /// Logically removes all components belonging to a specific `SemanticDomain` from an entity.
     ///
     /// This is an extremely fast, O(1) operation that only modifies the entity's
@@ -755,22 +1276,20 @@ impl World {
     ///   components were successfully removed. This can be sent to a garbage collector.
     /// - `None`: If the entity is not alive or did not have any components in the
     ///   specified `SemanticDomain`.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn remove_component_domain<C: Component>(
         &mut self,
         entity_id: EntityId,
     ) -> Option<PageIndex> {
         // 1. Validate the entity ID to ensure we're acting on a live entity.
-        let (id_in_world, metadata_slot) = self.entities.get_mut(entity_id.index as usize)?;
-        if id_in_world.generation != entity_id.generation || metadata_slot.is_none() {
-            return None;
-        }
+        self.live_metadata(entity_id, "remove_component_domain")?;
 
         // 2. Use the registry to find the component's domain.
         let domain = self.storage.registry.get_domain(TypeId::of::<C>())?;
 
         // 3. Remove the location entry from the entity's metadata.
         //    `HashMap::remove` returns the value that was at that key, which is exactly what we need.
-        let metadata = metadata_slot.as_mut().unwrap();
+        let metadata = self.entities.get_metadata_mut(entity_id)?;
         let location = metadata.locations.remove(&domain);
 
         // Clear the domain bitset if a component was removed.
@@ -791,25 +1310,38 @@ impl World {
     /// # Returns
     ///
     /// `None` if the entity is not alive or does not have the requested component.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn get_mut<T: Component>(&mut self, entity_id: EntityId) -> Option<&mut T> {
         // 1. Validate the entity ID.
-        let (id_in_world, metadata_opt) = self.entities.get(entity_id.index as usize).unwrap();
-        if id_in_world.generation != entity_id.generation || metadata_opt.is_none() {
-            return None;
-        }
-        let metadata = metadata_opt.as_ref().unwrap();
+        let metadata = self.live_metadata(entity_id, "get_mut")?;
+        let type_id = TypeId::of::<T>();
 
         // 2. Use the registry to find the component's domain and its location.
-        let domain = self.storage.registry.get_domain(TypeId::of::<T>())?;
-        let location = metadata.locations.get(&domain)?;
+        let location = match self.storage.registry.get_domain(type_id) {
+            Some(domain) if !self.sparse.contains_key(&type_id) => {
+                *metadata.locations.get(&domain)?
+            }
+            _ => {
+                // Sparse components live outside the pages.
+                let set = self
+                    .sparse
+                    .get_mut(&type_id)?
+                    .as_any_mut()
+                    .downcast_mut::<SparseSet<T>>()?;
+                let component = set.get_mut(entity_id)?;
+                self.changes.mark_changed(type_id, entity_id.index);
+                return Some(component);
+            }
+        };
 
         // 3. Get the component data from the page.
-        let type_id = TypeId::of::<T>();
         let page = self.storage.pages.get_mut(location.page_id as usize)?;
         let column = page.columns.get_mut(&type_id)?;
         let vec = column.as_any_mut().downcast_mut::<Vec<T>>()?;
 
-        vec.get_mut(location.row_index as usize)
+        let component = vec.get_mut(location.row_index as usize)?;
+        self.changes.mark_changed(type_id, entity_id.index);
+        Some(component)
     }
 
     /// Gets mutable references to components of type `T` for multiple entities simultaneously.
@@ -821,6 +1353,7 @@ impl World {
     ///
     /// An array of `Option<&mut T>`. If any entity is not found, does not have the component,
     /// or if there are duplicate requests for the same component instance, that entry will be `None`.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn get_many_mut<T: Component, const N: usize>(
         &mut self,
         ids: [EntityId; N],
@@ -828,6 +1361,9 @@ impl World {
         let mut results: [Option<&mut T>; N] = std::array::from_fn(|_| None);
 
         let type_id = TypeId::of::<T>();
+        if self.sparse.contains_key(&type_id) {
+            return self.get_many_sparse_mut(ids);
+        }
         let domain = match self.storage.registry.get_domain(type_id) {
             Some(d) => d,
             None => return results,
@@ -838,13 +1374,11 @@ impl World {
         let mut found_mask = [false; N];
 
         for i in 0..N {
-            if let Some((stored_id, Some(metadata))) = self.entities.get(ids[i].index as usize) {
-                // Ensure the EntityId matches (including generation)
-                if *stored_id == ids[i] {
-                    if let Some(loc) = metadata.locations.get(&domain) {
-                        locations[i] = (loc.page_id, loc.row_index);
-                        found_mask[i] = true;
-                    }
+            // Ensure the EntityId matches (including generation)
+            if let Some(metadata) = self.live_metadata(ids[i], "get_many_mut") {
+                if let Some(loc) = metadata.locations.get(&domain) {
+                    locations[i] = (loc.page_id, loc.row_index);
+                    found_mask[i] = true;
                 }
             }
         }
@@ -862,6 +1396,12 @@ impl World {
             }
         }
 
+        for (id, found) in ids.iter().zip(found_mask) {
+            if found {
+                self.changes.mark_changed(type_id, id.index);
+            }
+        }
+
         // 3. Retrieve references using unsafe to bypass split_at_mut complexity.
         // SAFETY: We have verified that all (page, row) pairs are unique,
         // so we are not creating multiple mutable references to the same data.
@@ -886,6 +1426,39 @@ impl World {
         results
     }
 
+    /// (Internal) The sparse-set counterpart of [`get_many_mut`](Self::get_many_mut).
+    fn get_many_sparse_mut<T: Component, const N: usize>(
+        &mut self,
+        ids: [EntityId; N],
+    ) -> [Option<&mut T>; N] {
+        let mut results: [Option<&mut T>; N] = std::array::from_fn(|_| None);
+        for i in 0..N {
+            if ids[i + 1..].contains(&ids[i]) {
+                // Collision detected: return all None for safety
+                return results;
+            }
+        }
+
+        let alive = ids.map(|id| self.live_metadata(id, "get_many_mut").is_some());
+        let type_id = TypeId::of::<T>();
+        let Some(set) = self.sparse_set_mut::<T>() else {
+            return results;
+        };
+        let set_ptr = set as *mut SparseSet<T>;
+        for i in 0..N {
+            if !alive[i] {
+                continue;
+            }
+            // SAFETY: the IDs are distinct, so every reference points at a
+            // different value of the set.
+            if let Some(value) = unsafe { (*set_ptr).get_mut(ids[i]) } {
+                results[i] = Some(value);
+                self.changes.mark_changed(type_id, ids[i].index);
+            }
+        }
+        results
+    }
+
     /// Gets an immutable reference to a single component `T` for a given entity.
     ///
     /// This provides direct, "random" access to a component.
@@ -893,13 +1466,14 @@ impl World {
     /// # Returns
     ///
     /// `None` if the entity is not alive or does not have the requested component.
+    #[cfg_attr(feature = "entity-debug", track_caller)]
     pub fn get<T: Component>(&self, entity_id: EntityId) -> Option<&T> {
         // 1. Validate the entity ID.
-        let (id_in_world, metadata_opt) = self.entities.get(entity_id.index as usize).unwrap();
-        if id_in_world.generation != entity_id.generation || metadata_opt.is_none() {
-            return None;
+        let metadata = self.live_metadata(entity_id, "get")?;
+
+        if let Some(set) = self.sparse_set::<T>() {
+            return set.get(entity_id);
         }
-        let metadata = metadata_opt.as_ref().unwrap();
 
         // 2. Use the registry to find the component's domain and its location.
         let domain = self.storage.registry.get_domain(TypeId::of::<T>())?;
@@ -925,27 +1499,49 @@ impl World {
             .filter_map(|(id, metadata_opt)| metadata_opt.as_ref().map(|_| *id))
     }
 
+    /// Returns `true` if [`serialize_archetype`](Self::serialize_archetype)
+    /// captures the whole world: every component lives in a page and is
+    /// plain data.
+    pub fn is_archetype_serializable(&self) -> bool {
+        self.sparse.values().all(|set| set.entities().is_empty())
+            && self
+                .storage
+                .pages
+                .iter()
+                .all(|page| page.columns.values().all(|column| column.is_plain_data()))
+    }
+
     /// Serializes the entire World state using a direct memory layout strategy.
     ///
-    /// This method is highly unsafe as it reads raw component memory.
+    /// This method is highly unsafe as it reads raw component memory. Only
+    /// plain-data components (no owned heap resources) can be snapshotted this
+    /// way; any other column makes the call fail.
+    ///
+    /// Sparse components are not part of the pages and are left out; check
+    /// [`is_archetype_serializable`](Self::is_archetype_serializable) first.
     pub fn serialize_archetype(&self) -> Result<Vec<u8>, bincode::error::EncodeError> {
         let mut serialized_pages = Vec::with_capacity(self.storage.pages.len());
         for page in &self.storage.pages {
             let mut serialized_columns = HashMap::new();
+            let mut type_names = Vec::with_capacity(page.type_ids.len());
 
             // Use the TypeRegistry to get the stable string name for each TypeId.
-            let type_names: Vec<String> = page
-                .type_ids
-                .iter()
-                .map(|id| self.type_registry.get_name_of(id).unwrap().to_string())
-                .collect();
-
             for type_id in &page.type_ids {
-                let type_name = self.type_registry.get_name_of(type_id).unwrap();
+                let type_name = self.type_registry.get_name_of(type_id).ok_or_else(|| {
+                    bincode::error::EncodeError::OtherString(format!(
+                        "component {type_id:?} has no registered name"
+                    ))
+                })?;
                 let column = &page.columns[type_id];
-                // UNSAFE: Copying raw bytes from the component vector.
+                if !column.is_plain_data() {
+                    return Err(bincode::error::EncodeError::OtherString(format!(
+                        "component '{type_name}' owns heap data and cannot be archetype-serialized"
+                    )));
+                }
+                // UNSAFE: Copying raw bytes from a plain-data component vector.
                 let bytes = unsafe { column.as_bytes() };
                 serialized_columns.insert(type_name.to_string(), bytes.to_vec());
+                type_names.push(type_name.to_string());
             }
 
             serialized_pages.push(SerializedPage {
@@ -964,75 +1560,197 @@ impl World {
 
     /// Deserializes and completely replaces the World state from a memory layout.
     ///
-    /// This method is highly unsafe as it writes raw bytes into component vectors.
+    /// The whole layout is validated before the world is touched: unknown or
+    /// non-plain component types, column sizes that disagree with the page's
+    /// entity count, and entity locations pointing outside the loaded pages are
+    /// all rejected with an error, leaving the world unchanged.
     pub fn deserialize_archetype(
         &mut self,
         data: &[u8],
     ) -> Result<(), bincode::error::DecodeError> {
-        let (layout, _): (SceneMemoryLayout, _) =
-            bincode::decode_from_slice(data, config::standard())?;
-
-        self.entities.entities = layout.entities;
-        self.entities.freed_entities = layout.freed_entities;
-        self.storage.pages.clear();
+        use bincode::error::DecodeError;
+
+        let (layout, _): (SceneMemoryLayout, _) = bincode::decode_from_slice(
+            data,
+            config::standard().with_limit::<{ crate::scene::SCENE_DECODE_LIMIT }>(),
+        )?;
+
+        // --- 1. Validate and build every page without touching the world. ---
+        let mut pages = Vec::with_capacity(layout.pages.len());
+        for (page_index, serialized_page) in layout.pages.into_iter().enumerate() {
+            if serialized_page.columns.len() != serialized_page.type_names.len() {
+                return Err(DecodeError::OtherString(format!(
+                    "page {page_index}: {} columns for {} component types",
+                    serialized_page.columns.len(),
+                    serialized_page.type_names.len()
+                )));
+            }
 
-        for serialized_page in layout.pages {
             // Use the TypeRegistry to convert string names back to TypeIds.
-            let type_ids: Vec<TypeId> = serialized_page
-                .type_names
-                .iter()
-                .map(|name| {
-                    self.type_registry
-                        .get_id_of(name)
-                        .expect("Serialized component type not registered")
-                })
-                .collect();
-
-            let mut new_page = ComponentPage {
-                type_ids,
-                entities: serialized_page.entities,
-                columns: HashMap::new(),
-            };
-
-            for (type_name, bytes) in &serialized_page.columns {
-                let type_id = self.type_registry.get_id_of(type_name).unwrap();
+            let mut type_ids = Vec::with_capacity(serialized_page.type_names.len());
+            let mut columns = HashMap::new();
+            for type_name in &serialized_page.type_names {
+                let type_id = self.type_registry.get_id_of(type_name).ok_or_else(|| {
+                    DecodeError::OtherString(format!(
+                        "page {page_index}: component '{type_name}' is not registered"
+                    ))
+                })?;
                 let constructor = self
                     .storage
                     .registry
                     .get_column_constructor(&type_id)
-                    .unwrap();
+                    .ok_or_else(|| {
+                        DecodeError::OtherString(format!(
+                            "page {page_index}: component '{type_name}' has no storage"
+                        ))
+                    })?;
+                let bytes = serialized_page.columns.get(type_name).ok_or_else(|| {
+                    DecodeError::OtherString(format!(
+                        "page {page_index}: missing column for '{type_name}'"
+                    ))
+                })?;
+
                 let mut column = constructor();
-                // UNSAFE: Writing raw bytes into the newly created component vector.
+                if !column.is_plain_data() {
+                    return Err(DecodeError::OtherString(format!(
+                        "page {page_index}: component '{type_name}' is not plain data"
+                    )));
+                }
+                let expected_len = column
+                    .element_size()
+                    .checked_mul(serialized_page.entities.len())
+                    .ok_or_else(|| {
+                        DecodeError::OtherString(format!("page {page_index}: column size overflow"))
+                    })?;
+                if bytes.len() != expected_len {
+                    return Err(DecodeError::OtherString(format!(
+                        "page {page_index}: column '{type_name}' has {} bytes, expected {expected_len}",
+                        bytes.len()
+                    )));
+                }
+                if columns.contains_key(&type_id) {
+                    return Err(DecodeError::OtherString(format!(
+                        "page {page_index}: duplicate component '{type_name}'"
+                    )));
+                }
+
+                // UNSAFE: the length matches `entities.len()` elements of a
+                // plain-data type, so no heap pointers are materialized.
                 unsafe {
                     column.set_from_bytes(bytes);
                 }
-                new_page.columns.insert(type_id, column);
+                type_ids.push(type_id);
+                columns.insert(type_id, column);
             }
-            self.storage.pages.push(new_page);
+
+            pages.push(ComponentPage {
+                type_ids,
+                entities: serialized_page.entities,
+                columns,
+            });
         }
 
+        // --- 2. Validate every live entity's locations against the pages. ---
+        for (slot, (id, metadata)) in layout.entities.iter().enumerate() {
+            if id.index as usize != slot {
+                return Err(DecodeError::OtherString(format!(
+                    "entity slot {slot} holds mismatched id {id:?}"
+                )));
+            }
+            let Some(metadata) = metadata else {
+                continue;
+            };
+            for location in metadata.locations.values() {
+                let row_entity = pages
+                    .get(location.page_id as usize)
+                    .and_then(|page| page.entities.get(location.row_index as usize));
+                if row_entity != Some(id) {
+                    return Err(DecodeError::OtherString(format!(
+                        "entity {id:?} points to an invalid page location"
+                    )));
+                }
+            }
+        }
+        for &freed in &layout.freed_entities {
+            if !matches!(layout.entities.get(freed as usize), Some((_, None))) {
+                return Err(DecodeError::OtherString(format!(
+                    "freed entity index {freed} is not a vacant slot"
+                )));
+            }
+        }
+
+        // --- 3. Commit: replace the world state and rebuild derived indices. ---
+        self.install_pages(layout.entities, layout.freed_entities, pages);
         Ok(())
     }
+
+    /// (Internal) Replaces every entity and page with the given ones, then
+    /// rebuilds the indices derived from them. The caller has checked that
+    /// entity locations and pages agree.
+    pub(crate) fn install_pages(
+        &mut self,
+        entities: Vec<(EntityId, Option<EntityMetadata>)>,
+        freed_entities: Vec<u32>,
+        pages: Vec<ComponentPage>,
+    ) {
+        self.entities.entities = entities;
+        self.entities.freed_entities = freed_entities;
+        self.storage.pages = pages;
+        self.storage.archetype_map.clear();
+        self.storage.domain_bitsets.clear();
+        self.storage.domain_stats.clear();
+        self.names
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .invalidate();
+        self.tags
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .invalidate();
+
+        for (page_id, page) in self.storage.pages.iter().enumerate() {
+            self.storage
+                .archetype_map
+                .insert(page.type_ids.clone(), page_id as u32);
+            if let Some(domain) = page
+                .type_ids
+                .first()
+                .and_then(|type_id| self.storage.registry.get_domain(*type_id))
+            {
+                self.storage
+                    .domain_stats
+                    .entry(domain)
+                    .or_default()
+                    .page_count += 1;
+            }
+        }
+        for (id, metadata) in &self.entities.entities {
+            let Some(metadata) = metadata else {
+                continue;
+            };
+            for domain in metadata.locations.keys() {
+                self.storage
+                    .domain_bitsets
+                    .entry(*domain)
+                    .or_default()
+                    .set(id.index);
+                self.storage
+                    .domain_stats
+                    .entry(*domain)
+                    .or_default()
+                    .entity_count += 1;
+            }
+        }
+    }
 }
 
 impl WorldMaintenance for World {
-    fn cleanup_orphan_at(&mut self, location: PageIndex, domain: SemanticDomain) {
-        let page = &mut self.storage.pages[location.page_id as usize];
-        if page.entities.is_empty() || location.row_index as usize >= page.entities.len() {
+    fn cleanup_orphan_at(&mut self, location: PageIndex, _domain: SemanticDomain) {
+        let page = &self.storage.pages[location.page_id as usize];
+        if location.row_index as usize >= page.entities.len() {
             return;
         }
-
-        let last_entity_in_page = *page.entities.last().unwrap();
-        page.swap_remove_row(location.row_index);
-
-        if let Some((_id, metadata_opt)) = self.entities.get_mut(last_entity_in_page.index as usize)
-        {
-            if let Some(metadata) = metadata_opt.as_mut() {
-                if let Some(loc) = metadata.locations.get_mut(&domain) {
-                    *loc = location;
-                }
-            }
-        }
+        self.swap_remove_at(location);
     }
 
     fn vacuum_hole_at(&mut self, page_index: u32, hole_row_index: u32) {
@@ -1056,6 +1774,18 @@ impl WorldMaintenance for World {
             self.cleanup_orphan_at(location, domain);
         }
     }
+
+    fn compact_page(&mut self, page_index: u32) -> usize {
+        let Some(page) = self.storage.pages.get_mut(page_index as usize) else {
+            return 0;
+        };
+        if !page.is_sparse() {
+            return 0;
+        }
+        let before = page.reserved_bytes();
+        page.shrink_to_fit();
+        before.saturating_sub(page.reserved_bytes())
+    }
 }
 
 impl Default for World {
@@ -0,0 +1,122 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Access to the frame's [`World`] from off the frame thread.
+//!
+//! Agents running on a worker thread get no `&mut World`: the frame thread
+//! keeps it for the agents that run there in the meantime. They get a
+//! [`WorldChannel`] instead, and send it the work that needs the world. The
+//! frame thread runs each request between two of its own agents, or while
+//! it waits for workers at the frame barrier, and sends the result back:
+//!
+//! ```rust,ignore
+//! let bodies = channel.with_world(|world| world.query::<&RigidBody>().count())?;
+//! ```
+//!
+//! The world can change between two requests, since frame-thread agents
+//! keep running in between.
+
+use crossbeam_channel::{Receiver, Sender};
+
+use crate::ecs::World;
+
+/// A unit of work sent to the frame thread.
+pub type WorldRequest = Box<dyn FnOnce(&mut World) + Send + 'static>;
+
+/// A worker's handle to the frame's [`World`].
+///
+/// Cheap to clone; every clone sends to the same [`WorldRequests`].
+#[derive(Clone)]
+pub struct WorldChannel {
+    tx: Sender<WorldRequest>,
+}
+
+/// The frame thread's end of a [`WorldChannel`].
+pub struct WorldRequests {
+    rx: Receiver<WorldRequest>,
+}
+
+/// Creates the two ends of a world channel.
+pub fn world_channel() -> (WorldChannel, WorldRequests) {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    (WorldChannel { tx }, WorldRequests { rx })
+}
+
+impl WorldChannel {
+    /// Runs `f` against the world on the frame thread and returns its
+    /// result.
+    ///
+    /// Blocks until the frame thread gets to the request. Returns `None` if
+    /// the frame thread dropped it without running it.
+    pub fn with_world<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World) -> R + Send + 'static,
+    ) -> Option<R> {
+        let (reply_tx, reply_rx) = crossbeam_channel::bounded(1);
+        let request: WorldRequest = Box::new(move |world| {
+            let _ = reply_tx.send(f(world));
+        });
+        self.tx.send(request).ok()?;
+        reply_rx.recv().ok()
+    }
+}
+
+impl WorldRequests {
+    /// The receiving end, for callers that wait on it alongside other
+    /// channels.
+    pub fn receiver(&self) -> &Receiver<WorldRequest> {
+        &self.rx
+    }
+
+    /// Runs every pending request against `world`, in the order they were
+    /// sent. Returns the number of requests run.
+    pub fn serve(&self, world: &mut World) -> usize {
+        let mut served = 0;
+        while let Ok(request) = self.rx.try_recv() {
+            request(world);
+            served += 1;
+        }
+        served
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::Transform;
+
+    #[test]
+    fn requests_run_on_the_serving_thread() {
+        let (channel, requests) = world_channel();
+        let mut world = World::new();
+
+        let worker = std::thread::spawn(move || {
+            channel.with_world(|world| world.spawn(Transform::identity()))
+        });
+        while !worker.is_finished() {
+            requests.serve(&mut world);
+            std::thread::yield_now();
+        }
+
+        let entity = worker.join().unwrap().expect("request answered");
+        assert!(world.get::<Transform>(entity).is_some());
+    }
+
+    #[test]
+    fn dropped_requests_answer_none() {
+        let (channel, requests) = world_channel();
+        drop(requests);
+        assert_eq!(channel.with_world(|_| 1), None);
+    }
+}
@@ -43,6 +43,7 @@ mod registration;
 pub mod render;
 mod selection;
 pub mod shadow;
+pub mod simulation_lod;
 pub mod ui;
 
 pub use audio::{AudioFlow, AudioView};
@@ -51,6 +52,7 @@ pub use registration::*;
 pub use render::RenderFlow;
 pub use selection::Selection;
 pub use shadow::{ShadowFlow, ShadowView};
+pub use simulation_lod::{SimulationLodFlow, SimulationLodSettings, SimulationLodView};
 pub use ui::UiFlow;
 
 use khora_core::control::gorna::ResourceBudget;
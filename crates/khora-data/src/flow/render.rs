@@ -20,23 +20,39 @@
 //! project) and AGDF-ready hooks for future per-domain adaptation (LOD,
 //! frustum culling, etc.).
 
+use std::sync::Mutex;
+
 use khora_core::{
-    math::{Mat4, Vec3},
-    renderer::{api::scene::GpuMesh, light::LightType},
+    asset::{AsAny, Material},
+    ecs::entity::EntityId,
+    math::{Aabb, AffineTransform, Mat4, Vec3, Vec4, EPSILON},
+    renderer::{
+        api::{core::DepthMode, scene::GpuMesh},
+        light::LightType,
+    },
     ServiceRegistry,
 };
 
 use crate::ecs::{
-    Camera, GlobalTransform, HandleComponent, Light, MaterialComponent, SemanticDomain, World,
+    AntiAliasing, AutoExposure, Bounds, Camera, Exposure, GlobalTransform, HandleComponent, Light,
+    MaterialComponent, MaterialOverride, Parent, PhysicsInterpolation, PostProcess, RenderLayers,
+    SemanticDomain, Skin, SkinnedMesh, Sky, Static, World,
 };
 use crate::flow::{Flow, Selection};
 use crate::register_flow;
-use crate::render::{ExtractedLight, ExtractedMesh, ExtractedView, RenderWorld};
+use crate::render::{
+    fold_id, ExtractedLight, ExtractedMesh, ExtractedSkin, ExtractedView, PreviousFrame,
+    RenderWorld, SortKey,
+};
 
 /// Projects the ECS World into the per-frame [`RenderWorld`] consumed by the
 /// render lanes.
 #[derive(Default)]
-pub struct RenderFlow;
+pub struct RenderFlow {
+    /// State of the last projected frame, handed to the next one so lanes
+    /// can compute motion vectors.
+    history: Mutex<PreviousFrame>,
+}
 
 impl Flow for RenderFlow {
     type View = RenderWorld;
@@ -48,7 +64,12 @@ impl Flow for RenderFlow {
         let mut rw = RenderWorld::new();
         extract_meshes(world, &mut rw);
         extract_lights(world, &mut rw);
-        extract_views(world, &mut rw);
+        rw.sky = world
+            .query::<(EntityId, &Sky)>()
+            .find(|(entity, _)| world.is_visible(*entity))
+            .map(|(_, sky)| sky.color);
+        let depth_mode = services.get::<DepthMode>().copied().unwrap_or_default();
+        extract_views(world, depth_mode, &mut rw);
 
         // No active scene Camera (e.g. editor in Editing mode where every
         // scene Camera is forced inactive)? Fall back to the shared
@@ -58,6 +79,14 @@ impl Flow for RenderFlow {
                 rw.views.push(view);
             }
         }
+        rw.cull_layers();
+        estimate_depth_complexity(world, &mut rw);
+        rw.resolve_skinning();
+        assign_sort_keys(&mut rw);
+        rw.sort_meshes();
+        if let Ok(mut history) = self.history.lock() {
+            rw.previous = std::mem::replace(&mut *history, PreviousFrame::capture(&rw));
+        }
         rw
     }
 }
@@ -65,35 +94,169 @@ impl Flow for RenderFlow {
 register_flow!(RenderFlow);
 
 fn extract_meshes(world: &World, render_world: &mut RenderWorld) {
-    let query = world.query::<(&GlobalTransform, &HandleComponent<GpuMesh>)>();
-    for (entity_id, (transform, gpu_mesh_handle)) in query.enumerate() {
-        let material = world
-            .query::<&MaterialComponent>()
-            .nth(entity_id)
-            .map(|m| m.handle.clone());
+    let query = world.query::<(EntityId, &GlobalTransform, &HandleComponent<GpuMesh>)>();
+    for (entity, transform, gpu_mesh_handle) in query {
+        // Hidden, or drawn by its static batch instead.
+        if !world.is_visible(entity)
+            || world
+                .get::<Static>(entity)
+                .is_some_and(|s| s.batched_into().is_some())
+        {
+            continue;
+        }
+        let material_component = world.get::<MaterialComponent>(entity);
+        let material = material_component.map(|m| m.handle.clone());
+        let material_uuid = material_component.map(|m| m.uuid);
+        let material_override = world
+            .get::<MaterialOverride>(entity)
+            .filter(|o| !o.is_empty())
+            .copied();
 
         render_world.meshes.push(ExtractedMesh {
-            transform: transform.0,
+            entity: Some(entity),
+            transform: rendered_transform(world, entity, transform),
             cpu_mesh_uuid: gpu_mesh_handle.uuid,
             gpu_mesh: gpu_mesh_handle.handle.clone(),
             material,
+            material_uuid,
+            material_override,
+            sort_key: SortKey::default(),
+            layers: world
+                .get::<RenderLayers>(entity)
+                .copied()
+                .unwrap_or_default(),
+            skin: extract_skin(world, entity, gpu_mesh_handle),
+        });
+    }
+}
+
+/// Skinning inputs of `entity`, once `skin_sync` has pointed its GPU mesh
+/// at the skin output. The mode is resolved later by
+/// [`RenderWorld::resolve_skinning`].
+fn extract_skin(
+    world: &World,
+    entity: EntityId,
+    gpu_mesh: &HandleComponent<GpuMesh>,
+) -> Option<ExtractedSkin> {
+    let skin = world.get::<Skin>(entity)?;
+    let skinned = world
+        .get::<SkinnedMesh>(entity)
+        .filter(|s| s.uuid == gpu_mesh.uuid)?;
+    Some(ExtractedSkin {
+        mode: skin.mode.unwrap_or_default(),
+        forced_mode: skin.mode,
+        passes: 1,
+        bind_pose: skinned.bind_pose,
+        influences: skinned.influences,
+        joint_matrices: skinned.joint_matrices,
+        layout: skinned.layout,
+    })
+}
+
+/// Keys every mesh by material type, material, mesh and distance to the
+/// primary view, so opaque draws sort into batches ordered front to back.
+fn assign_sort_keys(render_world: &mut RenderWorld) {
+    let eye = render_world
+        .views
+        .first()
+        .map(|v| v.position)
+        .unwrap_or(Vec3::ZERO);
+    for mesh in &mut render_world.meshes {
+        let pipeline = mesh.material.as_ref().map_or(0, |m| {
+            let material: &dyn Material = &***m;
+            fold_id(&AsAny::as_any(material).type_id())
         });
+        let material = mesh.material_uuid.as_ref().map_or(0, fold_id);
+        let depth = (mesh.transform.translation() - eye).length();
+        mesh.sort_key = SortKey::opaque(pipeline, material, fold_id(&mesh.cpu_mesh_uuid), depth);
+    }
+}
+
+/// Estimates the first view's overdraw as the summed screen coverage of the
+/// opaque meshes' world bounds. Coarse, since boxes overstate their meshes
+/// and occluded boxes count too, but cheap and stable frame to frame.
+fn estimate_depth_complexity(world: &World, render_world: &mut RenderWorld) {
+    let Some(view) = render_world.views.first() else {
+        return;
+    };
+    render_world.depth_complexity = render_world
+        .meshes
+        .iter()
+        .filter(|m| m.layers.intersects(view.layers) && m.is_opaque())
+        .filter_map(|m| world.get::<Bounds>(m.entity?)?.mesh())
+        .map(|aabb| screen_coverage(&aabb, &view.view_proj))
+        .sum();
+}
+
+/// Fraction of the screen, in `[0, 1]`, covered by the rectangle around
+/// `aabb` projected through `view_proj`.
+fn screen_coverage(aabb: &Aabb, view_proj: &Mat4) -> f32 {
+    let (mut min_x, mut min_y) = (f32::MAX, f32::MAX);
+    let (mut max_x, mut max_y) = (f32::MIN, f32::MIN);
+    let mut behind = 0;
+    for i in 0..8 {
+        let corner = Vec3::new(
+            if i & 1 == 0 { aabb.min.x } else { aabb.max.x },
+            if i & 2 == 0 { aabb.min.y } else { aabb.max.y },
+            if i & 4 == 0 { aabb.min.z } else { aabb.max.z },
+        );
+        let clip = *view_proj * Vec4::from_vec3(corner, 1.0);
+        if clip.w <= EPSILON {
+            behind += 1;
+            continue;
+        }
+        min_x = min_x.min(clip.x / clip.w);
+        min_y = min_y.min(clip.y / clip.w);
+        max_x = max_x.max(clip.x / clip.w);
+        max_y = max_y.max(clip.y / clip.w);
+    }
+    match behind {
+        8 => return 0.0,
+        // The box straddles the eye: assume it fills the screen.
+        1.. => return 1.0,
+        _ => {}
     }
+    let width = max_x.min(1.0) - min_x.max(-1.0);
+    let height = max_y.min(1.0) - min_y.max(-1.0);
+    if width <= 0.0 || height <= 0.0 {
+        return 0.0;
+    }
+    width * height / 4.0
+}
+
+/// The transform `entity` is drawn with: its global transform, moved by the
+/// [`PhysicsInterpolation`] of the closest interpolated body among itself and
+/// its ancestors, so bodies and what they carry move smoothly between ticks.
+fn rendered_transform(
+    world: &World,
+    entity: EntityId,
+    global_transform: &GlobalTransform,
+) -> AffineTransform {
+    let mut current = Some(entity);
+    while let Some(id) = current {
+        if let Some(interpolation) = world.get::<PhysicsInterpolation>(id) {
+            return match interpolation.correction() {
+                Some(correction) => AffineTransform(correction * global_transform.0 .0),
+                None => global_transform.0,
+            };
+        }
+        current = world.get::<Parent>(id).map(|parent| parent.0);
+    }
+    global_transform.0
 }
 
 fn extract_lights(world: &World, render_world: &mut RenderWorld) {
-    let light_query = world.query::<(&Light, &GlobalTransform)>();
-    for (light_comp, global_transform) in light_query {
-        if !light_comp.enabled {
+    let light_query = world.query::<(EntityId, &Light, &GlobalTransform)>();
+    for (entity, light_comp, global_transform) in light_query {
+        if !light_comp.enabled || !world.is_visible(entity) {
             continue;
         }
+        let transform = rendered_transform(world, entity, global_transform);
 
-        let position = global_transform.0.translation();
+        let position = transform.translation();
         let direction = match &light_comp.light_type {
-            LightType::Directional(dir_light) => {
-                global_transform.0.rotation() * dir_light.direction
-            }
-            LightType::Spot(spot_light) => global_transform.0.rotation() * spot_light.direction,
+            LightType::Directional(dir_light) => transform.rotation() * dir_light.direction,
+            LightType::Spot(spot_light) => transform.rotation() * spot_light.direction,
             LightType::Point(_) => Vec3::ZERO,
         };
 
@@ -103,29 +266,82 @@ fn extract_lights(world: &World, render_world: &mut RenderWorld) {
             direction,
             shadow_view_proj: Mat4::IDENTITY,
             shadow_atlas_index: None,
+            layers: world
+                .get::<RenderLayers>(entity)
+                .copied()
+                .unwrap_or(RenderLayers::ALL),
         });
     }
 }
 
-fn extract_views(world: &World, render_world: &mut RenderWorld) {
-    let camera_query = world.query::<(&Camera, &GlobalTransform)>();
-    for (camera, global_transform) in camera_query {
-        if !camera.is_active {
+fn extract_views(world: &World, depth_mode: DepthMode, render_world: &mut RenderWorld) {
+    let camera_query = world.query::<(EntityId, &Camera, &GlobalTransform)>();
+    for (entity, camera, global_transform) in camera_query {
+        if !camera.is_active || !world.is_enabled(entity) {
             continue;
         }
+        let transform = rendered_transform(world, entity, global_transform);
 
-        let position = global_transform.0.translation();
-        let rotation = global_transform.0.rotation();
+        let position = transform.translation();
+        let rotation = transform.rotation();
 
         let rotation_matrix = Mat4::from_quat(rotation.inverse());
         let translation_matrix = Mat4::from_translation(-position);
         let view_matrix = rotation_matrix * translation_matrix;
-        let proj_matrix = camera.projection_matrix();
+        let proj_matrix = camera.projection_matrix_for(depth_mode);
         let view_proj = proj_matrix * view_matrix;
 
+        if render_world.views.is_empty() {
+            render_world.auto_exposure = world.get::<AutoExposure>(entity).copied();
+            render_world.exposure = world.get::<Exposure>(entity).copied();
+            render_world.anti_aliasing = world.get::<AntiAliasing>(entity).copied();
+            render_world.post_process = world.get::<PostProcess>(entity).copied();
+        }
         render_world.views.push(ExtractedView {
             view_proj,
             position,
+            layers: world
+                .get::<RenderLayers>(entity)
+                .copied()
+                .unwrap_or(RenderLayers::ALL),
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box_at(center: Vec3) -> Aabb {
+        Aabb::from_center_half_extents(center, Vec3::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn test_screen_coverage_measures_the_projected_rectangle() {
+        // Identity maps the [-1, 1] square onto the whole screen.
+        assert!((screen_coverage(&unit_box_at(Vec3::ZERO), &Mat4::IDENTITY) - 1.0).abs() < 1e-6);
+
+        let half = Aabb::from_min_max(Vec3::new(0.0, -1.0, 0.0), Vec3::new(1.0, 1.0, 0.5));
+        assert!((screen_coverage(&half, &Mat4::IDENTITY) - 0.5).abs() < 1e-6);
+
+        // Clamped to the screen, then nothing left.
+        let beside = unit_box_at(Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(screen_coverage(&beside, &Mat4::IDENTITY), 0.0);
+    }
+
+    #[test]
+    fn test_screen_coverage_of_boxes_behind_the_eye() {
+        // Right-handed: the camera at the origin looks down -Z.
+        let projection = Mat4::perspective_rh_zo(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 100.0);
+
+        let behind = unit_box_at(Vec3::new(0.0, 0.0, 10.0));
+        assert_eq!(screen_coverage(&behind, &projection), 0.0);
+
+        let around = unit_box_at(Vec3::ZERO);
+        assert_eq!(screen_coverage(&around, &projection), 1.0);
+
+        let ahead = unit_box_at(Vec3::new(0.0, 0.0, -20.0));
+        let coverage = screen_coverage(&ahead, &projection);
+        assert!(coverage > 0.0 && coverage < 0.1);
+    }
+}
@@ -25,19 +25,38 @@
 //! Light indices in [`ShadowView::matrices`] match light positions in
 //! `RenderWorld.lights`: both are produced by iterating
 //! `world.query::<(&Light, &GlobalTransform)>()` and skipping disabled
-//! lights, in the same order. The shadow lane and the lit lanes therefore
+//! and hidden lights, in the same order. The shadow lane and the lit lanes therefore
 //! agree on which `i` refers to which light.
+//!
+//! # Cascades
+//!
+//! Directional lights also get [`ShadowCascades`]: the camera frustum,
+//! clamped to [`CASCADE_SHADOW_DISTANCE`], is cut into
+//! [`MAX_SHADOW_CASCADES`] slices and each slice gets its own orthographic
+//! fit. The `CascadedShadowLane` renders them; the single-map
+//! `ShadowPassLane` ignores them and uses [`ShadowView::matrices`].
 
 use std::collections::HashMap;
 
+use khora_core::ecs::entity::EntityId;
 use khora_core::math::{Mat4, Vec3, Vec4};
+use khora_core::renderer::api::scene::MAX_SHADOW_CASCADES;
 use khora_core::renderer::light::LightType;
 use khora_core::ServiceRegistry;
 
 use crate::ecs::{GlobalTransform, Light, SemanticDomain, World};
 use crate::flow::{Flow, Selection};
 use crate::register_flow;
-use crate::render::{primary_view, ExtractedView};
+use crate::render::{primary_view, ExtractedView, ShadowCascades};
+
+/// How far from the camera, in meters, directional shadow cascades reach.
+pub const CASCADE_SHADOW_DISTANCE: f32 = 150.0;
+
+/// Blend between logarithmic (1.0) and uniform (0.0) cascade splits.
+const CASCADE_SPLIT_LAMBDA: f32 = 0.75;
+
+/// Resolution of one shadow atlas layer, used for texel snapping.
+const SHADOW_MAP_SIZE: f32 = 2048.0;
 
 /// Output of [`ShadowFlow`].
 #[derive(Debug, Default, Clone)]
@@ -47,6 +66,9 @@ pub struct ShadowView {
     /// Per-light view-projection matrices, keyed by the light's position in
     /// `RenderWorld.lights`. Only shadow-casting lights have an entry.
     pub matrices: HashMap<usize, Mat4>,
+    /// Per-light cascades of the shadow-casting directional lights, keyed
+    /// like [`matrices`](Self::matrices).
+    pub cascades: HashMap<usize, ShadowCascades>,
 }
 
 /// Computes shadow view-projection matrices.
@@ -63,11 +85,12 @@ impl Flow for ShadowFlow {
     fn project(&self, world: &World, _sel: &Selection, services: &ServiceRegistry) -> Self::View {
         let camera_view = primary_view(world, services);
         let mut matrices = HashMap::new();
+        let mut cascades = HashMap::new();
         let mut light_count = 0;
 
         // Mirror RenderFlow's iteration so indices align across views.
-        for (light, transform) in world.query::<(&Light, &GlobalTransform)>() {
-            if !light.enabled {
+        for (entity, light, transform) in world.query::<(EntityId, &Light, &GlobalTransform)>() {
+            if !light.enabled || !world.is_visible(entity) {
                 continue;
             }
             let light_index = light_count;
@@ -96,11 +119,17 @@ impl Flow for ShadowFlow {
             let view_proj =
                 compute_shadow_view_proj(&light.light_type, position, direction, camera);
             matrices.insert(light_index, view_proj);
+            if matches!(light.light_type, LightType::Directional(_)) {
+                if let Some(c) = directional_shadow_cascades(direction, camera) {
+                    cascades.insert(light_index, c);
+                }
+            }
         }
 
         ShadowView {
             light_count,
             matrices,
+            cascades,
         }
     }
 }
@@ -137,16 +166,7 @@ fn compute_shadow_view_proj(
 /// so behaviour is identical — the only change is *where* it lives.
 fn directional_shadow_view_proj(direction: Vec3, camera: &ExtractedView) -> Mat4 {
     // 1. Camera frustum corners in world space.
-    let inv_view_proj = camera.view_proj.inverse().unwrap_or(Mat4::IDENTITY);
-    let mut corners = Vec::with_capacity(8);
-    for x in &[-1.0, 1.0] {
-        for y in &[-1.0, 1.0] {
-            for z in &[0.0, 1.0] {
-                let pt = inv_view_proj * Vec4::new(*x, *y, *z, 1.0);
-                corners.push(pt.truncate() / pt.w);
-            }
-        }
-    }
+    let corners = frustum_corners(camera);
 
     // 2. Light view matrix centered on frustum center.
     let light_dir = direction.normalize();
@@ -199,3 +219,181 @@ fn directional_shadow_view_proj(direction: Vec3, camera: &ExtractedView) -> Mat4
 
     light_proj * light_view
 }
+
+/// World-space corners of the camera frustum, in `(x, y, z)` NDC order
+/// with `z` innermost.
+fn frustum_corners(camera: &ExtractedView) -> Vec<Vec3> {
+    let inv_view_proj = camera.view_proj.inverse().unwrap_or(Mat4::IDENTITY);
+    let mut corners = Vec::with_capacity(8);
+    for x in &[-1.0, 1.0] {
+        for y in &[-1.0, 1.0] {
+            for z in &[0.0, 1.0] {
+                let mut pt = inv_view_proj * Vec4::new(*x, *y, *z, 1.0);
+                // An infinite far plane unprojects to w = 0; pull the corner
+                // just inside the depth range so the frustum stays bounded.
+                if pt.w.abs() < 1e-6 {
+                    let z = if *z > 0.5 { 0.999 } else { 0.001 };
+                    pt = inv_view_proj * Vec4::new(*x, *y, z, 1.0);
+                }
+                corners.push(pt.truncate() / pt.w);
+            }
+        }
+    }
+    corners
+}
+
+/// Splits the camera frustum into [`MAX_SHADOW_CASCADES`] slices and fits
+/// a texel-snapped orthographic projection around each one.
+///
+/// Each slice is bounded by a sphere rather than a box, so the projection
+/// keeps its size while the camera turns and edges do not swim.
+fn directional_shadow_cascades(direction: Vec3, camera: &ExtractedView) -> Option<ShadowCascades> {
+    let corners = frustum_corners(camera);
+
+    // The frustum edges as (near, far) corner pairs; reversed-Z swaps the
+    // NDC depth of the two planes, so sort each pair by view depth.
+    let forward = {
+        let near_center = (corners[0] + corners[2] + corners[4] + corners[6]) / 4.0;
+        let far_center = (corners[1] + corners[3] + corners[5] + corners[7]) / 4.0;
+        (far_center - near_center).normalize()
+    };
+    let depth_of = |p: Vec3| (p - camera.position).dot(forward);
+    let mut edges = [(Vec3::ZERO, Vec3::ZERO); 4];
+    for (i, edge) in edges.iter_mut().enumerate() {
+        let (a, b) = (corners[i * 2], corners[i * 2 + 1]);
+        *edge = if depth_of(a) <= depth_of(b) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+    }
+
+    let near = depth_of(edges[0].0);
+    let frustum_far = depth_of(edges[0].1);
+    let far = frustum_far.min(CASCADE_SHADOW_DISTANCE);
+    if !near.is_finite() || !far.is_finite() || far <= near || near <= 0.0 {
+        return None;
+    }
+
+    // Corners along each edge are linear in view depth.
+    let slice_corners = |depth: f32| {
+        let t = (depth - near) / (frustum_far - near);
+        edges.map(|(n, f)| Vec3::lerp(n, f, t))
+    };
+
+    let light_dir = direction.normalize();
+    let up = if light_dir.y.abs() > 0.99 {
+        Vec3::Z
+    } else {
+        Vec3::Y
+    };
+    let light_rotation = Mat4::look_at_rh(Vec3::ZERO, light_dir, up)?;
+
+    let mut cascades = ShadowCascades {
+        count: MAX_SHADOW_CASCADES,
+        view_proj: [Mat4::IDENTITY; MAX_SHADOW_CASCADES],
+        splits: [far; MAX_SHADOW_CASCADES],
+        texel_sizes: [1.0; MAX_SHADOW_CASCADES],
+    };
+    let mut slice_near = near;
+    for c in 0..MAX_SHADOW_CASCADES {
+        cascades.splits[c] = cascade_split(near, far, c + 1);
+        let slice_far = cascades.splits[c];
+
+        let points = [slice_corners(slice_near), slice_corners(slice_far)];
+        let points = points.iter().flatten();
+        let mut center = Vec3::ZERO;
+        for p in points.clone() {
+            center = center + *p;
+        }
+        center = center / 8.0;
+        let mut radius = points.map(|p| p.distance(center)).fold(0.0_f32, f32::max);
+        // Quantize the radius so the projection size is stable frame to frame.
+        radius = (radius * 16.0).ceil() / 16.0;
+
+        // Snap the center to whole texels in light space.
+        let texel = radius * 2.0 / SHADOW_MAP_SIZE;
+        let c_ls = light_rotation * Vec4::from_vec3(center, 1.0);
+        let cx = (c_ls.x / texel).floor() * texel;
+        let cy = (c_ls.y / texel).floor() * texel;
+
+        // Light space looks down -Z; pad towards the light for casters
+        // outside the slice.
+        let z_padding = 100.0;
+        let light_proj = Mat4::orthographic_rh_zo(
+            cx - radius,
+            cx + radius,
+            cy - radius,
+            cy + radius,
+            -(c_ls.z + radius) - z_padding,
+            radius - c_ls.z,
+        );
+        cascades.view_proj[c] = light_proj * light_rotation;
+        cascades.texel_sizes[c] = texel;
+        slice_near = slice_far;
+    }
+
+    Some(cascades)
+}
+
+/// View depth where cascade `index` (1-based) ends, from the practical
+/// split scheme: a blend of logarithmic and uniform splits.
+fn cascade_split(near: f32, far: f32, index: usize) -> f32 {
+    let f = index as f32 / MAX_SHADOW_CASCADES as f32;
+    let log = near * (far / near).powf(f);
+    let uniform = near + (far - near) * f;
+    CASCADE_SPLIT_LAMBDA * log + (1.0 - CASCADE_SPLIT_LAMBDA) * uniform
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::RenderLayers;
+
+    fn camera_looking_down_neg_z() -> ExtractedView {
+        let projection = Mat4::perspective_rh_zo(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 1000.0);
+        ExtractedView {
+            view_proj: projection,
+            position: Vec3::ZERO,
+            layers: RenderLayers::ALL,
+        }
+    }
+
+    #[test]
+    fn test_cascade_splits_grow_up_to_the_shadow_distance() {
+        let cascades =
+            directional_shadow_cascades(Vec3::new(0.3, -1.0, 0.2), &camera_looking_down_neg_z())
+                .unwrap();
+
+        assert_eq!(cascades.count, MAX_SHADOW_CASCADES);
+        for c in 1..MAX_SHADOW_CASCADES {
+            assert!(cascades.splits[c] > cascades.splits[c - 1]);
+            assert!(cascades.texel_sizes[c] > cascades.texel_sizes[c - 1]);
+        }
+        let last = cascades.splits[MAX_SHADOW_CASCADES - 1];
+        assert!((last - CASCADE_SHADOW_DISTANCE).abs() < 1e-2);
+        assert_eq!(cascades.cascade_at(0.5), Some(0));
+        assert_eq!(cascades.cascade_at(CASCADE_SHADOW_DISTANCE + 1.0), None);
+    }
+
+    #[test]
+    fn test_each_cascade_contains_its_slice_of_the_view() {
+        let camera = camera_looking_down_neg_z();
+        let cascades = directional_shadow_cascades(Vec3::new(0.3, -1.0, 0.2), &camera).unwrap();
+
+        // A point on the view axis at the middle of each slice lands
+        // inside that cascade's shadow map.
+        let mut slice_near = 0.1;
+        for c in 0..cascades.count {
+            let depth = (slice_near + cascades.splits[c]) * 0.5;
+            let clip = cascades.view_proj[c] * Vec4::new(0.0, 0.0, -depth, 1.0);
+            let ndc = clip.truncate() / clip.w;
+            assert!(
+                ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0,
+                "cascade {c}: {ndc:?}"
+            );
+            assert!((0.0..=1.0).contains(&ndc.z), "cascade {c}: {ndc:?}");
+            slice_near = cascades.splits[c];
+        }
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `SimulationLodFlow` — simulation level of detail by distance.
+//!
+//! Sorts every simulated entity into a [`SimulationBand`] from its distance
+//! to the nearest [`SimulationAnchor`] or active camera. Physics puts
+//! dormant bodies to sleep, animation pauses dormant entities and ticks
+//! reduced ones every few frames, and game systems read the same
+//! [`SimulationLod`] component.
+//!
+//! The flow sits in the physics domain, so it receives the physics agent's
+//! GORNA budget: under a low-power strategy the bands shrink and more of
+//! the world slows down; with headroom they grow.
+
+use khora_core::control::gorna::{ResourceBudget, StrategyId};
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Vec3;
+use khora_core::ServiceRegistry;
+
+use crate::ecs::{
+    AnimationPlayer, Animator, Camera, GlobalTransform, RigidBody, SemanticDomain,
+    SimulationAnchor, SimulationBand, SimulationLod, Without, World,
+};
+use crate::flow::{Flow, Selection};
+use crate::register_flow;
+
+/// Distance bands of simulation LOD. Register it as a service to override
+/// the defaults.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationLodSettings {
+    /// Entities closer than this to an anchor are updated every frame.
+    pub full_distance: f32,
+    /// Entities closer than this are updated every `reduced_interval`
+    /// frames; further ones are dormant.
+    pub reduced_distance: f32,
+    /// Frames between two updates in the reduced band.
+    pub reduced_interval: u32,
+}
+
+impl Default for SimulationLodSettings {
+    fn default() -> Self {
+        Self {
+            full_distance: 30.0,
+            reduced_distance: 80.0,
+            reduced_interval: 4,
+        }
+    }
+}
+
+/// View published by [`SimulationLodFlow`]: how many entities sit in each
+/// band, for telemetry and editor panels.
+#[derive(Debug, Default, Clone)]
+pub struct SimulationLodView {
+    /// Entities updated every frame.
+    pub full: usize,
+    /// Entities updated every few frames.
+    pub reduced: usize,
+    /// Entities not updated.
+    pub dormant: usize,
+    /// Factor the budget applied to the band distances.
+    pub distance_scale: f32,
+}
+
+/// Assigns every simulated entity its [`SimulationLod`].
+#[derive(Default)]
+pub struct SimulationLodFlow {
+    /// Factor applied to the band distances during the last `adapt`.
+    distance_scale: f32,
+}
+
+impl Flow for SimulationLodFlow {
+    type View = SimulationLodView;
+
+    const DOMAIN: SemanticDomain = SemanticDomain::Physics;
+    const NAME: &'static str = "simulation_lod";
+
+    fn adapt(
+        &mut self,
+        world: &mut World,
+        _sel: &Selection,
+        budget: &ResourceBudget,
+        services: &ServiceRegistry,
+    ) {
+        let settings = services
+            .get::<SimulationLodSettings>()
+            .copied()
+            .unwrap_or_default();
+        self.distance_scale = distance_scale(budget);
+        let anchors = anchors(world);
+        let scale = self.distance_scale;
+        let band_of = |position: Vec3| classify(position, &anchors, &settings, scale);
+
+        // Entities that already carry the component.
+        for (global, lod) in world.query_mut::<(&GlobalTransform, &mut SimulationLod)>() {
+            let (band, distance) = band_of(global.0.translation());
+            lod.band = band;
+            lod.distance = distance;
+            lod.interval = settings.reduced_interval.max(1);
+            lod.frame = lod.frame.wrapping_add(1);
+        }
+
+        // Simulated entities seen for the first time.
+        let mut fresh: Vec<(EntityId, Vec3)> = Vec::new();
+        fresh.extend(
+            world
+                .query::<(
+                    EntityId,
+                    &GlobalTransform,
+                    &RigidBody,
+                    Without<SimulationLod>,
+                )>()
+                .map(|(entity, global, _, _)| (entity, global.0.translation())),
+        );
+        fresh.extend(
+            world
+                .query::<(
+                    EntityId,
+                    &GlobalTransform,
+                    &AnimationPlayer,
+                    Without<SimulationLod>,
+                )>()
+                .map(|(entity, global, _, _)| (entity, global.0.translation())),
+        );
+        fresh.extend(
+            world
+                .query::<(
+                    EntityId,
+                    &GlobalTransform,
+                    &Animator,
+                    Without<SimulationLod>,
+                )>()
+                .map(|(entity, global, _, _)| (entity, global.0.translation())),
+        );
+        fresh.sort_by_key(|(entity, _)| (entity.index, entity.generation));
+        fresh.dedup_by_key(|(entity, _)| *entity);
+        for (entity, position) in fresh {
+            let (band, distance) = band_of(position);
+            let interval = settings.reduced_interval.max(1);
+            let _ = world.add_component(
+                entity,
+                SimulationLod {
+                    band,
+                    distance,
+                    interval,
+                    // Spread reduced entities over the interval.
+                    frame: entity.index % interval,
+                },
+            );
+        }
+    }
+
+    fn project(&self, world: &World, _sel: &Selection, _services: &ServiceRegistry) -> Self::View {
+        let mut view = SimulationLodView {
+            distance_scale: self.distance_scale,
+            ..Default::default()
+        };
+        for lod in world.query::<&SimulationLod>() {
+            match lod.band {
+                SimulationBand::Full => view.full += 1,
+                SimulationBand::Reduced => view.reduced += 1,
+                SimulationBand::Dormant => view.dormant += 1,
+            }
+        }
+        view
+    }
+}
+
+register_flow!(SimulationLodFlow);
+
+/// How far the budget stretches the band distances.
+fn distance_scale(budget: &ResourceBudget) -> f32 {
+    match budget.strategy_id {
+        StrategyId::LowPower => 0.5,
+        StrategyId::HighPerformance => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Positions and range scales of the enabled anchors and active cameras.
+fn anchors(world: &World) -> Vec<(Vec3, f32)> {
+    let mut anchors: Vec<(Vec3, f32)> = world
+        .query::<(EntityId, &SimulationAnchor, &GlobalTransform)>()
+        .filter(|(entity, _, _)| world.is_enabled(*entity))
+        .map(|(_, anchor, global)| (global.0.translation(), anchor.range_scale))
+        .collect();
+    anchors.extend(
+        world
+            .query::<(EntityId, &Camera, &GlobalTransform)>()
+            .filter(|(entity, camera, _)| camera.is_active && world.is_enabled(*entity))
+            .map(|(_, _, global)| (global.0.translation(), 1.0)),
+    );
+    anchors
+}
+
+/// Band and scaled distance of an entity at `position`. Without any anchor,
+/// everything runs at full rate.
+fn classify(
+    position: Vec3,
+    anchors: &[(Vec3, f32)],
+    settings: &SimulationLodSettings,
+    scale: f32,
+) -> (SimulationBand, f32) {
+    let Some(distance) = anchors
+        .iter()
+        .map(|(anchor, range)| (position - *anchor).length() / range.max(f32::EPSILON))
+        .reduce(f32::min)
+    else {
+        return (SimulationBand::Full, 0.0);
+    };
+    let band = if distance < settings.full_distance * scale {
+        SimulationBand::Full
+    } else if distance < settings.reduced_distance * scale {
+        SimulationBand::Reduced
+    } else {
+        SimulationBand::Dormant
+    };
+    (band, distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::Transform;
+
+    fn budget(strategy_id: StrategyId) -> ResourceBudget {
+        ResourceBudget {
+            strategy_id,
+            time_limit: std::time::Duration::from_millis(4),
+            memory_limit: None,
+            extra_params: Default::default(),
+        }
+    }
+
+    fn body_at(world: &mut World, x: f32) -> EntityId {
+        world.spawn((
+            Transform::identity(),
+            GlobalTransform::at_position(Vec3::new(x, 0.0, 0.0)),
+            RigidBody::new_dynamic(1.0),
+        ))
+    }
+
+    #[test]
+    fn test_bands_follow_the_distance_to_anchors() {
+        let mut world = World::new();
+        world.spawn((SimulationAnchor::default(), GlobalTransform::identity()));
+        let near = body_at(&mut world, 10.0);
+        let mid = body_at(&mut world, 50.0);
+        let far = body_at(&mut world, 200.0);
+
+        let mut flow = SimulationLodFlow::default();
+        let services = ServiceRegistry::new();
+        let sel = Selection::new();
+        flow.adapt(&mut world, &sel, &budget(StrategyId::Balanced), &services);
+
+        let band = |e| world.get::<SimulationLod>(e).unwrap().band;
+        assert_eq!(band(near), SimulationBand::Full);
+        assert_eq!(band(mid), SimulationBand::Reduced);
+        assert_eq!(band(far), SimulationBand::Dormant);
+
+        let view = flow.project(&world, &sel, &services);
+        assert_eq!((view.full, view.reduced, view.dormant), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_low_power_budget_tightens_the_bands() {
+        let mut world = World::new();
+        world.spawn((SimulationAnchor::default(), GlobalTransform::identity()));
+        let body = body_at(&mut world, 20.0);
+
+        let mut flow = SimulationLodFlow::default();
+        let services = ServiceRegistry::new();
+        let sel = Selection::new();
+        flow.adapt(&mut world, &sel, &budget(StrategyId::LowPower), &services);
+        assert_eq!(
+            world.get::<SimulationLod>(body).unwrap().band,
+            SimulationBand::Reduced
+        );
+    }
+
+    #[test]
+    fn test_reduced_entities_catch_up_on_skipped_frames() {
+        let lod = SimulationLod {
+            band: SimulationBand::Reduced,
+            distance: 50.0,
+            interval: 4,
+            frame: 0,
+        };
+        assert_eq!(lod.delta(0.01), Some(0.04));
+        let skipped = SimulationLod { frame: 1, ..lod };
+        assert_eq!(skipped.delta(0.01), None);
+        assert_eq!(SimulationLod::delta_of(None, 0.01), Some(0.01));
+    }
+}
@@ -34,7 +34,7 @@ use crate::assets::Assets;
 use crate::ecs::{SemanticDomain, World};
 use crate::flow::{Flow, Selection};
 use crate::register_flow;
-use crate::ui::components::{UiBorder, UiColor, UiImage, UiText, UiTransform};
+use crate::ui::components::{UiBorder, UiColor, UiImage, UiNineSlice, UiText, UiTransform};
 use crate::ui::{ExtractedUiNode, ExtractedUiText, UiScene};
 
 /// UI presentation Flow.
@@ -83,14 +83,16 @@ fn extract_nodes(world: &World, scene: &mut UiScene) {
         Option<&UiColor>,
         Option<&UiBorder>,
         Option<&UiImage>,
+        Option<&UiNineSlice>,
     )>();
-    for (transform, color, border, image) in query {
+    for (transform, color, border, image, nine_slice) in query {
         scene.nodes.push(ExtractedUiNode {
             pos: transform.pos,
             size: transform.size,
             color: color.copied(),
             border: border.copied(),
             image: image.copied(),
+            nine_slice: nine_slice.copied(),
             z_index: transform.z_index,
         });
     }
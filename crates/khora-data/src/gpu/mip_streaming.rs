@@ -0,0 +1,731 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Texture mip streaming — keeps each tracked texture resident down to the
+//! mip the camera needs, within a VRAM budget.
+//!
+//! [`MipStreamer`] tracks textures by asset UUID. Each frame the renderer
+//! side reports the finest mip it needs per texture through
+//! [`MipStreamer::request_mip`] (the `texture_streaming` DataSystem derives
+//! it from camera distance). [`MipStreamer::update`] then plans a target mip
+//! per texture that fits [`MipStreamingConfig::budget_bytes`] and rebuilds
+//! the GPU textures whose resident mips differ, on the executor's blocking
+//! threads. A finished rebuild replaces the previous texture on a later
+//! update and the old one is destroyed, so evicted mips really free VRAM.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use khora_core::asset::{AssetHandle, AssetUUID};
+use khora_core::executor::{SharedExecutor, Task};
+use khora_core::math::Origin3D;
+use khora_core::renderer::api::resource::{
+    CpuTexture, ImageAspect, TextureDescriptor, TextureDimension, TextureId, TextureUsage,
+    TextureViewDescriptor, TextureViewId,
+};
+use khora_core::renderer::api::util::{
+    downsample_2x, is_downsamplable, mip_extent, mip_level_count, SampleCount,
+};
+use khora_core::renderer::error::ResourceError;
+use khora_core::renderer::GraphicsDevice;
+use khora_core::telemetry::metrics::{MetricId, MetricValue};
+use khora_core::telemetry::monitoring::{
+    MonitoredResourceType, ResourceMonitor, ResourceUsageReport,
+};
+
+/// Tuning of the [`MipStreamer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipStreamingConfig {
+    /// VRAM all streamed textures may use together, in bytes.
+    pub budget_bytes: u64,
+    /// Texture rebuilds started per update, to spread uploads over frames.
+    pub max_uploads_per_frame: usize,
+    /// Updates a texture keeps its requested mip after the last request
+    /// before falling back to its coarsest mip.
+    pub eviction_delay_frames: u64,
+}
+
+impl Default for MipStreamingConfig {
+    fn default() -> Self {
+        Self {
+            budget_bytes: 256 * 1024 * 1024,
+            max_uploads_per_frame: 4,
+            eviction_delay_frames: 120,
+        }
+    }
+}
+
+/// The GPU copy of a streamed texture, as lanes bind it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamedTexture {
+    /// The GPU texture holding the resident mips.
+    pub texture: TextureId,
+    /// A view over every resident mip.
+    pub view: TextureViewId,
+    /// Mip of the source texture stored in the GPU texture's level 0.
+    pub base_mip: u32,
+}
+
+/// Mip residency of one tracked texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MipResidency {
+    /// Number of mips the texture streams between.
+    pub mip_count: u32,
+    /// Finest resident mip, `None` until the first upload finishes.
+    pub resident_mip: Option<u32>,
+    /// Finest mip the last update planned to make resident.
+    pub target_mip: u32,
+    /// VRAM held by the resident mips, in bytes.
+    pub gpu_bytes: u64,
+    /// A rebuild towards `target_mip` is in flight.
+    pub streaming: bool,
+}
+
+/// A rebuild of a texture's GPU copy running on the executor.
+struct Upload {
+    base_mip: u32,
+    task: Task<Result<StreamedTexture, ResourceError>>,
+}
+
+struct TrackedTexture {
+    source: AssetHandle<CpuTexture>,
+    /// Mips 1.. of the source, built by the first rebuild.
+    mips: Arc<OnceLock<Vec<Vec<u8>>>>,
+    mip_count: u32,
+    level_bytes: Vec<u64>,
+    /// Tracked by the `texture_streaming` system for an entity, rather
+    /// than by the app.
+    from_entity: bool,
+    resident: Option<StreamedTexture>,
+    /// Finest mip requested since the last update.
+    requested: Option<u32>,
+    wanted: u32,
+    last_request_frame: Option<u64>,
+    target: u32,
+    upload: Option<Upload>,
+}
+
+impl TrackedTexture {
+    fn coarsest(&self) -> u32 {
+        self.mip_count - 1
+    }
+
+    fn bytes_from(&self, mip: u32) -> u64 {
+        self.level_bytes[mip as usize..].iter().sum()
+    }
+
+    fn gpu_bytes(&self) -> u64 {
+        self.resident.map_or(0, |r| self.bytes_from(r.base_mip))
+    }
+}
+
+#[derive(Default)]
+struct StreamingState {
+    config: MipStreamingConfig,
+    textures: HashMap<AssetUUID, TrackedTexture>,
+    /// GPU copies of untracked textures, destroyed on the next update.
+    retired: Vec<StreamedTexture>,
+    /// Rebuilds of untracked textures; their results are destroyed.
+    orphaned: Vec<Task<Result<StreamedTexture, ResourceError>>>,
+    frame: u64,
+    peak_bytes: u64,
+}
+
+/// Engine-wide texture mip streaming.
+///
+/// Registered in the service registry at bootstrap. Apps can track textures
+/// that no entity references and drive them with their own requests:
+///
+/// ```rust,ignore
+/// let streamer = services.get::<MipStreamer>().cloned().unwrap();
+/// streamer.track(skybox_uuid, skybox_handle);
+/// streamer.request_mip(skybox_uuid, 0);
+/// let gpu = streamer.texture(&skybox_uuid); // once the upload finished
+/// ```
+///
+/// Only 2D textures with 8-bit channels get a mip chain; other formats are
+/// streamed as a single level.
+#[derive(Clone, Default)]
+pub struct MipStreamer {
+    state: Arc<Mutex<StreamingState>>,
+}
+
+impl MipStreamer {
+    /// Creates a streamer with the default [`MipStreamingConfig`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses `config` from the next update on.
+    pub fn with_config(self, config: MipStreamingConfig) -> Self {
+        self.set_config(config);
+        self
+    }
+
+    /// Returns the current configuration.
+    pub fn config(&self) -> MipStreamingConfig {
+        self.with_state(|state| state.config).unwrap_or_default()
+    }
+
+    /// Replaces the configuration, e.g. to follow a new VRAM budget.
+    pub fn set_config(&self, config: MipStreamingConfig) {
+        self.with_state(|state| state.config = config);
+    }
+
+    /// Starts streaming `texture`. Tracking an already tracked texture does
+    /// nothing.
+    pub fn track(&self, uuid: AssetUUID, texture: AssetHandle<CpuTexture>) {
+        self.track_with(uuid, texture, false);
+    }
+
+    /// Tracks the texture of an entity; released again by
+    /// [`untrack_unreferenced`](Self::untrack_unreferenced).
+    pub(crate) fn track_for_entity(&self, uuid: AssetUUID, texture: AssetHandle<CpuTexture>) {
+        self.track_with(uuid, texture, true);
+    }
+
+    fn track_with(&self, uuid: AssetUUID, texture: AssetHandle<CpuTexture>, from_entity: bool) {
+        self.with_state(|state| {
+            if state.textures.contains_key(&uuid) {
+                return;
+            }
+            let mip_count = streamable_mip_count(&texture);
+            let bytes_per_pixel = texture.format.bytes_per_pixel() as u64;
+            let level_bytes = (0..mip_count)
+                .map(|mip| {
+                    let extent = mip_extent(texture.size, mip);
+                    extent.width as u64
+                        * extent.height as u64
+                        * extent.depth_or_array_layers as u64
+                        * bytes_per_pixel
+                })
+                .collect();
+            state.textures.insert(
+                uuid,
+                TrackedTexture {
+                    source: texture,
+                    mips: Arc::new(OnceLock::new()),
+                    mip_count,
+                    level_bytes,
+                    from_entity,
+                    resident: None,
+                    requested: None,
+                    wanted: mip_count - 1,
+                    last_request_frame: None,
+                    target: mip_count - 1,
+                    upload: None,
+                },
+            );
+        });
+    }
+
+    /// Stops streaming `uuid` and releases its GPU copy on the next update.
+    pub fn untrack(&self, uuid: &AssetUUID) {
+        self.with_state(|state| state.untrack(uuid));
+    }
+
+    /// Untracks entity textures that no entity in `referenced` uses anymore.
+    pub(crate) fn untrack_unreferenced(&self, referenced: &HashSet<AssetUUID>) {
+        self.with_state(|state| {
+            let stale: Vec<AssetUUID> = state
+                .textures
+                .iter()
+                .filter(|(uuid, t)| t.from_entity && !referenced.contains(uuid))
+                .map(|(uuid, _)| *uuid)
+                .collect();
+            for uuid in stale {
+                state.untrack(&uuid);
+            }
+        });
+    }
+
+    /// Returns `true` if `uuid` is being streamed.
+    pub fn is_tracked(&self, uuid: &AssetUUID) -> bool {
+        self.with_state(|state| state.textures.contains_key(uuid))
+            .unwrap_or(false)
+    }
+
+    /// Reports that `mip` is the finest level of `uuid` needed this frame.
+    ///
+    /// Several requests in the same frame keep the finest one.
+    pub fn request_mip(&self, uuid: &AssetUUID, mip: u32) {
+        self.with_state(|state| {
+            if let Some(texture) = state.textures.get_mut(uuid) {
+                let mip = mip.min(texture.coarsest());
+                texture.requested = Some(texture.requested.map_or(mip, |r| r.min(mip)));
+            }
+        });
+    }
+
+    /// Returns the GPU copy of `uuid` to bind, once one is resident.
+    pub fn texture(&self, uuid: &AssetUUID) -> Option<StreamedTexture> {
+        self.with_state(|state| state.textures.get(uuid).and_then(|t| t.resident))
+            .flatten()
+    }
+
+    /// Returns the mip residency of `uuid`, if it is tracked.
+    pub fn residency(&self, uuid: &AssetUUID) -> Option<MipResidency> {
+        self.with_state(|state| {
+            state.textures.get(uuid).map(|t| MipResidency {
+                mip_count: t.mip_count,
+                resident_mip: t.resident.map(|r| r.base_mip),
+                target_mip: t.target,
+                gpu_bytes: t.gpu_bytes(),
+                streaming: t.upload.is_some(),
+            })
+        })
+        .flatten()
+    }
+
+    /// Returns the VRAM held by every resident mip, in bytes.
+    pub fn gpu_bytes(&self) -> u64 {
+        self.with_state(|state| state.gpu_bytes()).unwrap_or(0)
+    }
+
+    /// Advances streaming by one frame.
+    ///
+    /// Swaps in finished rebuilds, plans each texture's target mip from the
+    /// requests since the last update and the VRAM budget, then starts up
+    /// to [`MipStreamingConfig::max_uploads_per_frame`] rebuilds. Rebuilds
+    /// run on `executor`'s blocking threads, or inline without one.
+    pub fn update(&self, device: &Arc<dyn GraphicsDevice>, executor: Option<&SharedExecutor>) {
+        self.with_state(|state| {
+            state.frame += 1;
+            state.collect_finished(device.as_ref());
+            state.plan();
+            state.start_uploads(device, executor);
+            state.peak_bytes = state.peak_bytes.max(state.gpu_bytes());
+        });
+    }
+
+    /// Destroys every GPU copy and stops streaming all textures.
+    pub fn clear(&self, device: &dyn GraphicsDevice) {
+        self.with_state(|state| {
+            let uuids: Vec<AssetUUID> = state.textures.keys().copied().collect();
+            for uuid in uuids {
+                state.untrack(&uuid);
+            }
+            state.collect_finished(device);
+        });
+    }
+
+    fn with_state<R>(&self, f: impl FnOnce(&mut StreamingState) -> R) -> Option<R> {
+        match self.state.lock() {
+            Ok(mut state) => Some(f(&mut state)),
+            Err(e) => {
+                log::error!("MipStreamer: mutex poisoned: {}", e);
+                None
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for MipStreamer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tracked = self.with_state(|state| state.textures.len());
+        f.debug_struct("MipStreamer")
+            .field("textures", &tracked)
+            .finish()
+    }
+}
+
+impl StreamingState {
+    fn gpu_bytes(&self) -> u64 {
+        self.textures.values().map(TrackedTexture::gpu_bytes).sum()
+    }
+
+    fn untrack(&mut self, uuid: &AssetUUID) {
+        if let Some(texture) = self.textures.remove(uuid) {
+            self.retired.extend(texture.resident);
+            if let Some(upload) = texture.upload {
+                self.orphaned.push(upload.task);
+            }
+        }
+    }
+
+    /// Releases retired copies and swaps in the rebuilds that finished.
+    fn collect_finished(&mut self, device: &dyn GraphicsDevice) {
+        let mut still_running = Vec::new();
+        for mut task in self.orphaned.drain(..) {
+            match task.try_take() {
+                Some(Ok(texture)) => self.retired.push(texture),
+                Some(Err(_)) => {}
+                None if task.is_finished() => {}
+                None => still_running.push(task),
+            }
+        }
+        self.orphaned = still_running;
+
+        for (uuid, texture) in self.textures.iter_mut() {
+            let Some(upload) = texture.upload.as_mut() else {
+                continue;
+            };
+            match upload.task.try_take() {
+                Some(Ok(streamed)) => {
+                    self.retired.extend(texture.resident.replace(streamed));
+                    texture.upload = None;
+                }
+                Some(Err(e)) => {
+                    log::error!(
+                        "MipStreamer: Failed to stream mip {} of texture {:?}: {:?}",
+                        upload.base_mip,
+                        uuid,
+                        e
+                    );
+                    texture.upload = None;
+                }
+                None if upload.task.is_finished() => {
+                    log::error!("MipStreamer: Texture {:?} upload was dropped", uuid);
+                    texture.upload = None;
+                }
+                None => {}
+            }
+        }
+
+        for retired in self.retired.drain(..) {
+            release(device, retired);
+        }
+    }
+
+    /// Picks the target mip of every texture: the requested one while it
+    /// is fresh, then coarser mips until the total fits the budget.
+    fn plan(&mut self) {
+        let frame = self.frame;
+        let delay = self.config.eviction_delay_frames;
+        let mut entries: Vec<&mut TrackedTexture> = self.textures.values_mut().collect();
+        let mut stale = Vec::with_capacity(entries.len());
+        let mut targets = Vec::with_capacity(entries.len());
+        for texture in entries.iter_mut() {
+            if let Some(mip) = texture.requested.take() {
+                texture.wanted = mip;
+                texture.last_request_frame = Some(frame);
+            }
+            let fresh = texture
+                .last_request_frame
+                .is_some_and(|last| frame - last <= delay);
+            stale.push(!fresh);
+            targets.push(if fresh {
+                texture.wanted
+            } else {
+                texture.coarsest()
+            });
+        }
+
+        let levels: Vec<&[u64]> = entries.iter().map(|t| t.level_bytes.as_slice()).collect();
+        fit_budget(&mut targets, &stale, &levels, self.config.budget_bytes);
+        for (texture, target) in entries.into_iter().zip(targets) {
+            texture.target = target;
+        }
+    }
+
+    /// Starts the rebuilds that bring resident mips to their targets.
+    ///
+    /// Textures with nothing resident go first, then those giving VRAM
+    /// back, then refinements, the largest jumps first.
+    fn start_uploads(
+        &mut self,
+        device: &Arc<dyn GraphicsDevice>,
+        executor: Option<&SharedExecutor>,
+    ) {
+        let mut pending: Vec<(u8, u32, AssetUUID)> = self
+            .textures
+            .iter()
+            .filter(|(_, t)| t.upload.is_none())
+            .filter_map(|(uuid, t)| match t.resident {
+                None => Some((0, 0, *uuid)),
+                Some(r) if r.base_mip < t.target => Some((1, t.target - r.base_mip, *uuid)),
+                Some(r) if r.base_mip > t.target => Some((2, r.base_mip - t.target, *uuid)),
+                Some(_) => None,
+            })
+            .collect();
+        pending.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        pending.truncate(self.config.max_uploads_per_frame);
+
+        for (_, _, uuid) in pending {
+            let Some(texture) = self.textures.get_mut(&uuid) else {
+                continue;
+            };
+            let base_mip = texture.target;
+            let job = {
+                let device = Arc::clone(device);
+                let source = texture.source.clone();
+                let mips = Arc::clone(&texture.mips);
+                let mip_count = texture.mip_count;
+                move || upload_mips(device.as_ref(), &source, &mips, base_mip, mip_count)
+            };
+            match executor {
+                Some(executor) => {
+                    texture.upload = Some(Upload {
+                        base_mip,
+                        task: executor.spawn_blocking(job),
+                    });
+                }
+                None => match job() {
+                    Ok(streamed) => self.retired.extend(texture.resident.replace(streamed)),
+                    Err(e) => log::error!(
+                        "MipStreamer: Failed to stream mip {} of texture {:?}: {:?}",
+                        base_mip,
+                        uuid,
+                        e
+                    ),
+                },
+            }
+        }
+    }
+}
+
+/// Coarsens `targets` one mip at a time until the textures fit `budget`,
+/// taking from stale textures first and then from the largest mip.
+fn fit_budget(targets: &mut [u32], stale: &[bool], levels: &[&[u64]], budget: u64) {
+    let mut total: u64 = targets
+        .iter()
+        .zip(levels)
+        .map(|(&mip, bytes)| bytes[mip as usize..].iter().sum::<u64>())
+        .sum();
+    while total > budget {
+        let victim = (0..targets.len())
+            .filter(|&i| (targets[i] as usize) + 1 < levels[i].len())
+            .max_by_key(|&i| (stale[i], levels[i][targets[i] as usize]));
+        let Some(i) = victim else {
+            break;
+        };
+        total -= levels[i][targets[i] as usize];
+        targets[i] += 1;
+    }
+}
+
+/// Number of mips `texture` streams between: its full chain when mips can
+/// be built on the CPU, otherwise just the base level.
+fn streamable_mip_count(texture: &CpuTexture) -> u32 {
+    let expected = texture.size.width as usize
+        * texture.size.height as usize
+        * texture.format.bytes_per_pixel() as usize;
+    if texture.dimension == TextureDimension::D2
+        && texture.size.depth_or_array_layers == 1
+        && is_downsamplable(texture.format)
+        && texture.pixels.len() >= expected
+    {
+        mip_level_count(texture.size.width, texture.size.height)
+    } else {
+        1
+    }
+}
+
+/// Builds mips 1.. of `source` from its base level.
+fn build_mips(source: &CpuTexture, mip_count: u32) -> Vec<Vec<u8>> {
+    let channels = source.format.bytes_per_pixel();
+    let mut mips: Vec<Vec<u8>> = Vec::with_capacity(mip_count.saturating_sub(1) as usize);
+    for mip in 1..mip_count {
+        let parent = mip_extent(source.size, mip - 1);
+        let pixels = mips.last().map_or(source.pixels.as_slice(), Vec::as_slice);
+        let next = downsample_2x(pixels, parent.width, parent.height, channels);
+        mips.push(next);
+    }
+    mips
+}
+
+/// Creates a GPU texture holding mips `base_mip..mip_count` of `source`.
+fn upload_mips(
+    device: &dyn GraphicsDevice,
+    source: &CpuTexture,
+    mips: &OnceLock<Vec<Vec<u8>>>,
+    base_mip: u32,
+    mip_count: u32,
+) -> Result<StreamedTexture, ResourceError> {
+    let mips = mips.get_or_init(|| build_mips(source, mip_count));
+    let bytes_per_pixel = source.format.bytes_per_pixel();
+    let texture = device.create_texture(&TextureDescriptor {
+        label: Some(Cow::Borrowed("Streamed Texture")),
+        size: mip_extent(source.size, base_mip),
+        mip_level_count: mip_count - base_mip,
+        sample_count: SampleCount::X1,
+        dimension: source.dimension,
+        format: source.format,
+        usage: source.usage | TextureUsage::TEXTURE_BINDING | TextureUsage::COPY_DST,
+        view_formats: Cow::Borrowed(&[]),
+    })?;
+
+    let write_levels = || -> Result<TextureViewId, ResourceError> {
+        for mip in base_mip..mip_count {
+            let extent = mip_extent(source.size, mip);
+            let data = match mip {
+                0 => source.pixels.as_slice(),
+                mip => mips[mip as usize - 1].as_slice(),
+            };
+            device.write_texture_mip(
+                texture,
+                mip - base_mip,
+                data,
+                Some(extent.width * bytes_per_pixel),
+                Origin3D::default(),
+                extent,
+            )?;
+        }
+        device.create_texture_view(
+            texture,
+            &TextureViewDescriptor {
+                label: Some(Cow::Borrowed("Streamed Texture View")),
+                format: None,
+                dimension: None,
+                aspect: ImageAspect::All,
+                base_mip_level: 0,
+                mip_level_count: None,
+                base_array_layer: 0,
+                array_layer_count: None,
+            },
+        )
+    };
+    match write_levels() {
+        Ok(view) => Ok(StreamedTexture {
+            texture,
+            view,
+            base_mip,
+        }),
+        Err(e) => {
+            let _ = device.destroy_texture(texture);
+            Err(e)
+        }
+    }
+}
+
+fn release(device: &dyn GraphicsDevice, texture: StreamedTexture) {
+    if let Err(e) = device.destroy_texture_view(texture.view) {
+        log::warn!("MipStreamer: Failed to destroy texture view: {:?}", e);
+    }
+    if let Err(e) = device.destroy_texture(texture.texture) {
+        log::warn!("MipStreamer: Failed to destroy texture: {:?}", e);
+    }
+}
+
+impl ResourceMonitor for MipStreamer {
+    fn monitor_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed("TextureStreaming")
+    }
+
+    fn resource_type(&self) -> MonitoredResourceType {
+        MonitoredResourceType::Vram
+    }
+
+    fn get_usage_report(&self) -> ResourceUsageReport {
+        self.with_state(|state| ResourceUsageReport {
+            current_bytes: state.gpu_bytes(),
+            peak_bytes: Some(state.peak_bytes),
+            total_capacity_bytes: Some(state.config.budget_bytes),
+        })
+        .unwrap_or_default()
+    }
+
+    fn get_metrics(&self) -> Vec<(MetricId, MetricValue)> {
+        let (textures, streaming, bytes) = self
+            .with_state(|state| {
+                let streaming = state
+                    .textures
+                    .values()
+                    .filter(|t| t.upload.is_some())
+                    .count();
+                (state.textures.len(), streaming, state.gpu_bytes())
+            })
+            .unwrap_or_default();
+        let gauge = |name: &str, value: f64| {
+            (
+                MetricId::new("assets.mip_streaming", name),
+                MetricValue::Gauge(value),
+            )
+        };
+        vec![
+            gauge("textures", textures as f64),
+            gauge("streaming", streaming as f64),
+            gauge("gpu_bytes", bytes as f64),
+        ]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::math::Extent3D;
+    use khora_core::renderer::api::util::TextureFormat;
+
+    fn texture(size: u32, format: TextureFormat) -> AssetHandle<CpuTexture> {
+        AssetHandle::new(CpuTexture {
+            pixels: vec![0; (size * size * format.bytes_per_pixel()) as usize],
+            size: Extent3D {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            format,
+            mip_level_count: 1,
+            sample_count: SampleCount::X1,
+            dimension: TextureDimension::D2,
+            usage: TextureUsage::TEXTURE_BINDING,
+        })
+    }
+
+    #[test]
+    fn only_8_bit_textures_get_a_mip_chain() {
+        let streamer = MipStreamer::new();
+        let color = AssetUUID::new();
+        let hdr = AssetUUID::new();
+        streamer.track(color, texture(64, TextureFormat::Rgba8UnormSrgb));
+        streamer.track(hdr, texture(64, TextureFormat::Rgba16Float));
+
+        let color = streamer.residency(&color).unwrap();
+        assert_eq!(color.mip_count, 7);
+        assert_eq!(color.resident_mip, None);
+        assert_eq!(streamer.residency(&hdr).unwrap().mip_count, 1);
+    }
+
+    #[test]
+    fn requests_keep_the_finest_mip_of_the_frame() {
+        let streamer = MipStreamer::new();
+        let uuid = AssetUUID::new();
+        streamer.track(uuid, texture(64, TextureFormat::Rgba8Unorm));
+        streamer.request_mip(&uuid, 4);
+        streamer.request_mip(&uuid, 2);
+        streamer.request_mip(&uuid, 99);
+
+        streamer.with_state(|state| {
+            state.plan();
+            assert_eq!(state.textures[&uuid].target, 2);
+        });
+    }
+
+    #[test]
+    fn budget_coarsens_stale_textures_first() {
+        // Two 4-mip chains of 64 + 16 + 4 + 1 bytes.
+        let levels: [&[u64]; 2] = [&[64, 16, 4, 1], &[64, 16, 4, 1]];
+        let mut targets = [0, 0];
+        fit_budget(&mut targets, &[false, true], &levels, 110);
+        assert_eq!(targets, [0, 1]);
+
+        // Over budget even at the coarsest mips: stop there.
+        let mut targets = [0, 0];
+        fit_budget(&mut targets, &[false, false], &levels, 1);
+        assert_eq!(targets, [3, 3]);
+    }
+
+    #[test]
+    fn mips_are_built_down_to_one_texel() {
+        let source = texture(8, TextureFormat::R8Unorm);
+        let mips = build_mips(&source, 4);
+        let sizes: Vec<usize> = mips.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![16, 4, 1]);
+    }
+}
@@ -17,12 +17,19 @@
 //! This module provides:
 //! - [`GpuCache`]: the engine-wide, shared GPU mesh cache.
 //! - [`ProjectionRegistry`]: drives CPU→GPU mesh upload before agents run.
+//! - [`ResidencyManager`]: residency policies and explicit make-resident/evict control.
+//! - [`MipStreamer`]: per-mip texture residency driven by camera distance,
+//!   within a VRAM budget.
 //!
-//! Both are registered into the [`ServiceRegistry`] during bootstrap and
+//! All four are registered into the [`ServiceRegistry`] during bootstrap and
 //! must not be held as local fields inside agents.
 
 pub mod cache;
+pub mod mip_streaming;
 pub mod projection;
+pub mod residency;
 
 pub use cache::GpuCache;
+pub use mip_streaming::{MipResidency, MipStreamer, MipStreamingConfig, StreamedTexture};
 pub use projection::ProjectionRegistry;
+pub use residency::{ResidencyEntry, ResidencyManager, ResidencyRequest};
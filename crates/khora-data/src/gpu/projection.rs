@@ -23,13 +23,20 @@
 //! shared `GpuCache` is fully up to date.  This call is idempotent: entities
 //! already holding a `HandleComponent<GpuMesh>` are skipped via the
 //! `Without<HandleComponent<GpuMesh>>` query filter.
+//!
+//! Uploads honour the [`ResidencyManager`] policies: assets can skip the
+//! GPU entirely, or have their CPU copy dropped once uploaded. Explicit
+//! residency requests are applied at the start of each sync.
 
 use crate::{
-    ecs::{HandleComponent, Without, World},
-    gpu::GpuCache,
+    ecs::{
+        HandleComponent, IncludeDisabled, MorphedMesh, PendingAssetKind, PendingAssets, Without,
+        World,
+    },
+    gpu::{GpuCache, ResidencyEntry, ResidencyManager, ResidencyRequest},
 };
 use khora_core::{
-    asset::AssetHandle,
+    asset::{AssetHandle, AssetUUID, Residency, ResidencyTier},
     ecs::entity::EntityId,
     renderer::{
         api::{
@@ -40,7 +47,8 @@ use khora_core::{
         GraphicsDevice,
     },
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 /// Engine-wide CPU→GPU mesh upload service.
 ///
@@ -50,12 +58,26 @@ use std::collections::HashMap;
 #[derive(Clone)]
 pub struct ProjectionRegistry {
     cache: GpuCache,
+    residency: ResidencyManager,
+    /// Vertex buffer size of each per-entity mesh from
+    /// [`upload_deformed`](Self::upload_deformed), in bytes.
+    deformed: Arc<Mutex<HashMap<AssetUUID, u64>>>,
 }
 
 impl ProjectionRegistry {
     /// Creates a new `ProjectionRegistry` backed by the given shared cache.
     pub fn new(cache: GpuCache) -> Self {
-        Self { cache }
+        Self {
+            cache,
+            residency: ResidencyManager::new(),
+            deformed: Arc::default(),
+        }
+    }
+
+    /// Uses `residency` for upload policies and residency requests.
+    pub fn with_residency(mut self, residency: ResidencyManager) -> Self {
+        self.residency = residency;
+        self
     }
 
     /// Returns a reference to the underlying `GpuCache`.
@@ -63,6 +85,11 @@ impl ProjectionRegistry {
         &self.cache
     }
 
+    /// Returns the residency manager driving this projection.
+    pub fn residency(&self) -> &ResidencyManager {
+        &self.residency
+    }
+
     /// Uploads any newly loaded CPU meshes to the GPU and tags their ECS entities.
     ///
     /// For each entity that has `HandleComponent<Mesh>` but not yet
@@ -72,23 +99,41 @@ impl ProjectionRegistry {
     /// 3. Inserts the result into `GpuCache`.
     /// 4. Adds `HandleComponent<GpuMesh>` to the entity so subsequent frames skip it.
     ///
+    /// Meshes whose policy is CPU-only, whose GPU copy was evicted, or whose
+    /// CPU copy was dropped are not uploaded. Once uploads are done, CPU
+    /// copies are dropped where the policy asks for it and the residency
+    /// table is refreshed.
+    ///
     /// This method is idempotent and safe to call every frame.
     pub fn sync_all(&self, world: &mut World, device: &dyn GraphicsDevice) {
+        for request in self.residency.take_requests() {
+            self.apply_request(world, request);
+        }
+
         // Phase 1: collect pending uploads (read-only ECS borrow).
         let mut pending: HashMap<EntityId, HandleComponent<GpuMesh>> = HashMap::new();
+        let mut uploaded_bytes: HashMap<AssetUUID, u64> = HashMap::new();
 
         {
             let query = world.query::<(
                 EntityId,
                 &HandleComponent<Mesh>,
                 Without<HandleComponent<GpuMesh>>,
+                IncludeDisabled,
             )>();
 
-            for (entity_id, mesh_handle_comp, _) in query {
+            for (entity_id, mesh_handle_comp, _, _) in query {
                 let uuid = mesh_handle_comp.uuid;
+                if !self.residency.policy::<Mesh>(&uuid).uploads()
+                    || self.residency.is_gpu_evicted(&uuid)
+                    || !is_cpu_resident(mesh_handle_comp)
+                {
+                    continue;
+                }
 
                 // Cache miss: upload to GPU for the first time.
                 if !self.cache.0.read().unwrap().contains(&uuid) {
+                    uploaded_bytes.insert(uuid, mesh_bytes(mesh_handle_comp));
                     let gpu_mesh = Self::upload_mesh(mesh_handle_comp, device);
                     self.cache
                         .0
@@ -114,16 +159,230 @@ impl ProjectionRegistry {
         for (entity_id, component) in pending {
             let _ = world.add_component(entity_id, component);
         }
+
+        // Phase 3: drop CPU copies the policies no longer need.
+        let droppable: Vec<AssetUUID> = world
+            .query::<(
+                &HandleComponent<Mesh>,
+                &HandleComponent<GpuMesh>,
+                IncludeDisabled,
+            )>()
+            .filter(|(mesh, _, _)| {
+                is_cpu_resident(mesh) && self.residency.policy::<Mesh>(&mesh.uuid).drops_cpu_copy()
+            })
+            .map(|(mesh, _, _)| mesh.uuid)
+            .collect();
+        for uuid in droppable {
+            self.drop_cpu_copy(world, uuid);
+        }
+
+        self.refresh_residency(world, uploaded_bytes);
+    }
+
+    /// Applies one explicit residency request to the world and the cache.
+    fn apply_request(&self, world: &mut World, request: ResidencyRequest) {
+        let ResidencyRequest {
+            uuid,
+            tier,
+            resident,
+        } = request;
+        match (tier, resident) {
+            (ResidencyTier::Gpu, false) => {
+                self.residency.set_gpu_evicted(uuid, true);
+                self.cache.0.write().unwrap().remove(&uuid);
+                let tagged: Vec<EntityId> = world
+                    .query::<(EntityId, &HandleComponent<GpuMesh>, IncludeDisabled)>()
+                    .filter(|(_, gpu, _)| gpu.uuid == uuid)
+                    .map(|(entity, _, _)| entity)
+                    .collect();
+                for entity in tagged {
+                    let _ = world.remove_component::<HandleComponent<GpuMesh>>(entity);
+                }
+            }
+            (ResidencyTier::Cpu, false) => self.drop_cpu_copy(world, uuid),
+            (tier, true) => {
+                self.residency.pin_for::<Mesh>(uuid, tier);
+                if tier == ResidencyTier::Gpu {
+                    self.residency.set_gpu_evicted(uuid, false);
+                    if self.cache.0.read().unwrap().contains(&uuid) {
+                        return;
+                    }
+                }
+                // Uploading needs the CPU copy too, so both tiers reload it.
+                let stripped: Vec<EntityId> = world
+                    .query::<(EntityId, &HandleComponent<Mesh>, IncludeDisabled)>()
+                    .filter(|(_, mesh, _)| mesh.uuid == uuid && !is_cpu_resident(mesh))
+                    .map(|(entity, _, _)| entity)
+                    .collect();
+                for entity in stripped {
+                    PendingAssets::request(world, entity, PendingAssetKind::Mesh, uuid);
+                }
+            }
+        }
+    }
+
+    /// Replaces every CPU copy of `uuid` with a data-less placeholder.
+    ///
+    /// The placeholder keeps the UUID, topology and bounding box, so
+    /// culling, picking bounds and scene references keep working.
+    fn drop_cpu_copy(&self, world: &mut World, uuid: AssetUUID) {
+        let mut placeholder: Option<AssetHandle<Mesh>> = None;
+        for (mesh, _) in world.query_mut::<(&mut HandleComponent<Mesh>, IncludeDisabled)>() {
+            if mesh.uuid != uuid || !is_cpu_resident(mesh) {
+                continue;
+            }
+            let handle = placeholder
+                .get_or_insert_with(|| AssetHandle::new(stripped_mesh(&mesh.handle)))
+                .clone();
+            mesh.handle = handle;
+        }
+        if placeholder.is_some() {
+            self.residency.release_cpu(uuid);
+        }
+    }
+
+    /// Rebuilds the residency table from the world and the GPU cache.
+    fn refresh_residency(&self, world: &World, uploaded_bytes: HashMap<AssetUUID, u64>) {
+        let cache = self.cache.0.read().unwrap();
+        let mut entries: HashMap<AssetUUID, ResidencyEntry> = HashMap::new();
+        for (mesh, _) in world.query::<(&HandleComponent<Mesh>, IncludeDisabled)>() {
+            let entry = entries.entry(mesh.uuid).or_default();
+            if is_cpu_resident(mesh) {
+                entry.cpu_bytes = mesh_bytes(mesh);
+            }
+            entry.residency = Residency::from_parts(
+                entry.residency.has_cpu() || is_cpu_resident(mesh),
+                cache.contains(&mesh.uuid),
+            );
+            if let Some(bytes) = uploaded_bytes.get(&mesh.uuid) {
+                entry.gpu_bytes = *bytes;
+            }
+        }
+        self.residency.update_entries(entries);
+    }
+
+    /// Uploads a per-entity deformed copy of a mesh under `uuid`.
+    ///
+    /// The first call creates the GPU buffers; later calls for the same
+    /// `uuid` rewrite the vertex buffer in place. If the mesh no longer fits
+    /// the buffers — its vertex size, index count or topology changed — they
+    /// are destroyed and allocated again, and a new handle is returned.
+    pub fn upload_deformed(
+        &self,
+        uuid: AssetUUID,
+        mesh: &Mesh,
+        device: &dyn GraphicsDevice,
+    ) -> AssetHandle<GpuMesh> {
+        let vertex_data = mesh.create_vertex_buffer();
+        let index_count = mesh
+            .indices
+            .as_ref()
+            .map_or(0, |indices| indices.len() as u32);
+        let existing = self.cache.0.read().unwrap().get(&uuid).cloned();
+        let allocated = self.deformed.lock().unwrap().get(&uuid).copied();
+        if let (Some(handle), Some(allocated)) = (existing, allocated) {
+            if allocated == vertex_data.len() as u64
+                && handle.index_count == index_count
+                && handle.primitive_topology == mesh.primitive_type
+            {
+                if let Err(e) = device.write_buffer(handle.vertex_buffer, 0, &vertex_data) {
+                    log::warn!("Failed to update deformed mesh {:?}: {:?}", uuid, e);
+                }
+                return handle;
+            }
+            self.release_deformed(uuid, device);
+        }
+
+        let handle = AssetHandle::new(Self::upload_mesh(mesh, device));
+        self.cache.0.write().unwrap().insert(uuid, handle.clone());
+        self.deformed
+            .lock()
+            .unwrap()
+            .insert(uuid, vertex_data.len() as u64);
+        handle
+    }
+
+    /// Frees a mesh uploaded by [`upload_deformed`](Self::upload_deformed):
+    /// drops it from the cache and destroys its buffers.
+    ///
+    /// Handles still held elsewhere must not be drawn afterwards. Returns
+    /// `false` if `uuid` is not a deformed mesh.
+    pub fn release_deformed(&self, uuid: AssetUUID, device: &dyn GraphicsDevice) -> bool {
+        if self.deformed.lock().unwrap().remove(&uuid).is_none() {
+            return false;
+        }
+        let Some(mesh) = self.cache.0.write().unwrap().remove(&uuid) else {
+            return true;
+        };
+        for buffer in [mesh.vertex_buffer, mesh.index_buffer] {
+            if let Err(e) = device.destroy_buffer(buffer) {
+                log::warn!("Failed to destroy a buffer of mesh {:?}: {:?}", uuid, e);
+            }
+        }
+        true
+    }
+
+    /// Frees every deformed mesh no [`MorphedMesh`] in `world` refers to
+    /// any more, because its entity was despawned or lost its deformer.
+    ///
+    /// Returns the number of meshes freed.
+    pub fn release_unused_deformed(&self, world: &World, device: &dyn GraphicsDevice) -> usize {
+        let live: HashSet<AssetUUID> = world
+            .query::<(&MorphedMesh, IncludeDisabled)>()
+            .map(|(morphed, _)| morphed.uuid)
+            .collect();
+        let unused: Vec<AssetUUID> = self
+            .deformed
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|uuid| !live.contains(uuid))
+            .copied()
+            .collect();
+        unused
+            .into_iter()
+            .filter(|uuid| self.release_deformed(*uuid, device))
+            .count()
+    }
+
+    /// Uploads a per-entity copy of a skinned mesh under `uuid`, in its
+    /// bind pose.
+    ///
+    /// The vertex buffer is also a storage buffer, so the skinning compute
+    /// pass can write posed vertices into it. Later calls for the same
+    /// `uuid` return the existing mesh.
+    pub fn upload_skin_target(
+        &self,
+        uuid: AssetUUID,
+        mesh: &Mesh,
+        device: &dyn GraphicsDevice,
+    ) -> AssetHandle<GpuMesh> {
+        if let Some(handle) = self.cache.0.read().unwrap().get(&uuid) {
+            return handle.clone();
+        }
+        let usage = BufferUsage::VERTEX | BufferUsage::STORAGE | BufferUsage::COPY_DST;
+        let handle = AssetHandle::new(Self::upload_mesh_with_usage(mesh, device, usage));
+        self.cache.0.write().unwrap().insert(uuid, handle.clone());
+        handle
     }
 
     /// Uploads a single CPU [`Mesh`] to the GPU and returns the resulting [`GpuMesh`].
     fn upload_mesh(mesh: &Mesh, device: &dyn GraphicsDevice) -> GpuMesh {
+        Self::upload_mesh_with_usage(mesh, device, BufferUsage::VERTEX | BufferUsage::COPY_DST)
+    }
+
+    /// Like [`Self::upload_mesh`], with explicit vertex buffer usage flags.
+    fn upload_mesh_with_usage(
+        mesh: &Mesh,
+        device: &dyn GraphicsDevice,
+        vertex_usage: BufferUsage,
+    ) -> GpuMesh {
         // Upload vertex buffer.
         let vertex_data = mesh.create_vertex_buffer();
         let vb_desc = BufferDescriptor {
             label: Some("Mesh Vertex Buffer".into()),
             size: vertex_data.len() as u64,
-            usage: BufferUsage::VERTEX | BufferUsage::COPY_DST,
+            usage: vertex_usage,
             mapped_at_creation: false,
         };
         let vertex_buffer = device
@@ -165,3 +424,32 @@ impl ProjectionRegistry {
         }
     }
 }
+
+/// Returns `true` if `mesh` still carries its vertex data.
+fn is_cpu_resident(mesh: &Mesh) -> bool {
+    !mesh.positions.is_empty()
+}
+
+/// Size of the vertex and index data of `mesh`, in bytes.
+fn mesh_bytes(mesh: &Mesh) -> u64 {
+    let vertices = mesh.positions.len() * mesh.vertex_size();
+    let indices = mesh.indices.as_ref().map_or(0, |i| i.len() * 4);
+    (vertices + indices) as u64
+}
+
+/// A copy of `mesh` without vertex data.
+fn stripped_mesh(mesh: &Mesh) -> Mesh {
+    Mesh {
+        positions: Vec::new(),
+        normals: None,
+        tex_coords: None,
+        tangents: None,
+        colors: None,
+        indices: None,
+        primitive_type: mesh.primitive_type,
+        bounding_box: mesh.bounding_box,
+        vertex_layout: mesh.vertex_layout.clone(),
+        morph_targets: Vec::new(),
+        skin: None,
+    }
+}
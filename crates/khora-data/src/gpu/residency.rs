@@ -0,0 +1,410 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Asset residency control — which copies of an uploaded asset stay in memory.
+//!
+//! [`ResidencyManager`] holds the [`ResidencyPolicy`] of every asset type
+//! (with optional per-asset overrides), queues explicit `make_resident` /
+//! `evict` requests, and keeps the residency table that the
+//! [`ProjectionRegistry`](super::ProjectionRegistry) refreshes each frame.
+//! It is also a [`ResourceMonitor`], so residency shows up in telemetry.
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use khora_core::asset::{
+    Asset, AssetUUID, Residency, ResidencyPolicy, ResidencyStats, ResidencyTier,
+};
+use khora_core::telemetry::metrics::{MetricId, MetricValue};
+use khora_core::telemetry::monitoring::{
+    MonitoredResourceType, ResourceMonitor, ResourceUsageReport,
+};
+
+/// An explicit residency change, applied on the next projection sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResidencyRequest {
+    /// The asset to change.
+    pub uuid: AssetUUID,
+    /// The copy the request is about.
+    pub tier: ResidencyTier,
+    /// `true` to make the copy resident, `false` to evict it.
+    pub resident: bool,
+}
+
+/// Residency bookkeeping for one tracked asset.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResidencyEntry {
+    /// Where the asset currently lives.
+    pub residency: Residency,
+    /// Size of the CPU copy, in bytes.
+    pub cpu_bytes: u64,
+    /// Size of the GPU copy, in bytes.
+    pub gpu_bytes: u64,
+}
+
+#[derive(Debug, Default)]
+struct ResidencyState {
+    type_policies: HashMap<TypeId, ResidencyPolicy>,
+    asset_policies: HashMap<AssetUUID, ResidencyPolicy>,
+    requests: Vec<ResidencyRequest>,
+    entries: HashMap<AssetUUID, ResidencyEntry>,
+    gpu_evicted: HashSet<AssetUUID>,
+    cpu_released: Vec<AssetUUID>,
+    peak_bytes: u64,
+}
+
+/// Engine-wide residency policies and explicit residency control.
+///
+/// Registered in the service registry at bootstrap, before `setup`, so apps
+/// can configure policies up front and keep a clone for runtime requests:
+///
+/// ```rust,ignore
+/// let residency = services.get::<ResidencyManager>().cloned().unwrap();
+/// residency.set_type_policy::<Mesh>(ResidencyPolicy::DropCpuAfterUpload);
+/// residency.evict(far_level_mesh, ResidencyTier::Gpu);
+/// ```
+///
+/// Unconfigured types use [`ResidencyPolicy::KeepCpuCopy`].
+#[derive(Debug, Clone, Default)]
+pub struct ResidencyManager {
+    state: Arc<Mutex<ResidencyState>>,
+}
+
+impl ResidencyManager {
+    /// Creates a manager with every type on the default policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the policy used by every asset of type `A` without an override.
+    pub fn set_type_policy<A: Asset>(&self, policy: ResidencyPolicy) {
+        self.with_state(|state| {
+            state.type_policies.insert(TypeId::of::<A>(), policy);
+        });
+    }
+
+    /// Overrides the policy of a single asset.
+    pub fn set_policy(&self, uuid: AssetUUID, policy: ResidencyPolicy) {
+        self.with_state(|state| {
+            state.asset_policies.insert(uuid, policy);
+        });
+    }
+
+    /// Removes the per-asset override of `uuid`, if any.
+    pub fn clear_policy(&self, uuid: &AssetUUID) {
+        self.with_state(|state| {
+            state.asset_policies.remove(uuid);
+        });
+    }
+
+    /// Returns the policy in effect for the asset `uuid` of type `A`.
+    pub fn policy<A: Asset>(&self, uuid: &AssetUUID) -> ResidencyPolicy {
+        self.with_state(|state| state.policy_of(TypeId::of::<A>(), uuid))
+            .unwrap_or_default()
+    }
+
+    /// Requests that the `tier` copy of `uuid` becomes resident.
+    ///
+    /// A dropped CPU copy is reloaded through the asset pipeline; a GPU copy
+    /// is re-uploaded from the CPU copy (reloading it first if needed). If
+    /// the asset's policy would undo the request, the asset is pinned to
+    /// [`ResidencyPolicy::KeepCpuCopy`].
+    pub fn make_resident(&self, uuid: AssetUUID, tier: ResidencyTier) {
+        self.push_request(ResidencyRequest {
+            uuid,
+            tier,
+            resident: true,
+        });
+    }
+
+    /// Requests that the `tier` copy of `uuid` is released.
+    ///
+    /// Evicting both copies leaves the asset [`Residency::Unloaded`] until
+    /// it is made resident again.
+    pub fn evict(&self, uuid: AssetUUID, tier: ResidencyTier) {
+        self.push_request(ResidencyRequest {
+            uuid,
+            tier,
+            resident: false,
+        });
+    }
+
+    /// Returns where `uuid` currently lives.
+    pub fn residency(&self, uuid: &AssetUUID) -> Residency {
+        self.with_state(|state| {
+            state
+                .entries
+                .get(uuid)
+                .map(|entry| entry.residency)
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Aggregates the residency table.
+    pub fn stats(&self) -> ResidencyStats {
+        self.with_state(|state| {
+            let mut stats = ResidencyStats::default();
+            for entry in state.entries.values() {
+                stats.record(entry.residency, entry.cpu_bytes, entry.gpu_bytes);
+            }
+            stats
+        })
+        .unwrap_or_default()
+    }
+
+    /// Drains the requests queued since the last call.
+    pub fn take_requests(&self) -> Vec<ResidencyRequest> {
+        self.with_state(|state| std::mem::take(&mut state.requests))
+            .unwrap_or_default()
+    }
+
+    /// Drains the assets whose CPU copy was dropped since the last call.
+    ///
+    /// The asset service uses this to release its own cached copy, so the
+    /// memory is actually returned.
+    pub fn take_cpu_released(&self) -> Vec<AssetUUID> {
+        self.with_state(|state| std::mem::take(&mut state.cpu_released))
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the GPU copy of `uuid` was explicitly evicted and
+    /// must not be uploaded again until it is made resident.
+    pub fn is_gpu_evicted(&self, uuid: &AssetUUID) -> bool {
+        self.with_state(|state| state.gpu_evicted.contains(uuid))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn set_gpu_evicted(&self, uuid: AssetUUID, evicted: bool) {
+        self.with_state(|state| {
+            if evicted {
+                state.gpu_evicted.insert(uuid);
+            } else {
+                state.gpu_evicted.remove(&uuid);
+            }
+        });
+    }
+
+    pub(crate) fn release_cpu(&self, uuid: AssetUUID) {
+        self.with_state(|state| {
+            if !state.cpu_released.contains(&uuid) {
+                state.cpu_released.push(uuid);
+            }
+        });
+    }
+
+    /// Replaces the residency table with `entries`, carrying over known
+    /// GPU sizes for entries that do not report one.
+    pub(crate) fn update_entries(&self, mut entries: HashMap<AssetUUID, ResidencyEntry>) {
+        self.with_state(|state| {
+            for (uuid, entry) in entries.iter_mut() {
+                if entry.gpu_bytes == 0 {
+                    if let Some(previous) = state.entries.get(uuid) {
+                        entry.gpu_bytes = previous.gpu_bytes;
+                    }
+                }
+            }
+            state.entries = entries;
+            let total: u64 = state
+                .entries
+                .values()
+                .map(|e| {
+                    let mut bytes = 0;
+                    if e.residency.has_cpu() {
+                        bytes += e.cpu_bytes;
+                    }
+                    if e.residency.has_gpu() {
+                        bytes += e.gpu_bytes;
+                    }
+                    bytes
+                })
+                .sum();
+            state.peak_bytes = state.peak_bytes.max(total);
+        });
+    }
+
+    fn push_request(&self, request: ResidencyRequest) {
+        self.with_state(|state| state.requests.push(request));
+    }
+
+    fn undoes(policy: ResidencyPolicy, tier: ResidencyTier) -> bool {
+        match tier {
+            ResidencyTier::Cpu => policy.drops_cpu_copy(),
+            ResidencyTier::Gpu => !policy.uploads(),
+        }
+    }
+
+    /// Pins `uuid` of type `A` to [`ResidencyPolicy::KeepCpuCopy`] if its
+    /// effective policy would undo a `make_resident(tier)` request.
+    pub(crate) fn pin_for<A: Asset>(&self, uuid: AssetUUID, tier: ResidencyTier) {
+        self.with_state(|state| {
+            let policy = state.policy_of(TypeId::of::<A>(), &uuid);
+            if Self::undoes(policy, tier) {
+                state
+                    .asset_policies
+                    .insert(uuid, ResidencyPolicy::KeepCpuCopy);
+            }
+        });
+    }
+
+    fn with_state<R>(&self, f: impl FnOnce(&mut ResidencyState) -> R) -> Option<R> {
+        match self.state.lock() {
+            Ok(mut state) => Some(f(&mut state)),
+            Err(e) => {
+                log::error!("ResidencyManager: mutex poisoned: {}", e);
+                None
+            }
+        }
+    }
+}
+
+impl ResidencyState {
+    fn policy_of(&self, type_id: TypeId, uuid: &AssetUUID) -> ResidencyPolicy {
+        self.asset_policies
+            .get(uuid)
+            .or_else(|| self.type_policies.get(&type_id))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl ResourceMonitor for ResidencyManager {
+    fn monitor_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed("AssetResidency")
+    }
+
+    fn resource_type(&self) -> MonitoredResourceType {
+        MonitoredResourceType::SystemRam
+    }
+
+    fn get_usage_report(&self) -> ResourceUsageReport {
+        let stats = self.stats();
+        let peak = self.with_state(|state| state.peak_bytes).unwrap_or(0);
+        ResourceUsageReport {
+            current_bytes: stats.cpu_bytes + stats.gpu_bytes,
+            peak_bytes: Some(peak),
+            total_capacity_bytes: None,
+        }
+    }
+
+    fn get_metrics(&self) -> Vec<(MetricId, MetricValue)> {
+        let stats = self.stats();
+        let gauge = |name: &str, value: f64| {
+            (
+                MetricId::new("assets.residency", name),
+                MetricValue::Gauge(value),
+            )
+        };
+        vec![
+            gauge("cpu_only", stats.cpu_only as f64),
+            gauge("gpu_only", stats.gpu_only as f64),
+            gauge("both", stats.both as f64),
+            gauge("unloaded", stats.unloaded as f64),
+            gauge("cpu_bytes", stats.cpu_bytes as f64),
+            gauge("gpu_bytes", stats.gpu_bytes as f64),
+        ]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::renderer::api::scene::{GpuMesh, Mesh};
+
+    #[test]
+    fn asset_override_wins_over_type_policy() {
+        let residency = ResidencyManager::new();
+        let pinned = AssetUUID::new();
+        let other = AssetUUID::new();
+        residency.set_type_policy::<Mesh>(ResidencyPolicy::DropCpuAfterUpload);
+        residency.set_policy(pinned, ResidencyPolicy::KeepCpuCopy);
+
+        assert_eq!(
+            residency.policy::<Mesh>(&pinned),
+            ResidencyPolicy::KeepCpuCopy
+        );
+        assert_eq!(
+            residency.policy::<Mesh>(&other),
+            ResidencyPolicy::DropCpuAfterUpload
+        );
+        assert_eq!(
+            residency.policy::<GpuMesh>(&other),
+            ResidencyPolicy::KeepCpuCopy
+        );
+    }
+
+    #[test]
+    fn make_resident_pins_assets_whose_policy_would_undo_it() {
+        let residency = ResidencyManager::new();
+        let uuid = AssetUUID::new();
+        residency.set_type_policy::<Mesh>(ResidencyPolicy::DropCpuAfterUpload);
+
+        residency.make_resident(uuid, ResidencyTier::Cpu);
+        let requests = residency.take_requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].resident);
+        assert!(residency.take_requests().is_empty());
+
+        residency.pin_for::<Mesh>(uuid, ResidencyTier::Cpu);
+        assert_eq!(
+            residency.policy::<Mesh>(&uuid),
+            ResidencyPolicy::KeepCpuCopy
+        );
+    }
+
+    #[test]
+    fn stats_and_peak_follow_the_residency_table() {
+        let residency = ResidencyManager::new();
+        let a = AssetUUID::new();
+        let b = AssetUUID::new();
+        residency.update_entries(HashMap::from([
+            (
+                a,
+                ResidencyEntry {
+                    residency: Residency::Both,
+                    cpu_bytes: 64,
+                    gpu_bytes: 64,
+                },
+            ),
+            (
+                b,
+                ResidencyEntry {
+                    residency: Residency::GpuOnly,
+                    cpu_bytes: 0,
+                    gpu_bytes: 32,
+                },
+            ),
+        ]));
+        assert_eq!(residency.residency(&b), Residency::GpuOnly);
+        assert_eq!(residency.get_usage_report().current_bytes, 160);
+
+        // A later refresh without a GPU size keeps the known one.
+        residency.update_entries(HashMap::from([(
+            b,
+            ResidencyEntry {
+                residency: Residency::GpuOnly,
+                ..Default::default()
+            },
+        )]));
+        let stats = residency.stats();
+        assert_eq!(stats.gpu_only, 1);
+        assert_eq!(stats.gpu_bytes, 32);
+        assert_eq!(residency.get_usage_report().peak_bytes, Some(160));
+    }
+}
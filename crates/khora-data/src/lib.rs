@@ -19,6 +19,7 @@
 #![warn(missing_docs)]
 
 pub mod assets;
+pub mod audio;
 pub mod ecs;
 pub mod flow;
 pub mod gpu;
@@ -26,6 +27,7 @@ pub mod render;
 pub mod scene;
 pub mod ui;
 
-pub use gpu::{GpuCache, ProjectionRegistry};
+pub use audio::AudioEvents;
+pub use gpu::{GpuCache, MipStreamer, ProjectionRegistry, ResidencyManager};
 pub use ui::components::*;
 // pub use ui::layout_view::*; // Temporarily commented out if unused or fix path
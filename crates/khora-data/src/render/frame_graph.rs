@@ -20,9 +20,10 @@
 //! GORNA strategy to negotiate, no behavior to swap. The submission step
 //! (`submit_frame_graph`) is invoked directly by `EngineCore`.
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+use khora_core::graph::{Dag, NodeId};
 use khora_core::renderer::api::command::CommandBufferId;
 use khora_core::renderer::GraphicsDevice;
 
@@ -39,15 +40,36 @@ pub enum ResourceId {
     Depth,
     /// Shadow atlas — written by `ShadowAgent`, read by `RenderAgent`.
     ShadowAtlas,
+    /// Posed vertices written by the skinning pre-pass.
+    SkinnedVertices,
     /// Custom resource keyed by an opaque integer (plugin / future use).
     Custom(u64),
 }
 
+/// Compositing layer of a pass.
+///
+/// Passes are submitted layer by layer, so a UI pass always lands on top of
+/// the scene no matter which agent recorded first. Dependencies only order
+/// passes within a layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[non_exhaustive]
+pub enum PassLayer {
+    /// Pre-scene compute work (e.g. skinning) whose output scene passes consume.
+    Prepare,
+    /// 3D scene passes (shadows, geometry, post-processing).
+    #[default]
+    Scene,
+    /// Screen-space UI, composited above the scene.
+    Ui,
+}
+
 /// Declarative description of a render pass.
 #[derive(Debug, Clone)]
 pub struct PassDescriptor {
     /// Debug-friendly pass name.
     pub name: &'static str,
+    /// Compositing layer the pass belongs to.
+    pub layer: PassLayer,
     /// Resources read by this pass.
     pub reads: Vec<ResourceId>,
     /// Resources written by this pass.
@@ -59,11 +81,18 @@ impl PassDescriptor {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            layer: PassLayer::Scene,
             reads: Vec::new(),
             writes: Vec::new(),
         }
     }
 
+    /// Places the pass on `layer`.
+    pub fn layer(mut self, layer: PassLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
     /// Adds a read dependency.
     pub fn reads(mut self, id: ResourceId) -> Self {
         self.reads.push(id);
@@ -111,6 +140,11 @@ impl FrameGraph {
         self.passes.len()
     }
 
+    /// Names of the recorded passes, in recording order.
+    pub fn pass_names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|p| p.descriptor.name).collect()
+    }
+
     /// Whether the graph has no recorded passes.
     pub fn is_empty(&self) -> bool {
         self.passes.is_empty()
@@ -124,13 +158,15 @@ impl FrameGraph {
 
     /// Drains the graph and returns command buffers in submission order.
     ///
-    /// The order is computed by a stable Kahn's algorithm over read/write
+    /// Passes are grouped by [`PassLayer`] first. Within the grouping the
+    /// order is computed by a stable Kahn's algorithm over read/write
     /// dependencies: a pass that writes resource `R` must run before any
     /// pass that reads `R`. Insertion order is the tie-breaker, so when the
     /// declared order is already valid (the common case) the result equals
     /// insertion order.
     pub fn compile(&mut self) -> Vec<CommandBufferId> {
-        let passes = std::mem::take(&mut self.passes);
+        let mut passes = std::mem::take(&mut self.passes);
+        passes.sort_by_key(|p| p.descriptor.layer);
         sorted_passes(passes)
             .into_iter()
             .map(|p| p.command_buffer)
@@ -168,16 +204,17 @@ fn sorted_passes(passes: Vec<RecordedPass>) -> Vec<RecordedPass> {
     // Edge i → j when pass i writes a resource that pass j reads (and j > i in
     // insertion order; the latest writer is the one that produces the value
     // for downstream readers).
+    let mut graph: Dag<&'static str, ResourceId> = Dag::new();
+    let ids: Vec<NodeId> = passes
+        .iter()
+        .map(|pass| graph.add_node(pass.descriptor.name))
+        .collect();
     let mut last_writer: HashMap<ResourceId, usize> = HashMap::new();
-    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
-    let mut in_deg = vec![0usize; n];
-
     for (i, pass) in passes.iter().enumerate() {
         for read in &pass.descriptor.reads {
             if let Some(&writer) = last_writer.get(read) {
                 if writer != i {
-                    adj[writer].push(i);
-                    in_deg[i] += 1;
+                    graph.add_edge(ids[writer], ids[i], *read);
                 }
             }
         }
@@ -186,28 +223,21 @@ fn sorted_passes(passes: Vec<RecordedPass>) -> Vec<RecordedPass> {
         }
     }
 
-    let mut queue: VecDeque<usize> = (0..n).filter(|&i| in_deg[i] == 0).collect();
-    let mut order = Vec::with_capacity(n);
-    let mut visited: HashSet<usize> = HashSet::new();
-    while let Some(i) = queue.pop_front() {
-        if !visited.insert(i) {
-            continue;
+    let order = match graph.topological_order() {
+        Ok(order) => order,
+        Err(err) => {
+            log::error!(
+                "FrameGraph: {} — submitting in recording order",
+                err.map(|id| graph[id])
+            );
+            ids
         }
-        order.push(i);
-        for &j in &adj[i] {
-            in_deg[j] -= 1;
-            if in_deg[j] == 0 {
-                queue.push_back(j);
-            }
-        }
-    }
-
-    debug_assert_eq!(order.len(), n, "FrameGraph cycle detected");
+    };
 
     let mut indexed: Vec<Option<RecordedPass>> = passes.into_iter().map(Some).collect();
     order
         .into_iter()
-        .map(|i| indexed[i].take().expect("pass already taken"))
+        .filter_map(|id| indexed[id.index()].take())
         .collect()
 }
 
@@ -275,6 +305,41 @@ mod tests {
         assert!(pos_b < pos_c);
     }
 
+    #[test]
+    fn ui_layer_is_submitted_after_scene_layer() {
+        let mut g = FrameGraph::new();
+        g.add_pass(
+            PassDescriptor::new("ui")
+                .layer(PassLayer::Ui)
+                .reads(ResourceId::Color)
+                .writes(ResourceId::Color),
+            buf(1),
+        );
+        g.add_pass(
+            PassDescriptor::new("scene").writes(ResourceId::Color),
+            buf(2),
+        );
+        assert_eq!(g.compile(), vec![buf(2), buf(1)]);
+    }
+
+    #[test]
+    fn prepare_layer_is_submitted_before_scene_layer() {
+        let mut g = FrameGraph::new();
+        g.add_pass(
+            PassDescriptor::new("shadow")
+                .reads(ResourceId::SkinnedVertices)
+                .writes(ResourceId::ShadowAtlas),
+            buf(1),
+        );
+        g.add_pass(
+            PassDescriptor::new("skinning")
+                .layer(PassLayer::Prepare)
+                .writes(ResourceId::SkinnedVertices),
+            buf(2),
+        );
+        assert_eq!(g.compile(), vec![buf(2), buf(1)]);
+    }
+
     #[test]
     fn clear_drops_passes() {
         let mut g = FrameGraph::new();
@@ -25,23 +25,33 @@
 
 mod editor_view;
 mod frame_graph;
+mod motion;
+mod picking;
 mod shadow_outputs;
+mod sort_key;
 mod world;
 
 pub use editor_view::EditorViewportOverride;
 pub use frame_graph::{
-    submit_frame_graph, FrameGraph, PassDescriptor, ResourceId, SharedFrameGraph,
+    submit_frame_graph, FrameGraph, PassDescriptor, PassLayer, ResourceId, SharedFrameGraph,
 };
-pub use shadow_outputs::{ShadowEntries, ShadowEntry};
-pub use world::{ExtractedLight, ExtractedMesh, ExtractedView, RenderWorld};
+pub use motion::PreviousFrame;
+pub use picking::{
+    decode_entity_id, decode_entity_pixel, encode_entity_id, EntityPicker, PickHandle, PickRequest,
+    SharedEntityPicker,
+};
+pub use shadow_outputs::{ShadowCascades, ShadowEntries, ShadowEntry};
+pub use sort_key::{batch_ranges, fold_id, SortKey};
+pub use world::{ExtractedLight, ExtractedMesh, ExtractedSkin, ExtractedView, RenderWorld};
 
 use khora_core::{
+    ecs::entity::EntityId,
     math::{Mat4, Vec3},
-    renderer::api::resource::ViewInfo,
+    renderer::api::{core::DepthMode, resource::ViewInfo},
     ServiceRegistry,
 };
 
-use crate::ecs::{Camera, GlobalTransform, World};
+use crate::ecs::{Camera, GlobalTransform, RenderLayers, World};
 
 /// Shadow result for a single light: view-projection matrix + atlas layer index.
 pub type ShadowResult = (khora_core::math::Mat4, i32);
@@ -56,8 +66,9 @@ pub type ShadowResult = (khora_core::math::Mat4, i32);
 /// also used by the editor for non-render concerns (camera prepare, gizmo
 /// space, etc.).
 pub fn extract_active_camera_view(world: &World) -> Option<ViewInfo> {
-    for (camera, global_transform) in world.query::<(&Camera, &GlobalTransform)>() {
-        if !camera.is_active {
+    for (entity, camera, global_transform) in world.query::<(EntityId, &Camera, &GlobalTransform)>()
+    {
+        if !camera.is_active || !world.is_enabled(entity) {
             continue;
         }
 
@@ -87,21 +98,33 @@ pub fn extract_active_camera_view(world: &World) -> Option<ViewInfo> {
 /// [`ShadowFlow`](crate::flow::ShadowFlow) so both flows agree on which
 /// view their data is built around (CSM frustum slicing in particular
 /// needs the same camera the lit pass will sample shadows from).
+///
+/// The view-projection follows the registered [`DepthMode`].
 pub fn primary_view(world: &World, services: &ServiceRegistry) -> Option<ExtractedView> {
-    for (camera, global_transform) in world.query::<(&Camera, &GlobalTransform)>() {
-        if !camera.is_active {
+    let depth_mode = services.get::<DepthMode>().copied().unwrap_or_default();
+    for (entity, camera, global_transform) in world.query::<(EntityId, &Camera, &GlobalTransform)>()
+    {
+        if !camera.is_active || !world.is_enabled(entity) {
             continue;
         }
         let position = global_transform.0.translation();
         let rotation = global_transform.0.rotation();
         let view_matrix = Mat4::from_quat(rotation.inverse()) * Mat4::from_translation(-position);
-        let view_proj = camera.projection_matrix() * view_matrix;
+        let view_proj = camera.projection_matrix_for(depth_mode) * view_matrix;
         return Some(ExtractedView {
             view_proj,
             position,
+            layers: world
+                .get::<RenderLayers>(entity)
+                .copied()
+                .unwrap_or(RenderLayers::ALL),
         });
     }
     services
         .get::<EditorViewportOverride>()
         .and_then(|o| o.get())
+        .map(|view| ExtractedView {
+            view_proj: depth_mode.apply(view.view_proj),
+            ..view
+        })
 }
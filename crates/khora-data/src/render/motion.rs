@@ -0,0 +1,135 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Previous-frame state needed to compute per-pixel motion vectors.
+
+use std::collections::HashMap;
+
+use khora_core::{
+    ecs::entity::EntityId,
+    math::{affine_transform::AffineTransform, Mat4},
+};
+
+use super::{ExtractedMesh, RenderWorld};
+
+/// The view and mesh transforms of the previously extracted frame.
+///
+/// `RenderFlow` captures it after each extraction and hands it to the next
+/// frame's [`RenderWorld`], so the motion vector lane can project every
+/// vertex with both the current and the previous matrices.
+#[derive(Debug, Clone, Default)]
+pub struct PreviousFrame {
+    /// View-projection of the previous frame's first view, if it had one.
+    pub view_proj: Option<Mat4>,
+    /// World transform of every entity mesh the previous frame drew.
+    pub transforms: HashMap<EntityId, AffineTransform>,
+}
+
+impl PreviousFrame {
+    /// Records the first view and the entity meshes of `render_world`.
+    pub fn capture(render_world: &RenderWorld) -> Self {
+        Self {
+            view_proj: render_world.views.first().map(|v| v.view_proj),
+            transforms: render_world
+                .meshes
+                .iter()
+                .filter_map(|m| Some((m.entity?, m.transform)))
+                .collect(),
+        }
+    }
+
+    /// The previous transform of `mesh`, or its current one when the
+    /// previous frame did not draw it (just spawned, or no entity).
+    pub fn transform_of(&self, mesh: &ExtractedMesh) -> AffineTransform {
+        mesh.entity
+            .and_then(|entity| self.transforms.get(&entity))
+            .copied()
+            .unwrap_or(mesh.transform)
+    }
+
+    /// The previous view-projection, or `current` on the first frame.
+    pub fn view_proj_or(&self, current: Mat4) -> Mat4 {
+        self.view_proj.unwrap_or(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{ExtractedView, SortKey};
+    use khora_core::asset::{AssetHandle, AssetUUID};
+    use khora_core::math::Vec3;
+    use khora_core::renderer::api::{
+        pipeline::enums::PrimitiveTopology, resource::BufferId, scene::GpuMesh, util::IndexFormat,
+    };
+
+    fn mesh(entity: Option<EntityId>, position: Vec3) -> ExtractedMesh {
+        ExtractedMesh {
+            entity,
+            transform: AffineTransform::from_translation(position),
+            cpu_mesh_uuid: AssetUUID::new(),
+            gpu_mesh: AssetHandle::new(GpuMesh {
+                vertex_buffer: BufferId(0),
+                index_buffer: BufferId(1),
+                index_count: 3,
+                index_format: IndexFormat::Uint32,
+                primitive_topology: PrimitiveTopology::TriangleList,
+            }),
+            material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: SortKey::default(),
+            layers: Default::default(),
+            skin: None,
+        }
+    }
+
+    fn entity(index: u32) -> EntityId {
+        EntityId {
+            index,
+            generation: 0,
+        }
+    }
+
+    #[test]
+    fn capture_records_view_and_entity_meshes() {
+        let mut world = RenderWorld::new();
+        let view_proj = Mat4::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        world.views.push(ExtractedView {
+            view_proj,
+            position: Vec3::ZERO,
+            layers: Default::default(),
+        });
+        world.meshes.push(mesh(Some(entity(1)), Vec3::Y));
+        world.meshes.push(mesh(None, Vec3::X));
+
+        let previous = PreviousFrame::capture(&world);
+        assert_eq!(previous.view_proj, Some(view_proj));
+        assert_eq!(previous.transforms.len(), 1);
+        assert_eq!(previous.transforms[&entity(1)].translation(), Vec3::Y);
+    }
+
+    #[test]
+    fn transform_of_falls_back_to_current() {
+        let mut world = RenderWorld::new();
+        world.meshes.push(mesh(Some(entity(1)), Vec3::Y));
+        let previous = PreviousFrame::capture(&world);
+
+        let moved = mesh(Some(entity(1)), Vec3::Z);
+        assert_eq!(previous.transform_of(&moved).translation(), Vec3::Y);
+        let spawned = mesh(Some(entity(2)), Vec3::Z);
+        assert_eq!(previous.transform_of(&spawned).translation(), Vec3::Z);
+        assert_eq!(previous.view_proj_or(Mat4::IDENTITY), Mat4::IDENTITY);
+    }
+}
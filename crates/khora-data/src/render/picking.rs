@@ -0,0 +1,179 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pixel-exact entity picking through the ID pass.
+//!
+//! [`EntityPicker::pick_pixel`] queues a request and returns a
+//! [`PickHandle`]. While requests are pending, the render agent runs the ID
+//! pass, which draws every mesh's entity ID into an integer target and reads
+//! back the pixel under each request a frame or two later. The handle then
+//! holds the entity drawn at that pixel, or `None` over the background.
+//!
+//! ```ignore
+//! let picker = services.get::<SharedEntityPicker>().unwrap();
+//! let pick = picker.lock().unwrap().pick_pixel(cursor);
+//! // ...on a later frame:
+//! if let Some(entity) = pick.try_take() {
+//!     log::info!("picked {:?}", entity);
+//! }
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::{ecs::entity::EntityId, math::Vec2};
+
+/// The [`EntityPicker`] shared through the service registry.
+pub type SharedEntityPicker = Arc<Mutex<EntityPicker>>;
+
+/// Packs `entity` into the two `u32` channels of an ID pass texel.
+///
+/// The index is stored plus one so that `0` marks pixels no mesh covers.
+pub fn encode_entity_id(entity: EntityId) -> [u32; 2] {
+    [entity.index.wrapping_add(1), entity.generation]
+}
+
+/// Unpacks an ID pass texel written by [`encode_entity_id`].
+pub fn decode_entity_id(texel: [u32; 2]) -> Option<EntityId> {
+    let index = texel[0].checked_sub(1)?;
+    Some(EntityId {
+        index,
+        generation: texel[1],
+    })
+}
+
+/// Unpacks an ID pass texel from the little-endian bytes read back from
+/// the GPU.
+pub fn decode_entity_pixel(bytes: &[u8]) -> Option<EntityId> {
+    let channel = |i: usize| {
+        bytes
+            .get(i * 4..i * 4 + 4)
+            .and_then(|b| b.try_into().ok())
+            .map(u32::from_le_bytes)
+    };
+    decode_entity_id([channel(0)?, channel(1)?])
+}
+
+/// The pending result of a [`EntityPicker::pick_pixel`] request.
+#[derive(Clone, Default)]
+pub struct PickHandle {
+    result: Arc<Mutex<Option<Option<EntityId>>>>,
+}
+
+impl PickHandle {
+    /// Returns `true` once the pick resolved and was not taken yet.
+    pub fn is_ready(&self) -> bool {
+        self.result.lock().is_ok_and(|r| r.is_some())
+    }
+
+    /// Takes the result if the pick resolved: the entity under the pixel,
+    /// or `None` when no mesh covers it.
+    pub fn try_take(&self) -> Option<Option<EntityId>> {
+        self.result.lock().ok().and_then(|mut r| r.take())
+    }
+}
+
+/// A pick waiting for the ID pass.
+pub struct PickRequest {
+    /// Pixel to read, in physical pixels from the top-left of the surface.
+    pub screen_pos: Vec2,
+    handle: PickHandle,
+}
+
+impl PickRequest {
+    /// Resolves the request's handle with `entity`.
+    pub fn resolve(self, entity: Option<EntityId>) {
+        if let Ok(mut result) = self.handle.result.lock() {
+            *result = Some(entity);
+        }
+    }
+}
+
+/// Queue of pixel picks served by the ID pass.
+#[derive(Default)]
+pub struct EntityPicker {
+    pending: Vec<PickRequest>,
+}
+
+impl EntityPicker {
+    /// Creates an empty picker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the entity drawn at `screen_pos`, in physical pixels from
+    /// the top-left of the surface. The result arrives a few frames later.
+    pub fn pick_pixel(&mut self, screen_pos: Vec2) -> PickHandle {
+        let handle = PickHandle::default();
+        self.pending.push(PickRequest {
+            screen_pos,
+            handle: handle.clone(),
+        });
+        handle
+    }
+
+    /// Whether picks wait for the ID pass.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Takes the pending picks; the ID pass resolves each one.
+    pub fn take_requests(&mut self) -> Vec<PickRequest> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entity_id_round_trips_through_a_texel() {
+        let entity = EntityId {
+            index: 0,
+            generation: 7,
+        };
+        assert_eq!(encode_entity_id(entity), [1, 7]);
+        assert_eq!(decode_entity_id(encode_entity_id(entity)), Some(entity));
+        assert_eq!(decode_entity_id([0, 0]), None);
+
+        let bytes: Vec<u8> = [5u32, 2].iter().flat_map(|c| c.to_le_bytes()).collect();
+        assert_eq!(
+            decode_entity_pixel(&bytes),
+            Some(EntityId {
+                index: 4,
+                generation: 2
+            })
+        );
+        assert_eq!(decode_entity_pixel(&bytes[..4]), None);
+    }
+
+    #[test]
+    fn test_pick_resolves_its_handle_once() {
+        let mut picker = EntityPicker::new();
+        let handle = picker.pick_pixel(Vec2::new(3.0, 4.0));
+        assert!(picker.has_pending());
+        assert!(!handle.is_ready());
+
+        let requests = picker.take_requests();
+        assert!(!picker.has_pending());
+        assert_eq!(requests[0].screen_pos, Vec2::new(3.0, 4.0));
+        for request in requests {
+            request.resolve(None);
+        }
+
+        assert!(handle.is_ready());
+        assert_eq!(handle.try_take(), Some(None));
+        assert_eq!(handle.try_take(), None);
+    }
+}
@@ -25,14 +25,52 @@
 use std::collections::HashMap;
 
 use khora_core::math::Mat4;
+use khora_core::renderer::api::scene::MAX_SHADOW_CASCADES;
 
 /// Shadow data computed by the shadow pass for a single light.
 #[derive(Debug, Clone, Copy)]
 pub struct ShadowEntry {
     /// Light's view-projection matrix used to sample the shadow atlas.
     pub view_proj: Mat4,
-    /// Layer index inside the shadow atlas.
+    /// Layer index inside the shadow atlas. With cascades, the layer of
+    /// the first cascade; the others follow it.
     pub atlas_index: i32,
+    /// Cascades of a directional light, when the shadow lane rendered them.
+    pub cascades: Option<ShadowCascades>,
+}
+
+/// The cascades splitting a directional light's shadow along the camera
+/// frustum, nearest first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowCascades {
+    /// Number of cascades in use, at most [`MAX_SHADOW_CASCADES`].
+    pub count: usize,
+    /// Light view-projection of each cascade.
+    pub view_proj: [Mat4; MAX_SHADOW_CASCADES],
+    /// View depth, in meters, where each cascade ends.
+    pub splits: [f32; MAX_SHADOW_CASCADES],
+    /// World-space size of one shadow texel in each cascade.
+    pub texel_sizes: [f32; MAX_SHADOW_CASCADES],
+}
+
+impl ShadowCascades {
+    /// Cascades of a light that has a single shadow map covering the
+    /// whole view.
+    pub fn single(view_proj: Mat4) -> Self {
+        Self {
+            count: 1,
+            view_proj: [view_proj; MAX_SHADOW_CASCADES],
+            splits: [f32::MAX; MAX_SHADOW_CASCADES],
+            texel_sizes: [1.0; MAX_SHADOW_CASCADES],
+        }
+    }
+
+    /// The cascade covering `view_depth`, if any.
+    pub fn cascade_at(&self, view_depth: f32) -> Option<usize> {
+        self.splits[..self.count]
+            .iter()
+            .position(|&split| view_depth <= split)
+    }
 }
 
 /// Per-frame shadow lookup keyed by light index in `RenderWorld.lights`.
@@ -19,13 +19,31 @@
 //! once per frame in the engine's hot loop.
 
 use khora_core::{
-    asset::{AssetHandle, AssetUUID, Material},
-    math::{affine_transform::AffineTransform, Vec3},
-    renderer::{api::scene::GpuMesh, light::LightType},
+    asset::{AlphaMode, AssetHandle, AssetUUID, Material, MaterialParams},
+    ecs::entity::EntityId,
+    math::{affine_transform::AffineTransform, LinearRgba, Vec3},
+    renderer::{
+        api::{
+            resource::BufferId,
+            scene::{GpuMesh, SkinVertexLayout, SkinningMode},
+        },
+        light::LightType,
+    },
 };
 
+use std::ops::Range;
+
+use crate::ecs::{
+    AntiAliasing, AutoExposure, Exposure, MaterialOverride, PostProcess, RenderLayers,
+};
+
+use super::motion::PreviousFrame;
+use super::sort_key::{batch_ranges, SortKey};
+
 /// Flat, GPU-friendly representation of a single mesh to render.
 pub struct ExtractedMesh {
+    /// Entity the mesh was extracted from, if any; read by the ID pass.
+    pub entity: Option<EntityId>,
     /// World-space transform derived from `GlobalTransform`.
     pub transform: AffineTransform,
     /// UUID of the loaded CPU mesh — useful for debugging or mapping.
@@ -34,6 +52,72 @@ pub struct ExtractedMesh {
     pub gpu_mesh: AssetHandle<GpuMesh>,
     /// Optional material handle.  `None` means use a default material.
     pub material: Option<AssetHandle<Box<dyn Material>>>,
+    /// UUID of the material asset, when `material` is set.
+    pub material_uuid: Option<AssetUUID>,
+    /// Per-entity parameter overrides applied on top of `material`.
+    pub material_override: Option<MaterialOverride>,
+    /// Draw-order key assigned during extraction; see [`SortKey`].
+    pub sort_key: SortKey,
+    /// Render layers the mesh is on.
+    pub layers: RenderLayers,
+    /// GPU skinning inputs, for meshes bound to a skeleton.
+    pub skin: Option<ExtractedSkin>,
+}
+
+impl ExtractedMesh {
+    /// Resolves the material parameters to upload for this draw: defaults,
+    /// then the material's values, then the entity's overrides.
+    pub fn material_params(&self) -> MaterialParams {
+        let params = self
+            .material
+            .as_ref()
+            .map(|m| MaterialParams::from_material(&***m))
+            .unwrap_or_default();
+        match &self.material_override {
+            Some(o) => o.apply(params),
+            None => params,
+        }
+    }
+
+    /// Whether the mesh's material is opaque; meshes without a material are.
+    pub fn is_opaque(&self) -> bool {
+        self.material
+            .as_ref()
+            .is_none_or(|m| m.alpha_mode() == AlphaMode::Opaque)
+    }
+}
+
+/// GPU skinning inputs of a skinned mesh.
+///
+/// `gpu_mesh` is the entity's skin output mesh: it shares the bind pose's
+/// index buffer and, when [`needs_pre_pass`](Self::needs_pre_pass) holds,
+/// receives the posed vertices from the skinning compute pass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtractedSkin {
+    /// Mode the mesh is skinned with this frame.
+    pub mode: SkinningMode,
+    /// Mode forced by the entity's `Skin`, if any.
+    pub forced_mode: Option<SkinningMode>,
+    /// Number of passes drawing the mesh this frame.
+    pub passes: u32,
+    /// Bind-pose vertices (storage buffer).
+    pub bind_pose: BufferId,
+    /// Per-vertex joint influences (storage buffer).
+    pub influences: BufferId,
+    /// Mesh-space joint matrices (storage buffer).
+    pub joint_matrices: BufferId,
+    /// Layout of the bind-pose and output vertices.
+    pub layout: SkinVertexLayout,
+}
+
+impl ExtractedSkin {
+    /// Returns `true` if the compute pre-pass must write the posed vertices.
+    ///
+    /// Vertex-shader skinning only covers the main scene pass, so any extra
+    /// pass (e.g. a shadow map) still needs the pre-pass output.
+    pub fn needs_pre_pass(&self) -> bool {
+        self.mode == SkinningMode::ComputePrePass || self.passes > 1
+    }
 }
 
 /// Flat, GPU-friendly representation of a light source.
@@ -49,6 +133,19 @@ pub struct ExtractedLight {
     pub shadow_view_proj: khora_core::math::Mat4,
     /// Index into the shadow atlas, or `None` if the light casts no shadow.
     pub shadow_atlas_index: Option<i32>,
+    /// Render layers the light illuminates and casts shadows from.
+    pub layers: RenderLayers,
+}
+
+impl ExtractedLight {
+    /// Returns `true` if the light renders a shadow map.
+    pub fn casts_shadows(&self) -> bool {
+        match &self.light_type {
+            LightType::Directional(l) => l.shadow_enabled,
+            LightType::Point(l) => l.shadow_enabled,
+            LightType::Spot(l) => l.shadow_enabled,
+        }
+    }
 }
 
 /// Flat representation of a camera view.
@@ -58,6 +155,8 @@ pub struct ExtractedView {
     pub view_proj: khora_core::math::Mat4,
     /// World-space camera position.
     pub position: Vec3,
+    /// Render layers the view draws.
+    pub layers: RenderLayers,
 }
 
 /// All scene data needed to render one frame.
@@ -72,6 +171,26 @@ pub struct RenderWorld {
     pub lights: Vec<ExtractedLight>,
     /// Active camera views.
     pub views: Vec<ExtractedView>,
+    /// Background color from a [`Sky`](crate::ecs::Sky), replacing the
+    /// clear color when set.
+    pub sky: Option<LinearRgba>,
+    /// Eye adaptation settings of the first view's camera; when set, the
+    /// scene is rendered to an HDR target and metered before tone mapping.
+    pub auto_exposure: Option<AutoExposure>,
+    /// Manual exposure of the first view's camera; lanes scale light
+    /// intensities by it, or by [`Exposure::default`] when unset.
+    pub exposure: Option<Exposure>,
+    /// Anti-aliasing settings of the first view's camera; when unset, the
+    /// scene is not anti-aliased.
+    pub anti_aliasing: Option<AntiAliasing>,
+    /// Post-processing stack of the first view's camera; when set, the
+    /// scene is rendered to an HDR target and post-processed before display.
+    pub post_process: Option<PostProcess>,
+    /// View and mesh transforms of the previous frame, for motion vectors.
+    pub previous: PreviousFrame,
+    /// Estimated overdraw of the first view: the summed screen coverage of
+    /// the drawn meshes' bounds, in screens. Drives the depth prepass.
+    pub depth_complexity: f32,
 }
 
 impl RenderWorld {
@@ -85,6 +204,61 @@ impl RenderWorld {
         self.meshes.clear();
         self.lights.clear();
         self.views.clear();
+        self.sky = None;
+        self.auto_exposure = None;
+        self.exposure = None;
+        self.anti_aliasing = None;
+        self.post_process = None;
+        self.previous = PreviousFrame::default();
+        self.depth_complexity = 0.0;
+    }
+
+    /// Stable-sorts the meshes by their [`SortKey`], so draws sharing a
+    /// pipeline, material and mesh become contiguous.
+    pub fn sort_meshes(&mut self) {
+        self.meshes.sort_by_key(|m| m.sort_key);
+    }
+
+    /// Index ranges of contiguous meshes sharing the same mesh and material.
+    ///
+    /// Meaningful after [`sort_meshes`](Self::sort_meshes); each range can be
+    /// drawn with a single vertex/index buffer bind.
+    pub fn mesh_batches(&self) -> Vec<Range<usize>> {
+        batch_ranges(&self.meshes, |a, b| {
+            a.cpu_mesh_uuid == b.cpu_mesh_uuid && a.material_uuid == b.material_uuid
+        })
+    }
+
+    /// Counts the passes drawing each skinned mesh and resolves its
+    /// [`SkinningMode`].
+    ///
+    /// A mesh is drawn once by the main view plus once per shadow-casting
+    /// light sharing one of its layers. Call after [`cull_layers`](Self::cull_layers).
+    pub fn resolve_skinning(&mut self) {
+        for mesh in &mut self.meshes {
+            let Some(skin) = mesh.skin.as_mut() else {
+                continue;
+            };
+            let shadow_passes = self
+                .lights
+                .iter()
+                .filter(|l| l.casts_shadows() && l.layers.intersects(mesh.layers))
+                .count() as u32;
+            skin.passes = 1 + shadow_passes;
+            skin.mode = skin
+                .forced_mode
+                .unwrap_or_else(|| SkinningMode::for_pass_count(skin.passes));
+        }
+    }
+
+    /// Drops meshes that share no layer with the primary view.
+    ///
+    /// Without a view every mesh is kept.
+    pub fn cull_layers(&mut self) {
+        let Some(layers) = self.views.first().map(|v| v.layers) else {
+            return;
+        };
+        self.meshes.retain(|m| m.layers.intersects(layers));
     }
 
     /// Returns the number of directional lights.
@@ -115,6 +289,9 @@ impl RenderWorld {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use khora_core::renderer::api::{
+        pipeline::enums::PrimitiveTopology, resource::BufferId, util::IndexFormat,
+    };
     use khora_core::renderer::light::{DirectionalLight, PointLight, SpotLight};
 
     #[test]
@@ -134,6 +311,7 @@ mod tests {
             direction: Vec3::new(0.0, -1.0, 0.0),
             shadow_view_proj: khora_core::math::Mat4::IDENTITY,
             shadow_atlas_index: None,
+            layers: Default::default(),
         });
         assert_eq!(world.lights.len(), 1);
 
@@ -142,6 +320,120 @@ mod tests {
         assert!(world.meshes.is_empty());
     }
 
+    fn mesh(mesh: AssetUUID, key: SortKey) -> ExtractedMesh {
+        ExtractedMesh {
+            entity: None,
+            transform: AffineTransform::default(),
+            cpu_mesh_uuid: mesh,
+            gpu_mesh: AssetHandle::new(GpuMesh {
+                vertex_buffer: BufferId(0),
+                index_buffer: BufferId(1),
+                index_count: 3,
+                index_format: IndexFormat::Uint32,
+                primitive_topology: PrimitiveTopology::TriangleList,
+            }),
+            material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: key,
+            layers: Default::default(),
+            skin: None,
+        }
+    }
+
+    #[test]
+    fn sort_meshes_groups_batches() {
+        let a = AssetUUID::new();
+        let b = AssetUUID::new();
+        let mut world = RenderWorld::new();
+        world.meshes.push(mesh(a, SortKey::opaque(0, 0, 1, 5.0)));
+        world.meshes.push(mesh(b, SortKey::opaque(0, 0, 2, 1.0)));
+        world.meshes.push(mesh(a, SortKey::opaque(0, 0, 1, 2.0)));
+
+        world.sort_meshes();
+        let order: Vec<_> = world.meshes.iter().map(|m| m.cpu_mesh_uuid).collect();
+        assert_eq!(order, vec![a, a, b]);
+        // Front to back within the batch.
+        assert!(world.meshes[0].sort_key < world.meshes[1].sort_key);
+        assert_eq!(world.mesh_batches(), vec![0..2, 2..3]);
+    }
+
+    #[test]
+    fn cull_layers_keeps_meshes_seen_by_the_view() {
+        let mut world = RenderWorld::new();
+        world
+            .meshes
+            .push(mesh(AssetUUID::new(), SortKey::default()));
+        let mut weapon = mesh(AssetUUID::new(), SortKey::default());
+        weapon.layers = RenderLayers::layer(1);
+        world.meshes.push(weapon);
+
+        world.cull_layers();
+        assert_eq!(world.meshes.len(), 2, "no view keeps every mesh");
+
+        world.views.push(ExtractedView {
+            view_proj: khora_core::math::Mat4::IDENTITY,
+            position: Vec3::ZERO,
+            layers: RenderLayers::layer(1),
+        });
+        world.cull_layers();
+        assert_eq!(world.meshes.len(), 1);
+        assert_eq!(world.meshes[0].layers, RenderLayers::layer(1));
+    }
+
+    #[test]
+    fn resolve_skinning_counts_shadow_passes() {
+        let skin = ExtractedSkin {
+            mode: SkinningMode::VertexShader,
+            forced_mode: None,
+            passes: 0,
+            bind_pose: BufferId(2),
+            influences: BufferId(3),
+            joint_matrices: BufferId(4),
+            layout: SkinVertexLayout {
+                vertex_count: 3,
+                stride: 8,
+                normal_offset: 3,
+                uv_offset: 6,
+            },
+        };
+        let mut world = RenderWorld::new();
+        let mut single = mesh(AssetUUID::new(), SortKey::default());
+        single.skin = Some(skin);
+        world.meshes.push(single);
+
+        world.resolve_skinning();
+        let resolved = world.meshes[0].skin.unwrap();
+        assert_eq!(resolved.passes, 1);
+        assert_eq!(resolved.mode, SkinningMode::VertexShader);
+        assert!(!resolved.needs_pre_pass());
+
+        world.lights.push(ExtractedLight {
+            light_type: LightType::Directional(DirectionalLight {
+                shadow_enabled: true,
+                ..Default::default()
+            }),
+            position: Vec3::ZERO,
+            direction: Vec3::new(0.0, -1.0, 0.0),
+            shadow_view_proj: khora_core::math::Mat4::IDENTITY,
+            shadow_atlas_index: None,
+            layers: Default::default(),
+        });
+        world.resolve_skinning();
+        let resolved = world.meshes[0].skin.unwrap();
+        assert_eq!(resolved.passes, 2);
+        assert_eq!(resolved.mode, SkinningMode::ComputePrePass);
+
+        world.meshes[0].skin.as_mut().unwrap().forced_mode = Some(SkinningMode::VertexShader);
+        world.resolve_skinning();
+        let resolved = world.meshes[0].skin.unwrap();
+        assert_eq!(resolved.mode, SkinningMode::VertexShader);
+        assert!(
+            resolved.needs_pre_pass(),
+            "the shadow pass still needs posed vertices"
+        );
+    }
+
     #[test]
     fn light_count_methods_filter_by_type() {
         let mut world = RenderWorld::new();
@@ -151,6 +443,7 @@ mod tests {
             direction: Vec3::new(0.0, -1.0, 0.0),
             shadow_view_proj: khora_core::math::Mat4::IDENTITY,
             shadow_atlas_index: None,
+            layers: Default::default(),
         });
         world.lights.push(ExtractedLight {
             light_type: LightType::Point(PointLight::default()),
@@ -158,6 +451,7 @@ mod tests {
             direction: Vec3::ZERO,
             shadow_view_proj: khora_core::math::Mat4::IDENTITY,
             shadow_atlas_index: None,
+            layers: Default::default(),
         });
         world.lights.push(ExtractedLight {
             light_type: LightType::Point(PointLight::default()),
@@ -165,6 +459,7 @@ mod tests {
             direction: Vec3::ZERO,
             shadow_view_proj: khora_core::math::Mat4::IDENTITY,
             shadow_atlas_index: None,
+            layers: Default::default(),
         });
         world.lights.push(ExtractedLight {
             light_type: LightType::Spot(SpotLight::default()),
@@ -172,6 +467,7 @@ mod tests {
             direction: Vec3::new(0.0, -1.0, 0.0),
             shadow_view_proj: khora_core::math::Mat4::IDENTITY,
             shadow_atlas_index: None,
+            layers: Default::default(),
         });
 
         assert_eq!(world.directional_light_count(), 1);
@@ -16,10 +16,12 @@
 //!
 //! Uses inventory-based component registration to handle all component types
 //! automatically. Each component is serialized as a base64-encoded blob keyed
-//! by type name in a human-readable RON structure.
+//! by type name in a human-readable RON or JSON structure.
 
-use super::{DeserializationError, SerializationError, SerializationStrategy};
-use crate::ecs::World;
+use super::{
+    remap, saved_entities, DeserializationError, SerializationError, SerializationStrategy,
+};
+use crate::ecs::{EntityMap, World};
 use crate::scene::registry::ComponentRegistration;
 use khora_core::ecs::entity::EntityId;
 use serde::{Deserialize, Serialize};
@@ -50,30 +52,67 @@ pub struct SceneDefinition {
     pub entities: Vec<EntityDefinition>,
 }
 
+/// The text format a [`DefinitionSerializationStrategy`] reads and writes.
+///
+/// Both formats carry the same [`SceneDefinition`] schema, so a scene
+/// converts between them without loss.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DefinitionFormat {
+    /// Pretty-printed RON, the default for hand-edited scenes.
+    #[default]
+    Ron,
+    /// Pretty-printed JSON, for external tools and review workflows.
+    Json,
+}
+
+impl DefinitionFormat {
+    /// Returns the strategy ID recorded in the header of scenes written in
+    /// this format.
+    pub fn strategy_id(self) -> &'static str {
+        match self {
+            DefinitionFormat::Ron => "KH_DEFINITION_RON_V1",
+            DefinitionFormat::Json => "KH_DEFINITION_JSON_V1",
+        }
+    }
+}
+
 /// A serialization strategy that uses a stable, intermediate representation.
 ///
 /// Iterates all registered components via inventory to serialize every
-/// component on every entity. The output is human-readable RON with
-/// base64-encoded binary component data.
+/// component on every entity. The output is human-readable RON or JSON, see
+/// [`DefinitionFormat`], with base64-encoded binary component data.
 #[derive(Default)]
-pub struct DefinitionSerializationStrategy;
+pub struct DefinitionSerializationStrategy {
+    format: DefinitionFormat,
+}
 
 impl DefinitionSerializationStrategy {
-    /// Creates a new `DefinitionSerializationStrategy`.
+    /// Creates a new `DefinitionSerializationStrategy` writing RON.
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Creates a `DefinitionSerializationStrategy` writing `format`.
+    pub fn with_format(format: DefinitionFormat) -> Self {
+        Self { format }
+    }
+
+    /// Returns the format this strategy reads and writes.
+    pub fn format(&self) -> DefinitionFormat {
+        self.format
     }
 }
 
 impl SerializationStrategy for DefinitionSerializationStrategy {
     fn get_strategy_id(&self) -> &'static str {
-        "KH_DEFINITION_RON_V1"
+        self.format.strategy_id()
     }
 
     fn serialize(&self, world: &World) -> Result<Vec<u8>, SerializationError> {
         let mut entity_defs = Vec::new();
 
-        for entity_id in world.iter_entities() {
+        for entity_id in saved_entities(world) {
             let mut component_defs = Vec::new();
 
             // Iterate ALL registered components via inventory.
@@ -98,22 +137,42 @@ impl SerializationStrategy for DefinitionSerializationStrategy {
             entities: entity_defs,
         };
 
-        let pretty_config = ron::ser::PrettyConfig::default().indentor("  ".to_string());
-        ron::ser::to_string_pretty(&scene_definition, pretty_config)
-            .map(|s| s.into_bytes())
-            .map_err(|e| SerializationError::ProcessingFailed(e.to_string()))
+        match self.format {
+            DefinitionFormat::Ron => {
+                let pretty_config = ron::ser::PrettyConfig::default().indentor("  ".to_string());
+                ron::ser::to_string_pretty(&scene_definition, pretty_config)
+                    .map(|s| s.into_bytes())
+                    .map_err(|e| SerializationError::ProcessingFailed(e.to_string()))
+            }
+            DefinitionFormat::Json => serde_json::to_vec_pretty(&scene_definition)
+                .map_err(|e| SerializationError::ProcessingFailed(e.to_string())),
+        }
     }
 
     fn deserialize(&self, data: &[u8], world: &mut World) -> Result<(), DeserializationError> {
-        let scene_def: SceneDefinition = ron::de::from_bytes(data)
-            .map_err(|e| DeserializationError::InvalidFormat(e.to_string()))?;
+        self.instantiate(data, world).map(|_| ())
+    }
+
+    fn instantiate(
+        &self,
+        data: &[u8],
+        world: &mut World,
+    ) -> Result<Vec<EntityId>, DeserializationError> {
+        let scene_def: SceneDefinition = match self.format {
+            DefinitionFormat::Ron => ron::de::from_bytes(data)
+                .map_err(|e| DeserializationError::InvalidFormat(e.to_string()))?,
+            DefinitionFormat::Json => serde_json::from_slice(data)
+                .map_err(|e| DeserializationError::InvalidFormat(e.to_string()))?,
+        };
 
         let mut id_map = HashMap::<EntityId, EntityId>::new();
+        let mut spawned = Vec::with_capacity(scene_def.entities.len());
 
         // First pass: spawn all entities.
         for entity_def in &scene_def.entities {
             let new_id = world.spawn(());
             id_map.insert(entity_def.id, new_id);
+            spawned.push(new_id);
         }
 
         // Second pass: add components.
@@ -136,7 +195,11 @@ impl SerializationStrategy for DefinitionSerializationStrategy {
             }
         }
 
-        Ok(())
+        remap::remap_parents(world, &id_map);
+        remap::remap_children(world, &id_map);
+        remap::break_parent_cycles(world, &id_map);
+        remap::remap_entity_refs(world, &EntityMap::from(id_map));
+        Ok(spawned)
     }
 }
 
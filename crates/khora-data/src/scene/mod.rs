@@ -19,8 +19,12 @@ pub mod registry;
 
 mod archetype_strategy;
 mod definition_strategy;
+mod prefab;
 mod recipe_strategy;
+pub(crate) mod remap;
+mod static_batching;
 mod strategy;
+mod validation;
 
 pub use recipe::*;
 pub use registry::*;
@@ -28,4 +32,6 @@ pub use registry::*;
 pub use archetype_strategy::*;
 pub use definition_strategy::*;
 pub use recipe_strategy::*;
+pub use static_batching::*;
 pub use strategy::*;
+pub use validation::*;
@@ -0,0 +1,76 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spawning saved scenes into a live world as prefabs.
+
+use super::{
+    DefinitionFormat, DefinitionSerializationStrategy, DeserializationError,
+    RecipeSerializationStrategy, SerializationStrategy,
+};
+use crate::ecs::World;
+use khora_core::ecs::entity::EntityId;
+use khora_core::scene::{SceneFile, HEADER_MAGIC_BYTES};
+
+impl World {
+    /// Spawns the entities of a saved scene into this world, next to the
+    /// ones already here.
+    ///
+    /// Each saved entity gets a fresh `EntityId`. `Parent` and `Children`
+    /// are rewritten to the new ids, and references to entities outside the
+    /// prefab are dropped, so its top-level entities become roots. The
+    /// prefab can be instantiated any number of times.
+    ///
+    /// Works with the Definition and Recipe strategies; an Archetype scene
+    /// mirrors a whole world's memory layout and is refused.
+    ///
+    /// # Returns
+    /// The spawned entities, in the order the prefab stores them.
+    pub fn instantiate(
+        &mut self,
+        prefab: &SceneFile,
+    ) -> Result<Vec<EntityId>, DeserializationError> {
+        if prefab.header.magic_bytes != HEADER_MAGIC_BYTES
+            || prefab.header.payload_length != prefab.payload.len() as u64
+        {
+            return Err(DeserializationError::InvalidFormat(
+                "invalid scene header".to_string(),
+            ));
+        }
+        let strategy_id = prefab.header.strategy_id_str().ok_or_else(|| {
+            DeserializationError::InvalidFormat("invalid strategy id".to_string())
+        })?;
+        let strategy = prefab_strategy(strategy_id).ok_or_else(|| {
+            DeserializationError::InvalidFormat(format!(
+                "strategy {strategy_id} cannot be instantiated"
+            ))
+        })?;
+        strategy.instantiate(&prefab.payload, self)
+    }
+}
+
+/// Returns the strategy that reads `strategy_id` payloads into a populated
+/// world.
+fn prefab_strategy(strategy_id: &str) -> Option<Box<dyn SerializationStrategy>> {
+    let recipe = RecipeSerializationStrategy::new();
+    if strategy_id == recipe.get_strategy_id() {
+        return Some(Box::new(recipe));
+    }
+    [DefinitionFormat::Ron, DefinitionFormat::Json]
+        .into_iter()
+        .find(|format| format.strategy_id() == strategy_id)
+        .map(|format| {
+            Box::new(DefinitionSerializationStrategy::with_format(format))
+                as Box<dyn SerializationStrategy>
+        })
+}
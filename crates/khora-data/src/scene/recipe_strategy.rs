@@ -17,9 +17,12 @@
 //! Uses `inventory`-based component registration for open serialization.
 //! All component types that derive `Component` are automatically handled.
 
-use super::{DeserializationError, SerializationError, SerializationStrategy};
+use super::{
+    remap, saved_entities, DeserializationError, SerializationError, SerializationStrategy,
+    SCENE_DECODE_LIMIT,
+};
 use crate::{
-    ecs::World,
+    ecs::{EntityMap, World},
     scene::{registry::ComponentRegistration, SceneCommand, SceneRecipe},
 };
 use bincode::config;
@@ -55,7 +58,7 @@ impl SerializationStrategy for RecipeSerializationStrategy {
         let mut commands = Vec::new();
 
         // 1. Collect nodes and edges for topological sort.
-        let nodes: Vec<EntityId> = world.iter_entities().collect();
+        let nodes: Vec<EntityId> = saved_entities(world).collect();
         let mut edges: Vec<(EntityId, EntityId)> = Vec::new();
 
         // Direct Parent access for topological sort (Parent is always registered).
@@ -66,8 +69,8 @@ impl SerializationStrategy for RecipeSerializationStrategy {
         }
 
         // 2. Topological sort.
-        let sorted_entities = topological_sort(nodes, edges).map_err(|_| {
-            SerializationError::ProcessingFailed("Cycle detected in scene hierarchy.".to_string())
+        let sorted_entities = topological_sort(nodes, edges).map_err(|err| {
+            SerializationError::ProcessingFailed(format!("Scene hierarchy has a {}.", err))
         })?;
 
         // 3. For each entity, iterate ALL component registrations and emit commands.
@@ -99,22 +102,40 @@ impl SerializationStrategy for RecipeSerializationStrategy {
     }
 
     fn deserialize(&self, data: &[u8], world: &mut World) -> Result<(), DeserializationError> {
-        let (recipe, _): (SceneRecipe, _) = bincode::decode_from_slice(data, config::standard())
-            .map_err(|e| DeserializationError::InvalidFormat(e.to_string()))?;
+        self.instantiate(data, world).map(|_| ())
+    }
+
+    fn instantiate(
+        &self,
+        data: &[u8],
+        world: &mut World,
+    ) -> Result<Vec<EntityId>, DeserializationError> {
+        let (recipe, _): (SceneRecipe, _) = bincode::decode_from_slice(
+            data,
+            config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+        )
+        .map_err(|e| DeserializationError::InvalidFormat(e.to_string()))?;
 
         let mut id_map = HashMap::<EntityId, EntityId>::new();
+        let mut spawned = Vec::new();
 
         for command in recipe.commands {
             match command {
                 SceneCommand::Spawn { id } => {
                     let new_id = world.spawn(());
                     id_map.insert(id, new_id);
+                    spawned.push(new_id);
                 }
                 SceneCommand::AddComponent {
                     entity_id,
                     component_type,
                     component_data,
                 } => {
+                    // The hierarchy is rebuilt from `SetParent` commands only;
+                    // a serialized `Parent` still holds the saved entity id.
+                    if component_type == "Parent" {
+                        continue;
+                    }
                     if let Some(new_id) = id_map.get(&entity_id) {
                         // Look up the registration by type_name.
                         for reg in inventory::iter::<ComponentRegistration> {
@@ -144,6 +165,9 @@ impl SerializationStrategy for RecipeSerializationStrategy {
             }
         }
 
-        Ok(())
+        remap::remap_children(world, &id_map);
+        remap::break_parent_cycles(world, &id_map);
+        remap::remap_entity_refs(world, &EntityMap::from(id_map));
+        Ok(spawned)
     }
 }
@@ -18,7 +18,7 @@
 //! at link time via `inventory`. The Definition and Recipe strategies
 //! iterate these registrations to handle all component types.
 
-use crate::ecs::World;
+use crate::ecs::{EntityMap, World};
 use khora_core::ecs::entity::EntityId;
 use std::any::TypeId;
 
@@ -70,6 +70,11 @@ pub struct ComponentRegistration {
     /// from `entity`. Used by the editor inspector's per-card delete button
     /// to drop a component by `type_name` lookup.
     pub remove: fn(&mut World, EntityId) -> Result<(), String>,
+
+    /// Rewrites the [`EntityRef`](crate::ecs::EntityRef)s held by the
+    /// component on `entity` through the map, after a load or a merge gave
+    /// entities new IDs. `None` for components without entity references.
+    pub map_entities: Option<fn(&mut World, EntityId, &EntityMap)>,
 }
 
 inventory::collect!(ComponentRegistration);
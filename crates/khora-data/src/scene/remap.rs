@@ -0,0 +1,90 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Post-load fix-ups for entity references stored inside components.
+//!
+//! Scene payloads carry the *saved* `EntityId`s; loading spawns fresh ones.
+//! `Parent`, `Children` and every `EntityRef` must be rewritten through the
+//! old → new map, and anything that points outside the loaded scene (or
+//! forms a parent cycle in a corrupted file) is dropped rather than left
+//! dangling.
+
+use crate::ecs::{Children, EntityMap, Parent, World};
+use crate::scene::ComponentRegistration;
+use khora_core::ecs::entity::EntityId;
+use std::collections::{HashMap, HashSet};
+
+/// Rewrites every loaded entity's `Parent` through `id_map`, removing parents
+/// that are not part of the loaded scene.
+pub(crate) fn remap_parents(world: &mut World, id_map: &HashMap<EntityId, EntityId>) {
+    for &entity in id_map.values() {
+        let Some(old_parent) = world.get::<Parent>(entity).map(|p| p.0) else {
+            continue;
+        };
+        match id_map.get(&old_parent) {
+            Some(&new_parent) if new_parent != entity => {
+                if let Some(parent) = world.get_mut::<Parent>(entity) {
+                    parent.0 = new_parent;
+                }
+            }
+            _ => {
+                let _ = world.remove_component_now::<Parent>(entity);
+            }
+        }
+    }
+}
+
+/// Rewrites every loaded entity's `Children` through `id_map`, dropping
+/// children that are not part of the loaded scene.
+pub(crate) fn remap_children(world: &mut World, id_map: &HashMap<EntityId, EntityId>) {
+    for &entity in id_map.values() {
+        let Some(children) = world.get_mut::<Children>(entity) else {
+            continue;
+        };
+        children.0 = children
+            .0
+            .iter()
+            .filter_map(|old| id_map.get(old).copied())
+            .collect();
+    }
+}
+
+/// Removes the `Parent` of any loaded entity whose parent chain loops back on
+/// itself, so hierarchy walks over the loaded scene always terminate.
+pub(crate) fn break_parent_cycles(world: &mut World, id_map: &HashMap<EntityId, EntityId>) {
+    for &entity in id_map.values() {
+        let mut seen = HashSet::from([entity]);
+        let mut current = entity;
+        while let Some(parent) = world.get::<Parent>(current).map(|p| p.0) {
+            if !seen.insert(parent) {
+                let _ = world.remove_component_now::<Parent>(current);
+                break;
+            }
+            current = parent;
+        }
+    }
+}
+
+/// Rewrites the `EntityRef`s of every component on the mapped entities,
+/// through each component's registered `map_entities`.
+pub(crate) fn remap_entity_refs(world: &mut World, id_map: &EntityMap) {
+    for reg in inventory::iter::<ComponentRegistration> {
+        let Some(map_entities) = reg.map_entities else {
+            continue;
+        };
+        for entity in id_map.new_ids() {
+            map_entities(world, entity, id_map);
+        }
+    }
+}
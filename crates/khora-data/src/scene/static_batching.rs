@@ -0,0 +1,404 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Static scene baking — frozen transforms and merged meshes.
+//!
+//! [`bake_static`] runs once a level is loaded. It freezes the world
+//! transform of every [`Static`] entity, so `transform_propagation` skips
+//! them, and merges static meshes that share a material, render layers and
+//! vertex format into one [`StaticBatch`] mesh in world space. Thousands of
+//! small props then cost a handful of draws. [`unbake_static`] undoes it, for
+//! tools that need to move or save static entities again.
+
+use khora_core::asset::{AssetHandle, AssetUUID};
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Aabb, Mat3, Mat4, Vec4};
+use khora_core::renderer::api::pipeline::{PrimitiveTopology, VertexAttributeDescriptor};
+use khora_core::renderer::api::scene::Mesh;
+
+use crate::ecs::systems::transform_propagation_system;
+use crate::ecs::{
+    GlobalTransform, HandleComponent, IncludeDisabled, MaterialComponent, MaterialOverride,
+    MorphWeights, RenderLayers, Skin, Static, StaticBatch, World,
+};
+
+/// What a call to [`bake_static`] did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StaticBakeReport {
+    /// Static entities whose transforms were frozen.
+    pub baked: usize,
+    /// Meshes merged into batches.
+    pub merged: usize,
+    /// Batch entities created.
+    pub batches: usize,
+}
+
+/// Bakes every [`Static`] entity that has not been baked yet.
+///
+/// Global transforms are brought up to date first, then frozen. Meshes are
+/// merged when they are triangle lists without skinning, morph targets or
+/// material overrides; a group of one mesh is left as it is. Baking again
+/// after spawning more static entities only bakes the new ones.
+pub fn bake_static(world: &mut World) -> StaticBakeReport {
+    // Stage 1: freeze up-to-date transforms.
+    transform_propagation_system(world);
+    let fresh: Vec<EntityId> = world
+        .query::<(EntityId, &Static)>()
+        .filter(|(_, marker)| !marker.baked)
+        .map(|(entity, _)| entity)
+        .collect();
+    for &entity in &fresh {
+        if let Some(marker) = world.get_mut::<Static>(entity) {
+            marker.baked = true;
+        }
+    }
+
+    // Stage 2: group the batchable meshes.
+    let mut groups: Vec<BatchGroup> = Vec::new();
+    for &entity in &fresh {
+        let Some(part) = batch_part(world, entity) else {
+            continue;
+        };
+        match groups.iter_mut().find(|group| group.accepts(&part)) {
+            Some(group) => group.parts.push(part),
+            None => groups.push(BatchGroup { parts: vec![part] }),
+        }
+    }
+
+    // Stage 3: one batch entity per group of two or more meshes.
+    let mut report = StaticBakeReport {
+        baked: fresh.len(),
+        ..Default::default()
+    };
+    for group in groups.into_iter().filter(|group| group.parts.len() > 1) {
+        let members: Vec<EntityId> = group.parts.iter().map(|part| part.entity).collect();
+        let first = &group.parts[0];
+        let mesh = HandleComponent {
+            handle: AssetHandle::new(merge_meshes(&group.parts)),
+            uuid: AssetUUID::new(),
+        };
+        let batch = match first.material.clone() {
+            Some(material) => world.spawn((
+                GlobalTransform::identity(),
+                mesh,
+                material,
+                first.layers,
+                StaticBatch {
+                    members: members.clone(),
+                },
+            )),
+            None => world.spawn((
+                GlobalTransform::identity(),
+                mesh,
+                first.layers,
+                StaticBatch {
+                    members: members.clone(),
+                },
+            )),
+        };
+        for &member in &members {
+            if let Some(marker) = world.get_mut::<Static>(member) {
+                marker.batched_into = Some(batch);
+            }
+        }
+        report.merged += members.len();
+        report.batches += 1;
+    }
+
+    if report.baked > 0 {
+        log::info!(
+            "Baked {} static entities: {} meshes merged into {} batches",
+            report.baked,
+            report.merged,
+            report.batches
+        );
+    }
+    report
+}
+
+/// Undoes [`bake_static`]: despawns the batch entities and lets every
+/// static entity be moved and drawn on its own again.
+///
+/// Returns the number of static entities unbaked.
+pub fn unbake_static(world: &mut World) -> usize {
+    let batches: Vec<EntityId> = world
+        .query::<(EntityId, &StaticBatch, IncludeDisabled)>()
+        .map(|(entity, _, _)| entity)
+        .collect();
+    for batch in batches {
+        world.despawn(batch);
+    }
+
+    let mut unbaked = 0;
+    for (marker, _) in world.query_mut::<(&mut Static, IncludeDisabled)>() {
+        if marker.baked {
+            marker.baked = false;
+            marker.batched_into = None;
+            unbaked += 1;
+        }
+    }
+    unbaked
+}
+
+/// A static mesh that may be merged, with everything its batch must share.
+struct BatchPart {
+    entity: EntityId,
+    transform: Mat4,
+    mesh: AssetHandle<Mesh>,
+    material: Option<MaterialComponent>,
+    layers: RenderLayers,
+}
+
+impl BatchPart {
+    fn material_uuid(&self) -> Option<AssetUUID> {
+        self.material.as_ref().map(|material| material.uuid)
+    }
+
+    /// Attributes the mesh carries, which merged meshes must agree on.
+    fn format(&self) -> (&[VertexAttributeDescriptor], [bool; 4]) {
+        let mesh: &Mesh = &self.mesh;
+        (
+            &mesh.vertex_layout,
+            [
+                mesh.normals.is_some(),
+                mesh.tex_coords.is_some(),
+                mesh.tangents.is_some(),
+                mesh.colors.is_some(),
+            ],
+        )
+    }
+}
+
+/// Meshes that will be merged into one batch.
+struct BatchGroup {
+    parts: Vec<BatchPart>,
+}
+
+impl BatchGroup {
+    fn accepts(&self, part: &BatchPart) -> bool {
+        let first = &self.parts[0];
+        first.material_uuid() == part.material_uuid()
+            && first.layers == part.layers
+            && first.format() == part.format()
+    }
+}
+
+/// Returns `entity`'s mesh if it may be batched.
+fn batch_part(world: &World, entity: EntityId) -> Option<BatchPart> {
+    if !world.get::<Static>(entity)?.batch
+        || world.get::<Skin>(entity).is_some()
+        || world.get::<MorphWeights>(entity).is_some()
+        || world
+            .get::<MaterialOverride>(entity)
+            .is_some_and(|o| !o.is_empty())
+    {
+        return None;
+    }
+    let mesh = world.get::<HandleComponent<Mesh>>(entity)?;
+    let transform = world.get::<GlobalTransform>(entity)?.to_matrix();
+    let data: &Mesh = &mesh.handle;
+    // An empty mesh may be a CPU copy dropped after upload.
+    if data.positions.is_empty()
+        || data.primitive_type != PrimitiveTopology::TriangleList
+        || data.has_morph_targets()
+        || data.skin.is_some()
+    {
+        return None;
+    }
+    Some(BatchPart {
+        entity,
+        transform,
+        mesh: mesh.handle.clone(),
+        material: world.get::<MaterialComponent>(entity).cloned(),
+        layers: world
+            .get::<RenderLayers>(entity)
+            .copied()
+            .unwrap_or_default(),
+    })
+}
+
+/// Merges `parts` into one mesh in world space.
+fn merge_meshes(parts: &[BatchPart]) -> Mesh {
+    let first: &Mesh = &parts[0].mesh;
+    let mut merged = Mesh {
+        positions: Vec::new(),
+        normals: first.normals.as_ref().map(|_| Vec::new()),
+        tex_coords: first.tex_coords.as_ref().map(|_| Vec::new()),
+        tangents: first.tangents.as_ref().map(|_| Vec::new()),
+        colors: first.colors.as_ref().map(|_| Vec::new()),
+        indices: Some(Vec::new()),
+        primitive_type: PrimitiveTopology::TriangleList,
+        bounding_box: Aabb::INVALID,
+        vertex_layout: first.vertex_layout.clone(),
+        morph_targets: Vec::new(),
+        skin: None,
+    };
+
+    for part in parts {
+        let mesh: &Mesh = &part.mesh;
+        let base = merged.positions.len() as u32;
+        let linear = Mat3::from_mat4(&part.transform);
+        let normal_matrix = linear.inverse().map_or(linear, |m| m.transpose());
+        // A mirroring transform flips the triangle winding and the bitangent.
+        let mirrored = linear.determinant() < 0.0;
+
+        merged.positions.extend(
+            mesh.positions
+                .iter()
+                .map(|&p| part.transform.transform_point(p)),
+        );
+        if let (Some(out), Some(normals)) = (merged.normals.as_mut(), &mesh.normals) {
+            out.extend(normals.iter().map(|&n| (normal_matrix * n).normalize()));
+        }
+        if let (Some(out), Some(tex_coords)) = (merged.tex_coords.as_mut(), &mesh.tex_coords) {
+            out.extend_from_slice(tex_coords);
+        }
+        if let (Some(out), Some(tangents)) = (merged.tangents.as_mut(), &mesh.tangents) {
+            out.extend(tangents.iter().map(|t| {
+                let axis = (linear * t.truncate()).normalize();
+                let sign = if mirrored { -t.w } else { t.w };
+                Vec4::new(axis.x, axis.y, axis.z, sign)
+            }));
+        }
+        if let (Some(out), Some(colors)) = (merged.colors.as_mut(), &mesh.colors) {
+            out.extend_from_slice(colors);
+        }
+
+        let indices: Vec<u32> = match &mesh.indices {
+            Some(indices) => indices.clone(),
+            None => (0..mesh.positions.len() as u32).collect(),
+        };
+        let out = merged.indices.get_or_insert_with(Vec::new);
+        for triangle in indices.chunks_exact(3) {
+            let (a, b, c) = (triangle[0], triangle[1], triangle[2]);
+            if mirrored {
+                out.extend([base + a, base + c, base + b]);
+            } else {
+                out.extend([base + a, base + b, base + c]);
+            }
+        }
+    }
+
+    merged.bounding_box = Aabb::from_points(&merged.positions).unwrap_or(Aabb::INVALID);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::Transform;
+    use khora_core::asset::{Material, StandardMaterial};
+    use khora_core::math::Vec3;
+
+    fn triangle() -> HandleComponent<Mesh> {
+        let positions = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        HandleComponent {
+            handle: AssetHandle::new(Mesh {
+                bounding_box: Aabb::from_points(&positions).unwrap(),
+                positions,
+                normals: Some(vec![Vec3::Z; 3]),
+                tex_coords: None,
+                tangents: None,
+                colors: None,
+                indices: Some(vec![0, 1, 2]),
+                primitive_type: PrimitiveTopology::TriangleList,
+                vertex_layout: Vec::new(),
+                morph_targets: Vec::new(),
+                skin: None,
+            }),
+            uuid: AssetUUID::new(),
+        }
+    }
+
+    fn material() -> MaterialComponent {
+        MaterialComponent {
+            handle: AssetHandle::new(Box::new(StandardMaterial::default()) as Box<dyn Material>),
+            uuid: AssetUUID::new(),
+        }
+    }
+
+    fn spawn_prop(world: &mut World, x: f32, material: &MaterialComponent) -> EntityId {
+        world.spawn((
+            Transform::from_translation(Vec3::new(x, 0.0, 0.0)),
+            GlobalTransform::identity(),
+            triangle(),
+            material.clone(),
+            Static::new(),
+        ))
+    }
+
+    #[test]
+    fn test_bake_merges_meshes_sharing_a_material() {
+        let mut world = World::new();
+        let stone = material();
+        let wood = material();
+        let a = spawn_prop(&mut world, 0.0, &stone);
+        let b = spawn_prop(&mut world, 10.0, &stone);
+        let c = spawn_prop(&mut world, 20.0, &wood);
+        let moving = world.spawn((
+            Transform::identity(),
+            GlobalTransform::identity(),
+            triangle(),
+            stone.clone(),
+        ));
+
+        let report = bake_static(&mut world);
+        assert_eq!(
+            report,
+            StaticBakeReport {
+                baked: 3,
+                merged: 2,
+                batches: 1
+            }
+        );
+
+        let batch = world.get::<Static>(a).unwrap().batched_into().unwrap();
+        assert_eq!(world.get::<Static>(b).unwrap().batched_into(), Some(batch));
+        assert_eq!(world.get::<Static>(c).unwrap().batched_into(), None);
+        assert!(world.get::<Static>(moving).is_none());
+        assert_eq!(world.get::<StaticBatch>(batch).unwrap().members, vec![a, b]);
+
+        let merged = world.get::<HandleComponent<Mesh>>(batch).unwrap();
+        assert_eq!(merged.positions.len(), 6);
+        assert_eq!(merged.positions[4], Vec3::new(11.0, 0.0, 0.0));
+        assert_eq!(merged.indices.as_deref(), Some(&[0, 1, 2, 3, 4, 5][..]));
+        assert_eq!(
+            world.get::<MaterialComponent>(batch).unwrap().uuid,
+            stone.uuid
+        );
+
+        // A second bake has nothing left to do.
+        assert_eq!(bake_static(&mut world), StaticBakeReport::default());
+    }
+
+    #[test]
+    fn test_baked_transforms_are_frozen_until_unbaked() {
+        let mut world = World::new();
+        let stone = material();
+        let prop = spawn_prop(&mut world, 5.0, &stone);
+        spawn_prop(&mut world, 6.0, &stone);
+        bake_static(&mut world);
+
+        world.get_mut::<Transform>(prop).unwrap().translation = Vec3::new(0.0, 3.0, 0.0);
+        transform_propagation_system(&mut world);
+        let frozen = world.get::<GlobalTransform>(prop).unwrap();
+        assert_eq!(frozen.0.translation(), Vec3::new(5.0, 0.0, 0.0));
+
+        assert_eq!(unbake_static(&mut world), 2);
+        assert_eq!(world.query::<&StaticBatch>().count(), 0);
+        transform_propagation_system(&mut world);
+        let moved = world.get::<GlobalTransform>(prop).unwrap();
+        assert_eq!(moved.0.translation(), Vec3::new(0.0, 3.0, 0.0));
+    }
+}
@@ -14,9 +14,15 @@
 
 //! Defines the abstract contract for serialization strategies and their associated types.
 
-use crate::ecs::World;
+use crate::ecs::{NonSerialized, StaticBatch, World};
+use khora_core::ecs::entity::EntityId;
 use std::fmt;
 
+/// Upper bound, in bytes, on what a single bincode decode of scene data may
+/// allocate. Length prefixes in corrupted payloads beyond this are rejected
+/// instead of triggering huge allocations.
+pub const SCENE_DECODE_LIMIT: usize = 256 * 1024 * 1024;
+
 /// An error that can occur during the serialization process.
 #[derive(Debug)]
 pub enum SerializationError {
@@ -89,4 +95,35 @@ pub trait SerializationStrategy {
     /// # Returns
     /// A `Result` indicating success or a `DeserializationError`.
     fn deserialize(&self, data: &[u8], world: &mut World) -> Result<(), DeserializationError>;
+
+    /// Spawns the entities of a payload into a world that may already hold
+    /// others, as for a prefab.
+    ///
+    /// Saved entity references (`Parent`, `Children`) are remapped to the
+    /// fresh entities; references to entities outside the payload are
+    /// dropped, so its top-level entities become roots. Strategies that can
+    /// only replace the whole world, like the Archetype one, keep the
+    /// default, which refuses.
+    ///
+    /// # Returns
+    /// The spawned entities, in payload order.
+    fn instantiate(
+        &self,
+        data: &[u8],
+        world: &mut World,
+    ) -> Result<Vec<EntityId>, DeserializationError> {
+        let _ = (data, world);
+        Err(DeserializationError::WorldPopulationFailed(format!(
+            "strategy {} cannot spawn into an existing world",
+            self.get_strategy_id()
+        )))
+    }
+}
+
+/// Returns the entities a scene saves: all of them but static batches,
+/// which the bake rebuilds, and runtime entities marked [`NonSerialized`].
+pub(crate) fn saved_entities(world: &World) -> impl Iterator<Item = EntityId> + '_ {
+    world.iter_entities().filter(|&e| {
+        world.get::<StaticBatch>(e).is_none() && world.get::<NonSerialized>(e).is_none()
+    })
 }
@@ -0,0 +1,280 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Referential integrity checks over a loaded world.
+//!
+//! Broken scene data rarely fails loudly: a dangling `Parent` leaves an
+//! entity frozen at the origin, a NaN scale makes it vanish. The validation
+//! pass finds these up front and reports them as [`SceneIssue`]s.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use khora_core::asset::AssetUUID;
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Quaternion, Vec3};
+
+use crate::ecs::{
+    Children, Collider, GlobalTransform, IncludeDisabled, Parent, PendingAssetKind, PendingAssets,
+    Transform, World,
+};
+
+/// Bound on hierarchy depth, against cyclic `Parent` chains.
+const MAX_DEPTH: usize = 1024;
+
+/// One integrity problem found by [`World::validate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum SceneIssue {
+    /// The entity's `Parent` points at an entity that does not exist.
+    DanglingParent {
+        /// The child.
+        entity: EntityId,
+        /// The missing parent.
+        parent: EntityId,
+    },
+    /// The entity's `Children` lists an entity that does not exist.
+    DanglingChild {
+        /// The parent.
+        entity: EntityId,
+        /// The missing child.
+        child: EntityId,
+    },
+    /// The entity's `Parent` chain loops back on itself.
+    ParentCycle {
+        /// An entity on the loop.
+        entity: EntityId,
+    },
+    /// The entity has a `GlobalTransform` but no `Transform`, so nothing
+    /// ever updates it.
+    OrphanedGlobalTransform {
+        /// The entity.
+        entity: EntityId,
+    },
+    /// The entity has a `Collider` but no `Transform` to place it.
+    ColliderWithoutTransform {
+        /// The entity.
+        entity: EntityId,
+    },
+    /// The entity's `Transform` or `GlobalTransform` holds a NaN or an
+    /// infinity.
+    NonFiniteTransform {
+        /// The entity.
+        entity: EntityId,
+    },
+    /// The entity references an asset the asset lookup does not know.
+    MissingAsset {
+        /// The entity.
+        entity: EntityId,
+        /// What the reference resolves into.
+        kind: PendingAssetKind,
+        /// The missing asset.
+        uuid: AssetUUID,
+    },
+}
+
+impl SceneIssue {
+    /// Returns the entity the issue was found on.
+    pub fn entity(&self) -> EntityId {
+        match *self {
+            SceneIssue::DanglingParent { entity, .. }
+            | SceneIssue::DanglingChild { entity, .. }
+            | SceneIssue::ParentCycle { entity }
+            | SceneIssue::OrphanedGlobalTransform { entity }
+            | SceneIssue::ColliderWithoutTransform { entity }
+            | SceneIssue::NonFiniteTransform { entity }
+            | SceneIssue::MissingAsset { entity, .. } => entity,
+        }
+    }
+}
+
+impl fmt::Display for SceneIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneIssue::DanglingParent { entity, parent } => {
+                write!(f, "{entity:?}: parent {parent:?} does not exist")
+            }
+            SceneIssue::DanglingChild { entity, child } => {
+                write!(f, "{entity:?}: child {child:?} does not exist")
+            }
+            SceneIssue::ParentCycle { entity } => {
+                write!(f, "{entity:?}: parent chain forms a cycle")
+            }
+            SceneIssue::OrphanedGlobalTransform { entity } => {
+                write!(f, "{entity:?}: GlobalTransform without a Transform")
+            }
+            SceneIssue::ColliderWithoutTransform { entity } => {
+                write!(f, "{entity:?}: Collider without a Transform")
+            }
+            SceneIssue::NonFiniteTransform { entity } => {
+                write!(f, "{entity:?}: transform is not finite")
+            }
+            SceneIssue::MissingAsset { entity, kind, uuid } => {
+                write!(f, "{entity:?}: {kind:?} asset {uuid:?} is missing")
+            }
+        }
+    }
+}
+
+/// The issues found by one validation pass, in entity order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    /// Every issue found.
+    pub issues: Vec<SceneIssue>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if no issue was found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Logs every issue as a warning, prefixed with `context`.
+    pub fn log(&self, context: &str) {
+        for issue in &self.issues {
+            log::warn!("{}: {}", context, issue);
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return f.write_str("no issues");
+        }
+        writeln!(f, "{} issue(s):", self.issues.len())?;
+        for issue in &self.issues {
+            writeln!(f, "  {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl World {
+    /// Checks the world for broken references and invalid values.
+    ///
+    /// Reports dangling `Parent` and `Children` references, parent cycles,
+    /// `GlobalTransform`s and `Collider`s without a `Transform`, and
+    /// transforms holding NaN or infinity. Asset references are only
+    /// checked by [`validate_with_assets`](Self::validate_with_assets),
+    /// which can reach the assets.
+    pub fn validate(&self) -> ValidationReport {
+        let mut issues = Vec::new();
+        let mut cyclic = HashSet::new();
+        for entity in self.iter_entities() {
+            self.check_hierarchy(entity, &mut cyclic, &mut issues);
+
+            let transform = self.get::<Transform>(entity);
+            if transform.is_none() {
+                if self.get::<GlobalTransform>(entity).is_some() {
+                    issues.push(SceneIssue::OrphanedGlobalTransform { entity });
+                }
+                if self.get::<Collider>(entity).is_some() {
+                    issues.push(SceneIssue::ColliderWithoutTransform { entity });
+                }
+            }
+            let finite = transform.is_none_or(|t| {
+                vec3_finite(t.translation) && quat_finite(t.rotation) && vec3_finite(t.scale)
+            }) && self.get::<GlobalTransform>(entity).is_none_or(|g| {
+                g.to_matrix()
+                    .to_cols_array_2d()
+                    .iter()
+                    .flatten()
+                    .all(|v| v.is_finite())
+            });
+            if !finite {
+                issues.push(SceneIssue::NonFiniteTransform { entity });
+            }
+        }
+        ValidationReport { issues }
+    }
+
+    /// Runs [`validate`](Self::validate) and also reports every asset
+    /// reference still waiting for resolution whose UUID `asset_exists`
+    /// rejects.
+    ///
+    /// Call it between a scene load and asset resolution, with a lookup
+    /// backed by the asset service.
+    pub fn validate_with_assets(
+        &self,
+        asset_exists: impl Fn(&AssetUUID) -> bool,
+    ) -> ValidationReport {
+        let mut report = self.validate();
+        for (entity, pending, _) in self.query::<(EntityId, &PendingAssets, IncludeDisabled)>() {
+            for request in &pending.requests {
+                // Materials are saved inline too, so a missing asset is not
+                // a broken reference.
+                if request.kind == PendingAssetKind::Material {
+                    continue;
+                }
+                if !asset_exists(&request.uuid) {
+                    report.issues.push(SceneIssue::MissingAsset {
+                        entity,
+                        kind: request.kind,
+                        uuid: request.uuid,
+                    });
+                }
+            }
+        }
+        report
+    }
+
+    fn check_hierarchy(
+        &self,
+        entity: EntityId,
+        cyclic: &mut HashSet<EntityId>,
+        issues: &mut Vec<SceneIssue>,
+    ) {
+        let is_alive = |id: EntityId| self.entities.get_metadata(id).is_some();
+        if let Some(&Parent(parent)) = self.get::<Parent>(entity) {
+            if !is_alive(parent) {
+                issues.push(SceneIssue::DanglingParent { entity, parent });
+            } else if !cyclic.contains(&entity) {
+                // Walk up; report each loop once, on the first member reached.
+                let mut seen = vec![entity];
+                let mut current = parent;
+                while seen.len() < MAX_DEPTH {
+                    if let Some(start) = seen.iter().position(|&e| e == current) {
+                        let first = seen[start];
+                        if cyclic.insert(first) {
+                            issues.push(SceneIssue::ParentCycle { entity: first });
+                        }
+                        cyclic.extend(seen[start..].iter().copied());
+                        break;
+                    }
+                    seen.push(current);
+                    match self.get::<Parent>(current) {
+                        Some(&Parent(next)) => current = next,
+                        None => break,
+                    }
+                }
+            }
+        }
+        if let Some(children) = self.get::<Children>(entity) {
+            for &child in &children.0 {
+                if !is_alive(child) {
+                    issues.push(SceneIssue::DanglingChild { entity, child });
+                }
+            }
+        }
+    }
+}
+
+fn vec3_finite(v: Vec3) -> bool {
+    v.x.is_finite() && v.y.is_finite() && v.z.is_finite()
+}
+
+fn quat_finite(q: Quaternion) -> bool {
+    q.x.is_finite() && q.y.is_finite() && q.z.is_finite() && q.w.is_finite()
+}
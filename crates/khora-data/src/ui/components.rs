@@ -270,3 +270,269 @@ impl Default for UiText {
         }
     }
 }
+
+/// Anchor/pivot placement of a UI element inside its parent rect.
+///
+/// Anchors are normalized coordinates in the parent rect (`(0, 0)` is the
+/// top-left corner, `(1, 1)` the bottom-right). When `anchor_min` and
+/// `anchor_max` coincide the element keeps a fixed `size` around that
+/// point; when they differ the element stretches with the parent and
+/// `size` is added to the anchored span. The `pivot` is the normalized
+/// point of the element placed at the anchor reference, shifted by
+/// `offset` pixels.
+///
+/// The parent rect is the [`UiTransform`] of the entity's `Parent`; roots
+/// are anchored to the surface. The `ui_layout` data system resolves
+/// anchors into [`UiTransform`] every tick.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct UiAnchor {
+    /// Normalized top-left corner of the anchored span in the parent.
+    pub anchor_min: Vec2,
+    /// Normalized bottom-right corner of the anchored span in the parent.
+    pub anchor_max: Vec2,
+    /// Normalized point of the element aligned with the anchor reference.
+    pub pivot: Vec2,
+    /// Pixel offset of the pivot from the anchor reference.
+    pub offset: Vec2,
+    /// Pixel size, added to the anchored span when stretching.
+    pub size: Vec2,
+}
+
+impl Default for UiAnchor {
+    fn default() -> Self {
+        Self::new(Vec2::ZERO, Vec2::ZERO)
+    }
+}
+
+impl UiAnchor {
+    /// Anchors an element of fixed `size` to the normalized point `anchor`,
+    /// with the pivot on the same relative point of the element.
+    pub fn new(anchor: Vec2, size: Vec2) -> Self {
+        Self {
+            anchor_min: anchor,
+            anchor_max: anchor,
+            pivot: anchor,
+            offset: Vec2::ZERO,
+            size,
+        }
+    }
+
+    /// Stretches the element over the whole parent rect.
+    pub fn stretch() -> Self {
+        Self {
+            anchor_min: Vec2::ZERO,
+            anchor_max: Vec2::ONE,
+            pivot: Vec2::new(0.5, 0.5),
+            offset: Vec2::ZERO,
+            size: Vec2::ZERO,
+        }
+    }
+
+    /// Sets the normalized pivot.
+    pub fn with_pivot(mut self, pivot: Vec2) -> Self {
+        self.pivot = pivot;
+        self
+    }
+
+    /// Sets the pixel offset from the anchor reference.
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Resolves the element rect `(pos, size)` inside the parent rect.
+    pub fn resolve(&self, parent_pos: Vec2, parent_size: Vec2) -> (Vec2, Vec2) {
+        let span_min = parent_pos + parent_size * self.anchor_min;
+        let span_max = parent_pos + parent_size * self.anchor_max;
+        let size = (span_max - span_min) + self.size;
+        let size = Vec2::new(size.x.max(0.0), size.y.max(0.0));
+        let reference = span_min + (span_max - span_min) * self.pivot;
+        (reference + self.offset - size * self.pivot, size)
+    }
+}
+
+/// Nine-slice scaling for a [`UiImage`].
+///
+/// The image is cut into a 3x3 grid: corners keep their on-screen size,
+/// edges stretch along one axis and the center stretches along both, so
+/// panels and buttons can be resized without distorting their frame.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Default)]
+pub struct UiNineSlice {
+    /// Cut lines as fractions of the source image (`0.0..=1.0`) measured
+    /// from each side.
+    pub slice: UiRect<f32>,
+    /// On-screen size, in pixels, of the fixed borders.
+    pub border: UiRect<f32>,
+}
+
+/// One of the nine quads produced by [`UiNineSlice::patches`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiSlicePatch {
+    /// Screen-space position of the quad.
+    pub pos: Vec2,
+    /// Screen-space size of the quad.
+    pub size: Vec2,
+    /// UV of the quad's top-left corner.
+    pub uv_min: Vec2,
+    /// UV of the quad's bottom-right corner.
+    pub uv_max: Vec2,
+}
+
+impl UiNineSlice {
+    /// Creates a nine-slice with the same cut and border on every side.
+    pub fn uniform(slice: f32, border: f32) -> Self {
+        Self {
+            slice: UiRect::all(slice),
+            border: UiRect::all(border),
+        }
+    }
+
+    /// Splits the rect `(pos, size)`, textured with `[uv_min, uv_max]`,
+    /// into nine quads in row-major order.
+    ///
+    /// Borders wider than the rect are scaled down proportionally so the
+    /// corners never overlap.
+    pub fn patches(&self, pos: Vec2, size: Vec2, uv_min: Vec2, uv_max: Vec2) -> [UiSlicePatch; 9] {
+        let fit = |a: f32, b: f32, extent: f32| {
+            let total = a + b;
+            if total > extent && total > 0.0 {
+                let scale = extent / total;
+                (a * scale, b * scale)
+            } else {
+                (a, b)
+            }
+        };
+        let (left, right) = fit(self.border.left, self.border.right, size.x);
+        let (top, bottom) = fit(self.border.top, self.border.bottom, size.y);
+
+        let xs = [pos.x, pos.x + left, pos.x + size.x - right, pos.x + size.x];
+        let ys = [pos.y, pos.y + top, pos.y + size.y - bottom, pos.y + size.y];
+        let uv_span = uv_max - uv_min;
+        let us = [
+            uv_min.x,
+            uv_min.x + uv_span.x * self.slice.left,
+            uv_max.x - uv_span.x * self.slice.right,
+            uv_max.x,
+        ];
+        let vs = [
+            uv_min.y,
+            uv_min.y + uv_span.y * self.slice.top,
+            uv_max.y - uv_span.y * self.slice.bottom,
+            uv_max.y,
+        ];
+
+        std::array::from_fn(|i| {
+            let (col, row) = (i % 3, i / 3);
+            UiSlicePatch {
+                pos: Vec2::new(xs[col], ys[row]),
+                size: Vec2::new(xs[col + 1] - xs[col], ys[row + 1] - ys[row]),
+                uv_min: Vec2::new(us[col], vs[row]),
+                uv_max: Vec2::new(us[col + 1], vs[row + 1]),
+            }
+        })
+    }
+}
+
+/// A clickable UI element.
+///
+/// Requires a [`UiTransform`] and a [`UiInteraction`]. The `ui_interaction`
+/// data system tints the element's [`UiColor`] with the color matching its
+/// interaction state and raises [`clicked`](Self::clicked) for the tick in
+/// which the pointer is released over a pressed button.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct UiButton {
+    /// Color when idle.
+    pub normal: Vec4,
+    /// Color while hovered.
+    pub hovered: Vec4,
+    /// Color while pressed.
+    pub pressed: Vec4,
+    /// Color while disabled.
+    pub disabled: Vec4,
+    /// Disabled buttons ignore the pointer.
+    pub enabled: bool,
+    /// Set for one tick after a click.
+    #[component(skip)]
+    pub clicked: bool,
+}
+
+impl Default for UiButton {
+    fn default() -> Self {
+        Self::new(Vec4::new(0.8, 0.8, 0.8, 1.0))
+    }
+}
+
+impl UiButton {
+    /// Creates a button with hover/press colors derived from `normal`.
+    pub fn new(normal: Vec4) -> Self {
+        let shade = |factor: f32| {
+            Vec4::new(
+                (normal.x * factor).min(1.0),
+                (normal.y * factor).min(1.0),
+                (normal.z * factor).min(1.0),
+                normal.w,
+            )
+        };
+        Self {
+            normal,
+            hovered: shade(1.15),
+            pressed: shade(0.75),
+            disabled: Vec4::new(normal.x, normal.y, normal.z, normal.w * 0.5),
+            enabled: true,
+            clicked: false,
+        }
+    }
+
+    /// Overrides the hover and press colors.
+    pub fn with_colors(mut self, hovered: Vec4, pressed: Vec4) -> Self {
+        self.hovered = hovered;
+        self.pressed = pressed;
+        self
+    }
+
+    /// Returns the color for the given interaction state.
+    pub fn color_for(&self, state: UiInteractionState) -> Vec4 {
+        if !self.enabled {
+            return self.disabled;
+        }
+        match state {
+            UiInteractionState::Hovered => self.hovered,
+            UiInteractionState::Pressed => self.pressed,
+            UiInteractionState::Normal | UiInteractionState::Focused => self.normal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nine_slice_keeps_corners_and_stretches_center() {
+        let slice = UiNineSlice::uniform(0.25, 16.0);
+        let patches = slice.patches(
+            Vec2::new(10.0, 20.0),
+            Vec2::new(200.0, 100.0),
+            Vec2::ZERO,
+            Vec2::ONE,
+        );
+
+        assert_eq!(patches[0].pos, Vec2::new(10.0, 20.0));
+        assert_eq!(patches[0].size, Vec2::new(16.0, 16.0));
+        assert_eq!(patches[0].uv_max, Vec2::new(0.25, 0.25));
+        assert_eq!(patches[4].size, Vec2::new(168.0, 68.0));
+        assert_eq!(patches[4].uv_min, Vec2::new(0.25, 0.25));
+        assert_eq!(patches[8].pos + patches[8].size, Vec2::new(210.0, 120.0));
+        assert_eq!(patches[8].uv_max, Vec2::ONE);
+    }
+
+    #[test]
+    fn nine_slice_shrinks_borders_that_do_not_fit() {
+        let slice = UiNineSlice::uniform(0.25, 40.0);
+        let patches = slice.patches(Vec2::ZERO, Vec2::new(40.0, 200.0), Vec2::ZERO, Vec2::ONE);
+
+        assert_eq!(patches[0].size.x, 20.0);
+        assert_eq!(patches[1].size.x, 0.0);
+        assert_eq!(patches[2].size.x, 20.0);
+    }
+}
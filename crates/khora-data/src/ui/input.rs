@@ -0,0 +1,134 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pointer routing for the retained UI tree.
+//!
+//! The engine feeds every tick's [`InputEvent`]s into the
+//! [`UiInputRouter`] before the pre-simulation systems run; the
+//! `ui_interaction` data system then hit-tests the UI against the routed
+//! pointer and records which element owns it, so gameplay code can ignore
+//! clicks the UI already consumed.
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::Vec2;
+use khora_core::platform::{InputEvent, MouseButton};
+
+/// Snapshot of the primary pointer for one tick.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UiPointer {
+    /// Cursor position in surface pixels, once the cursor has moved.
+    pub position: Option<Vec2>,
+    /// Whether the primary (left) button is held.
+    pub down: bool,
+    /// Whether the primary button went down this tick.
+    pub just_pressed: bool,
+    /// Whether the primary button went up this tick.
+    pub just_released: bool,
+}
+
+#[derive(Debug, Default)]
+struct RouterState {
+    pointer: UiPointer,
+    hovered: Option<EntityId>,
+    captured: Option<EntityId>,
+    focused: Option<EntityId>,
+}
+
+/// Routes pointer input to the UI before it reaches gameplay.
+///
+/// Registered in the service registry at bootstrap. Apps query
+/// [`is_pointer_over_ui`](Self::is_pointer_over_ui) in `update` to skip
+/// world interaction (camera drags, picking) under UI elements.
+#[derive(Debug, Clone, Default)]
+pub struct UiInputRouter {
+    state: Arc<Mutex<RouterState>>,
+}
+
+impl UiInputRouter {
+    /// Creates a router with no pointer position yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new tick: clears the per-tick edges and applies `events`.
+    pub fn route(&self, events: &[InputEvent]) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let pointer = &mut state.pointer;
+        pointer.just_pressed = false;
+        pointer.just_released = false;
+        for event in events {
+            match event {
+                InputEvent::MouseMoved { x, y } => pointer.position = Some(Vec2::new(*x, *y)),
+                InputEvent::MouseButtonPressed {
+                    button: MouseButton::Left,
+                } => {
+                    pointer.down = true;
+                    pointer.just_pressed = true;
+                }
+                InputEvent::MouseButtonReleased {
+                    button: MouseButton::Left,
+                } => {
+                    pointer.down = false;
+                    pointer.just_released = true;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the pointer state of the current tick.
+    pub fn pointer(&self) -> UiPointer {
+        self.state.lock().map(|s| s.pointer).unwrap_or_default()
+    }
+
+    /// Returns the topmost input-blocking element under the pointer.
+    pub fn hovered(&self) -> Option<EntityId> {
+        self.state.lock().ok().and_then(|s| s.hovered)
+    }
+
+    /// Returns the element that received the current press, if still held.
+    pub fn captured(&self) -> Option<EntityId> {
+        self.state.lock().ok().and_then(|s| s.captured)
+    }
+
+    /// Returns the focused element, if any.
+    pub fn focused(&self) -> Option<EntityId> {
+        self.state.lock().ok().and_then(|s| s.focused)
+    }
+
+    /// Whether the pointer is over, or pressing, an input-blocking element.
+    pub fn is_pointer_over_ui(&self) -> bool {
+        self.state
+            .lock()
+            .map(|s| s.hovered.is_some() || s.captured.is_some())
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn set_targets(
+        &self,
+        hovered: Option<EntityId>,
+        captured: Option<EntityId>,
+        focused: Option<EntityId>,
+    ) {
+        if let Ok(mut state) = self.state.lock() {
+            state.hovered = hovered;
+            state.captured = captured;
+            state.focused = focused;
+        }
+    }
+}
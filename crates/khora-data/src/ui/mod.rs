@@ -17,8 +17,10 @@
 //! Provides UI components and layout management for the Khora Engine.
 
 pub mod components;
+mod input;
 pub mod layout_view;
 mod scene;
 
 pub use components::*;
+pub use input::{UiInputRouter, UiPointer};
 pub use scene::{ExtractedUiNode, ExtractedUiText, UiAtlasMap, UiScene};
@@ -26,7 +26,7 @@ use khora_core::renderer::api::text::TextLayout;
 use khora_core::renderer::api::util::AtlasRect;
 use std::collections::HashMap;
 
-use crate::ui::components::{UiBorder, UiColor, UiImage};
+use crate::ui::components::{UiBorder, UiColor, UiImage, UiNineSlice};
 
 /// A flat, GPU-friendly representation of a single UI node.
 #[derive(Debug, Clone)]
@@ -41,6 +41,8 @@ pub struct ExtractedUiNode {
     pub border: Option<UiBorder>,
     /// Optional image asset reference.
     pub image: Option<UiImage>,
+    /// Optional nine-slice scaling of the image.
+    pub nine_slice: Option<UiNineSlice>,
     /// Z-index for sorting.
     pub z_index: i32,
 }
@@ -102,6 +102,24 @@ const NAVIGATE_ACTIONS: &[Command] = &[
         action: "documentation",
         icon: Icon::Code,
     },
+    Command {
+        label: "Frame Selected",
+        description: "Fit the selection in the viewport (F)",
+        action: "frame_selected",
+        icon: Icon::Crosshair,
+    },
+    Command {
+        label: "Toggle Grid",
+        description: "Show or hide the ground grid",
+        action: "toggle_grid",
+        icon: Icon::Grid,
+    },
+    Command {
+        label: "Toggle Axes",
+        description: "Show or hide the world origin axes",
+        action: "toggle_axes",
+        icon: Icon::Axes,
+    },
 ];
 
 const SECTIONS: &[Section] = &[
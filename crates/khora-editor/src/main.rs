@@ -77,6 +77,8 @@ struct EditorApp {
     /// DCC context handle (Phase 2.2). Locked each frame for the Control
     /// Plane summary bar — exposes mode, budget multiplier, hardware load.
     dcc_context: Option<Arc<std::sync::RwLock<khora_sdk::DccContext>>>,
+    /// Console variables, edited from the Control Plane's Console vars tab.
+    cvars: Option<khora_sdk::CVarRegistry>,
     /// View info computed in `before_agents` and re-used by `after_agents`
     /// for gizmo rendering.
     last_view_info: Option<ViewInfo>,
@@ -95,6 +97,8 @@ struct EditorApp {
     /// active scene `Camera` exists (i.e. Editing mode). Updated each frame
     /// in `before_agents` from the editor's free camera.
     viewport_override: khora_sdk::khora_data::render::EditorViewportOverride,
+    /// The whole world as it was when Play was pressed, put back on Stop.
+    play_snapshot: Option<WorldSnapshot>,
 }
 
 impl EditorApp {
@@ -140,7 +144,7 @@ impl EditorApp {
                         state.scene_roots.clear();
                         state.entity_count = 0;
                         state.play_mode = PlayMode::Editing;
-                        state.scene_snapshot = None;
+                        self.play_snapshot = None;
                         log::info!("New scene created (cleared {} entities)", all.len());
                     }
                 }
@@ -176,7 +180,7 @@ impl EditorApp {
                     if let Ok(mut state) = self.editor_state.lock() {
                         match state.play_mode {
                             PlayMode::Editing => {
-                                state.scene_snapshot = Some(scene_io::snapshot_scene(world));
+                                self.play_snapshot = Some(world.snapshot());
                                 state.play_mode = PlayMode::Playing;
                                 log::info!("Play mode: started");
                             }
@@ -201,15 +205,10 @@ impl EditorApp {
                         if state.play_mode == PlayMode::Playing
                             || state.play_mode == PlayMode::Paused
                         {
-                            if let Some(snapshot) = state.scene_snapshot.take() {
-                                drop(state);
-                                scene_io::restore_scene(world, &snapshot);
-                                if let Ok(mut state) = self.editor_state.lock() {
-                                    state.play_mode = PlayMode::Editing;
-                                }
-                            } else {
-                                state.play_mode = PlayMode::Editing;
+                            if let Some(snapshot) = self.play_snapshot.take() {
+                                world.restore(&snapshot);
                             }
+                            state.play_mode = PlayMode::Editing;
                             log::info!("Play mode: stopped - scene restored");
                         }
                     }
@@ -267,6 +266,22 @@ impl EditorApp {
                     }
                 }
 
+                "frame_selected" => {
+                    if let Ok(mut state) = self.editor_state.lock() {
+                        state.pending_frame_selected = true;
+                    }
+                }
+                "toggle_grid" => {
+                    if let Ok(mut state) = self.editor_state.lock() {
+                        state.overlays.grid = !state.overlays.grid;
+                    }
+                }
+                "toggle_axes" => {
+                    if let Ok(mut state) = self.editor_state.lock() {
+                        state.overlays.axes = !state.overlays.axes;
+                    }
+                }
+
                 "documentation" => {
                     let _ = open::that("https://github.com/eraflo/KhoraEngine");
                     log::info!("Opening documentation in browser");
@@ -285,6 +300,38 @@ impl EditorApp {
             }
         }
     }
+
+    /// Applies pending frame-selected and bookmark requests to the editor
+    /// camera.
+    fn process_camera_requests(&mut self, world: &GameWorld) {
+        let Ok(mut state) = self.editor_state.lock() else {
+            return;
+        };
+        let Ok(mut cam) = self.camera.lock() else {
+            return;
+        };
+
+        if std::mem::take(&mut state.pending_frame_selected) {
+            match ops::selection_bounds(world, &state) {
+                Some(bounds) => {
+                    cam.frame_bounds(&bounds);
+                }
+                None => log::info!("Frame Selected: nothing selected"),
+            }
+        }
+
+        if let Some(name) = state.pending_bookmark_save.take() {
+            state.bookmarks.save(name.clone(), cam.pose());
+            log::info!("Saved camera bookmark '{}'", name);
+        }
+
+        if let Some(name) = state.pending_bookmark_restore.take() {
+            match state.bookmarks.get(&name) {
+                Some(pose) => cam.set_pose(pose),
+                None => log::info!("No camera bookmark '{}'", name),
+            }
+        }
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────
@@ -319,6 +366,7 @@ impl EngineApp for EditorApp {
             monitors: None,
             agent_registry: None,
             dcc_context: None,
+            cvars: None,
             last_view_info: None,
             middle_down: false,
             right_down: false,
@@ -328,6 +376,7 @@ impl EngineApp for EditorApp {
             last_cursor_pos: None,
             last_frame_time: Instant::now(),
             viewport_override: khora_sdk::khora_data::render::EditorViewportOverride::new(),
+            play_snapshot: None,
         }
     }
 
@@ -354,6 +403,7 @@ impl EngineApp for EditorApp {
         self.dcc_context = services
             .get::<Arc<std::sync::RwLock<khora_sdk::DccContext>>>()
             .cloned();
+        self.cvars = services.get::<khora_sdk::CVarRegistry>().cloned();
 
         // Cache the raw winit window so overlay calls can hand it back to egui.
         self.raw_window = services.get::<Arc<winit::window::Window>>().cloned();
@@ -444,6 +494,7 @@ impl EngineApp for EditorApp {
                         brand_theme.clone(),
                         self.agent_registry.clone(),
                         self.dcc_context.clone(),
+                        self.cvars.clone(),
                     )),
                 );
 
@@ -579,6 +630,7 @@ impl EngineApp for EditorApp {
                                 "KeyW" => state.gizmo_mode = GizmoMode::Move,
                                 "KeyE" => state.gizmo_mode = GizmoMode::Rotate,
                                 "KeyR" => state.gizmo_mode = GizmoMode::Scale,
+                                "KeyF" => state.pending_frame_selected = true,
                                 _ => {}
                             }
                         }
@@ -615,6 +667,17 @@ impl EngineApp for EditorApp {
                             }
                         }
                     }
+
+                    // Ctrl+1..9 bookmarks the editor camera, 1..9 jumps back.
+                    if let Some(slot) = key_code.strip_prefix("Digit").filter(|s| *s != "0") {
+                        if let Ok(mut state) = self.editor_state.lock() {
+                            if self.ctrl_held {
+                                state.pending_bookmark_save = Some(slot.to_owned());
+                            } else {
+                                state.pending_bookmark_restore = Some(slot.to_owned());
+                            }
+                        }
+                    }
                 }
                 InputEvent::KeyReleased { key_code } => {
                     if key_code == "ShiftLeft" || key_code == "ShiftRight" {
@@ -656,6 +719,7 @@ impl EngineApp for EditorApp {
         }
 
         self.process_menu_actions(world);
+        self.process_camera_requests(world);
 
         if let Ok(mut state) = self.editor_state.lock() {
             ops::apply_edits(world, &mut state);
@@ -892,12 +956,12 @@ impl EngineApp for EditorApp {
         };
 
         let (vw, vh) = wgpu_rs.viewport_size();
-        let play_mode = self
+        let (play_mode, overlays) = self
             .editor_state
             .lock()
             .ok()
-            .map(|s| s.play_mode)
-            .unwrap_or(PlayMode::Editing);
+            .map(|s| (s.play_mode, s.overlays))
+            .unwrap_or_default();
 
         let view_info = match play_mode {
             PlayMode::Editing => match self.camera.lock() {
@@ -934,9 +998,11 @@ impl EngineApp for EditorApp {
             .set(Some(khora_sdk::khora_data::render::ExtractedView {
                 view_proj: view_info.view_projection_matrix(),
                 position: view_info.camera_position,
+                layers: khora_sdk::prelude::ecs::RenderLayers::ALL,
             }));
 
         let clear = khora_sdk::prelude::math::LinearRgba::new(0.15, 0.15, 0.18, 1.0);
+        wgpu_rs.set_grid_visible(overlays.grid);
         if let Err(e) = wgpu_rs.render_viewport(clear, &view_info) {
             log::error!("editor: render_viewport failed: {e:?}");
         }
@@ -952,11 +1018,13 @@ impl EngineApp for EditorApp {
         // Render gizmos for the current selection on top of the 3D scene.
         if let Some(view_info) = self.last_view_info.as_ref() {
             let gizmo_lines = if let Ok(state) = self.editor_state.lock() {
-                if state.selection.is_empty() {
+                let mut lines = if state.selection.is_empty() {
                     Vec::new()
                 } else {
                     crate::mod_gizmo::collect_gizmo_lines(world, &state, view_info)
-                }
+                };
+                lines.extend(crate::mod_gizmo::collect_overlay_lines(&state));
+                lines
             } else {
                 Vec::new()
             };
@@ -14,7 +14,10 @@
 
 //! Gizmo rendering for the editor viewport.
 
-use khora_sdk::editor_ui::{generate_selection_gizmos, EditorState, GizmoKind, GizmoLineInstance};
+use khora_sdk::editor_ui::gizmo::transform_axes;
+use khora_sdk::editor_ui::{
+    generate_selection_gizmos, EditorState, GizmoKind, GizmoLineInstance, PlayMode,
+};
 use khora_sdk::khora_core::math::Mat4;
 use khora_sdk::khora_core::renderer::api::resource::ViewInfo;
 use khora_sdk::khora_core::renderer::api::scene::mesh::Mesh;
@@ -68,3 +71,13 @@ pub fn collect_gizmo_lines(
 
     generate_selection_gizmos(&entries, editor_state.gizmo_mode)
 }
+
+/// Collects the navigation overlay lines (world origin axes) enabled in
+/// [`EditorState::overlays`]. Overlays are editing aids, so nothing is drawn
+/// while the game is running.
+pub fn collect_overlay_lines(editor_state: &EditorState) -> Vec<GizmoLineInstance> {
+    if editor_state.play_mode != PlayMode::Editing || !editor_state.overlays.axes {
+        return Vec::new();
+    }
+    transform_axes(&Mat4::IDENTITY, 1.0)
+}
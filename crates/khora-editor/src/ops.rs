@@ -17,6 +17,7 @@
 use khora_sdk::editor_ui::*;
 use khora_sdk::khora_data::ecs::{HandleComponent, SemanticDomain};
 use khora_sdk::prelude::ecs::*;
+use khora_sdk::prelude::math::{Aabb, Vec3};
 use khora_sdk::{GameWorld, Mesh};
 
 /// Maps [`SemanticDomain`] to the small integer tag the editor side uses
@@ -198,19 +199,46 @@ pub fn duplicate_entity(world: &mut GameWorld, entity: EntityId, state: &mut Edi
     log::info!("Duplicated entity {:?} -> {:?}", entity, new_entity);
 }
 
-/// Deletes every currently selected entity and clears selection/inspector state.
+/// Deletes every currently selected entity, with its children, and clears
+/// selection/inspector state.
 pub fn delete_selection(world: &mut GameWorld, state: &mut EditorState) {
     let to_delete: Vec<EntityId> = state.selection.iter().copied().collect();
+    let mut deleted = 0;
     for entity in &to_delete {
-        world.despawn(*entity);
+        deleted += world.despawn_recursive(*entity);
     }
-    if !to_delete.is_empty() {
-        log::info!("Deleted {} entities", to_delete.len());
+    if deleted > 0 {
+        log::info!("Deleted {} entities", deleted);
     }
     state.clear_selection();
     state.inspected = None;
 }
 
+/// Box enclosing every selected entity, used by "Frame Selected".
+///
+/// Prefers each entity's hierarchy [`Bounds`]; entities without a mesh
+/// anywhere below them contribute a unit box around their world position.
+pub fn selection_bounds(world: &GameWorld, state: &EditorState) -> Option<Aabb> {
+    let mut bounds = Aabb::INVALID;
+    for &entity in &state.selection {
+        let entity_box = match world.get_component::<Bounds>(entity) {
+            Some(b) if b.hierarchy.is_valid() => b.hierarchy,
+            _ => {
+                let position = match world.get_component::<GlobalTransform>(entity) {
+                    Some(global) => global.0.translation(),
+                    None => match world.get_component::<Transform>(entity) {
+                        Some(local) => local.translation,
+                        None => continue,
+                    },
+                };
+                Aabb::from_center_half_extents(position, Vec3::new(0.5, 0.5, 0.5))
+            }
+        };
+        bounds = bounds.merge(&entity_box);
+    }
+    bounds.is_valid().then_some(bounds)
+}
+
 /// Keeps ECS scene-camera activation consistent with the current play mode.
 pub fn sync_scene_cameras_for_mode(world: &mut GameWorld, mode: PlayMode) {
     let entities: Vec<EntityId> = world.iter_entities().collect();
@@ -245,6 +245,7 @@ impl EditorPanel for ConsolePanel {
                     if !filter_lower.is_empty()
                         && !entry.message.to_lowercase().contains(&filter_lower)
                         && !entry.target.to_lowercase().contains(&filter_lower)
+                        && !entry.thread.to_lowercase().contains(&filter_lower)
                     {
                         continue;
                     }
@@ -257,6 +258,7 @@ impl EditorPanel for ConsolePanel {
                     };
                     ui_s.horizontal(&mut |ui_h| {
                         ui_h.colored_label(color, prefix);
+                        ui_h.colored_label(theme_clone.text_muted, &format!("({})", entry.thread));
                         ui_h.colored_label(theme_clone.primary, &format!("[{}]", entry.target));
                         ui_h.colored_label(theme_clone.text, &entry.message);
                     });
@@ -24,6 +24,11 @@
 //!   timing isn't exposed by `ExecutionScheduler` yet, so this is a
 //!   schedule, not a timeline.
 //! - an Inspector for the selected agent, showing real status fields.
+//! - a Console vars tab, sharing the Schedule column, that edits the
+//!   engine's [`CVarRegistry`] with widgets picked by type: toggles for
+//!   bools, sliders for ranged numbers, drag values and text fields
+//!   otherwise. Edits are tagged [`CVarOrigin::Overlay`], so they show up in
+//!   the log and respect the registry's allowlist.
 //!
 //! When the registry isn't available (engine not booted, headless test) the
 //! workspace falls back to a clear "(no agents registered)" state instead
@@ -33,7 +38,8 @@ use std::sync::{Arc, Mutex};
 
 use khora_sdk::editor_ui::*;
 use khora_sdk::{
-    AgentId, AgentImportance, AgentRegistry, AgentStatus, DccContext, ExecutionPhase, StrategyId,
+    AgentId, AgentImportance, AgentRegistry, AgentStatus, CVar, CVarOrigin, CVarRegistry,
+    CVarValue, DccContext, ExecutionPhase, StrategyId,
 };
 
 use crate::widgets::brand::paint_diamond_filled;
@@ -90,6 +96,17 @@ impl AgentSnapshot {
     }
 }
 
+/// Which view fills the middle column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CenterTab {
+    Schedule,
+    ConsoleVars,
+}
+
+/// A console variable change requested from the overlay this frame.
+/// `None` restores the default.
+type CVarEdit = (String, Option<CVarValue>);
+
 /// Crate-of-origin convention: built-in `khora-agents` agents have known
 /// `AgentId` values; everything else is conventionally classified as
 /// `user-plugin` (extension). This mapping is data-only — it doesn't try to
@@ -99,6 +116,8 @@ fn crate_for_id(id: AgentId) -> &'static str {
         AgentId::Renderer
         | AgentId::ShadowRenderer
         | AgentId::Physics
+        | AgentId::Animation
+        | AgentId::InverseKinematics
         | AgentId::Ecs
         | AgentId::Ui
         | AgentId::Audio
@@ -111,6 +130,8 @@ pub struct ControlPlanePanel {
     theme: EditorTheme,
     registry: Option<Arc<Mutex<AgentRegistry>>>,
     dcc_context: Option<Arc<std::sync::RwLock<DccContext>>>,
+    cvars: Option<CVarRegistry>,
+    center_tab: CenterTab,
     selected_idx: usize,
 }
 
@@ -120,12 +141,15 @@ impl ControlPlanePanel {
         theme: EditorTheme,
         registry: Option<Arc<Mutex<AgentRegistry>>>,
         dcc_context: Option<Arc<std::sync::RwLock<DccContext>>>,
+        cvars: Option<CVarRegistry>,
     ) -> Self {
         Self {
             state,
             theme,
             registry,
             dcc_context,
+            cvars,
+            center_tab: CenterTab::Schedule,
             selected_idx: 0,
         }
     }
@@ -140,10 +164,7 @@ impl ControlPlanePanel {
             return Vec::new();
         };
         let mut out = Vec::new();
-        for agent_arc in reg.iter() {
-            let Ok(agent) = agent_arc.lock() else {
-                continue;
-            };
+        for agent in reg.iter() {
             let timing = agent.execution_timing();
             let status = agent.report_status();
             let id = agent.id();
@@ -592,7 +613,7 @@ impl ControlPlanePanel {
     }
 
     fn paint_schedule_panel(
-        &self,
+        &mut self,
         ui: &mut dyn UiBuilder,
         rect: [f32; 4],
         agents: &[AgentSnapshot],
@@ -609,15 +630,35 @@ impl ControlPlanePanel {
         );
 
         paint_panel_header(ui, [x, y, w, 34.0], 34.0, theme);
-        let _ = panel_tab(
+        let (schedule_clicked, schedule_w) = panel_tab(
             ui,
             "cp-tab-schedule",
             [x + 6.0, y + 6.0],
             "Schedule",
             None,
-            true,
+            self.center_tab == CenterTab::Schedule,
+            theme,
+        );
+        let cvar_count = self.cvars.as_ref().map(|c| c.list().len().to_string());
+        let (cvars_clicked, _) = panel_tab(
+            ui,
+            "cp-tab-cvars",
+            [x + 10.0 + schedule_w, y + 6.0],
+            "Console vars",
+            cvar_count.as_deref(),
+            self.center_tab == CenterTab::ConsoleVars,
             theme,
         );
+        if schedule_clicked {
+            self.center_tab = CenterTab::Schedule;
+        } else if cvars_clicked {
+            self.center_tab = CenterTab::ConsoleVars;
+        }
+        if self.center_tab == CenterTab::ConsoleVars {
+            self.paint_cvars_body(ui, [x, y + 42.0, w, h - 42.0], theme);
+            return;
+        }
+
         ui.paint_text_styled(
             [x + w - 14.0, y + 13.0],
             "16.67ms target · per-phase timing WIP",
@@ -716,6 +757,55 @@ impl ControlPlanePanel {
         }
     }
 
+    /// Lists every console variable with an editor picked by its type.
+    /// Variables the allowlist keeps from the overlay are shown read-only.
+    fn paint_cvars_body(&self, ui: &mut dyn UiBuilder, rect: [f32; 4], theme: &EditorTheme) {
+        let [x, y, w, h] = rect;
+        let Some(cvars) = self.cvars.as_ref() else {
+            ui.paint_text_styled(
+                [x + 16.0, y + 8.0],
+                "(console variables unavailable)",
+                11.5,
+                theme.text_muted,
+                FontFamilyHint::Proportional,
+                TextAlign::Left,
+            );
+            return;
+        };
+        let vars = cvars.list();
+        if vars.is_empty() {
+            ui.paint_text_styled(
+                [x + 16.0, y + 8.0],
+                "(no console variables registered)",
+                11.5,
+                theme.text_muted,
+                FontFamilyHint::Proportional,
+                TextAlign::Left,
+            );
+            return;
+        }
+
+        let mut edits: Vec<CVarEdit> = Vec::new();
+        ui.region_at([x + 12.0, y, w - 24.0, h - 8.0], &mut |ui_inner| {
+            ui_inner.scroll_area("cp-cvars-scroll", &mut |ui_s| {
+                for var in &vars {
+                    let editable = cvars.is_editable(&var.name, &CVarOrigin::Overlay);
+                    cvar_row(ui_s, var, editable, &mut edits);
+                }
+            });
+        });
+
+        for (name, value) in edits {
+            let result = match value {
+                Some(value) => cvars.set_from(&name, value, CVarOrigin::Overlay),
+                None => cvars.reset_from(&name, CVarOrigin::Overlay),
+            };
+            if let Err(e) = result {
+                log::warn!("Control Plane: {e}");
+            }
+        }
+    }
+
     fn paint_inspector_panel(
         &self,
         ui: &mut dyn UiBuilder,
@@ -956,6 +1046,64 @@ impl AgentSnapshot {
     }
 }
 
+/// One console variable row: the type-appropriate editor, a reset button
+/// when the value differs from the default, and the description as tooltip.
+fn cvar_row(ui: &mut dyn UiBuilder, var: &CVar, editable: bool, edits: &mut Vec<CVarEdit>) {
+    ui.horizontal(&mut |row| {
+        if !editable {
+            row.label(&var.name);
+            row.monospace(&var.value.to_string());
+            row.tooltip_for_last(&format!("{} (locked by the allowlist)", var.description));
+            return;
+        }
+
+        let range = var.range.map(|(min, max)| (min as f32, max as f32));
+        let edited = match &var.value {
+            CVarValue::Bool(value) => {
+                let mut local = *value;
+                row.checkbox(&mut local, &var.name)
+                    .then_some(CVarValue::Bool(local))
+            }
+            CVarValue::Float(value) => {
+                let mut local = *value as f32;
+                let changed = match range {
+                    Some((min, max)) => row.slider_f32(&var.name, &mut local, min, max),
+                    None => row.drag_value_f32(&var.name, &mut local, 0.01),
+                };
+                changed.then_some(CVarValue::Float(local as f64))
+            }
+            CVarValue::Int(value) => {
+                let mut local = *value as f32;
+                let changed = match range {
+                    Some((min, max)) => row.slider_f32(&var.name, &mut local, min, max),
+                    None => row.drag_value_f32(&var.name, &mut local, 1.0),
+                };
+                let rounded = local.round() as i64;
+                (changed && rounded != *value).then_some(CVarValue::Int(rounded))
+            }
+            CVarValue::String(value) => {
+                row.label(&var.name);
+                let mut local = value.clone();
+                row.text_edit_singleline(&mut local)
+                    .then_some(CVarValue::String(local))
+            }
+            other => {
+                row.label(&var.name);
+                row.monospace(&other.to_string());
+                None
+            }
+        };
+        row.tooltip_for_last(&var.description);
+        if let Some(value) = edited {
+            edits.push((var.name.clone(), Some(value)));
+        }
+
+        if var.value != var.default && row.small_button("Reset") {
+            edits.push((var.name.clone(), None));
+        }
+    });
+}
+
 fn phase_color_for(phase: ExecutionPhase, theme: &EditorTheme) -> [f32; 4] {
     if phase == ExecutionPhase::INIT {
         theme.text_muted
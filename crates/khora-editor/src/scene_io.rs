@@ -14,88 +14,12 @@
 
 //! Scene serialization and project-asset scanning helpers.
 
-use crate::util::{bytemuck_transform, unbytemuck_transform};
 use khora_sdk::editor_ui::AssetEntry;
 use khora_sdk::prelude::ecs::*;
 use khora_sdk::prelude::math::{LinearRgba, Vec3};
 use khora_sdk::{GameWorld, SceneFile, SerializationGoal, SerializationService};
 use std::path::{Path, PathBuf};
 
-/// Serializes all entity transforms into a binary snapshot for play-mode restore.
-pub fn snapshot_scene(world: &GameWorld) -> Vec<u8> {
-    let entities: Vec<EntityId> = world.iter_entities().collect();
-    let mut data: Vec<u8> = Vec::new();
-
-    let count = entities.len() as u32;
-    data.extend_from_slice(&count.to_le_bytes());
-
-    for &entity in &entities {
-        data.extend_from_slice(&entity.index.to_le_bytes());
-        data.extend_from_slice(&entity.generation.to_le_bytes());
-
-        if let Some(t) = world.get_component::<Transform>(entity) {
-            data.push(1);
-            data.extend_from_slice(&bytemuck_transform(t));
-        } else {
-            data.push(0);
-        }
-    }
-
-    data
-}
-
-/// Restores entity transforms from a binary snapshot.
-pub fn restore_scene(world: &mut GameWorld, snapshot: &[u8]) {
-    if snapshot.len() < 4 {
-        return;
-    }
-
-    let count = u32::from_le_bytes([snapshot[0], snapshot[1], snapshot[2], snapshot[3]]) as usize;
-    let mut offset = 4;
-
-    for _ in 0..count {
-        if offset + 8 > snapshot.len() {
-            break;
-        }
-
-        let index = u32::from_le_bytes([
-            snapshot[offset],
-            snapshot[offset + 1],
-            snapshot[offset + 2],
-            snapshot[offset + 3],
-        ]);
-        let generation = u32::from_le_bytes([
-            snapshot[offset + 4],
-            snapshot[offset + 5],
-            snapshot[offset + 6],
-            snapshot[offset + 7],
-        ]);
-        offset += 8;
-
-        let entity = EntityId { index, generation };
-
-        if offset >= snapshot.len() {
-            break;
-        }
-
-        let has_transform = snapshot[offset];
-        offset += 1;
-
-        if has_transform == 1 {
-            if offset + 40 > snapshot.len() {
-                break;
-            }
-
-            let transform = unbytemuck_transform(&snapshot[offset..offset + 40]);
-            offset += 40;
-
-            if let Some(existing) = world.get_component_mut::<Transform>(entity) {
-                *existing = transform;
-            }
-        }
-    }
-}
-
 /// Serializes the current scene to a KHORASCN file at the given path.
 pub fn save_scene_to(world: &GameWorld, path: &str) {
     let agent = SerializationService::new();
@@ -193,7 +117,6 @@ fn create_default_scene(world: &mut GameWorld, path: &Path) {
         Light::new(LightType::Directional(DirectionalLight {
             direction: Vec3::new(-0.4, -0.8, -0.45),
             color: LinearRgba::WHITE,
-            intensity: 1.0,
             ..Default::default()
         })),
         Name("Directional Light".to_string()),
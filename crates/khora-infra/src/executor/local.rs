@@ -0,0 +1,154 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A single-threaded executor driven by the engine loop.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::Instant;
+
+use khora_core::executor::{BoxFuture, Executor};
+
+use super::queue::RunQueue;
+use super::task::{TaskCell, TaskQueue};
+use super::timer::Timers;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Bound on the rounds of a single [`LocalExecutor::run_until_stalled`],
+/// so a task that wakes itself on every poll cannot hold the frame.
+const MAX_ROUNDS: usize = 64;
+
+/// Runs tasks, blocking jobs and timers on whichever thread calls
+/// [`run_until_stalled`](Self::run_until_stalled) — the engine thread, once
+/// per frame, through [`Executor::tick`].
+///
+/// Nothing runs in between, which makes it deterministic and a good fit
+/// for tests and headless runs. Blocking jobs run inline during the tick,
+/// so real I/O belongs on a [`ThreadPoolExecutor`](super::ThreadPoolExecutor).
+pub struct LocalExecutor {
+    tasks: Arc<TaskQueue>,
+    blocking: RunQueue<Job>,
+    timers: Arc<Timers>,
+}
+
+impl LocalExecutor {
+    /// Creates an executor with nothing queued.
+    pub fn new() -> Self {
+        Self {
+            tasks: Arc::new(RunQueue::new()),
+            blocking: RunQueue::new(),
+            timers: Arc::default(),
+        }
+    }
+
+    /// Fires due timers, runs queued blocking jobs and polls woken tasks
+    /// until none is left. Returns how many jobs and polls ran.
+    pub fn run_until_stalled(&self) -> usize {
+        let mut steps = 0;
+        for _ in 0..MAX_ROUNDS {
+            self.timers.fire_expired(Instant::now());
+            let before = steps;
+            for _ in 0..self.blocking.len() {
+                let Some(job) = self.blocking.try_pop() else {
+                    break;
+                };
+                if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                    log::error!("LocalExecutor: a blocking job panicked");
+                }
+                steps += 1;
+            }
+            for _ in 0..self.tasks.len() {
+                let Some(task) = self.tasks.try_pop() else {
+                    break;
+                };
+                task.run();
+                steps += 1;
+            }
+            if steps == before {
+                break;
+            }
+        }
+        steps
+    }
+}
+
+impl Default for LocalExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Executor for LocalExecutor {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    fn spawn_boxed(&self, future: BoxFuture<'static, ()>) {
+        TaskCell::spawn(future, &self.tasks);
+    }
+
+    fn spawn_blocking_boxed(&self, job: Box<dyn FnOnce() + Send + 'static>) {
+        if self.blocking.push(job).is_err() {
+            log::warn!("LocalExecutor: blocking job spawned after shutdown was dropped");
+        }
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        self.timers.sleep_until(deadline)
+    }
+
+    fn tick(&self) {
+        self.run_until_stalled();
+    }
+
+    fn shutdown(&self) {
+        self.tasks.close();
+        self.blocking.close();
+        self.timers.close();
+    }
+}
+
+impl Drop for LocalExecutor {
+    fn drop(&mut self) {
+        // Breaks the task <-> queue reference cycle of pending tasks.
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::executor::SharedExecutor;
+    use std::time::Duration;
+
+    #[test]
+    fn tasks_only_run_when_ticked() {
+        let executor: SharedExecutor = Arc::new(LocalExecutor::new());
+        let mut blocking = executor.spawn_blocking(|| 2);
+        let inner = Arc::clone(&executor);
+        let mut task = executor.spawn(async move {
+            let doubled = inner.spawn_blocking(|| 21).await.unwrap_or(0) * 2;
+            inner.sleep(Duration::from_millis(1)).await;
+            doubled
+        });
+        assert!(!task.is_finished());
+
+        executor.tick();
+        assert_eq!(blocking.try_take(), Some(2));
+        std::thread::sleep(Duration::from_millis(2));
+        executor.tick();
+        assert_eq!(task.try_take(), Some(42));
+    }
+}
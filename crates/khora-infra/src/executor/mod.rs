@@ -0,0 +1,32 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The engine's implementations of [`khora_core::executor::Executor`].
+//!
+//! - [`LocalExecutor`] runs everything on the engine thread, a little each
+//!   frame when the engine calls [`Executor::tick`].
+//! - [`ThreadPoolExecutor`] polls tasks on worker threads and runs blocking
+//!   jobs on a separate set of I/O threads, so a slow read never stalls a
+//!   task. It is the engine's default.
+//!
+//! [`Executor::tick`]: khora_core::executor::Executor::tick
+
+pub mod local;
+mod queue;
+mod task;
+pub mod thread_pool;
+mod timer;
+
+pub use local::LocalExecutor;
+pub use thread_pool::{ThreadPoolExecutor, DEFAULT_BLOCKING_THREADS};
@@ -0,0 +1,98 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A closable FIFO shared by producers and worker threads.
+
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+struct Items<T> {
+    queue: VecDeque<T>,
+    closed: bool,
+}
+
+/// A FIFO that workers can wait on. Once closed it rejects new items and
+/// wakes every waiting worker.
+pub(crate) struct RunQueue<T> {
+    items: Mutex<Items<T>>,
+    ready: Condvar,
+}
+
+impl<T> RunQueue<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            items: Mutex::new(Items {
+                queue: VecDeque::new(),
+                closed: false,
+            }),
+            ready: Condvar::new(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Items<T>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Queues `item`. Returns it back if the queue is closed.
+    pub(crate) fn push(&self, item: T) -> Result<(), T> {
+        let mut items = self.lock();
+        if items.closed {
+            return Err(item);
+        }
+        items.queue.push_back(item);
+        drop(items);
+        self.ready.notify_one();
+        Ok(())
+    }
+
+    /// Takes the oldest item without waiting.
+    pub(crate) fn try_pop(&self) -> Option<T> {
+        self.lock().queue.pop_front()
+    }
+
+    /// Waits for an item. Returns `None` once the queue is closed.
+    pub(crate) fn pop_blocking(&self) -> Option<T> {
+        let mut items = self.lock();
+        loop {
+            if items.closed {
+                return None;
+            }
+            if let Some(item) = items.queue.pop_front() {
+                return Some(item);
+            }
+            items = self
+                .ready
+                .wait(items)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Returns the number of queued items.
+    pub(crate) fn len(&self) -> usize {
+        self.lock().queue.len()
+    }
+
+    /// Closes the queue and drops the items still in it.
+    pub(crate) fn close(&self) {
+        let dropped = {
+            let mut items = self.lock();
+            items.closed = true;
+            std::mem::take(&mut items.queue)
+        };
+        self.ready.notify_all();
+        // Dropped outside the lock: dropping a task may wake another one,
+        // which pushes to this queue.
+        drop(dropped);
+    }
+}
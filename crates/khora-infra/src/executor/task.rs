@@ -0,0 +1,72 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A spawned future and the waker that reschedules it.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Wake, Waker};
+
+use khora_core::executor::BoxFuture;
+
+use super::queue::RunQueue;
+
+/// The queue tasks are scheduled on.
+pub(crate) type TaskQueue = RunQueue<Arc<TaskCell>>;
+
+/// A spawned future. Waking it pushes it back on its queue.
+pub(crate) struct TaskCell {
+    /// `None` once the future has completed or panicked.
+    future: Mutex<Option<BoxFuture<'static, ()>>>,
+    queue: Arc<TaskQueue>,
+}
+
+impl TaskCell {
+    /// Wraps `future` and schedules its first poll on `queue`.
+    pub(crate) fn spawn(future: BoxFuture<'static, ()>, queue: &Arc<TaskQueue>) {
+        let cell = Arc::new(TaskCell {
+            future: Mutex::new(Some(future)),
+            queue: Arc::clone(queue),
+        });
+        if queue.push(cell).is_err() {
+            log::warn!("executor: task spawned after shutdown was dropped");
+        }
+    }
+
+    /// Polls the future once. A panic drops the task and is logged.
+    pub(crate) fn run(self: Arc<Self>) {
+        let waker = Waker::from(Arc::clone(&self));
+        let mut cx = Context::from_waker(&waker);
+        let mut slot = self.future.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(future) = slot.as_mut() else {
+            return;
+        };
+        match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(&mut cx))) {
+            Ok(Poll::Pending) => {}
+            Ok(Poll::Ready(())) => *slot = None,
+            Err(_) => {
+                log::error!("executor: a task panicked and was dropped");
+                *slot = None;
+            }
+        }
+    }
+}
+
+impl Wake for TaskCell {
+    fn wake(self: Arc<Self>) {
+        let queue = Arc::clone(&self.queue);
+        // A closed queue drops the task, which is what shutdown wants.
+        let _ = queue.push(self);
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A multi-threaded executor with separate threads for blocking jobs.
+
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::Instant;
+
+use khora_core::executor::{BoxFuture, Executor};
+use khora_core::threading::{self, ThreadRole};
+
+use super::queue::RunQueue;
+use super::task::{TaskCell, TaskQueue};
+use super::timer::Timers;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Number of blocking-job threads used by
+/// [`ThreadPoolExecutor::with_available_parallelism`].
+pub const DEFAULT_BLOCKING_THREADS: usize = 4;
+
+/// Polls tasks on `khora-async-*` worker threads, runs blocking jobs on
+/// `khora-blocking-*` I/O threads and fires timers from `khora-timer`.
+///
+/// Shutting down closes the queues: queued work is dropped and the threads
+/// exit once their current job returns. Shutdown does not wait for them.
+pub struct ThreadPoolExecutor {
+    tasks: Arc<TaskQueue>,
+    blocking: Arc<RunQueue<Job>>,
+    timers: Arc<Timers>,
+    workers: usize,
+}
+
+impl ThreadPoolExecutor {
+    /// Starts `workers` task threads and `blocking_threads` blocking-job
+    /// threads, at least one of each.
+    pub fn new(workers: usize, blocking_threads: usize) -> io::Result<Self> {
+        let executor = Self {
+            tasks: Arc::new(RunQueue::new()),
+            blocking: Arc::new(RunQueue::new()),
+            timers: Arc::default(),
+            workers: workers.max(1),
+        };
+        // On error, dropping `executor` closes the queues so the threads
+        // already started exit.
+        for i in 0..executor.workers {
+            let tasks = Arc::clone(&executor.tasks);
+            threading::spawn_named(format!("khora-async-{i}"), ThreadRole::Worker, move || {
+                while let Some(task) = tasks.pop_blocking() {
+                    task.run();
+                }
+            })?;
+        }
+        for i in 0..blocking_threads.max(1) {
+            let blocking = Arc::clone(&executor.blocking);
+            threading::spawn_named(format!("khora-blocking-{i}"), ThreadRole::Io, move || {
+                while let Some(job) = blocking.pop_blocking() {
+                    if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                        log::error!("ThreadPoolExecutor: a blocking job panicked");
+                    }
+                }
+            })?;
+        }
+        let timers = Arc::clone(&executor.timers);
+        threading::spawn_named("khora-timer", ThreadRole::Worker, move || timers.run())?;
+        Ok(executor)
+    }
+
+    /// Starts one task thread per available core, minus the engine thread,
+    /// and [`DEFAULT_BLOCKING_THREADS`] blocking-job threads.
+    pub fn with_available_parallelism() -> io::Result<Self> {
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self::new(cores.saturating_sub(1), DEFAULT_BLOCKING_THREADS)
+    }
+
+    /// Returns the number of task threads.
+    pub fn workers(&self) -> usize {
+        self.workers
+    }
+}
+
+impl Executor for ThreadPoolExecutor {
+    fn name(&self) -> &str {
+        "thread-pool"
+    }
+
+    fn spawn_boxed(&self, future: BoxFuture<'static, ()>) {
+        TaskCell::spawn(future, &self.tasks);
+    }
+
+    fn spawn_blocking_boxed(&self, job: Box<dyn FnOnce() + Send + 'static>) {
+        if self.blocking.push(job).is_err() {
+            log::warn!("ThreadPoolExecutor: blocking job spawned after shutdown was dropped");
+        }
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        self.timers.sleep_until(deadline)
+    }
+
+    fn shutdown(&self) {
+        self.tasks.close();
+        self.blocking.close();
+        self.timers.close();
+    }
+}
+
+impl Drop for ThreadPoolExecutor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::executor::{block_on, SharedExecutor};
+    use std::time::Duration;
+
+    #[test]
+    fn runs_tasks_blocking_jobs_and_timers() {
+        let executor: SharedExecutor = Arc::new(ThreadPoolExecutor::new(2, 1).unwrap());
+        let inner = Arc::clone(&executor);
+        let task = executor.spawn(async move {
+            let read = inner.spawn_blocking(|| vec![1u8, 2, 3]);
+            inner.sleep(Duration::from_millis(5)).await;
+            read.await.map_or(0, |bytes| bytes.len())
+        });
+        assert_eq!(block_on(task), Some(3));
+
+        let slow = executor.sleep(Duration::from_secs(10));
+        let timed_out = executor.timeout(Duration::from_millis(5), slow);
+        assert_eq!(block_on(timed_out), None);
+
+        executor.shutdown();
+        assert_eq!(block_on(executor.spawn(async { 1 })), None);
+    }
+}
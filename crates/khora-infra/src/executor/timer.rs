@@ -0,0 +1,182 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deadline-ordered wakers behind the executors' `sleep_until`.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::Instant;
+
+use khora_core::executor::BoxFuture;
+
+struct Entry {
+    deadline: Instant,
+    seq: u64,
+    waker: Waker,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.deadline, self.seq).cmp(&(other.deadline, other.seq))
+    }
+}
+
+#[derive(Default)]
+struct TimerState {
+    entries: BinaryHeap<Reverse<Entry>>,
+    next_seq: u64,
+    closed: bool,
+}
+
+/// Pending timers, fired either by a dedicated thread ([`run`](Self::run))
+/// or by polling ([`fire_expired`](Self::fire_expired)).
+#[derive(Default)]
+pub(crate) struct Timers {
+    state: Mutex<TimerState>,
+    changed: Condvar,
+}
+
+impl Timers {
+    fn lock(&self) -> MutexGuard<'_, TimerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns a future completing once `deadline` has passed.
+    pub(crate) fn sleep_until(self: &Arc<Self>, deadline: Instant) -> BoxFuture<'static, ()> {
+        Box::pin(Sleep {
+            deadline,
+            timers: Arc::clone(self),
+        })
+    }
+
+    fn register(&self, deadline: Instant, waker: Waker) {
+        let mut state = self.lock();
+        if state.closed {
+            return;
+        }
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.entries.push(Reverse(Entry {
+            deadline,
+            seq,
+            waker,
+        }));
+        drop(state);
+        self.changed.notify_one();
+    }
+
+    /// Removes the timers due at `now` from `state`.
+    fn take_expired(state: &mut TimerState, now: Instant) -> Vec<Waker> {
+        let mut expired = Vec::new();
+        while state
+            .entries
+            .peek()
+            .is_some_and(|Reverse(entry)| entry.deadline <= now)
+        {
+            if let Some(Reverse(entry)) = state.entries.pop() {
+                expired.push(entry.waker);
+            }
+        }
+        expired
+    }
+
+    /// Wakes the timers due at `now` and returns the next deadline.
+    pub(crate) fn fire_expired(&self, now: Instant) -> Option<Instant> {
+        let (expired, next) = {
+            let mut state = self.lock();
+            let expired = Self::take_expired(&mut state, now);
+            (expired, state.entries.peek().map(|Reverse(e)| e.deadline))
+        };
+        expired.into_iter().for_each(Waker::wake);
+        next
+    }
+
+    /// Fires timers as they come due until [`close`](Self::close) is called.
+    pub(crate) fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            if state.closed {
+                return;
+            }
+            let now = Instant::now();
+            let expired = Self::take_expired(&mut state, now);
+            if !expired.is_empty() {
+                drop(state);
+                expired.into_iter().for_each(Waker::wake);
+                state = self.lock();
+                continue;
+            }
+            state = match state.entries.peek().map(|Reverse(e)| e.deadline) {
+                Some(deadline) => {
+                    self.changed
+                        .wait_timeout(state, deadline.saturating_duration_since(now))
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+
+    /// Drops every pending timer and stops [`run`](Self::run).
+    pub(crate) fn close(&self) {
+        let dropped = {
+            let mut state = self.lock();
+            state.closed = true;
+            std::mem::take(&mut state.entries)
+        };
+        self.changed.notify_all();
+        drop(dropped);
+    }
+}
+
+struct Sleep {
+    deadline: Instant,
+    timers: Arc<Timers>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        // Registered on every poll: a spurious extra wake-up is harmless,
+        // a missed one would stall the task.
+        self.timers.register(self.deadline, cx.waker().clone());
+        Poll::Pending
+    }
+}
@@ -100,6 +100,8 @@ impl WgpuBackendSelector {
             name: info.name.clone(),
             backend_type: Self::backend_to_type(info.backend),
             device_type: Self::device_type_to_type(info.device_type),
+            driver: info.driver.clone(),
+            driver_info: info.driver_info.clone(),
         }
     }
 
@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use khora_core::math::{Extent3D, Origin3D};
 use khora_core::renderer::api::command::{
     BindGroupId, CommandBufferId, ComputePassDescriptor, ComputePipelineId, RenderPassDescriptor,
 };
 use khora_core::renderer::api::pipeline::RenderPipelineId;
 use khora_core::renderer::api::resource::buffer as api_buf;
+use khora_core::renderer::api::resource::TextureId;
 use khora_core::renderer::api::util::IndexFormat;
 use khora_core::renderer::traits::{CommandEncoder, ComputePass, GpuProfiler, RenderPass};
 use std::any::Any;
@@ -286,13 +288,31 @@ impl CommandEncoder for WgpuCommandEncoder {
         &'encoder mut self,
         descriptor: &ComputePassDescriptor<'encoder>,
     ) -> Box<dyn ComputePass<'encoder> + 'encoder> {
+        // Hooked timestamps are only recorded while a profiler is active.
+        let query_set = descriptor
+            .timestamp_writes
+            .as_ref()
+            .and_then(|_| self.device.timestamp_query_set());
+        let timestamp_writes = descriptor
+            .timestamp_writes
+            .as_ref()
+            .zip(query_set.as_ref())
+            .map(|(writes, query_set)| wgpu::ComputePassTimestampWrites {
+                query_set,
+                beginning_of_pass_write_index: writes
+                    .beginning_of_pass_hook
+                    .map(|hook| WgpuTimestampProfiler::query_index(*hook)),
+                end_of_pass_write_index: writes
+                    .end_of_pass_hook
+                    .map(|hook| WgpuTimestampProfiler::query_index(*hook)),
+            });
         let pass =
             self.encoder
                 .as_mut()
                 .unwrap()
                 .begin_compute_pass(&wgpu::ComputePassDescriptor {
                     label: descriptor.label,
-                    timestamp_writes: None, // TODO
+                    timestamp_writes,
                 });
 
         Box::new(WgpuComputePass {
@@ -355,6 +375,48 @@ impl CommandEncoder for WgpuCommandEncoder {
         }
     }
 
+    fn copy_texture_to_buffer(
+        &mut self,
+        source: &TextureId,
+        origin: Origin3D,
+        destination: &api_buf::BufferId,
+        destination_offset: u64,
+        bytes_per_row: u32,
+        size: Extent3D,
+    ) {
+        let (Some(texture), Some(buffer)) = (
+            self.device.get_wgpu_texture(*source),
+            self.device.get_wgpu_buffer(*destination),
+        ) else {
+            log::warn!(
+                "WgpuCommandEncoder: copy_texture_to_buffer skipped, {:?} or {:?} not found.",
+                source,
+                destination
+            );
+            return;
+        };
+        let Some(encoder) = self.encoder.as_mut() else {
+            return;
+        };
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: origin.into_wgpu(),
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: destination_offset,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(size.height),
+                },
+            },
+            size.into_wgpu(),
+        );
+    }
+
     fn finish(mut self: Box<Self>) -> CommandBufferId {
         let finished_encoder = self.encoder.take().unwrap();
         self.device
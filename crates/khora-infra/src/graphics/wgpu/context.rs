@@ -22,10 +22,12 @@ use winit::dpi::PhysicalSize;
 /// Holds the core WGPU state objects required for rendering.
 /// This structure manages the connection to the graphics API for a specific surface.
 /// It is initialized with a pre-selected adapter, making it a passive component.
+///
+/// Headless contexts (see [`WgpuGraphicsContext::new_headless`]) carry no
+/// surface; their `surface_config` only records the offscreen format and size.
 #[derive(Debug)]
 pub struct WgpuGraphicsContext {
-    pub surface: wgpu::Surface<'static>,
-    #[allow(dead_code)]
+    pub surface: Option<wgpu::Surface<'static>>,
     pub adapter: wgpu::Adapter,
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
@@ -37,8 +39,9 @@ pub struct WgpuGraphicsContext {
     pub adapter_name: String,
     pub adapter_backend: wgpu::Backend,
     pub adapter_device_type: wgpu::DeviceType,
+    pub adapter_driver: String,
+    pub adapter_driver_info: String,
     pub active_device_features: wgpu::Features,
-    #[allow(dead_code)]
     pub device_limits: wgpu::Limits,
 }
 
@@ -78,30 +81,9 @@ impl WgpuGraphicsContext {
         );
 
         // --- 2. Create Logical Device and Command Queue from Adapter ---
-        let required_features_for_engine: Features = wgpu::Features::TIMESTAMP_QUERY;
-        let features_to_enable: Features = adapter.features() & required_features_for_engine;
-
-        let (device, queue) = adapter
-            .request_device(&wgpu::DeviceDescriptor {
-                label: Some("Khora Engine Logical Device"),
-                required_features: features_to_enable,
-                required_limits: wgpu::Limits::default(),
-                experimental_features: wgpu::ExperimentalFeatures::default(),
-                memory_hints: wgpu::MemoryHints::default(),
-                trace: wgpu::Trace::default(),
-            })
-            .await
-            .map_err(|e| anyhow!("Failed to create logical device: {}", e))?;
-        log::info!("Logical device and command queue created.");
-
-        device.on_uncaptured_error(std::sync::Arc::new(|e| {
-            log::error!("WGPU Uncaptured Error: {e:?}");
-        }));
-
+        let (device, queue) = Self::request_device(&adapter).await?;
         let active_device_features = device.features();
         let device_limits = device.limits();
-        log::info!("Active device features: {active_device_features:?}");
-        log::info!("Device limits: {device_limits:?}");
 
         // --- 3. Configure Surface ---
         let surface_caps = surface.get_capabilities(&adapter);
@@ -130,7 +112,7 @@ impl WgpuGraphicsContext {
         surface.configure(&device, &surface_config);
 
         Ok(WgpuGraphicsContext {
-            surface,
+            surface: Some(surface),
             adapter,
             device,
             queue,
@@ -138,11 +120,95 @@ impl WgpuGraphicsContext {
             adapter_name: adapter_info.name,
             adapter_backend: adapter_info.backend,
             adapter_device_type: adapter_info.device_type,
+            adapter_driver: adapter_info.driver,
+            adapter_driver_info: adapter_info.driver_info,
             active_device_features,
             device_limits,
         })
     }
 
+    /// Asynchronously initializes a graphics context without any window surface.
+    ///
+    /// Used for offscreen rendering (golden-image tests, thumbnails). Frames are
+    /// rendered into caller-owned textures of `format`; `get_current_texture`
+    /// always returns `None`.
+    ///
+    /// ## Arguments
+    /// * `adapter` - The pre-selected `wgpu::Adapter` to use.
+    /// * `size` - The size of the offscreen target, reported as the surface size.
+    /// * `format` - The color format reported as the surface format.
+    pub async fn new_headless(
+        adapter: Adapter,
+        size: PhysicalSize<u32>,
+        format: wgpu::TextureFormat,
+    ) -> Result<Self> {
+        log::info!("Initializing headless WGPU Graphics Context...");
+
+        let adapter_info = adapter.get_info();
+        log::info!(
+            "Using provided graphics adapter: \"{}\" (Backend: {:?})",
+            adapter_info.name,
+            adapter_info.backend
+        );
+
+        let (device, queue) = Self::request_device(&adapter).await?;
+        let active_device_features = device.features();
+        let device_limits = device.limits();
+
+        let surface_config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            format,
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+
+        Ok(WgpuGraphicsContext {
+            surface: None,
+            adapter,
+            device,
+            queue,
+            surface_config,
+            adapter_name: adapter_info.name,
+            adapter_backend: adapter_info.backend,
+            adapter_device_type: adapter_info.device_type,
+            adapter_driver: adapter_info.driver,
+            adapter_driver_info: adapter_info.driver_info,
+            active_device_features,
+            device_limits,
+        })
+    }
+
+    /// Requests the logical device and queue with the features the engine uses.
+    async fn request_device(adapter: &Adapter) -> Result<(wgpu::Device, wgpu::Queue)> {
+        let required_features_for_engine: Features = wgpu::Features::TIMESTAMP_QUERY;
+        let features_to_enable: Features = adapter.features() & required_features_for_engine;
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("Khora Engine Logical Device"),
+                required_features: features_to_enable,
+                required_limits: wgpu::Limits::default(),
+                experimental_features: wgpu::ExperimentalFeatures::default(),
+                memory_hints: wgpu::MemoryHints::default(),
+                trace: wgpu::Trace::default(),
+            })
+            .await
+            .map_err(|e| anyhow!("Failed to create logical device: {}", e))?;
+        log::info!("Logical device and command queue created.");
+
+        device.on_uncaptured_error(std::sync::Arc::new(|e| {
+            log::error!("WGPU Uncaptured Error: {e:?}");
+        }));
+
+        log::info!("Active device features: {:?}", device.features());
+        log::info!("Device limits: {:?}", device.limits());
+        Ok((device, queue))
+    }
+
     /// Reconfigures the underlying surface (swapchain) when the window is resized.
     pub fn resize(&mut self, new_width: u32, new_height: u32) {
         if new_width > 0 && new_height > 0 {
@@ -151,7 +217,9 @@ impl WgpuGraphicsContext {
             );
             self.surface_config.width = new_width;
             self.surface_config.height = new_height;
-            self.surface.configure(&self.device, &self.surface_config);
+            if let Some(surface) = &self.surface {
+                surface.configure(&self.device, &self.surface_config);
+            }
         } else {
             log::warn!(
                 "WGPUGraphicsContext: Ignoring resize request to zero dimensions: {new_width}x{new_height}"
@@ -163,10 +231,11 @@ impl WgpuGraphicsContext {
     /// This is useful for obtaining the texture to render into.
     ///
     /// ## Returns
-    /// * `wgpu::CurrentSurfaceTexture` - The result of acquiring the next swapchain frame.
-    ///   See [`wgpu::CurrentSurfaceTexture`] for how each variant should be handled.
-    pub fn get_current_texture(&self) -> wgpu::CurrentSurfaceTexture {
-        self.surface.get_current_texture()
+    /// * `Option<wgpu::CurrentSurfaceTexture>` - The result of acquiring the next swapchain
+    ///   frame, or `None` for a headless context. See [`wgpu::CurrentSurfaceTexture`] for how
+    ///   each variant should be handled.
+    pub fn get_current_texture(&self) -> Option<wgpu::CurrentSurfaceTexture> {
+        self.surface.as_ref().map(|s| s.get_current_texture())
     }
 
     #[allow(dead_code)]
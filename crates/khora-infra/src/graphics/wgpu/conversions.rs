@@ -197,6 +197,7 @@ impl IntoWgpu<wgpu::TextureFormat> for TextureFormat {
             TextureFormat::R32Float => wgpu::TextureFormat::R32Float,
             TextureFormat::Rg32Float => wgpu::TextureFormat::Rg32Float,
             TextureFormat::Rgba32Float => wgpu::TextureFormat::Rgba32Float,
+            TextureFormat::Rg32Uint => wgpu::TextureFormat::Rg32Uint,
             TextureFormat::Depth16Unorm => wgpu::TextureFormat::Depth16Unorm,
             TextureFormat::Depth24Plus => wgpu::TextureFormat::Depth24Plus,
             TextureFormat::Depth24PlusStencil8 => wgpu::TextureFormat::Depth24PlusStencil8,
@@ -221,6 +222,7 @@ pub fn from_wgpu_texture_format(format: wgpu::TextureFormat) -> TextureFormat {
         wgpu::TextureFormat::R32Float => TextureFormat::R32Float,
         wgpu::TextureFormat::Rg32Float => TextureFormat::Rg32Float,
         wgpu::TextureFormat::Rgba32Float => TextureFormat::Rgba32Float,
+        wgpu::TextureFormat::Rg32Uint => TextureFormat::Rg32Uint,
         wgpu::TextureFormat::Depth16Unorm => TextureFormat::Depth16Unorm,
         wgpu::TextureFormat::Depth24Plus => TextureFormat::Depth24Plus,
         wgpu::TextureFormat::Depth32Float => TextureFormat::Depth32Float,
@@ -0,0 +1,197 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deferred destruction of GPU resources, released against a frame fence.
+//!
+//! Destroying a transient resource through the [`GraphicsDevice`] only
+//! unregisters its ID. The wgpu object is parked in a
+//! [`DeferredDestructionQueue`], tagged with the frame it was retired in, and
+//! dropped once the GPU has finished every submission of that frame.
+//!
+//! Releases are capped per frame so a lane tearing down hundreds of per-frame
+//! bind groups does not turn into a single spike. When the ready backlog
+//! outgrows the cap, a `1 / DRAIN_FRAMES` share of it is released each frame
+//! instead, so a steady stream of retirements never accumulates.
+//!
+//! [`GraphicsDevice`]: khora_core::renderer::GraphicsDevice
+
+use std::collections::VecDeque;
+
+/// Default number of resources released per frame.
+pub const DEFAULT_RELEASE_BUDGET: usize = 64;
+
+/// A ready backlog larger than the budget is released at a rate of one
+/// `DRAIN_FRAMES`-th of it per frame.
+pub const DRAIN_FRAMES: usize = 8;
+
+/// Counters describing the deferred-destruction queue after a collection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeferredDestructionStats {
+    /// Resources retired but not released yet.
+    pub pending: usize,
+    /// VRAM still held by the pending resources, in bytes.
+    pub pending_bytes: u64,
+    /// Resources released by the last collection.
+    pub released: usize,
+    /// VRAM returned by the last collection, in bytes.
+    pub released_bytes: u64,
+}
+
+#[derive(Debug)]
+struct Retired<T> {
+    fence: u64,
+    bytes: u64,
+    resource: T,
+}
+
+/// A FIFO of retired resources waiting for their frame fence to signal.
+#[derive(Debug)]
+pub(crate) struct DeferredDestructionQueue<T> {
+    entries: VecDeque<Retired<T>>,
+    pending_bytes: u64,
+    budget: usize,
+}
+
+impl<T> Default for DeferredDestructionQueue<T> {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            pending_bytes: 0,
+            budget: DEFAULT_RELEASE_BUDGET,
+        }
+    }
+}
+
+impl<T> DeferredDestructionQueue<T> {
+    /// Sets the number of resources released per frame. Clamped to at least one.
+    pub(crate) fn set_budget(&mut self, per_frame: usize) {
+        self.budget = per_frame.max(1);
+    }
+
+    /// Parks `resource` until the frame `fence` has completed on the GPU.
+    ///
+    /// Fences must be pushed in non-decreasing order.
+    pub(crate) fn retire(&mut self, fence: u64, bytes: u64, resource: T) {
+        debug_assert!(self.entries.back().is_none_or(|last| last.fence <= fence));
+        self.pending_bytes += bytes;
+        self.entries.push_back(Retired {
+            fence,
+            bytes,
+            resource,
+        });
+    }
+
+    /// Pops the resources whose fence is at or below `completed_fence`,
+    /// within this frame's budget.
+    ///
+    /// Returns the popped resources, for the caller to drop outside its
+    /// locks, and the bytes they held.
+    pub(crate) fn collect(&mut self, completed_fence: u64) -> (Vec<T>, u64) {
+        let ready = self
+            .entries
+            .iter()
+            .take_while(|e| e.fence <= completed_fence)
+            .count();
+        let limit = ready.min(self.budget.max(ready.div_ceil(DRAIN_FRAMES)));
+
+        let mut bytes = 0;
+        let released = self
+            .entries
+            .drain(..limit)
+            .map(|e| {
+                bytes += e.bytes;
+                e.resource
+            })
+            .collect();
+        self.pending_bytes -= bytes;
+        (released, bytes)
+    }
+
+    /// Pops every resource regardless of its fence. Only sound once the
+    /// device is idle.
+    pub(crate) fn drain_all(&mut self) -> (Vec<T>, u64) {
+        let bytes = std::mem::take(&mut self.pending_bytes);
+        (self.entries.drain(..).map(|e| e.resource).collect(), bytes)
+    }
+
+    /// The number of resources waiting to be released.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The bytes held by the resources waiting to be released.
+    pub(crate) fn pending_bytes(&self) -> u64 {
+        self.pending_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn releases_only_completed_fences() {
+        let mut queue = DeferredDestructionQueue::default();
+        queue.retire(1, 100, "a");
+        queue.retire(2, 50, "b");
+
+        assert_eq!(queue.collect(0), (vec![], 0));
+        assert_eq!(queue.collect(1), (vec!["a"], 100));
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.pending_bytes(), 50);
+        assert_eq!(queue.collect(5), (vec!["b"], 50));
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn budget_spreads_releases_across_frames() {
+        let mut queue = DeferredDestructionQueue::default();
+        queue.set_budget(4);
+        for i in 0..10 {
+            queue.retire(1, 1, i);
+        }
+
+        assert_eq!(queue.collect(1).0, vec![0, 1, 2, 3]);
+        assert_eq!(queue.collect(1).0, vec![4, 5, 6, 7]);
+        assert_eq!(queue.collect(1).0, vec![8, 9]);
+    }
+
+    #[test]
+    fn large_backlog_outpaces_the_budget() {
+        let mut queue = DeferredDestructionQueue::default();
+        queue.set_budget(1);
+        for i in 0..(DRAIN_FRAMES * 10) {
+            queue.retire(1, 0, i);
+        }
+
+        assert_eq!(queue.collect(1).0.len(), 10);
+        let mut frames = 1;
+        while queue.len() > 0 {
+            queue.collect(1);
+            frames += 1;
+        }
+        assert!(frames < DRAIN_FRAMES * 10);
+    }
+
+    #[test]
+    fn drain_all_ignores_fences() {
+        let mut queue = DeferredDestructionQueue::default();
+        queue.retire(7, 8, ());
+        queue.retire(9, 8, ());
+
+        assert_eq!(queue.drain_all().1, 16);
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.pending_bytes(), 0);
+    }
+}
@@ -81,19 +81,21 @@ use crate::graphics::wgpu::command::WgpuCommandEncoder;
 use crate::graphics::wgpu::conversions::{from_wgpu_texture_format, IntoWgpu};
 
 use super::context::WgpuGraphicsContext;
-struct MapAsyncFutureState {
-    result: Mutex<Option<Result<(), ResourceError>>>,
+use super::deferred::{DeferredDestructionQueue, DeferredDestructionStats};
+
+struct MapAsyncFutureState<T> {
+    result: Mutex<Option<Result<T, ResourceError>>>,
     // The Waker to wake up the Future when the result is ready
     waker: Mutex<Option<Waker>>,
 }
 
 // Custom Future implementation to wrap the MapAsyncFutureState
-struct MapAsyncOperationFuture {
-    state: Arc<MapAsyncFutureState>,
+struct MapAsyncOperationFuture<T> {
+    state: Arc<MapAsyncFutureState<T>>,
 }
 
-impl Future for MapAsyncOperationFuture {
-    type Output = Result<(), ResourceError>;
+impl<T> Future for MapAsyncOperationFuture<T> {
+    type Output = Result<T, ResourceError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         let mut result_guard = self.state.result.lock().unwrap();
@@ -167,6 +169,17 @@ pub(crate) struct WgpuPipelineLayoutEntry {
     pub(crate) wgpu_layout: Arc<wgpu::PipelineLayout>,
 }
 
+/// A destroyed resource waiting in the deferred-destruction queue.
+#[allow(dead_code)] // Held only to be dropped once its frame fence signals.
+#[derive(Debug)]
+enum RetiredResource {
+    Buffer(Arc<wgpu::Buffer>),
+    Texture(Arc<wgpu::Texture>),
+    TextureView(Arc<wgpu::TextureView>),
+    Sampler(Arc<wgpu::Sampler>),
+    BindGroup(Arc<wgpu::BindGroup>),
+}
+
 /// The internal, non-clonable state of the WgpuDevice.
 /// This struct holds all the GPU resources and state, protected by an Arc.
 #[derive(Debug)]
@@ -206,6 +219,20 @@ pub struct WgpuDeviceInternal {
     /// The submission index returned by the most recent `queue.submit()` call.
     /// Used to synchronize frame acquisition with GPU completion.
     last_submission_index: Mutex<Option<wgpu::SubmissionIndex>>,
+    /// The profiler's timestamp query set, written by compute passes that
+    /// request [`GpuHook`](khora_core::renderer::api::core::GpuHook) timestamps.
+    timestamp_queries: Mutex<Option<wgpu::QuerySet>>,
+
+    // Deferred destruction
+    /// Serial of the frame being recorded; retired resources are tagged with it.
+    frame_fence: AtomicU64,
+    /// Highest frame serial whose submissions the GPU has finished.
+    completed_fence: Arc<AtomicU64>,
+    /// Destroyed buffers, textures, views, samplers and bind groups awaiting
+    /// their frame fence.
+    retired: Mutex<DeferredDestructionQueue<RetiredResource>>,
+    /// Resources released by the most recent [`WgpuDevice::end_frame`].
+    last_release: Mutex<(usize, u64)>,
 }
 
 /// A clonable, thread-safe handle to the WGPU graphics device.
@@ -246,6 +273,11 @@ impl WgpuDevice {
                 pending_command_buffers: Mutex::new(HashMap::new()),
                 command_buffer_id_counter: AtomicU64::new(0),
                 last_submission_index: Mutex::new(None),
+                timestamp_queries: Mutex::new(None),
+                frame_fence: AtomicU64::new(1),
+                completed_fence: Arc::new(AtomicU64::new(0)),
+                retired: Mutex::new(DeferredDestructionQueue::default()),
+                last_release: Mutex::new((0, 0)),
             }),
         }
     }
@@ -398,6 +430,23 @@ impl WgpuDevice {
         }
     }
 
+    /// Sets the query set that hooked compute passes write their timestamps
+    /// to, or clears it when profiling stops.
+    pub(crate) fn set_timestamp_query_set(&self, query_set: Option<wgpu::QuerySet>) {
+        if let Ok(mut slot) = self.internal.timestamp_queries.lock() {
+            *slot = query_set;
+        }
+    }
+
+    /// Returns the timestamp query set, if a profiler registered one.
+    pub(crate) fn timestamp_query_set(&self) -> Option<wgpu::QuerySet> {
+        self.internal
+            .timestamp_queries
+            .lock()
+            .ok()
+            .and_then(|slot| slot.clone())
+    }
+
     /// Polls the underlying wgpu::Device in a non-blocking manner.
     /// This is essential to process pending `map_async` callbacks from the GPU,
     /// allowing systems like the GpuProfiler to receive data.
@@ -426,6 +475,112 @@ impl WgpuDevice {
         }
     }
 
+    /// Parks a destroyed resource until the GPU has finished the frame that
+    /// retired it. `bytes` stays counted as allocated VRAM until then.
+    fn retire(&self, bytes: u64, resource: RetiredResource) -> Result<(), ResourceError> {
+        let mut retired =
+            self.internal.retired.lock().map_err(|e| {
+                ResourceError::BackendError(format!("Mutex poisoned (retired): {e}"))
+            })?;
+        // Read under the lock so fences reach the queue in order.
+        let fence = self.internal.frame_fence.load(Ordering::Acquire);
+        retired.retire(fence, bytes, resource);
+        Ok(())
+    }
+
+    /// Closes the current frame for deferred destruction.
+    ///
+    /// Registers a fence that signals once everything submitted so far has
+    /// executed, then releases retired resources whose fence has already
+    /// signaled, within the per-frame budget. Call once per frame, after the
+    /// frame's last submission.
+    pub fn end_frame(&self) -> DeferredDestructionStats {
+        let fence = self.internal.frame_fence.fetch_add(1, Ordering::AcqRel);
+        if let Ok(context) = self.internal.context.lock() {
+            let completed = Arc::clone(&self.internal.completed_fence);
+            context.queue.on_submitted_work_done(move || {
+                completed.fetch_max(fence, Ordering::AcqRel);
+            });
+        }
+
+        let completed = self.internal.completed_fence.load(Ordering::Acquire);
+        let released = match self.internal.retired.lock() {
+            Ok(mut retired) => retired.collect(completed),
+            Err(e) => {
+                log::error!("WgpuDevice: deferred-destruction queue poisoned: {e}");
+                return DeferredDestructionStats::default();
+            }
+        };
+        self.record_release(released)
+    }
+
+    /// Releases every retired resource at once, ignoring fences and the
+    /// budget. Only call once the device is idle, e.g. on shutdown after
+    /// [`Self::poll_device_blocking`].
+    pub fn flush_deferred_destructions(&self) -> DeferredDestructionStats {
+        let released = match self.internal.retired.lock() {
+            Ok(mut retired) => retired.drain_all(),
+            Err(e) => {
+                log::error!("WgpuDevice: deferred-destruction queue poisoned: {e}");
+                return DeferredDestructionStats::default();
+            }
+        };
+        self.record_release(released)
+    }
+
+    /// Sets how many retired resources [`Self::end_frame`] releases per frame.
+    ///
+    /// A ready backlog larger than the budget still drains at a fixed share
+    /// per frame, so a low budget cannot leak.
+    pub fn set_destruction_budget(&self, per_frame: usize) {
+        if let Ok(mut retired) = self.internal.retired.lock() {
+            retired.set_budget(per_frame);
+        }
+    }
+
+    /// Returns the state of the deferred-destruction queue and what the last
+    /// [`Self::end_frame`] released.
+    pub fn deferred_destruction_stats(&self) -> DeferredDestructionStats {
+        let (released, released_bytes) = self
+            .internal
+            .last_release
+            .lock()
+            .map(|last| *last)
+            .unwrap_or_default();
+        let (pending, pending_bytes) = self
+            .internal
+            .retired
+            .lock()
+            .map(|retired| (retired.len(), retired.pending_bytes()))
+            .unwrap_or_default();
+        DeferredDestructionStats {
+            pending,
+            pending_bytes,
+            released,
+            released_bytes,
+        }
+    }
+
+    /// Drops released resources outside the queue lock and settles VRAM
+    /// accounting for them.
+    fn record_release(
+        &self,
+        (resources, bytes): (Vec<RetiredResource>, u64),
+    ) -> DeferredDestructionStats {
+        let released = resources.len();
+        drop(resources);
+        self.internal
+            .vram_allocated_bytes
+            .fetch_sub(bytes as usize, Ordering::Relaxed);
+        if let Ok(mut last) = self.internal.last_release.lock() {
+            *last = (released, bytes);
+        }
+        if released > 0 {
+            log::trace!("WgpuDevice: released {released} retired resources ({bytes} bytes)");
+        }
+        self.deferred_destruction_stats()
+    }
+
     /// Creates a texture view for a raw wgpu::Texture (e.g., from the swap chain)
     /// and registers it with the device, returning an abstract ID.
     pub(crate) fn register_texture_view(
@@ -1025,11 +1180,10 @@ impl GraphicsDevice for WgpuDevice {
     fn destroy_buffer(&self, id: api_buf::BufferId) -> Result<(), ResourceError> {
         let mut buffers = self.internal.buffers.lock().unwrap();
 
-        // Remove the buffer from the map and track VRAM usage
+        // Unregister the buffer; its memory is released once the GPU is done with it
         if let Some(entry) = buffers.remove(&id) {
-            self.internal
-                .vram_allocated_bytes
-                .fetch_sub(entry.size as usize, Ordering::Relaxed);
+            drop(buffers);
+            self.retire(entry.size, RetiredResource::Buffer(entry.wgpu_buffer))?;
             log::debug!("WgpuDevice: Destroyed buffer with ID: {id:?}");
             Ok(())
         } else {
@@ -1141,6 +1295,57 @@ impl GraphicsDevice for WgpuDevice {
         })
     }
 
+    fn read_buffer_async(
+        &self,
+        id: api_buf::BufferId,
+        offset: u64,
+        size: u64,
+    ) -> Box<dyn Future<Output = Result<Vec<u8>, ResourceError>> + Send + 'static> {
+        let Some(buffer) = self.get_wgpu_buffer(id) else {
+            return Box::new(async { Err(ResourceError::NotFound) });
+        };
+        if offset + size > buffer.size() {
+            return Box::new(async { Err(ResourceError::OutOfBounds) });
+        }
+
+        let shared_state = Arc::new(MapAsyncFutureState {
+            result: Mutex::new(None),
+            waker: Mutex::new(None),
+        });
+        let state = Arc::clone(&shared_state);
+        let mapped_buffer = Arc::clone(&buffer);
+        buffer
+            .slice(offset..offset + size)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let result = match result {
+                    Ok(()) => {
+                        let bytes = mapped_buffer
+                            .slice(offset..offset + size)
+                            .get_mapped_range()
+                            .to_vec();
+                        mapped_buffer.unmap();
+                        Ok(bytes)
+                    }
+                    Err(e) => {
+                        log::error!("Failed to map buffer {id:?} for reading: {e:?}");
+                        Err(ResourceError::BackendError(format!(
+                            "WGPU map_async failed: {e:?}"
+                        )))
+                    }
+                };
+                if let Ok(mut slot) = state.result.lock() {
+                    *slot = Some(result);
+                }
+                if let Some(waker) = state.waker.lock().ok().and_then(|mut w| w.take()) {
+                    waker.wake();
+                }
+            });
+
+        Box::new(MapAsyncOperationFuture {
+            state: shared_state,
+        })
+    }
+
     fn create_texture(
         &self,
         descriptor: &api_tex::TextureDescriptor,
@@ -1202,11 +1407,10 @@ impl GraphicsDevice for WgpuDevice {
     fn destroy_texture(&self, id: api_tex::TextureId) -> Result<(), ResourceError> {
         let mut textures = self.internal.textures.lock().unwrap();
 
-        // Remove the texture from the map and track VRAM usage
+        // Unregister the texture; its memory is released once the GPU is done with it
         if let Some(entry) = textures.remove(&id) {
-            self.internal
-                .vram_allocated_bytes
-                .fetch_sub(entry.size as usize, Ordering::Relaxed);
+            drop(textures);
+            self.retire(entry.size, RetiredResource::Texture(entry.wgpu_texture))?;
             log::debug!("WgpuDevice: Destroyed texture with ID: {id:?}");
             Ok(())
         } else {
@@ -1221,6 +1425,18 @@ impl GraphicsDevice for WgpuDevice {
         bytes_per_row: Option<u32>,
         offset: dimension::Origin3D,
         size: dimension::Extent3D,
+    ) -> Result<(), ResourceError> {
+        self.write_texture_mip(texture_id, 0, data, bytes_per_row, offset, size)
+    }
+
+    fn write_texture_mip(
+        &self,
+        texture_id: api_tex::TextureId,
+        mip_level: u32,
+        data: &[u8],
+        bytes_per_row: Option<u32>,
+        offset: dimension::Origin3D,
+        size: dimension::Extent3D,
     ) -> Result<(), ResourceError> {
         let textures = self.internal.textures.lock().unwrap();
         let entry = textures.get(&texture_id).ok_or(ResourceError::NotFound)?;
@@ -1229,7 +1445,7 @@ impl GraphicsDevice for WgpuDevice {
         context.queue.write_texture(
             wgpu::TexelCopyTextureInfo {
                 texture: &entry.wgpu_texture,
-                mip_level: 0, // Assuming base mip for now
+                mip_level,
                 origin: offset.into_wgpu(),
                 aspect: wgpu::TextureAspect::All, // Assuming all aspects for now
             },
@@ -1242,9 +1458,10 @@ impl GraphicsDevice for WgpuDevice {
             size.into_wgpu(),
         );
         log::debug!(
-            "WgpuDevice: Wrote {} bytes to texture ID: {:?} at offset {:?}",
+            "WgpuDevice: Wrote {} bytes to texture ID: {:?} mip {} at offset {:?}",
             data.len(),
             texture_id,
+            mip_level,
             offset
         );
         Ok(())
@@ -1302,8 +1519,10 @@ impl GraphicsDevice for WgpuDevice {
     fn destroy_texture_view(&self, id: api_tex::TextureViewId) -> Result<(), ResourceError> {
         let mut texture_views = self.internal.texture_views.lock().unwrap();
 
-        // Remove the texture view from the map
-        if texture_views.remove(&id).is_some() {
+        // Unregister the texture view and defer its release
+        if let Some(entry) = texture_views.remove(&id) {
+            drop(texture_views);
+            self.retire(0, RetiredResource::TextureView(entry.wgpu_view))?;
             log::debug!("WgpuDevice: Destroyed texture view with ID: {id:?}");
             Ok(())
         } else {
@@ -1358,8 +1577,10 @@ impl GraphicsDevice for WgpuDevice {
     fn destroy_sampler(&self, id: api_tex::SamplerId) -> Result<(), ResourceError> {
         let mut samplers = self.internal.samplers.lock().unwrap();
 
-        // Remove the sampler from the map
-        if samplers.remove(&id).is_some() {
+        // Unregister the sampler and defer its release
+        if let Some(entry) = samplers.remove(&id) {
+            drop(samplers);
+            self.retire(0, RetiredResource::Sampler(entry.wgpu_sampler))?;
             log::debug!("WgpuDevice: Destroyed sampler with ID: {id:?}");
             Ok(())
         } else {
@@ -1400,6 +1621,8 @@ impl GraphicsDevice for WgpuDevice {
                 wgpu::DeviceType::Cpu => RendererDeviceType::Cpu,
                 _ => RendererDeviceType::Unknown,
             },
+            driver: context_guard.adapter_driver.clone(),
+            driver_info: context_guard.adapter_driver_info.clone(),
         }
     }
 
@@ -1422,6 +1645,11 @@ impl GraphicsDevice for WgpuDevice {
             "polygon_mode_point" => context_guard
                 .active_device_features
                 .contains(wgpu::Features::POLYGON_MODE_POINT),
+            "compute_shaders" => context_guard
+                .adapter
+                .get_downlevel_capabilities()
+                .flags
+                .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS),
             _ => {
                 log::warn!(
                     "WgpuDevice: Unsupported feature_name query in supports_feature: {feature_name}"
@@ -1431,6 +1659,14 @@ impl GraphicsDevice for WgpuDevice {
         }
     }
 
+    fn max_texture_dimension_2d(&self) -> u32 {
+        let Ok(context_guard) = self.internal.context.lock() else {
+            log::error!("WgpuDevice: Mutex poisoned (context) on max_texture_dimension_2d");
+            return 0;
+        };
+        context_guard.device_limits.max_texture_dimension_2d
+    }
+
     fn create_command_encoder(&self, label: Option<&str>) -> Box<dyn CommandEncoder> {
         let context_guard = self.internal.context.lock().unwrap();
         let descriptor = wgpu::CommandEncoderDescriptor { label };
@@ -1665,7 +1901,9 @@ impl GraphicsDevice for WgpuDevice {
             ResourceError::BackendError(format!("Mutex poisoned (bind_groups): {e}"))
         })?;
 
-        if bind_groups.remove(&id).is_some() {
+        if let Some(entry) = bind_groups.remove(&id) {
+            drop(bind_groups);
+            self.retire(0, RetiredResource::BindGroup(entry.wgpu_bind_group))?;
             log::debug!("WgpuDevice: Destroyed bind group with ID: {id:?}");
             Ok(())
         } else {
@@ -1745,9 +1983,12 @@ mod tests {
             cpu_render_submission_time_ms: 0.2,
             gpu_main_pass_time_ms: 8.0,
             gpu_frame_total_time_ms: 10.5,
+            gpu_depth_prepass_time_ms: 0.0,
             draw_calls: 100,
             triangles_rendered: 5000,
             vram_usage_estimate_mb: 256.0,
+            pending_gpu_destructions: 0,
+            pending_gpu_destruction_mb: 0.0,
         };
 
         monitor.update_from_frame_stats(&stats);
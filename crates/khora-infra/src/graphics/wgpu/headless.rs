@@ -0,0 +1,233 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Windowless WGPU rendering into an offscreen target with CPU readback.
+//!
+//! [`HeadlessWgpu`] owns a surface-less [`WgpuGraphicsContext`], a
+//! [`WgpuDevice`] over it, and a fixed-size color + depth target. Lanes
+//! render into [`HeadlessWgpu::color_target`] exactly as they would into a
+//! swapchain frame; [`HeadlessWgpu::read_color`] then copies the pixels back
+//! for comparison (golden-image tests) or encoding (thumbnails).
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{ColorTarget, DepthTarget};
+use khora_core::renderer::api::core::BackendSelectionConfig;
+use khora_core::renderer::api::resource::TextureViewId;
+use khora_core::renderer::traits::GraphicsBackendSelector;
+use khora_core::renderer::{GraphicsDevice, RenderError};
+use winit::dpi::PhysicalSize;
+
+use super::backend::WgpuBackendSelector;
+use super::context::WgpuGraphicsContext;
+use super::device::WgpuDevice;
+
+/// Color format of the offscreen target. sRGB-encoded RGBA8 so the readback
+/// bytes can be written to a PNG as-is.
+const HEADLESS_COLOR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// A windowless WGPU device rendering into a fixed-size offscreen target.
+#[derive(Debug)]
+pub struct HeadlessWgpu {
+    context: Arc<Mutex<WgpuGraphicsContext>>,
+    device: Arc<WgpuDevice>,
+    color_texture: wgpu::Texture,
+    // Kept alive for as long as its registered view is in use.
+    _depth_texture: wgpu::Texture,
+    color_view: TextureViewId,
+    depth_view: TextureViewId,
+    width: u32,
+    height: u32,
+}
+
+impl HeadlessWgpu {
+    /// Selects an adapter and creates a headless device with a
+    /// `width` x `height` color (`Rgba8UnormSrgb`) and depth (`Depth32Float`) target.
+    ///
+    /// Fails with [`RenderError::InitializationFailed`] when no adapter is
+    /// available (e.g. CI machines without a GPU or software rasterizer).
+    pub fn new(width: u32, height: u32) -> Result<Self, RenderError> {
+        pollster::block_on(Self::initialize(width.max(1), height.max(1)))
+    }
+
+    async fn initialize(width: u32, height: u32) -> Result<Self, RenderError> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let selection = WgpuBackendSelector::new(instance)
+            .select_backend(&BackendSelectionConfig::default())
+            .await
+            .map_err(|e| RenderError::InitializationFailed(e.to_string()))?;
+
+        let context = WgpuGraphicsContext::new_headless(
+            selection.adapter,
+            PhysicalSize::new(width, height),
+            HEADLESS_COLOR_FORMAT,
+        )
+        .await
+        .map_err(|e| RenderError::InitializationFailed(e.to_string()))?;
+
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let color_texture = context.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("headless_color"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HEADLESS_COLOR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let depth_texture = context.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("headless_depth"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Depth32Float,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+
+        let context = Arc::new(Mutex::new(context));
+        let device = Arc::new(WgpuDevice::new(context.clone()));
+        let color_view = device
+            .register_texture_view(&color_texture, Some("headless_color_view"))
+            .map_err(|e| RenderError::Internal(format!("register headless color view: {e}")))?;
+        let depth_view = device
+            .register_texture_view(&depth_texture, Some("headless_depth_view"))
+            .map_err(|e| RenderError::Internal(format!("register headless depth view: {e}")))?;
+
+        log::info!("Headless WGPU target created: {width}x{height} ({HEADLESS_COLOR_FORMAT:?})");
+
+        Ok(Self {
+            context,
+            device,
+            color_texture,
+            _depth_texture: depth_texture,
+            color_view,
+            depth_view,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the device as the engine-facing [`GraphicsDevice`] trait object.
+    pub fn graphics_device(&self) -> Arc<dyn GraphicsDevice> {
+        self.device.clone()
+    }
+
+    /// Returns the offscreen color target, ready to insert into a `LaneContext`.
+    pub fn color_target(&self) -> ColorTarget {
+        ColorTarget(self.color_view)
+    }
+
+    /// Returns the offscreen depth target, ready to insert into a `LaneContext`.
+    pub fn depth_target(&self) -> DepthTarget {
+        DepthTarget(self.depth_view)
+    }
+
+    /// Returns the `(width, height)` of the offscreen target.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the name of the adapter the device was created on.
+    pub fn adapter_name(&self) -> String {
+        self.context
+            .lock()
+            .map(|gc| gc.adapter_name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Waits for all submitted work, then copies the color target back to the CPU.
+    ///
+    /// Returns tightly packed sRGB RGBA8 rows, top row first
+    /// (`width * height * 4` bytes).
+    pub fn read_color(&self) -> Result<Vec<u8>, RenderError> {
+        let gc = self
+            .context
+            .lock()
+            .map_err(|_| RenderError::Internal("Context lock poisoned".into()))?;
+
+        let unpadded_row = self.width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_row = unpadded_row.div_ceil(align) * align;
+
+        let staging = gc.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("headless_readback"),
+            size: padded_row as u64 * self.height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = gc
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("headless_readback_encoder"),
+            });
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.color_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &staging,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row),
+                    rows_per_image: Some(self.height),
+                },
+            },
+            wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+        );
+        gc.queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = staging.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |res| {
+            let _ = tx.send(res);
+        });
+        gc.device
+            .poll(wgpu::PollType::Wait {
+                submission_index: None,
+                timeout: None,
+            })
+            .map_err(|e| RenderError::Internal(format!("headless readback poll: {e:?}")))?;
+        rx.recv()
+            .map_err(|_| RenderError::Internal("headless readback callback dropped".into()))?
+            .map_err(|e| RenderError::Internal(format!("headless readback map: {e:?}")))?;
+
+        let mut pixels = Vec::with_capacity((unpadded_row * self.height) as usize);
+        {
+            let data = slice.get_mapped_range();
+            for row in data.chunks(padded_row as usize) {
+                pixels.extend_from_slice(&row[..unpadded_row as usize]);
+            }
+        }
+        staging.unmap();
+        drop(gc);
+        // The GPU is idle here, so this is a frame boundary for deferred destruction.
+        self.device.end_frame();
+        Ok(pixels)
+    }
+}
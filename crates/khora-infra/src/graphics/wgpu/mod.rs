@@ -18,8 +18,12 @@ mod backend;
 mod command;
 pub(crate) mod context;
 mod conversions;
+mod deferred;
 mod device;
+mod headless;
 mod profiler;
 mod system;
 
+pub use self::deferred::{DeferredDestructionStats, DEFAULT_RELEASE_BUDGET};
+pub use self::headless::HeadlessWgpu;
 pub use self::system::WgpuRenderSystem;
@@ -14,6 +14,7 @@
 
 use crate::graphics::wgpu::command::WgpuCommandEncoder;
 use crate::graphics::wgpu::device::WgpuDevice;
+use khora_core::renderer::api::core::GpuHook;
 use khora_core::renderer::traits::{CommandEncoder, GpuProfiler};
 use std::any::Any;
 use std::sync::{
@@ -21,14 +22,22 @@ use std::sync::{
     Arc,
 };
 
+/// Number of timestamp queries: one per [`GpuHook`].
+const QUERY_COUNT: usize = GpuHook::ALL.len();
+
+/// Size of the resolved timestamps, in bytes.
+const RESOLVE_BUFFER_SIZE: u64 = (QUERY_COUNT * std::mem::size_of::<u64>()) as u64;
+
 /// WgpuTimestampProfiler encapsulates GPU timestamp query logic.
 /// Frame-lag model: timestamps written during frame N are read at the start of frame N+2.
 /// Layout (two-pass scheme):
 ///   Pass A (frame start / main pass begin):   begin-> index 0 (frame_start), end-> index 1 (main_pass_begin)
 ///   Pass B (main pass end / frame end):       begin-> index 2 (main_pass_end),  end-> index 3 (frame_end)
+///   Depth prepass brackets (hooked compute passes): index 4 (begin), index 5 (end)
 /// Durations derived:
 ///   main_pass = index2 - index1
 ///   frame_total = index3 - index0
+///   depth_prepass = index5 - index4, when the prepass ran
 #[derive(Debug)]
 pub struct WgpuTimestampProfiler {
     query_set: wgpu::QuerySet,
@@ -39,10 +48,12 @@ pub struct WgpuTimestampProfiler {
     period_ns: f32,
     raw_main_pass_ms: f32,
     raw_frame_total_ms: f32,
+    raw_depth_prepass_ms: f32,
     smooth_main_pass_ms: f32,
     smooth_frame_total_ms: f32,
+    smooth_depth_prepass_ms: f32,
     ema_alpha: f32,
-    last_raw: Option<[u64; 4]>,
+    last_raw: Option<[u64; QUERY_COUNT]>,
 }
 
 impl WgpuTimestampProfiler {
@@ -57,10 +68,9 @@ impl WgpuTimestampProfiler {
         let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
             label: Some("Khora GPU Timestamp QuerySet"),
             ty: wgpu::QueryType::Timestamp,
-            count: 4,
+            count: QUERY_COUNT as u32,
         });
 
-        const RESOLVE_BUFFER_SIZE: u64 = 32; // 4 * u64 timestamps
         let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Khora GPU Timestamp Resolve Buffer"),
             size: RESOLVE_BUFFER_SIZE,
@@ -92,8 +102,10 @@ impl WgpuTimestampProfiler {
             period_ns: 1.0, // Default value, must be updated via `set_timestamp_period`
             raw_main_pass_ms: 0.0,
             raw_frame_total_ms: 0.0,
+            raw_depth_prepass_ms: 0.0,
             smooth_main_pass_ms: 0.0,
             smooth_frame_total_ms: 0.0,
+            smooth_depth_prepass_ms: 0.0,
             ema_alpha: 0.2,
             last_raw: None,
         })
@@ -106,6 +118,17 @@ impl WgpuTimestampProfiler {
     }
 
     // These crate-public methods are implementation details for the WGPU backend.
+
+    /// The query set holding one timestamp per [`GpuHook`].
+    pub(crate) fn query_set(&self) -> &wgpu::QuerySet {
+        &self.query_set
+    }
+
+    /// The query written for `hook`: queries are laid out in hook order.
+    pub(crate) fn query_index(hook: GpuHook) -> u32 {
+        hook as u32
+    }
+
     pub(crate) fn compute_pass_a_timestamp_writes(&self) -> wgpu::ComputePassTimestampWrites<'_> {
         wgpu::ComputePassTimestampWrites {
             query_set: &self.query_set,
@@ -151,13 +174,15 @@ impl GpuProfiler for WgpuTimestampProfiler {
                 let slice = self.staging_buffers[i].slice(..);
                 // The buffer is mapped now, so we can get its contents.
                 let data = slice.get_mapped_range();
-                let timestamps: [u64; 4] = bytemuck::pod_read_unaligned(&data[..32]);
+                let timestamps: [u64; QUERY_COUNT] =
+                    bytemuck::pod_read_unaligned(&data[..RESOLVE_BUFFER_SIZE as usize]);
                 drop(data); // Data guard is dropped, which is a prerequisite for unmapping.
                 self.staging_buffers[i].unmap();
                 self.staging_pending[i] = false;
 
                 self.last_raw = Some(timestamps);
-                let [frame_start, main_begin, main_end, frame_end] = timestamps;
+                let [frame_start, main_begin, main_end, frame_end, prepass_begin, prepass_end] =
+                    timestamps;
 
                 if main_end > main_begin && frame_end > frame_start {
                     self.raw_main_pass_ms =
@@ -177,6 +202,21 @@ impl GpuProfiler for WgpuTimestampProfiler {
                         a * self.raw_frame_total_ms + (1.0 - a) * self.smooth_frame_total_ms
                     };
                 }
+
+                // An empty or backwards span means the prepass did not run.
+                if prepass_begin != 0 && prepass_end > prepass_begin {
+                    self.raw_depth_prepass_ms =
+                        ((prepass_end - prepass_begin) as f32 * self.period_ns) / 1_000_000.0;
+                    let a = self.ema_alpha;
+                    self.smooth_depth_prepass_ms = if self.smooth_depth_prepass_ms == 0.0 {
+                        self.raw_depth_prepass_ms
+                    } else {
+                        a * self.raw_depth_prepass_ms + (1.0 - a) * self.smooth_depth_prepass_ms
+                    };
+                } else {
+                    self.raw_depth_prepass_ms = 0.0;
+                    self.smooth_depth_prepass_ms = 0.0;
+                }
             }
         }
     }
@@ -188,7 +228,12 @@ impl GpuProfiler for WgpuTimestampProfiler {
             .expect("Encoder must be a WgpuCommandEncoder for profiling");
 
         if let Some(wgpu_encoder) = concrete_encoder.wgpu_encoder_mut() {
-            wgpu_encoder.resolve_query_set(&self.query_set, 0..4, &self.resolve_buffer, 0);
+            wgpu_encoder.resolve_query_set(
+                &self.query_set,
+                0..QUERY_COUNT as u32,
+                &self.resolve_buffer,
+                0,
+            );
         }
     }
 
@@ -216,7 +261,7 @@ impl GpuProfiler for WgpuTimestampProfiler {
                 0,
                 &self.staging_buffers[staging_idx],
                 0,
-                32,
+                RESOLVE_BUFFER_SIZE,
             );
         }
     }
@@ -251,6 +296,10 @@ impl GpuProfiler for WgpuTimestampProfiler {
         self.smooth_frame_total_ms
     }
 
+    fn last_depth_prepass_ms(&self) -> f32 {
+        self.smooth_depth_prepass_ms
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -317,5 +366,6 @@ mod tests {
         // 3. Assert initial state using the public GpuProfiler trait methods.
         assert_eq!(profiler.last_main_pass_ms(), 0.0);
         assert_eq!(profiler.last_frame_total_ms(), 0.0);
+        assert_eq!(profiler.last_depth_prepass_ms(), 0.0);
     }
 }
@@ -18,16 +18,18 @@ use crate::telemetry::gpu_monitor::GpuMonitor;
 
 use super::backend::WgpuBackendSelector;
 use super::context::WgpuGraphicsContext;
+use super::conversions::IntoWgpu;
+use super::deferred::DeferredDestructionStats;
 use super::device::WgpuDevice;
 use super::profiler::WgpuTimestampProfiler;
-use khora_core::math::LinearRgba;
+use khora_core::math::{Extent2D, LinearRgba};
 use khora_core::platform::window::{KhoraWindow, KhoraWindowHandle};
 use khora_core::renderer::api::command::{
     BindGroupId, BindGroupLayoutId, LoadOp, Operations, RenderPassColorAttachment,
     RenderPassDescriptor, StoreOp,
 };
 use khora_core::renderer::api::core::{
-    BackendSelectionConfig, GraphicsAdapterInfo, RenderSettings, RenderStats,
+    BackendSelectionConfig, DepthMode, GraphicsAdapterInfo, RenderSettings, RenderStats,
 };
 use khora_core::renderer::api::resource::{
     BufferId, ImageAspect, TextureDescriptor, TextureDimension, TextureId, TextureUsage,
@@ -77,6 +79,10 @@ pub struct WgpuRenderSystem {
     // --- Frame lifecycle ---
     /// Surface texture acquired by `begin_frame()`, consumed by `end_frame()`.
     active_frame_texture: Option<wgpu::SurfaceTexture>,
+    /// Set by `suspend()`; `begin_frame()` refuses to acquire while true.
+    suspended: bool,
+    /// Depth convention for every depth attachment and the grid pipeline.
+    depth_mode: DepthMode,
 
     // --- Resize Heuristics State ---
     last_resize_event: Option<Instant>,
@@ -106,6 +112,7 @@ pub struct WgpuRenderSystem {
     grid_camera_bind_group_layout: Option<wgpu::BindGroupLayout>,
     grid_camera_bind_group: Option<wgpu::BindGroup>,
     grid_camera_buffer: Option<wgpu::Buffer>,
+    grid_visible: bool,
 
     // --- Gizmo Pipeline ---
     gizmo_pipeline: Option<wgpu::RenderPipeline>,
@@ -131,6 +138,8 @@ impl fmt::Debug for WgpuRenderSystem {
                 &self.gpu_profiler.as_ref().map(|_| "GpuProfiler(...)"),
             )
             .field("current_frame_view_id", &self.current_frame_view_id)
+            .field("suspended", &self.suspended)
+            .field("depth_mode", &self.depth_mode)
             .field(
                 "camera_uniform_buffer",
                 &self.camera_uniform_buffer.as_ref().map(|_| "Buffer(...)"),
@@ -178,6 +187,8 @@ impl WgpuRenderSystem {
             depth_texture: None,
             depth_texture_view: None,
             active_frame_texture: None,
+            suspended: false,
+            depth_mode: DepthMode::default(),
             last_resize_event: None,
             pending_resize: false,
             last_surface_config: None,
@@ -197,6 +208,7 @@ impl WgpuRenderSystem {
             grid_camera_bind_group_layout: None,
             grid_camera_bind_group: None,
             grid_camera_buffer: None,
+            grid_visible: true,
             gizmo_pipeline: None,
             gizmo_camera_bind_group_layout: None,
             gizmo_storage_bind_group_layout: None,
@@ -258,6 +270,8 @@ impl WgpuRenderSystem {
                 if let Some(mut profiler) = WgpuTimestampProfiler::new(&gc_guard.device) {
                     let period = gc_guard.queue.get_timestamp_period();
                     profiler.set_timestamp_period(period);
+                    // Lets lanes record hooked spans such as the depth prepass.
+                    device_arc.set_timestamp_query_set(Some(profiler.query_set().clone()));
                     self.gpu_profiler = Some(Box::new(profiler));
                 }
             } else {
@@ -606,7 +620,7 @@ impl WgpuRenderSystem {
                 depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
                     view: depth_view,
                     depth_ops: Some(wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(1.0),
+                        load: wgpu::LoadOp::Clear(self.depth_mode.clear_depth()),
                         store: wgpu::StoreOp::Store,
                     }),
                     stencil_ops: None,
@@ -622,6 +636,11 @@ impl WgpuRenderSystem {
         Ok(())
     }
 
+    /// Shows or hides the infinite ground grid drawn by [`Self::render_viewport`].
+    pub fn set_grid_visible(&mut self, visible: bool) {
+        self.grid_visible = visible;
+    }
+
     /// Initialises the grid render pipeline.
     ///
     /// Must be called after `create_viewport_target` so the surface
@@ -708,7 +727,7 @@ impl WgpuRenderSystem {
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: wgpu::TextureFormat::Depth32Float,
                 depth_write_enabled: Some(true),
-                depth_compare: Some(wgpu::CompareFunction::LessEqual),
+                depth_compare: Some(self.depth_mode.compare_or_equal().into_wgpu()),
                 stencil: Default::default(),
                 bias: Default::default(),
             }),
@@ -731,6 +750,33 @@ impl WgpuRenderSystem {
         (self.viewport_width, self.viewport_height)
     }
 
+    /// Returns the state of the deferred-destruction queue, or the default
+    /// (empty) stats before initialization.
+    pub fn deferred_destruction_stats(&self) -> DeferredDestructionStats {
+        self.wgpu_device
+            .as_ref()
+            .map(|device| device.deferred_destruction_stats())
+            .unwrap_or_default()
+    }
+
+    /// Sets how many destroyed GPU resources are released per frame once the
+    /// GPU is done with them. See
+    /// [`DEFAULT_RELEASE_BUDGET`](super::DEFAULT_RELEASE_BUDGET).
+    pub fn set_destruction_budget(&self, per_frame: usize) {
+        if let Some(device) = self.wgpu_device.as_ref() {
+            device.set_destruction_budget(per_frame);
+        }
+    }
+
+    /// Closes the frame for deferred destruction and records the queue state
+    /// in the frame statistics.
+    fn collect_retired_resources(&mut self, device: &WgpuDevice) {
+        let stats = device.end_frame();
+        self.last_frame_stats.pending_gpu_destructions = stats.pending as u32;
+        self.last_frame_stats.pending_gpu_destruction_mb =
+            stats.pending_bytes as f32 / (1024.0 * 1024.0);
+    }
+
     /// Renders the viewport: clear + grid + (future) 3D content.
     ///
     /// `view_info` supplies the camera matrices for grid rendering.
@@ -757,12 +803,13 @@ impl WgpuRenderSystem {
 
         // Upload camera uniforms for the grid (VP matrix + camera pos).
         if let Some(buf) = &self.grid_camera_buffer {
-            let vp = view_info.view_projection_matrix();
+            let vp = self.depth_mode.apply(view_info.view_projection_matrix());
             let cam_pos = view_info.camera_position;
-            // Layout: mat4x4<f32>(64 bytes) + vec4<f32>(16 bytes) = 80 bytes
+            // Layout: mat4x4<f32>(64 bytes) + vec4<f32>(16 bytes) = 80 bytes.
+            // `w` carries the near-plane NDC depth for the grid's unprojection.
             let mut data = [0u8; 80];
             data[..64].copy_from_slice(bytemuck::bytes_of(&vp));
-            let pos_arr = [cam_pos.x, cam_pos.y, cam_pos.z, 1.0f32];
+            let pos_arr = [cam_pos.x, cam_pos.y, cam_pos.z, self.depth_mode.near_ndc()];
             data[64..80].copy_from_slice(bytemuck::cast_slice(&pos_arr));
             gc.queue.write_buffer(buf, 0, &data);
         }
@@ -793,7 +840,7 @@ impl WgpuRenderSystem {
                 depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
                     view: depth_view,
                     depth_ops: Some(wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(1.0),
+                        load: wgpu::LoadOp::Clear(self.depth_mode.clear_depth()),
                         store: wgpu::StoreOp::Store,
                     }),
                     stencil_ops: None,
@@ -804,8 +851,11 @@ impl WgpuRenderSystem {
             });
 
             // Draw the infinite grid.
-            if let (Some(pipeline), Some(bg)) = (&self.grid_pipeline, &self.grid_camera_bind_group)
-            {
+            if let (true, Some(pipeline), Some(bg)) = (
+                self.grid_visible,
+                &self.grid_pipeline,
+                &self.grid_camera_bind_group,
+            ) {
                 pass.set_pipeline(pipeline);
                 pass.set_bind_group(0, bg, &[]);
                 pass.draw(0..6, 0..1);
@@ -1129,6 +1179,7 @@ impl RenderSystem for WgpuRenderSystem {
         &mut self,
         window: &dyn KhoraWindow,
     ) -> Result<Vec<Arc<dyn ResourceMonitor>>, RenderError> {
+        khora_core::assert_main_thread!("WgpuRenderSystem::init");
         let (width, height) = window.inner_size();
         let window_size = PhysicalSize::new(width, height);
         let window_handle_arc = window.clone_handle_arc();
@@ -1281,7 +1332,12 @@ impl RenderSystem for WgpuRenderSystem {
         device.wait_for_last_submission();
         let output_surface_texture = loop {
             let mut gc_guard = gc.lock().unwrap();
-            match gc_guard.get_current_texture() {
+            let Some(current) = gc_guard.get_current_texture() else {
+                return Err(RenderError::SurfaceAcquisitionFailed(
+                    "headless context has no surface".to_string(),
+                ));
+            };
+            match current {
                 wgpu::CurrentSurfaceTexture::Success(texture) => break texture,
                 wgpu::CurrentSurfaceTexture::Suboptimal(texture) => {
                     log::debug!(
@@ -1385,7 +1441,7 @@ impl RenderSystem for WgpuRenderSystem {
                 RenderPassDepthStencilAttachment {
                     view: depth_view,
                     depth_ops: Some(Operations {
-                        load: LoadOp::Clear(1.0), // Clear to far plane (1.0)
+                        load: LoadOp::Clear(self.depth_mode.clear_depth()), // Clear to far plane
                         store: StoreOp::Store,
                     }),
                     stencil_ops: None, // No stencil operations
@@ -1447,12 +1503,14 @@ impl RenderSystem for WgpuRenderSystem {
 
         // --- 8. Present the final image to the screen ---
         output_surface_texture.present();
+        self.collect_retired_resources(&device);
 
         // --- 9. Update final frame statistics ---
         self.frame_count += 1;
         if let Some(p) = self.gpu_profiler.as_ref() {
             self.last_frame_stats.gpu_main_pass_time_ms = p.last_main_pass_ms();
             self.last_frame_stats.gpu_frame_total_time_ms = p.last_frame_total_ms();
+            self.last_frame_stats.gpu_depth_prepass_time_ms = p.last_depth_prepass_ms();
         }
         let full_frame_ms = full_frame_timer.elapsed_ms().unwrap_or(0);
         self.last_frame_stats.frame_number = self.frame_count;
@@ -1468,6 +1526,9 @@ impl RenderSystem for WgpuRenderSystem {
     }
 
     fn begin_frame(&mut self) -> Result<FrameTargets, RenderError> {
+        if self.suspended {
+            return Err(RenderError::Suspended);
+        }
         let device = self
             .wgpu_device
             .clone()
@@ -1522,7 +1583,12 @@ impl RenderSystem for WgpuRenderSystem {
         // --- Acquire swapchain texture ---
         let output_surface_texture = loop {
             let mut gc_guard = gc.lock().unwrap();
-            match gc_guard.get_current_texture() {
+            let Some(current) = gc_guard.get_current_texture() else {
+                return Err(RenderError::SurfaceAcquisitionFailed(
+                    "headless context has no surface".to_string(),
+                ));
+            };
+            match current {
                 wgpu::CurrentSurfaceTexture::Success(texture)
                 | wgpu::CurrentSurfaceTexture::Suboptimal(texture) => break texture,
                 status @ (wgpu::CurrentSurfaceTexture::Lost
@@ -1552,25 +1618,58 @@ impl RenderSystem for WgpuRenderSystem {
         self.active_frame_texture = Some(output_surface_texture);
 
         // Compute targets for the engine: choose between swapchain and viewport.
-        let (color, depth) = if self.render_to_viewport {
+        let (color, depth, width, height) = if self.render_to_viewport {
             let c = self
                 .viewport_color_view_id
                 .ok_or_else(|| RenderError::Internal("viewport color view not created".into()))?;
-            (c, self.viewport_depth_view_id)
+            (
+                c,
+                self.viewport_depth_view_id,
+                self.viewport_width,
+                self.viewport_height,
+            )
         } else {
-            (target_view_id, self.depth_texture_view)
+            (
+                target_view_id,
+                self.depth_texture_view,
+                self.current_width,
+                self.current_height,
+            )
         };
 
-        Ok(FrameTargets { color, depth })
+        Ok(FrameTargets {
+            color,
+            depth,
+            size: Extent2D { width, height },
+        })
     }
 
     fn end_frame(&mut self) -> Result<RenderStats, RenderError> {
+        // The frame graph is submitted by now: resolve the timestamps its
+        // lanes wrote through hooked passes.
+        if let (Some(profiler), Some(device)) = (self.gpu_profiler.as_ref(), &self.wgpu_device) {
+            let mut encoder =
+                device.create_command_encoder(Some("Khora Timestamp Resolve Encoder"));
+            profiler.resolve_and_copy(encoder.as_mut());
+            profiler.copy_to_staging(encoder.as_mut(), self.frame_count);
+            device.submit_command_buffer(encoder.finish());
+        }
+
         if let Some(texture) = self.active_frame_texture.take() {
             texture.present();
         }
+        if let Some(device) = self.wgpu_device.clone() {
+            self.collect_retired_resources(&device);
+        }
 
+        if let Some(p) = self.gpu_profiler.as_mut() {
+            p.schedule_map_after_submit(self.frame_count);
+        }
         self.frame_count += 1;
         self.last_frame_stats.frame_number = self.frame_count;
+        if let Some(p) = self.gpu_profiler.as_ref() {
+            self.last_frame_stats.gpu_depth_prepass_time_ms = p.last_depth_prepass_ms();
+        }
 
         if let Some(monitor) = &self.gpu_monitor {
             monitor.update_from_frame_stats(&self.last_frame_stats);
@@ -1649,10 +1748,60 @@ impl RenderSystem for WgpuRenderSystem {
             .is_some_and(|d| d.supports_feature(feature_name))
     }
 
+    fn depth_mode(&self) -> DepthMode {
+        self.depth_mode
+    }
+
+    fn set_depth_mode(&mut self, mode: DepthMode) {
+        self.depth_mode = mode;
+    }
+
+    fn suspend(&mut self) {
+        if self.suspended {
+            return;
+        }
+        self.suspended = true;
+        if let Some(device) = self.wgpu_device.as_ref() {
+            device.wait_for_last_submission();
+        }
+        // A frame acquired but never presented would keep the swapchain
+        // image checked out for the whole time the window is hidden.
+        self.active_frame_texture = None;
+        if let Some(old_id) = self.current_frame_view_id.take() {
+            if let Some(device) = self.wgpu_device.as_ref() {
+                let _ = device.destroy_texture_view(old_id);
+            }
+        }
+        log::info!("WgpuRenderSystem: suspended.");
+    }
+
+    fn resume(&mut self) {
+        if !self.suspended {
+            return;
+        }
+        self.suspended = false;
+        // The surface may have been invalidated while minimized; configure it
+        // again at the last non-zero size before the next acquire.
+        if let Some(gc_arc_mutex) = &self.graphics_context_shared {
+            if let Ok(mut gc_guard) = gc_arc_mutex.lock() {
+                gc_guard.resize(self.current_width, self.current_height);
+                self.last_surface_config = Some(Instant::now());
+                self.pending_resize = false;
+                self.pending_resize_frames = 0;
+            }
+        }
+        log::info!(
+            "WgpuRenderSystem: resumed at {}x{}.",
+            self.current_width,
+            self.current_height
+        );
+    }
+
     fn shutdown(&mut self) {
         log::info!("WgpuRenderSystem shutting down...");
         if let Some(mut profiler) = self.gpu_profiler.take() {
             if let Some(device) = self.wgpu_device.as_ref() {
+                device.set_timestamp_query_set(None);
                 if let Some(wgpu_profiler) = profiler
                     .as_any_mut()
                     .downcast_mut::<WgpuTimestampProfiler>()
@@ -1666,6 +1815,10 @@ impl RenderSystem for WgpuRenderSystem {
                 let _ = device.destroy_texture_view(old_id);
             }
         }
+        if let Some(device) = self.wgpu_device.as_ref() {
+            device.poll_device_blocking();
+            device.flush_deferred_destructions();
+        }
         self.wgpu_device = None;
         self.graphics_context_shared = None;
         self.gpu_monitor = None;
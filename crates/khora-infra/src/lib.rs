@@ -18,19 +18,24 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "audio")]
 pub mod audio;
+pub mod executor;
 pub mod graphics;
+#[cfg(feature = "physics")]
 pub mod physics;
 pub mod platform;
 pub mod renderer;
 pub mod telemetry;
 pub mod ui;
 
-pub use graphics::wgpu::WgpuRenderSystem;
+pub use executor::{LocalExecutor, ThreadPoolExecutor};
+pub use graphics::wgpu::{DeferredDestructionStats, HeadlessWgpu, WgpuRenderSystem};
 pub use platform::window::{WinitWindow, WinitWindowBuilder};
 pub use renderer::StandardTextRenderer;
 pub use telemetry::{
-    gpu_monitor::GpuMonitor, memory_monitor::MemoryMonitor, vram_monitor::VramMonitor,
+    gpu_monitor::GpuMonitor, hardware_monitor::HardwareHealthMonitor,
+    memory_monitor::MemoryMonitor, vram_monitor::VramMonitor,
 };
 pub use ui::egui::{EguiEditorShell, EguiFrameRenderState, EguiOverlay, EguiUiBuilder};
 pub use ui::taffy::taffy_layout::TaffyLayoutSystem;
@@ -25,6 +25,7 @@ use khora_core::physics::{
 };
 use rapier3d::control::*;
 use rapier3d::prelude::*;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use conversions::*;
@@ -36,6 +37,7 @@ pub struct RapierPhysicsWorld {
     rigid_body_set: RigidBodySet,
     collider_set: ColliderSet,
     gravity: Vector,
+    gravity_overrides: HashMap<RigidBodyHandle, Vec3>,
     integration_parameters: IntegrationParameters,
     physics_pipeline: PhysicsPipeline,
     island_manager: IslandManager,
@@ -53,6 +55,7 @@ impl Default for RapierPhysicsWorld {
             rigid_body_set: RigidBodySet::new(),
             collider_set: ColliderSet::new(),
             gravity: Vector::new(0.0, -9.81, 0.0),
+            gravity_overrides: HashMap::new(),
             integration_parameters: IntegrationParameters::default(),
             physics_pipeline: PhysicsPipeline::new(),
             island_manager: IslandManager::new(),
@@ -73,6 +76,16 @@ impl PhysicsProvider for RapierPhysicsWorld {
             events: self.events.clone(),
         };
 
+        // Rapier only knows one gravity vector: overridden bodies keep it and
+        // receive a corrective force making up the difference.
+        for (handle, gravity) in &self.gravity_overrides {
+            if let Some(rb) = self.rigid_body_set.get_mut(to_rapier_rb_handle(*handle)) {
+                let scale = rb.mass() * rb.gravity_scale();
+                rb.reset_forces(false);
+                rb.add_force((to_rapier_vec(*gravity) - self.gravity) * scale, false);
+            }
+        }
+
         self.physics_pipeline.step(
             self.gravity,
             &self.integration_parameters,
@@ -93,6 +106,25 @@ impl PhysicsProvider for RapierPhysicsWorld {
         self.gravity = to_rapier_vec(gravity);
     }
 
+    fn gravity(&self) -> Vec3 {
+        from_rapier_vec(self.gravity)
+    }
+
+    fn set_body_gravity(&mut self, handle: RigidBodyHandle, gravity: Option<Vec3>) {
+        match gravity {
+            Some(gravity) => {
+                self.gravity_overrides.insert(handle, gravity);
+            }
+            None => {
+                if self.gravity_overrides.remove(&handle).is_some() {
+                    if let Some(rb) = self.rigid_body_set.get_mut(to_rapier_rb_handle(handle)) {
+                        rb.reset_forces(false);
+                    }
+                }
+            }
+        }
+    }
+
     fn add_body(&mut self, desc: RigidBodyDesc) -> RigidBodyHandle {
         let rb_type = match desc.body_type {
             BodyType::Dynamic => RigidBodyType::Dynamic,
@@ -107,6 +139,7 @@ impl PhysicsProvider for RapierPhysicsWorld {
             .angvel(to_rapier_vec(desc.angular_velocity))
             .additional_mass(desc.mass)
             .ccd_enabled(desc.ccd_enabled)
+            .gravity_scale(desc.gravity_scale)
             .build();
 
         let handle = self.rigid_body_set.insert(rigid_body);
@@ -114,6 +147,7 @@ impl PhysicsProvider for RapierPhysicsWorld {
     }
 
     fn remove_body(&mut self, handle: RigidBodyHandle) {
+        self.gravity_overrides.remove(&handle);
         let rb_handle = to_rapier_rb_handle(handle);
         self.rigid_body_set.remove(
             rb_handle,
@@ -211,6 +245,18 @@ impl PhysicsProvider for RapierPhysicsWorld {
             rb.set_linvel(to_rapier_vec(desc.linear_velocity), true);
             rb.set_angvel(to_rapier_vec(desc.angular_velocity), true);
             rb.enable_ccd(desc.ccd_enabled);
+            rb.set_gravity_scale(desc.gravity_scale, true);
+        }
+    }
+
+    fn set_body_sleeping(&mut self, handle: RigidBodyHandle, sleeping: bool) {
+        let rb_handle = to_rapier_rb_handle(handle);
+        if let Some(rb) = self.rigid_body_set.get_mut(rb_handle) {
+            if sleeping {
+                rb.sleep();
+            } else if rb.is_sleeping() {
+                rb.wake_up(true);
+            }
         }
     }
 
@@ -243,15 +289,27 @@ impl PhysicsProvider for RapierPhysicsWorld {
         (backend.vertices, backend.indices)
     }
 
-    fn cast_ray(&self, ray: &Ray, max_toi: f32, solid: bool) -> Option<RaycastHit> {
+    fn cast_ray(
+        &self,
+        ray: &Ray,
+        max_toi: f32,
+        solid: bool,
+        exclude: Option<ColliderHandle>,
+    ) -> Option<RaycastHit> {
         let rapier_ray =
             rapier3d::geometry::Ray::new(to_rapier_vec(ray.origin), to_rapier_vec(ray.direction));
 
+        let filter = match exclude {
+            Some(collider) => {
+                QueryFilter::default().exclude_collider(to_rapier_cl_handle(collider))
+            }
+            None => QueryFilter::default(),
+        };
         let query_pipeline = self.broad_phase.as_query_pipeline(
             self.narrow_phase.query_dispatcher(),
             &self.rigid_body_set,
             &self.collider_set,
-            QueryFilter::default(),
+            filter,
         );
 
         let (handle, intersection) =
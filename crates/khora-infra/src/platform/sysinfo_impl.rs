@@ -14,7 +14,9 @@
 
 //! sysinfo-based implementation of the HardwareMonitor trait.
 
-use khora_core::platform::{BatteryLevel, HardwareMonitor, ThermalStatus};
+use khora_core::platform::{
+    BatteryLevel, CpuSurvey, GpuSurvey, HardwareMonitor, HardwareSurvey, MonitorInfo, ThermalStatus,
+};
 use std::sync::{Arc, Mutex};
 use sysinfo::{Components, System};
 
@@ -40,6 +42,38 @@ impl SysinfoMonitor {
             // refresh_components is now handled by new_with_refreshed_list in thermal_status or similar
         }
     }
+
+    /// Describes the host: OS, CPU and memory from sysinfo, plus the
+    /// graphics adapter and displays the caller got from the renderer and
+    /// the windowing backend.
+    pub fn survey(&self, gpu: Option<GpuSurvey>, monitors: Vec<MonitorInfo>) -> HardwareSurvey {
+        let (cpu, total_memory_bytes) = match self.system.lock() {
+            Ok(system) => {
+                let cpus = system.cpus();
+                let cpu = CpuSurvey {
+                    brand: cpus
+                        .first()
+                        .map(|cpu| cpu.brand().trim().to_string())
+                        .unwrap_or_default(),
+                    physical_cores: System::physical_core_count(),
+                    logical_cores: cpus.len(),
+                    frequency_mhz: cpus.iter().map(|cpu| cpu.frequency()).max().unwrap_or(0),
+                };
+                (cpu, system.total_memory())
+            }
+            Err(_) => (CpuSurvey::default(), 0),
+        };
+
+        HardwareSurvey {
+            os: System::long_os_version()
+                .or_else(System::name)
+                .unwrap_or_default(),
+            cpu,
+            total_memory_bytes,
+            gpu,
+            monitors,
+        }
+    }
 }
 
 impl HardwareMonitor for SysinfoMonitor {
@@ -72,9 +106,16 @@ impl HardwareMonitor for SysinfoMonitor {
     }
 
     fn battery_level(&self) -> BatteryLevel {
-        // sysinfo doesn't easily expose battery on all platforms via System.
-        // For v0.1 we return Mains.
-        BatteryLevel::Mains
+        // sysinfo does not expose batteries. Linux reports them in sysfs;
+        // elsewhere we assume mains power.
+        #[cfg(target_os = "linux")]
+        {
+            linux_battery_level(std::path::Path::new("/sys/class/power_supply"))
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            BatteryLevel::Mains
+        }
     }
 
     fn cpu_load(&self) -> f32 {
@@ -91,3 +132,32 @@ impl Default for SysinfoMonitor {
         Self::new()
     }
 }
+
+/// Reads the battery level from a sysfs `power_supply` directory.
+///
+/// The host is on battery only while some battery reports `Discharging`;
+/// the lowest charge among the discharging batteries picks the level.
+#[cfg(target_os = "linux")]
+fn linux_battery_level(power_supply: &std::path::Path) -> BatteryLevel {
+    let read = |path: std::path::PathBuf| {
+        std::fs::read_to_string(path)
+            .map(|s| s.trim().to_string())
+            .ok()
+    };
+    let Ok(entries) = std::fs::read_dir(power_supply) else {
+        return BatteryLevel::Mains;
+    };
+    let lowest_charge = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|supply| read(supply.join("type")).as_deref() == Some("Battery"))
+        .filter(|supply| read(supply.join("status")).as_deref() == Some("Discharging"))
+        .filter_map(|supply| read(supply.join("capacity"))?.parse::<u8>().ok())
+        .min();
+    match lowest_charge {
+        None => BatteryLevel::Mains,
+        Some(0..=10) => BatteryLevel::Critical,
+        Some(11..=20) => BatteryLevel::Low,
+        Some(_) => BatteryLevel::High,
+    }
+}
@@ -15,6 +15,7 @@
 //! A `winit`-based implementation of the `KhoraWindow` trait.
 
 use khora_core::platform::window::{KhoraWindow, KhoraWindowHandle};
+use khora_core::platform::MonitorInfo;
 use raw_window_handle::{
     DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, WindowHandle,
 };
@@ -98,6 +99,7 @@ impl WinitWindowBuilder {
     /// # Errors
     /// Returns an `OsError` if the underlying `winit` window creation fails.
     pub fn build(self, event_loop: &ActiveEventLoop) -> Result<WinitWindow, OsError> {
+        khora_core::assert_main_thread!("WinitWindowBuilder::build");
         log::info!(
             "Building window with title: '{}' and size: {}x{}",
             self.title,
@@ -172,4 +174,22 @@ impl KhoraWindow for WinitWindow {
         self.inner.id().hash(&mut hasher);
         hasher.finish()
     }
+
+    /// Lists the monitors winit can see, flagging the one the window is on.
+    fn monitors(&self) -> Vec<MonitorInfo> {
+        let current = self.inner.current_monitor();
+        self.inner
+            .available_monitors()
+            .map(|monitor| {
+                let size = monitor.size();
+                MonitorInfo {
+                    name: monitor.name(),
+                    resolution: (size.width, size.height),
+                    refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
+                    scale_factor: monitor.scale_factor(),
+                    is_current: current.as_ref() == Some(&monitor),
+                }
+            })
+            .collect()
+    }
 }
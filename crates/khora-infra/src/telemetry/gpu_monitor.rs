@@ -55,12 +55,20 @@ impl GpuMonitor {
         let main_pass_begin_us = (frame_end_us - main_pass_duration_us) / 2;
         let main_pass_end_us = main_pass_begin_us + main_pass_duration_us;
 
-        let mut hook_timings = [None; 4];
+        let mut hook_timings = [None; GpuHook::ALL.len()];
         hook_timings[GpuHook::FrameStart as usize] = Some(frame_start_us);
         hook_timings[GpuHook::MainPassBegin as usize] = Some(main_pass_begin_us);
         hook_timings[GpuHook::MainPassEnd as usize] = Some(main_pass_end_us);
         hook_timings[GpuHook::FrameEnd as usize] = Some(frame_end_us);
 
+        // The depth prepass, when it ran, ends right where the main pass begins.
+        let depth_prepass_us = (render_stats.gpu_depth_prepass_time_ms * 1000.0) as u32;
+        if depth_prepass_us > 0 {
+            hook_timings[GpuHook::DepthPrepassBegin as usize] =
+                Some(main_pass_begin_us.saturating_sub(depth_prepass_us));
+            hook_timings[GpuHook::DepthPrepassEnd as usize] = Some(main_pass_begin_us);
+        }
+
         let report = GpuReport {
             frame_number: render_stats.frame_number,
             hook_timings_us: hook_timings,
@@ -128,6 +136,13 @@ impl ResourceMonitor for GpuMonitor {
                     MetricValue::Gauge(main_ms as f64 / 1000.0),
                 ));
             }
+
+            if let Some(prepass_us) = report.depth_prepass_duration_us() {
+                metrics.push((
+                    MetricId::new("renderer", "depth_prepass_gpu_time"),
+                    MetricValue::Gauge(prepass_us as f64 / 1000.0),
+                ));
+            }
         }
 
         metrics
@@ -146,6 +161,7 @@ impl ResourceMonitor for GpuMonitor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use khora_core::telemetry::metrics::MetricId;
 
     #[test]
     fn gpu_monitor_creation() {
@@ -168,9 +184,12 @@ mod tests {
             cpu_render_submission_time_ms: 0.5,
             gpu_main_pass_time_ms: 16.67,
             gpu_frame_total_time_ms: 16.67,
+            gpu_depth_prepass_time_ms: 0.0,
             draw_calls: 100,
             triangles_rendered: 1000,
             vram_usage_estimate_mb: 256.0,
+            pending_gpu_destructions: 0,
+            pending_gpu_destruction_mb: 0.0,
         };
 
         // Update stats
@@ -196,9 +215,12 @@ mod tests {
             cpu_render_submission_time_ms: 0.05,
             gpu_main_pass_time_ms: 16.67,
             gpu_frame_total_time_ms: 17.0,
+            gpu_depth_prepass_time_ms: 0.0,
             draw_calls: 50,
             triangles_rendered: 500,
             vram_usage_estimate_mb: 128.0,
+            pending_gpu_destructions: 0,
+            pending_gpu_destruction_mb: 0.0,
         };
 
         monitor.update_from_frame_stats(&render_stats);
@@ -222,9 +244,12 @@ mod tests {
             cpu_render_submission_time_ms: 0.0,
             gpu_main_pass_time_ms: 0.0,
             gpu_frame_total_time_ms: 0.0,
+            gpu_depth_prepass_time_ms: 0.0,
             draw_calls: 0,
             triangles_rendered: 0,
             vram_usage_estimate_mb: 0.0,
+            pending_gpu_destructions: 0,
+            pending_gpu_destruction_mb: 0.0,
         };
 
         monitor.update_from_frame_stats(&render_stats);
@@ -233,5 +258,31 @@ mod tests {
         // With zero timing values, hook timings will still be calculated (starting at 0)
         assert_eq!(report.frame_total_duration_us(), Some(0)); // 0ms = 0μs
         assert_eq!(report.main_pass_duration_us(), Some(0)); // 0ms = 0μs
+        assert_eq!(report.depth_prepass_duration_us(), None); // prepass did not run
+    }
+
+    #[test]
+    fn gpu_report_depth_prepass_span() {
+        let monitor = GpuMonitor::new("TestGPU".to_string());
+
+        let render_stats = RenderStats {
+            gpu_main_pass_time_ms: 8.0,
+            gpu_frame_total_time_ms: 12.0,
+            gpu_depth_prepass_time_ms: 1.5,
+            ..Default::default()
+        };
+
+        monitor.update_from_frame_stats(&render_stats);
+        let report = monitor.get_gpu_report().unwrap();
+
+        assert_eq!(report.depth_prepass_duration_us(), Some(1500));
+        assert_eq!(
+            report.get_hook_timing_us(GpuHook::DepthPrepassEnd),
+            report.get_hook_timing_us(GpuHook::MainPassBegin)
+        );
+        assert!(monitor
+            .get_metrics()
+            .iter()
+            .any(|(id, _)| *id == MetricId::new("renderer", "depth_prepass_gpu_time")));
     }
 }
@@ -0,0 +1,96 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hardware Health Monitor
+//!
+//! Polls a [`HardwareMonitor`] — thermal status, battery level and CPU
+//! load — on each telemetry update and forwards the result as a
+//! [`HardwareReport`], which the DCC uses for its thermal heuristics and
+//! the power governor.
+
+use std::borrow::Cow;
+use std::sync::Mutex;
+
+use khora_core::platform::HardwareMonitor;
+use khora_core::telemetry::monitoring::{
+    HardwareReport, MonitoredResourceType, ResourceMonitor, ResourceUsageReport,
+};
+
+use crate::platform::sysinfo_impl::SysinfoMonitor;
+
+/// Resource monitor turning platform readings into hardware reports.
+pub struct HardwareHealthMonitor {
+    id: String,
+    platform: SysinfoMonitor,
+    last_report: Mutex<Option<HardwareReport>>,
+}
+
+impl HardwareHealthMonitor {
+    /// Creates a monitor polling `platform`.
+    pub fn new(id: String, platform: SysinfoMonitor) -> Self {
+        Self {
+            id,
+            platform,
+            last_report: Mutex::new(None),
+        }
+    }
+
+    /// Returns the report of the last update.
+    pub fn last_report(&self) -> Option<HardwareReport> {
+        *self.last_report.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl std::fmt::Debug for HardwareHealthMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HardwareHealthMonitor")
+            .field("id", &self.id)
+            .field("last_report", &self.last_report())
+            .finish()
+    }
+}
+
+impl ResourceMonitor for HardwareHealthMonitor {
+    fn monitor_id(&self) -> Cow<'static, str> {
+        Cow::Owned(self.id.clone())
+    }
+
+    fn resource_type(&self) -> MonitoredResourceType {
+        MonitoredResourceType::Hardware
+    }
+
+    fn get_usage_report(&self) -> ResourceUsageReport {
+        ResourceUsageReport::default()
+    }
+
+    fn get_hardware_report(&self) -> Option<HardwareReport> {
+        self.last_report()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn update(&self) {
+        self.platform.refresh();
+        let report = HardwareReport {
+            thermal: self.platform.thermal_status(),
+            battery: self.platform.battery_level(),
+            cpu_load: self.platform.cpu_load(),
+            gpu_load: None,
+            gpu_timings: None,
+        };
+        *self.last_report.lock().unwrap_or_else(|e| e.into_inner()) = Some(report);
+    }
+}
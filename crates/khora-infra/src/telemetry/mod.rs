@@ -15,5 +15,6 @@
 //! Telemetry monitoring for system resources.
 
 pub mod gpu_monitor;
+pub mod hardware_monitor;
 pub mod memory_monitor;
 pub mod vram_monitor;
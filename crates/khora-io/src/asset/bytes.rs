@@ -0,0 +1,106 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Undecoded asset bytes, owned or borrowed from a memory-mapped archive.
+
+use std::fmt;
+use std::ops::{Deref, Range};
+use std::sync::Arc;
+
+use khora_core::asset::AssetUUID;
+use memmap2::Mmap;
+
+/// The raw bytes of an asset.
+///
+/// Loose files and streamed packs produce an owned buffer. A
+/// [`MappedPackLoader`](super::MappedPackLoader) hands out a view into its
+/// mapping instead, so reading the asset copies nothing.
+#[derive(Default)]
+pub struct AssetBytes {
+    repr: Repr,
+}
+
+enum Repr {
+    Owned(Vec<u8>),
+    Mapped { map: Arc<Mmap>, range: Range<usize> },
+}
+
+impl Default for Repr {
+    fn default() -> Self {
+        Repr::Owned(Vec::new())
+    }
+}
+
+impl AssetBytes {
+    /// Views `range` of a mapped archive. The range must be in bounds.
+    pub(crate) fn mapped(map: Arc<Mmap>, range: Range<usize>) -> Self {
+        debug_assert!(range.end <= map.len());
+        Self {
+            repr: Repr::Mapped { map, range },
+        }
+    }
+
+    /// Returns `true` if the bytes live in a memory-mapped archive.
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.repr, Repr::Mapped { .. })
+    }
+
+    /// Returns the bytes as an owned buffer, copying only mapped bytes.
+    pub fn into_vec(self) -> Vec<u8> {
+        match self.repr {
+            Repr::Owned(bytes) => bytes,
+            Repr::Mapped { map, range } => map[range].to_vec(),
+        }
+    }
+}
+
+impl Deref for AssetBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.repr {
+            Repr::Owned(bytes) => bytes,
+            Repr::Mapped { map, range } => &map[range.clone()],
+        }
+    }
+}
+
+impl From<Vec<u8>> for AssetBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            repr: Repr::Owned(bytes),
+        }
+    }
+}
+
+impl fmt::Debug for AssetBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssetBytes")
+            .field("len", &self.len())
+            .field("mapped", &self.is_mapped())
+            .finish()
+    }
+}
+
+/// An asset read by [`AssetService::read_async`](super::AssetService::read_async),
+/// ready to be decoded.
+#[derive(Debug)]
+pub struct AssetPayload {
+    /// The asset the bytes belong to.
+    pub uuid: AssetUUID,
+    /// The asset type, which selects the decoder.
+    pub type_name: String,
+    /// The bytes, patch deltas applied.
+    pub bytes: AssetBytes,
+}
@@ -14,7 +14,7 @@
 
 //! Asset decoder trait — raw bytes to typed asset.
 
-use khora_core::asset::Asset;
+use khora_core::asset::{Asset, AssetLoadQuality};
 use std::error::Error;
 
 /// A trait for types that can decode a specific kind of asset from raw bytes.
@@ -24,4 +24,17 @@ use std::error::Error;
 pub trait AssetDecoder<A: Asset> {
     /// Parses a byte slice and converts it into an instance of the asset `A`.
     fn load(&self, bytes: &[u8]) -> Result<A, Box<dyn Error + Send + Sync>>;
+
+    /// Decodes the asset at the given quality.
+    ///
+    /// Decoders that cannot trade quality for memory keep the default,
+    /// which ignores `quality` and calls [`load`](Self::load).
+    fn load_with_quality(
+        &self,
+        bytes: &[u8],
+        quality: AssetLoadQuality,
+    ) -> Result<A, Box<dyn Error + Send + Sync>> {
+        let _ = quality;
+        self.load(bytes)
+    }
 }
@@ -0,0 +1,34 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Material decoder: encoded material → `Box<dyn Material>`.
+
+use khora_core::asset::Material;
+use khora_data::ecs::decode_material;
+
+use crate::asset::AssetDecoder;
+
+/// Decodes material assets, stored in the encoding scenes use for inline
+/// materials.
+#[derive(Clone, Default)]
+pub struct MaterialDecoder;
+
+impl AssetDecoder<Box<dyn Material>> for MaterialDecoder {
+    fn load(
+        &self,
+        bytes: &[u8],
+    ) -> Result<Box<dyn Material>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        decode_material(bytes).map_err(Into::into)
+    }
+}
@@ -23,7 +23,7 @@ use khora_core::{
     renderer::api::{
         pipeline::enums::{PrimitiveTopology, VertexFormat},
         pipeline::VertexAttributeDescriptor,
-        scene::Mesh,
+        scene::{Mesh, MeshSkin, MorphTarget},
     },
 };
 use std::{error::Error, sync::Arc};
@@ -71,6 +71,8 @@ impl AssetDecoder<Mesh> for GltfDecoder {
         let tangents = self.extract_tangents(&reader);
         let colors = self.extract_colors(&reader);
         let indices = self.extract_indices(&reader);
+        let morph_targets = self.extract_morph_targets(&reader);
+        let skin = self.extract_skin(&reader);
 
         let bounding_box = {
             let bb = primitive.bounding_box();
@@ -96,6 +98,8 @@ impl AssetDecoder<Mesh> for GltfDecoder {
             primitive_type: self.map_primitive_type(primitive.mode()),
             bounding_box,
             vertex_layout,
+            morph_targets,
+            skin,
         })
     }
 }
@@ -165,6 +169,36 @@ impl GltfDecoder {
             .map(|iter| iter.map(|[x, y, z]| Vec3::new(x, y, z)).collect())
     }
 
+    fn extract_morph_targets<'a, 's, F>(&self, reader: &Reader<'a, 's, F>) -> Vec<MorphTarget>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        reader
+            .read_morph_targets()
+            .enumerate()
+            .map(|(i, (positions, normals, _tangents))| MorphTarget {
+                name: format!("target_{}", i),
+                position_deltas: positions
+                    .map(|iter| iter.map(|[x, y, z]| Vec3::new(x, y, z)).collect())
+                    .unwrap_or_default(),
+                normal_deltas: normals
+                    .map(|iter| iter.map(|[x, y, z]| Vec3::new(x, y, z)).collect()),
+            })
+            .collect()
+    }
+
+    fn extract_skin<'a, 's, F>(&self, reader: &Reader<'a, 's, F>) -> Option<MeshSkin>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let joint_indices: Vec<[u16; 4]> = reader.read_joints(0)?.into_u16().collect();
+        let joint_weights: Vec<[f32; 4]> = reader.read_weights(0)?.into_f32().collect();
+        Some(MeshSkin {
+            joint_indices,
+            joint_weights,
+        })
+    }
+
     fn extract_tex_coords<'a, 's, F>(&self, reader: &Reader<'a, 's, F>) -> Option<Vec<Vec2>>
     where
         F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
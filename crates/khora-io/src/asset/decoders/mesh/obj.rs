@@ -128,6 +128,8 @@ impl AssetDecoder<Mesh> for ObjDecoder {
             primitive_type: PrimitiveTopology::TriangleList,
             bounding_box,
             vertex_layout,
+            morph_targets: Vec::new(),
+            skin: None,
         })
     }
 }
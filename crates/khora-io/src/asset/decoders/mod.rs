@@ -15,18 +15,24 @@
 //! Concrete [`AssetDecoder`] implementations: bytes → typed asset.
 //!
 //! Decoders are pure CPU work; they have no GPU/IO state. They are
-//! registered with the [`AssetService`] via `register_decoder` and dispatched
-//! by asset type name during `load`.
+//! registered with the [`AssetService`] via `register_decoder`, or for every
+//! service through an [`AssetDecoderRegistration`], and dispatched by asset
+//! type name during `load`.
 //!
 //! [`AssetDecoder`]: super::AssetDecoder
+//! [`AssetDecoderRegistration`]: super::AssetDecoderRegistration
 //! [`AssetService`]: super::AssetService
 
 pub mod audio;
 pub mod font;
+pub mod material;
 pub mod mesh;
 pub mod texture;
+pub mod timeline;
 
 pub use audio::*;
 pub use font::*;
+pub use material::*;
 pub use mesh::*;
 pub use texture::*;
+pub use timeline::*;
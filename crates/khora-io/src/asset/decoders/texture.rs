@@ -16,6 +16,7 @@
 
 use anyhow::{Context, Result};
 use khora_core::{
+    asset::AssetLoadQuality,
     math::Extent3D,
     renderer::api::{
         resource::{CpuTexture, TextureDimension, TextureUsage},
@@ -34,7 +35,24 @@ impl AssetDecoder<CpuTexture> for TextureDecoder {
         &self,
         bytes: &[u8],
     ) -> Result<CpuTexture, Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let img = image::load_from_memory(bytes).context("Failed to decode image from memory")?;
+        self.load_with_quality(bytes, AssetLoadQuality::Full)
+    }
+
+    fn load_with_quality(
+        &self,
+        bytes: &[u8],
+        quality: AssetLoadQuality,
+    ) -> Result<CpuTexture, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mut img =
+            image::load_from_memory(bytes).context("Failed to decode image from memory")?;
+
+        // Skipping a mip level halves each dimension, down to 1 texel.
+        let skip = quality.skipped_texture_mips().min(31);
+        if skip > 0 {
+            let width = (img.width() >> skip).max(1);
+            let height = (img.height() >> skip).max(1);
+            img = img.resize_exact(width, height, image::imageops::FilterType::Triangle);
+        }
 
         let rgba_img = img.to_rgba8();
         let (width, height) = rgba_img.dimensions();
@@ -0,0 +1,113 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Timeline decoder: encoded timeline → `Timeline`.
+
+use bincode::config;
+use khora_core::animation::Timeline;
+use khora_data::scene::SCENE_DECODE_LIMIT;
+
+use crate::asset::{AssetDecoder, AssetDecoderRegistration};
+
+/// Decodes cutscene timelines stored in bincode's standard encoding.
+///
+/// Registered for the `timeline` asset type in every `AssetService`.
+#[derive(Clone, Default)]
+pub struct TimelineDecoder;
+
+impl TimelineDecoder {
+    /// Encodes `timeline` in the form this decoder reads back, for tools
+    /// writing timeline assets.
+    pub fn encode(timeline: &Timeline) -> Result<Vec<u8>, bincode::error::EncodeError> {
+        bincode::encode_to_vec(timeline, config::standard())
+    }
+}
+
+impl AssetDecoder<Timeline> for TimelineDecoder {
+    fn load(
+        &self,
+        bytes: &[u8],
+    ) -> Result<Timeline, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let (timeline, _) = bincode::decode_from_slice(
+            bytes,
+            config::standard().with_limit::<{ SCENE_DECODE_LIMIT }>(),
+        )?;
+        Ok(timeline)
+    }
+}
+
+inventory::submit! {
+    AssetDecoderRegistration {
+        type_name: "timeline",
+        register: |assets| assets.register_decoder("timeline", TimelineDecoder),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use khora_core::animation::{TimelineMarker, TimelineTrack};
+    use khora_core::asset::{AssetMetadata, AssetSource, AssetUUID};
+    use khora_telemetry::MetricsRegistry;
+
+    use super::*;
+    use crate::asset::{AssetService, FileLoader};
+
+    #[test]
+    fn timeline_round_trips_through_a_fresh_asset_service() {
+        let timeline = Timeline::new("intro")
+            .with_track(TimelineTrack::CameraCuts(vec![
+                TimelineMarker::new(0.0, "wide"),
+                TimelineMarker::new(2.5, "close"),
+            ]))
+            .with_track(TimelineTrack::Callbacks(vec![TimelineMarker::new(
+                4.0, "door",
+            )]))
+            .with_length(5.0);
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("intro.timeline"),
+            TimelineDecoder::encode(&timeline).unwrap(),
+        )
+        .unwrap();
+        let uuid = AssetUUID::new_v5("cutscenes/intro.timeline");
+        let index = vec![AssetMetadata {
+            uuid,
+            source_path: "intro.timeline".into(),
+            asset_type_name: "timeline".to_string(),
+            dependencies: vec![],
+            variants: HashMap::from([(
+                "default".to_string(),
+                AssetSource::Path("intro.timeline".into()),
+            )]),
+            tags: vec![],
+        }];
+        let index = bincode::serde::encode_to_vec(&index, config::standard()).unwrap();
+
+        // No `register_decoder` call: the registration is picked up by `new`.
+        let mut assets = AssetService::new(
+            &index,
+            Box::new(FileLoader::new(dir.path())),
+            Arc::new(MetricsRegistry::new()),
+        )
+        .unwrap();
+        let loaded = assets.load::<Timeline>(&uuid).unwrap();
+
+        assert_eq!(*loaded, timeline);
+        assert_eq!(loaded.camera_at(3.0), Some("close"));
+    }
+}
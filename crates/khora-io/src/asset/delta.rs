@@ -0,0 +1,236 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Binary deltas between two versions of an asset, used by patch archives.
+//!
+//! The encoding is a list of copy-from-base and insert-literal operations.
+//! [`AssetDelta::diff`] finds copies by hashing fixed-size blocks of the base,
+//! which catches in-place edits, insertions and deletions without needing a
+//! full suffix search.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Size of the base blocks matched by [`AssetDelta::diff`].
+const BLOCK_SIZE: usize = 32;
+
+/// One step of rebuilding the new bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeltaOp {
+    /// Copies `len` bytes of the base, starting at `offset`.
+    Copy {
+        /// Start of the copied range in the base.
+        offset: u64,
+        /// Number of bytes copied.
+        len: u64,
+    },
+    /// Appends literal bytes.
+    Insert(Vec<u8>),
+}
+
+/// An error raised while decoding or applying an [`AssetDelta`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaError {
+    /// The delta could not be encoded.
+    Encode(String),
+    /// The delta bytes could not be decoded.
+    Decode(String),
+    /// The base does not match the one the delta was computed from.
+    BaseMismatch,
+    /// A copy reaches past the end of the base.
+    OutOfBounds,
+}
+
+impl fmt::Display for DeltaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Encode(e) => write!(f, "failed to encode asset delta: {e}"),
+            Self::Decode(e) => write!(f, "failed to decode asset delta: {e}"),
+            Self::BaseMismatch => write!(f, "asset delta applied to the wrong base"),
+            Self::OutOfBounds => write!(f, "asset delta copies past the end of its base"),
+        }
+    }
+}
+
+impl std::error::Error for DeltaError {}
+
+/// A binary diff turning one version of an asset into the next.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetDelta {
+    base_len: u64,
+    base_hash: u64,
+    ops: Vec<DeltaOp>,
+}
+
+impl AssetDelta {
+    /// Computes the delta turning `base` into `target`.
+    pub fn diff(base: &[u8], target: &[u8]) -> Self {
+        let mut blocks: HashMap<u64, usize> = HashMap::new();
+        for (i, block) in base.chunks_exact(BLOCK_SIZE).enumerate() {
+            blocks.entry(fnv1a(block)).or_insert(i * BLOCK_SIZE);
+        }
+
+        let mut ops = Vec::new();
+        let mut literal = Vec::new();
+        let mut i = 0;
+        while i + BLOCK_SIZE <= target.len() {
+            let window = &target[i..i + BLOCK_SIZE];
+            let matched = blocks
+                .get(&fnv1a(window))
+                .copied()
+                .filter(|&offset| &base[offset..offset + BLOCK_SIZE] == window);
+            let Some(offset) = matched else {
+                literal.push(target[i]);
+                i += 1;
+                continue;
+            };
+
+            let mut len = BLOCK_SIZE;
+            while offset + len < base.len()
+                && i + len < target.len()
+                && base[offset + len] == target[i + len]
+            {
+                len += 1;
+            }
+            if !literal.is_empty() {
+                ops.push(DeltaOp::Insert(std::mem::take(&mut literal)));
+            }
+            push_copy(&mut ops, offset as u64, len as u64);
+            i += len;
+        }
+        literal.extend_from_slice(&target[i..]);
+        if !literal.is_empty() {
+            ops.push(DeltaOp::Insert(literal));
+        }
+
+        Self {
+            base_len: base.len() as u64,
+            base_hash: fnv1a(base),
+            ops,
+        }
+    }
+
+    /// Rebuilds the new bytes from `base`.
+    ///
+    /// # Errors
+    /// Returns [`DeltaError::BaseMismatch`] if `base` is not the version the
+    /// delta was computed from.
+    pub fn apply(&self, base: &[u8]) -> Result<Vec<u8>, DeltaError> {
+        if base.len() as u64 != self.base_len || fnv1a(base) != self.base_hash {
+            return Err(DeltaError::BaseMismatch);
+        }
+        let mut out = Vec::new();
+        for op in &self.ops {
+            match op {
+                DeltaOp::Copy { offset, len } => {
+                    let range = usize::try_from(*offset)
+                        .ok()
+                        .zip(usize::try_from(offset + len).ok())
+                        .and_then(|(start, end)| base.get(start..end))
+                        .ok_or(DeltaError::OutOfBounds)?;
+                    out.extend_from_slice(range);
+                }
+                DeltaOp::Insert(bytes) => out.extend_from_slice(bytes),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the operations of the delta.
+    pub fn ops(&self) -> &[DeltaOp] {
+        &self.ops
+    }
+
+    /// Encodes the delta for storage in a patch archive.
+    pub fn encode(&self) -> Result<Vec<u8>, DeltaError> {
+        bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .map_err(|e| DeltaError::Encode(e.to_string()))
+    }
+
+    /// Decodes a delta read from a patch archive.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DeltaError> {
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map(|(delta, _)| delta)
+            .map_err(|e| DeltaError::Decode(e.to_string()))
+    }
+}
+
+/// Appends a copy, merging it into the previous one when contiguous.
+fn push_copy(ops: &mut Vec<DeltaOp>, offset: u64, len: u64) {
+    if let Some(DeltaOp::Copy {
+        offset: prev_offset,
+        len: prev_len,
+    }) = ops.last_mut()
+    {
+        if *prev_offset + *prev_len == offset {
+            *prev_len += len;
+            return;
+        }
+    }
+    ops.push(DeltaOp::Copy { offset, len });
+}
+
+/// 64-bit FNV-1a hash.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 31 % 251) as u8).collect()
+    }
+
+    #[test]
+    fn roundtrip_with_edit_insert_and_delete() {
+        let base = sample(4096);
+        let mut target = base.clone();
+        target[100] ^= 0xff;
+        target.splice(2000..2000, [1u8, 2, 3, 4, 5]);
+        target.drain(3000..3100);
+
+        let delta = AssetDelta::diff(&base, &target);
+        let encoded = delta.encode().unwrap();
+        let decoded = AssetDelta::decode(&encoded).unwrap();
+        assert_eq!(decoded.apply(&base).unwrap(), target);
+        assert!(encoded.len() < target.len() / 4);
+    }
+
+    #[test]
+    fn identical_data_is_one_copy() {
+        let base = sample(1000);
+        let delta = AssetDelta::diff(&base, &base);
+        assert_eq!(
+            delta.ops(),
+            &[DeltaOp::Copy {
+                offset: 0,
+                len: 1000
+            }]
+        );
+    }
+
+    #[test]
+    fn wrong_base_is_rejected() {
+        let base = sample(512);
+        let delta = AssetDelta::diff(&base, &sample(600));
+        let mut other = base.clone();
+        other[0] ^= 1;
+        assert_eq!(delta.apply(&other), Err(DeltaError::BaseMismatch));
+    }
+}
@@ -18,14 +18,15 @@
 
 use anyhow::{bail, Context, Result};
 use khora_core::asset::AssetSource;
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
-use super::AssetIo;
+use super::{AssetBytes, AssetIo, AssetRead};
 
 /// File-based asset loader for editor/development mode.
 ///
 /// Reads assets directly from individual files on disk. The `root` path is
 /// typically `<project>/assets/`.
+#[derive(Clone)]
 pub struct FileLoader {
     root: PathBuf,
 }
@@ -37,17 +38,33 @@ impl FileLoader {
     }
 }
 
-impl AssetIo for FileLoader {
-    fn load_bytes(&mut self, source: &AssetSource) -> Result<Vec<u8>> {
+impl FileLoader {
+    fn read_file(&self, source: &AssetSource) -> Result<Vec<u8>> {
         match source {
             AssetSource::Path(rel) => {
                 let full_path = self.root.join(rel);
                 std::fs::read(&full_path)
                     .with_context(|| format!("Failed to read asset: {:?}", full_path))
             }
-            AssetSource::Packed { .. } => {
+            AssetSource::Packed { .. } | AssetSource::Delta { .. } => {
                 bail!("FileLoader does not support Packed sources")
             }
         }
     }
 }
+
+impl AssetRead for FileLoader {
+    fn read(&self, source: &AssetSource) -> Result<AssetBytes> {
+        self.read_file(source).map(AssetBytes::from)
+    }
+}
+
+impl AssetIo for FileLoader {
+    fn load_bytes(&mut self, source: &AssetSource) -> Result<Vec<u8>> {
+        self.read_file(source)
+    }
+
+    fn reader(&self) -> Option<Arc<dyn AssetRead>> {
+        Some(Arc::new(self.clone()))
+    }
+}
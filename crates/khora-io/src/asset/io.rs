@@ -14,9 +14,13 @@
 
 //! Abstraction over asset I/O backends.
 
+use std::sync::Arc;
+
 use anyhow::Result;
 use khora_core::asset::AssetSource;
 
+use super::AssetBytes;
+
 /// Trait for asset I/O backends (file system or pack archive).
 ///
 /// Implementations handle the low-level reading of raw bytes from storage.
@@ -24,4 +28,24 @@ use khora_core::asset::AssetSource;
 pub trait AssetIo: Send + Sync {
     /// Loads raw bytes from the given asset source.
     fn load_bytes(&mut self, source: &AssetSource) -> Result<Vec<u8>>;
+
+    /// Loads raw bytes, without copying them if the backend can.
+    fn load_shared(&mut self, source: &AssetSource) -> Result<AssetBytes> {
+        self.load_bytes(source).map(AssetBytes::from)
+    }
+
+    /// Returns a reader other threads can use, which async reads require.
+    /// `None` if the backend only reads from its owner.
+    fn reader(&self) -> Option<Arc<dyn AssetRead>> {
+        None
+    }
+}
+
+/// Shared, thread-safe read access to an asset backend.
+///
+/// Used by [`AssetService::read_async`](super::AssetService::read_async) to
+/// read off the frame thread.
+pub trait AssetRead: Send + Sync {
+    /// Reads raw bytes from the given asset source.
+    fn read(&self, source: &AssetSource) -> Result<AssetBytes>;
 }
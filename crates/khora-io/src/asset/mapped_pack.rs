@@ -0,0 +1,100 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Memory-mapped pack archive reader.
+
+use anyhow::{bail, Context, Result};
+use khora_core::asset::AssetSource;
+use memmap2::Mmap;
+use std::{fs::File, path::Path, sync::Arc};
+
+use super::{AssetBytes, AssetIo, AssetRead};
+
+/// Reads a packfile through a read-only memory mapping.
+///
+/// Suited to large archives: the OS pages data in on demand, and reads
+/// return views into the mapping instead of copies. Clones share the
+/// mapping, so any thread can read.
+#[derive(Clone)]
+pub struct MappedPackLoader {
+    map: Arc<Mmap>,
+}
+
+impl MappedPackLoader {
+    /// Maps `pack_file` into memory.
+    pub fn new(pack_file: &File) -> Result<Self> {
+        // SAFETY: packfiles are read-only build outputs and the engine never
+        // writes to a mounted archive. As with any mapping, another process
+        // truncating the file while it is mapped is not guarded against.
+        let map = unsafe { Mmap::map(pack_file) }.context("Failed to memory-map pack file")?;
+        Ok(Self { map: Arc::new(map) })
+    }
+
+    /// Opens and maps the packfile at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).with_context(|| format!("Failed to open pack file {:?}", path))?;
+        Self::new(&file)
+    }
+
+    /// Returns the size of the mapped archive in bytes.
+    pub fn len(&self) -> u64 {
+        self.map.len() as u64
+    }
+
+    /// Returns `true` if the mapped archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl AssetRead for MappedPackLoader {
+    fn read(&self, source: &AssetSource) -> Result<AssetBytes> {
+        match source {
+            AssetSource::Packed { offset, size } | AssetSource::Delta { offset, size } => {
+                let range = offset
+                    .checked_add(*size)
+                    .filter(|end| *end <= self.len())
+                    .map(|end| *offset as usize..end as usize);
+                match range {
+                    Some(range) => Ok(AssetBytes::mapped(Arc::clone(&self.map), range)),
+                    None => bail!(
+                        "Asset range {}..+{} lies outside the {}-byte pack file",
+                        offset,
+                        size,
+                        self.len()
+                    ),
+                }
+            }
+            AssetSource::Path(_) => {
+                bail!("MappedPackLoader does not support Path sources")
+            }
+        }
+    }
+}
+
+impl AssetIo for MappedPackLoader {
+    fn load_bytes(&mut self, source: &AssetSource) -> Result<Vec<u8>> {
+        self.read(source).map(AssetBytes::into_vec)
+    }
+
+    fn load_shared(&mut self, source: &AssetSource) -> Result<AssetBytes> {
+        self.read(source)
+    }
+
+    fn reader(&self) -> Option<Arc<dyn AssetRead>> {
+        Some(Arc::new(self.clone()))
+    }
+}
@@ -14,18 +14,30 @@
 
 //! Asset I/O and decoding services.
 
+mod bytes;
 mod decoder;
 pub mod decoders;
+mod delta;
 mod file;
 mod io;
+mod mapped_pack;
 mod pack;
+mod prefetch;
 mod registry;
+mod resolution;
 mod service;
+mod thumbnail_cache;
 
+pub use bytes::*;
 pub use decoder::*;
 pub use decoders::*;
+pub use delta::*;
 pub use file::*;
 pub use io::*;
+pub use mapped_pack::*;
 pub use pack::*;
+pub use prefetch::*;
 pub use registry::*;
+pub use resolution::*;
 pub use service::*;
+pub use thumbnail_cache::*;
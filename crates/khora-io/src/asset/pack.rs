@@ -21,34 +21,43 @@ use khora_core::asset::AssetSource;
 use std::{
     fs::File,
     io::{Read, Seek, SeekFrom},
+    sync::{Arc, Mutex, PoisonError},
 };
 
-use super::AssetIo;
+use super::{AssetBytes, AssetIo, AssetRead};
 
 /// Pack-based asset loader for release mode.
 ///
 /// Reads assets from a `.pack` archive file by seeking to the recorded offset
-/// and reading the specified number of bytes.
+/// and reading the specified number of bytes. Clones share the file handle
+/// and take turns reading it. For large archives, prefer
+/// [`MappedPackLoader`](super::MappedPackLoader).
+#[derive(Clone)]
 pub struct PackLoader {
-    pack_file: File,
+    pack_file: Arc<Mutex<File>>,
 }
 
 impl PackLoader {
     /// Creates a new `PackLoader` with the given pack file handle.
     pub fn new(pack_file: File) -> Self {
-        Self { pack_file }
+        Self {
+            pack_file: Arc::new(Mutex::new(pack_file)),
+        }
     }
-}
 
-impl AssetIo for PackLoader {
-    fn load_bytes(&mut self, source: &AssetSource) -> Result<Vec<u8>> {
+    fn read_range(&self, source: &AssetSource) -> Result<Vec<u8>> {
         match source {
-            AssetSource::Packed { offset, size } => {
+            // Deltas are stored like any other blob; `AssetService` applies them.
+            AssetSource::Packed { offset, size } | AssetSource::Delta { offset, size } => {
                 let mut buffer = vec![0; *size as usize];
-                self.pack_file
+                let mut pack_file = self
+                    .pack_file
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                pack_file
                     .seek(SeekFrom::Start(*offset))
                     .context("Failed to seek to asset location in pack file")?;
-                self.pack_file
+                pack_file
                     .read_exact(&mut buffer)
                     .context("Failed to read asset bytes from pack file")?;
                 Ok(buffer)
@@ -59,3 +68,19 @@ impl AssetIo for PackLoader {
         }
     }
 }
+
+impl AssetRead for PackLoader {
+    fn read(&self, source: &AssetSource) -> Result<AssetBytes> {
+        self.read_range(source).map(AssetBytes::from)
+    }
+}
+
+impl AssetIo for PackLoader {
+    fn load_bytes(&mut self, source: &AssetSource) -> Result<Vec<u8>> {
+        self.read_range(source)
+    }
+
+    fn reader(&self) -> Option<Arc<dyn AssetRead>> {
+        Some(Arc::new(self.clone()))
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cost estimate of the prefetches queued on the asset service.
+
+/// What the prefetches still queued on the `AssetService` will cost.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrefetchEstimate {
+    /// Number of queued prefetches.
+    pub requests: usize,
+    /// Bytes read from the archives if every payload is fetched.
+    pub io_bytes: u64,
+    /// Part of `io_bytes` that belongs to textures, whose decoded size
+    /// depends on the load quality.
+    pub texture_bytes: u64,
+}
@@ -14,9 +14,9 @@
 
 //! Decoder registry — type-erased dispatch for asset decoding.
 
-use super::AssetDecoder;
+use super::{AssetDecoder, AssetService};
 use anyhow::{anyhow, Result};
-use khora_core::asset::Asset;
+use khora_core::asset::{Asset, AssetLoadQuality};
 use khora_telemetry::{
     metrics::registry::{CounterHandle, HistogramHandle},
     MetricsRegistry, ScopedMetricTimer,
@@ -24,15 +24,28 @@ use khora_telemetry::{
 use std::{any::Any, collections::HashMap, sync::Arc};
 
 trait AnyDecoder: Send + Sync {
-    fn decode_any(&self, bytes: &[u8], metrics: &DecoderMetrics) -> Result<Box<dyn Any + Send>>;
+    fn decode_any(
+        &self,
+        bytes: &[u8],
+        quality: AssetLoadQuality,
+        metrics: &DecoderMetrics,
+    ) -> Result<Box<dyn Any + Send>>;
 }
 
 struct DecoderWrapper<A: Asset, L: AssetDecoder<A>>(L, std::marker::PhantomData<A>);
 
 impl<A: Asset, L: AssetDecoder<A> + Send + Sync> AnyDecoder for DecoderWrapper<A, L> {
-    fn decode_any(&self, bytes: &[u8], metrics: &DecoderMetrics) -> Result<Box<dyn Any + Send>> {
+    fn decode_any(
+        &self,
+        bytes: &[u8],
+        quality: AssetLoadQuality,
+        metrics: &DecoderMetrics,
+    ) -> Result<Box<dyn Any + Send>> {
         let _timer = ScopedMetricTimer::new(&metrics.decode_time_ms);
-        let asset: A = self.0.load(bytes).map_err(|e| anyhow!(e.to_string()))?;
+        let asset: A = self
+            .0
+            .load_with_quality(bytes, quality)
+            .map_err(|e| anyhow!(e.to_string()))?;
         metrics.assets_decoded_total.increment()?;
         Ok(Box::new(asset))
     }
@@ -62,6 +75,29 @@ impl DecoderMetrics {
     }
 }
 
+/// Registration entry for a built-in asset decoder — submitted by each
+/// decoder module via [`inventory::submit!`].
+///
+/// [`AssetService::new`] registers every submitted decoder, so assets of
+/// that type load without any wiring in the engine.
+///
+/// ```rust,ignore
+/// inventory::submit! {
+///     AssetDecoderRegistration {
+///         type_name: "timeline",
+///         register: |assets| assets.register_decoder("timeline", TimelineDecoder),
+///     }
+/// }
+/// ```
+pub struct AssetDecoderRegistration {
+    /// Asset type name the decoder handles, as in the asset metadata.
+    pub type_name: &'static str,
+    /// Registers the decoder with the service.
+    pub register: fn(&mut AssetService),
+}
+
+inventory::collect!(AssetDecoderRegistration);
+
 /// Registry of asset decoders, keyed by type name.
 pub struct DecoderRegistry {
     metrics: DecoderMetrics,
@@ -90,12 +126,22 @@ impl DecoderRegistry {
 
     /// Decodes an asset of the specified type from raw bytes.
     pub fn decode<A: Asset>(&self, type_name: &str, bytes: &[u8]) -> Result<A> {
+        self.decode_with_quality(type_name, bytes, AssetLoadQuality::Full)
+    }
+
+    /// Decodes an asset of the specified type from raw bytes at `quality`.
+    pub fn decode_with_quality<A: Asset>(
+        &self,
+        type_name: &str,
+        bytes: &[u8],
+        quality: AssetLoadQuality,
+    ) -> Result<A> {
         let decoder = self
             .decoders
             .get(type_name)
             .ok_or_else(|| anyhow!("No decoder registered for asset type '{}'", type_name))?;
 
-        let asset_any = decoder.decode_any(bytes, &self.metrics)?;
+        let asset_any = decoder.decode_any(bytes, quality, &self.metrics)?;
         let asset_boxed = asset_any.downcast::<A>().map_err(|_| {
             anyhow!(
                 "Decoder for type '{}' returned a different asset type than requested.",
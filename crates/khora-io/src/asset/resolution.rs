@@ -0,0 +1,401 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Binds asset references left behind by scene deserialization.
+//!
+//! Scene strategies live in `khora-data` and cannot reach the
+//! [`AssetService`], so imported assets are recorded on the entity as
+//! [`PendingAssets`]. This pass never reads from disk: it queues each asset
+//! as a required prefetch, which the `AssetAgent` works through within its
+//! budget, and attaches the matching handle once the asset is cached.
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::asset::{Asset, AssetHandle, AssetUUID, Material};
+use khora_core::ecs::entity::EntityId;
+use khora_core::renderer::api::scene::Mesh;
+use khora_core::ServiceRegistry;
+use khora_data::ecs::{
+    AssetRequest, DataSystemRegistration, HandleComponent, IncludeDisabled, MaterialComponent,
+    PendingAssetKind, PendingAssets, TickPhase, World,
+};
+use khora_data::ResidencyManager;
+
+use super::service::AssetService;
+
+/// What one pass did with a pending request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Resolution {
+    /// The handle was attached.
+    Bound,
+    /// The asset is queued for the `AssetAgent`; try again next pass.
+    Waiting,
+    /// The asset cannot be bound; the request is dropped.
+    Dropped,
+}
+
+/// Advances every [`PendingAssets`] marker in `world`.
+///
+/// Cached assets are bound at once; the others are queued on `assets` and
+/// stay pending. Requests for assets that are not in the index, or that
+/// failed to load, are logged and dropped rather than bound to an empty
+/// handle. The marker is removed once nothing is left pending. Returns the
+/// number of handles bound.
+pub fn resolve_pending_assets(world: &mut World, assets: &mut AssetService) -> usize {
+    let pending: Vec<(EntityId, Vec<AssetRequest>)> = world
+        .query::<(EntityId, &PendingAssets, IncludeDisabled)>()
+        .map(|(entity, pending, _)| (entity, pending.requests.clone()))
+        .collect();
+
+    let mut resolved = 0;
+    for (entity, requests) in pending {
+        let count = requests.len();
+        let mut waiting = Vec::new();
+        for request in requests {
+            match resolve_request(world, assets, entity, request) {
+                Resolution::Bound => resolved += 1,
+                Resolution::Waiting => waiting.push(request),
+                Resolution::Dropped => {}
+            }
+        }
+        if waiting.is_empty() {
+            let _ = world.remove_component::<PendingAssets>(entity);
+        } else if waiting.len() != count {
+            if let Some(pending) = world.get_mut::<PendingAssets>(entity) {
+                pending.requests = waiting;
+            }
+        }
+    }
+    resolved
+}
+
+fn resolve_request(
+    world: &mut World,
+    assets: &mut AssetService,
+    entity: EntityId,
+    request: AssetRequest,
+) -> Resolution {
+    match request.kind {
+        PendingAssetKind::Mesh => resolve(world, assets, entity, request, bind_mesh),
+        PendingAssetKind::Material => resolve(world, assets, entity, request, bind_material),
+        other => {
+            log::warn!("No resolver for pending asset kind {:?}", other);
+            Resolution::Dropped
+        }
+    }
+}
+
+/// Binds `request` with `bind` if its asset is cached, or queues it.
+fn resolve<A: Asset>(
+    world: &mut World,
+    assets: &mut AssetService,
+    entity: EntityId,
+    request: AssetRequest,
+    bind: fn(&mut World, EntityId, AssetHandle<A>, AssetUUID) -> bool,
+) -> Resolution {
+    let AssetRequest { kind, uuid } = request;
+    if let Some(handle) = assets.cached::<A>(&uuid) {
+        return match bind(world, entity, handle, uuid) {
+            true => Resolution::Bound,
+            false => Resolution::Dropped,
+        };
+    }
+
+    if assets.metadata(&uuid).is_none() {
+        if kind == PendingAssetKind::Material {
+            // Most materials only exist inline in the scene.
+            log::trace!(
+                "Material {:?} is not an asset; keeping its inline copy",
+                uuid
+            );
+        } else {
+            log::warn!(
+                "Cannot resolve {:?} {:?} for {:?}: not in the asset index",
+                kind,
+                uuid,
+                entity
+            );
+        }
+        return Resolution::Dropped;
+    }
+    if assets.prefetch_failed(&uuid) {
+        log::warn!("Failed to load {:?} {:?} for {:?}", kind, uuid, entity);
+        return Resolution::Dropped;
+    }
+    if assets.is_cached(&uuid) {
+        log::warn!("Asset {:?} for {:?} is not a {:?}", uuid, entity, kind);
+        return Resolution::Dropped;
+    }
+
+    assets.prefetch_required(uuid);
+    Resolution::Waiting
+}
+
+fn bind_mesh(
+    world: &mut World,
+    entity: EntityId,
+    handle: AssetHandle<Mesh>,
+    uuid: AssetUUID,
+) -> bool {
+    // An entity reloading a dropped CPU copy still holds its placeholder.
+    match world.get_mut::<HandleComponent<Mesh>>(entity) {
+        Some(existing) => {
+            *existing = HandleComponent { handle, uuid };
+            true
+        }
+        None => world
+            .add_component(entity, HandleComponent { handle, uuid })
+            .is_ok(),
+    }
+}
+
+fn bind_material(
+    world: &mut World,
+    entity: EntityId,
+    handle: AssetHandle<Box<dyn Material>>,
+    uuid: AssetUUID,
+) -> bool {
+    match world.get_mut::<MaterialComponent>(entity) {
+        Some(material) => {
+            *material = MaterialComponent { handle, uuid };
+            true
+        }
+        // The material was removed while it was loading.
+        None => false,
+    }
+}
+
+fn asset_resolution_system(world: &mut World, services: &ServiceRegistry) {
+    let Some(service) = services.get::<Arc<Mutex<AssetService>>>() else {
+        return;
+    };
+    let Ok(mut assets) = service.lock() else {
+        log::error!("AssetService mutex poisoned; skipping asset resolution");
+        return;
+    };
+    let resolved = resolve_pending_assets(world, &mut assets);
+    if resolved > 0 {
+        log::debug!("Bound {} deserialized asset reference(s)", resolved);
+    }
+
+    // Meshes whose CPU copy was dropped by their residency policy must not
+    // stay alive in the service cache either.
+    if let Some(residency) = services.get::<ResidencyManager>() {
+        for uuid in residency.take_cpu_released() {
+            assets.unload::<Mesh>(&uuid);
+        }
+    }
+}
+
+inventory::submit! {
+    DataSystemRegistration {
+        name: "asset_resolution",
+        phase: TickPhase::PreSimulation,
+        run: asset_resolution_system,
+        order_hint: 0,
+        runs_after: &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asset::{AssetDecoder, FileLoader, MaterialDecoder};
+    use khora_core::asset::{AssetLoadQuality, AssetMetadata, AssetSource, StandardMaterial};
+    use khora_core::math::LinearRgba;
+    use khora_data::ecs::{serialize_material_component, ProceduralMeshKind, SerializableMeshRef};
+    use khora_telemetry::MetricsRegistry;
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    struct PlaneDecoder;
+
+    impl AssetDecoder<Mesh> for PlaneDecoder {
+        fn load(&self, _bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+            let plane = SerializableMeshRef::Procedural {
+                kind: ProceduralMeshKind::Plane,
+                params: [1.0, 1.0, 0.0, 0.0],
+            };
+            Ok((*plane.into_handle().handle).clone())
+        }
+    }
+
+    fn metadata(uuid: AssetUUID, type_name: &str, file: &str) -> AssetMetadata {
+        AssetMetadata {
+            uuid,
+            source_path: file.into(),
+            asset_type_name: type_name.to_string(),
+            dependencies: vec![],
+            variants: HashMap::from([("default".to_string(), AssetSource::Path(file.into()))]),
+            tags: vec![],
+        }
+    }
+
+    /// A service over a temporary directory holding `files`, indexed by
+    /// `index`.
+    fn service(
+        dir: &tempfile::TempDir,
+        index: Vec<AssetMetadata>,
+        files: &[(&str, Vec<u8>)],
+    ) -> AssetService {
+        for (name, bytes) in files {
+            std::fs::write(dir.path().join(name), bytes).unwrap();
+        }
+        let index = bincode::serde::encode_to_vec(&index, bincode::config::standard()).unwrap();
+        let mut assets = AssetService::new(
+            &index,
+            Box::new(FileLoader::new(dir.path())),
+            Arc::new(MetricsRegistry::new()),
+        )
+        .unwrap();
+        assets.register_decoder("mesh", PlaneDecoder);
+        assets.register_decoder("material", MaterialDecoder);
+        assets
+    }
+
+    #[test]
+    fn mesh_is_bound_once_the_asset_agent_prefetched_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let uuid = AssetUUID::new_v5("meshes/plane.mesh");
+        let mut assets = service(
+            &dir,
+            vec![metadata(uuid, "mesh", "plane.mesh")],
+            &[("plane.mesh", vec![0])],
+        );
+        let mut world = World::new();
+        let entity = world.spawn(());
+        PendingAssets::request(&mut world, entity, PendingAssetKind::Mesh, uuid);
+
+        // Nothing is read on the frame thread: the request waits in the queue.
+        assert_eq!(resolve_pending_assets(&mut world, &mut assets), 0);
+        assert_eq!(assets.load_count(), 0);
+        assert_eq!(assets.pending_prefetch_count(), 1);
+        assert!(world.get::<HandleComponent<Mesh>>(entity).is_none());
+        assert!(world.get::<PendingAssets>(entity).is_some());
+
+        // What the AssetAgent's prefetch lane does on its pass.
+        assert_eq!(assets.process_prefetch(8), 1);
+
+        assert_eq!(resolve_pending_assets(&mut world, &mut assets), 1);
+        let mesh = world.get::<HandleComponent<Mesh>>(entity).unwrap();
+        assert_eq!(mesh.uuid, uuid);
+        assert!(world.get::<PendingAssets>(entity).is_none());
+    }
+
+    #[test]
+    fn required_prefetch_reads_the_payload_under_metadata_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let uuid = AssetUUID::new_v5("meshes/plane.mesh");
+        let mut assets = service(
+            &dir,
+            vec![metadata(uuid, "mesh", "plane.mesh")],
+            &[("plane.mesh", vec![0])],
+        );
+        assets.set_load_quality(AssetLoadQuality::MetadataOnly);
+        let mut world = World::new();
+        let entity = world.spawn(());
+        PendingAssets::request(&mut world, entity, PendingAssetKind::Mesh, uuid);
+
+        resolve_pending_assets(&mut world, &mut assets);
+        assets.process_prefetch(8);
+
+        assert_eq!(resolve_pending_assets(&mut world, &mut assets), 1);
+        assert!(world.get::<HandleComponent<Mesh>>(entity).is_some());
+    }
+
+    #[test]
+    fn material_asset_replaces_the_inline_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let uuid = AssetUUID::new_v5("materials/red.material");
+        let red = StandardMaterial {
+            base_color: LinearRgba::RED,
+            ..Default::default()
+        };
+        let bytes = serialize_material_component(red.base_color, &red).unwrap();
+        let mut assets = service(
+            &dir,
+            vec![metadata(uuid, "material", "red.material")],
+            &[("red.material", bytes)],
+        );
+        let mut world = World::new();
+        let inline: Box<dyn Material> = Box::new(StandardMaterial::default());
+        let entity = world.spawn(MaterialComponent {
+            handle: AssetHandle::new(inline),
+            uuid,
+        });
+        PendingAssets::request(&mut world, entity, PendingAssetKind::Material, uuid);
+
+        resolve_pending_assets(&mut world, &mut assets);
+        assets.process_prefetch(8);
+
+        assert_eq!(resolve_pending_assets(&mut world, &mut assets), 1);
+        let material = world.get::<MaterialComponent>(entity).unwrap();
+        assert_eq!(material.handle.base_color(), LinearRgba::RED);
+        assert!(world.get::<PendingAssets>(entity).is_none());
+    }
+
+    #[test]
+    fn inline_material_without_an_asset_is_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut assets = service(&dir, vec![], &[]);
+        let mut world = World::new();
+        let uuid = AssetUUID::new();
+        let inline: Box<dyn Material> = Box::new(StandardMaterial {
+            base_color: LinearRgba::GREEN,
+            ..Default::default()
+        });
+        let entity = world.spawn(MaterialComponent {
+            handle: AssetHandle::new(inline),
+            uuid,
+        });
+        PendingAssets::request(&mut world, entity, PendingAssetKind::Material, uuid);
+
+        assert_eq!(resolve_pending_assets(&mut world, &mut assets), 0);
+        assert_eq!(assets.pending_prefetch_count(), 0);
+        let material = world.get::<MaterialComponent>(entity).unwrap();
+        assert_eq!(material.handle.base_color(), LinearRgba::GREEN);
+        assert!(world.get::<PendingAssets>(entity).is_none());
+    }
+
+    #[test]
+    fn unknown_and_failed_assets_are_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing_file = AssetUUID::new_v5("meshes/missing.mesh");
+        let mut assets = service(
+            &dir,
+            vec![metadata(missing_file, "mesh", "missing.mesh")],
+            &[],
+        );
+        let mut world = World::new();
+        let unknown = world.spawn(());
+        PendingAssets::request(
+            &mut world,
+            unknown,
+            PendingAssetKind::Mesh,
+            AssetUUID::new(),
+        );
+        let failing = world.spawn(());
+        PendingAssets::request(&mut world, failing, PendingAssetKind::Mesh, missing_file);
+
+        resolve_pending_assets(&mut world, &mut assets);
+        assert!(world.get::<PendingAssets>(unknown).is_none());
+        assert!(world.get::<PendingAssets>(failing).is_some());
+
+        assert_eq!(assets.process_prefetch(8), 0);
+        assert!(assets.prefetch_failed(&missing_file));
+
+        assert_eq!(resolve_pending_assets(&mut world, &mut assets), 0);
+        assert!(world.get::<PendingAssets>(failing).is_none());
+        assert!(world.get::<HandleComponent<Mesh>>(failing).is_none());
+    }
+}
@@ -16,19 +16,88 @@
 //!
 //! This service provides a `load()` API backed by a VFS + IO layer + decoder registry.
 //! No GORNA negotiation, no per-frame budget — assets are loaded on-demand.
+//! Unloading unused assets and working through queued prefetches are
+//! driven by the `AssetAgent` through [`AssetService::unload_unused`] and
+//! [`AssetService::process_prefetch`], at the [`AssetLoadQuality`] its
+//! strategy picked.
 
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context, Result};
-use khora_core::asset::{Asset, AssetHandle, AssetUUID};
+use khora_core::asset::{
+    ArchiveInfo, Asset, AssetHandle, AssetLoadQuality, AssetMetadata, AssetSource, AssetUUID,
+    PlatformTarget, WeakHandle,
+};
+use khora_core::executor::{Executor, Task};
 use khora_data::assets::Assets;
 use khora_telemetry::MetricsRegistry;
 
+use super::bytes::{AssetBytes, AssetPayload};
+use super::delta::AssetDelta;
 use super::io::AssetIo;
-use super::registry::DecoderRegistry;
-use crate::vfs::VirtualFileSystem;
+use super::prefetch::PrefetchEstimate;
+use super::registry::{AssetDecoderRegistration, DecoderRegistry};
+use crate::vfs::{ResolvedAsset, VirtualFileSystem};
+
+/// Type-erased access to one `Assets<A>` storage.
+trait AssetStorage: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn contains(&self, uuid: &AssetUUID) -> bool;
+    fn sweep_unused(&mut self, now: Instant, grace: Duration, limit: usize) -> usize;
+}
+
+impl<A: Asset> AssetStorage for Assets<A> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn contains(&self, uuid: &AssetUUID) -> bool {
+        Assets::contains(self, uuid)
+    }
+
+    fn sweep_unused(&mut self, now: Instant, grace: Duration, limit: usize) -> usize {
+        // The returned handles are the last ones, so dropping them frees
+        // the assets.
+        Assets::sweep_unused(self, now, grace, limit).len()
+    }
+}
+
+/// Decodes a prefetched payload and caches it in the storage of its type.
+type PrefetchStore = fn(
+    &mut HashMap<TypeId, Box<dyn AssetStorage>>,
+    &DecoderRegistry,
+    &AssetUUID,
+    &str,
+    &[u8],
+    AssetLoadQuality,
+) -> Result<()>;
+
+fn store_prefetched<A: Asset>(
+    storages: &mut HashMap<TypeId, Box<dyn AssetStorage>>,
+    decoders: &DecoderRegistry,
+    uuid: &AssetUUID,
+    type_name: &str,
+    bytes: &[u8],
+    quality: AssetLoadQuality,
+) -> Result<()> {
+    let asset: A = decoders.decode_with_quality::<A>(type_name, bytes, quality)?;
+    let assets = storages
+        .entry(TypeId::of::<A>())
+        .or_insert_with(|| Box::new(Assets::<A>::new()))
+        .as_any_mut()
+        .downcast_mut::<Assets<A>>()
+        .ok_or_else(|| anyhow!("Mismatched asset storage type"))?;
+    assets.insert(*uuid, AssetHandle::new(asset));
+    Ok(())
+}
 
 /// The asset management service.
 ///
@@ -36,14 +105,28 @@ use crate::vfs::VirtualFileSystem;
 /// Registered in `ServiceRegistry` and accessed by game code via `AppContext`.
 pub struct AssetService {
     vfs: VirtualFileSystem,
-    io: Box<dyn AssetIo>,
+    /// One reader per mounted archive, indexed like the VFS layers.
+    io: Vec<Box<dyn AssetIo>>,
     decoders: DecoderRegistry,
-    storages: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    storages: HashMap<TypeId, Box<dyn AssetStorage>>,
+    /// How prefetches store each registered asset type, by type name.
+    prefetch_stores: HashMap<String, PrefetchStore>,
+    /// Assets waiting to be prefetched, oldest first.
+    prefetch_queue: VecDeque<AssetUUID>,
+    /// Queued prefetches something is waiting on, read in full whatever
+    /// the load quality.
+    required_prefetches: HashSet<AssetUUID>,
+    /// Prefetches that failed since they were last queued.
+    failed_prefetches: HashSet<AssetUUID>,
+    load_quality: AssetLoadQuality,
     load_count: usize,
+    unload_count: usize,
+    prefetch_count: usize,
 }
 
 impl AssetService {
-    /// Creates a new `AssetService`.
+    /// Creates a new `AssetService`, with every decoder submitted through
+    /// an [`AssetDecoderRegistration`] already registered.
     pub fn new(
         index_bytes: &[u8],
         io: Box<dyn AssetIo>,
@@ -52,13 +135,55 @@ impl AssetService {
         let vfs = VirtualFileSystem::new(index_bytes)
             .context("Failed to initialize VirtualFileSystem from index bytes")?;
 
-        Ok(Self {
+        let mut service = Self {
             vfs,
-            io,
+            io: vec![io],
             decoders: DecoderRegistry::new(metrics_registry),
             storages: HashMap::new(),
+            prefetch_stores: HashMap::new(),
+            prefetch_queue: VecDeque::new(),
+            required_prefetches: HashSet::new(),
+            failed_prefetches: HashSet::new(),
+            load_quality: AssetLoadQuality::Full,
             load_count: 0,
-        })
+            unload_count: 0,
+            prefetch_count: 0,
+        };
+        for registration in inventory::iter::<AssetDecoderRegistration> {
+            (registration.register)(&mut service);
+        }
+        Ok(service)
+    }
+
+    /// Mounts a patch archive over the base and every patch mounted so far.
+    ///
+    /// `io` reads the patch's own packfile. Mount patches before loading:
+    /// assets already cached keep their previous bytes.
+    pub fn mount_patch(
+        &mut self,
+        index_bytes: &[u8],
+        info: ArchiveInfo,
+        io: Box<dyn AssetIo>,
+    ) -> Result<()> {
+        let layer = self
+            .vfs
+            .mount_patch(index_bytes, info)
+            .map_err(|e| anyhow!("Failed to mount patch archive: {:?}", e))?;
+        debug_assert_eq!(layer, self.io.len());
+        self.io.push(io);
+        Ok(())
+    }
+
+    /// Returns the version of the mounted asset content.
+    pub fn content_version(&self) -> u32 {
+        self.vfs.version()
+    }
+
+    /// Sets the platform and tier whose asset variants are loaded.
+    ///
+    /// Assets already cached keep the variant they were loaded with.
+    pub fn set_platform_target(&mut self, target: PlatformTarget) {
+        self.vfs.set_target(target);
     }
 
     /// Registers a decoder for a specific asset type.
@@ -68,55 +193,391 @@ impl AssetService {
         decoder: impl super::decoder::AssetDecoder<A> + Send + Sync + 'static,
     ) {
         self.decoders.register::<A>(type_name, decoder);
+        self.prefetch_stores
+            .insert(type_name.to_string(), store_prefetched::<A>);
     }
 
-    /// Loads, decodes, and returns a typed handle to an asset.
-    pub fn load<A: Asset>(&mut self, uuid: &AssetUUID) -> Result<AssetHandle<A>> {
-        let type_id = TypeId::of::<A>();
-
-        // Get or create the typed storage.
-        let storage = self
-            .storages
-            .entry(type_id)
-            .or_insert_with(|| Box::new(Assets::<A>::new()));
+    /// Sets the quality of the loads that follow.
+    ///
+    /// Assets already cached keep the quality they were loaded with.
+    pub fn set_load_quality(&mut self, quality: AssetLoadQuality) {
+        self.load_quality = quality;
+    }
 
-        let assets = storage
-            .downcast_mut::<Assets<A>>()
-            .ok_or_else(|| anyhow!("Mismatched asset storage type"))?;
+    /// Returns the quality assets are currently loaded at.
+    pub fn load_quality(&self) -> AssetLoadQuality {
+        self.load_quality
+    }
 
+    /// Loads, decodes, and returns a typed handle to an asset.
+    pub fn load<A: Asset>(&mut self, uuid: &AssetUUID) -> Result<AssetHandle<A>> {
         // Return cached handle if already loaded.
-        if let Some(handle) = assets.get(uuid) {
+        if let Some(handle) = self.typed_storage::<A>()?.get(uuid) {
             return Ok(handle.clone());
         }
 
-        // VFS lookup → IO → Decode → Store
-        let metadata = self
-            .vfs
-            .get_metadata(uuid)
-            .ok_or_else(|| anyhow!("Asset with UUID {:?} not found in VFS", uuid))?;
+        // VFS lookup → IO (+ patch deltas) → Decode → Store
+        let payload = Self::read_payload(&self.vfs, &mut self.io, uuid)?;
+        self.load_payload(payload)
+    }
+
+    /// Reads an asset's bytes on `executor`'s blocking threads, patch
+    /// deltas applied.
+    ///
+    /// Only the index lookup happens now, so a large payload does not stall
+    /// the frame. Hand the result to [`load_payload`](Self::load_payload) to
+    /// decode and cache it. Fails at once if the asset is unknown or one of
+    /// its archives has no [`reader`](AssetIo::reader).
+    pub fn read_async(
+        &self,
+        uuid: &AssetUUID,
+        executor: &(dyn Executor + 'static),
+    ) -> Result<Task<Result<AssetPayload>>> {
+        let resolved = Self::resolve(&self.vfs, uuid)?;
+        let chain = resolved
+            .chain
+            .iter()
+            .rev()
+            .map(|(layer, source)| {
+                let reader = self.io[*layer]
+                    .reader()
+                    .ok_or_else(|| anyhow!("Archive layer {} cannot be read off-thread", layer))?;
+                Ok((reader, (*source).clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let uuid = *uuid;
+        let type_name = resolved.metadata.asset_type_name.clone();
 
-        let source = metadata
-            .variants
-            .get("default")
-            .ok_or_else(|| anyhow!("Asset {:?} has no 'default' variant", uuid))?;
+        Ok(executor.spawn_blocking(move || -> Result<AssetPayload> {
+            let mut bytes = AssetBytes::default();
+            for (reader, source) in &chain {
+                bytes = Self::stack_layer(&uuid, bytes, source, reader.read(source)?)?;
+            }
+            Ok(AssetPayload {
+                uuid,
+                type_name,
+                bytes,
+            })
+        }))
+    }
+
+    /// Decodes and caches a payload from [`read_async`](Self::read_async),
+    /// then returns a typed handle like [`load`](Self::load).
+    ///
+    /// If the asset was cached in the meantime, the payload is dropped and
+    /// the cached handle returned.
+    pub fn load_payload<A: Asset>(&mut self, payload: AssetPayload) -> Result<AssetHandle<A>> {
+        if let Some(handle) = self.typed_storage::<A>()?.get(&payload.uuid) {
+            return Ok(handle.clone());
+        }
 
-        let bytes = self.io.load_bytes(source)?;
-        let asset: A = self
-            .decoders
-            .decode::<A>(&metadata.asset_type_name, &bytes)?;
+        // Metadata-only applies to prefetches: an explicit load needs the
+        // payload, so it decodes it in full.
+        let quality = match self.load_quality {
+            AssetLoadQuality::MetadataOnly => AssetLoadQuality::Full,
+            quality => quality,
+        };
+        let asset: A =
+            self.decoders
+                .decode_with_quality::<A>(&payload.type_name, &payload.bytes, quality)?;
 
         let handle = AssetHandle::new(asset);
-        assets.insert(*uuid, handle.clone());
+        self.typed_storage::<A>()?
+            .insert(payload.uuid, handle.clone());
 
         self.load_count += 1;
         Ok(handle)
     }
 
+    /// Returns the storage of `A` assets, creating it on first use.
+    fn typed_storage<A: Asset>(&mut self) -> Result<&mut Assets<A>> {
+        self.storages
+            .entry(TypeId::of::<A>())
+            .or_insert_with(|| Box::new(Assets::<A>::new()))
+            .as_any_mut()
+            .downcast_mut::<Assets<A>>()
+            .ok_or_else(|| anyhow!("Mismatched asset storage type"))
+    }
+
+    /// Looks up the variant of an asset to read.
+    fn resolve<'a>(vfs: &'a VirtualFileSystem, uuid: &AssetUUID) -> Result<ResolvedAsset<'a>> {
+        if vfs.get_metadata(uuid).is_none() {
+            return Err(anyhow!("Asset with UUID {:?} not found in VFS", uuid));
+        }
+        vfs.resolve(uuid).ok_or_else(|| {
+            anyhow!(
+                "Asset {:?} has no variant for {:?} nor a 'default' one",
+                uuid,
+                vfs.target()
+            )
+        })
+    }
+
+    /// Reads the bytes of an asset variant, patch deltas applied, along
+    /// with its type name.
+    fn read_payload(
+        vfs: &VirtualFileSystem,
+        io: &mut [Box<dyn AssetIo>],
+        uuid: &AssetUUID,
+    ) -> Result<AssetPayload> {
+        let resolved = Self::resolve(vfs, uuid)?;
+
+        // Read the full copy at the bottom of the chain, then replay each
+        // patch's delta on top of it.
+        let mut bytes = AssetBytes::default();
+        for (layer, source) in resolved.chain.iter().rev() {
+            let raw = io[*layer].load_shared(source)?;
+            bytes = Self::stack_layer(uuid, bytes, source, raw)?;
+        }
+        Ok(AssetPayload {
+            uuid: *uuid,
+            type_name: resolved.metadata.asset_type_name.clone(),
+            bytes,
+        })
+    }
+
+    /// Puts the bytes `raw` read from one layer on top of the lower layers'
+    /// `bytes`: a delta patches them, full bytes replace them.
+    fn stack_layer(
+        uuid: &AssetUUID,
+        bytes: AssetBytes,
+        source: &AssetSource,
+        raw: AssetBytes,
+    ) -> Result<AssetBytes> {
+        match source {
+            AssetSource::Delta { .. } => AssetDelta::decode(&raw)
+                .and_then(|delta| delta.apply(&bytes))
+                .map(AssetBytes::from)
+                .with_context(|| format!("Failed to patch asset {:?}", uuid)),
+            _ => Ok(raw),
+        }
+    }
+
+    /// Queues an asset to be loaded ahead of use by
+    /// [`process_prefetch`](Self::process_prefetch).
+    ///
+    /// Returns `false` if the asset is unknown, already queued or already
+    /// cached.
+    pub fn prefetch(&mut self, uuid: AssetUUID) -> bool {
+        if self.vfs.get_metadata(&uuid).is_none()
+            || self
+                .storages
+                .values()
+                .any(|storage| storage.contains(&uuid))
+            || self.prefetch_queue.contains(&uuid)
+        {
+            return false;
+        }
+        self.failed_prefetches.remove(&uuid);
+        self.prefetch_queue.push_back(uuid);
+        true
+    }
+
+    /// Queues an asset like [`prefetch`](Self::prefetch), for a caller that
+    /// needs its payload: it is read in full even under
+    /// [`AssetLoadQuality::MetadataOnly`].
+    ///
+    /// An asset already queued as a plain prefetch is upgraded. Returns
+    /// `false` if the asset is unknown or already cached.
+    pub fn prefetch_required(&mut self, uuid: AssetUUID) -> bool {
+        if !self.prefetch(uuid) && !self.prefetch_queue.contains(&uuid) {
+            return false;
+        }
+        self.required_prefetches.insert(uuid);
+        true
+    }
+
+    /// Returns the cached copy of an asset, without loading it.
+    pub fn cached<A: Asset>(&self, uuid: &AssetUUID) -> Option<AssetHandle<A>> {
+        self.storages
+            .get(&TypeId::of::<A>())?
+            .as_any()
+            .downcast_ref::<Assets<A>>()?
+            .get(uuid)
+            .cloned()
+    }
+
+    /// Returns `true` if an asset is cached, whatever its type.
+    pub fn is_cached(&self, uuid: &AssetUUID) -> bool {
+        self.storages.values().any(|storage| storage.contains(uuid))
+    }
+
+    /// Returns `true` if the last prefetch of an asset failed. Queuing it
+    /// again clears the failure.
+    pub fn prefetch_failed(&self, uuid: &AssetUUID) -> bool {
+        self.failed_prefetches.contains(uuid)
+    }
+
+    /// Estimates what the queued prefetches will read from disk.
+    ///
+    /// Loose files are measured on disk; a patch delta counts its encoded
+    /// size on top of the copy it applies to.
+    pub fn prefetch_estimate(&self) -> PrefetchEstimate {
+        let mut estimate = PrefetchEstimate {
+            requests: self.prefetch_queue.len(),
+            ..Default::default()
+        };
+        for uuid in &self.prefetch_queue {
+            let Some(resolved) = self.vfs.resolve(uuid) else {
+                continue;
+            };
+            let bytes: u64 = resolved
+                .chain
+                .iter()
+                .map(|(_, source)| match source {
+                    AssetSource::Packed { size, .. } | AssetSource::Delta { size, .. } => *size,
+                    AssetSource::Path(path) => std::fs::metadata(path).map_or(0, |m| m.len()),
+                })
+                .sum();
+            estimate.io_bytes += bytes;
+            if resolved.metadata.asset_type_name == "texture" {
+                estimate.texture_bytes += bytes;
+            }
+        }
+        estimate
+    }
+
+    /// Works through up to `limit` queued prefetches at the current load
+    /// quality.
+    ///
+    /// Under [`AssetLoadQuality::MetadataOnly`] a prefetch only resolves
+    /// the asset's variant, unless it was
+    /// [required](Self::prefetch_required); otherwise its payload is read,
+    /// decoded and cached so the next `load` returns at once. A prefetch
+    /// that fails is logged, dropped and reported by
+    /// [`prefetch_failed`](Self::prefetch_failed). Returns the number of
+    /// prefetches completed.
+    pub fn process_prefetch(&mut self, limit: usize) -> usize {
+        let mut done = 0;
+        while done < limit {
+            let Some(uuid) = self.prefetch_queue.pop_front() else {
+                break;
+            };
+            let required = self.required_prefetches.remove(&uuid);
+            match self.prefetch_one(&uuid, required) {
+                Ok(()) => done += 1,
+                Err(e) => {
+                    log::warn!("Failed to prefetch asset {:?}: {}", uuid, e);
+                    self.failed_prefetches.insert(uuid);
+                }
+            }
+        }
+        self.prefetch_count += done;
+        done
+    }
+
+    fn prefetch_one(&mut self, uuid: &AssetUUID, required: bool) -> Result<()> {
+        let quality = match self.load_quality {
+            AssetLoadQuality::MetadataOnly if required => AssetLoadQuality::Full,
+            quality => quality,
+        };
+        if !quality.prefetches_payload() {
+            return self
+                .vfs
+                .resolve(uuid)
+                .map(|_| ())
+                .ok_or_else(|| anyhow!("Asset {:?} has no variant to prefetch", uuid));
+        }
+        let payload = Self::read_payload(&self.vfs, &mut self.io, uuid)?;
+        let store = self
+            .prefetch_stores
+            .get(&payload.type_name)
+            .ok_or_else(|| {
+                anyhow!(
+                    "No decoder registered for asset type '{}'",
+                    payload.type_name
+                )
+            })?;
+        store(
+            &mut self.storages,
+            &self.decoders,
+            uuid,
+            &payload.type_name,
+            &payload.bytes,
+            quality,
+        )
+    }
+
+    /// Returns the index entry of an asset.
+    pub fn metadata(&self, uuid: &AssetUUID) -> Option<&AssetMetadata> {
+        self.vfs.get_metadata(uuid)
+    }
+
+    /// Reads the undecoded bytes of an asset, patch deltas applied.
+    ///
+    /// For payloads no decoder produces an `Asset` from, such as scene files
+    /// read by tooling. Nothing is cached.
+    pub fn load_bytes(&mut self, uuid: &AssetUUID) -> Result<Vec<u8>> {
+        Self::read_payload(&self.vfs, &mut self.io, uuid).map(|payload| payload.bytes.into_vec())
+    }
+
+    /// Loads an asset like [`load`](Self::load), but returns a handle that
+    /// does not keep it loaded.
+    pub fn load_weak<A: Asset>(&mut self, uuid: &AssetUUID) -> Result<WeakHandle<A>> {
+        Ok(self.load::<A>(uuid)?.downgrade(*uuid))
+    }
+
+    /// Returns a strong handle to the asset `weak` refers to, loading it
+    /// again if it was unloaded since.
+    ///
+    /// `weak` is pointed at the reloaded copy, so later
+    /// [`WeakHandle::get`] calls find it without going through the service.
+    pub fn upgrade<A: Asset>(&mut self, weak: &mut WeakHandle<A>) -> Result<AssetHandle<A>> {
+        if let Some(handle) = weak.get() {
+            return Ok(handle);
+        }
+        let handle = self.load::<A>(&weak.uuid())?;
+        weak.rebind(&handle);
+        Ok(handle)
+    }
+
+    /// Drops the cached copy of an asset so its memory can be reclaimed
+    /// once every outstanding handle is gone. The next `load` reads it again.
+    ///
+    /// Returns `true` if the asset was cached.
+    pub fn unload<A: Asset>(&mut self, uuid: &AssetUUID) -> bool {
+        self.storages
+            .get_mut(&TypeId::of::<A>())
+            .and_then(|storage| storage.as_any_mut().downcast_mut::<Assets<A>>())
+            .is_some_and(|assets| assets.remove(uuid).is_some())
+    }
+
+    /// Unloads up to `limit` cached assets, of any type, that have had no
+    /// handle outside the service for at least `grace`.
+    ///
+    /// Meant to be called regularly: each call also notes which assets
+    /// became unused, and their grace period starts then. Returns the
+    /// number of assets unloaded.
+    pub fn unload_unused(&mut self, now: Instant, grace: Duration, limit: usize) -> usize {
+        let mut unloaded = 0;
+        for storage in self.storages.values_mut() {
+            unloaded += storage.sweep_unused(now, grace, limit - unloaded);
+        }
+        self.unload_count += unloaded;
+        unloaded
+    }
+
     /// Returns the total number of assets loaded so far.
     pub fn load_count(&self) -> usize {
         self.load_count
     }
 
+    /// Returns the total number of assets unloaded by
+    /// [`unload_unused`](Self::unload_unused) so far.
+    pub fn unload_count(&self) -> usize {
+        self.unload_count
+    }
+
+    /// Returns the total number of prefetches completed so far.
+    pub fn prefetch_count(&self) -> usize {
+        self.prefetch_count
+    }
+
+    /// Returns the number of prefetches still queued.
+    pub fn pending_prefetch_count(&self) -> usize {
+        self.prefetch_queue.len()
+    }
+
     /// Returns the number of cached asset type storages.
     pub fn cached_type_count(&self) -> usize {
         self.storages.len()
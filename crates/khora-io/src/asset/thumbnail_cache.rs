@@ -0,0 +1,137 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Thumbnails stored next to an archive's index.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use khora_core::asset::{AssetUUID, Thumbnail};
+
+/// Name of the thumbnail file inside an archive directory.
+pub const THUMBNAIL_FILE: &str = "thumbnails.bin";
+
+/// Thumbnails of an archive's assets, by UUID.
+///
+/// Written at pack time as `thumbnails.bin` next to `index.bin`, and filled
+/// on demand at runtime by whoever renders the missing ones.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailCache {
+    thumbnails: HashMap<AssetUUID, Thumbnail>,
+}
+
+impl ThumbnailCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the cache of the archive in `dir`. An archive without one
+    /// yields an empty cache.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self> {
+        let path = dir.as_ref().join(THUMBNAIL_FILE);
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        let bytes = std::fs::read(&path)
+            .with_context(|| format!("Failed to read thumbnails at '{}'", path.display()))?;
+        Self::from_bytes(&bytes)
+            .with_context(|| format!("Failed to decode thumbnails at '{}'", path.display()))
+    }
+
+    /// Writes the cache into the archive directory `dir`.
+    pub fn save(&self, dir: impl AsRef<Path>) -> Result<()> {
+        let path = dir.as_ref().join(THUMBNAIL_FILE);
+        std::fs::write(&path, self.to_bytes()?)
+            .with_context(|| format!("Failed to write thumbnails to '{}'", path.display()))
+    }
+
+    /// Decodes a cache written by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (thumbnails, _) =
+            bincode::serde::decode_from_slice(bytes, bincode::config::standard())?;
+        Ok(Self { thumbnails })
+    }
+
+    /// Encodes the cache.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(bincode::serde::encode_to_vec(
+            &self.thumbnails,
+            bincode::config::standard(),
+        )?)
+    }
+
+    /// Returns the thumbnail of an asset.
+    pub fn get(&self, uuid: &AssetUUID) -> Option<&Thumbnail> {
+        self.thumbnails.get(uuid)
+    }
+
+    /// Returns `true` if the asset has a thumbnail.
+    pub fn contains(&self, uuid: &AssetUUID) -> bool {
+        self.thumbnails.contains_key(uuid)
+    }
+
+    /// Stores the thumbnail of an asset, returning the one it replaces.
+    pub fn insert(&mut self, uuid: AssetUUID, thumbnail: Thumbnail) -> Option<Thumbnail> {
+        self.thumbnails.insert(uuid, thumbnail)
+    }
+
+    /// Drops the thumbnail of an asset, e.g. once its source changed.
+    pub fn remove(&mut self, uuid: &AssetUUID) -> Option<Thumbnail> {
+        self.thumbnails.remove(uuid)
+    }
+
+    /// Returns the number of thumbnails.
+    pub fn len(&self) -> usize {
+        self.thumbnails.len()
+    }
+
+    /// Returns `true` if the cache holds no thumbnail.
+    pub fn is_empty(&self) -> bool {
+        self.thumbnails.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thumbnail(value: u8) -> Thumbnail {
+        Thumbnail::new(2, 2, vec![value; 16]).unwrap()
+    }
+
+    #[test]
+    fn test_missing_file_is_an_empty_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ThumbnailCache::load(dir.path()).unwrap();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let (a, b) = (AssetUUID::new_v5("a.obj"), AssetUUID::new_v5("b.kscene"));
+        let mut cache = ThumbnailCache::new();
+        cache.insert(a, thumbnail(10));
+        cache.insert(b, thumbnail(20));
+        assert_eq!(cache.insert(b, thumbnail(30)), Some(thumbnail(20)));
+        cache.save(dir.path()).unwrap();
+
+        let loaded = ThumbnailCache::load(dir.path()).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded.get(&a), Some(&thumbnail(10)));
+        assert_eq!(loaded.get(&b).and_then(|t| t.pixel(1, 1)), Some([30; 4]));
+    }
+}
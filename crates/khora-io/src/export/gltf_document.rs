@@ -0,0 +1,289 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The subset of the glTF 2.0 JSON schema written by the exporter.
+//!
+//! Only the properties Khora has data for are modelled; everything else is
+//! left out of the document, which the specification allows.
+
+use serde::Serialize;
+
+/// Component type of an accessor holding `f32`s.
+pub(crate) const FLOAT: u32 = 5126;
+/// Component type of an accessor holding `u32`s.
+pub(crate) const UNSIGNED_INT: u32 = 5125;
+/// Buffer view target of vertex attributes.
+pub(crate) const ARRAY_BUFFER: u32 = 34962;
+/// Buffer view target of indices.
+pub(crate) const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Extension carrying the lights of the scene.
+pub(crate) const KHR_LIGHTS_PUNCTUAL: &str = "KHR_lights_punctual";
+/// Extension marking materials that ignore lighting.
+pub(crate) const KHR_MATERIALS_UNLIT: &str = "KHR_materials_unlit";
+
+/// The root of a glTF document, with a single scene.
+///
+/// Its buffer is declared when the document is encoded, since `.gltf` and
+/// `.glb` files reference it differently.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Document {
+    pub asset: AssetInfo,
+    pub scene: u32,
+    pub scenes: Vec<Scene>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub nodes: Vec<Node>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub meshes: Vec<Mesh>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub materials: Vec<Material>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cameras: Vec<Camera>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub accessors: Vec<Accessor>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub buffer_views: Vec<BufferView>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions_used: Vec<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<DocumentExtensions>,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self {
+            asset: AssetInfo::default(),
+            scene: 0,
+            scenes: vec![Scene::default()],
+            nodes: Vec::new(),
+            meshes: Vec::new(),
+            materials: Vec::new(),
+            cameras: Vec::new(),
+            accessors: Vec::new(),
+            buffer_views: Vec::new(),
+            extensions_used: Vec::new(),
+            extensions: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct AssetInfo {
+    pub version: &'static str,
+    pub generator: &'static str,
+}
+
+impl Default for AssetInfo {
+    fn default() -> Self {
+        Self {
+            version: "2.0",
+            generator: concat!("Khora Engine ", env!("CARGO_PKG_VERSION")),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct Scene {
+    pub nodes: Vec<u32>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct Node {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<[f32; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<[f32; 4]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<[f32; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matrix: Option<[f32; 16]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mesh: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<NodeExtensions>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct NodeExtensions {
+    #[serde(rename = "KHR_lights_punctual")]
+    pub lights_punctual: NodeLight,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct NodeLight {
+    pub light: u32,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct DocumentExtensions {
+    #[serde(rename = "KHR_lights_punctual")]
+    pub lights_punctual: Lights,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct Lights {
+    pub lights: Vec<Light>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Light {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub color: [f32; 3],
+    pub intensity: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spot: Option<Spot>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Spot {
+    pub inner_cone_angle: f32,
+    pub outer_cone_angle: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Mesh {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub primitives: Vec<Primitive>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Primitive {
+    pub attributes: Attributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indices: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material: Option<u32>,
+    pub mode: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<Attributes>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub(crate) struct Attributes {
+    #[serde(rename = "POSITION", skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(rename = "NORMAL", skip_serializing_if = "Option::is_none")]
+    pub normal: Option<u32>,
+    #[serde(rename = "TANGENT", skip_serializing_if = "Option::is_none")]
+    pub tangent: Option<u32>,
+    #[serde(rename = "TEXCOORD_0", skip_serializing_if = "Option::is_none")]
+    pub tex_coord: Option<u32>,
+    #[serde(rename = "COLOR_0", skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Material {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub pbr_metallic_roughness: PbrMetallicRoughness,
+    pub emissive_factor: [f32; 3],
+    pub alpha_mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_cutoff: Option<f32>,
+    pub double_sided: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<MaterialExtensions>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PbrMetallicRoughness {
+    pub base_color_factor: [f32; 4],
+    pub metallic_factor: f32,
+    pub roughness_factor: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct MaterialExtensions {
+    #[serde(rename = "KHR_materials_unlit")]
+    pub unlit: Unlit,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Unlit {}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Camera {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub perspective: Option<Perspective>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orthographic: Option<Orthographic>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Perspective {
+    #[serde(rename = "aspectRatio")]
+    pub aspect_ratio: f32,
+    pub yfov: f32,
+    pub znear: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zfar: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Orthographic {
+    pub xmag: f32,
+    pub ymag: f32,
+    pub znear: f32,
+    pub zfar: f32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Accessor {
+    pub buffer_view: u32,
+    pub component_type: u32,
+    pub count: u32,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<Vec<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BufferView {
+    pub buffer: u32,
+    pub byte_offset: u32,
+    pub byte_length: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Buffer {
+    pub byte_length: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
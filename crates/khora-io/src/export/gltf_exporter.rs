@@ -0,0 +1,560 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports parts of a `World` to glTF 2.0.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use base64::Engine;
+use khora_core::asset::{AlphaMode, AssetUUID, StandardMaterial, UnlitMaterial};
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Quaternion, Vec3};
+use khora_core::renderer::api::pipeline::enums::PrimitiveTopology;
+use khora_core::renderer::api::scene::Mesh as KhoraMesh;
+use khora_core::renderer::light::LightType;
+use khora_data::ecs::{
+    Camera as CameraComponent, Children, GlobalTransform, HandleComponent, IncludeDisabled,
+    Light as LightComponent, MaterialComponent, Name, Parent, ProjectionType, Transform, Without,
+    World,
+};
+
+use super::gltf_document::*;
+
+/// Magic number opening a GLB file (`glTF`).
+const GLB_MAGIC: u32 = 0x4654_6C67;
+/// Type of the GLB chunk holding the JSON document.
+const GLB_JSON_CHUNK: u32 = 0x4E4F_534A;
+/// Type of the GLB chunk holding the binary buffer.
+const GLB_BIN_CHUNK: u32 = 0x004E_4942;
+
+/// An error raised while exporting to glTF.
+#[derive(Debug)]
+pub enum GltfExportError {
+    /// The entity to export is not alive.
+    EntityNotFound(EntityId),
+    /// The document could not be encoded as JSON.
+    Encode(String),
+    /// The file could not be written.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for GltfExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EntityNotFound(entity) => write!(f, "cannot export dead entity {entity:?}"),
+            Self::Encode(e) => write!(f, "failed to encode glTF document: {e}"),
+            Self::Io(e) => write!(f, "failed to write glTF file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for GltfExportError {}
+
+impl From<std::io::Error> for GltfExportError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// The accessors of a mesh already written to the buffer.
+#[derive(Clone)]
+struct EncodedMesh {
+    attributes: Attributes,
+    indices: Option<u32>,
+    targets: Vec<Attributes>,
+    mode: u32,
+}
+
+/// Builds a glTF document from entity subtrees of one or more worlds.
+///
+/// Each exported entity becomes a node carrying its `Name`, local
+/// `Transform`, mesh, material, camera and light, with its `Children` as
+/// child nodes. Meshes and materials are written once per asset, however many
+/// entities share them.
+///
+/// ```rust,ignore
+/// let mut exporter = GltfExporter::new();
+/// exporter.add_subtree(&world, ship)?;
+/// exporter.write(Path::new("ship.glb"))?;
+/// ```
+///
+/// Textures, skins and animations are not exported. Materials other than
+/// [`StandardMaterial`] and [`UnlitMaterial`] are approximated from their
+/// base and emissive colors.
+#[derive(Default)]
+pub struct GltfExporter {
+    document: Document,
+    buffer: Vec<u8>,
+    encoded_meshes: HashMap<AssetUUID, Option<EncodedMesh>>,
+    meshes: HashMap<(AssetUUID, Option<AssetUUID>), u32>,
+    materials: HashMap<AssetUUID, u32>,
+}
+
+impl GltfExporter {
+    /// Creates an exporter with an empty scene.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `root` and all its descendants as a root node of the scene.
+    ///
+    /// If `root` has a parent, its node is placed with its `GlobalTransform`
+    /// so it keeps its place in the world. Returns the index of the node.
+    pub fn add_subtree(&mut self, world: &World, root: EntityId) -> Result<u32, GltfExportError> {
+        if !world.is_alive(root) {
+            return Err(GltfExportError::EntityNotFound(root));
+        }
+        let node = self.add_node(world, root, world.get::<Parent>(root).is_some());
+        self.document.scenes[0].nodes.push(node);
+        Ok(node)
+    }
+
+    /// Adds every root entity of `world` that has a `Transform`, disabled
+    /// ones included, together with their descendants. Returns how many roots
+    /// were added.
+    pub fn add_world(&mut self, world: &World) -> usize {
+        let roots: Vec<EntityId> = world
+            .query::<(EntityId, &Transform, Without<Parent>, IncludeDisabled)>()
+            .map(|(entity, ..)| entity)
+            .collect();
+        for &root in &roots {
+            let node = self.add_node(world, root, false);
+            self.document.scenes[0].nodes.push(node);
+        }
+        roots.len()
+    }
+
+    /// Returns the number of nodes exported so far.
+    pub fn node_count(&self) -> usize {
+        self.document.nodes.len()
+    }
+
+    /// Returns the number of meshes exported so far.
+    pub fn mesh_count(&self) -> usize {
+        self.document.meshes.len()
+    }
+
+    /// Encodes the scene as a binary `.glb` file.
+    pub fn to_glb(&self) -> Result<Vec<u8>, GltfExportError> {
+        let mut json = self.to_json(None)?.into_bytes();
+        pad_to_four(&mut json, b' ');
+        let mut bin = self.buffer.clone();
+        pad_to_four(&mut bin, 0);
+
+        let bin_chunk_len = if bin.is_empty() { 0 } else { 8 + bin.len() };
+        let total = 12 + 8 + json.len() + bin_chunk_len;
+        let mut glb = Vec::with_capacity(total);
+        glb.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&(total as u32).to_le_bytes());
+        glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        glb.extend_from_slice(&GLB_JSON_CHUNK.to_le_bytes());
+        glb.extend_from_slice(&json);
+        if !bin.is_empty() {
+            glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+            glb.extend_from_slice(&GLB_BIN_CHUNK.to_le_bytes());
+            glb.extend_from_slice(&bin);
+        }
+        Ok(glb)
+    }
+
+    /// Encodes the scene as a `.gltf` JSON file, with the buffer embedded as
+    /// a base64 data URI.
+    pub fn to_gltf(&self) -> Result<String, GltfExportError> {
+        let uri = format!(
+            "data:application/octet-stream;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(&self.buffer)
+        );
+        self.to_json(Some(uri))
+    }
+
+    /// Writes the scene to `path`: a `.glb` file if the extension is `glb`,
+    /// a `.gltf` file otherwise.
+    pub fn write(&self, path: &Path) -> Result<(), GltfExportError> {
+        let is_glb = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("glb"));
+        let bytes = if is_glb {
+            self.to_glb()?
+        } else {
+            self.to_gltf()?.into_bytes()
+        };
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Serializes the document, declaring the buffer with `uri` (`None` for
+    /// the GLB binary chunk).
+    fn to_json(&self, uri: Option<String>) -> Result<String, GltfExportError> {
+        let mut json = serde_json::to_value(&self.document)
+            .map_err(|e| GltfExportError::Encode(e.to_string()))?;
+        if !self.buffer.is_empty() {
+            let buffer = Buffer {
+                byte_length: self.buffer.len() as u32,
+                uri,
+            };
+            json["buffers"] = serde_json::to_value([buffer])
+                .map_err(|e| GltfExportError::Encode(e.to_string()))?;
+        }
+        serde_json::to_string(&json).map_err(|e| GltfExportError::Encode(e.to_string()))
+    }
+
+    fn add_node(&mut self, world: &World, entity: EntityId, world_space: bool) -> u32 {
+        let mut node = Node {
+            name: world.get::<Name>(entity).map(|name| name.0.clone()),
+            ..Default::default()
+        };
+        match (world_space, world.get::<GlobalTransform>(entity)) {
+            (true, Some(global)) => {
+                node.matrix = Some(bytemuck::cast(global.0.to_matrix().cols));
+            }
+            _ => {
+                let transform = world.get::<Transform>(entity).copied().unwrap_or_default();
+                node.translation = Some(vec3(transform.translation));
+                node.rotation = Some(quat(transform.rotation));
+                node.scale = Some(vec3(transform.scale));
+            }
+        }
+        if let Some(mesh) = world.get::<HandleComponent<KhoraMesh>>(entity) {
+            let material = world.get::<MaterialComponent>(entity);
+            node.mesh = self.mesh(mesh, material);
+        }
+        if let Some(camera) = world.get::<CameraComponent>(entity) {
+            node.camera = Some(self.camera(camera));
+        }
+
+        let index = self.document.nodes.len() as u32;
+        self.document.nodes.push(node);
+
+        if let Some(light) = world.get::<LightComponent>(entity).filter(|l| l.enabled) {
+            let (light, direction) = self.light(light);
+            let rotation = direction
+                .map(|d| Quaternion::from_rotation_arc(Vec3::new(0.0, 0.0, -1.0), d))
+                .filter(|r| *r != Quaternion::IDENTITY);
+            let extensions = Some(NodeExtensions {
+                lights_punctual: NodeLight { light },
+            });
+            match rotation {
+                // glTF lights shine down their node's -Z, so an aimed light
+                // gets a child node turned towards its direction.
+                Some(rotation) => {
+                    let child = self.document.nodes.len() as u32;
+                    self.document.nodes.push(Node {
+                        rotation: Some(quat(rotation)),
+                        extensions,
+                        ..Default::default()
+                    });
+                    self.document.nodes[index as usize].children.push(child);
+                }
+                None => self.document.nodes[index as usize].extensions = extensions,
+            }
+        }
+
+        if let Some(children) = world.get::<Children>(entity) {
+            for &child in &children.0 {
+                if world.is_alive(child) {
+                    let child = self.add_node(world, child, false);
+                    self.document.nodes[index as usize].children.push(child);
+                }
+            }
+        }
+        index
+    }
+
+    fn mesh(
+        &mut self,
+        mesh: &HandleComponent<KhoraMesh>,
+        material: Option<&MaterialComponent>,
+    ) -> Option<u32> {
+        let key = (mesh.uuid, material.map(|m| m.uuid));
+        if let Some(&index) = self.meshes.get(&key) {
+            return Some(index);
+        }
+        let encoded = match self.encoded_meshes.get(&mesh.uuid) {
+            Some(encoded) => encoded.clone(),
+            None => {
+                let encoded = self.encode_mesh(&mesh.handle);
+                self.encoded_meshes.insert(mesh.uuid, encoded.clone());
+                encoded
+            }
+        }?;
+        let material = material.map(|m| self.material(m));
+        let index = self.document.meshes.len() as u32;
+        self.document.meshes.push(Mesh {
+            name: None,
+            primitives: vec![Primitive {
+                attributes: encoded.attributes,
+                indices: encoded.indices,
+                material,
+                mode: encoded.mode,
+                targets: encoded.targets,
+            }],
+        });
+        self.meshes.insert(key, index);
+        Some(index)
+    }
+
+    fn encode_mesh(&mut self, mesh: &KhoraMesh) -> Option<EncodedMesh> {
+        if mesh.positions.is_empty() {
+            log::warn!("Skipping a mesh without vertices in the glTF export");
+            return None;
+        }
+        let attributes = Attributes {
+            position: Some(self.vec3_accessor(&mesh.positions, true)),
+            normal: mesh
+                .normals
+                .as_deref()
+                .map(|n| self.vec3_accessor(n, false)),
+            tangent: mesh
+                .tangents
+                .as_deref()
+                .map(|t| self.float_accessor(bytemuck::cast_slice(t), t.len(), "VEC4")),
+            tex_coord: mesh
+                .tex_coords
+                .as_deref()
+                .map(|uv| self.float_accessor(bytemuck::cast_slice(uv), uv.len(), "VEC2")),
+            color: mesh
+                .colors
+                .as_deref()
+                .map(|c| self.float_accessor(bytemuck::cast_slice(c), c.len(), "VEC4")),
+        };
+        let indices = mesh.indices.as_deref().filter(|i| !i.is_empty()).map(|i| {
+            let view = self.push_view(bytemuck::cast_slice(i), Some(ELEMENT_ARRAY_BUFFER));
+            self.push_accessor(view, UNSIGNED_INT, i.len(), "SCALAR", None)
+        });
+        let targets = mesh
+            .morph_targets
+            .iter()
+            .map(|target| Attributes {
+                position: Some(self.vec3_accessor(&target.position_deltas, true)),
+                normal: target
+                    .normal_deltas
+                    .as_deref()
+                    .map(|n| self.vec3_accessor(n, false)),
+                ..Default::default()
+            })
+            .collect();
+        Some(EncodedMesh {
+            attributes,
+            indices,
+            targets,
+            mode: match mesh.primitive_type {
+                PrimitiveTopology::PointList => 0,
+                PrimitiveTopology::LineList => 1,
+                PrimitiveTopology::LineStrip => 3,
+                PrimitiveTopology::TriangleList => 4,
+                PrimitiveTopology::TriangleStrip => 5,
+            },
+        })
+    }
+
+    fn material(&mut self, material: &MaterialComponent) -> u32 {
+        if let Some(&index) = self.materials.get(&material.uuid) {
+            return index;
+        }
+        let source = &**material.handle;
+        let base_color = source.base_color();
+        let emissive = source.emissive_color();
+        let mut gltf = Material {
+            name: None,
+            pbr_metallic_roughness: PbrMetallicRoughness {
+                base_color_factor: [base_color.r, base_color.g, base_color.b, base_color.a],
+                metallic_factor: 0.0,
+                roughness_factor: 1.0,
+            },
+            emissive_factor: [emissive.r, emissive.g, emissive.b],
+            alpha_mode: "OPAQUE",
+            alpha_cutoff: None,
+            double_sided: false,
+            extensions: None,
+        };
+        let alpha_mode = if let Some(standard) = source.as_any().downcast_ref::<StandardMaterial>()
+        {
+            gltf.pbr_metallic_roughness.metallic_factor = standard.metallic;
+            gltf.pbr_metallic_roughness.roughness_factor = standard.roughness;
+            gltf.double_sided = standard.double_sided;
+            standard.alpha_mode
+        } else if let Some(unlit) = source.as_any().downcast_ref::<UnlitMaterial>() {
+            gltf.extensions = Some(MaterialExtensions { unlit: Unlit {} });
+            self.use_extension(KHR_MATERIALS_UNLIT);
+            unlit.alpha_mode
+        } else {
+            source.alpha_mode()
+        };
+        (gltf.alpha_mode, gltf.alpha_cutoff) = match alpha_mode {
+            AlphaMode::Opaque => ("OPAQUE", None),
+            AlphaMode::Mask(cutoff) => ("MASK", Some(cutoff)),
+            AlphaMode::Blend => ("BLEND", None),
+        };
+
+        let index = self.document.materials.len() as u32;
+        self.document.materials.push(gltf);
+        self.materials.insert(material.uuid, index);
+        index
+    }
+
+    fn camera(&mut self, camera: &CameraComponent) -> u32 {
+        let gltf = match camera.projection {
+            ProjectionType::Perspective { fov_y_radians } => Camera {
+                kind: "perspective",
+                perspective: Some(Perspective {
+                    aspect_ratio: camera.aspect_ratio,
+                    yfov: fov_y_radians,
+                    znear: camera.z_near,
+                    zfar: camera.z_far.is_finite().then_some(camera.z_far),
+                }),
+                orthographic: None,
+            },
+            // glTF orthographic cameras cannot see behind their node.
+            ProjectionType::Orthographic { width, height } => Camera {
+                kind: "orthographic",
+                perspective: None,
+                orthographic: Some(Orthographic {
+                    xmag: width / 2.0,
+                    ymag: height / 2.0,
+                    znear: camera.z_near.max(0.0),
+                    zfar: camera.z_far.min(f32::MAX),
+                }),
+            },
+        };
+        let index = self.document.cameras.len() as u32;
+        self.document.cameras.push(gltf);
+        index
+    }
+
+    /// Adds a light and returns its index and, for aimed lights, the
+    /// direction it shines in the entity's space.
+    fn light(&mut self, light: &LightComponent) -> (u32, Option<Vec3>) {
+        let (gltf, direction) = match &light.light_type {
+            LightType::Directional(sun) => (
+                Light {
+                    kind: "directional",
+                    color: [sun.color.r, sun.color.g, sun.color.b],
+                    intensity: sun.intensity,
+                    range: None,
+                    spot: None,
+                },
+                Some(sun.direction),
+            ),
+            LightType::Point(point) => (
+                Light {
+                    kind: "point",
+                    color: [point.color.r, point.color.g, point.color.b],
+                    intensity: point.luminous_intensity(),
+                    range: Some(point.range),
+                    spot: None,
+                },
+                None,
+            ),
+            LightType::Spot(spot) => (
+                Light {
+                    kind: "spot",
+                    color: [spot.color.r, spot.color.g, spot.color.b],
+                    intensity: spot.luminous_intensity(),
+                    range: Some(spot.range),
+                    spot: Some(Spot {
+                        inner_cone_angle: spot.inner_cone_angle,
+                        outer_cone_angle: spot.outer_cone_angle,
+                    }),
+                },
+                Some(spot.direction),
+            ),
+        };
+        self.use_extension(KHR_LIGHTS_PUNCTUAL);
+        let lights = &mut self
+            .document
+            .extensions
+            .get_or_insert_with(Default::default)
+            .lights_punctual
+            .lights;
+        lights.push(gltf);
+        ((lights.len() - 1) as u32, direction)
+    }
+
+    fn use_extension(&mut self, name: &'static str) {
+        if !self.document.extensions_used.contains(&name) {
+            self.document.extensions_used.push(name);
+        }
+    }
+
+    /// Writes a `VEC3` accessor, with bounds when `bounded` (required for
+    /// positions).
+    fn vec3_accessor(&mut self, values: &[Vec3], bounded: bool) -> u32 {
+        let bounds = bounded.then(|| {
+            values.iter().fold(
+                (vec![f32::MAX; 3], vec![f32::MIN; 3]),
+                |(mut min, mut max), v| {
+                    for (axis, value) in [v.x, v.y, v.z].into_iter().enumerate() {
+                        min[axis] = min[axis].min(value);
+                        max[axis] = max[axis].max(value);
+                    }
+                    (min, max)
+                },
+            )
+        });
+        let view = self.push_view(bytemuck::cast_slice(values), Some(ARRAY_BUFFER));
+        self.push_accessor(view, FLOAT, values.len(), "VEC3", bounds)
+    }
+
+    fn float_accessor(&mut self, floats: &[f32], count: usize, kind: &'static str) -> u32 {
+        let view = self.push_view(bytemuck::cast_slice(floats), Some(ARRAY_BUFFER));
+        self.push_accessor(view, FLOAT, count, kind, None)
+    }
+
+    fn push_view(&mut self, bytes: &[u8], target: Option<u32>) -> u32 {
+        pad_to_four(&mut self.buffer, 0);
+        self.document.buffer_views.push(BufferView {
+            buffer: 0,
+            byte_offset: self.buffer.len() as u32,
+            byte_length: bytes.len() as u32,
+            target,
+        });
+        self.buffer.extend_from_slice(bytes);
+        (self.document.buffer_views.len() - 1) as u32
+    }
+
+    fn push_accessor(
+        &mut self,
+        buffer_view: u32,
+        component_type: u32,
+        count: usize,
+        kind: &'static str,
+        bounds: Option<(Vec<f32>, Vec<f32>)>,
+    ) -> u32 {
+        let (min, max) = bounds.unzip();
+        self.document.accessors.push(Accessor {
+            buffer_view,
+            component_type,
+            count: count as u32,
+            kind,
+            min,
+            max,
+        });
+        (self.document.accessors.len() - 1) as u32
+    }
+}
+
+fn vec3(v: Vec3) -> [f32; 3] {
+    [v.x, v.y, v.z]
+}
+
+fn quat(q: Quaternion) -> [f32; 4] {
+    [q.x, q.y, q.z, q.w]
+}
+
+fn pad_to_four(bytes: &mut Vec<u8>, fill: u8) {
+    bytes.resize(bytes.len().next_multiple_of(4), fill);
+}
@@ -14,10 +14,11 @@
 
 //! # Khora I/O
 //!
-//! I/O services for asset loading, VFS, and scene serialization.
+//! I/O services for asset loading, VFS, scene serialization and export.
 //! These are on-demand services, not agents — they don't participate
 //! in GORNA strategy negotiation.
 
 pub mod asset;
+pub mod export;
 pub mod serialization;
 pub mod vfs;
@@ -22,7 +22,7 @@ mod service;
 
 // Re-export strategies from khora-data for convenience.
 pub use khora_data::scene::{
-    ArchetypeSerializationStrategy, DefinitionSerializationStrategy, DeserializationError,
-    RecipeSerializationStrategy, SerializationError, SerializationStrategy,
+    ArchetypeSerializationStrategy, DefinitionFormat, DefinitionSerializationStrategy,
+    DeserializationError, RecipeSerializationStrategy, SerializationError, SerializationStrategy,
 };
 pub use service::*;
@@ -19,7 +19,10 @@
 //! chosen by the caller.
 
 use super::*;
-use khora_core::scene::{SceneFile, SceneHeader, SerializationGoal};
+use khora_core::ecs::entity::EntityId;
+use khora_core::scene::{
+    SceneFile, SceneHeader, SceneMetadata, SerializationGoal, SCENE_FORMAT_VERSION,
+};
 use khora_data::ecs::World;
 use std::collections::HashMap;
 
@@ -47,11 +50,13 @@ impl SerializationService {
     pub fn new() -> Self {
         let mut strategies: HashMap<String, Box<dyn SerializationStrategy>> = HashMap::new();
 
-        let definition_strategy = DefinitionSerializationStrategy::new();
-        strategies.insert(
-            definition_strategy.get_strategy_id().to_string(),
-            Box::new(definition_strategy),
-        );
+        for format in [DefinitionFormat::Ron, DefinitionFormat::Json] {
+            let definition_strategy = DefinitionSerializationStrategy::with_format(format);
+            strategies.insert(
+                definition_strategy.get_strategy_id().to_string(),
+                Box::new(definition_strategy),
+            );
+        }
 
         let recipe_strategy = RecipeSerializationStrategy::new();
         strategies.insert(
@@ -76,14 +81,39 @@ impl SerializationService {
     ) -> Result<SceneFile, SerializationServiceError> {
         let strategy_id = match goal {
             SerializationGoal::HumanReadableDebug | SerializationGoal::LongTermStability => {
-                "KH_DEFINITION_RON_V1"
+                DefinitionFormat::Ron.strategy_id()
             }
+            SerializationGoal::ExternalTools => DefinitionFormat::Json.strategy_id(),
             SerializationGoal::SmallestFileSize | SerializationGoal::EditorInterchange => {
                 "KH_RECIPE_V1"
             }
+            SerializationGoal::FastestLoad if !world.is_archetype_serializable() => {
+                log::warn!(
+                    "World has heap-owning or sparse components; saving with the recipe \
+                     strategy instead of the archetype one"
+                );
+                "KH_RECIPE_V1"
+            }
             SerializationGoal::FastestLoad => "KH_ARCHETYPE_V1",
         };
+        self.save_with(world, strategy_id)
+    }
+
+    /// Saves the `World` with the Definition strategy in an explicit text
+    /// `format`, whatever goal would pick.
+    pub fn save_definition(
+        &self,
+        world: &World,
+        format: DefinitionFormat,
+    ) -> Result<SceneFile, SerializationServiceError> {
+        self.save_with(world, format.strategy_id())
+    }
 
+    fn save_with(
+        &self,
+        world: &World,
+        strategy_id: &str,
+    ) -> Result<SceneFile, SerializationServiceError> {
         let strategy = self
             .strategies
             .get(strategy_id)
@@ -99,32 +129,79 @@ impl SerializationService {
 
         let header = SceneHeader {
             magic_bytes: khora_core::scene::HEADER_MAGIC_BYTES,
-            format_version: 1,
+            format_version: SCENE_FORMAT_VERSION,
             strategy_id: strategy_id_bytes,
             payload_length: payload.len() as u64,
         };
-
-        Ok(SceneFile { header, payload })
+        let metadata = world
+            .get_resource::<SceneMetadata>()
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(SceneFile {
+            header,
+            payload,
+            metadata,
+        })
     }
 
     /// Populates a `World` from a `SceneFile`.
+    ///
+    /// The file's [`SceneMetadata`] becomes the world's resource, and is
+    /// written back by the next save. Debug builds then run [`World::validate`] and log what it finds.
     pub fn load_world(
         &self,
         file: &SceneFile,
         world: &mut World,
     ) -> Result<(), SerializationServiceError> {
-        let strategy_id = str::from_utf8(&file.header.strategy_id)
-            .map_err(|_| SerializationServiceError::InvalidHeader)?
-            .trim_end_matches('\0');
+        self.strategy_for(file)?
+            .deserialize(&file.payload, world)
+            .map_err(|e| SerializationServiceError::ProcessingError(e.to_string()))?;
+        world.insert_resource(file.metadata.clone());
+        if cfg!(debug_assertions) {
+            world.validate().log("Scene load");
+        }
+        Ok(())
+    }
 
-        let strategy = self
-            .strategies
-            .get(strategy_id)
-            .ok_or(SerializationServiceError::StrategyNotFound)?;
+    /// Spawns the entities of `prefab` into a `World` that may already hold
+    /// others, remapping their entity references.
+    ///
+    /// Returns the spawned entities. See [`World::instantiate`].
+    pub fn instantiate(
+        &self,
+        prefab: &SceneFile,
+        world: &mut World,
+    ) -> Result<Vec<EntityId>, SerializationServiceError> {
+        let spawned = self
+            .strategy_for(prefab)?
+            .instantiate(&prefab.payload, world)
+            .map_err(|e| SerializationServiceError::ProcessingError(e.to_string()))?;
+        if cfg!(debug_assertions) {
+            world.validate().log("Prefab instantiation");
+        }
+        Ok(spawned)
+    }
 
-        strategy
-            .deserialize(&file.payload, world)
-            .map_err(|e| SerializationServiceError::ProcessingError(e.to_string()))
+    fn strategy_for(
+        &self,
+        file: &SceneFile,
+    ) -> Result<&dyn SerializationStrategy, SerializationServiceError> {
+        if file.header.magic_bytes != khora_core::scene::HEADER_MAGIC_BYTES
+            || file.header.payload_length != file.payload.len() as u64
+        {
+            return Err(SerializationServiceError::InvalidHeader);
+        }
+
+        let strategy_id = file
+            .header
+            .strategy_id_str()
+            .ok_or(SerializationServiceError::InvalidHeader)?;
+
+        self.strategies
+            .get(strategy_id)
+            .map(|strategy| strategy.as_ref())
+            .ok_or(SerializationServiceError::StrategyNotFound)
     }
 }
 
@@ -164,6 +241,83 @@ mod tests {
         assert_eq!(*new_root_transform, root_transform);
     }
 
+    #[test]
+    fn test_definition_json_round_trip() {
+        let mut source_world = World::new();
+
+        let root_transform = Transform {
+            translation: Vec3::new(4.0, 5.0, 6.0),
+            ..Default::default()
+        };
+        source_world.spawn((root_transform, GlobalTransform::identity()));
+
+        let service = SerializationService::new();
+        let scene_file = service
+            .save_world(&source_world, SerializationGoal::ExternalTools)
+            .unwrap();
+        assert_eq!(
+            str::from_utf8(&scene_file.header.strategy_id)
+                .unwrap()
+                .trim_end_matches('\0'),
+            "KH_DEFINITION_JSON_V1"
+        );
+        assert!(scene_file.payload.starts_with(b"{"));
+
+        let mut dest_world = World::new();
+        service.load_world(&scene_file, &mut dest_world).unwrap();
+        let mut root_query = dest_world.query::<(&Transform, Without<Parent>)>();
+        let (new_root_transform, _) = root_query.next().expect("Should be one root entity");
+        assert_eq!(*new_root_transform, root_transform);
+
+        // The explicit format picks the same strategy.
+        let explicit = service
+            .save_definition(&source_world, DefinitionFormat::Json)
+            .unwrap();
+        assert_eq!(explicit.header.strategy_id, scene_file.header.strategy_id);
+    }
+
+    #[test]
+    fn test_instantiate_prefab_twice() {
+        let mut prefab_world = World::new();
+        let leader = prefab_world.spawn((Transform::default(), GlobalTransform::identity()));
+        prefab_world.spawn((
+            Transform::default(),
+            GlobalTransform::identity(),
+            Parent(leader),
+        ));
+
+        let service = SerializationService::new();
+        let mut world = World::new();
+        let existing = world.spawn(Transform::default());
+        for goal in [
+            SerializationGoal::EditorInterchange,
+            SerializationGoal::LongTermStability,
+        ] {
+            let prefab = service.save_world(&prefab_world, goal).unwrap();
+            let first = world.instantiate(&prefab).unwrap();
+            let second = service.instantiate(&prefab, &mut world).unwrap();
+            assert_eq!(first.len(), 2);
+            assert_eq!(second.len(), 2);
+
+            for squad in [&first, &second] {
+                let root = squad
+                    .iter()
+                    .copied()
+                    .find(|&e| world.get::<Parent>(e).is_none())
+                    .unwrap();
+                let member = squad.iter().copied().find(|&e| e != root).unwrap();
+                assert_eq!(world.get::<Parent>(member), Some(&Parent(root)));
+            }
+        }
+        assert!(world.get::<Transform>(existing).is_some());
+        assert_eq!(world.iter_entities().count(), 9);
+
+        let snapshot = service
+            .save_world(&prefab_world, SerializationGoal::FastestLoad)
+            .unwrap();
+        assert!(world.instantiate(&snapshot).is_err());
+    }
+
     #[test]
     fn test_recipe_serialization_round_trip() {
         let mut source_world = World::new();
@@ -224,4 +378,39 @@ mod tests {
             "Root entity should exist with the same ID"
         );
     }
+
+    #[test]
+    fn test_metadata_and_tags_survive_every_goal() {
+        use khora_data::ecs::Tags;
+
+        let mut source_world = World::new();
+        let guard = source_world.spawn((Transform::default(), Tags::new(["enemy", "guard"])));
+        let mut metadata = SceneMetadata {
+            author: "level-design".to_string(),
+            description: "Castle gate".to_string(),
+            ..Default::default()
+        };
+        metadata.set_flag("night", true);
+        source_world.insert_resource(metadata.clone());
+
+        let service = SerializationService::new();
+        for goal in [
+            SerializationGoal::LongTermStability,
+            SerializationGoal::ExternalTools,
+            SerializationGoal::EditorInterchange,
+            SerializationGoal::FastestLoad,
+        ] {
+            let bytes = service.save_world(&source_world, goal).unwrap().to_bytes();
+            let scene_file = SceneFile::from_bytes(&bytes).unwrap();
+            assert_eq!(scene_file.metadata, metadata);
+
+            let mut dest_world = World::new();
+            service.load_world(&scene_file, &mut dest_world).unwrap();
+            assert_eq!(dest_world.get_resource::<SceneMetadata>(), Some(&metadata));
+            let tagged = dest_world.query_tagged("guard");
+            assert_eq!(tagged.len(), 1, "{goal:?}");
+            assert!(dest_world.has_tag(tagged[0], "enemy"));
+        }
+        assert!(source_world.has_tag(guard, "guard"));
+    }
 }
@@ -19,11 +19,42 @@
 //! support asset loading and management by offering O(1) lookups of asset metadata
 //! using asset UUIDs. The VFS is typically initialized from a packed binary index
 //! file and serves as the primary source of truth for asset metadata in the engine.
+//!
+//! Patch archives are mounted as layers on top of the base index. The topmost
+//! layer that knows an asset wins; a patch entry with no variants removes it.
 
 use bincode;
-use khora_core::asset::{AssetMetadata, AssetUUID};
+use khora_core::asset::{ArchiveInfo, AssetMetadata, AssetSource, AssetUUID, PlatformTarget};
 use std::collections::HashMap;
 
+/// An error raised when mounting a patch archive.
+#[derive(Debug)]
+pub enum MountError {
+    /// The patch index is not a valid, bincode-encoded list of `AssetMetadata`.
+    Decode(bincode::error::DecodeError),
+    /// The archive is not a patch.
+    NotAPatch,
+    /// The patch applies to a different version than the mounted one.
+    VersionMismatch {
+        /// Version currently mounted.
+        mounted: u32,
+        /// Version the patch expects.
+        expected: u32,
+    },
+}
+
+/// The sources to read, top layer first, to rebuild one asset variant.
+///
+/// Every entry but the last is an [`AssetSource::Delta`]; the last one holds
+/// full bytes.
+#[derive(Debug)]
+pub struct ResolvedAsset<'a> {
+    /// Metadata from the topmost layer holding the asset.
+    pub metadata: &'a AssetMetadata,
+    /// `(layer, source)` pairs, topmost first.
+    pub chain: Vec<(usize, &'a AssetSource)>,
+}
+
 /// The runtime representation of the asset index (`index.bin`).
 ///
 /// The Virtual File System is a service that provides fast, in-memory access
@@ -32,9 +63,13 @@ use std::collections::HashMap;
 /// about loading assets.
 #[derive(Debug)]
 pub struct VirtualFileSystem {
-    /// The internal index mapping asset UUIDs to their metadata.
-    /// This provides O(1) average-time lookups.
-    index: HashMap<AssetUUID, AssetMetadata>,
+    /// One index per mounted archive, base first. Each maps asset UUIDs to
+    /// their metadata for O(1) average-time lookups.
+    layers: Vec<HashMap<AssetUUID, AssetMetadata>>,
+    /// Version of the asset content with every layer mounted.
+    version: u32,
+    /// The platform and tier whose variants [`resolve`](Self::resolve) picks.
+    target: PlatformTarget,
 }
 
 impl VirtualFileSystem {
@@ -47,25 +82,95 @@ impl VirtualFileSystem {
     /// Returns a `DecodeError` if the byte slice is not a valid, bincode-encoded
     /// list of `AssetMetadata`.
     pub fn new(index_bytes: &[u8]) -> Result<Self, bincode::error::DecodeError> {
-        let config = bincode::config::standard();
-        // First, decode the bytes into a flat list of metadata.
-        let (metadata_vec, _): (Vec<AssetMetadata>, _) =
-            bincode::serde::decode_from_slice(index_bytes, config)?;
-
-        // Then, build the HashMap for fast lookups.
-        let index = metadata_vec
-            .into_iter()
-            .map(|meta| (meta.uuid, meta))
-            .collect();
-
-        Ok(Self { index })
+        Ok(Self {
+            layers: vec![decode_index(index_bytes)?],
+            version: 0,
+            target: PlatformTarget::current(),
+        })
+    }
+
+    /// Creates a `VirtualFileSystem` for a versioned base archive.
+    pub fn versioned(
+        index_bytes: &[u8],
+        info: ArchiveInfo,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let mut vfs = Self::new(index_bytes)?;
+        vfs.version = info.version;
+        Ok(vfs)
+    }
+
+    /// Mounts a patch archive's index on top of the current layers.
+    ///
+    /// Returns the layer index the patch's sources belong to.
+    ///
+    /// # Errors
+    /// Fails if `info` is not a patch over the currently mounted version, or
+    /// if the index cannot be decoded.
+    pub fn mount_patch(
+        &mut self,
+        index_bytes: &[u8],
+        info: ArchiveInfo,
+    ) -> Result<usize, MountError> {
+        let expected = info.base_version.ok_or(MountError::NotAPatch)?;
+        if expected != self.version {
+            return Err(MountError::VersionMismatch {
+                mounted: self.version,
+                expected,
+            });
+        }
+        self.layers
+            .push(decode_index(index_bytes).map_err(MountError::Decode)?);
+        self.version = info.version;
+        Ok(self.layers.len() - 1)
+    }
+
+    /// Returns the version of the asset content with every patch applied.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the number of mounted archives, base included.
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Sets the platform and tier used to select asset variants.
+    pub fn set_target(&mut self, target: PlatformTarget) {
+        self.target = target;
+    }
+
+    /// Returns the platform and tier used to select asset variants.
+    pub fn target(&self) -> &PlatformTarget {
+        &self.target
     }
 
     /// Retrieves the metadata for a given asset UUID.
     ///
     /// This is the primary query method used by the `AssetAgent`.
     pub fn get_metadata(&self, uuid: &AssetUUID) -> Option<&AssetMetadata> {
-        self.index.get(uuid)
+        self.top_entry(uuid).map(|(_, metadata)| metadata)
+    }
+
+    /// Resolves the sources to read for the variant the current target
+    /// should load, following deltas down to a full copy.
+    ///
+    /// Returns `None` if the asset is unknown, removed by a patch, has no
+    /// suitable variant, or a delta has no base below it.
+    pub fn resolve(&self, uuid: &AssetUUID) -> Option<ResolvedAsset<'_>> {
+        let (top, metadata) = self.top_entry(uuid)?;
+        let (key, source) = metadata.select_variant(&self.target)?;
+
+        let mut chain = vec![(top, source)];
+        let mut layer = top;
+        while matches!(chain.last(), Some((_, AssetSource::Delta { .. }))) {
+            let (below, base) = (0..layer).rev().find_map(|l| {
+                let base = self.layers[l].get(uuid)?.variants.get(&key)?;
+                Some((l, base))
+            })?;
+            chain.push((below, base));
+            layer = below;
+        }
+        Some(ResolvedAsset { metadata, chain })
     }
 
     /// Returns an iterator over all asset metadata entries in the VFS.
@@ -73,11 +178,47 @@ impl VirtualFileSystem {
     /// Useful for editor tooling (asset browser) that needs to display all
     /// available assets.
     pub fn iter_all(&self) -> impl Iterator<Item = &AssetMetadata> {
-        self.index.values()
+        self.layers
+            .iter()
+            .enumerate()
+            .flat_map(|(layer, index)| {
+                index
+                    .iter()
+                    .map(move |(uuid, metadata)| (layer, uuid, metadata))
+            })
+            .filter(|(layer, uuid, _)| self.top_entry(uuid).is_some_and(|(top, _)| top == *layer))
+            .map(|(_, _, metadata)| metadata)
     }
 
     /// Returns the total number of indexed assets.
     pub fn asset_count(&self) -> usize {
-        self.index.len()
+        self.iter_all().count()
+    }
+
+    /// Finds the topmost layer knowing `uuid`, unless it removed the asset.
+    fn top_entry(&self, uuid: &AssetUUID) -> Option<(usize, &AssetMetadata)> {
+        let (layer, metadata) = self
+            .layers
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(layer, index)| index.get(uuid).map(|m| (layer, m)))?;
+        (!metadata.variants.is_empty()).then_some((layer, metadata))
     }
 }
+
+/// Decodes an `index.bin` into a lookup table.
+fn decode_index(
+    index_bytes: &[u8],
+) -> Result<HashMap<AssetUUID, AssetMetadata>, bincode::error::DecodeError> {
+    let config = bincode::config::standard();
+    // First, decode the bytes into a flat list of metadata.
+    let (metadata_vec, _): (Vec<AssetMetadata>, _) =
+        bincode::serde::decode_from_slice(index_bytes, config)?;
+
+    // Then, build the HashMap for fast lookups.
+    Ok(metadata_vec
+        .into_iter()
+        .map(|meta| (meta.uuid, meta))
+        .collect())
+}
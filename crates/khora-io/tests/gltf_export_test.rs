@@ -0,0 +1,144 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! glTF export tests.
+//!
+//! Exported files are read back with the `gltf` crate and Khora's own mesh
+//! decoder, so a file Blender would reject fails here first.
+
+use std::sync::Arc;
+
+use khora_core::asset::{AssetHandle, AssetUUID, Material, StandardMaterial};
+use khora_core::math::{geometry::Aabb, LinearRgba, Vec3};
+use khora_core::renderer::api::pipeline::enums::PrimitiveTopology;
+use khora_core::renderer::api::scene::Mesh;
+use khora_core::renderer::light::{LightType, PointLight};
+use khora_data::ecs::{
+    Camera, GlobalTransform, HandleComponent, Light, MaterialComponent, Name, Transform, World,
+};
+use khora_io::asset::{AssetDecoder, FileSystemResolver, GltfDecoder};
+use khora_io::export::GltfExporter;
+
+fn triangle() -> Mesh {
+    let positions = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 2.0, 0.0),
+    ];
+    Mesh {
+        bounding_box: Aabb::from_min_max(Vec3::ZERO, Vec3::new(1.0, 2.0, 0.0)),
+        positions,
+        normals: Some(vec![Vec3::new(0.0, 0.0, 1.0); 3]),
+        tex_coords: None,
+        tangents: None,
+        colors: None,
+        indices: Some(vec![0, 1, 2]),
+        primitive_type: PrimitiveTopology::TriangleList,
+        vertex_layout: Vec::new(),
+        morph_targets: Vec::new(),
+        skin: None,
+    }
+}
+
+/// A ship with two hulls sharing one mesh, a camera and a point light.
+fn ship_world() -> (World, khora_core::ecs::entity::EntityId) {
+    let mut world = World::new();
+    let mesh = HandleComponent {
+        handle: AssetHandle::new(triangle()),
+        uuid: AssetUUID::new(),
+    };
+    let material = MaterialComponent {
+        handle: AssetHandle::new(Box::new(StandardMaterial {
+            base_color: LinearRgba::new(0.8, 0.1, 0.1, 1.0),
+            metallic: 1.0,
+            roughness: 0.25,
+            ..Default::default()
+        }) as Box<dyn Material>),
+        uuid: AssetUUID::new(),
+    };
+
+    let ship = world.spawn((
+        Name::new("Ship"),
+        Transform::from_translation(Vec3::new(0.0, 5.0, 0.0)),
+        GlobalTransform::identity(),
+    ));
+    for x in [-1.0, 1.0] {
+        let hull = world.spawn((
+            Name::new("Hull"),
+            Transform::from_translation(Vec3::new(x, 0.0, 0.0)),
+            GlobalTransform::identity(),
+            mesh.clone(),
+            material.clone(),
+        ));
+        world.set_parent(hull, Some(ship));
+    }
+    let camera = world.spawn((
+        Transform::default(),
+        GlobalTransform::identity(),
+        Camera::default_perspective(),
+    ));
+    world.set_parent(camera, Some(ship));
+    let lamp = world.spawn((
+        Transform::default(),
+        GlobalTransform::identity(),
+        Light::new(LightType::Point(PointLight::default())),
+    ));
+    world.set_parent(lamp, Some(ship));
+    (world, ship)
+}
+
+#[test]
+fn glb_export_is_a_valid_gltf_scene() {
+    let (world, ship) = ship_world();
+    let mut exporter = GltfExporter::new();
+    exporter.add_subtree(&world, ship).unwrap();
+    assert_eq!(exporter.node_count(), 5);
+    assert_eq!(exporter.mesh_count(), 1);
+
+    let glb = exporter.to_glb().unwrap();
+    let gltf = gltf::Gltf::from_slice(&glb).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let root = scene.nodes().next().unwrap();
+    assert_eq!(root.name(), Some("Ship"));
+    assert_eq!(root.transform().decomposed().0, [0.0, 5.0, 0.0]);
+    assert_eq!(root.children().count(), 4);
+    assert_eq!(root.children().filter(|n| n.mesh().is_some()).count(), 2);
+    assert!(root.children().any(|n| n.camera().is_some()));
+
+    let material = gltf.materials().next().unwrap();
+    assert_eq!(material.pbr_metallic_roughness().metallic_factor(), 1.0);
+    assert_eq!(material.pbr_metallic_roughness().roughness_factor(), 0.25);
+    assert!(gltf
+        .extensions_used()
+        .any(|name| name == "KHR_lights_punctual"));
+
+    // Khora's own decoder reads the mesh back unchanged.
+    let decoder = GltfDecoder::new(Arc::new(FileSystemResolver::new(".")));
+    let mesh = decoder.load(&glb).unwrap();
+    assert_eq!(mesh.positions, triangle().positions);
+    assert_eq!(mesh.indices, Some(vec![0, 1, 2]));
+}
+
+#[test]
+fn gltf_export_embeds_its_buffer() {
+    let (world, _) = ship_world();
+    let mut exporter = GltfExporter::new();
+    assert_eq!(exporter.add_world(&world), 1);
+
+    let json = exporter.to_gltf().unwrap();
+    assert!(json.contains("data:application/octet-stream;base64,"));
+    let decoder = GltfDecoder::new(Arc::new(FileSystemResolver::new(".")));
+    let mesh = decoder.load(json.as_bytes()).unwrap();
+    assert_eq!(mesh.normals, triangle().normals);
+}
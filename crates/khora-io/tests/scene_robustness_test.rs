@@ -0,0 +1,192 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Robustness tests for scene loading.
+//!
+//! Corrupted, truncated or hostile scene files must come back as errors —
+//! never as panics or unbounded allocations. These properties are checked for
+//! the container format and for every serialization strategy.
+
+use khora_core::math::Vec3;
+use khora_core::scene::{SceneFile, SceneHeader, SerializationGoal};
+use khora_data::ecs::{GlobalTransform, Name, Parent, Transform, World};
+use khora_io::serialization::SerializationService;
+use proptest::prelude::*;
+
+const GOALS: [SerializationGoal; 4] = [
+    SerializationGoal::LongTermStability,
+    SerializationGoal::ExternalTools,
+    SerializationGoal::EditorInterchange,
+    SerializationGoal::FastestLoad,
+];
+
+fn sample_world() -> World {
+    let mut world = World::new();
+    let root = world.spawn((
+        Transform {
+            translation: Vec3::new(1.0, 2.0, 3.0),
+            ..Default::default()
+        },
+        GlobalTransform::identity(),
+    ));
+    world.spawn((
+        Transform::default(),
+        GlobalTransform::identity(),
+        Parent(root),
+    ));
+    world
+}
+
+fn saved_bytes(goal: SerializationGoal) -> Vec<u8> {
+    SerializationService::new()
+        .save_world(&sample_world(), goal)
+        .expect("sample world should save")
+        .to_bytes()
+}
+
+/// Parses and loads `bytes` into a fresh world. Any outcome but a panic is fine.
+fn parse_and_load(bytes: &[u8]) {
+    if let Ok(file) = SceneFile::from_bytes(bytes) {
+        let mut world = World::new();
+        let _ = SerializationService::new().load_world(&file, &mut world);
+    }
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_bytes_never_panic(bytes in proptest::collection::vec(any::<u8>(), 0..512)) {
+        parse_and_load(&bytes);
+    }
+
+    #[test]
+    fn truncated_files_never_panic(goal in 0..GOALS.len(), cut in any::<prop::sample::Index>()) {
+        let bytes = saved_bytes(GOALS[goal]);
+        let cut = cut.index(bytes.len());
+        parse_and_load(&bytes[..cut]);
+    }
+
+    #[test]
+    fn bit_flipped_files_never_panic(
+        goal in 0..GOALS.len(),
+        flips in proptest::collection::vec((any::<prop::sample::Index>(), 0u8..8), 1..16),
+    ) {
+        let mut bytes = saved_bytes(GOALS[goal]);
+        for (index, bit) in flips {
+            let i = index.index(bytes.len());
+            bytes[i] ^= 1 << bit;
+        }
+        parse_and_load(&bytes);
+    }
+
+    #[test]
+    fn payload_length_is_bounded_by_input(goal in 0..GOALS.len(), length in any::<u64>()) {
+        let mut bytes = saved_bytes(GOALS[goal]);
+        let available = (bytes.len() - SceneHeader::SIZE) as u64;
+        let mut header = SceneHeader::from_bytes(&bytes).unwrap();
+        header.payload_length = length;
+        bytes[..SceneHeader::SIZE].copy_from_slice(&header.to_bytes());
+
+        let parsed = SceneFile::from_bytes(&bytes);
+        if length > available {
+            prop_assert!(parsed.is_err());
+        } else {
+            prop_assert_eq!(parsed.unwrap().payload.len() as u64, length);
+        }
+        parse_and_load(&bytes);
+    }
+
+    #[test]
+    fn arbitrary_strategy_ids_never_panic(goal in 0..GOALS.len(), id in any::<[u8; 32]>()) {
+        let mut file = SerializationService::new()
+            .save_world(&sample_world(), GOALS[goal])
+            .unwrap();
+        file.header.strategy_id = id;
+        let mut world = World::new();
+        let _ = SerializationService::new().load_world(&file, &mut world);
+    }
+
+    #[test]
+    fn arbitrary_payloads_never_panic(
+        goal in 0..GOALS.len(),
+        payload in proptest::collection::vec(any::<u8>(), 0..1024),
+    ) {
+        let mut file = SerializationService::new()
+            .save_world(&sample_world(), GOALS[goal])
+            .unwrap();
+        file.header.payload_length = payload.len() as u64;
+        file.payload = payload;
+        let mut world = World::new();
+        let _ = SerializationService::new().load_world(&file, &mut world);
+    }
+}
+
+#[test]
+fn mismatched_payload_length_is_rejected() {
+    let service = SerializationService::new();
+    let mut file = service
+        .save_world(&sample_world(), SerializationGoal::EditorInterchange)
+        .unwrap();
+    file.header.payload_length += 1;
+
+    let mut world = World::new();
+    assert!(service.load_world(&file, &mut world).is_err());
+}
+
+#[test]
+fn archetype_save_rejects_heap_owning_components() {
+    let mut world = World::new();
+    world.spawn((Transform::default(), Name("owned".to_string())));
+
+    assert!(!world.is_archetype_serializable());
+    assert!(world.serialize_archetype().is_err());
+}
+
+#[test]
+fn fastest_load_falls_back_for_heap_owning_components() {
+    let mut world = World::new();
+    world.spawn((Transform::default(), Name("owned".to_string())));
+
+    let service = SerializationService::new();
+    let file = service
+        .save_world(&world, SerializationGoal::FastestLoad)
+        .unwrap();
+    let strategy = std::str::from_utf8(&file.header.strategy_id).unwrap();
+    assert_eq!(strategy.trim_end_matches('\0'), "KH_RECIPE_V1");
+
+    let mut dest = World::new();
+    service.load_world(&file, &mut dest).unwrap();
+    assert!(dest.find_by_name("owned").is_some());
+}
+
+#[test]
+fn dangling_parent_is_dropped_on_load() {
+    let mut source = World::new();
+    let parent = source.spawn((Transform::default(), GlobalTransform::identity()));
+    source.spawn((
+        Transform::default(),
+        GlobalTransform::identity(),
+        Parent(parent),
+    ));
+    source.despawn(parent);
+
+    let service = SerializationService::new();
+    let file = service
+        .save_world(&source, SerializationGoal::LongTermStability)
+        .unwrap();
+
+    let mut dest = World::new();
+    service.load_world(&file, &mut dest).unwrap();
+    assert_eq!(dest.query::<&Parent>().count(), 0);
+    assert_eq!(dest.query::<&Transform>().count(), 1);
+}
@@ -0,0 +1,376 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Animation graph evaluation for every [`Animator`] in the world.
+
+use khora_core::animation::{AnimationParameters, AnimationState, Pose};
+use khora_core::ecs::entity::EntityId;
+use khora_core::lane::{
+    AnimationBlendLimit, AnimationDeltaTime, Lane, LaneContext, LaneError, LaneKind, Slot,
+};
+use khora_core::math::Vec3;
+use khora_data::ecs::systems::animation_player::{apply_sample, find_descendant};
+use khora_data::ecs::{Animator, AnimatorPlayback, Crossfade, SimulationLod, Transform, World};
+
+/// The standard animation graph lane.
+///
+/// Each execution advances every [`Animator`] by [`AnimationDeltaTime`],
+/// fires at most one transition per animator, and blends up to
+/// [`AnimationBlendLimit`] motions into its pose. A limit of `1` keeps only
+/// the dominant motion, skipping crossfades and blend-space mixing.
+#[derive(Debug, Default)]
+pub struct AnimationGraphLane;
+
+impl AnimationGraphLane {
+    /// Creates a new `AnimationGraphLane`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Advances `animator` by `delta_seconds` and returns its blended pose.
+    ///
+    /// Root motion extracted from root-motion states is added to
+    /// [`Animator::root_motion`].
+    pub fn evaluate(animator: &mut Animator, delta_seconds: f32, blend_limit: usize) -> Pose {
+        let mut pose = Pose::new();
+        let Some(graph) = animator.graph.clone() else {
+            return pose;
+        };
+        let blend_limit = blend_limit.max(1);
+        let delta = delta_seconds * animator.speed;
+        let root = graph.root_bone();
+        let params = &mut animator.parameters;
+        let playback = &mut animator.playback;
+
+        let current = *playback.state.get_or_insert(graph.entry());
+        let Some(state) = graph.state(current) else {
+            *playback = AnimatorPlayback::default();
+            return pose;
+        };
+
+        // 1. Advance the current state and any crossfade out of a previous
+        //    one, accumulating their weighted root displacement.
+        let mut root_motion = Vec3::ZERO;
+        let (from_time, to_time, cycles) =
+            Self::advance(state, &mut playback.normalized_time, delta, params);
+        let mut weight = 1.0;
+        if let Some(fade) = &mut playback.crossfade {
+            fade.elapsed += delta;
+            weight = fade.progress();
+            if let Some(from) = graph.state(fade.from) {
+                let (a, b, c) = Self::advance(from, &mut fade.from_time, delta, params);
+                if let (true, Some(root)) = (from.root_motion, root) {
+                    root_motion = root_motion
+                        + from
+                            .motion
+                            .root_displacement(a, b, c, params, blend_limit, root)
+                            * (1.0 - weight);
+                }
+            }
+            if fade.progress() >= 1.0 {
+                playback.crossfade = None;
+            }
+        }
+        if let (true, Some(root)) = (state.root_motion, root) {
+            root_motion = root_motion
+                + state.motion.root_displacement(
+                    from_time,
+                    to_time,
+                    cycles,
+                    params,
+                    blend_limit,
+                    root,
+                ) * weight;
+        }
+
+        // 2. Fire the first satisfied transition once no crossfade is pending.
+        if playback.crossfade.is_none() {
+            if let Some((transition, to)) =
+                graph.next_transition(current, playback.normalized_time, params)
+            {
+                transition.consume_triggers(params);
+                playback.crossfade = (transition.crossfade > 0.0).then_some(Crossfade {
+                    from: current,
+                    from_time: playback.normalized_time,
+                    elapsed: 0.0,
+                    duration: transition.crossfade,
+                });
+                playback.state = Some(to);
+                playback.normalized_time = 0.0;
+            }
+        }
+
+        // 3. Blend the active motions.
+        let Some(state) = playback.state.and_then(|i| graph.state(i)) else {
+            return pose;
+        };
+        let params: &AnimationParameters = params;
+        match playback.crossfade.map(|f| (f, graph.state(f.from))) {
+            Some((fade, Some(from))) => {
+                let w = fade.progress();
+                if blend_limit == 1 {
+                    let (dominant, time) = if w >= 0.5 {
+                        (state, playback.normalized_time)
+                    } else {
+                        (from, fade.from_time)
+                    };
+                    Self::sample(dominant, &mut pose, time, 1.0, params, 1, root);
+                } else {
+                    let limit = blend_limit - 1;
+                    Self::sample(
+                        from,
+                        &mut pose,
+                        fade.from_time,
+                        1.0 - w,
+                        params,
+                        limit,
+                        root,
+                    );
+                    let time = playback.normalized_time;
+                    Self::sample(state, &mut pose, time, w, params, limit, root);
+                }
+            }
+            _ => {
+                let time = playback.normalized_time;
+                Self::sample(state, &mut pose, time, 1.0, params, blend_limit, root);
+            }
+        }
+
+        animator.root_motion = animator.root_motion + root_motion;
+        pose
+    }
+
+    /// Advances `state`'s normalized `time` and returns the time before and
+    /// after, with the number of loop wrap-arounds in between.
+    fn advance(
+        state: &AnimationState,
+        time: &mut f32,
+        delta_seconds: f32,
+        params: &AnimationParameters,
+    ) -> (f32, f32, i32) {
+        let from = *time;
+        *time = state.advance(from, delta_seconds, params);
+        let forward = delta_seconds * state.speed >= 0.0;
+        let cycles = match (state.looping, forward) {
+            (true, true) if *time < from => 1,
+            (true, false) if *time > from => -1,
+            _ => 0,
+        };
+        (from, *time, cycles)
+    }
+
+    /// Samples `state` into `pose`, playing root motion in place when the
+    /// state extracts it.
+    fn sample(
+        state: &AnimationState,
+        pose: &mut Pose,
+        time: f32,
+        weight: f32,
+        params: &AnimationParameters,
+        max_clips: usize,
+        root: Option<&str>,
+    ) {
+        match (state.root_motion, root) {
+            (true, Some(root)) => state
+                .motion
+                .sample_into_without_root_motion(pose, time, weight, params, max_clips, root),
+            _ => state
+                .motion
+                .sample_into(pose, time, weight, params, max_clips),
+        }
+    }
+
+    fn step(&self, world: &mut World, delta_seconds: f32, blend_limit: usize) {
+        // Phase 1: evaluate every animator.
+        let mut poses: Vec<(EntityId, Pose)> = Vec::new();
+        let mut displacements: Vec<(EntityId, Vec3)> = Vec::new();
+        for (entity, animator, lod) in
+            world.query_mut::<(EntityId, &mut Animator, Option<&SimulationLod>)>()
+        {
+            // Dormant animators keep their last pose.
+            let Some(delta_seconds) = SimulationLod::delta_of(lod, delta_seconds) else {
+                continue;
+            };
+            let pose = Self::evaluate(animator, delta_seconds, blend_limit);
+            if !pose.is_empty() {
+                poses.push((entity, pose));
+            }
+            if animator.apply_root_motion {
+                displacements.push((entity, animator.take_root_motion()));
+            }
+        }
+
+        // Root motion is expressed in the animated entity's local space.
+        for (entity, delta) in displacements {
+            if let Some(t) = world.get_mut::<Transform>(entity) {
+                t.translation = t.translation + t.rotation.rotate_vec3(t.scale * delta);
+            }
+        }
+
+        // Phase 2: resolve track targets and write the blended values.
+        for (root, pose) in poses {
+            for (target, value) in pose.into_samples() {
+                let entity = match target {
+                    None => Some(root),
+                    Some(name) => find_descendant(world, root, &name),
+                };
+                if let Some(entity) = entity {
+                    apply_sample(world, entity, value);
+                }
+            }
+        }
+    }
+}
+
+impl Lane for AnimationGraphLane {
+    fn strategy_name(&self) -> &'static str {
+        "AnimationGraph"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Animation
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let delta_seconds = ctx
+            .get::<AnimationDeltaTime>()
+            .ok_or(LaneError::missing("AnimationDeltaTime"))?
+            .0;
+        let blend_limit = ctx
+            .get::<AnimationBlendLimit>()
+            .map_or(usize::MAX, |limit| limit.0);
+        let world = ctx
+            .get::<Slot<World>>()
+            .ok_or(LaneError::missing("Slot<World>"))?
+            .get();
+
+        self.step(world, delta_seconds, blend_limit);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::animation::{
+        AnimationClip, AnimationGraph, AnimationState, BlendSpace1D, Condition, Curve, Motion,
+        SampledValue, TrackValues, Transition,
+    };
+    use khora_core::asset::AssetHandle;
+
+    fn constant_clip(name: &str, x: f32) -> AssetHandle<AnimationClip> {
+        AssetHandle::new(
+            AnimationClip::new(name).with_track(TrackValues::Translation(Curve::new([
+                (0.0, Vec3::new(x, 0.0, 0.0)),
+                (1.0, Vec3::new(x, 0.0, 0.0)),
+            ]))),
+        )
+    }
+
+    fn translation_x(pose: &Pose) -> f32 {
+        match pose.iter().next() {
+            Some((None, SampledValue::Translation(v))) => v.x,
+            other => panic!("unexpected pose entry {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_transition_crossfades_between_states() {
+        let graph = AnimationGraph::new(AnimationState::new(
+            "idle",
+            Motion::Clip(constant_clip("idle", 0.0)),
+        ))
+        .with_state(AnimationState::new(
+            "run",
+            Motion::Clip(constant_clip("run", 10.0)),
+        ))
+        .with_transition(
+            Transition::new("idle", "run")
+                .when(Condition::IsTrue("moving".into()))
+                .with_crossfade(1.0),
+        );
+        let mut animator = Animator::new(AssetHandle::new(graph));
+
+        let pose = AnimationGraphLane::evaluate(&mut animator, 0.1, usize::MAX);
+        assert_eq!(animator.current_state(), Some("idle"));
+        assert_eq!(translation_x(&pose), 0.0);
+
+        animator.set_bool("moving", true);
+        AnimationGraphLane::evaluate(&mut animator, 0.1, usize::MAX);
+        assert_eq!(animator.current_state(), Some("run"));
+        assert!(animator.is_transitioning());
+
+        let pose = AnimationGraphLane::evaluate(&mut animator, 0.5, usize::MAX);
+        assert!((translation_x(&pose) - 5.0).abs() < 1e-4);
+
+        // The dominant-only budget snaps to whichever side weighs more.
+        let pose = AnimationGraphLane::evaluate(&mut animator, 0.1, 1);
+        assert_eq!(translation_x(&pose), 10.0);
+
+        AnimationGraphLane::evaluate(&mut animator, 1.0, usize::MAX);
+        assert!(!animator.is_transitioning());
+    }
+
+    #[test]
+    fn test_blend_space_follows_parameter() {
+        let locomotion = BlendSpace1D::new("speed")
+            .with_clip(0.0, constant_clip("walk", 2.0))
+            .with_clip(1.0, constant_clip("run", 6.0));
+        let graph = AnimationGraph::new(AnimationState::new(
+            "locomotion",
+            Motion::BlendSpace1D(locomotion),
+        ));
+        let mut animator = Animator::new(AssetHandle::new(graph));
+        animator.set_float("speed", 0.25);
+
+        let pose = AnimationGraphLane::evaluate(&mut animator, 0.0, usize::MAX);
+        assert!((translation_x(&pose) - 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_root_motion_is_extracted_and_stripped() {
+        // The hips move 2 units forward per 1 second loop while bobbing.
+        let walk = AssetHandle::new(AnimationClip::new("walk").with_target_track(
+            "hips",
+            TrackValues::Translation(Curve::new([
+                (0.0, Vec3::new(0.0, 1.0, 0.0)),
+                (0.5, Vec3::new(0.0, 1.2, 1.0)),
+                (1.0, Vec3::new(0.0, 1.0, 2.0)),
+            ])),
+        ));
+        let graph =
+            AnimationGraph::new(AnimationState::new("walk", Motion::Clip(walk)).with_root_motion())
+                .with_root_bone("hips");
+        let mut animator = Animator::new(AssetHandle::new(graph));
+
+        let pose = AnimationGraphLane::evaluate(&mut animator, 0.5, usize::MAX);
+        let hips = pose.iter().next().map(|(_, v)| v.clone());
+        assert_eq!(
+            hips,
+            Some(SampledValue::Translation(Vec3::new(0.0, 1.2, 0.0)))
+        );
+        assert!((animator.take_root_motion().z - 1.0).abs() < 1e-4);
+
+        // Wrapping around the loop keeps the displacement continuous.
+        AnimationGraphLane::evaluate(&mut animator, 0.75, usize::MAX);
+        assert!((animator.take_root_motion().z - 1.5).abs() < 1e-4);
+    }
+}
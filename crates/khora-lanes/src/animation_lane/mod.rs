@@ -0,0 +1,25 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Animation Lane
+//!
+//! Evaluates animation graphs: picks state transitions, blends the active
+//! motions and writes the resulting poses back into the ECS. Timelines play
+//! cutscenes on top of the animated poses. Inverse kinematics corrects them
+//! afterwards, in its own [`ik_lane`](crate::ik_lane).
+
+mod animation_graph_lane;
+mod timeline_lane;
+
+pub use animation_graph_lane::*;
+pub use timeline_lane::*;
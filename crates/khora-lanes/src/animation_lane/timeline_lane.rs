@@ -0,0 +1,323 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Cutscene evaluation for every [`TimelinePlayer`] in the world.
+
+use std::collections::HashMap;
+
+use khora_core::animation::SampledValue;
+use khora_core::ecs::entity::EntityId;
+use khora_core::lane::{AnimationDeltaTime, Lane, LaneContext, LaneError, LaneKind, Slot};
+use khora_data::ecs::systems::animation_player::apply_sample;
+use khora_data::ecs::{Camera, TimelinePlayer, World};
+use khora_data::AudioEvents;
+
+/// What one timeline evaluation asks of the world, by binding name.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TimelineFrame {
+    /// Animation values for bound entities.
+    pub samples: Vec<(String, SampledValue)>,
+    /// The camera binding that should be active.
+    pub camera: Option<String>,
+    /// Sound events to post: emitter binding and event name.
+    pub sounds: Vec<(String, String)>,
+    /// Whether playback ended or was stopped, releasing the cameras.
+    pub finished: bool,
+}
+
+/// The standard timeline lane.
+///
+/// Each execution advances every playing [`TimelinePlayer`] by
+/// [`AnimationDeltaTime`] and applies its timeline: animation tracks are
+/// written into the bound entities, the camera of the current cut becomes
+/// the only active one, crossed sound events are posted to [`AudioEvents`]
+/// when the context carries it, and crossed callbacks are queued on the
+/// player. Runs after the animation graph so cutscenes override gameplay
+/// animation.
+#[derive(Debug, Default)]
+pub struct TimelineLane;
+
+impl TimelineLane {
+    /// Creates a new `TimelineLane`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Advances `player` by `delta_seconds` and returns what its timeline
+    /// asks of the world. Returns `None` when there is nothing to apply.
+    ///
+    /// A paused player keeps returning the pose at its current time, so
+    /// seeking while paused previews the cutscene; no markers fire.
+    /// Callbacks crossed on the way are queued on the player.
+    pub fn advance(player: &mut TimelinePlayer, delta_seconds: f32) -> Option<TimelineFrame> {
+        // Stopped or finished players leave the world alone, apart from
+        // handing the view back once.
+        let stopped = !player.playing && (player.last_time.is_none() || player.is_finished());
+        let timeline = match player.timeline.clone() {
+            Some(timeline) if !stopped => timeline,
+            _ => {
+                return player.active_camera.is_some().then(|| TimelineFrame {
+                    finished: true,
+                    ..Default::default()
+                })
+            }
+        };
+
+        let duration = timeline.duration();
+        let from = player.last_time.unwrap_or(-1.0);
+        let mut wrapped = false;
+        if player.playing {
+            player.time += delta_seconds * player.speed;
+            if player.time >= duration {
+                if player.looping && duration > 0.0 {
+                    player.time %= duration;
+                    wrapped = true;
+                } else {
+                    player.time = duration;
+                    player.playing = false;
+                }
+            }
+        }
+        let crossed = if wrapped {
+            let mut crossed = timeline.crossed(from, duration);
+            crossed.extend(timeline.crossed(-1.0, player.time));
+            crossed
+        } else {
+            timeline.crossed(from, player.time)
+        };
+        player.last_time = Some(player.time);
+
+        let mut frame = TimelineFrame {
+            samples: timeline
+                .sample(player.time)
+                .map(|(binding, value)| (binding.to_owned(), value))
+                .collect(),
+            camera: timeline.camera_at(player.time).map(str::to_owned),
+            finished: player.is_finished(),
+            ..Default::default()
+        };
+        for (binding, marker) in crossed {
+            match binding {
+                Some(binding) => frame.sounds.push((binding.to_owned(), marker.name.clone())),
+                None => player.callbacks.push(marker.name.clone()),
+            }
+        }
+        Some(frame)
+    }
+
+    fn step(&self, world: &mut World, delta_seconds: f32, audio: Option<&AudioEvents>) {
+        // Phase 1: advance every player.
+        let mut frames: Vec<(EntityId, HashMap<String, EntityId>, TimelineFrame)> = Vec::new();
+        for (entity, player) in world.query_mut::<(EntityId, &mut TimelinePlayer)>() {
+            if let Some(frame) = Self::advance(player, delta_seconds) {
+                frames.push((entity, player.bindings.clone(), frame));
+            }
+        }
+        if frames.is_empty() {
+            return;
+        }
+
+        let resolve = |world: &World, bindings: &HashMap<String, EntityId>, name: &str| {
+            bindings
+                .get(name)
+                .copied()
+                .or_else(|| world.find_by_name(name))
+        };
+
+        // Phase 2: apply the frames.
+        for (player_entity, bindings, frame) in frames {
+            for (binding, value) in frame.samples {
+                if let Some(entity) = resolve(world, &bindings, &binding) {
+                    apply_sample(world, entity, value);
+                }
+            }
+
+            if let Some(audio) = audio {
+                for (binding, event) in &frame.sounds {
+                    match resolve(world, &bindings, binding) {
+                        Some(emitter) => audio.post_event(event.clone(), emitter),
+                        None => log::warn!("Timeline sound '{}': unbound '{}'", event, binding),
+                    }
+                }
+            }
+
+            let camera = frame
+                .camera
+                .as_deref()
+                .and_then(|name| resolve(world, &bindings, name));
+            if frame.finished {
+                Self::release_cameras(world, player_entity);
+            } else if let Some(camera) = camera {
+                Self::cut_to(world, player_entity, camera);
+            }
+        }
+    }
+
+    /// Makes `camera` the only active camera, remembering which cameras
+    /// were active before the timeline took over.
+    fn cut_to(world: &mut World, player_entity: EntityId, camera: EntityId) {
+        let first_cut = match world.get::<TimelinePlayer>(player_entity) {
+            Some(player) if player.active_camera == Some(camera) => return,
+            Some(player) => player.active_camera.is_none(),
+            None => return,
+        };
+        let mut suspended = Vec::new();
+        for (entity, cam) in world.query_mut::<(EntityId, &mut Camera)>() {
+            if entity == camera {
+                cam.is_active = true;
+            } else if cam.is_active {
+                cam.is_active = false;
+                suspended.push(entity);
+            }
+        }
+        if let Some(player) = world.get_mut::<TimelinePlayer>(player_entity) {
+            player.active_camera = Some(camera);
+            if first_cut {
+                player.suspended_cameras = suspended;
+            }
+        }
+    }
+
+    /// Hands the view back to the cameras active before the first cut.
+    fn release_cameras(world: &mut World, player_entity: EntityId) {
+        let Some(player) = world.get_mut::<TimelinePlayer>(player_entity) else {
+            return;
+        };
+        let Some(active) = player.active_camera.take() else {
+            return;
+        };
+        let suspended = std::mem::take(&mut player.suspended_cameras);
+        if let Some(cam) = world.get_mut::<Camera>(active) {
+            cam.is_active = false;
+        }
+        for entity in suspended {
+            if let Some(cam) = world.get_mut::<Camera>(entity) {
+                cam.is_active = true;
+            }
+        }
+    }
+}
+
+impl Lane for TimelineLane {
+    fn strategy_name(&self) -> &'static str {
+        "Timeline"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Animation
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let delta_seconds = ctx
+            .get::<AnimationDeltaTime>()
+            .ok_or(LaneError::missing("AnimationDeltaTime"))?
+            .0;
+        let audio = ctx.get::<AudioEvents>().cloned();
+        let world = ctx
+            .get::<Slot<World>>()
+            .ok_or(LaneError::missing("Slot<World>"))?
+            .get();
+
+        self.step(world, delta_seconds, audio.as_ref());
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::animation::{Curve, Timeline, TimelineMarker, TimelineTrack, TrackValues};
+    use khora_core::asset::AssetHandle;
+    use khora_core::math::Vec3;
+    use khora_data::ecs::{Name, Transform};
+
+    fn cutscene() -> AssetHandle<Timeline> {
+        AssetHandle::new(
+            Timeline::new("intro")
+                .with_track(TimelineTrack::CameraCuts(vec![TimelineMarker::new(
+                    0.0, "shot",
+                )]))
+                .with_track(TimelineTrack::Animation {
+                    binding: "hero".into(),
+                    values: TrackValues::Translation(Curve::new([
+                        (0.0, Vec3::ZERO),
+                        (2.0, Vec3::new(2.0, 0.0, 0.0)),
+                    ])),
+                })
+                .with_track(TimelineTrack::Callbacks(vec![
+                    TimelineMarker::new(0.0, "start"),
+                    TimelineMarker::new(1.5, "door"),
+                ])),
+        )
+    }
+
+    #[test]
+    fn test_callbacks_fire_once_and_playback_ends() {
+        let mut player = TimelinePlayer::new(cutscene());
+
+        TimelineLane::advance(&mut player, 0.5).unwrap();
+        assert_eq!(player.take_callbacks(), vec!["start"]);
+
+        TimelineLane::advance(&mut player, 0.5).unwrap();
+        assert!(player.take_callbacks().is_empty());
+
+        let frame = TimelineLane::advance(&mut player, 5.0).unwrap();
+        assert_eq!(player.take_callbacks(), vec!["door"]);
+        assert!(frame.finished);
+        assert!(!player.playing);
+        assert_eq!(player.time, 2.0);
+    }
+
+    #[test]
+    fn test_looping_wraps_and_refires_markers() {
+        let mut player = TimelinePlayer::new(cutscene());
+        player.looping = true;
+        TimelineLane::advance(&mut player, 1.0);
+        player.take_callbacks();
+
+        TimelineLane::advance(&mut player, 1.5);
+        assert_eq!(player.take_callbacks(), vec!["door", "start"]);
+        assert!((player.time - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cuts_take_over_the_view_and_hand_it_back() {
+        let mut world = World::new();
+        let gameplay = world.spawn(Camera::default_perspective());
+        let mut shot_camera = Camera::default_perspective();
+        shot_camera.is_active = false;
+        let shot = world.spawn((shot_camera, Name::new("shot")));
+        let hero = world.spawn((Transform::identity(), Name::new("hero")));
+        world.spawn(TimelinePlayer::new(cutscene()));
+
+        let lane = TimelineLane::new();
+        lane.step(&mut world, 1.0, None);
+        assert!(world.get::<Camera>(shot).unwrap().is_active);
+        assert!(!world.get::<Camera>(gameplay).unwrap().is_active);
+        let x = world.get::<Transform>(hero).unwrap().translation.x;
+        assert!((x - 1.0).abs() < 1e-5);
+
+        lane.step(&mut world, 2.0, None);
+        lane.step(&mut world, 0.1, None);
+        assert!(world.get::<Camera>(gameplay).unwrap().is_active);
+        assert!(!world.get::<Camera>(shot).unwrap().is_active);
+    }
+}
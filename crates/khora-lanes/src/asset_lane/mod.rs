@@ -0,0 +1,25 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Lane
+//!
+//! Releases assets nothing references anymore: cached CPU copies held by
+//! the `AssetService` and uploaded meshes held by the `GpuCache`. Also
+//! loads queued assets ahead of use, at the quality the agent picked.
+
+mod prefetch_lane;
+mod unload_lane;
+
+pub use prefetch_lane::*;
+pub use unload_lane::*;
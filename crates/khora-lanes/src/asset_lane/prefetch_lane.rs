@@ -0,0 +1,83 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Works through the assets queued for prefetch at the agent's load quality.
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::asset::AssetLoadQuality;
+use khora_core::lane::{
+    AssetPrefetchLimit, AssetsPrefetched, Lane, LaneContext, LaneError, LaneKind,
+};
+use khora_io::asset::AssetService;
+
+/// The asset prefetch lane.
+///
+/// Applies the [`AssetLoadQuality`] found in the context to the
+/// `AssetService`, so later loads use it too, then completes at most
+/// [`AssetPrefetchLimit`] queued prefetches. Without a quality in the
+/// context the service keeps its current one.
+///
+/// Writes [`AssetsPrefetched`] back into the context.
+#[derive(Debug, Default)]
+pub struct AssetPrefetchLane;
+
+impl AssetPrefetchLane {
+    /// Creates a new `AssetPrefetchLane`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Lane for AssetPrefetchLane {
+    fn strategy_name(&self) -> &'static str {
+        "AssetPrefetch"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Asset
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let limit = ctx.get::<AssetPrefetchLimit>().map_or(usize::MAX, |l| l.0);
+        let quality = ctx.get::<AssetLoadQuality>().copied();
+        let mut prefetched = 0;
+
+        if let Some(service) = ctx.get::<Arc<Mutex<AssetService>>>() {
+            match service.lock() {
+                Ok(mut service) => {
+                    if let Some(quality) = quality {
+                        service.set_load_quality(quality);
+                    }
+                    prefetched = service.process_prefetch(limit);
+                }
+                Err(_) => log::warn!("AssetPrefetchLane: AssetService lock poisoned"),
+            }
+        }
+
+        if prefetched > 0 {
+            log::debug!("AssetPrefetchLane: prefetched {} asset(s)", prefetched);
+        }
+        ctx.insert(AssetsPrefetched(prefetched));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unloads assets that stayed unreferenced for the configured grace period.
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use khora_core::lane::{AssetUnloadLimit, AssetsUnloaded, Lane, LaneContext, LaneError, LaneKind};
+use khora_core::renderer::GraphicsDevice;
+use khora_data::assets::AssetUnloadConfig;
+use khora_data::GpuCache;
+use khora_io::asset::AssetService;
+
+/// The asset unload lane.
+///
+/// Sweeps the `AssetService` storages, then the `GpuCache`, releasing at
+/// most [`AssetUnloadLimit`] assets per run, oldest unused first. An asset
+/// counts as unused once its storage holds the only handle left, and is
+/// released after [`AssetUnloadConfig::grace_period`].
+///
+/// GPU meshes are only swept when a graphics device is in the context, so
+/// their buffers are destroyed rather than leaked. A mesh unloaded from
+/// the cache is uploaded again by the projection if an entity uses it.
+///
+/// Writes [`AssetsUnloaded`] back into the context.
+#[derive(Debug, Default)]
+pub struct AssetUnloadLane;
+
+impl AssetUnloadLane {
+    /// Creates a new `AssetUnloadLane`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Releases unused GPU meshes and destroys their buffers.
+    fn sweep_gpu(
+        cache: &GpuCache,
+        device: &dyn GraphicsDevice,
+        now: Instant,
+        config: &AssetUnloadConfig,
+        limit: usize,
+    ) -> usize {
+        let swept = match cache.0.write() {
+            Ok(mut meshes) => meshes.sweep_unused(now, config.grace_period, limit),
+            Err(_) => {
+                log::warn!("AssetUnloadLane: GpuCache lock poisoned");
+                return 0;
+            }
+        };
+        for (uuid, mesh) in &swept {
+            for buffer in [mesh.vertex_buffer, mesh.index_buffer] {
+                if let Err(e) = device.destroy_buffer(buffer) {
+                    log::warn!("Failed to destroy a buffer of mesh {:?}: {:?}", uuid, e);
+                }
+            }
+        }
+        swept.len()
+    }
+}
+
+impl Lane for AssetUnloadLane {
+    fn strategy_name(&self) -> &'static str {
+        "AssetUnload"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Asset
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let limit = ctx.get::<AssetUnloadLimit>().map_or(usize::MAX, |l| l.0);
+        let config = ctx.get::<AssetUnloadConfig>().copied().unwrap_or_default();
+        let now = Instant::now();
+        let mut unloaded = 0;
+
+        if let Some(service) = ctx.get::<Arc<Mutex<AssetService>>>() {
+            match service.lock() {
+                Ok(mut service) => {
+                    unloaded += service.unload_unused(now, config.grace_period, limit);
+                }
+                Err(_) => log::warn!("AssetUnloadLane: AssetService lock poisoned"),
+            }
+        }
+
+        if let (Some(cache), Some(device)) =
+            (ctx.get::<GpuCache>(), ctx.get::<Arc<dyn GraphicsDevice>>())
+        {
+            unloaded += Self::sweep_gpu(cache, device.as_ref(), now, &config, limit - unloaded);
+        }
+
+        if unloaded > 0 {
+            log::debug!("AssetUnloadLane: unloaded {} unused asset(s)", unloaded);
+        }
+        ctx.insert(AssetsUnloaded(unloaded));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::asset::{AssetHandle, AssetUUID};
+    use khora_core::renderer::api::{
+        pipeline::PrimitiveTopology, resource::BufferId, scene::GpuMesh, util::IndexFormat,
+    };
+
+    #[test]
+    fn test_asset_unload_lane_creation() {
+        let lane = AssetUnloadLane::new();
+        assert_eq!(lane.strategy_name(), "AssetUnload");
+        assert_eq!(lane.lane_kind(), LaneKind::Asset);
+    }
+
+    #[test]
+    fn test_gpu_cache_is_kept_without_a_device() {
+        let cache = GpuCache::new();
+        let uuid = AssetUUID::new_v5("mesh");
+        cache.0.write().unwrap().insert(
+            uuid,
+            AssetHandle::new(GpuMesh {
+                vertex_buffer: BufferId(0),
+                index_buffer: BufferId(1),
+                index_count: 0,
+                index_format: IndexFormat::Uint32,
+                primitive_topology: PrimitiveTopology::TriangleList,
+            }),
+        );
+
+        let mut ctx = LaneContext::new();
+        ctx.insert(cache.clone());
+        ctx.insert(AssetUnloadConfig::with_grace_period(
+            std::time::Duration::ZERO,
+        ));
+        AssetUnloadLane::new().execute(&mut ctx).unwrap();
+
+        assert!(cache.0.read().unwrap().contains(&uuid));
+        assert_eq!(ctx.get::<AssetsUnloaded>().map(|u| u.0), Some(0));
+    }
+}
@@ -15,7 +15,11 @@
 //! The core audio processing lane, responsible for mixing and spatializing sound sources.
 
 use khora_core::audio::device::StreamInfo;
-use khora_data::ecs::{AudioListener, AudioSource, GlobalTransform, PlaybackState, World};
+use khora_core::lane::AudioMixMode;
+use khora_core::math::affine_transform::AffineTransform;
+use khora_data::ecs::{
+    AudioListener, AudioSource, Disabled, GlobalTransform, PlaybackState, Without, World,
+};
 
 /// A lane that performs spatialized audio mixing.
 #[derive(Default)]
@@ -41,7 +45,9 @@ impl khora_core::lane::Lane for SpatialMixingLane {
         &self,
         ctx: &mut khora_core::lane::LaneContext,
     ) -> Result<(), khora_core::lane::LaneError> {
-        use khora_core::lane::{AudioOutputSlot, AudioStreamInfo, LaneError, Slot};
+        use khora_core::lane::{
+            AudioOutputSlot, AudioStreamInfo, AudioVoiceLimit, LaneError, Slot,
+        };
 
         let stream_info = ctx
             .get::<AudioStreamInfo>()
@@ -56,7 +62,12 @@ impl khora_core::lane::Lane for SpatialMixingLane {
             .ok_or(LaneError::missing("Slot<World>"))?
             .get();
 
-        self.mix(world, output_buffer, &stream_info);
+        let mode = ctx.get::<AudioMixMode>().copied().unwrap_or_default();
+        let voice_limit = ctx
+            .get::<AudioVoiceLimit>()
+            .map_or(usize::MAX, |limit| limit.0);
+
+        self.mix_with(world, output_buffer, &stream_info, mode, voice_limit);
         Ok(())
     }
 
@@ -70,23 +81,63 @@ impl khora_core::lane::Lane for SpatialMixingLane {
 }
 
 impl SpatialMixingLane {
-    /// Mixes all active `AudioSource`s into a single output buffer, applying 3D spatialization.
+    /// Mixes all active `AudioSource`s into a single output buffer, applying
+    /// 3D spatialization, with no voice limit.
     pub fn mix(&self, world: &mut World, output_buffer: &mut [f32], stream_info: &StreamInfo) {
+        self.mix_with(
+            world,
+            output_buffer,
+            stream_info,
+            AudioMixMode::Spatial,
+            usize::MAX,
+        );
+    }
+
+    /// Mixes the active `AudioSource`s into a single output buffer.
+    ///
+    /// `mode` selects the spatialization and effects applied to each source.
+    /// At most `voice_limit` sources are mixed audibly, picked by their
+    /// volume at the listener; the others advance silently.
+    pub fn mix_with(
+        &self,
+        world: &mut World,
+        output_buffer: &mut [f32],
+        stream_info: &StreamInfo,
+        mode: AudioMixMode,
+        voice_limit: usize,
+    ) {
         output_buffer.fill(0.0);
 
         // --- Step 1: Find the listener (if any) ---
         let listener_transform = world
-            .query::<(&AudioListener, &GlobalTransform)>()
+            .query::<(&AudioListener, &GlobalTransform, Without<Disabled>)>()
             .next()
-            .map(|(_, t)| t.0);
-
-        // --- Step 2 & 3: Process and mix all active sources ---
+            .map(|(_, t, _)| t.0);
+        let spatial = mode != AudioMixMode::Stereo;
+
+        // --- Step 2: Pick the audible voices ---
+        // Queries over an unchanged world yield sources in the same order,
+        // so the mask built here lines up with the mixing pass below.
+        // Disabled sources are left out of both: they pause where they are.
+        let gains: Vec<Option<SourceGain>> = world
+            .query::<(&AudioSource, &GlobalTransform, Without<Disabled>)>()
+            .map(|(source, transform, _)| {
+                let playing = source.state.is_some() || source.autoplay;
+                playing.then(|| SourceGain::compute(source, transform, listener_transform, spatial))
+            })
+            .collect();
+        let audible = Self::select_voices(&gains, voice_limit);
+
+        // --- Step 3: Process and mix all active sources ---
         let samples_to_write = output_buffer.len() / stream_info.channels as usize;
 
-        for (source, source_transform) in world.query_mut::<(&mut AudioSource, &GlobalTransform)>()
-        {
-            if source.autoplay && source.state.is_none() {
-                source.state = Some(PlaybackState { cursor: 0.0 });
+        let sources = world.query_mut::<(&mut AudioSource, &GlobalTransform, Without<Disabled>)>();
+        for (((source, _, _), gain), audible) in sources.zip(gains).zip(audible) {
+            let Some(gain) = gain else {
+                continue;
+            };
+            if source.state.is_none() {
+                source.state = Some(PlaybackState::start());
             }
 
             let sound_data = &source.handle;
@@ -98,46 +149,52 @@ impl SpatialMixingLane {
                 continue;
             }
 
-            let resample_ratio = sound_data.sample_rate as f32 / stream_info.sample_rate as f32;
-            let (mut volume, mut pan) = (source.volume, 0.5);
+            let resample_ratio = sound_data.sample_rate as f32 / stream_info.sample_rate as f32
+                * source.pitch.max(0.0);
 
-            if let Some(listener_mat) = listener_transform {
-                let source_pos = source_transform.0.translation();
-                let listener_pos = listener_mat.translation();
-                let listener_right = listener_mat.right();
-                let to_source = source_pos - listener_pos;
-                let distance = to_source.length();
-
-                volume *= 1.0 / (1.0 + distance * distance);
-                if distance > 0.001 {
-                    pan = (to_source.normalize().dot(listener_right) + 1.0) * 0.5;
-                }
+            if !audible {
+                Self::advance_silently(
+                    source,
+                    num_frames,
+                    resample_ratio * samples_to_write as f32,
+                );
+                continue;
             }
 
-            let vol_l = volume * (1.0 - pan).sqrt();
-            let vol_r = volume * pan.sqrt();
+            let (vol_l, vol_r) = if spatial {
+                (
+                    gain.volume * (1.0 - gain.pan).sqrt(),
+                    gain.volume * gain.pan.sqrt(),
+                )
+            } else {
+                (gain.volume, gain.volume)
+            };
+            let lowpass_alpha = match mode {
+                AudioMixMode::SpatialEffects => 1.0 / (1.0 + gain.distance * AIR_ABSORPTION),
+                _ => 1.0,
+            };
 
             for i in 0..samples_to_write {
-                // Get a mutable reference to the cursor for this iteration.
+                // Get a mutable reference to the state for this iteration.
                 // If the state becomes None mid-loop, we stop processing this source.
-                let cursor = if let Some(state) = source.state.as_mut() {
-                    &mut state.cursor
+                let state = if let Some(state) = source.state.as_mut() {
+                    state
                 } else {
                     break;
                 };
 
                 // --- Robust End-of-Sound and Loop Handling ---
-                if *cursor >= num_frames as f32 {
+                if state.cursor >= num_frames as f32 {
                     if source.looping {
-                        *cursor %= num_frames as f32;
+                        state.cursor %= num_frames as f32;
                     } else {
                         source.state = None;
                         break; // Stop processing samples for this source
                     }
                 }
 
-                let cursor_floor = cursor.floor() as usize;
-                let cursor_fract = cursor.fract();
+                let cursor_floor = state.cursor.floor() as usize;
+                let cursor_fract = state.cursor.fract();
 
                 // For looping sounds, the next sample might wrap around to the beginning.
                 let next_frame_idx = (cursor_floor + 1) % num_frames;
@@ -153,7 +210,13 @@ impl SpatialMixingLane {
 
                 let s1 = sound_data.samples[s1_idx];
                 let s2 = sound_data.samples[s2_idx];
-                let sample = s1 + (s2 - s1) * cursor_fract;
+                let mut sample = s1 + (s2 - s1) * cursor_fract;
+
+                // Air absorption: a one-pole low-pass, stronger with distance.
+                if lowpass_alpha < 1.0 {
+                    state.lowpass += lowpass_alpha * (sample - state.lowpass);
+                    sample = state.lowpass;
+                }
 
                 // Mix into output buffer
                 let out_idx = i * stream_info.channels as usize;
@@ -161,11 +224,11 @@ impl SpatialMixingLane {
                     output_buffer[out_idx] += sample * vol_l;
                     output_buffer[out_idx + 1] += sample * vol_r;
                 } else {
-                    output_buffer[out_idx] += sample * volume;
+                    output_buffer[out_idx] += sample * gain.volume;
                 }
 
                 // Advance cursor
-                *cursor += resample_ratio;
+                state.cursor += resample_ratio;
             }
         }
 
@@ -174,6 +237,83 @@ impl SpatialMixingLane {
             *sample = sample.clamp(-1.0, 1.0);
         }
     }
+
+    /// Marks the `voice_limit` loudest playing sources as audible.
+    fn select_voices(gains: &[Option<SourceGain>], voice_limit: usize) -> Vec<bool> {
+        let mut playing: Vec<usize> = (0..gains.len()).filter(|&i| gains[i].is_some()).collect();
+        let mut audible = vec![false; gains.len()];
+        if playing.len() > voice_limit {
+            playing.sort_by(|&a, &b| {
+                let volume = |i: usize| gains[i].map_or(0.0, |g| g.volume);
+                volume(b).total_cmp(&volume(a))
+            });
+            playing.truncate(voice_limit);
+        }
+        for i in playing {
+            audible[i] = true;
+        }
+        audible
+    }
+
+    /// Moves a virtual voice's cursor forward by `frames` without mixing it.
+    fn advance_silently(source: &mut AudioSource, num_frames: usize, frames: f32) {
+        let Some(state) = source.state.as_mut() else {
+            return;
+        };
+        state.cursor += frames;
+        if state.cursor >= num_frames as f32 {
+            if source.looping {
+                state.cursor %= num_frames as f32;
+            } else {
+                source.state = None;
+            }
+        }
+    }
+}
+
+/// Low-pass strength of air absorption, per meter from the listener.
+const AIR_ABSORPTION: f32 = 0.02;
+
+/// The volume and position of a source as heard by the listener.
+#[derive(Debug, Clone, Copy)]
+struct SourceGain {
+    /// Volume after distance attenuation.
+    volume: f32,
+    /// Stereo position, from `0.0` (left) to `1.0` (right).
+    pan: f32,
+    /// Distance to the listener, or `0.0` without one.
+    distance: f32,
+}
+
+impl SourceGain {
+    fn compute(
+        source: &AudioSource,
+        transform: &GlobalTransform,
+        listener: Option<AffineTransform>,
+        spatial: bool,
+    ) -> Self {
+        let mut gain = Self {
+            volume: source.volume,
+            pan: 0.5,
+            distance: 0.0,
+        };
+        let Some(listener_mat) = listener.filter(|_| spatial) else {
+            return gain;
+        };
+
+        let source_pos = transform.0.translation();
+        let listener_pos = listener_mat.translation();
+        let listener_right = listener_mat.right();
+        let to_source = source_pos - listener_pos;
+        let distance = to_source.length();
+
+        gain.volume *= 1.0 / (1.0 + distance * distance);
+        gain.distance = distance;
+        if distance > 0.001 {
+            gain.pan = (to_source.normalize().dot(listener_right) + 1.0) * 0.5;
+        }
+        gain
+    }
 }
 
 #[cfg(test)]
@@ -221,6 +361,7 @@ mod tests {
                 autoplay: true,
                 looping: false,
                 volume: 1.0,
+                pitch: 1.0,
                 state: None,
             },
             GlobalTransform(AffineTransform::from_translation(Vec3::new(10.0, 0.0, 0.0))),
@@ -267,6 +408,7 @@ mod tests {
                 autoplay: true,
                 looping: true,
                 volume: 1.0,
+                pitch: 1.0,
                 state: None,
             },
             GlobalTransform(AffineTransform::from_translation(Vec3::new(1.0, 0.0, 0.0))),
@@ -285,6 +427,7 @@ mod tests {
                 autoplay: true,
                 looping: true,
                 volume: 1.0,
+                pitch: 1.0,
                 state: None,
             },
             GlobalTransform(AffineTransform::from_translation(Vec3::new(
@@ -319,6 +462,7 @@ mod tests {
                 autoplay: true,
                 looping: false, // Does not loop
                 volume: 1.0,
+                pitch: 1.0,
                 state: None,
             },
             GlobalTransform::default(),
@@ -361,6 +505,7 @@ mod tests {
                 autoplay: true,
                 looping: true, // Loops
                 volume: 1.0,
+                pitch: 1.0,
                 state: None,
             },
             GlobalTransform::default(),
@@ -383,4 +528,104 @@ mod tests {
         assert!(!approx_eq(buffer[6], 0.0)); // Middle (after loop)
         assert!(!approx_eq(buffer[11], 0.0)); // End
     }
+
+    fn spawn_looping_source(world: &mut World, x: f32) -> khora_core::ecs::entity::EntityId {
+        world.spawn((
+            AudioSource {
+                handle: create_test_sound(1024, 44100),
+                autoplay: true,
+                looping: true,
+                volume: 1.0,
+                pitch: 1.0,
+                state: None,
+            },
+            GlobalTransform(AffineTransform::from_translation(Vec3::new(x, 0.0, 0.0))),
+        ))
+    }
+
+    #[test]
+    fn test_voice_limit_keeps_loudest_and_advances_the_rest() {
+        let mut world = World::new();
+        let stream_info = StreamInfo {
+            channels: 2,
+            sample_rate: 44100,
+        };
+        world.spawn((AudioListener, GlobalTransform(AffineTransform::IDENTITY)));
+        let near = spawn_looping_source(&mut world, 1.0);
+        let far = spawn_looping_source(&mut world, 50.0);
+
+        let mut limited = vec![0.0; 128];
+        SpatialMixingLane::new().mix_with(
+            &mut world,
+            &mut limited,
+            &stream_info,
+            AudioMixMode::Spatial,
+            1,
+        );
+
+        let mut near_only = World::new();
+        near_only.spawn((AudioListener, GlobalTransform(AffineTransform::IDENTITY)));
+        spawn_looping_source(&mut near_only, 1.0);
+        let mut expected = vec![0.0; 128];
+        SpatialMixingLane::new().mix(&mut near_only, &mut expected, &stream_info);
+
+        assert!(limited
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| approx_eq(*a, *b)));
+        // The culled voice kept playing in the background.
+        let cursor = |id| {
+            world
+                .get::<AudioSource>(id)
+                .unwrap()
+                .state
+                .as_ref()
+                .unwrap()
+                .cursor
+        };
+        assert!(approx_eq(cursor(far), cursor(near)));
+    }
+
+    #[test]
+    fn test_stereo_mode_ignores_distance_and_panning() {
+        let mut world = World::new();
+        let stream_info = StreamInfo {
+            channels: 2,
+            sample_rate: 44100,
+        };
+        world.spawn((AudioListener, GlobalTransform(AffineTransform::IDENTITY)));
+        spawn_looping_source(&mut world, 100.0);
+
+        let mut buffer = vec![0.0; 128];
+        SpatialMixingLane::new().mix_with(
+            &mut world,
+            &mut buffer,
+            &stream_info,
+            AudioMixMode::Stereo,
+            usize::MAX,
+        );
+
+        let peak = buffer.iter().map(|s| s.abs()).fold(0.0, f32::max);
+        assert!(peak > 0.5, "Stereo passthrough should not attenuate");
+        assert!(buffer.chunks(2).all(|frame| approx_eq(frame[0], frame[1])));
+    }
+
+    #[test]
+    fn test_air_absorption_damps_distant_high_frequencies() {
+        let stream_info = StreamInfo {
+            channels: 1,
+            sample_rate: 44100,
+        };
+        // The test sound is `sin(i)`, a tone far above what air absorption keeps.
+        let energy = |mode| {
+            let mut world = World::new();
+            world.spawn((AudioListener, GlobalTransform(AffineTransform::IDENTITY)));
+            spawn_looping_source(&mut world, 30.0);
+            let mut buffer = vec![0.0; 256];
+            SpatialMixingLane::new().mix_with(&mut world, &mut buffer, &stream_info, mode, 8);
+            buffer.iter().map(|s| s * s).sum::<f32>()
+        };
+
+        assert!(energy(AudioMixMode::SpatialEffects) < energy(AudioMixMode::Spatial) * 0.9);
+    }
 }
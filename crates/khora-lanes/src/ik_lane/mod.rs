@@ -0,0 +1,22 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! # Inverse Kinematics Lane
+//!
+//! Corrects animated poses towards world-space targets with two-bone and
+//! FABRIK solvers. A lane kind of its own, so GORNA budgets it apart from
+//! animation sampling.
+
+mod solver_lane;
+
+pub use solver_lane::*;
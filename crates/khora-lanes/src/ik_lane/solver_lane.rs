@@ -0,0 +1,237 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Inverse kinematics for every enabled [`IkConstraint`] in the world.
+
+use khora_core::animation::ik::{solve_fabrik, solve_two_bone};
+use khora_core::ecs::entity::EntityId;
+use khora_core::lane::{
+    IkChainsSolved, IkIterationLimit, Lane, LaneContext, LaneError, LaneKind, Slot,
+};
+use khora_core::math::{Quaternion, Vec3};
+use khora_data::ecs::{IkConstraint, IkSolver, Parent, Transform, World};
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// A world-space rigid transform with per-axis scale.
+#[derive(Debug, Clone, Copy)]
+struct WorldPose {
+    translation: Vec3,
+    rotation: Quaternion,
+    scale: Vec3,
+}
+
+impl WorldPose {
+    const IDENTITY: Self = Self {
+        translation: Vec3::ZERO,
+        rotation: Quaternion::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
+    fn compose(&self, local: &Transform) -> Self {
+        Self {
+            translation: self.translation
+                + self.rotation.rotate_vec3(self.scale * local.translation),
+            rotation: (self.rotation * local.rotation).normalize(),
+            scale: self.scale * local.scale,
+        }
+    }
+}
+
+/// The inverse kinematics lane.
+///
+/// Runs after the animation lanes so it corrects the animated pose.
+/// Solved joint positions are turned back into local rotations, keeping
+/// bone lengths and the rest of the hierarchy intact. Poses are composed
+/// from local `Transform`s rather than `GlobalTransform`, which is not
+/// propagated until the next substrate pass.
+#[derive(Debug, Default)]
+pub struct InverseKinematicsLane;
+
+impl InverseKinematicsLane {
+    /// Creates a new `InverseKinematicsLane`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns the world pose of `entity`, composed from local transforms.
+    fn world_pose(world: &World, entity: EntityId) -> WorldPose {
+        let mut ancestry = vec![entity];
+        let mut current = entity;
+        while let Some(parent) = world.get::<Parent>(current) {
+            current = parent.0;
+            ancestry.push(current);
+        }
+        ancestry.iter().rev().fold(WorldPose::IDENTITY, |pose, &e| {
+            match world.get::<Transform>(e) {
+                Some(local) => pose.compose(local),
+                None => pose,
+            }
+        })
+    }
+
+    /// Returns the chain from its root down to `effector`, or `None` if the
+    /// effector has fewer than `length` ancestors.
+    fn chain(world: &World, effector: EntityId, length: usize) -> Option<Vec<EntityId>> {
+        let mut chain = vec![effector];
+        for _ in 0..length {
+            let parent = world.get::<Parent>(*chain.last()?)?.0;
+            chain.push(parent);
+        }
+        chain.reverse();
+        Some(chain)
+    }
+
+    /// Solves `constraint` for `effector`, rotating its ancestors in place.
+    fn solve(world: &mut World, effector: EntityId, constraint: &IkConstraint, iterations: usize) {
+        let Some(chain) = Self::chain(world, effector, constraint.chain_length()) else {
+            log::trace!(
+                "IK: effector {:?} has a shorter chain than required",
+                effector
+            );
+            return;
+        };
+        let root_parent = match world.get::<Parent>(chain[0]) {
+            Some(parent) => Self::world_pose(world, parent.0),
+            None => WorldPose::IDENTITY,
+        };
+
+        // 1. Forward kinematics for the current (animated) pose.
+        let mut poses = Vec::with_capacity(chain.len());
+        let mut parent_pose = root_parent;
+        for &joint in &chain {
+            let Some(local) = world.get::<Transform>(joint) else {
+                return;
+            };
+            parent_pose = parent_pose.compose(local);
+            poses.push(parent_pose);
+        }
+        let animated: Vec<Vec3> = poses.iter().map(|p| p.translation).collect();
+
+        // 2. Solve for new joint positions.
+        let mut solved = animated.clone();
+        match constraint.solver {
+            IkSolver::TwoBone { pole } => {
+                let (mid, end) =
+                    solve_two_bone(solved[0], solved[1], solved[2], constraint.target, pole);
+                solved[1] = mid;
+                solved[2] = end;
+            }
+            IkSolver::Fabrik { tolerance, .. } => {
+                solve_fabrik(&mut solved, constraint.target, tolerance, iterations);
+            }
+            _ => {
+                log::trace!("IK: effector {:?} uses an unsupported solver", effector);
+                return;
+            }
+        }
+        let weight = constraint.weight.clamp(0.0, 1.0);
+        for (s, a) in solved.iter_mut().zip(&animated) {
+            *s = Vec3::lerp(*a, *s, weight);
+        }
+
+        // 3. Rotate each joint so its child lands on the solved position.
+        let mut parent_pose = root_parent;
+        for i in 0..chain.len() - 1 {
+            let Some(local) = world.get::<Transform>(chain[i]).copied() else {
+                return;
+            };
+            let pose = parent_pose.compose(&local);
+            let Some(child_local) = world.get::<Transform>(chain[i + 1]) else {
+                return;
+            };
+            let child = pose.compose(child_local).translation;
+            let delta = Quaternion::from_rotation_arc(
+                child - pose.translation,
+                solved[i + 1] - pose.translation,
+            );
+            let rotation = (parent_pose.rotation.inverse() * delta * pose.rotation).normalize();
+            if let Some(t) = world.get_mut::<Transform>(chain[i]) {
+                t.rotation = rotation;
+            }
+            parent_pose = parent_pose.compose(&Transform { rotation, ..local });
+        }
+    }
+
+    /// Solves every enabled constraint and returns how many there were.
+    fn step(&self, world: &mut World, iterations: usize) -> usize {
+        let constraints: Vec<(EntityId, IkConstraint)> = world
+            .query::<(EntityId, &IkConstraint)>()
+            .filter(|(_, c)| c.enabled && c.weight > 0.0)
+            .map(|(e, c)| (e, *c))
+            .collect();
+        let count = constraints.len();
+        for (effector, constraint) in constraints {
+            Self::solve(world, effector, &constraint, iterations);
+        }
+        count
+    }
+}
+
+impl Lane for InverseKinematicsLane {
+    fn strategy_name(&self) -> &'static str {
+        "InverseKinematics"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::InverseKinematics
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let iterations = ctx
+            .get::<IkIterationLimit>()
+            .map_or(DEFAULT_ITERATIONS, |limit| limit.0);
+        let world = ctx
+            .get::<Slot<World>>()
+            .ok_or(LaneError::missing("Slot<World>"))?
+            .get();
+
+        let solved = self.step(world, iterations);
+        ctx.insert(IkChainsSolved(solved));
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_bone_constraint_reaches_target() {
+        let mut world = World::new();
+        let shoulder = world.spawn(Transform::identity());
+        let elbow = world.spawn((
+            Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+            Parent(shoulder),
+        ));
+        let target = Vec3::new(1.0, 1.0, 0.0);
+        let hand = world.spawn((
+            Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+            Parent(elbow),
+            IkConstraint::two_bone(target, Some(Vec3::new(0.0, 0.0, 1.0))),
+        ));
+
+        InverseKinematicsLane::new().step(&mut world, DEFAULT_ITERATIONS);
+
+        let reached = InverseKinematicsLane::world_pose(&world, hand).translation;
+        assert!(reached.distance(target) < 1e-3);
+    }
+}
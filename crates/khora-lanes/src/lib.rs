@@ -18,7 +18,10 @@
 
 #![warn(missing_docs)]
 
+pub mod animation_lane;
+pub mod asset_lane;
 pub mod audio_lane;
+pub mod ik_lane;
 pub mod physics_lane;
 pub mod render_lane;
 pub mod ui_lane;
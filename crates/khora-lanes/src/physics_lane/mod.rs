@@ -15,21 +15,50 @@
 //! Physics Lane
 //!
 //! The physics lane is responsible for synchronizing the physics world with the ECS world.
+//!
+//! Physics lanes take the world and the provider as `Slot`s when they run on
+//! the frame thread. Off it, they take a [`WorldChannel`] and the
+//! [`SharedPhysicsProvider`] instead: the world work is sent to the frame
+//! thread, and only the simulation step runs where the lane does.
 
 mod native_lanes;
 mod physics_debug_lane;
+mod physics_interpolation_lane;
 
 pub use native_lanes::*;
 pub use physics_debug_lane::*;
+pub use physics_interpolation_lane::*;
 
 use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use khora_core::ecs::entity::EntityId;
-use khora_core::physics::{ColliderDesc, PhysicsProvider, RigidBodyDesc};
-use khora_data::ecs::{Collider, GlobalTransform, Parent, RigidBody, Transform, World};
+use khora_core::lane::LaneError;
+use khora_core::math::Vec3;
+use khora_core::physics::{ColliderDesc, CollisionEvent, PhysicsProvider, RigidBodyDesc};
+use khora_data::ecs::{
+    ActiveEvents, Collider, Disabled, GlobalTransform, GravityZone, Parent, PhysicsInterpolation,
+    PhysicsMaterial, PhysicsPose, RigidBody, SimulationLod, Transform, Without, World,
+    WorldChannel,
+};
+
+/// The physics provider as registered in the service registry.
+pub type SharedPhysicsProvider = Arc<Mutex<Box<dyn PhysicsProvider>>>;
+
+fn lock_provider(
+    provider: &SharedPhysicsProvider,
+) -> Result<MutexGuard<'_, Box<dyn PhysicsProvider>>, LaneError> {
+    provider.lock().map_err(|e| {
+        LaneError::ExecutionFailed(format!("physics provider mutex poisoned: {e}").into())
+    })
+}
+
+fn world_unavailable() -> LaneError {
+    LaneError::ExecutionFailed("the frame thread dropped a world request".into())
+}
 
 /// The standard physics lane for industrial-grade simulation.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct StandardPhysicsLane;
 
 impl StandardPhysicsLane {
@@ -43,6 +72,11 @@ impl StandardPhysicsLane {
         let mut active_bodies = HashSet::new();
         let mut active_colliders = HashSet::new();
 
+        // 0. Disabled entities leave the simulation: their bodies and
+        //    colliders are cleaned up as orphans below, and re-added once
+        //    they are enabled again.
+        self.release_disabled(world);
+
         // 1. Sync RigidBodies
         let rb_map = self.sync_rigid_bodies(world, provider, &mut active_bodies);
 
@@ -60,9 +94,17 @@ impl StandardPhysicsLane {
         active_bodies: &mut HashSet<khora_core::physics::RigidBodyHandle>,
     ) -> HashMap<EntityId, khora_core::physics::RigidBodyHandle> {
         let mut rb_map = HashMap::new();
-        let query = world.query_mut::<(EntityId, &GlobalTransform, &mut RigidBody)>();
-
-        for (entity_id, transform, rb) in query {
+        let query = world.query_mut::<(
+            EntityId,
+            &GlobalTransform,
+            &mut RigidBody,
+            Option<&SimulationLod>,
+            Option<&mut PhysicsInterpolation>,
+            Without<Disabled>,
+        )>();
+
+        for (entity_id, transform, rb, lod, interpolation, _) in query {
+            let dormant = lod.is_some_and(SimulationLod::is_dormant);
             let current_pos = transform.0.translation();
             let current_rot = transform.0.rotation();
 
@@ -74,20 +116,31 @@ impl StandardPhysicsLane {
                 angular_velocity: rb.angular_velocity,
                 mass: rb.mass,
                 ccd_enabled: rb.ccd_enabled,
+                gravity_scale: rb.gravity_scale,
             };
 
-            let handle = if let Some(handle) = rb.handle {
+            let handle = if let Some(handle) = rb.handle.filter(|_| dormant) {
+                // Dormant bodies sleep; syncing them would wake them up.
+                provider.set_body_sleeping(handle, true);
+                handle
+            } else if let Some(handle) = rb.handle {
                 // Teleport detection
                 let (phys_pos, phys_rot) = provider.get_body_transform(handle);
                 if (phys_pos - current_pos).length_squared() > 0.0001
                     || phys_rot.dot(current_rot).abs() < 0.9999
                 {
                     provider.set_body_transform(handle, current_pos, current_rot);
+                    if let Some(interpolation) = interpolation {
+                        interpolation.snap();
+                    }
                 }
                 provider.update_body_properties(handle, desc);
                 handle
             } else {
                 let h = provider.add_body(desc);
+                if dormant {
+                    provider.set_body_sleeping(h, true);
+                }
                 rb.handle = Some(h);
                 h
             };
@@ -121,21 +174,17 @@ impl StandardPhysicsLane {
             parent_transforms.insert(id, *gt);
         }
 
-        // Collect optional physics state for entities with colliders.
-        let mut active_events = HashSet::new();
-        for (id, _) in world.query::<(EntityId, &khora_data::ecs::ActiveEvents)>() {
-            active_events.insert(id);
-        }
-
-        let mut materials = HashMap::new();
-        for (id, mat) in world.query::<(EntityId, &khora_data::ecs::PhysicsMaterial)>() {
-            materials.insert(id, *mat);
-        }
-
-        let query = world.query_mut::<(EntityId, &mut Collider, &GlobalTransform)>();
-        for (entity_id, collider, transform) in query {
-            let is_active = active_events.contains(&entity_id);
-            let material = materials.get(&entity_id).cloned().unwrap_or_default();
+        let query = world.query_mut::<(
+            EntityId,
+            &mut Collider,
+            &GlobalTransform,
+            Option<&ActiveEvents>,
+            Option<&PhysicsMaterial>,
+            Without<Disabled>,
+        )>();
+        for (entity_id, collider, transform, active_events, material, _) in query {
+            let is_active = active_events.is_some();
+            let material = material.copied().unwrap_or_default();
 
             let desc = self.build_collider_desc(
                 entity_id,
@@ -161,6 +210,44 @@ impl StandardPhysicsLane {
         }
     }
 
+    /// Gives every body standing in a [`GravityZone`] its own gravity, and
+    /// hands the others back to the global gravity.
+    fn apply_gravity_zones(&self, world: &World, provider: &mut dyn PhysicsProvider) {
+        let zones: Vec<(GlobalTransform, GravityZone)> = world
+            .query::<(&GlobalTransform, &GravityZone, Without<Disabled>)>()
+            .map(|(transform, zone, _)| (*transform, zone.clone()))
+            .collect();
+        let world_gravity = provider.gravity();
+
+        for (transform, rb, _) in world.query::<(&GlobalTransform, &RigidBody, Without<Disabled>)>()
+        {
+            let Some(handle) = rb.handle else {
+                continue;
+            };
+            let position = transform.0.translation();
+            let mut gravity = None;
+            let mut replace_world = false;
+            for (zone_transform, zone) in &zones {
+                if let Some(accel) = zone.acceleration_at(zone_transform, position) {
+                    gravity = Some(gravity.unwrap_or(Vec3::ZERO) + accel);
+                    replace_world |= zone.replace_world_gravity;
+                }
+            }
+            let gravity = gravity.map(|g| if replace_world { g } else { g + world_gravity });
+            provider.set_body_gravity(handle, gravity);
+        }
+    }
+
+    /// Forgets the physics handles of disabled entities.
+    fn release_disabled(&self, world: &mut World) {
+        for (rb, _) in world.query_mut::<(&mut RigidBody, &Disabled)>() {
+            rb.handle = None;
+        }
+        for (collider, _) in world.query_mut::<(&mut Collider, &Disabled)>() {
+            collider.handle = None;
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_collider_desc(
         &self,
@@ -245,13 +332,23 @@ impl StandardPhysicsLane {
 
     /// Synchronizes components from the physics provider back to ECS.
     fn sync_from_world(&self, world: &mut World, provider: &dyn PhysicsProvider) {
-        let query = world.query_mut::<(&mut Transform, &mut RigidBody)>();
-        for (transform, rb) in query {
+        let query = world.query_mut::<(
+            &mut Transform,
+            &mut RigidBody,
+            Option<&mut PhysicsInterpolation>,
+        )>();
+        for (transform, rb, interpolation) in query {
             if let Some(handle) = rb.handle {
                 let (pos, rot) = provider.get_body_transform(handle);
                 // Update transform
                 transform.translation = pos;
                 transform.rotation = rot;
+                if let Some(interpolation) = interpolation {
+                    interpolation.push(PhysicsPose {
+                        translation: pos,
+                        rotation: rot,
+                    });
+                }
             }
         }
     }
@@ -291,16 +388,25 @@ impl StandardPhysicsLane {
         }
     }
 
+    /// Publishes the step's collision events to the world's
+    /// `Events<CollisionEvent>` queue, and gives each `CollisionEvents`
+    /// component only the events that involve its entity's collider.
     fn dispatch_events(&self, world: &mut World, provider: &dyn PhysicsProvider) {
         let events = provider.get_collision_events();
-        let query = world.query_mut::<(EntityId, &mut khora_data::ecs::CollisionEvents)>();
-        for (_, buffer) in query {
-            if events.is_empty() {
-                buffer.events.clear();
-            } else {
-                buffer.events = events.clone();
-            }
+
+        let query = world.query_mut::<(&Collider, &mut khora_data::ecs::CollisionEvents)>();
+        for (collider, buffer) in query {
+            buffer.events.clear();
+            let Some(handle) = collider.handle else {
+                continue;
+            };
+            buffer.events.extend(events.iter().copied().filter(|event| {
+                let (CollisionEvent::Started(a, b) | CollisionEvent::Stopped(a, b)) = event;
+                *a == handle || *b == handle
+            }));
         }
+
+        world.events_mut::<CollisionEvent>().send_batch(events);
     }
 }
 
@@ -317,16 +423,22 @@ impl khora_core::lane::Lane for StandardPhysicsLane {
         &self,
         ctx: &mut khora_core::lane::LaneContext,
     ) -> Result<(), khora_core::lane::LaneError> {
-        use khora_core::lane::{LaneError, Slot};
+        use khora_core::lane::Slot;
 
         let dt = ctx
             .get::<khora_core::lane::PhysicsDeltaTime>()
             .ok_or(LaneError::missing("PhysicsDeltaTime"))?
             .0;
-        let world = ctx
-            .get::<Slot<World>>()
-            .ok_or(LaneError::missing("Slot<World>"))?
-            .get();
+        let Some(world) = ctx.get::<Slot<World>>() else {
+            let channel = ctx
+                .get::<WorldChannel>()
+                .ok_or(LaneError::missing("Slot<World>"))?;
+            let provider = ctx
+                .get::<SharedPhysicsProvider>()
+                .ok_or(LaneError::missing("SharedPhysicsProvider"))?;
+            return self.step_through(channel, provider, dt);
+        };
+        let world = world.get();
         let provider = ctx
             .get::<Slot<dyn PhysicsProvider>>()
             .ok_or(LaneError::missing("Slot<dyn PhysicsProvider>"))?
@@ -348,12 +460,50 @@ impl khora_core::lane::Lane for StandardPhysicsLane {
 impl StandardPhysicsLane {
     /// Executes the full physics step: sync, simulate, writeback, characters, events.
     pub fn step(&self, world: &mut World, provider: &mut dyn PhysicsProvider, dt: f32) {
+        self.sync_in(world, provider);
+        provider.step(dt);
+        self.write_back(world, provider);
+    }
+
+    /// Executes the same step from off the frame thread.
+    ///
+    /// Syncing in and writing back are sent through `channel`; only the
+    /// simulation runs on the calling thread. The provider is never locked
+    /// while waiting on the frame thread.
+    pub fn step_through(
+        &self,
+        channel: &WorldChannel,
+        provider: &SharedPhysicsProvider,
+        dt: f32,
+    ) -> Result<(), LaneError> {
+        let lane = *self;
+
+        let shared = Arc::clone(provider);
+        channel
+            .with_world(move |world| {
+                lane.sync_in(world, lock_provider(&shared)?.as_mut());
+                Ok(())
+            })
+            .ok_or_else(world_unavailable)??;
+
+        lock_provider(provider)?.step(dt);
+
+        let shared = Arc::clone(provider);
+        channel
+            .with_world(move |world| {
+                lane.write_back(world, lock_provider(&shared)?.as_ref());
+                Ok(())
+            })
+            .ok_or_else(world_unavailable)?
+    }
+
+    fn sync_in(&self, world: &mut World, provider: &mut dyn PhysicsProvider) {
         // 1. Sync ECS -> Physics World
         self.sync_to_world(world, provider);
+        self.apply_gravity_zones(world, provider);
+    }
 
-        // 2. Simulate
-        provider.step(dt);
-
+    fn write_back(&self, world: &mut World, provider: &dyn PhysicsProvider) {
         // 3. Sync Physics World -> ECS (Transforms)
         self.sync_from_world(world, provider);
 
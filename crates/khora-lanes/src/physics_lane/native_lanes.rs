@@ -16,7 +16,9 @@ use khora_core::ecs::entity::EntityId;
 use khora_core::physics::{
     ContactManifold, DynamicTree, ImpulseSolver, NarrowPhase, VelocityState,
 };
-use khora_data::ecs::{Collider, CollisionPair, CollisionPairs, GlobalTransform, RigidBody, World};
+use khora_data::ecs::{
+    Collider, CollisionPair, CollisionPairs, Disabled, GlobalTransform, RigidBody, Without, World,
+};
 use std::collections::HashMap;
 use std::sync::RwLock;
 
@@ -62,8 +64,8 @@ impl NativeBroadphaseLane {
         let mut current_entities = std::collections::HashSet::new();
 
         // Query entities with Collider and GlobalTransform
-        let query = world.query::<(EntityId, &Collider, &GlobalTransform)>();
-        for (entity_id, collider, transform) in query {
+        let query = world.query::<(EntityId, &Collider, &GlobalTransform, Without<Disabled>)>();
+        for (entity_id, collider, transform, _) in query {
             let world_aabb = collider.shape.compute_aabb().transform(&transform.0 .0);
             current_entities.insert(entity_id);
 
@@ -191,8 +193,8 @@ impl NativeSolverLane {
 
     fn integrate_velocities(&self, world: &mut World, dt: f32) {
         let gravity = khora_core::math::Vec3::new(0.0, -9.81, 0.0);
-        let query = world.query_mut::<&mut RigidBody>();
-        for rb in query {
+        let query = world.query_mut::<(&mut RigidBody, Without<Disabled>)>();
+        for (rb, _) in query {
             if rb.body_type == khora_core::physics::BodyType::Dynamic {
                 // v = v + a*dt
                 rb.linear_velocity = rb.linear_velocity + (gravity * dt);
@@ -201,8 +203,12 @@ impl NativeSolverLane {
     }
 
     fn integrate_positions(&self, world: &mut World, dt: f32) {
-        let query = world.query_mut::<(&mut khora_data::ecs::Transform, &RigidBody)>();
-        for (transform, rb) in query {
+        let query = world.query_mut::<(
+            &mut khora_data::ecs::Transform,
+            &RigidBody,
+            Without<Disabled>,
+        )>();
+        for (transform, rb, _) in query {
             if rb.body_type == khora_core::physics::BodyType::Dynamic {
                 // Integrate translation
                 transform.translation = transform.translation + (rb.linear_velocity * dt);
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use khora_core::physics::PhysicsProvider;
-use khora_data::ecs::{PhysicsDebugData, World};
+use khora_data::ecs::{PhysicsDebugData, World, WorldChannel};
+
+use super::{lock_provider, world_unavailable, SharedPhysicsProvider};
 
 /// A lane dedicated to extracting debug information from the physics engine.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct PhysicsDebugLane;
 
 impl PhysicsDebugLane {
@@ -45,10 +47,23 @@ impl khora_core::lane::Lane for PhysicsDebugLane {
             .get::<khora_core::lane::PhysicsDeltaTime>()
             .ok_or(LaneError::missing("PhysicsDeltaTime"))?
             .0;
-        let world = ctx
-            .get::<Slot<World>>()
-            .ok_or(LaneError::missing("Slot<World>"))?
-            .get();
+        let Some(world) = ctx.get::<Slot<World>>() else {
+            let channel = ctx
+                .get::<WorldChannel>()
+                .ok_or(LaneError::missing("Slot<World>"))?;
+            let shared = ctx
+                .get::<SharedPhysicsProvider>()
+                .ok_or(LaneError::missing("SharedPhysicsProvider"))?
+                .clone();
+            let lane = *self;
+            return channel
+                .with_world(move |world| {
+                    lane.step(world, lock_provider(&shared)?.as_mut(), dt);
+                    Ok(())
+                })
+                .ok_or_else(world_unavailable)?;
+        };
+        let world = world.get();
         let provider = ctx
             .get::<Slot<dyn PhysicsProvider>>()
             .ok_or(LaneError::missing("Slot<dyn PhysicsProvider>"))?
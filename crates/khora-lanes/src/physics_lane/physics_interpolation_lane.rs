@@ -0,0 +1,81 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use khora_data::ecs::{PhysicsInterpolation, World, WorldChannel};
+
+use super::world_unavailable;
+
+/// A lane handing the fraction of a physics tick elapsed since the latest
+/// one to every [`PhysicsInterpolation`] component.
+///
+/// Runs every frame, whether or not the simulation stepped.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PhysicsInterpolationLane;
+
+impl PhysicsInterpolationLane {
+    /// Creates a new `PhysicsInterpolationLane`.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl khora_core::lane::Lane for PhysicsInterpolationLane {
+    fn strategy_name(&self) -> &'static str {
+        "PhysicsInterpolation"
+    }
+
+    fn lane_kind(&self) -> khora_core::lane::LaneKind {
+        khora_core::lane::LaneKind::Physics
+    }
+
+    fn execute(
+        &self,
+        ctx: &mut khora_core::lane::LaneContext,
+    ) -> Result<(), khora_core::lane::LaneError> {
+        use khora_core::lane::{LaneError, Slot};
+
+        let alpha = ctx
+            .get::<khora_core::lane::PhysicsInterpolationAlpha>()
+            .ok_or(LaneError::missing("PhysicsInterpolationAlpha"))?
+            .0;
+        let Some(world) = ctx.get::<Slot<World>>() else {
+            let lane = *self;
+            return ctx
+                .get::<WorldChannel>()
+                .ok_or(LaneError::missing("Slot<World>"))?
+                .with_world(move |world| lane.step(world, alpha))
+                .ok_or_else(world_unavailable);
+        };
+
+        self.step(world.get(), alpha);
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl PhysicsInterpolationLane {
+    /// Stores `alpha` in every interpolated body.
+    pub fn step(&self, world: &mut World, alpha: f32) {
+        for interpolation in world.query_mut::<&mut PhysicsInterpolation>() {
+            interpolation.alpha = alpha;
+        }
+    }
+}
@@ -0,0 +1,432 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Auto-exposure lane — histogram-based eye adaptation of the HDR scene.
+//!
+//! During [`PostStage::Resolve`] the lane meters a log-luminance histogram
+//! of the [`HdrSceneTarget`] (compute), adapts the stored exposure toward
+//! the target EV, and publishes it as an [`ExposureBuffer`] for the tone
+//! mapping lane to apply.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{
+    ExposureBuffer, HdrSceneTarget, Lane, LaneContext, LaneError, LaneKind, LaneRequirements,
+    PostStage, Ref, RenderDeltaTime, Slot, TargetSize,
+};
+use khora_core::math::Extent2D;
+use khora_core::renderer::api::{
+    command::{
+        BindGroupDescriptor, BindGroupEntry, BindGroupId, BindGroupLayoutDescriptor,
+        BindGroupLayoutEntry, BindGroupLayoutId, BindingResource, BindingType, BufferBindingType,
+        ComputePassDescriptor, ComputePipelineDescriptor, ComputePipelineId, TextureSampleType,
+    },
+    core::{ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::PipelineLayoutDescriptor,
+    resource::{BufferDescriptor, BufferId, BufferUsage, TextureViewDimension, TextureViewId},
+    util::ShaderStageFlags,
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::ecs::AutoExposure;
+use khora_data::render::RenderWorld;
+
+/// Histogram bins, matching `BIN_COUNT` in `auto_exposure.wgsl`.
+const HISTOGRAM_BINS: usize = 256;
+/// Pixels per side of a histogram workgroup tile.
+const TILE_SIZE: u32 = 16;
+/// log2 luminance mapped to the first non-black bin.
+const MIN_LOG_LUMINANCE: f32 = -10.0;
+/// log2 luminance span covered by the non-black bins.
+const LOG_LUMINANCE_RANGE: f32 = 22.0;
+/// Estimated cost of metering one pixel.
+const PIXEL_COST: f32 = 0.000_000_3;
+
+/// Metering and adaptation parameters, matching `Params` in
+/// `auto_exposure.wgsl`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ExposureParams {
+    size: [u32; 2],
+    min_log_lum: f32,
+    log_lum_range: f32,
+    min_ev: f32,
+    max_ev: f32,
+    speed_up: f32,
+    speed_down: f32,
+    compensation: f32,
+    delta_time: f32,
+    pre_exposure: f32,
+    _pad: f32,
+}
+
+impl ExposureParams {
+    fn new(size: Extent2D, settings: &AutoExposure, pre_exposure: f32, delta_time: f32) -> Self {
+        Self {
+            size: [size.width, size.height],
+            min_log_lum: MIN_LOG_LUMINANCE,
+            log_lum_range: LOG_LUMINANCE_RANGE,
+            min_ev: settings.min_ev,
+            max_ev: settings.max_ev,
+            speed_up: settings.speed_up,
+            speed_down: settings.speed_down,
+            compensation: settings.compensation,
+            delta_time,
+            pre_exposure,
+            _pad: 0.0,
+        }
+    }
+}
+
+/// Stored exposure state, matching `Exposure` in the shaders.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ExposureState {
+    ev: f32,
+    exposure: f32,
+    initialized: u32,
+    _pad: u32,
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct AutoExposureGpu {
+    meter_layout: BindGroupLayoutId,
+    histogram_pipeline: ComputePipelineId,
+    average_pipeline: ComputePipelineId,
+    params_buffer: BufferId,
+    histogram_buffer: BufferId,
+    exposure_buffer: BufferId,
+    /// Metering bind group and the HDR view it reads.
+    meter_bind_group: Option<(TextureViewId, BindGroupId)>,
+}
+
+/// A post-processing lane adapting exposure to the scene's brightness.
+///
+/// Driven by the first view's [`AutoExposure`] settings; the render agent
+/// only runs it when the camera carries that component, before the tone
+/// mapping lane.
+#[derive(Default)]
+pub struct AutoExposureLane {
+    gpu: Mutex<Option<AutoExposureGpu>>,
+}
+
+impl AutoExposureLane {
+    /// Creates a new `AutoExposureLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for AutoExposureLane {
+    fn strategy_name(&self) -> &'static str {
+        "AutoExposure"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn requirements(&self) -> LaneRequirements {
+        // The luminance histogram and its average are compute passes.
+        LaneRequirements::NONE.with_compute()
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<TargetSize>() {
+            Some(size) => (size.0.width * size.0.height) as f32 * PIXEL_COST,
+            None => 0.5,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let gpu = AutoExposureGpu::new(device.as_ref())
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let stage = *ctx
+            .get::<PostStage>()
+            .ok_or(LaneError::missing("PostStage"))?;
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            return Err(LaneError::NotInitialized);
+        };
+
+        match stage {
+            PostStage::Resolve => {
+                let hdr = ctx
+                    .get::<HdrSceneTarget>()
+                    .ok_or(LaneError::missing("HdrSceneTarget"))?
+                    .0;
+                let render_world = ctx
+                    .get::<Ref<RenderWorld>>()
+                    .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+                    .get();
+                let delta_time = ctx.get::<RenderDeltaTime>().map_or(0.0, |d| d.0);
+                let encoder = ctx
+                    .get::<Slot<dyn CommandEncoder>>()
+                    .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+                    .get();
+                let settings = render_world.auto_exposure.unwrap_or_default();
+                let pre_exposure = render_world.exposure.unwrap_or_default().scale();
+                let params = ExposureParams::new(size, &settings, pre_exposure, delta_time);
+                gpu.resolve(device.as_ref(), encoder, hdr, &params)
+                    .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+                ctx.insert(ExposureBuffer(gpu.exposure_buffer));
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl AutoExposureGpu {
+    fn new(device: &dyn GraphicsDevice) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::AUTO_EXPOSURE_WGSL;
+
+        let meter_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("auto_exposure_meter_layout"),
+                entries: &[
+                    BindGroupLayoutEntry::buffer(
+                        0,
+                        ShaderStageFlags::COMPUTE,
+                        BufferBindingType::Uniform,
+                        false,
+                        None,
+                    ),
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStageFlags::COMPUTE,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: false },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                    },
+                    BindGroupLayoutEntry::buffer(
+                        2,
+                        ShaderStageFlags::COMPUTE,
+                        BufferBindingType::Storage { read_only: false },
+                        false,
+                        None,
+                    ),
+                    BindGroupLayoutEntry::buffer(
+                        3,
+                        ShaderStageFlags::COMPUTE,
+                        BufferBindingType::Storage { read_only: false },
+                        false,
+                        None,
+                    ),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+        let meter_module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("auto_exposure_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(AUTO_EXPOSURE_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let meter_pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Auto Exposure Pipeline Layout")),
+                bind_group_layouts: &[meter_layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+        let compute_pipeline = |label: &'static str, entry_point: &'static str| {
+            device
+                .create_compute_pipeline(&ComputePipelineDescriptor {
+                    label: Some(Cow::Borrowed(label)),
+                    layout: Some(meter_pipeline_layout),
+                    shader_module: meter_module,
+                    entry_point: Cow::Borrowed(entry_point),
+                })
+                .map_err(RenderError::ResourceError)
+        };
+        let histogram_pipeline = compute_pipeline("Luminance Histogram Pipeline", "cs_histogram")?;
+        let average_pipeline = compute_pipeline("Exposure Adaptation Pipeline", "cs_average")?;
+
+        let params_buffer = device
+            .create_buffer(&BufferDescriptor {
+                label: Some("Auto Exposure Params".into()),
+                size: std::mem::size_of::<ExposureParams>() as u64,
+                usage: BufferUsage::UNIFORM | BufferUsage::COPY_DST,
+                mapped_at_creation: false,
+            })
+            .map_err(RenderError::ResourceError)?;
+        let histogram_buffer = device
+            .create_buffer_with_data(
+                &BufferDescriptor {
+                    label: Some("Luminance Histogram".into()),
+                    size: (HISTOGRAM_BINS * std::mem::size_of::<u32>()) as u64,
+                    usage: BufferUsage::STORAGE,
+                    mapped_at_creation: false,
+                },
+                bytemuck::cast_slice(&[0u32; HISTOGRAM_BINS]),
+            )
+            .map_err(RenderError::ResourceError)?;
+        let exposure_buffer = device
+            .create_buffer_with_data(
+                &BufferDescriptor {
+                    label: Some("Exposure State".into()),
+                    size: std::mem::size_of::<ExposureState>() as u64,
+                    usage: BufferUsage::STORAGE,
+                    mapped_at_creation: false,
+                },
+                bytemuck::bytes_of(&ExposureState {
+                    ev: 0.0,
+                    exposure: 1.0,
+                    initialized: 0,
+                    _pad: 0,
+                }),
+            )
+            .map_err(RenderError::ResourceError)?;
+
+        Ok(Self {
+            meter_layout,
+            histogram_pipeline,
+            average_pipeline,
+            params_buffer,
+            histogram_buffer,
+            exposure_buffer,
+            meter_bind_group: None,
+        })
+    }
+
+    /// Returns the metering bind group reading `hdr`, rebuilding it when
+    /// the HDR target was recreated.
+    fn meter_bind_group(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        hdr: TextureViewId,
+    ) -> Result<BindGroupId, RenderError> {
+        if let Some((_, bind_group)) = self.meter_bind_group.filter(|(view, _)| *view == hdr) {
+            return Ok(bind_group);
+        }
+        if let Some((_, old)) = self.meter_bind_group.take() {
+            if let Err(e) = device.destroy_bind_group(old) {
+                log::warn!("AutoExposureLane: Failed to destroy bind group: {:?}", e);
+            }
+        }
+        let bind_group = device
+            .create_bind_group(&BindGroupDescriptor {
+                label: Some("auto_exposure_meter_bind_group"),
+                layout: self.meter_layout,
+                entries: &[
+                    BindGroupEntry::buffer(0, self.params_buffer, 0, None),
+                    BindGroupEntry {
+                        binding: 1,
+                        resource: BindingResource::TextureView(hdr),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry::buffer(2, self.histogram_buffer, 0, None),
+                    BindGroupEntry::buffer(3, self.exposure_buffer, 0, None),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+        self.meter_bind_group = Some((hdr, bind_group));
+        Ok(bind_group)
+    }
+
+    /// Meters `hdr` and adapts the exposure toward it.
+    fn resolve(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        hdr: TextureViewId,
+        params: &ExposureParams,
+    ) -> Result<(), RenderError> {
+        let bind_group = self.meter_bind_group(device, hdr)?;
+        device
+            .write_buffer(self.params_buffer, 0, bytemuck::bytes_of(params))
+            .map_err(RenderError::ResourceError)?;
+
+        let mut pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("Auto Exposure Metering"),
+            timestamp_writes: None,
+        });
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.set_pipeline(&self.histogram_pipeline);
+        pass.dispatch_workgroups(
+            params.size[0].div_ceil(TILE_SIZE),
+            params.size[1].div_ceil(TILE_SIZE),
+            1,
+        );
+        pass.set_pipeline(&self.average_pipeline);
+        pass.dispatch_workgroups(1, 1, 1);
+        Ok(())
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Some((_, bind_group)) = self.meter_bind_group {
+            if let Err(e) = device.destroy_bind_group(bind_group) {
+                log::warn!("AutoExposureLane: Failed to destroy bind group: {:?}", e);
+            }
+        }
+        for pipeline in [self.histogram_pipeline, self.average_pipeline] {
+            if let Err(e) = device.destroy_compute_pipeline(pipeline) {
+                log::warn!("AutoExposureLane: Failed to destroy pipeline: {:?}", e);
+            }
+        }
+        for buffer in [
+            self.params_buffer,
+            self.histogram_buffer,
+            self.exposure_buffer,
+        ] {
+            if let Err(e) = device.destroy_buffer(buffer) {
+                log::warn!("AutoExposureLane: Failed to destroy buffer: {:?}", e);
+            }
+        }
+        if let Err(e) = device.destroy_bind_group_layout(self.meter_layout) {
+            log::warn!("AutoExposureLane: Failed to destroy layout: {:?}", e);
+        }
+    }
+}
@@ -0,0 +1,599 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Bloom lane — glow bleeding out of the bright parts of the HDR scene.
+//!
+//! During [`PostStage::Resolve`] the lane keeps the highlights of the
+//! [`HdrSceneTarget`] in the first level of a half-resolution mip chain,
+//! blurs them down the chain with a 13-tap filter, then back up with a tent
+//! filter, adding each level into the one above. The first level is then
+//! published as the [`BloomTarget`] the tone mapping lane adds to the scene.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{
+    BloomTarget, HdrSceneTarget, Lane, LaneContext, LaneError, LaneKind, PostStage, Ref, Slot,
+    TargetSize,
+};
+use khora_core::math::{Extent2D, Extent3D, LinearRgba};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupDescriptor, BindGroupEntry, BindGroupId, BindGroupLayoutDescriptor,
+        BindGroupLayoutEntry, BindGroupLayoutId, BindingResource, BindingType, BufferBindingType,
+        LoadOp, Operations, RenderPassColorAttachment, RenderPassDescriptor, SamplerBindingType,
+        StoreOp, TextureSampleType,
+    },
+    core::{ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology, state::ColorWrites, BlendComponentDescriptor, BlendFactor,
+        BlendOperation, BlendStateDescriptor, ColorTargetStateDescriptor,
+        MultisampleStateDescriptor, PipelineLayoutDescriptor, PrimitiveStateDescriptor,
+        RenderPipelineDescriptor, RenderPipelineId,
+    },
+    resource::{
+        BufferDescriptor, BufferId, BufferUsage, ImageAspect, SamplerId, TextureDescriptor,
+        TextureDimension, TextureId, TextureUsage, TextureViewDescriptor, TextureViewDimension,
+        TextureViewId,
+    },
+    util::{SampleCount, ShaderStageFlags},
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::ecs::Bloom;
+use khora_data::render::RenderWorld;
+
+use super::render_target::create_linear_sampler;
+use super::HDR_SCENE_FORMAT;
+
+/// Most levels in the bloom chain; each one widens the glow.
+const MAX_BLOOM_MIPS: u32 = 6;
+/// Estimated cost of blooming one pixel of the frame.
+const PIXEL_COST: f32 = 0.000_000_6;
+
+/// Threshold parameters, matching `Params` in `bloom.wgsl`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BloomParams {
+    threshold: f32,
+    knee: f32,
+    _pad: [f32; 2],
+}
+
+/// Size of the first level of the bloom chain for a frame of `size`.
+fn chain_size(size: Extent2D) -> Extent2D {
+    Extent2D {
+        width: (size.width / 2).max(1),
+        height: (size.height / 2).max(1),
+    }
+}
+
+/// Number of levels in the bloom chain for a frame of `size`, stopping
+/// before either side drops below one pixel.
+fn chain_mip_count(size: Extent2D) -> u32 {
+    let base = chain_size(size);
+    let full_chain = u32::BITS - base.width.min(base.height).leading_zeros();
+    full_chain.min(MAX_BLOOM_MIPS)
+}
+
+/// The mip chain and the bind groups sampling each of its levels,
+/// recreated when the frame size or the HDR target changes.
+struct BloomChain {
+    size: Extent2D,
+    source: TextureViewId,
+    texture: TextureId,
+    /// One single-level view per mip, rendered into and sampled from.
+    mips: Vec<TextureViewId>,
+    /// Bind group sampling the HDR scene, read by the prefilter.
+    prefilter_bind_group: BindGroupId,
+    /// Bind group sampling each mip.
+    mip_bind_groups: Vec<BindGroupId>,
+}
+
+impl BloomChain {
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        for bind_group in std::iter::once(self.prefilter_bind_group).chain(self.mip_bind_groups) {
+            if let Err(e) = device.destroy_bind_group(bind_group) {
+                log::warn!("BloomLane: Failed to destroy bind group: {:?}", e);
+            }
+        }
+        for view in self.mips {
+            if let Err(e) = device.destroy_texture_view(view) {
+                log::warn!("BloomLane: Failed to destroy view: {:?}", e);
+            }
+        }
+        if let Err(e) = device.destroy_texture(self.texture) {
+            log::warn!("BloomLane: Failed to destroy texture: {:?}", e);
+        }
+    }
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct BloomGpu {
+    layout: BindGroupLayoutId,
+    prefilter_pipeline: RenderPipelineId,
+    downsample_pipeline: RenderPipelineId,
+    upsample_pipeline: RenderPipelineId,
+    sampler: SamplerId,
+    params_buffer: BufferId,
+    chain: Option<BloomChain>,
+}
+
+/// A post-processing lane making the brightest parts of the scene glow.
+///
+/// Driven by the first view's [`PostProcess`](khora_data::ecs::PostProcess)
+/// bloom settings. The most expensive effect of the stack, so the render
+/// agent skips it when its budget runs short.
+#[derive(Default)]
+pub struct BloomLane {
+    gpu: Mutex<Option<BloomGpu>>,
+}
+
+impl BloomLane {
+    /// Creates a new `BloomLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for BloomLane {
+    fn strategy_name(&self) -> &'static str {
+        "Bloom"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<TargetSize>() {
+            Some(size) => (size.0.width * size.0.height) as f32 * PIXEL_COST,
+            None => 0.6,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let gpu = BloomGpu::new(device.as_ref())
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let stage = *ctx
+            .get::<PostStage>()
+            .ok_or(LaneError::missing("PostStage"))?;
+        if stage != PostStage::Resolve {
+            return Ok(());
+        }
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+        let hdr = ctx
+            .get::<HdrSceneTarget>()
+            .ok_or(LaneError::missing("HdrSceneTarget"))?
+            .0;
+        let render_world = ctx
+            .get::<Ref<RenderWorld>>()
+            .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+            .get();
+        let Some(settings) = render_world.post_process.and_then(|p| p.bloom) else {
+            return Ok(());
+        };
+        if size.width == 0 || size.height == 0 {
+            return Ok(());
+        }
+        let encoder = ctx
+            .get::<Slot<dyn CommandEncoder>>()
+            .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+            .get();
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let view = gpu
+            .render(device.as_ref(), encoder, hdr, size, &settings)
+            .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+        ctx.insert(BloomTarget(view));
+        Ok(())
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl BloomGpu {
+    fn new(device: &dyn GraphicsDevice) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::BLOOM_WGSL;
+
+        let layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("bloom_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStageFlags::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStageFlags::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    },
+                    BindGroupLayoutEntry::buffer(
+                        2,
+                        ShaderStageFlags::FRAGMENT,
+                        BufferBindingType::Uniform,
+                        false,
+                        None,
+                    ),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("bloom_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(BLOOM_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Bloom Pipeline Layout")),
+                bind_group_layouts: &[layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+        let additive = BlendComponentDescriptor {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::One,
+            operation: BlendOperation::Add,
+        };
+        let pipeline = |label: &'static str, entry_point: &'static str, additive_blend: bool| {
+            device
+                .create_render_pipeline(&RenderPipelineDescriptor {
+                    label: Some(Cow::Borrowed(label)),
+                    layout: Some(pipeline_layout),
+                    vertex_shader_module: module,
+                    vertex_entry_point: Cow::Borrowed("vs_main"),
+                    fragment_shader_module: Some(module),
+                    fragment_entry_point: Some(Cow::Borrowed(entry_point)),
+                    color_target_states: Cow::Owned(vec![ColorTargetStateDescriptor {
+                        format: HDR_SCENE_FORMAT,
+                        blend: additive_blend.then_some(BlendStateDescriptor {
+                            color: additive,
+                            alpha: additive,
+                        }),
+                        write_mask: ColorWrites::ALL,
+                    }]),
+                    vertex_buffers_layout: Cow::Owned(vec![]), // Fullscreen triangle from vertex_index
+                    primitive_state: PrimitiveStateDescriptor {
+                        topology: PrimitiveTopology::TriangleList,
+                        ..Default::default()
+                    },
+                    depth_stencil_state: None,
+                    multisample_state: MultisampleStateDescriptor {
+                        count: SampleCount::X1,
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                })
+                .map_err(RenderError::ResourceError)
+        };
+        let prefilter_pipeline = pipeline("Bloom Prefilter Pipeline", "fs_prefilter", false)?;
+        let downsample_pipeline = pipeline("Bloom Downsample Pipeline", "fs_downsample", false)?;
+        let upsample_pipeline = pipeline("Bloom Upsample Pipeline", "fs_upsample", true)?;
+        let sampler = create_linear_sampler(device, "Bloom Sampler")?;
+        let params_buffer = device
+            .create_buffer(&BufferDescriptor {
+                label: Some("Bloom Params".into()),
+                size: std::mem::size_of::<BloomParams>() as u64,
+                usage: BufferUsage::UNIFORM | BufferUsage::COPY_DST,
+                mapped_at_creation: false,
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        Ok(Self {
+            layout,
+            prefilter_pipeline,
+            downsample_pipeline,
+            upsample_pipeline,
+            sampler,
+            params_buffer,
+            chain: None,
+        })
+    }
+
+    /// Creates a bind group sampling `view`.
+    fn sampling_bind_group(
+        &self,
+        device: &dyn GraphicsDevice,
+        view: TextureViewId,
+    ) -> Result<BindGroupId, RenderError> {
+        device
+            .create_bind_group(&BindGroupDescriptor {
+                label: Some("bloom_bind_group"),
+                layout: self.layout,
+                entries: &[
+                    BindGroupEntry {
+                        binding: 0,
+                        resource: BindingResource::TextureView(view),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry {
+                        binding: 1,
+                        resource: BindingResource::Sampler(self.sampler),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry::buffer(2, self.params_buffer, 0, None),
+                ],
+            })
+            .map_err(RenderError::ResourceError)
+    }
+
+    fn create_chain(
+        &self,
+        device: &dyn GraphicsDevice,
+        size: Extent2D,
+        source: TextureViewId,
+    ) -> Result<BloomChain, RenderError> {
+        let base = chain_size(size);
+        let mip_count = chain_mip_count(size);
+        let texture = device
+            .create_texture(&TextureDescriptor {
+                label: Some(Cow::Borrowed("Bloom Chain")),
+                size: Extent3D {
+                    width: base.width,
+                    height: base.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: mip_count,
+                sample_count: SampleCount::X1,
+                dimension: TextureDimension::D2,
+                format: HDR_SCENE_FORMAT,
+                usage: TextureUsage::RENDER_ATTACHMENT | TextureUsage::TEXTURE_BINDING,
+                view_formats: Cow::Borrowed(&[]),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let prefilter_bind_group = match self.sampling_bind_group(device, source) {
+            Ok(bind_group) => bind_group,
+            Err(e) => {
+                if let Err(e) = device.destroy_texture(texture) {
+                    log::warn!("BloomLane: Failed to destroy texture: {:?}", e);
+                }
+                return Err(e);
+            }
+        };
+        // Filled level by level, so a failure destroys what was created.
+        let mut chain = BloomChain {
+            size,
+            source,
+            texture,
+            mips: Vec::with_capacity(mip_count as usize),
+            prefilter_bind_group,
+            mip_bind_groups: Vec::with_capacity(mip_count as usize),
+        };
+        for mip in 0..mip_count {
+            match self.create_mip(device, texture, mip) {
+                Ok((view, bind_group)) => {
+                    chain.mips.push(view);
+                    chain.mip_bind_groups.push(bind_group);
+                }
+                Err(e) => {
+                    chain.destroy(device);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(chain)
+    }
+
+    /// Creates the view of level `mip` of the chain and the bind group
+    /// sampling it.
+    fn create_mip(
+        &self,
+        device: &dyn GraphicsDevice,
+        texture: TextureId,
+        mip: u32,
+    ) -> Result<(TextureViewId, BindGroupId), RenderError> {
+        let view = device
+            .create_texture_view(
+                texture,
+                &TextureViewDescriptor {
+                    label: Some(Cow::Borrowed("Bloom Mip")),
+                    format: Some(HDR_SCENE_FORMAT),
+                    dimension: Some(TextureViewDimension::D2),
+                    aspect: ImageAspect::All,
+                    base_mip_level: mip,
+                    mip_level_count: Some(1),
+                    base_array_layer: 0,
+                    array_layer_count: Some(1),
+                },
+            )
+            .map_err(RenderError::ResourceError)?;
+        match self.sampling_bind_group(device, view) {
+            Ok(bind_group) => Ok((view, bind_group)),
+            Err(e) => {
+                if let Err(e) = device.destroy_texture_view(view) {
+                    log::warn!("BloomLane: Failed to destroy view: {:?}", e);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Renders the bloom of `hdr` and returns the view of its first level,
+    /// recreating the chain when the frame size or the HDR target changed.
+    fn render(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        hdr: TextureViewId,
+        size: Extent2D,
+        settings: &Bloom,
+    ) -> Result<TextureViewId, RenderError> {
+        let chain = match self.chain.take() {
+            Some(chain) if chain.size == size && chain.source == hdr => chain,
+            old => {
+                if let Some(old) = old {
+                    old.destroy(device);
+                }
+                self.create_chain(device, size, hdr)?
+            }
+        };
+        let chain = self.chain.insert(chain);
+
+        let params = BloomParams {
+            threshold: settings.threshold.max(0.0),
+            knee: settings.knee.max(0.0),
+            _pad: [0.0; 2],
+        };
+        device
+            .write_buffer(self.params_buffer, 0, bytemuck::bytes_of(&params))
+            .map_err(RenderError::ResourceError)?;
+
+        // Upsampling adds onto the level's own downsample.
+        let mut draw = |label: &'static str,
+                        target: TextureViewId,
+                        pipeline: RenderPipelineId,
+                        source: BindGroupId,
+                        accumulate: bool| {
+            let color_attachment = RenderPassColorAttachment {
+                view: &target,
+                resolve_target: None,
+                ops: Operations {
+                    load: if accumulate {
+                        LoadOp::Load
+                    } else {
+                        LoadOp::Clear(LinearRgba::new(0.0, 0.0, 0.0, 1.0))
+                    },
+                    store: StoreOp::Store,
+                },
+                base_array_layer: 0,
+            };
+            let render_pass_desc = RenderPassDescriptor {
+                label: Some(label),
+                color_attachments: &[color_attachment],
+                depth_stencil_attachment: None,
+            };
+            let mut pass = encoder.begin_render_pass(&render_pass_desc);
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &source, &[]);
+            pass.draw(0..3, 0..1);
+        };
+        draw(
+            "Bloom Prefilter",
+            chain.mips[0],
+            self.prefilter_pipeline,
+            chain.prefilter_bind_group,
+            false,
+        );
+        for mip in 1..chain.mips.len() {
+            draw(
+                "Bloom Downsample",
+                chain.mips[mip],
+                self.downsample_pipeline,
+                chain.mip_bind_groups[mip - 1],
+                false,
+            );
+        }
+        for mip in (0..chain.mips.len() - 1).rev() {
+            draw(
+                "Bloom Upsample",
+                chain.mips[mip],
+                self.upsample_pipeline,
+                chain.mip_bind_groups[mip + 1],
+                true,
+            );
+        }
+        Ok(chain.mips[0])
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Some(chain) = self.chain {
+            chain.destroy(device);
+        }
+        for pipeline in [
+            self.prefilter_pipeline,
+            self.downsample_pipeline,
+            self.upsample_pipeline,
+        ] {
+            if let Err(e) = device.destroy_render_pipeline(pipeline) {
+                log::warn!("BloomLane: Failed to destroy pipeline: {:?}", e);
+            }
+        }
+        if let Err(e) = device.destroy_sampler(self.sampler) {
+            log::warn!("BloomLane: Failed to destroy sampler: {:?}", e);
+        }
+        if let Err(e) = device.destroy_buffer(self.params_buffer) {
+            log::warn!("BloomLane: Failed to destroy buffer: {:?}", e);
+        }
+        if let Err(e) = device.destroy_bind_group_layout(self.layout) {
+            log::warn!("BloomLane: Failed to destroy layout: {:?}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extent(width: u32, height: u32) -> Extent2D {
+        Extent2D { width, height }
+    }
+
+    #[test]
+    fn test_chain_starts_at_half_resolution() {
+        assert_eq!(chain_size(extent(1920, 1080)), extent(960, 540));
+        assert_eq!(chain_size(extent(1, 1)), extent(1, 1));
+    }
+
+    #[test]
+    fn test_chain_mip_count_stops_at_one_pixel() {
+        assert_eq!(chain_mip_count(extent(1920, 1080)), MAX_BLOOM_MIPS);
+        // 8x4 base: 8x4, 4x2, 2x1.
+        assert_eq!(chain_mip_count(extent(16, 8)), 3);
+        assert_eq!(chain_mip_count(extent(1, 1)), 1);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cascaded shadow lane - splits each directional light's shadow across
+//! several atlas layers along the camera frustum.
+
+use khora_core::lane::Lane;
+use khora_core::renderer::api::scene::MAX_SHADOW_CASCADES;
+use khora_core::renderer::light::LightType;
+
+use super::ShadowPassLane;
+
+/// Atlas layers of the cascaded shadow pass: the cascades of two
+/// directional lights, or one sun and four spot lights.
+const CASCADED_ATLAS_LAYERS: u32 = 2 * MAX_SHADOW_CASCADES as u32;
+
+/// A shadow lane rendering cascaded shadow maps for directional lights.
+///
+/// A single shadow map stretched over the whole view leaves nearby shadows
+/// blurry or missing. This lane renders each cascade computed by
+/// `ShadowFlow` into its own atlas layer, nearest first, so texel density
+/// follows the camera. Spot lights keep one layer each.
+///
+/// Lit lanes pick the cascade per fragment from the
+/// [`ShadowCascades`](khora_data::render::ShadowCascades) published in the
+/// light's [`ShadowEntry`](khora_data::render::ShadowEntry).
+pub struct CascadedShadowLane {
+    /// The depth pass doing the GPU work, configured for cascades.
+    pass: ShadowPassLane,
+}
+
+impl Default for CascadedShadowLane {
+    fn default() -> Self {
+        Self {
+            pass: ShadowPassLane::cascaded(CASCADED_ATLAS_LAYERS),
+        }
+    }
+}
+
+impl CascadedShadowLane {
+    /// Creates a new `CascadedShadowLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for CascadedShadowLane {
+    fn strategy_name(&self) -> &'static str {
+        "CascadedShadow"
+    }
+
+    fn lane_kind(&self) -> khora_core::lane::LaneKind {
+        khora_core::lane::LaneKind::Shadow
+    }
+
+    fn requirements(&self) -> khora_core::lane::LaneRequirements {
+        self.pass.requirements()
+    }
+
+    fn estimate_cost(&self, ctx: &khora_core::lane::LaneContext) -> f32 {
+        let render_world = match ctx.get::<khora_core::lane::Ref<khora_data::render::RenderWorld>>()
+        {
+            Some(slot) => slot.get(),
+            None => return 1.0,
+        };
+        // Every cascade is one more depth pass over the scene.
+        let shadow_passes: usize = render_world
+            .lights
+            .iter()
+            .map(|l| match &l.light_type {
+                LightType::Directional(dl) if dl.shadow_enabled => MAX_SHADOW_CASCADES,
+                LightType::Directional(_) => 0,
+                LightType::Point(pl) => pl.shadow_enabled as usize,
+                LightType::Spot(sl) => sl.shadow_enabled as usize,
+            })
+            .sum();
+        (shadow_passes as f32) * (render_world.meshes.len() as f32) * 0.001
+    }
+
+    fn on_initialize(
+        &self,
+        ctx: &mut khora_core::lane::LaneContext,
+    ) -> Result<(), khora_core::lane::LaneError> {
+        self.pass.on_initialize(ctx)
+    }
+
+    fn execute(
+        &self,
+        ctx: &mut khora_core::lane::LaneContext,
+    ) -> Result<(), khora_core::lane::LaneError> {
+        self.pass.execute(ctx)
+    }
+
+    fn on_shutdown(&self, ctx: &mut khora_core::lane::LaneContext) {
+        self.pass.on_shutdown(ctx);
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
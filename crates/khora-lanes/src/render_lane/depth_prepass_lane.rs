@@ -0,0 +1,427 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Depth prepass lane — lays down scene depth before the shading pass.
+//!
+//! The lane draws every opaque, rigid mesh of the main view into the scene's
+//! depth target (the multisampled one under MSAA) with a depth-only
+//! pipeline, then inserts [`DepthPrepared`] so the scene lane loads that
+//! depth instead of clearing it. Shading then runs once per pixel, for the
+//! nearest surface only.
+//!
+//! The pass is bracketed by the [`GpuHook::DepthPrepassBegin`] and
+//! [`GpuHook::DepthPrepassEnd`] timestamps, so profilers report it as its
+//! own GPU span.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex, RwLock};
+
+use khora_core::lane::{
+    DepthPrepared, DepthTarget, Lane, LaneContext, LaneError, LaneKind, MultisampleTargets,
+    ProjectionJitter, Ref, Slot,
+};
+use khora_core::math::{Mat4, Vec3};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindGroupLayoutId, BindingType,
+        BufferBindingType, ComputePassDescriptor, LoadOp, Operations, PassTimestampWrites,
+        RenderPassDepthStencilAttachment, RenderPassDescriptor, StoreOp,
+    },
+    core::{DepthMode, GpuHook, ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology,
+        state::{DepthBiasState, StencilFaceState},
+        DepthStencilStateDescriptor, MultisampleStateDescriptor, PipelineLayoutDescriptor,
+        PrimitiveStateDescriptor, RenderPipelineDescriptor, RenderPipelineId, StaticVertex,
+    },
+    resource::{CameraUniformData, TextureViewId},
+    scene::GpuMesh,
+    util::{
+        dynamic_uniform_buffer::{
+            DynamicUniformRingBuffer, DEFAULT_MAX_ELEMENTS, MIN_UNIFORM_ALIGNMENT,
+        },
+        SampleCount, ShaderStageFlags, TextureFormat,
+    },
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::assets::Assets;
+use khora_data::render::RenderWorld;
+
+use super::MSAA_SAMPLE_COUNT;
+
+/// Per-mesh uniform of the depth prepass.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct PrepassModelUniforms {
+    model_matrix: [[f32; 4]; 4],
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct DepthPrepassGpu {
+    pipeline: RenderPipelineId,
+    pipeline_msaa: RenderPipelineId,
+    camera_layout: BindGroupLayoutId,
+    model_layout: BindGroupLayoutId,
+    camera_ring: DynamicUniformRingBuffer,
+    model_ring: DynamicUniformRingBuffer,
+    depth_mode: DepthMode,
+}
+
+/// A rendering lane that fills the scene depth ahead of shading (early-Z).
+///
+/// Worth its extra geometry pass only when meshes overlap heavily; the
+/// render agent decides from the scene's estimated depth complexity.
+#[derive(Default)]
+pub struct DepthPrepassLane {
+    gpu: Mutex<Option<DepthPrepassGpu>>,
+}
+
+impl DepthPrepassLane {
+    /// Creates a new `DepthPrepassLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for DepthPrepassLane {
+    fn strategy_name(&self) -> &'static str {
+        "DepthPrepass"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<Ref<RenderWorld>>() {
+            Some(render_world) => render_world.get().meshes.len() as f32 * 0.0005,
+            None => 1.0,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let depth_mode = ctx.get::<DepthMode>().copied().unwrap_or_default();
+        let gpu = DepthPrepassGpu::new(device.as_ref(), depth_mode)
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let gpu_meshes = ctx
+            .get::<Arc<RwLock<Assets<GpuMesh>>>>()
+            .ok_or(LaneError::missing("Arc<RwLock<Assets<GpuMesh>>>"))?
+            .clone();
+        let render_world = ctx
+            .get::<Ref<RenderWorld>>()
+            .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+            .get();
+        // Under MSAA the scene tests against the multisampled depth.
+        let multisample = ctx.get::<MultisampleTargets>().copied();
+        let depth_view = match multisample {
+            Some(targets) => targets.depth,
+            None => {
+                ctx.get::<DepthTarget>()
+                    .ok_or(LaneError::missing("DepthTarget"))?
+                    .0
+            }
+        };
+        let jitter = ctx.get::<ProjectionJitter>().copied();
+        let encoder = ctx
+            .get::<Slot<dyn CommandEncoder>>()
+            .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+            .get();
+
+        let mut gpu_lock = self.gpu.lock().map_err(|_| LaneError::NotInitialized)?;
+        let gpu = gpu_lock.as_mut().ok_or(LaneError::NotInitialized)?;
+
+        let prepared = gpu
+            .render(
+                device.as_ref(),
+                encoder,
+                render_world,
+                &gpu_meshes,
+                jitter,
+                depth_view,
+                multisample.is_some(),
+            )
+            .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+        drop(gpu_lock);
+
+        if prepared {
+            ctx.insert(DepthPrepared);
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl DepthPrepassGpu {
+    fn new(device: &dyn GraphicsDevice, depth_mode: DepthMode) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::DEPTH_PREPASS_WGSL;
+
+        let uniform_entries = [BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStageFlags::VERTEX,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: None,
+            },
+        }];
+        let camera_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("depth_prepass_camera_layout"),
+                entries: &uniform_entries,
+            })
+            .map_err(RenderError::ResourceError)?;
+        let model_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("depth_prepass_model_layout"),
+                entries: &uniform_entries,
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let shader_module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("depth_prepass_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(DEPTH_PREPASS_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Depth Prepass Pipeline Layout")),
+                bind_group_layouts: &[camera_layout, model_layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let vertex_layout = StaticVertex::POSITION_ONLY;
+
+        let pipeline = |label: &'static str, count: SampleCount| {
+            device
+                .create_render_pipeline(&RenderPipelineDescriptor {
+                    label: Some(Cow::Borrowed(label)),
+                    layout: Some(pipeline_layout),
+                    vertex_shader_module: shader_module,
+                    vertex_entry_point: Cow::Borrowed("vs_main"),
+                    fragment_shader_module: None,
+                    fragment_entry_point: None,
+                    color_target_states: Cow::Borrowed(&[]),
+                    vertex_buffers_layout: Cow::Owned(vec![vertex_layout.clone()]),
+                    primitive_state: PrimitiveStateDescriptor {
+                        topology: PrimitiveTopology::TriangleList,
+                        ..Default::default()
+                    },
+                    depth_stencil_state: Some(DepthStencilStateDescriptor {
+                        format: TextureFormat::Depth32Float,
+                        depth_write_enabled: true,
+                        depth_compare: depth_mode.compare(),
+                        stencil_front: StencilFaceState::default(),
+                        stencil_back: StencilFaceState::default(),
+                        stencil_read_mask: 0,
+                        stencil_write_mask: 0,
+                        bias: DepthBiasState::default(),
+                    }),
+                    multisample_state: MultisampleStateDescriptor {
+                        count,
+                        mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    },
+                })
+                .map_err(RenderError::ResourceError)
+        };
+        let pipeline_msaa = pipeline("Depth Prepass Pipeline (MSAA)", MSAA_SAMPLE_COUNT)?;
+        let pipeline = pipeline("Depth Prepass Pipeline", SampleCount::X1)?;
+
+        let camera_ring = DynamicUniformRingBuffer::new(
+            device,
+            camera_layout,
+            0,
+            std::mem::size_of::<CameraUniformData>() as u32,
+            1,
+            MIN_UNIFORM_ALIGNMENT,
+            "Depth Prepass Camera Ring",
+        )
+        .map_err(RenderError::ResourceError)?;
+        let model_ring = DynamicUniformRingBuffer::new(
+            device,
+            model_layout,
+            0,
+            std::mem::size_of::<PrepassModelUniforms>() as u32,
+            DEFAULT_MAX_ELEMENTS,
+            MIN_UNIFORM_ALIGNMENT,
+            "Depth Prepass Model Ring",
+        )
+        .map_err(RenderError::ResourceError)?;
+
+        Ok(Self {
+            pipeline,
+            pipeline_msaa,
+            camera_layout,
+            model_layout,
+            camera_ring,
+            model_ring,
+            depth_mode,
+        })
+    }
+
+    /// Clears `depth_view` and draws the depth of every opaque, rigid mesh
+    /// of the main view into it. Returns `false` when there is no view.
+    ///
+    /// Skinned meshes may be posed in the scene's vertex shader, and
+    /// transparent ones must not occlude what lies behind them; the scene
+    /// pass draws both against the prepass depth as usual.
+    #[allow(clippy::too_many_arguments)]
+    fn render(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        render_world: &RenderWorld,
+        gpu_meshes: &RwLock<Assets<GpuMesh>>,
+        jitter: Option<ProjectionJitter>,
+        depth_view: TextureViewId,
+        multisampled: bool,
+    ) -> Result<bool, RenderError> {
+        let Some(view) = render_world.views.first() else {
+            return Ok(false);
+        };
+        // Same matrix as the scene lanes, jitter included, so both passes
+        // rasterize identical depths.
+        let view_proj = match jitter {
+            Some(ProjectionJitter(j)) => {
+                Mat4::from_translation(Vec3::new(j.x, j.y, 0.0)) * view.view_proj
+            }
+            None => view.view_proj,
+        };
+        let camera = CameraUniformData {
+            view_projection: view_proj.to_cols_array_2d(),
+            camera_position: [view.position.x, view.position.y, view.position.z, 1.0],
+        };
+
+        self.camera_ring.advance();
+        self.model_ring.advance();
+        let camera_offset = self
+            .camera_ring
+            .push(device, bytemuck::bytes_of(&camera))
+            .map_err(RenderError::ResourceError)?;
+        let camera_bg = *self.camera_ring.current_bind_group();
+
+        let Ok(gpu_meshes) = gpu_meshes.read() else {
+            return Ok(false);
+        };
+        let mut draws = Vec::with_capacity(render_world.meshes.len());
+        for mesh in &render_world.meshes {
+            if mesh.skin.is_some() || !mesh.is_opaque() || !mesh.layers.intersects(view.layers) {
+                continue;
+            }
+            let Some(gpu_mesh) = gpu_meshes.get(&mesh.cpu_mesh_uuid) else {
+                continue;
+            };
+            let uniforms = PrepassModelUniforms {
+                model_matrix: mesh.transform.to_matrix().to_cols_array_2d(),
+            };
+            match self.model_ring.push(device, bytemuck::bytes_of(&uniforms)) {
+                Ok(offset) => draws.push((*self.model_ring.current_bind_group(), offset, gpu_mesh)),
+                Err(e) => log::error!("DepthPrepassLane: Failed to push model uniform: {:?}", e),
+            }
+        }
+
+        encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("Depth Prepass Begin Timestamp"),
+            timestamp_writes: Some(PassTimestampWrites {
+                beginning_of_pass_hook: Some(&GpuHook::DepthPrepassBegin),
+                end_of_pass_hook: None,
+            }),
+        });
+        {
+            let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("Depth Prepass"),
+                color_attachments: &[],
+                depth_stencil_attachment: Some(RenderPassDepthStencilAttachment {
+                    view: &depth_view,
+                    depth_ops: Some(Operations {
+                        load: LoadOp::Clear(self.depth_mode.clear_depth()),
+                        store: StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                    base_array_layer: 0,
+                }),
+            });
+            let pipeline = if multisampled {
+                &self.pipeline_msaa
+            } else {
+                &self.pipeline
+            };
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &camera_bg, &[camera_offset]);
+            for (model_bg, model_offset, gpu_mesh) in &draws {
+                pass.set_bind_group(1, model_bg, &[*model_offset]);
+                pass.set_vertex_buffer(0, &gpu_mesh.vertex_buffer, 0);
+                pass.set_index_buffer(&gpu_mesh.index_buffer, 0, gpu_mesh.index_format);
+                pass.draw_indexed(0..gpu_mesh.index_count, 0, 0..1);
+            }
+        }
+        encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("Depth Prepass End Timestamp"),
+            timestamp_writes: Some(PassTimestampWrites {
+                beginning_of_pass_hook: Some(&GpuHook::DepthPrepassEnd),
+                end_of_pass_hook: None,
+            }),
+        });
+        Ok(true)
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        self.camera_ring.destroy(device);
+        self.model_ring.destroy(device);
+        for pipeline in [self.pipeline, self.pipeline_msaa] {
+            if let Err(e) = device.destroy_render_pipeline(pipeline) {
+                log::warn!("DepthPrepassLane: Failed to destroy pipeline: {:?}", e);
+            }
+        }
+        for layout in [self.camera_layout, self.model_layout] {
+            if let Err(e) = device.destroy_bind_group_layout(layout) {
+                log::warn!("DepthPrepassLane: Failed to destroy layout: {:?}", e);
+            }
+        }
+    }
+}
@@ -44,13 +44,15 @@
 //! - Configurable tile size and max lights per tile
 //! - Runtime-adjustable configuration via `ForwardPlusTileConfig`
 
-use crate::render_lane::ShaderComplexity;
+use crate::render_lane::{PipelineVariants, ShaderComplexity};
 use khora_data::render::RenderWorld;
 
 use khora_core::renderer::api::{
     command::BindGroupLayoutId,
+    core::{LightCullingStats, LightingDebugView},
     util::{
         dynamic_uniform_buffer::DynamicUniformRingBuffer, uniform_ring_buffer::UniformRingBuffer,
+        SharedReadback,
     },
 };
 use khora_core::{
@@ -73,7 +75,8 @@ use khora_core::{
     },
 };
 use khora_data::assets::Assets;
-use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 
 // --- Cost Estimation Constants ---
 
@@ -92,6 +95,12 @@ const PER_TILE_COST: f32 = 0.0001;
 /// Cost factor per light-tile intersection test.
 const LIGHT_TILE_TEST_COST: f32 = 0.00001;
 
+/// Tiles the light grid buffer holds (1920x1080 in 16-pixel tiles).
+const LIGHT_GRID_TILES: u32 = 120 * 68;
+
+/// Per-tile light cap of `light_culling.wgsl`.
+const CULLING_MAX_LIGHTS_PER_TILE: u32 = 128;
+
 // --- ForwardPlusLane ---
 
 /// GPU resource handles for the Forward+ compute pass.
@@ -135,8 +144,10 @@ pub struct ForwardPlusGpuResources {
     pub forward_bind_group: Option<BindGroupId>,
     /// Compute pipeline for light culling.
     pub culling_pipeline: Option<ComputePipelineId>,
-    /// Render pipeline for the Forward+ pass.
-    pub render_pipeline: Option<RenderPipelineId>,
+    /// Render pipeline for the Forward+ pass, per kind of color target.
+    pub render_pipelines: Option<PipelineVariants>,
+    /// Additive pipelines drawing the overdraw debug view.
+    pub overdraw_pipelines: Option<PipelineVariants>,
 }
 
 impl ForwardPlusGpuResources {
@@ -176,6 +187,12 @@ pub struct ForwardPlusLane {
 
     /// GPU resources for compute and render passes.
     pub gpu_resources: std::sync::Mutex<ForwardPlusGpuResources>,
+
+    /// Statistics of the last light grid read back, if any.
+    culling_stats: Arc<Mutex<Option<LightCullingStats>>>,
+
+    /// Whether a light grid readback is waiting for the GPU.
+    stats_in_flight: Arc<AtomicBool>,
 }
 
 impl Default for ForwardPlusLane {
@@ -185,6 +202,8 @@ impl Default for ForwardPlusLane {
             shader_complexity: ShaderComplexity::SimpleLit,
             screen_size: (1920, 1080),
             gpu_resources: std::sync::Mutex::new(ForwardPlusGpuResources::default()),
+            culling_stats: Arc::new(Mutex::new(None)),
+            stats_in_flight: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -240,6 +259,16 @@ impl ForwardPlusLane {
         tiles_x * tiles_y
     }
 
+    /// Returns the light culling statistics of the last light grid read
+    /// back.
+    ///
+    /// The grid is only read back on frames whose context holds a
+    /// [`SharedReadback`], so the statistics lag the scene by the readback
+    /// latency and stay `None` until the first one completes.
+    pub fn culling_stats(&self) -> Option<LightCullingStats> {
+        self.culling_stats.lock().ok().and_then(|stats| *stats)
+    }
+
     /// Returns the effective number of lights in the scene.
     ///
     /// This counts all light types (directional, point, spot) that will be
@@ -292,6 +321,11 @@ impl khora_core::lane::Lane for ForwardPlusLane {
         khora_core::lane::LaneKind::Render
     }
 
+    fn requirements(&self) -> khora_core::lane::LaneRequirements {
+        // Light culling runs in a compute pass.
+        khora_core::lane::LaneRequirements::NONE.with_compute()
+    }
+
     fn estimate_cost(&self, ctx: &khora_core::lane::LaneContext) -> f32 {
         let render_world = match ctx.get::<khora_core::lane::Ref<khora_data::render::RenderWorld>>()
         {
@@ -318,7 +352,11 @@ impl khora_core::lane::Lane for ForwardPlusLane {
             .ok_or(khora_core::lane::LaneError::missing(
                 "Arc<dyn GraphicsDevice>",
             ))?;
-        self.on_gpu_init(device.as_ref())
+        let depth_mode = ctx
+            .get::<khora_core::renderer::api::core::DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        self.on_gpu_init(device.as_ref(), depth_mode)
             .map_err(|e| khora_core::lane::LaneError::InitializationFailed(Box::new(e)))
     }
 
@@ -347,10 +385,15 @@ impl khora_core::lane::Lane for ForwardPlusLane {
             .get::<Ref<khora_data::render::RenderWorld>>()
             .ok_or(LaneError::missing("Ref<RenderWorld>"))?
             .get();
-        let color_target = ctx
-            .get::<khora_core::lane::ColorTarget>()
-            .ok_or(LaneError::missing("ColorTarget"))?
-            .0;
+        let hdr_target = ctx.get::<khora_core::lane::HdrSceneTarget>().map(|t| t.0);
+        let color_target = match hdr_target {
+            Some(view) => view,
+            None => {
+                ctx.get::<khora_core::lane::ColorTarget>()
+                    .ok_or(LaneError::missing("ColorTarget"))?
+                    .0
+            }
+        };
         let depth_target = ctx
             .get::<khora_core::lane::DepthTarget>()
             .ok_or(LaneError::missing("DepthTarget"))?
@@ -371,6 +414,21 @@ impl khora_core::lane::Lane for ForwardPlusLane {
         );
         render_ctx.shadow_atlas = shadow_atlas.as_ref();
         render_ctx.shadow_sampler = shadow_sampler.as_ref();
+        render_ctx.depth_mode = ctx
+            .get::<khora_core::renderer::api::core::DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        render_ctx.hdr = hdr_target.is_some();
+        let multisample = ctx.get::<khora_core::lane::MultisampleTargets>().copied();
+        if let Some(targets) = multisample.as_ref() {
+            render_ctx.multisample_into(&targets.color, &targets.depth);
+        }
+        if let Some(jitter) = ctx.get::<khora_core::lane::ProjectionJitter>() {
+            render_ctx.jitter = jitter.0;
+        }
+        render_ctx.depth_prepared = ctx.contains::<khora_core::lane::DepthPrepared>();
+        render_ctx.debug_view = ctx.get::<LightingDebugView>().copied().unwrap_or_default();
+        let readback = ctx.get::<SharedReadback>().cloned();
 
         self.render(
             render_world,
@@ -378,6 +436,7 @@ impl khora_core::lane::Lane for ForwardPlusLane {
             encoder,
             &render_ctx,
             &gpu_meshes,
+            readback.as_ref(),
         );
         Ok(())
     }
@@ -408,8 +467,8 @@ impl ForwardPlusLane {
         self.gpu_resources
             .lock()
             .unwrap()
-            .render_pipeline
-            .unwrap_or(RenderPipelineId(0))
+            .render_pipelines
+            .map_or(RenderPipelineId(0), |p| p.ldr())
     }
 
     fn render(
@@ -419,8 +478,15 @@ impl ForwardPlusLane {
         encoder: &mut dyn CommandEncoder,
         render_ctx: &RenderContext,
         gpu_meshes: &RwLock<Assets<GpuMesh>>,
+        readback: Option<&SharedReadback>,
     ) {
         let mut resources = self.gpu_resources.lock().unwrap();
+        let pipelines = if render_ctx.debug_view == LightingDebugView::Overdraw {
+            resources.overdraw_pipelines
+        } else {
+            resources.render_pipelines
+        };
+        let render_pipeline = pipelines.map(|p| p.select(render_ctx));
 
         // 1. Get Active Camera View.
         //
@@ -429,7 +495,7 @@ impl ForwardPlusLane {
         let Some(view) = render_world.views.first() else {
             let color_attachment = khora_core::renderer::api::command::RenderPassColorAttachment {
                 view: render_ctx.color_target,
-                resolve_target: None,
+                resolve_target: render_ctx.resolve_target,
                 ops: khora_core::renderer::api::command::Operations {
                     load: khora_core::renderer::api::command::LoadOp::Clear(render_ctx.clear_color),
                     store: khora_core::renderer::api::command::StoreOp::Store,
@@ -443,7 +509,9 @@ impl ForwardPlusLane {
                     khora_core::renderer::api::command::RenderPassDepthStencilAttachment {
                         view: depth_view,
                         depth_ops: Some(khora_core::renderer::api::command::Operations {
-                            load: khora_core::renderer::api::command::LoadOp::Clear(1.0),
+                            load: khora_core::renderer::api::command::LoadOp::Clear(
+                                render_ctx.depth_mode.clear_depth(),
+                            ),
                             store: khora_core::renderer::api::command::StoreOp::Store,
                         }),
                         stencil_ops: None,
@@ -457,7 +525,7 @@ impl ForwardPlusLane {
 
         // 2. Prepare Camera Uniforms (Group 0)
         let camera_uniforms = CameraUniformData {
-            view_projection: view.view_proj.to_cols_array_2d(),
+            view_projection: render_ctx.jittered(view.view_proj).to_cols_array_2d(),
             camera_position: [view.position.x, view.position.y, view.position.z, 1.0],
         };
 
@@ -483,20 +551,30 @@ impl ForwardPlusLane {
                 num_tiles_y,
                 config.tile_size.pixels(),
                 config.max_lights_per_tile,
+                render_ctx.debug_view.shader_index(),
+                0,
+                0,
+                0,
             ];
             let _ = device.write_buffer(tile_buffer, 0, bytemuck::cast_slice(&tile_info));
         }
 
-        // 4. Update Light Data
+        // 4. Update Light Data, pre-exposed so HDR values stay in range
+        let pre_exposure = render_world.exposure.unwrap_or_default().scale();
         let lights: Vec<_> = render_world
             .lights
             .iter()
             .map(|l| {
-                khora_core::renderer::GpuLight::from_parts(
+                let light = khora_core::renderer::GpuLight::from_parts(
                     [l.position.x, l.position.y, l.position.z],
                     [l.direction.x, l.direction.y, l.direction.z],
                     &l.light_type,
-                )
+                );
+                khora_core::renderer::GpuLight {
+                    layers: l.layers.0,
+                    intensity: light.intensity * pre_exposure,
+                    ..light
+                }
             })
             .collect();
 
@@ -544,6 +622,11 @@ impl ForwardPlusLane {
             compute_pass.dispatch_workgroups(num_tiles_x, num_tiles_y, 1);
         }
 
+        // Read the culled light grid back for statistics, one request at a time.
+        if let (Some(readback), Some(grid)) = (readback, resources.light_grid_buffer) {
+            self.request_culling_stats(device, encoder, readback, grid, lights.len() as u32);
+        }
+
         // 5. Prepare Per-Mesh Data (Dynamic Uniforms)
         let mut draw_commands = Vec::new();
 
@@ -561,15 +644,7 @@ impl ForwardPlusLane {
                 let model_mat = extracted_mesh.transform.to_matrix();
                 let normal_mat = model_mat.inverse().unwrap_or_default().transpose();
 
-                let mut base_color = khora_core::math::LinearRgba::WHITE;
-                let mut emissive = khora_core::math::LinearRgba::BLACK;
-                let mut specular_power = 32.0;
-
-                if let Some(mat_handle) = &extracted_mesh.material {
-                    base_color = mat_handle.base_color();
-                    emissive = mat_handle.emissive_color();
-                    specular_power = mat_handle.specular_power();
-                }
+                let params = extracted_mesh.material_params();
 
                 let model_uniforms = khora_core::renderer::api::scene::ModelUniforms {
                     model_matrix: model_mat.to_cols_array_2d(),
@@ -577,9 +652,10 @@ impl ForwardPlusLane {
                 };
 
                 let material_uniforms = khora_core::renderer::api::scene::MaterialUniforms {
-                    base_color,
-                    emissive: emissive.with_alpha(specular_power),
-                    ambient: khora_core::math::LinearRgba::new(0.05, 0.05, 0.05, 1.0),
+                    base_color: params.base_color,
+                    emissive: params.emissive.with_alpha(params.specular_power),
+                    ambient: [0.05, 0.05, 0.05],
+                    layers: extracted_mesh.layers.0,
                 };
 
                 // Push to rings and get offsets/ids
@@ -606,7 +682,7 @@ impl ForwardPlusLane {
                 };
 
                 draw_commands.push(khora_core::renderer::api::command::DrawCommand {
-                    pipeline: resources.render_pipeline.unwrap_or(RenderPipelineId(0)),
+                    pipeline: render_pipeline.unwrap_or(RenderPipelineId(0)),
                     vertex_buffer: gpu_mesh_handle.vertex_buffer,
                     index_buffer: gpu_mesh_handle.index_buffer,
                     index_count: gpu_mesh_handle.index_count,
@@ -622,7 +698,7 @@ impl ForwardPlusLane {
         // 6. Render Pass
         let color_attachment = RenderPassColorAttachment {
             view: render_ctx.color_target,
-            resolve_target: None,
+            resolve_target: render_ctx.resolve_target,
             ops: Operations {
                 load: LoadOp::Clear(render_ctx.clear_color),
                 store: StoreOp::Store,
@@ -637,7 +713,7 @@ impl ForwardPlusLane {
                 RenderPassDepthStencilAttachment {
                     view: depth_view,
                     depth_ops: Some(Operations {
-                        load: LoadOp::Clear(1.0),
+                        load: render_ctx.depth_load(),
                         store: StoreOp::Store,
                     }),
                     stencil_ops: None,
@@ -657,13 +733,15 @@ impl ForwardPlusLane {
         }
 
         // Set Render Pipeline
-        if let Some(ref pipeline) = resources.render_pipeline {
+        if let Some(ref pipeline) = render_pipeline {
             render_pass.set_pipeline(pipeline);
         } else {
             return;
         }
 
         // Draw Cached Commands
+        // Sorted draws arrive batched by mesh; rebind buffers only at batch edges.
+        let mut current_buffers = None;
         for cmd in &draw_commands {
             if let Some(ref bg) = cmd.model_bind_group {
                 render_pass.set_bind_group(1, bg, &[cmd.model_offset]);
@@ -672,12 +750,77 @@ impl ForwardPlusLane {
                 render_pass.set_bind_group(2, bg, &[cmd.material_offset]);
             }
 
-            render_pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
-            render_pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+            if current_buffers != Some((cmd.vertex_buffer, cmd.index_buffer)) {
+                render_pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
+                render_pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+                current_buffers = Some((cmd.vertex_buffer, cmd.index_buffer));
+            }
             render_pass.draw_indexed(0..cmd.index_count, 0, 0..1);
         }
     }
 
+    fn request_culling_stats(
+        &self,
+        device: &dyn khora_core::renderer::GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        readback: &SharedReadback,
+        grid: BufferId,
+        light_count: u32,
+    ) {
+        if self.stats_in_flight.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let tiles = self.total_tiles().min(LIGHT_GRID_TILES);
+        let max_lights_per_tile = self
+            .tile_config
+            .max_lights_per_tile
+            .min(CULLING_MAX_LIGHTS_PER_TILE);
+        let handle = match readback.lock() {
+            Ok(mut readback) => {
+                readback.read_buffer(device, encoder, &grid, 0, u64::from(tiles) * 2 * 4)
+            }
+            Err(_) => {
+                self.stats_in_flight.store(false, Ordering::Release);
+                return;
+            }
+        };
+        let handle = match handle {
+            Ok(handle) => handle,
+            Err(e) => {
+                log::warn!(
+                    "ForwardPlusLane: Failed to read back the light grid: {:?}",
+                    e
+                );
+                self.stats_in_flight.store(false, Ordering::Release);
+                return;
+            }
+        };
+
+        let culling_stats = self.culling_stats.clone();
+        let in_flight = self.stats_in_flight.clone();
+        handle.on_ready(move |result| {
+            match result {
+                Ok(data) => {
+                    // The grid holds an (offset, count) pair per tile.
+                    let counts = data
+                        .bytes
+                        .chunks_exact(8)
+                        .map(|pair| u32::from_le_bytes([pair[4], pair[5], pair[6], pair[7]]));
+                    let stats = LightCullingStats::from_tile_counts(
+                        light_count,
+                        max_lights_per_tile,
+                        counts,
+                    );
+                    if let Ok(mut slot) = culling_stats.lock() {
+                        *slot = Some(stats);
+                    }
+                }
+                Err(e) => log::warn!("ForwardPlusLane: Light grid readback failed: {:?}", e),
+            }
+            in_flight.store(false, Ordering::Release);
+        });
+    }
+
     fn estimate_render_cost(
         &self,
         render_world: &RenderWorld,
@@ -729,6 +872,7 @@ impl ForwardPlusLane {
     fn on_gpu_init(
         &self,
         device: &dyn khora_core::renderer::GraphicsDevice,
+        depth_mode: khora_core::renderer::api::core::DepthMode,
     ) -> Result<(), khora_core::renderer::error::RenderError> {
         use crate::render_lane::shaders::FORWARD_PLUS_WGSL;
         use khora_core::renderer::api::{
@@ -737,12 +881,11 @@ impl ForwardPlusLane {
                 BindGroupLayoutEntry, BindingType, BufferBindingType,
             },
             core::{ShaderModuleDescriptor, ShaderSourceData},
-            pipeline::enums::{CompareFunction, VertexFormat, VertexStepMode},
             pipeline::state::{ColorWrites, DepthBiasState, StencilFaceState},
             pipeline::{
                 ColorTargetStateDescriptor, DepthStencilStateDescriptor,
                 MultisampleStateDescriptor, PrimitiveStateDescriptor, RenderPipelineDescriptor,
-                VertexAttributeDescriptor, VertexBufferLayoutDescriptor,
+                StaticVertex, Vertex,
             },
             resource::CameraUniformData,
             scene::{MaterialUniforms, ModelUniforms},
@@ -899,29 +1042,7 @@ impl ForwardPlusLane {
             })
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
 
-        let vertex_attributes = vec![
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 0,
-                shader_location: 0,
-            },
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 12,
-                shader_location: 1,
-            },
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x2,
-                offset: 24,
-                shader_location: 2,
-            },
-        ];
-
-        let vertex_layout = VertexBufferLayoutDescriptor {
-            array_stride: 32,
-            step_mode: VertexStepMode::Vertex,
-            attributes: Cow::Owned(vertex_attributes),
-        };
+        let vertex_layout = StaticVertex::LAYOUT;
 
         // Explicit Render Pipeline Layout
         let render_pipeline_layout = device
@@ -953,7 +1074,7 @@ impl ForwardPlusLane {
             depth_stencil_state: Some(DepthStencilStateDescriptor {
                 format: khora_core::renderer::api::util::TextureFormat::Depth32Float,
                 depth_write_enabled: true,
-                depth_compare: CompareFunction::Less,
+                depth_compare: depth_mode.compare_or_equal(),
                 stencil_front: StencilFaceState::default(),
                 stencil_back: StencilFaceState::default(),
                 stencil_read_mask: 0,
@@ -974,9 +1095,8 @@ impl ForwardPlusLane {
             },
         };
 
-        let pipeline_id = device
-            .create_render_pipeline(&pipeline_desc)
-            .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
+        let render_pipelines = PipelineVariants::new(device, &pipeline_desc, "fs_hdr")?;
+        let overdraw_pipelines = PipelineVariants::overdraw(device, &pipeline_desc, "fs_overdraw")?;
 
         // Compute Pipeline for Culling
         let culling_pipeline_layout = device
@@ -1036,9 +1156,10 @@ impl ForwardPlusLane {
         let light_grid_buffer = device
             .create_buffer(&khora_core::renderer::api::resource::BufferDescriptor {
                 label: Some(Cow::Borrowed("Forward+ Light Grid Buffer")),
-                size: 120 * 68 * 2 * 4,
+                size: u64::from(LIGHT_GRID_TILES) * 2 * 4,
                 usage: khora_core::renderer::api::resource::BufferUsage::STORAGE
-                    | khora_core::renderer::api::resource::BufferUsage::COPY_DST,
+                    | khora_core::renderer::api::resource::BufferUsage::COPY_DST
+                    | khora_core::renderer::api::resource::BufferUsage::COPY_SRC,
                 mapped_at_creation: false,
             })
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
@@ -1143,7 +1264,8 @@ impl ForwardPlusLane {
         res.culling_bind_group = Some(culling_bg);
         res.forward_bind_group = Some(forward_bg);
         res.culling_pipeline = Some(culling_pipeline);
-        res.render_pipeline = Some(pipeline_id);
+        res.render_pipelines = Some(render_pipelines);
+        res.overdraw_pipelines = Some(overdraw_pipelines);
 
         Ok(())
     }
@@ -1173,6 +1295,12 @@ impl ForwardPlusLane {
         if let Some(id) = resources.culling_uniforms_buffer.take() {
             let _ = device.destroy_buffer(id);
         }
+        if let Some(pipelines) = resources.render_pipelines.take() {
+            pipelines.destroy(device);
+        }
+        if let Some(pipelines) = resources.overdraw_pipelines.take() {
+            pipelines.destroy(device);
+        }
     }
 }
 
@@ -1220,6 +1348,12 @@ mod tests {
         assert_eq!(lane.strategy_name(), "ForwardPlus");
     }
 
+    #[test]
+    fn test_culling_stats_absent_before_readback() {
+        let lane = ForwardPlusLane::new();
+        assert_eq!(lane.culling_stats(), None);
+    }
+
     #[test]
     fn test_pipeline_id() {
         let lane = ForwardPlusLane::new();
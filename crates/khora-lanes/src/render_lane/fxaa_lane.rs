@@ -0,0 +1,363 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! FXAA lane — screen-space edge anti-aliasing of the final image.
+//!
+//! In [`PostStage::Prepare`] the lane swaps the [`ColorTarget`] for an
+//! intermediate target of the same format, so the scene (and tone mapping,
+//! if any) lands there. In [`PostStage::Resolve`] it filters that target
+//! into the real [`ColorTarget`].
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{
+    ColorTarget, Lane, LaneContext, LaneError, LaneKind, PostStage, Slot, TargetSize,
+};
+use khora_core::math::{Extent2D, LinearRgba};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupDescriptor, BindGroupEntry, BindGroupId, BindGroupLayoutDescriptor,
+        BindGroupLayoutEntry, BindGroupLayoutId, BindingResource, BindingType, LoadOp, Operations,
+        RenderPassColorAttachment, RenderPassDescriptor, SamplerBindingType, StoreOp,
+        TextureSampleType,
+    },
+    core::{ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology, state::ColorWrites, ColorTargetStateDescriptor,
+        MultisampleStateDescriptor, PipelineLayoutDescriptor, PrimitiveStateDescriptor,
+        RenderPipelineDescriptor, RenderPipelineId,
+    },
+    resource::{SamplerId, TextureUsage, TextureViewDimension, TextureViewId},
+    util::{SampleCount, ShaderStageFlags, TextureFormat},
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+
+use super::render_target::{create_linear_sampler, RenderTarget};
+
+/// Estimated cost of filtering one pixel.
+const PIXEL_COST: f32 = 0.000_000_1;
+
+/// The target the scene renders into and the bind group reading it.
+struct FxaaInput {
+    target: RenderTarget,
+    bind_group: BindGroupId,
+}
+
+impl FxaaInput {
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Err(e) = device.destroy_bind_group(self.bind_group) {
+            log::warn!("FxaaLane: Failed to destroy bind group: {:?}", e);
+        }
+        self.target.destroy(device);
+    }
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct FxaaGpu {
+    layout: BindGroupLayoutId,
+    pipeline: RenderPipelineId,
+    sampler: SamplerId,
+    format: TextureFormat,
+    input: Option<FxaaInput>,
+}
+
+/// An anti-aliasing lane smoothing edges detected in the final image.
+///
+/// The cheapest strategy: one fullscreen pass, no extra scene samples and
+/// no history, at the cost of softening fine texture detail.
+#[derive(Default)]
+pub struct FxaaLane {
+    gpu: Mutex<Option<FxaaGpu>>,
+}
+
+impl FxaaLane {
+    /// Creates a new `FxaaLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for FxaaLane {
+    fn strategy_name(&self) -> &'static str {
+        "Fxaa"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<TargetSize>() {
+            Some(size) => (size.0.width * size.0.height) as f32 * PIXEL_COST,
+            None => 0.2,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let gpu = FxaaGpu::new(device.as_ref())
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let stage = *ctx
+            .get::<PostStage>()
+            .ok_or(LaneError::missing("PostStage"))?;
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            return Err(LaneError::NotInitialized);
+        };
+
+        match stage {
+            PostStage::Prepare => {
+                if size.width == 0 || size.height == 0 {
+                    return Ok(());
+                }
+                let view = gpu
+                    .prepare(device.as_ref(), size)
+                    .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+                ctx.insert(ColorTarget(view));
+                Ok(())
+            }
+            PostStage::Resolve => {
+                let color_target = ctx
+                    .get::<ColorTarget>()
+                    .ok_or(LaneError::missing("ColorTarget"))?
+                    .0;
+                let encoder = ctx
+                    .get::<Slot<dyn CommandEncoder>>()
+                    .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+                    .get();
+                gpu.resolve(encoder, color_target, size);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl FxaaGpu {
+    fn new(device: &dyn GraphicsDevice) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::FXAA_WGSL;
+
+        let format = device
+            .get_surface_format()
+            .unwrap_or(TextureFormat::Rgba8UnormSrgb);
+        let layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("fxaa_layout"),
+                entries: &[
+                    BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: ShaderStageFlags::FRAGMENT,
+                        ty: BindingType::Texture {
+                            sample_type: TextureSampleType::Float { filterable: true },
+                            view_dimension: TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                    },
+                    BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: ShaderStageFlags::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    },
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("fxaa_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(FXAA_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("FXAA Pipeline Layout")),
+                bind_group_layouts: &[layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline = device
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some(Cow::Borrowed("FXAA Pipeline")),
+                layout: Some(pipeline_layout),
+                vertex_shader_module: module,
+                vertex_entry_point: Cow::Borrowed("vs_main"),
+                fragment_shader_module: Some(module),
+                fragment_entry_point: Some(Cow::Borrowed("fs_main")),
+                color_target_states: Cow::Owned(vec![ColorTargetStateDescriptor {
+                    format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                }]),
+                vertex_buffers_layout: Cow::Owned(vec![]), // Fullscreen triangle from vertex_index
+                primitive_state: PrimitiveStateDescriptor {
+                    topology: PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil_state: None,
+                multisample_state: MultisampleStateDescriptor {
+                    count: SampleCount::X1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            })
+            .map_err(RenderError::ResourceError)?;
+        let sampler = create_linear_sampler(device, "FXAA Sampler")?;
+
+        Ok(Self {
+            layout,
+            pipeline,
+            sampler,
+            format,
+            input: None,
+        })
+    }
+
+    /// Returns the target the scene should render into, recreating it when
+    /// the frame size changed.
+    fn prepare(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        size: Extent2D,
+    ) -> Result<TextureViewId, RenderError> {
+        if let Some(input) = self
+            .input
+            .as_ref()
+            .filter(|i| i.target.matches(size, self.format))
+        {
+            return Ok(input.target.view);
+        }
+        if let Some(old) = self.input.take() {
+            old.destroy(device);
+        }
+        let target = RenderTarget::new(
+            device,
+            "FXAA Input",
+            size,
+            self.format,
+            TextureUsage::RENDER_ATTACHMENT | TextureUsage::TEXTURE_BINDING,
+            SampleCount::X1,
+        )?;
+        let bind_group = match device.create_bind_group(&BindGroupDescriptor {
+            label: Some("fxaa_bind_group"),
+            layout: self.layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(target.view),
+                    _phantom: std::marker::PhantomData,
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(self.sampler),
+                    _phantom: std::marker::PhantomData,
+                },
+            ],
+        }) {
+            Ok(bind_group) => bind_group,
+            Err(e) => {
+                target.destroy(device);
+                return Err(RenderError::ResourceError(e));
+            }
+        };
+        let view = target.view;
+        self.input = Some(FxaaInput { target, bind_group });
+        Ok(view)
+    }
+
+    /// Filters the input into `color_target`. Does nothing if no input was
+    /// prepared at this size, as the scene was then drawn straight to the
+    /// color target.
+    fn resolve(
+        &self,
+        encoder: &mut dyn CommandEncoder,
+        color_target: TextureViewId,
+        size: Extent2D,
+    ) {
+        let Some(input) = self.input.as_ref().filter(|i| i.target.size == size) else {
+            return;
+        };
+        let color_attachment = RenderPassColorAttachment {
+            view: &color_target,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Clear(LinearRgba::new(0.0, 0.0, 0.0, 1.0)),
+                store: StoreOp::Store,
+            },
+            base_array_layer: 0,
+        };
+        let render_pass_desc = RenderPassDescriptor {
+            label: Some("FXAA Pass"),
+            color_attachments: &[color_attachment],
+            depth_stencil_attachment: None,
+        };
+        let mut pass = encoder.begin_render_pass(&render_pass_desc);
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &input.bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Some(input) = self.input {
+            input.destroy(device);
+        }
+        if let Err(e) = device.destroy_render_pipeline(self.pipeline) {
+            log::warn!("FxaaLane: Failed to destroy pipeline: {:?}", e);
+        }
+        if let Err(e) = device.destroy_sampler(self.sampler) {
+            log::warn!("FxaaLane: Failed to destroy sampler: {:?}", e);
+        }
+        if let Err(e) = device.destroy_bind_group_layout(self.layout) {
+            log::warn!("FxaaLane: Failed to destroy layout: {:?}", e);
+        }
+    }
+}
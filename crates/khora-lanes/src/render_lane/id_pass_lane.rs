@@ -0,0 +1,568 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Entity ID pass lane — renders entity IDs for pixel-exact picking.
+//!
+//! The lane only draws while [`EntityPicker`] requests are pending. It
+//! renders every mesh of the main view into an `Rg32Uint` target sized to
+//! the surface, then reads back the pixel under each request through the
+//! shared [`GpuReadback`](khora_core::renderer::api::util::GpuReadback).
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex, RwLock};
+
+use khora_core::lane::{Lane, LaneContext, LaneError, LaneKind, Ref, Slot};
+use khora_core::math::{Extent3D, LinearRgba, Origin3D};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindGroupLayoutId, BindingType,
+        BufferBindingType, LoadOp, Operations, RenderPassColorAttachment,
+        RenderPassDepthStencilAttachment, RenderPassDescriptor, StoreOp,
+    },
+    core::{DepthMode, ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology,
+        state::{ColorWrites, DepthBiasState, StencilFaceState},
+        ColorTargetStateDescriptor, DepthStencilStateDescriptor, MultisampleStateDescriptor,
+        PipelineLayoutDescriptor, PrimitiveStateDescriptor, RenderPipelineDescriptor,
+        RenderPipelineId, StaticVertex,
+    },
+    resource::{
+        CameraUniformData, ImageAspect, TextureDescriptor, TextureDimension, TextureId,
+        TextureUsage, TextureViewDescriptor, TextureViewDimension, TextureViewId,
+    },
+    scene::GpuMesh,
+    util::{
+        dynamic_uniform_buffer::{
+            DynamicUniformRingBuffer, DEFAULT_MAX_ELEMENTS, MIN_UNIFORM_ALIGNMENT,
+        },
+        SampleCount, ShaderStageFlags, SharedReadback, TextureFormat,
+    },
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::assets::Assets;
+use khora_data::render::{
+    decode_entity_pixel, encode_entity_id, PickRequest, RenderWorld, SharedEntityPicker,
+};
+
+/// Format of the entity ID target.
+const ID_FORMAT: TextureFormat = TextureFormat::Rg32Uint;
+
+/// Per-mesh uniform of the ID pass.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct IdModelUniforms {
+    model_matrix: [[f32; 4]; 4],
+    /// Packed entity ID in `xy`, padding in `zw`.
+    entity_id: [u32; 4],
+}
+
+/// ID and depth targets, recreated when the surface is resized.
+struct IdTargets {
+    size: (u32, u32),
+    id_texture: TextureId,
+    id_view: TextureViewId,
+    depth_texture: TextureId,
+    depth_view: TextureViewId,
+}
+
+impl IdTargets {
+    fn new(device: &dyn GraphicsDevice, size: (u32, u32)) -> Result<Self, RenderError> {
+        let (id_texture, id_view) = create_target(
+            device,
+            size,
+            ID_FORMAT,
+            TextureUsage::RENDER_ATTACHMENT | TextureUsage::COPY_SRC,
+            ImageAspect::All,
+            "Entity ID Target",
+        )?;
+        let (depth_texture, depth_view) = create_target(
+            device,
+            size,
+            TextureFormat::Depth32Float,
+            TextureUsage::RENDER_ATTACHMENT,
+            ImageAspect::DepthOnly,
+            "Entity ID Depth",
+        )?;
+        Ok(Self {
+            size,
+            id_texture,
+            id_view,
+            depth_texture,
+            depth_view,
+        })
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        for view in [self.id_view, self.depth_view] {
+            if let Err(e) = device.destroy_texture_view(view) {
+                log::warn!("IdPassLane: Failed to destroy target view: {:?}", e);
+            }
+        }
+        for texture in [self.id_texture, self.depth_texture] {
+            if let Err(e) = device.destroy_texture(texture) {
+                log::warn!("IdPassLane: Failed to destroy target texture: {:?}", e);
+            }
+        }
+    }
+}
+
+fn create_target(
+    device: &dyn GraphicsDevice,
+    (width, height): (u32, u32),
+    format: TextureFormat,
+    usage: TextureUsage,
+    aspect: ImageAspect,
+    label: &'static str,
+) -> Result<(TextureId, TextureViewId), RenderError> {
+    let texture = device
+        .create_texture(&TextureDescriptor {
+            label: Some(Cow::Borrowed(label)),
+            size: Extent3D {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: SampleCount::X1,
+            dimension: TextureDimension::D2,
+            format,
+            usage,
+            view_formats: Cow::Borrowed(&[]),
+        })
+        .map_err(RenderError::ResourceError)?;
+    let view = device
+        .create_texture_view(
+            texture,
+            &TextureViewDescriptor {
+                label: Some(Cow::Borrowed(label)),
+                format: Some(format),
+                dimension: Some(TextureViewDimension::D2),
+                aspect,
+                base_mip_level: 0,
+                mip_level_count: Some(1),
+                base_array_layer: 0,
+                array_layer_count: Some(1),
+            },
+        )
+        .map_err(RenderError::ResourceError)?;
+    Ok((texture, view))
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct IdPassGpu {
+    pipeline: RenderPipelineId,
+    camera_layout: BindGroupLayoutId,
+    model_layout: BindGroupLayoutId,
+    camera_ring: DynamicUniformRingBuffer,
+    model_ring: DynamicUniformRingBuffer,
+    depth_mode: DepthMode,
+    targets: Option<IdTargets>,
+}
+
+/// A rendering lane that draws entity IDs for pixel-exact picking.
+///
+/// Unlike ray-vs-AABB picking, the ID pass resolves exactly the triangle
+/// drawn at a pixel, however dense the scene.
+#[derive(Default)]
+pub struct IdPassLane {
+    gpu: Mutex<Option<IdPassGpu>>,
+}
+
+impl IdPassLane {
+    /// Creates a new `IdPassLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for IdPassLane {
+    fn strategy_name(&self) -> &'static str {
+        "EntityIdPass"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<Ref<RenderWorld>>() {
+            Some(render_world) => render_world.get().meshes.len() as f32 * 0.001,
+            None => 1.0,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let depth_mode = ctx.get::<DepthMode>().copied().unwrap_or_default();
+        let gpu = IdPassGpu::new(device.as_ref(), depth_mode)
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let picker = ctx
+            .get::<SharedEntityPicker>()
+            .ok_or(LaneError::missing("SharedEntityPicker"))?
+            .clone();
+        let requests = picker
+            .lock()
+            .map(|mut p| p.take_requests())
+            .unwrap_or_default();
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let gpu_meshes = ctx
+            .get::<Arc<RwLock<Assets<GpuMesh>>>>()
+            .ok_or(LaneError::missing("Arc<RwLock<Assets<GpuMesh>>>"))?
+            .clone();
+        let readback = ctx
+            .get::<SharedReadback>()
+            .ok_or(LaneError::missing("SharedReadback"))?
+            .clone();
+        let render_world = ctx
+            .get::<Ref<RenderWorld>>()
+            .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+            .get();
+        let encoder = ctx
+            .get::<Slot<dyn CommandEncoder>>()
+            .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+            .get();
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            resolve_all(requests);
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            resolve_all(requests);
+            return Err(LaneError::NotInitialized);
+        };
+
+        let size = device.get_surface_size();
+        let id_texture = match gpu.render(device.as_ref(), encoder, render_world, &gpu_meshes, size)
+        {
+            Ok(Some(texture)) => texture,
+            Ok(None) => {
+                resolve_all(requests);
+                return Ok(());
+            }
+            Err(e) => {
+                resolve_all(requests);
+                return Err(LaneError::ExecutionFailed(Box::new(e)));
+            }
+        };
+
+        let Ok(mut readback) = readback.lock() else {
+            resolve_all(requests);
+            return Ok(());
+        };
+        for request in requests {
+            let (x, y) = (request.screen_pos.x, request.screen_pos.y);
+            if x < 0.0 || y < 0.0 || x >= size.0 as f32 || y >= size.1 as f32 {
+                request.resolve(None);
+                continue;
+            }
+            let pixel = readback.read_texture(
+                device.as_ref(),
+                encoder,
+                &id_texture,
+                ID_FORMAT,
+                Origin3D {
+                    x: x as u32,
+                    y: y as u32,
+                    z: 0,
+                },
+                Extent3D {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+            );
+            match pixel {
+                Ok(handle) => handle.on_ready(move |result| {
+                    request.resolve(
+                        result
+                            .ok()
+                            .and_then(|data| decode_entity_pixel(&data.bytes)),
+                    )
+                }),
+                Err(e) => {
+                    log::warn!("IdPassLane: Failed to read back pick: {:?}", e);
+                    request.resolve(None);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Resolves picks the pass could not serve to "nothing under the pixel".
+fn resolve_all(requests: Vec<PickRequest>) {
+    for request in requests {
+        request.resolve(None);
+    }
+}
+
+impl IdPassGpu {
+    fn new(device: &dyn GraphicsDevice, depth_mode: DepthMode) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::ID_PASS_WGSL;
+
+        let uniform_entries = [BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStageFlags::VERTEX | ShaderStageFlags::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: None,
+            },
+        }];
+        let camera_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("id_pass_camera_layout"),
+                entries: &uniform_entries,
+            })
+            .map_err(RenderError::ResourceError)?;
+        let model_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("id_pass_model_layout"),
+                entries: &uniform_entries,
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let shader_module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("id_pass_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(ID_PASS_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Entity ID Pipeline Layout")),
+                bind_group_layouts: &[camera_layout, model_layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let vertex_layout = StaticVertex::POSITION_ONLY;
+
+        let pipeline = device
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some(Cow::Borrowed("Entity ID Pipeline")),
+                layout: Some(pipeline_layout),
+                vertex_shader_module: shader_module,
+                vertex_entry_point: Cow::Borrowed("vs_main"),
+                fragment_shader_module: Some(shader_module),
+                fragment_entry_point: Some(Cow::Borrowed("fs_main")),
+                color_target_states: Cow::Owned(vec![ColorTargetStateDescriptor {
+                    format: ID_FORMAT,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                }]),
+                vertex_buffers_layout: Cow::Owned(vec![vertex_layout]),
+                primitive_state: PrimitiveStateDescriptor {
+                    topology: PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil_state: Some(DepthStencilStateDescriptor {
+                    format: TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: depth_mode.compare(),
+                    stencil_front: StencilFaceState::default(),
+                    stencil_back: StencilFaceState::default(),
+                    stencil_read_mask: 0,
+                    stencil_write_mask: 0,
+                    bias: DepthBiasState::default(),
+                }),
+                multisample_state: MultisampleStateDescriptor {
+                    count: SampleCount::X1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let camera_ring = DynamicUniformRingBuffer::new(
+            device,
+            camera_layout,
+            0,
+            std::mem::size_of::<CameraUniformData>() as u32,
+            1,
+            MIN_UNIFORM_ALIGNMENT,
+            "Entity ID Camera Ring",
+        )
+        .map_err(RenderError::ResourceError)?;
+        let model_ring = DynamicUniformRingBuffer::new(
+            device,
+            model_layout,
+            0,
+            std::mem::size_of::<IdModelUniforms>() as u32,
+            DEFAULT_MAX_ELEMENTS,
+            MIN_UNIFORM_ALIGNMENT,
+            "Entity ID Model Ring",
+        )
+        .map_err(RenderError::ResourceError)?;
+
+        Ok(Self {
+            pipeline,
+            camera_layout,
+            model_layout,
+            camera_ring,
+            model_ring,
+            depth_mode,
+            targets: None,
+        })
+    }
+
+    /// Draws the main view's entity IDs, returning the ID texture, or
+    /// `None` when there is no view or surface to draw.
+    fn render(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        render_world: &RenderWorld,
+        gpu_meshes: &RwLock<Assets<GpuMesh>>,
+        size: (u32, u32),
+    ) -> Result<Option<TextureId>, RenderError> {
+        let Some(view) = render_world.views.first() else {
+            return Ok(None);
+        };
+        if size.0 == 0 || size.1 == 0 {
+            return Ok(None);
+        }
+        if self.targets.as_ref().is_none_or(|t| t.size != size) {
+            if let Some(old) = self.targets.take() {
+                old.destroy(device);
+            }
+            self.targets = Some(IdTargets::new(device, size)?);
+        }
+        let Some(targets) = self.targets.as_ref() else {
+            return Ok(None);
+        };
+
+        self.camera_ring.advance();
+        self.model_ring.advance();
+        let camera = CameraUniformData {
+            view_projection: view.view_proj.to_cols_array_2d(),
+            camera_position: [view.position.x, view.position.y, view.position.z, 1.0],
+        };
+        let camera_offset = self
+            .camera_ring
+            .push(device, bytemuck::bytes_of(&camera))
+            .map_err(RenderError::ResourceError)?;
+        let camera_bg = *self.camera_ring.current_bind_group();
+
+        let Ok(gpu_meshes) = gpu_meshes.read() else {
+            return Ok(None);
+        };
+        let mut draws = Vec::with_capacity(render_world.meshes.len());
+        for mesh in &render_world.meshes {
+            let Some(entity) = mesh.entity else {
+                continue;
+            };
+            if !mesh.layers.intersects(view.layers) {
+                continue;
+            }
+            let Some(gpu_mesh) = gpu_meshes.get(&mesh.cpu_mesh_uuid) else {
+                continue;
+            };
+            let [index, generation] = encode_entity_id(entity);
+            let uniforms = IdModelUniforms {
+                model_matrix: mesh.transform.to_matrix().to_cols_array_2d(),
+                entity_id: [index, generation, 0, 0],
+            };
+            match self.model_ring.push(device, bytemuck::bytes_of(&uniforms)) {
+                Ok(offset) => draws.push((*self.model_ring.current_bind_group(), offset, gpu_mesh)),
+                Err(e) => log::error!("IdPassLane: Failed to push model uniform: {:?}", e),
+            }
+        }
+
+        let color_attachment = RenderPassColorAttachment {
+            view: &targets.id_view,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Clear(LinearRgba::new(0.0, 0.0, 0.0, 0.0)),
+                store: StoreOp::Store,
+            },
+            base_array_layer: 0,
+        };
+        let render_pass_desc = RenderPassDescriptor {
+            label: Some("Entity ID Pass"),
+            color_attachments: &[color_attachment],
+            depth_stencil_attachment: Some(RenderPassDepthStencilAttachment {
+                view: &targets.depth_view,
+                depth_ops: Some(Operations {
+                    load: LoadOp::Clear(self.depth_mode.clear_depth()),
+                    store: StoreOp::Discard,
+                }),
+                stencil_ops: None,
+                base_array_layer: 0,
+            }),
+        };
+        let mut pass = encoder.begin_render_pass(&render_pass_desc);
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &camera_bg, &[camera_offset]);
+        for (model_bg, model_offset, gpu_mesh) in &draws {
+            pass.set_bind_group(1, model_bg, &[*model_offset]);
+            pass.set_vertex_buffer(0, &gpu_mesh.vertex_buffer, 0);
+            pass.set_index_buffer(&gpu_mesh.index_buffer, 0, gpu_mesh.index_format);
+            pass.draw_indexed(0..gpu_mesh.index_count, 0, 0..1);
+        }
+        Ok(Some(targets.id_texture))
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        self.camera_ring.destroy(device);
+        self.model_ring.destroy(device);
+        if let Some(targets) = self.targets {
+            targets.destroy(device);
+        }
+        if let Err(e) = device.destroy_render_pipeline(self.pipeline) {
+            log::warn!("IdPassLane: Failed to destroy pipeline: {:?}", e);
+        }
+        for layout in [self.camera_layout, self.model_layout] {
+            if let Err(e) = device.destroy_bind_group_layout(layout) {
+                log::warn!("IdPassLane: Failed to destroy layout: {:?}", e);
+            }
+        }
+    }
+}
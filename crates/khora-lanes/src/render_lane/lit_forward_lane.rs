@@ -29,6 +29,7 @@ use khora_core::math::{Extent2D, Extent3D, LinearRgba, Mat4, Origin3D};
 #[allow(unused_imports)]
 use khora_core::renderer::api::command::BindGroupLayoutId;
 
+use crate::render_lane::PipelineVariants;
 use khora_core::renderer::api::util::uniform_ring_buffer::UniformRingBuffer;
 use khora_core::{
     asset::Material,
@@ -38,20 +39,20 @@ use khora_core::{
                 LoadOp, Operations, RenderPassColorAttachment, RenderPassDepthStencilAttachment,
                 RenderPassDescriptor, StoreOp,
             },
-            core::RenderContext,
+            core::{LightingDebugView, RenderContext},
             pipeline::enums::PrimitiveTopology,
             pipeline::RenderPipelineId,
             scene::{
                 DirectionalLightUniform, GpuMesh, LightingUniforms, MaterialUniforms,
-                ModelUniforms, PointLightUniform, SpotLightUniform, MAX_DIRECTIONAL_LIGHTS,
-                MAX_POINT_LIGHTS, MAX_SPOT_LIGHTS,
+                ModelUniforms, PointLightUniform, SkinningMode, SpotLightUniform,
+                MAX_DIRECTIONAL_LIGHTS, MAX_POINT_LIGHTS, MAX_SHADOW_CASCADES, MAX_SPOT_LIGHTS,
             },
         },
         traits::CommandEncoder,
     },
 };
 use khora_data::assets::Assets;
-use khora_data::render::RenderWorld;
+use khora_data::render::{RenderWorld, ShadowCascades};
 use std::sync::RwLock;
 
 /// Constants for cost estimation.
@@ -130,12 +131,20 @@ pub struct LitForwardLane {
     pub max_point_lights: u32,
     /// Maximum number of spot lights supported per pass.
     pub max_spot_lights: u32,
-    /// The stored render pipeline handle.
-    pipeline: std::sync::Mutex<Option<RenderPipelineId>>,
+    /// The stored render pipeline, per kind of color target.
+    pipelines: std::sync::Mutex<Option<PipelineVariants>>,
+    /// Pipelines that skin vertices in the vertex shader.
+    skinned_pipelines: std::sync::Mutex<Option<PipelineVariants>>,
+    /// Additive pipelines drawing the overdraw debug view.
+    overdraw_pipelines: std::sync::Mutex<Option<PipelineVariants>>,
+    /// Overdraw pipelines for vertex-shader skinned meshes.
+    skinned_overdraw_pipelines: std::sync::Mutex<Option<PipelineVariants>>,
     /// Layout for Camera (Group 0)
     camera_layout: std::sync::Mutex<Option<BindGroupLayoutId>>,
     /// Layout for Model (Group 1)
     model_layout: std::sync::Mutex<Option<BindGroupLayoutId>>,
+    /// Layout for skinned Model (Group 1): model uniform + skin buffers.
+    skinned_model_layout: std::sync::Mutex<Option<BindGroupLayoutId>>,
     /// Layout for Material (Group 2)
     material_layout: std::sync::Mutex<Option<BindGroupLayoutId>>,
     /// Layout for Lighting (Group 3) — full layout with shadow atlas + sampler for pipeline.
@@ -155,9 +164,13 @@ impl Default for LitForwardLane {
             max_directional_lights: 4,
             max_point_lights: 16,
             max_spot_lights: 8,
-            pipeline: std::sync::Mutex::new(None),
+            pipelines: std::sync::Mutex::new(None),
+            skinned_pipelines: std::sync::Mutex::new(None),
+            overdraw_pipelines: std::sync::Mutex::new(None),
+            skinned_overdraw_pipelines: std::sync::Mutex::new(None),
             camera_layout: std::sync::Mutex::new(None),
             model_layout: std::sync::Mutex::new(None),
+            skinned_model_layout: std::sync::Mutex::new(None),
             material_layout: std::sync::Mutex::new(None),
             light_layout: std::sync::Mutex::new(None),
             lighting_buffer_layout: std::sync::Mutex::new(None),
@@ -243,7 +256,11 @@ impl khora_core::lane::Lane for LitForwardLane {
             .ok_or(khora_core::lane::LaneError::missing(
                 "Arc<dyn GraphicsDevice>",
             ))?;
-        self.on_gpu_init(device.as_ref())
+        let depth_mode = ctx
+            .get::<khora_core::renderer::api::core::DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        self.on_gpu_init(device.as_ref(), depth_mode)
             .map_err(|e| khora_core::lane::LaneError::InitializationFailed(Box::new(e)))
     }
 
@@ -272,10 +289,15 @@ impl khora_core::lane::Lane for LitForwardLane {
             .get::<Ref<khora_data::render::RenderWorld>>()
             .ok_or(LaneError::missing("Ref<RenderWorld>"))?
             .get();
-        let color_target = ctx
-            .get::<khora_core::lane::ColorTarget>()
-            .ok_or(LaneError::missing("ColorTarget"))?
-            .0;
+        let hdr_target = ctx.get::<khora_core::lane::HdrSceneTarget>().map(|t| t.0);
+        let color_target = match hdr_target {
+            Some(view) => view,
+            None => {
+                ctx.get::<khora_core::lane::ColorTarget>()
+                    .ok_or(LaneError::missing("ColorTarget"))?
+                    .0
+            }
+        };
         let depth_target = ctx
             .get::<khora_core::lane::DepthTarget>()
             .ok_or(LaneError::missing("DepthTarget"))?
@@ -296,6 +318,20 @@ impl khora_core::lane::Lane for LitForwardLane {
         );
         render_ctx.shadow_atlas = shadow_atlas.as_ref();
         render_ctx.shadow_sampler = shadow_sampler.as_ref();
+        render_ctx.depth_mode = ctx
+            .get::<khora_core::renderer::api::core::DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        render_ctx.hdr = hdr_target.is_some();
+        let multisample = ctx.get::<khora_core::lane::MultisampleTargets>().copied();
+        if let Some(targets) = multisample.as_ref() {
+            render_ctx.multisample_into(&targets.color, &targets.depth);
+        }
+        if let Some(jitter) = ctx.get::<khora_core::lane::ProjectionJitter>() {
+            render_ctx.jitter = jitter.0;
+        }
+        render_ctx.depth_prepared = ctx.contains::<khora_core::lane::DepthPrepared>();
+        render_ctx.debug_view = ctx.get::<LightingDebugView>().copied().unwrap_or_default();
 
         // Per-light shadow data published by `shadow_pass_lane` into the
         // per-frame OutputDeck. Cloned out so the borrow on `ctx` is short.
@@ -338,7 +374,10 @@ impl LitForwardLane {
         _material: Option<&khora_core::asset::AssetHandle<Box<dyn Material>>>,
     ) -> RenderPipelineId {
         // Return the stored pipeline. Fallback to pipeline 0 if on_gpu_init hasn't run yet.
-        self.pipeline.lock().unwrap().unwrap_or(RenderPipelineId(0))
+        self.pipelines
+            .lock()
+            .unwrap()
+            .map_or(RenderPipelineId(0), |p| p.ldr())
     }
 
     fn render(
@@ -362,7 +401,7 @@ impl LitForwardLane {
         let Some(view) = render_world.views.first() else {
             let color_attachment = khora_core::renderer::api::command::RenderPassColorAttachment {
                 view: render_ctx.color_target,
-                resolve_target: None,
+                resolve_target: render_ctx.resolve_target,
                 ops: khora_core::renderer::api::command::Operations {
                     load: khora_core::renderer::api::command::LoadOp::Clear(render_ctx.clear_color),
                     store: khora_core::renderer::api::command::StoreOp::Store,
@@ -376,7 +415,9 @@ impl LitForwardLane {
                     khora_core::renderer::api::command::RenderPassDepthStencilAttachment {
                         view: depth_view,
                         depth_ops: Some(khora_core::renderer::api::command::Operations {
-                            load: khora_core::renderer::api::command::LoadOp::Clear(1.0),
+                            load: khora_core::renderer::api::command::LoadOp::Clear(
+                                render_ctx.depth_mode.clear_depth(),
+                            ),
                             store: khora_core::renderer::api::command::StoreOp::Store,
                         }),
                         stencil_ops: None,
@@ -394,7 +435,7 @@ impl LitForwardLane {
 
         // Camera Uniforms — write to persistent ring buffer
         let camera_uniforms = khora_core::renderer::api::resource::CameraUniformData {
-            view_projection: view.view_proj.to_cols_array_2d(),
+            view_projection: render_ctx.jittered(view.view_proj).to_cols_array_2d(),
             camera_position: [view.position.x, view.position.y, view.position.z, 1.0],
         };
 
@@ -420,13 +461,16 @@ impl LitForwardLane {
             directional_lights: [DirectionalLightUniform {
                 direction: [0.0; 4],
                 color: khora_core::math::LinearRgba::BLACK,
-                shadow_view_proj: [[0.0; 4]; 4],
-                shadow_params: [0.0; 4],
+                cascade_view_proj: [[[0.0; 4]; 4]; MAX_SHADOW_CASCADES],
+                cascade_texel_sizes: [0.0; MAX_SHADOW_CASCADES],
+                shadow_params: [0.0; 3],
+                layers: 0,
             }; MAX_DIRECTIONAL_LIGHTS],
             point_lights: [PointLightUniform {
                 position: [0.0; 4],
                 color: khora_core::math::LinearRgba::BLACK,
-                shadow_params: [0.0; 4],
+                shadow_params: [0.0; 3],
+                layers: 0,
             }; MAX_POINT_LIGHTS],
             spot_lights: [SpotLightUniform {
                 position: [0.0; 4],
@@ -434,16 +478,21 @@ impl LitForwardLane {
                 color: khora_core::math::LinearRgba::BLACK,
                 params: [0.0; 4],
                 shadow_view_proj: [[0.0; 4]; 4],
-                shadow_params: [0.0; 4],
+                shadow_params: [0.0; 3],
+                layers: 0,
             }; MAX_SPOT_LIGHTS],
             num_directional_lights: 0,
             num_point_lights: 0,
             num_spot_lights: 0,
-            _padding: 0,
+            debug_view: render_ctx.debug_view.shader_index(),
         };
 
+        // Lights are in lux and candela; pre-expose them so HDR values stay
+        // in range.
+        let pre_exposure = render_world.exposure.unwrap_or_default().scale();
         for (light_index, light) in render_world.lights.iter().enumerate() {
             let shadow = shadow_entries.get(light_index);
+            let intensity = light.light_type.shading_intensity() * pre_exposure;
             let shadow_view_proj = shadow
                 .map(|e| e.view_proj)
                 .unwrap_or(khora_core::math::Mat4::IDENTITY);
@@ -454,16 +503,23 @@ impl LitForwardLane {
                     if (lighting_uniforms.num_directional_lights as usize) < MAX_DIRECTIONAL_LIGHTS
                     {
                         let idx = lighting_uniforms.num_directional_lights as usize;
+                        // A single-map shadow pass publishes no cascades;
+                        // its map then acts as the only cascade.
+                        let cascades = shadow
+                            .and_then(|e| e.cascades)
+                            .unwrap_or_else(|| ShadowCascades::single(shadow_view_proj));
                         lighting_uniforms.directional_lights[idx] = DirectionalLightUniform {
                             direction: [
                                 light.direction.x,
                                 light.direction.y,
                                 light.direction.z,
-                                0.0,
+                                cascades.count as f32,
                             ],
-                            color: d.color.with_alpha(d.intensity),
-                            shadow_view_proj: shadow_view_proj.to_cols_array_2d(),
-                            shadow_params: [shadow_index, d.shadow_bias, d.shadow_normal_bias, 0.0],
+                            color: d.color.with_alpha(intensity),
+                            cascade_view_proj: cascades.view_proj.map(|m| m.to_cols_array_2d()),
+                            cascade_texel_sizes: cascades.texel_sizes,
+                            shadow_params: [shadow_index, d.shadow_bias, d.shadow_normal_bias],
+                            layers: light.layers.0,
                         };
                         lighting_uniforms.num_directional_lights += 1;
                     }
@@ -478,8 +534,9 @@ impl LitForwardLane {
                                 light.position.z,
                                 p.range,
                             ],
-                            color: p.color.with_alpha(p.intensity),
-                            shadow_params: [shadow_index, p.shadow_bias, p.shadow_normal_bias, 0.0],
+                            color: p.color.with_alpha(intensity),
+                            shadow_params: [shadow_index, p.shadow_bias, p.shadow_normal_bias],
+                            layers: light.layers.0,
                         };
                         lighting_uniforms.num_point_lights += 1;
                     }
@@ -500,10 +557,11 @@ impl LitForwardLane {
                                 light.direction.z,
                                 s.inner_cone_angle.cos(),
                             ],
-                            color: s.color.with_alpha(s.intensity),
+                            color: s.color.with_alpha(intensity),
                             params: [s.outer_cone_angle.cos(), 0.0, 0.0, 0.0],
                             shadow_view_proj: shadow_view_proj.to_cols_array_2d(),
-                            shadow_params: [shadow_index, s.shadow_bias, s.shadow_normal_bias, 0.0],
+                            shadow_params: [shadow_index, s.shadow_bias, s.shadow_normal_bias],
+                            layers: light.layers.0,
                         };
                         lighting_uniforms.num_spot_lights += 1;
                     }
@@ -537,7 +595,23 @@ impl LitForwardLane {
         let gpu_mesh_assets = gpu_meshes.read().unwrap();
 
         // Pipeline binding logic moved before render pass to avoid issues
-        let pipeline_id = self.pipeline.lock().unwrap().unwrap_or(RenderPipelineId(0));
+        let (pipelines, skinned_pipelines) = if render_ctx.debug_view == LightingDebugView::Overdraw
+        {
+            (&self.overdraw_pipelines, &self.skinned_overdraw_pipelines)
+        } else {
+            (&self.pipelines, &self.skinned_pipelines)
+        };
+        let pipeline_id = pipelines
+            .lock()
+            .unwrap()
+            .map_or(RenderPipelineId(0), |p| p.select(render_ctx));
+        let skinned = (
+            skinned_pipelines
+                .lock()
+                .unwrap()
+                .map(|p| p.select(render_ctx)),
+            *self.skinned_model_layout.lock().unwrap(),
+        );
 
         // Prepare Draw Commands
         let mut draw_commands = Vec::with_capacity(render_world.meshes.len());
@@ -557,15 +631,7 @@ impl LitForwardLane {
                     continue;
                 };
 
-                let mut base_color = khora_core::math::LinearRgba::WHITE;
-                let mut emissive = khora_core::math::LinearRgba::BLACK;
-                let mut specular_power = 32.0;
-
-                if let Some(mat_handle) = &extracted_mesh.material {
-                    base_color = mat_handle.base_color();
-                    emissive = mat_handle.emissive_color();
-                    specular_power = mat_handle.specular_power();
-                }
+                let params = extracted_mesh.material_params();
 
                 let model_uniforms = ModelUniforms {
                     model_matrix: model_mat.to_cols_array_2d(),
@@ -589,9 +655,10 @@ impl LitForwardLane {
                 };
 
                 let material_uniforms = MaterialUniforms {
-                    base_color,
-                    emissive: emissive.with_alpha(specular_power),
-                    ambient: khora_core::math::LinearRgba::new(0.1, 0.1, 0.1, 0.0),
+                    base_color: params.base_color,
+                    emissive: params.emissive.with_alpha(params.specular_power),
+                    ambient: [0.1, 0.1, 0.1],
+                    layers: extracted_mesh.layers.0,
                 };
                 let material_buffer = match device.create_buffer_with_data(
                     &BufferDescriptor {
@@ -609,9 +676,49 @@ impl LitForwardLane {
                     Err(_) => continue,
                 };
 
-                // Create Bind Groups 1 & 2
+                // Vertex-shader skinned meshes bind their skin buffers in group 1
+                // and fetch bind-pose vertices themselves.
+                let mut mesh_pipeline = pipeline_id;
                 let mut model_bg = None;
-                if let Some(layout) = *self.model_layout.lock().unwrap() {
+                if let (Some(skin), (Some(skinned_pipeline), Some(layout))) =
+                    (&extracted_mesh.skin, skinned)
+                {
+                    if skin.mode == SkinningMode::VertexShader {
+                        let layout_buffer = match device.create_buffer_with_data(
+                            &BufferDescriptor {
+                                label: None,
+                                size: std::mem::size_of_val(&skin.layout) as u64,
+                                usage: BufferUsage::UNIFORM,
+                                mapped_at_creation: false,
+                            },
+                            bytemuck::bytes_of(&skin.layout),
+                        ) {
+                            Ok(b) => {
+                                temp_buffers.push(b);
+                                b
+                            }
+                            Err(_) => continue,
+                        };
+                        if let Ok(bg) = device.create_bind_group(&BindGroupDescriptor {
+                            label: None,
+                            layout,
+                            entries: &[
+                                BindGroupEntry::buffer(0, model_buffer, 0, None),
+                                BindGroupEntry::buffer(1, skin.bind_pose, 0, None),
+                                BindGroupEntry::buffer(2, skin.influences, 0, None),
+                                BindGroupEntry::buffer(3, skin.joint_matrices, 0, None),
+                                BindGroupEntry::buffer(4, layout_buffer, 0, None),
+                            ],
+                        }) {
+                            model_bg = Some(bg);
+                            temp_bind_groups.push(bg);
+                            mesh_pipeline = skinned_pipeline;
+                        }
+                    }
+                }
+
+                // Create Bind Groups 1 & 2
+                if let (None, Some(layout)) = (model_bg, *self.model_layout.lock().unwrap()) {
                     if let Ok(bg) = device.create_bind_group(&BindGroupDescriptor {
                         label: None,
                         layout,
@@ -651,7 +758,7 @@ impl LitForwardLane {
                 }
 
                 draw_commands.push(khora_core::renderer::api::command::DrawCommand {
-                    pipeline: pipeline_id,
+                    pipeline: mesh_pipeline,
                     vertex_buffer: gpu_mesh_handle.vertex_buffer,
                     index_buffer: gpu_mesh_handle.index_buffer,
                     index_format: gpu_mesh_handle.index_format,
@@ -667,7 +774,7 @@ impl LitForwardLane {
         // Render Pass
         let color_attachment = RenderPassColorAttachment {
             view: render_ctx.color_target,
-            resolve_target: None,
+            resolve_target: render_ctx.resolve_target,
             ops: Operations {
                 load: LoadOp::Clear(render_ctx.clear_color),
                 store: StoreOp::Store,
@@ -682,7 +789,7 @@ impl LitForwardLane {
                 RenderPassDepthStencilAttachment {
                     view: depth_view,
                     depth_ops: Some(Operations {
-                        load: LoadOp::Clear(1.0),
+                        load: render_ctx.depth_load(),
                         store: StoreOp::Store,
                     }),
                     stencil_ops: None,
@@ -757,10 +864,12 @@ impl LitForwardLane {
 
         let mut current_pipeline: Option<RenderPipelineId> = None;
 
+        // Sorted draws arrive batched by mesh; rebind buffers only at batch edges.
+        let mut current_buffers = None;
         for cmd in &draw_commands {
-            if current_pipeline != Some(pipeline_id) {
-                render_pass.set_pipeline(&pipeline_id);
-                current_pipeline = Some(pipeline_id);
+            if current_pipeline != Some(cmd.pipeline) {
+                render_pass.set_pipeline(&cmd.pipeline);
+                current_pipeline = Some(cmd.pipeline);
             }
 
             if let Some(bg) = &cmd.model_bind_group {
@@ -771,8 +880,11 @@ impl LitForwardLane {
                 render_pass.set_bind_group(2, bg, &[]);
             }
 
-            render_pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
-            render_pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+            if current_buffers != Some((cmd.vertex_buffer, cmd.index_buffer)) {
+                render_pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
+                render_pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+                current_buffers = Some((cmd.vertex_buffer, cmd.index_buffer));
+            }
 
             render_pass.draw_indexed(0..cmd.index_count, 0, 0..1);
         }
@@ -835,6 +947,7 @@ impl LitForwardLane {
     fn on_gpu_init(
         &self,
         device: &dyn khora_core::renderer::GraphicsDevice,
+        depth_mode: khora_core::renderer::api::core::DepthMode,
     ) -> Result<(), khora_core::renderer::error::RenderError> {
         use crate::render_lane::shaders::LIT_FORWARD_WGSL;
         use khora_core::renderer::api::{
@@ -842,12 +955,11 @@ impl LitForwardLane {
                 BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, BufferBindingType,
             },
             core::{ShaderModuleDescriptor, ShaderSourceData},
-            pipeline::enums::{CompareFunction, VertexFormat, VertexStepMode},
             pipeline::state::{ColorWrites, DepthBiasState, StencilFaceState},
             pipeline::{
                 ColorTargetStateDescriptor, DepthStencilStateDescriptor,
                 MultisampleStateDescriptor, PrimitiveStateDescriptor, RenderPipelineDescriptor,
-                VertexAttributeDescriptor, VertexBufferLayoutDescriptor,
+                StaticVertex, Vertex,
             },
             util::{SampleCount, ShaderStageFlags, TextureFormat},
         };
@@ -889,6 +1001,29 @@ impl LitForwardLane {
             })
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
 
+        // Group 1 (skinned variant): model uniform + skin storage buffers
+        let skin_entry = |binding, ty| BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStageFlags::VERTEX,
+            ty: BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+        };
+        let skinned_model_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("lit_forward_skinned_model_layout"),
+                entries: &[
+                    skin_entry(0, BufferBindingType::Uniform),
+                    skin_entry(1, BufferBindingType::Storage { read_only: true }),
+                    skin_entry(2, BufferBindingType::Storage { read_only: true }),
+                    skin_entry(3, BufferBindingType::Storage { read_only: true }),
+                    skin_entry(4, BufferBindingType::Uniform),
+                ],
+            })
+            .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
+
         // Group 2: Material
         let material_layout = device
             .create_bind_group_layout(&BindGroupLayoutDescriptor {
@@ -948,29 +1083,7 @@ impl LitForwardLane {
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
 
         // 3. Create Pipeline
-        let vertex_attributes = vec![
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 0,
-                shader_location: 0,
-            },
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 12,
-                shader_location: 1,
-            },
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x2,
-                offset: 24,
-                shader_location: 2,
-            },
-        ];
-
-        let vertex_layout = VertexBufferLayoutDescriptor {
-            array_stride: 32, // 3+3+2 floats * 4 bytes
-            step_mode: VertexStepMode::Vertex,
-            attributes: Cow::Owned(vertex_attributes),
-        };
+        let vertex_layout = StaticVertex::LAYOUT;
 
         let pipeline_layout_ids = vec![camera_layout, model_layout, material_layout, light_layout];
         // Store bind group layouts for creating bind groups during rendering.
@@ -1004,7 +1117,8 @@ impl LitForwardLane {
             depth_stencil_state: Some(DepthStencilStateDescriptor {
                 format: TextureFormat::Depth32Float,
                 depth_write_enabled: true,
-                depth_compare: CompareFunction::Less,
+                // Or-equal, so surfaces a depth prepass wrote still shade.
+                depth_compare: depth_mode.compare_or_equal(),
                 stencil_front: StencilFaceState::default(),
                 stencil_back: StencilFaceState::default(),
                 stencil_read_mask: 0,
@@ -1025,12 +1139,38 @@ impl LitForwardLane {
             },
         };
 
-        let pipeline_id = device
-            .create_render_pipeline(&pipeline_desc)
+        let pipelines = PipelineVariants::new(device, &pipeline_desc, "fs_hdr")?;
+        *self.pipelines.lock().unwrap() = Some(pipelines);
+        let overdraw_pipelines = PipelineVariants::overdraw(device, &pipeline_desc, "fs_overdraw")?;
+        *self.overdraw_pipelines.lock().unwrap() = Some(overdraw_pipelines);
+
+        // Skinned variant: same state, vertices pulled from the skin buffers.
+        let skinned_pipeline_layout_id = device
+            .create_pipeline_layout(
+                &khora_core::renderer::api::pipeline::PipelineLayoutDescriptor {
+                    label: Some(Cow::Borrowed("LitForward Skinned Pipeline Layout")),
+                    bind_group_layouts: &[
+                        camera_layout,
+                        skinned_model_layout,
+                        material_layout,
+                        light_layout,
+                    ],
+                },
+            )
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
-
-        let mut pipeline_lock = self.pipeline.lock().unwrap();
-        *pipeline_lock = Some(pipeline_id);
+        let skinned_pipeline_desc = RenderPipelineDescriptor {
+            label: Some(Cow::Borrowed("LitForward Skinned Pipeline")),
+            layout: Some(skinned_pipeline_layout_id),
+            vertex_entry_point: Cow::Borrowed("vs_skinned"),
+            vertex_buffers_layout: Cow::Owned(Vec::new()),
+            ..pipeline_desc.clone()
+        };
+        let skinned_pipelines = PipelineVariants::new(device, &skinned_pipeline_desc, "fs_hdr")?;
+        *self.skinned_pipelines.lock().unwrap() = Some(skinned_pipelines);
+        let skinned_overdraw_pipelines =
+            PipelineVariants::overdraw(device, &skinned_pipeline_desc, "fs_overdraw")?;
+        *self.skinned_overdraw_pipelines.lock().unwrap() = Some(skinned_overdraw_pipelines);
+        *self.skinned_model_layout.lock().unwrap() = Some(skinned_model_layout);
 
         // 4. Create Persistent Ring Buffers for camera and lighting uniforms.
         // This eliminates per-frame buffer allocation in the render hot path.
@@ -1095,9 +1235,17 @@ impl LitForwardLane {
             ring.destroy(device);
         }
 
-        let mut pipeline_lock = self.pipeline.lock().unwrap();
-        if let Some(id) = pipeline_lock.take() {
-            let _ = device.destroy_render_pipeline(id);
+        if let Some(pipelines) = self.pipelines.lock().unwrap().take() {
+            pipelines.destroy(device);
+        }
+        if let Some(pipelines) = self.skinned_pipelines.lock().unwrap().take() {
+            pipelines.destroy(device);
+        }
+        if let Some(pipelines) = self.overdraw_pipelines.lock().unwrap().take() {
+            pipelines.destroy(device);
+        }
+        if let Some(pipelines) = self.skinned_overdraw_pipelines.lock().unwrap().take() {
+            pipelines.destroy(device);
         }
         if let Some(id) = self.camera_layout.lock().unwrap().take() {
             let _ = device.destroy_bind_group_layout(id);
@@ -1105,6 +1253,9 @@ impl LitForwardLane {
         if let Some(id) = self.model_layout.lock().unwrap().take() {
             let _ = device.destroy_bind_group_layout(id);
         }
+        if let Some(id) = self.skinned_model_layout.lock().unwrap().take() {
+            let _ = device.destroy_bind_group_layout(id);
+        }
         if let Some(id) = self.material_layout.lock().unwrap().take() {
             let _ = device.destroy_bind_group_layout(id);
         }
@@ -1191,10 +1342,16 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: AffineTransform::default(),
             cpu_mesh_uuid: mesh_uuid,
             gpu_mesh: AssetHandle::new(create_test_gpu_mesh(300)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
@@ -1228,10 +1385,16 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: AffineTransform::default(),
             cpu_mesh_uuid: mesh_uuid,
             gpu_mesh: AssetHandle::new(create_test_gpu_mesh(300)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         // Add 4 directional lights
@@ -1242,6 +1405,7 @@ mod tests {
                 direction: Vec3::new(0.0, -1.0, 0.0),
                 shadow_view_proj: Mat4::IDENTITY,
                 shadow_atlas_index: None,
+                layers: Default::default(),
             });
         }
 
@@ -1269,10 +1433,16 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: AffineTransform::default(),
             cpu_mesh_uuid: mesh_uuid,
             gpu_mesh: AssetHandle::new(create_test_gpu_mesh(300)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
@@ -1319,6 +1489,7 @@ mod tests {
                 direction: Vec3::new(0.0, -1.0, 0.0),
                 shadow_view_proj: Mat4::IDENTITY,
                 shadow_atlas_index: None,
+                layers: Default::default(),
             });
         }
 
@@ -1330,6 +1501,7 @@ mod tests {
                 direction: Vec3::ZERO,
                 shadow_view_proj: Mat4::IDENTITY,
                 shadow_atlas_index: None,
+                layers: Default::default(),
             });
         }
 
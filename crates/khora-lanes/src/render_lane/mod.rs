@@ -18,15 +18,40 @@
 //! [`khora_data::render`].  This module exposes the lanes that consume that
 //! data and the UI-scene types specific to the UI render pipeline.
 
+mod auto_exposure_lane;
+mod bloom_lane;
+mod cascaded_shadow_lane;
+mod depth_prepass_lane;
 mod forward_plus_lane;
+mod fxaa_lane;
+mod id_pass_lane;
 mod lit_forward_lane;
+mod motion_vector_lane;
+mod msaa_lane;
+mod pipeline_variants;
+mod render_target;
 pub mod shaders;
 mod shadow_pass_lane;
 mod simple_unlit_lane;
+mod skinning_lane;
+mod taa_lane;
+mod tone_map_lane;
 mod ui_render_lane;
 
+pub use auto_exposure_lane::*;
+pub use bloom_lane::*;
+pub use cascaded_shadow_lane::*;
+pub use depth_prepass_lane::*;
 pub use forward_plus_lane::*;
+pub use fxaa_lane::*;
+pub use id_pass_lane::*;
 pub use lit_forward_lane::*;
+pub use motion_vector_lane::*;
+pub use msaa_lane::*;
+pub use pipeline_variants::*;
 pub use shadow_pass_lane::*;
 pub use simple_unlit_lane::*;
+pub use skinning_lane::*;
+pub use taa_lane::*;
+pub use tone_map_lane::*;
 pub use ui_render_lane::*;
@@ -0,0 +1,455 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Motion vector lane — per-pixel screen-space motion for temporal passes.
+//!
+//! The lane renders every mesh of the main view into an `Rg16Float` target
+//! sized to the frame, projecting each vertex with both the current and the
+//! previous frame's camera and model matrices, and publishes the result as
+//! the [`MotionVectorTarget`]. Pixels no mesh covers keep zero motion.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex, RwLock};
+
+use khora_core::lane::{
+    Lane, LaneContext, LaneError, LaneKind, MotionVectorTarget, Ref, Slot, TargetSize,
+};
+use khora_core::math::{Extent2D, LinearRgba};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindGroupLayoutId, BindingType,
+        BufferBindingType, LoadOp, Operations, RenderPassColorAttachment,
+        RenderPassDepthStencilAttachment, RenderPassDescriptor, StoreOp,
+    },
+    core::{DepthMode, ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology,
+        state::{ColorWrites, DepthBiasState, StencilFaceState},
+        ColorTargetStateDescriptor, DepthStencilStateDescriptor, MultisampleStateDescriptor,
+        PipelineLayoutDescriptor, PrimitiveStateDescriptor, RenderPipelineDescriptor,
+        RenderPipelineId, StaticVertex,
+    },
+    resource::{TextureUsage, TextureViewId},
+    scene::GpuMesh,
+    util::{
+        dynamic_uniform_buffer::{
+            DynamicUniformRingBuffer, DEFAULT_MAX_ELEMENTS, MIN_UNIFORM_ALIGNMENT,
+        },
+        SampleCount, ShaderStageFlags, TextureFormat,
+    },
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::assets::Assets;
+use khora_data::render::RenderWorld;
+
+use super::render_target::RenderTarget;
+
+/// Format of the motion vector target.
+const MOTION_FORMAT: TextureFormat = TextureFormat::Rg16Float;
+
+/// Per-frame camera uniform, matching `CameraUniforms` in
+/// `motion_vectors.wgsl`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct MotionCameraUniforms {
+    view_projection: [[f32; 4]; 4],
+    prev_view_projection: [[f32; 4]; 4],
+}
+
+/// Per-mesh uniform, matching `ModelUniforms` in `motion_vectors.wgsl`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct MotionModelUniforms {
+    model_matrix: [[f32; 4]; 4],
+    prev_model_matrix: [[f32; 4]; 4],
+}
+
+/// Motion and depth targets, recreated on resize.
+struct MotionTargets {
+    motion: RenderTarget,
+    depth: RenderTarget,
+}
+
+impl MotionTargets {
+    fn new(device: &dyn GraphicsDevice, size: Extent2D) -> Result<Self, RenderError> {
+        let motion = RenderTarget::new(
+            device,
+            "Motion Vector Target",
+            size,
+            MOTION_FORMAT,
+            TextureUsage::RENDER_ATTACHMENT | TextureUsage::TEXTURE_BINDING,
+            SampleCount::X1,
+        )?;
+        let depth = match RenderTarget::new(
+            device,
+            "Motion Vector Depth",
+            size,
+            TextureFormat::Depth32Float,
+            TextureUsage::RENDER_ATTACHMENT,
+            SampleCount::X1,
+        ) {
+            Ok(depth) => depth,
+            Err(e) => {
+                motion.destroy(device);
+                return Err(e);
+            }
+        };
+        Ok(Self { motion, depth })
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        self.motion.destroy(device);
+        self.depth.destroy(device);
+    }
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct MotionVectorGpu {
+    pipeline: RenderPipelineId,
+    camera_layout: BindGroupLayoutId,
+    model_layout: BindGroupLayoutId,
+    camera_ring: DynamicUniformRingBuffer,
+    model_ring: DynamicUniformRingBuffer,
+    depth_mode: DepthMode,
+    targets: Option<MotionTargets>,
+}
+
+/// A rendering lane writing how far each pixel moved since the previous
+/// frame, for TAA reprojection.
+#[derive(Default)]
+pub struct MotionVectorLane {
+    gpu: Mutex<Option<MotionVectorGpu>>,
+}
+
+impl MotionVectorLane {
+    /// Creates a new `MotionVectorLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for MotionVectorLane {
+    fn strategy_name(&self) -> &'static str {
+        "MotionVectors"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<Ref<RenderWorld>>() {
+            Some(render_world) => render_world.get().meshes.len() as f32 * 0.001,
+            None => 1.0,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let depth_mode = ctx.get::<DepthMode>().copied().unwrap_or_default();
+        let gpu = MotionVectorGpu::new(device.as_ref(), depth_mode)
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let gpu_meshes = ctx
+            .get::<Arc<RwLock<Assets<GpuMesh>>>>()
+            .ok_or(LaneError::missing("Arc<RwLock<Assets<GpuMesh>>>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+        let render_world = ctx
+            .get::<Ref<RenderWorld>>()
+            .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+            .get();
+        let encoder = ctx
+            .get::<Slot<dyn CommandEncoder>>()
+            .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+            .get();
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            return Err(LaneError::NotInitialized);
+        };
+
+        let target = gpu
+            .render(device.as_ref(), encoder, render_world, &gpu_meshes, size)
+            .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+        if let Some(view) = target {
+            ctx.insert(MotionVectorTarget(view));
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl MotionVectorGpu {
+    fn new(device: &dyn GraphicsDevice, depth_mode: DepthMode) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::MOTION_VECTORS_WGSL;
+
+        let uniform_entries = [BindGroupLayoutEntry {
+            binding: 0,
+            visibility: ShaderStageFlags::VERTEX,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: None,
+            },
+        }];
+        let camera_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("motion_vector_camera_layout"),
+                entries: &uniform_entries,
+            })
+            .map_err(RenderError::ResourceError)?;
+        let model_layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("motion_vector_model_layout"),
+                entries: &uniform_entries,
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let shader_module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("motion_vector_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(MOTION_VECTORS_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Motion Vector Pipeline Layout")),
+                bind_group_layouts: &[camera_layout, model_layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let vertex_layout = StaticVertex::POSITION_ONLY;
+
+        let pipeline = device
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some(Cow::Borrowed("Motion Vector Pipeline")),
+                layout: Some(pipeline_layout),
+                vertex_shader_module: shader_module,
+                vertex_entry_point: Cow::Borrowed("vs_main"),
+                fragment_shader_module: Some(shader_module),
+                fragment_entry_point: Some(Cow::Borrowed("fs_main")),
+                color_target_states: Cow::Owned(vec![ColorTargetStateDescriptor {
+                    format: MOTION_FORMAT,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                }]),
+                vertex_buffers_layout: Cow::Owned(vec![vertex_layout]),
+                primitive_state: PrimitiveStateDescriptor {
+                    topology: PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil_state: Some(DepthStencilStateDescriptor {
+                    format: TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: depth_mode.compare(),
+                    stencil_front: StencilFaceState::default(),
+                    stencil_back: StencilFaceState::default(),
+                    stencil_read_mask: 0,
+                    stencil_write_mask: 0,
+                    bias: DepthBiasState::default(),
+                }),
+                multisample_state: MultisampleStateDescriptor {
+                    count: SampleCount::X1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let camera_ring = DynamicUniformRingBuffer::new(
+            device,
+            camera_layout,
+            0,
+            std::mem::size_of::<MotionCameraUniforms>() as u32,
+            1,
+            MIN_UNIFORM_ALIGNMENT,
+            "Motion Vector Camera Ring",
+        )
+        .map_err(RenderError::ResourceError)?;
+        let model_ring = DynamicUniformRingBuffer::new(
+            device,
+            model_layout,
+            0,
+            std::mem::size_of::<MotionModelUniforms>() as u32,
+            DEFAULT_MAX_ELEMENTS,
+            MIN_UNIFORM_ALIGNMENT,
+            "Motion Vector Model Ring",
+        )
+        .map_err(RenderError::ResourceError)?;
+
+        Ok(Self {
+            pipeline,
+            camera_layout,
+            model_layout,
+            camera_ring,
+            model_ring,
+            depth_mode,
+            targets: None,
+        })
+    }
+
+    /// Draws the main view's motion vectors, returning the motion target,
+    /// or `None` when there is no view or frame to draw.
+    fn render(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        render_world: &RenderWorld,
+        gpu_meshes: &RwLock<Assets<GpuMesh>>,
+        size: Extent2D,
+    ) -> Result<Option<TextureViewId>, RenderError> {
+        let Some(view) = render_world.views.first() else {
+            return Ok(None);
+        };
+        if size.width == 0 || size.height == 0 {
+            return Ok(None);
+        }
+        if self.targets.as_ref().is_none_or(|t| t.motion.size != size) {
+            if let Some(old) = self.targets.take() {
+                old.destroy(device);
+            }
+            self.targets = Some(MotionTargets::new(device, size)?);
+        }
+        let Some(targets) = self.targets.as_ref() else {
+            return Ok(None);
+        };
+
+        self.camera_ring.advance();
+        self.model_ring.advance();
+        // Both matrices are unjittered, so jitter never shows up as motion.
+        let camera = MotionCameraUniforms {
+            view_projection: view.view_proj.to_cols_array_2d(),
+            prev_view_projection: render_world
+                .previous
+                .view_proj_or(view.view_proj)
+                .to_cols_array_2d(),
+        };
+        let camera_offset = self
+            .camera_ring
+            .push(device, bytemuck::bytes_of(&camera))
+            .map_err(RenderError::ResourceError)?;
+        let camera_bg = *self.camera_ring.current_bind_group();
+
+        let Ok(gpu_meshes) = gpu_meshes.read() else {
+            return Ok(None);
+        };
+        let mut draws = Vec::with_capacity(render_world.meshes.len());
+        for mesh in &render_world.meshes {
+            if !mesh.layers.intersects(view.layers) {
+                continue;
+            }
+            let Some(gpu_mesh) = gpu_meshes.get(&mesh.cpu_mesh_uuid) else {
+                continue;
+            };
+            let uniforms = MotionModelUniforms {
+                model_matrix: mesh.transform.to_matrix().to_cols_array_2d(),
+                prev_model_matrix: render_world
+                    .previous
+                    .transform_of(mesh)
+                    .to_matrix()
+                    .to_cols_array_2d(),
+            };
+            match self.model_ring.push(device, bytemuck::bytes_of(&uniforms)) {
+                Ok(offset) => draws.push((*self.model_ring.current_bind_group(), offset, gpu_mesh)),
+                Err(e) => log::error!("MotionVectorLane: Failed to push model uniform: {:?}", e),
+            }
+        }
+
+        let color_attachment = RenderPassColorAttachment {
+            view: &targets.motion.view,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Clear(LinearRgba::new(0.0, 0.0, 0.0, 0.0)),
+                store: StoreOp::Store,
+            },
+            base_array_layer: 0,
+        };
+        let render_pass_desc = RenderPassDescriptor {
+            label: Some("Motion Vector Pass"),
+            color_attachments: &[color_attachment],
+            depth_stencil_attachment: Some(RenderPassDepthStencilAttachment {
+                view: &targets.depth.view,
+                depth_ops: Some(Operations {
+                    load: LoadOp::Clear(self.depth_mode.clear_depth()),
+                    store: StoreOp::Discard,
+                }),
+                stencil_ops: None,
+                base_array_layer: 0,
+            }),
+        };
+        let mut pass = encoder.begin_render_pass(&render_pass_desc);
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &camera_bg, &[camera_offset]);
+        for (model_bg, model_offset, gpu_mesh) in &draws {
+            pass.set_bind_group(1, model_bg, &[*model_offset]);
+            pass.set_vertex_buffer(0, &gpu_mesh.vertex_buffer, 0);
+            pass.set_index_buffer(&gpu_mesh.index_buffer, 0, gpu_mesh.index_format);
+            pass.draw_indexed(0..gpu_mesh.index_count, 0, 0..1);
+        }
+        Ok(Some(targets.motion.view))
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        self.camera_ring.destroy(device);
+        self.model_ring.destroy(device);
+        if let Some(targets) = self.targets {
+            targets.destroy(device);
+        }
+        if let Err(e) = device.destroy_render_pipeline(self.pipeline) {
+            log::warn!("MotionVectorLane: Failed to destroy pipeline: {:?}", e);
+        }
+        for layout in [self.camera_layout, self.model_layout] {
+            if let Err(e) = device.destroy_bind_group_layout(layout) {
+                log::warn!("MotionVectorLane: Failed to destroy layout: {:?}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MSAA lane — multisampled scene targets resolved by the scene pass.
+//!
+//! In [`PostStage::Prepare`] the lane publishes [`MultisampleTargets`]
+//! sized to the frame. The scene lane then draws into them and resolves the
+//! color into the [`HdrSceneTarget`] when one is set, or into the
+//! [`ColorTarget`] otherwise, so there is nothing left to do at resolve.
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{
+    ColorTarget, HdrSceneTarget, Lane, LaneContext, LaneError, LaneKind, MultisampleTargets,
+    PostStage, TargetSize,
+};
+use khora_core::math::Extent2D;
+use khora_core::renderer::api::{
+    resource::TextureUsage,
+    util::{SampleCount, TextureFormat},
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::GraphicsDevice;
+
+use super::render_target::RenderTarget;
+use super::HDR_SCENE_FORMAT;
+
+/// Sample count of the multisampled scene targets.
+pub const MSAA_SAMPLE_COUNT: SampleCount = SampleCount::X4;
+
+/// Samples per pixel, matching [`MSAA_SAMPLE_COUNT`].
+const SAMPLES_PER_PIXEL: f32 = 4.0;
+/// Estimated cost of shading and resolving one sample.
+const SAMPLE_COST: f32 = 0.000_000_2;
+
+/// Multisampled color and depth targets, recreated on resize or when the
+/// scene switches between HDR and swapchain output.
+struct MsaaTargets {
+    color: RenderTarget,
+    depth: RenderTarget,
+}
+
+impl MsaaTargets {
+    fn new(
+        device: &dyn GraphicsDevice,
+        size: Extent2D,
+        format: TextureFormat,
+    ) -> Result<Self, RenderError> {
+        let color = RenderTarget::new(
+            device,
+            "MSAA Color Target",
+            size,
+            format,
+            TextureUsage::RENDER_ATTACHMENT,
+            MSAA_SAMPLE_COUNT,
+        )?;
+        let depth = match RenderTarget::new(
+            device,
+            "MSAA Depth Target",
+            size,
+            TextureFormat::Depth32Float,
+            TextureUsage::RENDER_ATTACHMENT,
+            MSAA_SAMPLE_COUNT,
+        ) {
+            Ok(depth) => depth,
+            Err(e) => {
+                color.destroy(device);
+                return Err(e);
+            }
+        };
+        Ok(Self { color, depth })
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        self.color.destroy(device);
+        self.depth.destroy(device);
+    }
+}
+
+/// An anti-aliasing lane rendering the scene with multiple samples per
+/// pixel.
+///
+/// MSAA only smooths geometric edges, but it is stable under motion and
+/// needs no history, at the cost of shading and storing every sample.
+#[derive(Default)]
+pub struct MsaaLane {
+    targets: Mutex<Option<MsaaTargets>>,
+}
+
+impl MsaaLane {
+    /// Creates a new `MsaaLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for MsaaLane {
+    fn strategy_name(&self) -> &'static str {
+        "Msaa"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<TargetSize>() {
+            Some(size) => (size.0.width * size.0.height) as f32 * SAMPLES_PER_PIXEL * SAMPLE_COST,
+            None => 1.0,
+        }
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let stage = *ctx
+            .get::<PostStage>()
+            .ok_or(LaneError::missing("PostStage"))?;
+        if stage != PostStage::Prepare {
+            return Ok(());
+        }
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+        if size.width == 0 || size.height == 0 || ctx.get::<ColorTarget>().is_none() {
+            return Ok(());
+        }
+        let format = if ctx.contains::<HdrSceneTarget>() {
+            HDR_SCENE_FORMAT
+        } else {
+            device
+                .get_surface_format()
+                .unwrap_or(TextureFormat::Rgba8UnormSrgb)
+        };
+
+        let Ok(mut targets) = self.targets.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        if targets
+            .as_ref()
+            .is_none_or(|t| !t.color.matches(size, format))
+        {
+            if let Some(old) = targets.take() {
+                old.destroy(device.as_ref());
+            }
+            *targets = Some(
+                MsaaTargets::new(device.as_ref(), size, format)
+                    .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?,
+            );
+        }
+        if let Some(targets) = targets.as_ref() {
+            ctx.insert(MultisampleTargets {
+                color: targets.color.view,
+                depth: targets.depth.view,
+            });
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(targets) = self.targets.lock().ok().and_then(|mut t| t.take()) {
+            targets.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
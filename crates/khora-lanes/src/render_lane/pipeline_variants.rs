@@ -0,0 +1,155 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Target-dependent variants of a scene pipeline.
+
+use std::borrow::Cow;
+
+use khora_core::renderer::api::{
+    core::RenderContext,
+    pipeline::{
+        BlendComponentDescriptor, BlendFactor, BlendOperation, BlendStateDescriptor,
+        CompareFunction, RenderPipelineDescriptor, RenderPipelineId,
+    },
+    util::SampleCount,
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::GraphicsDevice;
+
+use super::{HDR_SCENE_FORMAT, MSAA_SAMPLE_COUNT};
+
+/// One scene pipeline, built for every kind of color target a scene lane
+/// may draw into.
+///
+/// Scene lanes draw to the swapchain-format color target or, with
+/// auto-exposure, to the HDR scene target; either may be multisampled for
+/// MSAA. A pipeline must match the format and sample count of its pass
+/// attachments, so all four variants are built up front.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineVariants {
+    ldr: RenderPipelineId,
+    hdr: RenderPipelineId,
+    ldr_msaa: RenderPipelineId,
+    hdr_msaa: RenderPipelineId,
+}
+
+impl PipelineVariants {
+    /// Builds the variants of `desc`, which must describe the single-sample
+    /// pipeline writing to the swapchain format. HDR variants use the
+    /// `hdr_entry_point` fragment shader, which must output linear color.
+    pub fn new(
+        device: &dyn GraphicsDevice,
+        desc: &RenderPipelineDescriptor<'_>,
+        hdr_entry_point: &'static str,
+    ) -> Result<Self, RenderError> {
+        let variant = |hdr: bool, count: SampleCount| {
+            let mut variant = desc.clone();
+            if hdr {
+                variant.fragment_entry_point = Some(Cow::Borrowed(hdr_entry_point));
+                variant.color_target_states = Cow::Owned(
+                    desc.color_target_states
+                        .iter()
+                        .cloned()
+                        .map(|mut state| {
+                            state.format = HDR_SCENE_FORMAT;
+                            state
+                        })
+                        .collect(),
+                );
+            }
+            variant.multisample_state.count = count;
+            variant.label = desc.label.as_ref().map(|label| {
+                let suffix = match (hdr, count == SampleCount::X1) {
+                    (false, true) => "",
+                    (true, true) => " (HDR)",
+                    (false, false) => " (MSAA)",
+                    (true, false) => " (HDR, MSAA)",
+                };
+                Cow::Owned(format!("{label}{suffix}"))
+            });
+            device
+                .create_render_pipeline(&variant)
+                .map_err(RenderError::ResourceError)
+        };
+        Ok(Self {
+            ldr: variant(false, SampleCount::X1)?,
+            hdr: variant(true, SampleCount::X1)?,
+            ldr_msaa: variant(false, MSAA_SAMPLE_COUNT)?,
+            hdr_msaa: variant(true, MSAA_SAMPLE_COUNT)?,
+        })
+    }
+
+    /// Builds the variants of the overdraw view of `desc`. Every target runs
+    /// the `entry_point` fragment shader, blended additively with the depth
+    /// test and depth writes off, so each shaded fragment adds up.
+    pub fn overdraw(
+        device: &dyn GraphicsDevice,
+        desc: &RenderPipelineDescriptor<'_>,
+        entry_point: &'static str,
+    ) -> Result<Self, RenderError> {
+        let additive = BlendComponentDescriptor {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::One,
+            operation: BlendOperation::Add,
+        };
+        let mut overdraw = desc.clone();
+        overdraw.fragment_entry_point = Some(Cow::Borrowed(entry_point));
+        overdraw.color_target_states = Cow::Owned(
+            desc.color_target_states
+                .iter()
+                .cloned()
+                .map(|mut state| {
+                    state.blend = Some(BlendStateDescriptor {
+                        color: additive,
+                        alpha: additive,
+                    });
+                    state
+                })
+                .collect(),
+        );
+        if let Some(depth) = overdraw.depth_stencil_state.as_mut() {
+            depth.depth_write_enabled = false;
+            depth.depth_compare = CompareFunction::Always;
+        }
+        overdraw.label = desc
+            .label
+            .as_ref()
+            .map(|label| Cow::Owned(format!("{label} (Overdraw)")));
+        Self::new(device, &overdraw, entry_point)
+    }
+
+    /// The single-sample pipeline writing to the swapchain format.
+    pub fn ldr(&self) -> RenderPipelineId {
+        self.ldr
+    }
+
+    /// The variant matching the targets of `render_ctx`.
+    pub fn select(&self, render_ctx: &RenderContext) -> RenderPipelineId {
+        match (render_ctx.hdr, render_ctx.multisampled()) {
+            (false, false) => self.ldr,
+            (true, false) => self.hdr,
+            (false, true) => self.ldr_msaa,
+            (true, true) => self.hdr_msaa,
+        }
+    }
+
+    /// Destroys every variant.
+    pub fn destroy(self, device: &dyn GraphicsDevice) {
+        for pipeline in [self.ldr, self.hdr, self.ldr_msaa, self.hdr_msaa] {
+            if let Err(e) = device.destroy_render_pipeline(pipeline) {
+                log::warn!("PipelineVariants: Failed to destroy pipeline: {:?}", e);
+            }
+        }
+    }
+}
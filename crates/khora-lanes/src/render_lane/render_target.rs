@@ -0,0 +1,128 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Frame-sized intermediate targets owned by post-processing lanes, and
+//! the sampler they are read through.
+
+use std::borrow::Cow;
+
+use khora_core::math::{Extent2D, Extent3D};
+use khora_core::renderer::api::{
+    resource::{
+        AddressMode, FilterMode, ImageAspect, MipmapFilterMode, SamplerDescriptor, SamplerId,
+        TextureDescriptor, TextureDimension, TextureId, TextureUsage, TextureViewDescriptor,
+        TextureViewDimension, TextureViewId,
+    },
+    util::{SampleCount, TextureFormat},
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::GraphicsDevice;
+
+/// A 2D texture and its full view, recreated by its owner on resize.
+#[derive(Debug)]
+pub(crate) struct RenderTarget {
+    pub texture: TextureId,
+    pub view: TextureViewId,
+    pub size: Extent2D,
+    pub format: TextureFormat,
+}
+
+impl RenderTarget {
+    /// Creates a single-mip target of `size` and `format`.
+    pub fn new(
+        device: &dyn GraphicsDevice,
+        label: &'static str,
+        size: Extent2D,
+        format: TextureFormat,
+        usage: TextureUsage,
+        samples: SampleCount,
+    ) -> Result<Self, RenderError> {
+        let texture = device
+            .create_texture(&TextureDescriptor {
+                label: Some(Cow::Borrowed(label)),
+                size: Extent3D {
+                    width: size.width,
+                    height: size.height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: samples,
+                dimension: TextureDimension::D2,
+                format,
+                usage,
+                view_formats: Cow::Borrowed(&[]),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let view = device
+            .create_texture_view(
+                texture,
+                &TextureViewDescriptor {
+                    label: Some(Cow::Borrowed(label)),
+                    format: Some(format),
+                    dimension: Some(TextureViewDimension::D2),
+                    aspect: ImageAspect::All,
+                    base_mip_level: 0,
+                    mip_level_count: Some(1),
+                    base_array_layer: 0,
+                    array_layer_count: Some(1),
+                },
+            )
+            .map_err(RenderError::ResourceError)?;
+        Ok(Self {
+            texture,
+            view,
+            size,
+            format,
+        })
+    }
+
+    /// Whether the target can be reused for a frame of `size` and `format`.
+    pub fn matches(&self, size: Extent2D, format: TextureFormat) -> bool {
+        self.size == size && self.format == format
+    }
+
+    /// Destroys the view and the texture.
+    pub fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Err(e) = device.destroy_texture_view(self.view) {
+            log::warn!("RenderTarget: Failed to destroy view: {:?}", e);
+        }
+        if let Err(e) = device.destroy_texture(self.texture) {
+            log::warn!("RenderTarget: Failed to destroy texture: {:?}", e);
+        }
+    }
+}
+
+/// Creates the bilinear, edge-clamped sampler post-processing passes read
+/// their inputs through.
+pub(crate) fn create_linear_sampler(
+    device: &dyn GraphicsDevice,
+    label: &'static str,
+) -> Result<SamplerId, RenderError> {
+    device
+        .create_sampler(&SamplerDescriptor {
+            label: Some(Cow::Borrowed(label)),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            address_mode_w: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            mipmap_filter: MipmapFilterMode::Nearest,
+            lod_min_clamp: 0.0,
+            lod_max_clamp: 1.0,
+            compare: None,
+            anisotropy_clamp: 1,
+            border_color: None,
+        })
+        .map_err(RenderError::ResourceError)
+}
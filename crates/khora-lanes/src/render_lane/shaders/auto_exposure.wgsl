@@ -0,0 +1,112 @@
+// Auto-Exposure Compute Shader
+//
+// Meters the HDR scene target and adapts the camera exposure to it:
+// 1. `cs_histogram` bins every pixel by log2 luminance (16x16 tiles,
+//    workgroup-local bins merged into the global histogram).
+// 2. `cs_average` reduces the histogram to the mean log luminance, derives
+//    the target EV100 and eases the stored exposure toward it. It also
+//    clears the histogram for the next frame.
+
+struct Params {
+    size: vec2<u32>,        // HDR target size in pixels
+    min_log_lum: f32,       // log2 luminance mapped to bin 1
+    log_lum_range: f32,     // log2 luminance span of bins 1..255
+    min_ev: f32,
+    max_ev: f32,
+    speed_up: f32,          // 1/s, toward brighter scenes
+    speed_down: f32,        // 1/s, toward darker scenes
+    compensation: f32,      // In stops
+    delta_time: f32,        // Seconds since the previous frame
+    pre_exposure: f32,      // Scale the lanes already applied to the lights
+    _pad: f32,
+};
+
+struct Exposure {
+    ev: f32,                // Current EV100
+    exposure: f32,          // Scale applied to the HDR color before tone mapping
+    initialized: u32,       // 0 until the first frame is metered
+    _pad: u32,
+};
+
+const BIN_COUNT: u32 = 256u;
+// Pixels darker than this land in bin 0 and are left out of the average.
+const BLACK_LUMINANCE: f32 = 0.0001;
+
+@group(0) @binding(0)
+var<uniform> params: Params;
+
+@group(0) @binding(1)
+var hdr: texture_2d<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> histogram: array<atomic<u32>, 256>;
+
+@group(0) @binding(3)
+var<storage, read_write> exposure: Exposure;
+
+var<workgroup> local_bins: array<atomic<u32>, 256>;
+var<workgroup> weighted: array<f32, 256>;
+
+fn luminance_bin(color: vec3<f32>) -> u32 {
+    let lum = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    if (lum < BLACK_LUMINANCE) {
+        return 0u;
+    }
+    let t = clamp((log2(lum) - params.min_log_lum) / params.log_lum_range, 0.0, 1.0);
+    return u32(t * f32(BIN_COUNT - 2u)) + 1u;
+}
+
+@compute @workgroup_size(16, 16, 1)
+fn cs_histogram(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+) {
+    atomicStore(&local_bins[local_index], 0u);
+    workgroupBarrier();
+
+    if (gid.x < params.size.x && gid.y < params.size.y) {
+        let color = textureLoad(hdr, vec2<i32>(gid.xy), 0).rgb;
+        atomicAdd(&local_bins[luminance_bin(color)], 1u);
+    }
+    workgroupBarrier();
+
+    atomicAdd(&histogram[local_index], atomicLoad(&local_bins[local_index]));
+}
+
+@compute @workgroup_size(256, 1, 1)
+fn cs_average(@builtin(local_invocation_index) local_index: u32) {
+    let count = atomicLoad(&histogram[local_index]);
+    weighted[local_index] = f32(count) * f32(local_index);
+    atomicStore(&histogram[local_index], 0u);
+    workgroupBarrier();
+
+    for (var stride = BIN_COUNT / 2u; stride > 0u; stride = stride / 2u) {
+        if (local_index < stride) {
+            weighted[local_index] += weighted[local_index + stride];
+        }
+        workgroupBarrier();
+    }
+
+    if (local_index == 0u) {
+        // `count` is bin 0 here: the black pixels.
+        let lit_pixels = max(f32(params.size.x * params.size.y) - f32(count), 1.0);
+        let mean_bin = max(weighted[0] / lit_pixels - 1.0, 0.0);
+        let log_lum = mean_bin / f32(BIN_COUNT - 2u) * params.log_lum_range + params.min_log_lum
+            - log2(params.pre_exposure);
+
+        // EV100 of the average luminance (K = 12.5): log2(L * 100 / 12.5).
+        let target_ev = clamp(log_lum + 3.0 - params.compensation, params.min_ev, params.max_ev);
+
+        var ev = target_ev;
+        if (exposure.initialized != 0u) {
+            let speed = select(params.speed_down, params.speed_up, target_ev > exposure.ev);
+            ev = mix(exposure.ev, target_ev, 1.0 - exp(-params.delta_time * speed));
+        }
+
+        exposure.ev = ev;
+        // Saturation-based exposure: 1 / max luminance (1.2 * 2^EV100),
+        // relative to the pre-exposed HDR target.
+        exposure.exposure = 1.0 / (1.2 * exp2(ev) * params.pre_exposure);
+        exposure.initialized = 1u;
+    }
+}
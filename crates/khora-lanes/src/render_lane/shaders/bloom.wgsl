@@ -0,0 +1,81 @@
+// Bloom Shader
+// Builds the bloom mip chain with fullscreen triangles: `fs_prefilter`
+// keeps the scene's highlights while halving it, `fs_downsample` blurs each
+// level into the next with a 13-tap filter, and `fs_upsample` blurs each
+// level back into the one above with a 3x3 tent, blended additively.
+
+struct Params {
+    threshold: f32,
+    knee: f32,
+    _pad: vec2<f32>,
+};
+
+@group(0) @binding(0)
+var source: texture_2d<f32>;
+
+@group(0) @binding(1)
+var linear_sampler: sampler;
+
+@group(0) @binding(2)
+var<uniform> params: Params;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> VertexOutput {
+    let uv = vec2<f32>(f32((idx << 1u) & 2u), f32(idx & 2u));
+    var out: VertexOutput;
+    out.position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+fn tap(uv: vec2<f32>, offset: vec2<f32>) -> vec3<f32> {
+    let texel = 1.0 / vec2<f32>(textureDimensions(source));
+    return textureSampleLevel(source, linear_sampler, uv + offset * texel, 0.0).rgb;
+}
+
+// 13 bilinear taps covering a 6x6 texel footprint, weighted so the
+// inner box counts for half.
+fn downsample(uv: vec2<f32>) -> vec3<f32> {
+    let inner = tap(uv, vec2<f32>(-1.0, -1.0)) + tap(uv, vec2<f32>(1.0, -1.0))
+        + tap(uv, vec2<f32>(-1.0, 1.0)) + tap(uv, vec2<f32>(1.0, 1.0));
+    let corners = tap(uv, vec2<f32>(-2.0, -2.0)) + tap(uv, vec2<f32>(2.0, -2.0))
+        + tap(uv, vec2<f32>(-2.0, 2.0)) + tap(uv, vec2<f32>(2.0, 2.0));
+    let edges = tap(uv, vec2<f32>(0.0, -2.0)) + tap(uv, vec2<f32>(-2.0, 0.0))
+        + tap(uv, vec2<f32>(2.0, 0.0)) + tap(uv, vec2<f32>(0.0, 2.0));
+    let center = tap(uv, vec2<f32>(0.0));
+    return inner * 0.125 + corners * 0.03125 + edges * 0.0625 + center * 0.125;
+}
+
+// Keeps the part of `color` above the threshold, easing in over the knee.
+fn prefilter(color: vec3<f32>) -> vec3<f32> {
+    let brightness = max(color.r, max(color.g, color.b));
+    var soft = clamp(brightness - params.threshold + params.knee, 0.0, 2.0 * params.knee);
+    soft = soft * soft / (4.0 * params.knee + 0.00001);
+    let contribution = max(soft, brightness - params.threshold) / max(brightness, 0.00001);
+    return color * contribution;
+}
+
+@fragment
+fn fs_prefilter(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(prefilter(downsample(in.uv)), 1.0);
+}
+
+@fragment
+fn fs_downsample(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(downsample(in.uv), 1.0);
+}
+
+@fragment
+fn fs_upsample(in: VertexOutput) -> @location(0) vec4<f32> {
+    let edges = tap(in.uv, vec2<f32>(0.0, -1.0)) + tap(in.uv, vec2<f32>(-1.0, 0.0))
+        + tap(in.uv, vec2<f32>(1.0, 0.0)) + tap(in.uv, vec2<f32>(0.0, 1.0));
+    let corners = tap(in.uv, vec2<f32>(-1.0, -1.0)) + tap(in.uv, vec2<f32>(1.0, -1.0))
+        + tap(in.uv, vec2<f32>(-1.0, 1.0)) + tap(in.uv, vec2<f32>(1.0, 1.0));
+    let center = tap(in.uv, vec2<f32>(0.0));
+    return vec4<f32>((center * 4.0 + edges * 2.0 + corners) / 16.0, 1.0);
+}
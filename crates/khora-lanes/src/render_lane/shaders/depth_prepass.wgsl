@@ -0,0 +1,41 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Depth prepass: writes scene depth only, so the shading pass runs its
+// fragment shader once per pixel.
+//
+// The position math must match the scene shaders term for term (model first,
+// then view-projection) so both passes produce bit-identical depths.
+
+struct CameraUniforms {
+    view_projection: mat4x4<f32>,
+    camera_position: vec4<f32>,
+};
+
+struct ModelUniforms {
+    model_matrix: mat4x4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> camera: CameraUniforms;
+@group(1) @binding(0) var<uniform> model: ModelUniforms;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+};
+
+@vertex
+fn vs_main(input: VertexInput) -> @builtin(position) vec4<f32> {
+    let world_pos = model.model_matrix * vec4<f32>(input.position, 1.0);
+    return camera.view_projection * world_pos;
+}
@@ -53,7 +53,7 @@ struct MaterialUniforms {
     emissive: vec3<f32>,
     specular_power: f32,
     ambient: vec3<f32>,
-    _padding: f32,
+    layers: u32,          // render layer mask of the mesh
 };
 
 @group(2) @binding(0)
@@ -61,7 +61,7 @@ var<uniform> material: MaterialUniforms;
 
 // --- Forward+ Light Data ---
 
-// Unified light structure (matches GpuLight in Rust, 64 bytes)
+// Unified light structure (matches GpuLight in Rust, 80 bytes)
 struct GpuLight {
     position: vec3<f32>,
     range: f32,
@@ -71,7 +71,11 @@ struct GpuLight {
     light_type: u32,      // 0 = directional, 1 = point, 2 = spot
     inner_cone_cos: f32,
     outer_cone_cos: f32,
-    _padding: vec2<f32>,
+    shadow_map_index: i32,
+    shadow_bias: f32,
+    shadow_normal_bias: f32,
+    layers: u32,          // render layer mask
+    _padding: vec2<u32>,
 };
 
 // Tile info uniform
@@ -79,6 +83,8 @@ struct TileInfo {
     tile_count: vec2<u32>,
     tile_size: u32,
     max_lights_per_tile: u32,
+    debug_view: u32,      // LightingDebugView shader index, 0 = regular shading
+    _padding: vec3<u32>,
 };
 
 @group(3) @binding(0)
@@ -95,10 +101,11 @@ var<uniform> tile_info: TileInfo;
 
 // --- Lighting Functions ---
 
+// Inverse-square falloff of a candela intensity, windowed to reach zero at range.
 fn calculate_attenuation(distance: f32, range: f32) -> f32 {
     let normalized_distance = distance / range;
-    let attenuation = saturate(1.0 - normalized_distance * normalized_distance);
-    return attenuation * attenuation;
+    let window = saturate(1.0 - normalized_distance * normalized_distance);
+    return window * window / max(distance * distance, 0.0001);
 }
 
 fn calculate_spot_attenuation(
@@ -175,19 +182,22 @@ fn calculate_light_contribution(
     return blinn_phong(N, V, L, light.color, light.intensity * attenuation, diffuse_color, specular_power);
 }
 
-@fragment
-fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+// Index of the culling tile containing the fragment.
+fn fragment_tile(input: VertexOutput) -> u32 {
+    let tile_x = u32(input.clip_position.x) / tile_info.tile_size;
+    let tile_y = u32(input.clip_position.y) / tile_info.tile_size;
+    return tile_y * tile_info.tile_count.x + tile_x;
+}
+
+// Linear, un-tone-mapped lighting of one fragment.
+fn shade(input: VertexOutput) -> vec3<f32> {
     // Prepare surface data
     let N = normalize(input.normal);
     let V = normalize(camera.camera_position.xyz - input.world_position);
     let diffuse_color = material.base_color.rgb;
     
-    // Calculate tile index from clip position
-    let tile_x = u32(input.clip_position.x) / tile_info.tile_size;
-    let tile_y = u32(input.clip_position.y) / tile_info.tile_size;
-    let tile_index = tile_y * tile_info.tile_count.x + tile_x;
-    
     // Read light grid for this tile
+    let tile_index = fragment_tile(input);
     let light_offset = light_grid[tile_index * 2u];
     let light_count = light_grid[tile_index * 2u + 1u];
     
@@ -198,6 +208,9 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     for (var i = 0u; i < light_count; i++) {
         let light_index = light_indices[light_offset + i];
         let light = lights[light_index];
+        if ((light.layers & material.layers) == 0u) {
+            continue;
+        }
         
         final_color += calculate_light_contribution(
             light,
@@ -211,6 +224,66 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Add emissive
     final_color += material.emissive;
     
+    return final_color;
+}
+
+// --- Debug Views ---
+// Indices match LightingDebugView::shader_index. Forward+ samples no
+// shadow cascades, so that view shows plain geometry.
+
+const DEBUG_VIEW_NONE: u32 = 0u;
+const DEBUG_VIEW_LIGHT_HEATMAP: u32 = 1u;
+const DEBUG_VIEW_NORMALS: u32 = 3u;
+const DEBUG_VIEW_ROUGHNESS: u32 = 4u;
+const DEBUG_VIEW_ALBEDO: u32 = 5u;
+
+// Light count shown as full red by the heatmap
+const HEATMAP_MAX_LIGHTS: f32 = 16.0;
+
+// Blue (no light) through cyan, green and yellow to red (HEATMAP_MAX_LIGHTS or more).
+fn heatmap(count: u32) -> vec3<f32> {
+    let t = saturate(f32(count) / HEATMAP_MAX_LIGHTS);
+    return vec3<f32>(
+        saturate(4.0 * t - 2.0),
+        min(saturate(4.0 * t), saturate(4.0 - 4.0 * t)),
+        saturate(2.0 - 4.0 * t),
+    );
+}
+
+// The debug visualization of one fragment, already display-ready.
+fn debug_color(input: VertexOutput) -> vec3<f32> {
+    let N = normalize(input.normal);
+    let V = normalize(camera.camera_position.xyz - input.world_position);
+    // Facing-ratio shading keeps shapes readable under flat debug colors
+    let facing = 0.5 + 0.5 * max(dot(N, V), 0.0);
+    switch (tile_info.debug_view) {
+        case DEBUG_VIEW_LIGHT_HEATMAP: {
+            // The tile's culled light count, before layer filtering
+            return heatmap(light_grid[fragment_tile(input) * 2u + 1u]) * facing;
+        }
+        case DEBUG_VIEW_NORMALS: {
+            return N * 0.5 + 0.5;
+        }
+        case DEBUG_VIEW_ROUGHNESS: {
+            // Blinn-Phong exponent to roughness: alpha = sqrt(2 / (n + 2))
+            return vec3<f32>(sqrt(2.0 / (material.specular_power + 2.0)));
+        }
+        case DEBUG_VIEW_ALBEDO: {
+            return material.base_color.rgb;
+        }
+        default: {
+            return vec3<f32>(facing);
+        }
+    }
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (tile_info.debug_view != DEBUG_VIEW_NONE) {
+        return vec4<f32>(debug_color(input), 1.0);
+    }
+    var final_color = shade(input);
+    
     // Reinhard tone mapping
     final_color = final_color / (final_color + vec3<f32>(1.0));
     
@@ -219,3 +292,19 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     
     return vec4<f32>(final_color, material.base_color.a);
 }
+
+// Linear output for an HDR scene target; a post pass tone-maps it.
+@fragment
+fn fs_hdr(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (tile_info.debug_view != DEBUG_VIEW_NONE) {
+        return vec4<f32>(debug_color(input), 1.0);
+    }
+    return vec4<f32>(shade(input), material.base_color.a);
+}
+
+// Overdraw view: every fragment adds a fixed amount, blended additively
+// with the depth test off, so brightness counts the layers shaded per pixel.
+@fragment
+fn fs_overdraw(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(0.08, 0.04, 0.01, 1.0);
+}
@@ -0,0 +1,123 @@
+// FXAA Shader
+// Fast approximate anti-aliasing: finds luminance edges in the tone-mapped
+// frame, walks along each edge to its ends and resamples across it, drawn
+// as one fullscreen triangle.
+
+@group(0) @binding(0) var frame: texture_2d<f32>;
+@group(0) @binding(1) var linear_sampler: sampler;
+
+// Contrast below which a pixel is not treated as an edge.
+const EDGE_THRESHOLD_MIN: f32 = 0.0312;
+// Same threshold, relative to the brightest neighbor.
+const EDGE_THRESHOLD_MAX: f32 = 0.125;
+// How much sub-pixel aliasing is removed, in [0, 1].
+const SUBPIXEL_QUALITY: f32 = 0.75;
+// Steps taken along an edge in each direction.
+const SEARCH_STEPS: i32 = 8;
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((idx << 1u) & 2u), f32(idx & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+fn luma(color: vec3<f32>) -> f32 {
+    return dot(color, vec3<f32>(0.299, 0.587, 0.114));
+}
+
+fn sample_luma(uv: vec2<f32>) -> f32 {
+    return luma(textureSampleLevel(frame, linear_sampler, uv, 0.0).rgb);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let texel = 1.0 / vec2<f32>(textureDimensions(frame));
+    let uv = position.xy * texel;
+    let center = textureSampleLevel(frame, linear_sampler, uv, 0.0);
+
+    let l_c = luma(center.rgb);
+    let l_n = sample_luma(uv + vec2<f32>(0.0, -texel.y));
+    let l_s = sample_luma(uv + vec2<f32>(0.0, texel.y));
+    let l_e = sample_luma(uv + vec2<f32>(texel.x, 0.0));
+    let l_w = sample_luma(uv + vec2<f32>(-texel.x, 0.0));
+    let l_min = min(l_c, min(min(l_n, l_s), min(l_e, l_w)));
+    let l_max = max(l_c, max(max(l_n, l_s), max(l_e, l_w)));
+    let range = l_max - l_min;
+    if (range < max(EDGE_THRESHOLD_MIN, l_max * EDGE_THRESHOLD_MAX)) {
+        return center;
+    }
+
+    let l_ne = sample_luma(uv + vec2<f32>(texel.x, -texel.y));
+    let l_nw = sample_luma(uv - texel);
+    let l_se = sample_luma(uv + texel);
+    let l_sw = sample_luma(uv + vec2<f32>(-texel.x, texel.y));
+
+    // A horizontal edge has its luminance gradient along y.
+    let horizontal = abs(l_n + l_s - 2.0 * l_c) * 2.0
+        + abs(l_ne + l_se - 2.0 * l_e)
+        + abs(l_nw + l_sw - 2.0 * l_w);
+    let vertical = abs(l_e + l_w - 2.0 * l_c) * 2.0
+        + abs(l_ne + l_nw - 2.0 * l_n)
+        + abs(l_se + l_sw - 2.0 * l_s);
+    let is_horizontal = horizontal >= vertical;
+
+    // Step across the edge toward its steeper side.
+    let l_pos = select(l_e, l_s, is_horizontal);
+    let l_neg = select(l_w, l_n, is_horizontal);
+    let pos_steeper = abs(l_pos - l_c) >= abs(l_neg - l_c);
+    let gradient = 0.25 * max(abs(l_pos - l_c), abs(l_neg - l_c));
+    let edge_luma = 0.5 * (l_c + select(l_neg, l_pos, pos_steeper));
+    var step_len = select(texel.x, texel.y, is_horizontal);
+    if (!pos_steeper) {
+        step_len = -step_len;
+    }
+
+    // Walk along the edge in both directions until its luminance changes.
+    var edge_uv = uv;
+    if (is_horizontal) {
+        edge_uv.y += 0.5 * step_len;
+    } else {
+        edge_uv.x += 0.5 * step_len;
+    }
+    let along = select(vec2<f32>(0.0, texel.y), vec2<f32>(texel.x, 0.0), is_horizontal);
+    var uv_p = edge_uv + along;
+    var uv_n = edge_uv - along;
+    var delta_p = sample_luma(uv_p) - edge_luma;
+    var delta_n = sample_luma(uv_n) - edge_luma;
+    var done_p = abs(delta_p) >= gradient;
+    var done_n = abs(delta_n) >= gradient;
+    for (var i = 1; i < SEARCH_STEPS && !(done_p && done_n); i++) {
+        if (!done_p) {
+            uv_p += along;
+            delta_p = sample_luma(uv_p) - edge_luma;
+            done_p = abs(delta_p) >= gradient;
+        }
+        if (!done_n) {
+            uv_n -= along;
+            delta_n = sample_luma(uv_n) - edge_luma;
+            done_n = abs(delta_n) >= gradient;
+        }
+    }
+
+    // Blend toward the nearer end, if that end agrees with the center.
+    let dist_p = select(uv_p.y - uv.y, uv_p.x - uv.x, is_horizontal);
+    let dist_n = select(uv.y - uv_n.y, uv.x - uv_n.x, is_horizontal);
+    let end_delta = select(delta_n, delta_p, dist_p < dist_n);
+    var edge_offset = 0.0;
+    if ((end_delta < 0.0) != (l_c < edge_luma)) {
+        edge_offset = 0.5 - min(dist_p, dist_n) / (dist_p + dist_n);
+    }
+
+    // Sub-pixel aliasing: a lone bright or dark pixel.
+    let average = (2.0 * (l_n + l_s + l_e + l_w) + l_ne + l_nw + l_se + l_sw) / 12.0;
+    let subpixel = smoothstep(0.0, 1.0, clamp(abs(average - l_c) / range, 0.0, 1.0));
+    let offset = max(edge_offset, subpixel * subpixel * SUBPIXEL_QUALITY);
+
+    var final_uv = uv;
+    if (is_horizontal) {
+        final_uv.y += offset * step_len;
+    } else {
+        final_uv.x += offset * step_len;
+    }
+    return vec4<f32>(textureSampleLevel(frame, linear_sampler, final_uv, 0.0).rgb, center.a);
+}
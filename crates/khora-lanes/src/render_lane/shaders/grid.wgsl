@@ -1,11 +1,13 @@
 // Khora Engine — Infinite editor grid shader.
 //
 // Renders an infinite XZ plane grid using a fullscreen triangle.
-// The fragment shader reconstructs world position from depth=0 on
-// the near plane and the camera inverse VP matrix, then draws
-// antialiased grid lines with fade-out by distance.
+// The fragment shader reconstructs world position from the near plane
+// and the camera inverse VP matrix, then draws antialiased grid lines
+// with fade-out by distance.
 //
 // Bind group 0: Camera uniforms (view_projection, camera_position).
+// `camera_position.w` holds the NDC depth of the near plane: 0 for the
+// standard depth range, 1 for reversed-Z.
 
 struct CameraUniforms {
     view_projection: mat4x4<f32>,
@@ -46,8 +48,10 @@ fn vs_main(@builtin(vertex_index) idx: u32) -> VertexOutput {
 
     var out: VertexOutput;
     out.clip_position = vec4(p.xy, 0.0, 1.0);
-    out.near_point = unproject(vec3(p.xy, 0.0), inv_vp); // near plane
-    out.far_point  = unproject(vec3(p.xy, 1.0), inv_vp); // far plane
+    // The second point sits mid-range rather than on the far plane, which
+    // an infinite projection places at infinity.
+    out.near_point = unproject(vec3(p.xy, camera.camera_position.w), inv_vp); // near plane
+    out.far_point  = unproject(vec3(p.xy, 0.5), inv_vp); // along the view ray
     return out;
 }
 
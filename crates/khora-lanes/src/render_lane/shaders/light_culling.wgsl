@@ -33,7 +33,11 @@ struct GpuLight {
     light_type: u32,      // 0 = directional, 1 = point, 2 = spot
     inner_cone_cos: f32,
     outer_cone_cos: f32,
-    _padding: vec2<f32>,
+    shadow_map_index: i32,
+    shadow_bias: f32,
+    shadow_normal_bias: f32,
+    layers: u32,          // render layer mask
+    _padding: vec2<u32>,
 };
 
 @group(0) @binding(1)
@@ -155,11 +159,13 @@ fn cs_main(
             f32((tile_y + 1u) * TILE_SIZE) / uniforms.screen_dimensions.y * 2.0 - 1.0
         );
         
-        // Convert corners to view space (at near plane z = 1.0 in NDC)
-        let tl = ndc_to_view(vec3<f32>(tile_min_ndc.x, tile_max_ndc.y, 1.0));
-        let tr = ndc_to_view(vec3<f32>(tile_max_ndc.x, tile_max_ndc.y, 1.0));
-        let bl = ndc_to_view(vec3<f32>(tile_min_ndc.x, tile_min_ndc.y, 1.0));
-        let br = ndc_to_view(vec3<f32>(tile_max_ndc.x, tile_min_ndc.y, 1.0));
+        // Convert corners to view space at mid-range NDC depth: only the ray
+        // direction matters, and z = 0.5 stays finite for both depth
+        // conventions and for infinite far planes.
+        let tl = ndc_to_view(vec3<f32>(tile_min_ndc.x, tile_max_ndc.y, 0.5));
+        let tr = ndc_to_view(vec3<f32>(tile_max_ndc.x, tile_max_ndc.y, 0.5));
+        let bl = ndc_to_view(vec3<f32>(tile_min_ndc.x, tile_min_ndc.y, 0.5));
+        let br = ndc_to_view(vec3<f32>(tile_max_ndc.x, tile_min_ndc.y, 0.5));
         let origin = vec3<f32>(0.0, 0.0, 0.0);
         
         // Create frustum planes (normals pointing inward)
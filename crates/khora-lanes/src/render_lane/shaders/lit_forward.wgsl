@@ -57,6 +57,85 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     return out;
 }
 
+// --- Vertex-Shader Skinning ---
+// Used by the skinned pipeline variant: vertices are fetched by index from
+// the bind-pose storage buffer and posed before the usual model transform.
+// Layout and math match skinning.wgsl.
+
+struct SkinLayout {
+    vertex_count: u32,
+    stride: u32,         // In f32 words
+    normal_offset: u32,  // In f32 words, SKIN_ABSENT = no normal
+    uv_offset: u32,      // In f32 words, SKIN_ABSENT = no uv
+};
+
+struct SkinInfluence {
+    joints: vec4<u32>,
+    weights: vec4<f32>,
+};
+
+const SKIN_ABSENT: u32 = 0xffffffffu;
+
+@group(1) @binding(1)
+var<storage, read> skin_bind_pose: array<f32>;
+
+@group(1) @binding(2)
+var<storage, read> skin_influences: array<SkinInfluence>;
+
+@group(1) @binding(3)
+var<storage, read> skin_joints: array<mat4x4<f32>>;
+
+@group(1) @binding(4)
+var<uniform> skin_layout: SkinLayout;
+
+fn skin_matrix(influence: SkinInfluence) -> mat4x4<f32> {
+    let w = influence.weights;
+    if (w.x + w.y + w.z + w.w == 0.0) {
+        return mat4x4<f32>(
+            vec4<f32>(1.0, 0.0, 0.0, 0.0),
+            vec4<f32>(0.0, 1.0, 0.0, 0.0),
+            vec4<f32>(0.0, 0.0, 1.0, 0.0),
+            vec4<f32>(0.0, 0.0, 0.0, 1.0),
+        );
+    }
+    let j = influence.joints;
+    return skin_joints[j.x] * w.x + skin_joints[j.y] * w.y + skin_joints[j.z] * w.z
+        + skin_joints[j.w] * w.w;
+}
+
+fn skin_vec3(index: u32) -> vec3<f32> {
+    return vec3<f32>(skin_bind_pose[index], skin_bind_pose[index + 1u], skin_bind_pose[index + 2u]);
+}
+
+@vertex
+fn vs_skinned(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let base = vertex_index * skin_layout.stride;
+
+    var normal = vec3<f32>(0.0, 1.0, 0.0);
+    if (skin_layout.normal_offset != SKIN_ABSENT) {
+        normal = skin_vec3(base + skin_layout.normal_offset);
+    }
+    var uv = vec2<f32>(0.0, 0.0);
+    if (skin_layout.uv_offset != SKIN_ABSENT) {
+        let u = base + skin_layout.uv_offset;
+        uv = vec2<f32>(skin_bind_pose[u], skin_bind_pose[u + 1u]);
+    }
+
+    // Pose in mesh space, then continue exactly like vs_main
+    let m = skin_matrix(skin_influences[vertex_index]);
+    let position = m * vec4<f32>(skin_vec3(base), 1.0);
+    let posed_normal = (m * vec4<f32>(normal, 0.0)).xyz;
+
+    let world_pos = model.model_matrix * position;
+    out.world_position = world_pos.xyz;
+    out.clip_position = camera.view_projection * world_pos;
+    out.normal = normalize((model.normal_matrix * vec4<f32>(posed_normal, 0.0)).xyz);
+    out.uv = uv;
+
+    return out;
+}
+
 
 // --- Fragment Shader ---
 
@@ -66,7 +145,7 @@ struct MaterialUniforms {
     emissive: vec3<f32>,        // RGB emissive color
     specular_power: f32,        // Specular exponent (shininess)
     ambient: vec3<f32>,         // Ambient color
-    _padding: f32,              // Alignment padding
+    layers: u32,                // Render layer mask of the mesh
 };
 
 @group(2) @binding(0)
@@ -75,16 +154,19 @@ var<uniform> material: MaterialUniforms;
 // --- Light Structures (must match Rust repr(C) layout) ---
 
 struct DirectionalLight {
-    direction: vec4<f32>,            // xyz = direction, w = padding
+    direction: vec4<f32>,            // xyz = direction, w = cascade count
     color: vec4<f32>,                // rgb = color, a = intensity
-    shadow_view_proj: mat4x4<f32>,   // Light's view-projection for shadow mapping
-    shadow_params: vec4<f32>,        // x = atlas_index (-1 = no shadow), y = bias, z = normal_bias, w = padding
+    cascade_view_proj: array<mat4x4<f32>, 4>, // Light's view-projection per cascade, nearest first
+    cascade_texel_sizes: vec4<f32>,  // World-space shadow texel size per cascade
+    shadow_params: vec3<f32>,        // x = atlas_index of cascade 0 (-1 = no shadow), y = bias, z = normal_bias
+    layers: u32,                     // Render layer mask
 };
 
 struct PointLight {
     position: vec4<f32>,             // xyz = position, w = range
     color: vec4<f32>,                // rgb = color, a = intensity
-    shadow_params: vec4<f32>,        // x = atlas_index (-1 = no shadow), y = bias, z = normal_bias, w = padding
+    shadow_params: vec3<f32>,        // x = atlas_index (-1 = no shadow), y = bias, z = normal_bias
+    layers: u32,                     // Render layer mask
 };
 
 struct SpotLight {
@@ -93,13 +175,15 @@ struct SpotLight {
     color: vec4<f32>,                // rgb = color, a = intensity
     params: vec4<f32>,               // x = outer_cone_cos, yzw = padding
     shadow_view_proj: mat4x4<f32>,   // Light's view-projection for shadow mapping
-    shadow_params: vec4<f32>,        // x = atlas_index (-1 = no shadow), y = bias, z = normal_bias, w = padding
+    shadow_params: vec3<f32>,        // x = atlas_index (-1 = no shadow), y = bias, z = normal_bias
+    layers: u32,                     // Render layer mask
 };
 
 // Light arrays with fixed sizes matching LitForwardLane defaults
 const MAX_DIRECTIONAL_LIGHTS: u32 = 4u;
 const MAX_POINT_LIGHTS: u32 = 16u;
 const MAX_SPOT_LIGHTS: u32 = 8u;
+const MAX_SHADOW_CASCADES: u32 = 4u;
 
 struct LightingUniforms {
     directional_lights: array<DirectionalLight, 4>,
@@ -108,7 +192,7 @@ struct LightingUniforms {
     num_directional_lights: u32,
     num_point_lights: u32,
     num_spot_lights: u32,
-    _padding: u32,
+    debug_view: u32,      // LightingDebugView shader index, 0 = regular shading
 };
 
 @group(3) @binding(0)
@@ -176,14 +260,65 @@ fn sample_shadow_pcf(
     return shadow / 9.0;
 }
 
+/// Returns the nearest cascade of directional light `light_index` whose map
+/// covers `world_pos`, or the cascade count when none does.
+fn directional_cascade(light_index: u32, world_pos: vec3<f32>) -> u32 {
+    let cascade_count = min(u32(lights.directional_lights[light_index].direction.w), MAX_SHADOW_CASCADES);
+    for (var c = 0u; c < cascade_count; c++) {
+        let shadow_vp = lights.directional_lights[light_index].cascade_view_proj[c];
+        let light_clip = shadow_vp * vec4<f32>(world_pos, 1.0);
+        let light_ndc = light_clip.xyz / light_clip.w;
+        // Keep the PCF kernel inside the cascade; beyond it, try the next one.
+        if (abs(light_ndc.x) <= 0.99 && abs(light_ndc.y) <= 0.99) {
+            return c;
+        }
+    }
+    return cascade_count;
+}
+
+/// Samples the shadow of directional light `light_index`, picking the
+/// nearest cascade whose map covers the fragment.
+/// Returns a shadow factor: 1.0 = fully lit, 0.0 = fully in shadow.
+fn sample_directional_shadow(
+    light_index: u32,
+    world_pos: vec3<f32>,
+    N: vec3<f32>,
+) -> f32 {
+    let first_index = i32(lights.directional_lights[light_index].shadow_params.x);
+    if (first_index < 0) {
+        return 1.0;
+    }
+    let cascade_count = min(u32(lights.directional_lights[light_index].direction.w), MAX_SHADOW_CASCADES);
+    let c = directional_cascade(light_index, world_pos);
+    if (c >= cascade_count) {
+        return 1.0; // Beyond the last cascade
+    }
+    let bias = lights.directional_lights[light_index].shadow_params.y;
+    let normal_bias = lights.directional_lights[light_index].shadow_params.z;
+    let texel_sizes = lights.directional_lights[light_index].cascade_texel_sizes;
+
+    // Far cascades have larger texels and need a proportionally larger
+    // normal offset to stay free of acne.
+    let texel_scale = texel_sizes[c] / max(texel_sizes[0], 1e-6);
+    return sample_shadow_pcf(
+        lights.directional_lights[light_index].cascade_view_proj[c],
+        world_pos,
+        N,
+        first_index + i32(c),
+        bias,
+        normal_bias * texel_scale,
+    );
+}
+
 // --- Lighting Functions ---
 
-/// Calculates attenuation for point/spot lights based on distance and range
+/// Calculates attenuation for point/spot lights based on distance and range.
+/// Intensities are in candela, so this turns them into lux.
 fn calculate_attenuation(distance: f32, range: f32) -> f32 {
-    // Smooth attenuation that reaches zero at range
+    // Inverse-square falloff, windowed to reach zero at range
     let normalized_distance = distance / range;
-    let attenuation = saturate(1.0 - normalized_distance * normalized_distance);
-    return attenuation * attenuation;
+    let window = saturate(1.0 - normalized_distance * normalized_distance);
+    return window * window / max(distance * distance, 0.0001);
 }
 
 /// Calculates spotlight cone attenuation
@@ -236,17 +371,13 @@ fn calculate_directional_lights(
     
     for (var i = 0u; i < lights.num_directional_lights && i < MAX_DIRECTIONAL_LIGHTS; i++) {
         let light = lights.directional_lights[i];
+        if ((light.layers & material.layers) == 0u) {
+            continue;
+        }
         let L = -normalize(light.direction.xyz);  // Reverse direction (toward light)
         
         // Shadow factor
-        let shadow = sample_shadow_pcf(
-            light.shadow_view_proj,
-            world_position,
-            N,
-            i32(light.shadow_params.x),
-            light.shadow_params.y,
-            light.shadow_params.z,
-        );
+        let shadow = sample_directional_shadow(i, world_position, N);
         
         result += blinn_phong(
             N, V, L,
@@ -272,6 +403,9 @@ fn calculate_point_lights(
     
     for (var i = 0u; i < lights.num_point_lights && i < MAX_POINT_LIGHTS; i++) {
         let light = lights.point_lights[i];
+        if ((light.layers & material.layers) == 0u) {
+            continue;
+        }
         let light_vec = light.position.xyz - world_position;
         let distance = length(light_vec);
         
@@ -307,6 +441,9 @@ fn calculate_spot_lights(
     
     for (var i = 0u; i < lights.num_spot_lights && i < MAX_SPOT_LIGHTS; i++) {
         let light = lights.spot_lights[i];
+        if ((light.layers & material.layers) == 0u) {
+            continue;
+        }
         let light_vec = light.position.xyz - world_position;
         let distance = length(light_vec);
         
@@ -352,8 +489,8 @@ fn calculate_spot_lights(
     return result;
 }
 
-@fragment
-fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+// Linear, un-tone-mapped lighting of one fragment.
+fn shade(input: VertexOutput) -> vec3<f32> {
     // Prepare surface data
     let N = normalize(input.normal);
     let V = normalize(camera.camera_position.xyz - input.world_position);
@@ -372,6 +509,121 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Add emissive
     final_color += material.emissive;
     
+    return final_color;
+}
+
+// --- Debug Views ---
+// Indices match LightingDebugView::shader_index.
+
+const DEBUG_VIEW_NONE: u32 = 0u;
+const DEBUG_VIEW_LIGHT_HEATMAP: u32 = 1u;
+const DEBUG_VIEW_NORMALS: u32 = 3u;
+const DEBUG_VIEW_ROUGHNESS: u32 = 4u;
+const DEBUG_VIEW_ALBEDO: u32 = 5u;
+const DEBUG_VIEW_SHADOW_CASCADES: u32 = 6u;
+
+// Light count shown as full red by the heatmap
+const HEATMAP_MAX_LIGHTS: f32 = 16.0;
+
+/// Blue (no light) through cyan, green and yellow to red (HEATMAP_MAX_LIGHTS or more).
+fn heatmap(count: u32) -> vec3<f32> {
+    let t = saturate(f32(count) / HEATMAP_MAX_LIGHTS);
+    return vec3<f32>(
+        saturate(4.0 * t - 2.0),
+        min(saturate(4.0 * t), saturate(4.0 - 4.0 * t)),
+        saturate(2.0 - 4.0 * t),
+    );
+}
+
+/// Number of lights whose range, cone and layers reach the fragment.
+fn count_lights(world_position: vec3<f32>) -> u32 {
+    var count = 0u;
+    for (var i = 0u; i < lights.num_directional_lights && i < MAX_DIRECTIONAL_LIGHTS; i++) {
+        if ((lights.directional_lights[i].layers & material.layers) != 0u) {
+            count += 1u;
+        }
+    }
+    for (var i = 0u; i < lights.num_point_lights && i < MAX_POINT_LIGHTS; i++) {
+        let light = lights.point_lights[i];
+        if ((light.layers & material.layers) != 0u
+            && distance(light.position.xyz, world_position) <= light.position.w) {
+            count += 1u;
+        }
+    }
+    for (var i = 0u; i < lights.num_spot_lights && i < MAX_SPOT_LIGHTS; i++) {
+        let light = lights.spot_lights[i];
+        let light_vec = light.position.xyz - world_position;
+        if ((light.layers & material.layers) == 0u || length(light_vec) > light.position.w) {
+            continue;
+        }
+        let cone = calculate_spot_attenuation(
+            normalize(light_vec),
+            normalize(light.direction.xyz),
+            light.direction.w,
+            light.params.x
+        );
+        if (cone > 0.0) {
+            count += 1u;
+        }
+    }
+    return count;
+}
+
+/// Color of the cascade the first shadowed directional light samples, or
+/// gray outside every cascade.
+fn cascade_color(world_position: vec3<f32>) -> vec3<f32> {
+    for (var i = 0u; i < lights.num_directional_lights && i < MAX_DIRECTIONAL_LIGHTS; i++) {
+        let light = lights.directional_lights[i];
+        if ((light.layers & material.layers) == 0u || light.shadow_params.x < 0.0) {
+            continue;
+        }
+        switch (directional_cascade(i, world_position)) {
+            case 0u: { return vec3<f32>(1.0, 0.2, 0.2); }
+            case 1u: { return vec3<f32>(0.2, 1.0, 0.2); }
+            case 2u: { return vec3<f32>(0.2, 0.4, 1.0); }
+            case 3u: { return vec3<f32>(1.0, 1.0, 0.2); }
+            default: { return vec3<f32>(0.5); }
+        }
+    }
+    return vec3<f32>(0.5);
+}
+
+/// The debug visualization of one fragment, already display-ready.
+fn debug_color(input: VertexOutput) -> vec3<f32> {
+    let N = normalize(input.normal);
+    let V = normalize(camera.camera_position.xyz - input.world_position);
+    // Facing-ratio shading keeps shapes readable under flat debug colors
+    let facing = 0.5 + 0.5 * max(dot(N, V), 0.0);
+    switch (lights.debug_view) {
+        case DEBUG_VIEW_LIGHT_HEATMAP: {
+            return heatmap(count_lights(input.world_position)) * facing;
+        }
+        case DEBUG_VIEW_NORMALS: {
+            return N * 0.5 + 0.5;
+        }
+        case DEBUG_VIEW_ROUGHNESS: {
+            // Blinn-Phong exponent to roughness: alpha = sqrt(2 / (n + 2))
+            return vec3<f32>(sqrt(2.0 / (material.specular_power + 2.0)));
+        }
+        case DEBUG_VIEW_ALBEDO: {
+            return material.base_color.rgb;
+        }
+        case DEBUG_VIEW_SHADOW_CASCADES: {
+            return cascade_color(input.world_position) * facing;
+        }
+        default: {
+            return vec3<f32>(facing);
+        }
+    }
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (lights.debug_view != DEBUG_VIEW_NONE) {
+        return vec4<f32>(debug_color(input), 1.0);
+    }
+    var final_color = shade(input);
+    
     // Simple tone mapping (Reinhard)
     final_color = final_color / (final_color + vec3<f32>(1.0));
     
@@ -380,3 +632,19 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     
     return vec4<f32>(final_color, material.base_color.a);
 }
+
+// Linear output for an HDR scene target; a post pass tone-maps it.
+@fragment
+fn fs_hdr(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (lights.debug_view != DEBUG_VIEW_NONE) {
+        return vec4<f32>(debug_color(input), 1.0);
+    }
+    return vec4<f32>(shade(input), material.base_color.a);
+}
+
+// Overdraw view: every fragment adds a fixed amount, blended additively
+// with the depth test off, so brightness counts the layers shaded per pixel.
+@fragment
+fn fs_overdraw(input: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(0.08, 0.04, 0.01, 1.0);
+}
@@ -47,7 +47,9 @@
 /// - Up to 16 point lights
 /// - Up to 8 spot lights
 ///
-/// Uses Blinn-Phong BRDF with Reinhard tone mapping.
+/// Uses Blinn-Phong BRDF with Reinhard tone mapping. The `fs_hdr` entry
+/// point writes linear color instead, for an HDR scene target. Directional
+/// lights sample up to 4 shadow cascades from the shadow atlas.
 pub const LIT_FORWARD_WGSL: &str = include_str!("lit_forward.wgsl");
 
 /// Standard PBR (Physically-Based Rendering) shader.
@@ -56,6 +58,9 @@ pub const LIT_FORWARD_WGSL: &str = include_str!("lit_forward.wgsl");
 /// - GGX/Trowbridge-Reitz normal distribution
 /// - Schlick-GGX geometry function
 /// - Fresnel-Schlick approximation
+///
+/// Its directional light (group 3) uses the `DirectionalLightUniform`
+/// layout and samples cascaded shadows from the atlas bound beside it.
 pub const STANDARD_PBR_WGSL: &str = include_str!("standard_pbr.wgsl");
 
 /// Simple unlit shader for vertex-colored objects.
@@ -96,9 +101,64 @@ pub const LIGHT_CULLING_WGSL: &str = include_str!("light_culling.wgsl");
 /// O(lights_per_tile) complexity instead of O(all_lights).
 pub const FORWARD_PLUS_WGSL: &str = include_str!("forward_plus.wgsl");
 
+/// Skinning compute shader for the skinned-mesh pre-pass.
+///
+/// Poses one mesh's bind-pose vertices with linear blend skinning and
+/// writes them to its output vertex buffer, one invocation per vertex.
+pub const SKINNING_WGSL: &str = include_str!("skinning.wgsl");
+
 /// Minimal depth-only shader for shadow map generation.
 pub const SHADOW_PASS_WGSL: &str = include_str!("shadow_pass.wgsl");
 
+/// Entity ID shader for pixel-exact picking.
+///
+/// Writes each mesh's packed entity ID into an `Rg32Uint` target.
+pub const ID_PASS_WGSL: &str = include_str!("id_pass.wgsl");
+
+/// Depth-only shader for the scene's depth prepass.
+///
+/// Transforms positions exactly like the scene shaders, so the shading pass
+/// can test against the prepass depth with an or-equal compare.
+pub const DEPTH_PREPASS_WGSL: &str = include_str!("depth_prepass.wgsl");
+
+/// Auto-exposure compute shader.
+///
+/// `cs_histogram` bins the HDR scene target by log2 luminance;
+/// `cs_average` derives the target EV100 from the histogram and adapts the
+/// stored exposure toward it.
+pub const AUTO_EXPOSURE_WGSL: &str = include_str!("auto_exposure.wgsl");
+
+/// Fullscreen tone mapping shader.
+///
+/// Adds bloom to the HDR scene target and scales it by the exposure, then
+/// applies the selected tone mapping operator, the vignette and gamma
+/// correction.
+pub const TONEMAP_WGSL: &str = include_str!("tonemap.wgsl");
+
+/// Bloom mip chain shader.
+///
+/// `fs_prefilter` thresholds the HDR scene into the chain's first level,
+/// `fs_downsample` blurs each level into the next and `fs_upsample` blurs
+/// them back up, to be blended additively.
+pub const BLOOM_WGSL: &str = include_str!("bloom.wgsl");
+
+/// Motion vector shader.
+///
+/// Projects each vertex with the current and previous frame's matrices and
+/// writes its screen-space motion, in UV units, to an `Rg16Float` target.
+pub const MOTION_VECTORS_WGSL: &str = include_str!("motion_vectors.wgsl");
+
+/// Temporal anti-aliasing resolve shader.
+///
+/// Blends the current frame with the reprojected, neighborhood-clamped
+/// history and writes both the sharpened output and the next history.
+pub const TAA_WGSL: &str = include_str!("taa.wgsl");
+
+/// Fast approximate anti-aliasing shader.
+///
+/// Smooths luminance edges of the tone-mapped frame in one fullscreen pass.
+pub const FXAA_WGSL: &str = include_str!("fxaa.wgsl");
+
 /// Shader for UI elements (quads, text, icons).
 pub const UI_WGSL: &str = include_str!("ui.wgsl");
 
@@ -154,6 +214,13 @@ mod tests {
         assert!(LIGHT_CULLING_WGSL.contains("@workgroup_size"));
     }
 
+    #[test]
+    fn test_skinning_shader_valid() {
+        assert!(SKINNING_WGSL.contains("@compute"));
+        assert!(SKINNING_WGSL.contains("cs_main"));
+        assert!(LIT_FORWARD_WGSL.contains("fn vs_skinned"));
+    }
+
     #[test]
     fn test_forward_plus_shader_valid() {
         assert!(FORWARD_PLUS_WGSL.contains("@vertex"));
@@ -167,6 +234,60 @@ mod tests {
         assert!(SHADOW_PASS_WGSL.contains("view_projection"));
     }
 
+    #[test]
+    fn test_depth_prepass_shader_valid() {
+        assert!(DEPTH_PREPASS_WGSL.contains("@vertex"));
+        assert!(!DEPTH_PREPASS_WGSL.contains("@fragment"));
+        assert!(DEPTH_PREPASS_WGSL.contains("model.model_matrix * vec4<f32>(input.position, 1.0)"));
+    }
+
+    #[test]
+    fn test_id_pass_shader_valid() {
+        assert!(ID_PASS_WGSL.contains("@vertex"));
+        assert!(ID_PASS_WGSL.contains("@fragment"));
+        assert!(ID_PASS_WGSL.contains("entity_id"));
+    }
+
+    #[test]
+    fn test_auto_exposure_shader_valid() {
+        assert!(AUTO_EXPOSURE_WGSL.contains("@compute"));
+        assert!(AUTO_EXPOSURE_WGSL.contains("fn cs_histogram"));
+        assert!(AUTO_EXPOSURE_WGSL.contains("fn cs_average"));
+    }
+
+    #[test]
+    fn test_tonemap_shader_valid() {
+        assert!(TONEMAP_WGSL.contains("@vertex"));
+        assert!(TONEMAP_WGSL.contains("@fragment"));
+        assert!(LIT_FORWARD_WGSL.contains("fn fs_hdr"));
+        assert!(FORWARD_PLUS_WGSL.contains("fn fs_hdr"));
+        assert!(TONEMAP_WGSL.contains("fn aces"));
+    }
+
+    #[test]
+    fn test_bloom_shader_valid() {
+        assert!(BLOOM_WGSL.contains("@vertex"));
+        assert!(BLOOM_WGSL.contains("fn fs_prefilter"));
+        assert!(BLOOM_WGSL.contains("fn fs_downsample"));
+        assert!(BLOOM_WGSL.contains("fn fs_upsample"));
+    }
+
+    #[test]
+    fn test_motion_vectors_shader_valid() {
+        assert!(MOTION_VECTORS_WGSL.contains("@vertex"));
+        assert!(MOTION_VECTORS_WGSL.contains("@fragment"));
+        assert!(MOTION_VECTORS_WGSL.contains("prev_view_projection"));
+        assert!(MOTION_VECTORS_WGSL.contains("prev_model_matrix"));
+    }
+
+    #[test]
+    fn test_anti_aliasing_shaders_valid() {
+        assert!(TAA_WGSL.contains("@fragment"));
+        assert!(TAA_WGSL.contains("@location(1) history"));
+        assert!(FXAA_WGSL.contains("@vertex"));
+        assert!(FXAA_WGSL.contains("@fragment"));
+    }
+
     #[test]
     fn test_ui_shader_valid() {
         assert!(UI_WGSL.contains("@vertex"));
@@ -0,0 +1,44 @@
+// Motion Vector Shader
+// Projects every vertex with both the current and the previous frame's
+// matrices and writes the screen-space motion between them, in UV units.
+
+struct CameraUniforms {
+    view_projection: mat4x4<f32>,
+    prev_view_projection: mat4x4<f32>,
+};
+
+struct ModelUniforms {
+    model_matrix: mat4x4<f32>,
+    prev_model_matrix: mat4x4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> camera: CameraUniforms;
+@group(1) @binding(0) var<uniform> model: ModelUniforms;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) current: vec4<f32>,
+    @location(1) previous: vec4<f32>,
+};
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    let local = vec4<f32>(input.position, 1.0);
+    var out: VertexOutput;
+    out.current = camera.view_projection * model.model_matrix * local;
+    out.previous = camera.prev_view_projection * model.prev_model_matrix * local;
+    out.clip_position = out.current;
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec2<f32> {
+    let current = input.current.xy / input.current.w;
+    let previous = input.previous.xy / input.previous.w;
+    // NDC y points up while UV v points down.
+    return (current - previous) * vec2<f32>(0.5, -0.5);
+}
@@ -0,0 +1,81 @@
+// Skinning Compute Pre-Pass
+// Poses the bind-pose vertices of one skinned mesh into its output vertex
+// buffer (linear blend skinning), so every later pass draws the mesh like
+// a static one.
+
+struct SkinLayout {
+    vertex_count: u32,
+    stride: u32,         // In f32 words
+    normal_offset: u32,  // In f32 words, ABSENT = no normal
+    uv_offset: u32,      // In f32 words, ABSENT = no uv (unused here)
+};
+
+struct SkinInfluence {
+    joints: vec4<u32>,
+    weights: vec4<f32>,
+};
+
+const ABSENT: u32 = 0xffffffffu;
+
+@group(0) @binding(0)
+var<uniform> skin_layout: SkinLayout;
+
+@group(0) @binding(1)
+var<storage, read> bind_pose: array<f32>;
+
+@group(0) @binding(2)
+var<storage, read> influences: array<SkinInfluence>;
+
+@group(0) @binding(3)
+var<storage, read> joints: array<mat4x4<f32>>;
+
+@group(0) @binding(4)
+var<storage, read_write> skinned: array<f32>;
+
+/// Blends the joint matrices influencing a vertex.
+/// Vertices without any weight keep their bind pose.
+fn skin_matrix(influence: SkinInfluence) -> mat4x4<f32> {
+    let w = influence.weights;
+    if (w.x + w.y + w.z + w.w == 0.0) {
+        return mat4x4<f32>(
+            vec4<f32>(1.0, 0.0, 0.0, 0.0),
+            vec4<f32>(0.0, 1.0, 0.0, 0.0),
+            vec4<f32>(0.0, 0.0, 1.0, 0.0),
+            vec4<f32>(0.0, 0.0, 0.0, 1.0),
+        );
+    }
+    let j = influence.joints;
+    return joints[j.x] * w.x + joints[j.y] * w.y + joints[j.z] * w.z + joints[j.w] * w.w;
+}
+
+fn read_vec3(index: u32) -> vec3<f32> {
+    return vec3<f32>(bind_pose[index], bind_pose[index + 1u], bind_pose[index + 2u]);
+}
+
+fn write_vec3(index: u32, value: vec3<f32>) {
+    skinned[index] = value.x;
+    skinned[index + 1u] = value.y;
+    skinned[index + 2u] = value.z;
+}
+
+@compute @workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let v = id.x;
+    if (v >= skin_layout.vertex_count) {
+        return;
+    }
+    let base = v * skin_layout.stride;
+
+    // Carry every attribute over, then overwrite the skinned ones.
+    for (var i = 0u; i < skin_layout.stride; i = i + 1u) {
+        skinned[base + i] = bind_pose[base + i];
+    }
+
+    let m = skin_matrix(influences[v]);
+    write_vec3(base, (m * vec4<f32>(read_vec3(base), 1.0)).xyz);
+
+    if (skin_layout.normal_offset != ABSENT) {
+        let n = base + skin_layout.normal_offset;
+        write_vec3(n, normalize((m * vec4<f32>(read_vec3(n), 0.0)).xyz));
+    }
+}
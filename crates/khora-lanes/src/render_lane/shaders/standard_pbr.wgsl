@@ -73,19 +73,86 @@ struct MaterialUniforms {
 @group(2) @binding(0)
 var<uniform> material: MaterialUniforms;
 
-// Simple directional light for basic lighting
+// Directional light with cascaded shadows, laid out like `DirectionalLightUniform`
 struct DirectionalLight {
-    direction: vec3<f32>,
-    _padding1: f32,
-    color: vec3<f32>,
-    intensity: f32,
+    direction: vec4<f32>,            // xyz = direction, w = cascade count
+    color: vec4<f32>,                // rgb = color, a = intensity
+    cascade_view_proj: array<mat4x4<f32>, 4>, // Light's view-projection per cascade, nearest first
+    cascade_texel_sizes: vec4<f32>,  // World-space shadow texel size per cascade
+    shadow_params: vec3<f32>,        // x = atlas_index of cascade 0 (-1 = no shadow), y = bias, z = normal_bias
+    layers: u32,                     // Render layer mask
 };
 
 @group(3) @binding(0)
 var<uniform> light: DirectionalLight;
 
+// Shadow atlas written by the shadow pass, one layer per cascade
+@group(3) @binding(1)
+var shadow_atlas: texture_depth_2d_array;
+
+@group(3) @binding(2)
+var shadow_sampler: sampler_comparison;
+
 // Constants
 const PI: f32 = 3.14159265359;
+const MAX_SHADOW_CASCADES: u32 = 4u;
+const SHADOW_ATLAS_SIZE: f32 = 2048.0;
+
+// --- Shadow Sampling ---
+
+/// Samples one cascade with a 3x3 PCF kernel.
+/// Returns a shadow factor: 1.0 = fully lit, 0.0 = fully in shadow.
+fn sample_cascade_pcf(shadow_vp: mat4x4<f32>, world_pos: vec3<f32>, layer: i32, bias: f32) -> f32 {
+    let light_clip = shadow_vp * vec4<f32>(world_pos, 1.0);
+    let light_ndc = light_clip.xyz / light_clip.w;
+
+    // Convert from NDC [-1,1] to UV [0,1] (note: Y is flipped)
+    let shadow_uv = vec2<f32>(
+        light_ndc.x * 0.5 + 0.5,
+        1.0 - (light_ndc.y * 0.5 + 0.5),
+    );
+    let depth = light_ndc.z - bias;
+
+    let texel_size = 1.0 / SHADOW_ATLAS_SIZE;
+    var shadow = 0.0;
+    for (var y = -1; y <= 1; y++) {
+        for (var x = -1; x <= 1; x++) {
+            let offset = vec2<f32>(f32(x), f32(y)) * texel_size;
+            shadow += textureSampleCompareLevel(
+                shadow_atlas,
+                shadow_sampler,
+                shadow_uv + offset,
+                layer,
+                depth,
+            );
+        }
+    }
+    return shadow / 9.0;
+}
+
+/// Samples the sun's shadow from the nearest cascade covering `world_pos`.
+fn sample_directional_shadow(world_pos: vec3<f32>, N: vec3<f32>) -> f32 {
+    let first_index = i32(light.shadow_params.x);
+    if (first_index < 0) {
+        return 1.0;
+    }
+    let cascade_count = min(u32(light.direction.w), MAX_SHADOW_CASCADES);
+
+    for (var c = 0u; c < cascade_count; c++) {
+        let shadow_vp = light.cascade_view_proj[c];
+        let light_clip = shadow_vp * vec4<f32>(world_pos, 1.0);
+        let light_ndc = light_clip.xyz / light_clip.w;
+        // Keep the PCF kernel inside the cascade; beyond it, try the next one.
+        if (abs(light_ndc.x) > 0.99 || abs(light_ndc.y) > 0.99) {
+            continue;
+        }
+        // Far cascades have larger texels and need a larger normal offset.
+        let texel_scale = light.cascade_texel_sizes[c] / max(light.cascade_texel_sizes[0], 1e-6);
+        let biased_pos = world_pos + N * light.shadow_params.z * texel_scale;
+        return sample_cascade_pcf(shadow_vp, biased_pos, first_index + i32(c), light.shadow_params.y);
+    }
+    return 1.0; // Beyond the last cascade
+}
 
 // Simplified PBR calculations
 // This is a basic implementation - will be enhanced with full PBR in issue #48
@@ -138,7 +205,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let V = normalize(camera.camera_position.xyz - in.world_position);
     
     // Light direction (negated because we typically define light direction as "to light")
-    let L = normalize(-light.direction);
+    let L = normalize(-light.direction.xyz);
     
     // Half vector
     let H = normalize(V + L);
@@ -169,8 +236,9 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let diffuse = kD * albedo / PI;
     
     // Combine diffuse and specular
-    let radiance = light.color * light.intensity;
-    let Lo = (diffuse + specular) * radiance * NdotL;
+    let radiance = light.color.rgb * light.color.a;
+    let shadow = sample_directional_shadow(in.world_position, N);
+    let Lo = (diffuse + specular) * radiance * NdotL * shadow;
     
     // Simple ambient (will be replaced with proper ambient lighting later)
     let ambient = vec3<f32>(0.03) * albedo;
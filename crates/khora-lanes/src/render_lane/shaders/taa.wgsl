@@ -0,0 +1,72 @@
+// Temporal Anti-Aliasing Resolve Shader
+// Blends the jittered current frame with the history reprojected through
+// the motion vectors. The history is clamped to the current 3x3
+// neighborhood to reject stale samples, and the output optionally gets the
+// current frame's high frequencies back to counter the accumulated blur.
+
+struct Params {
+    // x: history feedback, y: sharpness, z: 1 when the history is valid.
+    settings: vec4<f32>,
+};
+
+@group(0) @binding(0) var current: texture_2d<f32>;
+@group(0) @binding(1) var history: texture_2d<f32>;
+@group(0) @binding(2) var motion: texture_2d<f32>;
+@group(0) @binding(3) var linear_sampler: sampler;
+@group(0) @binding(4) var<uniform> params: Params;
+
+struct ResolveOutput {
+    @location(0) color: vec4<f32>,
+    @location(1) history: vec4<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((idx << 1u) & 2u), f32(idx & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+fn load_current(pixel: vec2<i32>, size: vec2<i32>) -> vec3<f32> {
+    return textureLoad(current, clamp(pixel, vec2<i32>(0), size - 1), 0).rgb;
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> ResolveOutput {
+    let size = vec2<i32>(textureDimensions(current));
+    let pixel = vec2<i32>(position.xy);
+    let center = load_current(pixel, size);
+
+    // Neighborhood bounds for the history clamp, and the cross average used
+    // to extract detail for sharpening.
+    var lo = center;
+    var hi = center;
+    var cross = vec3<f32>(0.0);
+    for (var y = -1; y <= 1; y++) {
+        for (var x = -1; x <= 1; x++) {
+            let color = load_current(pixel + vec2<i32>(x, y), size);
+            lo = min(lo, color);
+            hi = max(hi, color);
+            if (abs(x) + abs(y) == 1) {
+                cross += color;
+            }
+        }
+    }
+
+    // Reproject: the pixel was at `uv - velocity` in the previous frame.
+    let uv = position.xy / vec2<f32>(size);
+    let velocity = textureLoad(motion, pixel, 0).xy;
+    let history_uv = uv - velocity;
+    var feedback = params.settings.x * params.settings.z;
+    if (any(history_uv < vec2<f32>(0.0)) || any(history_uv > vec2<f32>(1.0))) {
+        feedback = 0.0;
+    }
+    let previous = clamp(textureSampleLevel(history, linear_sampler, history_uv, 0.0).rgb, lo, hi);
+    let resolved = mix(center, previous, feedback);
+
+    let detail = (center - cross * 0.25) * params.settings.y;
+
+    var out: ResolveOutput;
+    out.color = vec4<f32>(max(resolved + detail, vec3<f32>(0.0)), 1.0);
+    out.history = vec4<f32>(resolved, 1.0);
+    return out;
+}
@@ -0,0 +1,90 @@
+// Tone Mapping Shader
+// Composites the HDR scene into the final image: adds bloom, applies the
+// exposure, maps the result into display range with the selected operator,
+// then vignettes and gamma-encodes it, drawn as one fullscreen triangle.
+
+struct Exposure {
+    ev: f32,
+    exposure: f32,
+    initialized: u32,
+    _pad: u32,
+};
+
+struct Params {
+    tone_mapping: u32,
+    inv_gamma: f32,
+    bloom_intensity: f32,
+    vignette_intensity: f32,
+    vignette_radius: f32,
+    vignette_smoothness: f32,
+    _pad: vec2<f32>,
+};
+
+// Operators, matching `ToneMapping::shader_index`.
+const TONE_MAP_CLAMP: u32 = 0u;
+const TONE_MAP_REINHARD: u32 = 1u;
+const TONE_MAP_ACES: u32 = 2u;
+
+@group(0) @binding(0)
+var hdr: texture_2d<f32>;
+
+@group(0) @binding(1)
+var<storage, read> exposure: Exposure;
+
+@group(0) @binding(2)
+var bloom: texture_2d<f32>;
+
+@group(0) @binding(3)
+var linear_sampler: sampler;
+
+@group(0) @binding(4)
+var<uniform> params: Params;
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((idx << 1u) & 2u), f32(idx & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+// Narkowicz's fit of the ACES filmic curve.
+fn aces(color: vec3<f32>) -> vec3<f32> {
+    let a = color * (2.51 * color + 0.03);
+    let b = color * (2.43 * color + 0.59) + 0.14;
+    return clamp(a / b, vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+fn tone_map(color: vec3<f32>) -> vec3<f32> {
+    switch params.tone_mapping {
+        case TONE_MAP_REINHARD: {
+            return color / (color + vec3<f32>(1.0));
+        }
+        case TONE_MAP_ACES: {
+            return aces(color);
+        }
+        default: {
+            return clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
+        }
+    }
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let uv = position.xy / vec2<f32>(textureDimensions(hdr));
+    var color = textureLoad(hdr, vec2<i32>(position.xy), 0).rgb;
+    color += textureSampleLevel(bloom, linear_sampler, uv, 0.0).rgb * params.bloom_intensity;
+    color = tone_map(color * exposure.exposure);
+
+    // Vignette: 0 at the center, 1 at the corners.
+    let distance = length(uv - vec2<f32>(0.5)) * 1.41421356;
+    let edge = smoothstep(
+        params.vignette_radius,
+        params.vignette_radius + params.vignette_smoothness,
+        distance,
+    );
+    color *= 1.0 - params.vignette_intensity * edge;
+
+    // Gamma correction
+    color = pow(color, vec3<f32>(params.inv_gamma));
+
+    return vec4<f32>(color, 1.0);
+}
@@ -30,7 +30,7 @@ struct MaterialUniforms {
     emissive: vec3<f32>,
     specular_power: f32,
     ambient: vec3<f32>,
-    _padding: f32,
+    layers: u32,
 };
 
 @group(2) @binding(0)
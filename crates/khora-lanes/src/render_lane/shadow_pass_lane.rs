@@ -29,9 +29,15 @@ use khora_core::renderer::{
     GraphicsDevice,
 };
 use khora_data::assets::Assets;
-use khora_data::render::RenderWorld;
+use khora_data::render::{RenderWorld, ShadowEntry};
 use std::sync::RwLock;
 
+/// Atlas layers of the single-map shadow pass: one per shadow-casting light.
+const DEFAULT_ATLAS_LAYERS: u32 = 4;
+
+/// Width and height of every shadow atlas layer, in texels.
+pub(super) const SHADOW_ATLAS_SIZE: u32 = 2048;
+
 /// A rendering lane dedicated to producing shadow maps.
 ///
 /// It renders the scene from the perspective of shadow-casting lights
@@ -50,13 +56,18 @@ pub struct ShadowPassLane {
     /// Comparison sampler for PCF.
     pub shadow_sampler: RwLock<Option<SamplerId>>,
     /// Stores calculated shadow matrices and atlas indices for the main pass.
-    /// Mapping: Light Index -> (Shadow Matrix, Atlas Index)
-    pub shadow_results: RwLock<std::collections::HashMap<usize, (khora_core::math::Mat4, i32)>>,
+    /// Mapping: Light Index -> Shadow Entry
+    pub shadow_results: RwLock<std::collections::HashMap<usize, ShadowEntry>>,
     /// Dynamic ring buffer for the shadow camera (light view-projection) uniforms.
     /// Uses dynamic offsets so each light can have its own camera data in the same frame.
     pub camera_ring: RwLock<Option<DynamicUniformRingBuffer>>,
     /// Dynamic ring buffer for per-mesh model uniforms.
     pub model_ring: RwLock<Option<DynamicUniformRingBuffer>>,
+    /// Number of layers in the shadow atlas.
+    atlas_layers: u32,
+    /// Whether directional lights render their cascades from the
+    /// `ShadowView` instead of a single map.
+    cascaded: bool,
 }
 
 impl Default for ShadowPassLane {
@@ -71,6 +82,8 @@ impl Default for ShadowPassLane {
             shadow_results: RwLock::new(std::collections::HashMap::new()),
             camera_ring: RwLock::new(None),
             model_ring: RwLock::new(None),
+            atlas_layers: DEFAULT_ATLAS_LAYERS,
+            cascaded: false,
         }
     }
 }
@@ -80,6 +93,16 @@ impl ShadowPassLane {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// A shadow pass rendering directional lights as cascades into an atlas
+    /// of `atlas_layers` layers. Backs [`CascadedShadowLane`](super::CascadedShadowLane).
+    pub(super) fn cascaded(atlas_layers: u32) -> Self {
+        Self {
+            atlas_layers,
+            cascaded: true,
+            ..Self::default()
+        }
+    }
 }
 
 impl khora_core::lane::Lane for ShadowPassLane {
@@ -91,6 +114,10 @@ impl khora_core::lane::Lane for ShadowPassLane {
         khora_core::lane::LaneKind::Shadow
     }
 
+    fn requirements(&self) -> khora_core::lane::LaneRequirements {
+        khora_core::lane::LaneRequirements::NONE.with_min_texture_size(SHADOW_ATLAS_SIZE)
+    }
+
     fn estimate_cost(&self, ctx: &khora_core::lane::LaneContext) -> f32 {
         let render_world = match ctx.get::<khora_core::lane::Ref<khora_data::render::RenderWorld>>()
         {
@@ -175,14 +202,8 @@ impl khora_core::lane::Lane for ShadowPassLane {
         if let Some(deck_slot) = ctx.get::<Slot<khora_core::lane::OutputDeck>>() {
             let deck = deck_slot.get();
             let entries = deck.slot::<khora_data::render::ShadowEntries>();
-            for (i, (matrix, atlas_index)) in self.get_shadow_results().iter() {
-                entries.insert(
-                    *i,
-                    khora_data::render::ShadowEntry {
-                        view_proj: *matrix,
-                        atlas_index: *atlas_index,
-                    },
-                );
+            for (i, entry) in self.get_shadow_results() {
+                entries.insert(i, entry);
             }
         }
 
@@ -276,11 +297,17 @@ impl ShadowPassLane {
             index_format: IndexFormat,
         }
 
-        /// All data needed to execute one light's shadow pass.
-        struct LightPass {
+        /// One atlas layer rendered from one light view-projection.
+        struct SlicePass {
             atlas_index: i32,
             camera_bg: BindGroupId,
             camera_offset: u32,
+        }
+
+        /// All data needed to execute one light's shadow passes; cascades
+        /// share the light's draw commands.
+        struct LightPass {
+            slices: Vec<SlicePass>,
             draw_cmds: Vec<ShadowDrawCmd>,
         }
 
@@ -304,31 +331,64 @@ impl ShadowPassLane {
             let shadow_view_proj = shadow_view
                 .and_then(|sv| sv.matrices.get(&i).copied())
                 .unwrap_or(khora_core::math::Mat4::IDENTITY);
+            let cascades = match light.light_type {
+                khora_core::renderer::light::LightType::Directional(_) if self.cascaded => {
+                    shadow_view.and_then(|sv| sv.cascades.get(&i).copied())
+                }
+                _ => None,
+            };
+            let slice_matrices = match &cascades {
+                Some(c) => &c.view_proj[..c.count],
+                None => std::slice::from_ref(&shadow_view_proj),
+            };
+
+            if next_atlas_index + slice_matrices.len() as i32 > self.atlas_layers as i32 {
+                log::debug!(
+                    "ShadowPassLane: shadow atlas full, light {} casts no shadow",
+                    i
+                );
+                continue;
+            }
 
             // Store result for main pass consumption
             let atlas_index = next_atlas_index;
-            next_atlas_index += 1;
-            shadow_results.insert(i, (shadow_view_proj, atlas_index));
-
-            // 2. Push camera (light VP) uniform for this light
-            let camera_data = CameraUniformData {
-                view_projection: shadow_view_proj.to_cols_array_2d(),
-                camera_position: [light.position.x, light.position.y, light.position.z, 1.0],
-            };
+            next_atlas_index += slice_matrices.len() as i32;
+            shadow_results.insert(
+                i,
+                ShadowEntry {
+                    view_proj: slice_matrices[0],
+                    atlas_index,
+                    cascades,
+                },
+            );
 
-            let camera_offset = match camera_ring.push(device, bytemuck::bytes_of(&camera_data)) {
-                Ok(off) => off,
-                Err(e) => {
-                    log::error!("ShadowPassLane: Failed to push camera uniform: {:?}", e);
-                    continue;
+            // 2. Push camera (light VP) uniform for each atlas slice of this light
+            let mut slices = Vec::with_capacity(slice_matrices.len());
+            for (layer, view_proj) in (atlas_index..).zip(slice_matrices) {
+                let camera_data = CameraUniformData {
+                    view_projection: view_proj.to_cols_array_2d(),
+                    camera_position: [light.position.x, light.position.y, light.position.z, 1.0],
+                };
+
+                match camera_ring.push(device, bytemuck::bytes_of(&camera_data)) {
+                    Ok(camera_offset) => slices.push(SlicePass {
+                        atlas_index: layer,
+                        camera_bg: *camera_ring.current_bind_group(),
+                        camera_offset,
+                    }),
+                    Err(e) => {
+                        log::error!("ShadowPassLane: Failed to push camera uniform: {:?}", e);
+                    }
                 }
-            };
-            let camera_bg = *camera_ring.current_bind_group();
+            }
 
             // 3. Pre-collect per-mesh draw commands
             let mut draw_cmds = Vec::with_capacity(render_world.meshes.len());
 
             for mesh in &render_world.meshes {
+                if !mesh.layers.intersects(light.layers) {
+                    continue;
+                }
                 if let Some(gpu_mesh) = gpu_meshes_guard.get(&mesh.cpu_mesh_uuid) {
                     let model_mat = mesh.transform.to_matrix();
                     let normal_mat = if let Some(inv) = model_mat.inverse() {
@@ -363,12 +423,7 @@ impl ShadowPassLane {
                 }
             }
 
-            light_passes.push(LightPass {
-                atlas_index,
-                camera_bg,
-                camera_offset,
-                draw_cmds,
-            });
+            light_passes.push(LightPass { slices, draw_cmds });
         }
 
         // Drop write guards before beginning render passes (avoids holding
@@ -377,7 +432,10 @@ impl ShadowPassLane {
         drop(camera_lock);
 
         // 4. Execute all render passes
-        for lp in &light_passes {
+        for (lp, slice) in light_passes
+            .iter()
+            .flat_map(|lp| lp.slices.iter().map(move |slice| (lp, slice)))
+        {
             let depth_attachment = RenderPassDepthStencilAttachment {
                 view: &atlas_view,
                 depth_ops: Some(Operations {
@@ -385,7 +443,7 @@ impl ShadowPassLane {
                     store: StoreOp::Store,
                 }),
                 stencil_ops: None,
-                base_array_layer: lp.atlas_index as u32,
+                base_array_layer: slice.atlas_index as u32,
             };
 
             let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
@@ -395,12 +453,17 @@ impl ShadowPassLane {
             });
 
             pass.set_pipeline(&pipeline);
-            pass.set_bind_group(0, &lp.camera_bg, &[lp.camera_offset]);
+            pass.set_bind_group(0, &slice.camera_bg, &[slice.camera_offset]);
 
+            // Sorted draws arrive batched by mesh; rebind buffers only at batch edges.
+            let mut current_buffers = None;
             for cmd in &lp.draw_cmds {
                 pass.set_bind_group(1, &cmd.model_bg, &[cmd.model_offset]);
-                pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
-                pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+                if current_buffers != Some((cmd.vertex_buffer, cmd.index_buffer)) {
+                    pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
+                    pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+                    current_buffers = Some((cmd.vertex_buffer, cmd.index_buffer));
+                }
                 pass.draw_indexed(0..cmd.index_count, 0, 0..1);
             }
         }
@@ -424,9 +487,7 @@ impl ShadowPassLane {
         (shadow_lights as f32) * (render_world.meshes.len() as f32) * 0.001
     }
 
-    fn get_shadow_results(
-        &self,
-    ) -> std::collections::HashMap<usize, (khora_core::math::Mat4, i32)> {
+    fn get_shadow_results(&self) -> std::collections::HashMap<usize, ShadowEntry> {
         self.shadow_results.read().unwrap().clone()
     }
 
@@ -448,12 +509,11 @@ impl ShadowPassLane {
                 BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, BufferBindingType,
             },
             core::{ShaderModuleDescriptor, ShaderSourceData},
-            pipeline::enums::{CompareFunction, PrimitiveTopology, VertexFormat, VertexStepMode},
+            pipeline::enums::{CompareFunction, PrimitiveTopology},
             pipeline::state::{DepthBiasState, StencilFaceState},
             pipeline::{
                 DepthStencilStateDescriptor, MultisampleStateDescriptor, PipelineLayoutDescriptor,
-                PrimitiveStateDescriptor, RenderPipelineDescriptor, VertexAttributeDescriptor,
-                VertexBufferLayoutDescriptor,
+                PrimitiveStateDescriptor, RenderPipelineDescriptor, StaticVertex,
             },
             util::{SampleCount, ShaderStageFlags, TextureFormat},
         };
@@ -505,15 +565,7 @@ impl ShadowPassLane {
             })
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
 
-        let vertex_layout = VertexBufferLayoutDescriptor {
-            array_stride: 32, // pos(12) + norm(12) + uv(8)
-            step_mode: VertexStepMode::Vertex,
-            attributes: Cow::Owned(vec![VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 0,
-                shader_location: 0,
-            }]),
-        };
+        let vertex_layout = StaticVertex::POSITION_ONLY;
 
         let pipeline_desc = RenderPipelineDescriptor {
             label: Some(Cow::Borrowed("Shadow Pass Pipeline")),
@@ -567,7 +619,7 @@ impl ShadowPassLane {
             camera_layout,
             0,
             std::mem::size_of::<CameraUniformData>() as u32,
-            16, // max shadow-casting lights per frame
+            16, // max shadow atlas slices per frame
             MIN_UNIFORM_ALIGNMENT,
             "Shadow Camera Ring",
         )
@@ -595,8 +647,8 @@ impl ShadowPassLane {
             TextureViewDimension,
         };
 
-        let atlas_size = 2048;
-        let atlas_layers = 4; // Placeholder for MAX_SHADOW_CASTERS
+        let atlas_size = SHADOW_ATLAS_SIZE;
+        let atlas_layers = self.atlas_layers;
 
         let atlas = device
             .create_texture(&TextureDescriptor {
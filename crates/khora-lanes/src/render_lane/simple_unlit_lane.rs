@@ -26,6 +26,7 @@
 //! and deterministic execution. It contains minimal branching logic and is designed to
 //! be driven by a higher-level `RenderAgent`.
 
+use crate::render_lane::PipelineVariants;
 use khora_core::{
     asset::Material,
     renderer::{
@@ -58,7 +59,8 @@ use std::sync::RwLock;
 /// - **Minimal state changes** (one pipeline bind per material, ideally)
 /// - **Suitable for**: High frame rates, simple scenes, or as a debug/fallback renderer
 pub struct SimpleUnlitLane {
-    pipeline: std::sync::Mutex<Option<RenderPipelineId>>,
+    /// The pipeline, per kind of color target.
+    pipelines: std::sync::Mutex<Option<PipelineVariants>>,
     camera_layout: std::sync::Mutex<Option<khora_core::renderer::api::command::BindGroupLayoutId>>,
     model_layout: std::sync::Mutex<Option<khora_core::renderer::api::command::BindGroupLayoutId>>,
     camera_ring: std::sync::Mutex<
@@ -84,7 +86,7 @@ impl SimpleUnlitLane {
     /// Creates a new `SimpleUnlitLane`.
     pub fn new() -> Self {
         Self {
-            pipeline: std::sync::Mutex::new(None),
+            pipelines: std::sync::Mutex::new(None),
             camera_layout: std::sync::Mutex::new(None),
             model_layout: std::sync::Mutex::new(None),
             camera_ring: std::sync::Mutex::new(None),
@@ -130,7 +132,11 @@ impl khora_core::lane::Lane for SimpleUnlitLane {
             .ok_or(khora_core::lane::LaneError::missing(
                 "Arc<dyn GraphicsDevice>",
             ))?;
-        self.on_gpu_init(device.as_ref())
+        let depth_mode = ctx
+            .get::<khora_core::renderer::api::core::DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        self.on_gpu_init(device.as_ref(), depth_mode)
             .map_err(|e| khora_core::lane::LaneError::InitializationFailed(Box::new(e)))
     }
 
@@ -159,10 +165,15 @@ impl khora_core::lane::Lane for SimpleUnlitLane {
             .get::<Ref<khora_data::render::RenderWorld>>()
             .ok_or(LaneError::missing("Ref<RenderWorld>"))?
             .get();
-        let color_target = ctx
-            .get::<khora_core::lane::ColorTarget>()
-            .ok_or(LaneError::missing("ColorTarget"))?
-            .0;
+        let hdr_target = ctx.get::<khora_core::lane::HdrSceneTarget>().map(|t| t.0);
+        let color_target = match hdr_target {
+            Some(view) => view,
+            None => {
+                ctx.get::<khora_core::lane::ColorTarget>()
+                    .ok_or(LaneError::missing("ColorTarget"))?
+                    .0
+            }
+        };
         let depth_target = ctx
             .get::<khora_core::lane::DepthTarget>()
             .ok_or(LaneError::missing("DepthTarget"))?
@@ -183,6 +194,19 @@ impl khora_core::lane::Lane for SimpleUnlitLane {
         );
         render_ctx.shadow_atlas = shadow_atlas.as_ref();
         render_ctx.shadow_sampler = shadow_sampler.as_ref();
+        render_ctx.depth_mode = ctx
+            .get::<khora_core::renderer::api::core::DepthMode>()
+            .copied()
+            .unwrap_or_default();
+        render_ctx.hdr = hdr_target.is_some();
+        let multisample = ctx.get::<khora_core::lane::MultisampleTargets>().copied();
+        if let Some(targets) = multisample.as_ref() {
+            render_ctx.multisample_into(&targets.color, &targets.depth);
+        }
+        if let Some(jitter) = ctx.get::<khora_core::lane::ProjectionJitter>() {
+            render_ctx.jitter = jitter.0;
+        }
+        render_ctx.depth_prepared = ctx.contains::<khora_core::lane::DepthPrepared>();
 
         self.render(
             render_world,
@@ -217,7 +241,10 @@ impl SimpleUnlitLane {
         _material: Option<&khora_core::asset::AssetHandle<Box<dyn Material>>>,
     ) -> RenderPipelineId {
         // Return the stored pipeline, or 0 if not initialized.
-        self.pipeline.lock().unwrap().unwrap_or(RenderPipelineId(0))
+        self.pipelines
+            .lock()
+            .unwrap()
+            .map_or(RenderPipelineId(0), |p| p.ldr())
     }
 
     fn render(
@@ -237,7 +264,7 @@ impl SimpleUnlitLane {
         let Some(view) = render_world.views.first() else {
             let color_attachment = RenderPassColorAttachment {
                 view: render_ctx.color_target,
-                resolve_target: None,
+                resolve_target: render_ctx.resolve_target,
                 ops: Operations {
                     load: LoadOp::Clear(render_ctx.clear_color),
                     store: StoreOp::Store,
@@ -251,7 +278,7 @@ impl SimpleUnlitLane {
                     RenderPassDepthStencilAttachment {
                         view: depth_view,
                         depth_ops: Some(Operations {
-                            load: LoadOp::Clear(1.0),
+                            load: render_ctx.depth_load(),
                             store: StoreOp::Store,
                         }),
                         stencil_ops: None,
@@ -267,7 +294,7 @@ impl SimpleUnlitLane {
 
         // 2. Prepare Camera Uniforms via Persistent Ring Buffer
         let camera_uniforms = CameraUniformData {
-            view_projection: view.view_proj.to_cols_array_2d(),
+            view_projection: render_ctx.jittered(view.view_proj).to_cols_array_2d(),
             camera_position: [view.position.x, view.position.y, view.position.z, 1.0],
         };
 
@@ -313,10 +340,17 @@ impl SimpleUnlitLane {
         // 3. Prepare Draw Commands
         let mut draw_commands = Vec::with_capacity(render_world.meshes.len());
 
+        // HDR and multisampled targets need their own pipeline variant.
+        let target_pipeline = match *self.pipelines.lock().unwrap() {
+            Some(p) if render_ctx.hdr || render_ctx.multisampled() => Some(p.select(render_ctx)),
+            _ => None,
+        };
         for extracted_mesh in &render_world.meshes {
             if let Some(gpu_mesh_handle) = gpu_mesh_assets.get(&extracted_mesh.cpu_mesh_uuid) {
                 // Get the pre-computed pipeline for this mesh
-                let pipeline = self.get_pipeline_for_material(extracted_mesh.material.as_ref());
+                let pipeline = target_pipeline.unwrap_or_else(|| {
+                    self.get_pipeline_for_material(extracted_mesh.material.as_ref())
+                });
 
                 // Create Per-Mesh Uniforms
                 let model_mat = extracted_mesh.transform.to_matrix();
@@ -327,10 +361,7 @@ impl SimpleUnlitLane {
                     continue; // Skip if degenerate transform
                 };
 
-                let mut base_color = khora_core::math::LinearRgba::WHITE;
-                if let Some(mat_handle) = &extracted_mesh.material {
-                    base_color = mat_handle.base_color();
-                }
+                let base_color = extracted_mesh.material_params().base_color;
 
                 let model_uniforms = ModelUniforms {
                     model_matrix: model_mat.to_cols_array_2d(),
@@ -347,7 +378,8 @@ impl SimpleUnlitLane {
                 let material_uniforms = khora_core::renderer::api::scene::MaterialUniforms {
                     base_color,
                     emissive: khora_core::math::LinearRgba::BLACK,
-                    ambient: khora_core::math::LinearRgba::BLACK,
+                    ambient: [0.0; 3],
+                    layers: extracted_mesh.layers.0,
                 };
 
                 let mat_offset =
@@ -374,7 +406,7 @@ impl SimpleUnlitLane {
         // Configure the render pass to render into the provided color target
         let color_attachment = RenderPassColorAttachment {
             view: render_ctx.color_target,
-            resolve_target: None,
+            resolve_target: render_ctx.resolve_target,
             ops: Operations {
                 load: LoadOp::Clear(render_ctx.clear_color),
                 store: StoreOp::Store,
@@ -389,7 +421,7 @@ impl SimpleUnlitLane {
                 RenderPassDepthStencilAttachment {
                     view: depth_view,
                     depth_ops: Some(Operations {
-                        load: LoadOp::Clear(1.0),
+                        load: render_ctx.depth_load(),
                         store: StoreOp::Store,
                     }),
                     stencil_ops: None,
@@ -407,6 +439,8 @@ impl SimpleUnlitLane {
         // Track the last pipeline we bound to avoid redundant state changes
         let mut current_pipeline: Option<RenderPipelineId> = None;
 
+        // Sorted draws arrive batched by mesh; rebind buffers only at batch edges.
+        let mut current_buffers = None;
         for cmd in &draw_commands {
             if current_pipeline != Some(cmd.pipeline) {
                 render_pass.set_pipeline(&cmd.pipeline);
@@ -421,8 +455,11 @@ impl SimpleUnlitLane {
                 render_pass.set_bind_group(2, bg, &[cmd.material_offset]);
             }
 
-            render_pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
-            render_pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+            if current_buffers != Some((cmd.vertex_buffer, cmd.index_buffer)) {
+                render_pass.set_vertex_buffer(0, &cmd.vertex_buffer, 0);
+                render_pass.set_index_buffer(&cmd.index_buffer, 0, cmd.index_format);
+                current_buffers = Some((cmd.vertex_buffer, cmd.index_buffer));
+            }
             render_pass.draw_indexed(0..cmd.index_count, 0, 0..1);
         }
     }
@@ -471,6 +508,7 @@ impl SimpleUnlitLane {
     fn on_gpu_init(
         &self,
         device: &dyn khora_core::renderer::GraphicsDevice,
+        depth_mode: khora_core::renderer::api::core::DepthMode,
     ) -> Result<(), khora_core::renderer::error::RenderError> {
         use crate::render_lane::shaders::UNLIT_WGSL;
         use khora_core::renderer::api::{
@@ -478,12 +516,11 @@ impl SimpleUnlitLane {
                 BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, BufferBindingType,
             },
             core::{ShaderModuleDescriptor, ShaderSourceData},
-            pipeline::enums::{CompareFunction, VertexFormat, VertexStepMode},
             pipeline::state::{ColorWrites, DepthBiasState, StencilFaceState},
             pipeline::{
                 ColorTargetStateDescriptor, DepthStencilStateDescriptor,
                 MultisampleStateDescriptor, PrimitiveStateDescriptor, RenderPipelineDescriptor,
-                VertexAttributeDescriptor, VertexBufferLayoutDescriptor,
+                StaticVertex, Vertex,
             },
             resource::CameraUniformData,
             scene::ModelUniforms,
@@ -559,33 +596,8 @@ impl SimpleUnlitLane {
             })
             .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
 
-        // 3. Define Vertex Layout (matching our standard vertex buffer)
-        // Attribute 0: Position (vec3<f32>)
-        // Attribute 1: Normal (vec3<f32>)
-        // Attribute 2: UV (vec2<f32>)
-        let vertex_attributes = vec![
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 0,
-                shader_location: 0,
-            },
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x3,
-                offset: 12, // 3 * size_of<f32>
-                shader_location: 1,
-            },
-            VertexAttributeDescriptor {
-                format: VertexFormat::Float32x2,
-                offset: 24, // 6 * size_of<f32>
-                shader_location: 2,
-            },
-        ];
-
-        let vertex_layout = VertexBufferLayoutDescriptor {
-            array_stride: 32, // 3*4 + 3*4 + 2*4
-            step_mode: VertexStepMode::Vertex,
-            attributes: Cow::Owned(vertex_attributes),
-        };
+        // 3. Vertex layout of the standard static mesh buffer
+        let vertex_layout = StaticVertex::LAYOUT;
 
         // 4. Create Pipeline Layout
         let pipeline_layout_ids = vec![camera_layout, model_layout, material_layout];
@@ -614,7 +626,7 @@ impl SimpleUnlitLane {
             depth_stencil_state: Some(DepthStencilStateDescriptor {
                 format: khora_core::renderer::api::util::TextureFormat::Depth32Float,
                 depth_write_enabled: true,
-                depth_compare: CompareFunction::Less,
+                depth_compare: depth_mode.compare_or_equal(),
                 stencil_front: StencilFaceState::default(),
                 stencil_back: StencilFaceState::default(),
                 stencil_read_mask: 0,
@@ -635,12 +647,10 @@ impl SimpleUnlitLane {
             },
         };
 
-        let pipeline_id = device
-            .create_render_pipeline(&pipeline_desc)
-            .map_err(khora_core::renderer::error::RenderError::ResourceError)?;
-
-        let mut pipeline_lock = self.pipeline.lock().unwrap();
-        *pipeline_lock = Some(pipeline_id);
+        // The unlit shader does no tone mapping, so the HDR variants keep
+        // `fs_main` and only the target format differs.
+        let pipelines = PipelineVariants::new(device, &pipeline_desc, "fs_main")?;
+        *self.pipelines.lock().unwrap() = Some(pipelines);
 
         let camera_ring = UniformRingBuffer::new(
             device,
@@ -694,9 +704,8 @@ impl SimpleUnlitLane {
         if let Some(ring) = self.material_ring.lock().unwrap().take() {
             ring.destroy(device);
         }
-        let mut pipeline_lock = self.pipeline.lock().unwrap();
-        if let Some(id) = pipeline_lock.take() {
-            let _ = device.destroy_render_pipeline(id);
+        if let Some(pipelines) = self.pipelines.lock().unwrap().take() {
+            pipelines.destroy(device);
         }
     }
 }
@@ -757,10 +766,16 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: mesh_uuid,
             gpu_mesh: gpu_mesh_handle,
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
@@ -794,10 +809,16 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: mesh_uuid,
             gpu_mesh: gpu_mesh_handle,
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
@@ -844,16 +865,28 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: line_uuid,
             gpu_mesh: line_mesh_handle,
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: point_uuid,
             gpu_mesh: point_mesh_handle,
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
@@ -908,22 +941,40 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: mesh1_uuid,
             gpu_mesh: AssetHandle::new(create_test_mesh(600)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: mesh2_uuid,
             gpu_mesh: AssetHandle::new(create_test_mesh(102)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: mesh3_uuid,
             gpu_mesh: AssetHandle::new(create_test_mesh(150)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
@@ -959,10 +1010,16 @@ mod tests {
         // Reference a mesh that doesn't exist in the cache
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: AssetUUID::new(),
             gpu_mesh: AssetHandle::new(create_test_mesh(300)),
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let cost = lane.estimate_render_cost(&render_world, &gpu_meshes);
@@ -993,10 +1050,16 @@ mod tests {
 
         let mut render_world = RenderWorld::default();
         render_world.meshes.push(ExtractedMesh {
+            entity: None,
             transform: Default::default(),
             cpu_mesh_uuid: mesh_uuid,
             gpu_mesh: handle,
             material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: None,
         });
 
         let gpu_meshes_lock = Arc::new(RwLock::new(gpu_meshes));
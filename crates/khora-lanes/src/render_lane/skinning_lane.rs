@@ -0,0 +1,382 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Skinning compute pre-pass — poses skinned meshes into their output
+//! vertex buffers before any scene or shadow pass draws them.
+//!
+//! A mesh goes through the pre-pass when its [`ExtractedSkin`] asks for it
+//! (compute mode, or drawn by more than one pass), or when the active scene
+//! lane cannot skin in its vertex shader (see [`VertexSkinning`]).
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use khora_core::lane::{
+    LaneContext, LaneError, LaneKind, LaneRequirements, Ref, Slot, VertexSkinning,
+};
+use khora_core::renderer::api::command::{
+    BindGroupDescriptor, BindGroupEntry, BindGroupId, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindGroupLayoutId, BufferBindingType, ComputePassDescriptor,
+    ComputePipelineDescriptor, ComputePipelineId,
+};
+use khora_core::renderer::api::core::{ShaderModuleDescriptor, ShaderSourceData};
+use khora_core::renderer::api::pipeline::PipelineLayoutDescriptor;
+use khora_core::renderer::api::resource::{BufferDescriptor, BufferId, BufferUsage};
+use khora_core::renderer::api::util::ShaderStageFlags;
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::render::{ExtractedMesh, ExtractedSkin, RenderWorld};
+
+/// Invocations per workgroup, matching `@workgroup_size` in `skinning.wgsl`.
+const WORKGROUP_SIZE: u32 = 64;
+/// Estimated cost of skinning one vertex.
+const VERTEX_COST: f32 = 0.00002;
+
+/// Per-skin GPU state reused across frames.
+#[derive(Debug, Clone, Copy)]
+struct SkinBinding {
+    layout_buffer: BufferId,
+    bind_group: BindGroupId,
+}
+
+/// Runs the skinning compute pre-pass for the frame's skinned meshes.
+#[derive(Debug, Default)]
+pub struct SkinningLane {
+    pipeline: Mutex<Option<ComputePipelineId>>,
+    bind_group_layout: Mutex<Option<BindGroupLayoutId>>,
+    /// Bindings keyed by (joint matrix buffer, output vertex buffer).
+    bindings: Mutex<HashMap<(BufferId, BufferId), SkinBinding>>,
+}
+
+impl SkinningLane {
+    /// Creates a new `SkinningLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the skins the pre-pass must pose this frame.
+    ///
+    /// `vertex_skinning` tells whether the scene lane poses vertex-shader
+    /// mode meshes on its own.
+    pub fn pending(
+        render_world: &RenderWorld,
+        vertex_skinning: bool,
+    ) -> impl Iterator<Item = (&ExtractedMesh, &ExtractedSkin)> {
+        render_world.meshes.iter().filter_map(move |mesh| {
+            let skin = mesh.skin.as_ref()?;
+            (skin.needs_pre_pass() || !vertex_skinning).then_some((mesh, skin))
+        })
+    }
+
+    fn binding(
+        &self,
+        device: &dyn GraphicsDevice,
+        layout: BindGroupLayoutId,
+        skin: &ExtractedSkin,
+        output: BufferId,
+    ) -> Option<SkinBinding> {
+        let key = (skin.joint_matrices, output);
+        if let Some(binding) = self.bindings.lock().unwrap().get(&key) {
+            return Some(*binding);
+        }
+
+        let layout_buffer = match device.create_buffer_with_data(
+            &BufferDescriptor {
+                label: Some("Skin Layout Uniform".into()),
+                size: std::mem::size_of_val(&skin.layout) as u64,
+                usage: BufferUsage::UNIFORM,
+                mapped_at_creation: false,
+            },
+            bytemuck::bytes_of(&skin.layout),
+        ) {
+            Ok(buffer) => buffer,
+            Err(e) => {
+                log::error!("SkinningLane: Failed to create layout uniform: {:?}", e);
+                return None;
+            }
+        };
+        let bind_group = match device.create_bind_group(&BindGroupDescriptor {
+            label: Some("skinning_bind_group"),
+            layout,
+            entries: &[
+                BindGroupEntry::buffer(0, layout_buffer, 0, None),
+                BindGroupEntry::buffer(1, skin.bind_pose, 0, None),
+                BindGroupEntry::buffer(2, skin.influences, 0, None),
+                BindGroupEntry::buffer(3, skin.joint_matrices, 0, None),
+                BindGroupEntry::buffer(4, output, 0, None),
+            ],
+        }) {
+            Ok(bind_group) => bind_group,
+            Err(e) => {
+                log::error!("SkinningLane: Failed to create bind group: {:?}", e);
+                let _ = device.destroy_buffer(layout_buffer);
+                return None;
+            }
+        };
+
+        let binding = SkinBinding {
+            layout_buffer,
+            bind_group,
+        };
+        self.bindings.lock().unwrap().insert(key, binding);
+        Some(binding)
+    }
+
+    /// Releases bindings whose skin was not drawn this frame.
+    fn prune(&self, device: &dyn GraphicsDevice, live: &HashSet<(BufferId, BufferId)>) {
+        self.bindings.lock().unwrap().retain(|key, binding| {
+            if live.contains(key) {
+                return true;
+            }
+            let _ = device.destroy_bind_group(binding.bind_group);
+            let _ = device.destroy_buffer(binding.layout_buffer);
+            false
+        });
+    }
+
+    fn on_gpu_init(&self, device: &dyn GraphicsDevice) -> Result<(), RenderError> {
+        use crate::render_lane::shaders::SKINNING_WGSL;
+        use std::borrow::Cow;
+
+        let storage = |binding, read_only| {
+            BindGroupLayoutEntry::buffer(
+                binding,
+                ShaderStageFlags::COMPUTE,
+                BufferBindingType::Storage { read_only },
+                false,
+                None,
+            )
+        };
+        let layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("skinning_layout"),
+                entries: &[
+                    BindGroupLayoutEntry::buffer(
+                        0,
+                        ShaderStageFlags::COMPUTE,
+                        BufferBindingType::Uniform,
+                        false,
+                        None,
+                    ),
+                    storage(1, true),
+                    storage(2, true),
+                    storage(3, true),
+                    storage(4, false),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Skinning Pipeline Layout")),
+                bind_group_layouts: &[layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let shader_module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("skinning_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(SKINNING_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let pipeline = device
+            .create_compute_pipeline(&ComputePipelineDescriptor {
+                label: Some(Cow::Borrowed("Skinning Pipeline")),
+                layout: Some(pipeline_layout),
+                shader_module,
+                entry_point: Cow::Borrowed("cs_main"),
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        *self.bind_group_layout.lock().unwrap() = Some(layout);
+        *self.pipeline.lock().unwrap() = Some(pipeline);
+        Ok(())
+    }
+
+    fn on_gpu_shutdown(&self, device: &dyn GraphicsDevice) {
+        self.prune(device, &HashSet::new());
+        if let Some(id) = self.pipeline.lock().unwrap().take() {
+            let _ = device.destroy_compute_pipeline(id);
+        }
+        if let Some(id) = self.bind_group_layout.lock().unwrap().take() {
+            let _ = device.destroy_bind_group_layout(id);
+        }
+    }
+}
+
+impl khora_core::lane::Lane for SkinningLane {
+    fn strategy_name(&self) -> &'static str {
+        "GpuSkinning"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn requirements(&self) -> LaneRequirements {
+        // Poses are written by a compute pre-pass.
+        LaneRequirements::NONE.with_compute()
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        let Some(render_world) = ctx.get::<Ref<RenderWorld>>().map(|r| r.get()) else {
+            return 0.0;
+        };
+        let vertex_skinning = ctx.get::<VertexSkinning>().is_some_and(|v| v.0);
+        Self::pending(render_world, vertex_skinning)
+            .map(|(_, skin)| skin.layout.vertex_count as f32 * VERTEX_COST)
+            .sum()
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<std::sync::Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        self.on_gpu_init(device.as_ref())
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<std::sync::Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let encoder = ctx
+            .get::<Slot<dyn CommandEncoder>>()
+            .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+            .get();
+        let render_world = ctx
+            .get::<Ref<RenderWorld>>()
+            .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+            .get();
+        let vertex_skinning = ctx.get::<VertexSkinning>().is_some_and(|v| v.0);
+
+        let (Some(pipeline), Some(layout)) = (
+            *self.pipeline.lock().unwrap(),
+            *self.bind_group_layout.lock().unwrap(),
+        ) else {
+            return Err(LaneError::NotInitialized);
+        };
+
+        let mut live = HashSet::new();
+        let mut dispatches = Vec::new();
+        for (mesh, skin) in Self::pending(render_world, vertex_skinning) {
+            let output = mesh.gpu_mesh.vertex_buffer;
+            let Some(binding) = self.binding(device.as_ref(), layout, skin, output) else {
+                continue;
+            };
+            live.insert((skin.joint_matrices, output));
+            dispatches.push((
+                binding.bind_group,
+                skin.layout.vertex_count.div_ceil(WORKGROUP_SIZE),
+            ));
+        }
+        self.prune(device.as_ref(), &live);
+        if dispatches.is_empty() {
+            return Ok(());
+        }
+
+        let mut pass = encoder.begin_compute_pass(&ComputePassDescriptor {
+            label: Some("Skinning Pre-Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&pipeline);
+        for (bind_group, workgroups) in &dispatches {
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.dispatch_workgroups(*workgroups, 1, 1);
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        if let Some(device) = ctx.get::<std::sync::Arc<dyn GraphicsDevice>>() {
+            self.on_gpu_shutdown(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::asset::{AssetHandle, AssetUUID};
+    use khora_core::lane::Lane;
+    use khora_core::renderer::api::pipeline::enums::PrimitiveTopology;
+    use khora_core::renderer::api::scene::{GpuMesh, SkinVertexLayout, SkinningMode};
+    use khora_core::renderer::api::util::IndexFormat;
+
+    fn skinned_mesh(mode: SkinningMode, passes: u32) -> ExtractedMesh {
+        ExtractedMesh {
+            entity: None,
+            transform: Default::default(),
+            cpu_mesh_uuid: AssetUUID::new(),
+            gpu_mesh: AssetHandle::new(GpuMesh {
+                vertex_buffer: BufferId(0),
+                index_buffer: BufferId(1),
+                index_count: 3,
+                index_format: IndexFormat::Uint32,
+                primitive_topology: PrimitiveTopology::TriangleList,
+            }),
+            material: None,
+            material_uuid: None,
+            material_override: None,
+            sort_key: Default::default(),
+            layers: Default::default(),
+            skin: Some(ExtractedSkin {
+                mode,
+                forced_mode: None,
+                passes,
+                bind_pose: BufferId(2),
+                influences: BufferId(3),
+                joint_matrices: BufferId(4),
+                layout: SkinVertexLayout {
+                    vertex_count: 3,
+                    stride: 8,
+                    normal_offset: 3,
+                    uv_offset: 6,
+                },
+            }),
+        }
+    }
+
+    #[test]
+    fn test_skinning_lane_creation() {
+        let lane = SkinningLane::new();
+        assert_eq!(lane.strategy_name(), "GpuSkinning");
+        assert_eq!(lane.lane_kind(), LaneKind::Render);
+    }
+
+    #[test]
+    fn test_pending_respects_vertex_skinning() {
+        let mut render_world = RenderWorld::default();
+        render_world
+            .meshes
+            .push(skinned_mesh(SkinningMode::VertexShader, 1));
+        render_world
+            .meshes
+            .push(skinned_mesh(SkinningMode::ComputePrePass, 2));
+
+        assert_eq!(SkinningLane::pending(&render_world, true).count(), 1);
+        assert_eq!(SkinningLane::pending(&render_world, false).count(), 2);
+    }
+}
@@ -0,0 +1,549 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! TAA lane — temporal anti-aliasing of the final image.
+//!
+//! In [`PostStage::Prepare`] the lane swaps the [`ColorTarget`] for an
+//! intermediate target and publishes a sub-pixel [`ProjectionJitter`], so
+//! each frame samples a different point of every pixel. In
+//! [`PostStage::Resolve`] it blends that target with the history
+//! reprojected through the [`MotionVectorTarget`], writing both the real
+//! [`ColorTarget`] and the next frame's history.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{
+    ColorTarget, Lane, LaneContext, LaneError, LaneKind, MotionVectorTarget, PostStage,
+    ProjectionJitter, Ref, Slot, TargetSize,
+};
+use khora_core::math::{Extent2D, LinearRgba, Vec2};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupDescriptor, BindGroupEntry, BindGroupLayoutDescriptor, BindGroupLayoutEntry,
+        BindGroupLayoutId, BindingResource, BindingType, BufferBindingType, LoadOp, Operations,
+        RenderPassColorAttachment, RenderPassDescriptor, SamplerBindingType, StoreOp,
+        TextureSampleType,
+    },
+    core::{ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology, state::ColorWrites, ColorTargetStateDescriptor,
+        MultisampleStateDescriptor, PipelineLayoutDescriptor, PrimitiveStateDescriptor,
+        RenderPipelineDescriptor, RenderPipelineId,
+    },
+    resource::{
+        BufferDescriptor, BufferId, BufferUsage, SamplerId, TextureUsage, TextureViewDimension,
+        TextureViewId,
+    },
+    util::{SampleCount, ShaderStageFlags, TextureFormat},
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::ecs::AntiAliasing;
+use khora_data::render::RenderWorld;
+
+use super::render_target::{create_linear_sampler, RenderTarget};
+
+/// Format of the accumulated history.
+const HISTORY_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+/// Length of the jitter sequence before it repeats.
+const JITTER_PHASES: u32 = 8;
+/// Estimated cost of resolving one pixel.
+const PIXEL_COST: f32 = 0.000_000_3;
+
+/// Resolve parameters, matching `Params` in `taa.wgsl`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct TaaParams {
+    /// Feedback, sharpness, history validity, padding.
+    settings: [f32; 4],
+}
+
+/// The `index`-th element of the Halton low-discrepancy sequence in `base`.
+fn halton(mut index: u32, base: u32) -> f32 {
+    let mut fraction = 1.0;
+    let mut result = 0.0;
+    while index > 0 {
+        fraction /= base as f32;
+        result += fraction * (index % base) as f32;
+        index /= base;
+    }
+    result
+}
+
+/// Sub-pixel NDC offset of `frame`, cycling through a Halton(2, 3)
+/// pattern within one pixel of a `size` target.
+fn jitter(frame: u32, size: Extent2D) -> Vec2 {
+    let index = frame % JITTER_PHASES + 1;
+    Vec2::new(
+        (halton(index, 2) - 0.5) * 2.0 / size.width as f32,
+        (halton(index, 3) - 0.5) * 2.0 / size.height as f32,
+    )
+}
+
+/// The scene input and the two history targets the resolve ping-pongs
+/// between, recreated on resize.
+struct TaaTargets {
+    input: RenderTarget,
+    history: [RenderTarget; 2],
+    /// Index of the history read this frame; the other one is written.
+    read: usize,
+    /// Whether the read history holds a resolved frame.
+    valid: bool,
+}
+
+impl TaaTargets {
+    fn new(
+        device: &dyn GraphicsDevice,
+        size: Extent2D,
+        format: TextureFormat,
+    ) -> Result<Self, RenderError> {
+        let usage = TextureUsage::RENDER_ATTACHMENT | TextureUsage::TEXTURE_BINDING;
+        let input = RenderTarget::new(device, "TAA Input", size, format, usage, SampleCount::X1)?;
+        let history =
+            |label| RenderTarget::new(device, label, size, HISTORY_FORMAT, usage, SampleCount::X1);
+        let a = match history("TAA History A") {
+            Ok(a) => a,
+            Err(e) => {
+                input.destroy(device);
+                return Err(e);
+            }
+        };
+        let b = match history("TAA History B") {
+            Ok(b) => b,
+            Err(e) => {
+                input.destroy(device);
+                a.destroy(device);
+                return Err(e);
+            }
+        };
+        Ok(Self {
+            input,
+            history: [a, b],
+            read: 0,
+            valid: false,
+        })
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        self.input.destroy(device);
+        for history in self.history {
+            history.destroy(device);
+        }
+    }
+}
+
+/// GPU state of the lane, built in `on_initialize`.
+struct TaaGpu {
+    layout: BindGroupLayoutId,
+    pipeline: RenderPipelineId,
+    params_buffer: BufferId,
+    sampler: SamplerId,
+    format: TextureFormat,
+    frame: u32,
+    targets: Option<TaaTargets>,
+}
+
+/// An anti-aliasing lane accumulating jittered frames over time.
+///
+/// TAA resolves both geometric and shading aliasing for about the cost of
+/// one fullscreen pass plus motion vectors, but can ghost or blur under
+/// fast motion.
+#[derive(Default)]
+pub struct TaaLane {
+    gpu: Mutex<Option<TaaGpu>>,
+}
+
+impl TaaLane {
+    /// Creates a new `TaaLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for TaaLane {
+    fn strategy_name(&self) -> &'static str {
+        "Taa"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<TargetSize>() {
+            Some(size) => (size.0.width * size.0.height) as f32 * PIXEL_COST,
+            None => 0.5,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let gpu = TaaGpu::new(device.as_ref())
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let stage = *ctx
+            .get::<PostStage>()
+            .ok_or(LaneError::missing("PostStage"))?;
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            return Err(LaneError::NotInitialized);
+        };
+
+        match stage {
+            PostStage::Prepare => {
+                if size.width == 0 || size.height == 0 {
+                    return Ok(());
+                }
+                let view = gpu
+                    .prepare(device.as_ref(), size)
+                    .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+                ctx.insert(ColorTarget(view));
+                ctx.insert(ProjectionJitter(jitter(gpu.frame, size)));
+                gpu.frame = gpu.frame.wrapping_add(1);
+                Ok(())
+            }
+            PostStage::Resolve => {
+                let color_target = ctx
+                    .get::<ColorTarget>()
+                    .ok_or(LaneError::missing("ColorTarget"))?
+                    .0;
+                let motion = ctx
+                    .get::<MotionVectorTarget>()
+                    .ok_or(LaneError::missing("MotionVectorTarget"))?
+                    .0;
+                let render_world = ctx
+                    .get::<Ref<RenderWorld>>()
+                    .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+                    .get();
+                let encoder = ctx
+                    .get::<Slot<dyn CommandEncoder>>()
+                    .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+                    .get();
+                let settings = render_world.anti_aliasing.unwrap_or_default();
+                gpu.resolve(
+                    device.as_ref(),
+                    encoder,
+                    color_target,
+                    motion,
+                    size,
+                    &settings,
+                )
+                .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl TaaGpu {
+    fn new(device: &dyn GraphicsDevice) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::TAA_WGSL;
+
+        let format = device
+            .get_surface_format()
+            .unwrap_or(TextureFormat::Rgba8UnormSrgb);
+        let texture = |binding| BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStageFlags::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: true },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+        };
+        let layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("taa_layout"),
+                entries: &[
+                    texture(0),
+                    texture(1),
+                    texture(2),
+                    BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: ShaderStageFlags::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    },
+                    BindGroupLayoutEntry::buffer(
+                        4,
+                        ShaderStageFlags::FRAGMENT,
+                        BufferBindingType::Uniform,
+                        false,
+                        None,
+                    ),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("taa_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(TAA_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("TAA Pipeline Layout")),
+                bind_group_layouts: &[layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline = device
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some(Cow::Borrowed("TAA Resolve Pipeline")),
+                layout: Some(pipeline_layout),
+                vertex_shader_module: module,
+                vertex_entry_point: Cow::Borrowed("vs_main"),
+                fragment_shader_module: Some(module),
+                fragment_entry_point: Some(Cow::Borrowed("fs_main")),
+                color_target_states: Cow::Owned(vec![
+                    ColorTargetStateDescriptor {
+                        format,
+                        blend: None,
+                        write_mask: ColorWrites::ALL,
+                    },
+                    ColorTargetStateDescriptor {
+                        format: HISTORY_FORMAT,
+                        blend: None,
+                        write_mask: ColorWrites::ALL,
+                    },
+                ]),
+                vertex_buffers_layout: Cow::Owned(vec![]), // Fullscreen triangle from vertex_index
+                primitive_state: PrimitiveStateDescriptor {
+                    topology: PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil_state: None,
+                multisample_state: MultisampleStateDescriptor {
+                    count: SampleCount::X1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let params_buffer = device
+            .create_buffer(&BufferDescriptor {
+                label: Some("TAA Params".into()),
+                size: std::mem::size_of::<TaaParams>() as u64,
+                usage: BufferUsage::UNIFORM | BufferUsage::COPY_DST,
+                mapped_at_creation: false,
+            })
+            .map_err(RenderError::ResourceError)?;
+        let sampler = create_linear_sampler(device, "TAA Sampler")?;
+
+        Ok(Self {
+            layout,
+            pipeline,
+            params_buffer,
+            sampler,
+            format,
+            frame: 0,
+            targets: None,
+        })
+    }
+
+    /// Returns the target the scene should render into, recreating the
+    /// targets (and dropping the history) when the frame size changed.
+    fn prepare(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        size: Extent2D,
+    ) -> Result<TextureViewId, RenderError> {
+        if let Some(targets) = self
+            .targets
+            .as_ref()
+            .filter(|t| t.input.matches(size, self.format))
+        {
+            return Ok(targets.input.view);
+        }
+        if let Some(old) = self.targets.take() {
+            old.destroy(device);
+        }
+        let targets = TaaTargets::new(device, size, self.format)?;
+        let view = targets.input.view;
+        self.targets = Some(targets);
+        Ok(view)
+    }
+
+    /// Resolves the input and history into `color_target` and the other
+    /// history. Does nothing if no input was prepared at this size, as the
+    /// scene was then drawn straight to the color target.
+    fn resolve(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        color_target: TextureViewId,
+        motion: TextureViewId,
+        size: Extent2D,
+        settings: &AntiAliasing,
+    ) -> Result<(), RenderError> {
+        let Some(targets) = self.targets.as_mut().filter(|t| t.input.size == size) else {
+            return Ok(());
+        };
+        let params = TaaParams {
+            settings: [
+                settings.taa_feedback,
+                settings.taa_sharpness,
+                if targets.valid { 1.0 } else { 0.0 },
+                0.0,
+            ],
+        };
+        device
+            .write_buffer(self.params_buffer, 0, bytemuck::bytes_of(&params))
+            .map_err(RenderError::ResourceError)?;
+
+        let texture_entry = |binding, view| BindGroupEntry {
+            binding,
+            resource: BindingResource::TextureView(view),
+            _phantom: std::marker::PhantomData,
+        };
+        let write = 1 - targets.read;
+        let bind_group = device
+            .create_bind_group(&BindGroupDescriptor {
+                label: Some("taa_bind_group"),
+                layout: self.layout,
+                entries: &[
+                    texture_entry(0, targets.input.view),
+                    texture_entry(1, targets.history[targets.read].view),
+                    texture_entry(2, motion),
+                    BindGroupEntry {
+                        binding: 3,
+                        resource: BindingResource::Sampler(self.sampler),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry::buffer(4, self.params_buffer, 0, None),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        {
+            let ops = || Operations {
+                load: LoadOp::Clear(LinearRgba::new(0.0, 0.0, 0.0, 1.0)),
+                store: StoreOp::Store,
+            };
+            let color_attachments = [
+                RenderPassColorAttachment {
+                    view: &color_target,
+                    resolve_target: None,
+                    ops: ops(),
+                    base_array_layer: 0,
+                },
+                RenderPassColorAttachment {
+                    view: &targets.history[write].view,
+                    resolve_target: None,
+                    ops: ops(),
+                    base_array_layer: 0,
+                },
+            ];
+            let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("TAA Resolve Pass"),
+                color_attachments: &color_attachments,
+                depth_stencil_attachment: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+        // The bind group is only referenced by the recorded commands.
+        let _ = device.destroy_bind_group(bind_group);
+
+        targets.read = write;
+        targets.valid = true;
+        Ok(())
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Some(targets) = self.targets {
+            targets.destroy(device);
+        }
+        if let Err(e) = device.destroy_render_pipeline(self.pipeline) {
+            log::warn!("TaaLane: Failed to destroy pipeline: {:?}", e);
+        }
+        if let Err(e) = device.destroy_buffer(self.params_buffer) {
+            log::warn!("TaaLane: Failed to destroy buffer: {:?}", e);
+        }
+        if let Err(e) = device.destroy_sampler(self.sampler) {
+            log::warn!("TaaLane: Failed to destroy sampler: {:?}", e);
+        }
+        if let Err(e) = device.destroy_bind_group_layout(self.layout) {
+            log::warn!("TaaLane: Failed to destroy layout: {:?}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halton_matches_the_radical_inverse() {
+        assert_eq!(halton(1, 2), 0.5);
+        assert_eq!(halton(2, 2), 0.25);
+        assert_eq!(halton(3, 2), 0.75);
+        assert!((halton(1, 3) - 1.0 / 3.0).abs() < 1e-6);
+        assert!((halton(2, 3) - 2.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn jitter_stays_within_one_pixel_and_cycles() {
+        let size = Extent2D {
+            width: 1920,
+            height: 1080,
+        };
+        for frame in 0..JITTER_PHASES {
+            let offset = jitter(frame, size);
+            assert!(offset.x.abs() <= 1.0 / size.width as f32);
+            assert!(offset.y.abs() <= 1.0 / size.height as f32);
+        }
+        assert_eq!(jitter(0, size), jitter(JITTER_PHASES, size));
+        assert_ne!(jitter(0, size), jitter(1, size));
+    }
+}
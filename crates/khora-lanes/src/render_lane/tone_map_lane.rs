@@ -0,0 +1,570 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Tone mapping lane — HDR scene target and final composite of the
+//! post-processing stack.
+//!
+//! The lane runs in two [`PostStage`]s around the scene pass:
+//!
+//! - **Prepare** publishes an [`HdrSceneTarget`] sized to the frame, which
+//!   the scene lane renders into with linear, un-tone-mapped output.
+//! - **Resolve**, after the other post lanes, adds the [`BloomTarget`] to the
+//!   HDR image, scales it by the [`ExposureBuffer`], then tone-maps,
+//!   vignettes and gamma-encodes it into the [`ColorTarget`]. Without those
+//!   keys it adds no bloom and keeps an exposure of one.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use khora_core::lane::{
+    BloomTarget, ColorTarget, ExposureBuffer, HdrSceneTarget, Lane, LaneContext, LaneError,
+    LaneKind, PostStage, Ref, Slot, TargetSize,
+};
+use khora_core::math::{Extent2D, Extent3D, LinearRgba, Origin3D};
+use khora_core::renderer::api::{
+    command::{
+        BindGroupDescriptor, BindGroupEntry, BindGroupId, BindGroupLayoutDescriptor,
+        BindGroupLayoutEntry, BindGroupLayoutId, BindingResource, BindingType, BufferBindingType,
+        LoadOp, Operations, RenderPassColorAttachment, RenderPassDescriptor, SamplerBindingType,
+        StoreOp, TextureSampleType,
+    },
+    core::{ShaderModuleDescriptor, ShaderSourceData},
+    pipeline::{
+        enums::PrimitiveTopology, state::ColorWrites, ColorTargetStateDescriptor,
+        MultisampleStateDescriptor, PipelineLayoutDescriptor, PrimitiveStateDescriptor,
+        RenderPipelineDescriptor, RenderPipelineId,
+    },
+    resource::{
+        BufferDescriptor, BufferId, BufferUsage, SamplerId, TextureUsage, TextureViewDimension,
+        TextureViewId,
+    },
+    util::{SampleCount, ShaderStageFlags, TextureFormat},
+};
+use khora_core::renderer::error::RenderError;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_data::ecs::PostProcess;
+use khora_data::render::RenderWorld;
+
+use super::render_target::{create_linear_sampler, RenderTarget};
+
+/// Format of the HDR scene target the scene lanes render into.
+pub const HDR_SCENE_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+
+/// Estimated cost of compositing one pixel.
+const PIXEL_COST: f32 = 0.000_000_2;
+
+/// Composite parameters, matching `Params` in `tonemap.wgsl`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+struct ToneMapParams {
+    tone_mapping: u32,
+    inv_gamma: f32,
+    bloom_intensity: f32,
+    vignette_intensity: f32,
+    vignette_radius: f32,
+    vignette_smoothness: f32,
+    _pad: [f32; 2],
+}
+
+impl ToneMapParams {
+    /// Parameters for `settings`; bloom only counts when a bloom target was
+    /// rendered this frame.
+    fn new(settings: &PostProcess, bloomed: bool) -> Self {
+        let vignette = settings.vignette.unwrap_or_default();
+        Self {
+            tone_mapping: settings.tone_mapping.shader_index(),
+            inv_gamma: 1.0 / settings.gamma.max(0.1),
+            bloom_intensity: settings
+                .bloom
+                .filter(|_| bloomed)
+                .map_or(0.0, |b| b.intensity),
+            vignette_intensity: settings.vignette.map_or(0.0, |v| v.intensity),
+            vignette_radius: vignette.radius,
+            vignette_smoothness: vignette.smoothness,
+            _pad: [0.0; 2],
+        }
+    }
+}
+
+/// HDR view, exposure buffer and bloom view a composite bind group reads.
+type CompositeInputs = (TextureViewId, BufferId, TextureViewId);
+
+/// GPU state of the lane, built in `on_initialize`.
+struct ToneMapGpu {
+    layout: BindGroupLayoutId,
+    pipeline: RenderPipelineId,
+    sampler: SamplerId,
+    params_buffer: BufferId,
+    /// Exposure of one, read when no auto-exposure ran.
+    unit_exposure: BufferId,
+    /// 1x1 black texture, read when no bloom ran.
+    no_bloom: RenderTarget,
+    target: Option<RenderTarget>,
+    bind_group: Option<(CompositeInputs, BindGroupId)>,
+}
+
+/// A post-processing lane owning the HDR scene target and mapping it to
+/// the display.
+///
+/// Driven by the first view's [`PostProcess`] settings, or their defaults
+/// (Reinhard, gamma 2.2, no vignette) when the camera only carries
+/// [`AutoExposure`](khora_data::ecs::AutoExposure).
+#[derive(Default)]
+pub struct ToneMapLane {
+    gpu: Mutex<Option<ToneMapGpu>>,
+}
+
+impl ToneMapLane {
+    /// Creates a new `ToneMapLane`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Lane for ToneMapLane {
+    fn strategy_name(&self) -> &'static str {
+        "ToneMapping"
+    }
+
+    fn lane_kind(&self) -> LaneKind {
+        LaneKind::Render
+    }
+
+    fn estimate_cost(&self, ctx: &LaneContext) -> f32 {
+        match ctx.get::<TargetSize>() {
+            Some(size) => (size.0.width * size.0.height) as f32 * PIXEL_COST,
+            None => 0.2,
+        }
+    }
+
+    fn on_initialize(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?;
+        let gpu = ToneMapGpu::new(device.as_ref())
+            .map_err(|e| LaneError::InitializationFailed(Box::new(e)))?;
+        if let Ok(mut slot) = self.gpu.lock() {
+            *slot = Some(gpu);
+        }
+        Ok(())
+    }
+
+    fn execute(&self, ctx: &mut LaneContext) -> Result<(), LaneError> {
+        let stage = *ctx
+            .get::<PostStage>()
+            .ok_or(LaneError::missing("PostStage"))?;
+        let device = ctx
+            .get::<Arc<dyn GraphicsDevice>>()
+            .ok_or(LaneError::missing("Arc<dyn GraphicsDevice>"))?
+            .clone();
+        let size = ctx
+            .get::<TargetSize>()
+            .ok_or(LaneError::missing("TargetSize"))?
+            .0;
+
+        let Ok(mut gpu_lock) = self.gpu.lock() else {
+            return Err(LaneError::NotInitialized);
+        };
+        let Some(gpu) = gpu_lock.as_mut() else {
+            return Err(LaneError::NotInitialized);
+        };
+
+        match stage {
+            PostStage::Prepare => {
+                if size.width == 0 || size.height == 0 {
+                    return Ok(());
+                }
+                let view = gpu
+                    .prepare(device.as_ref(), size)
+                    .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))?;
+                ctx.insert(HdrSceneTarget(view));
+                Ok(())
+            }
+            PostStage::Resolve => {
+                let color_target = ctx
+                    .get::<ColorTarget>()
+                    .ok_or(LaneError::missing("ColorTarget"))?
+                    .0;
+                let render_world = ctx
+                    .get::<Ref<RenderWorld>>()
+                    .ok_or(LaneError::missing("Ref<RenderWorld>"))?
+                    .get();
+                let exposure = ctx.get::<ExposureBuffer>().map(|e| e.0);
+                let bloom = ctx.get::<BloomTarget>().map(|b| b.0);
+                let encoder = ctx
+                    .get::<Slot<dyn CommandEncoder>>()
+                    .ok_or(LaneError::missing("Slot<dyn CommandEncoder>"))?
+                    .get();
+                let settings = render_world.post_process.unwrap_or_default();
+                let params = ToneMapParams::new(&settings, bloom.is_some());
+                gpu.resolve(
+                    device.as_ref(),
+                    encoder,
+                    color_target,
+                    size,
+                    exposure,
+                    bloom,
+                    &params,
+                )
+                .map_err(|e| LaneError::ExecutionFailed(Box::new(e)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn on_shutdown(&self, ctx: &mut LaneContext) {
+        let Some(device) = ctx.get::<Arc<dyn GraphicsDevice>>() else {
+            return;
+        };
+        if let Some(gpu) = self.gpu.lock().ok().and_then(|mut g| g.take()) {
+            gpu.destroy(device.as_ref());
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl ToneMapGpu {
+    fn new(device: &dyn GraphicsDevice) -> Result<Self, RenderError> {
+        use crate::render_lane::shaders::TONEMAP_WGSL;
+
+        let texture = |binding, filterable| BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStageFlags::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+        };
+        let layout = device
+            .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                label: Some("tonemap_layout"),
+                entries: &[
+                    texture(0, false),
+                    BindGroupLayoutEntry::buffer(
+                        1,
+                        ShaderStageFlags::FRAGMENT,
+                        BufferBindingType::Storage { read_only: true },
+                        false,
+                        None,
+                    ),
+                    texture(2, true),
+                    BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: ShaderStageFlags::FRAGMENT,
+                        ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    },
+                    BindGroupLayoutEntry::buffer(
+                        4,
+                        ShaderStageFlags::FRAGMENT,
+                        BufferBindingType::Uniform,
+                        false,
+                        None,
+                    ),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+
+        let module = device
+            .create_shader_module(&ShaderModuleDescriptor {
+                label: Some("tonemap_shader"),
+                source: ShaderSourceData::Wgsl(Cow::Borrowed(TONEMAP_WGSL)),
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline_layout = device
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                label: Some(Cow::Borrowed("Tonemap Pipeline Layout")),
+                bind_group_layouts: &[layout],
+            })
+            .map_err(RenderError::ResourceError)?;
+        let pipeline = device
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: Some(Cow::Borrowed("Tonemap Pipeline")),
+                layout: Some(pipeline_layout),
+                vertex_shader_module: module,
+                vertex_entry_point: Cow::Borrowed("vs_main"),
+                fragment_shader_module: Some(module),
+                fragment_entry_point: Some(Cow::Borrowed("fs_main")),
+                color_target_states: Cow::Owned(vec![ColorTargetStateDescriptor {
+                    format: device
+                        .get_surface_format()
+                        .unwrap_or(TextureFormat::Rgba8UnormSrgb),
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                }]),
+                vertex_buffers_layout: Cow::Owned(vec![]), // Fullscreen triangle from vertex_index
+                primitive_state: PrimitiveStateDescriptor {
+                    topology: PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil_state: None,
+                multisample_state: MultisampleStateDescriptor {
+                    count: SampleCount::X1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            })
+            .map_err(RenderError::ResourceError)?;
+        let sampler = create_linear_sampler(device, "Tonemap Sampler")?;
+
+        let params_buffer = device
+            .create_buffer(&BufferDescriptor {
+                label: Some("Tonemap Params".into()),
+                size: std::mem::size_of::<ToneMapParams>() as u64,
+                usage: BufferUsage::UNIFORM | BufferUsage::COPY_DST,
+                mapped_at_creation: false,
+            })
+            .map_err(RenderError::ResourceError)?;
+        // Same layout as the auto-exposure state: ev, exposure, initialized.
+        let unit_exposure = device
+            .create_buffer_with_data(
+                &BufferDescriptor {
+                    label: Some("Unit Exposure".into()),
+                    size: 16,
+                    usage: BufferUsage::STORAGE,
+                    mapped_at_creation: false,
+                },
+                bytemuck::cast_slice(&[0.0f32, 1.0, 0.0, 0.0]),
+            )
+            .map_err(RenderError::ResourceError)?;
+        let no_bloom = RenderTarget::new(
+            device,
+            "No Bloom",
+            Extent2D {
+                width: 1,
+                height: 1,
+            },
+            HDR_SCENE_FORMAT,
+            TextureUsage::TEXTURE_BINDING | TextureUsage::COPY_DST,
+            SampleCount::X1,
+        )?;
+        device
+            .write_texture(
+                no_bloom.texture,
+                &[0u8; 8],
+                Some(8),
+                Origin3D { x: 0, y: 0, z: 0 },
+                Extent3D {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+            )
+            .map_err(RenderError::ResourceError)?;
+
+        Ok(Self {
+            layout,
+            pipeline,
+            sampler,
+            params_buffer,
+            unit_exposure,
+            no_bloom,
+            target: None,
+            bind_group: None,
+        })
+    }
+
+    /// Returns the HDR scene target, recreating it when the frame size
+    /// changed.
+    fn prepare(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        size: Extent2D,
+    ) -> Result<TextureViewId, RenderError> {
+        if let Some(target) = self
+            .target
+            .as_ref()
+            .filter(|t| t.matches(size, HDR_SCENE_FORMAT))
+        {
+            return Ok(target.view);
+        }
+        if let Some(old) = self.target.take() {
+            old.destroy(device);
+        }
+        let target = RenderTarget::new(
+            device,
+            "HDR Scene Target",
+            size,
+            HDR_SCENE_FORMAT,
+            TextureUsage::RENDER_ATTACHMENT | TextureUsage::TEXTURE_BINDING,
+            SampleCount::X1,
+        )?;
+        let view = target.view;
+        self.target = Some(target);
+        Ok(view)
+    }
+
+    /// Returns the composite bind group reading `inputs`, rebuilding it
+    /// when one of them changed.
+    fn bind_group(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        inputs: CompositeInputs,
+    ) -> Result<BindGroupId, RenderError> {
+        if let Some((_, bind_group)) = self.bind_group.filter(|(cached, _)| *cached == inputs) {
+            return Ok(bind_group);
+        }
+        if let Some((_, old)) = self.bind_group.take() {
+            if let Err(e) = device.destroy_bind_group(old) {
+                log::warn!("ToneMapLane: Failed to destroy bind group: {:?}", e);
+            }
+        }
+        let (hdr, exposure, bloom) = inputs;
+        let bind_group = device
+            .create_bind_group(&BindGroupDescriptor {
+                label: Some("tonemap_bind_group"),
+                layout: self.layout,
+                entries: &[
+                    BindGroupEntry {
+                        binding: 0,
+                        resource: BindingResource::TextureView(hdr),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry::buffer(1, exposure, 0, None),
+                    BindGroupEntry {
+                        binding: 2,
+                        resource: BindingResource::TextureView(bloom),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry {
+                        binding: 3,
+                        resource: BindingResource::Sampler(self.sampler),
+                        _phantom: std::marker::PhantomData,
+                    },
+                    BindGroupEntry::buffer(4, self.params_buffer, 0, None),
+                ],
+            })
+            .map_err(RenderError::ResourceError)?;
+        self.bind_group = Some((inputs, bind_group));
+        Ok(bind_group)
+    }
+
+    /// Composites the HDR target into `color_target`. Does nothing if no
+    /// target was prepared at this size, as the scene was then drawn
+    /// straight to the color target.
+    #[allow(clippy::too_many_arguments)]
+    fn resolve(
+        &mut self,
+        device: &dyn GraphicsDevice,
+        encoder: &mut dyn CommandEncoder,
+        color_target: TextureViewId,
+        size: Extent2D,
+        exposure: Option<BufferId>,
+        bloom: Option<TextureViewId>,
+        params: &ToneMapParams,
+    ) -> Result<(), RenderError> {
+        let Some(hdr) = self
+            .target
+            .as_ref()
+            .filter(|t| t.size == size)
+            .map(|t| t.view)
+        else {
+            return Ok(());
+        };
+        let inputs = (
+            hdr,
+            exposure.unwrap_or(self.unit_exposure),
+            bloom.unwrap_or(self.no_bloom.view),
+        );
+        let bind_group = self.bind_group(device, inputs)?;
+        device
+            .write_buffer(self.params_buffer, 0, bytemuck::bytes_of(params))
+            .map_err(RenderError::ResourceError)?;
+
+        let color_attachment = RenderPassColorAttachment {
+            view: &color_target,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Clear(LinearRgba::new(0.0, 0.0, 0.0, 1.0)),
+                store: StoreOp::Store,
+            },
+            base_array_layer: 0,
+        };
+        let render_pass_desc = RenderPassDescriptor {
+            label: Some("Tonemap Pass"),
+            color_attachments: &[color_attachment],
+            depth_stencil_attachment: None,
+        };
+        let mut pass = encoder.begin_render_pass(&render_pass_desc);
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.draw(0..3, 0..1);
+        Ok(())
+    }
+
+    fn destroy(self, device: &dyn GraphicsDevice) {
+        if let Some((_, bind_group)) = self.bind_group {
+            if let Err(e) = device.destroy_bind_group(bind_group) {
+                log::warn!("ToneMapLane: Failed to destroy bind group: {:?}", e);
+            }
+        }
+        if let Some(target) = self.target {
+            target.destroy(device);
+        }
+        self.no_bloom.destroy(device);
+        if let Err(e) = device.destroy_render_pipeline(self.pipeline) {
+            log::warn!("ToneMapLane: Failed to destroy pipeline: {:?}", e);
+        }
+        if let Err(e) = device.destroy_sampler(self.sampler) {
+            log::warn!("ToneMapLane: Failed to destroy sampler: {:?}", e);
+        }
+        for buffer in [self.params_buffer, self.unit_exposure] {
+            if let Err(e) = device.destroy_buffer(buffer) {
+                log::warn!("ToneMapLane: Failed to destroy buffer: {:?}", e);
+            }
+        }
+        if let Err(e) = device.destroy_bind_group_layout(self.layout) {
+            log::warn!("ToneMapLane: Failed to destroy layout: {:?}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::renderer::api::core::ToneMapping;
+    use khora_data::ecs::{Bloom, Vignette};
+
+    #[test]
+    fn test_params_disable_missing_effects() {
+        let settings = PostProcess::default().with_tone_mapping(ToneMapping::Aces);
+        let params = ToneMapParams::new(&settings, false);
+        assert_eq!(params.tone_mapping, ToneMapping::Aces.shader_index());
+        assert_eq!(params.bloom_intensity, 0.0);
+        assert_eq!(params.vignette_intensity, 0.0);
+        assert!((params.inv_gamma - 1.0 / 2.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_params_carry_enabled_effects() {
+        let bloom = Bloom {
+            intensity: 0.3,
+            ..Default::default()
+        };
+        let settings = PostProcess::default()
+            .with_bloom(bloom)
+            .with_vignette(Vignette::default());
+        let params = ToneMapParams::new(&settings, true);
+        assert_eq!(params.bloom_intensity, 0.3);
+        assert_eq!(params.vignette_intensity, Vignette::default().intensity);
+        assert_eq!(params.vignette_radius, Vignette::default().radius);
+    }
+}
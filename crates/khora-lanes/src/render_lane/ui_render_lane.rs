@@ -20,7 +20,7 @@ use std::sync::{Arc, Mutex};
 
 use crate::render_lane::shaders::UI_WGSL;
 use khora_core::lane::{Lane, LaneContext, LaneError, LaneKind, Ref, Slot};
-use khora_core::math::{Mat4, Vec4};
+use khora_core::math::{Mat4, Vec2, Vec4};
 use khora_core::renderer::api::command::{
     BindGroupDescriptor, BindGroupEntry, BindGroupId, BindGroupLayoutDescriptor,
     BindGroupLayoutEntry, BindGroupLayoutId, BindingResource, BindingType, BufferBinding,
@@ -370,16 +370,38 @@ impl Lane for UiRenderLane {
             let (uv_min, uv_max) = node
                 .image
                 .and_then(|img| atlas_map.get(&img.texture))
-                .map(|rect| (rect.min.into(), rect.max.into()))
-                .unwrap_or(([0.0, 0.0], [1.0, 1.0]));
+                .map(|rect| (rect.min, rect.max))
+                .unwrap_or((Vec2::ZERO, Vec2::ONE));
+
+            // Nine-sliced images are drawn as nine plain textured quads;
+            // the frame comes from the image, not from the SDF border.
+            if let (Some(slice), Some(_)) = (node.nine_slice, node.image) {
+                for patch in slice.patches(node.pos, node.size, uv_min, uv_max) {
+                    if instances.len() >= self.max_instances {
+                        break;
+                    }
+                    if patch.size.x <= 0.0 || patch.size.y <= 0.0 {
+                        continue;
+                    }
+                    instances.push(UiInstanceData {
+                        pos: patch.pos.into(),
+                        size: patch.size.into(),
+                        color: color_vec.into(),
+                        params: [0.0, 0.0, has_texture, 0.0],
+                        uv_min: patch.uv_min.into(),
+                        uv_max: patch.uv_max.into(),
+                    });
+                }
+                continue;
+            }
 
             instances.push(UiInstanceData {
                 pos: node.pos.into(),
                 size: node.size.into(),
                 color: color_vec.into(),
                 params: [border_radius, border_width, has_texture, 0.0],
-                uv_min,
-                uv_max,
+                uv_min: uv_min.into(),
+                uv_max: uv_max.into(),
             });
         }
 
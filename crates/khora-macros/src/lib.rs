@@ -39,6 +39,11 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields};
 /// Use `#[component(skip)]` on fields that should not be serialized
 /// (e.g., GPU handles). Those fields are filled with `Default::default()`
 /// when deserializing back.
+///
+/// Fields of type `EntityRef`, `Option<EntityRef>`, `Vec<EntityRef>` or
+/// `[EntityRef; N]` are rewritten when a scene load or a world merge gives
+/// entities new IDs. Mark other fields implementing `MapEntities` with
+/// `#[component(entities)]` to get the same treatment.
 #[proc_macro_derive(Component, attributes(component))]
 pub fn derive_component(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -162,6 +167,34 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         })
         .collect();
 
+    // Fields holding entity references, skipped ones included: a merge
+    // moves them as they are.
+    let entity_fields: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| mentions_entity_ref(&f.ty) || has_component_flag(&f.attrs, "entities"))
+        .map(|(index, f)| match &f.ident {
+            Some(fname) => quote! { #fname },
+            None => {
+                let index = syn::Index::from(index);
+                quote! { #index }
+            }
+        })
+        .collect();
+    let map_entities = if entity_fields.is_empty() {
+        quote! { None }
+    } else {
+        quote! {
+            Some(|world: &mut crate::ecs::World,
+                  entity: khora_core::ecs::entity::EntityId,
+                  map: &crate::ecs::EntityMap| {
+                if let Some(component) = world.get_mut::<#name>(entity) {
+                    #(crate::ecs::MapEntities::map_entities(&mut component.#entity_fields, map);)*
+                }
+            })
+        }
+    };
+
     let all_from_fields: Vec<_> = from_serializable_included
         .into_iter()
         .chain(from_serializable_skipped)
@@ -289,13 +322,15 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 },
                 deserialize_recipe: |world, entity, data| {
                     let (s, _): (#serializable_name, _) = bincode::decode_from_slice_with_context(
-                        data, bincode::config::standard(), ()
+                        data,
+                        bincode::config::standard().with_limit::<{ crate::scene::SCENE_DECODE_LIMIT }>(),
+                        ()
                     ).map_err(|e| e.to_string())?;
-                    world.add_component(entity, <#name>::from(s)).ok();
+                    world.insert_component(entity, <#name>::from(s)).ok();
                     Ok(())
                 },
                 create_default: |world, entity| {
-                    world.add_component(entity, <#name>::default()).ok();
+                    world.insert_component(entity, <#name>::default()).ok();
                     Ok(())
                 },
                 to_json: |world, entity| {
@@ -323,9 +358,47 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                         Err(e) => Err(format!("{:?}", e)),
                     }
                 },
+                map_entities: #map_entities,
             }
         }
     };
 
     TokenStream::from(expanded)
 }
+
+/// Returns `true` if a `#[component(...)]` attribute carries `flag`.
+fn has_component_flag(attrs: &[syn::Attribute], flag: &str) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("component") {
+            return false;
+        }
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(flag) {
+                found = true;
+            }
+            Ok(())
+        });
+        found
+    })
+}
+
+/// Returns `true` if `ty` is `EntityRef` or wraps it in generic arguments
+/// or an array, such as `Option<EntityRef>` or `[EntityRef; 4]`.
+fn mentions_entity_ref(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.iter().any(|segment| {
+            if segment.ident == "EntityRef" {
+                return true;
+            }
+            let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return false;
+            };
+            args.args
+                .iter()
+                .any(|arg| matches!(arg, syn::GenericArgument::Type(ty) if mentions_entity_ref(ty)))
+        }),
+        syn::Type::Array(array) => mentions_entity_ref(&array.elem),
+        _ => false,
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `Archive` — VFS queries over a packed asset archive.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use khora_core::asset::{ArchiveInfo, AssetSource, AssetUUID, PlatformTarget};
+use khora_io::vfs::VirtualFileSystem;
+
+use crate::asset_summary::AssetSummary;
+
+/// The index of a packed archive, with any patches mounted over it.
+///
+/// Opens the directory written by `xtask assets pack`: `index.bin`, and
+/// `archive.bin` when present. The packfile itself is not read.
+pub struct Archive {
+    vfs: VirtualFileSystem,
+}
+
+impl Archive {
+    /// Opens the archive in `dir`.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref();
+        let index = read_index(dir)?;
+        let info = read_info(dir)?;
+        let vfs = VirtualFileSystem::versioned(&index, info)
+            .with_context(|| format!("Failed to decode the index in '{}'", dir.display()))?;
+        Ok(Self { vfs })
+    }
+
+    /// Mounts the patch archive in `dir` over the archives opened so far.
+    pub fn mount_patch(&mut self, dir: impl AsRef<Path>) -> Result<()> {
+        let dir = dir.as_ref();
+        let index = read_index(dir)?;
+        let info = read_info(dir)?;
+        self.vfs
+            .mount_patch(&index, info)
+            .map_err(|e| anyhow!("Failed to mount patch '{}': {:?}", dir.display(), e))?;
+        Ok(())
+    }
+
+    /// Returns the content version with every patch applied.
+    pub fn version(&self) -> u32 {
+        self.vfs.version()
+    }
+
+    /// Sets the platform and tier [`resolve`](Self::resolve) picks variants for.
+    pub fn set_target(&mut self, target: PlatformTarget) {
+        self.vfs.set_target(target);
+    }
+
+    /// Returns the number of assets.
+    pub fn len(&self) -> usize {
+        self.vfs.asset_count()
+    }
+
+    /// Returns `true` if the archive holds no asset.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns every asset, sorted by source path.
+    pub fn assets(&self) -> Vec<AssetSummary> {
+        let mut assets: Vec<AssetSummary> = self.vfs.iter_all().map(Into::into).collect();
+        assets.sort_by(|a, b| a.source_path.cmp(&b.source_path));
+        assets
+    }
+
+    /// Returns an asset by UUID.
+    pub fn get(&self, uuid: &AssetUUID) -> Option<AssetSummary> {
+        self.vfs.get_metadata(uuid).map(Into::into)
+    }
+
+    /// Returns the asset packed from `source_path`.
+    pub fn find_by_path(&self, source_path: &str) -> Option<AssetSummary> {
+        self.vfs
+            .iter_all()
+            .find(|metadata| metadata.source_path == Path::new(source_path))
+            .map(Into::into)
+    }
+
+    /// Returns the variant the engine would load for the current target,
+    /// and the bytes it would read, patch deltas included.
+    pub fn resolve(&self, uuid: &AssetUUID) -> Option<(String, u64)> {
+        let resolved = self.vfs.resolve(uuid)?;
+        let (key, _) = resolved.metadata.select_variant(self.vfs.target())?;
+        let bytes = resolved
+            .chain
+            .iter()
+            .map(|(_, source)| match source {
+                AssetSource::Packed { size, .. } | AssetSource::Delta { size, .. } => *size,
+                AssetSource::Path(path) => std::fs::metadata(path).map_or(0, |m| m.len()),
+            })
+            .sum();
+        Some((key, bytes))
+    }
+}
+
+fn read_index(dir: &Path) -> Result<Vec<u8>> {
+    let path = dir.join("index.bin");
+    std::fs::read(&path).with_context(|| format!("Failed to read '{}'", path.display()))
+}
+
+/// Reads `archive.bin`, treating an archive without one as version 0.
+fn read_info(dir: &Path) -> Result<ArchiveInfo> {
+    let path = dir.join("archive.bin");
+    if !path.exists() {
+        return Ok(ArchiveInfo::full(0));
+    }
+    let bytes =
+        std::fs::read(&path).with_context(|| format!("Failed to read '{}'", path.display()))?;
+    let (info, _) = bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
+        .with_context(|| format!("Failed to decode '{}'", path.display()))?;
+    Ok(info)
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `AssetSummary` — one asset of an archive index, in plain values.
+
+use khora_core::asset::{AssetMetadata, AssetUUID};
+
+/// What the index of an archive says about one asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetSummary {
+    /// Stable identifier of the asset.
+    pub uuid: AssetUUID,
+    /// Path of the source file, relative to the project.
+    pub source_path: String,
+    /// Asset type, e.g. `texture` or `mesh`.
+    pub type_name: String,
+    /// Assets this one depends on.
+    pub dependencies: Vec<AssetUUID>,
+    /// Keys of the packed variants, sorted.
+    pub variants: Vec<String>,
+    /// Semantic tags.
+    pub tags: Vec<String>,
+}
+
+impl From<&AssetMetadata> for AssetSummary {
+    fn from(metadata: &AssetMetadata) -> Self {
+        let mut variants: Vec<String> = metadata.variants.keys().cloned().collect();
+        variants.sort();
+        Self {
+            uuid: metadata.uuid,
+            source_path: metadata.source_path.to_string_lossy().into_owned(),
+            type_name: metadata.asset_type_name.clone(),
+            dependencies: metadata.dependencies.clone(),
+            variants,
+            tags: metadata.tags.clone(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Khora Python bindings
+//!
+//! Scripting access to the offline parts of the engine, for batch jobs
+//! written by technical artists and for test fixtures:
+//!
+//! - [`AssetPipeline`] runs the `xtask` asset packer and patcher.
+//! - [`SceneDocument`] creates, edits and saves scene files; [`SceneInfo`]
+//!   inspects one without keeping it.
+//! - [`Archive`] queries the VFS index of a packed archive.
+//!
+//! The Rust API is always built. The `python` feature adds the PyO3 module,
+//! imported as `khora`; build it with `maturin develop` from this crate.
+
+#![warn(missing_docs)]
+
+mod archive;
+mod asset_summary;
+mod pipeline;
+#[cfg(feature = "python")]
+mod python;
+mod scene_document;
+mod scene_info;
+
+pub use archive::*;
+pub use asset_summary::*;
+pub use pipeline::*;
+pub use scene_document::*;
+pub use scene_info::*;
@@ -0,0 +1,94 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `AssetPipeline` — runs the `xtask` asset commands of a project.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use khora_core::asset::AssetPlatform;
+
+/// Runs `cargo xtask assets …` in a project checkout.
+///
+/// The packer lives in `xtask`, so the bindings drive it the way a
+/// developer would rather than linking it. `cargo` is taken from the
+/// `CARGO` environment variable when set.
+pub struct AssetPipeline {
+    project_root: PathBuf,
+}
+
+impl AssetPipeline {
+    /// Creates a pipeline for the project at `project_root`.
+    pub fn new(project_root: impl Into<PathBuf>) -> Self {
+        Self {
+            project_root: project_root.into(),
+        }
+    }
+
+    /// Returns the directory `pack` writes to.
+    pub fn output_dir(&self, platform: Option<AssetPlatform>) -> PathBuf {
+        let dir = self.project_root.join(".dist/assets");
+        match platform {
+            Some(platform) => dir.join(platform.as_str()),
+            None => dir,
+        }
+    }
+
+    /// Packs every asset of the manifest into a full archive.
+    pub fn pack(&self, platform: Option<AssetPlatform>, version: u32) -> Result<()> {
+        let mut args: Vec<OsString> = vec!["pack".into(), "--version".into()];
+        args.push(version.to_string().into());
+        push_platform(&mut args, platform);
+        self.run(args)
+    }
+
+    /// Builds a patch archive against the full archive in `base`.
+    pub fn patch(
+        &self,
+        base: &Path,
+        platform: Option<AssetPlatform>,
+        version: Option<u32>,
+    ) -> Result<()> {
+        let mut args: Vec<OsString> = vec!["patch".into(), "--base".into(), base.into()];
+        push_platform(&mut args, platform);
+        if let Some(version) = version {
+            args.push("--version".into());
+            args.push(version.to_string().into());
+        }
+        self.run(args)
+    }
+
+    fn run(&self, args: Vec<OsString>) -> Result<()> {
+        let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let status = Command::new(cargo)
+            .current_dir(&self.project_root)
+            .args(["xtask", "assets"])
+            .args(&args)
+            .status()
+            .context("Failed to run cargo xtask")?;
+        if !status.success() {
+            bail!("cargo xtask assets {:?} failed with {}", args, status);
+        }
+        Ok(())
+    }
+}
+
+fn push_platform(args: &mut Vec<OsString>, platform: Option<AssetPlatform>) {
+    if let Some(platform) = platform {
+        args.push("--platform".into());
+        args.push(platform.as_str().into());
+    }
+}
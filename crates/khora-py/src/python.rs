@@ -0,0 +1,270 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `khora` Python module.
+//!
+//! Thin PyO3 wrappers over the Rust API of this crate. Entities cross the
+//! boundary as `(index, generation)` tuples, transforms as
+//! `(translation, rotation, scale)` tuples and assets as dicts.
+
+use std::path::PathBuf;
+
+use khora_core::asset::{AssetPlatform, AssetUUID, PlatformTarget};
+use khora_core::ecs::entity::EntityId;
+use khora_core::math::{Quaternion, Vec3};
+use khora_data::ecs::Transform;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{
+    parse_serialization_goal, Archive, AssetPipeline, AssetSummary, SceneDocument, SceneInfo,
+};
+
+type PyEntity = (u32, u32);
+type PyTransform = ((f32, f32, f32), (f32, f32, f32, f32), (f32, f32, f32));
+
+fn runtime_error(error: anyhow::Error) -> PyErr {
+    PyRuntimeError::new_err(format!("{error:#}"))
+}
+
+fn entity(id: PyEntity) -> EntityId {
+    EntityId {
+        index: id.0,
+        generation: id.1,
+    }
+}
+
+fn to_transform((t, r, s): PyTransform) -> Transform {
+    Transform::new(
+        Vec3::new(t.0, t.1, t.2),
+        Quaternion::new(r.0, r.1, r.2, r.3),
+        Vec3::new(s.0, s.1, s.2),
+    )
+}
+
+fn from_transform(transform: Transform) -> PyTransform {
+    let (t, r, s) = (transform.translation, transform.rotation, transform.scale);
+    ((t.x, t.y, t.z), (r.x, r.y, r.z, r.w), (s.x, s.y, s.z))
+}
+
+fn platform(name: Option<&str>) -> PyResult<Option<AssetPlatform>> {
+    name.map(|name| name.parse().map_err(PyValueError::new_err))
+        .transpose()
+}
+
+fn uuid(text: &str) -> PyResult<AssetUUID> {
+    text.parse()
+        .map_err(|e| PyValueError::new_err(format!("Invalid asset UUID '{text}': {e}")))
+}
+
+fn asset_dict<'py>(py: Python<'py>, asset: &AssetSummary) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("uuid", asset.uuid.to_string())?;
+    dict.set_item("source_path", &asset.source_path)?;
+    dict.set_item("type", &asset.type_name)?;
+    let dependencies: Vec<String> = asset.dependencies.iter().map(|d| d.to_string()).collect();
+    dict.set_item("dependencies", dependencies)?;
+    dict.set_item("variants", &asset.variants)?;
+    dict.set_item("tags", &asset.tags)?;
+    Ok(dict)
+}
+
+/// A scene being created or edited.
+#[pyclass(name = "Scene", unsendable)]
+struct PyScene {
+    document: SceneDocument,
+}
+
+#[pymethods]
+impl PyScene {
+    #[new]
+    fn new() -> Self {
+        Self {
+            document: SceneDocument::new(),
+        }
+    }
+
+    #[staticmethod]
+    fn open(path: PathBuf) -> PyResult<Self> {
+        let document = SceneDocument::open(path).map_err(runtime_error)?;
+        Ok(Self { document })
+    }
+
+    #[pyo3(signature = (path, goal = "long_term_stability"))]
+    fn save(&self, path: PathBuf, goal: &str) -> PyResult<()> {
+        let goal = parse_serialization_goal(goal)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown serialization goal '{goal}'")))?;
+        self.document.save(path, goal).map_err(runtime_error)
+    }
+
+    #[pyo3(signature = (name = None, transform = None))]
+    fn spawn(&mut self, name: Option<&str>, transform: Option<PyTransform>) -> PyEntity {
+        let transform = transform.map_or_else(Transform::identity, to_transform);
+        let id = self.document.spawn(name, transform);
+        (id.index, id.generation)
+    }
+
+    fn despawn(&mut self, id: PyEntity) -> bool {
+        self.document.despawn(entity(id))
+    }
+
+    fn entities(&self) -> Vec<PyEntity> {
+        self.document
+            .entities()
+            .into_iter()
+            .map(|id| (id.index, id.generation))
+            .collect()
+    }
+
+    fn name(&self, id: PyEntity) -> Option<String> {
+        self.document.name(entity(id)).map(str::to_owned)
+    }
+
+    fn find(&self, name: &str) -> Option<PyEntity> {
+        self.document.find(name).map(|id| (id.index, id.generation))
+    }
+
+    fn transform(&self, id: PyEntity) -> Option<PyTransform> {
+        self.document.transform(entity(id)).map(from_transform)
+    }
+
+    fn set_transform(&mut self, id: PyEntity, transform: PyTransform) -> bool {
+        self.document
+            .set_transform(entity(id), to_transform(transform))
+    }
+
+    fn __len__(&self) -> usize {
+        self.document.entities().len()
+    }
+}
+
+/// A packed asset archive, with any patches mounted over it.
+#[pyclass(name = "Archive", unsendable)]
+struct PyArchive {
+    archive: Archive,
+}
+
+#[pymethods]
+impl PyArchive {
+    #[new]
+    fn new(dir: PathBuf) -> PyResult<Self> {
+        let archive = Archive::open(dir).map_err(runtime_error)?;
+        Ok(Self { archive })
+    }
+
+    fn mount_patch(&mut self, dir: PathBuf) -> PyResult<()> {
+        self.archive.mount_patch(dir).map_err(runtime_error)
+    }
+
+    #[getter]
+    fn version(&self) -> u32 {
+        self.archive.version()
+    }
+
+    #[pyo3(signature = (platform, tier = None))]
+    fn set_target(&mut self, platform: &str, tier: Option<String>) -> PyResult<()> {
+        let platform: AssetPlatform = platform.parse().map_err(PyValueError::new_err)?;
+        let mut target = PlatformTarget::new(platform);
+        if let Some(tier) = tier {
+            target = target.with_tier(tier);
+        }
+        self.archive.set_target(target);
+        Ok(())
+    }
+
+    fn assets<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.archive
+            .assets()
+            .iter()
+            .map(|asset| asset_dict(py, asset))
+            .collect()
+    }
+
+    fn get<'py>(&self, py: Python<'py>, uuid_text: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+        self.archive
+            .get(&uuid(uuid_text)?)
+            .map(|asset| asset_dict(py, &asset))
+            .transpose()
+    }
+
+    fn find_by_path<'py>(
+        &self,
+        py: Python<'py>,
+        source_path: &str,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        self.archive
+            .find_by_path(source_path)
+            .map(|asset| asset_dict(py, &asset))
+            .transpose()
+    }
+
+    fn resolve(&self, uuid_text: &str) -> PyResult<Option<(String, u64)>> {
+        Ok(self.archive.resolve(&uuid(uuid_text)?))
+    }
+
+    fn __len__(&self) -> usize {
+        self.archive.len()
+    }
+}
+
+/// Packs the assets of the project at `project_root`.
+#[pyfunction]
+#[pyo3(signature = (project_root, platform = None, version = 0))]
+fn pack_assets(project_root: PathBuf, platform: Option<&str>, version: u32) -> PyResult<PathBuf> {
+    let platform = self::platform(platform)?;
+    let pipeline = AssetPipeline::new(project_root);
+    pipeline.pack(platform, version).map_err(runtime_error)?;
+    Ok(pipeline.output_dir(platform))
+}
+
+/// Builds a patch archive against the full archive in `base`.
+#[pyfunction]
+#[pyo3(signature = (project_root, base, platform = None, version = None))]
+fn patch_assets(
+    project_root: PathBuf,
+    base: PathBuf,
+    platform: Option<&str>,
+    version: Option<u32>,
+) -> PyResult<()> {
+    let platform = self::platform(platform)?;
+    AssetPipeline::new(project_root)
+        .patch(&base, platform, version)
+        .map_err(runtime_error)
+}
+
+/// Summarizes the scene file at `path`.
+#[pyfunction]
+fn inspect_scene(py: Python<'_>, path: PathBuf) -> PyResult<Bound<'_, PyDict>> {
+    let info = SceneInfo::inspect(path).map_err(runtime_error)?;
+    let dict = PyDict::new(py);
+    dict.set_item("strategy", info.strategy)?;
+    dict.set_item("format_version", info.format_version)?;
+    dict.set_item("payload_bytes", info.payload_bytes)?;
+    dict.set_item("entity_count", info.entity_count)?;
+    dict.set_item("author", info.metadata.author)?;
+    dict.set_item("description", info.metadata.description)?;
+    dict.set_item("flags", info.metadata.flags)?;
+    Ok(dict)
+}
+
+#[pymodule]
+fn khora(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyScene>()?;
+    m.add_class::<PyArchive>()?;
+    m.add_function(wrap_pyfunction!(pack_assets, m)?)?;
+    m.add_function(wrap_pyfunction!(patch_assets, m)?)?;
+    m.add_function(wrap_pyfunction!(inspect_scene, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `SceneDocument` — a scene file opened for scripting.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use khora_core::ecs::entity::EntityId;
+use khora_core::scene::{SceneFile, SerializationGoal};
+use khora_data::ecs::{GlobalTransform, Name, Transform, World};
+use khora_io::serialization::SerializationService;
+
+/// A scene held in memory while a script builds or edits it.
+///
+/// Entities carry a [`Transform`], a matching [`GlobalTransform`] and an
+/// optional [`Name`]; anything else a loaded scene holds is kept as is
+/// and saved back.
+pub struct SceneDocument {
+    world: World,
+}
+
+impl SceneDocument {
+    /// Creates an empty scene.
+    pub fn new() -> Self {
+        Self {
+            world: World::new(),
+        }
+    }
+
+    /// Reads the scene file at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .with_context(|| format!("Failed to read scene file '{}'", path.display()))?;
+        Self::from_bytes(&bytes)
+            .with_context(|| format!("Failed to load scene file '{}'", path.display()))
+    }
+
+    /// Decodes a scene from the bytes of a scene file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let file =
+            SceneFile::from_bytes(bytes).map_err(|e| anyhow!("Invalid scene file: {:?}", e))?;
+        let mut world = World::new();
+        SerializationService::new()
+            .load_world(&file, &mut world)
+            .map_err(|e| anyhow!("Failed to deserialize scene: {:?}", e))?;
+        Ok(Self { world })
+    }
+
+    /// Encodes the scene with the strategy suited to `goal`.
+    pub fn to_bytes(&self, goal: SerializationGoal) -> Result<Vec<u8>> {
+        let file = SerializationService::new()
+            .save_world(&self.world, goal)
+            .map_err(|e| anyhow!("Failed to serialize scene: {:?}", e))?;
+        Ok(file.to_bytes())
+    }
+
+    /// Writes the scene to `path`.
+    pub fn save(&self, path: impl AsRef<Path>, goal: SerializationGoal) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_bytes(goal)?)
+            .with_context(|| format!("Failed to write scene file '{}'", path.display()))
+    }
+
+    /// Spawns an entity at `transform`, named `name` if given.
+    pub fn spawn(&mut self, name: Option<&str>, transform: Transform) -> EntityId {
+        let global = GlobalTransform::new(transform.to_mat4());
+        match name {
+            Some(name) => self.world.spawn((transform, global, Name::new(name))),
+            None => self.world.spawn((transform, global)),
+        }
+    }
+
+    /// Despawns an entity. Returns `false` if it was not alive.
+    pub fn despawn(&mut self, entity: EntityId) -> bool {
+        self.world.despawn(entity)
+    }
+
+    /// Returns the live entities.
+    pub fn entities(&self) -> Vec<EntityId> {
+        self.world.iter_entities().collect()
+    }
+
+    /// Returns the name of an entity.
+    pub fn name(&self, entity: EntityId) -> Option<&str> {
+        self.world.get::<Name>(entity).map(|name| name.0.as_str())
+    }
+
+    /// Returns the first entity with this name.
+    pub fn find(&self, name: &str) -> Option<EntityId> {
+        self.world
+            .iter_entities()
+            .find(|&entity| self.name(entity) == Some(name))
+    }
+
+    /// Returns the local transform of an entity.
+    pub fn transform(&self, entity: EntityId) -> Option<Transform> {
+        self.world.get::<Transform>(entity).copied()
+    }
+
+    /// Replaces the transform of an entity. Returns `false` if it has none.
+    ///
+    /// The global transform is set from it as if the entity had no parent;
+    /// the engine recomputes hierarchies when the scene is loaded.
+    pub fn set_transform(&mut self, entity: EntityId, transform: Transform) -> bool {
+        let Some(local) = self.world.get_mut::<Transform>(entity) else {
+            return false;
+        };
+        *local = transform;
+        if let Some(global) = self.world.get_mut::<GlobalTransform>(entity) {
+            *global = GlobalTransform::new(transform.to_mat4());
+        }
+        true
+    }
+
+    /// Returns the underlying world, for edits the document does not cover.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+}
+
+impl Default for SceneDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses the goal names used by scripts: `fastest_load`,
+/// `smallest_file_size`, `human_readable_debug`, `long_term_stability`,
+/// `editor_interchange` and `external_tools`.
+pub fn parse_serialization_goal(name: &str) -> Option<SerializationGoal> {
+    Some(match name {
+        "fastest_load" => SerializationGoal::FastestLoad,
+        "smallest_file_size" => SerializationGoal::SmallestFileSize,
+        "human_readable_debug" => SerializationGoal::HumanReadableDebug,
+        "long_term_stability" => SerializationGoal::LongTermStability,
+        "editor_interchange" => SerializationGoal::EditorInterchange,
+        "external_tools" => SerializationGoal::ExternalTools,
+        _ => return None,
+    })
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `SceneInfo` — what a scene file holds, read without keeping it.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use khora_core::scene::{SceneFile, SceneMetadata};
+
+use crate::scene_document::SceneDocument;
+
+/// Summary of a scene file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneInfo {
+    /// Serialization strategy the payload was written with, e.g.
+    /// `KH_RECIPE_V1`.
+    pub strategy: String,
+    /// Version of the file header.
+    pub format_version: u8,
+    /// Size of the payload in bytes.
+    pub payload_bytes: u64,
+    /// Number of entities in the scene.
+    pub entity_count: usize,
+    /// Author, description and gameplay flags of the scene.
+    pub metadata: SceneMetadata,
+}
+
+impl SceneInfo {
+    /// Reads and summarizes the scene file at `path`.
+    pub fn inspect(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .with_context(|| format!("Failed to read scene file '{}'", path.display()))?;
+        let file = SceneFile::from_bytes(&bytes)
+            .map_err(|e| anyhow!("Invalid scene file '{}': {:?}", path.display(), e))?;
+        let strategy = String::from_utf8_lossy(&file.header.strategy_id)
+            .trim_end_matches('\0')
+            .to_string();
+        let document = SceneDocument::from_bytes(&bytes)
+            .with_context(|| format!("Failed to load scene file '{}'", path.display()))?;
+
+        Ok(Self {
+            strategy,
+            format_version: file.header.format_version,
+            payload_bytes: file.header.payload_length,
+            entity_count: document.entities().len(),
+            metadata: file.metadata,
+        })
+    }
+}
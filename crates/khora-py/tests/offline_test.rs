@@ -0,0 +1,132 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the Rust API behind the Python bindings.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use khora::{Archive, SceneDocument, SceneInfo};
+use khora_core::asset::{ArchiveInfo, AssetMetadata, AssetSource, AssetUUID};
+use khora_core::math::Vec3;
+use khora_core::scene::SerializationGoal;
+use khora_data::ecs::Transform;
+
+fn write_archive(dir: &Path, assets: &[AssetMetadata], info: Option<ArchiveInfo>) {
+    let config = bincode::config::standard();
+    let index = bincode::serde::encode_to_vec(assets, config).unwrap();
+    std::fs::write(dir.join("index.bin"), index).unwrap();
+    if let Some(info) = info {
+        let info = bincode::serde::encode_to_vec(info, config).unwrap();
+        std::fs::write(dir.join("archive.bin"), info).unwrap();
+    }
+}
+
+fn asset(path: &str, variants: &[(&str, AssetSource)]) -> AssetMetadata {
+    AssetMetadata {
+        uuid: AssetUUID::new_v5(path),
+        source_path: PathBuf::from(path),
+        asset_type_name: "texture".to_string(),
+        dependencies: Vec::new(),
+        variants: variants
+            .iter()
+            .map(|(key, source)| (key.to_string(), source.clone()))
+            .collect::<HashMap<_, _>>(),
+        tags: vec!["ui".to_string()],
+    }
+}
+
+#[test]
+fn test_scene_document_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("level.kscene");
+
+    let mut scene = SceneDocument::new();
+    let player = scene.spawn(
+        Some("Player"),
+        Transform::from_translation(Vec3::new(1.0, 2.0, 3.0)),
+    );
+    let prop = scene.spawn(None, Transform::identity());
+    assert!(scene.despawn(prop));
+    assert!(scene.set_transform(
+        player,
+        Transform::from_translation(Vec3::new(4.0, 5.0, 6.0))
+    ));
+    scene
+        .save(&path, SerializationGoal::LongTermStability)
+        .unwrap();
+
+    let loaded = SceneDocument::open(&path).unwrap();
+    assert_eq!(loaded.entities().len(), 1);
+    let player = loaded.find("Player").expect("named entity survives");
+    assert_eq!(
+        loaded.transform(player).unwrap().translation,
+        Vec3::new(4.0, 5.0, 6.0)
+    );
+
+    let info = SceneInfo::inspect(&path).unwrap();
+    assert_eq!(info.entity_count, 1);
+    assert!(!info.strategy.is_empty());
+    assert!(info.payload_bytes > 0);
+}
+
+#[test]
+fn test_archive_queries_and_patches() {
+    let base = tempfile::tempdir().unwrap();
+    let patch = tempfile::tempdir().unwrap();
+    let packed = |size| AssetSource::Packed { offset: 0, size };
+    write_archive(
+        base.path(),
+        &[
+            asset(
+                "textures/logo.png",
+                &[("default", packed(100)), ("mobile", packed(40))],
+            ),
+            asset("textures/old.png", &[("default", packed(10))]),
+        ],
+        None,
+    );
+    write_archive(
+        patch.path(),
+        &[
+            asset(
+                "textures/logo.png",
+                &[("default", AssetSource::Delta { offset: 0, size: 8 })],
+            ),
+            asset("textures/old.png", &[]),
+        ],
+        Some(ArchiveInfo::patch(0, 1)),
+    );
+
+    let mut archive = Archive::open(base.path()).unwrap();
+    assert_eq!(archive.version(), 0);
+    assert_eq!(archive.len(), 2);
+    let logo = archive.find_by_path("textures/logo.png").unwrap();
+    assert_eq!(logo.variants, vec!["default", "mobile"]);
+    assert_eq!(archive.get(&logo.uuid), Some(logo.clone()));
+
+    archive.mount_patch(patch.path()).unwrap();
+    assert_eq!(archive.version(), 1);
+    assert_eq!(archive.len(), 1, "the patch removes old.png");
+    assert_eq!(
+        archive.resolve(&logo.uuid),
+        Some(("default".to_string(), 108))
+    );
+}
+
+#[test]
+fn test_archive_reports_missing_index() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(Archive::open(dir.path()).is_err());
+}
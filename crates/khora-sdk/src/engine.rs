@@ -20,21 +20,45 @@
 //! The engine owns: DCC, scheduler, telemetry, service registry, frame loop.
 //! The app owns: window, renderer, agents, phases, game logic.
 
-use khora_control::{substrate, DccConfig, DccService, EngineMode};
-use khora_core::lane::{ClearColor, ColorTarget, DepthTarget};
+use khora_control::{substrate, DccService, EngineMode};
+use khora_core::executor::SharedExecutor;
+use khora_core::lane::{ClearColor, ColorTarget, DepthTarget, TargetSize};
+use khora_core::math::Vec2;
+use khora_core::platform::{
+    GpuSurvey, HardwareSurvey, MonitorInfo, PowerEvent, PowerPolicy, PowerState,
+};
+use khora_core::renderer::api::core::DepthMode;
+use khora_core::renderer::api::util::{GpuReadback, SharedReadback};
 use khora_core::renderer::traits::RenderSystem;
 use khora_core::renderer::GraphicsDevice;
+use khora_core::utils::frame_time::{FrameTime, SharedFrameTime};
 use khora_core::ServiceRegistry;
 use khora_data::ecs::TickPhase;
-use khora_data::render::{submit_frame_graph, FrameGraph, SharedFrameGraph};
-use khora_telemetry::TelemetryService;
+use khora_data::render::{
+    submit_frame_graph, EntityPicker, FrameGraph, PickHandle, SharedEntityPicker, SharedFrameGraph,
+};
+use khora_infra::platform::sysinfo_impl::SysinfoMonitor;
+use khora_infra::HardwareHealthMonitor;
+use khora_infra::{LocalExecutor, ThreadPoolExecutor};
+use khora_telemetry::{MetricHistory, TelemetryService};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex, RwLock};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use crate::run_log_dir::RunLogDir;
+use crate::startup::{StartupPhase, StartupProfile};
 use crate::traits::EngineApp;
+use crate::watchdog::Watchdog;
+use crate::worlds::{SharedWorlds, WorldId, Worlds};
 use crate::GameWorld;
 use crate::InputEvent;
+use crate::PluginSet;
+
+/// How long each shutdown step waits for in-flight work before moving on.
+pub const SHUTDOWN_STEP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How often a suspended engine runs its [`background_tick`](EngineCore::background_tick).
+pub const BACKGROUND_TICK_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Well-known viewport handle for the primary 3D viewport.
 pub const PRIMARY_VIEWPORT: khora_core::ui::editor::viewport_texture::ViewportTextureHandle =
@@ -58,6 +82,21 @@ pub struct EngineCore<A: EngineApp> {
     services: Arc<ServiceRegistry>,
     input_events: VecDeque<InputEvent>,
     simulation_started: bool,
+    frame_time: SharedFrameTime,
+    last_tick: Option<Instant>,
+    fixed_delta: Option<Duration>,
+    watchdog: Option<Watchdog>,
+    worlds: SharedWorlds,
+    /// The mode to restore on resume; `Some` while suspended.
+    suspended_mode: Option<EngineMode>,
+    shut_down: bool,
+    startup: StartupProfile,
+    /// When bootstrap ended; `Some` until the first frame completes.
+    first_frame_start: Option<Instant>,
+    executor: Option<SharedExecutor>,
+    /// Power state and policy last picked up from the DCC's governor.
+    power_state: PowerState,
+    power_policy: PowerPolicy,
 }
 
 impl<A: EngineApp> EngineCore<A> {
@@ -73,10 +112,24 @@ impl<A: EngineApp> EngineCore<A> {
                 hardware: khora_control::HardwareState::default(),
                 mode: EngineMode::Playing,
                 global_budget_multiplier: 1.0,
+                power: PowerState::Unrestricted,
+                power_policy: PowerPolicy::UNRESTRICTED,
             })),
             services: Arc::new(ServiceRegistry::new()),
             input_events: VecDeque::new(),
             simulation_started: false,
+            frame_time: Arc::new(RwLock::new(FrameTime::default())),
+            last_tick: None,
+            fixed_delta: None,
+            watchdog: None,
+            worlds: Arc::new(Mutex::new(Worlds::new())),
+            suspended_mode: None,
+            shut_down: false,
+            startup: StartupProfile::new(),
+            first_frame_start: None,
+            executor: None,
+            power_state: PowerState::Unrestricted,
+            power_policy: PowerPolicy::UNRESTRICTED,
         }
     }
 
@@ -86,9 +139,28 @@ impl<A: EngineApp> EngineCore<A> {
     /// This method takes ownership of the `services` registry populated
     /// by the windowing driver's bootstrap closure.  It wraps the registry
     /// in an `Arc` internally once all built-in services have been inserted.
-    pub fn bootstrap(&mut self, mut app: A, mut services: ServiceRegistry) {
+    ///
+    /// Bootstrap is timed as [`StartupPhase::Engine`] in the registry's
+    /// [`StartupProfile`], or in the engine's own if the driver supplied
+    /// none.
+    pub fn bootstrap(&mut self, app: A, mut services: ServiceRegistry) {
+        // The windowing driver may have started the profile before platform
+        // init; otherwise startup is counted from engine creation.
+        if let Some(profile) = services.get::<StartupProfile>() {
+            self.startup = profile.clone();
+        } else {
+            services.insert(self.startup.clone());
+        }
+        let startup = self.startup.clone();
+        startup.time(StartupPhase::Engine, || {
+            self.bootstrap_engine(app, services)
+        });
+        self.first_frame_start = Some(Instant::now());
+    }
+
+    fn bootstrap_engine(&mut self, mut app: A, mut services: ServiceRegistry) {
         // Create DCC + telemetry
-        let (mut dcc, dcc_rx) = DccService::new(DccConfig::default());
+        let (mut dcc, dcc_rx) = DccService::new(A::dcc_config());
         let telemetry =
             TelemetryService::new(Duration::from_secs(1)).with_dcc_sender(dcc.event_sender());
 
@@ -98,14 +170,80 @@ impl<A: EngineApp> EngineCore<A> {
         // internal Arc-shared structures, so doing so before `app.setup` is
         // safe.
         services.insert(telemetry.monitor_registry().clone());
+        // Per-frame metric history: the overlay and profiler correlate
+        // spikes with frames through it.
+        services.insert(telemetry.history().clone());
+        // Wildcard metric subscriptions for dashboards and remote tools.
+        services.insert(telemetry.subscriptions().clone());
         services.insert(dcc.agent_registry().clone());
         // Live DCC context: shared `Arc<RwLock<Context>>` updated by the
         // DCC cold thread, read by observers each frame.
         services.insert(dcc.context_handle());
+        // Console variables: the DCC registered the power governor's
+        // tuning; apps and plugins add their own in `setup`. Values saved
+        // by the last run apply now, or when their variable is registered.
+        if let Some(path) = A::cvar_path() {
+            match dcc.cvars().load(&path) {
+                Ok(count) => log::info!("Loaded {} CVars from {}", count, path.display()),
+                Err(e) => log::warn!("Could not load CVars from {}: {}", path.display(), e),
+            }
+        }
+        services.insert(dcc.cvars().clone());
+        // Named vertex layouts: custom lanes and materials register theirs
+        // in `setup` and check meshes against them.
+        services.insert(khora_core::renderer::api::pipeline::VertexFormatRegistry::new());
+
+        // Sound events: apps clone the handle in `setup` to post events;
+        // the `sound_events` DataSystem resolves them each tick.
+        services.insert(khora_data::AudioEvents::new());
+
+        // Audio mixing quality: written by the AudioAgent from its GORNA
+        // strategy, read by the audio callback before each mix.
+        services.insert(khora_data::audio::AudioMixSettings::new());
+
+        // Asset residency: apps set per-type policies in `setup` and keep a
+        // clone for explicit make_resident/evict requests. Reported to
+        // telemetry as the "AssetResidency" monitor.
+        let residency = khora_data::ResidencyManager::new();
+        telemetry
+            .monitor_registry()
+            .register(Arc::new(residency.clone()));
+        services.insert(residency.clone());
+
+        // Texture mip streaming: the `texture_streaming` DataSystem tracks
+        // entity textures and keeps their mips within the VRAM budget. Apps
+        // may replace the config in `setup`. Reported to telemetry as the
+        // "TextureStreaming" monitor.
+        let mip_streamer = khora_data::MipStreamer::new();
+        telemetry
+            .monitor_registry()
+            .register(Arc::new(mip_streamer.clone()));
+        services.insert(mip_streamer);
+
+        // UI input routing: fed with every tick's input before the
+        // pre-simulation systems, read by the `ui_interaction` DataSystem.
+        services.insert(khora_data::ui::UiInputRouter::new());
 
         // Create the game world
         let mut game_world = GameWorld::new();
 
+        // ECS query profiling: off until an app enables it. Reported to
+        // telemetry as the "EcsQueries" monitor, one frame at a time.
+        let query_profiler = khora_data::ecs::QueryProfiler::new();
+        game_world
+            .inner_world_mut()
+            .set_query_profiler(query_profiler.clone());
+        telemetry
+            .monitor_registry()
+            .register(Arc::new(query_profiler.clone()));
+        services.insert(query_profiler);
+
+        // Plugins register their components, events and services before the
+        // app's setup, so the app can rely on them.
+        let mut plugins = PluginSet::new();
+        app.plugins(&mut plugins);
+        plugins.build(&mut game_world, &mut services);
+
         // Call app setup — pass a temporary Arc view so the API is unchanged.
         // We own `services` exclusively here; no other Arc clone exists yet.
         {
@@ -120,18 +258,39 @@ impl<A: EngineApp> EngineCore<A> {
             });
         }
 
+        // ── Depth convention ─────────────────────────────────────────────────
+        // Flows and lanes read `DepthMode` to agree on projection, depth test
+        // and depth clear. Apps may insert one in bootstrap; otherwise the
+        // renderer's own mode is used.
+        if !services.contains::<DepthMode>() {
+            let depth_mode = services
+                .get::<Arc<Mutex<Box<dyn RenderSystem>>>>()
+                .and_then(|rs| rs.lock().ok().map(|rs| rs.depth_mode()))
+                .unwrap_or_default();
+            services.insert(depth_mode);
+        }
+
         // Register agents via the app's AgentProvider trait.
         app.register_agents(&dcc, &mut services);
+        plugins.register_agents(&dcc, &mut services);
 
         // ── Data-layer GPU services ──────────────────────────────────────────
         // GpuCache: engine-wide shared GPU mesh store. All agents read from it.
         // ProjectionRegistry: runs sync_all() once per frame in tick_with_services()
         // before the scheduler dispatches agents.
         let gpu_cache = khora_data::GpuCache::new();
-        let proj_registry = khora_data::ProjectionRegistry::new(gpu_cache.clone());
+        let proj_registry =
+            khora_data::ProjectionRegistry::new(gpu_cache.clone()).with_residency(residency);
         services.insert(gpu_cache);
         services.insert(proj_registry);
 
+        // ── Asset unloading ──────────────────────────────────────────────────
+        // The AssetAgent releases assets left unreferenced for this grace
+        // period. Apps may insert their own config in bootstrap.
+        if !services.contains::<khora_data::assets::AssetUnloadConfig>() {
+            services.insert(khora_data::assets::AssetUnloadConfig::default());
+        }
+
         // ── Frame graph ──────────────────────────────────────────────────────
         // Per-frame collection of render passes recorded by agents during the
         // OUTPUT phase. `tick_with_services()` drains it after the scheduler
@@ -139,15 +298,57 @@ impl<A: EngineApp> EngineCore<A> {
         let frame_graph: SharedFrameGraph = Arc::new(Mutex::new(FrameGraph::new()));
         services.insert(frame_graph);
 
+        // ── GPU readback ─────────────────────────────────────────────────────
+        // Frame-lagged GPU→CPU copies (picking, exposure, screenshots).
+        // `submit_passes()` ends its frame once the copies are submitted.
+        let readback: SharedReadback = Arc::new(Mutex::new(GpuReadback::new()));
+        services.insert(readback);
+
+        // ── Entity picking ───────────────────────────────────────────────────
+        // Pixel picks queued by `pick_pixel()`; the render agent runs the ID
+        // pass only while some are pending.
+        let picker: SharedEntityPicker = Arc::new(Mutex::new(EntityPicker::new()));
+        services.insert(picker);
+
+        // ── Frame time ───────────────────────────────────────────────────────
+        // Advanced once per tick in `drain_inputs()`; read by time-driven
+        // DataSystems (e.g. `material_animation`).
+        services.insert(self.frame_time.clone());
+
+        // ── Executor ─────────────────────────────────────────────────────────
+        // Async work (asset streaming, networking, HTTP). A driver injects
+        // its own by inserting a `SharedExecutor` before bootstrap; the
+        // default is a thread pool. Ticked in `run_maintenance()`.
+        let executor = match services.get::<SharedExecutor>() {
+            Some(executor) => executor.clone(),
+            None => {
+                let executor = default_executor();
+                services.insert(executor.clone());
+                executor
+            }
+        };
+        log::info!("EngineCore: using the '{}' executor", executor.name());
+        self.executor = Some(executor);
+
         // ── Scene-extraction data containers ─────────────────────────────────
         // RenderFlow + UiFlow publish their per-frame views directly into
         // the LaneBus during the Substrate Pass — no shared service needed.
 
         // EcsMaintenance — owned by ServiceRegistry so the `ecs_maintenance`
         // DataSystem (Maintenance phase) can fetch and tick it each frame.
-        services.insert(Arc::new(Mutex::new(khora_data::ecs::EcsMaintenance::new())));
+        // Page occupancy is reported to telemetry as the "EcsPages" monitor.
+        let maintenance = khora_data::ecs::EcsMaintenance::new();
+        telemetry
+            .monitor_registry()
+            .register(Arc::new(maintenance.monitor()));
+        services.insert(Arc::new(Mutex::new(maintenance)));
+
+        // Secondary worlds — created by the app, advanced after the primary
+        // world's agents in `run_scheduler()`.
+        services.insert(self.worlds.clone());
 
         // PhysicsQueryService: on-demand raycast/debug queries, no GORNA required.
+        #[cfg(feature = "physics")]
         if let Some(provider) = services
             .get::<std::sync::Arc<std::sync::Mutex<Box<dyn khora_core::physics::PhysicsProvider>>>>(
             )
@@ -160,29 +361,31 @@ impl<A: EngineApp> EngineCore<A> {
         // Register built-in agents (always present). Agents implement only the
         // `Agent` trait + `Default`, so construction goes through `Default::default()`.
         dcc.register_agent(
-            Arc::new(Mutex::new(
-                khora_agents::render_agent::RenderAgent::default(),
-            )),
+            Box::new(khora_agents::render_agent::RenderAgent::default()),
             1.0,
         );
         dcc.register_agent(
-            Arc::new(Mutex::new(
-                khora_agents::shadow_agent::ShadowAgent::default(),
-            )),
+            Box::new(khora_agents::shadow_agent::ShadowAgent::default()),
             1.0,
         );
+        #[cfg(feature = "physics")]
         dcc.register_agent(
-            Arc::new(Mutex::new(
-                khora_agents::physics_agent::PhysicsAgent::default(),
-            )),
+            Box::new(khora_agents::physics_agent::PhysicsAgent::default()),
             1.0,
         );
         dcc.register_agent(
-            Arc::new(Mutex::new(khora_agents::ui_agent::UiAgent::default())),
+            Box::new(khora_agents::animation_agent::AnimationAgent::default()),
             1.0,
         );
+        dcc.register_agent(Box::new(khora_agents::ik_agent::IkAgent::default()), 1.0);
+        dcc.register_agent(Box::new(khora_agents::ui_agent::UiAgent::default()), 1.0);
+        #[cfg(feature = "audio")]
         dcc.register_agent(
-            Arc::new(Mutex::new(khora_agents::audio_agent::AudioAgent::default())),
+            Box::new(khora_agents::audio_agent::AudioAgent::default()),
+            1.0,
+        );
+        dcc.register_agent(
+            Box::new(khora_agents::asset_agent::AssetAgent::default()),
             1.0,
         );
 
@@ -206,14 +409,23 @@ impl<A: EngineApp> EngineCore<A> {
         // so GORNA does not run health-checks before agents are ready.
         dcc.start(dcc_rx);
 
-        // Build scheduler
-        let agent_ids = vec![
+        // Build scheduler. Agents compiled out by SDK features are left out.
+        let agent_ids = [
             khora_core::control::gorna::AgentId::Renderer,
             khora_core::control::gorna::AgentId::ShadowRenderer,
+            #[cfg(feature = "physics")]
             khora_core::control::gorna::AgentId::Physics,
+            khora_core::control::gorna::AgentId::Animation,
+            khora_core::control::gorna::AgentId::InverseKinematics,
             khora_core::control::gorna::AgentId::Ui,
+            #[cfg(feature = "audio")]
             khora_core::control::gorna::AgentId::Audio,
+            khora_core::control::gorna::AgentId::Asset,
         ];
+        #[cfg(not(feature = "physics"))]
+        log::info!("khora-sdk built without the `physics` feature: PhysicsAgent not registered");
+        #[cfg(not(feature = "audio"))]
+        log::info!("khora-sdk built without the `audio` feature: AudioAgent not registered");
 
         let registry = dcc.agent_registry().clone();
         let mut scheduler =
@@ -224,6 +436,9 @@ impl<A: EngineApp> EngineCore<A> {
         for phase in custom_phases {
             scheduler.insert_after(khora_core::agent::ExecutionPhase::OUTPUT, phase);
         }
+        for hook in plugins.phase_hooks() {
+            scheduler.register_plugin(hook);
+        }
 
         let budget_channel = scheduler.budget_channel().clone();
         dcc.connect_budget_channel(budget_channel);
@@ -234,6 +449,18 @@ impl<A: EngineApp> EngineCore<A> {
                 "boot".to_string(),
             ));
 
+        // Stall watchdog: observes the heartbeat stamped by the staged
+        // frame methods below.
+        if let Some(config) = A::watchdog_config() {
+            match Watchdog::start(config, Some(dcc.agent_registry().clone())) {
+                Ok(watchdog) => self.watchdog = Some(watchdog),
+                Err(e) => log::error!("EngineCore: failed to start watchdog: {}", e),
+            }
+        }
+
+        // Plugin startup systems: agents are initialized, no frame has run.
+        plugins.startup(&mut game_world, &services_arc);
+
         // Store everything
         self.app = Some(app);
         self.game_world = Some(game_world);
@@ -276,36 +503,45 @@ impl<A: EngineApp> EngineCore<A> {
         let presents = self.begin_render_frame(&frame_services_arc);
         self.run_scheduler(&frame_services_arc);
         self.end_render_frame(presents);
+        self.run_maintenance();
+    }
 
-        // Substrate Pass — end-of-tick maintenance (compaction, deferred
-        // cleanup, idempotent best-effort work). Runs after every agent and
-        // after the I/O boundary so the world is in its final post-frame state.
-        if let Some(gw) = self.game_world.as_mut() {
-            substrate::run_data_systems(
-                gw.inner_world_mut(),
-                &self.services,
-                TickPhase::Maintenance,
-            );
-        }
+    /// Replaces wall-clock frame timing with a fixed step.
+    ///
+    /// With `Some(delta)`, every tick advances [`FrameTime`] by exactly
+    /// `delta`, which makes headless runs deterministic. `None` restores
+    /// wall-clock timing.
+    pub fn set_fixed_delta(&mut self, delta: Option<Duration>) {
+        self.fixed_delta = delta;
     }
 
     /// Stage 1 — drain queued input events. Also marks simulation started
-    /// (emits the `"simulation"` phase change on the first call) and ticks
-    /// the telemetry service.
+    /// (emits the `"simulation"` phase change on the first call), ticks
+    /// the telemetry service and advances the shared [`FrameTime`], which
+    /// is also copied into the primary world as a resource.
     pub fn drain_inputs(&mut self) -> Vec<InputEvent> {
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.heartbeat().begin_frame("drain_inputs");
+        }
         if !self.simulation_started {
-            if let Some(dcc) = &self.dcc {
-                let _ =
-                    dcc.event_sender()
-                        .send(khora_core::telemetry::TelemetryEvent::PhaseChange(
-                            "simulation".to_string(),
-                        ));
-            }
+            self.send_phase_change("simulation");
             self.simulation_started = true;
         }
         if let Some(telemetry) = self.telemetry.as_mut() {
             let _ = telemetry.tick();
         }
+        let now = Instant::now();
+        let delta = self.fixed_delta.unwrap_or_else(|| {
+            self.last_tick
+                .map_or(Duration::ZERO, |last| now.duration_since(last))
+        });
+        self.last_tick = Some(now);
+        if let Ok(mut frame_time) = self.frame_time.write() {
+            frame_time.advance(delta);
+            if let Some(gw) = self.game_world.as_mut() {
+                gw.insert_resource(*frame_time);
+            }
+        }
         self.input_events.drain(..).collect()
     }
 
@@ -313,11 +549,24 @@ impl<A: EngineApp> EngineCore<A> {
     /// extractions. Called between [`drain_inputs`](Self::drain_inputs) and
     /// [`begin_render_frame`](Self::begin_render_frame).
     pub fn run_app_update(&mut self, inputs: &[InputEvent]) {
+        self.beat("app_update");
         let (Some(app), Some(gw)) = (self.app.as_mut(), self.game_world.as_mut()) else {
             return;
         };
         let services = &self.services;
 
+        // Route pointer input to the UI first so `ui_interaction` and
+        // `app.update` agree on what the UI consumed this tick.
+        if let Some(router) = services.get::<khora_data::ui::UiInputRouter>() {
+            router.route(inputs);
+        }
+
+        // Publish the tick's input to the ECS event queue so data systems can
+        // read it with an `EventReader<InputEvent>`.
+        gw.inner_world_mut()
+            .events_mut::<InputEvent>()
+            .send_batch(inputs.iter().cloned());
+
         // Substrate Pass — pre-simulation invariants (input-driven mutations,
         // scene events that must be visible to agents).
         substrate::run_data_systems(gw.inner_world_mut(), services, TickPhase::PreSimulation);
@@ -339,7 +588,7 @@ impl<A: EngineApp> EngineCore<A> {
 
     /// Stage 3 — acquire the swapchain via `RenderSystem::begin_frame` and
     /// populate the per-frame [`FrameContext`] with `ColorTarget`,
-    /// `DepthTarget`, and `ClearColor`.
+    /// `DepthTarget`, `TargetSize`, and `ClearColor`.
     ///
     /// Returns `true` when a renderer is present and `begin_frame` succeeded
     /// (driver should later call [`present_frame`](Self::present_frame)).
@@ -352,6 +601,7 @@ impl<A: EngineApp> EngineCore<A> {
     /// available for editor overlay passes that paint on top of an
     /// offscreen-rendered scene.
     pub fn begin_render_frame(&mut self, frame_services_arc: &Arc<ServiceRegistry>) -> bool {
+        self.beat("begin_render_frame");
         let render_system = self
             .services
             .get::<Arc<Mutex<Box<dyn RenderSystem>>>>()
@@ -373,6 +623,7 @@ impl<A: EngineApp> EngineCore<A> {
                     if let Some(d) = targets.depth {
                         fctx.insert(DepthTarget(d));
                     }
+                    fctx.insert(TargetSize(targets.size));
                     fctx.insert(ClearColor(khora_core::math::LinearRgba::new(
                         0.1, 0.1, 0.15, 1.0,
                     )));
@@ -387,14 +638,20 @@ impl<A: EngineApp> EngineCore<A> {
     }
 
     /// Stage 4 — dispatch the scheduler so all registered agents execute
-    /// their phases for this frame.
+    /// their phases for this frame, then advance every running secondary
+    /// world (see [`Worlds`]).
     pub fn run_scheduler(&mut self, frame_services_arc: &Arc<ServiceRegistry>) {
+        self.beat("run_scheduler");
         let Some(gw) = self.game_world.as_mut() else {
             return;
         };
         if let Some(s) = self.scheduler.as_mut() {
             s.run_frame(gw.inner_world_mut(), frame_services_arc.clone());
         }
+        match self.worlds.lock() {
+            Ok(mut worlds) => worlds.run_frame(&self.services, &self.context),
+            Err(_) => log::error!("EngineCore: worlds lock poisoned"),
+        }
     }
 
     /// Stage 5a — submit recorded passes from the [`FrameGraph`] to the GPU.
@@ -407,6 +664,7 @@ impl<A: EngineApp> EngineCore<A> {
     /// between [`run_scheduler`](Self::run_scheduler) and
     /// [`present_frame`](Self::present_frame).
     pub fn submit_passes(&mut self, presents: bool) {
+        self.beat("submit_passes");
         let device = self
             .services
             .get::<Arc<dyn GraphicsDevice>>()
@@ -423,11 +681,20 @@ impl<A: EngineApp> EngineCore<A> {
         } else if let Some(graph) = &frame_graph {
             graph.lock().expect("FrameGraph mutex poisoned").clear();
         }
+
+        // Every copy of this frame is submitted (or dropped with the graph):
+        // start mapping them and hand out the finished readbacks.
+        if let (Some(readback), Some(device)) = (self.services.get::<SharedReadback>(), &device) {
+            if let Ok(mut readback) = readback.lock() {
+                readback.end_frame(device.as_ref());
+            }
+        }
     }
 
     /// Stage 5b — call `RenderSystem::end_frame` to present the swapchain.
     /// No-op when `presents` is `false`.
     pub fn present_frame(&mut self, presents: bool) {
+        self.beat("present_frame");
         if !presents {
             return;
         }
@@ -453,6 +720,107 @@ impl<A: EngineApp> EngineCore<A> {
         self.present_frame(presents);
     }
 
+    /// Stage 6 — Substrate Pass end-of-tick maintenance (compaction,
+    /// deferred cleanup, idempotent best-effort work). Runs after every
+    /// agent and after the I/O boundary so the world is in its final
+    /// post-frame state. Picks up power governor changes first and ends by
+    /// recording the frame's metrics in the telemetry history.
+    pub fn run_maintenance(&mut self) {
+        self.beat("run_maintenance");
+        if let Some(executor) = &self.executor {
+            executor.tick();
+        }
+        self.follow_power_policy();
+        if let Some(gw) = self.game_world.as_mut() {
+            substrate::run_data_systems(
+                gw.inner_world_mut(),
+                &self.services,
+                TickPhase::Maintenance,
+            );
+            gw.inner_world().query_profiler().end_frame();
+        }
+        if let Some(telemetry) = self.telemetry.as_mut() {
+            let frame = self
+                .frame_time
+                .read()
+                .map(|t| t.frame_count.saturating_sub(1))
+                .unwrap_or_default();
+            telemetry.record_frame(frame);
+        }
+        if let Some(start) = self.first_frame_start.take() {
+            self.startup
+                .record(StartupPhase::FirstFrame, start.elapsed());
+            self.finish_startup();
+        }
+    }
+
+    /// Logs the startup phase breakdown, warns if the first frame exceeded
+    /// [`EngineApp::first_frame_budget`] and exports every phase to
+    /// telemetry as a `startup.phase_ms` gauge labelled by phase.
+    fn finish_startup(&mut self) {
+        self.startup.finish();
+        log::info!("Startup finished:\n{}", self.startup.report());
+
+        let first_frame = self
+            .startup
+            .phase(StartupPhase::FirstFrame)
+            .unwrap_or_default();
+        if let Some(budget) = A::first_frame_budget() {
+            if first_frame > budget {
+                log::warn!(
+                    "First frame took {:.0} ms, over the {:.0} ms startup budget",
+                    first_frame.as_secs_f64() * 1000.0,
+                    budget.as_secs_f64() * 1000.0
+                );
+            }
+        }
+
+        let Some(telemetry) = self.telemetry.as_ref() else {
+            return;
+        };
+        let metrics = telemetry.metrics_registry();
+        for (phase, duration) in self.startup.phases() {
+            match metrics.register_gauge_with_labels(
+                "startup",
+                "phase_ms",
+                "Time spent in a startup phase",
+                "ms",
+                vec![("phase".to_string(), phase.name().to_string())],
+            ) {
+                Ok(gauge) => {
+                    let _ = gauge.set(duration.as_secs_f64() * 1000.0);
+                }
+                Err(e) => log::warn!("Failed to export startup timings: {}", e),
+            }
+        }
+        if let Ok(gauge) = metrics.register_gauge(
+            "startup",
+            "total_ms",
+            "Time from engine start to the end of the first frame",
+            "ms",
+        ) {
+            let _ = gauge.set(self.startup.total().as_secs_f64() * 1000.0);
+        }
+    }
+
+    /// Returns the startup phase timings. Complete once the first frame has
+    /// run its maintenance stage.
+    pub fn startup_profile(&self) -> &StartupProfile {
+        &self.startup
+    }
+
+    /// Returns the executor async work runs on, once bootstrapped.
+    pub fn executor(&self) -> Option<&SharedExecutor> {
+        self.executor.as_ref()
+    }
+
+    /// Stamps the watchdog heartbeat, if a watchdog is running.
+    fn beat(&self, phase: &'static str) {
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.heartbeat().beat(phase);
+        }
+    }
+
     /// Mutable accessor for the application instance. Used by the winit
     /// runner to invoke [`EngineApp`] lifecycle hooks between staged frame
     /// methods.
@@ -485,28 +853,400 @@ impl<A: EngineApp> EngineCore<A> {
         &self.services
     }
 
+    /// Requests the entity drawn at `screen_pos`, in physical pixels from the
+    /// top-left of the window. The handle resolves a few frames later, once
+    /// the ID pass has been read back. Returns `None` before bootstrap.
+    pub fn pick_pixel(&self, screen_pos: Vec2) -> Option<PickHandle> {
+        let picker = self.services.get::<SharedEntityPicker>()?;
+        let mut picker = picker.lock().ok()?;
+        Some(picker.pick_pixel(screen_pos))
+    }
+
+    /// Surveys the host hardware — OS, CPU, memory, the renderer's graphics
+    /// adapter and the given `monitors` — saves it as JSON to
+    /// [`EngineApp::hardware_report_path`] and to the installed
+    /// [`RunLogDir`], and hands it to telemetry, which
+    /// forwards it to the DCC. Called once by the windowing driver after
+    /// bootstrap.
+    pub fn record_hardware_survey(&mut self, monitors: Vec<MonitorInfo>) {
+        let gpu = self
+            .services
+            .get::<Arc<dyn GraphicsDevice>>()
+            .map(|device| GpuSurvey::from_adapter(&device.get_adapter_info(), None));
+        let platform = SysinfoMonitor::new();
+        let survey = platform.survey(gpu, monitors);
+        log::info!(
+            "Hardware: {} | CPU {} ({} threads) | RAM {} MB | GPU {}",
+            survey.os,
+            survey.cpu.brand,
+            survey.cpu.logical_cores,
+            survey.total_memory_bytes / (1024 * 1024),
+            survey
+                .gpu
+                .as_ref()
+                .map(|gpu| format!(
+                    "{} ({}, {} {})",
+                    gpu.name, gpu.backend, gpu.driver, gpu.driver_info
+                ))
+                .unwrap_or_else(|| "none".to_string())
+        );
+
+        let run_report = RunLogDir::current().map(RunLogDir::hardware_report_path);
+        for path in A::hardware_report_path().into_iter().chain(run_report) {
+            match survey.write_json(&path) {
+                Ok(()) => log::info!("Hardware report written to {}", path.display()),
+                Err(e) => log::warn!(
+                    "Failed to write the hardware report to {}: {}",
+                    path.display(),
+                    e
+                ),
+            }
+        }
+
+        if let Some(telemetry) = self.telemetry.as_mut() {
+            telemetry.record_hardware_survey(survey);
+            // Thermal, battery and CPU load from here on, for the DCC's
+            // heuristics and power governor.
+            telemetry
+                .monitor_registry()
+                .register(Arc::new(HardwareHealthMonitor::new(
+                    "Hardware".to_string(),
+                    platform,
+                )));
+        }
+    }
+
+    /// Returns the startup hardware survey, once
+    /// [`record_hardware_survey`](Self::record_hardware_survey) has run.
+    pub fn hardware_survey(&self) -> Option<&HardwareSurvey> {
+        self.telemetry.as_ref()?.hardware_survey()
+    }
+
+    /// Returns the per-frame metric history, once bootstrapped. The same
+    /// handle is registered in the `ServiceRegistry`.
+    pub fn metric_history(&self) -> Option<&MetricHistory> {
+        Some(self.telemetry.as_ref()?.history())
+    }
+
+    /// Returns the game world, if initialized.
+    pub fn game_world(&self) -> Option<&GameWorld> {
+        self.game_world.as_ref()
+    }
+
     /// Returns a mutable reference to the game world, if initialized.
     pub fn game_world_mut(&mut self) -> Option<&mut GameWorld> {
         self.game_world.as_mut()
     }
 
+    /// Returns the engine's secondary worlds.
+    pub fn worlds(&self) -> &SharedWorlds {
+        &self.worlds
+    }
+
+    /// Moves `entity` between any two worlds, the primary one included,
+    /// returning its ID in `to`.
+    pub fn transfer_entity(
+        &mut self,
+        entity: khora_core::ecs::entity::EntityId,
+        from: WorldId,
+        to: WorldId,
+    ) -> Option<khora_core::ecs::entity::EntityId> {
+        let mut worlds = self.worlds.lock().ok()?;
+        match (from, to) {
+            (WorldId::PRIMARY, WorldId::PRIMARY) => None,
+            (WorldId::PRIMARY, to) => {
+                worlds.transfer_from_primary(self.game_world.as_mut()?, entity, to)
+            }
+            (from, WorldId::PRIMARY) => {
+                worlds.transfer_to_primary(entity, from, self.game_world.as_mut()?)
+            }
+            (from, to) => worlds.transfer(entity, from, to),
+        }
+    }
+
     /// Returns the DCC service, if initialized.
     pub fn dcc(&self) -> Option<&DccService> {
         self.dcc.as_ref()
     }
 
-    /// Shuts down the engine, calling `app.on_shutdown()`.
+    /// Returns `true` while the engine is suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_mode.is_some()
+    }
+
+    /// Suspends the engine when its window is minimized or occluded.
+    ///
+    /// Switches the DCC to the background mode (GORNA parks every agent at
+    /// LowPower), waits for in-flight GPU work and stops the render system
+    /// from acquiring frames, then calls `app.on_suspend()`. Until
+    /// [`resume`](Self::resume), the driver calls
+    /// [`background_tick`](Self::background_tick) instead of running frames.
+    /// Calling this while already suspended is a no-op.
+    pub fn suspend(&mut self) {
+        if self.suspended_mode.is_some() || self.shut_down {
+            return;
+        }
+        let previous = self
+            .dcc
+            .as_ref()
+            .and_then(|dcc| dcc.context_handle().read().ok().map(|c| c.mode.clone()))
+            .unwrap_or(EngineMode::Playing);
+        log::info!("Engine suspended (was {:?}).", previous.name());
+        self.send_phase_change(EngineMode::background().name());
+        self.suspended_mode = Some(previous);
+
+        if let Some(renderer) = self.services.get::<Arc<Mutex<Box<dyn RenderSystem>>>>() {
+            if let Ok(mut renderer) = renderer.lock() {
+                renderer.suspend();
+            }
+        }
+        if let Some(app) = self.app.as_mut() {
+            app.on_suspend();
+        }
+    }
+
+    /// Resumes the engine after [`suspend`](Self::suspend).
+    ///
+    /// Restores the mode active before suspension (GORNA renegotiates full
+    /// budgets), reconfigures the render surface and calls
+    /// `app.on_resume()`. Frame timing restarts so the first frame does not
+    /// see the whole suspended interval as its delta. Calling this while not
+    /// suspended is a no-op.
+    pub fn resume(&mut self) {
+        let Some(previous) = self.suspended_mode.take() else {
+            return;
+        };
+        self.send_phase_change(previous.name());
+        self.last_tick = None;
+
+        if let Some(renderer) = self.services.get::<Arc<Mutex<Box<dyn RenderSystem>>>>() {
+            if let Ok(mut renderer) = renderer.lock() {
+                renderer.resume();
+            }
+        }
+        if let Some(app) = self.app.as_mut() {
+            app.on_resume();
+        }
+        log::info!("Engine resumed ({}).", previous.name());
+    }
+
+    /// The low-power tick run every [`BACKGROUND_TICK_INTERVAL`] while
+    /// suspended.
     ///
-    /// Note: renderer shutdown is the responsibility of the application,
-    /// since the renderer was created and registered by the app's bootstrap closure.
+    /// Keeps the watchdog, telemetry and GORNA mailboxes alive so the DCC can
+    /// still park and later restore agents; runs no app update, no agents and
+    /// no GPU work.
+    pub fn background_tick(&mut self) {
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.heartbeat().begin_frame("background_tick");
+        }
+        if let Some(telemetry) = self.telemetry.as_mut() {
+            let _ = telemetry.tick();
+        }
+        if let Some(dcc) = &self.dcc {
+            dcc.service_agent_mailboxes();
+        }
+    }
+
+    /// The power state last published by the DCC's governor.
+    pub fn power_state(&self) -> PowerState {
+        self.power_state
+    }
+
+    /// The policy of [`power_state`](Self::power_state).
+    pub fn power_policy(&self) -> PowerPolicy {
+        self.power_policy
+    }
+
+    /// Minimum time between the starts of two frames under the power
+    /// policy's FPS cap, or `None` when uncapped. Windowed drivers pace
+    /// redraws to it.
+    pub fn frame_interval(&self) -> Option<Duration> {
+        self.power_policy.frame_interval()
+    }
+
+    /// How often to run [`background_tick`](Self::background_tick) while
+    /// suspended: [`BACKGROUND_TICK_INTERVAL`], stretched when the power
+    /// policy keeps less background work.
+    pub fn background_tick_interval(&self) -> Duration {
+        BACKGROUND_TICK_INTERVAL.div_f32(self.power_policy.background_work_scale.max(0.1))
+    }
+
+    /// Picks up a new state or policy of the DCC's power governor: scales
+    /// ECS compaction to the policy's background work and sends a
+    /// [`PowerEvent`] to the primary world.
+    fn follow_power_policy(&mut self) {
+        let Some((state, policy)) = self.dcc.as_ref().and_then(|dcc| {
+            dcc.context_handle()
+                .read()
+                .ok()
+                .map(|c| (c.power, c.power_policy))
+        }) else {
+            return;
+        };
+        if state == self.power_state && policy == self.power_policy {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.power_state, state);
+        self.power_policy = policy;
+        if previous != state {
+            if let Some(telemetry) = self.telemetry.as_mut() {
+                telemetry.mark_event(format!("power:{}", state.name()));
+            }
+        }
+
+        if let Some(maintenance) = self
+            .services
+            .get::<Arc<Mutex<khora_data::ecs::EcsMaintenance>>>()
+        {
+            if let Ok(mut maintenance) = maintenance.lock() {
+                maintenance.set_work_scale(policy.background_work_scale);
+            }
+        }
+        if let Some(gw) = self.game_world.as_mut() {
+            gw.inner_world_mut()
+                .events_mut::<PowerEvent>()
+                .send(PowerEvent {
+                    previous,
+                    state,
+                    policy,
+                });
+        }
+    }
+
+    /// Sends a mode change to the DCC, if it is running.
+    fn send_phase_change(&mut self, name: &str) {
+        if let Some(telemetry) = self.telemetry.as_mut() {
+            telemetry.mark_event(format!("phase:{}", name));
+        }
+        if let Some(dcc) = &self.dcc {
+            let _ = dcc
+                .event_sender()
+                .send(khora_core::telemetry::TelemetryEvent::PhaseChange(
+                    name.to_string(),
+                ));
+        }
+    }
+
+    /// Shuts the engine down in a fixed order.
+    ///
+    /// 1. Stop the watchdog and the DCC thread — no further arbitration.
+    /// 2. Shut secondary worlds down, then stop worker-agent threads,
+    ///    waiting up to [`SHUTDOWN_STEP_TIMEOUT`].
+    /// 3. Call `Agent::on_shutdown()` in reverse priority order, while the
+    ///    graphics device is alive so lanes can release GPU resources. Each
+    ///    agent gets [`SHUTDOWN_STEP_TIMEOUT`].
+    /// 4. Call `app.on_shutdown()` — the place to persist settings — then
+    ///    save console variables to [`EngineApp::cvar_path`].
+    /// 5. Flush telemetry and the logger.
+    /// 6. Shut the render system down, destroying the GPU device.
+    ///
+    /// The window is destroyed by the windowing driver afterwards. If
+    /// leak-check mode is on, the net allocation change since it was enabled
+    /// is logged last. Calling this more than once is a no-op.
     pub fn shutdown(&mut self) {
+        if self.shut_down {
+            return;
+        }
+        self.shut_down = true;
+        log::info!("Engine shutdown: started.");
+
+        // The loop stops beating from here on; stop the watchdog first so
+        // a slow teardown step is not reported as a stall.
+        if let Some(mut watchdog) = self.watchdog.take() {
+            watchdog.stop();
+        }
+
+        // 1. Signal: no more GORNA rounds. Bounded by the DCC reply timeout.
+        if let Some(dcc) = self.dcc.as_mut() {
+            dcc.stop();
+        }
+
+        // 2. Wait for in-flight agent work on worker threads. Secondary
+        //    worlds go first, agents included, since their services fall
+        //    back to the primary world's.
+        if let Ok(mut worlds) = self.worlds.lock() {
+            worlds.shutdown(SHUTDOWN_STEP_TIMEOUT);
+        }
+        if let Some(scheduler) = self.scheduler.as_mut() {
+            let stuck = scheduler.shutdown(SHUTDOWN_STEP_TIMEOUT);
+            if !stuck.is_empty() {
+                log::warn!("Engine shutdown: worker agents still running: {:?}", stuck);
+            }
+        }
+
+        // 3. Agent teardown, highest priority last. Each agent gets
+        //    SHUTDOWN_STEP_TIMEOUT.
+        if let Some(dcc) = self.dcc.as_ref() {
+            let skipped = dcc.shutdown_agents(&self.services, SHUTDOWN_STEP_TIMEOUT);
+            if !skipped.is_empty() {
+                log::warn!("Engine shutdown: agents not shut down: {:?}", skipped);
+            }
+        }
+
+        // 4. App state and console variables, then the async work the app
+        //    may have left queued.
         if let Some(app) = self.app.as_mut() {
             app.on_shutdown();
         }
+        if let (Some(dcc), Some(path)) = (self.dcc.as_ref(), A::cvar_path()) {
+            match dcc.cvars().save(&path) {
+                Ok(count) => log::info!("Saved {} CVars to {}", count, path.display()),
+                Err(e) => log::warn!("Could not save CVars to {}: {}", path.display(), e),
+            }
+        }
+        if let Some(executor) = self.executor.as_ref() {
+            executor.shutdown();
+        }
+
+        // 5. Final telemetry values and buffered log lines.
+        if let Some(telemetry) = self.telemetry.as_mut() {
+            telemetry.flush();
+        }
+        log::logger().flush();
+
+        // 6. GPU device. Nothing may touch the renderer after this point.
+        if let Some(device) = self.services.get::<Arc<dyn GraphicsDevice>>() {
+            if let Some(readback) = self.services.get::<SharedReadback>() {
+                if let Ok(mut readback) = readback.lock() {
+                    readback.destroy(device.as_ref());
+                }
+            }
+            if let Some(streamer) = self.services.get::<khora_data::MipStreamer>() {
+                streamer.clear(device.as_ref());
+            }
+        }
+        if let Some(renderer) = self.services.get::<Arc<Mutex<Box<dyn RenderSystem>>>>() {
+            match renderer.lock() {
+                Ok(mut renderer) => renderer.shutdown(),
+                Err(_) => log::error!("Engine shutdown: render system lock poisoned"),
+            }
+        }
+
+        if khora_core::memory::leak::is_enabled() {
+            if let Some(report) = khora_core::memory::leak::report_since_enabled() {
+                report.log();
+            }
+        }
         log::info!("Engine shutdown complete.");
     }
 }
 
+/// Starts the default thread-pool executor, or a local one if its threads
+/// cannot be spawned.
+fn default_executor() -> SharedExecutor {
+    match ThreadPoolExecutor::with_available_parallelism() {
+        Ok(pool) => Arc::new(pool),
+        Err(e) => {
+            log::warn!(
+                "EngineCore: thread-pool executor unavailable ({}), using a local one",
+                e
+            );
+            Arc::new(LocalExecutor::new())
+        }
+    }
+}
+
 impl<A: EngineApp> Default for EngineCore<A> {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,99 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The engine's logger: several sinks, each with its own level.
+//!
+//! ```rust,ignore
+//! let run = RunLogDir::create("logs", 10)?.install();
+//! let console = env_logger::Builder::from_env(Env::default().default_filter_or("info")).build();
+//! let control = EngineLogger::new()
+//!     .with_sink("console", LevelFilter::Info, Box::new(console))
+//!     .with_sink(
+//!         "file",
+//!         LevelFilter::Debug,
+//!         Box::new(RotatingFileLog::open(run.log_path(), LogRotation::default())?),
+//!     )
+//!     .install(DEFAULT_LOG_TAIL_LINES)?;
+//! control.set_level("file", LevelFilter::Trace);
+//! ```
+
+use std::sync::Arc;
+
+use log::{LevelFilter, SetLoggerError};
+
+use crate::log_control::{LogControl, LogSink};
+use crate::log_tail::LogTail;
+
+/// Builds the global logger out of named sinks.
+#[derive(Default)]
+pub struct EngineLogger {
+    sinks: Vec<LogSink>,
+}
+
+impl EngineLogger {
+    /// Creates a logger without sinks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sink that receives the records at `level` or above and that
+    /// `log` itself accepts.
+    pub fn with_sink(
+        mut self,
+        name: impl Into<String>,
+        level: LevelFilter,
+        log: Box<dyn log::Log>,
+    ) -> Self {
+        self.sinks.push(LogSink::new(name.into(), level, log));
+        self
+    }
+
+    /// Installs the sinks as the global logger, behind a [`LogTail`] that
+    /// keeps the last `tail_lines` for diagnostics reports.
+    ///
+    /// Returns the handle that changes sink levels at runtime.
+    pub fn install(self, tail_lines: usize) -> Result<LogControl, SetLoggerError> {
+        let sinks = Arc::new(self.sinks);
+        let control = LogControl::new(Arc::clone(&sinks));
+        LogTail::install(Box::new(Fanout { sinks }), control.max_level(), tail_lines)?;
+        Ok(control)
+    }
+}
+
+/// Forwards each record to the sinks whose level accepts it.
+struct Fanout {
+    sinks: Arc<Vec<LogSink>>,
+}
+
+impl log::Log for Fanout {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.sinks
+            .iter()
+            .any(|sink| metadata.level() <= sink.level() && sink.log.enabled(metadata))
+    }
+
+    fn log(&self, record: &log::Record) {
+        for sink in self.sinks.iter() {
+            if record.level() <= sink.level() && sink.log.enabled(record.metadata()) {
+                sink.log.log(record);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        for sink in self.sinks.iter() {
+            sink.log.flush();
+        }
+    }
+}
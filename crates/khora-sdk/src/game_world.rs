@@ -19,12 +19,18 @@
 //! entities and components through a controlled API, never touching the raw
 //! `World` or `Assets` directly.
 
+use std::collections::HashMap;
+
 use khora_core::asset::{AssetHandle, AssetUUID};
 use khora_core::ecs::entity::EntityId;
 use khora_core::renderer::api::scene::Mesh;
+use khora_core::scene::SceneFile;
 use khora_data::ecs::{
-    Camera, Children, Component, ComponentBundle, GlobalTransform, HandleComponent, Parent, Query,
-    QueryMut, Transform, World, WorldQuery,
+    migrate_entities, Camera, Commands, Component, ComponentBundle, EntityMap, GlobalTransform,
+    HandleComponent, Query, QueryMut, Transform, TriggerEvent, World, WorldQuery, WorldSnapshot,
+};
+use khora_data::scene::{
+    bake_static, unbake_static, DeserializationError, StaticBakeReport, ValidationReport,
 };
 
 /// A high-level facade over the internal ECS `World` and `Assets` registry.
@@ -88,13 +94,53 @@ impl GameWorld {
         self.world.spawn(bundle)
     }
 
-    /// Removes an entity and all its components from the world.
+    /// Spawns one entity per bundle, with the page reserved once and
+    /// written contiguously. Returns the IDs in order.
+    ///
+    /// ```rust,ignore
+    /// let particles = world.spawn_batch((0..50_000).map(|_| (Transform::identity(), Velocity::default())));
+    /// ```
+    pub fn spawn_batch<B, I>(&mut self, bundles: I) -> Vec<EntityId>
+    where
+        B: ComponentBundle,
+        I: IntoIterator<Item = B>,
+    {
+        self.world.spawn_batch(bundles)
+    }
+
+    /// Returns `true` if `entity` is still alive. A handle kept after its
+    /// entity was despawned stays `false` even once the slot is reused.
+    pub fn is_alive(&self, entity: EntityId) -> bool {
+        self.world.is_alive(entity)
+    }
+
+    /// Returns the live entity whose index is `index`, if any.
+    pub fn entity_at(&self, index: u32) -> Option<EntityId> {
+        self.world.entity_at(index)
+    }
+
+    /// Removes an entity and all its components from the world. Its
+    /// children stay alive and become roots.
     ///
     /// Returns `true` if the entity existed and was removed.
     pub fn despawn(&mut self, entity: EntityId) -> bool {
         self.world.despawn(entity)
     }
 
+    /// Removes an entity together with all its descendants.
+    ///
+    /// Returns how many entities were removed.
+    pub fn despawn_recursive(&mut self, entity: EntityId) -> usize {
+        self.world.despawn_recursive(entity)
+    }
+
+    /// Applies commands queued while iterating a query, in queue order.
+    ///
+    /// Returns how many commands ran.
+    pub fn apply_commands(&mut self, commands: &mut Commands) -> usize {
+        commands.apply(&mut self.world)
+    }
+
     // ─────────────────────────────────────────────────────────────────────
     // Camera Helpers
     // ─────────────────────────────────────────────────────────────────────
@@ -111,6 +157,24 @@ impl GameWorld {
         self.world.spawn((camera, GlobalTransform::identity()))
     }
 
+    /// Spawns the entities of a saved scene next to the ones already here,
+    /// with fresh ids and their hierarchy remapped. Backed by
+    /// [`World::instantiate`].
+    ///
+    /// Returns the spawned entities.
+    pub fn instantiate(
+        &mut self,
+        prefab: &SceneFile,
+    ) -> Result<Vec<EntityId>, DeserializationError> {
+        self.world.instantiate(prefab)
+    }
+
+    /// Checks the scene for broken references and invalid transforms.
+    /// Backed by [`World::validate`].
+    pub fn validate(&self) -> ValidationReport {
+        self.world.validate()
+    }
+
     // ─────────────────────────────────────────────────────────────────────
     // Asset Management
     // ─────────────────────────────────────────────────────────────────────
@@ -298,71 +362,39 @@ impl GameWorld {
     /// Maintains both the `Parent` component on `child` and the `Children`
     /// list on the involved parents. Refuses cycles silently (a no-op).
     pub fn set_parent(&mut self, child: EntityId, new_parent: Option<EntityId>) {
-        // Refuse cycles: new_parent must not be a descendant of child.
-        if let Some(np) = new_parent {
-            if np == child || self.is_descendant_of(np, child) {
-                log::warn!(
-                    "set_parent: refused cycle (child={:?}, new_parent={:?})",
-                    child,
-                    np
-                );
-                return;
-            }
-        }
+        self.world.set_parent(child, new_parent);
+    }
 
-        // 1. Remove `child` from its previous parent's `Children`, if any.
-        let old_parent = self.world.get::<Parent>(child).map(|p| p.0);
-        if let Some(old) = old_parent {
-            if let Some(children) = self.world.get_mut::<Children>(old) {
-                children.0.retain(|c| *c != child);
-            }
-        }
+    /// Returns `entity`'s parent, if it has one.
+    pub fn parent(&self, entity: EntityId) -> Option<EntityId> {
+        self.world.parent(entity)
+    }
 
-        // 2. Update `Parent` on `child` (set or remove).
-        match new_parent {
-            Some(np) => {
-                if let Some(existing) = self.world.get_mut::<Parent>(child) {
-                    existing.0 = np;
-                } else {
-                    let _ = self.world.add_component(child, Parent(np));
-                }
-            }
-            None => {
-                // Surgical: drop only the `Parent` component, keep the
-                // entity's other Spatial components (Transform,
-                // GlobalTransform, Name, …) intact.
-                let _ = self.world.remove_component::<Parent>(child);
-            }
-        }
+    /// Returns `entity`'s direct children.
+    pub fn children(&self, entity: EntityId) -> &[EntityId] {
+        self.world.children(entity)
+    }
 
-        // 3. Add `child` to the new parent's `Children` (creating it if needed).
-        if let Some(np) = new_parent {
-            if let Some(children) = self.world.get_mut::<Children>(np) {
-                if !children.0.contains(&child) {
-                    children.0.push(child);
-                }
-            } else {
-                let _ = self.world.add_component(np, Children(vec![child]));
-            }
-        }
+    /// Returns an entity whose [`Name`](khora_data::ecs::Name) is `name`. Backed by
+    /// [`World::find_by_name`].
+    pub fn find_by_name(&self, name: &str) -> Option<EntityId> {
+        self.world.find_by_name(name)
     }
 
-    /// Returns whether `candidate` is a descendant of `ancestor` in the
-    /// scene hierarchy. Used by `set_parent` to refuse cycle-creating reparents.
-    fn is_descendant_of(&self, candidate: EntityId, ancestor: EntityId) -> bool {
-        let mut current = candidate;
-        // Bound the traversal to avoid infinite loops on a malformed hierarchy.
-        for _ in 0..1024 {
-            let Some(parent) = self.world.get::<Parent>(current) else {
-                return false;
-            };
-            if parent.0 == ancestor {
-                return true;
-            }
-            current = parent.0;
-        }
-        log::warn!("is_descendant_of: hierarchy traversal exceeded depth bound");
-        false
+    /// Returns every entity whose `Name` is `name`.
+    pub fn find_all_by_name(&self, name: &str) -> Vec<EntityId> {
+        self.world.find_all_by_name(name)
+    }
+
+    /// Returns every enabled entity whose [`Tags`](khora_data::ecs::Tags)
+    /// hold `tag`. Backed by [`World::query_tagged`].
+    pub fn query_tagged(&self, tag: &str) -> Vec<EntityId> {
+        self.world.query_tagged(tag)
+    }
+
+    /// Returns `true` if `entity` is tagged `tag`.
+    pub fn has_tag(&self, entity: EntityId, tag: &str) -> bool {
+        self.world.has_tag(entity, tag)
     }
 
     /// Adds a material to the asset registry and returns a handle component.
@@ -384,6 +416,138 @@ impl GameWorld {
         khora_data::ecs::MaterialComponent { handle, uuid }
     }
 
+    // ─────────────────────────────────────────────────────────────────────
+    // Enable / Visibility
+    // ─────────────────────────────────────────────────────────────────────
+
+    /// Enables or disables `entity` and its descendants without despawning
+    /// them. A disabled entity is not rendered, leaves the physics
+    /// simulation and pauses its sounds. Backed by [`World::set_enabled`].
+    pub fn set_enabled(&mut self, entity: EntityId, enabled: bool) {
+        self.world.set_enabled(entity, enabled);
+    }
+
+    /// Returns `true` unless `entity` or one of its ancestors is disabled.
+    pub fn is_enabled(&self, entity: EntityId) -> bool {
+        self.world.is_enabled(entity)
+    }
+
+    /// Shows or hides `entity` and its descendants. Hidden entities keep
+    /// simulating; they are only left out of rendering.
+    pub fn set_visible(&mut self, entity: EntityId, visible: bool) {
+        self.world.set_visible(entity, visible);
+    }
+
+    /// Returns `true` if `entity` is drawn: neither hidden nor disabled.
+    pub fn is_visible(&self, entity: EntityId) -> bool {
+        self.world.is_visible(entity)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Resources
+    // ─────────────────────────────────────────────────────────────────────
+
+    /// Stores `value` as the world's `T` resource, returning the previous one.
+    /// The engine keeps a [`FrameTime`](khora_core::utils::frame_time::FrameTime)
+    /// resource up to date every tick.
+    pub fn insert_resource<T: Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        self.world.insert_resource(value)
+    }
+
+    /// Returns the `T` resource, if one was inserted.
+    pub fn get_resource<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.world.get_resource::<T>()
+    }
+
+    /// Returns the `T` resource mutably, if one was inserted.
+    pub fn get_resource_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.world.get_resource_mut::<T>()
+    }
+
+    /// Removes the `T` resource and returns it.
+    pub fn remove_resource<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.world.remove_resource::<T>()
+    }
+
+    /// Runs `callback` every time a [`SceneTrigger`](khora_data::ecs::SceneTrigger)
+    /// fires an event named `name`. See [`World::on_trigger`].
+    pub fn on_trigger(
+        &mut self,
+        name: impl Into<String>,
+        callback: impl FnMut(&mut World, &TriggerEvent) + Send + Sync + 'static,
+    ) {
+        self.world.on_trigger(name, callback);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Multi-world
+    // ─────────────────────────────────────────────────────────────────────
+
+    /// Moves `entity` and its components into `dst`, returning its new ID
+    /// there, or `None` if it is not alive. Backed by
+    /// [`World::transfer_entity`]; entity references inside components are
+    /// not remapped.
+    pub fn transfer_entity(&mut self, entity: EntityId, dst: &mut GameWorld) -> Option<EntityId> {
+        self.world.transfer_entity(entity, &mut dst.world)
+    }
+
+    /// Moves every entity accepted by `filter` into `dst`, rewriting
+    /// `Parent` and `Children` between them, and returns the old → new ID
+    /// map. Asset handles are shared, not reloaded. See
+    /// [`migrate_entities`].
+    pub fn migrate_entities(
+        &mut self,
+        dst: &mut GameWorld,
+        filter: impl FnMut(&World, EntityId) -> bool,
+    ) -> HashMap<EntityId, EntityId> {
+        migrate_entities(&mut self.world, &mut dst.world, filter)
+    }
+
+    /// Moves every entity of `other` into this world and returns the old →
+    /// new ID map. Build `other` on a worker thread, e.g. a procedural
+    /// chunk, then merge it here. See [`World::merge`].
+    pub fn merge(&mut self, other: World) -> EntityMap {
+        self.world.merge(other)
+    }
+
+    /// Copies every entity and component into memory, for rollback or for
+    /// leaving play mode. See [`World::snapshot`].
+    pub fn snapshot(&self) -> WorldSnapshot {
+        self.world.snapshot()
+    }
+
+    /// Puts every entity and component back as `snapshot` captured them.
+    /// See [`World::restore`].
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) {
+        self.world.restore(snapshot);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Static Geometry
+    // ─────────────────────────────────────────────────────────────────────
+
+    /// Bakes the entities marked [`Static`](khora_data::ecs::Static): freezes
+    /// their transforms and merges meshes sharing a material into batches.
+    ///
+    /// Call it once the level is loaded; calling it again only bakes static
+    /// entities spawned since.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// world.spawn((Transform::from_translation(pos), GlobalTransform::identity(), rock.clone(), stone.clone(), Static::new()));
+    /// let report = world.bake_static();
+    /// log::info!("{} draws saved", report.merged - report.batches);
+    /// ```
+    pub fn bake_static(&mut self) -> StaticBakeReport {
+        bake_static(&mut self.world)
+    }
+
+    /// Undoes [`bake_static`](Self::bake_static), e.g. before moving static
+    /// entities or saving the scene. Returns the number of entities unbaked.
+    pub fn unbake_static(&mut self) -> usize {
+        unbake_static(&mut self.world)
+    }
+
     // ─────────────────────────────────────────────────────────────────────
     // Internal — used by the SDK, not exposed to users
     // ─────────────────────────────────────────────────────────────────────
@@ -0,0 +1,419 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Headless engine runner for integration tests.
+//!
+//! [`HeadlessRunner`] drives the same staged frame loop as the winit
+//! runner — input drain, `app.update` and the Substrate Pass, render frame,
+//! scheduler, pass submission, maintenance — without a window. Input comes
+//! from a frame-indexed [`InputScript`], time advances by a fixed step, and
+//! every frame leaves a [`FrameReport`] to assert on.
+//!
+//! ```rust,ignore
+//! let script = InputScript::new()
+//!     .key_tap(2, "KeyW")
+//!     .click(5, 100.0, 40.0);
+//! let mut runner = HeadlessRunner::new(MyGame::new()).with_script(script);
+//! runner.run(10);
+//! assert_eq!(runner.reports().len(), 10);
+//! assert!(runner.world().query::<&Player>().count() == 1);
+//! ```
+//!
+//! [`HeadlessRunner::assert_budget`] turns a run into a performance gate —
+//! see [`PerfBudget`].
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use khora_core::executor::SharedExecutor;
+use khora_core::renderer::api::core::RenderStats;
+use khora_core::renderer::traits::RenderSystem;
+use khora_core::ServiceRegistry;
+use khora_data::render::SharedFrameGraph;
+use khora_infra::LocalExecutor;
+
+use crate::perf_budget::{BudgetExceeded, PerfBudget};
+use crate::perf_profile::PerfProfile;
+use crate::traits::EngineApp;
+use crate::{EngineCore, GameWorld, InputEvent, MouseButton, PRIMARY_VIEWPORT};
+
+/// Default simulated frame step: 60 Hz.
+pub const HEADLESS_FRAME_STEP: Duration = Duration::from_nanos(16_666_667);
+
+/// Input events scheduled by frame index.
+///
+/// Frames are counted from 0; events scheduled for a frame are fed to the
+/// engine right before that frame runs, in insertion order.
+#[derive(Debug, Clone, Default)]
+pub struct InputScript {
+    events: BTreeMap<u64, Vec<InputEvent>>,
+}
+
+impl InputScript {
+    /// Creates an empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedules `event` for `frame`.
+    pub fn at(mut self, frame: u64, event: InputEvent) -> Self {
+        self.events.entry(frame).or_default().push(event);
+        self
+    }
+
+    /// Presses `key_code` on `frame` and releases it on the next frame.
+    pub fn key_tap(self, frame: u64, key_code: &str) -> Self {
+        self.key_hold(frame, frame + 1, key_code)
+    }
+
+    /// Presses `key_code` on frame `from` and releases it on frame `to`.
+    pub fn key_hold(self, from: u64, to: u64, key_code: &str) -> Self {
+        self.at(
+            from,
+            InputEvent::KeyPressed {
+                key_code: key_code.to_string(),
+            },
+        )
+        .at(
+            to,
+            InputEvent::KeyReleased {
+                key_code: key_code.to_string(),
+            },
+        )
+    }
+
+    /// Moves the cursor to `(x, y)` on `frame`.
+    pub fn move_cursor(self, frame: u64, x: f32, y: f32) -> Self {
+        self.at(frame, InputEvent::MouseMoved { x, y })
+    }
+
+    /// Moves to `(x, y)` and presses the left button on `frame`, then
+    /// releases it on the next frame.
+    pub fn click(self, frame: u64, x: f32, y: f32) -> Self {
+        self.move_cursor(frame, x, y)
+            .at(
+                frame,
+                InputEvent::MouseButtonPressed {
+                    button: MouseButton::Left,
+                },
+            )
+            .at(
+                frame + 1,
+                InputEvent::MouseButtonReleased {
+                    button: MouseButton::Left,
+                },
+            )
+    }
+
+    /// Returns the events scheduled for `frame`.
+    pub fn events_at(&self, frame: u64) -> &[InputEvent] {
+        self.events.get(&frame).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the last frame with scheduled events.
+    pub fn last_frame(&self) -> Option<u64> {
+        self.events.keys().next_back().copied()
+    }
+}
+
+/// What happened during one headless frame.
+#[derive(Debug, Clone, Default)]
+pub struct FrameReport {
+    /// Index of the frame, starting at 0.
+    pub frame: u64,
+    /// Number of input events delivered to `app.update`.
+    pub inputs: usize,
+    /// Names of the passes agents recorded into the frame graph.
+    pub passes: Vec<&'static str>,
+    /// Whether a render system acquired and presented a frame.
+    pub presented: bool,
+    /// Statistics of the presented frame, when a render system is present.
+    pub render_stats: Option<RenderStats>,
+    /// Number of entities alive at the end of the frame.
+    pub entities: usize,
+    /// Wall-clock time the runner spent on the frame.
+    pub cpu_time: Duration,
+}
+
+impl FrameReport {
+    /// Time of the frame: the longer of the CPU wall time and the GPU
+    /// frame time, when the render system measured one.
+    pub fn frame_time(&self) -> Duration {
+        let gpu_ms = self
+            .render_stats
+            .as_ref()
+            .map_or(0.0, |s| s.gpu_frame_total_time_ms.max(0.0));
+        self.cpu_time
+            .max(Duration::from_secs_f64(f64::from(gpu_ms) / 1000.0))
+    }
+}
+
+/// Aggregate statistics over a range of [`FrameReport`]s.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameStatsSummary {
+    /// Number of frames summarized.
+    pub frames: usize,
+    /// Frames that carried render statistics.
+    pub rendered_frames: usize,
+    /// Most draw calls in a single frame.
+    pub max_draw_calls: u32,
+    /// Most triangles in a single frame.
+    pub max_triangles: u32,
+    /// Longest [`FrameReport::frame_time`].
+    pub max_frame_time: Duration,
+    /// Mean [`FrameReport::frame_time`].
+    pub mean_frame_time: Duration,
+    /// Highest VRAM estimate, in megabytes.
+    pub peak_vram_mb: f32,
+}
+
+impl FrameStatsSummary {
+    /// Summarizes `reports`.
+    pub fn from_reports(reports: &[FrameReport]) -> Self {
+        let mut summary = Self {
+            frames: reports.len(),
+            ..Self::default()
+        };
+        let mut total = Duration::ZERO;
+        for report in reports {
+            let time = report.frame_time();
+            total += time;
+            summary.max_frame_time = summary.max_frame_time.max(time);
+            if let Some(stats) = &report.render_stats {
+                summary.rendered_frames += 1;
+                summary.max_draw_calls = summary.max_draw_calls.max(stats.draw_calls);
+                summary.max_triangles = summary.max_triangles.max(stats.triangles_rendered);
+                summary.peak_vram_mb = summary.peak_vram_mb.max(stats.vram_usage_estimate_mb);
+            }
+        }
+        if let Ok(frames) = u32::try_from(reports.len()) {
+            if frames > 0 {
+                summary.mean_frame_time = total / frames;
+            }
+        }
+        summary
+    }
+}
+
+/// Runs an [`EngineApp`] frame by frame without a window.
+///
+/// The runner bootstraps the engine with the given services — empty by
+/// default, so agents that need a GPU stay idle. Tests that exercise
+/// rendering inject a `GraphicsDevice` / `RenderSystem` pair (real or mock)
+/// through [`with_services`](Self::with_services). Unless one is injected,
+/// async work runs on a [`LocalExecutor`], ticked once per frame, so runs
+/// stay deterministic.
+pub struct HeadlessRunner<A: EngineApp> {
+    engine: EngineCore<A>,
+    script: InputScript,
+    frame: u64,
+    reports: Vec<FrameReport>,
+}
+
+impl<A: EngineApp> HeadlessRunner<A> {
+    /// Bootstraps `app` with no platform services.
+    pub fn new(app: A) -> Self {
+        Self::with_services(app, ServiceRegistry::new())
+    }
+
+    /// Bootstraps `app` with the given platform services.
+    pub fn with_services(app: A, mut services: ServiceRegistry) -> Self {
+        if services.get::<SharedExecutor>().is_none() {
+            let executor: SharedExecutor = Arc::new(LocalExecutor::new());
+            services.insert(executor);
+        }
+        let mut engine = EngineCore::new();
+        engine.set_fixed_delta(Some(HEADLESS_FRAME_STEP));
+        engine.bootstrap(app, services);
+        Self {
+            engine,
+            script: InputScript::new(),
+            frame: 0,
+            reports: Vec::new(),
+        }
+    }
+
+    /// Replaces the input script.
+    pub fn with_script(mut self, script: InputScript) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// Replaces the simulated frame step.
+    pub fn with_frame_step(mut self, step: Duration) -> Self {
+        self.engine.set_fixed_delta(Some(step));
+        self
+    }
+
+    /// Runs one frame and returns its report.
+    pub fn step(&mut self) -> &FrameReport {
+        let start = Instant::now();
+        for event in self.script.events_at(self.frame) {
+            self.engine.feed_input(event.clone());
+        }
+
+        let mut frame_services = ServiceRegistry::with_parent(Arc::clone(self.engine.services()));
+        frame_services.insert(PRIMARY_VIEWPORT);
+        let frame_services = Arc::new(frame_services);
+
+        let inputs = self.engine.drain_inputs();
+        self.engine.run_app_update(&inputs);
+        let presents = self.engine.begin_render_frame(&frame_services);
+        self.engine.run_scheduler(&frame_services);
+        let passes = self
+            .engine
+            .services()
+            .get::<SharedFrameGraph>()
+            .and_then(|graph| graph.lock().ok().map(|g| g.pass_names()))
+            .unwrap_or_default();
+        self.engine.end_render_frame(presents);
+        self.engine.run_maintenance();
+
+        let render_stats = presents
+            .then(|| {
+                self.engine
+                    .services()
+                    .get::<Arc<Mutex<Box<dyn RenderSystem>>>>()
+                    .and_then(|rs| rs.lock().ok().map(|rs| rs.get_last_frame_stats().clone()))
+            })
+            .flatten();
+
+        self.reports.push(FrameReport {
+            frame: self.frame,
+            inputs: inputs.len(),
+            passes,
+            presented: presents,
+            render_stats,
+            entities: self.world().iter_entities().count(),
+            cpu_time: start.elapsed(),
+        });
+        self.frame += 1;
+        self.reports.last().expect("report just pushed")
+    }
+
+    /// Runs `frames` frames and returns their reports.
+    pub fn run(&mut self, frames: u64) -> &[FrameReport] {
+        let first = self.reports.len();
+        for _ in 0..frames {
+            self.step();
+        }
+        &self.reports[first..]
+    }
+
+    /// Runs frames until every scripted event has been delivered.
+    pub fn run_script(&mut self) -> &[FrameReport] {
+        let remaining = self
+            .script
+            .last_frame()
+            .map_or(0, |last| (last + 1).saturating_sub(self.frame));
+        self.run(remaining)
+    }
+
+    /// Feeds `event` into the next frame, on top of the script.
+    pub fn inject(&mut self, event: InputEvent) {
+        self.engine.feed_input(event);
+    }
+
+    /// Suspends the engine as if the window were minimized — see
+    /// [`EngineCore::suspend`].
+    pub fn suspend(&mut self) {
+        self.engine.suspend();
+    }
+
+    /// Resumes the engine after [`suspend`](Self::suspend).
+    pub fn resume(&mut self) {
+        self.engine.resume();
+    }
+
+    /// Whether the engine is currently suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.engine.is_suspended()
+    }
+
+    /// Runs one low-power tick, as the windowed driver does while suspended.
+    pub fn background_tick(&mut self) {
+        self.engine.background_tick();
+    }
+
+    /// Index of the next frame to run.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Reports of every frame run so far.
+    pub fn reports(&self) -> &[FrameReport] {
+        &self.reports
+    }
+
+    /// Aggregate statistics of every frame run so far.
+    pub fn stats_summary(&self) -> FrameStatsSummary {
+        FrameStatsSummary::from_reports(&self.reports)
+    }
+
+    /// Checks the frames run so far against `budget`, scaling frame time by
+    /// the profile named in `KHORA_PERF_PROFILE` — see
+    /// [`PerfProfile::from_env`].
+    pub fn check_budget(&self, budget: &PerfBudget) -> Result<FrameStatsSummary, BudgetExceeded> {
+        budget.check(&self.reports, &PerfProfile::from_env())
+    }
+
+    /// Like [`check_budget`](Self::check_budget), but panics with the list
+    /// of broken bounds.
+    #[track_caller]
+    pub fn assert_budget(&self, budget: &PerfBudget) -> FrameStatsSummary {
+        match self.check_budget(budget) {
+            Ok(summary) => summary,
+            Err(exceeded) => panic!("{exceeded}"),
+        }
+    }
+
+    /// The game world.
+    pub fn world(&self) -> &GameWorld {
+        self.engine
+            .game_world()
+            .expect("HeadlessRunner bootstraps the engine")
+    }
+
+    /// Mutable access to the game world, e.g. to inject events between frames.
+    pub fn world_mut(&mut self) -> &mut GameWorld {
+        self.engine
+            .game_world_mut()
+            .expect("HeadlessRunner bootstraps the engine")
+    }
+
+    /// The application.
+    pub fn app_mut(&mut self) -> &mut A {
+        self.engine
+            .app_mut()
+            .expect("HeadlessRunner bootstraps the engine")
+    }
+
+    /// The engine service registry.
+    pub fn services(&self) -> &Arc<ServiceRegistry> {
+        self.engine.services()
+    }
+
+    /// Shuts the engine down — see [`EngineCore::shutdown`] for the order.
+    pub fn shutdown(mut self) {
+        self.engine.shutdown();
+    }
+}
+
+impl<A: EngineApp> Drop for HeadlessRunner<A> {
+    fn drop(&mut self) {
+        // No-op if `shutdown` already ran.
+        self.engine.shutdown();
+    }
+}
@@ -20,16 +20,49 @@
 #![warn(missing_docs)]
 
 mod engine;
+mod engine_logger;
 mod game_world;
+mod headless;
+mod log_control;
+mod log_tail;
+mod log_time;
+mod perf_budget;
+mod perf_profile;
+mod plugin;
+mod plugin_set;
+mod rotating_file_log;
+mod run_log_dir;
+mod startup;
+mod thumbnail;
 mod traits;
 mod vessel;
+mod watchdog;
 pub mod winit_adapters;
+mod world_desc;
+mod worlds;
 
 pub use engine::EngineCore;
+pub use engine_logger::EngineLogger;
 pub use game_world::GameWorld;
+pub use headless::{
+    FrameReport, FrameStatsSummary, HeadlessRunner, InputScript, HEADLESS_FRAME_STEP,
+};
+pub use log_control::LogControl;
+pub use log_tail::{LogTail, DEFAULT_LOG_TAIL_LINES};
+pub use perf_budget::{BudgetExceeded, BudgetViolation, PerfBudget};
+pub use perf_profile::{PerfProfile, PERF_PROFILE_ENV};
+pub use plugin::Plugin;
+pub use plugin_set::PluginSet;
+pub use rotating_file_log::{LogRotation, RotatingFileLog};
+pub use run_log_dir::RunLogDir;
+pub use startup::{StartupPhase, StartupProfile, DEFAULT_FIRST_FRAME_BUDGET};
+pub use thumbnail::ThumbnailService;
 pub use traits::{AgentProvider, EngineApp, PhaseProvider, WindowProvider};
 pub use vessel::{spawn_cube_at, spawn_plane, spawn_sphere, Vessel};
+pub use watchdog::{Heartbeat, Watchdog, WatchdogConfig};
 pub use winit_adapters::{run_winit, WinitAppRunner};
+pub use world_desc::WorldDesc;
+pub use worlds::{SharedWorlds, WorldId, Worlds};
 
 // Re-export window provider for convenience
 pub use winit_adapters::WinitWindowProvider;
@@ -47,58 +80,83 @@ pub mod editor_ui {
 // ─────────────────────────────────────────────────────────────────────
 
 // Control / DCC
-pub use khora_control::{Context as EngineContext, DccConfig, DccService, EngineMode};
+pub use khora_control::{
+    Context as EngineContext, DccConfig, DccService, EngineMode, EnginePlugin,
+};
 // Re-export the same Context as `DccContext` so editor code can use the
 // more descriptive name without a separate `use` line. (Same type — both
 // re-exports point at `khora_control::Context`.)
 pub use khora_control::registry::AgentRegistry;
 pub use khora_control::scheduler::ExecutionScheduler;
 pub use khora_control::Context as DccContext;
+pub use khora_control::{PowerGovernor, PowerPolicy, PowerState};
 
 // Core types
 pub use khora_core::agent::{AgentImportance, ExecutionPhase, ExecutionTiming};
 pub use khora_core::control::gorna::{AgentId, AgentStatus, StrategyId};
-pub use khora_core::telemetry::{MonitoredResourceType, TelemetryEvent};
+pub use khora_core::cvar::{
+    CVar, CVarAllowlist, CVarError, CVarKind, CVarOrigin, CVarRegistry, CVarRequest, CVarResponse,
+    CVarValue,
+};
+pub use khora_core::telemetry::{MetricId, MetricPattern, MonitoredResourceType, TelemetryEvent};
 pub use khora_core::ui::editor::generate_selection_gizmos;
 pub use khora_core::ui::editor::gizmo::GizmoKind;
 pub use khora_core::ui::editor::gizmo::GizmoLineInstance;
 pub use khora_core::ui::editor::viewport_texture::ViewportTextureHandle;
 pub use khora_core::ui::editor::{
-    AssetEntry, CommandHistory, ComponentJson, EditorCamera, EditorCommand, EditorLogCapture,
-    EditorMode, EditorPanel, EditorShell, EditorState, EditorTheme, EntityIcon, FontFamilyHint,
-    GizmoMode, Icon, InspectedEntity, Interaction, LogEntry, LogLevel, PanelLocation, PlayMode,
-    PropertyEdit, SceneNode, StatusBarData, TextAlign, UiBuilder,
+    AssetEntry, CameraBookmarks, CameraPose, CommandHistory, ComponentJson, EditorCamera,
+    EditorCommand, EditorLogCapture, EditorMode, EditorPanel, EditorShell, EditorState,
+    EditorTheme, EntityIcon, FontFamilyHint, GizmoMode, Icon, InspectedEntity, Interaction,
+    LogEntry, LogLevel, PanelLocation, PlayMode, PropertyEdit, SceneNode, StatusBarData, TextAlign,
+    UiBuilder, ViewportOverlays,
 };
 pub use khora_core::ServiceRegistry;
+pub use khora_core::{assert_main_thread, threading};
 
 // Telemetry service
 pub use khora_telemetry::MonitorRegistry;
 pub use khora_telemetry::TelemetryService;
+pub use khora_telemetry::{
+    FrameRecord, MetricHistory, MetricSample, MetricSubscription, MetricSubscriptions,
+};
 // AgentRegistry is already re-exported above (line 51) via
 // `pub use khora_control::registry::AgentRegistry`.
 
 // Infra / monitors
 pub use khora_infra::telemetry::memory_monitor::MemoryMonitor;
-pub use khora_infra::GpuMonitor;
+pub use khora_infra::{GpuMonitor, HardwareHealthMonitor};
+
+// Async executors.
+pub use khora_core::executor::{block_on, Executor, SharedExecutor, Task};
+pub use khora_infra::{LocalExecutor, ThreadPoolExecutor};
 
 // I/O
 pub use khora_core::asset::AssetSource;
-pub use khora_core::scene::{SceneFile, SerializationGoal};
-pub use khora_io::asset::{AssetIo, FileLoader};
-pub use khora_io::serialization::SerializationService;
+pub use khora_core::asset::{Thumbnail, DEFAULT_THUMBNAIL_SIZE};
+pub use khora_core::scene::{SceneFile, SceneMetadata, SerializationGoal};
+pub use khora_io::asset::{
+    AssetBytes, AssetIo, AssetRead, FileLoader, MappedPackLoader, PackLoader, ThumbnailCache,
+};
+pub use khora_io::export::{GltfExportError, GltfExporter};
+pub use khora_io::serialization::{DefinitionFormat, DeserializationError, SerializationService};
 
 // Mesh type (used by editor ops)
 pub use khora_core::renderer::api::scene::mesh::Mesh;
 
 /// Renderer sub-modules (used by editor gizmo)
 pub mod renderer {
+    pub use khora_core::renderer::api::core::DepthPrepassMode;
     pub use khora_core::renderer::api::resource;
     pub use khora_core::renderer::api::scene;
     pub use khora_core::renderer::light;
 }
 
-// WgpuRenderSystem (used by editor main)
-pub use khora_infra::WgpuRenderSystem;
+// Pixel-exact picking through the entity ID pass
+pub use khora_data::render::{EntityPicker, PickHandle, SharedEntityPicker};
+
+// WgpuRenderSystem (used by editor main) and the offscreen device used by
+// golden-image tests
+pub use khora_infra::{HeadlessWgpu, WgpuRenderSystem};
 
 // Data / ECS (needed for world restore)
 pub use khora_data;
@@ -111,7 +169,7 @@ pub use khora_core::renderer::traits::RenderSystem;
 pub use khora_data::ecs::HandleComponent;
 
 // PropertyEdit is in khora_core::ui::editor, already re-exported via editor_ui
-pub use khora_data::scene::ComponentRegistration;
+pub use khora_data::scene::{ComponentRegistration, SceneIssue, ValidationReport};
 
 // Agents (for when apps need to create their own)
 pub use khora_agents;
@@ -134,23 +192,66 @@ pub mod prelude {
     pub use crate::{WindowConfig, WindowIcon, PRIMARY_VIEWPORT};
 
     // Assets
-    pub use khora_core::asset::{AssetHandle, AssetUUID};
+    pub use khora_core::asset::{
+        AssetHandle, AssetUUID, Residency, ResidencyPolicy, ResidencyStats, ResidencyTier,
+    };
+    pub use khora_data::ResidencyManager;
 
-    // Memory tracking (for `#[global_allocator]`)
-    pub use khora_core::memory::SaaTrackingAllocator;
+    // Memory tracking (for `#[global_allocator]`) and leak checks
+    pub use khora_core::memory::{leak, LeakReport, LeakScope, SaaTrackingAllocator};
 
-    // Input
-    pub use khora_core::platform::{InputEvent, MouseButton};
+    // Input and power
+    pub use khora_core::platform::{InputEvent, MouseButton, PowerEvent};
 
     // ECS types
     pub mod ecs {
         //! Core ECS types for game logic.
         pub use khora_core::ecs::entity::EntityId;
         pub use khora_core::physics::{BodyType, ColliderShape};
+        pub use khora_core::renderer::api::core::{AntiAliasingMode, ToneMapping};
         pub use khora_core::renderer::light::{DirectionalLight, LightType, PointLight, SpotLight};
         pub use khora_data::ecs::{
-            AudioSource, Camera, Children, Collider, Component, ComponentBundle, GlobalTransform,
-            Light, MaterialComponent, Name, Parent, ProjectionType, RigidBody, Transform, Without,
+            Added, AnimationPlayer, Animator, AntiAliasing, AudioSource, AutoExposure, Bloom,
+            Bounds, Camera, CameraCollision, CameraRig, CameraRigMode, Changed, Children, Collider,
+            Commands, Component, ComponentBundle, Disabled, DynamicComponent, DynamicComponentId,
+            DynamicComponentLayout, DynamicFieldKind, DynamicValue, EntityMap, EntityRef, Exposure,
+            GlobalTransform, GravityZone, GravityZoneShape, Hidden, IkConstraint, IkSolver,
+            IncludeDisabled, Interactor, Light, LookAt, MapEntities, MaterialAnimation,
+            MaterialComponent, MaterialOverride, MorphWeights, Name, Or, Parent, PathFollower,
+            PathWrap, PhysicsInterpolation, PostProcess, ProjectionType, QueryFilter, RenderLayers,
+            RigidBody, SceneTrigger, SimulationAnchor, SimulationBand, SimulationLod, Skin, Sky,
+            SplinePath, Static, StaticBatch, Tag, Tags, TimeOfDay, TimelinePlayer, Transform,
+            TriggerCallbacks, TriggerEvent, TriggerKind, Vignette, Weather, WeatherAudio,
+            WeatherState, With, Without, WorldSnapshot,
+        };
+        pub use khora_data::flow::SimulationLodSettings;
+        pub use khora_data::scene::StaticBakeReport;
+    }
+
+    // Audio
+    pub mod audio {
+        //! Sound banks and the event service playing them.
+        pub use khora_data::assets::{SoundBank, SoundData, SoundEvent, SoundVariation};
+        pub use khora_data::AudioEvents;
+    }
+
+    // UI
+    pub mod ui {
+        //! Retained screen-space UI: anchored rects, images, text and buttons.
+        pub use khora_data::ui::{
+            UiAnchor, UiBorder, UiButton, UiColor, UiImage, UiInputRouter, UiInteraction,
+            UiInteractionState, UiNineSlice, UiNode, UiPointer, UiRect, UiText, UiTransform,
+        };
+    }
+
+    // Animation
+    pub mod animation {
+        //! Keyframed curves, clips and graphs for animating entities.
+        pub use khora_core::animation::{
+            Animatable, AnimationClip, AnimationGraph, AnimationParameters, AnimationState,
+            AnimationTrack, BlendSpace1D, BlendSpace2D, Condition, Curve, Easing, Interpolation,
+            Keyframe, Motion, Spline, Timeline, TimelineMarker, TimelineTrack, TrackValues,
+            Transition,
         };
     }
 
@@ -0,0 +1,109 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime control over the level of each log sink.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use log::LevelFilter;
+
+/// One named output of the [`EngineLogger`](crate::EngineLogger).
+pub(crate) struct LogSink {
+    pub(crate) name: String,
+    level: AtomicUsize,
+    pub(crate) log: Box<dyn log::Log>,
+}
+
+impl LogSink {
+    pub(crate) fn new(name: String, level: LevelFilter, log: Box<dyn log::Log>) -> Self {
+        Self {
+            name,
+            level: AtomicUsize::new(level as usize),
+            log,
+        }
+    }
+
+    pub(crate) fn level(&self) -> LevelFilter {
+        level_from_usize(self.level.load(Ordering::Relaxed))
+    }
+}
+
+/// A handle to the sinks of the installed [`EngineLogger`](crate::EngineLogger).
+///
+/// Cheap to clone. Register it as a service so tools such as the editor
+/// console can change levels while the engine runs.
+#[derive(Clone)]
+pub struct LogControl {
+    sinks: Arc<Vec<LogSink>>,
+}
+
+impl LogControl {
+    pub(crate) fn new(sinks: Arc<Vec<LogSink>>) -> Self {
+        Self { sinks }
+    }
+
+    /// Sets the level of the sink called `name`. Returns `false` if there
+    /// is no such sink.
+    pub fn set_level(&self, name: &str, level: LevelFilter) -> bool {
+        let Some(sink) = self.sinks.iter().find(|sink| sink.name == name) else {
+            return false;
+        };
+        sink.level.store(level as usize, Ordering::Relaxed);
+        log::set_max_level(self.max_level());
+        true
+    }
+
+    /// Returns the level of the sink called `name`.
+    pub fn level(&self, name: &str) -> Option<LevelFilter> {
+        self.sinks
+            .iter()
+            .find(|sink| sink.name == name)
+            .map(LogSink::level)
+    }
+
+    /// Returns every sink with its level, in installation order.
+    pub fn sinks(&self) -> Vec<(String, LevelFilter)> {
+        self.sinks
+            .iter()
+            .map(|sink| (sink.name.clone(), sink.level()))
+            .collect()
+    }
+
+    /// The most verbose level any sink accepts.
+    pub fn max_level(&self) -> LevelFilter {
+        self.sinks
+            .iter()
+            .map(LogSink::level)
+            .max()
+            .unwrap_or(LevelFilter::Off)
+    }
+}
+
+impl std::fmt::Debug for LogControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.sinks()).finish()
+    }
+}
+
+fn level_from_usize(level: usize) -> LevelFilter {
+    match level {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Error,
+        2 => LevelFilter::Warn,
+        3 => LevelFilter::Info,
+        4 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
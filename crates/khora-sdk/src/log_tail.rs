@@ -0,0 +1,110 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A ring buffer of recent log lines, for diagnostics dumps.
+//!
+//! [`LogTail`] wraps the application's logger. Every record is forwarded
+//! unchanged and a formatted copy is kept in a bounded, process-wide
+//! buffer. The [`Watchdog`](crate::Watchdog) writes that buffer into its
+//! stall reports.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Default number of lines kept by [`LogTail`].
+pub const DEFAULT_LOG_TAIL_LINES: usize = 256;
+
+static TAIL: Mutex<TailBuffer> = Mutex::new(TailBuffer {
+    lines: VecDeque::new(),
+    capacity: DEFAULT_LOG_TAIL_LINES,
+});
+
+struct TailBuffer {
+    lines: VecDeque<String>,
+    capacity: usize,
+}
+
+/// A `log::Log` wrapper that remembers the most recent lines.
+pub struct LogTail {
+    inner: Box<dyn log::Log>,
+}
+
+impl LogTail {
+    /// Wraps `inner`, keeping the last `capacity` lines.
+    pub fn new(inner: Box<dyn log::Log>, capacity: usize) -> Self {
+        let mut tail = TAIL.lock().unwrap_or_else(|e| e.into_inner());
+        tail.capacity = capacity.max(1);
+        while tail.lines.len() > tail.capacity {
+            tail.lines.pop_front();
+        }
+        Self { inner }
+    }
+
+    /// Wraps `inner` and installs the result as the global logger.
+    ///
+    /// ```rust,ignore
+    /// let logger = env_logger::Builder::from_env(Env::default().default_filter_or("info")).build();
+    /// let level = logger.filter();
+    /// LogTail::install(Box::new(logger), level, DEFAULT_LOG_TAIL_LINES)?;
+    /// ```
+    pub fn install(
+        inner: Box<dyn log::Log>,
+        level: log::LevelFilter,
+        capacity: usize,
+    ) -> Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(Self::new(inner, capacity)))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+
+    /// Returns a copy of the retained lines, oldest first.
+    pub fn recent() -> Vec<String> {
+        TAIL.lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .lines
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+impl log::Log for LogTail {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.inner.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "[{:<5} {} ({})] {}",
+            record.level(),
+            record.target(),
+            khora_core::threading::current_thread_name(),
+            record.args()
+        );
+        if let Ok(mut tail) = TAIL.lock() {
+            if tail.lines.len() >= tail.capacity {
+                tail.lines.pop_front();
+            }
+            tail.lines.push_back(line);
+        }
+        self.inner.log(record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
@@ -0,0 +1,352 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Performance budgets asserted on headless runs.
+//!
+//! A [`PerfBudget`] bounds the aggregate statistics of a run — draw calls,
+//! frame time and VRAM — and [`PerfBudget::check`] lists every bound a run
+//! broke, naming the worst frame of each. Warm-up frames (pipeline
+//! compilation, first uploads) can be excluded.
+//!
+//! ```rust,ignore
+//! let budget = PerfBudget::new()
+//!     .with_max_draw_calls(200)
+//!     .with_max_frame_time(Duration::from_millis(8))
+//!     .with_max_vram_mb(256.0)
+//!     .with_warmup_frames(5);
+//! let mut runner = HeadlessRunner::with_services(MyScene::new(), gpu_services);
+//! runner.run(120);
+//! runner.assert_budget(&budget);
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::headless::{FrameReport, FrameStatsSummary};
+use crate::perf_profile::PerfProfile;
+
+/// Upper bounds on the statistics of a headless run.
+///
+/// Unset bounds are not checked. Frame time is scaled by the
+/// [`PerfProfile`] of the machine; draw calls and VRAM are not.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PerfBudget {
+    /// Most draw calls any frame may encode.
+    pub max_draw_calls: Option<u32>,
+    /// Longest frame allowed on the reference machine.
+    pub max_frame_time: Option<Duration>,
+    /// Highest VRAM estimate allowed, in megabytes.
+    pub max_vram_mb: Option<f32>,
+    /// Number of leading frames left out of the check.
+    pub warmup_frames: usize,
+}
+
+impl PerfBudget {
+    /// Creates a budget with no bounds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bounds the draw calls of every frame.
+    pub fn with_max_draw_calls(mut self, draw_calls: u32) -> Self {
+        self.max_draw_calls = Some(draw_calls);
+        self
+    }
+
+    /// Bounds the frame time on the reference machine.
+    pub fn with_max_frame_time(mut self, frame_time: Duration) -> Self {
+        self.max_frame_time = Some(frame_time);
+        self
+    }
+
+    /// Bounds the VRAM estimate, in megabytes.
+    pub fn with_max_vram_mb(mut self, vram_mb: f32) -> Self {
+        self.max_vram_mb = Some(vram_mb);
+        self
+    }
+
+    /// Leaves the first `frames` frames out of the check.
+    pub fn with_warmup_frames(mut self, frames: usize) -> Self {
+        self.warmup_frames = frames;
+        self
+    }
+
+    /// Checks `reports` against the budget on a machine matching `profile`.
+    ///
+    /// Returns the summary of the measured frames, or every broken bound.
+    /// Draw-call and VRAM bounds are skipped with a warning when no measured
+    /// frame carries render statistics (no render system was injected).
+    pub fn check(
+        &self,
+        reports: &[FrameReport],
+        profile: &PerfProfile,
+    ) -> Result<FrameStatsSummary, BudgetExceeded> {
+        let measured = reports.get(self.warmup_frames..).unwrap_or(&[]);
+        let summary = FrameStatsSummary::from_reports(measured);
+        let mut violations = Vec::new();
+
+        if measured.is_empty() {
+            violations.push(BudgetViolation::NoFrames {
+                warmup: self.warmup_frames,
+            });
+        }
+
+        if summary.rendered_frames == 0
+            && !measured.is_empty()
+            && (self.max_draw_calls.is_some() || self.max_vram_mb.is_some())
+        {
+            log::warn!(
+                "No measured frame has render stats; draw-call and VRAM budgets are not checked"
+            );
+        }
+
+        if let Some(limit) = self.max_draw_calls {
+            let worst = measured
+                .iter()
+                .filter_map(|r| r.render_stats.as_ref().map(|s| (r.frame, s.draw_calls)))
+                .max_by_key(|&(_, count)| count);
+            if let Some((frame, count)) = worst.filter(|&(_, count)| count > limit) {
+                violations.push(BudgetViolation::DrawCalls {
+                    frame,
+                    count,
+                    limit,
+                });
+            }
+        }
+
+        if let Some(limit) = self.max_frame_time {
+            let limit = limit.mul_f32(profile.frame_time_scale);
+            let worst = measured
+                .iter()
+                .map(|r| (r.frame, r.frame_time()))
+                .max_by_key(|&(_, time)| time);
+            if let Some((frame, time)) = worst.filter(|&(_, time)| time > limit) {
+                violations.push(BudgetViolation::FrameTime { frame, time, limit });
+            }
+        }
+
+        if let Some(limit_mb) = self.max_vram_mb {
+            let worst = measured
+                .iter()
+                .filter_map(|r| {
+                    r.render_stats
+                        .as_ref()
+                        .map(|s| (r.frame, s.vram_usage_estimate_mb))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((frame, mb)) = worst.filter(|&(_, mb)| mb > limit_mb) {
+                violations.push(BudgetViolation::Vram {
+                    frame,
+                    mb,
+                    limit_mb,
+                });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(summary)
+        } else {
+            Err(BudgetExceeded {
+                profile: *profile,
+                summary,
+                violations,
+            })
+        }
+    }
+}
+
+/// One bound of a [`PerfBudget`] that a run broke.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum BudgetViolation {
+    /// Every frame was a warm-up frame, so nothing was measured.
+    NoFrames {
+        /// Warm-up frames of the budget.
+        warmup: usize,
+    },
+    /// A frame encoded too many draw calls.
+    DrawCalls {
+        /// Frame with the most draw calls.
+        frame: u64,
+        /// Draw calls of that frame.
+        count: u32,
+        /// The budget.
+        limit: u32,
+    },
+    /// A frame took too long.
+    FrameTime {
+        /// Slowest frame.
+        frame: u64,
+        /// Time of that frame.
+        time: Duration,
+        /// The budget, scaled by the profile.
+        limit: Duration,
+    },
+    /// The VRAM estimate went over the ceiling.
+    Vram {
+        /// Frame with the highest estimate.
+        frame: u64,
+        /// Estimate of that frame, in megabytes.
+        mb: f32,
+        /// The ceiling, in megabytes.
+        limit_mb: f32,
+    },
+}
+
+impl fmt::Display for BudgetViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoFrames { warmup } => {
+                write!(f, "no frame measured after {warmup} warm-up frames")
+            }
+            Self::DrawCalls {
+                frame,
+                count,
+                limit,
+            } => write!(f, "frame {frame}: {count} draw calls (budget {limit})"),
+            Self::FrameTime { frame, time, limit } => write!(
+                f,
+                "frame {frame}: {:.2} ms (budget {:.2} ms)",
+                time.as_secs_f64() * 1000.0,
+                limit.as_secs_f64() * 1000.0
+            ),
+            Self::Vram {
+                frame,
+                mb,
+                limit_mb,
+            } => write!(
+                f,
+                "frame {frame}: {mb:.1} MB of VRAM (budget {limit_mb:.1} MB)"
+            ),
+        }
+    }
+}
+
+/// A run that broke at least one bound of its [`PerfBudget`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetExceeded {
+    /// Profile the frame-time bound was scaled with.
+    pub profile: PerfProfile,
+    /// Summary of the measured frames.
+    pub summary: FrameStatsSummary,
+    /// Every broken bound.
+    pub violations: Vec<BudgetViolation>,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "performance budget exceeded on the '{}' profile ({} measured frames)",
+            self.profile.name, self.summary.frames
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n  - {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_core::renderer::api::core::RenderStats;
+
+    fn report(frame: u64, draw_calls: u32, cpu_ms: u64, vram_mb: f32) -> FrameReport {
+        FrameReport {
+            frame,
+            presented: true,
+            cpu_time: Duration::from_millis(cpu_ms),
+            render_stats: Some(RenderStats {
+                draw_calls,
+                vram_usage_estimate_mb: vram_mb,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_budget_reports_worst_frame_of_each_bound() {
+        let reports = [
+            report(0, 500, 200, 900.0),
+            report(1, 40, 4, 120.0),
+            report(2, 90, 12, 140.0),
+            report(3, 60, 5, 300.0),
+        ];
+        let budget = PerfBudget::new()
+            .with_max_draw_calls(80)
+            .with_max_frame_time(Duration::from_millis(10))
+            .with_max_vram_mb(256.0)
+            .with_warmup_frames(1);
+
+        let err = budget.check(&reports, &PerfProfile::REFERENCE).unwrap_err();
+        assert_eq!(err.summary.frames, 3);
+        assert_eq!(
+            err.violations,
+            vec![
+                BudgetViolation::DrawCalls {
+                    frame: 2,
+                    count: 90,
+                    limit: 80
+                },
+                BudgetViolation::FrameTime {
+                    frame: 2,
+                    time: Duration::from_millis(12),
+                    limit: Duration::from_millis(10)
+                },
+                BudgetViolation::Vram {
+                    frame: 3,
+                    mb: 300.0,
+                    limit_mb: 256.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_profile_scales_frame_time_only() {
+        let reports = [report(0, 90, 12, 100.0)];
+        let budget = PerfBudget::new()
+            .with_max_draw_calls(80)
+            .with_max_frame_time(Duration::from_millis(10));
+
+        let err = budget.check(&reports, &PerfProfile::CI).unwrap_err();
+        assert!(matches!(
+            err.violations.as_slice(),
+            [BudgetViolation::DrawCalls { .. }]
+        ));
+
+        let summary = PerfBudget::new()
+            .with_max_frame_time(Duration::from_millis(10))
+            .check(&reports, &PerfProfile::CI)
+            .unwrap();
+        assert_eq!(summary.max_draw_calls, 90);
+    }
+
+    #[test]
+    fn test_all_warmup_frames_is_a_violation() {
+        let reports = [report(0, 1, 1, 1.0)];
+        let err = PerfBudget::new()
+            .with_warmup_frames(5)
+            .check(&reports, &PerfProfile::REFERENCE)
+            .unwrap_err();
+        assert_eq!(
+            err.violations,
+            vec![BudgetViolation::NoFrames { warmup: 5 }]
+        );
+    }
+}
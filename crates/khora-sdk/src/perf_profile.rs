@@ -0,0 +1,83 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reference machine profiles for performance gates.
+//!
+//! Frame-time budgets are written for one reference machine. A
+//! [`PerfProfile`] scales them to the machine actually running the gate, so
+//! a slower CI runner does not fail a budget the reference machine meets.
+//! Draw-call and VRAM ceilings do not depend on the machine and are never
+//! scaled.
+
+/// Environment variable naming the profile of the current machine.
+pub const PERF_PROFILE_ENV: &str = "KHORA_PERF_PROFILE";
+
+/// How the machine running a performance gate compares to the reference one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerfProfile {
+    /// Name shown in gate reports.
+    pub name: &'static str,
+    /// Factor applied to frame-time budgets (`2.0` allows frames twice as long).
+    pub frame_time_scale: f32,
+}
+
+impl PerfProfile {
+    /// The machine budgets are written for.
+    pub const REFERENCE: Self = Self {
+        name: "reference",
+        frame_time_scale: 1.0,
+    };
+
+    /// Shared CI runners: slower and noisier than the reference machine.
+    pub const CI: Self = Self {
+        name: "ci",
+        frame_time_scale: 3.0,
+    };
+
+    /// Reads the profile from [`PERF_PROFILE_ENV`].
+    ///
+    /// Accepts `reference`, `ci`, or a bare scale such as `1.5`. Unset or
+    /// unrecognized values fall back to [`PerfProfile::REFERENCE`].
+    pub fn from_env() -> Self {
+        match std::env::var(PERF_PROFILE_ENV) {
+            Ok(value) => Self::parse(&value).unwrap_or_else(|| {
+                log::warn!("Unknown {PERF_PROFILE_ENV} '{value}', using the reference profile");
+                Self::REFERENCE
+            }),
+            Err(_) => Self::REFERENCE,
+        }
+    }
+
+    /// Parses a profile name or a bare frame-time scale.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "reference" => Some(Self::REFERENCE),
+            "ci" => Some(Self::CI),
+            other => other
+                .parse::<f32>()
+                .ok()
+                .filter(|scale| scale.is_finite() && *scale > 0.0)
+                .map(|frame_time_scale| Self {
+                    name: "custom",
+                    frame_time_scale,
+                }),
+        }
+    }
+}
+
+impl Default for PerfProfile {
+    fn default() -> Self {
+        Self::REFERENCE
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `Plugin` trait — packaged extensions installed by the application.
+
+use khora_control::{DccService, EnginePlugin};
+use khora_core::ServiceRegistry;
+
+use crate::GameWorld;
+
+/// A packaged engine extension.
+///
+/// Ecosystem crates implement `Plugin` to extend Khora without forking it;
+/// applications install plugins from [`EngineApp::plugins`](crate::EngineApp::plugins)
+/// with [`PluginSet::add_plugin`](crate::PluginSet::add_plugin). During
+/// bootstrap the engine calls, for every plugin in install order:
+///
+/// 1. [`build`](Self::build) — before the app's `setup`.
+/// 2. [`register_agents`](Self::register_agents) — after the app's own agents.
+/// 3. [`phase_hooks`](Self::phase_hooks) — once the scheduler exists.
+/// 4. [`startup`](Self::startup) — after every agent is initialized.
+///
+/// Lanes belong to agents, so a plugin ships its lanes inside the agents it
+/// registers. ECS components and `DataSystem`s are discovered at link time
+/// through `inventory::submit!`, so a plugin crate only needs to be linked
+/// for those; `build` is where their runtime registration happens.
+pub trait Plugin: Send + Sync + 'static {
+    /// Unique name of the plugin. A second plugin with the same name is
+    /// not installed.
+    fn name(&self) -> &str;
+
+    /// Registers components, events and services.
+    ///
+    /// Runs before the app's `setup`, so the app already sees them.
+    fn build(&self, _world: &mut GameWorld, _services: &mut ServiceRegistry) {}
+
+    /// Registers the plugin's agents with the DCC, like
+    /// [`AgentProvider::register_agents`](crate::AgentProvider::register_agents).
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+
+    /// Returns per-phase hooks run by the scheduler every frame, before the
+    /// agents of that phase. Use them to handle the world's events.
+    fn phase_hooks(&self) -> Vec<EnginePlugin> {
+        Vec::new()
+    }
+
+    /// Startup system: runs once, after every agent is initialized and
+    /// before the first frame.
+    fn startup(&self, _world: &mut GameWorld, _services: &ServiceRegistry) {}
+}
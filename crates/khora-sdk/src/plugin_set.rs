@@ -0,0 +1,103 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `PluginSet` — the plugins an application installs.
+
+use khora_control::{DccService, EnginePlugin};
+use khora_core::ServiceRegistry;
+
+use crate::plugin::Plugin;
+use crate::GameWorld;
+
+/// The plugins installed by an application, in install order.
+///
+/// Filled by [`EngineApp::plugins`](crate::EngineApp::plugins):
+///
+/// ```rust,ignore
+/// fn plugins(&self, plugins: &mut PluginSet) {
+///     plugins.add_plugin(TerrainPlugin).add_plugin(NetcodePlugin::default());
+/// }
+/// ```
+#[derive(Default)]
+pub struct PluginSet {
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+impl PluginSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Installs a plugin.
+    ///
+    /// A plugin whose name is already installed is skipped with a warning.
+    pub fn add_plugin(&mut self, plugin: impl Plugin) -> &mut Self {
+        if self.contains(plugin.name()) {
+            log::warn!(
+                "PluginSet: plugin '{}' is already installed, skipping",
+                plugin.name()
+            );
+        } else {
+            log::info!("PluginSet: installed plugin '{}'", plugin.name());
+            self.plugins.push(Box::new(plugin));
+        }
+        self
+    }
+
+    /// Returns `true` if a plugin with this name is installed.
+    pub fn contains(&self, name: &str) -> bool {
+        self.plugins.iter().any(|plugin| plugin.name() == name)
+    }
+
+    /// Returns the names of the installed plugins, in install order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.iter().map(|plugin| plugin.name())
+    }
+
+    /// Returns the number of installed plugins.
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// Returns `true` if no plugin is installed.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    pub(crate) fn build(&self, world: &mut GameWorld, services: &mut ServiceRegistry) {
+        for plugin in &self.plugins {
+            plugin.build(world, services);
+        }
+    }
+
+    pub(crate) fn register_agents(&self, dcc: &DccService, services: &mut ServiceRegistry) {
+        for plugin in &self.plugins {
+            plugin.register_agents(dcc, services);
+        }
+    }
+
+    pub(crate) fn phase_hooks(&self) -> Vec<EnginePlugin> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| plugin.phase_hooks())
+            .collect()
+    }
+
+    pub(crate) fn startup(&self, world: &mut GameWorld, services: &ServiceRegistry) {
+        for plugin in &self.plugins {
+            plugin.startup(world, services);
+        }
+    }
+}
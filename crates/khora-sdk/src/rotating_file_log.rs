@@ -0,0 +1,212 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A log file that rotates by size and by date.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, LineWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use crate::log_time::UtcTime;
+
+/// When a [`RotatingFileLog`] moves on to a new file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogRotation {
+    /// Rotate before a line would take the file past this size. `None`
+    /// disables the size limit.
+    pub max_bytes: Option<u64>,
+    /// Rotate on the first line of a new UTC day.
+    pub daily: bool,
+    /// Rotated files kept next to the active one. Older ones are deleted.
+    pub keep: usize,
+}
+
+impl Default for LogRotation {
+    fn default() -> Self {
+        Self {
+            max_bytes: Some(10 * 1024 * 1024),
+            daily: true,
+            keep: 5,
+        }
+    }
+}
+
+/// A `log::Log` that appends formatted lines to a file.
+///
+/// On rotation `engine.log` becomes `engine.1.log`, the previous
+/// `engine.1.log` becomes `engine.2.log`, and so on up to
+/// [`LogRotation::keep`]. The sink accepts every level; wrap it in an
+/// [`EngineLogger`](crate::EngineLogger) to filter it.
+pub struct RotatingFileLog {
+    path: PathBuf,
+    rotation: LogRotation,
+    state: Mutex<FileState>,
+}
+
+struct FileState {
+    /// `None` between a rotation and the next line, or after a failed open.
+    file: Option<LineWriter<File>>,
+    len: u64,
+    date: String,
+}
+
+impl RotatingFileLog {
+    /// Opens `path` for appending, creating it and its directory if needed.
+    pub fn open(path: impl Into<PathBuf>, rotation: LogRotation) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = open_append(&path)?;
+        let metadata = file.metadata()?;
+        let date = metadata
+            .modified()
+            .map(|modified| UtcTime::from_system(modified).date())
+            .unwrap_or_else(|_| UtcTime::now().date());
+        Ok(Self {
+            path,
+            rotation,
+            state: Mutex::new(FileState {
+                file: Some(LineWriter::new(file)),
+                len: metadata.len(),
+                date,
+            }),
+        })
+    }
+
+    /// The active log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of the `index`-th rotated file, 1 being the most recent.
+    pub fn rotated_path(&self, index: usize) -> PathBuf {
+        let stem = self
+            .path
+            .file_stem()
+            .map_or_else(|| "log".into(), |s| s.to_string_lossy());
+        let name = match self.path.extension() {
+            Some(ext) => format!("{}.{}.{}", stem, index, ext.to_string_lossy()),
+            None => format!("{}.{}", stem, index),
+        };
+        self.path.with_file_name(name)
+    }
+
+    /// Appends one line, rotating first if the policy asks for it.
+    pub fn write_line(&self, line: &str) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let today = UtcTime::now().date();
+        let bytes = line.len() as u64 + 1;
+        let too_big = self
+            .rotation
+            .max_bytes
+            .is_some_and(|max| state.len > 0 && state.len + bytes > max);
+        let new_day = self.rotation.daily && state.date != today;
+        if too_big || new_day {
+            self.rotate(&mut state)?;
+            state.date = today;
+        }
+        if state.file.is_none() {
+            state.file = Some(LineWriter::new(open_append(&self.path)?));
+        }
+        if let Some(file) = state.file.as_mut() {
+            writeln!(file, "{}", line)?;
+        }
+        state.len += bytes;
+        Ok(())
+    }
+
+    /// Closes the active file and shifts the rotated ones. The next line
+    /// opens a fresh file.
+    fn rotate(&self, state: &mut FileState) -> io::Result<()> {
+        // Windows cannot rename a file that is still open.
+        if let Some(mut file) = state.file.take() {
+            file.flush()?;
+        }
+        state.len = 0;
+        if self.rotation.keep == 0 {
+            return fs::remove_file(&self.path);
+        }
+        let oldest = self.rotated_path(self.rotation.keep);
+        if oldest.exists() {
+            fs::remove_file(&oldest)?;
+        }
+        for index in (1..self.rotation.keep).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.rotated_path(1))
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+impl log::Log for RotatingFileLog {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        let line = format!(
+            "[{} {:<5} {} ({})] {}",
+            UtcTime::now(),
+            record.level(),
+            record.target(),
+            khora_core::threading::current_thread_name(),
+            record.args()
+        );
+        // A logger has nowhere to report its own failures; the line is lost.
+        let _ = self.write_line(&line);
+    }
+
+    fn flush(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(file) = state.file.as_mut() {
+            let _ = file.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_by_size_and_keeps_the_newest_files() {
+        let dir = std::env::temp_dir().join(format!("khora-log-rotation-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let rotation = LogRotation {
+            max_bytes: Some(16),
+            daily: false,
+            keep: 2,
+        };
+        let log = RotatingFileLog::open(dir.join("engine.log"), rotation).unwrap();
+        for line in ["first line", "second line", "third line", "fourth line"] {
+            log.write_line(line).unwrap();
+        }
+
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(read(log.path().to_path_buf()), "fourth line\n");
+        assert_eq!(read(log.rotated_path(1)), "third line\n");
+        assert_eq!(read(log.rotated_path(2)), "second line\n");
+        assert!(!log.rotated_path(3).exists());
+        assert_eq!(log.rotated_path(1), dir.join("engine.1.log"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A directory per engine run, holding everything a bug report needs.
+//!
+//! ```text
+//! logs/
+//! └── run-2026-10-16_09-12-44-4242/
+//!     ├── engine.log        (RotatingFileLog, if the app installs one)
+//!     ├── hardware.json     (the startup hardware survey)
+//!     ├── crash-….txt       (one per panic)
+//!     └── khora-stall-….txt (watchdog reports)
+//! ```
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::log_tail::LogTail;
+use crate::log_time::UtcTime;
+
+static CURRENT: OnceLock<RunLogDir> = OnceLock::new();
+
+const RUN_PREFIX: &str = "run-";
+
+/// The log directory of the current run.
+#[derive(Debug, Clone)]
+pub struct RunLogDir {
+    path: PathBuf,
+}
+
+impl RunLogDir {
+    /// Creates `root/run-<UTC time>-<pid>`, then deletes the oldest run
+    /// directories under `root` so that at most `keep` remain, this one
+    /// included.
+    pub fn create(root: impl AsRef<Path>, keep: usize) -> io::Result<Self> {
+        let root = root.as_ref();
+        let name = format!(
+            "{}{}-{}",
+            RUN_PREFIX,
+            UtcTime::now().file_stamp(),
+            std::process::id()
+        );
+        let path = root.join(name);
+        fs::create_dir_all(&path)?;
+        prune_runs(root, keep.max(1))?;
+        Ok(Self { path })
+    }
+
+    /// Makes this the run's directory and installs the crash handler.
+    ///
+    /// Only the first call takes effect; later ones return the directory
+    /// already installed.
+    pub fn install(self) -> &'static RunLogDir {
+        let mut installed = false;
+        let current = CURRENT.get_or_init(|| {
+            installed = true;
+            self
+        });
+        if installed {
+            current.install_crash_handler();
+        }
+        current
+    }
+
+    /// The directory installed by [`install`](Self::install), if any.
+    ///
+    /// The engine writes its hardware report there, and the watchdog its
+    /// stall reports.
+    pub fn current() -> Option<&'static RunLogDir> {
+        CURRENT.get()
+    }
+
+    /// The run's directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Where the run's main log file goes.
+    pub fn log_path(&self) -> PathBuf {
+        self.path.join("engine.log")
+    }
+
+    /// Where the startup hardware survey is saved.
+    pub fn hardware_report_path(&self) -> PathBuf {
+        self.path.join("hardware.json")
+    }
+
+    /// Writes a crash report into the directory on every panic, then runs
+    /// the previous panic hook.
+    fn install_crash_handler(&'static self) {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let path = self
+                .path
+                .join(format!("crash-{}.txt", UtcTime::now().file_stamp()));
+            let mut report = String::new();
+            let _ = writeln!(report, "Khora crash report");
+            let _ = writeln!(report, "time: {} UTC", UtcTime::now());
+            let _ = writeln!(
+                report,
+                "thread: {}",
+                khora_core::threading::current_thread_name()
+            );
+            let _ = writeln!(report, "{}\n", info);
+            let _ = writeln!(
+                report,
+                "backtrace:\n{}",
+                std::backtrace::Backtrace::force_capture()
+            );
+            let _ = writeln!(report, "recent log lines:");
+            for line in LogTail::recent() {
+                let _ = writeln!(report, "  {}", line);
+            }
+            match fs::write(&path, report) {
+                Ok(()) => log::error!("Crash report written to {}", path.display()),
+                Err(e) => log::error!(
+                    "Failed to write the crash report to {}: {}",
+                    path.display(),
+                    e
+                ),
+            }
+            log::logger().flush();
+            previous(info);
+        }));
+    }
+}
+
+/// Deletes the oldest `run-*` directories under `root` beyond `keep`.
+fn prune_runs(root: &Path, keep: usize) -> io::Result<()> {
+    let mut runs: Vec<PathBuf> = fs::read_dir(root)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(RUN_PREFIX))
+        .map(|entry| entry.path())
+        .collect();
+    // Names start with a sortable UTC stamp.
+    runs.sort();
+    let excess = runs.len().saturating_sub(keep);
+    for old in &runs[..excess] {
+        if let Err(e) = fs::remove_dir_all(old) {
+            log::warn!("Failed to delete old run logs {}: {}", old.display(), e);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,255 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Startup time profiling.
+//!
+//! The engine splits startup into [`StartupPhase`]s and times each one in a
+//! shared [`StartupProfile`]: platform init (event loop and window), renderer
+//! init (the windowing driver's bootstrap closure), engine bootstrap (DCC,
+//! agents and `EngineApp::setup`) and the first frame. The asset index is
+//! loaded by the app, so the app times it itself:
+//!
+//! ```rust,ignore
+//! let profile = services.get::<StartupProfile>().cloned().unwrap_or_default();
+//! let assets = profile.time(StartupPhase::AssetIndex, || AssetService::new(&index, io, metrics))?;
+//! ```
+//!
+//! Time recorded for a phase inside another one is subtracted from the outer
+//! phase, so the phases add up without overlap.
+
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long the first frame may take, by default, before the engine warns.
+pub const DEFAULT_FIRST_FRAME_BUDGET: Duration = Duration::from_secs(3);
+
+/// A timed stage of engine startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StartupPhase {
+    /// Event loop and window creation.
+    Platform,
+    /// Graphics device, surface and renderer creation.
+    Renderer,
+    /// Loading the asset index (`index.bin`).
+    AssetIndex,
+    /// DCC, telemetry, agents, plugins and `EngineApp::setup`.
+    Engine,
+    /// From the end of bootstrap to the end of the first frame.
+    FirstFrame,
+}
+
+impl StartupPhase {
+    /// Every phase, in startup order.
+    pub const ALL: [StartupPhase; 5] = [
+        StartupPhase::Platform,
+        StartupPhase::Renderer,
+        StartupPhase::AssetIndex,
+        StartupPhase::Engine,
+        StartupPhase::FirstFrame,
+    ];
+
+    /// The phase's name, as used in logs and telemetry labels.
+    pub fn name(self) -> &'static str {
+        match self {
+            StartupPhase::Platform => "platform",
+            StartupPhase::Renderer => "renderer",
+            StartupPhase::AssetIndex => "asset_index",
+            StartupPhase::Engine => "engine",
+            StartupPhase::FirstFrame => "first_frame",
+        }
+    }
+}
+
+/// Phase timings of the current startup, shared through the
+/// `ServiceRegistry`. Cloning it shares the same timings.
+#[derive(Debug, Clone)]
+pub struct StartupProfile {
+    inner: Arc<Mutex<ProfileState>>,
+}
+
+#[derive(Debug)]
+struct ProfileState {
+    origin: Instant,
+    phases: Vec<(StartupPhase, Duration)>,
+    finished: Option<Duration>,
+}
+
+impl StartupProfile {
+    /// Starts a profile; [`total`](Self::total) counts from now.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ProfileState {
+                origin: Instant::now(),
+                phases: Vec::new(),
+                finished: None,
+            })),
+        }
+    }
+
+    /// Adds `duration` to `phase`.
+    pub fn record(&self, phase: StartupPhase, duration: Duration) {
+        let Ok(mut state) = self.inner.lock() else {
+            return;
+        };
+        match state.phases.iter_mut().find(|(p, _)| *p == phase) {
+            Some((_, total)) => *total += duration,
+            None => state.phases.push((phase, duration)),
+        }
+    }
+
+    /// Runs `f` and adds its duration to `phase`, minus whatever other
+    /// phases `f` recorded meanwhile.
+    pub fn time<R>(&self, phase: StartupPhase, f: impl FnOnce() -> R) -> R {
+        let nested_before = self.recorded();
+        let start = Instant::now();
+        let result = f();
+        let nested = self.recorded().saturating_sub(nested_before);
+        self.record(phase, start.elapsed().saturating_sub(nested));
+        result
+    }
+
+    /// Returns the time recorded for `phase`, if any.
+    pub fn phase(&self, phase: StartupPhase) -> Option<Duration> {
+        let state = self.inner.lock().ok()?;
+        state
+            .phases
+            .iter()
+            .find(|(p, _)| *p == phase)
+            .map(|(_, d)| *d)
+    }
+
+    /// Returns the recorded phases, in startup order.
+    pub fn phases(&self) -> Vec<(StartupPhase, Duration)> {
+        let Ok(state) = self.inner.lock() else {
+            return Vec::new();
+        };
+        StartupPhase::ALL
+            .iter()
+            .filter_map(|phase| state.phases.iter().find(|(p, _)| p == phase).copied())
+            .collect()
+    }
+
+    /// Returns the wall-clock time from [`new`](Self::new) to the end of the
+    /// first frame, or to now while startup is still running.
+    pub fn total(&self) -> Duration {
+        match self.inner.lock() {
+            Ok(state) => state.finished.unwrap_or_else(|| state.origin.elapsed()),
+            Err(_) => Duration::ZERO,
+        }
+    }
+
+    /// Returns `true` once the first frame has completed.
+    pub fn is_finished(&self) -> bool {
+        self.inner
+            .lock()
+            .map(|state| state.finished.is_some())
+            .unwrap_or(false)
+    }
+
+    /// Stops the total clock. Called by the engine after the first frame.
+    pub(crate) fn finish(&self) {
+        if let Ok(mut state) = self.inner.lock() {
+            if state.finished.is_none() {
+                state.finished = Some(state.origin.elapsed());
+            }
+        }
+    }
+
+    /// Formats the phases and the total as a one-line-per-phase table.
+    pub fn report(&self) -> String {
+        let total = self.total();
+        let mut out = String::new();
+        for (phase, duration) in self.phases() {
+            let share = if total.is_zero() {
+                0.0
+            } else {
+                duration.as_secs_f64() / total.as_secs_f64() * 100.0
+            };
+            let _ = writeln!(
+                out,
+                "  {:<12} {:>9.2} ms {:>5.1}%",
+                phase.name(),
+                duration.as_secs_f64() * 1000.0,
+                share
+            );
+        }
+        let _ = write!(
+            out,
+            "  {:<12} {:>9.2} ms",
+            "total",
+            total.as_secs_f64() * 1000.0
+        );
+        out
+    }
+
+    fn recorded(&self) -> Duration {
+        self.inner
+            .lock()
+            .map(|state| state.phases.iter().map(|(_, d)| *d).sum())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for StartupProfile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_phases_are_not_counted_twice() {
+        let profile = StartupProfile::new();
+        profile.time(StartupPhase::Engine, || {
+            std::thread::sleep(Duration::from_millis(5));
+            profile.record(StartupPhase::AssetIndex, Duration::from_millis(1000));
+        });
+
+        // The nested second is subtracted from, not added to, the engine phase.
+        let engine = profile.phase(StartupPhase::Engine).unwrap();
+        assert!(engine < Duration::from_millis(1000));
+        assert_eq!(
+            profile.phase(StartupPhase::AssetIndex),
+            Some(Duration::from_millis(1000))
+        );
+    }
+
+    #[test]
+    fn phases_come_back_in_startup_order_and_accumulate() {
+        let profile = StartupProfile::new();
+        profile.record(StartupPhase::FirstFrame, Duration::from_millis(3));
+        profile.record(StartupPhase::Platform, Duration::from_millis(1));
+        profile.record(StartupPhase::Platform, Duration::from_millis(1));
+
+        let phases = profile.phases();
+        assert_eq!(
+            phases,
+            vec![
+                (StartupPhase::Platform, Duration::from_millis(2)),
+                (StartupPhase::FirstFrame, Duration::from_millis(3)),
+            ]
+        );
+        assert!(!profile.is_finished());
+        profile.finish();
+        let total = profile.total();
+        assert!(profile.is_finished());
+        assert_eq!(profile.total(), total);
+        assert!(profile.report().contains("first_frame"));
+    }
+}
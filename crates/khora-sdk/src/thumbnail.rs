@@ -0,0 +1,326 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offscreen thumbnails of meshes, materials and scenes.
+//!
+//! The [`ThumbnailService`] frames its subject with a camera of its own,
+//! renders it through a shadow pass and the lit forward lane on a headless
+//! device, and reads the pixels back. It runs without a window, so the asset packer uses it at
+//! pack time and the editor on demand.
+
+use std::sync::{Arc, RwLock};
+
+use anyhow::{anyhow, bail, Result};
+use khora_core::asset::{AssetUUID, Material, StandardMaterial, Thumbnail};
+use khora_core::ecs::entity::EntityId;
+use khora_core::lane::{ClearColor, Lane, LaneContext, OutputDeck, Ref, Slot};
+use khora_core::math::{Aabb, LinearRgba, Mat4, Quaternion, Vec3};
+use khora_core::renderer::api::scene::{GpuMesh, Mesh};
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_core::scene::SceneFile;
+use khora_core::ServiceRegistry;
+use khora_data::assets::Assets;
+use khora_data::ecs::systems::bounds_sync_system;
+use khora_data::ecs::{
+    Bounds, Camera, GlobalTransform, HandleComponent, Light, MaterialComponent, Transform, Without,
+    World,
+};
+use khora_data::flow::{Flow, RenderFlow, Selection, ShadowFlow, ShadowView};
+use khora_data::render::RenderWorld;
+use khora_data::{GpuCache, ProjectionRegistry};
+use khora_infra::HeadlessWgpu;
+use khora_io::asset::{resolve_pending_assets, AssetService, ThumbnailCache};
+use khora_io::serialization::SerializationService;
+use khora_lanes::render_lane::{LitForwardLane, ShadowPassLane};
+
+use crate::game_world::GameWorld;
+use crate::vessel::spawn_sphere;
+
+/// Vertical field of view of the thumbnail camera.
+const THUMBNAIL_FOV: f32 = std::f32::consts::FRAC_PI_4;
+
+/// Where the camera sits relative to its subject: in front, to the right
+/// and above, the usual three-quarter view of asset browsers.
+const VIEW_DIRECTION: Vec3 = Vec3::new(0.6, 0.5, 1.0);
+
+/// Color of meshes that carry no material.
+const NEUTRAL_COLOR: LinearRgba = LinearRgba::new(0.7, 0.7, 0.7, 1.0);
+
+/// Asset types, by file extension, rendered as a single mesh.
+const MESH_TYPES: &[&str] = &["gltf", "glb", "obj"];
+
+/// Asset type of scene files.
+const SCENE_TYPE: &str = "kscene";
+
+/// Renders and caches thumbnails of assets.
+///
+/// Owns a headless device separate from the one a running engine renders
+/// with, so it can be created from tooling and from a running editor alike.
+pub struct ThumbnailService {
+    gpu: HeadlessWgpu,
+    /// Fills the shadow atlas the lit lane samples.
+    shadow_lane: ShadowPassLane,
+    lane: Box<dyn Lane>,
+    cache: ThumbnailCache,
+}
+
+impl ThumbnailService {
+    /// Creates a service rendering `size` x `size` thumbnails.
+    ///
+    /// Fails when no adapter is available, e.g. on a CI machine without a
+    /// GPU or software rasterizer.
+    pub fn new(size: u32) -> Result<Self> {
+        let gpu = HeadlessWgpu::new(size, size)?;
+        let shadow_lane = ShadowPassLane::new();
+        let lane: Box<dyn Lane> = Box::new(LitForwardLane::new());
+        let mut init_ctx = LaneContext::new();
+        init_ctx.insert(gpu.graphics_device());
+        shadow_lane
+            .on_initialize(&mut init_ctx)
+            .map_err(|e| anyhow!("Failed to initialize the thumbnail shadow lane: {}", e))?;
+        lane.on_initialize(&mut init_ctx)
+            .map_err(|e| anyhow!("Failed to initialize the thumbnail lane: {}", e))?;
+        Ok(Self {
+            gpu,
+            shadow_lane,
+            lane,
+            cache: ThumbnailCache::new(),
+        })
+    }
+
+    /// Starts from the thumbnails already in `cache`.
+    pub fn with_cache(mut self, cache: ThumbnailCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Returns the edge length of the thumbnails, in pixels.
+    pub fn size(&self) -> u32 {
+        self.gpu.size().0
+    }
+
+    /// Returns the thumbnails rendered or loaded so far.
+    pub fn cache(&self) -> &ThumbnailCache {
+        &self.cache
+    }
+
+    /// Returns the cache, e.g. to save it next to an archive.
+    pub fn into_cache(mut self) -> ThumbnailCache {
+        std::mem::take(&mut self.cache)
+    }
+
+    /// Returns `true` if assets of `type_name` get a thumbnail.
+    pub fn supports(type_name: &str) -> bool {
+        MESH_TYPES.contains(&type_name) || type_name == SCENE_TYPE
+    }
+
+    /// Returns the thumbnail of an asset, rendering it on a cache miss.
+    ///
+    /// Meshes are loaded through `assets`; scenes are read from it, along
+    /// with the meshes they reference.
+    pub fn thumbnail(&mut self, uuid: &AssetUUID, assets: &mut AssetService) -> Result<&Thumbnail> {
+        if !self.cache.contains(uuid) {
+            let thumbnail = self.render_asset(uuid, assets)?;
+            self.cache.insert(*uuid, thumbnail);
+        }
+        self.cache
+            .get(uuid)
+            .ok_or_else(|| anyhow!("Thumbnail of {:?} was not cached", uuid))
+    }
+
+    fn render_asset(&self, uuid: &AssetUUID, assets: &mut AssetService) -> Result<Thumbnail> {
+        let type_name = match assets.metadata(uuid) {
+            Some(metadata) => metadata.asset_type_name.clone(),
+            None => bail!("Asset {:?} not found in the VFS", uuid),
+        };
+        if MESH_TYPES.contains(&type_name.as_str()) {
+            let mesh = assets.load::<Mesh>(uuid)?;
+            self.render_mesh(&mesh)
+        } else if type_name == SCENE_TYPE {
+            let bytes = assets.load_bytes(uuid)?;
+            let scene = SceneFile::from_bytes(&bytes)
+                .map_err(|e| anyhow!("Invalid scene file {:?}: {:?}", uuid, e))?;
+            self.render_scene(&scene, Some(assets))
+        } else {
+            bail!("No thumbnail for assets of type '{}'", type_name)
+        }
+    }
+
+    /// Renders a mesh with a neutral material.
+    pub fn render_mesh(&self, mesh: &Mesh) -> Result<Thumbnail> {
+        let mut world = GameWorld::new();
+        let handle = world.add_mesh(mesh.clone());
+        world.spawn((Transform::identity(), GlobalTransform::identity(), handle));
+        self.render_world(world)
+    }
+
+    /// Renders a material on a sphere.
+    pub fn render_material<M: Material>(&self, material: M) -> Result<Thumbnail> {
+        let mut world = GameWorld::new();
+        let material = world.add_material(material);
+        spawn_sphere(&mut world, 0.5, 32, 16)
+            .with_component(material)
+            .build();
+        self.render_world(world)
+    }
+
+    /// Renders a scene file.
+    ///
+    /// With `assets`, the meshes the scene references by UUID are loaded;
+    /// without, only its procedural meshes show.
+    pub fn render_scene(
+        &self,
+        scene: &SceneFile,
+        assets: Option<&mut AssetService>,
+    ) -> Result<Thumbnail> {
+        let mut world = World::new();
+        SerializationService::new()
+            .load_world(scene, &mut world)
+            .map_err(|e| anyhow!("Failed to load scene: {:?}", e))?;
+        if let Some(assets) = assets {
+            // No asset agent runs here: work through the queued loads at
+            // once, then bind them.
+            resolve_pending_assets(&mut world, assets);
+            assets.process_prefetch(usize::MAX);
+            resolve_pending_assets(&mut world, assets);
+        }
+        self.render_world(GameWorld::from_world(world))
+    }
+
+    /// Renders everything in `world`.
+    ///
+    /// The world's cameras are replaced by one framing all its meshes.
+    /// Meshes without a material get a neutral one, and a directional light
+    /// is added if the world has none.
+    pub fn render_world(&self, mut world: GameWorld) -> Result<Thumbnail> {
+        stage(&mut world);
+
+        let device: Arc<dyn GraphicsDevice> = self.gpu.graphics_device();
+        let cache = GpuCache::new();
+        ProjectionRegistry::new(cache.clone()).sync_all(world.inner_world_mut(), device.as_ref());
+        let gpu_meshes: Arc<RwLock<Assets<GpuMesh>>> = cache.inner().clone();
+        let render_world: RenderWorld = RenderFlow::default().project(
+            world.inner_world(),
+            &Selection::new(),
+            &ServiceRegistry::new(),
+        );
+        let shadow_view: ShadowView = ShadowFlow.project(
+            world.inner_world(),
+            &Selection::new(),
+            &ServiceRegistry::new(),
+        );
+        let mut deck = OutputDeck::new();
+
+        let mut encoder = device.create_command_encoder(Some("Thumbnail Encoder"));
+        {
+            let mut ctx = LaneContext::new();
+            ctx.insert(device.clone());
+            ctx.insert(gpu_meshes);
+            // SAFETY: encoder outlives ctx, which is dropped before finish().
+            let encoder_slot = Slot::new(encoder.as_mut());
+            ctx.insert(unsafe {
+                std::mem::transmute::<Slot<dyn CommandEncoder>, Slot<dyn CommandEncoder>>(
+                    encoder_slot,
+                )
+            });
+            ctx.insert(Ref::new(&render_world));
+            ctx.insert(Ref::new(&shadow_view));
+            ctx.insert(Slot::new(&mut deck));
+            ctx.insert(self.gpu.color_target());
+            ctx.insert(self.gpu.depth_target());
+            // Transparent, so asset browsers draw their own background.
+            ctx.insert(ClearColor(LinearRgba::new(0.0, 0.0, 0.0, 0.0)));
+            self.shadow_lane
+                .execute(&mut ctx)
+                .map_err(|e| anyhow!("Thumbnail shadow pass failed: {}", e))?;
+            self.lane
+                .execute(&mut ctx)
+                .map_err(|e| anyhow!("Thumbnail render failed: {}", e))?;
+        }
+        device.submit_command_buffer(encoder.finish());
+
+        let (width, height) = self.gpu.size();
+        let pixels = self.gpu.read_color()?;
+        Thumbnail::new(width, height, pixels)
+            .ok_or_else(|| anyhow!("Thumbnail readback returned a truncated image"))
+    }
+}
+
+impl Drop for ThumbnailService {
+    fn drop(&mut self) {
+        let mut shutdown_ctx = LaneContext::new();
+        shutdown_ctx.insert(self.gpu.graphics_device());
+        self.lane.on_shutdown(&mut shutdown_ctx);
+        self.shadow_lane.on_shutdown(&mut shutdown_ctx);
+    }
+}
+
+/// Prepares `world` for a thumbnail: framing camera, materials and light.
+fn stage(world: &mut GameWorld) {
+    let cameras: Vec<EntityId> = world
+        .query::<(EntityId, &Camera)>()
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in cameras {
+        world.remove_component::<Camera>(entity);
+    }
+
+    let bare: Vec<EntityId> = world
+        .query::<(EntityId, &HandleComponent<Mesh>, Without<MaterialComponent>)>()
+        .map(|(entity, _, _)| entity)
+        .collect();
+    for entity in bare {
+        let material = world.add_material(StandardMaterial {
+            base_color: NEUTRAL_COLOR,
+            ..Default::default()
+        });
+        world.add_component(entity, material);
+    }
+
+    if world.query::<&Light>().next().is_none() {
+        world.spawn((Light::directional(), GlobalTransform::identity()));
+    }
+
+    bounds_sync_system(world.inner_world_mut());
+    let bounds = world
+        .query::<&Bounds>()
+        .map(|bounds| bounds.hierarchy)
+        .reduce(|a, b| a.merge(&b))
+        .unwrap_or_else(|| Aabb::from_half_extents(Vec3::new(0.5, 0.5, 0.5)));
+    world.spawn(framing_camera(&bounds));
+}
+
+/// Builds a camera looking at `bounds` from [`VIEW_DIRECTION`], far enough
+/// back for its bounding sphere to fit the view.
+fn framing_camera(bounds: &Aabb) -> (Camera, GlobalTransform) {
+    let radius = bounds.half_extents().length().max(0.01);
+    let distance = radius / (THUMBNAIL_FOV * 0.5).sin();
+    let direction = VIEW_DIRECTION.normalize();
+    let eye = bounds.center() + direction * distance;
+
+    // The camera looks down -Z: yaw it towards the subject, then pitch down.
+    let yaw = direction.x.atan2(direction.z);
+    let pitch = -direction.y.asin();
+    let rotation =
+        Quaternion::from_axis_angle(Vec3::Y, yaw) * Quaternion::from_axis_angle(Vec3::X, pitch);
+    let matrix = Mat4::from_translation(eye) * Mat4::from_quat(rotation);
+
+    let near = ((distance - radius) * 0.5).max(0.01);
+    let far = distance + radius * 2.0;
+    (
+        Camera::new_perspective(THUMBNAIL_FOV, 1.0, near, far),
+        GlobalTransform::new(matrix),
+    )
+}
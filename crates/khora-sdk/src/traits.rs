@@ -18,13 +18,18 @@
 //! provides concrete implementations of these traits to inject platform-specific
 //! behavior, agents, custom phases, and application logic.
 
-use khora_control::DccService;
+use khora_control::{DccConfig, DccService};
 use khora_core::agent::ExecutionPhase;
 use khora_core::platform::KhoraWindow;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::GameWorld;
 use crate::InputEvent;
+use crate::PluginSet;
+use crate::WatchdogConfig;
 use crate::WindowConfig;
+use crate::DEFAULT_FIRST_FRAME_BUDGET;
 
 // ─────────────────────────────────────────────────────────────────────
 // WindowProvider — abstracts the platform window backend
@@ -130,11 +135,64 @@ pub trait EngineApp: AgentProvider + PhaseProvider + Send + Sync {
     where
         Self: Sized;
 
+    /// Returns the stall watchdog configuration, or `None` (the default) to
+    /// run without one.
+    fn watchdog_config() -> Option<WatchdogConfig>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Returns the DCC configuration. Override it to tune the analysis
+    /// loop or to capture GORNA rounds for `cargo xtask gorna-replay`.
+    fn dcc_config() -> DccConfig
+    where
+        Self: Sized,
+    {
+        DccConfig::default()
+    }
+
+    /// Returns where the startup hardware survey is saved as JSON, or
+    /// `None` to skip writing it. Defaults to `khora-hardware.json` in the
+    /// temp directory; attach it to support tickets.
+    fn hardware_report_path() -> Option<PathBuf>
+    where
+        Self: Sized,
+    {
+        Some(std::env::temp_dir().join("khora-hardware.json"))
+    }
+
+    /// Returns the file console variables are kept in between runs, or
+    /// `None` (the default) to not keep them. Values are loaded at startup
+    /// and saved on shutdown, after [`on_shutdown`](Self::on_shutdown).
+    fn cvar_path() -> Option<PathBuf>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Returns how long the first frame may take, from the end of bootstrap,
+    /// before the engine logs a startup budget warning. `None` never warns.
+    fn first_frame_budget() -> Option<Duration>
+    where
+        Self: Sized,
+    {
+        Some(DEFAULT_FIRST_FRAME_BUDGET)
+    }
+
     /// Creates a new instance of the application.
     fn new() -> Self
     where
         Self: Sized;
 
+    /// Installs the app's plugins. Called once during engine
+    /// initialization, before [`setup`](Self::setup).
+    ///
+    /// Default: no plugin.
+    fn plugins(&self, _plugins: &mut PluginSet) {}
+
     /// Called once during engine initialization to set up the game world.
     fn setup(&mut self, world: &mut GameWorld, services: &khora_core::ServiceRegistry);
 
@@ -144,6 +202,14 @@ pub trait EngineApp: AgentProvider + PhaseProvider + Send + Sync {
     /// Called during shutdown to clean up application resources.
     fn on_shutdown(&mut self) {}
 
+    /// Called when the engine is suspended because the window was minimized
+    /// or occluded. No frames run until [`on_resume`](Self::on_resume).
+    fn on_suspend(&mut self) {}
+
+    /// Called when the engine resumes after [`on_suspend`](Self::on_suspend),
+    /// before the first rendered frame.
+    fn on_resume(&mut self) {}
+
     /// Optional: intercept a raw window event before the engine translates it into
     /// an [`InputEvent`]. Return `true` if the event was consumed (e.g., by an
     /// egui overlay) and should NOT be forwarded to game logic.
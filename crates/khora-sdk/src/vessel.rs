@@ -38,7 +38,7 @@
 use khora_core::ecs::entity::EntityId;
 use khora_core::math::{Aabb, Vec2, Vec3};
 use khora_core::renderer::api::{
-    pipeline::{PrimitiveTopology, VertexAttributeDescriptor, VertexFormat},
+    pipeline::{PrimitiveTopology, StaticVertex, Vertex},
     scene::Mesh,
 };
 use khora_data::ecs::{GlobalTransform, Transform};
@@ -205,25 +205,6 @@ fn create_plane(size: f32, y: f32) -> Mesh {
 
     let indices = vec![0u32, 1, 2, 0, 2, 3];
 
-    // Layout: Position (0), Normal (1), UV (2)
-    let vertex_layout = vec![
-        VertexAttributeDescriptor {
-            shader_location: 0,
-            format: VertexFormat::Float32x3,
-            offset: 0,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 1,
-            format: VertexFormat::Float32x3,
-            offset: 12,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 2,
-            format: VertexFormat::Float32x2,
-            offset: 24,
-        },
-    ];
-
     Mesh {
         positions,
         normals: Some(normals),
@@ -233,7 +214,9 @@ fn create_plane(size: f32, y: f32) -> Mesh {
         indices: Some(indices),
         primitive_type: PrimitiveTopology::TriangleList,
         bounding_box: Aabb::from_min_max(Vec3::new(-half, y, -half), Vec3::new(half, y, half)),
-        vertex_layout,
+        vertex_layout: StaticVertex::LAYOUT.attributes.into_owned(),
+        morph_targets: Vec::new(),
+        skin: None,
     }
 }
 
@@ -349,25 +332,6 @@ fn create_cube(size: f32) -> Mesh {
         20, 21, 22, 20, 22, 23,
     ];
 
-    // Layout: Position (0), Normal (1), UV (2)
-    let vertex_layout = vec![
-        VertexAttributeDescriptor {
-            shader_location: 0,
-            format: VertexFormat::Float32x3,
-            offset: 0,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 1,
-            format: VertexFormat::Float32x3,
-            offset: 12,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 2,
-            format: VertexFormat::Float32x2,
-            offset: 24,
-        },
-    ];
-
     Mesh {
         positions,
         normals: Some(normals),
@@ -380,7 +344,9 @@ fn create_cube(size: f32) -> Mesh {
             Vec3::new(-half, -half, -half),
             Vec3::new(half, half, half),
         ),
-        vertex_layout,
+        vertex_layout: StaticVertex::LAYOUT.attributes.into_owned(),
+        morph_targets: Vec::new(),
+        skin: None,
     }
 }
 
@@ -428,25 +394,6 @@ fn create_sphere(radius: f32, segments: u32, rings: u32) -> Mesh {
         }
     }
 
-    // Layout: Position (0), Normal (1), UV (2)
-    let vertex_layout = vec![
-        VertexAttributeDescriptor {
-            shader_location: 0,
-            format: VertexFormat::Float32x3,
-            offset: 0,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 1,
-            format: VertexFormat::Float32x3,
-            offset: 12,
-        },
-        VertexAttributeDescriptor {
-            shader_location: 2,
-            format: VertexFormat::Float32x2,
-            offset: 24,
-        },
-    ];
-
     Mesh {
         positions,
         normals: Some(normals),
@@ -459,6 +406,8 @@ fn create_sphere(radius: f32, segments: u32, rings: u32) -> Mesh {
             Vec3::new(-radius, -radius, -radius),
             Vec3::new(radius, radius, radius),
         ),
-        vertex_layout,
+        vertex_layout: StaticVertex::LAYOUT.attributes.into_owned(),
+        morph_targets: Vec::new(),
+        skin: None,
     }
 }
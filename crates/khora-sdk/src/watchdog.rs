@@ -0,0 +1,559 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Main-loop stall detection.
+//!
+//! The engine loop stamps a [`Heartbeat`] at every stage of the frame. A
+//! [`Watchdog`] thread polls it. If no beat arrives within the configured
+//! threshold, it writes a stall report to disk. The report contains the last
+//! stage reached, every thread's state, the agent statuses and the most
+//! recent log lines. Then it waits for the loop to recover before arming
+//! again.
+//!
+//! The report lists every thread: registered names and roles, plus on Linux
+//! the scheduler state and kernel wait channel from `/proc`. Rust cannot walk
+//! another thread's stack from inside the process, so the watchdog runs
+//! `gdb` or `eu-stack` against its own pid, whichever is on `PATH`, and
+//! embeds the backtraces of all threads. Without either tool, or when the
+//! tool cannot attach (e.g. Yama `ptrace_scope` forbids it), the report
+//! prints the debugger command to run by hand instead.
+
+use crate::log_tail::LogTail;
+use crate::run_log_dir::RunLogDir;
+use khora_control::registry::AgentRegistry;
+use khora_core::threading::{self, ThreadRole};
+use std::ffi::OsStr;
+use std::fmt::Write as _;
+use std::io::{self, Read as _};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Configuration for the stall [`Watchdog`].
+#[derive(Debug, Clone)]
+pub struct WatchdogConfig {
+    /// How long the main loop may go without a heartbeat before it counts as stalled.
+    pub stall_threshold: Duration,
+    /// How often the watchdog thread checks the heartbeat.
+    pub poll_interval: Duration,
+    /// Directory stall reports are written to. Defaults to the installed
+    /// [`RunLogDir`], or the OS temp directory.
+    pub dump_dir: PathBuf,
+    /// Maximum number of recent log lines included in a report.
+    pub log_lines: usize,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            stall_threshold: Duration::from_secs(5),
+            poll_interval: Duration::from_millis(250),
+            dump_dir: RunLogDir::current()
+                .map_or_else(std::env::temp_dir, |run| run.path().to_path_buf()),
+            log_lines: 200,
+        }
+    }
+}
+
+/// The main loop's progress marker, shared with the watchdog thread.
+#[derive(Clone)]
+pub struct Heartbeat {
+    state: Arc<HeartbeatState>,
+}
+
+struct HeartbeatState {
+    origin: Instant,
+    last_beat_micros: AtomicU64,
+    frame: AtomicU64,
+    phase: Mutex<&'static str>,
+}
+
+impl Heartbeat {
+    /// Creates a heartbeat that counts as having just beaten.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(HeartbeatState {
+                origin: Instant::now(),
+                last_beat_micros: AtomicU64::new(0),
+                frame: AtomicU64::new(0),
+                phase: Mutex::new("startup"),
+            }),
+        }
+    }
+
+    /// Records progress through `phase` of the current frame.
+    pub fn beat(&self, phase: &'static str) {
+        if let Ok(mut current) = self.state.phase.lock() {
+            *current = phase;
+        }
+        let micros = self.state.origin.elapsed().as_micros() as u64;
+        self.state.last_beat_micros.store(micros, Ordering::Release);
+    }
+
+    /// Starts a new frame, then beats with `phase`.
+    pub fn begin_frame(&self, phase: &'static str) {
+        self.state.frame.fetch_add(1, Ordering::Relaxed);
+        self.beat(phase);
+    }
+
+    /// Time since the last beat.
+    pub fn since_last_beat(&self) -> Duration {
+        let last = Duration::from_micros(self.state.last_beat_micros.load(Ordering::Acquire));
+        self.state.origin.elapsed().saturating_sub(last)
+    }
+
+    /// Number of frames started so far.
+    pub fn frame(&self) -> u64 {
+        self.state.frame.load(Ordering::Relaxed)
+    }
+
+    /// The last phase the loop reported.
+    pub fn phase(&self) -> &'static str {
+        self.state.phase.lock().map(|p| *p).unwrap_or("<poisoned>")
+    }
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A background thread that reports main-loop stalls.
+pub struct Watchdog {
+    heartbeat: Heartbeat,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Watchdog {
+    /// Starts the watchdog thread.
+    ///
+    /// `agents` is optional; when given, each agent's status is included in
+    /// the report. Agents lent out to their worker thread are listed as
+    /// such, and a registry held by the frame thread is not waited on.
+    pub fn start(
+        config: WatchdogConfig,
+        agents: Option<Arc<Mutex<AgentRegistry>>>,
+    ) -> io::Result<Self> {
+        let heartbeat = Heartbeat::new();
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread_heartbeat = heartbeat.clone();
+        let thread_running = Arc::clone(&running);
+        let handle = threading::spawn_named(
+            "khora-watchdog",
+            ThreadRole::Diagnostics,
+            move || {
+                let mut reported_frame = None;
+                while thread_running.load(Ordering::Relaxed) {
+                    std::thread::park_timeout(config.poll_interval);
+                    if !thread_running.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    let stalled_for = thread_heartbeat.since_last_beat();
+                    let frame = thread_heartbeat.frame();
+                    if stalled_for < config.stall_threshold {
+                        reported_frame = None;
+                        continue;
+                    }
+                    if reported_frame == Some(frame) {
+                        continue;
+                    }
+                    reported_frame = Some(frame);
+
+                    let report = build_stall_report(
+                        &thread_heartbeat,
+                        stalled_for,
+                        agents.as_ref(),
+                        &config,
+                    );
+                    match write_report(&config, &report) {
+                    Ok(path) => log::error!(
+                        "Main loop stalled for {:.1}s in '{}' (frame {}); report written to {}",
+                        stalled_for.as_secs_f32(),
+                        thread_heartbeat.phase(),
+                        frame,
+                        path.display()
+                    ),
+                    Err(e) => log::error!(
+                        "Main loop stalled for {:.1}s in '{}' (frame {}); failed to write report: {e}",
+                        stalled_for.as_secs_f32(),
+                        thread_heartbeat.phase(),
+                        frame
+                    ),
+                }
+                }
+            },
+        )?;
+
+        Ok(Self {
+            heartbeat,
+            running,
+            handle: Some(handle),
+        })
+    }
+
+    /// The heartbeat the main loop should stamp.
+    pub fn heartbeat(&self) -> &Heartbeat {
+        &self.heartbeat
+    }
+
+    /// Stops the watchdog thread and waits for it to exit.
+    pub fn stop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn build_stall_report(
+    heartbeat: &Heartbeat,
+    stalled_for: Duration,
+    agents: Option<&Arc<Mutex<AgentRegistry>>>,
+    config: &WatchdogConfig,
+) -> String {
+    let mut out = String::new();
+    let pid = std::process::id();
+
+    let _ = writeln!(out, "Khora main-loop stall report");
+    let _ = writeln!(out, "pid: {pid}");
+    let _ = writeln!(out, "stalled for: {:.3}s", stalled_for.as_secs_f64());
+    let _ = writeln!(out, "frame: {}", heartbeat.frame());
+    let _ = writeln!(out, "last phase: {}", heartbeat.phase());
+
+    let _ = writeln!(out, "\n== Registered threads ==");
+    for thread in threading::registered_threads() {
+        let _ = writeln!(
+            out,
+            "{:<24} {:?} ({:?})",
+            thread.name, thread.role, thread.id
+        );
+    }
+
+    let _ = writeln!(out, "\n== OS threads ==");
+    append_os_threads(&mut out);
+
+    let _ = writeln!(out, "\n== Backtraces ==");
+    append_backtraces(&mut out, pid, std::env::var_os("PATH").as_deref());
+
+    let _ = writeln!(out, "\n== Agents ==");
+    match agents {
+        None => {
+            let _ = writeln!(out, "(no agent registry attached)");
+        }
+        Some(registry) => match registry.try_lock() {
+            Err(_) => {
+                let _ = writeln!(out, "agent registry lock is held — not waiting on it");
+            }
+            Ok(registry) => {
+                for agent in registry.iter() {
+                    let status = agent.report_status();
+                    let _ = writeln!(
+                        out,
+                        "{:?}: strategy {:?}, health {:.2}, stalled {}, {}",
+                        status.agent_id,
+                        status.current_strategy,
+                        status.health_score,
+                        status.is_stalled,
+                        status.message
+                    );
+                }
+                for id in registry.lent_ids() {
+                    let _ = writeln!(out, "{:?}: running on its worker thread", id);
+                }
+            }
+        },
+    }
+
+    let _ = writeln!(out, "\n== Recent log lines ==");
+    let lines = LogTail::recent();
+    if lines.is_empty() {
+        let _ = writeln!(out, "(install LogTail to capture log lines)");
+    }
+    let skip = lines.len().saturating_sub(config.log_lines);
+    for line in &lines[skip..] {
+        let _ = writeln!(out, "{line}");
+    }
+
+    out
+}
+
+#[cfg(target_os = "linux")]
+fn append_os_threads(out: &mut String) {
+    let Ok(tasks) = std::fs::read_dir("/proc/self/task") else {
+        let _ = writeln!(out, "(/proc/self/task unavailable)");
+        return;
+    };
+    for task in tasks.flatten() {
+        let path = task.path();
+        let read = |name: &str| {
+            std::fs::read_to_string(path.join(name))
+                .map(|s| s.trim().to_owned())
+                .unwrap_or_default()
+        };
+        let comm = read("comm");
+        // Field 3 of `stat` (after the parenthesised comm) is the scheduler state.
+        let stat = read("stat");
+        let state = stat
+            .rsplit_once(')')
+            .and_then(|(_, rest)| rest.split_whitespace().next())
+            .unwrap_or("?")
+            .to_owned();
+        let wchan = read("wchan");
+        let _ = writeln!(
+            out,
+            "tid {:<8} {:<16} state {} wchan {}",
+            task.file_name().to_string_lossy(),
+            comm,
+            state,
+            if wchan.is_empty() || wchan == "0" {
+                "-"
+            } else {
+                &wchan
+            }
+        );
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn append_os_threads(out: &mut String) {
+    let _ = writeln!(out, "(per-thread OS state is only collected on Linux)");
+}
+
+/// Tools that print the stacks of every thread of a live process, tried in
+/// order, with the arguments that precede the pid.
+const BACKTRACE_TOOLS: &[(&str, &[&str])] = &[
+    ("gdb", &["-batch", "-ex", "thread apply all bt", "-p"]),
+    ("eu-stack", &["-p"]),
+];
+
+/// How long a backtrace tool may run before it is killed.
+const BACKTRACE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Appends the backtraces of every thread of `pid`, captured by the first
+/// [`BACKTRACE_TOOLS`] entry found in `search_path`. Falls back to the
+/// debugger command when no tool is found or none produces a backtrace.
+fn append_backtraces(out: &mut String, pid: u32, search_path: Option<&OsStr>) {
+    for (tool, args) in BACKTRACE_TOOLS {
+        let Some(program) = search_path
+            .into_iter()
+            .flat_map(std::env::split_paths)
+            .map(|dir| dir.join(tool))
+            .find(|path| path.is_file())
+        else {
+            continue;
+        };
+        let mut command = Command::new(&program);
+        command.args(*args).arg(pid.to_string());
+        match run_with_timeout(command, BACKTRACE_TIMEOUT) {
+            // Both tools number stack frames from `#0`. `gdb` exits cleanly
+            // even when it could not attach, so look for a frame.
+            Ok(stacks) if stacks.contains("#0") => {
+                let _ = writeln!(out, "(captured with {})", program.display());
+                let _ = writeln!(out, "{}", stacks.trim_end());
+                return;
+            }
+            Ok(output) => {
+                let _ = writeln!(
+                    out,
+                    "({} printed no backtrace: {})",
+                    program.display(),
+                    output.trim()
+                );
+            }
+            Err(e) => {
+                let _ = writeln!(out, "({} failed: {e})", program.display());
+            }
+        }
+    }
+    let _ = writeln!(
+        out,
+        "No backtrace tool worked. Capture them by hand:\n\
+         gdb -p {pid} -batch -ex \"thread apply all bt\"  (or: lldb -p {pid} -o \"bt all\")"
+    );
+}
+
+/// Runs `command` and returns its stdout and stderr, killing it after
+/// `timeout`.
+fn run_with_timeout(mut command: Command, timeout: Duration) -> io::Result<String> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Drain both pipes on their own threads so a chatty tool cannot block
+    // on a full pipe while this thread waits for it to exit.
+    let drain = |pipe: Option<Box<dyn io::Read + Send>>| {
+        std::thread::spawn(move || {
+            let mut bytes = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut bytes);
+            }
+            bytes
+        })
+    };
+    let stdout = drain(child.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = drain(child.stderr.take().map(|p| Box::new(p) as _));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if Instant::now() > deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+
+    let mut output = String::new();
+    for bytes in [stdout, stderr].map(|t| t.join().unwrap_or_default()) {
+        output.push_str(&String::from_utf8_lossy(&bytes));
+    }
+    match status {
+        None => Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("no answer within {}s", timeout.as_secs()),
+        )),
+        Some(status) if !status.success() => {
+            Err(io::Error::other(format!("{status}: {}", output.trim())))
+        }
+        Some(_) => Ok(output),
+    }
+}
+
+fn write_report(config: &WatchdogConfig, report: &str) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(&config.dump_dir)?;
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let path = config
+        .dump_dir
+        .join(format!("khora-stall-{}-{stamp}.txt", std::process::id()));
+    std::fs::write(&path, report)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stalled_loop_writes_one_report() {
+        let dump_dir = std::env::temp_dir().join(format!("khora-watchdog-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dump_dir);
+
+        let mut watchdog = Watchdog::start(
+            WatchdogConfig {
+                stall_threshold: Duration::from_millis(30),
+                poll_interval: Duration::from_millis(5),
+                dump_dir: dump_dir.clone(),
+                log_lines: 10,
+            },
+            None,
+        )
+        .expect("watchdog thread should start");
+        watchdog.heartbeat().begin_frame("test_phase");
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let reports = loop {
+            let reports: Vec<_> = std::fs::read_dir(&dump_dir)
+                .map(|d| d.flatten().map(|e| e.path()).collect())
+                .unwrap_or_default();
+            if !reports.is_empty() || Instant::now() > deadline {
+                break reports;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        // Give the watchdog time to (wrongly) write a second report for the same stall.
+        std::thread::sleep(Duration::from_millis(100));
+        watchdog.stop();
+
+        assert_eq!(reports.len(), 1);
+        let all: Vec<_> = std::fs::read_dir(&dump_dir).unwrap().flatten().collect();
+        assert_eq!(all.len(), 1);
+        let text = std::fs::read_to_string(&reports[0]).unwrap();
+        assert!(text.contains("last phase: test_phase"));
+        assert!(text.contains("frame: 1"));
+        assert!(text.contains("== Backtraces =="));
+        let _ = std::fs::remove_dir_all(&dump_dir);
+    }
+
+    #[test]
+    fn report_falls_back_to_the_debugger_command_without_tools() {
+        let mut out = String::new();
+        append_backtraces(&mut out, 4242, None);
+        assert!(out.contains("No backtrace tool worked"));
+        assert!(out.contains("gdb -p 4242"));
+
+        let empty_dir = std::env::temp_dir().join(format!("khora-no-tools-{}", std::process::id()));
+        std::fs::create_dir_all(&empty_dir).unwrap();
+        let mut out = String::new();
+        append_backtraces(&mut out, 4242, Some(empty_dir.as_os_str()));
+        assert!(out.contains("gdb -p 4242"));
+        let _ = std::fs::remove_dir_all(&empty_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn report_embeds_the_backtraces_a_tool_prints() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tool_dir =
+            std::env::temp_dir().join(format!("khora-fake-tools-{}", std::process::id()));
+        std::fs::create_dir_all(&tool_dir).unwrap();
+        let tool = tool_dir.join("eu-stack");
+        std::fs::write(
+            &tool,
+            "#!/bin/sh\necho \"PID $2 - process\"\necho \"#0  0x1234 stalled_fn\"\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut out = String::new();
+        append_backtraces(&mut out, 4242, Some(tool_dir.as_os_str()));
+        let _ = std::fs::remove_dir_all(&tool_dir);
+
+        assert!(out.contains("captured with"));
+        assert!(out.contains("PID 4242 - process"));
+        assert!(out.contains("#0  0x1234 stalled_fn"));
+        assert!(!out.contains("No backtrace tool worked"));
+    }
+
+    #[test]
+    fn beating_loop_is_not_reported() {
+        let heartbeat = Heartbeat::new();
+        heartbeat.begin_frame("input");
+        heartbeat.beat("agents");
+        assert!(heartbeat.since_last_beat() < Duration::from_secs(1));
+        assert_eq!(heartbeat.phase(), "agents");
+        assert_eq!(heartbeat.frame(), 1);
+    }
+}
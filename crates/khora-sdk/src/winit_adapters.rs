@@ -19,6 +19,7 @@
 
 use std::any::Any;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 use anyhow::Result;
 use khora_core::platform::KhoraWindow;
@@ -27,10 +28,11 @@ use khora_core::renderer::traits::RenderSystem;
 use khora_infra::platform::window::WinitWindow;
 use winit::application::ApplicationHandler;
 use winit::event::WindowEvent;
-use winit::event_loop::{ActiveEventLoop, EventLoop};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::window::WindowId;
 
-use crate::engine::{EngineCore, PRIMARY_VIEWPORT};
+use crate::engine::{EngineCore, PRIMARY_VIEWPORT, SHUTDOWN_STEP_TIMEOUT};
+use crate::startup::{StartupPhase, StartupProfile};
 use crate::traits::{EngineApp, WindowProvider};
 use crate::{InputEvent, WindowConfig};
 
@@ -111,6 +113,17 @@ pub struct WinitAppRunner<W: WindowProvider, A: EngineApp> {
     tokio_runtime: Option<tokio::runtime::Runtime>,
     /// Per-frame context, recreated each frame.
     frame_context: Option<Arc<FrameContext>>,
+    /// The window is fully hidden behind other windows.
+    occluded: bool,
+    /// The window was resized to zero (minimized on most platforms).
+    minimized: bool,
+    /// When the next background tick is due while suspended.
+    next_background_tick: Instant,
+    /// When the last frame started, to pace redraws to the power policy's
+    /// FPS cap.
+    last_frame_start: Option<Instant>,
+    /// Startup timings, counted from the runner's creation.
+    startup: StartupProfile,
 }
 
 impl<W: WindowProvider, A: EngineApp> WinitAppRunner<W, A> {
@@ -127,6 +140,11 @@ impl<W: WindowProvider, A: EngineApp> WinitAppRunner<W, A> {
             bootstrap: Some(Box::new(bootstrap)),
             tokio_runtime: None,
             frame_context: None,
+            occluded: false,
+            minimized: false,
+            next_background_tick: Instant::now(),
+            last_frame_start: None,
+            startup: StartupProfile::new(),
         }
     }
 
@@ -134,6 +152,7 @@ impl<W: WindowProvider, A: EngineApp> WinitAppRunner<W, A> {
     /// `EngineApp` lifecycle hooks. Sandbox-style apps (no overrides) get
     /// the same behavior as the legacy monolithic `tick`.
     fn run_frame(&mut self) {
+        self.last_frame_start = Some(Instant::now());
         // Build the per-frame service registry inheriting all engine services.
         let frame_services_arc = if let Some(rt) = &self.tokio_runtime {
             let fctx = FrameContext::new(rt.handle().clone());
@@ -192,6 +211,9 @@ impl<W: WindowProvider, A: EngineApp> WinitAppRunner<W, A> {
         // Stage 5b: present.
         self.engine.present_frame(presents);
 
+        // Stage 6: end-of-tick maintenance.
+        self.engine.run_maintenance();
+
         // Wait for hot-path tasks before returning so the next frame sees a
         // settled GPU state.
         if let Some(rt) = &self.tokio_runtime {
@@ -212,9 +234,13 @@ impl<W: WindowProvider, A: EngineApp> ApplicationHandler for WinitAppRunner<W, A
 
         let window_config = A::window_config();
         let window = W::create(event_loop as &dyn Any, &window_config);
+        self.startup
+            .record(StartupPhase::Platform, self.startup.total());
 
-        // Build service registry
+        // Build service registry. The startup profile goes in first so the
+        // bootstrap closure can time the asset index load.
         let mut services = khora_core::ServiceRegistry::new();
+        services.insert(self.startup.clone());
 
         // Insert a long-lived clone of the raw window handle so editor hooks
         // (e.g., overlay `begin_frame`) can retrieve `Arc<winit::window::Window>`
@@ -228,17 +254,21 @@ impl<W: WindowProvider, A: EngineApp> ApplicationHandler for WinitAppRunner<W, A
         // app may construct windowing-aware resources (e.g., an egui overlay
         // backed by `egui_winit::State`) without the SDK needing to know.
         let bootstrap = self.bootstrap.take().expect("bootstrap not set");
-        bootstrap(
-            window.as_khora_window(),
-            &mut services,
-            event_loop as &dyn Any,
-        );
+        self.startup.time(StartupPhase::Renderer, || {
+            bootstrap(
+                window.as_khora_window(),
+                &mut services,
+                event_loop as &dyn Any,
+            )
+        });
 
         // Bootstrap the engine, transferring ownership of services.
         // bootstrap() inserts built-in services (GpuCache, etc.), wraps in Arc,
         // and stores the final registry in self.engine.services.
         let app = A::new();
         self.engine.bootstrap(app, services);
+        self.engine
+            .record_hardware_survey(window.as_khora_window().monitors());
 
         // Cache renderer for resize handling (reads from the Arc stored by bootstrap).
         if let Some(rs) = self
@@ -290,9 +320,18 @@ impl<W: WindowProvider, A: EngineApp> ApplicationHandler for WinitAppRunner<W, A
                         r.resize(size.width, size.height);
                     }
                 }
+                self.minimized = size.width == 0 || size.height == 0;
+                self.update_suspension();
+            }
+            WindowEvent::Occluded(occluded) => {
+                self.occluded = occluded;
+                self.update_suspension();
             }
             WindowEvent::RedrawRequested => {
-                self.run_frame();
+                // The OS may still ask for redraws of a hidden window.
+                if !self.engine.is_suspended() {
+                    self.run_frame();
+                }
             }
             _ => {
                 if consumed_by_app {
@@ -307,16 +346,72 @@ impl<W: WindowProvider, A: EngineApp> ApplicationHandler for WinitAppRunner<W, A
         }
     }
 
-    fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if self.engine.is_suspended() {
+            // No redraws while hidden: wake up only for the low-power tick.
+            let now = Instant::now();
+            if now >= self.next_background_tick {
+                self.engine.background_tick();
+                self.next_background_tick = now + self.engine.background_tick_interval();
+            }
+            event_loop.set_control_flow(ControlFlow::WaitUntil(self.next_background_tick));
+            return;
+        }
+        // Under a power policy FPS cap, sleep until the next frame is due.
+        let next_frame = self
+            .engine
+            .frame_interval()
+            .zip(self.last_frame_start)
+            .map(|(interval, last)| last + interval)
+            .filter(|next| *next > Instant::now());
+        if let Some(next) = next_frame {
+            event_loop.set_control_flow(ControlFlow::WaitUntil(next));
+            return;
+        }
+        event_loop.set_control_flow(ControlFlow::Wait);
         if let Some(window) = &self.window {
             window.request_redraw();
         }
     }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        self.shutdown();
+    }
+}
+
+impl<W: WindowProvider, A: EngineApp> WinitAppRunner<W, A> {
+    /// Suspends the engine while the window is minimized or occluded and
+    /// resumes it once the window is visible again.
+    fn update_suspension(&mut self) {
+        let hidden = self.occluded || self.minimized;
+        if hidden && !self.engine.is_suspended() {
+            self.engine.suspend();
+            self.next_background_tick = Instant::now() + self.engine.background_tick_interval();
+        } else if !hidden && self.engine.is_suspended() {
+            self.engine.resume();
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+    }
+
+    /// Runs the engine shutdown sequence, then releases what the runner
+    /// owns: hot-path tasks, the renderer handle, and finally the window.
+    fn shutdown(&mut self) {
+        self.engine.shutdown();
+        self.frame_context = None;
+        if let Some(rt) = self.tokio_runtime.take() {
+            rt.shutdown_timeout(SHUTDOWN_STEP_TIMEOUT);
+        }
+        self.renderer = None;
+        self.window = None;
+    }
 }
 
 impl<W: WindowProvider, A: EngineApp> Drop for WinitAppRunner<W, A> {
     fn drop(&mut self) {
-        self.engine.shutdown();
+        // No-op if the event loop already delivered `exiting`.
+        self.shutdown();
     }
 }
 
@@ -351,10 +446,13 @@ pub fn run_winit<W: WindowProvider, A: EngineApp>(
         + Send
         + 'static,
 ) -> Result<()> {
+    khora_core::threading::register_main_thread();
     log::info!("Khora Engine: Starting...");
 
-    let event_loop = EventLoop::new()?;
+    // The runner starts the startup clock, so it is created first to count
+    // the event loop in platform init.
     let mut runner = WinitAppRunner::<W, A>::new(bootstrap);
+    let event_loop = EventLoop::new()?;
     event_loop.run_app(&mut runner)?;
     Ok(())
 }
@@ -0,0 +1,72 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Description of a secondary world's agents and services.
+
+use khora_core::agent::Agent;
+use khora_core::ServiceRegistry;
+
+/// Installs a service into a secondary world's registry.
+pub(crate) type ServiceInstaller = Box<dyn FnOnce(&mut ServiceRegistry) + Send>;
+
+/// Describes a secondary world: the agents that run it and the services it
+/// overrides.
+///
+/// Services not overridden are looked up in the engine's registry, so a
+/// world with its own physics simulation only needs its own provider.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let provider: Arc<Mutex<Box<dyn PhysicsProvider>>> =
+///     Arc::new(Mutex::new(Box::new(RapierPhysicsWorld::default())));
+/// let id = worlds.lock().unwrap().create(
+///     WorldDesc::new()
+///         .with_agent(PhysicsAgent::default(), 1.0)
+///         .with_service(provider),
+/// );
+/// ```
+#[derive(Default)]
+pub struct WorldDesc {
+    pub(crate) agents: Vec<(Box<dyn Agent>, f32)>,
+    pub(crate) services: Vec<ServiceInstaller>,
+    pub(crate) paused: bool,
+}
+
+impl WorldDesc {
+    /// Creates a description with no agents and no service overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an agent to the world, with its priority in the world's
+    /// registry.
+    pub fn with_agent(mut self, agent: impl Agent + 'static, priority: f32) -> Self {
+        self.agents.push((Box::new(agent), priority));
+        self
+    }
+
+    /// Overrides a service for this world only.
+    pub fn with_service<T: Send + Sync + 'static>(mut self, service: T) -> Self {
+        self.services
+            .push(Box::new(move |registry| registry.insert(service)));
+        self
+    }
+
+    /// Starts the world paused.
+    pub fn paused(mut self) -> Self {
+        self.paused = true;
+        self
+    }
+}
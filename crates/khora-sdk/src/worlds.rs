@@ -0,0 +1,390 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secondary worlds — isolated ECS worlds running next to the primary one.
+//!
+//! A server and a client in one process, a level loading in the background
+//! or an editor preview each get their own [`GameWorld`], their own agents
+//! and their own service overrides, advanced once per engine tick after the
+//! primary world. Each can be paused and stepped on its own, and entities
+//! move between worlds with [`Worlds::transfer`].
+
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use khora_control::{substrate, AgentRegistry, Context, ExecutionScheduler};
+use khora_core::ecs::entity::EntityId;
+use khora_core::lane::{LaneBus, OutputDeck};
+use khora_core::{EngineContext, ServiceRegistry};
+use khora_data::ecs::{EcsMaintenance, TickPhase};
+
+use crate::{GameWorld, WorldDesc};
+
+/// Identifies a world run by the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WorldId(u32);
+
+impl WorldId {
+    /// The world handed to [`EngineApp`](crate::EngineApp) callbacks.
+    pub const PRIMARY: WorldId = WorldId(0);
+}
+
+/// Shared handle to the engine's [`Worlds`], registered in the
+/// `ServiceRegistry`.
+pub type SharedWorlds = Arc<Mutex<Worlds>>;
+
+/// Agents, scheduler and services of a secondary world, built on its first
+/// frame once the engine's services are known.
+struct WorldRuntime {
+    registry: Arc<Mutex<AgentRegistry>>,
+    scheduler: ExecutionScheduler,
+    services: Arc<ServiceRegistry>,
+}
+
+impl WorldRuntime {
+    fn start(
+        desc: WorldDesc,
+        parent: &Arc<ServiceRegistry>,
+        context: &Arc<RwLock<Context>>,
+    ) -> Self {
+        let mut services = ServiceRegistry::with_parent(Arc::clone(parent));
+        // Page locations are per world: orphaned rows must be cleaned up
+        // in the world that left them.
+        services.insert(Arc::new(Mutex::new(EcsMaintenance::new())));
+        for install in desc.services {
+            install(&mut services);
+        }
+        let services = Arc::new(services);
+
+        let mut registry = AgentRegistry::new();
+        for (agent, priority) in desc.agents {
+            registry.register(agent, priority);
+        }
+        let bus = LaneBus::new();
+        let mut deck = OutputDeck::new();
+        registry.initialize_all(&mut EngineContext {
+            world: None,
+            services: Arc::clone(&services),
+            bus: &bus,
+            deck: &mut deck,
+        });
+
+        let agent_ids = registry.all_ids();
+        let registry = Arc::new(Mutex::new(registry));
+        let scheduler =
+            ExecutionScheduler::new(Arc::clone(&registry), Arc::clone(context), &agent_ids);
+        Self {
+            registry,
+            scheduler,
+            services,
+        }
+    }
+
+    fn shutdown(mut self, timeout: Duration) {
+        let stuck = self.scheduler.shutdown(timeout);
+        if !stuck.is_empty() {
+            log::warn!("Worlds: worker agents still running: {:?}", stuck);
+        }
+        match self.registry.lock() {
+            Ok(mut registry) => {
+                let skipped = registry.shutdown_all(&self.services, timeout);
+                if !skipped.is_empty() {
+                    log::warn!("Worlds: agents not shut down: {:?}", skipped);
+                }
+            }
+            Err(_) => log::error!("Worlds: agent registry lock poisoned"),
+        }
+    }
+}
+
+/// One secondary world and its run state.
+struct WorldSlot {
+    world: GameWorld,
+    desc: Option<WorldDesc>,
+    runtime: Option<WorldRuntime>,
+    paused: bool,
+    pending_steps: u32,
+}
+
+/// The secondary worlds run by the engine.
+///
+/// Registered as a [`SharedWorlds`] service: apps fetch it in `setup`,
+/// keep the handle and create, pause, step or destroy worlds from then on.
+/// The engine advances every running world once per tick, right after the
+/// primary world's agents. Nothing is rendered from a secondary world
+/// unless its own agents do so.
+#[derive(Default)]
+pub struct Worlds {
+    slots: Vec<Option<WorldSlot>>,
+}
+
+impl Worlds {
+    /// Creates an empty set of secondary worlds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a secondary world. Its agents are initialized on the next
+    /// engine tick; the world itself can be populated right away.
+    pub fn create(&mut self, desc: WorldDesc) -> WorldId {
+        let slot = WorldSlot {
+            world: GameWorld::new(),
+            paused: desc.paused,
+            desc: Some(desc),
+            runtime: None,
+            pending_steps: 0,
+        };
+        let index = match self.slots.iter().position(Option::is_none) {
+            Some(index) => {
+                self.slots[index] = Some(slot);
+                index
+            }
+            None => {
+                self.slots.push(Some(slot));
+                self.slots.len() - 1
+            }
+        };
+        WorldId(index as u32 + 1)
+    }
+
+    /// Shuts a secondary world's agents down and drops the world. Returns
+    /// `false` if there is no such world.
+    pub fn destroy(&mut self, id: WorldId, timeout: Duration) -> bool {
+        let Some(slot) = self.slot_index(id).and_then(|i| self.slots[i].take()) else {
+            return false;
+        };
+        if let Some(runtime) = slot.runtime {
+            runtime.shutdown(timeout);
+        }
+        true
+    }
+
+    /// Returns the IDs of every secondary world.
+    pub fn ids(&self) -> Vec<WorldId> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.is_some())
+            .map(|(index, _)| WorldId(index as u32 + 1))
+            .collect()
+    }
+
+    /// Returns a secondary world.
+    pub fn get(&self, id: WorldId) -> Option<&GameWorld> {
+        self.slot(id).map(|slot| &slot.world)
+    }
+
+    /// Returns a secondary world mutably.
+    pub fn get_mut(&mut self, id: WorldId) -> Option<&mut GameWorld> {
+        self.slot_mut(id).map(|slot| &mut slot.world)
+    }
+
+    /// Pauses or resumes a secondary world. A paused world keeps its
+    /// entities but runs neither its data systems nor its agents.
+    /// Returns `false` if there is no such world.
+    pub fn set_paused(&mut self, id: WorldId, paused: bool) -> bool {
+        let Some(slot) = self.slot_mut(id) else {
+            return false;
+        };
+        slot.paused = paused;
+        if !paused {
+            slot.pending_steps = 0;
+        }
+        true
+    }
+
+    /// Returns `true` if the world exists and is paused.
+    pub fn is_paused(&self, id: WorldId) -> bool {
+        self.slot(id).is_some_and(|slot| slot.paused)
+    }
+
+    /// Advances a paused world by `frames` engine ticks, one per tick.
+    /// Returns `false` if there is no such world or it is not paused.
+    pub fn step(&mut self, id: WorldId, frames: u32) -> bool {
+        match self.slot_mut(id) {
+            Some(slot) if slot.paused => {
+                slot.pending_steps = slot.pending_steps.saturating_add(frames);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves `entity` from one secondary world to another, returning its
+    /// new ID. Use [`transfer_from_primary`](Self::transfer_from_primary)
+    /// and [`transfer_to_primary`](Self::transfer_to_primary) for the
+    /// primary world.
+    pub fn transfer(&mut self, entity: EntityId, from: WorldId, to: WorldId) -> Option<EntityId> {
+        let (from, to) = (self.slot_index(from)?, self.slot_index(to)?);
+        if from == to {
+            return None;
+        }
+        let (low, high) = self.slots.split_at_mut(from.max(to));
+        let (src, dst) = if from < to {
+            (low[from].as_mut()?, high[0].as_mut()?)
+        } else {
+            (high[0].as_mut()?, low[to].as_mut()?)
+        };
+        src.world.transfer_entity(entity, &mut dst.world)
+    }
+
+    /// Moves `entity` from the primary world into a secondary world.
+    pub fn transfer_from_primary(
+        &mut self,
+        primary: &mut GameWorld,
+        entity: EntityId,
+        to: WorldId,
+    ) -> Option<EntityId> {
+        primary.transfer_entity(entity, self.get_mut(to)?)
+    }
+
+    /// Moves `entity` from a secondary world into the primary world.
+    pub fn transfer_to_primary(
+        &mut self,
+        entity: EntityId,
+        from: WorldId,
+        primary: &mut GameWorld,
+    ) -> Option<EntityId> {
+        self.get_mut(from)?.transfer_entity(entity, primary)
+    }
+
+    /// Advances every running secondary world by one tick: data systems,
+    /// then the world's agents through its own scheduler.
+    pub(crate) fn run_frame(
+        &mut self,
+        parent: &Arc<ServiceRegistry>,
+        context: &Arc<RwLock<Context>>,
+    ) {
+        for slot in self.slots.iter_mut().flatten() {
+            if slot.paused {
+                if slot.pending_steps == 0 {
+                    continue;
+                }
+                slot.pending_steps -= 1;
+            }
+
+            if let Some(desc) = slot.desc.take() {
+                slot.runtime = Some(WorldRuntime::start(desc, parent, context));
+            }
+            let Some(runtime) = slot.runtime.as_mut() else {
+                continue;
+            };
+
+            let world = slot.world.inner_world_mut();
+            for phase in [
+                TickPhase::PreSimulation,
+                TickPhase::PostSimulation,
+                TickPhase::PreExtract,
+            ] {
+                substrate::run_data_systems(world, &runtime.services, phase);
+            }
+            runtime
+                .scheduler
+                .run_frame(world, Arc::clone(&runtime.services));
+            substrate::run_data_systems(world, &runtime.services, TickPhase::Maintenance);
+        }
+    }
+
+    /// Shuts every secondary world down. Called once by the engine during
+    /// shutdown, before the primary world's agents.
+    pub(crate) fn shutdown(&mut self, timeout: Duration) {
+        for slot in self.slots.drain(..).flatten() {
+            if let Some(runtime) = slot.runtime {
+                runtime.shutdown(timeout);
+            }
+        }
+    }
+
+    fn slot_index(&self, id: WorldId) -> Option<usize> {
+        let index = (id.0 as usize).checked_sub(1)?;
+        self.slots.get(index)?.as_ref().map(|_| index)
+    }
+
+    fn slot(&self, id: WorldId) -> Option<&WorldSlot> {
+        self.slots.get(self.slot_index(id)?)?.as_ref()
+    }
+
+    fn slot_mut(&mut self, id: WorldId) -> Option<&mut WorldSlot> {
+        let index = self.slot_index(id)?;
+        self.slots[index].as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use khora_data::ecs::Transform;
+
+    #[test]
+    fn worlds_reuse_freed_ids_and_never_hand_out_primary() {
+        let mut worlds = Worlds::new();
+        let a = worlds.create(WorldDesc::new());
+        let b = worlds.create(WorldDesc::new());
+        assert_ne!(a, WorldId::PRIMARY);
+        assert_ne!(a, b);
+
+        assert!(worlds.destroy(a, Duration::from_millis(10)));
+        assert!(worlds.get(a).is_none());
+        assert_eq!(worlds.create(WorldDesc::new()), a);
+        assert_eq!(worlds.ids(), vec![a, b]);
+    }
+
+    #[test]
+    fn paused_worlds_only_run_their_steps() {
+        let parent = Arc::new(ServiceRegistry::new());
+        let context = Arc::new(RwLock::new(Context::default()));
+        let mut worlds = Worlds::new();
+        let id = worlds.create(WorldDesc::new().paused());
+        assert!(worlds.is_paused(id));
+
+        assert!(worlds.step(id, 2));
+        worlds.run_frame(&parent, &context);
+        worlds.run_frame(&parent, &context);
+        worlds.run_frame(&parent, &context);
+        assert_eq!(worlds.slot(id).map(|slot| slot.pending_steps), Some(0));
+
+        assert!(worlds.set_paused(id, false));
+        assert!(!worlds.step(id, 1));
+    }
+
+    #[test]
+    fn entities_move_between_worlds() {
+        let mut primary = GameWorld::new();
+        let mut worlds = Worlds::new();
+        let a = worlds.create(WorldDesc::new());
+        let b = worlds.create(WorldDesc::new());
+
+        let entity = primary.spawn(Transform::identity());
+        let in_a = worlds
+            .transfer_from_primary(&mut primary, entity, a)
+            .unwrap();
+        let in_b = worlds.transfer(in_a, a, b).unwrap();
+        let back = worlds.transfer_to_primary(in_b, b, &mut primary).unwrap();
+
+        assert!(worlds
+            .get(a)
+            .unwrap()
+            .inner_world()
+            .get::<Transform>(in_a)
+            .is_none());
+        assert!(worlds
+            .get(b)
+            .unwrap()
+            .inner_world()
+            .get::<Transform>(in_b)
+            .is_none());
+        assert!(primary.inner_world().get::<Transform>(back).is_some());
+        assert!(worlds.transfer(in_b, b, b).is_none());
+    }
+}
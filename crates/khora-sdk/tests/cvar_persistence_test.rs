@@ -0,0 +1,100 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CVar persistence integration test.
+//!
+//! Console variables changed during a run are saved on shutdown and back
+//! on the next start, both the engine's and those the app registers in
+//! `setup`.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use khora_sdk::{
+    AgentProvider, CVarRegistry, DccService, EngineApp, GameWorld, HeadlessRunner, InputEvent,
+    PhaseProvider, ServiceRegistry, WindowConfig,
+};
+
+/// Values seen in `setup`: the engine's FPS cap and the app's difficulty.
+type Seen = Arc<Mutex<Option<(i64, i64)>>>;
+
+#[derive(Default)]
+struct CVarApp {
+    /// Values to set in `setup`, if any.
+    change: Option<(i64, i64)>,
+    seen: Seen,
+}
+
+impl AgentProvider for CVarApp {
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+}
+
+impl PhaseProvider for CVarApp {}
+
+impl EngineApp for CVarApp {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn cvar_path() -> Option<PathBuf> {
+        Some(std::env::temp_dir().join(format!("khora-cvar-test-{}.json", std::process::id())))
+    }
+
+    fn setup(&mut self, _world: &mut GameWorld, services: &ServiceRegistry) {
+        let cvars = services.get::<CVarRegistry>().unwrap();
+        cvars
+            .register("game.difficulty", "Enemy difficulty", 1)
+            .unwrap();
+        *self.seen.lock().unwrap() = Some((
+            cvars.get_int("power.battery_fps_cap").unwrap(),
+            cvars.get_int("game.difficulty").unwrap(),
+        ));
+        if let Some((fps_cap, difficulty)) = self.change {
+            cvars.set("power.battery_fps_cap", fps_cap).unwrap();
+            cvars.set("game.difficulty", difficulty).unwrap();
+        }
+    }
+
+    fn update(&mut self, _world: &mut GameWorld, _inputs: &[InputEvent]) {}
+}
+
+fn run_once(change: Option<(i64, i64)>) -> (i64, i64) {
+    let seen = Seen::default();
+    let app = CVarApp {
+        change,
+        seen: Arc::clone(&seen),
+    };
+    let mut runner = HeadlessRunner::new(app);
+    runner.run(1);
+    runner.shutdown();
+    let values = *seen.lock().unwrap();
+    values.unwrap()
+}
+
+#[test]
+fn changed_cvars_survive_shutdown_and_restart() {
+    let path = CVarApp::cvar_path().unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    let defaults = run_once(Some((45, 3)));
+    assert_ne!(defaults, (45, 3));
+    assert!(path.exists());
+
+    assert_eq!(run_once(None), (45, 3));
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,343 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden-image tests for the render lanes.
+//!
+//! Each canonical scene is rendered offscreen through every scene
+//! `RenderLane` at a fixed resolution, read back, and compared against a
+//! stored reference in `tests/golden/` with a perceptual (YIQ) tolerance so
+//! that driver-level rasterization noise does not fail the suite.
+//!
+//! * Missing references are recorded on first run.
+//! * `KHORA_BLESS_GOLDENS=1` (or `cargo xtask golden --bless`) overwrites
+//!   every reference with the current output.
+//! * On mismatch, `<name>.actual.png` and `<name>.diff.png` are written to
+//!   Cargo's test tmp dir for inspection.
+//! * Without a usable adapter the tests log and skip.
+//!
+//! There is no wireframe render lane yet; add one to [`LANES`] once it exists.
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use khora_core::asset::{StandardMaterial, UnlitMaterial};
+use khora_core::lane::{ClearColor, Lane, LaneContext, OutputDeck, Ref, Slot};
+use khora_core::math::{LinearRgba, Mat4, Quaternion, Vec3};
+use khora_core::renderer::api::scene::GpuMesh;
+use khora_core::renderer::traits::CommandEncoder;
+use khora_core::renderer::GraphicsDevice;
+use khora_core::ServiceRegistry;
+use khora_data::assets::Assets;
+use khora_data::ecs::{Camera, GlobalTransform, Light, MaterialComponent};
+use khora_data::flow::{Flow, RenderFlow, Selection, ShadowFlow, ShadowView};
+use khora_data::render::RenderWorld;
+use khora_data::{GpuCache, ProjectionRegistry};
+use khora_lanes::render_lane::{ForwardPlusLane, LitForwardLane, ShadowPassLane, SimpleUnlitLane};
+use khora_sdk::{spawn_cube_at, spawn_plane, spawn_sphere, GameWorld, HeadlessWgpu};
+
+/// Fixed output resolution of every golden image.
+const GOLDEN_SIZE: u32 = 256;
+
+/// Per-pixel YIQ distance (0..1) under which two pixels count as equal.
+const PIXEL_THRESHOLD: f32 = 0.1;
+
+/// Fraction of pixels allowed to exceed [`PIXEL_THRESHOLD`].
+const MAX_DIFF_RATIO: f32 = 0.005;
+
+/// Environment variable that turns every comparison into a re-record.
+const BLESS_ENV: &str = "KHORA_BLESS_GOLDENS";
+
+/// The scene lanes under test, keyed by the name used in golden file names.
+const LANES: &[&str] = &["unlit", "lit_forward", "forward_plus"];
+
+fn make_lane(name: &str) -> Box<dyn Lane> {
+    match name {
+        "unlit" => Box::new(SimpleUnlitLane::new()),
+        "lit_forward" => Box::new(LitForwardLane::new()),
+        "forward_plus" => Box::new(ForwardPlusLane::new()),
+        other => panic!("unknown golden lane '{other}'"),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Canonical scenes
+// ─────────────────────────────────────────────────────────────────────
+
+fn material(world: &mut GameWorld, color: LinearRgba, unlit: bool) -> MaterialComponent {
+    if unlit {
+        world.add_material(UnlitMaterial {
+            base_color: color,
+            ..Default::default()
+        })
+    } else {
+        world.add_material(StandardMaterial {
+            base_color: color,
+            ..Default::default()
+        })
+    }
+}
+
+/// Camera 6 units back and 2 up, pitched down towards the origin.
+fn spawn_camera(world: &mut GameWorld) {
+    let eye = Vec3::new(0.0, 2.0, 6.0);
+    let pitch = -(2.0f32).atan2(6.0);
+    let matrix =
+        Mat4::from_translation(eye) * Mat4::from_quat(Quaternion::from_axis_angle(Vec3::X, pitch));
+    world.spawn((
+        Camera::new_perspective(std::f32::consts::FRAC_PI_4, 1.0, 0.1, 100.0),
+        GlobalTransform::new(matrix),
+    ));
+}
+
+/// A single cube on a ground plane under one directional light.
+fn scene_cube(world: &mut GameWorld, unlit: bool) {
+    spawn_camera(world);
+    let ground = material(world, LinearRgba::new(0.4, 0.4, 0.4, 1.0), unlit);
+    spawn_plane(world, 8.0, -0.5).with_component(ground).build();
+    let red = material(world, LinearRgba::new(0.8, 0.1, 0.1, 1.0), unlit);
+    spawn_cube_at(world, Vec3::ZERO, 1.0)
+        .with_component(red)
+        .build();
+    world.spawn((Light::directional(), GlobalTransform::identity()));
+}
+
+/// Three primitives lit by a directional light and two colored point lights.
+fn scene_primitives(world: &mut GameWorld, unlit: bool) {
+    spawn_camera(world);
+    let ground = material(world, LinearRgba::new(0.5, 0.5, 0.5, 1.0), unlit);
+    spawn_plane(world, 10.0, -0.5)
+        .with_component(ground)
+        .build();
+    let green = material(world, LinearRgba::new(0.1, 0.7, 0.2, 1.0), unlit);
+    spawn_cube_at(world, Vec3::new(-1.5, 0.0, 0.0), 1.0)
+        .with_component(green)
+        .build();
+    let blue = material(world, LinearRgba::new(0.1, 0.3, 0.9, 1.0), unlit);
+    spawn_sphere(world, 0.6, 24, 16)
+        .at_position(Vec3::new(1.5, 0.1, 0.0))
+        .with_component(blue)
+        .build();
+    world.spawn((Light::directional(), GlobalTransform::identity()));
+    world.spawn((
+        Light::point(),
+        GlobalTransform::at_position(Vec3::new(-2.0, 1.5, 1.5)),
+    ));
+    world.spawn((
+        Light::point(),
+        GlobalTransform::at_position(Vec3::new(2.0, 1.5, 1.5)),
+    ));
+}
+
+type SceneBuilder = fn(&mut GameWorld, bool);
+
+// ─────────────────────────────────────────────────────────────────────
+// Rendering
+// ─────────────────────────────────────────────────────────────────────
+
+/// Renders `scene` through the lane called `lane_name` and returns RGBA8 pixels.
+///
+/// A shadow pass runs first, as in the engine: the lit lanes need its atlas.
+fn render(gpu: &HeadlessWgpu, lane_name: &str, scene: SceneBuilder) -> Vec<u8> {
+    let device: Arc<dyn GraphicsDevice> = gpu.graphics_device();
+    let shadow_lane = ShadowPassLane::new();
+    let lane = make_lane(lane_name);
+
+    let mut init_ctx = LaneContext::new();
+    init_ctx.insert(device.clone());
+    shadow_lane
+        .on_initialize(&mut init_ctx)
+        .expect("shadow lane initialization failed");
+    lane.on_initialize(&mut init_ctx)
+        .expect("lane initialization failed");
+
+    let mut world = GameWorld::new();
+    scene(&mut world, lane_name == "unlit");
+
+    let cache = GpuCache::new();
+    ProjectionRegistry::new(cache.clone()).sync_all(world.inner_world_mut(), device.as_ref());
+    let gpu_meshes: Arc<RwLock<Assets<GpuMesh>>> = cache.inner().clone();
+    let render_world: RenderWorld = RenderFlow::default().project(
+        world.inner_world(),
+        &Selection::new(),
+        &ServiceRegistry::new(),
+    );
+    let shadow_view: ShadowView = ShadowFlow.project(
+        world.inner_world(),
+        &Selection::new(),
+        &ServiceRegistry::new(),
+    );
+    let mut deck = OutputDeck::new();
+
+    let mut encoder = device.create_command_encoder(Some("Golden Encoder"));
+    {
+        let mut ctx = LaneContext::new();
+        ctx.insert(device.clone());
+        ctx.insert(gpu_meshes.clone());
+        // SAFETY: encoder outlives ctx, which is dropped before finish().
+        let encoder_slot = Slot::new(encoder.as_mut());
+        ctx.insert(unsafe {
+            std::mem::transmute::<Slot<dyn CommandEncoder>, Slot<dyn CommandEncoder>>(encoder_slot)
+        });
+        ctx.insert(Ref::new(&render_world));
+        ctx.insert(Ref::new(&shadow_view));
+        ctx.insert(Slot::new(&mut deck));
+        ctx.insert(gpu.color_target());
+        ctx.insert(gpu.depth_target());
+        ctx.insert(ClearColor(LinearRgba::new(0.1, 0.1, 0.15, 1.0)));
+        shadow_lane
+            .execute(&mut ctx)
+            .expect("shadow lane execution failed");
+        lane.execute(&mut ctx).expect("lane execution failed");
+    }
+    device.submit_command_buffer(encoder.finish());
+
+    let pixels = gpu.read_color().expect("color readback failed");
+    lane.on_shutdown(&mut init_ctx);
+    shadow_lane.on_shutdown(&mut init_ctx);
+    pixels
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Perceptual comparison
+// ─────────────────────────────────────────────────────────────────────
+
+/// Maximum possible YIQ delta between two RGB colors.
+const MAX_YIQ_DELTA: f32 = 35215.0;
+
+/// Squared YIQ distance between two sRGB pixels (pixelmatch metric).
+fn yiq_delta(a: &[u8], b: &[u8]) -> f32 {
+    let yiq = |p: &[u8]| {
+        let (r, g, b) = (p[0] as f32, p[1] as f32, p[2] as f32);
+        (
+            r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_3,
+            r * 0.595_977_9 - g * 0.274_176_3 - b * 0.321_801_6,
+            r * 0.211_470_2 - g * 0.522_617_4 + b * 0.311_147_2,
+        )
+    };
+    let (ya, ia, qa) = yiq(a);
+    let (yb, ib, qb) = yiq(b);
+    let (dy, di, dq) = (ya - yb, ia - ib, qa - qb);
+    0.5053 * dy * dy + 0.299 * di * di + 0.1957 * dq * dq
+}
+
+/// Compares two RGBA8 images; returns the differing pixel count and a diff image.
+fn compare(actual: &[u8], expected: &[u8]) -> (usize, Vec<u8>) {
+    let limit = MAX_YIQ_DELTA * PIXEL_THRESHOLD * PIXEL_THRESHOLD;
+    let mut diff = Vec::with_capacity(actual.len());
+    let mut differing = 0;
+    for (a, e) in actual.chunks_exact(4).zip(expected.chunks_exact(4)) {
+        if yiq_delta(a, e) > limit {
+            differing += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            // Faded grayscale of the reference so the red pixels stand out.
+            let gray = (e[0] as u32 + e[1] as u32 + e[2] as u32) / 3;
+            let faded = (255 - (255 - gray) / 4) as u8;
+            diff.extend_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+    (differing, diff)
+}
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+fn save_png(path: &PathBuf, pixels: &[u8]) {
+    image::save_buffer(
+        path,
+        pixels,
+        GOLDEN_SIZE,
+        GOLDEN_SIZE,
+        image::ExtendedColorType::Rgba8,
+    )
+    .unwrap_or_else(|e| panic!("failed to write {}: {e}", path.display()));
+}
+
+/// Checks `actual` against `tests/golden/<name>.png`, recording it if blessing
+/// or if no reference exists yet.
+fn check_golden(name: &str, actual: &[u8]) {
+    let reference = golden_dir().join(format!("{name}.png"));
+    let bless = std::env::var(BLESS_ENV).is_ok_and(|v| v == "1");
+
+    if bless || !reference.exists() {
+        std::fs::create_dir_all(golden_dir()).expect("failed to create golden dir");
+        save_png(&reference, actual);
+        eprintln!("golden: recorded {}", reference.display());
+        return;
+    }
+
+    let expected = image::open(&reference)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", reference.display()))
+        .to_rgba8();
+    assert_eq!(
+        expected.dimensions(),
+        (GOLDEN_SIZE, GOLDEN_SIZE),
+        "golden {name} has the wrong size; re-bless it"
+    );
+
+    let (differing, diff) = compare(actual, expected.as_raw());
+    let ratio = differing as f32 / (GOLDEN_SIZE * GOLDEN_SIZE) as f32;
+    if ratio > MAX_DIFF_RATIO {
+        let out = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+        let actual_path = out.join(format!("{name}.actual.png"));
+        let diff_path = out.join(format!("{name}.diff.png"));
+        save_png(&actual_path, actual);
+        save_png(&diff_path, &diff);
+        panic!(
+            "golden {name}: {differing} pixels ({:.2}%) differ, limit {:.2}%\n  actual: {}\n  diff:   {}\n  run `cargo xtask golden --bless` if the change is intended",
+            ratio * 100.0,
+            MAX_DIFF_RATIO * 100.0,
+            actual_path.display(),
+            diff_path.display()
+        );
+    }
+}
+
+/// Renders `scene` through every lane and checks each result.
+fn run_scene(scene_name: &str, scene: SceneBuilder) {
+    let gpu = match HeadlessWgpu::new(GOLDEN_SIZE, GOLDEN_SIZE) {
+        Ok(gpu) => gpu,
+        Err(e) => {
+            eprintln!("Skipping golden scene '{scene_name}': no headless adapter ({e})");
+            return;
+        }
+    };
+    for lane in LANES {
+        let pixels = render(&gpu, lane, scene);
+        check_golden(&format!("{scene_name}_{lane}"), &pixels);
+    }
+}
+
+#[test]
+fn golden_cube() {
+    run_scene("cube", scene_cube);
+}
+
+#[test]
+fn golden_primitives() {
+    run_scene("primitives", scene_primitives);
+}
+
+#[test]
+fn perceptual_compare_ignores_small_shifts() {
+    let a = vec![100u8, 100, 100, 255];
+    let b = vec![102u8, 101, 99, 255];
+    assert_eq!(compare(&a, &b).0, 0);
+
+    let c = vec![200u8, 40, 40, 255];
+    assert_eq!(compare(&a, &c).0, 1);
+}
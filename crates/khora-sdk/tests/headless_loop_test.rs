@@ -0,0 +1,196 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Headless integration tests for the SDK frame loop.
+//!
+//! A small app is driven through [`HeadlessRunner`] with scripted input:
+//! the tests assert on world state, input delivery, UI routing, frame
+//! timing and the render report of a GPU-less run.
+
+use khora_sdk::prelude::ecs::{EntityId, GlobalTransform, Transform};
+use khora_sdk::prelude::math::{Vec2, Vec3, Vec4};
+use khora_sdk::prelude::ui::{UiButton, UiInputRouter, UiInteraction, UiTransform};
+use khora_sdk::{
+    AgentProvider, DccService, EngineApp, GameWorld, HeadlessRunner, InputEvent, InputScript,
+    PhaseProvider, ServiceRegistry, StartupPhase, StartupProfile, WindowConfig,
+    HEADLESS_FRAME_STEP,
+};
+
+use khora_core::utils::frame_time::SharedFrameTime;
+
+/// Walks forward one unit per frame while `KeyW` is held and counts
+/// clicks on a UI button.
+#[derive(Default)]
+struct ScriptedApp {
+    player: Option<EntityId>,
+    child: Option<EntityId>,
+    button: Option<EntityId>,
+    walking: bool,
+    updates: u64,
+    inputs_seen: usize,
+    clicks: u32,
+}
+
+impl AgentProvider for ScriptedApp {
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+}
+
+impl PhaseProvider for ScriptedApp {}
+
+impl EngineApp for ScriptedApp {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn setup(&mut self, world: &mut GameWorld, _services: &ServiceRegistry) {
+        let player = world.spawn((Transform::identity(), GlobalTransform::default()));
+        let child = world.spawn((
+            Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+            GlobalTransform::default(),
+        ));
+        world.set_parent(child, Some(player));
+        let button = world.spawn((
+            UiTransform {
+                pos: Vec2::new(10.0, 10.0),
+                size: Vec2::new(100.0, 40.0),
+                z_index: 0,
+            },
+            UiInteraction {
+                blocks_input: true,
+                ..Default::default()
+            },
+            UiButton::new(Vec4::new(0.3, 0.3, 0.3, 1.0)),
+        ));
+        self.player = Some(player);
+        self.child = Some(child);
+        self.button = Some(button);
+    }
+
+    fn update(&mut self, world: &mut GameWorld, inputs: &[InputEvent]) {
+        self.updates += 1;
+        self.inputs_seen += inputs.len();
+        for input in inputs {
+            match input {
+                InputEvent::KeyPressed { key_code } if key_code == "KeyW" => self.walking = true,
+                InputEvent::KeyReleased { key_code } if key_code == "KeyW" => self.walking = false,
+                _ => {}
+            }
+        }
+        if self.walking {
+            if let Some(transform) = self.player.and_then(|p| world.get_transform_mut(p)) {
+                transform.translation.z -= 1.0;
+            }
+        }
+        let clicked = self
+            .button
+            .and_then(|b| world.get_component::<UiButton>(b))
+            .is_some_and(|b| b.clicked);
+        if clicked {
+            self.clicks += 1;
+        }
+    }
+}
+
+#[test]
+fn runs_requested_number_of_frames() {
+    let mut runner = HeadlessRunner::new(ScriptedApp::new());
+    let reports = runner.run(5);
+
+    assert_eq!(reports.len(), 5);
+    assert_eq!(reports[4].frame, 4);
+    assert_eq!(runner.frame(), 5);
+    assert_eq!(runner.app_mut().updates, 5);
+}
+
+#[test]
+fn scripted_input_drives_world_state() {
+    let script = InputScript::new().key_hold(2, 5, "KeyW");
+    let mut runner = HeadlessRunner::new(ScriptedApp::new()).with_script(script);
+    runner.run(8);
+
+    let inputs: Vec<usize> = runner.reports().iter().map(|r| r.inputs).collect();
+    assert_eq!(inputs, vec![0, 0, 1, 0, 0, 1, 0, 0]);
+    assert_eq!(runner.app_mut().inputs_seen, 2);
+
+    let player = runner.app_mut().player.unwrap();
+    let child = runner.app_mut().child.unwrap();
+    let world = runner.world();
+    assert_eq!(
+        world.get_transform(player).unwrap().translation,
+        Vec3::new(0.0, 0.0, -3.0)
+    );
+    // transform_propagation ran after app.update on the last frame.
+    let child_global = world.get_component::<GlobalTransform>(child).unwrap();
+    assert_eq!(child_global.0.translation(), Vec3::new(0.0, 1.0, -3.0));
+}
+
+#[test]
+fn scripted_click_is_routed_to_ui_button() {
+    let script = InputScript::new()
+        .move_cursor(0, 50.0, 30.0)
+        .click(2, 50.0, 30.0);
+    let mut runner = HeadlessRunner::new(ScriptedApp::new()).with_script(script);
+    runner.run_script();
+    runner.run(2);
+
+    assert_eq!(runner.app_mut().clicks, 1);
+    let router = runner.services().get::<UiInputRouter>().unwrap().clone();
+    assert_eq!(router.hovered(), runner.app_mut().button);
+    assert!(router.is_pointer_over_ui());
+}
+
+#[test]
+fn frame_time_advances_by_fixed_step() {
+    let mut runner = HeadlessRunner::new(ScriptedApp::new());
+    runner.run(3);
+
+    let time = runner.services().get::<SharedFrameTime>().unwrap().clone();
+    let time = time.read().unwrap();
+    assert_eq!(time.frame_count, 3);
+    assert!((time.delta_seconds - HEADLESS_FRAME_STEP.as_secs_f32()).abs() < 1e-6);
+}
+
+#[test]
+fn gpu_less_run_records_nothing_to_present() {
+    let mut runner = HeadlessRunner::new(ScriptedApp::new());
+    let report = runner.step().clone();
+
+    assert!(!report.presented);
+    assert!(report.render_stats.is_none());
+    assert!(report.passes.is_empty());
+    assert_eq!(report.entities, 3);
+}
+
+#[test]
+fn startup_profile_completes_after_first_frame() {
+    let mut runner = HeadlessRunner::new(ScriptedApp::new());
+    let profile = runner
+        .services()
+        .get::<StartupProfile>()
+        .cloned()
+        .expect("bootstrap registers the startup profile");
+    assert!(profile.phase(StartupPhase::Engine).is_some());
+    assert!(!profile.is_finished());
+
+    runner.run(2);
+
+    assert!(profile.is_finished());
+    let phases: Vec<StartupPhase> = profile.phases().into_iter().map(|(p, _)| p).collect();
+    assert_eq!(phases, vec![StartupPhase::Engine, StartupPhase::FirstFrame]);
+    assert!(profile.total() >= profile.phase(StartupPhase::FirstFrame).unwrap());
+}
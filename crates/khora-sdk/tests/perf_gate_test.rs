@@ -0,0 +1,103 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Performance gates.
+//!
+//! Each gate runs a canonical scene headlessly and asserts a [`PerfBudget`]
+//! on the measured frames. Timing is meaningless in debug builds, so the
+//! gates are ignored by default; `cargo xtask test --perf` runs them in
+//! release. Frame-time budgets are for the reference machine and are scaled
+//! by `KHORA_PERF_PROFILE` (`reference`, `ci`, or a bare factor).
+//!
+//! Draw-call and VRAM ceilings only apply to frames that carry render
+//! statistics, i.e. when a render system is injected into the runner.
+
+use std::time::Duration;
+
+use khora_sdk::prelude::ecs::{EntityId, GlobalTransform, Transform};
+use khora_sdk::prelude::math::Vec3;
+use khora_sdk::{
+    spawn_cube_at, AgentProvider, DccService, EngineApp, GameWorld, HeadlessRunner, InputEvent,
+    PerfBudget, PhaseProvider, ServiceRegistry, WindowConfig,
+};
+
+/// Frames excluded from every gate (first uploads, cache warm-up).
+const WARMUP_FRAMES: usize = 10;
+
+/// Frames measured by every gate.
+const MEASURED_FRAMES: u64 = 120;
+
+/// A 20×20 grid of cubes, each with a child, spinning every frame.
+#[derive(Default)]
+struct CubeGrid {
+    roots: Vec<EntityId>,
+}
+
+impl AgentProvider for CubeGrid {
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+}
+
+impl PhaseProvider for CubeGrid {}
+
+impl EngineApp for CubeGrid {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn setup(&mut self, world: &mut GameWorld, _services: &ServiceRegistry) {
+        for x in 0..20 {
+            for z in 0..20 {
+                let position = Vec3::new(x as f32 * 2.0, 0.0, z as f32 * 2.0);
+                let root = spawn_cube_at(world, position, 1.0).build();
+                let child = world.spawn((
+                    Transform::from_translation(Vec3::new(0.0, 1.5, 0.0)),
+                    GlobalTransform::default(),
+                ));
+                world.set_parent(child, Some(root));
+                self.roots.push(root);
+            }
+        }
+    }
+
+    fn update(&mut self, world: &mut GameWorld, _inputs: &[InputEvent]) {
+        for &root in &self.roots {
+            if let Some(transform) = world.get_transform_mut(root) {
+                transform.translation.y = (transform.translation.y + 0.01) % 1.0;
+            }
+        }
+    }
+}
+
+fn cube_grid_budget() -> PerfBudget {
+    PerfBudget::new()
+        .with_max_draw_calls(800)
+        .with_max_frame_time(Duration::from_millis(4))
+        .with_max_vram_mb(256.0)
+        .with_warmup_frames(WARMUP_FRAMES)
+}
+
+#[test]
+#[ignore = "performance gate, run with `cargo xtask test --perf`"]
+fn cube_grid_stays_within_budget() {
+    let mut runner = HeadlessRunner::new(CubeGrid::new());
+    runner.run(WARMUP_FRAMES as u64 + MEASURED_FRAMES);
+
+    let summary = runner.assert_budget(&cube_grid_budget());
+    println!("cube_grid: {summary:?}");
+    assert_eq!(summary.frames, MEASURED_FRAMES as usize);
+}
@@ -0,0 +1,116 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Plugin integration test.
+//!
+//! Plugins installed by the app are built before its setup, run their
+//! startup system once, and hook into every frame.
+
+use std::sync::{Arc, Mutex};
+
+use khora_sdk::{
+    AgentProvider, DccService, EngineApp, EnginePlugin, ExecutionPhase, GameWorld, HeadlessRunner,
+    InputEvent, PhaseProvider, Plugin, PluginSet, ServiceRegistry, WindowConfig,
+};
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+/// Service inserted by the plugin and read by the app.
+struct Greeting(&'static str);
+
+struct GreetingPlugin {
+    journal: Journal,
+}
+
+impl Plugin for GreetingPlugin {
+    fn name(&self) -> &str {
+        "greeting"
+    }
+
+    fn build(&self, _world: &mut GameWorld, services: &mut ServiceRegistry) {
+        services.insert(Greeting("hello"));
+        self.journal.lock().unwrap().push("build".into());
+    }
+
+    fn phase_hooks(&self) -> Vec<EnginePlugin> {
+        let journal = Arc::clone(&self.journal);
+        let mut hook = EnginePlugin::new("greeting_hook");
+        hook.on_phase(ExecutionPhase::OBSERVE, move |_world| {
+            journal.lock().unwrap().push("hook".into());
+        });
+        vec![hook]
+    }
+
+    fn startup(&self, _world: &mut GameWorld, _services: &ServiceRegistry) {
+        self.journal.lock().unwrap().push("startup".into());
+    }
+}
+
+#[derive(Default)]
+struct PluginApp {
+    journal: Journal,
+}
+
+impl AgentProvider for PluginApp {
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+}
+
+impl PhaseProvider for PluginApp {}
+
+impl EngineApp for PluginApp {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn plugins(&self, plugins: &mut PluginSet) {
+        plugins
+            .add_plugin(GreetingPlugin {
+                journal: Arc::clone(&self.journal),
+            })
+            .add_plugin(GreetingPlugin {
+                journal: Arc::clone(&self.journal),
+            });
+        assert_eq!(plugins.len(), 1);
+    }
+
+    fn setup(&mut self, _world: &mut GameWorld, services: &ServiceRegistry) {
+        let greeting = services.get::<Greeting>().map_or("none", |g| g.0);
+        self.journal
+            .lock()
+            .unwrap()
+            .push(format!("setup:{greeting}"));
+    }
+
+    fn update(&mut self, _world: &mut GameWorld, _inputs: &[InputEvent]) {}
+}
+
+#[test]
+fn plugins_build_before_setup_and_hook_every_frame() {
+    let journal = Journal::default();
+    let app = PluginApp {
+        journal: Arc::clone(&journal),
+    };
+
+    let mut runner = HeadlessRunner::new(app);
+    runner.run(2);
+
+    assert_eq!(
+        *journal.lock().unwrap(),
+        vec!["build", "setup:hello", "startup", "hook", "hook"]
+    );
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shutdown-order integration test.
+//!
+//! Agents must be torn down before the app persists its state, and both
+//! before the render system releases the GPU device.
+
+use std::sync::{Arc, Mutex};
+
+use khora_core::agent::Agent;
+use khora_core::control::gorna::{
+    AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+};
+use khora_core::EngineContext;
+use khora_sdk::{
+    AgentProvider, DccService, EngineApp, GameWorld, HeadlessRunner, InputEvent, PhaseProvider,
+    ServiceRegistry, WindowConfig,
+};
+
+type Journal = Arc<Mutex<Vec<&'static str>>>;
+
+struct ProbeAgent {
+    journal: Journal,
+}
+
+impl Agent for ProbeAgent {
+    fn id(&self) -> AgentId {
+        AgentId::Asset
+    }
+
+    fn negotiate(&mut self, _: NegotiationRequest) -> NegotiationResponse {
+        NegotiationResponse {
+            strategies: Vec::new(),
+            timing_adjustment: None,
+        }
+    }
+
+    fn apply_budget(&mut self, _: ResourceBudget) {}
+
+    fn report_status(&self) -> AgentStatus {
+        AgentStatus {
+            agent_id: AgentId::Asset,
+            current_strategy: StrategyId::Balanced,
+            health_score: 1.0,
+            is_stalled: false,
+            message: String::new(),
+        }
+    }
+
+    fn on_shutdown(&mut self, _context: &mut EngineContext<'_>) {
+        self.journal.lock().unwrap().push("agent");
+    }
+
+    fn execute(&mut self, _context: &mut EngineContext<'_>) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Default)]
+struct ShutdownApp {
+    journal: Journal,
+}
+
+impl AgentProvider for ShutdownApp {
+    fn register_agents(&self, dcc: &DccService, _services: &mut ServiceRegistry) {
+        let probe = ProbeAgent {
+            journal: Arc::clone(&self.journal),
+        };
+        dcc.register_agent(Box::new(probe), 0.1);
+    }
+}
+
+impl PhaseProvider for ShutdownApp {}
+
+impl EngineApp for ShutdownApp {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn setup(&mut self, _world: &mut GameWorld, _services: &ServiceRegistry) {}
+
+    fn update(&mut self, _world: &mut GameWorld, _inputs: &[InputEvent]) {}
+
+    fn on_shutdown(&mut self) {
+        self.journal.lock().unwrap().push("app");
+    }
+}
+
+#[test]
+fn agents_shut_down_before_the_app() {
+    let journal = Journal::default();
+    let app = ShutdownApp {
+        journal: Arc::clone(&journal),
+    };
+
+    let mut runner = HeadlessRunner::new(app);
+    runner.run(2);
+    runner.shutdown();
+
+    assert_eq!(*journal.lock().unwrap(), vec!["agent", "app"]);
+}
+
+#[test]
+fn dropping_the_runner_shuts_down_once() {
+    let journal = Journal::default();
+    let app = ShutdownApp {
+        journal: Arc::clone(&journal),
+    };
+
+    let mut runner = HeadlessRunner::new(app);
+    runner.run(1);
+    drop(runner);
+
+    assert_eq!(*journal.lock().unwrap(), vec!["agent", "app"]);
+}
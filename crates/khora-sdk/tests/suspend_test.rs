@@ -0,0 +1,108 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Suspend/resume integration test.
+//!
+//! A minimized or occluded window suspends the engine: the app is told once,
+//! background ticks do no frame work, and resuming restores normal frames.
+
+use std::sync::{Arc, Mutex};
+
+use khora_sdk::{
+    AgentProvider, DccService, EngineApp, GameWorld, HeadlessRunner, InputEvent, PhaseProvider,
+    ServiceRegistry, WindowConfig,
+};
+
+type Journal = Arc<Mutex<Vec<&'static str>>>;
+
+#[derive(Default)]
+struct SuspendApp {
+    journal: Journal,
+}
+
+impl AgentProvider for SuspendApp {
+    fn register_agents(&self, _dcc: &DccService, _services: &mut ServiceRegistry) {}
+}
+
+impl PhaseProvider for SuspendApp {}
+
+impl EngineApp for SuspendApp {
+    fn window_config() -> WindowConfig {
+        WindowConfig::default()
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn setup(&mut self, _world: &mut GameWorld, _services: &ServiceRegistry) {}
+
+    fn update(&mut self, _world: &mut GameWorld, _inputs: &[InputEvent]) {
+        self.journal.lock().unwrap().push("update");
+    }
+
+    fn on_suspend(&mut self) {
+        self.journal.lock().unwrap().push("suspend");
+    }
+
+    fn on_resume(&mut self) {
+        self.journal.lock().unwrap().push("resume");
+    }
+}
+
+#[test]
+fn suspend_and_resume_notify_the_app_once() {
+    let journal = Journal::default();
+    let app = SuspendApp {
+        journal: Arc::clone(&journal),
+    };
+
+    let mut runner = HeadlessRunner::new(app);
+    runner.run(1);
+
+    runner.suspend();
+    runner.suspend();
+    assert!(runner.is_suspended());
+
+    runner.resume();
+    runner.resume();
+    assert!(!runner.is_suspended());
+
+    assert_eq!(
+        *journal.lock().unwrap(),
+        vec!["update", "suspend", "resume"]
+    );
+}
+
+#[test]
+fn background_ticks_run_no_frame_work() {
+    let journal = Journal::default();
+    let app = SuspendApp {
+        journal: Arc::clone(&journal),
+    };
+
+    let mut runner = HeadlessRunner::new(app);
+    runner.suspend();
+    for _ in 0..3 {
+        runner.background_tick();
+    }
+    runner.resume();
+    runner.run(1);
+
+    assert_eq!(runner.frame(), 1);
+    assert_eq!(
+        *journal.lock().unwrap(),
+        vec!["suspend", "resume", "update"]
+    );
+}
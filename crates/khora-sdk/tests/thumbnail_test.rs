@@ -0,0 +1,65 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for offscreen thumbnail rendering.
+//!
+//! Without a usable adapter the tests log and skip, like the golden-image
+//! suite.
+
+use khora_core::asset::StandardMaterial;
+use khora_core::math::{LinearRgba, Vec3};
+use khora_sdk::{spawn_cube_at, GameWorld, ThumbnailService};
+
+const SIZE: u32 = 64;
+
+fn service() -> Option<ThumbnailService> {
+    match ThumbnailService::new(SIZE) {
+        Ok(service) => Some(service),
+        Err(e) => {
+            eprintln!("Skipping thumbnail test: no headless adapter ({e:#})");
+            None
+        }
+    }
+}
+
+#[test]
+fn test_subject_is_framed_on_a_transparent_background() {
+    let Some(service) = service() else { return };
+
+    // Far from the origin: the camera must follow the bounds.
+    let mut world = GameWorld::new();
+    spawn_cube_at(&mut world, Vec3::new(40.0, -5.0, 12.0), 3.0).build();
+    let thumbnail = service.render_world(world).unwrap();
+
+    assert_eq!((thumbnail.width, thumbnail.height), (SIZE, SIZE));
+    assert_eq!(thumbnail.pixel(0, 0).map(|p| p[3]), Some(0));
+    let center = thumbnail.pixel(SIZE / 2, SIZE / 2).unwrap();
+    assert!(center[3] > 0, "the subject covers the center: {center:?}");
+}
+
+#[test]
+fn test_material_preview_uses_its_color() {
+    let Some(service) = service() else { return };
+
+    let thumbnail = service
+        .render_material(StandardMaterial {
+            base_color: LinearRgba::new(0.9, 0.05, 0.05, 1.0),
+            ..Default::default()
+        })
+        .unwrap();
+
+    let [r, g, b, a] = thumbnail.pixel(SIZE / 2, SIZE / 2).unwrap();
+    assert!(a > 0);
+    assert!(r > g && r > b, "red material renders red: {:?}", [r, g, b]);
+}
@@ -0,0 +1,268 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-frame metric history.
+//!
+//! The [`TelemetryService`](crate::TelemetryService) aggregates monitors on a
+//! fixed cadence. The history keeps, for each of the last N frames, every
+//! numeric metric value and the events marked during that frame, so a spike
+//! can be traced to the frame it happened on and to what happened around it.
+
+use khora_core::telemetry::MetricId;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Number of frames kept by default: ten seconds at 60 FPS.
+pub const DEFAULT_HISTORY_FRAMES: usize = 600;
+
+/// The metric values and events of one frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameRecord {
+    /// The engine frame number.
+    pub frame: u64,
+    /// Time since the telemetry service started.
+    pub at: Duration,
+    /// Every numeric metric value at the end of the frame.
+    pub metrics: Vec<(MetricId, f64)>,
+    /// Events marked during the frame (phase changes, loads, hitches).
+    pub events: Vec<String>,
+}
+
+impl FrameRecord {
+    /// Returns the value of `id` in this frame, if it was recorded.
+    pub fn value(&self, id: &MetricId) -> Option<f64> {
+        self.metrics
+            .iter()
+            .find(|(metric, _)| metric == id)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// A ring buffer of the last [`FrameRecord`]s, oldest first.
+///
+/// Cloning the history shares the same buffer, so the overlay and profiler
+/// read what the telemetry service writes.
+#[derive(Debug, Clone)]
+pub struct MetricHistory {
+    inner: Arc<Mutex<HistoryState>>,
+}
+
+#[derive(Debug)]
+struct HistoryState {
+    capacity: usize,
+    frames: VecDeque<FrameRecord>,
+}
+
+impl MetricHistory {
+    /// Creates a history keeping the last `capacity` frames (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            inner: Arc::new(Mutex::new(HistoryState {
+                capacity,
+                frames: VecDeque::with_capacity(capacity),
+            })),
+        }
+    }
+
+    /// Returns how many frames the history keeps.
+    pub fn capacity(&self) -> usize {
+        self.inner.lock().map(|s| s.capacity).unwrap_or(0)
+    }
+
+    /// Changes how many frames the history keeps, dropping the oldest ones
+    /// if it shrinks.
+    pub fn set_capacity(&self, capacity: usize) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.capacity = capacity.max(1);
+            let excess = state.frames.len().saturating_sub(state.capacity);
+            state.frames.drain(..excess);
+        }
+    }
+
+    /// Appends a frame, evicting the oldest one when full.
+    pub fn push(&self, record: FrameRecord) {
+        if let Ok(mut state) = self.inner.lock() {
+            if state.frames.len() == state.capacity {
+                state.frames.pop_front();
+            }
+            state.frames.push_back(record);
+        }
+    }
+
+    /// Returns the number of frames currently held.
+    pub fn len(&self) -> usize {
+        self.inner.lock().map(|s| s.frames.len()).unwrap_or(0)
+    }
+
+    /// Returns `true` if no frame has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the record of `frame`, if it is still in the buffer.
+    pub fn frame(&self, frame: u64) -> Option<FrameRecord> {
+        let state = self.inner.lock().ok()?;
+        let index = state
+            .frames
+            .binary_search_by_key(&frame, |record| record.frame)
+            .ok()?;
+        state.frames.get(index).cloned()
+    }
+
+    /// Returns the most recent record.
+    pub fn latest(&self) -> Option<FrameRecord> {
+        self.inner.lock().ok()?.frames.back().cloned()
+    }
+
+    /// Returns every record held, oldest first.
+    pub fn frames(&self) -> Vec<FrameRecord> {
+        self.inner
+            .lock()
+            .map(|s| s.frames.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns `(frame, value)` for every held frame that recorded `id`.
+    pub fn series(&self, id: &MetricId) -> Vec<(u64, f64)> {
+        let Ok(state) = self.inner.lock() else {
+            return Vec::new();
+        };
+        state
+            .frames
+            .iter()
+            .filter_map(|record| record.value(id).map(|value| (record.frame, value)))
+            .collect()
+    }
+
+    /// Returns the frames where `id` exceeded `threshold`, with their events.
+    pub fn spikes(&self, id: &MetricId, threshold: f64) -> Vec<FrameRecord> {
+        let Ok(state) = self.inner.lock() else {
+            return Vec::new();
+        };
+        state
+            .frames
+            .iter()
+            .filter(|record| record.value(id).is_some_and(|value| value > threshold))
+            .cloned()
+            .collect()
+    }
+
+    /// Drops every record.
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.frames.clear();
+        }
+    }
+
+    /// Exports the history in the Chrome trace event format, loadable in
+    /// Perfetto or `chrome://tracing` next to other traces: one counter
+    /// event per metric per frame and one instant event per marked event.
+    pub fn to_trace_events(&self) -> Value {
+        let mut events = Vec::new();
+        for record in self.frames() {
+            let ts = record.at.as_micros() as u64;
+            for (id, value) in &record.metrics {
+                events.push(json!({
+                    "name": id.to_string(),
+                    "ph": "C",
+                    "ts": ts,
+                    "pid": 0,
+                    "args": { "value": value, "frame": record.frame },
+                }));
+            }
+            for event in &record.events {
+                events.push(json!({
+                    "name": event,
+                    "ph": "i",
+                    "s": "g",
+                    "ts": ts,
+                    "pid": 0,
+                    "args": { "frame": record.frame },
+                }));
+            }
+        }
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+
+    /// Writes [`to_trace_events`](Self::to_trace_events) to `path` as JSON.
+    pub fn write_trace(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string(&self.to_trace_events())?;
+        std::fs::write(path, json)
+    }
+}
+
+impl Default for MetricHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_FRAMES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(frame: u64, frame_ms: f64) -> FrameRecord {
+        FrameRecord {
+            frame,
+            at: Duration::from_millis(frame * 16),
+            metrics: vec![(MetricId::new("renderer", "frame_time"), frame_ms)],
+            events: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ring_buffer_keeps_the_last_frames() {
+        let history = MetricHistory::new(3);
+        for frame in 0..5 {
+            history.push(record(frame, 16.0));
+        }
+        let frames: Vec<u64> = history.frames().iter().map(|r| r.frame).collect();
+        assert_eq!(frames, vec![2, 3, 4]);
+        assert!(history.frame(1).is_none());
+        assert_eq!(history.frame(3).map(|r| r.frame), Some(3));
+
+        history.set_capacity(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.frame(2), None);
+    }
+
+    #[test]
+    fn spikes_carry_their_frame_events() {
+        let id = MetricId::new("renderer", "frame_time");
+        let history = MetricHistory::new(10);
+        history.push(record(0, 16.0));
+        let mut hitch = record(1, 48.0);
+        hitch.events.push("asset_load".to_string());
+        history.push(hitch);
+        history.push(record(2, 17.0));
+
+        assert_eq!(history.series(&id).len(), 3);
+        let spikes = history.spikes(&id, 33.0);
+        assert_eq!(spikes.len(), 1);
+        assert_eq!(spikes[0].frame, 1);
+        assert_eq!(spikes[0].events, vec!["asset_load".to_string()]);
+
+        let trace = history.to_trace_events();
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(events.len(), 4);
+        assert!(events
+            .iter()
+            .any(|e| e["ph"] == "i" && e["args"]["frame"] == 1));
+    }
+}
@@ -18,13 +18,16 @@
 
 #![warn(missing_docs)]
 
+pub mod history;
 pub mod metrics;
 pub mod monitoring;
 pub mod service;
 pub mod storage;
 pub mod utils;
 
+pub use self::history::{FrameRecord, MetricHistory, DEFAULT_HISTORY_FRAMES};
 pub use self::service::TelemetryService;
 pub use crate::metrics::registry::MetricsRegistry;
+pub use crate::metrics::subscription::{MetricSample, MetricSubscription, MetricSubscriptions};
 pub use crate::monitoring::registry::MonitorRegistry;
 pub use crate::utils::*;
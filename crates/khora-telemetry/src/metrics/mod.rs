@@ -15,3 +15,4 @@
 //! Metrics storage and retrieval.
 
 pub mod registry;
+pub mod subscription;
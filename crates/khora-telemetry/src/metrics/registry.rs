@@ -16,6 +16,7 @@
 
 use crate::storage::{backend::MetricsBackend, memory_backend::InMemoryBackend};
 use khora_core::telemetry::metrics::{Metric, MetricId, MetricType, MetricsError, MetricsResult};
+use khora_core::telemetry::MetricPattern;
 use std::sync::Arc;
 
 /// Central registry for metrics in the KhoraEngine
@@ -149,6 +150,20 @@ impl MetricsRegistry {
         }
     }
 
+    /// Get all metrics whose hierarchical path matches `pattern`, sorted by
+    /// path. Tools use it to discover a group of metrics without knowing
+    /// their IDs.
+    pub fn find(&self, pattern: &MetricPattern) -> Vec<Metric> {
+        let mut metrics: Vec<Metric> = self
+            .backend
+            .list_all_metrics()
+            .into_iter()
+            .filter(|m| pattern.matches(&m.metadata.id))
+            .collect();
+        metrics.sort_by_cached_key(|m| m.metadata.id.to_string_formatted());
+        metrics
+    }
+
     /// Get all counters
     pub fn get_all_counters(&self) -> Vec<Metric> {
         if let Some(memory_backend) = self
@@ -327,6 +342,27 @@ impl HistogramHandle {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_find_by_pattern() {
+        let registry = MetricsRegistry::new();
+        for pass in ["shadow", "main"] {
+            registry
+                .register_gauge(format!("render/pass/{pass}"), "time_us", "Pass time", "us")
+                .unwrap();
+        }
+        registry
+            .register_counter("render/pass/main", "draw_calls", "Draw calls")
+            .unwrap();
+
+        let times = registry.find(&MetricPattern::new("render/pass/*/time_us"));
+        let paths: Vec<String> = times.iter().map(|m| m.metadata.id.path()).collect();
+        assert_eq!(
+            paths,
+            vec!["render/pass/main/time_us", "render/pass/shadow/time_us"]
+        );
+        assert_eq!(registry.find(&MetricPattern::new("render/**")).len(), 3);
+    }
+
     #[test]
     fn test_registry_creation() {
         let registry = MetricsRegistry::new();
@@ -0,0 +1,148 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wildcard subscriptions to groups of metrics.
+
+use crossbeam_channel::{Receiver, Sender, TryIter};
+use khora_core::telemetry::{MetricId, MetricPattern, MetricValue};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// One metric value delivered to a subscription.
+#[derive(Debug, Clone)]
+pub struct MetricSample {
+    /// The metric.
+    pub id: MetricId,
+    /// Its value when it was published.
+    pub value: MetricValue,
+}
+
+/// The set of live subscriptions, shared between the telemetry service,
+/// which publishes, and the tools that subscribe.
+#[derive(Debug, Clone, Default)]
+pub struct MetricSubscriptions {
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+}
+
+#[derive(Debug)]
+struct Subscriber {
+    pattern: MetricPattern,
+    sender: Sender<MetricSample>,
+}
+
+impl MetricSubscriptions {
+    /// Creates an empty set of subscriptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribes to every metric `pattern` matches. Samples arrive each
+    /// time the telemetry service publishes; dropping the subscription
+    /// ends it.
+    pub fn subscribe(&self, pattern: impl Into<MetricPattern>) -> MetricSubscription {
+        let pattern = pattern.into();
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(Subscriber {
+                pattern: pattern.clone(),
+                sender,
+            });
+        }
+        MetricSubscription { pattern, receiver }
+    }
+
+    /// Returns the number of live subscriptions.
+    pub fn len(&self) -> usize {
+        self.subscribers.lock().map(|s| s.len()).unwrap_or(0)
+    }
+
+    /// Returns `true` if nobody is subscribed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sends each sample to every subscription whose pattern matches it,
+    /// and forgets the subscriptions that were dropped.
+    pub fn publish(&self, samples: &[MetricSample]) {
+        let Ok(mut subscribers) = self.subscribers.lock() else {
+            return;
+        };
+        subscribers.retain(|subscriber| {
+            samples
+                .iter()
+                .filter(|sample| subscriber.pattern.matches(&sample.id))
+                .all(|sample| subscriber.sender.send(sample.clone()).is_ok())
+        });
+    }
+}
+
+/// The receiving end of a [`MetricSubscriptions::subscribe`] call.
+#[derive(Debug)]
+pub struct MetricSubscription {
+    pattern: MetricPattern,
+    receiver: Receiver<MetricSample>,
+}
+
+impl MetricSubscription {
+    /// Returns the pattern this subscription matches.
+    pub fn pattern(&self) -> &MetricPattern {
+        &self.pattern
+    }
+
+    /// Returns the samples received so far, without blocking.
+    pub fn try_iter(&self) -> TryIter<'_, MetricSample> {
+        self.receiver.try_iter()
+    }
+
+    /// Waits up to `timeout` for the next sample.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<MetricSample> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(path: &str, value: f64) -> MetricSample {
+        MetricSample {
+            id: MetricId::from_path(path),
+            value: MetricValue::Gauge(value),
+        }
+    }
+
+    #[test]
+    fn samples_reach_matching_subscriptions_only() {
+        let subscriptions = MetricSubscriptions::new();
+        let passes = subscriptions.subscribe("render/pass/*/time_us");
+        let memory = subscriptions.subscribe("memory/**");
+
+        subscriptions.publish(&[
+            sample("render/pass/shadow/time_us", 120.0),
+            sample("render/pass/main/time_us", 480.0),
+            sample("render/pass/main/draw_calls", 52.0),
+        ]);
+
+        let received: Vec<String> = passes.try_iter().map(|s| s.id.path()).collect();
+        assert_eq!(
+            received,
+            vec!["render/pass/shadow/time_us", "render/pass/main/time_us"]
+        );
+        assert_eq!(memory.try_iter().count(), 0);
+
+        drop(memory);
+        subscriptions.publish(&[sample("memory/heap", 1.0)]);
+        assert_eq!(subscriptions.len(), 1);
+    }
+}
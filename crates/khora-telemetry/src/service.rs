@@ -14,10 +14,14 @@
 
 //! Service for managing telemetry data and resource monitoring.
 
+use crate::history::{FrameRecord, MetricHistory};
 use crate::metrics::registry::MetricsRegistry;
+use crate::metrics::subscription::{MetricSample, MetricSubscription, MetricSubscriptions};
 use crate::monitoring::registry::MonitorRegistry;
 use crossbeam_channel::Sender;
+use khora_core::platform::HardwareSurvey;
 use khora_core::telemetry::event::TelemetryEvent;
+use khora_core::telemetry::MetricPattern;
 use std::time::{Duration, Instant};
 
 /// Central service for collecting and managing engine-wide telemetry.
@@ -33,6 +37,15 @@ pub struct TelemetryService {
     update_interval: Duration,
     /// Optional sender to forward events to the DCC.
     dcc_sender: Option<Sender<TelemetryEvent>>,
+    /// The hardware survey taken at startup, if any.
+    hardware_survey: Option<HardwareSurvey>,
+    /// Per-frame metric values, shared with observers.
+    history: MetricHistory,
+    /// Events marked since the last recorded frame.
+    pending_events: Vec<String>,
+    /// Wildcard subscriptions fed at each update.
+    subscriptions: MetricSubscriptions,
+    started: Instant,
 }
 
 impl TelemetryService {
@@ -44,9 +57,20 @@ impl TelemetryService {
             last_update: Instant::now(),
             update_interval,
             dcc_sender: None,
+            hardware_survey: None,
+            history: MetricHistory::default(),
+            pending_events: Vec::new(),
+            subscriptions: MetricSubscriptions::new(),
+            started: Instant::now(),
         }
     }
 
+    /// Sets how many frames the per-frame metric history keeps.
+    pub fn with_history_length(self, frames: usize) -> Self {
+        self.history.set_capacity(frames);
+        self
+    }
+
     /// Sets the sender for forwarding events to the DCC.
     pub fn with_dcc_sender(mut self, sender: Sender<TelemetryEvent>) -> Self {
         self.dcc_sender = Some(sender);
@@ -58,47 +82,147 @@ impl TelemetryService {
     /// Returns `true` if monitors were updated, `false` otherwise.
     pub fn tick(&mut self) -> bool {
         if self.last_update.elapsed() >= self.update_interval {
-            log::trace!("Updating all resource monitors...");
-            self.monitors.update_all();
-
-            // Forward monitor reports to DCC if sender is configured.
-            if let Some(sender) = &self.dcc_sender {
-                // 1. Forward monitor reports.
-                for monitor in self.monitors.get_all_monitors() {
-                    // Standard ResourceUsageReport (bytes)
-                    let report = monitor.get_usage_report();
-                    let _ = sender.send(TelemetryEvent::ResourceReport(report));
-
-                    // GPU Performance Report (timings)
-                    if let Some(gpu_report) = monitor.get_gpu_report() {
-                        let _ = sender.send(TelemetryEvent::GpuReport(gpu_report));
-                    }
-
-                    // Hardware Health Report (thermal, load)
-                    if let Some(hw_report) = monitor.get_hardware_report() {
-                        let _ = sender.send(TelemetryEvent::HardwareReport(hw_report));
-                    }
-
-                    // Discrete Metrics
-                    for (id, value) in monitor.get_metrics() {
-                        let _ = sender.send(TelemetryEvent::MetricUpdate { id, value });
-                    }
+            self.update();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Updates all monitors and forwards their reports now, regardless of
+    /// the update interval.
+    ///
+    /// Called during engine shutdown so the final values reach observers.
+    pub fn flush(&mut self) {
+        self.update();
+    }
+
+    fn update(&mut self) {
+        log::trace!("Updating all resource monitors...");
+        self.monitors.update_all();
+
+        // Forward monitor reports to DCC if sender is configured.
+        if let Some(sender) = &self.dcc_sender {
+            // 1. Forward monitor reports.
+            for monitor in self.monitors.get_all_monitors() {
+                // Standard ResourceUsageReport (bytes)
+                let report = monitor.get_usage_report();
+                let _ = sender.send(TelemetryEvent::ResourceReport(report));
+
+                // GPU Performance Report (timings)
+                if let Some(gpu_report) = monitor.get_gpu_report() {
+                    let _ = sender.send(TelemetryEvent::GpuReport(gpu_report));
+                }
+
+                // Hardware Health Report (thermal, load)
+                if let Some(hw_report) = monitor.get_hardware_report() {
+                    let _ = sender.send(TelemetryEvent::HardwareReport(hw_report));
                 }
 
-                // 2. Forward metric updates.
-                for metric in self.metrics.backend().list_all_metrics() {
-                    let _ = sender.send(TelemetryEvent::MetricUpdate {
-                        id: metric.metadata.id,
-                        value: metric.value,
-                    });
+                // Discrete Metrics
+                for (id, value) in monitor.get_metrics() {
+                    let _ = sender.send(TelemetryEvent::MetricUpdate { id, value });
                 }
             }
 
-            self.last_update = Instant::now();
-            true
-        } else {
-            false
+            // 2. Forward metric updates.
+            for metric in self.metrics.backend().list_all_metrics() {
+                let _ = sender.send(TelemetryEvent::MetricUpdate {
+                    id: metric.metadata.id,
+                    value: metric.value,
+                });
+            }
         }
+
+        // 3. Stream the values to wildcard subscribers.
+        if !self.subscriptions.is_empty() {
+            let mut samples: Vec<MetricSample> = self
+                .metrics
+                .backend()
+                .list_all_metrics()
+                .into_iter()
+                .map(|metric| MetricSample {
+                    id: metric.metadata.id,
+                    value: metric.value,
+                })
+                .collect();
+            for monitor in self.monitors.get_all_monitors() {
+                samples.extend(
+                    monitor
+                        .get_metrics()
+                        .into_iter()
+                        .map(|(id, value)| MetricSample { id, value }),
+                );
+            }
+            self.subscriptions.publish(&samples);
+        }
+
+        self.last_update = Instant::now();
+    }
+
+    /// Subscribes to every metric, from the registry or a monitor, whose
+    /// path matches `pattern` (e.g. `render/pass/*/time_us`). The values
+    /// arrive at each update.
+    pub fn subscribe(&self, pattern: impl Into<MetricPattern>) -> MetricSubscription {
+        self.subscriptions.subscribe(pattern)
+    }
+
+    /// Returns the shared subscription set, for tools that subscribe
+    /// without access to the service.
+    pub fn subscriptions(&self) -> &MetricSubscriptions {
+        &self.subscriptions
+    }
+
+    /// Keeps the startup hardware survey and forwards it to the DCC.
+    pub fn record_hardware_survey(&mut self, survey: HardwareSurvey) {
+        if let Some(sender) = &self.dcc_sender {
+            let _ = sender.send(TelemetryEvent::HardwareSurvey(Box::new(survey.clone())));
+        }
+        self.hardware_survey = Some(survey);
+    }
+
+    /// Attaches `event` to the frame being recorded next, so it shows up
+    /// next to that frame's metrics in the [`history`](Self::history).
+    pub fn mark_event(&mut self, event: impl Into<String>) {
+        self.pending_events.push(event.into());
+    }
+
+    /// Appends frame `frame` to the history: the current value of every
+    /// numeric metric in the registry and in the monitors, plus the events
+    /// marked since the previous frame. Called once per frame by the engine.
+    pub fn record_frame(&mut self, frame: u64) {
+        let mut metrics: Vec<_> = self
+            .metrics
+            .backend()
+            .list_all_metrics()
+            .into_iter()
+            .filter_map(|metric| Some((metric.metadata.id, metric.value.as_f64()?)))
+            .collect();
+        for monitor in self.monitors.get_all_monitors() {
+            metrics.extend(
+                monitor
+                    .get_metrics()
+                    .into_iter()
+                    .filter_map(|(id, value)| Some((id, value.as_f64()?))),
+            );
+        }
+        self.history.push(FrameRecord {
+            frame,
+            at: self.started.elapsed(),
+            metrics,
+            events: std::mem::take(&mut self.pending_events),
+        });
+    }
+
+    /// Returns the per-frame metric history. The handle is shared: it keeps
+    /// seeing new frames after this call.
+    pub fn history(&self) -> &MetricHistory {
+        &self.history
+    }
+
+    /// Returns the startup hardware survey, if one was recorded.
+    pub fn hardware_survey(&self) -> Option<&HardwareSurvey> {
+        self.hardware_survey.as_ref()
     }
 
     /// Returns a reference to the metrics registry.
@@ -0,0 +1,104 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scripted strategy costs.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How the true cost of a strategy evolves over arbitration rounds.
+#[non_exhaustive]
+#[derive(Clone)]
+pub enum CostCurve {
+    /// The same cost every round.
+    Constant(Duration),
+    /// `start` on round 0, growing by `per_round` every round after.
+    Linear {
+        /// Cost on round 0.
+        start: Duration,
+        /// Added every round.
+        per_round: Duration,
+    },
+    /// One cost per round; the last one holds afterwards.
+    Steps(Vec<Duration>),
+    /// Any function of the round index.
+    Custom(Arc<dyn Fn(u32) -> Duration + Send + Sync>),
+}
+
+impl CostCurve {
+    /// A constant cost of `ms` milliseconds, to the microsecond.
+    pub fn ms(ms: f32) -> Self {
+        CostCurve::Constant(Duration::from_micros((ms * 1000.0).round() as u64))
+    }
+
+    /// Returns the cost on `round`.
+    pub fn at(&self, round: u32) -> Duration {
+        match self {
+            CostCurve::Constant(cost) => *cost,
+            CostCurve::Linear { start, per_round } => *start + *per_round * round,
+            CostCurve::Steps(steps) => steps
+                .get(round as usize)
+                .or(steps.last())
+                .copied()
+                .unwrap_or_default(),
+            CostCurve::Custom(curve) => curve(round),
+        }
+    }
+}
+
+impl From<Duration> for CostCurve {
+    fn from(cost: Duration) -> Self {
+        CostCurve::Constant(cost)
+    }
+}
+
+impl fmt::Debug for CostCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CostCurve::Constant(cost) => f.debug_tuple("Constant").field(cost).finish(),
+            CostCurve::Linear { start, per_round } => f
+                .debug_struct("Linear")
+                .field("start", start)
+                .field("per_round", per_round)
+                .finish(),
+            CostCurve::Steps(steps) => f.debug_tuple("Steps").field(steps).finish(),
+            CostCurve::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curves_follow_the_round() {
+        let ms = Duration::from_millis;
+        assert_eq!(CostCurve::ms(4.0).at(7), ms(4));
+        let linear = CostCurve::Linear {
+            start: ms(2),
+            per_round: ms(3),
+        };
+        assert_eq!(linear.at(0), ms(2));
+        assert_eq!(linear.at(2), ms(8));
+        let steps = CostCurve::Steps(vec![ms(1), ms(5)]);
+        assert_eq!(steps.at(0), ms(1));
+        assert_eq!(steps.at(9), ms(5));
+        assert_eq!(
+            CostCurve::Custom(Arc::new(|r| Duration::from_millis(r as u64))).at(3),
+            ms(3)
+        );
+    }
+}
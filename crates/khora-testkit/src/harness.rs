@@ -0,0 +1,279 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Drives the DCC's analysis and arbitration round by round.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use khora_control::analysis::{AnalysisReport, HeuristicEngine};
+use khora_control::gorna::{ArbitrationOutcome, GornaArbitrator};
+use khora_control::mailbox::{self, AgentMailbox};
+use khora_control::metrics::MetricStore;
+use khora_control::Context;
+use khora_core::agent::Agent;
+use khora_core::control::gorna::{AgentId, StrategyId};
+use khora_core::telemetry::MetricId;
+use khora_core::threading::{self, ThreadRole};
+
+use crate::{AgentProbe, Scenario, ScenarioStep, SyntheticAgent};
+
+/// Default reply deadline of a harness's arbitrator.
+const DEFAULT_REPLY_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// What one round decided from, and what it decided.
+#[derive(Debug, Clone)]
+pub struct RoundReport {
+    /// Index of the round, from 0.
+    pub round: u32,
+    /// The context the round ran in.
+    pub context: Context,
+    /// The heuristics' report.
+    pub report: AnalysisReport,
+    /// The arbitrator's outcome.
+    pub outcome: ArbitrationOutcome,
+}
+
+impl RoundReport {
+    /// The frame budget the arbitrator fitted strategies into, in
+    /// milliseconds.
+    pub fn budget_ms(&self) -> f32 {
+        self.report.suggested_latency_ms * self.context.global_budget_multiplier
+    }
+
+    /// The strategy issued to `agent`, if it got a budget.
+    pub fn strategy(&self, agent: AgentId) -> Option<StrategyId> {
+        self.outcome
+            .budgets
+            .iter()
+            .find(|(id, _)| *id == agent)
+            .map(|(_, b)| b.strategy_id)
+    }
+
+    /// Total time allocated by the round's budgets.
+    pub fn allocated_time(&self) -> Duration {
+        self.outcome.budgets.iter().map(|(_, b)| b.time_limit).sum()
+    }
+
+    /// Total VRAM allocated by the round's budgets, in bytes.
+    pub fn allocated_vram(&self) -> u64 {
+        self.outcome
+            .budgets
+            .iter()
+            .filter_map(|(_, b)| b.memory_limit)
+            .sum()
+    }
+}
+
+/// Runs the DCC's heuristics and GORNA arbitrator against
+/// [`SyntheticAgent`]s, one round at a time.
+///
+/// Unlike the `DccService`, nothing runs on a timer: hardware state and
+/// telemetry only change when the test says so, and every call to
+/// [`round`](Self::round) analyzes and arbitrates exactly once — even when
+/// the heuristics see no reason to. Each agent answers its mailbox on its
+/// own thread, as it would on the main thread of a running engine.
+pub struct DccHarness {
+    arbitrator: GornaArbitrator,
+    heuristics: HeuristicEngine,
+    reply_timeout: Duration,
+    context: Context,
+    store: MetricStore,
+    clock: Arc<AtomicU32>,
+    agents: Vec<(AgentId, AgentProbe)>,
+    mailboxes: Vec<AgentMailbox>,
+    stop: Arc<AtomicBool>,
+    owners: Vec<JoinHandle<()>>,
+}
+
+impl DccHarness {
+    /// A harness with no agents, a default context and a 250ms reply
+    /// deadline.
+    pub fn new() -> Self {
+        Self {
+            arbitrator: GornaArbitrator::new(DEFAULT_REPLY_TIMEOUT),
+            heuristics: HeuristicEngine,
+            reply_timeout: DEFAULT_REPLY_TIMEOUT,
+            context: Context::default(),
+            store: MetricStore::new(),
+            clock: Arc::new(AtomicU32::new(0)),
+            agents: Vec::new(),
+            mailboxes: Vec::new(),
+            stop: Arc::new(AtomicBool::new(false)),
+            owners: Vec::new(),
+        }
+    }
+
+    /// Sets how long a round waits for agents to answer.
+    pub fn with_reply_timeout(mut self, timeout: Duration) -> Self {
+        self.arbitrator = GornaArbitrator::new(timeout);
+        self.reply_timeout = timeout;
+        self
+    }
+
+    /// Adds an agent, answering its mailbox on its own thread from now
+    /// on, and returns its probe.
+    pub fn add_agent(&mut self, mut agent: SyntheticAgent) -> AgentProbe {
+        let id = agent.id();
+        let probe = agent.probe();
+        agent.attach_clock(Arc::clone(&self.clock));
+        let (mailbox, inbox) = mailbox::channel(id);
+        let stop = Arc::clone(&self.stop);
+        let spawned = threading::spawn_named(
+            format!("khora-testkit-{:?}", id),
+            ThreadRole::Worker,
+            move || {
+                while !stop.load(Ordering::Relaxed) {
+                    inbox.service(&mut agent);
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+            },
+        );
+        match spawned {
+            Ok(handle) => self.owners.push(handle),
+            Err(e) => log::error!("DccHarness: cannot spawn {:?}'s thread: {}", id, e),
+        }
+        self.mailboxes.push(mailbox);
+        self.agents.push((id, probe.clone()));
+        probe
+    }
+
+    /// The context the next round runs in.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Mutable access to the context, for changes no [`ScenarioStep`]
+    /// covers.
+    pub fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+
+    /// Index of the next round.
+    pub fn next_round(&self) -> u32 {
+        self.clock.load(Ordering::Acquire)
+    }
+
+    /// Pushes one telemetry sample.
+    pub fn push_metric(&mut self, id: MetricId, value: f32) {
+        self.store.push(id, value);
+    }
+
+    /// Pushes `samples` frame times of `ms` milliseconds.
+    pub fn push_frame_times(&mut self, ms: f32, samples: usize) {
+        for _ in 0..samples {
+            self.push_metric(MetricId::new("renderer", "frame_time"), ms);
+        }
+    }
+
+    /// Pushes `frames` frame times, each the sum of what the agents'
+    /// current strategies really cost this round.
+    pub fn simulate_frames(&mut self, frames: usize) {
+        let round = self.next_round();
+        let frame: Duration = self
+            .agents
+            .iter()
+            .map(|(_, probe)| probe.actual_cost(round))
+            .sum();
+        self.push_frame_times(frame.as_secs_f32() * 1000.0, frames);
+    }
+
+    /// Analyzes the context and telemetry, arbitrates once, waits for the
+    /// budgets to reach the agents, and moves on to the next round.
+    pub fn round(&mut self) -> RoundReport {
+        let round = self.next_round();
+        self.context.refresh_budget_multiplier();
+        let report = self.heuristics.analyze(&self.context, &self.store);
+        let outcome = self
+            .arbitrator
+            .arbitrate(&self.context, &report, &self.mailboxes);
+        self.settle(round, &outcome);
+        self.clock.fetch_add(1, Ordering::AcqRel);
+
+        log::debug!(
+            "DccHarness: round {} issued {} budgets (emergency={}, unresponsive={:?})",
+            round,
+            outcome.budgets.len(),
+            outcome.emergency,
+            outcome.unresponsive
+        );
+        RoundReport {
+            round,
+            context: self.context.clone(),
+            report,
+            outcome,
+        }
+    }
+
+    /// Runs every step of `scenario` and returns a report per round.
+    pub fn run(&mut self, scenario: &Scenario) -> Vec<RoundReport> {
+        log::info!("DccHarness: running scenario '{}'", scenario.name());
+        let mut rounds = Vec::new();
+        for step in scenario.steps() {
+            match step {
+                ScenarioStep::Thermal(thermal) => self.context.hardware.thermal = *thermal,
+                ScenarioStep::Battery(battery) => self.context.hardware.battery = *battery,
+                ScenarioStep::Vram(vram) => self.context.hardware.available_vram = *vram,
+                ScenarioStep::Load { cpu, gpu } => {
+                    self.context.hardware.cpu_load = *cpu;
+                    self.context.hardware.gpu_load = *gpu;
+                }
+                ScenarioStep::Mode(mode) => self.context.mode = mode.clone(),
+                ScenarioStep::FrameTimes { ms, samples } => self.push_frame_times(*ms, *samples),
+                ScenarioStep::SimulateFrames(frames) => self.simulate_frames(*frames),
+                ScenarioStep::Round => rounds.push(self.round()),
+            }
+        }
+        rounds
+    }
+
+    /// Waits until every budget of `outcome` has reached its agent and no
+    /// agent is still inside a slowed-down negotiation, so the next round
+    /// starts from a quiet state.
+    fn settle(&self, round: u32, outcome: &ArbitrationOutcome) {
+        let deadline = Instant::now() + self.reply_timeout * 4;
+        let settled = || {
+            outcome.budgets.iter().all(|(id, _)| {
+                self.agents
+                    .iter()
+                    .filter(|(agent, _)| agent == id)
+                    .all(|(_, probe)| probe.has_budget_for(round))
+            }) && self.agents.iter().all(|(_, probe)| !probe.is_busy())
+        };
+        while !settled() {
+            if Instant::now() >= deadline {
+                log::warn!("DccHarness: round {} did not settle in time", round);
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
+
+impl Default for DccHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for DccHarness {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        for owner in self.owners.drain(..) {
+            let _ = owner.join();
+        }
+    }
+}
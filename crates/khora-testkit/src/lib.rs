@@ -0,0 +1,41 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Khora Testkit
+//!
+//! Deterministic test doubles for the control system. A [`SyntheticAgent`]
+//! negotiates scripted strategy costs and can be told to stall, answer
+//! late or misestimate its costs; a [`DccHarness`] drives the DCC's
+//! heuristics and GORNA arbitrator round by round through a [`Scenario`]
+//! — thermal throttling, a VRAM squeeze, a death spiral — so tests can
+//! assert on every arbitration outcome.
+//!
+//! ```rust,ignore
+//! let mut harness = DccHarness::new();
+//! harness.add_agent(SyntheticAgent::standard(AgentId::Renderer));
+//! let rounds = harness.run(&Scenario::death_spiral());
+//! assert!(rounds[1].outcome.emergency);
+//! ```
+
+#![warn(missing_docs)]
+
+mod cost_curve;
+mod harness;
+mod scenario;
+mod synthetic_agent;
+
+pub use cost_curve::CostCurve;
+pub use harness::{DccHarness, RoundReport};
+pub use scenario::{Scenario, ScenarioStep};
+pub use synthetic_agent::{AgentProbe, SyntheticAgent};
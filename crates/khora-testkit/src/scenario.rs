@@ -0,0 +1,125 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scripted sequences of hardware changes, telemetry and arbitration
+//! rounds.
+
+use khora_core::agent::EngineMode;
+use khora_core::platform::{BatteryLevel, ThermalStatus};
+
+/// One step of a [`Scenario`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScenarioStep {
+    /// Sets the thermal status.
+    Thermal(ThermalStatus),
+    /// Sets the battery level.
+    Battery(BatteryLevel),
+    /// Sets the available VRAM, in bytes; `None` lifts the limit.
+    Vram(Option<u64>),
+    /// Sets the CPU and GPU load, from 0.0 to 1.0.
+    Load {
+        /// CPU load.
+        cpu: f32,
+        /// GPU load.
+        gpu: f32,
+    },
+    /// Switches the engine mode.
+    Mode(EngineMode),
+    /// Pushes `samples` frame times of `ms` milliseconds.
+    FrameTimes {
+        /// Frame time, in milliseconds.
+        ms: f32,
+        /// Number of samples pushed.
+        samples: usize,
+    },
+    /// Pushes one frame time per frame, each the sum of what the agents'
+    /// current strategies really cost.
+    SimulateFrames(usize),
+    /// Runs one analysis and arbitration round.
+    Round,
+}
+
+/// A named sequence of [`ScenarioStep`]s, run by
+/// [`DccHarness::run`](crate::DccHarness::run).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scenario {
+    name: String,
+    steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+    /// An empty scenario.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Appends a step.
+    pub fn then(mut self, step: ScenarioStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Appends an arbitration round.
+    pub fn round(self) -> Self {
+        self.then(ScenarioStep::Round)
+    }
+
+    /// The scenario's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The scenario's steps, in order.
+    pub fn steps(&self) -> &[ScenarioStep] {
+        &self.steps
+    }
+
+    /// Four rounds: cool, throttling, critical, cool again.
+    pub fn thermal_throttle() -> Self {
+        Self::new("thermal throttle")
+            .round()
+            .then(ScenarioStep::Thermal(ThermalStatus::Throttling))
+            .round()
+            .then(ScenarioStep::Thermal(ThermalStatus::Critical))
+            .round()
+            .then(ScenarioStep::Thermal(ThermalStatus::Cool))
+            .round()
+    }
+
+    /// Two rounds: `from` bytes of VRAM available, then only `to`.
+    pub fn vram_squeeze(from: u64, to: u64) -> Self {
+        Self::new("VRAM squeeze")
+            .then(ScenarioStep::Vram(Some(from)))
+            .round()
+            .then(ScenarioStep::Vram(Some(to)))
+            .round()
+    }
+
+    /// Three rounds: healthy, then critical thermal with saturated CPU and
+    /// GPU — three simultaneous pressures — then recovered.
+    pub fn death_spiral() -> Self {
+        Self::new("death spiral")
+            .round()
+            .then(ScenarioStep::Thermal(ThermalStatus::Critical))
+            .then(ScenarioStep::Load { cpu: 1.0, gpu: 1.0 })
+            .round()
+            .then(ScenarioStep::Thermal(ThermalStatus::Cool))
+            .then(ScenarioStep::Load { cpu: 0.5, gpu: 0.5 })
+            .round()
+    }
+}
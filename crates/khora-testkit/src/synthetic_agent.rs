@@ -0,0 +1,278 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Agents with scripted costs and injected faults.
+
+use std::ops::Range;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use khora_core::agent::Agent;
+use khora_core::control::gorna::{
+    AgentId, AgentStatus, NegotiationRequest, NegotiationResponse, ResourceBudget, StrategyId,
+    StrategyOption,
+};
+use khora_core::EngineContext;
+
+use crate::CostCurve;
+
+/// One strategy a synthetic agent offers.
+#[derive(Debug, Clone)]
+struct SyntheticStrategy {
+    id: StrategyId,
+    cost: CostCurve,
+    vram: u64,
+}
+
+/// Shared state between a [`SyntheticAgent`] and its [`AgentProbe`].
+#[derive(Debug, Default)]
+struct ProbeState {
+    strategies: Vec<SyntheticStrategy>,
+    negotiations: u32,
+    budgets: Vec<(u32, ResourceBudget)>,
+    busy: bool,
+}
+
+/// A test's view of a [`SyntheticAgent`] after it has been handed over to
+/// a [`DccHarness`](crate::DccHarness).
+#[derive(Debug, Clone, Default)]
+pub struct AgentProbe {
+    state: Arc<Mutex<ProbeState>>,
+}
+
+impl AgentProbe {
+    fn lock(&self) -> MutexGuard<'_, ProbeState> {
+        // A panicking agent thread already fails the test; keep reading.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Number of negotiation requests the agent has answered.
+    pub fn negotiations(&self) -> u32 {
+        self.lock().negotiations
+    }
+
+    /// Every budget the agent received, with the round it arrived in.
+    pub fn budgets(&self) -> Vec<(u32, ResourceBudget)> {
+        self.lock().budgets.clone()
+    }
+
+    /// The strategy of the last budget received.
+    pub fn current_strategy(&self) -> Option<StrategyId> {
+        self.lock().budgets.last().map(|(_, b)| b.strategy_id)
+    }
+
+    /// What the current strategy really costs on `round`, whatever the
+    /// agent estimated. Strategies the agent does not offer (such as an
+    /// emergency LowPower) cost their budget's time limit. Zero before the
+    /// first budget.
+    pub fn actual_cost(&self, round: u32) -> Duration {
+        let state = self.lock();
+        let Some((_, budget)) = state.budgets.last() else {
+            return Duration::ZERO;
+        };
+        state
+            .strategies
+            .iter()
+            .find(|s| s.id == budget.strategy_id)
+            .map_or(budget.time_limit, |s| s.cost.at(round))
+    }
+
+    /// Returns `true` if the agent received a budget during `round`.
+    pub(crate) fn has_budget_for(&self, round: u32) -> bool {
+        self.lock().budgets.iter().any(|(r, _)| *r == round)
+    }
+
+    /// Returns `true` while the agent is inside a slowed-down negotiation.
+    pub(crate) fn is_busy(&self) -> bool {
+        self.lock().busy
+    }
+}
+
+/// An [`Agent`] whose strategies, costs and faults are scripted by a test.
+///
+/// Rounds are counted by the [`DccHarness`](crate::DccHarness) the agent
+/// is added to, starting at 0. Faults are given as round ranges.
+///
+/// ```rust,ignore
+/// let renderer = SyntheticAgent::standard(AgentId::Renderer)
+///     .estimating(0.5)            // claims half of what it really costs
+///     .stalled_during(3..5)       // reports itself stalled on rounds 3 and 4
+///     .slow_during(6..7, Duration::from_millis(300));
+/// ```
+#[derive(Debug)]
+pub struct SyntheticAgent {
+    id: AgentId,
+    estimate_bias: f32,
+    health: f32,
+    stalled: Vec<Range<u32>>,
+    slow: Vec<(Range<u32>, Duration)>,
+    clock: Arc<AtomicU32>,
+    probe: AgentProbe,
+}
+
+impl SyntheticAgent {
+    /// An agent offering no strategy at all; the arbitrator skips it until
+    /// strategies are added with [`with_strategy`](Self::with_strategy).
+    pub fn new(id: AgentId) -> Self {
+        Self {
+            id,
+            estimate_bias: 1.0,
+            health: 1.0,
+            stalled: Vec::new(),
+            slow: Vec::new(),
+            clock: Arc::new(AtomicU32::new(0)),
+            probe: AgentProbe::default(),
+        }
+    }
+
+    /// An agent offering LowPower (2ms, 16MB), Balanced (6ms, 128MB) and
+    /// HighPerformance (12ms, 512MB).
+    pub fn standard(id: AgentId) -> Self {
+        const MB: u64 = 1024 * 1024;
+        Self::new(id)
+            .with_strategy(StrategyId::LowPower, CostCurve::ms(2.0), 16 * MB)
+            .with_strategy(StrategyId::Balanced, CostCurve::ms(6.0), 128 * MB)
+            .with_strategy(StrategyId::HighPerformance, CostCurve::ms(12.0), 512 * MB)
+    }
+
+    /// Adds a strategy, or replaces the cost of one already offered.
+    pub fn with_strategy(self, id: StrategyId, cost: impl Into<CostCurve>, vram: u64) -> Self {
+        {
+            let mut state = self.probe.lock();
+            let strategy = SyntheticStrategy {
+                id,
+                cost: cost.into(),
+                vram,
+            };
+            match state.strategies.iter_mut().find(|s| s.id == id) {
+                Some(existing) => *existing = strategy,
+                None => state.strategies.push(strategy),
+            }
+        }
+        self
+    }
+
+    /// Reports `bias` × its true costs when negotiating: below 1.0 the
+    /// agent underestimates, above 1.0 it overestimates.
+    pub fn estimating(mut self, bias: f32) -> Self {
+        self.estimate_bias = bias;
+        self
+    }
+
+    /// Health score reported with every status.
+    pub fn with_health(mut self, health: f32) -> Self {
+        self.health = health;
+        self
+    }
+
+    /// Reports itself stalled during `rounds`.
+    pub fn stalled_during(mut self, rounds: Range<u32>) -> Self {
+        self.stalled.push(rounds);
+        self
+    }
+
+    /// Takes `delay` to answer negotiations during `rounds` — past the
+    /// reply deadline, the arbitrator lists it as unresponsive.
+    pub fn slow_during(mut self, rounds: Range<u32>, delay: Duration) -> Self {
+        self.slow.push((rounds, delay));
+        self
+    }
+
+    /// Returns the probe that keeps observing the agent once it is handed
+    /// over.
+    pub fn probe(&self) -> AgentProbe {
+        self.probe.clone()
+    }
+
+    /// Makes the agent count rounds with `clock`.
+    pub(crate) fn attach_clock(&mut self, clock: Arc<AtomicU32>) {
+        self.clock = clock;
+    }
+
+    fn round(&self) -> u32 {
+        self.clock.load(Ordering::Acquire)
+    }
+}
+
+impl Agent for SyntheticAgent {
+    fn id(&self) -> AgentId {
+        self.id
+    }
+
+    fn negotiate(&mut self, _request: NegotiationRequest) -> NegotiationResponse {
+        let round = self.round();
+        if let Some((_, delay)) = self.slow.iter().find(|(r, _)| r.contains(&round)) {
+            self.probe.lock().busy = true;
+            std::thread::sleep(*delay);
+            self.probe.lock().busy = false;
+        }
+
+        let mut state = self.probe.lock();
+        state.negotiations += 1;
+        let strategies = state
+            .strategies
+            .iter()
+            .map(|s| StrategyOption {
+                id: s.id,
+                estimated_time: s.cost.at(round).mul_f32(self.estimate_bias.max(0.0)),
+                estimated_vram: s.vram,
+            })
+            .collect();
+        NegotiationResponse {
+            strategies,
+            timing_adjustment: None,
+        }
+    }
+
+    fn apply_budget(&mut self, budget: ResourceBudget) {
+        let round = self.round();
+        log::debug!(
+            "SyntheticAgent {:?}: round {} budget {:?}",
+            self.id,
+            round,
+            budget.strategy_id
+        );
+        self.probe.lock().budgets.push((round, budget));
+    }
+
+    fn report_status(&self) -> AgentStatus {
+        let round = self.round();
+        let is_stalled = self.stalled.iter().any(|r| r.contains(&round));
+        AgentStatus {
+            agent_id: self.id,
+            current_strategy: self
+                .probe
+                .current_strategy()
+                .unwrap_or(StrategyId::Balanced),
+            health_score: if is_stalled { 0.0 } else { self.health },
+            is_stalled,
+            message: if is_stalled {
+                format!("synthetic stall on round {}", round)
+            } else {
+                String::new()
+            },
+        }
+    }
+
+    fn execute(&mut self, _context: &mut EngineContext<'_>) {}
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
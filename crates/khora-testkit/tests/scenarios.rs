@@ -0,0 +1,143 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! End-to-end DCC scenarios driven through the testkit harness.
+
+use std::time::Duration;
+
+use khora_core::control::gorna::{AgentId, StrategyId};
+use khora_testkit::{DccHarness, Scenario, ScenarioStep, SyntheticAgent};
+
+const MB: u64 = 1024 * 1024;
+
+fn fits(round: &khora_testkit::RoundReport) -> bool {
+    round.allocated_time().as_secs_f32() * 1000.0 <= round.budget_ms() + 0.01
+}
+
+#[test]
+fn thermal_throttle_keeps_allocations_within_the_shrunk_budget() {
+    let mut harness = DccHarness::new();
+    for id in [AgentId::Renderer, AgentId::Physics, AgentId::Audio] {
+        harness.add_agent(SyntheticAgent::standard(id));
+    }
+
+    let rounds = harness.run(&Scenario::thermal_throttle());
+
+    assert_eq!(rounds.len(), 4);
+    assert!(rounds.iter().all(fits));
+    assert!(!rounds[0].report.needs_negotiation);
+    assert!(rounds[1].report.needs_negotiation);
+    assert!(rounds[2].report.needs_negotiation);
+    assert!(rounds.iter().all(|r| !r.outcome.emergency));
+    assert_eq!(
+        rounds[3].strategy(AgentId::Renderer),
+        rounds[0].strategy(AgentId::Renderer)
+    );
+}
+
+#[test]
+fn vram_squeeze_downgrades_to_a_strategy_that_fits() {
+    let mut harness = DccHarness::new();
+    let renderer = harness.add_agent(SyntheticAgent::standard(AgentId::Renderer));
+
+    let rounds = harness.run(&Scenario::vram_squeeze(1024 * MB, 200 * MB));
+
+    assert_eq!(
+        rounds[0].strategy(AgentId::Renderer),
+        Some(StrategyId::HighPerformance)
+    );
+    assert_eq!(
+        rounds[1].strategy(AgentId::Renderer),
+        Some(StrategyId::Balanced)
+    );
+    assert!(rounds[1].allocated_vram() <= 200 * MB);
+    assert_eq!(renderer.current_strategy(), Some(StrategyId::Balanced));
+}
+
+#[test]
+fn death_spiral_forces_an_emergency_then_recovers() {
+    let mut harness = DccHarness::new();
+    let renderer = harness.add_agent(SyntheticAgent::standard(AgentId::Renderer));
+    harness.add_agent(SyntheticAgent::standard(AgentId::Physics));
+
+    let rounds = harness.run(&Scenario::death_spiral());
+
+    assert!(!rounds[0].outcome.emergency);
+    assert!(rounds[1].report.death_spiral_detected);
+    assert!(rounds[1].outcome.emergency);
+    assert!(rounds[1]
+        .outcome
+        .budgets
+        .iter()
+        .all(|(_, b)| b.strategy_id == StrategyId::LowPower));
+    assert!(!rounds[2].outcome.emergency);
+    assert_eq!(renderer.budgets().len(), 3);
+}
+
+#[test]
+fn two_stalled_agents_trigger_an_emergency() {
+    let mut harness = DccHarness::new();
+    harness.add_agent(SyntheticAgent::standard(AgentId::Renderer).stalled_during(1..2));
+    harness.add_agent(SyntheticAgent::standard(AgentId::Physics).stalled_during(1..2));
+    harness.add_agent(SyntheticAgent::standard(AgentId::Audio));
+
+    let rounds = harness.run(&Scenario::new("stall").round().round().round());
+
+    assert!(!rounds[0].outcome.emergency);
+    assert!(!rounds[1].report.death_spiral_detected);
+    assert!(rounds[1].outcome.emergency);
+    assert!(!rounds[2].outcome.emergency);
+}
+
+#[test]
+fn underestimating_agents_show_up_in_frame_time_alerts() {
+    let mut harness = DccHarness::new();
+    for id in [AgentId::Renderer, AgentId::Physics, AgentId::Ui] {
+        harness.add_agent(SyntheticAgent::standard(id).estimating(0.5));
+    }
+
+    let scenario = Scenario::new("underestimate")
+        .round()
+        .then(ScenarioStep::SimulateFrames(10))
+        .round();
+    let rounds = harness.run(&scenario);
+
+    // The agents claimed their choices fit the budget...
+    assert!(fits(&rounds[0]));
+    assert!(rounds[0].report.alerts.is_empty());
+    // ...but the frames they really produced did not.
+    assert!(rounds[1]
+        .report
+        .alerts
+        .iter()
+        .any(|a| a.starts_with("FrameTime: CRITICAL")));
+}
+
+#[test]
+fn a_slow_agent_is_unresponsive_only_while_slow() {
+    let mut harness = DccHarness::new().with_reply_timeout(Duration::from_millis(50));
+    let renderer = harness.add_agent(
+        SyntheticAgent::standard(AgentId::Renderer).slow_during(1..2, Duration::from_millis(120)),
+    );
+    harness.add_agent(SyntheticAgent::standard(AgentId::Physics));
+
+    let rounds = harness.run(&Scenario::new("slow").round().round().round());
+
+    assert!(rounds[0].outcome.unresponsive.is_empty());
+    assert_eq!(rounds[1].outcome.unresponsive, vec![AgentId::Renderer]);
+    assert_eq!(rounds[1].strategy(AgentId::Renderer), None);
+    assert!(rounds[1].strategy(AgentId::Physics).is_some());
+    assert!(rounds[2].outcome.unresponsive.is_empty());
+    assert_eq!(renderer.negotiations(), 3);
+}
@@ -30,7 +30,7 @@ use khora_sdk::run_winit;
 use khora_sdk::winit_adapters::WinitWindowProvider;
 use khora_sdk::{
     AgentProvider, DccService, EngineApp, GameWorld, InputEvent, PhaseProvider, RenderSystem,
-    ServiceRegistry, WgpuRenderSystem, WindowConfig,
+    ServiceRegistry, WatchdogConfig, WgpuRenderSystem, WindowConfig,
 };
 use std::sync::{Arc, Mutex};
 
@@ -165,6 +165,10 @@ impl EngineApp for SandboxGame {
         }
     }
 
+    fn watchdog_config() -> Option<WatchdogConfig> {
+        Some(WatchdogConfig::default())
+    }
+
     fn new() -> Self {
         log::info!("SandboxGame: Initializing...");
         Self {
@@ -184,6 +188,7 @@ impl EngineApp for SandboxGame {
         self.player = Some(
             khora_sdk::Vessel::at(world, Vec3::new(0.0, 2.0, 10.0))
                 .with_component(camera)
+                .with_component(khora_sdk::prelude::ecs::Exposure::SUNNY)
                 .with_rotation(Quaternion::from_axis_angle(Vec3::Y, std::f32::consts::PI))
                 .build(),
         );
@@ -193,7 +198,7 @@ impl EngineApp for SandboxGame {
         let sun_rotation = Quaternion::from_axis_angle(Vec3::X, -std::f32::consts::FRAC_PI_2 * 0.8);
         let mut sun_light = khora_sdk::prelude::ecs::Light::directional();
         if let khora_sdk::prelude::ecs::LightType::Directional(ref mut d) = sun_light.light_type {
-            d.intensity = 2.5;
+            d.intensity = khora_sdk::prelude::math::photometry::LUX_DIRECT_SUNLIGHT;
             d.shadow_enabled = true;
             d.shadow_bias = 0.005;
             d.shadow_normal_bias = 0.02;
@@ -221,7 +226,8 @@ impl EngineApp for SandboxGame {
 
         let mut point_light = khora_sdk::prelude::ecs::Light::point();
         if let khora_sdk::prelude::ecs::LightType::Point(ref mut p) = point_light.light_type {
-            p.intensity = 500.0;
+            // A floodlight, to stand out under direct sunlight.
+            p.intensity = 2_000_000.0;
             p.color = khora_sdk::prelude::math::LinearRgba::new(0.8, 0.9, 1.0, 1.0);
             p.range = 15.0;
         }
@@ -294,11 +300,33 @@ impl PhaseProvider for SandboxGame {
 fn main() -> Result<()> {
     use env_logger::{Builder, Env};
 
-    Builder::from_env(Env::default().default_filter_or("info"))
+    let logger = Builder::from_env(Env::default().default_filter_or("info"))
         // Suppress Epic Games / EOS overlay Vulkan loader JSON-not-found noise.
         // These are harmless OS-level loader warnings, not engine errors.
         .filter_module("wgpu_hal::vulkan::instance", log::LevelFilter::Off)
-        .init();
+        // Tag every line with the emitting thread's registered name.
+        .format(|buf, record| {
+            use std::io::Write;
+            writeln!(
+                buf,
+                "[{} {:<5} {} ({})] {}",
+                buf.timestamp(),
+                record.level(),
+                record.target(),
+                khora_sdk::threading::current_thread_name(),
+                record.args()
+            )
+        })
+        .build();
+    // One directory per run for the log file, hardware report and crash reports.
+    let run = khora_sdk::RunLogDir::create("logs", 10)?.install();
+    let file = khora_sdk::RotatingFileLog::open(run.log_path(), khora_sdk::LogRotation::default())?;
+    let level = logger.filter();
+    // The last lines are also kept for the watchdog's stall reports.
+    khora_sdk::EngineLogger::new()
+        .with_sink("console", level, Box::new(logger))
+        .with_sink("file", log::LevelFilter::Info, Box::new(file))
+        .install(khora_sdk::DEFAULT_LOG_TAIL_LINES)?;
 
     run_winit::<WinitWindowProvider, SandboxGame>(|window, services, _event_loop| {
         let mut rs = WgpuRenderSystem::new();
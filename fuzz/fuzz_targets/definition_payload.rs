@@ -0,0 +1,27 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Feeds raw bytes straight into the definition (RON) deserialization lane,
+//! bypassing the container header.
+
+#![no_main]
+
+use khora_data::ecs::World;
+use khora_io::serialization::{DefinitionSerializationStrategy, SerializationStrategy};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut world = World::new();
+    let _ = DefinitionSerializationStrategy::new().deserialize(data, &mut world);
+});
@@ -0,0 +1,55 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builds a structured header (so the magic bytes and strategy id are
+//! reached far more often than with raw bytes) around an arbitrary payload.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use khora_core::scene::{SceneFile, SceneHeader, HEADER_MAGIC_BYTES};
+use khora_data::ecs::World;
+use khora_io::serialization::SerializationService;
+use libfuzzer_sys::fuzz_target;
+
+const STRATEGY_IDS: [&str; 3] = ["KH_DEFINITION_RON_V1", "KH_RECIPE_V1", "KH_ARCHETYPE_V1"];
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    format_version: u8,
+    strategy: u8,
+    payload_length: u64,
+    payload: Vec<u8>,
+}
+
+fuzz_target!(|input: Input| {
+    let name = STRATEGY_IDS[input.strategy as usize % STRATEGY_IDS.len()];
+    let mut strategy_id = [0u8; 32];
+    strategy_id[..name.len()].copy_from_slice(name.as_bytes());
+
+    let header = SceneHeader {
+        magic_bytes: HEADER_MAGIC_BYTES,
+        format_version: input.format_version,
+        strategy_id,
+        payload_length: input.payload_length,
+    };
+
+    let mut bytes = header.to_bytes().to_vec();
+    bytes.extend_from_slice(&input.payload);
+
+    if let Ok(file) = SceneFile::from_bytes(&bytes) {
+        let mut world = World::new();
+        let _ = SerializationService::new().load_world(&file, &mut world);
+    }
+});
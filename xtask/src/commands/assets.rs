@@ -14,131 +14,353 @@
 
 use crate::commands::assets_config::AssetManifest;
 use crate::helpers::*;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use bincode;
-use khora_core::asset::{AssetMetadata, AssetSource, AssetUUID};
-use std::collections::HashMap;
+use khora_core::asset::{
+    ArchiveInfo, AssetMetadata, AssetPlatform, AssetSource, AssetUUID, PlatformTarget,
+    DEFAULT_THUMBNAIL_SIZE, DEFAULT_VARIANT,
+};
+use khora_io::asset::AssetDelta;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-pub fn pack() -> Result<()> {
-    print_task_start("Packing Assets", ROCKET, MAGENTA);
+/// Separates a platform variant from its base asset in a file name.
+const VARIANT_SEPARATOR: char = '@';
 
-    let manifest = load_manifest()?;
-    let dest_dir = PathBuf::from(".dist/assets");
-    fs::create_dir_all(&dest_dir)?;
+/// A source asset and the bytes of each of its variants.
+struct SourceAsset {
+    path: PathBuf,
+    variants: Vec<(String, Vec<u8>)>,
+}
 
-    let valid_source_dirs: Vec<PathBuf> = manifest
-        .source_directories
-        .into_iter()
-        .filter(|dir| dir.exists())
-        .collect();
+impl SourceAsset {
+    fn uuid(&self) -> Result<AssetUUID> {
+        let path_str = self.path.to_str().context("Invalid path encoding")?;
+        Ok(AssetUUID::new_v5(path_str))
+    }
 
-    if valid_source_dirs.is_empty() {
-        print_error("No valid source directories found. Nothing to pack.");
-        return Ok(());
+    /// Builds the index entry for this asset with the given variant sources.
+    fn metadata(&self, variants: HashMap<String, AssetSource>) -> Result<AssetMetadata> {
+        let asset_type_name = self
+            .path
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("")
+            .to_string();
+        Ok(AssetMetadata {
+            uuid: self.uuid()?,
+            source_path: self.path.clone(),
+            asset_type_name,
+            dependencies: Vec::new(),
+            variants,
+            tags: Vec::new(),
+        })
     }
+}
 
-    let asset_files = find_asset_files(&valid_source_dirs)?;
-    if asset_files.is_empty() {
-        print_success("No asset files found to pack.");
-        return Ok(());
+/// Appends blobs to a `data.pack` and collects the matching index entries.
+struct ArchiveWriter {
+    data_path: PathBuf,
+    data_file: File,
+    offset: u64,
+    metadata: Vec<AssetMetadata>,
+}
+
+impl ArchiveWriter {
+    fn create(dest_dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dest_dir)?;
+        let data_path = dest_dir.join("data.pack");
+        let data_file = File::create(&data_path)
+            .with_context(|| format!("Failed to create data pack at '{}'", data_path.display()))?;
+        Ok(Self {
+            data_path,
+            data_file,
+            offset: 0,
+            metadata: Vec::new(),
+        })
     }
 
-    println!(
-        "{}🔎 Found:{} {} potential asset files to process.",
-        BOLD,
-        RESET,
-        asset_files.len()
-    );
+    /// Writes a blob and returns its `(offset, size)` in the packfile.
+    fn write(&mut self, bytes: &[u8]) -> Result<(u64, u64)> {
+        self.data_file.write_all(bytes)?;
+        let at = self.offset;
+        self.offset += bytes.len() as u64;
+        Ok((at, bytes.len() as u64))
+    }
 
-    // This single function now handles the core logic.
-    build_packfiles(&asset_files, &dest_dir)?;
+    /// Writes `index.bin` and `archive.bin` next to the packfile.
+    fn finish(self, info: ArchiveInfo) -> Result<()> {
+        let dest_dir = self.data_path.parent().unwrap_or(Path::new("."));
+        let index_path = dest_dir.join("index.bin");
+        let archive_path = dest_dir.join("archive.bin");
 
-    print_success("Asset pipeline finished successfully.");
-    Ok(())
+        println!("{}💾 Writing index file...", BOLD);
+        let config = bincode::config::standard();
+        let encoded_index = bincode::serde::encode_to_vec(&self.metadata, config)
+            .context("Failed to serialize final metadata")?;
+        fs::write(&index_path, &encoded_index)
+            .with_context(|| format!("Failed to write index file to '{}'", index_path.display()))?;
+
+        let encoded_info = bincode::serde::encode_to_vec(info, config)
+            .context("Failed to serialize archive info")?;
+        fs::write(&archive_path, &encoded_info).with_context(|| {
+            format!(
+                "Failed to write archive info to '{}'",
+                archive_path.display()
+            )
+        })?;
+
+        println!(
+            "{}{} {} Wrote {} metadata entries to '{}' ({:.2} KB)",
+            BOLD,
+            GREEN,
+            CHECK,
+            self.metadata.len(),
+            index_path.display(),
+            encoded_index.len() as f64 / 1024.0
+        );
+        println!(
+            "{}{} {} Wrote asset data to '{}' ({:.2} MB)",
+            BOLD,
+            GREEN,
+            CHECK,
+            self.data_path.display(),
+            self.offset as f64 / (1024.0 * 1024.0)
+        );
+        Ok(())
+    }
 }
 
-/// Builds the `data.pack` and `index.bin` files from the list of source assets.
-fn build_packfiles(asset_files: &[PathBuf], dest_dir: &Path) -> Result<()> {
-    let index_path = dest_dir.join("index.bin");
-    let data_path = dest_dir.join("data.pack");
+pub fn pack(platform: Option<AssetPlatform>, version: u32, thumbnails: bool) -> Result<()> {
+    print_task_start("Packing Assets", ROCKET, MAGENTA);
 
-    let mut data_file = File::create(&data_path)
-        .with_context(|| format!("Failed to create data pack at '{}'", data_path.display()))?;
+    // A filtered pack goes into its own directory so it never overwrites
+    // the full one.
+    let dest_dir = match platform {
+        Some(platform) => PathBuf::from(".dist/assets").join(platform.as_str()),
+        None => PathBuf::from(".dist/assets"),
+    };
 
-    let mut final_metadata = Vec::new();
-    let mut current_offset = 0;
+    let Some(assets) = gather_assets(platform)? else {
+        return Ok(());
+    };
 
-    println!("{}📦 Packing asset data...", BOLD);
+    println!("{}📦 Packing asset data (version {})...", BOLD, version);
+    let mut writer = ArchiveWriter::create(&dest_dir)?;
+    for asset in &assets {
+        let mut variants = HashMap::new();
+        for (key, bytes) in &asset.variants {
+            let (offset, size) = writer.write(bytes)?;
+            variants.insert(key.clone(), AssetSource::Packed { offset, size });
+        }
+        writer.metadata.push(asset.metadata(variants)?);
+    }
+    writer.finish(ArchiveInfo::full(version))?;
 
-    for asset_path in asset_files {
-        let asset_bytes = fs::read(asset_path)
-            .with_context(|| format!("Failed to read asset file '{}'", asset_path.display()))?;
-        let size = asset_bytes.len() as u64;
+    if thumbnails {
+        super::thumbnails::run(&dest_dir, DEFAULT_THUMBNAIL_SIZE)?;
+    }
 
-        // Write data to the packfile
-        data_file.write_all(&asset_bytes)?;
+    print_success("Asset pipeline finished successfully.");
+    Ok(())
+}
 
-        // --- Generate Metadata ---
-        let path_str = asset_path.to_str().context("Invalid path encoding")?;
-        let uuid = AssetUUID::new_v5(path_str);
-        let asset_type_name = asset_path
-            .extension()
-            .and_then(|s| s.to_str())
-            .unwrap_or("")
-            .to_string();
+/// Builds a patch archive holding what changed since the full archive in
+/// `base_dir`.
+///
+/// Changed variants are stored as binary deltas when that is smaller than
+/// the new bytes. Once one variant of an asset changes, its other variants
+/// are listed too, as copy-only deltas, because a patch entry replaces the
+/// whole index entry. Removed assets get an entry with no variants.
+pub fn patch(base_dir: &Path, platform: Option<AssetPlatform>, version: Option<u32>) -> Result<()> {
+    print_task_start("Building Asset Patch", ROCKET, MAGENTA);
 
-        let mut variants = HashMap::new();
-        variants.insert(
-            "default".to_string(),
-            AssetSource::Packed {
-                offset: current_offset,
-                size,
-            },
+    let base_info = read_archive_info(base_dir)?;
+    if base_info.is_patch() {
+        bail!(
+            "'{}' is a patch archive; patches are built against a full archive",
+            base_dir.display()
+        );
+    }
+    let version = version.unwrap_or(base_info.version + 1);
+    if version <= base_info.version {
+        bail!(
+            "Patch version {} must be greater than the base version {}",
+            version,
+            base_info.version
         );
+    }
 
-        final_metadata.push(AssetMetadata {
-            uuid,
-            source_path: asset_path.clone(),
-            asset_type_name,
-            dependencies: Vec::new(),
-            variants,
-            tags: Vec::new(),
-        });
+    let index_bytes = fs::read(base_dir.join("index.bin"))
+        .with_context(|| format!("Failed to read base index in '{}'", base_dir.display()))?;
+    let (base_index, _): (Vec<AssetMetadata>, _) =
+        bincode::serde::decode_from_slice(&index_bytes, bincode::config::standard())
+            .context("Failed to decode base index")?;
+    let base_data = fs::read(base_dir.join("data.pack"))
+        .with_context(|| format!("Failed to read base data in '{}'", base_dir.display()))?;
+    let mut base_index: HashMap<AssetUUID, AssetMetadata> =
+        base_index.into_iter().map(|m| (m.uuid, m)).collect();
+
+    let Some(assets) = gather_assets(platform)? else {
+        return Ok(());
+    };
 
-        current_offset += size;
+    let mut dest_dir = PathBuf::from(".dist/patches");
+    if let Some(platform) = platform {
+        dest_dir.push(platform.as_str());
     }
+    dest_dir.push(format!("{}-{}", base_info.version, version));
 
-    println!("{}💾 Writing index file...", BOLD);
-    let config = bincode::config::standard();
-    let encoded_index = bincode::serde::encode_to_vec(&final_metadata, config)
-        .context("Failed to serialize final metadata")?;
+    println!(
+        "{}📦 Diffing against base version {}...",
+        BOLD, base_info.version
+    );
+    let mut writer = ArchiveWriter::create(&dest_dir)?;
+    let (mut added, mut changed) = (0, 0);
+    for asset in &assets {
+        let base = base_index.remove(&asset.uuid()?);
+        let base_bytes = |key: &str| -> Option<&[u8]> {
+            match base.as_ref()?.variants.get(key)? {
+                AssetSource::Packed { offset, size } => {
+                    base_data.get(*offset as usize..(*offset + *size) as usize)
+                }
+                _ => None,
+            }
+        };
+
+        let unchanged = base.as_ref().is_some_and(|b| {
+            b.variants.len() == asset.variants.len()
+                && asset
+                    .variants
+                    .iter()
+                    .all(|(key, bytes)| base_bytes(key) == Some(bytes.as_slice()))
+        });
+        if unchanged {
+            continue;
+        }
+        if base.is_some() {
+            changed += 1;
+        } else {
+            added += 1;
+        }
 
-    fs::write(&index_path, &encoded_index)
-        .with_context(|| format!("Failed to write index file to '{}'", index_path.display()))?;
+        let mut variants = HashMap::new();
+        for (key, bytes) in &asset.variants {
+            let delta = match base_bytes(key) {
+                Some(old) => Some(AssetDelta::diff(old, bytes).encode()?),
+                None => None,
+            };
+            let source = match delta {
+                Some(delta) if delta.len() < bytes.len() => {
+                    let (offset, size) = writer.write(&delta)?;
+                    AssetSource::Delta { offset, size }
+                }
+                _ => {
+                    let (offset, size) = writer.write(bytes)?;
+                    AssetSource::Packed { offset, size }
+                }
+            };
+            variants.insert(key.clone(), source);
+        }
+        writer.metadata.push(asset.metadata(variants)?);
+    }
+
+    // Whatever is left in the base index no longer exists.
+    let removed = base_index.len();
+    for (_, mut metadata) in base_index {
+        metadata.variants.clear();
+        writer.metadata.push(metadata);
+    }
 
     println!(
-        "{}{} {} Wrote {} metadata entries to '{}' ({:.2} KB)",
-        BOLD,
-        GREEN,
-        CHECK,
-        final_metadata.len(),
-        index_path.display(),
-        encoded_index.len() as f64 / 1024.0
+        "{}🔎 Patch:{} {} added, {} changed, {} removed.",
+        BOLD, RESET, added, changed, removed
     );
+    writer.finish(ArchiveInfo::patch(base_info.version, version))?;
+
+    print_success("Asset patch built successfully.");
+    Ok(())
+}
+
+/// Reads `archive.bin`, treating an archive without one as version 0.
+fn read_archive_info(dir: &Path) -> Result<ArchiveInfo> {
+    let path = dir.join("archive.bin");
+    if !path.exists() {
+        return Ok(ArchiveInfo::full(0));
+    }
+    let bytes = fs::read(&path)
+        .with_context(|| format!("Failed to read archive info at '{}'", path.display()))?;
+    let (info, _) = bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
+        .with_context(|| format!("Failed to decode archive info at '{}'", path.display()))?;
+    Ok(info)
+}
+
+/// Scans the manifest's source directories and groups files into assets.
+///
+/// Returns `None` when there is nothing to pack. With a `platform`,
+/// variants tagged for other platforms are left out.
+fn gather_assets(platform: Option<AssetPlatform>) -> Result<Option<Vec<SourceAsset>>> {
+    let manifest = load_manifest()?;
+    let valid_source_dirs: Vec<PathBuf> = manifest
+        .source_directories
+        .into_iter()
+        .filter(|dir| dir.exists())
+        .collect();
+
+    if valid_source_dirs.is_empty() {
+        print_error("No valid source directories found. Nothing to pack.");
+        return Ok(None);
+    }
+
+    let asset_files = find_asset_files(&valid_source_dirs)?;
+    if asset_files.is_empty() {
+        print_success("No asset files found to pack.");
+        return Ok(None);
+    }
+
     println!(
-        "{}{} {} Wrote asset data to '{}' ({:.2} MB)",
+        "{}🔎 Found:{} {} potential asset files to process.",
         BOLD,
-        GREEN,
-        CHECK,
-        data_path.display(),
-        current_offset as f64 / (1024.0 * 1024.0)
+        RESET,
+        asset_files.len()
     );
 
-    Ok(())
+    let target = platform.map(PlatformTarget::new);
+    let mut grouped: BTreeMap<PathBuf, Vec<(String, Vec<u8>)>> = BTreeMap::new();
+    for file in &asset_files {
+        let (base, key) = split_variant(file);
+        if target.as_ref().is_some_and(|t| !t.accepts(&key)) {
+            continue;
+        }
+        let bytes = fs::read(file)
+            .with_context(|| format!("Failed to read asset file '{}'", file.display()))?;
+        grouped.entry(base).or_default().push((key, bytes));
+    }
+
+    Ok(Some(
+        grouped
+            .into_iter()
+            .map(|(path, variants)| SourceAsset { path, variants })
+            .collect(),
+    ))
+}
+
+/// Splits `brick@mobile-low.png` into (`brick.png`, `"mobile-low"`).
+///
+/// Files without a variant tag are the asset's `"default"` encoding.
+fn split_variant(path: &Path) -> (PathBuf, String) {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let Some((base, key)) = stem.split_once(VARIANT_SEPARATOR) else {
+        return (path.to_path_buf(), DEFAULT_VARIANT.to_string());
+    };
+    let file_name = match path.extension().and_then(|s| s.to_str()) {
+        Some(ext) => format!("{base}.{ext}"),
+        None => base.to_string(),
+    };
+    (path.with_file_name(file_name), key.to_string())
 }
 
 /// Loads the `Assets.toml` manifest from the workspace root.
@@ -0,0 +1,49 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CRPECS layout dump.
+//!
+//! Loads a scene file into a fresh `World` and prints how its component data
+//! is laid out: the pages of each archetype, their occupancy, component sizes
+//! and bytes reserved for empty rows. Use `--json` to get a machine-readable
+//! report for comparing runs.
+
+use crate::helpers::*;
+use anyhow::{anyhow, Context, Result};
+use khora_core::scene::SceneFile;
+use khora_data::ecs::World;
+use khora_io::serialization::SerializationService;
+use std::path::Path;
+
+pub fn run(scene: &Path, json: bool) -> Result<()> {
+    let bytes =
+        std::fs::read(scene).with_context(|| format!("Failed to read {}", scene.display()))?;
+    let file = SceneFile::from_bytes(&bytes)
+        .map_err(|e| anyhow!("Invalid scene file {}: {:?}", scene.display(), e))?;
+
+    let mut world = World::new();
+    SerializationService::new()
+        .load_world(&file, &mut world)
+        .map_err(|e| anyhow!("Failed to load {}: {:?}", scene.display(), e))?;
+
+    let layout = world.layout_report();
+    if json {
+        println!("{}", layout.to_json()?);
+    } else {
+        print_task_start("ECS Layout", GEAR, CYAN);
+        println!("{}💡 Scene:{} {}\n", BOLD, RESET, scene.display());
+        print!("{layout}");
+    }
+    Ok(())
+}
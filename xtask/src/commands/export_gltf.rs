@@ -0,0 +1,92 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! glTF export of a scene.
+//!
+//! Loads a scene file into a fresh `World` and writes it, or the subtree of
+//! one named entity, as a `.gltf` or `.glb` file for DCC tools like Blender.
+//! Meshes referenced by UUID are loaded from a packed archive when one is
+//! given; without it, only procedural meshes are exported.
+
+use crate::helpers::*;
+use anyhow::{anyhow, Context, Result};
+use khora_core::scene::SceneFile;
+use khora_data::ecs::World;
+use khora_io::asset::{
+    resolve_pending_assets, AssetService, FileSystemResolver, GltfDecoder, ObjDecoder, PackLoader,
+};
+use khora_io::export::GltfExporter;
+use khora_io::serialization::SerializationService;
+use khora_telemetry::MetricsRegistry;
+use std::fs::{self, File};
+use std::path::Path;
+use std::sync::Arc;
+
+pub fn run(scene: &Path, out: &Path, root: Option<&str>, assets: Option<&Path>) -> Result<()> {
+    print_task_start("glTF Export", GEAR, CYAN);
+
+    let bytes = fs::read(scene).with_context(|| format!("Failed to read {}", scene.display()))?;
+    let file = SceneFile::from_bytes(&bytes)
+        .map_err(|e| anyhow!("Invalid scene file {}: {:?}", scene.display(), e))?;
+    let mut world = World::new();
+    SerializationService::new()
+        .load_world(&file, &mut world)
+        .map_err(|e| anyhow!("Failed to load {}: {:?}", scene.display(), e))?;
+
+    if let Some(archive_dir) = assets {
+        let index_bytes = fs::read(archive_dir.join("index.bin"))
+            .with_context(|| format!("Failed to read the index in '{}'", archive_dir.display()))?;
+        let data_file = File::open(archive_dir.join("data.pack"))
+            .with_context(|| format!("Failed to open the data in '{}'", archive_dir.display()))?;
+        let mut service = AssetService::new(
+            &index_bytes,
+            Box::new(PackLoader::new(data_file)),
+            Arc::new(MetricsRegistry::new()),
+        )?;
+        // Like the packer, external glTF buffers resolve from the working directory.
+        let decoder = GltfDecoder::new(Arc::new(FileSystemResolver::new(".")));
+        service.register_decoder("gltf", decoder.clone());
+        service.register_decoder("glb", decoder);
+        service.register_decoder("obj", ObjDecoder);
+        let bound = resolve_pending_assets(&mut world, &mut service);
+        println!("{}📦 Assets:{} {} handles bound", BOLD, RESET, bound);
+    }
+
+    let mut exporter = GltfExporter::new();
+    match root {
+        Some(name) => {
+            let entity = world
+                .find_by_name(name)
+                .ok_or_else(|| anyhow!("No entity named '{}' in {}", name, scene.display()))?;
+            exporter.add_subtree(&world, entity)?;
+        }
+        None => {
+            exporter.add_world(&world);
+        }
+    }
+    exporter
+        .write(out)
+        .with_context(|| format!("Failed to export {}", out.display()))?;
+
+    println!(
+        "{}{} {} Wrote {} nodes and {} meshes to '{}'",
+        BOLD,
+        GREEN,
+        CHECK,
+        exporter.node_count(),
+        exporter.mesh_count(),
+        out.display()
+    );
+    Ok(())
+}
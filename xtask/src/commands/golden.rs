@@ -0,0 +1,59 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden-image render tests.
+//!
+//! Runs `khora-sdk`'s `golden_image_test` suite, which renders canonical
+//! scenes through each render lane offscreen and compares them against the
+//! PNG references in `crates/khora-sdk/tests/golden/`. With `--bless` the
+//! references are overwritten with the current output instead.
+
+use crate::helpers::*;
+use anyhow::Result;
+
+/// Environment variable read by the golden test suite to re-record references.
+const BLESS_ENV: &str = "KHORA_BLESS_GOLDENS";
+
+pub fn run(bless: bool) -> Result<()> {
+    let args = [
+        "test",
+        "-p",
+        "khora-sdk",
+        "--test",
+        "golden_image_test",
+        "--",
+        "--nocapture",
+    ];
+
+    if bless {
+        print_task_start("Blessing Golden Images", FRAME, MAGENTA);
+        println!(
+            "{}💡 Info:{} Re-rendering every golden scene and overwriting the stored references",
+            BOLD, RESET
+        );
+        execute_command_with_env("cargo", &args, &[(BLESS_ENV, "1")], "Golden bless")?;
+        println!(
+            "{}💡 Review:{} inspect the changed PNGs with `git diff --stat crates/khora-sdk/tests/golden` before committing",
+            BOLD, RESET
+        );
+    } else {
+        print_task_start("Running Golden-Image Tests", FRAME, MAGENTA);
+        println!(
+            "{}💡 Info:{} Comparing offscreen renders against the stored references",
+            BOLD, RESET
+        );
+        execute_command("cargo", &args, "Golden")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GORNA dry run.
+//!
+//! Reads a capture of arbitration rounds recorded by the DCC and re-runs
+//! the budget fitting of each round with every solver, printing the
+//! strategy each agent would have been given. Edit the capture (agent
+//! priorities, VRAM, budget multiplier) to see how allocations move.
+
+use crate::helpers::*;
+use anyhow::{anyhow, Context, Result};
+use khora_control::gorna::{read_capture, replay, GornaSolver};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+pub fn run(capture: &Path, solver: Option<&str>) -> Result<()> {
+    let solvers: Vec<GornaSolver> = match solver {
+        Some(name) => vec![GornaSolver::from_name(name)
+            .ok_or_else(|| anyhow!("Unknown solver '{}' (expected greedy or exhaustive)", name))?],
+        None => GornaSolver::ALL.to_vec(),
+    };
+
+    let file =
+        File::open(capture).with_context(|| format!("Failed to open {}", capture.display()))?;
+    let rounds = read_capture(BufReader::new(file))
+        .with_context(|| format!("Invalid capture {}", capture.display()))?;
+
+    print_task_start("GORNA Replay", GEAR, YELLOW);
+    println!(
+        "{}💡 Capture:{} {} ({} rounds)\n",
+        BOLD,
+        RESET,
+        capture.display(),
+        rounds.len()
+    );
+
+    for (index, round) in rounds.iter().enumerate() {
+        let context = &round.context;
+        println!(
+            "{}Round {}{} — mode {}, budget {:.2}ms × {:.2}, VRAM {}, thermal {:?}, battery {:?}",
+            BOLD,
+            index,
+            RESET,
+            context.mode.name(),
+            round.report.suggested_latency_ms,
+            context.global_budget_multiplier,
+            context
+                .hardware
+                .available_vram
+                .or(context.hardware.total_vram)
+                .map_or_else(|| "unlimited".to_string(), |v| format!("{}MB", v >> 20)),
+            context.hardware.thermal,
+            context.hardware.battery,
+        );
+
+        for &solver in &solvers {
+            let outcome = replay(round, solver);
+            let total_ms: f64 = outcome
+                .budgets
+                .iter()
+                .map(|(_, b)| b.time_limit.as_secs_f64() * 1000.0)
+                .sum();
+            let note = if outcome.background {
+                " (background)"
+            } else if outcome.emergency {
+                " (emergency)"
+            } else {
+                ""
+            };
+            println!(
+                "  {}{:<10}{} {:>7.2}ms{}",
+                CYAN,
+                solver.name(),
+                RESET,
+                total_ms,
+                note
+            );
+            for (agent, budget) in &outcome.budgets {
+                println!(
+                    "    {:<16} {:<16} {:>7.2}ms",
+                    format!("{:?}", agent),
+                    format!("{:?}", budget.strategy_id),
+                    budget.time_limit.as_secs_f64() * 1000.0
+                );
+            }
+            for agent in &outcome.unresponsive {
+                println!(
+                    "    {:<16} {}unresponsive{}",
+                    format!("{:?}", agent),
+                    RED,
+                    RESET
+                );
+            }
+        }
+        println!();
+    }
+
+    print_success("Replay complete.");
+    Ok(())
+}
@@ -15,3 +15,9 @@
 pub mod assets;
 pub mod assets_config;
 pub mod ci;
+pub mod ecs_layout;
+pub mod export_gltf;
+pub mod golden;
+pub mod gorna_replay;
+pub mod perf;
+pub mod thumbnails;
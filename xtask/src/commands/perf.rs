@@ -0,0 +1,58 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Performance gates.
+//!
+//! Runs `khora-sdk`'s ignored `perf_gate_test` suite in release: each gate
+//! drives a canonical scene through `HeadlessRunner` and asserts a
+//! `PerfBudget` on draw calls, frame time and VRAM. `--profile` names the
+//! machine the gates run on, which scales the frame-time budgets.
+
+use crate::helpers::*;
+use anyhow::Result;
+
+/// Environment variable read by `PerfProfile::from_env`.
+const PROFILE_ENV: &str = "KHORA_PERF_PROFILE";
+
+pub fn run(profile: Option<&str>) -> Result<()> {
+    print_task_start("Running Performance Gates", TEST_TUBE, YELLOW);
+    let args = [
+        "test",
+        "-p",
+        "khora-sdk",
+        "--release",
+        "--test",
+        "perf_gate_test",
+        "--",
+        "--ignored",
+        "--nocapture",
+    ];
+
+    match profile {
+        Some(profile) => {
+            println!(
+                "{}💡 Info:{} Frame-time budgets scaled for the '{}' profile",
+                BOLD, RESET, profile
+            );
+            execute_command_with_env("cargo", &args, &[(PROFILE_ENV, profile)], "Perf gates")
+        }
+        None => {
+            println!(
+                "{}💡 Info:{} Frame-time budgets checked against the {} profile (reference by default)",
+                BOLD, RESET, PROFILE_ENV
+            );
+            execute_command("cargo", &args, "Perf gates")
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 eraflo
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pack-time thumbnail generation.
+//!
+//! Loads every mesh and scene of a packed archive through an `AssetService`,
+//! renders it offscreen with the SDK's `ThumbnailService`, and writes the
+//! results to `thumbnails.bin` next to the archive's index. Machines without
+//! a usable adapter skip the step with a warning.
+
+use crate::helpers::*;
+use anyhow::{Context, Result};
+use khora_core::asset::{AssetMetadata, AssetUUID};
+use khora_io::asset::{
+    AssetService, FileSystemResolver, GltfDecoder, ObjDecoder, PackLoader, THUMBNAIL_FILE,
+};
+use khora_sdk::ThumbnailService;
+use khora_telemetry::MetricsRegistry;
+use std::fs::{self, File};
+use std::path::Path;
+use std::sync::Arc;
+
+pub fn run(archive_dir: &Path, size: u32) -> Result<()> {
+    print_task_start("Generating Thumbnails", FRAME, MAGENTA);
+
+    let index_bytes = fs::read(archive_dir.join("index.bin"))
+        .with_context(|| format!("Failed to read the index in '{}'", archive_dir.display()))?;
+    let (index, _): (Vec<AssetMetadata>, _) =
+        bincode::serde::decode_from_slice(&index_bytes, bincode::config::standard())
+            .context("Failed to decode the index")?;
+    let data_file = File::open(archive_dir.join("data.pack"))
+        .with_context(|| format!("Failed to open the data in '{}'", archive_dir.display()))?;
+    let mut assets = AssetService::new(
+        &index_bytes,
+        Box::new(PackLoader::new(data_file)),
+        Arc::new(MetricsRegistry::new()),
+    )?;
+    assets.register_decoder("obj", ObjDecoder);
+
+    let mut service = match ThumbnailService::new(size) {
+        Ok(service) => service,
+        Err(e) => {
+            println!(
+                "{}{}⚠ Skipped:{} no headless adapter to render thumbnails with ({:#})",
+                BOLD, YELLOW, RESET, e
+            );
+            return Ok(());
+        }
+    };
+
+    let targets: Vec<(AssetUUID, &Path)> = index
+        .iter()
+        .filter(|m| ThumbnailService::supports(&m.asset_type_name) && !m.variants.is_empty())
+        .map(|m| (m.uuid, m.source_path.as_path()))
+        .collect();
+    println!(
+        "{}🔎 Found:{} {} assets with a thumbnail ({}x{}).",
+        BOLD,
+        RESET,
+        targets.len(),
+        size,
+        size
+    );
+
+    let mut failed = 0;
+    for (uuid, source_path) in &targets {
+        // External glTF buffers and images sit next to the source file.
+        let base = source_path.parent().unwrap_or(Path::new("."));
+        let decoder = GltfDecoder::new(Arc::new(FileSystemResolver::new(base)));
+        assets.register_decoder("gltf", decoder.clone());
+        assets.register_decoder("glb", decoder);
+
+        if let Err(e) = service.thumbnail(uuid, &mut assets) {
+            failed += 1;
+            print_error(&format!("{}: {:#}", source_path.display(), e));
+        }
+    }
+
+    let cache = service.into_cache();
+    cache.save(archive_dir)?;
+    println!(
+        "{}{} {} Wrote {} thumbnails to '{}' ({} failed)",
+        BOLD,
+        GREEN,
+        CHECK,
+        cache.len(),
+        archive_dir.join(THUMBNAIL_FILE).display(),
+        failed
+    );
+    Ok(())
+}
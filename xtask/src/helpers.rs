@@ -36,6 +36,7 @@ pub const TEST_TUBE: &str = "🧪";
 pub const MAGNIFIER: &str = "🔍";
 pub const BRUSH: &str = "🎨";
 pub const CLIPPY: &str = "📎";
+pub const FRAME: &str = "🖼";
 
 pub const BANNER: &str = concat!(
     "\x1b[1m",
@@ -63,7 +64,7 @@ pub fn print_custom_help() {
         HAMMER, BLUE, BOLD, RESET
     );
     println!(
-        "  {} {} {}test{}    - Run all tests in the workspace.",
+        "  {} {} {}test{}    - Run all tests in the workspace (`--perf` adds the performance gates).",
         TEST_TUBE, GREEN, BOLD, RESET
     );
     println!(
@@ -78,6 +79,22 @@ pub fn print_custom_help() {
         "  {} {} {}clippy{}  - Run clippy on all crates with warnings as errors.",
         CLIPPY, YELLOW, BOLD, RESET
     );
+    println!(
+        "  {} {} {}golden{}  - Run golden-image render tests (`--bless` to record new references).",
+        FRAME, MAGENTA, BOLD, RESET
+    );
+    println!(
+        "  {} {} {}ecs-layout{} - Dump the ECS page layout of a scene (`--json` for machine output).",
+        GEAR, CYAN, BOLD, RESET
+    );
+    println!(
+        "  {} {} {}export-gltf{} - Export a scene or entity subtree to glTF for DCC tools (`--root` for a subtree).",
+        FRAME, GREEN, BOLD, RESET
+    );
+    println!(
+        "  {} {} {}gorna-replay{} - Re-run a GORNA capture offline and print each solver's allocations.",
+        GEAR, YELLOW, BOLD, RESET
+    );
     println!(
         "  {} {} {}all{}     - Run all CI tasks (build, test, check, format, clippy).",
         ROCKET, RED, BOLD, RESET
@@ -109,11 +126,22 @@ pub fn print_command_info(cmd: &str, args: &[&str]) {
 }
 
 pub fn execute_command(cmd: &str, args: &[&str], task_name: &str) -> Result<()> {
+    execute_command_with_env(cmd, args, &[], task_name)
+}
+
+/// Like [`execute_command`], with extra environment variables set on the child process.
+pub fn execute_command_with_env(
+    cmd: &str,
+    args: &[&str],
+    envs: &[(&str, &str)],
+    task_name: &str,
+) -> Result<()> {
     let start_time = Instant::now();
     print_command_info(cmd, args);
 
     let mut command = Command::new(cmd);
     command.args(args);
+    command.envs(envs.iter().copied());
 
     let status = command.status()?;
     let duration = start_time.elapsed();
@@ -20,6 +20,8 @@ mod helpers;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use khora_core::asset::{AssetPlatform, DEFAULT_THUMBNAIL_SIZE};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -40,7 +42,14 @@ enum Commands {
     /// Build all crates in the workspace.
     Build,
     /// Run all tests in the workspace.
-    Test,
+    Test {
+        /// Also run the performance gates, in release.
+        #[clap(long)]
+        perf: bool,
+        /// Machine profile scaling the frame-time budgets (reference, ci, or a factor).
+        #[clap(long, requires = "perf")]
+        profile: Option<String>,
+    },
     /// Run `cargo check` on all crates.
     Check,
     /// Format all code in the workspace.
@@ -49,6 +58,43 @@ enum Commands {
     Clippy,
     /// Run all CI tasks (build, test, check, format, clippy).
     All,
+    /// Run the golden-image render tests.
+    Golden {
+        /// Overwrite the stored reference images with the current output.
+        #[clap(long)]
+        bless: bool,
+    },
+    /// Dump the CRPECS page layout of a scene to guide storage tuning.
+    EcsLayout {
+        /// Scene file to load.
+        scene: PathBuf,
+        /// Print the report as JSON instead of a table.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Export a scene, or one entity subtree, to a .gltf or .glb file.
+    ExportGltf {
+        /// Scene file to load.
+        scene: PathBuf,
+        /// File to write; a `.glb` extension selects the binary format.
+        #[clap(long, short)]
+        out: PathBuf,
+        /// Only export the entity with this name and its descendants.
+        #[clap(long)]
+        root: Option<String>,
+        /// Packed archive to load the scene's meshes from.
+        #[clap(long)]
+        assets: Option<PathBuf>,
+    },
+    /// Re-run the GORNA arbitrations of a capture and print what each
+    /// solver would have allocated.
+    GornaReplay {
+        /// Capture file written by the DCC (`DccConfig::capture_path`).
+        capture: PathBuf,
+        /// Only run this solver (greedy, exhaustive). Defaults to all.
+        #[clap(long)]
+        solver: Option<String>,
+    },
 
     /// Commands for asset pipeline management.
     #[clap(subcommand)]
@@ -58,7 +104,38 @@ enum Commands {
 #[derive(Subcommand, Debug)]
 pub enum AssetCommand {
     /// Scans, builds metadata, and packs all assets into optimized archives.
-    Pack,
+    Pack {
+        /// Only pack variants usable on this platform (desktop, mobile, web).
+        #[clap(long)]
+        platform: Option<AssetPlatform>,
+        /// Version recorded in the archive, used to match patches.
+        #[clap(long, default_value_t = 1)]
+        version: u32,
+        /// Also render thumbnails of meshes and scenes into `thumbnails.bin`.
+        #[clap(long)]
+        thumbnails: bool,
+    },
+    /// Renders thumbnails of the meshes and scenes of a packed archive.
+    Thumbnails {
+        /// Directory of the archive.
+        #[clap(long, default_value = ".dist/assets")]
+        dir: PathBuf,
+        /// Edge length of the thumbnails, in pixels.
+        #[clap(long, default_value_t = DEFAULT_THUMBNAIL_SIZE)]
+        size: u32,
+    },
+    /// Builds a delta patch archive against a previously packed full archive.
+    Patch {
+        /// Directory of the full archive players already have.
+        #[clap(long)]
+        base: PathBuf,
+        /// Only include variants usable on this platform (desktop, mobile, web).
+        #[clap(long)]
+        platform: Option<AssetPlatform>,
+        /// Version after the patch (defaults to the base version + 1).
+        #[clap(long)]
+        version: Option<u32>,
+    },
 }
 
 fn main() -> Result<()> {
@@ -67,14 +144,40 @@ fn main() -> Result<()> {
     if let Some(command) = cli.command {
         match command {
             Commands::Build => commands::ci::build()?,
-            Commands::Test => commands::ci::test()?,
+            Commands::Test { perf, profile } => {
+                commands::ci::test()?;
+                if perf {
+                    commands::perf::run(profile.as_deref())?;
+                }
+            }
             Commands::Check => commands::ci::check()?,
             Commands::Format => commands::ci::format()?,
             Commands::Clippy => commands::ci::clippy()?,
             Commands::All => commands::ci::all()?,
+            Commands::Golden { bless } => commands::golden::run(bless)?,
+            Commands::EcsLayout { scene, json } => commands::ecs_layout::run(&scene, json)?,
+            Commands::ExportGltf {
+                scene,
+                out,
+                root,
+                assets,
+            } => commands::export_gltf::run(&scene, &out, root.as_deref(), assets.as_deref())?,
+            Commands::GornaReplay { capture, solver } => {
+                commands::gorna_replay::run(&capture, solver.as_deref())?
+            }
 
             Commands::Assets(command) => match command {
-                AssetCommand::Pack => commands::assets::pack()?,
+                AssetCommand::Pack {
+                    platform,
+                    version,
+                    thumbnails,
+                } => commands::assets::pack(platform, version, thumbnails)?,
+                AssetCommand::Thumbnails { dir, size } => commands::thumbnails::run(&dir, size)?,
+                AssetCommand::Patch {
+                    base,
+                    platform,
+                    version,
+                } => commands::assets::patch(&base, platform, version)?,
             },
         }
     } else {